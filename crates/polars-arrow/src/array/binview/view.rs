@@ -449,7 +449,33 @@ fn validate_utf8(b: &[u8]) -> PolarsResult<()> {
 }
 
 pub fn validate_utf8_views<B: AsRef<[u8]>>(views: &[View], buffers: &[B]) -> PolarsResult<()> {
-    validate_views(views, buffers, validate_utf8)
+    // Validating the strings one by one is slow for short strings, so we validate every buffer
+    // at once instead. A slice of valid UTF-8 is valid UTF-8 if it starts and ends on a
+    // character boundary. Buffers can contain bytes no view refers to, if those are not valid
+    // UTF-8 we fall back to validating the strings.
+    if !buffers
+        .iter()
+        .all(|b| simdutf8::basic::from_utf8(b.as_ref()).is_ok())
+    {
+        return validate_views(views, buffers, validate_utf8);
+    }
+
+    validate_binary_views(views, buffers)?;
+    let is_boundary = |b: &[u8], i: usize| b.get(i).is_none_or(|&c| (c as i8) >= -0x40);
+    for view in views {
+        if view.length <= View::MAX_INLINE_SIZE {
+            validate_utf8(unsafe { view.get_inlined_slice_unchecked() })?;
+        } else {
+            let data = buffers[view.buffer_idx as usize].as_ref();
+            let start = view.offset as usize;
+            let end = start + view.length as usize;
+            polars_ensure!(
+                is_boundary(data, start) && is_boundary(data, end),
+                ComputeError: "invalid utf8"
+            );
+        }
+    }
+    Ok(())
 }
 
 /// Checks the views for valid UTF-8. Assumes the first num_trusted_buffers are
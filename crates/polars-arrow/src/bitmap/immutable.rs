@@ -193,6 +193,13 @@ impl Bitmap {
         )
     }
 
+    /// Returns the address and the size in bytes of the storage underlying this [`Bitmap`],
+    /// which may be shared with other bitmaps.
+    #[inline]
+    pub(crate) fn storage_bytes(&self) -> (usize, usize) {
+        (self.storage.as_ptr() as usize, self.storage.len())
+    }
+
     /// Returns the number of set bits on this [`Bitmap`].
     ///
     /// See `unset_bits` for details.
@@ -214,6 +214,16 @@ impl<T> Buffer<T> {
         self.storage.as_ptr()
     }
 
+    /// Returns the address and the size in bytes of the storage underlying this buffer, which
+    /// may be shared with other buffers.
+    #[inline]
+    pub(crate) fn storage_bytes(&self) -> (usize, usize) {
+        (
+            self.storage.as_ptr() as usize,
+            self.storage.len() * size_of::<T>(),
+        )
+    }
+
     /// Returns the start offset of this buffer within the underlying storage.
     #[inline]
     pub fn offset(&self) -> usize {
@@ -1,5 +1,8 @@
+use polars_utils::aliases::PlHashSet;
+
 use crate::array::*;
 use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
 use crate::datatypes::PhysicalType;
 pub use crate::types::PrimitiveType;
 use crate::{match_integer_type, with_match_primitive_type_full};
@@ -129,3 +132,244 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         },
     }
 }
+
+/// The kind of buffer an allocation of a [`MemoryProfile`] is attributed to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryKind {
+    Values,
+    Validity,
+    Offsets,
+    Dictionary,
+}
+
+/// The heap memory held by one or more arrays, by kind of buffer.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MemoryProfile {
+    /// The values, including the views and data buffers of view arrays and the keys of
+    /// dictionary arrays.
+    pub values: usize,
+    /// The validity bitmaps.
+    pub validity: usize,
+    /// The offsets of (large) binary, string, list and map arrays and the types and offsets
+    /// of union arrays.
+    pub offsets: usize,
+    /// Everything held by the values of dictionary arrays.
+    pub dictionary: usize,
+    /// The allocations that were already attributed by the [`MemoryProfiler`], which are not
+    /// included in the other kinds.
+    pub shared: usize,
+}
+
+impl MemoryProfile {
+    /// The memory attributed to this profile, excluding the shared memory.
+    pub fn total(&self) -> usize {
+        self.values + self.validity + self.offsets + self.dictionary
+    }
+
+    fn kind_mut(&mut self, kind: MemoryKind) -> &mut usize {
+        match kind {
+            MemoryKind::Values => &mut self.values,
+            MemoryKind::Validity => &mut self.validity,
+            MemoryKind::Offsets => &mut self.offsets,
+            MemoryKind::Dictionary => &mut self.dictionary,
+        }
+    }
+}
+
+impl std::ops::AddAssign for MemoryProfile {
+    fn add_assign(&mut self, other: Self) {
+        self.values += other.values;
+        self.validity += other.validity;
+        self.offsets += other.offsets;
+        self.dictionary += other.dictionary;
+        self.shared += other.shared;
+    }
+}
+
+/// Attributes the allocations held by arrays to [`MemoryProfile`]s.
+///
+/// Unlike [`estimated_bytes_size`], the whole allocation underlying a (sliced) buffer is
+/// counted, and only the first time it is seen: arrays that share a buffer with an array that
+/// was profiled before count it as [`MemoryProfile::shared`]. Profiling every column of a
+/// frame with the same profiler thus sums up to the memory held by the frame.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryProfiler {
+    seen: PlHashSet<usize>,
+}
+
+impl MemoryProfiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attribute an allocation of `size` bytes at `addr` as `kind` to `profile`, or as shared
+    /// if it was attributed before.
+    pub fn add_allocation(
+        &mut self,
+        addr: usize,
+        size: usize,
+        kind: MemoryKind,
+        profile: &mut MemoryProfile,
+    ) {
+        if size == 0 {
+            return;
+        }
+        if self.seen.insert(addr) {
+            *profile.kind_mut(kind) += size;
+        } else {
+            profile.shared += size;
+        }
+    }
+
+    fn add_buffer<T>(&mut self, buffer: &Buffer<T>, kind: MemoryKind, profile: &mut MemoryProfile) {
+        let (addr, size) = buffer.storage_bytes();
+        self.add_allocation(addr, size, kind, profile)
+    }
+
+    fn add_bitmap(
+        &mut self,
+        bitmap: Option<&Bitmap>,
+        kind: MemoryKind,
+        profile: &mut MemoryProfile,
+    ) {
+        if let Some(bitmap) = bitmap {
+            let (addr, size) = bitmap.storage_bytes();
+            self.add_allocation(addr, size, kind, profile)
+        }
+    }
+
+    /// Attribute the buffers of `array`, including its children, to `profile`.
+    pub fn add_array(&mut self, array: &dyn Array, profile: &mut MemoryProfile) {
+        self.add_array_as(array, None, profile)
+    }
+
+    /// Attribute all the buffers of `array` to [`MemoryProfile::dictionary`].
+    pub fn add_dictionary(&mut self, array: &dyn Array, profile: &mut MemoryProfile) {
+        self.add_array_as(array, Some(MemoryKind::Dictionary), profile)
+    }
+
+    fn add_array_as(
+        &mut self,
+        array: &dyn Array,
+        kind: Option<MemoryKind>,
+        profile: &mut MemoryProfile,
+    ) {
+        use PhysicalType::*;
+        let values = kind.unwrap_or(MemoryKind::Values);
+        let validity = kind.unwrap_or(MemoryKind::Validity);
+        let offsets = kind.unwrap_or(MemoryKind::Offsets);
+        match array.dtype().to_physical_type() {
+            Null => {},
+            Boolean => {
+                let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                self.add_bitmap(Some(array.values()), values, profile);
+                self.add_bitmap(array.validity(), validity, profile);
+            },
+            Primitive(PrimitiveType::DaysMs) => {
+                let array = array.as_any().downcast_ref::<DaysMsArray>().unwrap();
+                self.add_buffer(array.values(), values, profile);
+                self.add_bitmap(array.validity(), validity, profile);
+            },
+            Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<$T>>()
+                    .unwrap();
+                self.add_buffer(array.values(), values, profile);
+                self.add_bitmap(array.validity(), validity, profile);
+            }),
+            Binary | LargeBinary | Utf8 | LargeUtf8 => {
+                macro_rules! add_binary {
+                    ($ty:ty) => {{
+                        let array = array.as_any().downcast_ref::<$ty>().unwrap();
+                        self.add_buffer(array.values(), values, profile);
+                        self.add_buffer(array.offsets().buffer(), offsets, profile);
+                        self.add_bitmap(array.validity(), validity, profile);
+                    }};
+                }
+                match array.dtype().to_physical_type() {
+                    Binary => add_binary!(BinaryArray<i32>),
+                    LargeBinary => add_binary!(BinaryArray<i64>),
+                    Utf8 => add_binary!(Utf8Array<i32>),
+                    _ => add_binary!(Utf8Array<i64>),
+                }
+            },
+            BinaryView | Utf8View => {
+                macro_rules! add_binview {
+                    ($ty:ty) => {{
+                        let array = array.as_any().downcast_ref::<$ty>().unwrap();
+                        self.add_buffer(array.views(), values, profile);
+                        for buffer in array.data_buffers().iter() {
+                            self.add_buffer(buffer, values, profile);
+                        }
+                        self.add_bitmap(array.validity(), validity, profile);
+                    }};
+                }
+                match array.dtype().to_physical_type() {
+                    BinaryView => add_binview!(BinaryViewArray),
+                    _ => add_binview!(Utf8ViewArray),
+                }
+            },
+            FixedSizeBinary => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .unwrap();
+                self.add_buffer(array.values(), values, profile);
+                self.add_bitmap(array.validity(), validity, profile);
+            },
+            List => {
+                let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+                self.add_buffer(array.offsets().buffer(), offsets, profile);
+                self.add_bitmap(array.validity(), validity, profile);
+                self.add_array_as(array.values().as_ref(), kind, profile);
+            },
+            LargeList => {
+                let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                self.add_buffer(array.offsets().buffer(), offsets, profile);
+                self.add_bitmap(array.validity(), validity, profile);
+                self.add_array_as(array.values().as_ref(), kind, profile);
+            },
+            FixedSizeList => {
+                let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                self.add_bitmap(array.validity(), validity, profile);
+                self.add_array_as(array.values().as_ref(), kind, profile);
+            },
+            Struct => {
+                let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+                self.add_bitmap(array.validity(), validity, profile);
+                for field in array.values() {
+                    self.add_array_as(field.as_ref(), kind, profile);
+                }
+            },
+            Union => {
+                let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+                self.add_buffer(array.types(), offsets, profile);
+                if let Some(union_offsets) = array.offsets() {
+                    self.add_buffer(union_offsets, offsets, profile);
+                }
+                for field in array.fields() {
+                    self.add_array_as(field.as_ref(), kind, profile);
+                }
+            },
+            Map => {
+                let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+                self.add_buffer(array.offsets().buffer(), offsets, profile);
+                self.add_bitmap(array.validity(), validity, profile);
+                self.add_array_as(array.field().as_ref(), kind, profile);
+            },
+            Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                self.add_array_as(array.keys(), kind, profile);
+                self.add_array_as(
+                    array.values().as_ref(),
+                    Some(MemoryKind::Dictionary),
+                    profile,
+                );
+            }),
+        }
+    }
+}
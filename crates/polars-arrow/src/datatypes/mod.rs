@@ -183,6 +183,25 @@ pub struct ExtensionType {
     pub metadata: Option<PlSmallStr>,
 }
 
+/// The name of Arrow's canonical UUID extension type.
+pub const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+impl ExtensionType {
+    /// Arrow's canonical UUID extension type, stored as `FixedSizeBinary(16)`.
+    pub fn uuid() -> Self {
+        Self {
+            name: PlSmallStr::from_static(UUID_EXTENSION_NAME),
+            inner: ArrowDataType::FixedSizeBinary(16),
+            metadata: None,
+        }
+    }
+
+    /// Whether this is Arrow's canonical UUID extension type.
+    pub fn is_uuid(&self) -> bool {
+        self.name == UUID_EXTENSION_NAME && self.inner == ArrowDataType::FixedSizeBinary(16)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UnionType {
     pub fields: Vec<Field>,
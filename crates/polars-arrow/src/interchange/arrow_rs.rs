@@ -0,0 +1,166 @@
+//! Conversions to and from the `RecordBatch` of arrow-rs, which also implements
+//! [`IntoDataFrame`] and [`FromDataFrame`].
+//!
+//! Arrays are moved between the libraries through the C Data Interface, so the buffers are not
+//! copied.
+use std::sync::Arc;
+
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema, from_ffi, to_ffi};
+use arrow_array::{RecordBatchOptions, make_array};
+use polars_error::{PolarsResult, polars_bail, to_compute_err};
+pub use {arrow_array, arrow_schema};
+
+use super::{ChunkStream, FromDataFrame, IntoDataFrame};
+use crate::array::{Array, ArrayRef};
+use crate::compute::concatenate::concatenate;
+use crate::datatypes::{ArrowDataType, ArrowSchema, ArrowSchemaRef, Field};
+use crate::ffi;
+use crate::record_batch::RecordBatch;
+
+// SAFETY (for the transmutes below): both crates define the `#[repr(C)]` structs of the C Data
+// Interface, so a struct of one can be moved into the other.
+
+fn field_to_rs(field: &Field) -> FFI_ArrowSchema {
+    let schema = ffi::export_field_to_c(field);
+    unsafe { std::mem::transmute::<ffi::ArrowSchema, FFI_ArrowSchema>(schema) }
+}
+
+fn field_from_rs(schema: FFI_ArrowSchema) -> PolarsResult<Field> {
+    let schema = unsafe { std::mem::transmute::<FFI_ArrowSchema, ffi::ArrowSchema>(schema) };
+    unsafe { ffi::import_field_from_c(&schema) }
+}
+
+/// Convert a schema to an arrow-rs schema.
+pub fn schema_to_arrow_rs(schema: &ArrowSchema) -> PolarsResult<arrow_schema::SchemaRef> {
+    let dtype = ArrowDataType::Struct(schema.iter_values().cloned().collect());
+    let c_schema = field_to_rs(&Field::new("".into(), dtype, false));
+    let schema = arrow_schema::Schema::try_from(&c_schema).map_err(to_compute_err)?;
+    Ok(Arc::new(schema))
+}
+
+/// Convert an arrow-rs schema to a schema.
+pub fn schema_from_arrow_rs(schema: &arrow_schema::Schema) -> PolarsResult<ArrowSchemaRef> {
+    let c_schema = FFI_ArrowSchema::try_from(schema).map_err(to_compute_err)?;
+    let ArrowDataType::Struct(fields) = field_from_rs(c_schema)?.dtype else {
+        polars_bail!(ComputeError: "expected the schema of a struct");
+    };
+    Ok(Arc::new(
+        fields.into_iter().map(|f| (f.name.clone(), f)).collect(),
+    ))
+}
+
+/// Move an array of type `field` to arrow-rs, without copying its buffers.
+pub fn array_to_arrow_rs(array: ArrayRef, field: &Field) -> PolarsResult<arrow_array::ArrayRef> {
+    let c_schema = field_to_rs(field);
+    let c_array = ffi::export_array_to_c(array);
+    let c_array = unsafe { std::mem::transmute::<ffi::ArrowArray, FFI_ArrowArray>(c_array) };
+    let data = unsafe { from_ffi(c_array, &c_schema) }.map_err(to_compute_err)?;
+    Ok(make_array(data))
+}
+
+/// Import an arrow-rs array of type `dtype`, without copying its buffers.
+pub fn array_from_arrow_rs(
+    array: &arrow_array::ArrayRef,
+    dtype: &ArrowDataType,
+) -> PolarsResult<ArrayRef> {
+    let (c_array, _) = to_ffi(&array.to_data()).map_err(to_compute_err)?;
+    let c_array = unsafe { std::mem::transmute::<FFI_ArrowArray, ffi::ArrowArray>(c_array) };
+    unsafe { ffi::import_array_from_c(c_array, dtype.clone()) }
+}
+
+/// Convert a record batch to an arrow-rs record batch of the arrow-rs version of its schema.
+pub fn batch_to_arrow_rs(
+    batch: RecordBatch,
+    schema: &arrow_schema::SchemaRef,
+) -> PolarsResult<arrow_array::RecordBatch> {
+    let height = batch.height();
+    let (batch_schema, arrays) = batch.into_schema_and_arrays();
+    let columns = arrays
+        .into_iter()
+        .zip(batch_schema.iter_values())
+        .map(|(array, field)| array_to_arrow_rs(array, field))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let options = RecordBatchOptions::new().with_row_count(Some(height));
+    arrow_array::RecordBatch::try_new_with_options(schema.clone(), columns, &options)
+        .map_err(to_compute_err)
+}
+
+/// Convert an arrow-rs record batch to a record batch of the converted `schema` of the batch.
+pub fn batch_from_arrow_rs(
+    batch: &arrow_array::RecordBatch,
+    schema: &ArrowSchemaRef,
+) -> PolarsResult<RecordBatch> {
+    let arrays = batch
+        .columns()
+        .iter()
+        .zip(schema.iter_values())
+        .map(|(array, field)| array_from_arrow_rs(array, field.dtype()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    RecordBatch::try_new(batch.num_rows(), schema.clone(), arrays)
+}
+
+impl IntoDataFrame for arrow_array::RecordBatch {
+    fn schema(&self) -> PolarsResult<ArrowSchemaRef> {
+        schema_from_arrow_rs(&self.schema())
+    }
+
+    /// The requested schema is ignored, as arrow-rs does not cast while exporting.
+    fn into_chunks(
+        self,
+        _requested_schema: Option<&ArrowSchema>,
+    ) -> PolarsResult<(ArrowSchemaRef, ChunkStream)> {
+        let schema = IntoDataFrame::schema(&self)?;
+        let batch = batch_from_arrow_rs(&self, &schema)?;
+        Ok((schema, Box::new(std::iter::once(Ok(batch)))))
+    }
+}
+
+impl FromDataFrame for arrow_array::RecordBatch {
+    /// Collects the chunks into a single batch, concatenating the columns if there are several.
+    fn from_chunks(schema: ArrowSchemaRef, chunks: ChunkStream) -> PolarsResult<Self> {
+        let mut chunks = chunks.collect::<PolarsResult<Vec<_>>>()?;
+        let batch = if chunks.len() == 1 {
+            chunks.pop().unwrap()
+        } else {
+            let height = chunks.iter().map(|c| c.height()).sum();
+            let arrays = (0..schema.len())
+                .map(|i| {
+                    let arrays = chunks.iter().map(|c| c.arrays()[i].as_ref());
+                    concatenate(&arrays.collect::<Vec<&dyn Array>>())
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            RecordBatch::try_new(height, schema.clone(), arrays)?
+        };
+        batch_to_arrow_rs(batch, &schema_to_arrow_rs(&schema)?)
+    }
+}
+
+impl IntoDataFrame for Vec<arrow_array::RecordBatch> {
+    fn schema(&self) -> PolarsResult<ArrowSchemaRef> {
+        match self.first() {
+            Some(batch) => schema_from_arrow_rs(&batch.schema()),
+            None => Ok(ArrowSchemaRef::default()),
+        }
+    }
+
+    fn into_chunks(
+        self,
+        _requested_schema: Option<&ArrowSchema>,
+    ) -> PolarsResult<(ArrowSchemaRef, ChunkStream)> {
+        let schema = IntoDataFrame::schema(&self)?;
+        let chunk_schema = schema.clone();
+        let chunks = self
+            .into_iter()
+            .map(move |batch| batch_from_arrow_rs(&batch, &chunk_schema));
+        Ok((schema, Box::new(chunks)))
+    }
+}
+
+impl FromDataFrame for Vec<arrow_array::RecordBatch> {
+    fn from_chunks(schema: ArrowSchemaRef, chunks: ChunkStream) -> PolarsResult<Self> {
+        let rs_schema = schema_to_arrow_rs(&schema)?;
+        chunks
+            .map(|chunk| batch_to_arrow_rs(chunk?, &rs_schema))
+            .collect()
+    }
+}
@@ -0,0 +1,82 @@
+//! A Rust-native handshake to exchange data frames between libraries, the equivalent of the
+//! [Arrow PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html)
+//! without going through Python or C.
+//!
+//! A producer implements [`IntoDataFrame`] and a consumer [`FromDataFrame`]; [`convert`]
+//! connects the two:
+//! 1. the consumer sees the schema of the producer and may request a different one (e.g. with
+//!    large strings instead of string views), which the producer honors where it can;
+//! 2. the producer streams its chunks as [`RecordBatch`]es, which the consumer collects.
+//!
+//! With the `arrow_rs` feature this is implemented for the `RecordBatch` of arrow-rs.
+#[cfg(feature = "arrow_rs")]
+pub mod arrow_rs;
+
+use std::sync::Arc;
+
+use polars_error::PolarsResult;
+
+use crate::datatypes::{ArrowSchema, ArrowSchemaRef};
+use crate::record_batch::RecordBatch;
+
+/// The chunks of a data frame, which all have the schema it was exported with.
+pub type ChunkStream = Box<dyn Iterator<Item = PolarsResult<RecordBatch>> + Send>;
+
+/// A data frame that can be handed to a [`FromDataFrame`] consumer.
+pub trait IntoDataFrame {
+    /// The schema of the data frame as it would be exported without a requested schema.
+    fn schema(&self) -> PolarsResult<ArrowSchemaRef>;
+
+    /// Export the data frame as a stream of chunks.
+    ///
+    /// If the consumer requested a schema, the producer should convert the columns to it
+    /// where it can, and return an error if the request can't be satisfied. Returns the
+    /// schema of the chunks.
+    fn into_chunks(
+        self,
+        requested_schema: Option<&ArrowSchema>,
+    ) -> PolarsResult<(ArrowSchemaRef, ChunkStream)>;
+}
+
+/// A data frame that can be created from an [`IntoDataFrame`] producer.
+pub trait FromDataFrame: Sized {
+    /// The schema the consumer would like to receive for data exported with `schema`, or
+    /// `None` to receive it as it is.
+    fn requested_schema(_schema: &ArrowSchema) -> Option<ArrowSchema> {
+        None
+    }
+
+    /// Create the data frame from the exported chunks.
+    fn from_chunks(schema: ArrowSchemaRef, chunks: ChunkStream) -> PolarsResult<Self>;
+}
+
+/// Convert between data frame types with the [`IntoDataFrame`] / [`FromDataFrame`] handshake.
+pub fn convert<S: IntoDataFrame, T: FromDataFrame>(source: S) -> PolarsResult<T> {
+    let schema = source.schema()?;
+    let requested = T::requested_schema(&schema);
+    let (schema, chunks) = source.into_chunks(requested.as_ref())?;
+    T::from_chunks(schema, chunks)
+}
+
+impl IntoDataFrame for Vec<RecordBatch> {
+    fn schema(&self) -> PolarsResult<ArrowSchemaRef> {
+        match self.first() {
+            Some(batch) => Ok(Arc::new(batch.schema().clone())),
+            None => Ok(ArrowSchemaRef::default()),
+        }
+    }
+
+    fn into_chunks(
+        self,
+        _requested_schema: Option<&ArrowSchema>,
+    ) -> PolarsResult<(ArrowSchemaRef, ChunkStream)> {
+        let schema = self.schema()?;
+        Ok((schema, Box::new(self.into_iter().map(Ok))))
+    }
+}
+
+impl FromDataFrame for Vec<RecordBatch> {
+    fn from_chunks(_schema: ArrowSchemaRef, chunks: ChunkStream) -> PolarsResult<Self> {
+        chunks.collect()
+    }
+}
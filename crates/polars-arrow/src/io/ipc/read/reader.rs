@@ -1,4 +1,5 @@
 use std::io::{Read, Seek};
+use std::sync::Arc;
 
 use polars_error::PolarsResult;
 
@@ -7,6 +8,7 @@ use super::file::{get_message_from_block, get_record_batch};
 use super::{Dictionaries, FileMetadata, read_batch, read_file_dictionaries};
 use crate::array::Array;
 use crate::datatypes::ArrowSchema;
+use crate::io::trusted_utf8::{binary_as_strings, strings_as_binary};
 use crate::record_batch::RecordBatchT;
 
 /// An iterator of [`RecordBatchT`]s from an Arrow IPC file.
@@ -20,6 +22,8 @@ pub struct FileReader<R: Read + Seek> {
     remaining: usize,
     data_scratch: Vec<u8>,
     message_scratch: Vec<u8>,
+    // the metadata with the string columns read as binary, if UTF-8 is not validated
+    binary_metadata: Option<FileMetadata>,
 }
 
 impl<R: Read + Seek> FileReader<R> {
@@ -43,6 +47,7 @@ impl<R: Read + Seek> FileReader<R> {
             current_block: 0,
             data_scratch: Default::default(),
             message_scratch: Default::default(),
+            binary_metadata: None,
         }
     }
 
@@ -64,6 +69,7 @@ impl<R: Read + Seek> FileReader<R> {
             current_block: 0,
             data_scratch: Default::default(),
             message_scratch: Default::default(),
+            binary_metadata: None,
         }
     }
 
@@ -114,6 +120,30 @@ impl<R: Read + Seek> FileReader<R> {
         (self.data_scratch, self.message_scratch) = scratches;
     }
 
+    /// Set whether the string columns are validated to be UTF-8 (the default). Dictionaries are
+    /// always validated.
+    ///
+    /// # Safety
+    /// If not validated, the string data in the file must be valid UTF-8.
+    pub unsafe fn set_validate_utf8(&mut self, validate: bool) {
+        self.binary_metadata = (!validate).then(|| {
+            let schema = self
+                .metadata
+                .schema
+                .iter()
+                .map(|(name, field)| {
+                    let mut field = field.clone();
+                    field.dtype = strings_as_binary(&field.dtype);
+                    (name.clone(), field)
+                })
+                .collect();
+            FileMetadata {
+                schema: Arc::new(schema),
+                ..self.metadata.clone()
+            }
+        });
+    }
+
     fn read_dictionaries(&mut self) -> PolarsResult<()> {
         if self.dictionaries.is_none() {
             self.dictionaries = Some(read_file_dictionaries(
@@ -183,7 +213,7 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
         let chunk = read_batch(
             &mut self.reader,
             self.dictionaries.as_ref().unwrap(),
-            &self.metadata,
+            self.binary_metadata.as_ref().unwrap_or(&self.metadata),
             self.projection.as_ref().map(|x| x.columns.as_ref()),
             Some(self.remaining),
             block,
@@ -198,6 +228,21 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
         } else {
             chunk
         };
+        let chunk = match self.binary_metadata {
+            None => chunk,
+            Some(_) => chunk.map(|chunk| {
+                let schema = Arc::new(self.schema().clone());
+                let length = chunk.len();
+                let arrays = chunk
+                    .into_arrays()
+                    .into_iter()
+                    .zip(schema.iter_values())
+                    // SAFETY: the caller of `set_validate_utf8` trusts the data to be UTF-8.
+                    .map(|(array, field)| unsafe { binary_as_strings(array, &field.dtype) })
+                    .collect();
+                RecordBatchT::new(length, schema, arrays)
+            }),
+        };
         Some(chunk)
     }
 }
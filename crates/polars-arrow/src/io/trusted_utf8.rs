@@ -0,0 +1,101 @@
+//! Reading the string data of trusted sources without validating that it is UTF-8.
+//!
+//! Readers decode the string columns as their binary counterparts, which have the same layout,
+//! and reinterpret the arrays as strings afterwards.
+use crate::array::*;
+use crate::datatypes::{ArrowDataType, Field};
+
+/// The type with every (nested) string type replaced by the binary type of the same layout.
+pub fn strings_as_binary(dtype: &ArrowDataType) -> ArrowDataType {
+    use ArrowDataType as D;
+    let field = |f: &Field| Field {
+        dtype: strings_as_binary(&f.dtype),
+        ..f.clone()
+    };
+    match dtype {
+        D::Utf8 => D::Binary,
+        D::LargeUtf8 => D::LargeBinary,
+        D::Utf8View => D::BinaryView,
+        D::List(f) => D::List(Box::new(field(f))),
+        D::LargeList(f) => D::LargeList(Box::new(field(f))),
+        D::FixedSizeList(f, width) => D::FixedSizeList(Box::new(field(f)), *width),
+        D::Struct(fields) => D::Struct(fields.iter().map(field).collect()),
+        dtype => dtype.clone(),
+    }
+}
+
+/// Reinterpret an array read with the type of [`strings_as_binary`] as an array of `dtype`.
+///
+/// # Safety
+/// All binary values in `array` that become strings must be valid UTF-8.
+pub unsafe fn binary_as_strings(array: Box<dyn Array>, dtype: &ArrowDataType) -> Box<dyn Array> {
+    use ArrowDataType as D;
+    let arr = array.as_ref();
+    macro_rules! downcast {
+        ($ty:ty) => {
+            arr.as_any().downcast_ref::<$ty>().unwrap()
+        };
+    }
+    macro_rules! utf8 {
+        ($o:ty) => {{
+            let array = downcast!(BinaryArray<$o>);
+            unsafe {
+                Utf8Array::<$o>::new_unchecked(
+                    dtype.clone(),
+                    array.offsets().clone(),
+                    array.values().clone(),
+                    array.validity().cloned(),
+                )
+            }
+            .boxed()
+        }};
+    }
+    macro_rules! list {
+        ($o:ty, $f:expr) => {{
+            let array = downcast!(ListArray<$o>);
+            let values = unsafe { binary_as_strings(array.values().clone(), &$f.dtype) };
+            ListArray::<$o>::new(
+                dtype.clone(),
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        }};
+    }
+    match dtype {
+        D::Utf8 => utf8!(i32),
+        D::LargeUtf8 => utf8!(i64),
+        D::Utf8View => unsafe { downcast!(BinaryViewArray).to_utf8view_unchecked() }.boxed(),
+        D::List(f) => list!(i32, f),
+        D::LargeList(f) => list!(i64, f),
+        D::FixedSizeList(f, _) => {
+            let array = downcast!(FixedSizeListArray);
+            let values = unsafe { binary_as_strings(array.values().clone(), &f.dtype) };
+            FixedSizeListArray::new(
+                dtype.clone(),
+                array.len(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        },
+        D::Struct(fields) => {
+            let array = downcast!(StructArray);
+            let values = array
+                .values()
+                .iter()
+                .zip(fields)
+                .map(|(v, f)| unsafe { binary_as_strings(v.clone(), &f.dtype) })
+                .collect();
+            StructArray::new(
+                dtype.clone(),
+                array.len(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        },
+        _ => array,
+    }
+}
@@ -37,6 +37,7 @@ pub mod temporal_conversions;
 pub mod datatypes;
 
 pub mod ffi;
+pub mod interchange;
 pub mod legacy;
 pub mod pushable;
 pub mod util;
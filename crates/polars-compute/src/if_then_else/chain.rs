@@ -0,0 +1,142 @@
+use arrow::array::{Array, PrimitiveArray};
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+
+/// The value of a branch of an if-then-else chain.
+pub enum ChainValue<'a, T> {
+    Array(&'a PrimitiveArray<T>),
+    /// A value broadcast to every row, `None` for a null.
+    Scalar(Option<T>),
+}
+
+impl<T: NativeType> ChainValue<'_, T> {
+    fn validity_words(&self, len: usize) -> Option<Vec<u64>> {
+        match self {
+            Self::Array(arr) => arr.validity().map(words),
+            Self::Scalar(v) => v.is_none().then(|| vec![0; len.div_ceil(64)]),
+        }
+    }
+}
+
+/// The bits of `bitmap` in words of 64, the last word holding the remaining bits.
+fn words(bitmap: &Bitmap) -> Vec<u64> {
+    let mut chunks = bitmap.chunks::<u64>();
+    let remainder = (chunks.remainder_len() > 0).then(|| chunks.remainder());
+    chunks.by_ref().chain(remainder).collect()
+}
+
+fn blend<T: Copy>(mask: u64, values: &[T], out: &mut [T]) {
+    for (i, (out, v)) in out.iter_mut().zip(values).enumerate() {
+        *out = if (mask >> i) & 1 != 0 { *v } else { *out };
+    }
+}
+
+fn blend_broadcast<T: Copy>(mask: u64, value: T, out: &mut [T]) {
+    for (i, out) in out.iter_mut().enumerate() {
+        *out = if (mask >> i) & 1 != 0 { value } else { *out };
+    }
+}
+
+/// Take for every row the value of the first branch whose mask is set, or `otherwise` if none
+/// is, in a single pass over the rows.
+///
+/// The output is built in blocks of 64 rows: a block starts as `otherwise` and the values of the
+/// branches are blended in from the last to the first, so the block stays in cache.
+pub fn if_then_else_chain<T: NativeType>(
+    masks: &[Bitmap],
+    values: &[ChainValue<'_, T>],
+    otherwise: ChainValue<'_, T>,
+    len: usize,
+) -> PrimitiveArray<T> {
+    assert_eq!(masks.len(), values.len());
+    for v in values.iter().chain(std::iter::once(&otherwise)) {
+        if let ChainValue::Array(arr) = v {
+            assert_eq!(arr.len(), len);
+        }
+    }
+    let masks = masks
+        .iter()
+        .map(|m| {
+            assert_eq!(m.len(), len);
+            words(m)
+        })
+        .collect::<Vec<_>>();
+    let validities = values
+        .iter()
+        .map(|v| v.validity_words(len))
+        .collect::<Vec<_>>();
+    let otherwise_validity = otherwise.validity_words(len);
+
+    let n_words = len.div_ceil(64);
+    let mut out = Vec::with_capacity(len);
+    let mut validity = Vec::with_capacity(n_words);
+    for w in 0..n_words {
+        let (start, end) = (w * 64, (w * 64 + 64).min(len));
+        match &otherwise {
+            ChainValue::Array(arr) => out.extend_from_slice(&arr.values()[start..end]),
+            ChainValue::Scalar(v) => out.resize(end, v.unwrap_or_default()),
+        }
+        let block = &mut out[start..end];
+        let mut valid = otherwise_validity.as_ref().map_or(u64::MAX, |v| v[w]);
+        for ((mask, value), value_validity) in masks.iter().zip(values).zip(&validities).rev() {
+            let mask = mask[w];
+            if mask == 0 {
+                continue;
+            }
+            match value {
+                ChainValue::Array(arr) => blend(mask, &arr.values()[start..end], block),
+                ChainValue::Scalar(v) => blend_broadcast(mask, v.unwrap_or_default(), block),
+            }
+            let value_valid = value_validity.as_ref().map_or(u64::MAX, |v| v[w]);
+            valid = (mask & value_valid) | (!mask & valid);
+        }
+        validity.push(valid);
+    }
+
+    let validity = Bitmap::from_u8_vec(
+        validity.into_iter().flat_map(u64::to_le_bytes).collect(),
+        len,
+    );
+    let validity = (validity.unset_bits() > 0).then_some(validity);
+    PrimitiveArray::from_vec(out).with_validity(validity)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_if_then_else_chain() {
+        let len = 150;
+        let mask = |k: usize| Bitmap::from_iter((0..len).map(|i| i % k == 0));
+        let arr = |offset: i64| {
+            PrimitiveArray::from_iter((0..len as i64).map(|i| (i % 7 != 0).then_some(i + offset)))
+        };
+        let get = |arr: &PrimitiveArray<i64>, i| arr.is_valid(i).then(|| arr.value(i));
+        let (first, second) = (arr(1000), arr(2000));
+        // Slice a mask so that its bits don't start at a byte boundary.
+        let sliced = Bitmap::from_iter((0..len + 3).map(|i| i >= 3 && (i - 3) % 5 == 0));
+        let masks = [mask(2), mask(3), sliced.sliced(3, len)];
+        let values = [
+            ChainValue::Array(&first),
+            ChainValue::Scalar(Some(-1)),
+            ChainValue::Array(&second),
+        ];
+        let out = if_then_else_chain(&masks, &values, ChainValue::Scalar(None), len);
+
+        let expected = (0..len)
+            .map(|i| {
+                if i % 2 == 0 {
+                    get(&first, i)
+                } else if i % 3 == 0 {
+                    Some(-1)
+                } else if i % 5 == 0 {
+                    get(&second, i)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(out.iter().map(|v| v.copied()).collect::<Vec<_>>(), expected);
+    }
+}
@@ -9,12 +9,15 @@ use crate::NotSimdPrimitive;
 
 mod array;
 mod boolean;
+mod chain;
 mod list;
 mod scalar;
 #[cfg(feature = "simd")]
 mod simd;
 mod view;
 
+pub use chain::{ChainValue, if_then_else_chain};
+
 pub trait IfThenElseKernel: Sized + Array {
     type Scalar<'a>;
 
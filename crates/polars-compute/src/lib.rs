@@ -25,6 +25,7 @@ pub mod horizontal_flatten;
 pub mod hyperloglogplus;
 pub mod if_then_else;
 pub mod min_max;
+pub mod moment;
 pub mod propagate_dictionary;
 pub mod rolling;
 pub mod size;
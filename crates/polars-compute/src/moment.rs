@@ -0,0 +1,137 @@
+// Streaming central moments up to the fourth order.
+//
+// Algorithms from:
+// Formulas for robust, one-pass parallel computation of covariances and arbitrary-order
+// statistical moments.
+// Pébay, P. (2008).
+//
+// Removal of a single observation is the exact inverse of the insertion update, which allows
+// moments to be maintained over a sliding window without recomputation.
+
+#[derive(Default, Clone, Debug)]
+pub struct MomentState {
+    weight: f64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl MomentState {
+    pub fn new_single(x: f64) -> Self {
+        let mut out = Self::default();
+        out.insert_one(x);
+        out
+    }
+
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    pub fn insert_one(&mut self, x: f64) {
+        let n1 = self.weight;
+        let n = n1 + 1.0;
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+        self.weight = n;
+    }
+
+    pub fn remove_one(&mut self, x: f64) {
+        let n = self.weight;
+        let n1 = n - 1.0;
+        if n1 <= 0.0 {
+            *self = Self::default();
+            return;
+        }
+        let mean1 = (n * self.mean - x) / n1;
+        let delta = x - mean1;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        // Undo the updates of `insert_one` in reverse order.
+        let m2 = self.m2 - term1;
+        let m3 = self.m3 - term1 * delta_n * (n - 2.0) + 3.0 * delta_n * m2;
+        let m4 = self.m4 - term1 * delta_n2 * (n * n - 3.0 * n + 3.0) - 6.0 * delta_n2 * m2
+            + 4.0 * delta_n * m3;
+
+        self.weight = n1;
+        self.mean = mean1;
+        self.m2 = m2.max(0.0);
+        self.m3 = m3;
+        self.m4 = m4.max(0.0);
+    }
+
+    /// Returns (m2, m3, m4) normalized by the weight, or `None` if the state is empty.
+    fn normalized(&self) -> Option<(f64, f64, f64)> {
+        if self.weight == 0.0 {
+            return None;
+        }
+        let n = self.weight;
+        Some((self.m2 / n, self.m3 / n, self.m4 / n))
+    }
+
+    fn is_zero_variance(&self, m2: f64) -> bool {
+        m2 <= (f64::EPSILON * self.mean).powi(2)
+    }
+
+    /// Skewness, matching `MomentSeries::skew` in `polars-ops`.
+    pub fn skew(&self, bias: bool) -> Option<f64> {
+        let (m2, m3, _) = self.normalized()?;
+        let zero = self.is_zero_variance(m2);
+        let vals = if zero { f64::NAN } else { m3 / m2.powf(1.5) };
+        let n = self.weight;
+        Some(if !bias && !zero && n > 3.0 {
+            ((n - 1.0) * n).sqrt() / (n - 2.0) * vals
+        } else {
+            vals
+        })
+    }
+
+    /// Kurtosis, matching `MomentSeries::kurtosis` in `polars-ops`.
+    pub fn kurtosis(&self, fisher: bool, bias: bool) -> Option<f64> {
+        let (m2, _, m4) = self.normalized()?;
+        let zero = self.is_zero_variance(m2);
+        let vals = if zero { f64::NAN } else { m4 / (m2 * m2) };
+        let n = self.weight;
+        let out = if !bias && !zero && n > 3.0 {
+            3.0 + 1.0 / (n - 2.0) / (n - 3.0) * ((n * n - 1.0) * vals - 3.0 * (n - 1.0).powi(2))
+        } else {
+            vals
+        };
+        Some(if fisher { out - 3.0 } else { out })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_insert_remove_roundtrip() {
+        let values = [1.0, 4.0, -2.0, 8.0, 3.5, 0.25];
+        let mut full = MomentState::default();
+        values.iter().for_each(|v| full.insert_one(*v));
+
+        let mut tail = MomentState::default();
+        values[2..].iter().for_each(|v| tail.insert_one(*v));
+        full.remove_one(values[0]);
+        full.remove_one(values[1]);
+
+        let (a, b) = (full.skew(true).unwrap(), tail.skew(true).unwrap());
+        assert!((a - b).abs() < 1e-10);
+        let (a, b) = (
+            full.kurtosis(true, false).unwrap(),
+            tail.kurtosis(true, false).unwrap(),
+        );
+        assert!((a - b).abs() < 1e-10);
+    }
+}
@@ -2,6 +2,7 @@ mod min_max;
 pub mod no_nulls;
 pub mod nulls;
 pub mod quantile_filter;
+mod rank;
 mod window;
 
 use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
@@ -11,6 +12,7 @@ use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::types::NativeType;
 use num_traits::{Bounded, Float, NumCast, One, Zero};
 use polars_utils::float::IsFloat;
+pub use rank::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
@@ -35,6 +37,17 @@ pub enum QuantileMethod {
     Equiprobable,
 }
 
+/// How ties are resolved when ranking the current value within its rolling window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[strum(serialize_all = "snake_case")]
+pub enum RollingRankMethod {
+    #[default]
+    Average,
+    Min,
+    Max,
+}
+
 #[deprecated(note = "use QuantileMethod instead")]
 pub type QuantileInterpolOptions = QuantileMethod;
 
@@ -43,6 +56,9 @@ pub type QuantileInterpolOptions = QuantileMethod;
 pub enum RollingFnParams {
     Quantile(RollingQuantileParams),
     Var(RollingVarParams),
+    Skew { bias: bool },
+    Kurtosis { fisher: bool, bias: bool },
+    Rank { method: RollingRankMethod },
 }
 
 fn det_offsets(i: Idx, window_size: WindowSize, _len: Len) -> (usize, usize) {
@@ -56,6 +72,14 @@ fn det_offsets_center(i: Idx, window_size: WindowSize, len: Len) -> (usize, usiz
     )
 }
 
+fn finalize_moment(moment: &crate::moment::MomentState, params: &RollingFnParams) -> Option<f64> {
+    match params {
+        RollingFnParams::Skew { bias } => moment.skew(*bias),
+        RollingFnParams::Kurtosis { fisher, bias } => moment.kurtosis(*fisher, *bias),
+        _ => unreachable!("expected Skew or Kurtosis params"),
+    }
+}
+
 fn create_validity<Fo>(
     min_periods: usize,
     len: usize,
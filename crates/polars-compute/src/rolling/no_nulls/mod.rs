@@ -1,5 +1,6 @@
 mod mean;
 mod min_max;
+mod moment;
 mod quantile;
 mod sum;
 mod variance;
@@ -12,6 +13,7 @@ use arrow::legacy::utils::CustomIterTools;
 use arrow::types::NativeType;
 pub use mean::*;
 pub use min_max::*;
+pub use moment::*;
 use num_traits::{Float, Num, NumCast};
 pub use quantile::*;
 pub use sum::*;
@@ -0,0 +1,162 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use num_traits::{FromPrimitive, ToPrimitive};
+use polars_error::polars_ensure;
+
+use super::*;
+use crate::moment::MomentState;
+
+pub struct MomentWindow<'a, T> {
+    slice: &'a [T],
+    moment: MomentState,
+    params: RollingFnParams,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl<T: ToPrimitive + Copy> MomentWindow<'_, T> {
+    fn compute_moment(&mut self, start: usize, end: usize) {
+        self.moment = MomentState::default();
+        for value in &self.slice[start..end] {
+            let value: f64 = NumCast::from(*value).unwrap();
+            self.moment.insert_one(value);
+        }
+    }
+}
+
+impl<'a, T: NativeType + IsFloat + Float + ToPrimitive + FromPrimitive>
+    RollingAggWindowNoNulls<'a, T> for MomentWindow<'a, T>
+{
+    fn new(slice: &'a [T], start: usize, end: usize, params: Option<RollingFnParams>) -> Self {
+        let mut out = Self {
+            slice,
+            moment: MomentState::default(),
+            params: params.expect("expected Skew or Kurtosis params"),
+            last_start: start,
+            last_end: end,
+        };
+        out.compute_moment(start, end);
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        let recompute = if start >= self.last_end {
+            true
+        } else {
+            // remove elements that should leave the window
+            let mut recompute = false;
+            for idx in self.last_start..start {
+                // SAFETY: we are in bounds
+                let leaving_value = *self.slice.get_unchecked(idx);
+
+                // if the leaving value is not finite we need to recompute the window
+                if T::is_float() && !leaving_value.is_finite() {
+                    recompute = true;
+                    break;
+                }
+                let leaving_value: f64 = NumCast::from(leaving_value).unwrap();
+                self.moment.remove_one(leaving_value);
+            }
+            recompute
+        };
+
+        self.last_start = start;
+
+        if recompute {
+            self.compute_moment(start, end);
+        } else {
+            for idx in self.last_end..end {
+                let entering_value = *self.slice.get_unchecked(idx);
+                let entering_value: f64 = NumCast::from(entering_value).unwrap();
+
+                self.moment.insert_one(entering_value);
+            }
+        }
+        self.last_end = end;
+        finalize_moment(&self.moment, &self.params).map(|v| T::from_f64(v).unwrap())
+    }
+}
+
+fn rolling_moment<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: RollingFnParams,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat + ToPrimitive + FromPrimitive,
+{
+    polars_ensure!(
+        weights.is_none(),
+        InvalidOperation: "weights are not supported for rolling skew or kurtosis"
+    );
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<MomentWindow<_>, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        Some(params),
+    )
+}
+
+pub fn rolling_skew<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat + ToPrimitive + FromPrimitive,
+{
+    let params = params.unwrap_or(RollingFnParams::Skew { bias: true });
+    rolling_moment(values, window_size, min_periods, center, weights, params)
+}
+
+pub fn rolling_kurtosis<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat + ToPrimitive + FromPrimitive,
+{
+    let params = params.unwrap_or(RollingFnParams::Kurtosis {
+        fisher: true,
+        bias: true,
+    });
+    rolling_moment(values, window_size, min_periods, center, weights, params)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_skew() {
+        let values = &[1.0f64, 2.0, 4.0, 8.0, 3.0];
+
+        let out = rolling_skew(values, 3, 3, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out[..2], [None, None]);
+
+        let expected = [
+            0.3818017741606059_f64,
+            0.3818017741606059,
+            0.5951700641394972,
+        ];
+        for (out, expected) in out[2..].iter().zip(expected) {
+            assert!((out.unwrap() - expected).abs() < 1e-12);
+        }
+    }
+}
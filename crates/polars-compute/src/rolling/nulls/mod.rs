@@ -1,5 +1,6 @@
 mod mean;
 mod min_max;
+mod moment;
 mod quantile;
 mod sum;
 mod variance;
@@ -7,6 +8,7 @@ mod variance;
 use arrow::legacy::utils::CustomIterTools;
 pub use mean::*;
 pub use min_max::*;
+pub use moment::*;
 pub use quantile::*;
 pub use sum::*;
 pub use variance::*;
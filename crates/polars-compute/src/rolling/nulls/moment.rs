@@ -0,0 +1,192 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use num_traits::{FromPrimitive, ToPrimitive};
+
+use super::*;
+use crate::moment::MomentState;
+
+pub struct MomentWindow<'a, T> {
+    slice: &'a [T],
+    validity: &'a Bitmap,
+    moment: Option<MomentState>,
+    params: RollingFnParams,
+    last_start: usize,
+    last_end: usize,
+    null_count: usize,
+}
+
+impl<T: NativeType + ToPrimitive> MomentWindow<'_, T> {
+    unsafe fn compute_moment_and_null_count(&mut self, start: usize, end: usize) {
+        let mut moment = None;
+        let mut idx = start;
+        self.null_count = 0;
+        for value in &self.slice[start..end] {
+            let valid = self.validity.get_bit_unchecked(idx);
+            if valid {
+                let value: f64 = NumCast::from(*value).unwrap();
+                match &mut moment {
+                    None => moment = Some(MomentState::new_single(value)),
+                    Some(current) => current.insert_one(value),
+                }
+            } else {
+                self.null_count += 1;
+            }
+            idx += 1;
+        }
+        self.moment = moment;
+    }
+}
+
+impl<'a, T: NativeType + ToPrimitive + IsFloat + FromPrimitive> RollingAggWindowNulls<'a, T>
+    for MomentWindow<'a, T>
+{
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: Option<RollingFnParams>,
+    ) -> Self {
+        let mut out = Self {
+            slice,
+            validity,
+            moment: None,
+            params: params.expect("expected Skew or Kurtosis params"),
+            last_start: start,
+            last_end: end,
+            null_count: 0,
+        };
+        out.compute_moment_and_null_count(start, end);
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        let recompute = if start >= self.last_end {
+            true
+        } else {
+            // remove elements that should leave the window
+            let mut recompute = false;
+            for idx in self.last_start..start {
+                // SAFETY:
+                // we are in bounds
+                let valid = self.validity.get_bit_unchecked(idx);
+                if valid {
+                    let leaving_value = *self.slice.get_unchecked(idx);
+
+                    // if the leaving value is not finite we need to recompute the window
+                    if T::is_float() && !leaving_value.is_finite() {
+                        recompute = true;
+                        break;
+                    }
+                    let leaving_value: f64 = NumCast::from(leaving_value).unwrap();
+                    if let Some(m) = self.moment.as_mut() {
+                        m.remove_one(leaving_value)
+                    }
+                } else {
+                    // null value leaving the window
+                    self.null_count -= 1;
+
+                    if self.moment.is_none() {
+                        recompute = true;
+                        break;
+                    }
+                }
+            }
+            recompute
+        };
+
+        self.last_start = start;
+
+        if recompute {
+            self.compute_moment_and_null_count(start, end);
+        } else {
+            for idx in self.last_end..end {
+                let valid = self.validity.get_bit_unchecked(idx);
+
+                if valid {
+                    let entering_value = *self.slice.get_unchecked(idx);
+                    let entering_value: f64 = NumCast::from(entering_value).unwrap();
+
+                    match &mut self.moment {
+                        None => self.moment = Some(MomentState::new_single(entering_value)),
+                        Some(current) => current.insert_one(entering_value),
+                    }
+                } else {
+                    // null value entering the window
+                    self.null_count += 1;
+                }
+            }
+        }
+        self.last_end = end;
+        self.moment
+            .as_ref()
+            .filter(|m| m.weight() > 0.0)
+            .and_then(|m| finalize_moment(m, &self.params))
+            .map(|v| T::from_f64(v).unwrap())
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        ((self.last_end - self.last_start) - self.null_count) >= min_periods
+    }
+}
+
+fn rolling_moment<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: RollingFnParams,
+) -> ArrayRef
+where
+    T: NativeType + ToPrimitive + FromPrimitive + IsFloat + Float,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    let offsets_fn = if center {
+        det_offsets_center
+    } else {
+        det_offsets
+    };
+    rolling_apply_agg_window::<MomentWindow<_>, _, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        offsets_fn,
+        Some(params),
+    )
+}
+
+pub fn rolling_skew<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + ToPrimitive + FromPrimitive + IsFloat + Float,
+{
+    let params = params.unwrap_or(RollingFnParams::Skew { bias: true });
+    rolling_moment(arr, window_size, min_periods, center, weights, params)
+}
+
+pub fn rolling_kurtosis<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + ToPrimitive + FromPrimitive + IsFloat + Float,
+{
+    let params = params.unwrap_or(RollingFnParams::Kurtosis {
+        fisher: true,
+        bias: true,
+    });
+    rolling_moment(arr, window_size, min_periods, center, weights, params)
+}
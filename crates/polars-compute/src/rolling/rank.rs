@@ -0,0 +1,82 @@
+use polars_utils::total_ord::TotalOrd;
+
+use super::*;
+
+/// Rank of every value within its own rolling window.
+///
+/// Unlike the other rolling kernels this does not keep a running aggregate: the current value
+/// is compared against the (at most `window_size`) valid values in its window, which avoids
+/// materializing each window. Nulls are skipped, and a null value gets a null rank.
+pub fn rolling_rank<T>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    method: RollingRankMethod,
+) -> PrimitiveArray<f64>
+where
+    T: NativeType + TotalOrd,
+{
+    let offsets_fn = if center {
+        det_offsets_center
+    } else {
+        det_offsets
+    };
+    let len = values.len();
+    let is_valid = |i: usize| validity.is_none_or(|v| unsafe { v.get_bit_unchecked(i) });
+
+    (0..len)
+        .map(|idx| {
+            if !is_valid(idx) {
+                return None;
+            }
+            let (start, end) = offsets_fn(idx, window_size, len);
+            let current = &values[idx];
+            let mut n_valid = 0usize;
+            let mut n_less = 0usize;
+            let mut n_equal = 0usize;
+            for (i, v) in values[start..end].iter().enumerate() {
+                if !is_valid(start + i) {
+                    continue;
+                }
+                n_valid += 1;
+                if v.tot_lt(current) {
+                    n_less += 1;
+                } else if v.tot_eq(current) {
+                    n_equal += 1;
+                }
+            }
+            if n_valid < min_periods {
+                return None;
+            }
+            let rank = match method {
+                RollingRankMethod::Average => n_less as f64 + (n_equal as f64 + 1.0) / 2.0,
+                RollingRankMethod::Min => (n_less + 1) as f64,
+                RollingRankMethod::Max => (n_less + n_equal) as f64,
+            };
+            Some(rank)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_rank() {
+        let values = &[3.0f64, 1.0, 2.0, 2.0, 5.0];
+        let out = rolling_rank(values, None, 3, 1, false, RollingRankMethod::Average);
+        let out = out.iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(1.0), Some(1.0), Some(2.0), Some(2.5), Some(3.0)]
+        );
+
+        let validity = Bitmap::from(&[true, false, true, true, true]);
+        let out = rolling_rank(values, Some(&validity), 3, 2, false, RollingRankMethod::Min);
+        let out = out.iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(1.0), Some(1.0), Some(3.0)]);
+    }
+}
@@ -1,13 +1,188 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
+
 use super::*;
+use crate::chunked_array::ops::arity::broadcast_try_binary_elementwise;
+
+/// The maximum precision of a decimal backed by an `i128`.
+const MAX_PRECISION: usize = 38;
+
+/// How decimal arithmetic handles results that don't fit in the maximum precision of 38 digits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum DecimalOverflow {
+    /// Wrap around the underlying 128-bit integer, like the arithmetic operators do.
+    #[default]
+    Wrap,
+    /// Raise an error.
+    Error,
+    /// Clamp the result to the largest (or smallest) representable decimal.
+    Saturate,
+}
+
+/// The largest absolute physical value of a decimal with `precision` digits.
+///
+/// Arithmetic results do not declare a precision, so they are bounded by [`MAX_PRECISION`].
+fn max_abs_decimal(precision: Option<usize>) -> i128 {
+    10_i128.pow(precision.unwrap_or(MAX_PRECISION).min(MAX_PRECISION) as u32) - 1
+}
+
+/// Apply `checked` to the physical values, handling results that don't fit in `precision`
+/// digits according to `overflow`.
+///
+/// `approx` computes the same operation on floats and is only used to find the sign of a result
+/// that overflowed the physical `i128`.
+fn checked_arithmetic(
+    lhs: &Int128Chunked,
+    rhs: &Int128Chunked,
+    op: &str,
+    precision: Option<usize>,
+    overflow: DecimalOverflow,
+    checked: impl Fn(i128, i128) -> Option<i128>,
+    approx: impl Fn(f64, f64) -> f64,
+) -> PolarsResult<Int128Chunked> {
+    let max_abs = max_abs_decimal(precision);
+    broadcast_try_binary_elementwise(lhs, rhs, |l, r| {
+        let (Some(l), Some(r)) = (l, r) else {
+            return Ok(None);
+        };
+        match checked(l, r) {
+            Some(v) if v.abs() <= max_abs => Ok(Some(v)),
+            _ if overflow == DecimalOverflow::Saturate => {
+                let negative = approx(l as f64, r as f64) < 0.0;
+                Ok(Some(if negative { -max_abs } else { max_abs }))
+            },
+            _ => Err(polars_err!(
+                ComputeError: "decimal {} overflowed the precision of {} digits",
+                op, precision.unwrap_or(MAX_PRECISION)
+            )),
+        }
+    })
+}
+
+/// Ensure that the `scale` of the result of `op` fits in the maximum precision.
+fn check_result_scale(scale: usize, op: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        scale <= MAX_PRECISION,
+        ComputeError: "decimal {} results in a scale of {}, which exceeds the maximum precision of {} digits",
+        op, scale, MAX_PRECISION
+    );
+    Ok(())
+}
+
+/// Bring the physical values of `ca` up to `scale`.
+///
+/// Unless wrapping, the rescaled values have to fit in the declared precision of `ca`, grown by
+/// the added digits of the scale like in [`DecimalChunked::to_scale`].
+fn rescale(
+    ca: &DecimalChunked,
+    scale: usize,
+    overflow: DecimalOverflow,
+) -> PolarsResult<Cow<'_, Int128Chunked>> {
+    debug_assert!(scale >= ca.scale());
+    if scale == ca.scale() {
+        return Ok(Cow::Borrowed(&ca.0));
+    }
+    if overflow == DecimalOverflow::Wrap {
+        return Ok(Cow::Owned(ca.to_scale(scale)?.into_owned().0));
+    }
+    let added = scale - ca.scale();
+    let factor = Int128Chunked::from_slice(PlSmallStr::EMPTY, &[10_i128.pow(added as u32)]);
+    checked_arithmetic(
+        &ca.0,
+        &factor,
+        "rescaling",
+        ca.precision().map(|p| p + added),
+        overflow,
+        i128::checked_mul,
+        |l, r| l * r,
+    )
+    .map(Cow::Owned)
+}
+
+impl DecimalChunked {
+    /// Add `rhs`, handling results that don't fit in 38 digits according to `overflow`.
+    pub fn add_with_overflow(&self, rhs: &Self, overflow: DecimalOverflow) -> PolarsResult<Self> {
+        let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
+        let lhs = rescale(self, scale, overflow)?;
+        let rhs = rescale(rhs, scale, overflow)?;
+        let out = match overflow {
+            DecimalOverflow::Wrap => lhs.as_ref() + rhs.as_ref(),
+            _ => checked_arithmetic(
+                &lhs,
+                &rhs,
+                "addition",
+                None,
+                overflow,
+                i128::checked_add,
+                |l, r| l + r,
+            )?,
+        };
+        Ok(out.into_decimal_unchecked(None, scale))
+    }
+
+    /// Subtract `rhs`, handling results that don't fit in 38 digits according to `overflow`.
+    pub fn sub_with_overflow(&self, rhs: &Self, overflow: DecimalOverflow) -> PolarsResult<Self> {
+        let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
+        let lhs = rescale(self, scale, overflow)?;
+        let rhs = rescale(rhs, scale, overflow)?;
+        let out = match overflow {
+            DecimalOverflow::Wrap => lhs.as_ref() - rhs.as_ref(),
+            _ => checked_arithmetic(
+                &lhs,
+                &rhs,
+                "subtraction",
+                None,
+                overflow,
+                i128::checked_sub,
+                |l, r| l - r,
+            )?,
+        };
+        Ok(out.into_decimal_unchecked(None, scale))
+    }
+
+    /// Multiply by `rhs`, handling results that don't fit in 38 digits according to `overflow`.
+    pub fn mul_with_overflow(&self, rhs: &Self, overflow: DecimalOverflow) -> PolarsResult<Self> {
+        let scale = _get_decimal_scale_mul(self.scale(), rhs.scale());
+        let out = match overflow {
+            DecimalOverflow::Wrap => &self.0 * &rhs.0,
+            _ => {
+                check_result_scale(scale, "multiplication")?;
+                checked_arithmetic(
+                    &self.0,
+                    &rhs.0,
+                    "multiplication",
+                    None,
+                    overflow,
+                    i128::checked_mul,
+                    |l, r| l * r,
+                )?
+            },
+        };
+        Ok(out.into_decimal_unchecked(None, scale))
+    }
+
+    /// Divide by `rhs`, handling results that don't fit in 38 digits according to `overflow`.
+    ///
+    /// The quotient is never larger than the rescaled dividend, so only the rescaling can
+    /// overflow.
+    pub fn div_with_overflow(&self, rhs: &Self, overflow: DecimalOverflow) -> PolarsResult<Self> {
+        let scale = _get_decimal_scale_div(self.scale());
+        if overflow != DecimalOverflow::Wrap {
+            check_result_scale(scale + rhs.scale(), "division")?;
+        }
+        let lhs = rescale(self, scale + rhs.scale(), overflow)?;
+        Ok((lhs.as_ref() / &rhs.0).into_decimal_unchecked(None, scale))
+    }
+}
 
 impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 + &rhs.0).into_decimal_unchecked(None, scale))
+        self.add_with_overflow(rhs, DecimalOverflow::Wrap)
     }
 }
 
@@ -15,10 +190,7 @@ impl Sub for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 - &rhs.0).into_decimal_unchecked(None, scale))
+        self.sub_with_overflow(rhs, DecimalOverflow::Wrap)
     }
 }
 
@@ -26,8 +198,7 @@ impl Mul for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let scale = _get_decimal_scale_mul(self.scale(), rhs.scale());
-        Ok((&self.0 * &rhs.0).into_decimal_unchecked(None, scale))
+        self.mul_with_overflow(rhs, DecimalOverflow::Wrap)
     }
 }
 
@@ -35,9 +206,18 @@ impl Div for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn div(self, rhs: Self) -> Self::Output {
-        let scale = _get_decimal_scale_div(self.scale());
-        let lhs = self.to_scale(scale + rhs.scale())?;
-        Ok((&lhs.0 / &rhs.0).into_decimal_unchecked(None, scale))
+        self.div_with_overflow(rhs, DecimalOverflow::Wrap)
+    }
+}
+
+impl Rem for &DecimalChunked {
+    type Output = PolarsResult<DecimalChunked>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
+        let lhs = self.to_scale(scale)?;
+        let rhs = rhs.to_scale(scale)?;
+        Ok((&lhs.0 % &rhs.0).into_decimal_unchecked(None, scale))
     }
 }
 
@@ -54,3 +234,8 @@ pub fn _get_decimal_scale_div(scale_left: usize) -> usize {
     // Follow postgres and MySQL adding a fixed scale increment of 4
     scale_left + 4
 }
+
+pub fn _get_decimal_scale_floor_div() -> usize {
+    // The quotient of a floor division is always integral.
+    0
+}
@@ -108,6 +108,13 @@ pub fn using_string_cache() -> bool {
     *refcount > 0
 }
 
+/// The number of strings in the global string cache, and the number of bytes they take.
+pub fn string_cache_size() -> (usize, usize) {
+    let map = STRING_CACHE.read_map();
+    let n_bytes = map.payloads.iter().map(|s| s.len()).sum();
+    (map.len(), n_bytes)
+}
+
 // This is the hash and the Index offset in the linear buffer
 #[derive(Copy, Clone)]
 struct Key {
@@ -52,6 +52,8 @@ mod trusted_len;
 use std::slice::Iter;
 
 use arrow::legacy::prelude::*;
+#[cfg(feature = "random")]
+pub use random::ReservoirSampler;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::StructChunked;
 
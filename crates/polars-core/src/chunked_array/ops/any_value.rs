@@ -4,6 +4,7 @@ use polars_utils::sync::SyncPtr;
 
 #[cfg(feature = "object")]
 use crate::chunked_array::object::extension::polars_extension::PolarsExtension;
+use crate::extension::ExtensionChunked;
 use crate::prelude::*;
 use crate::series::implementations::null::NullChunked;
 use crate::utils::index_to_chunked_index;
@@ -135,6 +136,8 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let arr = arr.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
             PolarsExtension::arr_to_av(arr, idx)
         },
+        DataType::Extension(_, _, storage) => arr_to_any_value(arr, idx, storage),
+        DataType::Uuid => downcast_and_pack!(BinaryViewArray, Binary),
         DataType::Null => AnyValue::Null,
         DataType::BinaryOffset => downcast_and_pack!(LargeBinaryArray, Binary),
         dt => panic!("not implemented for {dt:?}"),
@@ -318,6 +321,17 @@ impl<T: PolarsObject> ChunkAnyValue for ObjectChunked<T> {
     }
 }
 
+impl ChunkAnyValue for ExtensionChunked {
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, index: usize) -> AnyValue {
+        self.storage().get_unchecked(index)
+    }
+
+    fn get_any_value(&self, index: usize) -> PolarsResult<AnyValue> {
+        self.storage().get(index)
+    }
+}
+
 impl ChunkAnyValue for NullChunked {
     #[inline]
     unsafe fn get_any_value_unchecked(&self, _index: usize) -> AnyValue {
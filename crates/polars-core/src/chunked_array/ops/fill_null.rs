@@ -1,5 +1,4 @@
 use arrow::bitmap::{Bitmap, BitmapBuilder};
-use arrow::legacy::kernels::set::set_at_nulls;
 use bytemuck::Zeroable;
 use num_traits::{NumCast, One, Zero};
 use polars_utils::itertools::Itertools;
@@ -361,7 +360,10 @@ where
     T: PolarsNumericType,
 {
     fn fill_null_with_values(&self, value: T::Native) -> PolarsResult<Self> {
-        Ok(self.apply_kernel(&|arr| Box::new(set_at_nulls(arr, value))))
+        // Only the chunks with nulls are copied.
+        let mut out = self.clone();
+        out.fill_null_with_values_in_place(value);
+        Ok(out)
     }
 }
 
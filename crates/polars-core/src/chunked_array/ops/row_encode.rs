@@ -99,6 +99,9 @@ pub fn get_row_encoding_context(dtype: &DataType, ordered: bool) -> Option<RowEn
 
         DataType::Unknown(_) => panic!("Unsupported in row encoding"),
 
+        DataType::Extension(_, _, storage) => get_row_encoding_context(storage, ordered),
+        DataType::Uuid => None,
+
         #[cfg(feature = "object")]
         DataType::Object(_) => panic!("Unsupported in row encoding"),
 
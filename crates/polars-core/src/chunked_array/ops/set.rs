@@ -1,5 +1,6 @@
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::legacy::kernels::set::{scatter_single_non_null, set_with_mask};
+use arrow::types::NativeType;
 
 use crate::prelude::*;
 use crate::utils::align_chunks_binary;
@@ -312,10 +313,149 @@ impl<'a> ChunkSet<'a, &'a [u8], Vec<u8>> for BinaryChunked {
     }
 }
 
+/// The values of `arr` as a mutable slice, copying them first if the buffer is shared.
+fn values_mut<N: NativeType>(arr: &mut PrimitiveArray<N>) -> &mut [N] {
+    if arr.get_mut_values().is_none() {
+        let values = arr.values().to_vec();
+        arr.set_values(values.into());
+    }
+    arr.get_mut_values().unwrap()
+}
+
+impl<T: PolarsNumericType> ChunkedArray<T> {
+    /// Returns the values of every chunk as mutable slices.
+    ///
+    /// This is copy-on-write: buffers that are uniquely owned are mutated in place, the others
+    /// are copied first, so other arrays sharing them are not affected. Null slots hold
+    /// arbitrary values.
+    pub fn make_values_mut(&mut self) -> impl Iterator<Item = &mut [T::Native]> {
+        self.set_sorted_flag(IsSorted::Not);
+        // SAFETY: we do not change the lengths or the types of the arrays.
+        unsafe { self.downcast_iter_mut() }.map(values_mut)
+    }
+
+    /// Set the values where the `mask` evaluates to `true` to `value`, in place.
+    ///
+    /// This is the copy-on-write variant of [`ChunkSet::set`]: only buffers that are shared
+    /// with other arrays are copied.
+    pub fn set_in_place(
+        &mut self,
+        mask: &BooleanChunked,
+        value: Option<T::Native>,
+    ) -> PolarsResult<()> {
+        check_bounds!(self, mask);
+        let mask = mask.rechunk();
+        let mask = mask.downcast_as_array().true_and_valid();
+
+        let mut offset = 0;
+        // SAFETY: we do not change the lengths or the types of the arrays.
+        for arr in unsafe { self.downcast_iter_mut() } {
+            let len = arr.len();
+            let mask = mask.clone().sliced(offset, len);
+            offset += len;
+            if mask.set_bits() == 0 {
+                continue;
+            }
+
+            if let Some(value) = value {
+                let values = values_mut(arr);
+                for idx in mask.true_idx_iter() {
+                    values[idx] = value;
+                }
+            }
+            let validity = match (arr.take_validity(), value) {
+                (None, Some(_)) => None,
+                (validity, _) => {
+                    let mut validity =
+                        validity.map_or_else(|| MutableBitmap::from_len_set(len), Bitmap::make_mut);
+                    for idx in mask.true_idx_iter() {
+                        validity.set(idx, value.is_some());
+                    }
+                    Some(validity.freeze())
+                },
+            };
+            arr.set_validity(validity);
+        }
+        self.compute_len();
+        self.set_sorted_flag(IsSorted::Not);
+        Ok(())
+    }
+
+    /// Replace the null values with `value`, in place.
+    ///
+    /// This is the copy-on-write variant of filling nulls with a value: only values buffers
+    /// that are shared with other arrays are copied.
+    pub fn fill_null_with_values_in_place(&mut self, value: T::Native) {
+        if !self.has_nulls() {
+            return;
+        }
+        // SAFETY: we do not change the lengths or the types of the arrays.
+        for arr in unsafe { self.downcast_iter_mut() } {
+            let Some(validity) = arr.take_validity() else {
+                continue;
+            };
+            if validity.unset_bits() == 0 {
+                continue;
+            }
+            let values = values_mut(arr);
+            for idx in (!&validity).true_idx_iter() {
+                values[idx] = value;
+            }
+        }
+        self.compute_len();
+        self.set_sorted_flag(IsSorted::Not);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
 
+    #[test]
+    fn test_set_in_place() -> PolarsResult<()> {
+        let mut ca = Int32Chunked::new("a".into(), &[Some(1), None, Some(3)]);
+        let ptr = ca.downcast_iter().next().unwrap().values().as_ptr();
+        // Buffers that are not shared are mutated in place.
+        let mask = BooleanChunked::new("mask".into(), &[Some(true), Some(true), None]);
+        ca.set_in_place(&mask, Some(5))?;
+        assert_eq!(Vec::from(&ca), &[Some(5), Some(5), Some(3)]);
+        assert_eq!(ca.downcast_iter().next().unwrap().values().as_ptr(), ptr);
+
+        // Shared buffers are copied.
+        let shared = ca.clone();
+        let mask = BooleanChunked::new("mask".into(), &[false, false, true]);
+        ca.set_in_place(&mask, None)?;
+        assert_eq!(Vec::from(&ca), &[Some(5), Some(5), None]);
+        assert_eq!(Vec::from(&shared), &[Some(5), Some(5), Some(3)]);
+        assert_eq!(ca.null_count(), 1);
+
+        ca.fill_null_with_values_in_place(0);
+        assert_eq!(Vec::from(&ca), &[Some(5), Some(5), Some(0)]);
+        assert_eq!(ca.null_count(), 0);
+        assert_eq!(Vec::from(&shared), &[Some(5), Some(5), Some(3)]);
+
+        for values in ca.make_values_mut() {
+            values[0] = 1;
+        }
+        assert_eq!(Vec::from(&ca), &[Some(1), Some(5), Some(0)]);
+
+        assert!(ca.set_in_place(&mask.slice(0, 2), None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_null_with_values_shares_chunks_without_nulls() -> PolarsResult<()> {
+        let mut ca = Int32Chunked::new("a".into(), &[1, 2]);
+        ca.append(&Int32Chunked::new("a".into(), &[None, Some(3)]))?;
+        let out = ca.fill_null_with_values(0)?;
+        assert_eq!(Vec::from(&out), &[Some(1), Some(2), Some(0), Some(3)]);
+        assert_eq!(Vec::from(&ca), &[Some(1), Some(2), None, Some(3)]);
+
+        let values_ptr = |ca: &Int32Chunked| ca.downcast_iter().next().unwrap().values().as_ptr();
+        assert_eq!(values_ptr(&out), values_ptr(&ca));
+        Ok(())
+    }
+
     #[test]
     fn test_set() {
         let ca = Int32Chunked::new(PlSmallStr::from_static("a"), &[1, 2, 3]);
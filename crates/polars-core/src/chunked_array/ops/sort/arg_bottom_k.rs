@@ -69,15 +69,19 @@ pub fn _arg_bottom_k(
         }
         &rows
     } else if sort_options.maintain_order {
-        // todo: maybe there is some more efficient method, comparable to select_nth_unstable
+        // Breaking ties by row index makes the order total, so the unstable selection keeps the
+        // order of equal rows.
+        let cmp_stable =
+            |a: &CompareRow, b: &CompareRow| a.bytes.cmp(b.bytes).then(a.idx.cmp(&b.idx));
+        let (lower, _el, _upper) = rows.select_nth_unstable_by(k, cmp_stable);
         if sort_options.multithreaded {
             POOL.install(|| {
-                rows.par_sort();
+                lower.par_sort_unstable_by(cmp_stable);
             })
         } else {
-            rows.sort();
+            lower.sort_unstable_by(cmp_stable);
         }
-        &rows[..k]
+        &*lower
     } else {
         // todo: possible multi threaded `select_nth_unstable`?
         let (lower, _el, _upper) = rows.select_nth_unstable(k);
@@ -1,4 +1,5 @@
 use compare_inner::NullOrderCmp;
+use num_traits::ToPrimitive;
 use polars_utils::itertools::Itertools;
 
 use super::*;
@@ -105,3 +106,144 @@ pub(crate) fn argsort_multiple_row_fmt(
     let ca: NoNull<IdxCa> = items.into_iter().map(|tpl| tpl.0).collect();
     Ok(ca.into_inner())
 }
+
+/// Encode an integer key into codes that order like the requested sort order.
+///
+/// The values are offset by their minimum so that the codes only take the bits needed for the
+/// range of the key, with one extra code for nulls. Returns the codes and their bit width, or
+/// `None` if `T` is not an integer type or the range does not fit in 64 bits.
+pub(crate) fn radix_key_codes<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    descending: bool,
+    nulls_last: bool,
+) -> Option<(Vec<u64>, u32)> {
+    if !T::get_dtype().is_integer() {
+        return None;
+    }
+    let (mut min, mut max) = (i128::MAX, i128::MIN);
+    for v in ca.iter().flatten() {
+        let v = v.to_i128()?;
+        min = min.min(v);
+        max = max.max(v);
+    }
+    if min > max {
+        (min, max) = (0, 0);
+    }
+    let range = u64::try_from(max.checked_sub(min)?).ok()?;
+    let has_nulls = ca.has_nulls();
+    let n_codes = range as u128 + 1 + has_nulls as u128;
+    let bits = u128::BITS - (n_codes - 1).leading_zeros();
+    if bits > u64::BITS {
+        return None;
+    }
+
+    let null_code = if nulls_last { range.wrapping_add(1) } else { 0 };
+    let offset = (has_nulls && !nulls_last) as u64;
+    let codes = ca
+        .iter()
+        .map(|opt_v| match opt_v {
+            Some(v) => {
+                let rank = (v.to_i128().unwrap() - min) as u64;
+                let rank = if descending { range - rank } else { rank };
+                rank + offset
+            },
+            None => null_code,
+        })
+        .collect();
+    Some((codes, bits))
+}
+
+/// [`radix_key_codes`] for integer and dictionary columns.
+///
+/// Enums and Categoricals are encoded by their physical value, so Categoricals with lexical
+/// ordering are not supported.
+fn column_radix_codes(c: &Column, descending: bool, nulls_last: bool) -> Option<(Vec<u64>, u32)> {
+    match c.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, CategoricalOrdering::Lexical)
+        | DataType::Enum(_, CategoricalOrdering::Lexical) => return None,
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => {},
+        DataType::Int128 => return None,
+        dt if dt.is_integer() || dt.is_temporal() => {},
+        _ => return None,
+    }
+    let s = c.as_materialized_series().to_physical_repr();
+    with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        radix_key_codes(ca, descending, nulls_last)
+    })
+}
+
+/// Stable arg sort of rows by any number of integer and dictionary keys.
+///
+/// The codes of all keys are packed into a single `u64` per row, the first key in the most
+/// significant bits, and the rows are sorted with a least significant digit radix sort. As the
+/// radix sort is stable, ties keep their original order. Returns `None` if one of the keys is not
+/// supported or if the keys do not fit in 64 bits together.
+pub(crate) fn arg_sort_multiple_radix(
+    first: (Vec<u64>, u32),
+    other: &[Column],
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> Option<IdxCa> {
+    let (mut packed, mut n_bits) = first;
+    for ((c, descending), nulls_last) in other.iter().zip(descending).zip(nulls_last) {
+        let (codes, bits) = column_radix_codes(c, *descending, *nulls_last)?;
+        n_bits += bits;
+        if n_bits > u64::BITS {
+            return None;
+        }
+        for (p, code) in packed.iter_mut().zip(codes) {
+            *p = p.checked_shl(bits).unwrap_or(0) | code;
+        }
+    }
+
+    let mut items: Vec<(u64, IdxSize)> = packed.into_iter().zip(0..).collect();
+    let mut scratch = items.clone();
+    for shift in (0..n_bits).step_by(8) {
+        let digit = |key: u64| ((key >> shift) & 0xFF) as usize;
+        let mut offsets = [0usize; 256];
+        for (key, _) in &items {
+            offsets[digit(*key)] += 1;
+        }
+        // All rows share this digit, the pass would not change the order.
+        if offsets.contains(&items.len()) {
+            continue;
+        }
+        let mut start = 0;
+        for offset in offsets.iter_mut() {
+            (*offset, start) = (start, start + *offset);
+        }
+        for item in &items {
+            let offset = &mut offsets[digit(item.0)];
+            scratch[*offset] = *item;
+            *offset += 1;
+        }
+        std::mem::swap(&mut items, &mut scratch);
+    }
+
+    let ca: NoNull<IdxCa> = items.into_iter().map(|(_, idx)| idx).collect_trusted();
+    Some(ca.into_inner())
+}
+
+/// The number of rows up to which a multithreaded sort still uses the radix sort.
+///
+/// The radix sort runs on a single thread, so larger multithreaded sorts use the parallel
+/// comparison and row-encoded sorts.
+const RADIX_SORT_MAX_PARALLEL_LEN: usize = 1 << 16;
+
+/// Whether a multi-column arg sort of `len` rows may use [`arg_sort_multiple_radix`].
+pub(crate) fn use_radix_arg_sort(len: usize, multithreaded: bool) -> bool {
+    !multithreaded || len <= RADIX_SORT_MAX_PARALLEL_LEN || POOL.current_num_threads() == 1
+}
+
+/// Try [`arg_sort_multiple_radix`] on the columns of a multi-column sort.
+pub(crate) fn argsort_multiple_radix(
+    by: &[Column],
+    descending: &[bool],
+    nulls_last: &[bool],
+) -> Option<IdxCa> {
+    let first = column_radix_codes(&by[0], descending[0], nulls_last[0])?;
+    arg_sort_multiple_radix(first, &by[1..], &descending[1..], &nulls_last[1..])
+}
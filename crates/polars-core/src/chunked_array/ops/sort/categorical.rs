@@ -54,6 +54,7 @@ impl CategoricalChunked {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         })
     }
 
@@ -11,11 +11,14 @@ mod categorical;
 use std::cmp::Ordering;
 
 pub(crate) use arg_sort::arg_sort_row_fmt;
-pub(crate) use arg_sort_multiple::argsort_multiple_row_fmt;
+pub(crate) use arg_sort_multiple::{
+    argsort_multiple_radix, argsort_multiple_row_fmt, use_radix_arg_sort,
+};
 use arrow::bitmap::{Bitmap, BitmapBuilder};
 use arrow::buffer::Buffer;
 use arrow::legacy::trusted_len::TrustedLenPush;
 use compare_inner::NonNull;
+use polars_utils::float::IsFloat;
 use rayon::prelude::*;
 pub use slice::*;
 
@@ -285,6 +288,22 @@ fn arg_sort_multiple_numeric<T: PolarsNumericType>(
 ) -> PolarsResult<IdxCa> {
     args_validate(ca, by, &options.descending, "descending")?;
     args_validate(ca, by, &options.nulls_last, "nulls_last")?;
+    let radix_codes = if use_radix_arg_sort(ca.len(), options.multithreaded) {
+        radix_key_codes(ca, options.descending[0], options.nulls_last[0])
+    } else {
+        None
+    };
+    if let Some(first) = radix_codes {
+        let radix = arg_sort_multiple_radix(
+            first,
+            by,
+            &options.descending[1..],
+            &options.nulls_last[1..],
+        );
+        if let Some(idx) = radix {
+            return Ok(idx);
+        }
+    }
     let mut count: IdxSize = 0;
 
     let no_nulls = ca.null_count() == 0;
@@ -312,13 +331,60 @@ fn arg_sort_multiple_numeric<T: PolarsNumericType>(
     }
 }
 
+/// The permutation that moves the NaN values of the sorted `ca` to `options.nan_position`.
+///
+/// Sorting treats NaN as larger than every number, so the NaN values form a single block at the
+/// end (ascending) or start (descending) of the non-null values. Returns `None` if that block is
+/// already in place.
+fn nan_position_perm<T>(ca: &ChunkedArray<T>, options: &SortOptions) -> Option<IdxCa>
+where
+    T: PolarsNumericType,
+{
+    let nans_first = match options.nan_position {
+        NanPosition::Largest => return None,
+        NanPosition::First => true,
+        NanPosition::Last => false,
+    };
+    if !T::Native::is_float() || nans_first == options.descending {
+        return None;
+    }
+    let nan_count: usize = ca
+        .downcast_iter()
+        .map(|arr| arr.iter().flatten().filter(|v| v.is_nan()).count())
+        .sum();
+    if nan_count == 0 {
+        return None;
+    }
+
+    let len = ca.len();
+    let null_count = ca.null_count();
+    let start = if options.nulls_last { 0 } else { null_count };
+    let mut perm: Vec<IdxSize> = (0..len as IdxSize).collect();
+    let valid = &mut perm[start..start + len - null_count];
+    if nans_first {
+        valid.rotate_right(nan_count);
+    } else {
+        valid.rotate_left(nan_count);
+    }
+    Some(IdxCa::from_vec(PlSmallStr::EMPTY, perm))
+}
+
 impl<T> ChunkSort<T> for ChunkedArray<T>
 where
     T: PolarsNumericType,
 {
     fn sort_with(&self, mut options: SortOptions) -> ChunkedArray<T> {
         options.multithreaded &= POOL.current_num_threads() > 1;
-        sort_with_numeric(self, options)
+        let sorted = sort_with_numeric(self, options);
+        match nan_position_perm(&sorted, &options) {
+            Some(perm) => {
+                // SAFETY: the permutation is in bounds.
+                let mut out = unsafe { sorted.take_unchecked(&perm) };
+                out.set_sorted_flag(IsSorted::Not);
+                out
+            },
+            None => sorted,
+        }
     }
 
     fn sort(&self, descending: bool) -> ChunkedArray<T> {
@@ -328,8 +394,18 @@ where
         })
     }
 
-    fn arg_sort(&self, options: SortOptions) -> IdxCa {
-        arg_sort_numeric(self, options)
+    fn arg_sort(&self, mut options: SortOptions) -> IdxCa {
+        let Some(perm) = nan_position_perm(self, &options) else {
+            return arg_sort_numeric(self, options);
+        };
+        let limit = options.limit.take();
+        let idx = arg_sort_numeric(self, options);
+        // SAFETY: the permutation is in bounds.
+        let idx = unsafe { idx.take_unchecked(&perm) };
+        match limit {
+            Some(limit) => idx.slice(0, limit as usize),
+            None => idx,
+        }
     }
 
     /// # Panics
@@ -377,6 +453,7 @@ impl ChunkSort<StringType> for StringChunked {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         })
     }
 
@@ -449,6 +526,7 @@ impl ChunkSort<BinaryType> for BinaryChunked {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         })
     }
 
@@ -584,6 +662,7 @@ impl ChunkSort<BinaryOffsetType> for BinaryOffsetChunked {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         })
     }
 
@@ -759,6 +838,7 @@ impl ChunkSort<BooleanType> for BooleanChunked {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         })
     }
 
@@ -883,6 +963,7 @@ mod test {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         });
         assert_eq!(
             Vec::from(&out),
@@ -903,6 +984,7 @@ mod test {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         });
         assert_eq!(
             Vec::from(&out),
@@ -927,6 +1009,32 @@ mod test {
         assert_eq!(Vec::from(&out), &[Some(false), Some(false), Some(true)]);
     }
 
+    #[test]
+    fn test_sort_nan_position() {
+        let a = Float64Chunked::new(
+            PlSmallStr::from_static("a"),
+            &[Some(f64::NAN), Some(1.0), None, Some(2.0)],
+        );
+        let to_vec = |ca: &Float64Chunked| {
+            ca.iter()
+                .map(|v| v.map(|v| if v.is_nan() { -1.0 } else { v }))
+                .collect::<Vec<_>>()
+        };
+
+        let options = SortOptions::default().with_nan_position(NanPosition::First);
+        let out = a.sort_with(options);
+        assert_eq!(to_vec(&out), &[None, Some(-1.0), Some(1.0), Some(2.0)]);
+        let idx = a.arg_sort(options);
+        assert_eq!(Vec::from(&idx), &[Some(2), Some(0), Some(1), Some(3)]);
+
+        let options = options
+            .with_order_descending(true)
+            .with_nulls_last(true)
+            .with_nan_position(NanPosition::Last);
+        let out = a.sort_with(options);
+        assert_eq!(to_vec(&out), &[Some(2.0), Some(1.0), Some(-1.0), None]);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_arg_sort_multiple() -> PolarsResult<()> {
@@ -1001,6 +1109,38 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_arg_sort_multiple_radix() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [Some(-3i32), Some(2), None, Some(2), Some(-3), None, Some(7)],
+            "b" => [Some(1u32), None, Some(4), Some(1), Some(0), Some(4), Some(1)],
+            "c" => [1i64 << 40, 5, 5, -1, 5, 5, 3],
+        )?;
+        let by = df.get_columns();
+        for (descending, nulls_last) in [
+            (vec![false, false, false], vec![false, false, false]),
+            (vec![true, false, true], vec![false, true, false]),
+            (vec![false, true, false], vec![true, true, true]),
+        ] {
+            let idx = super::argsort_multiple_radix(by, &descending, &nulls_last).unwrap();
+            let expected = super::argsort_multiple_row_fmt(by, descending, nulls_last, false)?;
+            assert_eq!(Vec::from(&idx), Vec::from(&expected));
+        }
+
+        // Keys that do not fit in 64 bits together are not radix sorted.
+        let wide = df!("a" => [i64::MIN, 0], "b" => [i64::MAX, 0])?;
+        let flags = [false, false];
+        assert!(super::argsort_multiple_radix(wide.get_columns(), &flags, &flags).is_none());
+
+        // Large multithreaded sorts use the parallel sorts instead.
+        assert!(super::use_radix_arg_sort(1 << 20, false));
+        assert_eq!(
+            super::use_radix_arg_sort(1 << 20, true),
+            POOL.current_num_threads() == 1
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_sort_string() {
         let ca = StringChunked::new(
@@ -1013,6 +1153,7 @@ mod test {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         });
         let expected = &[None, None, Some("a"), Some("b"), Some("c")];
         assert_eq!(Vec::from(&out), expected);
@@ -1023,6 +1164,7 @@ mod test {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         });
 
         let expected = &[None, None, Some("c"), Some("b"), Some("a")];
@@ -1034,6 +1176,7 @@ mod test {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         });
         let expected = &[Some("a"), Some("b"), Some("c"), None, None];
         assert_eq!(Vec::from(&out), expected);
@@ -1044,6 +1187,7 @@ mod test {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         });
         let expected = &[Some("c"), Some("b"), Some("a"), None, None];
         assert_eq!(Vec::from(&out), expected);
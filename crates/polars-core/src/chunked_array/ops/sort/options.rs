@@ -43,6 +43,25 @@ pub struct SortOptions {
     pub maintain_order: bool,
     /// Limit a sort output, this is for optimization purposes and might be ignored.
     pub limit: Option<IdxSize>,
+    /// Where to place NaN values of a float column.
+    /// Default [`NanPosition::Largest`].
+    pub nan_position: NanPosition,
+}
+
+/// Where NaN values end up when sorting a float column.
+///
+/// Null values are placed independently of NaN values, according to `nulls_last`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NanPosition {
+    /// NaN is larger than every number, so NaN values are last when sorting ascending and
+    /// first when sorting descending.
+    #[default]
+    Largest,
+    /// NaN values precede all other non-null values.
+    First,
+    /// NaN values follow all other non-null values.
+    Last,
 }
 
 /// Sort options for multi-series sorting.
@@ -110,6 +129,7 @@ impl Default for SortOptions {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         }
     }
 }
@@ -216,6 +236,12 @@ impl SortOptions {
         self
     }
 
+    /// Where to place NaN values of a float column. Default [`NanPosition::Largest`].
+    pub fn with_nan_position(mut self, nan_position: NanPosition) -> Self {
+        self.nan_position = nan_position;
+        self
+    }
+
     /// Reverse the order of sorting.
     pub fn with_order_reversed(mut self) -> Self {
         self.descending = !self.descending;
@@ -243,6 +269,7 @@ impl From<&SortMultipleOptions> for SortOptions {
             multithreaded: value.multithreaded,
             maintain_order: value.maintain_order,
             limit: value.limit,
+            nan_position: NanPosition::Largest,
         }
     }
 }
@@ -116,7 +116,9 @@ where
                     }
 
                     let arr: PrimitiveArray<T::Native> = arr.into();
-                    Ok(ChunkedArray::with_chunk(self.name().clone(), arr))
+                    let mut out = ChunkedArray::with_chunk(self.name().clone(), arr);
+                    out.set_sorted_flag(self.is_sorted_flag());
+                    Ok(out)
                 } else {
                     let mask = self.not_equal_missing(&self.shift(1));
                     self.filter(&mask)
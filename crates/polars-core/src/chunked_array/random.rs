@@ -5,10 +5,11 @@ use rand::prelude::*;
 use rand::seq::index::IndexVec;
 use rand_distr::{Normal, Standard, StandardNormal, Uniform};
 
+use crate::frame::group_by::GroupsIndicator;
 use crate::prelude::DataType::Float64;
 use crate::prelude::*;
 use crate::random::get_global_random_u64;
-use crate::utils::NoNull;
+use crate::utils::{NoNull, accumulate_dataframes_vertical_unchecked};
 
 fn create_rand_index_with_replacement(n: usize, len: usize, seed: Option<u64>) -> IdxCa {
     if len == 0 {
@@ -239,6 +240,221 @@ impl DataFrame {
     }
 }
 
+impl DataFrame {
+    /// Sample `n` rows from every group of `by`.
+    pub fn sample_n_stratified<I, S>(
+        &self,
+        by: I,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        self.sample_stratified_impl(by, |_| n, with_replacement, shuffle, seed)
+    }
+
+    /// Sample a fraction between 0.0-1.0 of every group of `by`.
+    pub fn sample_frac_stratified<I, S>(
+        &self,
+        by: I,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        polars_ensure!(
+            frac.is_finite() && frac >= 0.0,
+            ComputeError: "Sample fraction must be a non-negative number, got {}", frac
+        );
+        self.sample_stratified_impl(
+            by,
+            |len| (len as f64 * frac) as usize,
+            with_replacement,
+            shuffle,
+            seed,
+        )
+    }
+
+    fn sample_stratified_impl<I, S>(
+        &self,
+        by: I,
+        group_n: impl Fn(usize) -> usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let groups = self.group_by_stable(by)?.take_groups();
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+        let mut idx = Vec::new();
+        for group in groups.iter() {
+            let len = group.len();
+            let n = group_n(len);
+            ensure_shape(n, len, with_replacement)?;
+            let global_idx = |i: usize| match &group {
+                GroupsIndicator::Idx((_, rows)) => rows[i],
+                GroupsIndicator::Slice([first, _]) => first + i as IdxSize,
+            };
+
+            if with_replacement {
+                if len > 0 {
+                    idx.extend((0..n).map(|_| global_idx(rng.gen_range(0..len))));
+                }
+            } else if n == len {
+                let start = idx.len();
+                idx.extend((0..len).map(global_idx));
+                if shuffle {
+                    idx[start..].shuffle(&mut rng);
+                }
+            } else {
+                idx.extend(
+                    rand::seq::index::sample(&mut rng, len, n)
+                        .into_iter()
+                        .map(global_idx),
+                );
+            }
+        }
+        let idx = IdxCa::new_vec(PlSmallStr::EMPTY, idx);
+        // SAFETY: the indices are taken from the groups and thus within bounds.
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+}
+
+/// A uniform sample of at most `n` rows of a stream of [`DataFrame`]s.
+struct Reservoir {
+    seen: usize,
+    rows: DataFrame,
+}
+
+impl Reservoir {
+    /// Offer the rows of `df` to the sample. Once the reservoir holds `n` rows, every next row
+    /// replaces a random row with probability `n / seen` (Algorithm R).
+    ///
+    /// The accepted rows are gathered, so the sample does not keep the buffers of `df` alive.
+    fn push(&mut self, df: &DataFrame, n: usize, rng: &mut SmallRng) -> PolarsResult<()> {
+        let height = self.rows.height();
+        // The slots point into the concatenation of the current sample and the accepted rows.
+        let mut slots: Option<Vec<IdxSize>> = None;
+        let mut accepted = Vec::new();
+        for row in 0..df.height() {
+            self.seen += 1;
+            if height + accepted.len() < n {
+                accepted.push(row as IdxSize);
+                continue;
+            }
+            let slot = rng.gen_range(0..self.seen);
+            if slot < n {
+                let slots = slots.get_or_insert_with(|| (0..n as IdxSize).collect());
+                slots[slot] = (height + accepted.len()) as IdxSize;
+                accepted.push(row as IdxSize);
+            }
+        }
+        if accepted.is_empty() {
+            return Ok(());
+        }
+
+        let accepted = IdxCa::new_vec(PlSmallStr::EMPTY, accepted);
+        // SAFETY: `accepted` holds rows of `df` and `slots` positions within the concatenation.
+        unsafe {
+            self.rows.vstack_mut(&df.take_unchecked(&accepted))?;
+            if let Some(slots) = slots {
+                let slots = IdxCa::new_vec(PlSmallStr::EMPTY, slots);
+                self.rows = self.rows.take_unchecked(&slots);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Draws a uniform sample of at most `n` rows of every group of `by` from a stream of
+/// [`DataFrame`]s in a single pass.
+///
+/// Only the samples are kept in memory, so arbitrarily large inputs can be sampled by pushing
+/// them batch by batch. Without `by` the whole stream is one group. The groups are returned in
+/// the order in which they were first seen, the order of the rows within a group is
+/// unspecified.
+pub struct ReservoirSampler {
+    n: usize,
+    by: Vec<PlSmallStr>,
+    rng: SmallRng,
+    reservoirs: PlIndexMap<Vec<AnyValue<'static>>, Reservoir>,
+    empty: Option<DataFrame>,
+}
+
+impl ReservoirSampler {
+    pub fn new(n: usize, by: Vec<PlSmallStr>, seed: Option<u64>) -> Self {
+        Self {
+            n,
+            by,
+            rng: SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64)),
+            reservoirs: PlIndexMap::default(),
+            empty: None,
+        }
+    }
+
+    /// Offer the rows of `df` to the samples of their groups.
+    pub fn push(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let empty = self.empty.get_or_insert_with(|| df.clear());
+        if self.by.is_empty() {
+            let reservoir = self.reservoirs.entry(vec![]).or_insert_with(|| Reservoir {
+                seen: 0,
+                rows: empty.clone(),
+            });
+            return reservoir.push(df, self.n, &mut self.rng);
+        }
+
+        let keys = self
+            .by
+            .iter()
+            .map(|name| df.column(name))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let groups = df.group_by_stable(self.by.iter().cloned())?.take_groups();
+        for group in groups.iter() {
+            let key = keys
+                .iter()
+                .map(|c| Ok(c.get(group.first() as usize)?.into_static()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let rows = match group {
+                GroupsIndicator::Idx((_, idx)) => {
+                    let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx.to_vec());
+                    // SAFETY: the indices are taken from the groups and thus within bounds.
+                    unsafe { df.take_unchecked(&idx) }
+                },
+                GroupsIndicator::Slice([first, len]) => df.slice(first as i64, len as usize),
+            };
+            let reservoir = self.reservoirs.entry(key).or_insert_with(|| Reservoir {
+                seen: 0,
+                rows: empty.clone(),
+            });
+            reservoir.push(&rows, self.n, &mut self.rng)?;
+        }
+        Ok(())
+    }
+
+    /// The sampled rows; empty if no frame was pushed.
+    pub fn finish(self) -> DataFrame {
+        if self.reservoirs.is_empty() {
+            return self.empty.unwrap_or_default();
+        }
+        accumulate_dataframes_vertical_unchecked(
+            self.reservoirs
+                .into_values()
+                .map(|reservoir| reservoir.rows),
+        )
+    }
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -390,4 +606,60 @@ mod test {
             .is_ok()
         );
     }
+
+    #[test]
+    fn test_sample_stratified() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "a", "a", "a", "b", "b", "c"],
+            "v" => [1, 2, 3, 4, 5, 6, 7],
+        ]?;
+
+        let out = df.sample_n_stratified(["g"], 1, false, false, Some(0))?;
+        let g = out.column("g")?.str()?;
+        assert_eq!(g.into_no_null_iter().collect::<Vec<_>>(), &["a", "b", "c"]);
+
+        let out = df.sample_frac_stratified(["g"], 0.5, false, false, Some(0))?;
+        let g = out.column("g")?.str()?;
+        assert_eq!(g.into_no_null_iter().collect::<Vec<_>>(), &["a", "a", "b"]);
+
+        // Groups smaller than `n` can only be sampled with replacement.
+        assert!(
+            df.sample_n_stratified(["g"], 2, false, false, Some(0))
+                .is_err()
+        );
+        let out = df.sample_n_stratified(["g"], 2, true, false, Some(0))?;
+        assert_eq!(out.height(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reservoir_sampler() -> PolarsResult<()> {
+        let mut sampler = ReservoirSampler::new(4, vec![], Some(0));
+        for i in 0..10 {
+            let df = df!["v" => (i * 10..(i + 1) * 10).collect::<Vec<i32>>()]?;
+            sampler.push(&df)?;
+        }
+        let out = sampler.finish();
+        let mut values = out
+            .column("v")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        values.sort();
+        values.dedup();
+        assert_eq!(values.len(), 4);
+        assert!(values.iter().all(|v| (0..100).contains(v)));
+
+        // Every group is sampled on its own and groups with fewer rows are kept whole.
+        let mut sampler = ReservoirSampler::new(2, vec!["g".into()], Some(0));
+        sampler.push(&df!["g" => ["a", "b", "a", "a"], "v" => [1, 2, 3, 4]]?)?;
+        sampler.push(&df!["g" => ["c", "a", "a"], "v" => [5, 6, 7]]?)?;
+        let out = sampler.finish();
+        let g = out.column("g")?.str()?;
+        assert_eq!(
+            g.into_no_null_iter().collect::<Vec<_>>(),
+            &["a", "a", "b", "c"]
+        );
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+pub use polars_utils::numa::ThreadAffinity;
+
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -72,3 +74,39 @@ pub fn force_async() -> bool {
         .map(|value| value == "1")
         .unwrap_or_default()
 }
+
+/// When the in-memory engine rechunks its inputs into a single chunk.
+///
+/// Frames whose columns have misaligned chunks are always rechunked where operations need
+/// them aligned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RechunkPolicy {
+    /// Rechunk before operations that benefit from contiguous data, like sorts and group-bys.
+    #[default]
+    Auto,
+    /// Never rechunk when the chunks are aligned.
+    Never,
+    /// Like `Auto`, but only rechunk frames with more chunks than this.
+    Threshold(usize),
+    /// Like `Auto`, and also rechunk both inputs of a join.
+    AlwaysBeforeJoin,
+}
+
+pub fn rechunk_policy() -> RechunkPolicy {
+    match std::env::var("POLARS_RECHUNK_POLICY").as_deref() {
+        Ok("never") => RechunkPolicy::Never,
+        Ok("always_before_join") => RechunkPolicy::AlwaysBeforeJoin,
+        Ok(v) => v
+            .parse()
+            .map_or(RechunkPolicy::Auto, RechunkPolicy::Threshold),
+        _ => RechunkPolicy::Auto,
+    }
+}
+
+pub fn thread_affinity() -> ThreadAffinity {
+    match std::env::var("POLARS_THREAD_AFFINITY").as_deref() {
+        Ok("numa") => ThreadAffinity::Numa,
+        Ok("cores") => ThreadAffinity::Cores,
+        _ => ThreadAffinity::None,
+    }
+}
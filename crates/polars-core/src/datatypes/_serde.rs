@@ -116,6 +116,8 @@ enum SerializableDataType {
     Enum(Option<Series>, CategoricalOrdering),
     #[cfg(feature = "object")]
     Object(String),
+    Uuid,
+    Extension(PlSmallStr, Option<PlSmallStr>, Box<SerializableDataType>),
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -171,6 +173,12 @@ impl From<&DataType> for SerializableDataType {
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
             #[cfg(feature = "object")]
             Object(name) => Self::Object(name.to_string()),
+            Uuid => Self::Uuid,
+            Extension(name, metadata, storage) => Self::Extension(
+                name.clone(),
+                metadata.clone(),
+                Box::new(storage.as_ref().into()),
+            ),
             dt => panic!("{dt:?} not supported"),
         }
     }
@@ -231,6 +239,10 @@ impl From<SerializableDataType> for DataType {
             Decimal(precision, scale) => Self::Decimal(precision, scale),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown"),
+            Uuid => Self::Uuid,
+            Extension(name, metadata, storage) => {
+                Self::Extension(name, metadata, Box::new((*storage).into()))
+            },
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use arrow::datatypes::{DTYPE_CATEGORICAL, DTYPE_ENUM_VALUES, Metadata};
+use arrow::datatypes::{DTYPE_CATEGORICAL, DTYPE_ENUM_VALUES, ExtensionType, Metadata};
 #[cfg(feature = "dtype-array")]
 use polars_utils::format_tuple;
 use polars_utils::itertools::Itertools;
@@ -142,6 +142,12 @@ pub enum DataType {
     Enum(Option<Arc<RevMapping>>, CategoricalOrdering),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    /// A 128-bit UUID stored as 16 bytes per value, which maps to Arrow's canonical `arrow.uuid`
+    /// extension type.
+    Uuid,
+    /// A registered Arrow extension type with its name, serialized metadata and storage type.
+    /// See [`crate::extension`].
+    Extension(PlSmallStr, Option<PlSmallStr>, Box<DataType>),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown(UnknownKind),
 }
@@ -197,6 +203,9 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                (Extension(l_name, l_md, l_storage), Extension(r_name, r_md, r_storage)) => {
+                    l_name == r_name && l_md == r_md && l_storage == r_storage
+                },
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -455,6 +464,8 @@ impl DataType {
                     .collect();
                 Struct(new_fields)
             },
+            Extension(_, _, storage) => storage.to_physical(),
+            Uuid => Binary,
             _ => self.clone(),
         }
     }
@@ -564,6 +575,8 @@ impl DataType {
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            Extension(_, _, storage) => storage.contains_views(),
+            Uuid => true,
             _ => false,
         }
     }
@@ -857,6 +870,14 @@ impl DataType {
                 Ok(ArrowDataType::Struct(fields))
             },
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
+            Uuid => Ok(ArrowDataType::Extension(Box::new(ExtensionType::uuid()))),
+            Extension(name, metadata, storage) => {
+                Ok(ArrowDataType::Extension(Box::new(ExtensionType {
+                    name: name.clone(),
+                    inner: storage.try_to_arrow(compat_level)?,
+                    metadata: metadata.clone(),
+                })))
+            },
             Unknown(kind) => {
                 let dt = match kind {
                     UnknownKind::Any => ArrowDataType::Unknown,
@@ -983,6 +1004,8 @@ impl Display for DataType {
             DataType::Enum(_, _) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            DataType::Uuid => "uuid",
+            DataType::Extension(name, _, _) => return write!(f, "extension[{name}]"),
             DataType::Unknown(kind) => match kind {
                 UnknownKind::Any => "unknown",
                 UnknownKind::Int(_) => "dyn int",
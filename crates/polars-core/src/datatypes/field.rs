@@ -2,6 +2,7 @@ use arrow::datatypes::{DTYPE_ENUM_VALUES, Metadata};
 use polars_utils::pl_str::PlSmallStr;
 
 use super::*;
+use crate::extension;
 pub static EXTENSION_NAME: &str = "POLARS_EXTENSION_TYPE";
 
 /// Characterizes the name and the [`DataType`] of a column.
@@ -231,6 +232,14 @@ impl DataType {
                     panic!("activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE")
                 }
             },
+            ArrowDataType::Extension(ext) if ext.is_uuid() => DataType::Uuid,
+            ArrowDataType::Extension(ext) if extension::get_extension_type(&ext.name).is_some() => {
+                DataType::Extension(
+                    ext.name.clone(),
+                    ext.metadata.clone(),
+                    Box::new(Self::from_arrow(&ext.inner, bin_to_view, md)),
+                )
+            },
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => {
                 DataType::Decimal(Some(*precision), Some(*scale))
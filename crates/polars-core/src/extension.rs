@@ -0,0 +1,488 @@
+//! A registry of user-defined Arrow extension types.
+//!
+//! Arrays of an extension type are rejected when they are loaded, unless a downstream crate
+//! registered that type by name. A registered type is validated and loaded as a
+//! [`DataType::Extension`] backed by a [`Series`] of its storage type, see [`ExtensionChunked`].
+//! The data type is part of the schema and is kept by operations that don't change the values,
+//! such as `select`, `filter`, `sort` and `concat`. Computations on the values work on the
+//! storage type and return it. Writing to IPC or Parquet stores the extension name and metadata
+//! again.
+//!
+//! Arrow's canonical `arrow.uuid` extension type is built in and loads as [`DataType::Uuid`],
+//! backed by a [`BinaryChunked`] of 16-byte values.
+use std::sync::{LazyLock, RwLock};
+
+use arrow::array::*;
+use arrow::bitmap::Bitmap;
+use arrow::buffer::Buffer;
+use arrow::datatypes::{ExtensionType, PhysicalType, UUID_EXTENSION_NAME};
+use arrow::offset::{Offset, OffsetsBuffer};
+use arrow::with_match_primitive_type_full;
+
+use crate::prelude::*;
+
+/// An Arrow extension type that can be registered with [`register_extension_type`].
+pub trait ExtensionTypeImpl: Send + Sync {
+    /// The name of the extension type, as stored in the `ARROW:extension:name` field metadata.
+    fn name(&self) -> &str;
+
+    /// Check that `storage` and the serialized `metadata` form a valid instance of this type.
+    fn validate(&self, storage: &ArrowDataType, metadata: Option<&str>) -> PolarsResult<()>;
+}
+
+type Registry = PlHashMap<PlSmallStr, Arc<dyn ExtensionTypeImpl>>;
+
+static GLOBAL_EXTENSION_REGISTRY: LazyLock<RwLock<Registry>> = LazyLock::new(Default::default);
+
+/// Register an extension type, replacing an earlier registration of the same name.
+pub fn register_extension_type(ext: Arc<dyn ExtensionTypeImpl>) {
+    let mut registry = GLOBAL_EXTENSION_REGISTRY.write().unwrap();
+    registry.insert(ext.name().into(), ext);
+}
+
+/// Remove the extension type registered under `name`.
+pub fn unregister_extension_type(name: &str) -> Option<Arc<dyn ExtensionTypeImpl>> {
+    let mut registry = GLOBAL_EXTENSION_REGISTRY.write().unwrap();
+    registry.remove(name)
+}
+
+pub fn get_extension_type(name: &str) -> Option<Arc<dyn ExtensionTypeImpl>> {
+    let registry = GLOBAL_EXTENSION_REGISTRY.read().unwrap();
+    registry.get(name).cloned()
+}
+
+fn validate(ext: &ExtensionType) -> PolarsResult<()> {
+    polars_ensure!(
+        !matches!(
+            ext.inner.to_physical_type(),
+            PhysicalType::Dictionary(_) | PhysicalType::Map | PhysicalType::Union
+        ),
+        ComputeError: "extension type {:?} has {:?} storage; dictionary, map and union storage \
+        is not supported for extension types", ext.name, ext.inner
+    );
+    let Some(ext_impl) = get_extension_type(&ext.name) else {
+        polars_bail!(
+            ComputeError: "cannot load unregistered extension type {:?}; \
+            register it with `register_extension_type` to load it as its storage type",
+            ext.name
+        )
+    };
+    ext_impl.validate(&ext.inner, ext.metadata.as_deref())
+}
+
+/// Change the data type of `arr` to `dtype`, which must have the same physical layout.
+fn with_dtype(arr: &dyn Array, dtype: &ArrowDataType) -> PolarsResult<ArrayRef> {
+    polars_ensure!(
+        arr.dtype().to_physical_type() == dtype.to_physical_type(),
+        ComputeError: "cannot change the data type {:?} to {:?}", arr.dtype(), dtype
+    );
+    let dtype = dtype.clone();
+    let any = arr.as_any();
+    Ok(match dtype.to_physical_type() {
+        PhysicalType::Null => NullArray::try_new(dtype, arr.len())?.boxed(),
+        PhysicalType::Boolean => {
+            let arr = any.downcast_ref::<BooleanArray>().unwrap();
+            BooleanArray::try_new(dtype, arr.values().clone(), arr.validity().cloned())?.boxed()
+        },
+        PhysicalType::Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            let arr = any.downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            PrimitiveArray::<$T>::try_new(dtype, arr.values().clone(), arr.validity().cloned())?
+                .boxed()
+        }),
+        PhysicalType::Binary => with_offsets_dtype::<BinaryArray<i32>>(any, dtype)?,
+        PhysicalType::LargeBinary => with_offsets_dtype::<BinaryArray<i64>>(any, dtype)?,
+        PhysicalType::Utf8 => with_offsets_dtype::<Utf8Array<i32>>(any, dtype)?,
+        PhysicalType::LargeUtf8 => with_offsets_dtype::<Utf8Array<i64>>(any, dtype)?,
+        PhysicalType::BinaryView => {
+            let arr = any.downcast_ref::<BinaryViewArray>().unwrap();
+            BinaryViewArray::try_new(
+                dtype,
+                arr.views().clone(),
+                arr.data_buffers().clone(),
+                arr.validity().cloned(),
+            )?
+            .boxed()
+        },
+        PhysicalType::Utf8View => {
+            let arr = any.downcast_ref::<Utf8ViewArray>().unwrap();
+            Utf8ViewArray::try_new(
+                dtype,
+                arr.views().clone(),
+                arr.data_buffers().clone(),
+                arr.validity().cloned(),
+            )?
+            .boxed()
+        },
+        PhysicalType::FixedSizeBinary => {
+            let arr = any.downcast_ref::<FixedSizeBinaryArray>().unwrap();
+            FixedSizeBinaryArray::try_new(dtype, arr.values().clone(), arr.validity().cloned())?
+                .boxed()
+        },
+        PhysicalType::List => {
+            let arr = any.downcast_ref::<ListArray<i32>>().unwrap();
+            ListArray::<i32>::try_new(
+                dtype,
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )?
+            .boxed()
+        },
+        PhysicalType::LargeList => {
+            let arr = any.downcast_ref::<ListArray<i64>>().unwrap();
+            ListArray::<i64>::try_new(
+                dtype,
+                arr.offsets().clone(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )?
+            .boxed()
+        },
+        PhysicalType::FixedSizeList => {
+            let arr = any.downcast_ref::<FixedSizeListArray>().unwrap();
+            FixedSizeListArray::try_new(
+                dtype,
+                arr.len(),
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )?
+            .boxed()
+        },
+        PhysicalType::Struct => {
+            let arr = any.downcast_ref::<StructArray>().unwrap();
+            StructArray::try_new(
+                dtype,
+                arr.len(),
+                arr.values().to_vec(),
+                arr.validity().cloned(),
+            )?
+            .boxed()
+        },
+        physical => {
+            polars_bail!(ComputeError: "extension types of {:?} storage are not supported", physical)
+        },
+    })
+}
+
+/// An array with offsets into a values buffer, see [`with_dtype`].
+trait OffsetsArray: Array + Sized {
+    type Offset: Offset;
+
+    fn into_inner(
+        self,
+    ) -> (
+        ArrowDataType,
+        OffsetsBuffer<Self::Offset>,
+        Buffer<u8>,
+        Option<Bitmap>,
+    );
+
+    fn try_new(
+        dtype: ArrowDataType,
+        offsets: OffsetsBuffer<Self::Offset>,
+        values: Buffer<u8>,
+        validity: Option<Bitmap>,
+    ) -> PolarsResult<Self>;
+}
+
+macro_rules! impl_offsets_array {
+    ($array:ident) => {
+        impl<O: Offset> OffsetsArray for $array<O> {
+            type Offset = O;
+
+            fn into_inner(self) -> (ArrowDataType, OffsetsBuffer<O>, Buffer<u8>, Option<Bitmap>) {
+                $array::into_inner(self)
+            }
+
+            fn try_new(
+                dtype: ArrowDataType,
+                offsets: OffsetsBuffer<O>,
+                values: Buffer<u8>,
+                validity: Option<Bitmap>,
+            ) -> PolarsResult<Self> {
+                $array::try_new(dtype, offsets, values, validity)
+            }
+        }
+    };
+}
+
+impl_offsets_array!(BinaryArray);
+impl_offsets_array!(Utf8Array);
+
+fn with_offsets_dtype<A: OffsetsArray + Clone>(
+    any: &dyn std::any::Any,
+    dtype: ArrowDataType,
+) -> PolarsResult<ArrayRef> {
+    let (_, offsets, values, validity) = any.downcast_ref::<A>().unwrap().clone().into_inner();
+    Ok(Box::new(A::try_new(dtype, offsets, values, validity)?))
+}
+
+/// Validate the registered extension type of `chunks` and convert them to its storage type.
+pub(crate) fn to_storage_chunks(
+    chunks: Vec<ArrayRef>,
+    ext: &ExtensionType,
+) -> PolarsResult<Vec<ArrayRef>> {
+    validate(ext)?;
+    chunks
+        .iter()
+        .map(|arr| with_dtype(arr.as_ref(), &ext.inner))
+        .collect()
+}
+
+/// Convert `arrow.uuid` chunks to the [`BinaryChunked`] storage of [`DataType::Uuid`].
+pub(crate) fn uuid_to_storage_chunks(chunks: &[ArrayRef]) -> PolarsResult<Vec<ArrayRef>> {
+    chunks
+        .iter()
+        .map(|arr| {
+            let arr = with_dtype(arr.as_ref(), &ArrowDataType::FixedSizeBinary(16))?;
+            let arr = arr.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+            Ok(polars_compute::cast::fixed_size_binary_to_binview(arr).boxed())
+        })
+        .collect()
+}
+
+fn ensure_uuid_len(len: usize) -> PolarsResult<()> {
+    polars_ensure!(
+        len == 16,
+        ComputeError: "a UUID must be 16 bytes long, found a value of {} bytes", len
+    );
+    Ok(())
+}
+
+/// A [`Series`] of a registered extension type or of [`DataType::Uuid`], backed by a [`Series`]
+/// of its storage type.
+#[derive(Clone)]
+pub struct ExtensionChunked {
+    pub(crate) dtype: DataType,
+    pub(crate) storage: Series,
+}
+
+impl ExtensionChunked {
+    /// Wrap `storage` in the registered extension type `name`.
+    ///
+    /// The storage type and `metadata` are checked with [`ExtensionTypeImpl::validate`].
+    pub fn try_new(
+        name: PlSmallStr,
+        metadata: Option<PlSmallStr>,
+        storage: Series,
+    ) -> PolarsResult<Self> {
+        let ext = ExtensionType {
+            name,
+            inner: storage.dtype().to_arrow(CompatLevel::newest()),
+            metadata,
+        };
+        validate(&ext)?;
+        Ok(Self::new_unchecked(ext.name, ext.metadata, storage))
+    }
+
+    /// Wrap 16-byte values as [`DataType::Uuid`].
+    pub fn try_new_uuid(storage: BinaryChunked) -> PolarsResult<Self> {
+        for arr in storage.downcast_iter() {
+            arr.iter()
+                .flatten()
+                .try_for_each(|v| ensure_uuid_len(v.len()))?;
+        }
+        Ok(Self::new_uuid_unchecked(storage.into_series()))
+    }
+
+    /// Wrap a [`BinaryChunked`] of 16-byte values as [`DataType::Uuid`] without checking them.
+    pub(crate) fn new_uuid_unchecked(storage: Series) -> Self {
+        debug_assert_eq!(storage.dtype(), &DataType::Binary);
+        Self {
+            dtype: DataType::Uuid,
+            storage,
+        }
+    }
+
+    /// Wrap `storage` without validating it against the registered type.
+    pub(crate) fn new_unchecked(
+        name: PlSmallStr,
+        metadata: Option<PlSmallStr>,
+        storage: Series,
+    ) -> Self {
+        let dtype = DataType::Extension(name, metadata, Box::new(storage.dtype().clone()));
+        Self { dtype, storage }
+    }
+
+    /// The name of the extension type.
+    pub fn extension_name(&self) -> &str {
+        match &self.dtype {
+            DataType::Extension(name, _, _) => name,
+            DataType::Uuid => UUID_EXTENSION_NAME,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The serialized metadata of the extension type.
+    pub fn extension_metadata(&self) -> Option<&PlSmallStr> {
+        match &self.dtype {
+            DataType::Extension(_, metadata, _) => metadata.as_ref(),
+            DataType::Uuid => None,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn storage(&self) -> &Series {
+        &self.storage
+    }
+
+    pub fn into_storage(self) -> Series {
+        self.storage
+    }
+
+    /// Wrap the result of an operation on the storage, which must not change its data type.
+    pub(crate) fn with_storage(&self, storage: Series) -> Series {
+        debug_assert_eq!(storage.dtype(), self.storage.dtype());
+        Self {
+            dtype: self.dtype.clone(),
+            storage,
+        }
+        .into_series()
+    }
+
+    /// Convert a chunk to an Arrow array of the extension type.
+    pub(crate) fn to_arrow(&self, chunk_idx: usize, compat_level: CompatLevel) -> ArrayRef {
+        if let DataType::Uuid = self.dtype {
+            let arr = self
+                .storage
+                .binary()
+                .unwrap()
+                .downcast_get(chunk_idx)
+                .unwrap();
+            let mut values = Vec::with_capacity(arr.len() * 16);
+            for opt_v in arr.iter() {
+                values.extend_from_slice(opt_v.unwrap_or([0; 16].as_slice()));
+            }
+            let dtype = self.dtype.to_arrow(compat_level);
+            return FixedSizeBinaryArray::new(dtype, values.into(), arr.validity().cloned())
+                .boxed();
+        }
+        let arr = self.storage.to_arrow(chunk_idx, compat_level);
+        with_dtype(arr.as_ref(), &self.dtype.to_arrow(compat_level)).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::datatypes::IntegerType;
+
+    use super::*;
+
+    struct Meters;
+
+    impl ExtensionTypeImpl for Meters {
+        fn name(&self) -> &str {
+            "test.meters"
+        }
+
+        fn validate(&self, storage: &ArrowDataType, metadata: Option<&str>) -> PolarsResult<()> {
+            polars_ensure!(
+                storage == &ArrowDataType::Int32 && metadata.is_none(),
+                ComputeError: "invalid storage for meters: {:?}", storage
+            );
+            Ok(())
+        }
+    }
+
+    fn values(s: &Series) -> Vec<Option<i32>> {
+        s.extension()
+            .unwrap()
+            .storage()
+            .i32()
+            .unwrap()
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_extension_roundtrip() -> PolarsResult<()> {
+        let arrow_dtype = ArrowDataType::Extension(Box::new(ExtensionType {
+            name: "test.meters".into(),
+            inner: ArrowDataType::Int32,
+            metadata: None,
+        }));
+        let dtype = DataType::Extension("test.meters".into(), None, Box::new(DataType::Int32));
+        let arr = Int32Array::from_slice([1, 2, 3])
+            .to(arrow_dtype.clone())
+            .boxed();
+        assert!(Series::try_from((PlSmallStr::EMPTY, arr.clone())).is_err());
+
+        register_extension_type(Arc::new(Meters));
+        let s = Series::try_from((PlSmallStr::from_static("a"), arr))?;
+        assert_eq!(s.dtype(), &dtype);
+        assert_eq!(s.to_arrow(0, CompatLevel::newest()).dtype(), &arrow_dtype);
+
+        let df = DataFrame::new(vec![s.into(), Column::new("b".into(), [4, 5, 6])])?;
+        let selected = df.select(["a"])?;
+        assert_eq!(selected.schema().get("a"), Some(&dtype));
+
+        let mut concatenated = selected.clone();
+        concatenated.vstack_mut(&selected)?;
+        let a = concatenated.column("a")?.as_materialized_series();
+        assert_eq!(a.dtype(), &dtype);
+        assert_eq!(values(a), [1, 2, 3, 1, 2, 3].map(Some));
+
+        let a = a.filter(&a.extension()?.storage().i32()?.gt(1))?;
+        assert_eq!(a.dtype(), &dtype);
+        assert_eq!(values(&a), [2, 3, 2, 3].map(Some));
+
+        let plain = DataFrame::new(vec![Column::new("a".into(), [1i32])])?;
+        assert!(selected.clone().vstack_mut(&plain).is_err());
+
+        let cast = Series::new("c".into(), [1i64, 2]).cast(&dtype)?;
+        assert_eq!(cast.dtype(), &dtype);
+        assert_eq!(cast.cast(&DataType::Int32)?.dtype(), &DataType::Int32);
+        let float = Series::new("c".into(), [1.5f64]);
+        assert!(ExtensionChunked::try_new("test.meters".into(), None, float).is_err());
+
+        unregister_extension_type("test.meters");
+        Ok(())
+    }
+
+    #[test]
+    fn test_uuid() -> PolarsResult<()> {
+        let (a, b) = ([2u8; 16], [1u8; 16]);
+        let bytes = Series::new("id".into(), [a.as_slice(), b.as_slice()]);
+        let s = bytes.cast(&DataType::Uuid)?;
+        assert_eq!(s.dtype(), &DataType::Uuid);
+
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert_eq!(
+            arr.dtype(),
+            &ArrowDataType::Extension(Box::new(ExtensionType::uuid()))
+        );
+        let roundtrip = Series::try_from((PlSmallStr::from_static("id"), arr))?;
+        assert_eq!(roundtrip.dtype(), &DataType::Uuid);
+        assert!(roundtrip.cast(&DataType::Binary)?.equals_missing(&bytes));
+
+        let sorted = s.sort(SortOptions::default())?;
+        assert_eq!(sorted.dtype(), &DataType::Uuid);
+        let sorted = sorted.cast(&DataType::Binary)?;
+        assert_eq!(sorted.binary()?.get(0), Some(b.as_slice()));
+
+        let short = Series::new("id".into(), [[0u8; 15].as_slice()]);
+        assert!(short.strict_cast(&DataType::Uuid).is_err());
+        assert_eq!(short.cast(&DataType::Uuid)?.null_count(), 1);
+        assert!(
+            Series::new("id".into(), ["a"])
+                .cast(&DataType::Uuid)
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_extension_unsupported_storage() {
+        let ext = ExtensionType {
+            name: "test.dictionary".into(),
+            inner: ArrowDataType::Dictionary(
+                IntegerType::UInt32,
+                Box::new(ArrowDataType::Utf8View),
+                false,
+            ),
+            metadata: None,
+        };
+        let err = validate(&ext).unwrap_err();
+        assert!(err.to_string().contains("is not supported"));
+    }
+}
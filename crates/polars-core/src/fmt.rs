@@ -437,6 +437,13 @@ impl Debug for Series {
                     "Series"
                 )
             },
+            dt @ (DataType::Extension(_, _, _) | DataType::Uuid) => format_array!(
+                f,
+                self.extension().unwrap(),
+                format!("{dt}"),
+                self.name(),
+                "Series"
+            ),
             dt => panic!("{dt:?} not impl"),
         }
     }
@@ -0,0 +1,289 @@
+use arrow::array::*;
+use arrow::bitmap::Bitmap;
+use arrow::buffer::Buffer;
+use arrow::datatypes::{ArrowDataType, PhysicalType};
+use arrow::offset::{Offset, OffsetsBuffer};
+use arrow::{ffi, match_integer_type, with_match_primitive_type_full};
+
+use crate::prelude::*;
+
+/// What happens to the buffers of arrays imported with
+/// [`DataFrame::from_arrow_schema_and_buffers`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ArrowBufferMode {
+    /// Keep referring to the buffers of the producer, which are released once the
+    /// [`DataFrame`] and everything derived from it are dropped. Operations that would modify
+    /// them in place copy them first.
+    #[default]
+    CopyOnWrite,
+    /// Copy the buffers into memory owned by Polars, releasing the arrays of the producer
+    /// before returning.
+    Copy,
+}
+
+fn buffer<T: Clone>(buffer: &Buffer<T>, mode: ArrowBufferMode) -> Buffer<T> {
+    match mode {
+        ArrowBufferMode::CopyOnWrite => buffer.clone(),
+        ArrowBufferMode::Copy => buffer.to_vec().into(),
+    }
+}
+
+fn bitmap(bitmap: Option<&Bitmap>, mode: ArrowBufferMode) -> Option<Bitmap> {
+    bitmap.map(|bitmap| match mode {
+        ArrowBufferMode::CopyOnWrite => bitmap.clone(),
+        ArrowBufferMode::Copy => bitmap.iter().collect(),
+    })
+}
+
+fn offsets<O: Offset>(
+    offsets: &OffsetsBuffer<O>,
+    mode: ArrowBufferMode,
+) -> PolarsResult<OffsetsBuffer<O>> {
+    // checks that the offsets are monotonically increasing
+    OffsetsBuffer::try_from(buffer(offsets.buffer(), mode))
+}
+
+fn primitive<T: NativeType>(
+    array: &dyn Array,
+    mode: ArrowBufferMode,
+) -> PolarsResult<PrimitiveArray<T>> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    PrimitiveArray::try_new(
+        array.dtype().clone(),
+        buffer(array.values(), mode),
+        bitmap(array.validity(), mode),
+    )
+}
+
+fn binview<T: ViewType + ?Sized>(
+    array: &dyn Array,
+    mode: ArrowBufferMode,
+) -> PolarsResult<BinaryViewArrayGeneric<T>> {
+    let array = array
+        .as_any()
+        .downcast_ref::<BinaryViewArrayGeneric<T>>()
+        .unwrap();
+    let buffers = array
+        .data_buffers()
+        .iter()
+        .map(|b| buffer(b, mode))
+        .collect();
+    BinaryViewArrayGeneric::try_new(
+        array.dtype().clone(),
+        buffer(array.views(), mode),
+        buffers,
+        bitmap(array.validity(), mode),
+    )
+}
+
+/// Rebuild an imported array with the checked constructors, which validate the offsets,
+/// views, UTF-8 data and dictionary keys that importing trusts the producer for.
+fn rebuild(array: &dyn Array, mode: ArrowBufferMode) -> PolarsResult<Box<dyn Array>> {
+    use PhysicalType::*;
+    let dtype = array.dtype().clone();
+    Ok(match dtype.to_physical_type() {
+        Null => array.to_boxed(),
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let values = bitmap(Some(array.values()), mode).unwrap();
+            BooleanArray::try_new(dtype, values, bitmap(array.validity(), mode))?.boxed()
+        },
+        Primitive(primitive_type) => with_match_primitive_type_full!(primitive_type, |$T| {
+            primitive::<$T>(array, mode)?.boxed()
+        }),
+        Binary | LargeBinary | Utf8 | LargeUtf8 => {
+            macro_rules! rebuild_binary {
+                ($ty:ty) => {{
+                    let array = array.as_any().downcast_ref::<$ty>().unwrap();
+                    <$ty>::try_new(
+                        dtype,
+                        offsets(array.offsets(), mode)?,
+                        buffer(array.values(), mode),
+                        bitmap(array.validity(), mode),
+                    )?
+                    .boxed()
+                }};
+            }
+            match dtype.to_physical_type() {
+                Binary => rebuild_binary!(BinaryArray<i32>),
+                LargeBinary => rebuild_binary!(BinaryArray<i64>),
+                Utf8 => rebuild_binary!(Utf8Array<i32>),
+                _ => rebuild_binary!(Utf8Array<i64>),
+            }
+        },
+        BinaryView => binview::<[u8]>(array, mode)?.boxed(),
+        Utf8View => binview::<str>(array, mode)?.boxed(),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            FixedSizeBinaryArray::try_new(
+                dtype,
+                buffer(array.values(), mode),
+                bitmap(array.validity(), mode),
+            )?
+            .boxed()
+        },
+        List | LargeList => {
+            macro_rules! rebuild_list {
+                ($o:ty) => {{
+                    let array = array.as_any().downcast_ref::<ListArray<$o>>().unwrap();
+                    ListArray::<$o>::try_new(
+                        dtype,
+                        offsets(array.offsets(), mode)?,
+                        rebuild(array.values().as_ref(), mode)?,
+                        bitmap(array.validity(), mode),
+                    )?
+                    .boxed()
+                }};
+            }
+            match dtype.to_physical_type() {
+                List => rebuild_list!(i32),
+                _ => rebuild_list!(i64),
+            }
+        },
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            FixedSizeListArray::try_new(
+                dtype,
+                array.len(),
+                rebuild(array.values().as_ref(), mode)?,
+                bitmap(array.validity(), mode),
+            )?
+            .boxed()
+        },
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = array
+                .values()
+                .iter()
+                .map(|v| rebuild(v.as_ref(), mode))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            StructArray::try_new(dtype, array.len(), values, bitmap(array.validity(), mode))?
+                .boxed()
+        },
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let keys = primitive::<$T>(array.keys(), mode)?;
+            let values = rebuild(array.values().as_ref(), mode)?;
+            // checks that the keys are in bounds of the values
+            DictionaryArray::<$T>::try_new(dtype, keys, values)?.boxed()
+        }),
+        dt => polars_bail!(
+            ComputeError: "importing arrays of physical type {:?} is not supported", dt
+        ),
+    })
+}
+
+impl DataFrame {
+    /// Create a [`DataFrame`] from Arrow C Data Interface structs, without serializing through
+    /// IPC.
+    ///
+    /// The `schema` describes a struct whose fields are the columns, and each array in `arrays`
+    /// is a struct array of that type, holding a chunk of the rows (a record batch). The
+    /// arrays are moved into the [`DataFrame`]; the schema is only borrowed.
+    ///
+    /// Unlike a plain import, every array is validated: the offsets must be in bounds and
+    /// increasing, string data must be valid UTF-8, views must point into their buffers and
+    /// dictionary keys into their values. Whether the buffers of the producer are shared or
+    /// copied is set by `mode`.
+    ///
+    /// # Safety
+    /// The structs must be valid according to the
+    /// [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html): every
+    /// buffer it declares must be readable for the lengths it declares, and the arrays must
+    /// not be mutated by the producer while they are in use.
+    pub unsafe fn from_arrow_schema_and_buffers(
+        schema: &ffi::ArrowSchema,
+        arrays: Vec<ffi::ArrowArray>,
+        mode: ArrowBufferMode,
+    ) -> PolarsResult<Self> {
+        let field = unsafe { ffi::import_field_from_c(schema) }?;
+        let ArrowDataType::Struct(fields) = field.dtype() else {
+            polars_bail!(
+                SchemaMismatch: "expected the schema of a struct (a record batch), got {:?}",
+                field.dtype()
+            );
+        };
+        let arrow_schema: ArrowSchema =
+            fields.iter().map(|f| (f.name.clone(), f.clone())).collect();
+        polars_ensure!(
+            arrow_schema.len() == fields.len(),
+            Duplicate: "the schema has duplicate column names"
+        );
+
+        let mut df = DataFrame::empty_with_arrow_schema(&arrow_schema);
+        for array in arrays {
+            let array = unsafe { ffi::import_array_from_c(array, field.dtype().clone()) }?;
+            let array = rebuild(array.as_ref(), mode)?;
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            df.vstack_mut(&DataFrame::try_from(array.clone())?)?;
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn export(df: &DataFrame) -> (ffi::ArrowSchema, Vec<ffi::ArrowArray>) {
+        let fields = df
+            .schema()
+            .to_arrow(CompatLevel::newest())
+            .into_iter_values()
+            .collect();
+        let dtype = ArrowDataType::Struct(fields);
+        let field = ArrowField::new(PlSmallStr::EMPTY, dtype.clone(), false);
+        let arrays = df
+            .iter_chunks(CompatLevel::newest(), false)
+            .map(|batch| {
+                let array = StructArray::new(dtype.clone(), batch.len(), batch.into_arrays(), None);
+                ffi::export_array_to_c(array.boxed())
+            })
+            .collect();
+        (ffi::export_field_to_c(&field), arrays)
+    }
+
+    #[test]
+    fn test_from_arrow_schema_and_buffers() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [Some(1i64), None, Some(3)],
+            "b" => ["x", "yy", "a string longer than twelve bytes"],
+        ]?;
+        df.vstack_mut(&df.clone())?;
+
+        for mode in [ArrowBufferMode::CopyOnWrite, ArrowBufferMode::Copy] {
+            let (schema, arrays) = export(&df);
+            assert_eq!(arrays.len(), 2);
+            let out = unsafe { DataFrame::from_arrow_schema_and_buffers(&schema, arrays, mode) }?;
+            assert!(out.equals_missing(&df));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_arrow_schema_and_buffers_validates() {
+        let offsets = unsafe { OffsetsBuffer::new_unchecked(vec![0i32, 4, 2].into()) };
+        let values = unsafe {
+            Utf8Array::<i32>::new_unchecked(
+                ArrowDataType::Utf8,
+                offsets,
+                b"abcd".to_vec().into(),
+                None,
+            )
+        };
+        let dtype =
+            ArrowDataType::Struct(vec![ArrowField::new("a".into(), ArrowDataType::Utf8, true)]);
+        let field = ArrowField::new(PlSmallStr::EMPTY, dtype.clone(), false);
+        let array = StructArray::new(dtype, 2, vec![values.boxed()], None);
+
+        let schema = ffi::export_field_to_c(&field);
+        let arrays = vec![ffi::export_array_to_c(array.boxed())];
+        let out = unsafe {
+            DataFrame::from_arrow_schema_and_buffers(&schema, arrays, ArrowBufferMode::Copy)
+        };
+        assert!(out.is_err());
+    }
+}
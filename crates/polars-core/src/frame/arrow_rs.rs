@@ -0,0 +1,96 @@
+use arrow::interchange::arrow_rs::{
+    arrow_array, batch_from_arrow_rs, batch_to_arrow_rs, schema_from_arrow_rs, schema_to_arrow_rs,
+};
+use arrow::record_batch::RecordBatch;
+
+use crate::prelude::*;
+
+impl DataFrame {
+    /// Convert the [`DataFrame`] to arrow-rs record batches of at most `chunk_size` rows,
+    /// one batch at a time.
+    ///
+    /// The buffers are shared with the [`DataFrame`] where a batch falls into a single chunk of
+    /// a column; only the rows of a batch that span several chunks are copied. A frame without
+    /// rows gives no batches.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    pub fn iter_record_batches(
+        &self,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = PolarsResult<arrow_array::RecordBatch>> + '_ {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        let schema = Arc::new(self.schema().to_arrow(CompatLevel::newest()));
+        let rs_schema = schema_to_arrow_rs(&schema);
+
+        (0..self.height()).step_by(chunk_size).map(move |offset| {
+            let rs_schema = rs_schema.as_ref().map_err(Clone::clone)?;
+            let df = self.slice(offset as i64, chunk_size);
+            let arrays = df
+                .materialized_column_iter()
+                .map(|s| {
+                    let s = if s.n_chunks() == 1 {
+                        s.clone()
+                    } else {
+                        s.rechunk()
+                    };
+                    s.to_arrow(0, CompatLevel::newest())
+                })
+                .collect();
+            let batch = RecordBatch::try_new(df.height(), schema.clone(), arrays)?;
+            batch_to_arrow_rs(batch, rs_schema)
+        })
+    }
+
+    /// Create a [`DataFrame`] from arrow-rs record batches, converting them one at a time.
+    ///
+    /// Every batch becomes a chunk of the columns, sharing the buffers of the batch. All batches
+    /// must have the schema of the first; no batches give an empty [`DataFrame`].
+    pub fn from_record_batch_iter<I>(batches: I) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = arrow_array::RecordBatch>,
+    {
+        let mut batches = batches.into_iter();
+        let Some(first) = batches.next() else {
+            return Ok(DataFrame::empty());
+        };
+        let rs_schema = first.schema();
+        let schema = schema_from_arrow_rs(&rs_schema)?;
+
+        let mut df = DataFrame::empty_with_arrow_schema(&schema);
+        for batch in std::iter::once(first).chain(batches) {
+            polars_ensure!(
+                batch.schema() == rs_schema,
+                SchemaMismatch: "record batches must have the same schema, got {:?} and {:?}",
+                rs_schema, batch.schema()
+            );
+            df.append_record_batch(batch_from_arrow_rs(&batch, &schema)?)?;
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_record_batch_iter_roundtrip() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [Some(1i32), None, Some(3)],
+            "b" => ["x", "y", "a string longer than twelve bytes"],
+        ]?;
+        df.vstack_mut(&df.clone())?;
+
+        let batches = df
+            .iter_record_batches(4)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let heights = batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+        assert_eq!(heights, [4, 2]);
+
+        let out = DataFrame::from_record_batch_iter(batches)?;
+        assert_eq!(out.first_col_n_chunks(), 2);
+        assert!(out.equals_missing(&df));
+        Ok(())
+    }
+}
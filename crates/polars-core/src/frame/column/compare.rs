@@ -7,6 +7,14 @@ macro_rules! column_element_wise_broadcasting {
         match ($lhs, $rhs) {
             (Column::Series(lhs), Column::Scalar(rhs)) => $op(lhs, &rhs.as_single_value_series()),
             (Column::Scalar(lhs), Column::Series(rhs)) => $op(&lhs.as_single_value_series(), rhs),
+            (Column::Partitioned(lhs), Column::Scalar(rhs)) => {
+                $op(lhs.partitions(), &rhs.as_single_value_series())
+                    .map(|ca| lhs.expand_runs_bool(&ca))
+            },
+            (Column::Scalar(lhs), Column::Partitioned(rhs)) => {
+                $op(&lhs.as_single_value_series(), rhs.partitions())
+                    .map(|ca| rhs.expand_runs_bool(&ca))
+            },
             (Column::Scalar(lhs), Column::Scalar(rhs)) => {
                 $op(&lhs.as_single_value_series(), &rhs.as_single_value_series()).map(|ca| {
                     if ca.len() == 0 {
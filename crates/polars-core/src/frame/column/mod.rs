@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use arrow::bitmap::BitmapBuilder;
+use arrow::compute::aggregate::{MemoryKind, MemoryProfile, MemoryProfiler};
 use arrow::trusted_len::TrustMyLength;
 use num_traits::{Num, NumCast};
 use polars_compute::rolling::QuantileMethod;
@@ -76,6 +77,26 @@ impl Column {
         Self::Scalar(ScalarColumn::new(name, scalar, length))
     }
 
+    /// Store `series` run-end encoded, as a value and an end for every run of equal values.
+    ///
+    /// Slicing, filtering and comparing with a scalar operate on the runs without
+    /// materializing the column.
+    pub fn new_run_end_encoded(series: &Series) -> PolarsResult<Self> {
+        PartitionedColumn::from_runs(series).map(Self::Partitioned)
+    }
+
+    /// Run-end encode a materialized column if its runs are long enough to save memory, e.g. for
+    /// status or flag columns.
+    pub fn into_run_end_encoded_if_beneficial(self) -> PolarsResult<Self> {
+        let Column::Series(s) = &self else {
+            return Ok(self);
+        };
+        Ok(match PartitionedColumn::from_runs_if_beneficial(s)? {
+            Some(rle) => Self::Partitioned(rle),
+            None => self,
+        })
+    }
+
     pub fn new_row_index(name: PlSmallStr, offset: IdxSize, length: usize) -> PolarsResult<Column> {
         let length = IdxSize::try_from(length).unwrap_or(IdxSize::MAX);
 
@@ -546,8 +567,7 @@ impl Column {
     pub fn slice(&self, offset: i64, length: usize) -> Column {
         match self {
             Column::Series(s) => s.slice(offset, length).into(),
-            // @partition-opt
-            Column::Partitioned(s) => s.as_materialized_series().slice(offset, length).into(),
+            Column::Partitioned(s) => s.slice(offset, length).into(),
             Column::Scalar(s) => {
                 let (_, length) = slice_offsets(offset, length, s.len());
                 s.resize(length).into()
@@ -1284,6 +1304,7 @@ impl Column {
     pub fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Self> {
         match self {
             Column::Series(s) => s.filter(filter).map(Column::from),
+            Column::Partitioned(s) if filter.len() == s.len() => s.filter(filter).map(Column::from),
             Column::Partitioned(s) => s.as_materialized_series().filter(filter).map(Column::from),
             Column::Scalar(s) => {
                 if s.is_empty() {
@@ -1604,6 +1625,33 @@ impl Column {
         self.as_materialized_series().estimated_size()
     }
 
+    /// The memory held by the column, see [`DataFrame::memory_profile`].
+    ///
+    /// A [`ScalarColumn`] that was not materialized only holds its value, which is not
+    /// counted.
+    pub(crate) fn memory_profile(&self, profiler: &mut MemoryProfiler) -> MemoryProfile {
+        let mut profile = MemoryProfile::default();
+        let materialized = match self {
+            Column::Series(s) => return s.memory_profile(profiler),
+            Column::Partitioned(s) => {
+                profile += s.partitions().memory_profile(profiler);
+                let ends = s.partition_ends();
+                profiler.add_allocation(
+                    ends.as_ptr() as usize,
+                    size_of_val(ends),
+                    MemoryKind::Offsets,
+                    &mut profile,
+                );
+                s.lazy_as_materialized_series()
+            },
+            Column::Scalar(s) => s.lazy_as_materialized_series(),
+        };
+        if let Some(s) = materialized {
+            profile += s.memory_profile(profiler);
+        }
+        profile
+    }
+
     pub fn sort_with(&self, options: SortOptions) -> PolarsResult<Self> {
         match self {
             Column::Series(s) => s.sort_with(options).map(Self::from),
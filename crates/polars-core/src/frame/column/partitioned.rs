@@ -2,14 +2,24 @@ use std::borrow::Cow;
 use std::convert::identity;
 use std::sync::{Arc, OnceLock};
 
+use arrow::array::BooleanArray;
+use arrow::bitmap::MutableBitmap;
 use polars_error::{PolarsResult, polars_ensure};
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 
-use super::{AnyValue, Column, DataType, Field, IntoColumn, Series};
+use super::{
+    AnyValue, BooleanChunked, ChunkCompareEq, Column, DataType, Field, IntoColumn, Series,
+};
 use crate::chunked_array::cast::CastOptions;
 use crate::frame::Scalar;
 use crate::series::IsSorted;
+use crate::utils::slice_offsets;
+
+/// The minimum average run length for which run-end encoding a column is worth it.
+///
+/// Every run costs a value and an `IdxSize` end, so shorter runs rarely save memory.
+const MIN_AVG_RUN_LENGTH: usize = 16;
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -85,6 +95,35 @@ impl PartitionedColumn {
         Ok(unsafe { Self::new_unchecked(name, values, ends) })
     }
 
+    /// Run-end encode `s`, storing every run of equal consecutive values once.
+    pub fn from_runs(s: &Series) -> PolarsResult<Self> {
+        let starts = run_starts(s)?;
+        Self::from_run_starts(s, &starts)
+    }
+
+    /// Run-end encode `s` if its runs are long enough on average to save memory.
+    pub fn from_runs_if_beneficial(s: &Series) -> PolarsResult<Option<Self>> {
+        let starts = run_starts(s)?;
+        if starts.len() * MIN_AVG_RUN_LENGTH > s.len() {
+            return Ok(None);
+        }
+        Self::from_run_starts(s, &starts).map(Some)
+    }
+
+    fn from_run_starts(s: &Series, starts: &[IdxSize]) -> PolarsResult<Self> {
+        let ends = starts
+            .iter()
+            .skip(1)
+            .copied()
+            .chain((!s.is_empty()).then_some(s.len() as IdxSize))
+            .collect();
+        let mut values = s.take_slice(starts)?;
+        // The first value of every run is in the same order as the runs.
+        values.set_sorted_flag(s.is_sorted_flag());
+        // SAFETY: there is a value for every run and the runs are in order.
+        Ok(unsafe { Self::new_unchecked(s.name().clone(), values, ends) })
+    }
+
     pub fn new_empty(name: PlSmallStr, dtype: DataType) -> Self {
         Self {
             name,
@@ -144,7 +183,9 @@ impl PartitionedColumn {
 
         debug_assert_eq!(column.len(), prev_offset as usize);
 
-        column.take_materialized_series()
+        let mut s = column.take_materialized_series();
+        s.set_sorted_flag(values.is_sorted_flag());
+        s
     }
 
     /// Materialize the [`PartitionedColumn`] into a [`Series`].
@@ -186,6 +227,94 @@ impl PartitionedColumn {
         Ok(unsafe { Self::new_unchecked(self.name.clone(), result, self.ends.clone()) })
     }
 
+    pub fn slice(&self, offset: i64, length: usize) -> Self {
+        let (offset, length) = slice_offsets(offset, length, self.len());
+        if length == 0 {
+            return self.clear();
+        }
+        let end = offset + length;
+
+        // The runs that contain the first and the last element of the slice.
+        let first = self.ends.partition_point(|&e| e as usize <= offset);
+        let last = self.ends.partition_point(|&e| (e as usize) < end);
+
+        let values = self.values.slice(first as i64, last - first + 1);
+        let ends = self.ends[first..=last]
+            .iter()
+            .map(|&e| (e as usize).min(end) as IdxSize - offset as IdxSize)
+            .collect();
+        // SAFETY: the values and the ends are sliced to the same runs.
+        unsafe { Self::new_unchecked(self.name.clone(), values, ends) }
+    }
+
+    /// Filter without materializing, by counting the selected elements of every run.
+    ///
+    /// `filter` must have the length of the column.
+    pub fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Self> {
+        polars_ensure!(
+            filter.len() == self.len(),
+            ShapeMismatch: "filter's length: {} differs from that of the series: {}",
+            filter.len(), self.len()
+        );
+        let filter = filter.rechunk();
+        let arr = filter.downcast_as_array();
+        // Null counts as false.
+        let mask = match arr.validity() {
+            Some(validity) => arr.values() & validity,
+            None => arr.values().clone(),
+        };
+
+        let mut keep = Vec::new();
+        let mut ends = Vec::new();
+        let mut start = 0;
+        let mut new_end = 0;
+        for (i, &end) in self.ends.iter().enumerate() {
+            let len = end as usize - start;
+            let n_selected = len - mask.null_count_range(start, len);
+            if n_selected > 0 {
+                new_end += n_selected as IdxSize;
+                keep.push(i as IdxSize);
+                ends.push(new_end);
+            }
+            start = end as usize;
+        }
+        let mut values = self.values.take_slice(&keep)?;
+        if keep.len() < self.ends.len() {
+            // Dropping a run can leave runs of equal values next to each other, merge those.
+            let starts = run_starts(&values)?;
+            if starts.len() < values.len() {
+                ends = starts
+                    .iter()
+                    .skip(1)
+                    .map(|&start| ends[start as usize - 1])
+                    .chain(ends.last().copied())
+                    .collect();
+                values = values.take_slice(&starts)?;
+            }
+        }
+        values.set_sorted_flag(self.values.is_sorted_flag());
+        // SAFETY: there is a value for every kept run and the runs are in order.
+        Ok(unsafe { Self::new_unchecked(self.name.clone(), values, ends.into()) })
+    }
+
+    /// Expand `ca`, which holds a value for every run, to the length of the column.
+    pub fn expand_runs_bool(&self, ca: &BooleanChunked) -> BooleanChunked {
+        debug_assert_eq!(ca.len(), self.ends.len());
+        let mut values = MutableBitmap::with_capacity(self.len());
+        let mut validity = (ca.null_count() > 0).then(|| MutableBitmap::with_capacity(self.len()));
+        let mut start = 0;
+        for (v, &end) in ca.iter().zip(self.ends.iter()) {
+            let len = end as usize - start;
+            values.extend_constant(len, v.unwrap_or(false));
+            if let Some(validity) = &mut validity {
+                validity.extend_constant(len, v.is_some());
+            }
+            start = end as usize;
+        }
+        let arr = BooleanArray::from_data_default(values.into(), validity.map(|v| v.into()));
+        BooleanChunked::with_chunk(self.name.clone(), arr)
+    }
+
     pub fn extend_constant(&self, value: AnyValue, n: usize) -> PolarsResult<Self> {
         let mut new_ends = self.ends.to_vec();
         // @TODO: IdxSize checks
@@ -292,3 +421,106 @@ impl PartitionedColumn {
         self.values.and_reduce()
     }
 }
+
+/// The index of the first element of every run of equal consecutive values in `s`.
+fn run_starts(s: &Series) -> PolarsResult<Vec<IdxSize>> {
+    if s.is_empty() {
+        return Ok(vec![]);
+    }
+    let n = s.len();
+    // `changed[i]` is set if element `i + 1` starts a new run.
+    let changed = s.slice(1, n - 1).not_equal_missing(&s.slice(0, n - 1))?;
+    let mut starts = vec![0];
+    starts.extend(
+        changed
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, changed)| changed.unwrap_or(false).then_some(i as IdxSize + 1)),
+    );
+    Ok(starts)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_run_end_encoding() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [
+                Some(1),
+                Some(1),
+                None,
+                None,
+                None,
+                Some(2),
+                Some(1),
+                Some(1),
+            ],
+        );
+        let rle = PartitionedColumn::from_runs(&s)?;
+        assert_eq!(rle.partitions().len(), 4);
+        assert_eq!(rle.partition_ends(), &[2, 5, 6, 8]);
+        assert!(rle.as_materialized_series().equals_missing(&s));
+
+        for (offset, length) in [(0, 8), (1, 3), (3, 3), (-2, 2), (5, 1), (8, 1)] {
+            let sliced = rle.slice(offset, length);
+            assert!(
+                sliced
+                    .take_materialized_series()
+                    .equals_missing(&s.slice(offset, length))
+            );
+        }
+
+        let mask = BooleanChunked::new(
+            "".into(),
+            [
+                Some(true),
+                Some(false),
+                None,
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(true),
+            ],
+        );
+        let filtered = rle.filter(&mask)?;
+        assert_eq!(filtered.partition_ends(), &[1, 2, 4]);
+        assert!(
+            filtered
+                .take_materialized_series()
+                .equals_missing(&s.filter(&mask)?)
+        );
+
+        // Dropping the runs in between merges the runs of ones.
+        let mask = BooleanChunked::new(
+            "".into(),
+            [true, true, false, false, false, false, true, false],
+        );
+        let filtered = rle.filter(&mask)?;
+        assert_eq!(filtered.partitions().len(), 1);
+        assert_eq!(filtered.partition_ends(), &[3]);
+        assert!(
+            filtered
+                .take_materialized_series()
+                .equals_missing(&s.filter(&mask)?)
+        );
+
+        let eq = rle.expand_runs_bool(&rle.partitions().equal(1)?);
+        assert!(eq.into_series().equals_missing(&s.equal(1)?.into_series()));
+
+        assert!(PartitionedColumn::from_runs_if_beneficial(&s)?.is_none());
+        let mut s = Series::new("a".into(), (0..100).map(|i| i / 20).collect::<Vec<i32>>());
+        s.set_sorted_flag(IsSorted::Ascending);
+        let rle = PartitionedColumn::from_runs_if_beneficial(&s)?.unwrap();
+        assert_eq!(rle.partition_ends(), &[20, 40, 60, 80, 100]);
+        assert_eq!(
+            rle.take_materialized_series().is_sorted_flag(),
+            IsSorted::Ascending
+        );
+        Ok(())
+    }
+}
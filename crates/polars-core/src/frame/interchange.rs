@@ -0,0 +1,139 @@
+use arrow::interchange::{ChunkStream, FromDataFrame, IntoDataFrame};
+use arrow::record_batch::RecordBatch;
+
+use crate::prelude::*;
+
+impl IntoDataFrame for DataFrame {
+    fn schema(&self) -> PolarsResult<ArrowSchemaRef> {
+        Ok(Arc::new(
+            DataFrame::schema(self).to_arrow(CompatLevel::newest()),
+        ))
+    }
+
+    /// A requested schema is honored by exporting each column with the newest or the oldest
+    /// [`CompatLevel`], whichever gives the requested type (e.g. `LargeUtf8` instead of
+    /// `Utf8View`).
+    fn into_chunks(
+        mut self,
+        requested_schema: Option<&ArrowSchema>,
+    ) -> PolarsResult<(ArrowSchemaRef, ChunkStream)> {
+        let levels = match requested_schema {
+            None => vec![CompatLevel::newest(); self.width()],
+            Some(requested) => {
+                polars_ensure!(
+                    requested.len() == self.width(),
+                    SchemaMismatch: "requested a schema of {} fields for a frame of {} columns",
+                    requested.len(), self.width()
+                );
+                self.get_columns()
+                    .iter()
+                    .zip(requested.iter_values())
+                    .map(|(c, field)| {
+                        [CompatLevel::newest(), CompatLevel::oldest()]
+                            .into_iter()
+                            .find(|level| &c.dtype().to_arrow(*level) == field.dtype())
+                            .ok_or_else(|| {
+                                polars_err!(
+                                    SchemaMismatch: "cannot export column '{}' of type {} as {:?}",
+                                    c.name(), c.dtype(), field.dtype()
+                                )
+                            })
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?
+            },
+        };
+        let schema: ArrowSchemaRef = Arc::new(
+            self.get_columns()
+                .iter()
+                .zip(&levels)
+                .map(|(c, level)| {
+                    let field = c.field().to_arrow(*level);
+                    (field.name.clone(), field)
+                })
+                .collect(),
+        );
+
+        let height = self.height();
+        self.align_chunks_par();
+        let n_chunks = self.first_col_n_chunks();
+        let columns = self
+            .take_columns()
+            .into_iter()
+            .map(|c| c.take_materialized_series())
+            .collect::<Vec<_>>();
+
+        let chunk_schema = schema.clone();
+        let chunks: ChunkStream = if columns.is_empty() {
+            let chunk = RecordBatch::try_new(height, chunk_schema, vec![]);
+            Box::new(std::iter::once(chunk))
+        } else {
+            Box::new((0..n_chunks).map(move |i| {
+                let arrays = columns
+                    .iter()
+                    .zip(&levels)
+                    .map(|(s, level)| s.to_arrow(i, *level))
+                    .collect::<Vec<_>>();
+                RecordBatch::try_new(arrays[0].len(), chunk_schema.clone(), arrays)
+            }))
+        };
+        Ok((schema, chunks))
+    }
+}
+
+impl FromDataFrame for DataFrame {
+    fn from_chunks(schema: ArrowSchemaRef, chunks: ChunkStream) -> PolarsResult<Self> {
+        let mut df = DataFrame::empty_with_arrow_schema(&schema);
+        for chunk in chunks {
+            df.append_record_batch(chunk?)?;
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::interchange::convert;
+
+    use super::*;
+
+    /// A consumer that only understands the oldest arrow types.
+    struct OldestTypes(Vec<RecordBatch>);
+
+    impl FromDataFrame for OldestTypes {
+        fn requested_schema(schema: &ArrowSchema) -> Option<ArrowSchema> {
+            let fields = schema.iter_values().map(|field| {
+                let dtype = match field.dtype() {
+                    ArrowDataType::Utf8View => ArrowDataType::LargeUtf8,
+                    dtype => dtype.clone(),
+                };
+                let field = ArrowField::new(field.name.clone(), dtype, field.is_nullable);
+                (field.name.clone(), field)
+            });
+            Some(fields.collect())
+        }
+
+        fn from_chunks(_schema: ArrowSchemaRef, chunks: ChunkStream) -> PolarsResult<Self> {
+            chunks.collect::<PolarsResult<Vec<_>>>().map(Self)
+        }
+    }
+
+    #[test]
+    fn test_interchange_roundtrip() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [Some(1i32), None, Some(3)],
+            "b" => ["x", "y", "z"],
+        ]?;
+        df.vstack_mut(&df.clone())?;
+
+        let batches: Vec<RecordBatch> = convert(df.clone())?;
+        assert_eq!(batches.len(), 2);
+        let out: DataFrame = convert(batches)?;
+        assert!(out.equals_missing(&df));
+
+        let OldestTypes(batches) = convert(df.clone())?;
+        assert_eq!(batches[0].arrays()[1].dtype(), &ArrowDataType::LargeUtf8);
+        let out: DataFrame = convert(batches)?;
+        assert!(out.equals_missing(&df));
+        Ok(())
+    }
+}
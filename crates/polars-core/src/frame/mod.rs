@@ -3,6 +3,7 @@
 use std::sync::OnceLock;
 use std::{mem, ops};
 
+use arrow::compute::aggregate::{MemoryProfile, MemoryProfiler};
 use arrow::datatypes::ArrowSchemaRef;
 use polars_row::ArrayRef;
 use polars_schema::schema::ensure_matching_schema_names;
@@ -12,6 +13,7 @@ use rayon::prelude::*;
 use crate::chunked_array::flags::StatisticsFlags;
 #[cfg(feature = "algorithm_group_by")]
 use crate::chunked_array::ops::unique::is_unique_helper;
+use crate::config::{RechunkPolicy, rechunk_policy};
 use crate::prelude::*;
 #[cfg(feature = "row_hash")]
 use crate::utils::split_df;
@@ -20,6 +22,10 @@ use crate::{HEAD_DEFAULT_LENGTH, TAIL_DEFAULT_LENGTH};
 
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
+mod arrow_c_data;
+pub use arrow_c_data::ArrowBufferMode;
+#[cfg(feature = "arrow_rs")]
+mod arrow_rs;
 pub mod builder;
 mod chunks;
 pub use chunks::chunk_df_for_writing;
@@ -29,6 +35,7 @@ mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
 pub(crate) mod horizontal;
+mod interchange;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
@@ -44,7 +51,10 @@ use strum_macros::IntoStaticStr;
 use crate::POOL;
 #[cfg(feature = "row_hash")]
 use crate::hashing::_df_rows_to_hashes_threaded_vertical;
-use crate::prelude::sort::{argsort_multiple_row_fmt, prepare_arg_sort};
+use crate::prelude::sort::{
+    _broadcast_bools, argsort_multiple_radix, argsort_multiple_row_fmt, prepare_arg_sort,
+    use_radix_arg_sort,
+};
 use crate::series::IsSorted;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash, IntoStaticStr)]
@@ -210,11 +220,55 @@ impl DataFrame {
     /// However, this function will yield a smaller number. This is because this function returns
     /// the visible size of the buffer, not its total capacity.
     ///
-    /// FFI buffers are included in this estimation.
+    /// FFI buffers are included in this estimation. See [`DataFrame::memory_profile`] for the
+    /// memory held by every column, counting shared buffers once.
     pub fn estimated_size(&self) -> usize {
         self.columns.iter().map(Column::estimated_size).sum()
     }
 
+    /// The heap memory held by every column of the `DataFrame`, to find what takes up memory.
+    ///
+    /// Returns a row per column with its `column` name and `dtype` and the bytes held by its
+    /// `values`, `validity` bitmaps, `offsets`, and `dictionary` (the values of dictionary
+    /// arrays and categorical mappings), which sum up to its `total`.
+    ///
+    /// Unlike [`DataFrame::estimated_size`], the whole allocation underlying a sliced buffer
+    /// is counted, and buffers shared between columns (or within one) are only attributed to
+    /// the first column holding them. The bytes of a column that were already attributed are
+    /// reported as `shared`, so the `total` of all columns sums up to the memory held by the
+    /// `DataFrame`.
+    pub fn memory_profile(&self) -> PolarsResult<DataFrame> {
+        let mut profiler = MemoryProfiler::new();
+        let profiles = self
+            .columns
+            .iter()
+            .map(|c| c.memory_profile(&mut profiler))
+            .collect::<Vec<_>>();
+
+        let bytes = |name: &str, f: fn(&MemoryProfile) -> usize| {
+            let values = profiles.iter().map(|p| f(p) as u64).collect::<Vec<_>>();
+            UInt64Chunked::from_vec(name.into(), values).into_column()
+        };
+        DataFrame::new(vec![
+            StringChunked::from_iter_values(
+                "column".into(),
+                self.columns.iter().map(|c| c.name().as_str()),
+            )
+            .into_column(),
+            StringChunked::from_iter_values(
+                "dtype".into(),
+                self.columns.iter().map(|c| c.dtype().to_string()),
+            )
+            .into_column(),
+            bytes("values", |p| p.values),
+            bytes("validity", |p| p.validity),
+            bytes("offsets", |p| p.offsets),
+            bytes("dictionary", |p| p.dictionary),
+            bytes("shared", |p| p.shared),
+            bytes("total", MemoryProfile::total),
+        ])
+    }
+
     // Reduce monomorphization.
     fn try_apply_columns(
         &self,
@@ -576,6 +630,61 @@ impl DataFrame {
         self
     }
 
+    /// Aggregate all the chunks in the DataFrame to a single chunk in parallel, ahead of an
+    /// operation that benefits from contiguous data, if the [`RechunkPolicy`] allows it.
+    /// Otherwise, the chunks are only aligned.
+    pub fn rechunk_by_policy_par(&mut self) -> &mut Self {
+        let rechunk = match rechunk_policy() {
+            RechunkPolicy::Auto | RechunkPolicy::AlwaysBeforeJoin => true,
+            RechunkPolicy::Never => false,
+            RechunkPolicy::Threshold(n_chunks) => self.max_n_chunks() > n_chunks,
+        };
+        if rechunk {
+            self.as_single_chunk_par()
+        } else {
+            self.align_chunks_par()
+        }
+    }
+
+    /// Rechunk into evenly sized chunks of at most `chunk_size` rows, e.g. to balance the
+    /// work of parallel operations after a filter left chunks of skewed sizes.
+    ///
+    /// The columns are first aggregated to a single chunk, of which the new chunks are slices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("a" => (0..10).collect::<Vec<i32>>())?;
+    /// df.rechunk_to(4)?;
+    /// let lengths = df.column("a")?.as_materialized_series().chunk_lengths().collect::<Vec<_>>();
+    /// assert_eq!(lengths, [4, 3, 3]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn rechunk_to(&mut self, chunk_size: usize) -> PolarsResult<&mut Self> {
+        polars_ensure!(chunk_size > 0, InvalidOperation: "`chunk_size` must be positive");
+        self.as_single_chunk_par();
+
+        let height = self.height();
+        let n_chunks = height.div_ceil(chunk_size);
+        if n_chunks > 1 {
+            let (length, remainder) = (height / n_chunks, height % n_chunks);
+            let mut offset = 0;
+            let mut chunks = (0..n_chunks).map(|i| {
+                let length = length + usize::from(i < remainder);
+                let chunk = self.slice(offset as i64, length);
+                offset += length;
+                chunk
+            });
+            let mut out = chunks.next().unwrap();
+            for chunk in chunks {
+                out.vstack_mut_owned_unchecked(chunk);
+            }
+            *self = out;
+        }
+        Ok(self)
+    }
+
     /// Rechunks all columns to only have a single chunk.
     pub fn rechunk_mut(&mut self) {
         // SAFETY: We never adjust the length or names of the columns.
@@ -2156,6 +2265,7 @@ impl DataFrame {
                     multithreaded: sort_options.multithreaded,
                     maintain_order: sort_options.maintain_order,
                     limit: sort_options.limit,
+                    nan_position: NanPosition::Largest,
                 };
                 // fast path for a frame with a single series
                 // no need to compute the sort indices and then take by these indices
@@ -2170,9 +2280,24 @@ impl DataFrame {
                 s.arg_sort(options)
             },
             _ => {
-                if sort_options.nulls_last.iter().all(|&x| x)
-                    || has_nested
-                    || std::env::var("POLARS_ROW_FMT_SORT").is_ok()
+                _broadcast_bools(by_column.len(), &mut sort_options.descending);
+                _broadcast_bools(by_column.len(), &mut sort_options.nulls_last);
+                let force_row_fmt = std::env::var("POLARS_ROW_FMT_SORT").is_ok();
+                // Integer and dictionary keys that fit in 64 bits together are radix sorted.
+                let radix = if !force_row_fmt
+                    && use_radix_arg_sort(df.height(), sort_options.multithreaded)
+                {
+                    argsort_multiple_radix(
+                        &by_column,
+                        &sort_options.descending,
+                        &sort_options.nulls_last,
+                    )
+                } else {
+                    None
+                };
+                if let Some(idx) = radix {
+                    idx
+                } else if sort_options.nulls_last.iter().all(|&x| x) || has_nested || force_row_fmt
                 {
                     argsort_multiple_row_fmt(
                         &by_column,
@@ -3606,4 +3731,21 @@ mod test {
         assert_eq!(df.get_column_names(), &["a", "b", "c"]);
         Ok(())
     }
+
+    #[test]
+    fn test_memory_profile() -> PolarsResult<()> {
+        let a = Column::new("a".into(), [Some(1i64), None, Some(3)]);
+        let b = a.slice(1, 2).with_name("b".into());
+        let df = DataFrame::new(vec![a, b])?;
+
+        let profile = df.memory_profile()?;
+        let bytes = |name: &str| -> PolarsResult<Vec<u64>> {
+            Ok(profile.column(name)?.u64()?.into_no_null_iter().collect())
+        };
+        assert_eq!(bytes("values")?, [24, 0]);
+        assert_eq!(bytes("validity")?, [1, 0]);
+        assert_eq!(bytes("shared")?, [0, 25]);
+        assert_eq!(bytes("total")?, [25, 0]);
+        Ok(())
+    }
 }
@@ -13,6 +13,7 @@ use polars_utils::format_pl_smallstr;
 #[cfg(feature = "object")]
 use polars_utils::total_ord::TotalHash;
 use rayon::prelude::*;
+pub use transpose::TransposeDtype;
 
 use crate::POOL;
 use crate::prelude::*;
@@ -4,7 +4,53 @@ use either::Either;
 
 use super::*;
 
+/// The dtype of the columns of a transposed [`DataFrame`], passed to
+/// [`DataFrame::transpose_with_dtype`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum TransposeDtype {
+    /// The supertype of all columns, e.g. `String` for a frame with integer and string columns.
+    #[default]
+    Supertype,
+    /// Strictly cast every column to this dtype first.
+    Cast(DataType),
+    /// Keep the dtype of every column by transposing into struct columns with one field per
+    /// distinct input dtype, named after the dtype. In the row of a column, only the field of
+    /// its dtype is set.
+    #[cfg(feature = "dtype-struct")]
+    Struct,
+}
+
+/// Check that categorical columns share their categories, as transposing mixes their values.
+fn check_rev_maps(columns: &[Column], dtype: &DataType) -> PolarsResult<()> {
+    #[cfg(feature = "dtype-categorical")]
+    if let DataType::Categorical(_, _) | DataType::Enum(_, _) = dtype {
+        let mut valid = true;
+        let mut rev_map: Option<&Arc<RevMapping>> = None;
+        for s in columns.iter() {
+            if let DataType::Categorical(Some(col_rev_map), _)
+            | DataType::Enum(Some(col_rev_map), _) = &s.dtype()
+            {
+                match rev_map {
+                    Some(rev_map) => valid = valid && rev_map.same_src(col_rev_map),
+                    None => {
+                        rev_map = Some(col_rev_map);
+                    },
+                }
+            }
+        }
+        polars_ensure!(valid, string_cache_mismatch);
+    }
+    #[cfg(not(feature = "dtype-categorical"))]
+    let _ = (columns, dtype);
+    Ok(())
+}
+
 impl DataFrame {
+    fn header_column(&self, name: PlSmallStr) -> Column {
+        StringChunked::from_iter_values(name, self.get_column_names_owned().into_iter())
+            .into_column()
+    }
+
     pub(crate) fn transpose_from_dtype(
         &self,
         dtype: &DataType,
@@ -18,13 +64,7 @@ impl DataFrame {
             None => Vec::<Column>::with_capacity(new_width),
             Some(name) => {
                 let mut tmp = Vec::<Column>::with_capacity(new_width + 1);
-                tmp.push(
-                    StringChunked::from_iter_values(
-                        name,
-                        self.get_column_names_owned().into_iter(),
-                    )
-                    .into_column(),
-                );
+                tmp.push(self.header_column(name));
                 tmp
             },
         };
@@ -87,10 +127,80 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(new_height, cols_t) })
     }
 
+    /// Transpose the columns of every dtype separately, and combine them into struct columns
+    /// with a field per dtype.
+    #[cfg(feature = "dtype-struct")]
+    fn transpose_to_structs(
+        &self,
+        keep_names_as: Option<PlSmallStr>,
+        names_out: &[PlSmallStr],
+    ) -> PolarsResult<DataFrame> {
+        let mut dtypes = Vec::<DataType>::new();
+        for c in self.get_columns() {
+            if !dtypes.contains(c.dtype()) {
+                dtypes.push(c.dtype().clone());
+            }
+        }
+
+        let mut fields = (0..self.height())
+            .map(|_| Vec::<Column>::with_capacity(dtypes.len()))
+            .collect::<Vec<_>>();
+        for dtype in &dtypes {
+            let columns = self
+                .get_columns()
+                .iter()
+                .filter(|c| c.dtype() == dtype)
+                .cloned()
+                .collect::<Vec<_>>();
+            check_rev_maps(&columns, dtype)?;
+            let group = unsafe { DataFrame::new_no_checks(self.height(), columns) };
+
+            // Spread the transposed rows of this dtype over all rows, with nulls in the rows
+            // of the other dtypes.
+            let mut position: IdxSize = 0;
+            let idx = IdxCa::from_iter_options(
+                PlSmallStr::EMPTY,
+                self.get_columns().iter().map(|c| {
+                    (c.dtype() == dtype).then(|| {
+                        position += 1;
+                        position - 1
+                    })
+                }),
+            );
+            let transposed = group
+                .transpose_from_dtype(dtype, None, names_out)?
+                .take(&idx)?;
+            let field_name = format_pl_smallstr!("{dtype}");
+            for (fields, c) in fields.iter_mut().zip(transposed.take_columns()) {
+                fields.push(c.with_name(field_name.clone()));
+            }
+        }
+
+        let mut cols_t = Vec::<Column>::with_capacity(self.height() + 1);
+        cols_t.extend(keep_names_as.map(|name| self.header_column(name)));
+        for (fields, name) in fields.iter().zip(names_out) {
+            cols_t.push(
+                StructChunked::from_columns(name.clone(), self.width(), fields)?.into_column(),
+            );
+        }
+        Ok(unsafe { DataFrame::new_no_checks(self.width(), cols_t) })
+    }
+
     pub fn transpose(
         &mut self,
         keep_names_as: Option<&str>,
         new_col_names: Option<Either<String, Vec<String>>>,
+    ) -> PolarsResult<DataFrame> {
+        self.transpose_with_dtype(keep_names_as, new_col_names, TransposeDtype::Supertype)
+    }
+
+    /// Transpose a DataFrame, choosing the dtype of the output columns with `dtype` instead of
+    /// always using the supertype of the columns.
+    pub fn transpose_with_dtype(
+        &mut self,
+        keep_names_as: Option<&str>,
+        new_col_names: Option<Either<String, Vec<String>>>,
+        dtype: TransposeDtype,
     ) -> PolarsResult<DataFrame> {
         let new_col_names = match new_col_names {
             None => None,
@@ -100,13 +210,14 @@ impl DataFrame {
             )),
         };
 
-        self.transpose_impl(keep_names_as, new_col_names)
+        self.transpose_impl(keep_names_as, new_col_names, dtype)
     }
     /// Transpose a DataFrame. This is a very expensive operation.
     pub fn transpose_impl(
         &mut self,
         keep_names_as: Option<&str>,
         new_col_names: Option<Either<PlSmallStr, Vec<PlSmallStr>>>,
+        dtype: TransposeDtype,
     ) -> PolarsResult<DataFrame> {
         // We must iterate columns as [`AnyValue`], so we must be contiguous.
         self.as_single_chunk_par();
@@ -141,28 +252,24 @@ impl DataFrame {
             df.height() != 0 && df.width() != 0,
             NoData: "unable to transpose an empty DataFrame"
         );
-        let dtype = df.get_supertype().unwrap()?;
-        match dtype {
-            #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_, _) | DataType::Enum(_, _) => {
-                let mut valid = true;
-                let mut rev_map: Option<&Arc<RevMapping>> = None;
-                for s in self.columns.iter() {
-                    if let DataType::Categorical(Some(col_rev_map), _)
-                    | DataType::Enum(Some(col_rev_map), _) = &s.dtype()
-                    {
-                        match rev_map {
-                            Some(rev_map) => valid = valid && rev_map.same_src(col_rev_map),
-                            None => {
-                                rev_map = Some(col_rev_map);
-                            },
-                        }
-                    }
-                }
-                polars_ensure!(valid, string_cache_mismatch);
+        let dtype = match dtype {
+            TransposeDtype::Supertype => df.get_supertype().unwrap()?,
+            TransposeDtype::Cast(dtype) => {
+                let columns = df
+                    .get_columns()
+                    .iter()
+                    .map(|c| c.strict_cast(&dtype))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                df = Cow::Owned(unsafe { DataFrame::new_no_checks(df.height(), columns) });
+                dtype
             },
-            _ => {},
-        }
+            #[cfg(feature = "dtype-struct")]
+            TransposeDtype::Struct => {
+                return df
+                    .transpose_to_structs(keep_names_as.map(PlSmallStr::from_str), &names_out);
+            },
+        };
+        check_rev_maps(df.get_columns(), &dtype)?;
         df.transpose_from_dtype(&dtype, keep_names_as.map(PlSmallStr::from_str), &names_out)
     }
 }
@@ -331,4 +438,47 @@ mod test {
         assert!(out.equals_missing(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_transpose_with_dtype() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1, 2],
+            "b" => [1.5, 2.5],
+        ]?;
+        let out = df.transpose_with_dtype(None, None, TransposeDtype::Cast(DataType::Int64))?;
+        let expected = df![
+            "column_0" => [1i64, 1],
+            "column_1" => [2i64, 2],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let mut df = df![
+            "a" => [1, 2],
+            "b" => ["x", "y"],
+        ]?;
+        assert!(
+            df.transpose_with_dtype(None, None, TransposeDtype::Cast(DataType::Int32))
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_transpose_to_structs() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1, 2],
+            "b" => ["x", "y"],
+        ]?;
+        let out = df.transpose_with_dtype(Some("column"), None, TransposeDtype::Struct)?;
+        assert_eq!(out.shape(), (2, 3));
+        assert_eq!(out.column("column")?.str()?.get(1), Some("b"));
+
+        let row = out.column("column_1")?.struct_()?.fields_as_series();
+        assert_eq!(row[0].name().as_str(), "i32");
+        assert_eq!(row[0].i32()?.iter().collect::<Vec<_>>(), [Some(2), None]);
+        assert_eq!(row[1].name().as_str(), "str");
+        assert_eq!(row[1].str()?.iter().collect::<Vec<_>>(), [None, Some("y")]);
+        Ok(())
+    }
 }
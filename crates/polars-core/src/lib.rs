@@ -10,6 +10,7 @@ pub mod chunked_array;
 pub mod config;
 pub mod datatypes;
 pub mod error;
+pub mod extension;
 pub mod fmt;
 pub mod frame;
 pub mod functions;
@@ -28,7 +29,6 @@ pub mod testing;
 mod tests;
 
 use std::sync::{LazyLock, Mutex};
-use std::time::{SystemTime, UNIX_EPOCH};
 
 pub use datatypes::SchemaExtPl;
 pub use hashing::IdBuildHasher;
@@ -37,17 +37,24 @@ use rayon::{ThreadPool, ThreadPoolBuilder};
 #[cfg(feature = "dtype-categorical")]
 pub use crate::chunked_array::logical::categorical::string_cache::*;
 
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
 pub static PROCESS_ID: LazyLock<u128> = LazyLock::new(|| {
+    use std::time::{SystemTime, UNIX_EPOCH};
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
         .as_nanos()
 });
 
+// there is no system clock on wasm32-unknown-unknown
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+pub static PROCESS_ID: LazyLock<u128> = LazyLock::new(polars_utils::wasm::process_id);
+
 // this is re-exported in utils for polars child crates
 #[cfg(not(target_family = "wasm"))] // only use this on non wasm targets
 pub static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
     let thread_name = std::env::var("POLARS_THREAD_NAME").unwrap_or_else(|_| "polars".to_string());
+    let affinity = config::thread_affinity();
     ThreadPoolBuilder::new()
         .num_threads(
             std::env::var("POLARS_MAX_THREADS")
@@ -59,6 +66,11 @@ pub static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
                 }),
         )
         .thread_name(move |i| format!("{}-{}", thread_name, i))
+        .start_handler(move |i| {
+            affinity.place_current_thread(i);
+            #[cfg(target_os = "linux")]
+            register_pool_thread();
+        })
         .build()
         .expect("could not spawn threads")
 });
@@ -75,6 +87,42 @@ pub static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
 #[cfg(all(not(target_os = "emscripten"), target_family = "wasm"))] // use this on other wasm targets
 pub static POOL: LazyLock<polars_utils::wasm::Pool> = LazyLock::new(|| polars_utils::wasm::Pool);
 
+/// The `schedstat` files of the threads of [`POOL`], see [`thread_pool_cpu_time`].
+#[cfg(target_os = "linux")]
+static POOL_SCHEDSTATS: Mutex<Vec<std::path::PathBuf>> = Mutex::new(Vec::new());
+
+#[cfg(target_os = "linux")]
+fn register_pool_thread() {
+    // `/proc/thread-self` links to `<pid>/task/<tid>`.
+    if let Ok(task) = std::fs::read_link("/proc/thread-self") {
+        let path = std::path::Path::new("/proc").join(task).join("schedstat");
+        POOL_SCHEDSTATS.lock().unwrap().push(path);
+    }
+}
+
+/// The CPU time the threads of [`POOL`] have used since they were started. This is only known on
+/// Linux.
+pub fn thread_pool_cpu_time() -> Option<std::time::Duration> {
+    #[cfg(target_os = "linux")]
+    {
+        let paths = POOL_SCHEDSTATS.lock().unwrap();
+        if paths.is_empty() {
+            return None;
+        }
+        let mut nanos = 0;
+        for path in paths.iter() {
+            // The first field is the time spent on the CPU in nanoseconds.
+            let stat = std::fs::read_to_string(path).ok()?;
+            nanos += stat.split_whitespace().next()?.parse::<u64>().ok()?;
+        }
+        Some(std::time::Duration::from_nanos(nanos))
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
 // utility for the tests to ensure a single thread can execute
 pub static SINGLE_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
 
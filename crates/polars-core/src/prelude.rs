@@ -6,7 +6,9 @@ pub(crate) use arrow::array::*;
 pub use arrow::datatypes::{ArrowSchema, Field as ArrowField};
 pub use arrow::legacy::prelude::*;
 pub(crate) use arrow::trusted_len::TrustedLen;
-pub use polars_compute::rolling::{QuantileMethod, RollingFnParams, RollingVarParams};
+pub use polars_compute::rolling::{
+    QuantileMethod, RollingFnParams, RollingRankMethod, RollingVarParams,
+};
 pub use polars_utils::aliases::*;
 pub use polars_utils::index::{ChunkId, IdxSize, NullableIdxSize};
 pub use polars_utils::pl_str::PlSmallStr;
@@ -14,6 +16,8 @@ pub(crate) use polars_utils::total_ord::{TotalEq, TotalOrd};
 
 pub(crate) use crate::chunked_array::ChunkLenIter;
 pub use crate::chunked_array::ChunkedArray;
+#[cfg(feature = "random")]
+pub use crate::chunked_array::ReservoirSampler;
 #[cfg(feature = "dtype-struct")]
 pub use crate::chunked_array::StructChunked;
 pub use crate::chunked_array::arithmetic::ArithmeticChunked;
@@ -41,7 +45,7 @@ pub use crate::datatypes::string_cache::StringCacheHolder;
 pub use crate::datatypes::{ArrayCollectIterExt, *};
 pub use crate::error::signals::try_raise_keyboard_interrupt;
 pub use crate::error::{
-    PolarsError, PolarsResult, polars_bail, polars_ensure, polars_err, polars_warn,
+    ErrorContext, PolarsError, PolarsResult, polars_bail, polars_ensure, polars_err, polars_warn,
 };
 pub use crate::frame::column::{Column, IntoColumn};
 pub use crate::frame::explode::UnpivotArgsIR;
@@ -49,6 +53,8 @@ pub use crate::frame::explode::UnpivotArgsIR;
 pub(crate) use crate::frame::group_by::aggregations::*;
 #[cfg(feature = "algorithm_group_by")]
 pub use crate::frame::group_by::*;
+#[cfg(any(feature = "rows", feature = "object"))]
+pub use crate::frame::row::TransposeDtype;
 pub use crate::frame::{DataFrame, UniqueKeepStrategy};
 pub use crate::hashing::VecHash;
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
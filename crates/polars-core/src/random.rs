@@ -5,7 +5,8 @@ use rand::prelude::*;
 static POLARS_GLOBAL_RNG_STATE: LazyLock<Mutex<SmallRng>> =
     LazyLock::new(|| Mutex::new(SmallRng::from_entropy()));
 
-pub(crate) fn get_global_random_u64() -> u64 {
+/// The next number of the global random generator, which [`set_global_random_seed`] seeds.
+pub fn get_global_random_u64() -> u64 {
     POLARS_GLOBAL_RNG_STATE.lock().unwrap().next_u64()
 }
 
@@ -24,6 +24,12 @@ pub trait SchemaExt {
 
     /// Select fields using a bitmap.
     fn project_select(&self, select: &Bitmap) -> Self;
+
+    /// A [`SchemaContract`] that requires exactly these columns and data types.
+    fn strict_contract(self) -> SchemaContract;
+
+    /// A [`SchemaContract`] that drops other columns and casts to these data types.
+    fn coercing_contract(self) -> SchemaContract;
 }
 
 impl SchemaExt for Schema {
@@ -101,6 +107,79 @@ impl SchemaExt for Schema {
             .map(|((n, dt), _)| (n.clone(), dt.clone()))
             .collect()
     }
+
+    fn strict_contract(self) -> SchemaContract {
+        SchemaContract::new(self, ContractPolicy::Strict)
+    }
+
+    fn coercing_contract(self) -> SchemaContract {
+        SchemaContract::new(self, ContractPolicy::Coerce)
+    }
+}
+
+/// How a [`SchemaContract`] treats data that doesn't match it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ContractPolicy {
+    /// Raise on columns that aren't in the contract and on differing data types.
+    #[default]
+    Strict,
+    /// Drop columns that aren't in the contract and cast to the declared data types. Values that
+    /// can't be cast raise.
+    Coerce,
+}
+
+/// A declared schema that the data crossing a boundary, such as a scan or a sink, must satisfy.
+///
+/// Columns of the contract that are missing always raise.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaContract {
+    pub schema: SchemaRef,
+    pub policy: ContractPolicy,
+}
+
+impl SchemaContract {
+    pub fn new(schema: impl Into<SchemaRef>, policy: ContractPolicy) -> Self {
+        Self {
+            schema: schema.into(),
+            policy,
+        }
+    }
+
+    /// Check `input` against the contract, returning the columns of the contract in order,
+    /// with the data type to cast to if it differs.
+    ///
+    /// The error lists all violations of the contract.
+    pub fn resolve<'a>(
+        &'a self,
+        input: &Schema,
+    ) -> PolarsResult<Vec<(&'a PlSmallStr, Option<&'a DataType>)>> {
+        let mut violations = Vec::new();
+        let mut columns = Vec::with_capacity(self.schema.len());
+        for (name, dtype) in self.schema.iter() {
+            match input.get(name) {
+                None => violations.push(format!("missing column {name:?}")),
+                Some(input_dtype) if input_dtype == dtype => columns.push((name, None)),
+                Some(input_dtype) => match self.policy {
+                    ContractPolicy::Strict => violations.push(format!(
+                        "column {name:?} has data type {input_dtype}, expected {dtype}"
+                    )),
+                    ContractPolicy::Coerce => columns.push((name, Some(dtype))),
+                },
+            }
+        }
+        if self.policy == ContractPolicy::Strict {
+            for name in input.iter_names() {
+                if !self.schema.contains(name) {
+                    violations.push(format!("unexpected column {name:?}"));
+                }
+            }
+        }
+        polars_ensure!(
+            violations.is_empty(),
+            SchemaMismatch: "schema contract violated: {}", violations.join("; ")
+        );
+        Ok(columns)
+    }
 }
 
 pub trait SchemaNamesAndDtypes {
@@ -184,3 +263,36 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_schema_contract() {
+        let contract = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+        ]);
+        let input = Schema::from_iter([
+            Field::new("b".into(), DataType::String),
+            Field::new("a".into(), DataType::Int32),
+            Field::new("c".into(), DataType::Boolean),
+        ]);
+
+        let strict = contract.clone().strict_contract();
+        let err = strict.resolve(&input).unwrap_err().to_string();
+        assert!(
+            err.contains(r#"column "a" has data type i32, expected i64; unexpected column "c""#)
+        );
+
+        let coercing = contract.coercing_contract();
+        let columns = coercing.resolve(&input).unwrap();
+        let (a, b) = (PlSmallStr::from("a"), PlSmallStr::from("b"));
+        assert_eq!(columns, &[(&a, Some(&DataType::Int64)), (&b, None)]);
+
+        let input = Schema::from_iter([Field::new("a".into(), DataType::Int64)]);
+        let err = coercing.resolve(&input).unwrap_err().to_string();
+        assert!(err.contains(r#"missing column "b""#));
+    }
+}
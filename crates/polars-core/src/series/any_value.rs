@@ -3,6 +3,7 @@ use std::fmt::Write;
 use arrow::bitmap::MutableBitmap;
 
 use crate::chunked_array::builder::{AnonymousOwnedListBuilder, get_list_builder};
+use crate::extension::ExtensionChunked;
 use crate::prelude::*;
 use crate::utils::any_values_to_supertype;
 
@@ -139,6 +140,20 @@ impl Series {
             DataType::Struct(fields) => any_values_to_struct(values, fields, strict)?,
             #[cfg(feature = "object")]
             DataType::Object(_) => any_values_to_object(values)?,
+            DataType::Uuid => {
+                let storage = Series::from_any_values_and_dtype(
+                    PlSmallStr::EMPTY,
+                    values,
+                    &DataType::Binary,
+                    strict,
+                )?;
+                ExtensionChunked::try_new_uuid(storage.binary()?.clone())?.into_series()
+            },
+            DataType::Extension(name, metadata, storage) => {
+                let storage =
+                    Series::from_any_values_and_dtype(PlSmallStr::EMPTY, values, storage, strict)?;
+                ExtensionChunked::try_new(name.clone(), metadata.clone(), storage)?.into_series()
+            },
             DataType::Null => Series::new_null(PlSmallStr::EMPTY, values.len()),
             dt => {
                 polars_bail!(
@@ -21,6 +21,8 @@ use crate::chunked_array::object::registry::get_object_builder;
 use crate::chunked_array::temporal::parse_fixed_offset;
 #[cfg(feature = "timezones")]
 use crate::chunked_array::temporal::validate_time_zone;
+use crate::extension;
+use crate::extension::ExtensionChunked;
 use crate::prelude::*;
 
 impl Series {
@@ -129,6 +131,16 @@ impl Series {
                     unsafe { get_object_builder(name, 0).from_chunks(chunks) }
                 }
             },
+            Uuid => ExtensionChunked::new_uuid_unchecked(
+                BinaryChunked::from_chunks(name, chunks).into_series(),
+            )
+            .into_series(),
+            Extension(ext_name, metadata, storage) => ExtensionChunked::new_unchecked(
+                ext_name.clone(),
+                metadata.clone(),
+                Series::from_chunks_and_dtype_unchecked(name, chunks, storage),
+            )
+            .into_series(),
             Null => new_null(name, &chunks),
             Unknown(_) => {
                 panic!("dtype is unknown; consider supplying data-types for all operations")
@@ -461,6 +473,24 @@ impl Series {
                 };
                 Ok(s)
             },
+            ArrowDataType::Extension(ext) if ext.is_uuid() => {
+                let chunks = extension::uuid_to_storage_chunks(&chunks)?;
+                let storage = BinaryChunked::from_chunks(name, chunks).into_series();
+                Ok(ExtensionChunked::new_uuid_unchecked(storage).into_series())
+            },
+            ArrowDataType::Extension(ext) if ext.name != EXTENSION_NAME => {
+                let chunks = extension::to_storage_chunks(chunks, ext)?;
+                let storage =
+                    Self::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)?;
+                Ok(
+                    ExtensionChunked::new_unchecked(
+                        ext.name.clone(),
+                        ext.metadata.clone(),
+                        storage,
+                    )
+                    .into_series(),
+                )
+            },
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(_) => {
                 let (chunks, dtype) = to_physical_and_dtype(chunks, md);
@@ -522,6 +552,10 @@ unsafe fn to_physical_and_dtype(
             let chunks = cast_chunks(&arrays, &DataType::Binary, CastOptions::NonStrict).unwrap();
             (chunks, DataType::Binary)
         },
+        ArrowDataType::Float16 => {
+            let chunks = cast_chunks(&arrays, &DataType::Float32, CastOptions::NonStrict).unwrap();
+            (chunks, DataType::Float32)
+        },
         #[allow(unused_variables)]
         dt @ ArrowDataType::Dictionary(_, _, _) => {
             feature_gated!("dtype-categorical", {
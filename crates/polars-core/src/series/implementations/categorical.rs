@@ -24,6 +24,16 @@ impl SeriesWrap<CategoricalChunked> {
         out
     }
 
+    /// Aggregate the strings, for orderings that do not follow the physical values.
+    #[cfg(feature = "algorithm_group_by")]
+    fn agg_lexical<F>(&self, groups: &GroupsType, agg: F) -> Series
+    where
+        F: Fn(&Series, &GroupsType) -> Series,
+    {
+        let s = self.0.cast(&DataType::String).unwrap();
+        agg(&s, groups).cast(self.dtype()).unwrap()
+    }
+
     fn with_state<F>(&self, keep_fast_unique: bool, apply: F) -> CategoricalChunked
     where
         F: Fn(&UInt32Chunked) -> UInt32Chunked,
@@ -110,6 +120,26 @@ impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
         list.into_series()
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        if self.0.uses_lexical_ordering() {
+            return self.agg_lexical(groups, |s, groups| unsafe { s.agg_min(groups) });
+        }
+        let cats = self.0.physical().agg_min(groups);
+        self.finish_with_state(false, cats.u32().unwrap().clone())
+            .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        if self.0.uses_lexical_ordering() {
+            return self.agg_lexical(groups, |s, groups| unsafe { s.agg_max(groups) });
+        }
+        let cats = self.0.physical().agg_max(groups);
+        self.finish_with_state(false, cats.u32().unwrap().clone())
+            .into_series()
+    }
+
     #[cfg(feature = "algorithm_group_by")]
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
         #[cfg(feature = "performant")]
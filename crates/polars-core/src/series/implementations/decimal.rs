@@ -169,6 +169,22 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         self.agg_helper(|ca| ca.agg_list(groups))
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.0
+            .cast(&DataType::Float64)
+            .unwrap()
+            .agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.0
+            .cast(&DataType::Float64)
+            .unwrap()
+            .agg_var(groups, ddof)
+    }
+
     fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
         let rhs = rhs.decimal()?;
         ((&self.0) - rhs).map(|ca| ca.into_series())
@@ -185,6 +201,10 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         let rhs = rhs.decimal()?;
         ((&self.0) / rhs).map(|ca| ca.into_series())
     }
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal()?;
+        ((&self.0) % rhs).map(|ca| ca.into_series())
+    }
     #[cfg(feature = "algorithm_group_by")]
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
         self.0.group_tuples(multithreaded, sorted)
@@ -442,6 +462,16 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
         Ok(self.apply_scale(self.0.std_reduce(ddof)))
     }
 
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.0
+            .var(ddof)
+            .map(|v| v / (self.scale_factor() as f64).powi(2))
+    }
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        let v = SeriesTrait::var(self, ddof);
+        Ok(Scalar::new(DataType::Float64, v.into()))
+    }
+
     fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
         self.0
             .quantile_reduce(quantile, method)
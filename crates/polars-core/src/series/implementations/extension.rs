@@ -0,0 +1,274 @@
+use super::*;
+use crate::extension::ExtensionChunked;
+use crate::prelude::*;
+use crate::series::private::{PrivateSeries, PrivateSeriesNumeric};
+
+impl PrivateSeriesNumeric for ExtensionChunked {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        self.storage.0.bit_repr()
+    }
+}
+
+impl PrivateSeries for ExtensionChunked {
+    fn compute_len(&mut self) {
+        self.storage.compute_len()
+    }
+
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(Field::new(self.storage.name().clone(), self.dtype.clone()))
+    }
+
+    fn _dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.storage.get_flags()
+    }
+
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.storage._get_inner_mut()._set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.extension().unwrap();
+        self.storage
+            .equal_element(idx_self, idx_other, other.storage())
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.storage.0.into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.storage.0.into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.storage.vec_hash(random_state, buf)
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.storage.vec_hash_combine(build_hasher, hashes)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        polars_ensure!(
+            self.dtype == *other.dtype(),
+            opq = zip_with,
+            self.dtype,
+            other.dtype()
+        );
+        let other = other.extension()?.storage();
+        let storage = self.storage.0.zip_with_same_type(mask, other)?;
+        Ok(self.with_storage(storage))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.storage.group_tuples(multithreaded, sorted)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        self.storage.agg_list(groups)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.storage.arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for ExtensionChunked {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.storage.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.storage.chunk_lengths()
+    }
+
+    fn name(&self) -> &PlSmallStr {
+        self.storage.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.storage.chunks()
+    }
+
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.storage._get_inner_mut().chunks_mut()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.storage.shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.with_storage(self.storage.slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (l, r) = self.storage.split_at(offset);
+        (self.with_storage(l), self.with_storage(r))
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.dtype == *other.dtype(), append);
+        self.storage.append(other.extension()?.storage())?;
+        Ok(())
+    }
+
+    fn append_owned(&mut self, other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.dtype == *other.dtype(), append);
+        let other = other.extension()?.storage().clone();
+        self.storage.append_owned(other)?;
+        Ok(())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.dtype == *other.dtype(), extend);
+        self.storage.extend(other.extension()?.storage())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        Ok(self.with_storage(self.storage.filter(filter)?))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.with_storage(self.storage.take(indices)?))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.with_storage(self.storage.take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.with_storage(self.storage.take_slice(indices)?))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.with_storage(self.storage.take_slice_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.with_storage(self.storage.rechunk())
+    }
+
+    fn drop_nulls(&self) -> Series {
+        self.with_storage(self.storage.drop_nulls())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.with_storage(self.storage.new_from_index(index, length))
+    }
+
+    fn cast(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Uuid if self.dtype == DataType::Uuid => Ok(self.clone().into_series()),
+            _ if self.dtype == DataType::Uuid && dtype != &DataType::Binary => polars_bail!(
+                InvalidOperation: "cannot cast uuid to {}; use `uuid.to_string` to format UUIDs", dtype
+            ),
+            DataType::Extension(name, metadata, storage) => {
+                let storage = self.storage.cast_with_options(storage, options)?;
+                ExtensionChunked::try_new(name.clone(), metadata.clone(), storage)
+                    .map(|ca| ca.into_series())
+            },
+            _ => self.storage.cast_with_options(dtype, options),
+        }
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.storage.get_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        Ok(self.with_storage(self.storage.sort_with(options)?))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.storage.arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.storage.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.storage.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        Ok(self.with_storage(self.storage.unique()?))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.storage.n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.storage.arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.storage.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.storage.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.with_storage(self.storage.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.with_storage(self.storage.shift(periods))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.storage.as_phys_any()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
+
+unsafe impl IntoSeries for ExtensionChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(self))
+    }
+}
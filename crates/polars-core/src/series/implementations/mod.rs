@@ -14,6 +14,7 @@ mod datetime;
 mod decimal;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+mod extension;
 mod floats;
 mod list;
 pub(crate) mod null;
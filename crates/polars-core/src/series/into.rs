@@ -180,6 +180,9 @@ impl Series {
                     object_series_to_arrow_array(&s)
                 }
             },
+            DataType::Extension(_, _, _) | DataType::Uuid => {
+                self.extension().unwrap().to_arrow(chunk_idx, compat_level)
+            },
             DataType::String => {
                 if compat_level.0 >= 1 {
                     self.array_ref(chunk_idx).clone()
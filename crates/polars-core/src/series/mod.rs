@@ -31,7 +31,7 @@ use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
-use arrow::compute::aggregate::estimated_bytes_size;
+use arrow::compute::aggregate::{MemoryKind, MemoryProfile, MemoryProfiler, estimated_bytes_size};
 use arrow::offset::Offsets;
 pub use from::*;
 pub use iterator::{SeriesIter, SeriesPhysIter};
@@ -41,6 +41,7 @@ pub use series_trait::{IsSorted, *};
 
 use crate::POOL;
 use crate::chunked_array::cast::CastOptions;
+use crate::extension::ExtensionChunked;
 #[cfg(feature = "zip_with")]
 use crate::series::arithmetic::coerce_lhs_rhs;
 use crate::utils::{Wrap, handle_casting_failures, materialize_dyn_int};
@@ -381,6 +382,19 @@ impl Series {
     pub fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Self> {
         use DataType as D;
 
+        if let D::Extension(name, metadata, storage) = dtype {
+            if !matches!(self.dtype(), D::Extension(_, _, _)) {
+                let storage = self.cast_with_options(storage, options)?;
+                return ExtensionChunked::try_new(name.clone(), metadata.clone(), storage)
+                    .map(|ca| ca.into_series());
+            }
+        }
+        if let D::Uuid = dtype {
+            if !matches!(self.dtype(), D::Uuid) {
+                return self.cast_to_uuid(options);
+            }
+        }
+
         let do_clone = match dtype {
             D::Unknown(UnknownKind::Any) => true,
             D::Unknown(UnknownKind::Int(_)) if self.dtype().is_integer() => true,
@@ -576,11 +590,39 @@ impl Series {
                     .from_physical_unchecked(to.as_slice())
                     .map(|ca| ca.into_series())
             },
+            (D::Binary, D::Uuid) => {
+                Ok(ExtensionChunked::new_uuid_unchecked(self.clone()).into_series())
+            },
+            (_, D::Extension(name, metadata, storage)) => {
+                let storage = self.from_physical_unchecked(storage)?;
+                Ok(
+                    ExtensionChunked::new_unchecked(name.clone(), metadata.clone(), storage)
+                        .into_series(),
+                )
+            },
 
             _ => panic!("invalid from_physical({dtype:?}) for {:?}", self.dtype()),
         }
     }
 
+    /// Cast 16-byte [`DataType::Binary`] values to [`DataType::Uuid`].
+    ///
+    /// Values of another length raise in a strict cast and become null otherwise.
+    fn cast_to_uuid(&self, options: CastOptions) -> PolarsResult<Self> {
+        polars_ensure!(
+            matches!(self.dtype(), DataType::Binary | DataType::Null),
+            InvalidOperation: "cannot cast {} to uuid; use `str.to_uuid` to parse strings", self.dtype()
+        );
+        let s = self.cast_with_options(&DataType::Binary, options)?;
+        let ca = s.binary().unwrap();
+        let ca = if options.strict() {
+            ca.clone()
+        } else {
+            ca.apply(|opt_v| opt_v.filter(|v| v.len() == 16).map(Cow::Borrowed))
+        };
+        ExtensionChunked::try_new_uuid(ca).map(|ca| ca.into_series())
+    }
+
     /// Cast numerical types to f64, and keep floats as is.
     pub fn to_float(&self) -> PolarsResult<Series> {
         match self.dtype() {
@@ -750,6 +792,13 @@ impl Series {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
             },
+            Extension(_, _, _) | Uuid => Cow::Owned(
+                self.extension()
+                    .unwrap()
+                    .storage()
+                    .to_physical_repr()
+                    .into_owned(),
+            ),
             _ => Cow::Borrowed(self),
         }
     }
@@ -1019,6 +1068,41 @@ impl Series {
         size
     }
 
+    /// Attribute the heap memory held by the `Series` to a [`MemoryProfile`], counting the
+    /// buffers it shares with what `profiler` has seen before as shared.
+    ///
+    /// See [`DataFrame::memory_profile`].
+    pub fn memory_profile(&self, profiler: &mut MemoryProfiler) -> MemoryProfile {
+        let mut profile = MemoryProfile::default();
+        match self.dtype() {
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(Some(rv), _) | DataType::Enum(Some(rv), _) => match &**rv {
+                RevMapping::Local(arr, _) => profiler.add_dictionary(arr, &mut profile),
+                RevMapping::Global(map, arr, _) => {
+                    profiler.add_allocation(
+                        map as *const _ as usize,
+                        map.capacity() * size_of::<u32>() * 2,
+                        MemoryKind::Dictionary,
+                        &mut profile,
+                    );
+                    profiler.add_dictionary(arr, &mut profile);
+                },
+            },
+            #[cfg(feature = "object")]
+            DataType::Object(_) => {
+                // Like `estimated_size`, only the pointers to the python objects.
+                profile.values = self.estimated_size();
+                return profile;
+            },
+            _ => {},
+        }
+
+        for arr in self.chunks() {
+            profiler.add_array(&**arr, &mut profile);
+        }
+        profile
+    }
+
     /// Packs every element into a list.
     pub fn as_list(&self) -> ListChunked {
         let s = self.rechunk();
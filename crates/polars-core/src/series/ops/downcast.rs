@@ -1,4 +1,5 @@
 #![allow(unsafe_op_in_unsafe_fn)]
+use crate::extension::ExtensionChunked;
 use crate::prelude::*;
 use crate::series::implementations::null::NullChunked;
 
@@ -182,6 +183,11 @@ impl Series {
     pub fn try_null(&self) -> Option<&NullChunked> {
         try_unpack_chunked!(self, DataType::Null => NullChunked)
     }
+
+    /// Unpack to [`ExtensionChunked`] of dtype [`DataType::Extension`] or [`DataType::Uuid`]
+    pub fn try_extension(&self) -> Option<&ExtensionChunked> {
+        try_unpack_chunked!(self, DataType::Extension(_, _, _) | DataType::Uuid => ExtensionChunked)
+    }
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Int8`]
     pub fn i8(&self) -> PolarsResult<&Int8Chunked> {
         self.try_i8()
@@ -362,4 +368,10 @@ impl Series {
         self.try_null()
             .ok_or_else(|| unpack_chunked_err!(self => "Null"))
     }
+
+    /// Unpack to [`ExtensionChunked`] of dtype [`DataType::Extension`] or [`DataType::Uuid`]
+    pub fn extension(&self) -> PolarsResult<&ExtensionChunked> {
+        self.try_extension()
+            .ok_or_else(|| unpack_chunked_err!(self => "Extension"))
+    }
 }
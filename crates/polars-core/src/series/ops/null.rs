@@ -4,6 +4,7 @@ use arrow::offset::OffsetsBuffer;
 
 #[cfg(feature = "object")]
 use crate::chunked_array::object::registry::get_object_builder;
+use crate::extension::ExtensionChunked;
 use crate::prelude::*;
 
 impl Series {
@@ -87,6 +88,18 @@ impl Series {
                 }
                 .into_series()
             },
+            DataType::Uuid => ExtensionChunked::new_uuid_unchecked(Series::full_null(
+                name,
+                size,
+                &DataType::Binary,
+            ))
+            .into_series(),
+            DataType::Extension(ext_name, metadata, storage) => ExtensionChunked::new_unchecked(
+                ext_name.clone(),
+                metadata.clone(),
+                Series::full_null(name, size, storage),
+            )
+            .into_series(),
             DataType::Null => Series::new_null(name, size),
             DataType::Unknown(kind) => {
                 let dtype = kind.materialize().unwrap_or(DataType::Null);
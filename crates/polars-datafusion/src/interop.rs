@@ -0,0 +1,48 @@
+//! Conversions between Polars and the arrow-rs types used by DataFusion.
+use arrow::interchange::arrow_rs::{
+    array_from_arrow_rs, batch_to_arrow_rs, schema_from_arrow_rs, schema_to_arrow_rs,
+};
+use datafusion::arrow::array::{RecordBatch, RecordBatchOptions};
+use datafusion::arrow::datatypes::{Schema as RsSchema, SchemaRef as RsSchemaRef};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+
+/// The arrow-rs schema of a Polars schema.
+pub(crate) fn to_arrow_rs_schema(schema: &Schema) -> PolarsResult<RsSchemaRef> {
+    schema_to_arrow_rs(&schema.to_arrow(CompatLevel::newest()))
+}
+
+/// The Polars schema of an arrow-rs schema.
+pub(crate) fn from_arrow_rs_schema(schema: &RsSchema) -> PolarsResult<Schema> {
+    Ok(Schema::from_arrow_schema(&schema_from_arrow_rs(schema)?))
+}
+
+/// Convert the chunks of a [`DataFrame`] to record batches of the given (matching) schema.
+pub(crate) fn to_record_batches(
+    mut df: DataFrame,
+    schema: &RsSchemaRef,
+) -> PolarsResult<Vec<RecordBatch>> {
+    if df.width() == 0 {
+        let options = RecordBatchOptions::new().with_row_count(Some(df.height()));
+        let batch = RecordBatch::try_new_with_options(schema.clone(), vec![], &options);
+        return Ok(vec![batch.map_err(to_compute_err)?]);
+    }
+    df.align_chunks_par();
+    df.iter_chunks(CompatLevel::newest(), false)
+        .map(|batch| batch_to_arrow_rs(batch, schema))
+        .collect()
+}
+
+/// Convert a record batch to a [`DataFrame`].
+pub(crate) fn from_record_batch(batch: &RecordBatch) -> PolarsResult<DataFrame> {
+    let schema = schema_from_arrow_rs(&batch.schema())?;
+    let columns = schema
+        .iter_values()
+        .zip(batch.columns())
+        .map(|(field, array)| {
+            let array = array_from_arrow_rs(array, field.dtype())?;
+            Series::try_from((field, array)).map(Column::from)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    DataFrame::new_with_height(batch.num_rows(), columns)
+}
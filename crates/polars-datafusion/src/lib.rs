@@ -0,0 +1,22 @@
+//! Adapters to use Polars and DataFusion in the same process.
+//!
+//! * [`PolarsTableProvider`] exposes a [`LazyFrame`](polars_lazy::frame::LazyFrame) as a
+//!   DataFusion [`TableProvider`](datafusion::datasource::TableProvider).
+//! * [`scan_datafusion`] scans a DataFusion [`DataFrame`](datafusion::dataframe::DataFrame)
+//!   as a [`LazyFrame`](polars_lazy::frame::LazyFrame).
+//!
+//! Arrays cross between the engines through the Arrow C Data Interface, which hands over the
+//! buffers without copying them.
+//!
+//! The adapters are only built with the `datafusion` feature.
+#[cfg(feature = "datafusion")]
+mod interop;
+#[cfg(feature = "datafusion")]
+mod scan;
+#[cfg(feature = "datafusion")]
+mod table_provider;
+
+#[cfg(feature = "datafusion")]
+pub use scan::{DataFusionScan, scan_datafusion};
+#[cfg(feature = "datafusion")]
+pub use table_provider::PolarsTableProvider;
@@ -0,0 +1,84 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use datafusion::dataframe::DataFrame as DFDataFrame;
+use futures::TryStreamExt;
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_io::pl_async::get_runtime;
+use polars_lazy::prelude::*;
+
+use crate::interop::{from_arrow_rs_schema, from_record_batch};
+
+/// An [`AnonymousScan`] that executes a DataFusion [`DataFrame`](DFDataFrame).
+///
+/// The projection and the slice of the Polars query are pushed into the DataFusion plan.
+/// Every scan executes the plan again, and its record batches become the chunks of the
+/// scanned [`DataFrame`].
+///
+/// The scan does not stream: it collects every record batch of the DataFusion plan before the
+/// Polars query continues, so the whole result must fit in memory, also with the streaming
+/// engine.
+pub struct DataFusionScan {
+    df: DFDataFrame,
+    schema: SchemaRef,
+}
+
+impl DataFusionScan {
+    pub fn new(df: DFDataFrame) -> PolarsResult<Self> {
+        let schema = Arc::new(from_arrow_rs_schema(df.schema().inner())?);
+        Ok(Self { df, schema })
+    }
+}
+
+impl AnonymousScan for DataFusionScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut df = self.df.clone();
+        if let Some(columns) = &scan_opts.with_columns {
+            let columns = columns.iter().map(|c| c.as_str()).collect::<Vec<_>>();
+            df = df.select_columns(&columns).map_err(to_compute_err)?;
+        }
+        if let Some(n_rows) = scan_opts.n_rows {
+            df = df.limit(0, Some(n_rows)).map_err(to_compute_err)?;
+        }
+        let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
+
+        get_runtime().block_in_place_on(async move {
+            let mut stream = df.execute_stream().await.map_err(to_compute_err)?;
+            let mut out = DataFrame::empty_with_schema(&schema);
+            while let Some(batch) = stream.try_next().await.map_err(to_compute_err)? {
+                out.vstack_mut(&from_record_batch(&batch)?)?;
+            }
+            Ok(out)
+        })
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+/// Scan a DataFusion [`DataFrame`](DFDataFrame) as a [`LazyFrame`], which executes the
+/// DataFusion plan when it is collected. The result of the DataFusion plan is collected in
+/// memory, see [`DataFusionScan`].
+pub fn scan_datafusion(df: DFDataFrame) -> PolarsResult<LazyFrame> {
+    let scan = DataFusionScan::new(df)?;
+    let args = ScanArgsAnonymous {
+        schema: Some(scan.schema.clone()),
+        name: "DATAFUSION SCAN",
+        ..Default::default()
+    };
+    LazyFrame::anonymous_scan(Arc::new(scan), args)
+}
@@ -0,0 +1,130 @@
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::SchemaRef as RsSchemaRef;
+use datafusion::catalog::Session;
+use datafusion::datasource::{TableProvider, TableType};
+use datafusion::error::{DataFusionError, Result as DFResult};
+use datafusion::logical_expr::Expr as DFExpr;
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion::physical_plan::memory::MemoryExec;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+use crate::interop::{to_arrow_rs_schema, to_record_batches};
+
+/// A [`LazyFrame`] that can be registered as a table on a DataFusion `SessionContext`.
+///
+/// When DataFusion scans the table, the projected columns and the limit are pushed into the
+/// [`LazyFrame`], which is then collected by Polars. The chunks of the resulting [`DataFrame`]
+/// become the record batches of the scan.
+/// ```rust,no_run
+/// # use std::sync::Arc;
+/// # use datafusion::prelude::SessionContext;
+/// # use polars_core::prelude::*;
+/// # use polars_lazy::prelude::*;
+/// # use polars_datafusion::PolarsTableProvider;
+/// # async fn example(lf: LazyFrame) -> Result<(), Box<dyn std::error::Error>> {
+/// let ctx = SessionContext::new();
+/// ctx.register_table("t", Arc::new(PolarsTableProvider::new(lf)?))?;
+/// let batches = ctx.sql("SELECT a, count(*) FROM t GROUP BY a").await?.collect().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct PolarsTableProvider {
+    lf: LazyFrame,
+    schema: SchemaRef,
+    arrow_schema: RsSchemaRef,
+}
+
+impl PolarsTableProvider {
+    pub fn new(mut lf: LazyFrame) -> PolarsResult<Self> {
+        let schema = lf.collect_schema()?;
+        let arrow_schema = to_arrow_rs_schema(&schema)?;
+        Ok(Self {
+            lf,
+            schema,
+            arrow_schema,
+        })
+    }
+
+    /// The [`LazyFrame`] of the table.
+    pub fn lazy_frame(&self) -> &LazyFrame {
+        &self.lf
+    }
+}
+
+impl Debug for PolarsTableProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PolarsTableProvider")
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+fn to_datafusion_err(err: PolarsError) -> DataFusionError {
+    DataFusionError::External(Box::new(err))
+}
+
+#[async_trait]
+impl TableProvider for PolarsTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> RsSchemaRef {
+        self.arrow_schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        _filters: &[DFExpr],
+        limit: Option<usize>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        let mut lf = self.lf.clone();
+        if let Some(limit) = limit {
+            lf = lf.limit(limit as IdxSize);
+        }
+        let mut schema = self.arrow_schema.clone();
+        // without columns (e.g. for `count(*)`), only the number of rows is needed
+        let mut only_height = false;
+        if let Some(projection) = projection {
+            let columns = projection
+                .iter()
+                .map(|&i| col(self.schema.get_at_index(i).unwrap().0.clone()))
+                .collect::<Vec<_>>();
+            only_height = columns.is_empty();
+            lf = if only_height {
+                lf.select([len()])
+            } else {
+                lf.select(columns)
+            };
+            schema = Arc::new(schema.project(projection)?);
+        }
+
+        // collecting blocks, so it runs outside of the async workers
+        let batch_schema = schema.clone();
+        let batches = tokio::task::spawn_blocking(move || {
+            let mut df = lf.collect()?;
+            if only_height {
+                let height = df[0].idx()?.get(0).unwrap_or(0);
+                df = DataFrame::new_with_height(height as usize, vec![])?;
+            }
+            to_record_batches(df, &batch_schema)
+        })
+        .await
+        .map_err(|err| DataFusionError::External(Box::new(err)))?
+        .map_err(to_datafusion_err)?;
+
+        Ok(Arc::new(MemoryExec::try_new(&[batches], schema, None)?))
+    }
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "datafusion")]
+
+use std::sync::Arc;
+
+use datafusion::prelude::SessionContext;
+use polars_core::prelude::*;
+use polars_datafusion::{PolarsTableProvider, scan_datafusion};
+use polars_lazy::prelude::*;
+
+fn example_frame() -> DataFrame {
+    df![
+        "a" => ["x", "y", "x", "z"],
+        "b" => [1i64, 2, 3, 4],
+    ]
+    .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_polars_table_provider() {
+    let ctx = SessionContext::new();
+    let provider = PolarsTableProvider::new(example_frame().lazy()).unwrap();
+    ctx.register_table("t", Arc::new(provider)).unwrap();
+
+    let df = ctx
+        .sql("SELECT a, SUM(b) AS b FROM t WHERE b > 1 GROUP BY a ORDER BY a")
+        .await
+        .unwrap();
+    let out = scan_datafusion(df).unwrap().collect().unwrap();
+    let expected = df![
+        "a" => ["x", "y", "z"],
+        "b" => [3i64, 2, 4],
+    ]
+    .unwrap();
+    assert!(out.equals(&expected), "{out}");
+
+    let count = ctx.sql("SELECT COUNT(*) AS n FROM t").await.unwrap();
+    let out = scan_datafusion(count).unwrap().collect().unwrap();
+    assert_eq!(out.column("n").unwrap().i64().unwrap().get(0), Some(4));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_scan_datafusion_pushdown() {
+    let ctx = SessionContext::new();
+    let provider = PolarsTableProvider::new(example_frame().lazy()).unwrap();
+    let df = ctx.read_table(Arc::new(provider)).unwrap();
+
+    let out = scan_datafusion(df)
+        .unwrap()
+        .select([col("b")])
+        .limit(2)
+        .collect()
+        .unwrap();
+    let expected = df!["b" => [1i64, 2]].unwrap();
+    assert!(out.equals(&expected), "{out}");
+}
@@ -99,6 +99,46 @@ pub enum PolarsError {
         error: Box<PolarsError>,
         msg: ErrString,
     },
+    /// An error raised while executing a node of a query plan. Match on
+    /// [`get_err`](PolarsError::get_err) to get the kind of the error.
+    PlanContext {
+        error: Box<PolarsError>,
+        context: Box<ErrorContext>,
+    },
+}
+
+/// The node of a query plan that an error was raised in, so that the error can be mapped back to
+/// the part of the query that caused it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// The operator of the node, e.g. `filter` or `group_by`.
+    pub operator: String,
+    /// The expressions evaluated by the node, as they are displayed in query plans.
+    pub expressions: Vec<String>,
+    /// The names of the columns the expressions refer to.
+    pub columns: Vec<String>,
+}
+
+impl ErrorContext {
+    pub fn new(operator: impl Into<String>) -> Self {
+        Self {
+            operator: operator.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Error originated in plan node '{}'", self.operator)?;
+        if !self.expressions.is_empty() {
+            write!(f, " in expression(s): {}", self.expressions.join(", "))?;
+        }
+        if !self.columns.is_empty() {
+            write!(f, " on column(s): {:?}", self.columns)?;
+        }
+        Ok(())
+    }
 }
 
 impl Error for PolarsError {}
@@ -127,6 +167,7 @@ impl Display for PolarsError {
             StringCacheMismatch(msg) => write!(f, "string caches don't match: {msg}"),
             StructFieldNotFound(msg) => write!(f, "field not found: {msg}"),
             Context { error, msg } => write!(f, "{error}: {msg}"),
+            PlanContext { error, context } => write!(f, "{error}\n\n{context}"),
         }
     }
 }
@@ -213,9 +254,13 @@ impl PolarsError {
                     count += 1;
                     writeln!(&mut bt, "\t[{count}] {}", msg).unwrap();
                 }
-                material_error.wrap_msg(move |msg| {
+                let err = material_error.wrap_msg(move |msg| {
                     format!("{msg}\n\nThis error occurred with the following context stack:\n{bt}")
-                })
+                });
+                match error.error_context() {
+                    Some(context) => err.with_error_context(context.clone()),
+                    None => err,
+                }
             },
             err => err,
         }
@@ -248,14 +293,16 @@ impl PolarsError {
             StructFieldNotFound(msg) => StructFieldNotFound(func(msg).into()),
             SQLInterface(msg) => SQLInterface(func(msg).into()),
             SQLSyntax(msg) => SQLSyntax(func(msg).into()),
-            Context { error, .. } => error.wrap_msg(func),
+            Context { error, .. } | PlanContext { error, .. } => error.wrap_msg(func),
         }
     }
 
-    fn get_err(&self) -> &Self {
+    /// The error without the messages and the [`ErrorContext`] that were attached to it, to
+    /// match on its kind.
+    pub fn get_err(&self) -> &Self {
         use PolarsError::*;
         match self {
-            Context { error, .. } => error.get_err(),
+            Context { error, .. } | PlanContext { error, .. } => error.get_err(),
             err => err,
         }
     }
@@ -266,6 +313,28 @@ impl PolarsError {
             error: Box::new(self),
         }
     }
+
+    /// Attach the plan node the error was raised in. If the error already has an
+    /// [`ErrorContext`], it was raised in a node further down the plan and is kept as is.
+    pub fn with_error_context(self, context: ErrorContext) -> Self {
+        if self.error_context().is_some() {
+            return self;
+        }
+        PolarsError::PlanContext {
+            error: Box::new(self),
+            context: Box::new(context),
+        }
+    }
+
+    /// The plan node the error was raised in, if it is known.
+    pub fn error_context(&self) -> Option<&ErrorContext> {
+        use PolarsError::*;
+        match self {
+            PlanContext { context, .. } => Some(context),
+            Context { error, .. } => error.error_context(),
+            _ => None,
+        }
+    }
 }
 
 pub fn map_err<E: Error>(error: E) -> PolarsError {
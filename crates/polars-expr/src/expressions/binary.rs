@@ -7,6 +7,8 @@ use super::*;
 use crate::expressions::{
     AggState, AggregationContext, PartitionedAggregation, PhysicalExpr, UpdateGroups,
 };
+#[cfg(feature = "dtype-categorical")]
+use crate::state::CategoricalInterner;
 
 #[derive(Clone)]
 pub struct BinaryExpr {
@@ -41,6 +43,37 @@ impl BinaryExpr {
     }
 }
 
+/// Re-encode the operands of a comparison of local categoricals from different sources against the
+/// categories of the query, so that they can be compared.
+#[cfg(feature = "dtype-categorical")]
+fn intern_categoricals(
+    left: Column,
+    right: Column,
+    op: Operator,
+    state: &ExecutionState,
+) -> PolarsResult<(Column, Column)> {
+    if !op.is_comparison() || !CategoricalInterner::needs_interning(left.dtype(), right.dtype()) {
+        return Ok((left, right));
+    }
+    let (l, r) = state
+        .categorical_interner
+        .intern(left.categorical()?, right.categorical()?)?;
+    Ok((
+        l.into_series().with_name(left.name().clone()).into(),
+        r.into_series().with_name(right.name().clone()).into(),
+    ))
+}
+
+#[cfg(not(feature = "dtype-categorical"))]
+fn intern_categoricals(
+    left: Column,
+    right: Column,
+    _op: Operator,
+    _state: &ExecutionState,
+) -> PolarsResult<(Column, Column)> {
+    Ok((left, right))
+}
+
 /// Can partially do operations in place.
 fn apply_operator_owned(left: Column, right: Column, op: Operator) -> PolarsResult<Column> {
     match op {
@@ -120,10 +153,12 @@ impl BinaryExpr {
         mut ac_l: AggregationContext<'a>,
         ac_r: AggregationContext,
         aggregated: bool,
+        state: &ExecutionState,
     ) -> PolarsResult<AggregationContext<'a>> {
         // We want to be able to mutate in place, so we take the lhs to make sure that we drop.
         let lhs = ac_l.get_values().clone();
         let rhs = ac_r.get_values().clone();
+        let (lhs, rhs) = intern_categoricals(lhs, rhs, self.op, state)?;
 
         // Drop lhs so that we might operate in place.
         drop(ac_l.take());
@@ -217,6 +252,7 @@ impl PhysicalExpr for BinaryExpr {
             ShapeMismatch: "cannot evaluate two Series of different lengths ({} and {})",
             lhs.len(), rhs.len(),
         );
+        let (lhs, rhs) = intern_categoricals(lhs, rhs, self.op, state)?;
         apply_operator_owned(lhs, rhs, self.op)
     }
 
@@ -239,17 +275,17 @@ impl PhysicalExpr for BinaryExpr {
         match (ac_l.agg_state(), ac_r.agg_state()) {
             (AggState::Literal(s), AggState::NotAggregated(_))
             | (AggState::NotAggregated(_), AggState::Literal(s)) => match s.len() {
-                1 => self.apply_elementwise(ac_l, ac_r, false),
+                1 => self.apply_elementwise(ac_l, ac_r, false, state),
                 _ => self.apply_group_aware(ac_l, ac_r),
             },
             (AggState::Literal(_), AggState::Literal(_)) => self.apply_all_literal(ac_l, ac_r),
             (AggState::NotAggregated(_), AggState::NotAggregated(_)) => {
-                self.apply_elementwise(ac_l, ac_r, false)
+                self.apply_elementwise(ac_l, ac_r, false, state)
             },
             (
                 AggState::AggregatedScalar(_) | AggState::Literal(_),
                 AggState::AggregatedScalar(_) | AggState::Literal(_),
-            ) => self.apply_elementwise(ac_l, ac_r, true),
+            ) => self.apply_elementwise(ac_l, ac_r, true, state),
             (AggState::AggregatedScalar(_), AggState::NotAggregated(_))
             | (AggState::NotAggregated(_), AggState::AggregatedScalar(_)) => {
                 self.apply_group_aware(ac_l, ac_r)
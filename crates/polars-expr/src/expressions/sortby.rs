@@ -1,5 +1,6 @@
 use polars_core::POOL;
 use polars_core::chunked_array::from_iterator_par::ChunkedCollectParIterExt;
+use polars_core::chunked_array::ops::sort::arg_bottom_k::_arg_bottom_k;
 use polars_core::prelude::*;
 use polars_utils::idx_vec::IdxVec;
 use rayon::prelude::*;
@@ -46,6 +47,14 @@ fn prepare_bool_vec(values: &[bool], by_len: usize) -> Vec<bool> {
 
 static ERR_MSG: &str = "expressions in 'sort_by' produced a different number of groups";
 
+/// Keep the first `limit` sorted indices. Kernels may ignore the limit, so it is applied here.
+fn apply_limit(sorted_idx: IdxCa, limit: Option<IdxSize>) -> IdxCa {
+    match limit {
+        Some(limit) if (limit as usize) < sorted_idx.len() => sorted_idx.slice(0, limit as usize),
+        _ => sorted_idx,
+    }
+}
+
 fn check_groups(a: &GroupsType, b: &GroupsType) -> PolarsResult<()> {
     polars_ensure!(a.iter().zip(b.iter()).all(|(a, b)| {
         a.len() == b.len()
@@ -80,33 +89,46 @@ fn sort_by_groups_single_by(
         nulls_last: options.nulls_last,
         // We are already in par iter.
         multithreaded: false,
+        limit: options.limit,
         ..Default::default()
     };
+    let group_start = indicator.first();
     let new_idx = match indicator {
         GroupsIndicator::Idx((_, idx)) => {
             // SAFETY: group tuples are always in bounds.
             let group = unsafe { sort_by_s.take_slice_unchecked(idx) };
 
-            let sorted_idx = group.arg_sort(options);
+            let sorted_idx = apply_limit(group.arg_sort(options), options.limit);
             map_sorted_indices_to_group_idx(&sorted_idx, idx)
         },
         GroupsIndicator::Slice([first, len]) => {
             let group = sort_by_s.slice(first as i64, len as usize);
-            let sorted_idx = group.arg_sort(options);
+            let sorted_idx = apply_limit(group.arg_sort(options), options.limit);
             map_sorted_indices_to_group_slice(&sorted_idx, first)
         },
     };
-    let first = new_idx
-        .first()
-        .ok_or_else(|| polars_err!(ComputeError: "{}", ERR_MSG))?;
+    group_first(group_start, new_idx, options.limit)
+}
 
-    Ok((*first, new_idx))
+/// The first index of a sorted group. A group may only be empty if it was cut by a `limit`.
+fn group_first(
+    group_start: IdxSize,
+    new_idx: IdxVec,
+    limit: Option<IdxSize>,
+) -> PolarsResult<(IdxSize, IdxVec)> {
+    let first = match (new_idx.first(), limit) {
+        (Some(first), _) => *first,
+        (None, Some(_)) => group_start,
+        (None, None) => polars_bail!(ComputeError: "{}", ERR_MSG),
+    };
+    Ok((first, new_idx))
 }
 
 fn sort_by_groups_no_match_single<'a>(
     mut ac_in: AggregationContext<'a>,
     mut ac_by: AggregationContext<'a>,
     descending: bool,
+    limit: Option<IdxSize>,
     expr: &Expr,
 ) -> PolarsResult<AggregationContext<'a>> {
     let s_in = ac_in.aggregated();
@@ -125,8 +147,10 @@ fn sort_by_groups_no_match_single<'a>(
                         descending,
                         // We are already in par iter.
                         multithreaded: false,
+                        limit,
                         ..Default::default()
                     });
+                    let idx = apply_limit(idx, limit);
                     Ok(Some(unsafe { s.take_unchecked(&idx) }))
                 },
                 _ => Ok(None),
@@ -145,7 +169,9 @@ fn sort_by_groups_multiple_by(
     nulls_last: &[bool],
     multithreaded: bool,
     maintain_order: bool,
+    limit: Option<IdxSize>,
 ) -> PolarsResult<(IdxSize, IdxVec)> {
+    let group_start = indicator.first();
     let new_idx = match indicator {
         GroupsIndicator::Idx((_first, idx)) => {
             // SAFETY: group tuples are always in bounds.
@@ -160,13 +186,10 @@ fn sort_by_groups_multiple_by(
                 nulls_last: nulls_last.to_owned(),
                 multithreaded,
                 maintain_order,
-                limit: None,
+                limit,
             };
 
-            let sorted_idx = groups[0]
-                .as_materialized_series()
-                .arg_sort_multiple(&groups[1..], &options)
-                .unwrap();
+            let sorted_idx = arg_sort_multiple_limited(&groups, options)?;
             map_sorted_indices_to_group_idx(&sorted_idx, idx)
         },
         GroupsIndicator::Slice([first, len]) => {
@@ -181,20 +204,27 @@ fn sort_by_groups_multiple_by(
                 nulls_last: nulls_last.to_owned(),
                 multithreaded,
                 maintain_order,
-                limit: None,
+                limit,
             };
-            let sorted_idx = groups[0]
-                .as_materialized_series()
-                .arg_sort_multiple(&groups[1..], &options)
-                .unwrap();
+            let sorted_idx = arg_sort_multiple_limited(&groups, options)?;
             map_sorted_indices_to_group_slice(&sorted_idx, first)
         },
     };
-    let first = new_idx
-        .first()
-        .ok_or_else(|| polars_err!(ComputeError: "{}", ERR_MSG))?;
+    group_first(group_start, new_idx, limit)
+}
 
-    Ok((*first, new_idx))
+/// Arg sort by multiple columns, only sorting the first `limit` rows if a limit is set.
+fn arg_sort_multiple_limited(
+    by: &[Column],
+    mut options: SortMultipleOptions,
+) -> PolarsResult<IdxCa> {
+    let sorted_idx = match options.limit {
+        Some(limit) => _arg_bottom_k(limit as usize, by, &mut options)?.into_inner(),
+        None => by[0]
+            .as_materialized_series()
+            .arg_sort_multiple(&by[1..], &options)?,
+    };
+    Ok(apply_limit(sorted_idx, options.limit))
 }
 
 impl PhysicalExpr for SortByExpr {
@@ -211,7 +241,8 @@ impl PhysicalExpr for SortByExpr {
         let (series, sorted_idx) = if self.by.len() == 1 {
             let sorted_idx_f = || {
                 let s_sort_by = self.by[0].evaluate(df, state)?;
-                Ok(s_sort_by.arg_sort(SortOptions::from(&self.sort_options)))
+                let sorted_idx = s_sort_by.arg_sort(SortOptions::from(&self.sort_options));
+                Ok(apply_limit(sorted_idx, self.sort_options.limit))
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         } else {
@@ -271,15 +302,17 @@ impl PhysicalExpr for SortByExpr {
                     .with_order_descending_multi(descending)
                     .with_nulls_last_multi(nulls_last);
 
-                s_sort_by[0]
-                    .as_materialized_series()
-                    .arg_sort_multiple(&s_sort_by[1..], &options)
+                arg_sort_multiple_limited(&s_sort_by, options)
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         };
         let (sorted_idx, series) = (sorted_idx?, series?);
+        let expected_len = match self.sort_options.limit {
+            Some(limit) => series.len().min(limit as usize),
+            None => series.len(),
+        };
         polars_ensure!(
-            sorted_idx.len() == series.len(),
+            sorted_idx.len() == expected_len,
             expr = self.expr, ShapeMismatch:
             "`sort_by` produced different length ({}) than the Series that has to be sorted ({})",
             sorted_idx.len(), series.len()
@@ -344,6 +377,7 @@ impl PhysicalExpr for SortByExpr {
                     ac_in,
                     ac_sort_by,
                     self.sort_options.descending[0],
+                    self.sort_options.limit,
                     &self.expr,
                 );
             };
@@ -360,6 +394,7 @@ impl PhysicalExpr for SortByExpr {
                         &SortOptions {
                             descending: descending[0],
                             nulls_last: nulls_last[0],
+                            limit: self.sort_options.limit,
                             ..Default::default()
                         },
                     )
@@ -382,6 +417,7 @@ impl PhysicalExpr for SortByExpr {
                             &nulls_last,
                             self.sort_options.multithreaded,
                             self.sort_options.maintain_order,
+                            self.sort_options.limit,
                         )
                     })
                     .collect::<PolarsResult<_>>()
@@ -1,6 +1,9 @@
-use polars_core::POOL;
+use arrow::bitmap::Bitmap;
+use polars_compute::if_then_else::{ChainValue, if_then_else_chain};
 use polars_core::prelude::*;
+use polars_core::{POOL, with_match_physical_numeric_polars_type};
 use polars_plan::prelude::*;
+use rayon::prelude::*;
 
 use super::*;
 use crate::expressions::{AggregationContext, PhysicalExpr};
@@ -363,3 +366,161 @@ impl PartitionedAggregation for TernaryExpr {
         Ok(partitioned)
     }
 }
+
+/// A chain of at least this many `when/then` branches with a numeric output is evaluated by
+/// [`TernaryChainExpr`].
+pub(crate) const MIN_TERNARY_CHAIN_LEN: usize = 3;
+
+/// A chain of `when/then` branches, each in the `otherwise` of the previous one.
+///
+/// Instead of a `zip_with` per branch, which materializes every intermediate result, the
+/// predicates are turned into masks and the values are blended in a single pass. On groups the
+/// chain is evaluated as the nested [`TernaryExpr`]s.
+pub struct TernaryChainExpr {
+    predicates: Vec<Arc<dyn PhysicalExpr>>,
+    values: Vec<Arc<dyn PhysicalExpr>>,
+    otherwise: Arc<dyn PhysicalExpr>,
+    nested: Arc<TernaryExpr>,
+    run_par: bool,
+}
+
+impl TernaryChainExpr {
+    pub fn new(
+        predicates: Vec<Arc<dyn PhysicalExpr>>,
+        values: Vec<Arc<dyn PhysicalExpr>>,
+        otherwise: Arc<dyn PhysicalExpr>,
+        nested: Arc<TernaryExpr>,
+        run_par: bool,
+    ) -> Self {
+        Self {
+            predicates,
+            values,
+            otherwise,
+            nested,
+            run_par,
+        }
+    }
+}
+
+/// The mask of a predicate, with nulls as `false`.
+fn chain_mask(mask: &Column, len: usize) -> PolarsResult<Bitmap> {
+    let mask = mask.bool()?;
+    if mask.len() == 1 {
+        return Ok(Bitmap::new_with_value(mask.get(0) == Some(true), len));
+    }
+    let mask = mask.rechunk();
+    let arr = mask.downcast_as_array();
+    Ok(match arr.validity() {
+        Some(validity) => arr.values() & validity,
+        None => arr.values().clone(),
+    })
+}
+
+fn select_branches(
+    masks: &[Column],
+    values: &[Column],
+    otherwise: &Column,
+) -> PolarsResult<Column> {
+    let dtype = otherwise.dtype();
+    let lens = || {
+        masks
+            .iter()
+            .chain(values)
+            .chain(std::iter::once(otherwise))
+            .map(Column::len)
+    };
+    let len = lens().max().unwrap();
+    let fused = dtype.is_primitive_numeric()
+        && values.iter().all(|c| c.dtype() == dtype)
+        && lens().all(|l| l == len || l == 1);
+    if !fused {
+        // Let `zip_with` handle, or raise on, everything else.
+        return masks
+            .iter()
+            .zip(values)
+            .rev()
+            .try_fold(otherwise.clone(), |falsy, (mask, truthy)| {
+                truthy.zip_with(mask.bool()?, &falsy)
+            });
+    }
+
+    let masks = masks
+        .iter()
+        .map(|m| chain_mask(m, len))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    with_match_physical_numeric_polars_type!(dtype, |$T| {
+        let chunked = values
+            .iter()
+            .chain(std::iter::once(otherwise))
+            .map(|c| Ok(c.as_materialized_series().unpack::<$T>()?.rechunk()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut branches = chunked
+            .iter()
+            .map(|ca| {
+                if ca.len() == len {
+                    ChainValue::Array(ca.downcast_as_array())
+                } else {
+                    ChainValue::Scalar(ca.get(0))
+                }
+            })
+            .collect::<Vec<_>>();
+        let otherwise = branches.pop().unwrap();
+        let arr = if_then_else_chain(&masks, &branches, otherwise, len);
+        Ok(ChunkedArray::<$T>::with_chunk(values[0].name().clone(), arr).into_column())
+    })
+}
+
+impl PhysicalExpr for TernaryChainExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        self.nested.as_expression()
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        let mut state = state.split();
+        // Don't cache window functions as they run in parallel.
+        state.remove_cache_window_flag();
+        let exprs = self
+            .predicates
+            .iter()
+            .chain(&self.values)
+            .chain(std::iter::once(&self.otherwise))
+            .collect::<Vec<_>>();
+        let columns = if self.run_par {
+            POOL.install(|| {
+                exprs
+                    .par_iter()
+                    .map(|e| e.evaluate(df, &state))
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+        } else {
+            exprs
+                .iter()
+                .map(|e| e.evaluate(df, &state))
+                .collect::<PolarsResult<Vec<_>>>()
+        }?;
+
+        let n = self.predicates.len();
+        select_branches(&columns[..n], &columns[n..2 * n], &columns[2 * n])
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.nested.to_field(input_schema)
+    }
+
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        self.nested.evaluate_on_groups(df, groups, state)
+    }
+
+    fn as_partitioned_aggregator(&self) -> Option<&dyn PartitionedAggregation> {
+        self.nested.as_partitioned_aggregator()
+    }
+
+    fn is_scalar(&self) -> bool {
+        false
+    }
+}
@@ -410,6 +410,9 @@ fn create_physical_expr_inner(
                 inlined_eval: Default::default(),
             }))
         },
+        Ternary { .. } if is_ternary_chain(expression, ctxt, expr_arena, schema) => {
+            create_ternary_chain(expression, ctxt, expr_arena, schema, state)
+        },
         Ternary {
             predicate,
             truthy,
@@ -560,3 +563,85 @@ fn create_physical_expr_inner(
         },
     }
 }
+
+/// Whether `expression` starts a long enough chain of `when/then` branches with a numeric output
+/// to be evaluated by a [`TernaryChainExpr`].
+fn is_ternary_chain(
+    expression: Node,
+    ctxt: Context,
+    expr_arena: &Arena<AExpr>,
+    schema: &SchemaRef,
+) -> bool {
+    let mut len = 0;
+    let mut node = expression;
+    while let AExpr::Ternary { falsy, .. } = expr_arena.get(node) {
+        len += 1;
+        node = *falsy;
+    }
+    len >= MIN_TERNARY_CHAIN_LEN
+        && !is_scalar_ae(expression, expr_arena)
+        && expr_arena
+            .get(expression)
+            .to_field(schema, ctxt, expr_arena)
+            .is_ok_and(|field| field.dtype().is_primitive_numeric())
+}
+
+fn create_ternary_chain(
+    expression: Node,
+    ctxt: Context,
+    expr_arena: &Arena<AExpr>,
+    schema: &SchemaRef,
+    state: &mut ExpressionConversionState,
+) -> PolarsResult<Arc<dyn PhysicalExpr>> {
+    let mut predicates = vec![];
+    let mut values = vec![];
+    let mut branches = vec![];
+    let mut node = expression;
+    while let AExpr::Ternary {
+        predicate,
+        truthy,
+        falsy,
+    } = expr_arena.get(node)
+    {
+        state.reset();
+        predicates.push(create_physical_expr_inner(
+            *predicate, ctxt, expr_arena, schema, state,
+        )?);
+        let mut lit_count = state.local.has_lit as u8;
+        state.reset();
+        values.push(create_physical_expr_inner(
+            *truthy, ctxt, expr_arena, schema, state,
+        )?);
+        lit_count += state.local.has_lit as u8;
+        branches.push((node, lit_count));
+        node = *falsy;
+    }
+    state.reset();
+    let otherwise = create_physical_expr_inner(node, ctxt, expr_arena, schema, state)?;
+    let otherwise_lit = state.local.has_lit as u8;
+
+    // The nested ternaries, for the evaluation on groups.
+    let mut nested = None;
+    for (i, (node, lit_count)) in branches.iter().enumerate().rev() {
+        let (falsy, falsy_lit): (Arc<dyn PhysicalExpr>, u8) = match nested {
+            Some(nested) => (nested as Arc<dyn PhysicalExpr>, 0),
+            None => (otherwise.clone(), otherwise_lit),
+        };
+        nested = Some(Arc::new(TernaryExpr::new(
+            predicates[i].clone(),
+            values[i].clone(),
+            falsy,
+            node_to_expr(*node, expr_arena),
+            state.allow_threading && lit_count + falsy_lit < 2,
+            is_scalar_ae(*node, expr_arena),
+        )));
+    }
+    let nested = nested.unwrap();
+    Ok(Arc::new(TernaryChainExpr::new(
+        predicates,
+        values,
+        otherwise,
+        nested,
+        state.allow_threading,
+    )))
+}
@@ -58,6 +58,58 @@ impl WindowCache {
     }
 }
 
+/// Interns the categories of the categoricals that one query combines.
+///
+/// Without a global string cache, every source of categoricals gets its own local mapping from
+/// categories to physical values, so categoricals from different sources cannot be compared.
+/// Operations of the query re-encode such categoricals against the mapping of the query, which
+/// grows with every category it sees. The mapping lives as long as the [`ExecutionState`].
+#[cfg(feature = "dtype-categorical")]
+#[derive(Default)]
+pub struct CategoricalInterner {
+    /// An empty categorical whose mapping holds every category interned so far.
+    categories: Mutex<Option<CategoricalChunked>>,
+}
+
+#[cfg(feature = "dtype-categorical")]
+impl CategoricalInterner {
+    /// Whether `left` and `right` are local categoricals from different sources.
+    pub fn needs_interning(left: &DataType, right: &DataType) -> bool {
+        match (left, right) {
+            (DataType::Categorical(Some(l), _), DataType::Categorical(Some(r), _)) => {
+                l.is_local() && r.is_local() && !l.same_src(r)
+            },
+            _ => false,
+        }
+    }
+
+    /// Re-encode `left` and `right` against the mapping of the query, so that equal categories
+    /// have equal physical values.
+    pub fn intern(
+        &self,
+        left: &CategoricalChunked,
+        right: &CategoricalChunked,
+    ) -> PolarsResult<(CategoricalChunked, CategoricalChunked)> {
+        let mut guard = self.categories.lock().unwrap();
+        let categories = match guard.take() {
+            Some(categories) => categories,
+            // SAFETY: an empty physical is valid for any mapping.
+            None => unsafe {
+                CategoricalChunked::from_cats_and_dtype_unchecked(
+                    left.physical().clear(),
+                    left.dtype().clone(),
+                )
+            },
+        };
+        let (categories, left) = make_rhs_categoricals_compatible(&categories, left)?;
+        let (categories, right) = make_rhs_categoricals_compatible(&categories, right)?;
+        // Interning `right` may have added categories, which `left` has to know about too.
+        let (categories, left) = make_rhs_categoricals_compatible(&categories, &left)?;
+        *guard = Some(categories);
+        Ok((left, right))
+    }
+}
+
 bitflags! {
     #[repr(transparent)]
     #[derive(Copy, Clone)]
@@ -115,6 +167,9 @@ pub struct ExecutionState {
     pub ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
     stop: Arc<AtomicBool>,
+    /// Shared by all branches of the query.
+    #[cfg(feature = "dtype-categorical")]
+    pub categorical_interner: Arc<CategoricalInterner>,
 }
 
 impl ExecutionState {
@@ -132,6 +187,8 @@ impl ExecutionState {
             ext_contexts: Default::default(),
             node_timer: None,
             stop: Arc::new(AtomicBool::new(false)),
+            #[cfg(feature = "dtype-categorical")]
+            categorical_interner: Default::default(),
         }
     }
 
@@ -196,6 +253,8 @@ impl ExecutionState {
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            #[cfg(feature = "dtype-categorical")]
+            categorical_interner: self.categorical_interner.clone(),
         }
     }
 
@@ -309,6 +368,8 @@ impl Clone for ExecutionState {
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            #[cfg(feature = "dtype-categorical")]
+            categorical_interner: self.categorical_interner.clone(),
         }
     }
 }
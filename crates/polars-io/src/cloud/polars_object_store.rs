@@ -165,6 +165,10 @@ impl PolarsObjectStore {
         .buffered(get_concurrency_limit() as usize)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(%path, bytes = range.len()))
+    )]
     pub async fn get_range(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
         self.try_exec_rebuild_on_err(move |store| {
             let range = range.clone();
@@ -208,6 +212,18 @@ impl PolarsObjectStore {
     ///
     /// # Panics
     /// Panics if the same range start is used by more than 1 range.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                %path,
+                n_ranges = ranges.len(),
+                bytes = ranges.iter().map(|r| r.len()).sum::<usize>(),
+            )
+        )
+    )]
     pub async fn get_ranges_sort<
         K: TryFrom<usize, Error = impl std::fmt::Debug> + std::hash::Hash + Eq,
         T: From<Bytes>,
@@ -302,6 +318,10 @@ impl PolarsObjectStore {
         .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(%path, bytes = tracing::field::Empty))
+    )]
     pub async fn download(&self, path: &Path, file: &mut tokio::fs::File) -> PolarsResult<()> {
         let opt_size = self.head(path).await.ok().map(|x| x.size);
 
@@ -319,7 +339,7 @@ impl PolarsObjectStore {
                 let store = st;
                 let parts = opt_size.map(|x| split_range(0..x)).filter(|x| x.len() > 1);
 
-                if let Some(parts) = parts {
+                let _len = if let Some(parts) = parts {
                     tune_with_concurrency_budget(
                         parts.len().clamp(0, MAX_BUDGET_PER_REQUEST) as u32,
                         || async {
@@ -356,6 +376,9 @@ impl PolarsObjectStore {
                 // flush here (https://github.com/tokio-rs/tokio/issues/2307#issuecomment-596336451).
                 file.sync_all().await.map_err(PolarsError::from)?;
 
+                #[cfg(feature = "tracing")]
+                tracing::Span::current().record("bytes", pl_async::GetSize::size(&_len));
+
                 Ok(())
             }
         })
@@ -92,8 +92,7 @@ pub fn count_rows_from_slice_par(
         return count_rows_from_slice(bytes, quote_char, comment_prefix, eol_char, has_header);
     }
 
-    let file_chunks: Vec<(usize, usize)> =
-        get_file_chunks(bytes, n_threads, None, separator, quote_char, eol_char);
+    let file_chunks = get_file_chunks(bytes, n_threads, quote_char, eol_char);
 
     let iter = file_chunks.into_par_iter().map(|(start, stop)| {
         let bytes = &bytes[start..stop];
@@ -637,9 +636,9 @@ pub struct CountLines {
 
 #[derive(Copy, Clone, Debug)]
 pub struct LineStats {
-    newline_count: usize,
-    last_newline_offset: usize,
-    end_inside_string: bool,
+    pub(crate) newline_count: usize,
+    pub(crate) last_newline_offset: usize,
+    pub(crate) end_inside_string: bool,
 }
 
 impl CountLines {
@@ -3,47 +3,50 @@
 use std::io::Read;
 use std::mem::MaybeUninit;
 
-use super::parser::next_line_position;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+use super::parser::{CountLines, LineStats};
+#[cfg(feature = "decompress")]
+use super::parser::{next_line_position, next_line_position_naive};
 #[cfg(feature = "decompress")]
-use super::parser::next_line_position_naive;
 use super::splitfields::SplitFields;
 
-/// TODO: Remove this in favor of parallel CountLines::analyze_chunk
+/// Split `bytes` into about `n_chunks` chunks that end at a line ending.
 ///
-/// (see https://github.com/pola-rs/polars/issues/19078)
+/// Every chunk is scanned in parallel twice, once assuming it starts outside a quoted field and
+/// once assuming it starts inside one. The actual state at the start of every chunk is then
+/// resolved from left to right, so the chunks are never split at a line ending that is embedded
+/// in a quoted field.
 pub(crate) fn get_file_chunks(
     bytes: &[u8],
     n_chunks: usize,
-    expected_fields: Option<usize>,
-    separator: u8,
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Vec<(usize, usize)> {
-    let mut last_pos = 0;
+    let counter = CountLines::new(quote_char, eol_char);
     let total_len = bytes.len();
-    let chunk_size = total_len / n_chunks;
-    let mut offsets = Vec::with_capacity(n_chunks);
-    for _ in 0..n_chunks {
-        let search_pos = last_pos + chunk_size;
+    let chunk_size = total_len.div_ceil(n_chunks.max(1)).max(1);
+    let stats: Vec<[LineStats; 2]> = POOL.install(|| {
+        bytes
+            .par_chunks(chunk_size)
+            .map(|chunk| counter.analyze_chunk(chunk))
+            .collect()
+    });
 
-        if search_pos >= bytes.len() {
-            break;
+    let mut offsets = Vec::with_capacity(stats.len());
+    let mut last_pos = 0;
+    let mut inside_string = false;
+    for (i, stats) in stats.iter().enumerate() {
+        let stats = &stats[inside_string as usize];
+        if stats.newline_count > 0 {
+            let end_pos = i * chunk_size + stats.last_newline_offset + 1;
+            if end_pos < total_len {
+                offsets.push((last_pos, end_pos));
+                last_pos = end_pos;
+            }
         }
-
-        let end_pos = match next_line_position(
-            &bytes[search_pos..],
-            expected_fields,
-            separator,
-            quote_char,
-            eol_char,
-        ) {
-            Some(pos) => search_pos + pos,
-            None => {
-                break;
-            },
-        };
-        offsets.push((last_pos, end_pos));
-        last_pos = end_pos;
+        inside_string = stats.end_inside_string;
     }
     offsets.push((last_pos, total_len));
     offsets
@@ -198,11 +201,28 @@ mod test {
         let s = std::fs::read_to_string(path).unwrap();
         let bytes = s.as_bytes();
         // can be within -1 / +1 bounds.
-        assert!(
-            (get_file_chunks(bytes, 10, Some(4), b',', None, b'\n').len() as i32 - 10).abs() <= 1
-        );
-        assert!(
-            (get_file_chunks(bytes, 8, Some(4), b',', None, b'\n').len() as i32 - 8).abs() <= 1
-        );
+        assert!((get_file_chunks(bytes, 10, None, b'\n').len() as i32 - 10).abs() <= 1);
+        assert!((get_file_chunks(bytes, 8, None, b'\n').len() as i32 - 8).abs() <= 1);
+    }
+
+    #[test]
+    fn test_get_file_chunks_quoted_newlines() {
+        // Long enough to be scanned in SIMD blocks.
+        let row = "1,\"a\nb\n\"\"c\"\"\",\"x\ny\"\n".repeat(3);
+        let bytes = format!("a,b,c\n{}", row.repeat(50));
+        let bytes = bytes.as_bytes();
+
+        for n_chunks in 1..40 {
+            let chunks = get_file_chunks(bytes, n_chunks, Some(b'"'), b'\n');
+            assert_eq!(chunks.first().unwrap().0, 0);
+            assert_eq!(chunks.last().unwrap().1, bytes.len());
+            for w in chunks.windows(2) {
+                assert_eq!(w[0].1, w[1].0);
+            }
+            // Every chunk but the first starts at a row.
+            for &(start, _) in &chunks[1..] {
+                assert!(bytes[start..].starts_with(b"1,\"a"));
+            }
+        }
     }
 }
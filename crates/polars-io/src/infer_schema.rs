@@ -0,0 +1,474 @@
+//! Infer the schema of a file of any supported format, see [`SchemaInferExt::infer_from_path`].
+use std::fmt::Write;
+use std::io::{Read, Seek};
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_utils::open_file;
+
+/// The format of a file whose schema was inferred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InferredFormat {
+    Csv,
+    /// Newline-delimited JSON.
+    NdJson,
+    Parquet,
+    Ipc,
+}
+
+#[derive(Clone, Debug)]
+pub struct InferSchemaOptions {
+    /// The maximum number of bytes that are read of files that do not store their schema (CSV,
+    /// NDJSON). Files that do store it (Parquet, IPC) only have their metadata read.
+    pub sample_budget: usize,
+}
+
+impl Default for InferSchemaOptions {
+    fn default() -> Self {
+        Self {
+            sample_budget: 1 << 20,
+        }
+    }
+}
+
+/// How certain the inferred data type of a column is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnInference {
+    pub name: PlSmallStr,
+    /// The fraction of the sampled non-null values that have the inferred data type on their own,
+    /// from 0 to 1. It is 1 if the data type is stored in the file.
+    pub confidence: f64,
+    /// Why the data type may not be the right one, e.g. that the values have different types.
+    pub notes: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct InferredSchema {
+    pub format: InferredFormat,
+    pub schema: Schema,
+    /// The inference of every column of `schema`, in the same order.
+    pub columns: Vec<ColumnInference>,
+    /// Notes about the whole file, e.g. that only a part of it was sampled.
+    pub notes: Vec<String>,
+}
+
+pub trait SchemaInferExt {
+    /// Infer the schema of the file at `path`, reading at most `options.sample_budget` bytes.
+    ///
+    /// The format is taken from the file extension or, if it is not known, from the first bytes
+    /// of the file. JSON files are only supported if they are newline-delimited.
+    fn infer_from_path(path: &Path, options: &InferSchemaOptions) -> PolarsResult<InferredSchema>;
+}
+
+impl SchemaInferExt for Schema {
+    #[cfg_attr(not(feature = "csv"), allow(unused_variables))]
+    fn infer_from_path(path: &Path, options: &InferSchemaOptions) -> PolarsResult<InferredSchema> {
+        let mut file = open_file(path)?;
+        // Only CSV and NDJSON files are sampled, the other formats read their metadata.
+        let mut head = Vec::with_capacity(MAGIC_LEN);
+        file.by_ref()
+            .take(MAGIC_LEN as u64)
+            .read_to_end(&mut head)?;
+        file.rewind()?;
+
+        match detect_format(path, &head)? {
+            InferredFormat::Csv => {
+                #[cfg(feature = "csv")]
+                {
+                    let (sample, file_len) = read_sample(file, options.sample_budget)?;
+                    let is_tsv = path.extension().is_some_and(|ext| ext == "tsv");
+                    infer_csv(sample, file_len, is_tsv)
+                }
+                #[cfg(not(feature = "csv"))]
+                {
+                    polars_bail!(ComputeError: "cannot infer the schema of CSV files: the 'csv' feature is not enabled")
+                }
+            },
+            InferredFormat::NdJson => {
+                #[cfg(feature = "json")]
+                {
+                    let (sample, file_len) = read_sample(file, options.sample_budget)?;
+                    infer_ndjson(sample, file_len)
+                }
+                #[cfg(not(feature = "json"))]
+                {
+                    polars_bail!(ComputeError: "cannot infer the schema of NDJSON files: the 'json' feature is not enabled")
+                }
+            },
+            InferredFormat::Parquet => {
+                #[cfg(feature = "parquet")]
+                {
+                    use crate::prelude::{ParquetReader, SerReader};
+                    let schema = ParquetReader::new(file).schema()?;
+                    Ok(stored_schema(InferredFormat::Parquet, &schema))
+                }
+                #[cfg(not(feature = "parquet"))]
+                {
+                    polars_bail!(ComputeError: "cannot infer the schema of Parquet files: the 'parquet' feature is not enabled")
+                }
+            },
+            InferredFormat::Ipc => {
+                #[cfg(feature = "ipc")]
+                {
+                    use crate::prelude::{IpcReader, SerReader};
+                    let schema = IpcReader::new(file).schema()?;
+                    Ok(stored_schema(InferredFormat::Ipc, &schema))
+                }
+                #[cfg(not(feature = "ipc"))]
+                {
+                    polars_bail!(ComputeError: "cannot infer the schema of IPC files: the 'ipc' feature is not enabled")
+                }
+            },
+        }
+    }
+}
+
+/// The number of bytes that are read to detect the format of a file without a known extension.
+const MAGIC_LEN: usize = 8;
+
+fn detect_format(path: &Path, head: &[u8]) -> PolarsResult<InferredFormat> {
+    let ext = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+    let format = match ext.as_deref() {
+        Some("parquet" | "pq") => InferredFormat::Parquet,
+        Some("ipc" | "arrow" | "feather") => InferredFormat::Ipc,
+        Some("csv" | "tsv") => InferredFormat::Csv,
+        Some("ndjson" | "jsonl") => InferredFormat::NdJson,
+        Some("json") => {
+            // A JSON document is one array of records, NDJSON has a record on every line.
+            let first = head.iter().find(|b| !b.is_ascii_whitespace());
+            polars_ensure!(
+                first != Some(&b'['),
+                ComputeError: "cannot infer the schema of JSON files that are not newline-delimited: {}",
+                path.display()
+            );
+            InferredFormat::NdJson
+        },
+        _ if head.starts_with(b"PAR1") => InferredFormat::Parquet,
+        _ if head.starts_with(b"ARROW1") => InferredFormat::Ipc,
+        _ => InferredFormat::Csv,
+    };
+    Ok(format)
+}
+
+/// Read at most `budget` bytes of `file`, returns them and the length of the file.
+#[cfg(any(feature = "csv", feature = "json"))]
+fn read_sample(file: std::fs::File, budget: usize) -> PolarsResult<(Vec<u8>, usize)> {
+    let file_len = file.metadata()?.len() as usize;
+    let mut sample = Vec::with_capacity(budget.min(file_len));
+    file.take(budget as u64).read_to_end(&mut sample)?;
+    Ok((sample, file_len))
+}
+
+/// Cut a sample that does not cover the whole file after `last_eol`, the end of its last full
+/// line, so that the schema is not inferred from a partial line.
+#[cfg(any(feature = "csv", feature = "json"))]
+fn cut_sample(
+    sample: &mut Vec<u8>,
+    last_eol: Option<usize>,
+    file_len: usize,
+    notes: &mut Vec<String>,
+) -> PolarsResult<()> {
+    let Some(end) = last_eol else {
+        polars_bail!(ComputeError: "the sample budget of {} bytes does not contain a full line", sample.len());
+    };
+    sample.truncate(end + 1);
+    notes.push(format!(
+        "only the first {} of {} bytes were sampled",
+        sample.len(),
+        file_len
+    ));
+    Ok(())
+}
+
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+fn stored_schema(format: InferredFormat, schema: &ArrowSchema) -> InferredSchema {
+    let schema = Schema::from_arrow_schema(schema);
+    let columns = schema
+        .iter_names()
+        .map(|name| ColumnInference {
+            name: name.clone(),
+            confidence: 1.0,
+            notes: vec![],
+        })
+        .collect();
+    InferredSchema {
+        format,
+        schema,
+        columns,
+        notes: vec!["the schema is stored in the file".to_string()],
+    }
+}
+
+#[cfg(feature = "csv")]
+fn infer_csv(mut sample: Vec<u8>, file_len: usize, is_tsv: bool) -> PolarsResult<InferredSchema> {
+    use std::io::Cursor;
+
+    use crate::csv::read::_csv_read_internal::CountLines;
+    use crate::csv::read::schema_inference::infer_field_schema;
+    use crate::csv::read::{CsvParseOptions, CsvReadOptions, infer_file_schema};
+    use crate::mmap::ReaderBytes;
+    use crate::prelude::SerReader;
+
+    let mut notes = vec![];
+    if sample.len() < file_len {
+        // Quoted fields can contain newlines, so only a newline outside of quotes ends a line.
+        let [stats, _] = CountLines::new(Some(b'"'), b'\n').analyze_chunk(&sample);
+        let last_eol = (stats.newline_count > 0).then_some(stats.last_newline_offset);
+        cut_sample(&mut sample, last_eol, file_len, &mut notes)?;
+    }
+
+    let separator = if is_tsv {
+        b'\t'
+    } else {
+        sniff_separator(&sample)
+    };
+    if separator != b',' {
+        notes.push(format!("detected separator {:?}", separator as char));
+    }
+    let parse_options = CsvParseOptions::default().with_separator(separator);
+    let (schema, _, _) = infer_file_schema(
+        &ReaderBytes::Borrowed(&sample),
+        &parse_options,
+        None,
+        true,
+        None,
+        0,
+        0,
+        0,
+        true,
+    )?;
+
+    // Read the sample as strings to see how the values of every column were inferred.
+    let df = CsvReadOptions::default()
+        .with_infer_schema_length(Some(0))
+        .with_parse_options(parse_options)
+        .into_reader_with_file_handle(Cursor::new(sample))
+        .finish()?;
+
+    let columns = schema
+        .iter()
+        .map(|(name, dtype)| {
+            let mut counts = PlIndexMap::<DataType, usize>::default();
+            if let Ok(ca) = df.column(name).and_then(|c| c.str()) {
+                for value in ca.into_iter().flatten() {
+                    *counts
+                        .entry(infer_field_schema(value, false, false))
+                        .or_default() += 1;
+                }
+            }
+            column_inference(name, dtype, &counts)
+        })
+        .collect();
+
+    Ok(InferredSchema {
+        format: InferredFormat::Csv,
+        schema,
+        columns,
+        notes,
+    })
+}
+
+#[cfg(feature = "json")]
+fn infer_ndjson(mut sample: Vec<u8>, file_len: usize) -> PolarsResult<InferredSchema> {
+    use polars_error::to_compute_err;
+
+    let mut notes = vec![];
+    if sample.len() < file_len {
+        // JSON strings cannot contain raw newlines, so every newline ends a line.
+        let last_eol = sample.iter().rposition(|&b| b == b'\n');
+        cut_sample(&mut sample, last_eol, file_len, &mut notes)?;
+    }
+    let schema = crate::ndjson::infer_schema(&mut std::io::Cursor::new(&sample), None)?;
+
+    // Infer every line on its own to see how the values of every column were inferred.
+    let mut counts = vec![PlIndexMap::<DataType, usize>::default(); schema.len()];
+    for line in sample.split(|&b| b == b'\n') {
+        let line = simdutf8::basic::from_utf8(line).map_err(to_compute_err)?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let dtype = polars_json::ndjson::infer_iter(std::iter::once(line))?;
+        let DataType::Struct(fields) = DataType::from_arrow_dtype(&dtype) else {
+            continue;
+        };
+        for field in fields.iter().filter(|field| !field.dtype().is_null()) {
+            if let Some(i) = schema.index_of(field.name()) {
+                *counts[i].entry(field.dtype().clone()).or_default() += 1;
+            }
+        }
+    }
+
+    let columns = schema
+        .iter()
+        .zip(&counts)
+        .map(|((name, dtype), counts)| column_inference(name, dtype, counts))
+        .collect();
+
+    Ok(InferredSchema {
+        format: InferredFormat::NdJson,
+        schema,
+        columns,
+        notes,
+    })
+}
+
+/// Pick the separator that occurs most often in the header line.
+#[cfg(feature = "csv")]
+fn sniff_separator(sample: &[u8]) -> u8 {
+    let header = sample.split(|&b| b == b'\n').next().unwrap_or_default();
+    [b',', b';', b'\t', b'|']
+        .into_iter()
+        .rev()
+        .max_by_key(|sep| header.iter().filter(|b| *b == sep).count())
+        .unwrap()
+}
+
+#[cfg(any(feature = "csv", feature = "json"))]
+fn column_inference(
+    name: &PlSmallStr,
+    dtype: &DataType,
+    counts: &PlIndexMap<DataType, usize>,
+) -> ColumnInference {
+    let total = counts.values().sum::<usize>();
+    let mut notes = vec![];
+    if total == 0 {
+        notes.push(format!("all sampled values are null, defaulted to {dtype}"));
+        return ColumnInference {
+            name: name.clone(),
+            confidence: 0.0,
+            notes,
+        };
+    }
+
+    // Integers are also valid floats.
+    let consistent = counts
+        .iter()
+        .filter(|(value_dtype, _)| {
+            *value_dtype == dtype || (value_dtype.is_integer() && dtype.is_float())
+        })
+        .map(|(_, count)| count)
+        .sum::<usize>();
+    if counts.len() > 1 {
+        let mut note = String::from("the values have mixed types:");
+        for (i, (value_dtype, count)) in counts.iter().enumerate() {
+            let sep = if i == 0 { "" } else { "," };
+            write!(note, "{sep} {count} {value_dtype}").unwrap();
+        }
+        write!(note, "; inferred {dtype}").unwrap();
+        notes.push(note);
+    }
+    ColumnInference {
+        name: name.clone(),
+        confidence: consistent as f64 / total as f64,
+        notes,
+    }
+}
+
+#[cfg(all(test, feature = "csv"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_infer_csv_schema() -> PolarsResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("data.csv");
+        std::fs::write(&path, "a;b;c;d\n1;2;x;\n2;2.5;3;\n3;4;y;\n")?;
+
+        let out = Schema::infer_from_path(&path, &InferSchemaOptions::default())?;
+        assert_eq!(out.format, InferredFormat::Csv);
+        let expected = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::Float64),
+            Field::new("c".into(), DataType::String),
+            Field::new("d".into(), DataType::String),
+        ]);
+        assert_eq!(out.schema, expected);
+
+        let confidence = out.columns.iter().map(|c| c.confidence).collect::<Vec<_>>();
+        assert_eq!(&confidence[..2], &[1.0, 1.0]);
+        assert!((confidence[2] - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(confidence[3], 0.0);
+        assert!(out.columns[2].notes[0].contains("mixed types"));
+        assert!(out.columns[3].notes[0].contains("null"));
+
+        // The partial last line is dropped from the sample.
+        let out = Schema::infer_from_path(&path, &InferSchemaOptions { sample_budget: 20 })?;
+        assert_eq!(out.schema.len(), 4);
+        assert!(out.notes.iter().any(|n| n.contains("only the first 15 of")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_csv_schema_quoted_newlines() -> PolarsResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("data.csv");
+        std::fs::write(&path, "a,b\n1,\"x\ny\"\n2,\"p\nq\"\n")?;
+
+        // The budget ends after the newline inside the second quoted field, the sample is cut
+        // after the first row instead.
+        let out = Schema::infer_from_path(&path, &InferSchemaOptions { sample_budget: 17 })?;
+        let expected = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+        ]);
+        assert_eq!(out.schema, expected);
+        assert!(
+            out.notes
+                .iter()
+                .any(|n| n.contains("only the first 12 of 20"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_infer_ndjson_schema() -> PolarsResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("data.jsonl");
+        std::fs::write(
+            &path,
+            "{\"a\":1,\"b\":\"x\"}\n{\"a\":2.5,\"b\":null}\n{\"a\":3,\"c\":true}\n",
+        )?;
+
+        let out = Schema::infer_from_path(&path, &InferSchemaOptions::default())?;
+        assert_eq!(out.format, InferredFormat::NdJson);
+        let expected = Schema::from_iter([
+            Field::new("a".into(), DataType::Float64),
+            Field::new("b".into(), DataType::String),
+            Field::new("c".into(), DataType::Boolean),
+        ]);
+        assert_eq!(out.schema, expected);
+        assert!(out.columns.iter().all(|c| c.confidence == 1.0));
+        assert!(out.columns[0].notes[0].contains("mixed types"));
+
+        let out = Schema::infer_from_path(&path, &InferSchemaOptions { sample_budget: 20 })?;
+        assert_eq!(out.schema.len(), 2);
+        assert!(out.notes.iter().any(|n| n.contains("only the first 16 of")));
+
+        let path = dir.path().join("data.json");
+        std::fs::write(&path, "[{\"a\":1}]")?;
+        let err = Schema::infer_from_path(&path, &InferSchemaOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("not newline-delimited"));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_infer_parquet_schema_from_magic() -> PolarsResult<()> {
+        use crate::prelude::ParquetWriter;
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("data.bin");
+        let mut df = polars_core::df!["a" => [1i32, 2], "b" => ["x", "y"]]?;
+        ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+        let out = Schema::infer_from_path(&path, &InferSchemaOptions { sample_budget: 1 })?;
+        assert_eq!(out.format, InferredFormat::Parquet);
+        assert_eq!(&out.schema, df.schema().as_ref());
+        assert!(out.columns.iter().all(|c| c.confidence == 1.0));
+        Ok(())
+    }
+}
@@ -42,16 +42,21 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::RowIndex;
 use crate::hive::materialize_hive_partitions;
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
+use crate::{RowIndex, Utf8Validation};
 
 #[derive(Clone, Debug, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct IpcScanOptions;
+#[derive(Default)]
+pub struct IpcScanOptions {
+    /// Validate that the string columns are UTF-8.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub validate_utf8: Utf8Validation,
+}
 
 /// Read Arrows IPC format into a DataFrame
 ///
@@ -85,6 +90,7 @@ pub struct IpcReader<R: MmapBytesReader> {
     pub(super) memory_map: Option<PathBuf>,
     metadata: Option<read::FileMetadata>,
     schema: Option<ArrowSchemaRef>,
+    validate_utf8: bool,
 }
 
 fn check_mmap_err(err: PolarsError) -> PolarsResult<()> {
@@ -163,6 +169,16 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
+    /// Set whether the string columns are validated to be UTF-8 (the default). Memory-mapped
+    /// files are never validated.
+    ///
+    /// # Safety
+    /// If not validated, the string data in the file must be valid UTF-8.
+    pub unsafe fn validate_utf8(mut self, validate: bool) -> Self {
+        self.validate_utf8 = validate;
+        self
+    }
+
     /// Set if the file is to be memory_mapped. Only works with uncompressed files.
     /// The file name must be passed to register the memory mapped file.
     pub fn memory_mapped(mut self, path_buf: Option<PathBuf>) -> Self {
@@ -202,7 +218,8 @@ impl<R: MmapBytesReader> IpcReader<R> {
             metadata.schema.clone()
         };
 
-        let reader = read::FileReader::new(self.reader, metadata, self.projection, self.n_rows);
+        let mut reader = read::FileReader::new(self.reader, metadata, self.projection, self.n_rows);
+        unsafe { reader.set_validate_utf8(self.validate_utf8) };
 
         finish_reader(reader, rechunk, None, predicate, &schema, self.row_index)
     }
@@ -231,6 +248,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             memory_map: None,
             metadata: None,
             schema: None,
+            validate_utf8: true,
         }
     }
 
@@ -291,8 +309,9 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
 
             let metadata = self.get_metadata()?.clone();
 
-            let ipc_reader =
+            let mut ipc_reader =
                 read::FileReader::new(self.reader, metadata, self.projection, self.n_rows);
+            unsafe { ipc_reader.set_validate_utf8(self.validate_utf8) };
             let df = finish_reader(ipc_reader, rechunk, None, None, &schema, self.row_index)?;
             Ok(df)
         })()?;
@@ -6,6 +6,8 @@ mod ipc_reader_async;
 mod ipc_stream;
 #[cfg(feature = "ipc")]
 mod mmap;
+#[cfg(all(feature = "ipc", unix))]
+mod shared_memory;
 mod write;
 #[cfg(feature = "ipc")]
 pub use ipc_file::{IpcReader, IpcScanOptions};
@@ -13,4 +15,6 @@ pub use ipc_file::{IpcReader, IpcScanOptions};
 pub use ipc_reader_async::*;
 #[cfg(feature = "ipc_streaming")]
 pub use ipc_stream::*;
+#[cfg(all(feature = "ipc", unix))]
+pub use shared_memory::SharedMemory;
 pub use write::{BatchedWriter, IpcCompression, IpcWriter, IpcWriterOptions};
@@ -0,0 +1,139 @@
+use std::ffi::CString;
+use std::fs::File;
+use std::io::{Seek, SeekFrom};
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_utils::mmap::ensure_not_mapped;
+
+use super::{IpcReader, IpcWriter};
+use crate::shared::{SerReader, SerWriter};
+
+/// A shared memory object holding a [`DataFrame`] in the uncompressed Arrow IPC file layout,
+/// to hand frames between processes.
+///
+/// The writer serializes the frame into the object once; readers memory-map it, so the
+/// buffers of the frames they read point directly into the shared memory. The object is
+/// either a named POSIX shared memory object ([`SharedMemory::create`] and
+/// [`SharedMemory::open`]) or, on Linux, an anonymous `memfd` ([`SharedMemory::memfd`])
+/// whose file descriptor is passed to the other process, e.g. over a Unix socket.
+///
+/// Writing to an object that is still mapped by a frame in this process is an error, but
+/// other processes are not tracked, which is why [`SharedMemory::read`] is `unsafe`.
+#[derive(Debug)]
+pub struct SharedMemory {
+    file: File,
+}
+
+fn c_name(name: &str) -> PolarsResult<CString> {
+    CString::new(name).map_err(to_compute_err)
+}
+
+fn check_fd(fd: RawFd) -> PolarsResult<SharedMemory> {
+    if fd < 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+    // SAFETY: the descriptor was just returned to us and is not owned by anything else.
+    Ok(SharedMemory::from(unsafe { OwnedFd::from_raw_fd(fd) }))
+}
+
+impl SharedMemory {
+    /// Create a new named shared memory object, failing if `name` already exists.
+    ///
+    /// The name should start with a `/` and contain no other slashes. The object is only
+    /// accessible by the current user and lives until it is [unlinked](Self::unlink).
+    pub fn create(name: &str) -> PolarsResult<Self> {
+        let name = c_name(name)?;
+        let mode: libc::mode_t = 0o600;
+        let fd = unsafe {
+            libc::shm_open(
+                name.as_ptr(),
+                libc::O_CREAT | libc::O_EXCL | libc::O_RDWR,
+                mode,
+            )
+        };
+        check_fd(fd)
+    }
+
+    /// Open an existing named shared memory object.
+    pub fn open(name: &str) -> PolarsResult<Self> {
+        let name = c_name(name)?;
+        let mode: libc::mode_t = 0;
+        let fd = unsafe { libc::shm_open(name.as_ptr(), libc::O_RDWR, mode) };
+        check_fd(fd)
+    }
+
+    /// Remove a named shared memory object. Processes that opened it keep their access, the
+    /// memory is released once the last one closes it.
+    pub fn unlink(name: &str) -> PolarsResult<()> {
+        let name = c_name(name)?;
+        if unsafe { libc::shm_unlink(name.as_ptr()) } != 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        Ok(())
+    }
+
+    /// Create an anonymous shared memory file. The `name` is only used for debugging.
+    #[cfg(target_os = "linux")]
+    pub fn memfd(name: &str) -> PolarsResult<Self> {
+        let name = c_name(name)?;
+        let fd = unsafe { libc::memfd_create(name.as_ptr(), libc::MFD_CLOEXEC) };
+        check_fd(fd)
+    }
+
+    /// Write `df` into the shared memory, replacing its previous contents.
+    ///
+    /// The frame is written uncompressed, so that it can be memory-mapped by the readers. This
+    /// needs a platform whose shared memory objects support `write`, such as Linux.
+    pub fn write(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        ensure_not_mapped(&self.file.metadata()?)?;
+        self.file.set_len(0)?;
+        self.file.seek(SeekFrom::Start(0))?;
+        IpcWriter::new(&mut self.file)
+            .with_compression(None)
+            .finish(df)
+    }
+
+    /// Read the [`DataFrame`] in the shared memory, without copying its buffers.
+    ///
+    /// The frame keeps the memory mapped until it and everything derived from it are
+    /// dropped.
+    ///
+    /// # Safety
+    /// The buffers of the frame are the shared memory itself, so no process may write to or
+    /// truncate the object while the frame (or anything derived from it) is alive. This is only
+    /// checked for writes through a [`SharedMemory`] in this process; another process that
+    /// overwrites the object changes the values of the frame, and one that truncates it makes
+    /// reading them crash with `SIGBUS`.
+    pub unsafe fn read(&self) -> PolarsResult<DataFrame> {
+        IpcReader::new(self.file.try_clone()?).finish_memmapped(None)
+    }
+}
+
+impl From<OwnedFd> for SharedMemory {
+    /// Take a file descriptor of a shared memory object, e.g. received from another process.
+    fn from(fd: OwnedFd) -> Self {
+        Self {
+            file: File::from(fd),
+        }
+    }
+}
+
+impl From<SharedMemory> for OwnedFd {
+    fn from(shm: SharedMemory) -> Self {
+        shm.file.into()
+    }
+}
+
+impl AsFd for SharedMemory {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.file.as_fd()
+    }
+}
+
+impl AsRawFd for SharedMemory {
+    fn as_raw_fd(&self) -> RawFd {
+        self.file.as_raw_fd()
+    }
+}
@@ -0,0 +1,82 @@
+use arrow::datatypes::ArrowDataType;
+use arrow::io::avro::avro_schema::file::Block;
+use arrow::io::avro::{read, write};
+use polars_core::prelude::*;
+
+use super::KafkaFormat;
+use crate::ndjson::core::parse_ndjson;
+
+/// Decode the payloads of a micro-batch, one row per message.
+pub(super) fn decode_messages(
+    payloads: &[Vec<u8>],
+    schema: &Schema,
+    format: KafkaFormat,
+) -> PolarsResult<DataFrame> {
+    if payloads.is_empty() {
+        return Ok(DataFrame::empty_with_schema(schema));
+    }
+    match format {
+        KafkaFormat::Json => decode_json(payloads, schema),
+        KafkaFormat::Avro => decode_avro(payloads, schema),
+    }
+}
+
+fn decode_json(payloads: &[Vec<u8>], schema: &Schema) -> PolarsResult<DataFrame> {
+    let mut lines = Vec::with_capacity(payloads.iter().map(|p| p.len() + 1).sum());
+    for payload in payloads {
+        // Line breaks can only be whitespace in valid JSON, so a pretty-printed message
+        // becomes a single line.
+        lines.extend(payload.iter().map(|&b| match b {
+            b'\n' | b'\r' => b' ',
+            b => b,
+        }));
+        lines.push(b'\n');
+    }
+    parse_ndjson(&lines, Some(payloads.len()), schema, false)
+}
+
+fn decode_avro(payloads: &[Vec<u8>], schema: &Schema) -> PolarsResult<DataFrame> {
+    // The Avro reader builds its strings and binaries with 32-bit offsets.
+    let arrow_schema = schema
+        .iter_fields()
+        .map(|field| {
+            let mut field = field.to_arrow(CompatLevel::oldest());
+            field.dtype = match field.dtype {
+                ArrowDataType::LargeUtf8 => ArrowDataType::Utf8,
+                ArrowDataType::LargeBinary => ArrowDataType::Binary,
+                dtype => dtype,
+            };
+            (field.name.clone(), field)
+        })
+        .collect::<ArrowSchema>();
+    let record = write::to_record(&arrow_schema, "record".to_string())?;
+
+    let block = Block::new(payloads.len(), payloads.concat());
+    let projection = vec![true; arrow_schema.len()];
+    let batch = read::deserialize(&block, &arrow_schema, &record.fields, &projection)?;
+    Ok(DataFrame::from(batch))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_json_messages() -> PolarsResult<()> {
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+        ]);
+        let payloads = [
+            br#"{"a": 1, "b": "x"}"#.to_vec(),
+            b"{\n  \"a\": 2,\n  \"b\": \"y\"\n}".to_vec(),
+        ];
+        let df = decode_messages(&payloads, &schema, KafkaFormat::Json)?;
+        let expected = df!["a" => [1i64, 2], "b" => ["x", "y"]]?;
+        assert!(df.equals(&expected));
+
+        let df = decode_messages(&[], &schema, KafkaFormat::Avro)?;
+        assert_eq!(df.schema().as_ref(), &schema);
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+//! Read micro-batches of messages from a Kafka topic.
+//!
+//! [`KafkaBatchReader::next_batch`] polls the topic until it has
+//! [`KafkaOptions::max_messages`] messages or [`KafkaOptions::max_wait`] has passed, and
+//! decodes the payloads into a [`DataFrame`] of one row per message. That batch is pending:
+//! every call returns it again until [`KafkaBatchReader::commit`] commits its offsets for the
+//! consumer group. Only then does a call poll the following batch, which starts after the
+//! committed messages, also in a later run of the same job.
+mod decode;
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+pub use rdkafka;
+use rdkafka::Message;
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{BaseConsumer, CommitMode, Consumer};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How the payload of a message is encoded.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum KafkaFormat {
+    /// A JSON object per message.
+    #[default]
+    Json,
+    /// A single Avro datum per message (binary encoding, without a container header), written
+    /// with the record schema derived from the schema of the scan, in which every field is a
+    /// union with null.
+    Avro,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KafkaOptions {
+    pub format: KafkaFormat,
+    /// The maximum number of messages in a batch.
+    pub max_messages: usize,
+    /// The maximum time to wait for messages while collecting a batch.
+    pub max_wait: Duration,
+    /// The consumer group, whose committed offsets are where a batch starts.
+    pub group_id: String,
+    /// Other configuration of the consumer (librdkafka properties, e.g.
+    /// `auto.offset.reset`).
+    pub config: Vec<(String, String)>,
+}
+
+impl Default for KafkaOptions {
+    fn default() -> Self {
+        Self {
+            format: KafkaFormat::default(),
+            max_messages: 10_000,
+            max_wait: Duration::from_secs(1),
+            group_id: "polars".to_string(),
+            config: vec![],
+        }
+    }
+}
+
+/// Reads micro-batches of a Kafka topic, reusing one consumer for all batches.
+pub struct KafkaBatchReader {
+    state: Mutex<ReaderState>,
+    schema: SchemaRef,
+    options: KafkaOptions,
+}
+
+struct ReaderState {
+    consumer: BaseConsumer,
+    pending: Option<PendingBatch>,
+}
+
+/// A batch that was read but whose offsets are not committed yet.
+struct PendingBatch {
+    df: DataFrame,
+    n_messages: usize,
+}
+
+impl KafkaBatchReader {
+    pub fn try_new(
+        brokers: &str,
+        topic: &str,
+        schema: SchemaRef,
+        options: KafkaOptions,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            options.max_messages > 0,
+            InvalidOperation: "max_messages of a Kafka scan must be positive"
+        );
+        let mut config = ClientConfig::new();
+        for (key, value) in &options.config {
+            config.set(key, value);
+        }
+        let consumer: BaseConsumer = config
+            .set("bootstrap.servers", brokers)
+            .set("group.id", &options.group_id)
+            .set("enable.auto.commit", "false")
+            .create()
+            .map_err(to_compute_err)?;
+        consumer.subscribe(&[topic]).map_err(to_compute_err)?;
+        Ok(Self {
+            state: Mutex::new(ReaderState {
+                consumer,
+                pending: None,
+            }),
+            schema,
+            options,
+        })
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// The pending batch or, if there is none, the next batch of at most
+    /// [`KafkaOptions::max_messages`] messages, which becomes the pending batch.
+    ///
+    /// Returning the pending batch again lets several scans of one query (e.g. of a self-join)
+    /// see the same messages, and a query that failed be retried on them.
+    pub fn next_batch(&self) -> PolarsResult<DataFrame> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(pending) = &state.pending {
+            return Ok(pending.df.clone());
+        }
+
+        let deadline = Instant::now() + self.options.max_wait;
+        let mut payloads = Vec::new();
+        let mut n_messages = 0;
+        while payloads.len() < self.options.max_messages {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            if timeout.is_zero() {
+                break;
+            }
+            let Some(message) = state.consumer.poll(timeout) else {
+                break;
+            };
+            let message = message.map_err(to_compute_err)?;
+            n_messages += 1;
+            // Messages without a payload are deletions of their key (tombstones).
+            if let Some(payload) = message.payload() {
+                payloads.push(payload.to_vec());
+            }
+        }
+
+        let df = decode::decode_messages(&payloads, &self.schema, self.options.format)?;
+        state.pending = Some(PendingBatch {
+            df: df.clone(),
+            n_messages,
+        });
+        Ok(df)
+    }
+
+    /// Commit the offsets of the pending batch, so that the next call to
+    /// [`next_batch`](Self::next_batch) reads the following messages. Does nothing if there is
+    /// no pending batch.
+    pub fn commit(&self) -> PolarsResult<()> {
+        let mut state = self.state.lock().unwrap();
+        let Some(pending) = state.pending.take() else {
+            return Ok(());
+        };
+        if pending.n_messages > 0 {
+            // The consumer has not polled past the batch, so its position is the end of it.
+            if let Err(e) = state.consumer.commit_consumer_state(CommitMode::Sync) {
+                state.pending = Some(pending);
+                return Err(to_compute_err(e));
+            }
+        }
+        Ok(())
+    }
+}
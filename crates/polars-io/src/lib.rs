@@ -12,10 +12,13 @@ pub mod cloud;
 pub mod csv;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+pub mod infer_schema;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "kafka")]
+pub mod kafka;
 pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
@@ -11,6 +11,29 @@ pub struct RowIndex {
     pub offset: IdxSize,
 }
 
+/// Whether the string columns of a file are validated to be UTF-8 when it is read.
+///
+/// Validation can only be skipped with the `unsafe` [`Utf8Validation::trusted`]. This is not
+/// kept by serialization: a deserialized scan always validates.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Utf8Validation {
+    trusted: bool,
+}
+
+impl Utf8Validation {
+    /// Do not validate the string columns.
+    ///
+    /// # Safety
+    /// The string columns of every file that is read must be valid UTF-8.
+    pub unsafe fn trusted() -> Self {
+        Self { trusted: true }
+    }
+
+    pub fn is_enabled(self) -> bool {
+        !self.trusted
+    }
+}
+
 /// Options for Hive partitioning.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1,6 +1,7 @@
 use arrow::array::Array;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::Field;
+use arrow::io::trusted_utf8::{binary_as_strings, strings_as_binary};
 #[cfg(feature = "async")]
 use bytes::Bytes;
 #[cfg(feature = "async")]
@@ -65,10 +66,14 @@ fn _mmap_single_column<'a>(
 
 // similar to arrow2 serializer, except this accepts a slice instead of a vec.
 // this allows us to memory map
+//
+// If `validate_utf8` is false, the string columns are read as binary and reinterpreted as
+// strings, which skips their UTF-8 validation.
 pub fn to_deserializer(
     columns: Vec<(&ColumnChunkMetadata, MemSlice)>,
     field: Field,
     filter: Option<Filter>,
+    validate_utf8: bool,
 ) -> PolarsResult<(Box<dyn Array>, Bitmap)> {
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
@@ -84,5 +89,16 @@ pub fn to_deserializer(
         })
         .unzip();
 
-    column_iter_to_arrays(columns, types, field, filter)
+    // Predicates are evaluated on the decoded values, so they need the string types.
+    if validate_utf8 || matches!(filter, Some(Filter::Predicate(_))) {
+        return column_iter_to_arrays(columns, types, field, filter);
+    }
+    let dtype = field.dtype.clone();
+    let field = Field {
+        dtype: strings_as_binary(&dtype),
+        ..field
+    };
+    let (array, pred_true_mask) = column_iter_to_arrays(columns, types, field, filter)?;
+    // SAFETY: the validation is only disabled for files from trusted sources.
+    Ok((unsafe { binary_as_strings(array, &dtype) }, pred_true_mask))
 }
@@ -2,6 +2,8 @@ use polars_core::schema::SchemaRef;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::Utf8Validation;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetOptions {
@@ -9,6 +11,9 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Whether to check that the string columns are valid UTF-8.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub validate_utf8: Utf8Validation,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
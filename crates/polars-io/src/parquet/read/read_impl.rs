@@ -89,6 +89,24 @@ pub fn try_set_sorted_flag(
     }
 }
 
+/// Convert a column chunk that was read into a [`Column`].
+///
+/// Columns that the row group declares sorted keep equal values together, so they are run-end
+/// encoded if their runs are long enough to save memory.
+pub fn column_chunk_into_column(
+    series: Series,
+    col_idx: Option<usize>,
+    sorting_map: &PlHashMap<usize, IsSorted>,
+) -> PolarsResult<Column> {
+    let column = series.into_column();
+    match col_idx {
+        Some(col_idx) if sorting_map.contains_key(&col_idx) => {
+            column.into_run_end_encoded_if_beneficial()
+        },
+        _ => Ok(column),
+    }
+}
+
 pub fn create_sorting_map(md: &RowGroupMetadata) -> PlHashMap<usize, IsSorted> {
     let capacity = md.sorting_columns().map_or(0, |s| s.len());
     let mut sorting_map = PlHashMap::with_capacity(capacity);
@@ -118,6 +136,7 @@ fn column_idx_to_series(
     filter: Option<Filter>,
     file_schema: &ArrowSchema,
     store: &mmap::ColumnStore,
+    validate_utf8: bool,
 ) -> PolarsResult<(Series, Bitmap)> {
     let field = file_schema.get_at_index(column_i).unwrap().1;
 
@@ -126,7 +145,8 @@ fn column_idx_to_series(
         assert_dtypes(field.dtype())
     }
     let columns = mmap_columns(store, field_md);
-    let (array, pred_true_mask) = mmap::to_deserializer(columns, field.clone(), filter)?;
+    let (array, pred_true_mask) =
+        mmap::to_deserializer(columns, field.clone(), filter, validate_utf8)?;
     let series = Series::try_from((field, array))?;
 
     Ok((series, pred_true_mask))
@@ -146,6 +166,7 @@ fn rg_to_dfs(
     parallel: ParallelStrategy,
     projection: &[usize],
     use_statistics: bool,
+    validate_utf8: bool,
     hive_partition_columns: Option<&[Series]>,
 ) -> PolarsResult<Vec<DataFrame>> {
     if config::verbose() {
@@ -184,6 +205,7 @@ fn rg_to_dfs(
                     row_index,
                     projection,
                     use_statistics,
+                    validate_utf8,
                     hive_partition_columns,
                 );
             }
@@ -204,6 +226,7 @@ fn rg_to_dfs(
             parallel,
             projection,
             use_statistics,
+            validate_utf8,
             hive_partition_columns,
         ),
         _ => rg_to_dfs_par_over_rg(
@@ -218,6 +241,7 @@ fn rg_to_dfs(
             row_index,
             projection,
             use_statistics,
+            validate_utf8,
             hive_partition_columns,
         ),
     }
@@ -247,6 +271,7 @@ fn rg_to_dfs_prefiltered(
     row_index: Option<RowIndex>,
     projection: &[usize],
     use_statistics: bool,
+    validate_utf8: bool,
     hive_partition_columns: Option<&[Series]>,
 ) -> PolarsResult<Vec<DataFrame>> {
     if row_group_end > u32::MAX as usize {
@@ -385,8 +410,14 @@ fn rg_to_dfs_prefiltered(
                             },
                         };
 
-                        let (mut series, pred_true_mask) =
-                            column_idx_to_series(col_idx, part.as_slice(), filter, schema, store)?;
+                        let (mut series, pred_true_mask) = column_idx_to_series(
+                            col_idx,
+                            part.as_slice(),
+                            filter,
+                            schema,
+                            store,
+                            validate_utf8,
+                        )?;
 
                         debug_assert!(
                             pred_true_mask.is_empty() || pred_true_mask.len() == md.num_rows()
@@ -395,7 +426,7 @@ fn rg_to_dfs_prefiltered(
                             None => {
                                 try_set_sorted_flag(&mut series, col_idx, &sorting_map);
                                 Ok((
-                                    series.into_column(),
+                                    column_chunk_into_column(series, Some(col_idx), &sorting_map)?,
                                     (!pred_true_mask.is_empty()).then_some(pred_true_mask),
                                 ))
                             },
@@ -529,6 +560,7 @@ fn rg_to_dfs_prefiltered(
                                 Some(Filter::new_masked(filter_mask.clone())),
                                 schema,
                                 store,
+                                validate_utf8,
                             )?;
 
                             PolarsResult::Ok(array)
@@ -540,6 +572,7 @@ fn rg_to_dfs_prefiltered(
                                 None,
                                 schema,
                                 store,
+                                validate_utf8,
                             )?;
 
                             debug_assert_eq!(array.len(), md.num_rows());
@@ -566,7 +599,7 @@ fn rg_to_dfs_prefiltered(
 
                         try_set_sorted_flag(&mut series, col_idx, &sorting_map);
 
-                        Ok(series.into_column())
+                        column_chunk_into_column(series, Some(col_idx), &sorting_map)
                     })
                     .collect::<PolarsResult<Vec<Column>>>()?;
 
@@ -633,6 +666,7 @@ fn rg_to_dfs_optionally_par_over_columns(
     parallel: ParallelStrategy,
     projection: &[usize],
     use_statistics: bool,
+    validate_utf8: bool,
     hive_partition_columns: Option<&[Series]>,
 ) -> PolarsResult<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
@@ -677,10 +711,11 @@ fn rg_to_dfs_optionally_par_over_columns(
                 Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
                 schema,
                 store,
+                validate_utf8,
             )?;
 
             try_set_sorted_flag(&mut series, *column_i, &sorting_map);
-            Ok(series.into_column())
+            column_chunk_into_column(series, Some(*column_i), &sorting_map)
         };
 
         let columns = if let ParallelStrategy::Columns = parallel {
@@ -742,6 +777,7 @@ fn rg_to_dfs_par_over_rg(
     row_index: Option<RowIndex>,
     projection: &[usize],
     use_statistics: bool,
+    validate_utf8: bool,
     hive_partition_columns: Option<&[Series]>,
 ) -> PolarsResult<Vec<DataFrame>> {
     // compute the limits per row group and the row count offsets
@@ -825,10 +861,11 @@ fn rg_to_dfs_par_over_rg(
                             Some(Filter::new_ranged(slice.0, slice.0 + slice.1)),
                             schema,
                             store,
+                            validate_utf8,
                         )?;
 
                         try_set_sorted_flag(&mut series, *column_i, &sorting_map);
-                        Ok(series.into_column())
+                        column_chunk_into_column(series, Some(*column_i), &sorting_map)
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
@@ -868,6 +905,7 @@ pub fn read_parquet<R: MmapBytesReader>(
     mut parallel: ParallelStrategy,
     row_index: Option<RowIndex>,
     use_statistics: bool,
+    validate_utf8: bool,
     hive_partition_columns: Option<&[Series]>,
 ) -> PolarsResult<DataFrame> {
     // Fast path.
@@ -954,6 +992,7 @@ pub fn read_parquet<R: MmapBytesReader>(
         parallel,
         &materialized_projection,
         use_statistics,
+        validate_utf8,
         hive_partition_columns,
     )?;
 
@@ -1082,6 +1121,7 @@ pub struct BatchedParquetReader {
     parallel: ParallelStrategy,
     chunk_size: usize,
     use_statistics: bool,
+    validate_utf8: bool,
     hive_partition_columns: Option<Arc<[Series]>>,
     include_file_path: Option<Column>,
     /// Has returned at least one materialized frame.
@@ -1100,6 +1140,7 @@ impl BatchedParquetReader {
         row_index: Option<RowIndex>,
         chunk_size: usize,
         use_statistics: bool,
+        validate_utf8: bool,
         hive_partition_columns: Option<Vec<Series>>,
         include_file_path: Option<(PlSmallStr, Arc<str>)>,
         mut parallel: ParallelStrategy,
@@ -1139,6 +1180,7 @@ impl BatchedParquetReader {
             parallel,
             chunk_size,
             use_statistics,
+            validate_utf8,
             hive_partition_columns: hive_partition_columns.map(Arc::from),
             include_file_path: include_file_path.map(|(col, path)| {
                 Column::new_scalar(
@@ -1212,6 +1254,7 @@ impl BatchedParquetReader {
                 let parallel = self.parallel;
                 let projection = self.projection.clone();
                 let use_statistics = self.use_statistics;
+                let validate_utf8 = self.validate_utf8;
                 let hive_partition_columns = self.hive_partition_columns.clone();
                 let slice = self.slice;
 
@@ -1229,6 +1272,7 @@ impl BatchedParquetReader {
                         parallel,
                         &projection,
                         use_statistics,
+                        validate_utf8,
                         hive_partition_columns.as_deref(),
                     );
 
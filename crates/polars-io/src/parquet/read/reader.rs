@@ -41,6 +41,7 @@ pub struct ParquetReader<R: Read + Seek> {
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
     use_statistics: bool,
+    validate_utf8: bool,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -166,6 +167,15 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Set whether the string columns are validated to be UTF-8 (the default).
+    ///
+    /// # Safety
+    /// If not validated, the string data in the file must be valid UTF-8.
+    pub unsafe fn validate_utf8(mut self, validate: bool) -> Self {
+        self.validate_utf8 = validate;
+        self
+    }
+
     /// Number of rows in the parquet file.
     pub fn num_rows(&mut self) -> PolarsResult<usize> {
         let metadata = self.get_metadata()?;
@@ -220,6 +230,7 @@ impl<R: MmapBytesReader + 'static> ParquetReader<R> {
             self.row_index,
             chunk_size,
             self.use_statistics,
+            self.validate_utf8,
             self.hive_partition_columns,
             self.include_file_path,
             self.parallel,
@@ -243,6 +254,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             predicate: None,
             schema: None,
             use_statistics: true,
+            validate_utf8: true,
             hive_partition_columns: None,
             include_file_path: None,
         }
@@ -272,6 +284,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.parallel,
             self.row_index,
             self.use_statistics,
+            self.validate_utf8,
             self.hive_partition_columns.as_deref(),
         )?;
 
@@ -307,6 +320,7 @@ pub struct ParquetAsyncReader {
     predicate: Option<ScanIOPredicate>,
     row_index: Option<RowIndex>,
     use_statistics: bool,
+    validate_utf8: bool,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
     schema: Option<ArrowSchemaRef>,
@@ -328,6 +342,7 @@ impl ParquetAsyncReader {
             row_index: None,
             predicate: None,
             use_statistics: true,
+            validate_utf8: true,
             hive_partition_columns: None,
             include_file_path: None,
             schema: None,
@@ -449,6 +464,15 @@ impl ParquetAsyncReader {
         self
     }
 
+    /// Set whether the string columns are validated to be UTF-8 (the default).
+    ///
+    /// # Safety
+    /// If not validated, the string data in the file must be valid UTF-8.
+    pub unsafe fn validate_utf8(mut self, validate: bool) -> Self {
+        self.validate_utf8 = validate;
+        self
+    }
+
     pub fn with_hive_partition_columns(mut self, columns: Option<Vec<Series>>) -> Self {
         self.hive_partition_columns = columns;
         self
@@ -498,6 +522,7 @@ impl ParquetAsyncReader {
             self.row_index,
             chunk_size,
             self.use_statistics,
+            self.validate_utf8,
             self.hive_partition_columns,
             self.include_file_path,
             self.parallel,
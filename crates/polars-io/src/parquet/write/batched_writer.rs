@@ -159,6 +159,7 @@ fn pages_iter_to_compressor(
                     options.compression,
                     vec![],
                 )
+                .with_zstd_dictionary_size(options.zstd_dictionary_size)
                 .map_err(PolarsError::from),
             );
 
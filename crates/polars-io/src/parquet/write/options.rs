@@ -17,6 +17,8 @@ pub struct ParquetWriteOptions {
     pub row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
     pub data_page_size: Option<usize>,
+    /// If set, train a zstd dictionary of at most this many bytes for every column chunk.
+    pub zstd_dictionary_size: Option<usize>,
 }
 
 /// The compression strategy to use for writing Parquet files.
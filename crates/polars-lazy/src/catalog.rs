@@ -0,0 +1,231 @@
+//! Named datasets, so that queries can refer to tables like `sales.orders` by name instead of
+//! by their location.
+//!
+//! A [`Catalog`] resolves a name to a [`CatalogTable`]: the scan of the data together with its
+//! schema and statistics. The statistics are attached to the scan, so that the optimizer plans
+//! against them instead of estimating them from the files. [`FileCatalog`] is a catalog of the
+//! files in a directory; other catalogs, e.g. of a metastore, implement the trait themselves.
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// The statistics of a [`CatalogTable`].
+#[derive(Clone, Debug, Default)]
+pub struct TableStatistics {
+    /// The number of rows, if known.
+    pub num_rows: Option<usize>,
+}
+
+/// A dataset resolved by a [`Catalog`].
+#[derive(Clone)]
+pub struct CatalogTable {
+    pub scan: LazyFrame,
+    pub schema: SchemaRef,
+    pub statistics: TableStatistics,
+}
+
+impl CatalogTable {
+    /// The scan of the table, with its statistics attached for the optimizer.
+    pub fn into_lazy(self) -> LazyFrame {
+        let mut lf = self.scan;
+        if let DslPlan::Scan { file_info, .. } = &mut lf.logical_plan {
+            if file_info.is_none() {
+                let num_rows = self.statistics.num_rows;
+                let row_estimation = (num_rows, num_rows.unwrap_or(usize::MAX));
+                *file_info = Some(FileInfo::new(self.schema, None, row_estimation));
+            }
+        }
+        lf
+    }
+}
+
+/// A source of named datasets.
+///
+/// Names consist of parts separated by dots, e.g. `sales.orders`; how the parts are
+/// interpreted is up to the catalog.
+pub trait Catalog: Send + Sync {
+    /// Resolve `name`, or return `None` if the catalog has no dataset of that name.
+    fn resolve(&self, name: &str) -> PolarsResult<Option<CatalogTable>>;
+
+    /// The names of all datasets in the catalog.
+    fn table_names(&self) -> PolarsResult<Vec<String>>;
+
+    /// The scan of the dataset `name`, see [`CatalogTable::into_lazy`].
+    fn scan(&self, name: &str) -> PolarsResult<LazyFrame> {
+        match self.resolve(name)? {
+            Some(table) => Ok(table.into_lazy()),
+            None => polars_bail!(ComputeError: "catalog has no table named '{}'", name),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum FileFormat {
+    #[cfg(feature = "parquet")]
+    Parquet,
+    #[cfg(feature = "ipc")]
+    Ipc,
+    #[cfg(feature = "csv")]
+    Csv,
+    #[cfg(feature = "json")]
+    NDJson,
+}
+
+impl FileFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        Some(match path.extension()?.to_str()? {
+            #[cfg(feature = "parquet")]
+            "parquet" => Self::Parquet,
+            #[cfg(feature = "ipc")]
+            "ipc" | "arrow" | "feather" => Self::Ipc,
+            #[cfg(feature = "csv")]
+            "csv" => Self::Csv,
+            #[cfg(feature = "json")]
+            "ndjson" | "jsonl" => Self::NDJson,
+            _ => return None,
+        })
+    }
+
+    fn scan(self, path: &Path, schema: Option<SchemaRef>) -> PolarsResult<LazyFrame> {
+        match self {
+            #[cfg(feature = "parquet")]
+            Self::Parquet => {
+                let args = ScanArgsParquet {
+                    schema,
+                    ..Default::default()
+                };
+                LazyFrame::scan_parquet(path, args)
+            },
+            #[cfg(feature = "ipc")]
+            Self::Ipc => LazyFrame::scan_ipc(path, Default::default()),
+            #[cfg(feature = "csv")]
+            Self::Csv => LazyCsvReader::new(path).with_schema(schema).finish(),
+            #[cfg(feature = "json")]
+            Self::NDJson => LazyJsonLineReader::new(path).with_schema(schema).finish(),
+        }
+    }
+}
+
+/// A [`Catalog`] of the files below a root directory.
+///
+/// The name `sales.orders` refers to the file `<root>/sales/orders.<ext>`, or to all files
+/// `<root>/sales/orders/*.<ext>` of the directory `<root>/sales/orders`, where the extension
+/// determines the format: `parquet`, `ipc`/`arrow`/`feather`, `csv` or `ndjson`/`jsonl`.
+///
+/// The schema and row count of a table are read once and kept, so later queries are planned
+/// without touching the files. Call [`FileCatalog::refresh`] after a table changed.
+pub struct FileCatalog {
+    root: PathBuf,
+    tables: RwLock<PlHashMap<String, (SchemaRef, TableStatistics)>>,
+}
+
+impl FileCatalog {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            tables: Default::default(),
+        }
+    }
+
+    /// Forget the schema and statistics of `name`, so that they are read again.
+    pub fn refresh(&self, name: &str) {
+        self.tables.write().unwrap().remove(name);
+    }
+
+    /// The format and scanned path (a file or glob) of the table `name`.
+    fn locate(&self, name: &str) -> PolarsResult<Option<(FileFormat, PathBuf)>> {
+        if name.split('.').any(str::is_empty) {
+            return Ok(None);
+        }
+        let mut base = self.root.clone();
+        base.extend(name.split('.'));
+        if base.is_dir() {
+            return Ok(sorted_entries(&base)?.iter().find_map(|path| {
+                let format = FileFormat::from_path(path)?;
+                let glob = base.join(format!("*.{}", path.extension()?.to_str()?));
+                Some((format, glob))
+            }));
+        }
+
+        let Some(dir) = base.parent().filter(|dir| dir.is_dir()) else {
+            return Ok(None);
+        };
+        let file_name = base.file_name();
+        Ok(sorted_entries(dir)?.into_iter().find_map(|path| {
+            if path.is_file() && path.file_stem() == file_name {
+                Some((FileFormat::from_path(&path)?, path))
+            } else {
+                None
+            }
+        }))
+    }
+}
+
+fn sorted_entries(dir: &Path) -> PolarsResult<Vec<PathBuf>> {
+    let mut entries = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort_unstable();
+    Ok(entries)
+}
+
+impl Catalog for FileCatalog {
+    fn resolve(&self, name: &str) -> PolarsResult<Option<CatalogTable>> {
+        let Some((format, path)) = self.locate(name)? else {
+            return Ok(None);
+        };
+
+        let cached = self.tables.read().unwrap().get(name).cloned();
+        let (schema, statistics) = match cached {
+            Some(cached) => cached,
+            None => {
+                let mut lf = format.scan(&path, None)?;
+                let schema = lf.collect_schema()?;
+                let num_rows = lf.select([len()]).collect()?[0].get(0)?;
+                let statistics = TableStatistics {
+                    num_rows: num_rows.extract::<usize>(),
+                };
+                self.tables
+                    .write()
+                    .unwrap()
+                    .insert(name.to_string(), (schema.clone(), statistics.clone()));
+                (schema, statistics)
+            },
+        };
+
+        Ok(Some(CatalogTable {
+            scan: format.scan(&path, Some(schema.clone()))?,
+            schema,
+            statistics,
+        }))
+    }
+
+    fn table_names(&self) -> PolarsResult<Vec<String>> {
+        let mut names = vec![];
+        let mut stack = vec![(self.root.clone(), String::new())];
+        while let Some((dir, prefix)) = stack.pop() {
+            for path in sorted_entries(&dir)? {
+                let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                    continue;
+                };
+                let name = format!("{prefix}{stem}");
+                if path.is_dir() {
+                    // A directory is either a table or a namespace of tables.
+                    if self.locate(&name)?.is_some() {
+                        names.push(name);
+                    } else {
+                        stack.push((path, format!("{name}.")));
+                    }
+                } else if FileFormat::from_path(&path).is_some() {
+                    names.push(name);
+                }
+            }
+        }
+        names.sort_unstable();
+        names.dedup();
+        Ok(names)
+    }
+}
@@ -6,7 +6,9 @@ use rayon::prelude::*;
 use super::*;
 use crate::prelude::*;
 
-pub(crate) fn eval_field_to_dtype(f: &Field, expr: &Expr, list: bool) -> Field {
+/// Determine the output field of `expr` evaluated on the elements of `f`, with the columns of
+/// `context` available next to the elements.
+pub(crate) fn eval_field_to_dtype(f: &Field, context: &[Field], expr: &Expr, list: bool) -> Field {
     // Dummy df to determine output dtype.
     let dtype = f
         .dtype()
@@ -14,7 +16,16 @@ pub(crate) fn eval_field_to_dtype(f: &Field, expr: &Expr, list: bool) -> Field {
         .cloned()
         .unwrap_or_else(|| f.dtype().clone());
 
-    let df = Series::new_empty(PlSmallStr::EMPTY, &dtype).into_frame();
+    let columns = std::iter::once(Column::new_empty(PlSmallStr::EMPTY, &dtype))
+        .chain(
+            context
+                .iter()
+                .map(|fld| Column::new_empty(fld.name().clone(), fld.dtype())),
+        )
+        .collect();
+    let Ok(df) = DataFrame::new(columns) else {
+        return Field::new(f.name().clone(), DataType::Null);
+    };
 
     #[cfg(feature = "python")]
     let out = {
@@ -51,7 +62,7 @@ pub trait ExprEvalExtension: IntoExpr + Sized {
             c.rename(PlSmallStr::EMPTY);
 
             // Ensure we get the new schema.
-            let output_field = eval_field_to_dtype(c.field().as_ref(), &expr, false);
+            let output_field = eval_field_to_dtype(c.field().as_ref(), &[], &expr, false);
             let schema = Arc::new(Schema::from_iter(std::iter::once(output_field.clone())));
 
             let expr = expr.clone();
@@ -122,7 +133,7 @@ pub trait ExprEvalExtension: IntoExpr + Sized {
 
         this.apply(
             func,
-            GetOutput::map_field(move |f| Ok(eval_field_to_dtype(f, &expr2, false))),
+            GetOutput::map_field(move |f| Ok(eval_field_to_dtype(f, &[], &expr2, false))),
         )
         .with_fmt("expanding_eval")
     }
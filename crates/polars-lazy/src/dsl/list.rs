@@ -8,7 +8,7 @@ use polars_plan::constants::MAP_LIST_NAME;
 use polars_plan::dsl::*;
 use rayon::prelude::*;
 
-use crate::physical_plan::exotic::prepare_expression_for_context;
+use crate::physical_plan::exotic::prepare_expression_for_fields;
 use crate::prelude::*;
 
 pub trait IntoListNameSpace {
@@ -46,33 +46,75 @@ fn offsets_to_groups(offsets: &[i64]) -> Option<GroupPositions> {
     )
 }
 
+/// The names of the columns of the parent row that `expr` refers to, besides the element.
+fn parent_column_names(expr: &Expr) -> Vec<PlSmallStr> {
+    let mut names: Vec<PlSmallStr> = vec![];
+    for e in expr.into_iter() {
+        if let Expr::Column(name) = e {
+            if !name.is_empty() && !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+    }
+    names
+}
+
+/// The fields the sub-expression of `list.eval` is evaluated on: the element and the parent
+/// columns.
+fn eval_context_fields(lst: &ListChunked, parents: &[Column]) -> Vec<Field> {
+    std::iter::once(Field::new(PlSmallStr::EMPTY, lst.inner_dtype().clone()))
+        .chain(parents.iter().map(|c| c.field().into_owned()))
+        .collect()
+}
+
+/// Evaluate `phys_expr` on the sublist `s` of row `idx`, broadcasting that row of `parents`.
+fn eval_sublist(
+    phys_expr: &dyn PhysicalExpr,
+    state: &ExecutionState,
+    df_container: &mut DataFrame,
+    s: Series,
+    idx: usize,
+    parents: &[Column],
+) -> PolarsResult<Series> {
+    let len = s.len();
+    // SAFETY: all columns have the length of the sublist and distinct names.
+    unsafe {
+        df_container.with_column_unchecked(s.into_column());
+        for c in parents {
+            df_container.with_column_unchecked(c.new_from_index(idx, len));
+        }
+    }
+    let out = phys_expr.evaluate(df_container, state);
+    df_container.clear_columns();
+    out.map(|c| c.take_materialized_series())
+}
+
 fn run_per_sublist(
     s: Column,
     lst: &ListChunked,
+    parents: &[Column],
     expr: &Expr,
     parallel: bool,
     output_field: Field,
 ) -> PolarsResult<Option<Column>> {
-    let phys_expr = prepare_expression_for_context(
-        PlSmallStr::EMPTY,
-        expr,
-        lst.inner_dtype(),
-        Context::Default,
-    )?;
+    let phys_expr =
+        prepare_expression_for_fields(&eval_context_fields(lst, parents), expr, Context::Default)?;
 
     let state = ExecutionState::new();
 
     let mut err = None;
     let mut ca: ListChunked = if parallel {
         let m_err = Mutex::new(None);
+        let mut lst = lst.clone();
         let ca: ListChunked = lst
-            .par_iter()
-            .map(|opt_s| {
+            .par_iter_indexed()
+            .enumerate()
+            .map(|(idx, opt_s)| {
                 opt_s.and_then(|s| {
-                    let df = s.into_frame();
-                    let out = phys_expr.evaluate(&df, &state);
+                    let mut df = DataFrame::empty();
+                    let out = eval_sublist(phys_expr.as_ref(), &state, &mut df, s, idx, parents);
                     match out {
-                        Ok(s) => Some(s.take_materialized_series()),
+                        Ok(s) => Some(s),
                         Err(e) => {
                             *m_err.lock().unwrap() = Some(e);
                             None
@@ -87,13 +129,19 @@ fn run_per_sublist(
         let mut df_container = DataFrame::empty();
 
         lst.into_iter()
-            .map(|s| {
-                s.and_then(|s| unsafe {
-                    df_container.with_column_unchecked(s.into_column());
-                    let out = phys_expr.evaluate(&df_container, &state);
-                    df_container.clear_columns();
+            .enumerate()
+            .map(|(idx, s)| {
+                s.and_then(|s| {
+                    let out = eval_sublist(
+                        phys_expr.as_ref(),
+                        &state,
+                        &mut df_container,
+                        s,
+                        idx,
+                        parents,
+                    );
                     match out {
-                        Ok(s) => Some(s.take_materialized_series()),
+                        Ok(s) => Some(s),
                         Err(e) => {
                             err = Some(e);
                             None
@@ -119,6 +167,7 @@ fn run_per_sublist(
 fn run_on_group_by_engine(
     name: PlSmallStr,
     lst: &ListChunked,
+    parents: &[Column],
     expr: &Expr,
 ) -> PolarsResult<Option<Column>> {
     let lst = lst.rechunk();
@@ -132,9 +181,24 @@ fn run_on_group_by_engine(
     // Invariant in List means values physicals can be cast to inner dtype
     let values = unsafe { values.from_physical_unchecked(inner_dtype).unwrap() };
 
-    let df_context = values.into_frame();
-    let phys_expr =
-        prepare_expression_for_context(PlSmallStr::EMPTY, expr, inner_dtype, Context::Aggregation)?;
+    let mut df_context = values.into_frame();
+    if !parents.is_empty() {
+        // Repeat every row of the parent columns for all elements of its list.
+        let mut idx = vec![0 as IdxSize; df_context.height()];
+        for (i, w) in arr.offsets().as_slice().windows(2).enumerate() {
+            idx[w[0] as usize..w[1] as usize].fill(i as IdxSize);
+        }
+        let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx);
+        for c in parents {
+            // SAFETY: the column has the length of the values and a distinct name.
+            unsafe { df_context.with_column_unchecked(c.take(&idx)?) };
+        }
+    }
+    let phys_expr = prepare_expression_for_fields(
+        &eval_context_fields(&lst, parents),
+        expr,
+        Context::Aggregation,
+    )?;
 
     let state = ExecutionState::new();
     let mut ac = phys_expr.evaluate_on_groups(&df_context, &groups, &state)?;
@@ -150,11 +214,16 @@ fn run_on_group_by_engine(
 
 pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
     /// Run any [`Expr`] on these lists elements
+    ///
+    /// Besides the elements, `expr` can refer to other columns by name. The value of such a
+    /// column in the row of a list is broadcast to all elements of that list.
     fn eval(self, expr: Expr, parallel: bool) -> Expr {
         let this = self.into_list_name_space();
 
+        let parent_names = parent_column_names(&expr);
         let expr2 = expr.clone();
-        let func = move |c: Column| {
+        let func = move |columns: &mut [Column]| {
+            let c = std::mem::take(&mut columns[0]);
             for e in expr.into_iter() {
                 match e {
                     #[cfg(feature = "dtype-categorical")]
@@ -166,21 +235,29 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
                             ComputeError: "casting to categorical not allowed in `list.eval`"
                         )
                     },
-                    Expr::Column(name) => {
-                        polars_ensure!(
-                            name.is_empty(),
-                            ComputeError:
-                            "named columns are not allowed in `list.eval`; consider using `element` or `col(\"\")`"
-                        );
-                    },
                     _ => {},
                 }
             }
             let lst = c.list()?.clone();
+            let parents = columns[1..]
+                .iter()
+                .map(|p| match p.len() {
+                    1 => Ok(p.new_from_index(0, lst.len())),
+                    len => {
+                        polars_ensure!(
+                            len == lst.len(),
+                            ShapeMismatch: "column {:?} referenced in `list.eval` has length {}, \
+                            expected {}", p.name(), len, lst.len()
+                        );
+                        Ok(p.clone())
+                    },
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let parent_fields = eval_context_fields(&lst, &parents).split_off(1);
 
             // # fast returns
             // ensure we get the new schema
-            let output_field = eval_field_to_dtype(lst.ref_field(), &expr, true);
+            let output_field = eval_field_to_dtype(lst.ref_field(), &parent_fields, &expr, true);
             if lst.is_empty() {
                 return Ok(Some(Column::new_empty(
                     c.name().clone(),
@@ -199,16 +276,20 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
             };
 
             if fits_idx_size && c.null_count() == 0 && !is_user_apply() {
-                run_on_group_by_engine(c.name().clone(), &lst, &expr)
+                run_on_group_by_engine(c.name().clone(), &lst, &parents, &expr)
             } else {
-                run_per_sublist(c, &lst, &expr, parallel, output_field)
+                run_per_sublist(c, &lst, &parents, &expr, parallel, output_field)
             }
         };
 
+        let parent_exprs = parent_names.into_iter().map(col).collect::<Vec<_>>();
         this.0
-            .map(
+            .map_many(
                 func,
-                GetOutput::map_field(move |f| Ok(eval_field_to_dtype(f, &expr2, true))),
+                &parent_exprs,
+                GetOutput::map_fields(move |fields| {
+                    Ok(eval_field_to_dtype(&fields[0], &fields[1..], &expr2, true))
+                }),
             )
             .with_fmt("eval")
     }
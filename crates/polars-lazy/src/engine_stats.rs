@@ -0,0 +1,49 @@
+//! Statistics of the global state of the engine, e.g. for the health dashboards of applications
+//! that embed Polars.
+use std::time::Duration;
+
+use polars_core::POOL;
+
+/// A snapshot of the global state of the engine, see [`engine_stats`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EngineStats {
+    /// The number of threads of the thread pool.
+    pub thread_pool_size: usize,
+    /// The CPU time the threads of the thread pool have used since they were started, if the
+    /// platform reports it (only Linux). The utilization over an interval is the difference
+    /// of two snapshots divided by the length of the interval times
+    /// [`thread_pool_size`](Self::thread_pool_size).
+    pub thread_pool_cpu_time: Option<Duration>,
+    /// Whether the global string cache of the categoricals is enabled.
+    pub string_cache_enabled: bool,
+    /// The number of strings in the global string cache.
+    pub string_cache_len: usize,
+    /// The number of bytes of the strings in the global string cache.
+    pub string_cache_bytes: usize,
+    /// The number of bytes the streaming engine spilled to the temporary directory, including
+    /// those of other processes that use the same directory.
+    pub spill_bytes: u64,
+}
+
+/// Take a snapshot of the global state of the engine.
+///
+/// There is no pool of buffers to report on: the engine allocates buffers from the global
+/// allocator.
+pub fn engine_stats() -> EngineStats {
+    #[allow(unused_mut)]
+    let mut stats = EngineStats {
+        thread_pool_size: POOL.current_num_threads(),
+        thread_pool_cpu_time: polars_core::thread_pool_cpu_time(),
+        ..Default::default()
+    };
+    #[cfg(feature = "dtype-categorical")]
+    {
+        stats.string_cache_enabled = polars_core::using_string_cache();
+        (stats.string_cache_len, stats.string_cache_bytes) = polars_core::string_cache_size();
+    }
+    #[cfg(feature = "streaming")]
+    {
+        stats.spill_bytes = polars_pipe::pipeline::spill_dir_size();
+    }
+    stats
+}
@@ -0,0 +1,219 @@
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use arrow::io::ipc::format::ipc::MessageRef;
+use arrow::io::ipc::format::ipc::planus::ReadAsRoot;
+use arrow_format::flight::data::FlightData;
+pub use arrow_format::flight::data::FlightDescriptor;
+use arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use polars_core::error::to_compute_err;
+use polars_io::utils::file::DynWriteable;
+use polars_io::utils::sync_on_close::SyncOnCloseType;
+use tokio::sync::mpsc;
+
+use crate::prelude::*;
+
+/// The number of encoded messages that can be waiting for the Flight server. The streaming
+/// engine stalls once it is full, until the server accepts more data.
+const FLIGHT_CHANNEL_CAPACITY: usize = 2;
+
+/// The IPC file magic (`ARROW1`) and its padding.
+const IPC_FILE_HEADER_LEN: usize = 8;
+
+/// Splits the IPC file written by the IPC sink into the encapsulated messages (schema,
+/// dictionaries and record batches), and sends each as [`FlightData`]. Everything after the
+/// end-of-stream marker (the file footer) is dropped.
+struct FlightDataWriter {
+    tx: mpsc::Sender<FlightData>,
+    descriptor: Option<FlightDescriptor>,
+    buf: Vec<u8>,
+    header_seen: bool,
+    finished: bool,
+}
+
+impl FlightDataWriter {
+    fn new(tx: mpsc::Sender<FlightData>, descriptor: FlightDescriptor) -> Self {
+        Self {
+            tx,
+            descriptor: Some(descriptor),
+            buf: vec![],
+            header_seen: false,
+            finished: false,
+        }
+    }
+
+    /// Take the next full message from the buffer, if there is one.
+    fn next_message(&mut self) -> io::Result<Option<FlightData>> {
+        if !self.header_seen {
+            if self.buf.len() < IPC_FILE_HEADER_LEN {
+                return Ok(None);
+            }
+            self.buf.drain(..IPC_FILE_HEADER_LEN);
+            self.header_seen = true;
+        }
+        if self.buf.len() < 8 {
+            return Ok(None);
+        }
+        let meta_len = i32::from_le_bytes(self.buf[4..8].try_into().unwrap());
+        if meta_len == 0 {
+            self.finished = true;
+            self.buf = vec![];
+            return Ok(None);
+        }
+        let meta_end = 8 + meta_len as usize;
+        if self.buf.len() < meta_end {
+            return Ok(None);
+        }
+        let body_len = MessageRef::read_as_root(&self.buf[8..meta_end])
+            .and_then(|message| message.body_length())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        let body_end = meta_end + body_len as usize;
+        if self.buf.len() < body_end {
+            return Ok(None);
+        }
+
+        let data = FlightData {
+            flight_descriptor: self.descriptor.take(),
+            data_header: self.buf[8..meta_end].to_vec(),
+            app_metadata: vec![],
+            data_body: self.buf[meta_end..body_end].to_vec(),
+        };
+        self.buf.drain(..body_end);
+        Ok(Some(data))
+    }
+}
+
+impl io::Write for FlightDataWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.finished {
+            return Ok(buf.len());
+        }
+        self.buf.extend_from_slice(buf);
+        while let Some(data) = self.next_message()? {
+            // The sink writes from an async worker, which is handed off while this one waits.
+            tokio::task::block_in_place(|| self.tx.blocking_send(data)).map_err(|_| {
+                io::Error::new(io::ErrorKind::BrokenPipe, "the Flight upload stopped")
+            })?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl DynWriteable for FlightDataWriter {
+    fn as_dyn_write(&self) -> &(dyn io::Write + Send + 'static) {
+        self as _
+    }
+    fn as_mut_dyn_write(&mut self) -> &mut (dyn io::Write + Send + 'static) {
+        self as _
+    }
+    fn close(self: Box<Self>) -> io::Result<()> {
+        Ok(())
+    }
+    fn sync_on_close(&mut self, _sync_on_close: SyncOnCloseType) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl LazyFrame {
+    /// Stream the result of the query to an Arrow Flight server with a `DoPut` call to
+    /// `endpoint` (e.g. `http://localhost:8815`), for the flight identified by `descriptor`.
+    ///
+    /// The batches are uploaded while the streaming engine produces them. Only a few batches
+    /// are buffered: when the server does not keep up, the query waits. The data is encoded
+    /// with [`CompatLevel::oldest`], which every Flight implementation can read.
+    ///
+    /// Returns the number of uploaded rows in a [`SinkReport`] of a single file without a path.
+    pub fn sink_flight(
+        self,
+        endpoint: &str,
+        descriptor: FlightDescriptor,
+    ) -> PolarsResult<SinkReport> {
+        let runtime = polars_io::pl_async::get_runtime();
+        let (tx, mut rx) = mpsc::channel(FLIGHT_CHANNEL_CAPACITY);
+        let endpoint = endpoint.to_string();
+        let upload = runtime.spawn(async move {
+            let mut client = FlightServiceClient::connect(endpoint)
+                .await
+                .map_err(to_compute_err)?;
+            let messages = futures::stream::poll_fn(move |cx| rx.poll_recv(cx));
+            let mut results = client
+                .do_put(messages)
+                .await
+                .map_err(to_compute_err)?
+                .into_inner();
+            while results.message().await.map_err(to_compute_err)?.is_some() {}
+            PolarsResult::Ok(())
+        });
+
+        let writer: Box<dyn DynWriteable> = Box::new(FlightDataWriter::new(tx, descriptor));
+        let target = SpecialEq::new(Arc::new(Mutex::new(Some(writer))));
+        let options = IpcWriterOptions {
+            compat_level: CompatLevel::oldest(),
+            ..Default::default()
+        };
+        let out = self
+            .sink_ipc(
+                SinkTarget::Dyn(target.clone()),
+                options,
+                None,
+                SinkOptions::default(),
+            )
+            .and_then(|lf| lf.collect_with_engine(Engine::Streaming));
+        // Ends the upload if the query failed before it opened the target.
+        drop(target.lock().unwrap().take());
+
+        // A failed upload also fails the query (its writes are refused), so report it first.
+        runtime
+            .block_in_place_on(upload)
+            .unwrap_or_else(|e| Err(to_compute_err(e)))?;
+        SinkReport::from_frame(&out?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::io::ipc::read::FlightConsumer;
+    use arrow::io::ipc::write::EncodedData;
+    use polars_io::ipc::IpcWriter;
+    use polars_io::prelude::SerWriter;
+
+    use super::*;
+
+    fn encoded(data: FlightData) -> EncodedData {
+        EncodedData {
+            ipc_message: data.data_header,
+            arrow_data: data.data_body,
+        }
+    }
+
+    #[test]
+    fn test_flight_data_writer() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1i32, 2, 3],
+            "b" => ["x", "y", "z"],
+        ]?;
+        df.vstack_mut(&df.clone())?;
+
+        let (tx, mut rx) = mpsc::channel(16);
+        let mut writer = FlightDataWriter::new(tx, FlightDescriptor::default());
+        IpcWriter::new(&mut writer).finish(&mut df)?;
+        drop(writer);
+
+        let first = rx.try_recv().unwrap();
+        assert!(first.flight_descriptor.is_some());
+        let mut consumer = FlightConsumer::new(encoded(first))?;
+        let mut out = DataFrame::empty_with_arrow_schema(consumer.schema());
+        while let Ok(data) = rx.try_recv() {
+            assert!(data.flight_descriptor.is_none());
+            if let Some(batch) = consumer.consume(encoded(data))? {
+                out.append_record_batch(batch)?;
+            }
+        }
+        assert!(out.equals(&df));
+        Ok(())
+    }
+}
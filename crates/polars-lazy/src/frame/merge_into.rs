@@ -0,0 +1,66 @@
+use polars_core::prelude::*;
+use polars_ops::frame::{
+    _join_suffix_name, MERGE_IN_SOURCE, MERGE_IN_TARGET, MERGE_SOURCE_SUFFIX, MergeIntoOptions,
+    WhenMatched, WhenNotMatched, merge_into_join_args, validate_merge_into,
+};
+
+use crate::prelude::*;
+
+impl LazyFrame {
+    /// Merge the changes of `source` into this frame on the `keys` columns, the lazy variant
+    /// of [`MergeInto::merge_into`](polars_ops::frame::MergeInto::merge_into).
+    pub fn merge_into<I, S>(
+        mut self,
+        mut source: LazyFrame,
+        keys: I,
+        options: MergeIntoOptions,
+    ) -> PolarsResult<LazyFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let keys = keys.into_iter().map(Into::into).collect::<Vec<_>>();
+        let target_schema = self.collect_schema()?;
+        let source_schema = source.collect_schema()?;
+        validate_merge_into(&target_schema, &source_schema, &keys)?;
+
+        let on = keys.iter().map(|k| col(k.clone())).collect::<Vec<_>>();
+        let joined = self.with_column(lit(true).alias(MERGE_IN_TARGET)).join(
+            source.with_column(lit(true).alias(MERGE_IN_SOURCE)),
+            &on,
+            &on,
+            merge_into_join_args(),
+        );
+        let in_target = col(MERGE_IN_TARGET).is_not_null();
+        let in_source = col(MERGE_IN_SOURCE).is_not_null();
+
+        let columns = target_schema
+            .iter_names()
+            .map(|name| {
+                let value = col(name.clone());
+                if keys.contains(name) || !source_schema.contains(name) {
+                    return value;
+                }
+                let source_value = col(_join_suffix_name(name, MERGE_SOURCE_SUFFIX));
+                match options.when_matched {
+                    WhenMatched::Update => {
+                        when(in_source.clone()).then(source_value).otherwise(value)
+                    },
+                    WhenMatched::Delete | WhenMatched::Ignore => {
+                        when(in_target.clone()).then(value).otherwise(source_value)
+                    },
+                }
+                .alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+
+        let mut keep = in_target.clone();
+        if options.when_matched == WhenMatched::Delete {
+            keep = keep.and(in_source.not());
+        }
+        if options.when_not_matched == WhenNotMatched::Insert {
+            keep = keep.or(in_target.not());
+        }
+        Ok(joined.filter(keep).select(columns))
+    }
+}
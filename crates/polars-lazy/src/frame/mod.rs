@@ -6,8 +6,16 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+#[cfg(feature = "flight")]
+mod flight;
+#[cfg(feature = "merge_into")]
+mod merge_into;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+mod result_cache;
+mod survivorship;
+#[cfg(all(feature = "dynamic_group_by", feature = "range"))]
+mod upsample;
 
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
@@ -18,6 +26,8 @@ pub use csv::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "flight")]
+pub use flight::FlightDescriptor;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
@@ -31,13 +41,19 @@ use polars_core::prelude::*;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
 use polars_io::RowIndex;
 use polars_mem_engine::{Executor, create_multiple_physical_plans, create_physical_plan};
-use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
+#[cfg(feature = "pivot")]
+use polars_ops::frame::pivot::{UnpivotGroups, UnpivotGroupsArgs};
+use polars_ops::frame::{JoinAlgorithm, JoinCoalesce, MaintainOrderJoin};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
 use polars_plan::global::FETCH_ROWS;
 use polars_utils::pl_str::PlSmallStr;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+pub use result_cache::{ResultCache, ResultCacheOptions};
+pub use survivorship::{Survivorship, SurvivorshipRule};
+#[cfg(all(feature = "dynamic_group_by", feature = "range"))]
+pub use upsample::{UpsampleFill, UpsampleFillStrategy};
 
 use crate::frame::cached_arenas::CachedArena;
 #[cfg(feature = "streaming")]
@@ -555,6 +571,25 @@ impl LazyFrame {
         }])
     }
 
+    /// Enforce a [`SchemaContract`] on this LazyFrame, for example right after a scan or right
+    /// before a sink, so the boundary between two pipeline stages is typed.
+    ///
+    /// The schema is checked when the query is built. The output has the columns of the contract,
+    /// in its order. With [`ContractPolicy::Coerce`] the columns are cast strictly, so values
+    /// that don't fit raise when the query runs.
+    pub fn with_schema_contract(mut self, contract: &SchemaContract) -> PolarsResult<Self> {
+        let input = self.collect_schema()?;
+        let columns = contract
+            .resolve(&input)?
+            .into_iter()
+            .map(|(name, dtype)| match dtype {
+                Some(dtype) => col(name.clone()).strict_cast(dtype.clone()),
+                None => col(name.clone()),
+            })
+            .collect::<Vec<_>>();
+        Ok(self.select(columns))
+    }
+
     /// Fetch is like a collect operation, but it overwrites the number of rows read by every scan
     /// operation. This is a utility that helps debug a query on a smaller number of rows.
     ///
@@ -968,6 +1003,12 @@ impl LazyFrame {
             Option<std::time::Duration>,
         ) -> PolarsResult<()>,
     {
+        // `Instant` panics on wasm32-unknown-unknown
+        polars_ensure!(
+            !cfg!(all(target_arch = "wasm32", target_os = "unknown")),
+            InvalidOperation: "profiling is not supported on wasm32-unknown-unknown"
+        );
+
         let query_start = std::time::Instant::now();
         let (mut state, mut physical_plan, _) =
             self.prepare_collect_post_opt(false, Some(query_start), post_opt)?;
@@ -2046,6 +2087,45 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Unpivot several groups of columns at once into one value column per stub, like
+    /// `wide_to_long` of pandas.
+    ///
+    /// See [`UnpivotDF::unpivot_groups`](polars_ops::frame::pivot::UnpivotDF::unpivot_groups)
+    /// for how the columns are matched. Every suffix is selected from the input and the results
+    /// are concatenated, so no join is needed.
+    #[cfg(feature = "pivot")]
+    pub fn unpivot_groups(mut self, args: &UnpivotGroupsArgs) -> PolarsResult<LazyFrame> {
+        let schema = self.collect_schema()?;
+        let groups = UnpivotGroups::try_new(&schema, args)?;
+        let suffix_name = args
+            .suffix_name
+            .clone()
+            .unwrap_or_else(|| PlSmallStr::from_static("variable"));
+
+        let inputs = groups
+            .suffixes
+            .iter()
+            .enumerate()
+            .map(|(i, suffix)| {
+                let mut exprs = args
+                    .index
+                    .iter()
+                    .map(|name| col(name.clone()))
+                    .collect::<Vec<_>>();
+                exprs.push(lit(suffix.clone()).alias(suffix_name.clone()));
+                for (stub, (columns, dtype)) in args.stubs.iter().zip(&groups.stubs) {
+                    let value = match &columns[i] {
+                        Some(name) => col(name.clone()),
+                        None => lit(NULL),
+                    };
+                    exprs.push(value.cast(dtype.clone()).alias(stub.clone()));
+                }
+                self.clone().select(exprs)
+            })
+            .collect::<Vec<_>>();
+        concat(inputs, UnionArgs::default())
+    }
+
     /// Limit the DataFrame to the first `n` rows.
     ///
     /// Note if you don't want the rows to be scanned, use [`fetch`](LazyFrame::fetch).
@@ -2111,6 +2191,67 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Sample `n` rows from every group of `by`, without replacement. Groups with no more than
+    /// `n` rows are kept whole.
+    ///
+    /// The sample is drawn in a single pass with a reservoir per group, so only the sampled rows
+    /// are kept in memory, also on the streaming engine. The groups are in the order in which
+    /// they first occur, the order of the rows within a group is unspecified.
+    #[cfg(feature = "random")]
+    pub fn sample_n_stratified<E: AsRef<[IE]>, IE: Into<Selector> + Clone>(
+        self,
+        by: E,
+        n: usize,
+        seed: Option<u64>,
+    ) -> LazyFrame {
+        let by = by
+            .as_ref()
+            .iter()
+            .map(|ie| ie.clone().into())
+            .collect::<Vec<_>>();
+        self.map_private(DslFunction::ReservoirSample { n, by, seed })
+    }
+
+    /// Sample a fraction between 0.0-1.0 of every group of `by`, without replacement. The
+    /// sampled rows keep their order.
+    ///
+    /// Unlike [`LazyFrame::sample_n_stratified`] this needs the size of every group, so the
+    /// whole input is materialized.
+    #[cfg(all(feature = "random", feature = "range", feature = "rank"))]
+    pub fn sample_frac_stratified<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        mut self,
+        by: E,
+        frac: Expr,
+        seed: Option<u64>,
+    ) -> PolarsResult<LazyFrame> {
+        // The rows whose positions in a random permutation rank lowest within their group are a
+        // uniform sample of it. The permutation is taken over the whole frame, as permuting
+        // within the groups would keep the same positions of all groups of the same size.
+        const KEY: &str = "__POLARS_STRATIFIED_KEY";
+        polars_ensure!(
+            !self.collect_schema()?.contains(KEY),
+            Duplicate: "column name {:?} is reserved for stratified sampling", KEY
+        );
+        let rank = col(KEY).rank(
+            RankOptions {
+                method: RankMethod::Ordinal,
+                ..Default::default()
+            },
+            None,
+        );
+        let keep = rank
+            .cast(DataType::Float64)
+            .lt_eq(len().cast(DataType::Float64) * frac);
+        Ok(self
+            .with_column(
+                int_range(lit(0 as IdxSize), len(), 1, IDX_DTYPE)
+                    .shuffle(seed)
+                    .alias(KEY),
+            )
+            .filter(keep.over(by))
+            .drop([KEY]))
+    }
+
     /// Add a new column at index 0 that counts the rows.
     ///
     /// `name` is the name of the new column. `offset` is where to start counting from; if
@@ -2158,6 +2299,16 @@ impl LazyFrame {
         }
     }
 
+    /// Seed the random expressions of this LazyFrame, such as `sample`, `shuffle` and a random
+    /// `rank`, so that the query gives the same result on every run.
+    ///
+    /// Every expression without a seed of its own gets a different seed derived from `seed`,
+    /// which doesn't depend on the number of threads or the order in which they run. Random
+    /// expressions added after this call are not seeded.
+    pub fn with_random_seed(self, seed: u64) -> LazyFrame {
+        self.map_private(DslFunction::RandomSeed(seed))
+    }
+
     /// Return the number of non-null elements for each column.
     pub fn count(self) -> LazyFrame {
         self.select(vec![col(PlSmallStr::from_static("*")).count()])
@@ -2277,6 +2428,45 @@ impl LazyGroupBy {
             )
     }
 
+    /// Return the `k` rows of each group with the largest values of `by`.
+    ///
+    /// Ties are broken by the order of the rows, so all columns of a row stay together.
+    #[cfg(feature = "top_k")]
+    pub fn top_k_by<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        k: IdxSize,
+        by: E,
+        descending: Vec<bool>,
+    ) -> LazyFrame {
+        self.agg_exploded(|e| e.top_k_by(lit(k), by, descending))
+    }
+
+    /// Return the `k` rows of each group with the smallest values of `by`.
+    ///
+    /// Ties are broken by the order of the rows, so all columns of a row stay together.
+    #[cfg(feature = "top_k")]
+    pub fn bottom_k_by<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        k: IdxSize,
+        by: E,
+        descending: Vec<bool>,
+    ) -> LazyFrame {
+        self.agg_exploded(|e| e.bottom_k_by(lit(k), by, descending))
+    }
+
+    /// Aggregate all non-key columns with `f` and explode the result to one row per value.
+    #[cfg(feature = "top_k")]
+    fn agg_exploded(self, f: impl FnOnce(Expr) -> Expr) -> LazyFrame {
+        let keys = self
+            .keys
+            .iter()
+            .filter_map(|expr| expr_output_name(expr).ok())
+            .collect::<Vec<_>>();
+
+        let values = col(PlSmallStr::from_static("*")).exclude(keys);
+        self.agg([f(values.clone())]).explode_impl([values], true)
+    }
+
     /// Return last n rows of each group
     pub fn tail(self, n: Option<usize>) -> LazyFrame {
         let keys = self
@@ -2338,6 +2528,7 @@ pub struct JoinBuilder {
     nulls_equal: bool,
     coalesce: JoinCoalesce,
     maintain_order: MaintainOrderJoin,
+    algorithm: JoinAlgorithm,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -2355,6 +2546,7 @@ impl JoinBuilder {
             nulls_equal: false,
             coalesce: Default::default(),
             maintain_order: Default::default(),
+            algorithm: Default::default(),
         }
     }
 
@@ -2441,6 +2633,12 @@ impl JoinBuilder {
         self
     }
 
+    /// The algorithm used to match the keys, see [`JoinAlgorithm`].
+    pub fn algorithm(mut self, algorithm: JoinAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let opt_state = self.lf.opt_state;
@@ -2454,6 +2652,7 @@ impl JoinBuilder {
             nulls_equal: self.nulls_equal,
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
+            algorithm: self.algorithm,
         };
 
         let lp = self
@@ -2546,6 +2745,7 @@ impl JoinBuilder {
             nulls_equal: self.nulls_equal,
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
+            algorithm: self.algorithm,
         };
         let options = JoinOptions {
             allow_parallel: self.allow_parallel,
@@ -0,0 +1,164 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::*;
+
+/// The limits of a [`ResultCache`].
+#[derive(Clone, Debug)]
+pub struct ResultCacheOptions {
+    /// How long a result stays valid after it was computed. `None` keeps it until it is
+    /// evicted or a scanned file changes. Not supported on wasm32-unknown-unknown, which has
+    /// no clock.
+    pub ttl: Option<Duration>,
+    /// The maximum number of cached results.
+    pub max_entries: usize,
+    /// The maximum estimated size in bytes of all cached results. Results that are larger on
+    /// their own are not cached.
+    pub max_bytes: usize,
+}
+
+impl Default for ResultCacheOptions {
+    fn default() -> Self {
+        Self {
+            ttl: None,
+            max_entries: 128,
+            max_bytes: 1 << 30,
+        }
+    }
+}
+
+struct Entry {
+    df: DataFrame,
+    size: usize,
+    expires: Option<Instant>,
+    last_used: u64,
+    /// Keeps the in-memory data that the fingerprint refers to alive, see [`PlanFingerprint`].
+    _fingerprint: PlanFingerprint,
+}
+
+#[derive(Default)]
+struct Entries {
+    /// Keyed by the full 128-bit fingerprint.
+    map: PlHashMap<u128, Entry>,
+    bytes: usize,
+    tick: u64,
+}
+
+/// An in-process cache of query results, for services that run the same queries repeatedly.
+///
+/// Results are keyed by the fingerprint of the optimized plan, which includes the sizes and
+/// versions of the scanned files, so a query is run again once its input files change.
+/// Queries whose result is not determined by their plan, such as queries with UDFs, plugins or
+/// random expressions, are always run. The least recently used results are evicted when a limit of
+/// the [`ResultCacheOptions`] is reached.
+pub struct ResultCache {
+    options: ResultCacheOptions,
+    entries: Mutex<Entries>,
+}
+
+impl ResultCache {
+    pub fn new(options: ResultCacheOptions) -> Self {
+        Self {
+            options,
+            entries: Default::default(),
+        }
+    }
+
+    /// Collect `lf` with the in-memory engine, or return the cached result of the same query.
+    pub fn collect(&self, lf: LazyFrame) -> PolarsResult<DataFrame> {
+        // `Instant` panics on wasm32-unknown-unknown
+        polars_ensure!(
+            self.options.ttl.is_none() || !cfg!(all(target_arch = "wasm32", target_os = "unknown")),
+            InvalidOperation: "a result cache ttl is not supported on wasm32-unknown-unknown"
+        );
+        let mut alp_plan = lf.to_alp_optimized()?;
+        let Some(fingerprint) =
+            plan_fingerprint(alp_plan.lp_top, &alp_plan.lp_arena, &alp_plan.expr_arena)?
+        else {
+            return execute(&mut alp_plan);
+        };
+
+        if let Some(df) = self.get(fingerprint.hash) {
+            return Ok(df);
+        }
+        let df = execute(&mut alp_plan)?;
+        self.insert(fingerprint, df.clone());
+        Ok(df)
+    }
+
+    /// The number of cached results.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all cached results.
+    pub fn clear(&self) {
+        *self.entries.lock().unwrap() = Default::default();
+    }
+
+    fn get(&self, key: u128) -> Option<DataFrame> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.tick += 1;
+        let tick = entries.tick;
+        let entry = entries.map.get_mut(&key)?;
+        if entry
+            .expires
+            .is_some_and(|expires| expires <= Instant::now())
+        {
+            let entry = entries.map.remove(&key).unwrap();
+            entries.bytes -= entry.size;
+            return None;
+        }
+        entry.last_used = tick;
+        Some(entry.df.clone())
+    }
+
+    fn insert(&self, fingerprint: PlanFingerprint, df: DataFrame) {
+        let size = df.estimated_size();
+        if size > self.options.max_bytes || self.options.max_entries == 0 {
+            return;
+        }
+        let key = fingerprint.hash;
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(old) = entries.map.remove(&key) {
+            entries.bytes -= old.size;
+        }
+        while entries.map.len() >= self.options.max_entries
+            || entries.bytes + size > self.options.max_bytes
+        {
+            let (&lru, _) = entries
+                .map
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .unwrap();
+            let entry = entries.map.remove(&lru).unwrap();
+            entries.bytes -= entry.size;
+        }
+
+        entries.tick += 1;
+        let entry = Entry {
+            df,
+            size,
+            expires: self.options.ttl.map(|ttl| Instant::now() + ttl),
+            last_used: entries.tick,
+            _fingerprint: fingerprint,
+        };
+        entries.bytes += size;
+        entries.map.insert(key, entry);
+    }
+}
+
+fn execute(alp_plan: &mut IRPlan) -> PolarsResult<DataFrame> {
+    let mut physical_plan = create_physical_plan(
+        alp_plan.lp_top,
+        &mut alp_plan.lp_arena,
+        &mut alp_plan.expr_arena,
+    )?;
+    #[cfg(feature = "dtype-categorical")]
+    let _string_cache_hold = StringCacheHolder::hold();
+    physical_plan.execute(&mut ExecutionState::new())
+}
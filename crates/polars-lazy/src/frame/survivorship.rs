@@ -0,0 +1,115 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// Which value of a column survives when the duplicate rows of a key are merged.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SurvivorshipRule {
+    /// The value of the first row.
+    #[default]
+    First,
+    /// The value of the last row.
+    Last,
+    Max,
+    Min,
+    /// The first value that is not null.
+    NonNull,
+    /// The longest string or list (the first of the longest, on ties).
+    Longest,
+}
+
+/// A [`SurvivorshipRule`] for every column that is not a key.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Survivorship {
+    /// The rule of the columns without a rule of their own.
+    pub default: SurvivorshipRule,
+    pub columns: Vec<(PlSmallStr, SurvivorshipRule)>,
+}
+
+impl Survivorship {
+    pub fn new(default: SurvivorshipRule) -> Self {
+        Self {
+            default,
+            columns: vec![],
+        }
+    }
+
+    /// Set the rule of one column.
+    pub fn with_rule(mut self, name: impl Into<PlSmallStr>, rule: SurvivorshipRule) -> Self {
+        self.columns.push((name.into(), rule));
+        self
+    }
+
+    fn rule(&self, name: &str) -> SurvivorshipRule {
+        self.columns
+            .iter()
+            .rev()
+            .find(|(n, _)| n == name)
+            .map_or(self.default, |(_, rule)| *rule)
+    }
+}
+
+fn survivor(name: &PlSmallStr, dtype: &DataType, rule: SurvivorshipRule) -> PolarsResult<Expr> {
+    let c = col(name.clone());
+    Ok(match rule {
+        SurvivorshipRule::First => c.first(),
+        SurvivorshipRule::Last => c.last(),
+        SurvivorshipRule::Max => c.max(),
+        SurvivorshipRule::Min => c.min(),
+        SurvivorshipRule::NonNull => c.drop_nulls().first(),
+        SurvivorshipRule::Longest => {
+            let len = match dtype {
+                DataType::List(_) => c.clone().list().len(),
+                #[cfg(feature = "strings")]
+                DataType::String => c.clone().str().len_chars(),
+                dt => polars_bail!(
+                    InvalidOperation:
+                    "survivorship rule 'longest' needs a string or list column, '{}' is {}", name, dt
+                ),
+            };
+            c.get(len.arg_max())
+        },
+    })
+}
+
+impl LazyFrame {
+    /// Drop the duplicate rows of the `subset` key columns, merging the rows of every key into
+    /// one row whose values are chosen per column by `survivorship`.
+    ///
+    /// All columns are merged in a single group by of the keys; the merged rows are in the
+    /// order in which their keys first appear.
+    pub fn unique_with_survivorship(
+        mut self,
+        subset: Vec<PlSmallStr>,
+        survivorship: &Survivorship,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.collect_schema()?;
+        polars_ensure!(
+            !subset.is_empty(),
+            InvalidOperation: "unique with survivorship needs at least one key column"
+        );
+        for name in subset
+            .iter()
+            .chain(survivorship.columns.iter().map(|(n, _)| n))
+        {
+            polars_ensure!(
+                schema.contains(name),
+                ColumnNotFound: "'{}' is not a column of the frame", name
+            );
+        }
+
+        let aggs = schema
+            .iter()
+            .filter(|(name, _)| !subset.contains(name))
+            .map(|(name, dtype)| {
+                Ok(survivor(name, dtype, survivorship.rule(name))?.alias(name.clone()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let keys = subset.iter().map(|k| col(k.clone())).collect::<Vec<_>>();
+        let columns = schema
+            .iter_names()
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        Ok(self.group_by_stable(keys).agg(aggs).select(columns))
+    }
+}
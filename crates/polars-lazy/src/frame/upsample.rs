@@ -0,0 +1,225 @@
+use polars_core::prelude::*;
+use polars_ops::frame::MaintainOrderJoin;
+use polars_time::prelude::{ClosedWindow, Duration, ensure_duration_matches_dtype};
+
+use crate::prelude::*;
+
+const UPSAMPLE_IN_DATA: &str = "__POLARS_UPSAMPLE_IN_DATA";
+
+/// How [`LazyFrame::upsample`] fills a column of the rows it inserts.
+#[derive(Clone, Debug)]
+pub enum UpsampleFillStrategy {
+    /// Take the previous value.
+    Forward,
+    /// Take the next value.
+    Backward,
+    /// Interpolate linearly against the time column.
+    #[cfg(feature = "interpolate_by")]
+    Interpolate,
+    /// Fill with the value of this expression.
+    Value(Expr),
+}
+
+/// Which columns of the inserted rows [`LazyFrame::upsample`] fills.
+#[derive(Clone, Debug, Default)]
+pub enum UpsampleFill {
+    /// Leave the inserted values null.
+    #[default]
+    Null,
+    /// Fill every column that is not the time column or a `group_by` column.
+    All(UpsampleFillStrategy),
+    /// Fill the given columns, leave the others null.
+    Columns(Vec<(PlSmallStr, UpsampleFillStrategy)>),
+}
+
+impl UpsampleFillStrategy {
+    #[cfg_attr(not(feature = "interpolate_by"), allow(unused_variables))]
+    fn expr(&self, name: &PlSmallStr, time_column: &str, group_by: &[PlSmallStr]) -> Expr {
+        let column = col(name.clone());
+        let filled = match self {
+            Self::Forward => column.fill_null_with_strategy(FillNullStrategy::Forward(None)),
+            Self::Backward => column.fill_null_with_strategy(FillNullStrategy::Backward(None)),
+            #[cfg(feature = "interpolate_by")]
+            Self::Interpolate => column.interpolate_by(col(time_column)),
+            Self::Value(value) => return column.fill_null(value.clone()),
+        };
+        if group_by.is_empty() {
+            filled
+        } else {
+            filled.over(group_by.iter().cloned().map(col).collect::<Vec<_>>())
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Upsample at a regular interval `every`, the lazy variant of
+    /// [`PolarsUpsample::upsample`](polars_time::PolarsUpsample::upsample).
+    ///
+    /// This inserts a row for every point of the grid between the first and the last value of
+    /// `time_column` that does not occur in the data, per group of `group_by` if it is set. The
+    /// other columns of the inserted rows are filled according to `fill`; rows that are in the
+    /// data are never changed. Integer time columns take an index count such as `2i` as `every`.
+    pub fn upsample(
+        mut self,
+        time_column: &str,
+        every: Duration,
+        group_by: &[PlSmallStr],
+        maintain_order: bool,
+        fill: UpsampleFill,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.collect_schema()?;
+        let time_dtype = schema.try_get(time_column)?;
+        ensure_duration_matches_dtype(every, time_dtype, "every")?;
+
+        let time = col(time_column);
+        let grid = match time_dtype {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => date_range(
+                time.clone().min(),
+                time.max(),
+                every,
+                ClosedWindow::Both,
+            ),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, tz) => datetime_range(
+                time.clone().min(),
+                time.max(),
+                every,
+                ClosedWindow::Both,
+                Some(*tu),
+                tz.clone(),
+            ),
+            dtype if dtype.is_integer() => int_range(
+                time.clone().min(),
+                time.max() + lit(1).cast(dtype.clone()),
+                every.nanoseconds(),
+                dtype.clone(),
+            ),
+            dtype => {
+                polars_bail!(ComputeError: "upsample not allowed for index column of dtype {}", dtype)
+            },
+        }
+        .alias(time_column);
+
+        let keys = group_by.iter().cloned().map(col).collect::<Vec<_>>();
+        let lf_grid = if keys.is_empty() {
+            self.clone().select([grid])
+        } else {
+            let lgb = if maintain_order {
+                self.clone().group_by_stable(&keys)
+            } else {
+                self.clone().group_by(&keys)
+            };
+            lgb.agg([grid]).explode([col(time_column)])
+        };
+
+        let mut on = keys;
+        on.push(col(time_column));
+        let joined = lf_grid
+            .join_builder()
+            .with(self.with_column(lit(true).alias(UPSAMPLE_IN_DATA)))
+            .how(JoinType::Left)
+            .left_on(&on)
+            .right_on(&on)
+            .join_nulls(true)
+            .maintain_order(MaintainOrderJoin::Left)
+            .finish();
+
+        let fills = match fill {
+            UpsampleFill::Null => vec![],
+            UpsampleFill::All(strategy) => schema
+                .iter_names()
+                .filter(|name| name.as_str() != time_column && !group_by.contains(name))
+                .map(|name| (name.clone(), strategy.clone()))
+                .collect(),
+            UpsampleFill::Columns(fills) => fills,
+        };
+        let inserted = col(UPSAMPLE_IN_DATA).is_null();
+        let fills = fills
+            .into_iter()
+            .map(|(name, strategy)| {
+                when(inserted.clone())
+                    .then(strategy.expr(&name, time_column, group_by))
+                    .otherwise(col(name.clone()))
+                    .alias(name)
+            })
+            .collect::<Vec<_>>();
+
+        let columns = schema.iter_names().cloned().map(col).collect::<Vec<_>>();
+        Ok(joined.with_columns(fills).select(columns))
+    }
+}
+
+impl LazyGroupBy {
+    /// Aggregate a [`group_by_dynamic`](LazyFrame::group_by_dynamic) like [`agg`](Self::agg),
+    /// but also insert a row for every window without data and fill its aggregated columns
+    /// according to `fill`, see [`LazyFrame::upsample`].
+    ///
+    /// This requires [`Label::Left`] and column names as the `group_by` keys.
+    #[cfg(feature = "offset_by")]
+    pub fn agg_fill_gaps<E: AsRef<[Expr]>>(
+        self,
+        aggs: E,
+        fill: UpsampleFill,
+    ) -> PolarsResult<LazyFrame> {
+        use polars_time::prelude::Label;
+
+        const LOWER_BOUNDARY: &str = "_lower_boundary";
+        const UPPER_BOUNDARY: &str = "_upper_boundary";
+
+        let Some(options) = self.dynamic_options.clone() else {
+            polars_bail!(InvalidOperation: "filling gaps requires a `group_by_dynamic`");
+        };
+        polars_ensure!(
+            options.label == Label::Left,
+            InvalidOperation: "filling gaps requires `label='left'`"
+        );
+        let keys = self
+            .keys
+            .iter()
+            .map(|key| match key {
+                Expr::Column(name) => Ok(name.clone()),
+                _ => polars_bail!(
+                    InvalidOperation: "filling gaps requires column names as `group_by`"
+                ),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let time_column = options.index_column.clone();
+
+        let mut out = self.agg(aggs);
+        let schema = out.collect_schema()?;
+        let fill = match fill {
+            UpsampleFill::All(strategy) => UpsampleFill::Columns(
+                schema
+                    .iter_names()
+                    .filter(|name| {
+                        *name != &time_column
+                            && !keys.contains(name)
+                            && !matches!(name.as_str(), LOWER_BOUNDARY | UPPER_BOUNDARY)
+                    })
+                    .map(|name| (name.clone(), strategy.clone()))
+                    .collect(),
+            ),
+            fill => fill,
+        };
+        let out = out.upsample(&time_column, options.every, &keys, true, fill)?;
+        if !options.include_boundaries {
+            return Ok(out);
+        }
+
+        let index = col(time_column.clone());
+        let time_dtype = schema.try_get(&time_column)?;
+        let upper = if time_dtype.is_integer() {
+            index.clone() + lit(options.period.nanoseconds()).cast(time_dtype.clone())
+        } else {
+            index
+                .clone()
+                .dt()
+                .offset_by(lit(options.period.to_string()))
+        };
+        Ok(out.with_columns([
+            coalesce(&[col(LOWER_BOUNDARY), index]),
+            coalesce(&[col(UPPER_BOUNDARY), upper]),
+        ]))
+    }
+}
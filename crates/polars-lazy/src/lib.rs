@@ -199,13 +199,19 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 extern crate core;
 
+pub mod catalog;
 #[cfg(feature = "dot_diagram")]
 mod dot;
 pub mod dsl;
+pub mod engine_stats;
 pub mod frame;
+#[cfg(feature = "materialized_view")]
+pub mod materialized_view;
 pub mod physical_plan;
 pub mod prelude;
 
 mod scan;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "unbounded")]
+pub mod unbounded;
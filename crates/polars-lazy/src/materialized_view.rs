@@ -0,0 +1,167 @@
+//! Experimental: query results over append-only datasets that are kept up to date incrementally.
+//!
+//! A [`MaterializedView`] stores the result of a [`ViewQuery`] over the files matching a glob.
+//! [`MaterializedView::refresh`] finds the rows the dataset gained since the last refresh (new
+//! files and rows appended to the end of known files), runs the query over only those rows and
+//! merges the outcome into the stored result. This is correct because the query is restricted
+//! to a filter, followed by a group-by with distributive aggregations: the aggregate over all
+//! rows can be computed from the aggregates over the old and the new rows.
+//!
+//! # Non-goals
+//! * Datasets that are not append-only: a file that lost rows is an error, and rows that are
+//!   changed in place are not noticed.
+//! * Aggregations that are not distributive, e.g. `mean`, `median` or `n_unique`: they would
+//!   need to keep more state than the result.
+//! * Persisting the view: a new view recomputes the result from all files.
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::path_utils::expand_paths;
+
+use crate::prelude::*;
+
+/// The query of a [`MaterializedView`]: the rows that pass `filter`, grouped by `group_by`
+/// and aggregated with `aggs`.
+///
+/// Without `group_by` the aggregations are computed over all rows, and without `aggs` either
+/// the view holds the rows that pass the filter. The supported aggregations are `sum`, `min`,
+/// `max`, `count`, `len`, `first` and `last`, optionally aliased.
+#[derive(Clone, Debug, Default)]
+pub struct ViewQuery {
+    pub filter: Option<Expr>,
+    pub group_by: Vec<Expr>,
+    pub aggs: Vec<Expr>,
+}
+
+type ScanFn = Box<dyn Fn(&Path) -> PolarsResult<LazyFrame> + Send + Sync>;
+
+/// A stored query result that is updated with the new rows of its dataset, see the
+/// [module docs](self).
+pub struct MaterializedView {
+    pattern: PathBuf,
+    scan: ScanFn,
+    query: ViewQuery,
+    combine: Vec<fn(Expr) -> Expr>,
+    rows_seen: BTreeMap<PathBuf, usize>,
+    result: Option<DataFrame>,
+}
+
+fn combiner(agg: &Expr) -> PolarsResult<fn(Expr) -> Expr> {
+    Ok(match agg {
+        Expr::Alias(agg, _) => return combiner(agg),
+        Expr::Len | Expr::Agg(AggExpr::Sum(_) | AggExpr::Count(..)) => Expr::sum,
+        Expr::Agg(AggExpr::Min { .. }) => Expr::min,
+        Expr::Agg(AggExpr::Max { .. }) => Expr::max,
+        Expr::Agg(AggExpr::First(_)) => Expr::first,
+        Expr::Agg(AggExpr::Last(_)) => Expr::last,
+        _ => polars_bail!(
+            InvalidOperation: "materialized views only support the aggregations sum, min, max, \
+            count, len, first and last, got {:?}", agg
+        ),
+    })
+}
+
+impl MaterializedView {
+    /// Create a view over the files matching the glob `pattern`, which are scanned by `scan`,
+    /// and compute its result over the files that exist now.
+    pub fn new(
+        pattern: impl Into<PathBuf>,
+        scan: impl Fn(&Path) -> PolarsResult<LazyFrame> + Send + Sync + 'static,
+        query: ViewQuery,
+    ) -> PolarsResult<Self> {
+        let combine = query
+            .aggs
+            .iter()
+            .map(combiner)
+            .collect::<PolarsResult<_>>()?;
+        let mut view = Self {
+            pattern: pattern.into(),
+            scan: Box::new(scan),
+            query,
+            combine,
+            rows_seen: Default::default(),
+            result: None,
+        };
+        view.refresh()?;
+        Ok(view)
+    }
+
+    /// The current result, or `None` if no file matched the pattern yet.
+    pub fn result(&self) -> Option<&DataFrame> {
+        self.result.as_ref()
+    }
+
+    /// Update the result with the rows the dataset gained since the last refresh.
+    pub fn refresh(&mut self) -> PolarsResult<Option<&DataFrame>> {
+        let paths = expand_paths(std::slice::from_ref(&self.pattern), true, None)?;
+
+        let mut new_rows = vec![];
+        let mut rows_seen = self.rows_seen.clone();
+        for path in paths.iter() {
+            let lf = (self.scan)(path)?;
+            let n_rows = lf.clone().select([len()]).collect()?[0].get(0)?;
+            let n_rows = n_rows.extract::<usize>().unwrap();
+            let seen = rows_seen.entry(path.clone()).or_default();
+            polars_ensure!(
+                n_rows >= *seen,
+                ComputeError: "the file '{}' of a materialized view lost rows, but its dataset \
+                must be append-only", path.display()
+            );
+            if n_rows > *seen {
+                new_rows.push(lf.slice(*seen as i64, (n_rows - *seen) as IdxSize));
+                *seen = n_rows;
+            }
+        }
+        if new_rows.is_empty() {
+            return Ok(self.result.as_ref());
+        }
+
+        let delta = self.run(concat(new_rows, UnionArgs::default())?)?;
+        let result = match &self.result {
+            None => delta,
+            Some(result) => self.merge(result.clone(), delta)?,
+        };
+        // Only remember the rows once they are part of the result, so that a failed refresh
+        // can be retried.
+        self.rows_seen = rows_seen;
+        Ok(Some(&*self.result.insert(result)))
+    }
+
+    fn run(&self, mut lf: LazyFrame) -> PolarsResult<DataFrame> {
+        let query = &self.query;
+        if let Some(filter) = &query.filter {
+            lf = lf.filter(filter.clone());
+        }
+        let lf = if !query.group_by.is_empty() {
+            lf.group_by_stable(&query.group_by).agg(&query.aggs)
+        } else if !query.aggs.is_empty() {
+            lf.select(&query.aggs)
+        } else {
+            lf
+        };
+        lf.collect()
+    }
+
+    fn merge(&self, result: DataFrame, delta: DataFrame) -> PolarsResult<DataFrame> {
+        if self.query.aggs.is_empty() && self.query.group_by.is_empty() {
+            return result.vstack(&delta);
+        }
+
+        let n_keys = self.query.group_by.len();
+        let names = delta.get_column_names_owned();
+        let aggs = names[n_keys..]
+            .iter()
+            .zip(&self.combine)
+            .map(|(name, combine)| combine(col(name.clone())))
+            .collect::<Vec<_>>();
+        let both = concat([result.lazy(), delta.lazy()], UnionArgs::default())?;
+        if n_keys > 0 {
+            let keys = names[..n_keys].iter().map(|name| col(name.clone()));
+            both.group_by_stable(keys.collect::<Vec<_>>()).agg(aggs)
+        } else {
+            both.select(aggs)
+        }
+        .collect()
+    }
+}
@@ -17,14 +17,26 @@ pub(crate) fn prepare_expression_for_context(
     expr: &Expr,
     dtype: &DataType,
     ctxt: Context,
+) -> PolarsResult<Arc<dyn PhysicalExpr>> {
+    prepare_expression_for_fields(&[Field::new(name, dtype.clone())], expr, ctxt)
+}
+
+/// Like [`prepare_expression_for_context`], for an expression that refers to several columns.
+pub(crate) fn prepare_expression_for_fields(
+    fields: &[Field],
+    expr: &Expr,
+    ctxt: Context,
 ) -> PolarsResult<Arc<dyn PhysicalExpr>> {
     let mut lp_arena = Arena::with_capacity(8);
     let mut expr_arena = Arena::with_capacity(10);
 
     // create a dummy lazyframe and run a very simple optimization run so that
     // type coercion and simplify expression optimizations run.
-    let column = Series::full_null(name, 0, dtype);
-    let df = column.into_frame();
+    let columns = fields
+        .iter()
+        .map(|f| Column::full_null(f.name().clone(), 0, f.dtype()))
+        .collect();
+    let df = DataFrame::new(columns)?;
     let input_schema = df.schema().clone();
     let lf = df
         .lazy()
@@ -39,5 +39,5 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
         JoinType::Full => true,
         _ => false,
     };
-    supported && !args.validation.needs_checks()
+    supported && !args.validation.needs_checks() && args.algorithm != JoinAlgorithm::Merge
 }
@@ -357,6 +357,7 @@ pub(crate) fn insert_streaming_nodes(
                             .all(|fld| allowed_dtype(fld.dtype(), string_cache)),
                         // We need to be able to sink to disk or produce the aggregate return dtype.
                         DataType::Unknown(_) => false,
+                        DataType::Extension(_, _, _) | DataType::Uuid => false,
                         #[cfg(feature = "dtype-decimal")]
                         DataType::Decimal(_, _) => false,
                         DataType::Int128 => false,
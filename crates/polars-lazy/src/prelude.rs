@@ -5,15 +5,24 @@ pub use polars_io::csv::write::CsvWriterOptions;
 pub use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 pub use polars_io::json::JsonWriterOptions;
+#[cfg(feature = "kafka")]
+pub use polars_io::kafka::{KafkaBatchReader, KafkaFormat, KafkaOptions};
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
-pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+pub use polars_mem_engine::{
+    ExecutorOverride, GroupByInput, JoinInput, register_executor_override, to_arrow_arrays,
+    unregister_executor_override,
+};
+pub use polars_ops::prelude::{JoinAlgorithm, JoinArgs, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud")]
 pub use polars_plan::client::prepare_cloud_plan;
 pub use polars_plan::dsl::AnonymousScanOptions;
-pub use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, NULL, Null};
+pub use polars_plan::plans::{
+    AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, NULL, Null, ScanPolicy,
+    register_scan_policy, unregister_scan_policy,
+};
 pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
 #[cfg(feature = "rolling_window_by")]
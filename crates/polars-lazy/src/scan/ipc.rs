@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::ipc::IpcScanOptions;
-use polars_io::{HiveOptions, RowIndex};
+use polars_io::{HiveOptions, RowIndex, Utf8Validation};
 
 use crate::prelude::*;
 
@@ -16,6 +16,8 @@ pub struct ScanArgsIpc {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Validate that the string columns are UTF-8, see [`Self::with_trusted_utf8`].
+    pub validate_utf8: Utf8Validation,
 }
 
 impl Default for ScanArgsIpc {
@@ -28,10 +30,22 @@ impl Default for ScanArgsIpc {
             cloud_options: Default::default(),
             hive_options: Default::default(),
             include_file_paths: None,
+            validate_utf8: Default::default(),
         }
     }
 }
 
+impl ScanArgsIpc {
+    /// Do not validate that the string columns are UTF-8.
+    ///
+    /// # Safety
+    /// The string columns of every scanned file must be valid UTF-8.
+    pub unsafe fn with_trusted_utf8(mut self) -> Self {
+        self.validate_utf8 = unsafe { Utf8Validation::trusted() };
+        self
+    }
+}
+
 #[derive(Clone)]
 struct LazyIpcReader {
     args: ScanArgsIpc,
@@ -51,7 +65,9 @@ impl LazyFileListReader for LazyIpcReader {
     fn finish(self) -> PolarsResult<LazyFrame> {
         let args = self.args;
 
-        let options = IpcScanOptions {};
+        let options = IpcScanOptions {
+            validate_utf8: args.validate_utf8,
+        };
 
         let lf: LazyFrame = DslBuilder::scan_ipc(
             self.sources,
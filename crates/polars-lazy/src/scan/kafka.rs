@@ -0,0 +1,53 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_io::kafka::KafkaBatchReader;
+
+use crate::prelude::*;
+
+/// An [`AnonymousScan`] of the pending micro-batch of a Kafka topic.
+struct KafkaScan {
+    reader: Arc<KafkaBatchReader>,
+}
+
+impl AnonymousScan for KafkaScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let df = self.reader.next_batch()?;
+        match &scan_opts.with_columns {
+            Some(columns) => df.select(columns.iter().cloned()),
+            None => Ok(df),
+        }
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.reader.schema().clone())
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Scan the pending micro-batch of a Kafka topic, see [`KafkaBatchReader`].
+    ///
+    /// When the [`LazyFrame`] is collected, it reads a batch of at most
+    /// [`KafkaOptions::max_messages`] messages, waiting at most [`KafkaOptions::max_wait`]. Its
+    /// offsets are not committed: call [`KafkaBatchReader::commit`] once the result of the query
+    /// was handled. Until then, every scan, also of a later query, sees the same batch, so a
+    /// failed query can be run again without losing messages.
+    ///
+    /// A slice of the query does not limit how many messages are read.
+    pub fn scan_kafka(reader: Arc<KafkaBatchReader>) -> PolarsResult<Self> {
+        let args = ScanArgsAnonymous {
+            schema: Some(reader.schema().clone()),
+            name: "KAFKA SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(Arc::new(KafkaScan { reader }), args)
+    }
+}
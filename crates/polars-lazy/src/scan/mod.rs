@@ -4,6 +4,8 @@ pub(super) mod csv;
 pub(super) mod file_list_reader;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
+#[cfg(feature = "kafka")]
+mod kafka;
 #[cfg(feature = "json")]
 pub(super) mod ndjson;
 #[cfg(feature = "parquet")]
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParallelStrategy;
-use polars_io::{HiveOptions, RowIndex};
+use polars_io::{HiveOptions, RowIndex, Utf8Validation};
 
 use crate::prelude::*;
 
@@ -15,6 +15,8 @@ pub struct ScanArgsParquet {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub use_statistics: bool,
+    /// Check that the string columns are valid UTF-8, see [`Self::with_trusted_utf8`].
+    pub validate_utf8: Utf8Validation,
     pub schema: Option<SchemaRef>,
     pub low_memory: bool,
     pub rechunk: bool,
@@ -34,6 +36,7 @@ impl Default for ScanArgsParquet {
             cloud_options: None,
             hive_options: Default::default(),
             use_statistics: true,
+            validate_utf8: Default::default(),
             schema: None,
             rechunk: false,
             low_memory: false,
@@ -45,6 +48,17 @@ impl Default for ScanArgsParquet {
     }
 }
 
+impl ScanArgsParquet {
+    /// Do not check that the string columns are valid UTF-8.
+    ///
+    /// # Safety
+    /// The string columns of every scanned file must be valid UTF-8.
+    pub unsafe fn with_trusted_utf8(mut self) -> Self {
+        self.validate_utf8 = unsafe { Utf8Validation::trusted() };
+        self
+    }
+}
+
 #[derive(Clone)]
 struct LazyParquetReader {
     args: ScanArgsParquet,
@@ -75,6 +89,7 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.low_memory,
             self.args.cloud_options,
             self.args.use_statistics,
+            self.args.validate_utf8,
             self.args.schema,
             self.args.hive_options,
             self.args.glob,
@@ -451,6 +451,7 @@ fn take_aggregations() -> PolarsResult<()> {
                             multithreaded: true,
                             maintain_order: false,
                             limit: None,
+                            nan_position: NanPosition::Largest,
                         })
                         .head(Some(2)),
                 )
@@ -491,6 +492,7 @@ fn test_take_consistency() -> PolarsResult<()> {
                 multithreaded: true,
                 maintain_order: false,
                 limit: None,
+                nan_position: NanPosition::Largest,
             })
             .get(lit(0))])
         .collect()?;
@@ -510,6 +512,7 @@ fn test_take_consistency() -> PolarsResult<()> {
                 multithreaded: true,
                 maintain_order: false,
                 limit: None,
+                nan_position: NanPosition::Largest,
             })
             .get(lit(0))])
         .collect()?;
@@ -530,6 +533,7 @@ fn test_take_consistency() -> PolarsResult<()> {
                     multithreaded: true,
                     maintain_order: false,
                     limit: None,
+                    nan_position: NanPosition::Largest,
                 })
                 .get(lit(0))
                 .alias("1"),
@@ -542,6 +546,7 @@ fn test_take_consistency() -> PolarsResult<()> {
                             multithreaded: true,
                             maintain_order: false,
                             limit: None,
+                            nan_position: NanPosition::Largest,
                         })
                         .get(lit(0)),
                 )
@@ -615,3 +620,25 @@ fn test_anonymous_function_returns_scalar_all_null_20679() {
 
     assert_eq!(grouped_df.get_columns()[1].dtype(), &DataType::Null);
 }
+
+#[test]
+#[cfg(feature = "rolling_window")]
+fn test_rolling_map_closure() -> PolarsResult<()> {
+    let df = df!["a" => [1.0, 4.0, 2.0, 8.0, 3.0]]?;
+    let options = RollingOptionsFixedWindow {
+        window_size: 3,
+        ..Default::default()
+    };
+    let sum = |s: &Series| s.sum_reduce().unwrap().into_series(s.name().clone());
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .rolling_map(Arc::new(sum), GetOutput::same_type(), options.clone())
+                .alias("map"),
+            col("a").rolling_sum(options).alias("sum"),
+        ])
+        .collect()?;
+    assert!(out.column("map")?.equals_missing(out.column("sum")?));
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use super::*;
+use crate::catalog::{Catalog, FileCatalog};
+
+#[test]
+fn test_file_catalog() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join(format!("polars-catalog-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("sales/items"))?;
+    std::fs::write(
+        root.join("sales/orders.csv"),
+        "id,amount\n1,10\n2,20\n3,30\n",
+    )?;
+    std::fs::write(root.join("sales/items/0.csv"), "id,item\n1,a\n")?;
+    std::fs::write(root.join("sales/items/1.csv"), "id,item\n2,b\n")?;
+    let catalog = FileCatalog::new(&root);
+
+    assert_eq!(catalog.table_names()?, ["sales.items", "sales.orders"]);
+    assert!(catalog.resolve("sales.customers")?.is_none());
+
+    let orders = catalog.resolve("sales.orders")?.unwrap();
+    assert_eq!(orders.statistics.num_rows, Some(3));
+    assert_eq!(orders.schema.len(), 2);
+
+    // The statistics of the catalog are used when planning the scan.
+    let plan = orders.into_lazy().to_alp()?;
+    let IR::Scan { file_info, .. } = plan.lp_arena.get(plan.lp_top) else {
+        panic!("expected a scan")
+    };
+    assert_eq!(file_info.row_estimation, (Some(3), 3));
+
+    let out = catalog
+        .scan("sales.orders")?
+        .join(
+            catalog.scan("sales.items")?,
+            [col("id")],
+            [col("id")],
+            JoinType::Inner.into(),
+        )
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(out.column("item")?.str()?.get(1), Some("b"));
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
@@ -380,6 +380,19 @@ fn test_parquet_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_parquet_no_utf8_validation() -> PolarsResult<()> {
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let scan = |args| LazyFrame::scan_parquet(FOODS_PARQUET, args)?.collect();
+    // SAFETY: the file is valid UTF-8.
+    let df = scan(unsafe { ScanArgsParquet::default().with_trusted_utf8() })?;
+    assert_eq!(df.column("category")?.dtype(), &DataType::String);
+    assert!(df.equals(&scan(ScanArgsParquet::default())?));
+
+    Ok(())
+}
+
 #[test]
 fn test_scan_parquet_limit_9001() {
     init_files();
@@ -420,6 +433,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             cloud_options: None,
             hive_options: Default::default(),
             include_file_paths: None,
+            validate_utf8: Default::default(),
         },
     )?
     .collect()?;
@@ -711,3 +725,133 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_csv_quoted_newlines_parallel() -> PolarsResult<()> {
+    // The newlines in the quoted fields make the row boundaries that the parallel chunks must
+    // start at ambiguous.
+    let n_rows = 10_000;
+    let csv = format!("a,b\n{}", "1,\"x\ny\n\"\"z\"\"\"\n".repeat(n_rows));
+    let path =
+        std::env::temp_dir().join(format!("polars-quoted-newlines-{}.csv", std::process::id()));
+    std::fs::write(&path, &csv)?;
+
+    let df = CsvReadOptions::default()
+        .with_n_threads(Some(4))
+        .into_reader_with_file_handle(std::io::Cursor::new(csv.as_bytes()))
+        .finish()?;
+    assert_eq!(df.height(), n_rows);
+    let b = df.column("b")?.str()?;
+    assert!(b.into_no_null_iter().all(|v| v == "x\ny\n\"z\""));
+
+    // Counting the rows of a scan goes through the parallel line counter.
+    let out = LazyCsvReader::new(&path)
+        .finish()?
+        .select([len()])
+        .collect()?;
+    assert_eq!(
+        out.column("len")?.get(0)?,
+        AnyValue::from(n_rows as IdxSize)
+    );
+
+    let df = LazyCsvReader::new(&path).finish()?.collect()?;
+    assert_eq!(df.height(), n_rows);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_result_cache() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join(format!("polars-result-cache-{}.csv", std::process::id()));
+    std::fs::write(&path, "a\n1\n2\n")?;
+    let query = || LazyCsvReader::new(&path).finish();
+    let cache = ResultCache::new(Default::default());
+
+    let out = cache.collect(query()?.select([col("a").sum()]))?;
+    assert!(out.equals(&df!["a" => [3i64]]?));
+    let cached = cache.collect(query()?.select([col("a").sum()]))?;
+    assert!(cached.equals(&out));
+    assert_eq!(cache.len(), 1);
+
+    // The query is run again once the file changes.
+    std::fs::write(&path, "a\n1\n2\n3\n")?;
+    let out = cache.collect(query()?.select([col("a").sum()]))?;
+    assert!(out.equals(&df!["a" => [6i64]]?));
+    assert_eq!(cache.len(), 2);
+
+    // Queries with UDFs are not cached.
+    let udf = col("a").map(|c| Ok(Some(c)), GetOutput::same_type());
+    cache.collect(query()?.select([udf]))?;
+    assert_eq!(cache.len(), 2);
+
+    // Neither are queries with plugins, which may have state.
+    struct Identity;
+    impl StaticPlugin for Identity {
+        fn evaluate(&self, inputs: &mut [Column], _kwargs: &[u8]) -> PolarsResult<Column> {
+            Ok(inputs[0].clone())
+        }
+
+        fn output_field(&self, input_fields: &[Field], _kwargs: &[u8]) -> PolarsResult<Field> {
+            Ok(input_fields[0].clone())
+        }
+    }
+    register_static_plugin("result_cache_identity", Identity);
+    let plugin = static_plugin_function("result_cache_identity", [col("a")], &[])?;
+    cache.collect(query()?.select([plugin]))?;
+    assert_eq!(cache.len(), 2);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_policy() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join(format!("polars-scan-policy-{}.csv", std::process::id()));
+    std::fs::write(
+        &path,
+        "tenant,email,x\n1,a@x.com,1\n2,b@y.com,2\n1,c@z.com,3\n",
+    )?;
+    let query = || LazyCsvReader::new(&path).finish();
+
+    // The policies only apply to scans with a `tenant` column, so other tests are not affected.
+    register_scan_policy(|_, schema| {
+        if !schema.contains("tenant") {
+            return Ok(Default::default());
+        }
+        Ok(ScanPolicy {
+            filters: vec![col("tenant").eq(lit(1))],
+            masks: vec![lit("***").alias("email")],
+        })
+    });
+    let all = query()?.collect();
+    // A filter of the query on a masked column sees the masked values.
+    let by_email = query()?.filter(col("email").eq(lit("a@x.com"))).collect();
+
+    register_scan_policy(|_, schema| {
+        let masks = if schema.contains("tenant") {
+            vec![lit(1).alias("extra")]
+        } else {
+            vec![]
+        };
+        Ok(ScanPolicy {
+            masks,
+            ..Default::default()
+        })
+    });
+    let added_column = query()?.collect();
+    assert!(unregister_scan_policy());
+
+    let expected = df![
+        "tenant" => [1i64, 1],
+        "email" => ["***", "***"],
+        "x" => [1i64, 3],
+    ]?;
+    assert!(all?.equals(&expected));
+    assert_eq!(by_email?.height(), 0);
+    assert!(added_column.is_err());
+    assert_eq!(query()?.collect()?.height(), 3);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
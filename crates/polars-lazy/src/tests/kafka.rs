@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use polars_core::error::to_compute_err;
+use polars_io::kafka::rdkafka::config::ClientConfig;
+use polars_io::kafka::rdkafka::mocking::MockCluster;
+use polars_io::kafka::rdkafka::producer::{BaseProducer, BaseRecord, Producer};
+
+use super::*;
+
+#[test]
+fn test_scan_kafka_commits_after_the_query() -> PolarsResult<()> {
+    let cluster = MockCluster::new(1).map_err(to_compute_err)?;
+    cluster.create_topic("t", 1, 1).map_err(to_compute_err)?;
+    let producer: BaseProducer = ClientConfig::new()
+        .set("bootstrap.servers", cluster.bootstrap_servers())
+        .create()
+        .map_err(to_compute_err)?;
+    for a in 1..=4 {
+        let payload = format!(r#"{{"a": {a}}}"#);
+        producer
+            .send(BaseRecord::<(), _>::to("t").payload(&payload))
+            .map_err(|(e, _)| to_compute_err(e))?;
+    }
+    producer
+        .flush(Duration::from_secs(10))
+        .map_err(to_compute_err)?;
+
+    let options = KafkaOptions {
+        max_messages: 2,
+        max_wait: Duration::from_secs(30),
+        config: vec![("auto.offset.reset".into(), "earliest".into())],
+        ..Default::default()
+    };
+    let schema = Arc::new(Schema::from_iter([Field::new("a".into(), DataType::Int64)]));
+    let reader = Arc::new(KafkaBatchReader::try_new(
+        &cluster.bootstrap_servers(),
+        "t",
+        schema,
+        options,
+    )?);
+    let values = |df: DataFrame| -> PolarsResult<Vec<Option<i64>>> {
+        Ok(df.column("a")?.i64()?.into_iter().collect())
+    };
+
+    // Both scans of the query see the same batch.
+    let lf = LazyFrame::scan_kafka(reader.clone())?;
+    let out = concat([lf.clone(), lf], UnionArgs::default())?.collect()?;
+    assert_eq!(values(out)?, [Some(1), Some(2), Some(1), Some(2)]);
+
+    // Without a commit, a query gets the batch again.
+    let out = LazyFrame::scan_kafka(reader.clone())?.collect()?;
+    assert_eq!(values(out)?, [Some(1), Some(2)]);
+
+    reader.commit()?;
+    let out = LazyFrame::scan_kafka(reader.clone())?.collect()?;
+    assert_eq!(values(out)?, [Some(3), Some(4)]);
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use polars_core::prelude::*;
+
+use crate::materialized_view::{MaterializedView, ViewQuery};
+use crate::prelude::*;
+
+fn scan(path: &Path) -> PolarsResult<LazyFrame> {
+    LazyCsvReader::new(path).finish()
+}
+
+fn query() -> ViewQuery {
+    ViewQuery {
+        filter: Some(col("v").gt(lit(0))),
+        group_by: vec![col("k")],
+        aggs: vec![
+            col("v").sum(),
+            col("v").max().alias("v_max"),
+            len().alias("n"),
+        ],
+    }
+}
+
+#[test]
+fn test_materialized_view_refresh() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join(format!("polars-mv-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let glob = dir.join("*.csv");
+    std::fs::write(dir.join("0.csv"), "k,v\na,1\nb,2\na,-1\n")?;
+
+    let mut view = MaterializedView::new(&glob, scan, query())?;
+    let expected = df![
+        "k" => ["a", "b"],
+        "v" => [1i64, 2],
+        "v_max" => [1i64, 2],
+        "n" => [1 as IdxSize, 1],
+    ]?;
+    assert!(view.result().unwrap().equals(&expected));
+
+    // Rows appended to a known file and a new file.
+    std::fs::write(dir.join("0.csv"), "k,v\na,1\nb,2\na,-1\na,5\n")?;
+    std::fs::write(dir.join("1.csv"), "k,v\nc,3\nb,4\n")?;
+    let out = view.refresh()?.unwrap().sort(["k"], Default::default())?;
+    let full = scan(&glob)?
+        .filter(col("v").gt(lit(0)))
+        .group_by([col("k")])
+        .agg(query().aggs)
+        .sort(["k"], Default::default())
+        .collect()?;
+    assert!(out.equals(&full));
+
+    // A refresh without new rows keeps the result.
+    let before = view.result().unwrap().clone();
+    assert!(view.refresh()?.unwrap().equals(&before));
+
+    // The dataset must be append-only.
+    std::fs::write(dir.join("1.csv"), "k,v\nc,3\n")?;
+    assert!(view.refresh().is_err());
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_materialized_view_unsupported_agg() {
+    let query = ViewQuery {
+        aggs: vec![col("v").mean()],
+        ..Default::default()
+    };
+    let out = MaterializedView::new("does-not-exist/*.csv", scan, query);
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+}
@@ -1,10 +1,16 @@
 mod aggregations;
 mod arity;
+#[cfg(feature = "csv")]
+mod catalog;
 #[cfg(all(feature = "strings", feature = "cse"))]
 mod cse;
 #[cfg(feature = "parquet")]
 mod io;
+#[cfg(feature = "kafka")]
+mod kafka;
 mod logical;
+#[cfg(all(feature = "materialized_view", feature = "csv"))]
+mod materialized_view;
 mod optimization_checks;
 #[cfg(all(feature = "strings", feature = "cse"))]
 mod pdsh;
@@ -14,6 +20,8 @@ mod queries;
 mod schema;
 #[cfg(feature = "streaming")]
 mod streaming;
+#[cfg(feature = "unbounded")]
+mod unbounded;
 
 fn get_arenas() -> (Arena<AExpr>, Arena<IR>) {
     let expr_arena = Arena::with_capacity(16);
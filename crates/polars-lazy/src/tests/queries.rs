@@ -1667,6 +1667,7 @@ fn test_single_group_result() -> PolarsResult<()> {
                 multithreaded: true,
                 maintain_order: false,
                 limit: None,
+                nan_position: NanPosition::Largest,
             })
             .over([col("a")])])
         .collect()?;
@@ -1948,3 +1949,241 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+fn test_static_plugin() -> PolarsResult<()> {
+    // adds the little-endian i32 in the kwargs to its input
+    struct AddOffset;
+
+    impl StaticPlugin for AddOffset {
+        fn evaluate(&self, inputs: &mut [Column], kwargs: &[u8]) -> PolarsResult<Column> {
+            let offset = i32::from_le_bytes(kwargs.try_into().unwrap());
+            let s = inputs[0].as_materialized_series();
+            Ok((s.cast(&DataType::Int32)? + offset).into_column())
+        }
+
+        fn output_field(&self, input_fields: &[Field], _kwargs: &[u8]) -> PolarsResult<Field> {
+            Ok(Field::new(input_fields[0].name().clone(), DataType::Int32))
+        }
+    }
+
+    register_static_plugin("add_offset", AddOffset);
+    let plugin = static_plugin_function("add_offset", [col("a")], &10i32.to_le_bytes())?;
+    let q = df![
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "x"],
+    ]?
+    .lazy()
+    .select([col("b"), plugin.clone().alias("c")]);
+
+    assert_eq!(q.clone().collect_schema()?.get("c"), Some(&DataType::Int32));
+    let out = q.collect()?;
+    assert!(out.equals(&df![
+        "b" => ["x", "y", "x"],
+        "c" => [11, 12, 13],
+    ]?));
+
+    assert!(static_plugin_function("unknown", [col("a")], &[]).is_err());
+    assert!(unregister_static_plugin("add_offset"));
+    assert!(
+        df!["a" => [1i64]]?
+            .lazy()
+            .select([plugin])
+            .collect()
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_executor_override() -> PolarsResult<()> {
+    // claims the joins on `override_key`, returning the number of left keys
+    struct CountKeys;
+
+    impl ExecutorOverride for CountKeys {
+        fn name(&self) -> &str {
+            "count_keys"
+        }
+
+        fn join(&self, input: &JoinInput) -> PolarsResult<Option<DataFrame>> {
+            if input.left_on[0].name() != "override_key" {
+                return Ok(None);
+            }
+            let keys = to_arrow_arrays(input.left_on);
+            Ok(Some(df!["n_keys" => [keys[0].len() as u32]]?))
+        }
+    }
+
+    let left = df!["override_key" => [1, 2, 3], "a" => [1, 2, 3]]?.lazy();
+    let right = df!["override_key" => [1, 2], "a" => [1, 2]]?.lazy();
+    let join = |left: LazyFrame, right: LazyFrame, on: &str| {
+        left.join(right, [col(on)], [col(on)], JoinType::Inner.into())
+            .collect()
+    };
+
+    register_executor_override(CountKeys);
+    let claimed = join(left.clone(), right.clone(), "override_key");
+    let declined = join(left, right, "a");
+    assert!(unregister_executor_override());
+    assert!(claimed?.equals(&df!["n_keys" => [3u32]]?));
+    assert_eq!(declined?.height(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_error_context() -> PolarsResult<()> {
+    let df = df!["a" => ["1", "x"], "b" => [1, 2]]?;
+    let err = df
+        .lazy()
+        .select([col("a").strict_cast(DataType::Int64) + col("b")])
+        .collect()
+        .unwrap_err();
+
+    let context = err.error_context().unwrap();
+    assert_eq!(context.operator, "select");
+    assert_eq!(context.columns, ["a", "b"]);
+    assert_eq!(context.expressions.len(), 1);
+    let msg = err.to_string();
+    assert!(msg.contains("Error originated in plan node 'select'"));
+    assert!(matches!(err.get_err(), PolarsError::InvalidOperation(_)));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_engine_stats() -> PolarsResult<()> {
+    let _holder = StringCacheHolder::hold();
+    df!["a" => ["engine-stats"]]?
+        .lazy()
+        .select([col("a").cast(DataType::Categorical(None, Default::default()))])
+        .collect()?;
+
+    let stats = crate::engine_stats::engine_stats();
+    assert_eq!(
+        stats.thread_pool_size,
+        polars_core::POOL.current_num_threads()
+    );
+    assert!(stats.string_cache_enabled);
+    assert!(stats.string_cache_len >= 1);
+    assert!(stats.string_cache_bytes >= "engine-stats".len());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "random", feature = "range", feature = "rank"))]
+fn test_sample_stratified() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "a", "b", "b", "c"],
+        "v" => [1, 2, 3, 4, 5, 6, 7],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .sample_n_stratified([col("g")], 3, Some(0))
+        .collect()?;
+    let g = out.column("g")?.str()?;
+    assert_eq!(
+        g.into_no_null_iter().collect::<Vec<_>>(),
+        &["a", "a", "a", "b", "b", "c"]
+    );
+    #[cfg(feature = "new_streaming")]
+    {
+        let streamed = df
+            .clone()
+            .lazy()
+            .sample_n_stratified([col("g")], 3, Some(0))
+            .collect_with_engine(Engine::Streaming)?;
+        assert!(streamed.equals(&out));
+    }
+
+    let out = df
+        .clone()
+        .lazy()
+        .sample_frac_stratified([col("g")], lit(0.5), Some(0))?
+        .collect()?;
+    let g = out.column("g")?.str()?;
+    assert_eq!(g.into_no_null_iter().collect::<Vec<_>>(), &["a", "a", "b"]);
+    // The sampled rows keep their order.
+    let v = out
+        .column("v")?
+        .i32()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    assert!(v.is_sorted());
+
+    // The internal key column must not overwrite a column of the input.
+    let taken = df
+        .lazy()
+        .with_column(lit(1).alias("__POLARS_STRATIFIED_KEY"));
+    assert!(
+        taken
+            .sample_frac_stratified([col("g")], lit(0.5), Some(0))
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "dynamic_group_by",
+    feature = "range",
+    feature = "interpolate_by",
+    feature = "offset_by"
+))]
+fn test_upsample_fill() -> PolarsResult<()> {
+    use polars_time::Duration;
+
+    let df = df![
+        "time" => [0i64, 2, 3, 0, 3],
+        "group" => ["a", "a", "a", "b", "b"],
+        "value" => [Some(1.0), Some(3.0), None, Some(10.0), Some(40.0)],
+    ]?;
+    let out = df
+        .lazy()
+        .upsample(
+            "time",
+            Duration::parse("1i"),
+            &["group".into()],
+            true,
+            UpsampleFill::All(UpsampleFillStrategy::Interpolate),
+        )?
+        .collect()?;
+    let expected = df![
+        "time" => [0i64, 1, 2, 3, 0, 1, 2, 3],
+        "group" => ["a", "a", "a", "a", "b", "b", "b", "b"],
+        // The null that is in the data is kept.
+        "value" => [
+            Some(1.0), Some(2.0), Some(3.0), None, Some(10.0), Some(20.0), Some(30.0), Some(40.0),
+        ],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let df = df![
+        "time" => [0i64, 1, 4],
+        "value" => [1, 2, 3],
+    ]?;
+    let options = DynamicGroupOptions {
+        every: Duration::parse("1i"),
+        period: Duration::parse("1i"),
+        offset: Duration::parse("0i"),
+        include_boundaries: true,
+        ..Default::default()
+    };
+    let out = df
+        .lazy()
+        .group_by_dynamic(col("time"), Vec::<Expr>::new(), options)
+        .agg_fill_gaps(
+            [col("value").sum()],
+            UpsampleFill::All(UpsampleFillStrategy::Forward),
+        )?
+        .collect()?;
+    let expected = df![
+        "_lower_boundary" => [0i64, 1, 2, 3, 4],
+        "_upper_boundary" => [1i64, 2, 3, 4, 5],
+        "time" => [0i64, 1, 2, 3, 4],
+        "value" => [1, 2, 2, 2, 3],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use super::*;
+use crate::unbounded::*;
+
+struct Batches(VecDeque<DataFrame>);
+
+impl UnboundedSource for Batches {
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        Ok(self.0.pop_front())
+    }
+}
+
+fn batch(times_ms: &[i64], values: &[i32]) -> DataFrame {
+    let time = Int64Chunked::new("time".into(), times_ms)
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_column();
+    DataFrame::new(vec![time, Column::new("value".into(), values)]).unwrap()
+}
+
+#[test]
+fn test_unbounded_tumbling_windows() -> PolarsResult<()> {
+    let source = Batches(VecDeque::from([
+        batch(&[0, 500, 1200], &[1, 2, 3]),
+        batch(&[1500, 2600], &[4, 5]),
+        // late for the window [0, 1000), which was closed by the previous batch
+        batch(&[900, 2700], &[6, 7]),
+    ]));
+    let options = WindowOptions {
+        time_column: "time".into(),
+        every: Duration::from_secs(1),
+        allowed_lateness: Duration::from_millis(500),
+    };
+    let mut emitted = vec![];
+    let sink = |window: Window, df: DataFrame| -> PolarsResult<()> {
+        let sum = df.column("value")?.i32()?.get(0).unwrap();
+        emitted.push((window.start, sum));
+        Ok(())
+    };
+    let mut query =
+        UnboundedQuery::new(source, options, |lf| lf.select([col("value").sum()]), sink);
+    while query.step()? {}
+    assert_eq!(query.late_rows(), 1);
+    query.finish()?;
+
+    assert_eq!(emitted, [(0, 3), (1000, 7), (2000, 12)]);
+    Ok(())
+}
@@ -0,0 +1,262 @@
+//! Experimental: queries over sources that never end.
+//!
+//! An [`UnboundedQuery`] reads batches from an [`UnboundedSource`], assigns their rows to
+//! tumbling windows of event time, and once the watermark passes the end of a window, runs an
+//! ordinary lazy query over the rows of that window and hands the result to an
+//! [`UnboundedSink`]. The watermark is the largest event time seen so far minus the allowed
+//! lateness; rows that arrive for a window that was already closed are dropped and counted
+//! in [`UnboundedQuery::late_rows`].
+//!
+//! # Non-goals
+//! This mode is deliberately small, and does not (and is not planned to):
+//! * combine several unbounded sources, e.g. join two streams;
+//! * emit results before a window closes, or update a window after it closed;
+//! * spill the rows of open windows to disk: they are kept in memory;
+//! * checkpoint its state: after a restart, the open windows are empty, and delivery to the
+//!   sink is at least once at best (depending on the source);
+//! * run the query of a window with the streaming engine: every window is collected in
+//!   memory.
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// A source of batches that never ends.
+pub trait UnboundedSource: Send {
+    /// The next batch, or `None` if there are no new rows at the moment.
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>>;
+}
+
+#[cfg(feature = "kafka")]
+impl UnboundedSource for polars_io::kafka::KafkaBatchReader {
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let df = polars_io::kafka::KafkaBatchReader::next_batch(self)?;
+        // From here on the rows are kept in the open windows, which are not checkpointed.
+        self.commit()?;
+        Ok((df.height() > 0).then_some(df))
+    }
+}
+
+/// The half-open interval of event time `[start, end)` of a window, in the time unit of the
+/// time column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Window {
+    pub start: i64,
+    pub end: i64,
+}
+
+/// Receives the result of the query of every window, in the order the windows close.
+pub trait UnboundedSink: Send {
+    fn emit(&mut self, window: Window, df: DataFrame) -> PolarsResult<()>;
+}
+
+/// A callback is the simplest sink.
+impl<F> UnboundedSink for F
+where
+    F: FnMut(Window, DataFrame) -> PolarsResult<()> + Send,
+{
+    fn emit(&mut self, window: Window, df: DataFrame) -> PolarsResult<()> {
+        self(window, df)
+    }
+}
+
+/// Appends a Parquet file for every window to a directory, named after the start of the
+/// window (`part-<start>.parquet`), so that the directory can be scanned as a dataset.
+#[cfg(feature = "parquet")]
+pub struct ParquetDatasetSink {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(feature = "parquet")]
+impl ParquetDatasetSink {
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> PolarsResult<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl UnboundedSink for ParquetDatasetSink {
+    fn emit(&mut self, window: Window, mut df: DataFrame) -> PolarsResult<()> {
+        use polars_io::parquet::write::ParquetWriter;
+
+        // Written under a temporary name, so that a scan of the dataset never sees a
+        // partially written file.
+        let path = self.dir.join(format!("part-{:020}.parquet", window.start));
+        let tmp_path = path.with_extension("parquet.tmp");
+        ParquetWriter::new(std::fs::File::create(&tmp_path)?).finish(&mut df)?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+}
+
+/// The windows of an [`UnboundedQuery`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WindowOptions {
+    /// The `Datetime` column with the event time of the rows.
+    pub time_column: PlSmallStr,
+    /// The length of the tumbling windows.
+    pub every: Duration,
+    /// How far the event time can be behind the largest event time seen, before its window
+    /// is closed.
+    pub allowed_lateness: Duration,
+}
+
+type WindowQuery = Box<dyn Fn(LazyFrame) -> LazyFrame + Send>;
+
+/// Runs a query over each window of an [`UnboundedSource`], see the [module docs](self).
+pub struct UnboundedQuery<S, K> {
+    source: S,
+    sink: K,
+    options: WindowOptions,
+    query: WindowQuery,
+    open: BTreeMap<i64, Vec<DataFrame>>,
+    time_unit: Option<TimeUnit>,
+    watermark: Option<i64>,
+    late_rows: usize,
+}
+
+impl<S: UnboundedSource, K: UnboundedSink> UnboundedQuery<S, K> {
+    /// Create a query that runs `query` on the rows of every window of `source`, and emits
+    /// the results to `sink`.
+    pub fn new<F>(source: S, options: WindowOptions, query: F, sink: K) -> Self
+    where
+        F: Fn(LazyFrame) -> LazyFrame + Send + 'static,
+    {
+        Self {
+            source,
+            sink,
+            options,
+            query: Box::new(query),
+            open: BTreeMap::new(),
+            time_unit: None,
+            watermark: None,
+            late_rows: 0,
+        }
+    }
+
+    /// The number of rows that were dropped because their window was already closed, or
+    /// because they had no event time.
+    pub fn late_rows(&self) -> usize {
+        self.late_rows
+    }
+
+    /// Read one batch from the source, and emit the windows that it closes. Returns whether
+    /// the source had a batch.
+    pub fn step(&mut self) -> PolarsResult<bool> {
+        let Some(batch) = self.source.next_batch()? else {
+            return Ok(false);
+        };
+        self.push(batch)?;
+        self.close_windows(|window, watermark| window.end <= watermark)?;
+        Ok(true)
+    }
+
+    /// Step until `should_stop` returns true, sleeping for `poll_interval` whenever the
+    /// source has no new rows. The windows that are still open are not emitted: call
+    /// [`finish`](Self::finish) for that.
+    pub fn run(
+        &mut self,
+        poll_interval: Duration,
+        mut should_stop: impl FnMut() -> bool,
+    ) -> PolarsResult<()> {
+        while !should_stop() {
+            if !self.step()? {
+                std::thread::sleep(poll_interval);
+            }
+        }
+        Ok(())
+    }
+
+    /// Emit all windows that are still open, regardless of the watermark, and return the
+    /// source and the sink.
+    pub fn finish(mut self) -> PolarsResult<(S, K)> {
+        self.close_windows(|_, _| true)?;
+        Ok((self.source, self.sink))
+    }
+
+    fn every(&self, time_unit: TimeUnit) -> PolarsResult<i64> {
+        let every = match time_unit {
+            TimeUnit::Nanoseconds => self.options.every.as_nanos(),
+            TimeUnit::Microseconds => self.options.every.as_micros(),
+            TimeUnit::Milliseconds => self.options.every.as_millis(),
+        };
+        polars_ensure!(
+            every > 0,
+            InvalidOperation: "the windows of an unbounded query cannot be empty"
+        );
+        Ok(every as i64)
+    }
+
+    fn lateness(&self, time_unit: TimeUnit) -> i64 {
+        let lateness = match time_unit {
+            TimeUnit::Nanoseconds => self.options.allowed_lateness.as_nanos(),
+            TimeUnit::Microseconds => self.options.allowed_lateness.as_micros(),
+            TimeUnit::Milliseconds => self.options.allowed_lateness.as_millis(),
+        };
+        lateness as i64
+    }
+
+    /// Assign the rows of `batch` to their windows, and advance the watermark.
+    fn push(&mut self, batch: DataFrame) -> PolarsResult<()> {
+        let time = batch.column(&self.options.time_column)?.datetime()?;
+        match self.time_unit {
+            Some(time_unit) => polars_ensure!(
+                time_unit == time.time_unit(),
+                SchemaMismatch: "the time column changed from {} to {}", time_unit, time.time_unit()
+            ),
+            None => self.time_unit = Some(time.time_unit()),
+        }
+        let every = self.every(time.time_unit())?;
+        let lateness = self.lateness(time.time_unit());
+        let starts = time
+            .physical()
+            .apply_values(|t| t.div_euclid(every) * every);
+
+        let mut new_starts = starts.iter().flatten().collect::<Vec<_>>();
+        new_starts.sort_unstable();
+        new_starts.dedup();
+        self.late_rows += starts.null_count();
+        for start in new_starts {
+            let rows = batch.filter(&starts.equal(start))?;
+            match self.watermark {
+                Some(watermark) if start + every <= watermark => self.late_rows += rows.height(),
+                _ => self.open.entry(start).or_default().push(rows),
+            }
+        }
+
+        if let Some(max) = time.physical().max() {
+            let watermark = max - lateness;
+            self.watermark = Some(self.watermark.map_or(watermark, |w| w.max(watermark)));
+        }
+        Ok(())
+    }
+
+    fn close_windows(&mut self, is_closed: impl Fn(Window, i64) -> bool) -> PolarsResult<()> {
+        let (Some(watermark), Some(time_unit)) = (self.watermark, self.time_unit) else {
+            return Ok(());
+        };
+        let every = self.every(time_unit)?;
+        while let Some(entry) = self.open.first_entry() {
+            let start = *entry.key();
+            let window = Window {
+                start,
+                end: start + every,
+            };
+            if !is_closed(window, watermark) {
+                break;
+            }
+            let frames = entry.remove();
+            let mut df = frames[0].clone();
+            for other in &frames[1..] {
+                df.vstack_mut(other)?;
+            }
+            let out = (self.query)(df.lazy()).collect()?;
+            self.sink.emit(window, out)?;
+        }
+        Ok(())
+    }
+}
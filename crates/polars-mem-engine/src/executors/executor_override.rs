@@ -0,0 +1,103 @@
+//! Hand the execution of selected operators to an external backend, e.g. one running on a GPU.
+//!
+//! A downstream crate implements [`ExecutorOverride`] and installs it with
+//! [`register_executor_override`]. The in-memory engine then offers it every join and group-by
+//! before running the operator itself. The override declines an operator by returning
+//! `Ok(None)`, after which the engine runs it as usual, so a backend only has to implement the
+//! cases it supports.
+use std::sync::RwLock;
+
+use super::*;
+
+static OVERRIDE: RwLock<Option<Arc<dyn ExecutorOverride>>> = RwLock::new(None);
+
+/// A join offered to an [`ExecutorOverride`].
+pub struct JoinInput<'a> {
+    pub left: &'a DataFrame,
+    pub right: &'a DataFrame,
+    /// The evaluated join keys of `left`.
+    pub left_on: &'a [Column],
+    /// The evaluated join keys of `right`.
+    pub right_on: &'a [Column],
+    pub args: &'a JoinArgs,
+}
+
+/// A group-by offered to an [`ExecutorOverride`].
+pub struct GroupByInput<'a> {
+    pub df: &'a DataFrame,
+    /// The evaluated group keys.
+    pub keys: &'a [Column],
+    /// The aggregations, each giving one output column after the keys.
+    pub aggs: &'a [Expr],
+    pub maintain_order: bool,
+    /// The slice of the groups to return.
+    pub slice: Option<(i64, usize)>,
+}
+
+/// An external implementation of physical operators.
+///
+/// A claimed operator must return the same frame as the engine would: the same columns,
+/// types and, where the operator guarantees it, order of the rows.
+pub trait ExecutorOverride: Send + Sync {
+    /// The name of the backend, shown in the verbose output.
+    fn name(&self) -> &str;
+
+    /// Execute a join, or return `None` to leave it to the engine.
+    ///
+    /// Joins with additional join type options, such as inequality joins, are not offered.
+    fn join(&self, input: &JoinInput) -> PolarsResult<Option<DataFrame>> {
+        let _ = input;
+        Ok(None)
+    }
+
+    /// Execute a group-by, or return `None` to leave it to the engine.
+    ///
+    /// Group-bys that apply a function to every group are not offered.
+    fn group_by(&self, input: &GroupByInput) -> PolarsResult<Option<DataFrame>> {
+        let _ = input;
+        Ok(None)
+    }
+}
+
+/// Install `executor_override`, replacing the one installed before.
+pub fn register_executor_override(executor_override: impl ExecutorOverride + 'static) {
+    *OVERRIDE.write().unwrap() = Some(Arc::new(executor_override));
+}
+
+/// Remove the installed [`ExecutorOverride`], returning whether there was one.
+pub fn unregister_executor_override() -> bool {
+    OVERRIDE.write().unwrap().take().is_some()
+}
+
+/// The contiguous Arrow arrays of `columns`, e.g. to copy the keys of an operator to a device.
+pub fn to_arrow_arrays(columns: &[Column]) -> Vec<ArrayRef> {
+    columns
+        .iter()
+        .map(|c| {
+            c.as_materialized_series()
+                .rechunk()
+                .to_arrow(0, CompatLevel::newest())
+        })
+        .collect()
+}
+
+/// Offer an operator to the installed override, if any.
+pub(super) fn try_override(
+    state: &ExecutionState,
+    operator: &str,
+    f: impl FnOnce(&dyn ExecutorOverride) -> PolarsResult<Option<DataFrame>>,
+) -> PolarsResult<Option<DataFrame>> {
+    let Some(executor_override) = OVERRIDE.read().unwrap().clone() else {
+        return Ok(None);
+    };
+    let out = f(executor_override.as_ref())?;
+    if state.verbose() {
+        let outcome = if out.is_some() {
+            "executed"
+        } else {
+            "declined"
+        };
+        eprintln!("{operator} {outcome} by {}", executor_override.name());
+    }
+    Ok(out)
+}
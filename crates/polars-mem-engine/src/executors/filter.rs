@@ -109,7 +109,10 @@ impl Executor for FilterExec {
 
         state.clone().record(
             || {
-                let df = self.execute_impl(df, state);
+                let df = self.execute_impl(df, state).map_err(|e| {
+                    let predicate = std::slice::from_ref(&self.predicate);
+                    e.with_error_context(physical_error_context("filter", predicate))
+                });
                 if state.verbose() {
                     eprintln!("dataframe filtered");
                 }
@@ -28,6 +28,7 @@ pub struct GroupByExec {
     maintain_order: bool,
     input_schema: SchemaRef,
     slice: Option<(i64, usize)>,
+    agg_exprs: Vec<Expr>,
 }
 
 impl GroupByExec {
@@ -40,6 +41,7 @@ impl GroupByExec {
         maintain_order: bool,
         input_schema: SchemaRef,
         slice: Option<(i64, usize)>,
+        agg_exprs: Vec<Expr>,
     ) -> Self {
         Self {
             input,
@@ -49,6 +51,7 @@ impl GroupByExec {
             maintain_order,
             input_schema,
             slice,
+            agg_exprs,
         }
     }
 }
@@ -63,7 +66,7 @@ pub(super) fn group_by_helper(
     maintain_order: bool,
     slice: Option<(i64, usize)>,
 ) -> PolarsResult<DataFrame> {
-    df.as_single_chunk_par();
+    df.rechunk_by_policy_par();
     let gb = df.group_by_with_series(keys, true, maintain_order)?;
 
     if let Some(f) = apply {
@@ -95,11 +98,23 @@ pub(super) fn group_by_helper(
 
 impl GroupByExec {
     fn execute_impl(&mut self, state: &ExecutionState, df: DataFrame) -> PolarsResult<DataFrame> {
-        let keys = self
+        let keys: Vec<Column> = self
             .keys
             .iter()
             .map(|e| e.evaluate(&df, state))
             .collect::<PolarsResult<_>>()?;
+        if self.apply.is_none() {
+            let input = GroupByInput {
+                df: &df,
+                keys: &keys,
+                aggs: &self.agg_exprs,
+                maintain_order: self.maintain_order,
+                slice: self.slice,
+            };
+            if let Some(df) = try_override(state, "group_by", |o| o.group_by(&input))? {
+                return Ok(df);
+            }
+        }
         group_by_helper(
             df,
             keys,
@@ -138,11 +153,15 @@ impl Executor for GroupByExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        let out = if state.has_node_timer() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
             self.execute_impl(state, df)
-        }
+        };
+        out.map_err(|e| {
+            let keys = self.keys.iter().filter_map(|k| k.as_expression());
+            e.with_error_context(error_context("group_by", keys.chain(&self.agg_exprs)))
+        })
     }
 }
@@ -16,7 +16,6 @@ pub struct PartitionGroupByExec {
     from_partitioned_ds: bool,
     #[allow(dead_code)]
     keys: Vec<Expr>,
-    #[allow(dead_code)]
     aggs: Vec<Expr>,
 }
 
@@ -266,6 +265,17 @@ impl PartitionGroupByExec {
             // of groups.
             let keys = self.keys(&original_df, state)?;
 
+            let input = GroupByInput {
+                df: &original_df,
+                keys: &keys,
+                aggs: &self.aggs,
+                maintain_order: self.maintain_order,
+                slice: self.slice,
+            };
+            if let Some(df) = try_override(state, "group_by", |o| o.group_by(&input))? {
+                return Ok(df);
+            }
+
             if !can_run_partitioned(&keys, &original_df, state, self.from_partitioned_ds)? {
                 return group_by_helper(
                     original_df,
@@ -1,3 +1,4 @@
+use polars_core::config::{RechunkPolicy, rechunk_policy};
 use polars_ops::frame::DataFrameJoinOps;
 
 use super::*;
@@ -71,8 +72,12 @@ impl Executor for JoinExec {
             (input_left.execute(state), input_right.execute(state))
         };
 
-        let df_left = df_left?;
-        let df_right = df_right?;
+        let mut df_left = df_left?;
+        let mut df_right = df_right?;
+        if rechunk_policy() == RechunkPolicy::AlwaysBeforeJoin {
+            df_left.as_single_chunk_par();
+            df_right.as_single_chunk_par();
+        }
 
         let profile_name = if state.has_node_timer() {
             let by = self
@@ -140,6 +145,19 @@ impl Executor for JoinExec {
                 }
             }
 
+            if self.options.is_none() {
+                let input = JoinInput {
+                    left: &df_left,
+                    right: &df_right,
+                    left_on: &left_on_series,
+                    right_on: &right_on_series,
+                    args: &self.args,
+                };
+                if let Some(df) = try_override(state, "join", |o| o.join(&input))? {
+                    return Ok(df);
+                }
+            }
+
             let df = df_left._join_impl(
                 &df_right,
                 left_on_series.into_iter().map(|c| c.take_materialized_series()).collect(),
@@ -156,5 +174,9 @@ impl Executor for JoinExec {
             df
 
         }, profile_name)
+        .map_err(|e| {
+            let on = self.left_on.iter().chain(&self.right_on);
+            e.with_error_context(error_context("join", on.filter_map(|k| k.as_expression())))
+        })
     }
 }
@@ -1,5 +1,6 @@
 mod cache;
 mod executor;
+mod executor_override;
 mod ext_context;
 mod filter;
 mod group_by;
@@ -17,6 +18,8 @@ mod scan;
 mod slice;
 mod sort;
 mod stack;
+#[cfg(feature = "tracing")]
+mod traced;
 mod udf;
 mod union;
 mod unique;
@@ -24,6 +27,10 @@ mod unique;
 use std::borrow::Cow;
 
 pub use executor::*;
+pub use executor_override::{
+    ExecutorOverride, GroupByInput, JoinInput, register_executor_override, to_arrow_arrays,
+    unregister_executor_override,
+};
 use polars_core::POOL;
 use polars_plan::global::FETCH_ROWS;
 use polars_plan::utils::*;
@@ -31,6 +38,7 @@ use projection_utils::*;
 use rayon::prelude::*;
 
 pub(super) use self::cache::*;
+use self::executor_override::try_override;
 pub(super) use self::ext_context::*;
 pub(super) use self::filter::*;
 pub(super) use self::group_by::*;
@@ -49,7 +57,29 @@ pub(super) use self::scan::*;
 pub(super) use self::slice::*;
 pub(super) use self::sort::*;
 pub(super) use self::stack::*;
+#[cfg(feature = "tracing")]
+pub(super) use self::traced::*;
 pub(super) use self::udf::*;
 pub(super) use self::union::*;
 pub(super) use self::unique::*;
 use crate::prelude::*;
+
+/// The [`ErrorContext`] of the plan node `operator` evaluating `exprs`, which is attached to the
+/// errors raised while the node is executed.
+fn error_context<'a>(operator: &str, exprs: impl IntoIterator<Item = &'a Expr>) -> ErrorContext {
+    let mut context = ErrorContext::new(operator);
+    for e in exprs {
+        context.expressions.push(format!("{e:?}"));
+        for name in expr_to_leaf_column_names_iter(e) {
+            if !context.columns.iter().any(|c| c == name.as_str()) {
+                context.columns.push(name.to_string());
+            }
+        }
+    }
+    context
+}
+
+/// The [`ErrorContext`] of the plan node `operator` evaluating the physical expressions `exprs`.
+fn physical_error_context(operator: &str, exprs: &[Arc<dyn PhysicalExpr>]) -> ErrorContext {
+    error_context(operator, exprs.iter().filter_map(|e| e.as_expression()))
+}
@@ -16,7 +16,6 @@ pub struct IpcExec {
     pub(crate) sources: ScanSources,
     pub(crate) file_info: FileInfo,
     pub(crate) predicate: Option<ScanPredicate>,
-    #[allow(dead_code)]
     pub(crate) options: IpcScanOptions,
     pub(crate) file_options: FileScanOptions,
     pub(crate) hive_parts: Option<Arc<Vec<HivePartitions>>>,
@@ -98,7 +97,7 @@ impl IpcExec {
                 ScanSourceRef::Buffer(buff) => buff.clone(),
             };
 
-            IpcReader::new(std::io::Cursor::new(memslice))
+            let reader = IpcReader::new(std::io::Cursor::new(memslice))
                 .with_n_rows(n_rows)
                 .with_row_index(self.file_options.row_index.clone())
                 .with_projection(projection.clone())
@@ -112,8 +111,9 @@ impl IpcExec {
                         .include_file_paths
                         .as_ref()
                         .map(|x| (x.clone(), Arc::from(source.to_include_path_name()))),
-                )
-                .finish()
+                );
+            // SAFETY: validation can only be disabled with the unsafe `Utf8Validation::trusted`.
+            unsafe { reader.validate_utf8(self.options.validate_utf8.is_enabled()) }.finish()
         };
 
         let mut dfs = if let Some(mut n_rows) = self.file_options.pre_slice.map(|x| {
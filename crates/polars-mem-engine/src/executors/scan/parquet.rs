@@ -206,6 +206,8 @@ impl ParquetExec {
                             .as_ref()
                             .map(|x| (x.clone(), Arc::from(source.to_include_path_name()))),
                     );
+                // SAFETY: validation can only be disabled with the unsafe `Utf8Validation::trusted`.
+                reader = unsafe { reader.validate_utf8(self.options.validate_utf8.is_enabled()) };
 
                 reader.num_rows().map(|num_rows| (reader, num_rows))
             });
@@ -436,6 +438,7 @@ impl ParquetExec {
 
             // Now read the actual data.
             let use_statistics = self.options.use_statistics;
+            let validate_utf8 = self.options.validate_utf8.is_enabled();
             let base_row_index_ref = &base_row_index;
             let include_file_paths = self.file_options.include_file_paths.as_ref();
             let first_schema = first_schema.clone();
@@ -465,6 +468,8 @@ impl ParquetExec {
                             offset: rc.offset + cumulative_read as IdxSize,
                         });
 
+                        // SAFETY: validation is only disabled by the caller of the scan, for trusted files.
+                        let reader = unsafe { reader.validate_utf8(validate_utf8) };
                         let df = reader
                             .with_slice(Some(slice))
                             .with_row_index(row_index)
@@ -16,7 +16,7 @@ impl SortExec {
         mut df: DataFrame,
     ) -> PolarsResult<DataFrame> {
         state.should_stop()?;
-        df.as_single_chunk_par();
+        df.rechunk_by_policy_par();
 
         let height = df.height();
 
@@ -69,11 +69,12 @@ impl Executor for SortExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        let out = if state.has_node_timer() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
             self.execute_impl(state, df)
-        }
+        };
+        out.map_err(|e| e.with_error_context(physical_error_context("sort", &self.by_column)))
     }
 }
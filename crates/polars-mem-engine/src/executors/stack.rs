@@ -129,11 +129,12 @@ impl Executor for StackExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        let out = if state.has_node_timer() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
             self.execute_impl(state, df)
-        }
+        };
+        out.map_err(|e| e.with_error_context(physical_error_context("with_columns", &self.exprs)))
     }
 }
@@ -0,0 +1,18 @@
+use super::*;
+
+/// Executes a plan node in a `tracing` span named after it, which records the height of the
+/// output. The spans of the inputs are nested in it.
+pub struct TracedExec {
+    pub(crate) name: &'static str,
+    pub(crate) input: Box<dyn Executor>,
+}
+
+impl Executor for TracedExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let span = tracing::info_span!("execute", node = self.name, rows = tracing::field::Empty);
+        let _guard = span.enter();
+        let df = self.input.execute(state)?;
+        span.record("rows", df.height());
+        Ok(df)
+    }
+}
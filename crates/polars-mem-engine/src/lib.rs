@@ -3,7 +3,10 @@ mod planner;
 mod predicate;
 mod prelude;
 
-pub use executors::Executor;
+pub use executors::{
+    Executor, ExecutorOverride, GroupByInput, JoinInput, register_executor_override,
+    to_arrow_arrays, unregister_executor_override,
+};
 #[cfg(feature = "python")]
 pub use planner::python_scan_predicate;
 pub use planner::{create_multiple_physical_plans, create_physical_plan, create_scan_predicate};
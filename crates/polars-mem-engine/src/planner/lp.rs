@@ -190,6 +190,24 @@ fn create_physical_plan_impl(
     state: &mut ConversionState,
     // Cache nodes in order of discovery
     cache_nodes: &mut PlIndexMap<usize, Box<dyn Executor>>,
+) -> PolarsResult<Box<dyn Executor>> {
+    #[cfg(feature = "tracing")]
+    let name = lp_arena.get(root).name();
+    let executor = create_physical_node(root, lp_arena, expr_arena, state, cache_nodes)?;
+    #[cfg(feature = "tracing")]
+    let executor = Box::new(executors::TracedExec {
+        name,
+        input: executor,
+    });
+    Ok(executor)
+}
+
+fn create_physical_node(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    state: &mut ConversionState,
+    cache_nodes: &mut PlIndexMap<usize, Box<dyn Executor>>,
 ) -> PolarsResult<Box<dyn Executor>> {
     use IR::*;
 
@@ -248,6 +266,12 @@ fn create_physical_plan_impl(
                         input,
                         name: name.to_string(),
                         f: Box::new(move |mut df, _state| {
+                            let key = sink_options.report_key.as_deref();
+                            let key_dtype = key.map(|k| df.column(k).map(|c| c.dtype().clone()));
+                            let key_dtype = key_dtype.transpose()?;
+                            let mut report = SinkFileReport::new(&target);
+                            report.update(&df, key)?;
+
                             let mut file = target
                                 .open_into_writeable(&sink_options, cloud_options.as_ref())?;
                             let writer = &mut *file;
@@ -262,6 +286,7 @@ fn create_physical_plan_impl(
                                         .with_statistics(options.statistics)
                                         .with_row_group_size(options.row_group_size)
                                         .with_data_page_size(options.data_page_size)
+                                        .with_zstd_dictionary_size(options.zstd_dictionary_size)
                                         .finish(&mut df)?;
                                 },
                                 #[cfg(feature = "ipc")]
@@ -321,7 +346,11 @@ fn create_physical_plan_impl(
                             file.sync_on_close(sink_options.sync_on_close)?;
                             file.close()?;
 
-                            Ok(None)
+                            report.finish();
+                            let report = SinkReport {
+                                files: vec![report],
+                            };
+                            report.into_frame(key_dtype.as_ref()).map(Some)
                         }),
                     }))
                 },
@@ -686,6 +715,10 @@ fn create_physical_plan_impl(
                 )))
             } else {
                 let input = recurse!(input, state)?;
+                let aggs = aggs
+                    .iter()
+                    .map(|e| e.to_expr(expr_arena))
+                    .collect::<Vec<_>>();
                 Ok(Box::new(executors::GroupByExec::new(
                     input,
                     phys_keys,
@@ -694,6 +727,7 @@ fn create_physical_plan_impl(
                     maintain_order,
                     input_schema,
                     options.slice,
+                    aggs,
                 )))
             }
         },
@@ -0,0 +1,190 @@
+//! Vector operations on the sub-arrays of numeric arrays.
+//!
+//! The kernels accumulate in several independent lanes, so the compiler can vectorize them.
+use arrow::array::{Array, PrimitiveArray};
+use arrow::types::NativeType;
+use num_traits::Float;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_float_polars_type;
+
+const LANES: usize = 8;
+
+pub(crate) fn dot_slice<T: Float>(a: &[T], b: &[T]) -> T {
+    let mut acc = [T::zero(); LANES];
+    let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .fold(T::zero(), |sum, (x, y)| sum + *x * *y);
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for ((acc, x), y) in acc.iter_mut().zip(a).zip(b) {
+            *acc = *acc + *x * *y;
+        }
+    }
+    acc.into_iter().fold(tail, |sum, x| sum + x)
+}
+
+pub(crate) fn l2_norm_slice<T: Float>(a: &[T]) -> T {
+    dot_slice(a, a).sqrt()
+}
+
+/// The squared Euclidean distance between two vectors of the same length.
+pub(crate) fn squared_l2_distance_slice<T: Float>(a: &[T], b: &[T]) -> T {
+    let mut acc = [T::zero(); LANES];
+    let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .fold(T::zero(), |sum, (x, y)| sum + (*x - *y) * (*x - *y));
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for ((acc, x), y) in acc.iter_mut().zip(a).zip(b) {
+            *acc = *acc + (*x - *y) * (*x - *y);
+        }
+    }
+    acc.into_iter().fold(tail, |sum, x| sum + x)
+}
+
+#[derive(Clone, Copy)]
+pub(super) enum VectorOp {
+    Dot,
+    CosineSimilarity,
+}
+
+impl VectorOp {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Dot => "dot",
+            Self::CosineSimilarity => "cosine_similarity",
+        }
+    }
+
+    fn apply<T: Float>(self, a: &[T], b: &[T]) -> T {
+        match self {
+            Self::Dot => dot_slice(a, b),
+            Self::CosineSimilarity => dot_slice(a, b) / (l2_norm_slice(a) * l2_norm_slice(b)),
+        }
+    }
+}
+
+/// The float type of the output: `Float32` if all inputs are, otherwise `Float64`.
+fn float_dtype(inner: &[&DataType]) -> DataType {
+    if inner.iter().all(|dt| **dt == DataType::Float32) {
+        DataType::Float32
+    } else {
+        DataType::Float64
+    }
+}
+
+/// Cast the inner values of `ca` to `float`.
+fn to_float_array(ca: &ArrayChunked, float: &DataType, op: &str) -> PolarsResult<ArrayChunked> {
+    polars_ensure!(
+        ca.inner_dtype().is_primitive_numeric(),
+        InvalidOperation: "`arr.{}` requires numeric arrays, got {}", op, ca.dtype()
+    );
+    let out = ca.cast(&DataType::Array(Box::new(float.clone()), ca.width()))?;
+    Ok(out.array()?.rechunk().into_owned())
+}
+
+/// The values of every sub-array, or `None` if the sub-array is null or contains a null.
+///
+/// `ca` must consist of a single chunk.
+pub(crate) fn sub_arrays<T: NativeType>(ca: &ArrayChunked) -> impl Iterator<Item = Option<&[T]>> {
+    let width = ca.width();
+    let arr = ca.downcast_as_array();
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    (0..arr.len()).map(move |i| {
+        let start = i * width;
+        let is_null = !arr.is_valid(i)
+            || values
+                .validity()
+                .is_some_and(|v| v.null_count_range(start, width) > 0);
+        (!is_null).then(|| &values.values()[start..start + width])
+    })
+}
+
+/// Apply `op` to every pair of sub-arrays, broadcasting a single sub-array of either side.
+pub(super) fn binary_vector_op(
+    ca: &ArrayChunked,
+    other: &ArrayChunked,
+    op: VectorOp,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        ca.width() == other.width(),
+        ShapeMismatch: "`arr.{}` requires arrays of the same width, got {} and {}",
+        op.name(), ca.width(), other.width()
+    );
+    let (len, other_len) = (ca.len(), other.len());
+    polars_ensure!(
+        len == other_len || len == 1 || other_len == 1,
+        ShapeMismatch: "`arr.{}` requires inputs of the same length, got {} and {}",
+        op.name(), len, other_len
+    );
+    let float = float_dtype(&[ca.inner_dtype(), other.inner_dtype()]);
+    let lhs = to_float_array(ca, &float, op.name())?;
+    let rhs = to_float_array(other, &float, op.name())?;
+
+    with_match_physical_float_polars_type!(float, |$T| {
+        type Native = <$T as PolarsNumericType>::Native;
+        let f = |a: Option<&[Native]>, b: Option<&[Native]>| Some(op.apply(a?, b?));
+        let mut out: ChunkedArray<$T> = if len == other_len {
+            sub_arrays::<Native>(&lhs)
+                .zip(sub_arrays::<Native>(&rhs))
+                .map(|(a, b)| f(a, b))
+                .collect()
+        } else if other_len == 1 {
+            let b = sub_arrays::<Native>(&rhs).next().unwrap();
+            sub_arrays::<Native>(&lhs).map(|a| f(a, b)).collect()
+        } else {
+            let a = sub_arrays::<Native>(&lhs).next().unwrap();
+            sub_arrays::<Native>(&rhs).map(|b| f(a, b)).collect()
+        };
+        out.rename(ca.name().clone());
+        Ok(out.into_series())
+    })
+}
+
+/// The Euclidean norm of every sub-array.
+pub(super) fn l2_norm(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let float = float_dtype(&[ca.inner_dtype()]);
+    let arr = to_float_array(ca, &float, "l2_norm")?;
+
+    with_match_physical_float_polars_type!(float, |$T| {
+        type Native = <$T as PolarsNumericType>::Native;
+        let mut out: ChunkedArray<$T> = sub_arrays::<Native>(&arr)
+            .map(|a| Some(l2_norm_slice(a?)))
+            .collect();
+        out.rename(ca.name().clone());
+        Ok(out.into_series())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_vector_kernels() {
+        let a = (0..19).map(|i| i as f64).collect::<Vec<_>>();
+        let b = (0..19).map(|i| (i % 3) as f64 - 1.0).collect::<Vec<_>>();
+        let naive = a.iter().zip(&b).map(|(x, y)| x * y).sum::<f64>();
+        assert_eq!(dot_slice(&a, &b), naive);
+        assert_eq!(dot_slice::<f64>(&[], &[]), 0.0);
+        assert_eq!(l2_norm_slice(&[3.0f32, 4.0]), 5.0);
+        let naive = a
+            .iter()
+            .zip(&b)
+            .map(|(x, y)| (x - y) * (x - y))
+            .sum::<f64>();
+        assert_eq!(squared_l2_distance_slice(&a, &b), naive);
+
+        let cos = VectorOp::CosineSimilarity;
+        assert!((cos.apply(&[1.0, 0.0], &[1.0, 1.0]) - 0.5f64.sqrt()).abs() < 1e-12);
+        assert!(cos.apply::<f64>(&[0.0, 0.0], &[1.0, 1.0]).is_nan());
+    }
+}
@@ -4,6 +4,7 @@ mod count;
 mod dispersion;
 mod get;
 mod join;
+pub(crate) mod linalg;
 mod min_max;
 mod namespace;
 mod sum_mean;
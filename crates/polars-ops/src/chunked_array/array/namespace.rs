@@ -1,3 +1,6 @@
+use polars_core::utils::slice_offsets;
+
+use super::linalg::{VectorOp, binary_vector_op, l2_norm};
 use super::min_max::AggType;
 use super::*;
 #[cfg(feature = "array_count")]
@@ -51,6 +54,25 @@ pub trait ArrayNameSpace: AsArray {
         }
     }
 
+    /// The dot product of every sub-array with the matching sub-array of `other`.
+    ///
+    /// The result is `Float32` if both arrays hold `Float32` values, otherwise `Float64`. A single
+    /// sub-array of either side is broadcast, and sub-arrays with a null give a null.
+    fn array_dot(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        binary_vector_op(self.as_array(), other, VectorOp::Dot)
+    }
+
+    /// The cosine similarity of every sub-array with the matching sub-array of `other`, following
+    /// the rules of [`ArrayNameSpace::array_dot`]. A zero vector gives NaN.
+    fn array_cosine_similarity(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        binary_vector_op(self.as_array(), other, VectorOp::CosineSimilarity)
+    }
+
+    /// The Euclidean norm of every sub-array.
+    fn array_l2_norm(&self) -> PolarsResult<Series> {
+        l2_norm(self.as_array())
+    }
+
     fn array_median(&self) -> PolarsResult<Series> {
         let ca = self.as_array();
         dispersion::median_with_nulls(ca)
@@ -138,6 +160,16 @@ pub trait ArrayNameSpace: AsArray {
         array_count_matches(ca, element)
     }
 
+    /// Slice every sub-array. As all sub-arrays have the same width the output is an array as
+    /// well, with the width of the slice.
+    fn array_slice(&self, offset: i64, length: Option<usize>) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        let (start, len) = slice_offsets(offset, length.unwrap_or(usize::MAX), ca.width());
+        let out = ca.try_apply_amortized_to_list(|s| Ok(s.as_ref().slice(start as i64, len)))?;
+        let dtype = DataType::Array(Box::new(ca.inner_dtype().clone()), len);
+        out.cast(&dtype)?.array().cloned()
+    }
+
     fn array_shift(&self, n: &Series) -> PolarsResult<Series> {
         let ca = self.as_array();
         let n_s = n.cast(&DataType::Int64)?;
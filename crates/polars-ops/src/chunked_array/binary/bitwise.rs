@@ -0,0 +1,24 @@
+use polars_core::prelude::arity::broadcast_try_binary_elementwise;
+use polars_core::prelude::*;
+
+/// Combine the bytes of every pair of values with `op`. Both values must have the same length.
+pub(super) fn bitwise_binary(
+    ca: &BinaryChunked,
+    other: &BinaryChunked,
+    name: &str,
+    op: impl Fn(u8, u8) -> u8,
+) -> PolarsResult<BinaryChunked> {
+    broadcast_try_binary_elementwise(ca, other, |a: Option<&[u8]>, b: Option<&[u8]>| {
+        let (Some(a), Some(b)) = (a, b) else {
+            return Ok(None);
+        };
+        polars_ensure!(
+            a.len() == b.len(),
+            InvalidOperation: "`bin.{}` requires values of the same size, got {} and {} bytes",
+            name, a.len(), b.len()
+        );
+        Ok(Some(
+            a.iter().zip(b).map(|(a, b)| op(*a, *b)).collect::<Vec<_>>(),
+        ))
+    })
+}
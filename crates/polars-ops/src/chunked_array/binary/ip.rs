@@ -0,0 +1,156 @@
+//! Conversion between IP addresses and their bytes: 4 for an IPv4 and 16 for an IPv6 address.
+use std::net::IpAddr;
+
+use polars_core::prelude::*;
+
+/// Parse an IPv4 (eg: "192.168.0.1") or IPv6 (eg: "2001:db8::1") address into its bytes.
+pub(super) fn parse_ip(s: &[u8]) -> Option<Vec<u8>> {
+    let ip: IpAddr = std::str::from_utf8(s).ok()?.parse().ok()?;
+    Some(match ip {
+        IpAddr::V4(ip) => ip.octets().to_vec(),
+        IpAddr::V6(ip) => ip.octets().to_vec(),
+    })
+}
+
+/// The address stored in `bytes`, if it has the length of an IPv4 or IPv6 address.
+pub(super) fn ip_from_bytes(bytes: &[u8]) -> Option<IpAddr> {
+    match bytes.len() {
+        4 => Some(IpAddr::from(<[u8; 4]>::try_from(bytes).unwrap())),
+        16 => Some(IpAddr::from(<[u8; 16]>::try_from(bytes).unwrap())),
+        _ => None,
+    }
+}
+
+/// The IPv4 address stored in `bytes` as an integer.
+pub(super) fn ipv4_to_integer(bytes: &[u8]) -> PolarsResult<u32> {
+    let octets = <[u8; 4]>::try_from(bytes).map_err(|_| {
+        polars_err!(
+            ComputeError:
+            "an IPv4 address must be 4 bytes long, found a value of {} bytes", bytes.len()
+        )
+    })?;
+    Ok(u32::from_be_bytes(octets))
+}
+
+/// The IP address stored in `bytes` as a 128-bit integer, IPv4 addresses are mapped to IPv6
+/// (eg: "::ffff:10.0.0.1").
+pub(super) fn ipv6_to_integer(bytes: &[u8]) -> PolarsResult<u128> {
+    let ip = match ip_from_bytes(bytes).ok_or_else(|| invalid_ip_length(bytes.len()))? {
+        IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+        IpAddr::V6(ip) => ip,
+    };
+    Ok(u128::from(ip))
+}
+
+pub(super) fn invalid_ip_length(len: usize) -> PolarsError {
+    polars_err!(
+        ComputeError:
+        "an IP address must be 4 (IPv4) or 16 (IPv6) bytes long, found a value of {} bytes", len
+    )
+}
+
+/// An IP network in CIDR notation, eg: "10.0.0.0/8".
+pub(super) struct Subnet {
+    network: u128,
+    mask: u128,
+    is_ipv4: bool,
+}
+
+impl Subnet {
+    /// Parse a subnet in CIDR notation. Host bits of the address are ignored and a missing
+    /// prefix length selects a single address.
+    pub(super) fn parse(cidr: &str) -> PolarsResult<Self> {
+        let invalid =
+            || polars_err!(InvalidOperation: "invalid subnet in CIDR notation: {:?}", cidr);
+        let (addr, prefix_len) = match cidr.split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (cidr, None),
+        };
+        let addr: IpAddr = addr.parse().map_err(|_| invalid())?;
+        let (network, bits) = match addr {
+            IpAddr::V4(ip) => (u32::from(ip) as u128, 32),
+            IpAddr::V6(ip) => (u128::from(ip), 128),
+        };
+        let prefix_len = match prefix_len {
+            Some(prefix_len) => prefix_len.parse::<u32>().map_err(|_| invalid())?,
+            None => bits,
+        };
+        polars_ensure!(prefix_len <= bits, InvalidOperation: "invalid subnet in CIDR notation: {:?}", cidr);
+        // The mask covers the `prefix_len` most significant bits of an address of `bits` bits.
+        let mask = match prefix_len {
+            0 => 0,
+            _ => (u128::MAX << (bits - prefix_len)) & (u128::MAX >> (128 - bits)),
+        };
+        Ok(Self {
+            network: network & mask,
+            mask,
+            is_ipv4: bits == 32,
+        })
+    }
+
+    /// Whether `ip` is in this subnet. Addresses of the other IP version are never contained.
+    pub(super) fn contains(&self, ip: IpAddr) -> bool {
+        let ip = match ip {
+            IpAddr::V4(ip) if self.is_ipv4 => u32::from(ip) as u128,
+            IpAddr::V6(ip) if !self.is_ipv4 => u128::from(ip),
+            _ => return false,
+        };
+        ip & self.mask == self.network
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        ip_from_bytes(&parse_ip(s.as_bytes()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_ip_to_integer() {
+        let bytes = parse_ip(b"192.168.0.1").unwrap();
+        assert_eq!(ipv4_to_integer(&bytes).unwrap(), 0xC0A8_0001);
+        assert_eq!(ipv6_to_integer(&bytes).unwrap(), 0xFFFF_C0A8_0001);
+
+        let bytes = parse_ip(b"2001:db8::1").unwrap();
+        assert!(ipv4_to_integer(&bytes).is_err());
+        assert_eq!(
+            ipv6_to_integer(&bytes).unwrap(),
+            0x2001_0DB8_0000_0000_0000_0000_0000_0001
+        );
+        assert!(ipv6_to_integer(&[0; 5]).is_err());
+    }
+
+    #[test]
+    fn test_subnet_contains() {
+        let subnet = Subnet::parse("10.0.0.0/8").unwrap();
+        assert!(subnet.contains(ip("10.255.0.1")));
+        assert!(!subnet.contains(ip("11.0.0.1")));
+        assert!(!subnet.contains(ip("::ffff:10.0.0.1")));
+
+        let subnet = Subnet::parse("192.168.1.77/24").unwrap();
+        assert!(subnet.contains(ip("192.168.1.1")));
+        assert!(!subnet.contains(ip("192.168.2.1")));
+
+        let subnet = Subnet::parse("2001:db8::/32").unwrap();
+        assert!(subnet.contains(ip("2001:db8:ffff::1")));
+        assert!(!subnet.contains(ip("2001:db9::1")));
+        assert!(!subnet.contains(ip("10.0.0.1")));
+
+        assert!(Subnet::parse("0.0.0.0/0").unwrap().contains(ip("1.2.3.4")));
+        assert!(Subnet::parse("::/0").unwrap().contains(ip("::1")));
+        assert!(Subnet::parse("1.2.3.4").unwrap().contains(ip("1.2.3.4")));
+        assert!(!Subnet::parse("1.2.3.4").unwrap().contains(ip("1.2.3.5")));
+
+        for cidr in [
+            "10.0.0.0/33",
+            "10.0.0/8",
+            "::/129",
+            "10.0.0.0/",
+            "10.0.0.0/-1",
+        ] {
+            assert!(Subnet::parse(cidr).is_err(), "{cidr}");
+        }
+    }
+}
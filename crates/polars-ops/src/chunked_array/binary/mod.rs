@@ -1,5 +1,12 @@
+mod bitwise;
 mod cast_binary_to_numerical;
+mod digest;
+#[cfg(feature = "binary_encoding")]
+mod ip;
 mod namespace;
+mod slice;
+#[cfg(feature = "binary_encoding")]
+mod uuid;
 
 pub use namespace::*;
 use polars_core::prelude::*;
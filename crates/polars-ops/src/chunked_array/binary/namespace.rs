@@ -1,5 +1,7 @@
 #[cfg(feature = "binary_encoding")]
 use std::borrow::Cow;
+#[cfg(feature = "binary_encoding")]
+use std::fmt::Write;
 
 use arrow::with_match_primitive_type;
 #[cfg(feature = "binary_encoding")]
@@ -8,11 +10,30 @@ use base64::Engine as _;
 use base64::engine::general_purpose;
 use memchr::memmem::find;
 use polars_compute::size::binary_size_bytes;
-use polars_core::prelude::arity::{broadcast_binary_elementwise_values, unary_elementwise_values};
+use polars_core::prelude::arity::{
+    broadcast_binary_elementwise, broadcast_binary_elementwise_values, unary_elementwise_values,
+};
+use xxhash_rust::xxh3::xxh3_64_with_seed;
 
+use super::bitwise::bitwise_binary;
 use super::cast_binary_to_numerical::cast_binview_to_primitive_dyn;
+use super::digest::crc32;
+#[cfg(feature = "binary_encoding")]
+use super::ip::{
+    Subnet, invalid_ip_length, ip_from_bytes, ipv4_to_integer, ipv6_to_integer, parse_ip,
+};
+#[cfg(feature = "binary_encoding")]
+use super::uuid::{format_uuid, parse_uuid};
 use super::*;
 
+/// The URL safe `base64` alphabet without padding, which also decodes padded input.
+#[cfg(feature = "binary_encoding")]
+const BASE64_URL: general_purpose::GeneralPurpose = general_purpose::GeneralPurpose::new(
+    &base64::alphabet::URL_SAFE,
+    general_purpose::NO_PAD
+        .with_decode_padding_mode(base64::engine::DecodePaddingMode::Indifferent),
+);
+
 pub trait BinaryNameSpaceImpl: AsBinary {
     /// Check if binary contains given literal
     fn contains(&self, lit: &[u8]) -> BooleanChunked {
@@ -98,6 +119,82 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         ca.apply_kernel_cast(&binary_size_bytes)
     }
 
+    /// Slice the values to at most `length` bytes, starting at `offset`.
+    ///
+    /// A negative `offset` counts from the end of the value, and a null `length` slices to the
+    /// end of the value.
+    fn bin_slice(&self, offset: &Column, length: &Column) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        let offset = offset.cast(&DataType::Int64)?;
+        // We strict cast, otherwise negative value will be treated as a valid length.
+        let length = length.strict_cast(&DataType::UInt64)?;
+
+        slice::slice(ca, offset.i64()?, length.u64()?)
+    }
+
+    /// Append the values of `other` to the values.
+    fn concat(&self, other: &BinaryChunked) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        polars_ensure!(
+            ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+            length_mismatch = "bin.concat",
+            ca.len(),
+            other.len()
+        );
+        Ok(broadcast_binary_elementwise(
+            ca,
+            other,
+            |a: Option<&[u8]>, b: Option<&[u8]>| Some([a?, b?].concat()),
+        ))
+    }
+
+    /// The bitwise AND of the bytes of the values and the values of `other`.
+    fn bitwise_and(&self, other: &BinaryChunked) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        polars_ensure!(
+            ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+            length_mismatch = "bin.bitwise_and",
+            ca.len(),
+            other.len()
+        );
+        bitwise_binary(ca, other, "bitwise_and", |a, b| a & b)
+    }
+
+    /// The bitwise OR of the bytes of the values and the values of `other`.
+    fn bitwise_or(&self, other: &BinaryChunked) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        polars_ensure!(
+            ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+            length_mismatch = "bin.bitwise_or",
+            ca.len(),
+            other.len()
+        );
+        bitwise_binary(ca, other, "bitwise_or", |a, b| a | b)
+    }
+
+    /// The bitwise XOR of the bytes of the values and the values of `other`.
+    fn bitwise_xor(&self, other: &BinaryChunked) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        polars_ensure!(
+            ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+            length_mismatch = "bin.bitwise_xor",
+            ca.len(),
+            other.len()
+        );
+        bitwise_binary(ca, other, "bitwise_xor", |a, b| a ^ b)
+    }
+
+    /// The CRC-32 checksum of every value.
+    fn crc32(&self) -> UInt32Chunked {
+        unary_elementwise_values(self.as_binary(), crc32)
+    }
+
+    /// The 64-bit XXH3 hash of every value. Unlike `hash`, it doesn't depend on the version of
+    /// Polars, so it can be stored.
+    fn xxh3(&self, seed: u64) -> UInt64Chunked {
+        unary_elementwise_values(self.as_binary(), |b| xxh3_64_with_seed(b, seed))
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn hex_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
         let ca = self.as_binary();
@@ -154,6 +251,144 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         }
     }
 
+    /// Decode the URL and filename safe variant of `base64`, with or without padding.
+    #[cfg(feature = "binary_encoding")]
+    fn base64url_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        if strict {
+            ca.try_apply_nonnull_values_generic(|s| {
+                BASE64_URL.decode(s).map_err(|_e| {
+                    polars_err!(
+                        ComputeError:
+                        "invalid `base64url` encoding found; try setting `strict=false` to ignore"
+                    )
+                })
+            })
+        } else {
+            Ok(ca.apply(|opt_s| opt_s.and_then(|s| BASE64_URL.decode(s).ok().map(Cow::Owned))))
+        }
+    }
+
+    /// Encode the values with the URL and filename safe variant of `base64`, without padding.
+    #[cfg(feature = "binary_encoding")]
+    fn base64url_encode(&self) -> Series {
+        let ca = self.as_binary();
+        unsafe {
+            ca.apply_values(|s| BASE64_URL.encode(s).into_bytes().into())
+                .cast_unchecked(&DataType::String)
+                .unwrap()
+        }
+    }
+
+    /// Parse the UUIDs in their textual form into their 16 bytes.
+    #[cfg(feature = "binary_encoding")]
+    fn uuid_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        if strict {
+            ca.try_apply_nonnull_values_generic(|s| {
+                parse_uuid(s).map(|b| b.to_vec()).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError:
+                        "invalid UUID found: {:?}; try setting `strict=false` to ignore",
+                        String::from_utf8_lossy(s)
+                    )
+                })
+            })
+        } else {
+            Ok(ca.apply(|opt_s| opt_s.and_then(|s| parse_uuid(s).map(|b| Cow::Owned(b.to_vec())))))
+        }
+    }
+
+    /// Format 16-byte values as UUIDs in the lowercase hyphenated form.
+    #[cfg(feature = "binary_encoding")]
+    fn uuid_encode(&self) -> PolarsResult<StringChunked> {
+        let ca = self.as_binary();
+        ca.try_apply_into_string_amortized(|b, buf| {
+            let b: &[u8; 16] = b.try_into().map_err(|_| {
+                polars_err!(
+                    ComputeError:
+                    "a UUID must be 16 bytes long, found a value of {} bytes", b.len()
+                )
+            })?;
+            format_uuid(b, buf);
+            Ok(())
+        })
+    }
+
+    /// Parse IPv4 and IPv6 addresses in their textual form into their 4 or 16 bytes.
+    #[cfg(feature = "binary_encoding")]
+    fn ip_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        if strict {
+            ca.try_apply_nonnull_values_generic(|s| {
+                parse_ip(s).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError:
+                        "invalid IP address found: {:?}; try setting `strict=false` to ignore",
+                        String::from_utf8_lossy(s)
+                    )
+                })
+            })
+        } else {
+            Ok(ca.apply(|opt_s| opt_s.and_then(|s| parse_ip(s).map(Cow::Owned))))
+        }
+    }
+
+    /// Format 4-byte and 16-byte values as IPv4 and IPv6 addresses respectively.
+    #[cfg(feature = "binary_encoding")]
+    fn ip_encode(&self) -> PolarsResult<StringChunked> {
+        let ca = self.as_binary();
+        ca.try_apply_into_string_amortized(|b, buf| {
+            let ip = ip_from_bytes(b).ok_or_else(|| invalid_ip_length(b.len()))?;
+            write!(buf, "{ip}").unwrap();
+            Ok(())
+        })
+    }
+
+    /// Check if the IP addresses in their binary form are in the subnet given in CIDR notation,
+    /// eg: "10.0.0.0/8". Addresses of the other IP version are never in the subnet.
+    #[cfg(feature = "binary_encoding")]
+    fn ip_is_in_subnet(&self, cidr: &str) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_binary();
+        let subnet = Subnet::parse(cidr)?;
+        ca.try_apply_nonnull_values_generic(|b| {
+            let ip = ip_from_bytes(b).ok_or_else(|| invalid_ip_length(b.len()))?;
+            Ok(subnet.contains(ip))
+        })
+    }
+
+    /// Convert IPv4 addresses in their binary form to integers, eg: "192.168.0.1" to
+    /// 3232235521.
+    #[cfg(feature = "binary_encoding")]
+    fn ipv4_to_integer(&self) -> PolarsResult<UInt32Chunked> {
+        let ca = self.as_binary();
+        ca.try_apply_nonnull_values_generic(ipv4_to_integer)
+    }
+
+    /// Convert IP addresses in their binary form to 128-bit integers, stored as a struct of the
+    /// `high` and `low` 64 bits as there is no 128-bit unsigned integer data type. IPv4
+    /// addresses are mapped to IPv6, eg: "10.0.0.1" to "::ffff:10.0.0.1".
+    #[cfg(all(feature = "binary_encoding", feature = "dtype-struct"))]
+    fn ipv6_to_integer(&self) -> PolarsResult<StructChunked> {
+        let ca = self.as_binary();
+        let mut high = Vec::with_capacity(ca.len());
+        let mut low = Vec::with_capacity(ca.len());
+        for opt_b in ca.iter() {
+            let ip = match opt_b {
+                Some(b) => ipv6_to_integer(b)?,
+                None => 0,
+            };
+            high.push((ip >> 64) as u64);
+            low.push(ip as u64);
+        }
+        let fields = [
+            UInt64Chunked::from_vec(PlSmallStr::from_static("high"), high).into_series(),
+            UInt64Chunked::from_vec(PlSmallStr::from_static("low"), low).into_series(),
+        ];
+        let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+        Ok(out.with_outer_validity(ca.rechunk_validity()))
+    }
+
     #[cfg(feature = "binary_encoding")]
     #[allow(clippy::wrong_self_convention)]
     fn from_buffer(&self, dtype: &DataType, is_little_endian: bool) -> PolarsResult<Series> {
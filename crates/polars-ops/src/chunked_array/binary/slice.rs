@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+
+use polars_core::prelude::arity::{ternary_elementwise, unary_elementwise};
+use polars_core::prelude::*;
+
+/// The byte range of a value of `len` bytes that starts at `offset` and spans at most `length`
+/// bytes. A negative `offset` counts from the end of the value.
+fn slice_range(len: usize, offset: i64, length: u64) -> (usize, usize) {
+    let len = len as u64;
+    let (start, length) = if offset >= 0 {
+        ((offset as u64).min(len), length)
+    } else if offset.unsigned_abs() <= len {
+        (len - offset.unsigned_abs(), length)
+    } else {
+        // An offset before the start of the value also shortens the slice, like `str.slice`.
+        (0, length.saturating_sub(offset.unsigned_abs() - len))
+    };
+    let end = start.saturating_add(length).min(len);
+    (start as usize, end as usize)
+}
+
+fn slice_ternary<'a>(
+    val: Option<&'a [u8]>,
+    offset: Option<i64>,
+    length: Option<u64>,
+) -> Option<&'a [u8]> {
+    let val = val?;
+    let (start, end) = slice_range(val.len(), offset?, length.unwrap_or(u64::MAX));
+    Some(&val[start..end])
+}
+
+fn broadcast<'a, T: PolarsDataType>(ca: &'a ChunkedArray<T>, len: usize) -> Cow<'a, ChunkedArray<T>>
+where
+    ChunkedArray<T>: ChunkExpandAtIndex<T>,
+{
+    if ca.len() == 1 && len != 1 {
+        Cow::Owned(ca.new_from_index(0, len))
+    } else {
+        Cow::Borrowed(ca)
+    }
+}
+
+pub(super) fn slice(
+    ca: &BinaryChunked,
+    offset: &Int64Chunked,
+    length: &UInt64Chunked,
+) -> PolarsResult<BinaryChunked> {
+    if offset.len() == 1 && length.len() == 1 {
+        let (offset, length) = (offset.get(0), length.get(0));
+        return Ok(unary_elementwise(ca, |val| {
+            slice_ternary(val, offset, length)
+        }));
+    }
+
+    let len = ca.len().max(offset.len()).max(length.len());
+    for other_len in [ca.len(), offset.len(), length.len()] {
+        polars_ensure!(
+            other_len == len || other_len == 1,
+            length_mismatch = "bin.slice",
+            len,
+            other_len
+        );
+    }
+    let (ca, offset, length) = (
+        broadcast(ca, len),
+        broadcast(offset, len),
+        broadcast(length, len),
+    );
+    Ok(ternary_elementwise(&ca, &offset, &length, slice_ternary))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_slice_range() {
+        assert_eq!(slice_range(5, 1, 2), (1, 3));
+        assert_eq!(slice_range(5, -2, u64::MAX), (3, 5));
+        assert_eq!(slice_range(3, -10, 8), (0, 1));
+        assert_eq!(slice_range(3, 10, 8), (3, 3));
+        assert_eq!(slice_range(3, i64::MIN, 8), (0, 0));
+    }
+}
@@ -0,0 +1,83 @@
+//! Conversion between the 16 bytes of a UUID and its textual form.
+
+/// Parse a UUID in the hyphenated form (eg: "67e55044-10b1-426f-9247-bb680e5fe0c8"), the simple
+/// form without hyphens, or either of those wrapped in braces or prefixed with "urn:uuid:".
+///
+/// Hexadecimal digits are case-insensitive.
+pub(super) fn parse_uuid(s: &[u8]) -> Option<[u8; 16]> {
+    let s = if let Some(s) = s.strip_prefix(b"urn:uuid:") {
+        s
+    } else if let Some(s) = s.strip_prefix(b"{") {
+        s.strip_suffix(b"}")?
+    } else {
+        s
+    };
+    let hyphenated = match s.len() {
+        32 => false,
+        36 => true,
+        _ => return None,
+    };
+
+    let mut out = [0; 16];
+    let mut i = 0;
+    for (pos, &c) in s.iter().enumerate() {
+        if hyphenated && matches!(pos, 8 | 13 | 18 | 23) {
+            if c != b'-' {
+                return None;
+            }
+            continue;
+        }
+        let nibble = (c as char).to_digit(16)? as u8;
+        out[i / 2] |= if i % 2 == 0 { nibble << 4 } else { nibble };
+        i += 1;
+    }
+    Some(out)
+}
+
+/// Write the 16 bytes of a UUID to `buf` in the lowercase hyphenated form.
+pub(super) fn format_uuid(bytes: &[u8; 16], buf: &mut String) {
+    for (i, b) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            buf.push('-');
+        }
+        buf.push(char::from_digit((b >> 4) as u32, 16).unwrap());
+        buf.push(char::from_digit((b & 0xf) as u32, 16).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_uuid_roundtrip() {
+        let canonical = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let expected = parse_uuid(canonical.as_bytes()).unwrap();
+        assert_eq!(expected[0], 0x67);
+        assert_eq!(expected[15], 0xc8);
+
+        for s in [
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        ] {
+            assert_eq!(parse_uuid(s.as_bytes()), Some(expected), "{s}");
+        }
+
+        let mut buf = String::new();
+        format_uuid(&expected, &mut buf);
+        assert_eq!(buf, canonical);
+
+        for s in [
+            "",
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+            "67e55044-10b1-426f-9247_bb680e5fe0c8",
+            "67e55044-10b1-426f-9247-bb680e5fe0cg",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "+7e55044-10b1-426f-9247-bb680e5fe0c8",
+        ] {
+            assert_eq!(parse_uuid(s.as_bytes()), None, "{s}");
+        }
+    }
+}
@@ -11,7 +11,7 @@ use arrow::offset::OffsetsBuffer;
 use arrow::types::NativeType;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_type;
-use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash, TotalOrdWrap};
+use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash, TotalOrd, TotalOrdWrap};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -102,6 +102,53 @@ where
     }
 }
 
+/// The unique values of the sorted slice `a` that are (or are not) in the sorted slice `b`.
+fn sorted_filter<'a, T: TotalOrd + Copy>(
+    a: &'a [T],
+    b: &'a [T],
+    in_b: bool,
+) -> impl Iterator<Item = Option<T>> + 'a {
+    let mut j = 0;
+    a.iter()
+        .enumerate()
+        .filter(move |(i, v)| *i == 0 || a[i - 1].tot_ne(*v))
+        .filter(move |(_, v)| {
+            while j < b.len() && b[j].tot_lt(*v) {
+                j += 1;
+            }
+            (j < b.len() && b[j].tot_eq(*v)) == in_b
+        })
+        .map(|(_, v)| Some(*v))
+}
+
+/// Merge-based variant of [`set_operation`] for sorted lists without nulls.
+///
+/// It yields the same values in the same order as the hash-based variant, except for the
+/// difference, which is returned in sorted order.
+fn sorted_set_operation<T: NativeType + TotalOrd>(
+    a: &[T],
+    b: &[T],
+    out: &mut MutablePrimitiveArray<T>,
+    set_op: SetOperation,
+) -> usize {
+    match set_op {
+        SetOperation::Intersection => out.extend_buf(sorted_filter(a, b, true)),
+        SetOperation::Union => {
+            out.extend_buf(sorted_filter(a, &[], false));
+            out.extend_buf(sorted_filter(b, a, false))
+        },
+        SetOperation::Difference => out.extend_buf(sorted_filter(a, b, false)),
+        SetOperation::SymmetricDifference => {
+            out.extend_buf(sorted_filter(a, b, false));
+            out.extend_buf(sorted_filter(b, a, false))
+        },
+    }
+}
+
+fn is_sorted<T: TotalOrd>(values: &[T]) -> bool {
+    values.windows(2).all(|w| w[0].tot_le(&w[1]))
+}
+
 fn copied_wrapper_opt<T: Copy + TotalEq + TotalHash>(
     v: Option<&T>,
 ) -> <Option<T> as ToTotalOrd>::TotalOrdItem {
@@ -138,11 +185,13 @@ fn primitive<T>(
     validity: Option<Bitmap>,
 ) -> PolarsResult<ListArray<i64>>
 where
-    T: NativeType + TotalHash + TotalEq + Copy + ToTotalOrd,
+    T: NativeType + TotalHash + TotalEq + TotalOrd + Copy + ToTotalOrd,
     <Option<T> as ToTotalOrd>::TotalOrdItem: Hash + Eq + Copy,
 {
     let broadcast_lhs = offsets_a.len() == 2;
     let broadcast_rhs = offsets_b.len() == 2;
+    // Sorted lists (e.g. the output of `list.sort`) are merged instead of hashed.
+    let try_sorted = a.null_count() == 0 && b.null_count() == 0;
 
     let mut set = Default::default();
     let mut set2: PlIndexSet<<Option<T> as ToTotalOrd>::TotalOrdItem> = Default::default();
@@ -179,6 +228,26 @@ where
         let start_b = *offsets_b.get(i - 1).unwrap_or(&first_b) as usize;
         let end_b = *offsets_b.get(i).unwrap_or(&second_b) as usize;
 
+        let (start_b, end_b) = if broadcast_rhs {
+            (first_b as usize, second_b as usize)
+        } else {
+            (start_b, end_b)
+        };
+        let (start_a, end_a) = if broadcast_lhs {
+            (first_a as usize, second_a as usize)
+        } else {
+            (start_a, end_a)
+        };
+        if try_sorted {
+            let values_a = &a.values()[start_a..end_a];
+            let values_b = &b.values()[start_b..end_b];
+            if is_sorted(values_a) && is_sorted(values_b) {
+                let offset = sorted_set_operation(values_a, values_b, &mut values_out, set_op);
+                offsets.push(offset as i64);
+                continue;
+            }
+        }
+
         // The branches are the same every loop.
         // We rely on branch prediction here.
         let offset = if broadcast_rhs {
@@ -1,7 +1,6 @@
 #![allow(unsafe_op_in_unsafe_fn)]
 use arrow::array::{Array, PrimitiveArray};
 use polars_core::prelude::*;
-use polars_core::series::IsSorted;
 use polars_core::utils::arrow::bitmap::MutableBitmap;
 use polars_core::utils::arrow::types::NativeType;
 use polars_utils::index::check_bounds;
@@ -104,32 +103,18 @@ where
         check_bounds(idx, self.len() as IdxSize)?;
         let mut ca = std::mem::take(self);
         ca.rechunk_mut();
+        let len = ca.len();
 
+        // Copies the values first if they are shared, and unsets the sorted flag.
+        let ptr = ca.make_values_mut().next().unwrap().as_mut_ptr();
         // SAFETY:
         // we will not modify the length
-        // and we unset the sorted flag.
-        ca.set_sorted_flag(IsSorted::Not);
         let arr = unsafe { ca.downcast_iter_mut() }.next().unwrap();
-        let len = arr.len();
-
-        match arr.get_mut_values() {
-            Some(current_values) => {
-                let ptr = current_values.as_mut_ptr();
-
-                // reborrow because the bck does not allow it
-                let current_values = unsafe { &mut *std::slice::from_raw_parts_mut(ptr, len) };
-                // SAFETY:
-                // we checked bounds
-                unsafe { scatter_impl(current_values, values, arr, idx, len) };
-            },
-            None => {
-                let mut new_values = arr.values().as_slice().to_vec();
-                // SAFETY:
-                // we checked bounds
-                unsafe { scatter_impl(&mut new_values, values, arr, idx, len) };
-                arr.set_values(new_values.into());
-            },
-        };
+        // reborrow because the bck does not allow it, the values buffer is not replaced
+        let current_values = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+        // SAFETY:
+        // we checked bounds
+        unsafe { scatter_impl(current_values, values, arr, idx, len) };
 
         // The null count may have changed - make sure to update the ChunkedArray
         let new_null_count = arr.null_count();
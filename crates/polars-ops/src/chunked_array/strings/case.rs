@@ -103,6 +103,44 @@ pub(super) fn to_lowercase<'a>(ca: &'a StringChunked) -> StringChunked {
     ca.apply_mut(f)
 }
 
+/// Full case folding, for caseless comparisons.
+///
+/// Every character is mapped to the lowercase of its full uppercase mapping, so "ß" and "ẞ"
+/// fold to "ss" and every sigma to "σ". Unlike `to_lowercase` this isn't meant for display.
+pub(super) fn to_casefold<'a>(ca: &'a StringChunked) -> StringChunked {
+    // Amortize allocation.
+    let mut buf = Vec::new();
+    let f = |s: &'a str| -> &'a str {
+        convert_while_ascii(s.as_bytes(), u8::to_ascii_lowercase, &mut buf);
+
+        // SAFETY: we know this is a valid char boundary since
+        // out.len() is only progressed if ascii bytes are found.
+        let rest = unsafe { s.get_unchecked(buf.len()..) };
+
+        // SAFETY: We have written only valid ASCII to our vec.
+        let mut s = unsafe { String::from_utf8_unchecked(std::mem::take(&mut buf)) };
+
+        for c in rest.chars() {
+            // The dotless i folds to itself rather than to "i".
+            if c == 'ı' {
+                s.push(c);
+                continue;
+            }
+            for upper in c.to_uppercase() {
+                s.extend(upper.to_lowercase());
+            }
+        }
+
+        // Put buf back for next iteration.
+        buf = s.into_bytes();
+
+        // SAFETY: apply_mut will copy value from buf before next iteration.
+        let slice = unsafe { std::str::from_utf8_unchecked(&buf) };
+        unsafe { std::mem::transmute::<&str, &'a str>(slice) }
+    };
+    ca.apply_mut(f)
+}
+
 // Inlined from std.
 pub(super) fn to_uppercase<'a>(ca: &'a StringChunked) -> StringChunked {
     // Amortize allocation.
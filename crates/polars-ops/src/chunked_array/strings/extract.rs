@@ -1,8 +1,10 @@
 use std::iter::zip;
 
 #[cfg(feature = "extract_groups")]
-use arrow::array::{Array, StructArray};
+use arrow::array::{Array, ListArray, StructArray};
 use arrow::array::{MutablePlString, Utf8ViewArray};
+#[cfg(feature = "extract_groups")]
+use arrow::offset::Offsets;
 use polars_core::prelude::arity::{try_binary_mut_with_options, try_unary_mut_with_options};
 use regex::Regex;
 
@@ -73,6 +75,73 @@ pub(super) fn extract_groups(
     Series::try_from((ca.name().clone(), chunks))
 }
 
+#[cfg(feature = "extract_groups")]
+fn extract_all_groups_array(
+    arr: &Utf8ViewArray,
+    reg: &Regex,
+    n_groups: usize,
+    dtype: ArrowDataType,
+) -> PolarsResult<ArrayRef> {
+    let mut builders = (0..n_groups)
+        .map(|_| MutablePlString::with_capacity(arr.len()))
+        .collect::<Vec<_>>();
+    let mut offsets = Offsets::<i64>::with_capacity(arr.len());
+
+    for opt_v in arr {
+        let mut n_matches = 0;
+        if let Some(s) = opt_v {
+            for caps in reg.captures_iter(s) {
+                for (i, builder) in builders.iter_mut().enumerate() {
+                    builder.push(caps.get(i + 1).map(|m| m.as_str()));
+                }
+                n_matches += 1;
+            }
+        }
+        // Null strings get an empty slot here and are masked by arr's validity mask below.
+        offsets.try_push(n_matches)?;
+    }
+
+    let ArrowDataType::LargeList(field) = &dtype else {
+        unreachable!() // Implementation error if it isn't a list.
+    };
+    let values = builders.into_iter().map(|a| a.freeze().boxed()).collect();
+    let structs = StructArray::new(
+        field.dtype().clone(),
+        *offsets.last() as usize,
+        values,
+        None,
+    );
+    Ok(ListArray::<i64>::new(
+        dtype,
+        offsets.into(),
+        structs.boxed(),
+        arr.validity().cloned(),
+    )
+    .boxed())
+}
+
+#[cfg(feature = "extract_groups")]
+pub(super) fn extract_all_groups(
+    ca: &StringChunked,
+    pat: &str,
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    let reg = polars_utils::regex_cache::compile_regex(pat)?;
+    let n_groups = reg.captures_len() - 1;
+    polars_ensure!(
+        n_groups > 0,
+        InvalidOperation: "the pattern of `extract_all_groups` must have at least one capture group"
+    );
+
+    let arrow_dtype = dtype.try_to_arrow(CompatLevel::newest())?;
+    let chunks = ca
+        .downcast_iter()
+        .map(|array| extract_all_groups_array(array, &reg, n_groups, arrow_dtype.clone()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Series::try_from((ca.name().clone(), chunks))
+}
+
 fn extract_group_reg_lit(
     arr: &Utf8ViewArray,
     reg: &Regex,
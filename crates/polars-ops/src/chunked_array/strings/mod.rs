@@ -18,6 +18,8 @@ mod normalize;
 mod pad;
 #[cfg(feature = "string_reverse")]
 mod reverse;
+#[cfg(feature = "string_similarity")]
+pub(crate) mod similarity;
 #[cfg(feature = "strings")]
 mod split;
 #[cfg(feature = "strings")]
@@ -26,6 +28,8 @@ mod strip;
 mod substring;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
+#[cfg(feature = "string_url")]
+mod url;
 
 #[cfg(feature = "strings")]
 pub use concat::*;
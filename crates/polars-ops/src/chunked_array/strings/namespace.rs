@@ -61,6 +61,20 @@ pub trait StringNameSpaceImpl: AsString {
         ca.apply_values(|s| general_purpose::STANDARD.encode(s).into())
     }
 
+    /// Parse UUIDs into their 16 bytes, see [`BinaryNameSpaceImpl::uuid_decode`].
+    #[cfg(feature = "binary_encoding")]
+    fn to_uuid(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_string();
+        ca.as_binary().uuid_decode(strict)
+    }
+
+    /// Parse IP addresses into their bytes, see [`BinaryNameSpaceImpl::ip_decode`].
+    #[cfg(feature = "binary_encoding")]
+    fn to_ip(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_string();
+        ca.as_binary().ip_decode(strict)
+    }
+
     #[cfg(feature = "string_to_integer")]
     // Parse a string number with base _radix_ into a decimal (i64)
     fn to_integer(&self, base: &UInt32Chunked, strict: bool) -> PolarsResult<Int64Chunked> {
@@ -533,6 +547,14 @@ pub trait StringNameSpaceImpl: AsString {
         super::extract::extract_groups(ca, pat, dtype)
     }
 
+    #[cfg(feature = "extract_groups")]
+    /// Extract the capture groups of every match of pattern and return them as a list of
+    /// structs.
+    fn extract_all_groups(&self, pat: &str, dtype: &DataType) -> PolarsResult<Series> {
+        let ca = self.as_string();
+        super::extract::extract_all_groups(ca, pat, dtype)
+    }
+
     /// Count all successive non-overlapping regex matches.
     fn count_matches(&self, pat: &str, literal: bool) -> PolarsResult<UInt32Chunked> {
         let ca = self.as_string();
@@ -592,6 +614,13 @@ pub trait StringNameSpaceImpl: AsString {
         case::to_lowercase(ca)
     }
 
+    /// Fold the case of the strings, for caseless comparisons.
+    #[must_use]
+    fn to_casefold(&self) -> StringChunked {
+        let ca = self.as_string();
+        case::to_casefold(ca)
+    }
+
     /// Modify the strings to their uppercase equivalent.
     #[must_use]
     fn to_uppercase(&self) -> StringChunked {
@@ -622,6 +651,14 @@ pub trait StringNameSpaceImpl: AsString {
         normalize::normalize(ca, form)
     }
 
+    /// Removes the diacritics of the string values
+    #[must_use]
+    #[cfg(feature = "string_normalize")]
+    fn str_strip_diacritics(&self) -> StringChunked {
+        let ca = self.as_string();
+        normalize::strip_diacritics(ca)
+    }
+
     /// Reverses the string values
     #[must_use]
     #[cfg(feature = "string_reverse")]
@@ -630,6 +667,64 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Compute the Levenshtein distance to the strings of `other`.
+    ///
+    /// Distances above `max_distance` are null.
+    #[cfg(feature = "string_similarity")]
+    fn str_levenshtein(&self, other: &StringChunked, max_distance: Option<u32>) -> UInt32Chunked {
+        similarity::levenshtein(self.as_string(), other, max_distance)
+    }
+
+    /// Compute the Hamming distance to the strings of `other`.
+    ///
+    /// Strings of different lengths and distances above `max_distance` are null.
+    #[cfg(feature = "string_similarity")]
+    fn str_hamming(&self, other: &StringChunked, max_distance: Option<u32>) -> UInt32Chunked {
+        similarity::hamming(self.as_string(), other, max_distance)
+    }
+
+    /// Compute the Jaro-Winkler similarity to the strings of `other`.
+    #[cfg(feature = "string_similarity")]
+    fn str_jaro_winkler(&self, other: &StringChunked) -> Float64Chunked {
+        similarity::jaro_winkler(self.as_string(), other)
+    }
+
+    /// Compute the token set ratio to the strings of `other`.
+    #[cfg(feature = "string_similarity")]
+    fn str_token_set_ratio(&self, other: &StringChunked) -> Float64Chunked {
+        similarity::token_set_ratio(self.as_string(), other)
+    }
+
+    /// Get the lowercase scheme of the URLs.
+    #[cfg(feature = "string_url")]
+    fn str_url_scheme(&self) -> StringChunked {
+        url::url_scheme(self.as_string())
+    }
+
+    /// Get the lowercase host of the URLs.
+    #[cfg(feature = "string_url")]
+    fn str_url_host(&self) -> StringChunked {
+        url::url_host(self.as_string())
+    }
+
+    /// Get the percent-encoded path of the URLs.
+    #[cfg(feature = "string_url")]
+    fn str_url_path(&self) -> StringChunked {
+        url::url_path(self.as_string())
+    }
+
+    /// Get the decoded value of the query parameter `key` of the URLs.
+    #[cfg(feature = "string_url")]
+    fn str_url_query_param(&self, key: &str) -> StringChunked {
+        url::url_query_param(self.as_string(), key)
+    }
+
+    /// Decode the percent-encoded escapes of the strings.
+    #[cfg(feature = "string_url")]
+    fn str_url_decode(&self) -> StringChunked {
+        url::url_decode(self.as_string())
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
@@ -1,5 +1,6 @@
 use polars_core::prelude::{StringChunked, StringChunkedBuilder};
 use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -36,3 +37,14 @@ pub fn normalize(ca: &StringChunked, form: UnicodeForm) -> StringChunked {
         UnicodeForm::NFKD => normalize_with(ca, |s, b| b.extend(s.nfkd())),
     }
 }
+
+/// Remove the diacritics, such as accents, from every string.
+///
+/// Characters are decomposed into a base character and combining marks with NFD, the marks
+/// are dropped and the result is recomposed with NFC. Characters without a decomposition,
+/// such as "ø" or "ł", are kept as is.
+pub fn strip_diacritics(ca: &StringChunked) -> StringChunked {
+    normalize_with(ca, |s, b| {
+        b.extend(s.nfd().filter(|c| !is_combining_mark(*c)).nfc())
+    })
+}
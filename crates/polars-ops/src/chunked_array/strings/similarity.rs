@@ -0,0 +1,286 @@
+//! Edit distances and similarity scores between two strings.
+//!
+//! All metrics compare unicode scalar values, so a multi-byte character counts as one edit.
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+
+/// Call `f` on the bytes of both strings if they are ascii, otherwise on their chars.
+fn with_units<R>(a: &str, b: &str, f: impl Fn(&[u32], &[u32]) -> R) -> R {
+    if a.is_ascii() && b.is_ascii() {
+        let a = a.bytes().map(u32::from).collect::<Vec<_>>();
+        let b = b.bytes().map(u32::from).collect::<Vec<_>>();
+        f(&a, &b)
+    } else {
+        let a = a.chars().map(u32::from).collect::<Vec<_>>();
+        let b = b.chars().map(u32::from).collect::<Vec<_>>();
+        f(&a, &b)
+    }
+}
+
+/// The Levenshtein distance, or `None` as soon as it is known to exceed `max_distance`.
+fn levenshtein_units(a: &[u32], b: &[u32], max_distance: Option<u32>) -> Option<u32> {
+    let max_distance = max_distance.map_or(usize::MAX, |d| d as usize);
+    // Iterate over the longer string so a row is as short as possible.
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if a.len() - b.len() > max_distance {
+        return None;
+    }
+
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        let mut row_min = cur[0];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + (ca != cb) as usize;
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+            row_min = row_min.min(cur[j + 1]);
+        }
+        // The distance never drops below the minimum of a row.
+        if row_min > max_distance {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    let distance = prev[b.len()];
+    (distance <= max_distance).then_some(distance as u32)
+}
+
+/// The Hamming distance, or `None` if the lengths differ or it exceeds `max_distance`.
+fn hamming_units(a: &[u32], b: &[u32], max_distance: Option<u32>) -> Option<u32> {
+    if a.len() != b.len() {
+        return None;
+    }
+    let max_distance = max_distance.unwrap_or(u32::MAX);
+    let mut distance = 0;
+    for (ca, cb) in a.iter().zip(b) {
+        distance += (ca != cb) as u32;
+        if distance > max_distance {
+            return None;
+        }
+    }
+    Some(distance)
+}
+
+fn jaro_units(a: &[u32], b: &[u32]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::with_capacity(a.len().min(b.len()));
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *ca {
+                b_matched[j] = true;
+                a_matches.push(*ca);
+                break;
+            }
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+    let b_matches = b
+        .iter()
+        .zip(&b_matched)
+        .filter_map(|(cb, matched)| matched.then_some(cb));
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(ca, cb)| ca != cb)
+        .count();
+
+    let m = a_matches.len() as f64;
+    let t = transpositions as f64 / 2.0;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+/// The Jaro-Winkler similarity, which boosts the Jaro similarity of strings with a common
+/// prefix of up to four characters.
+fn jaro_winkler_units(a: &[u32], b: &[u32]) -> f64 {
+    let jaro = jaro_units(a, b);
+    let prefix = a.iter().zip(b).take(4).take_while(|(a, b)| a == b).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// The normalized Indel similarity, `1 - (insertions + deletions) / (len(a) + len(b))`.
+fn indel_ratio(a: &str, b: &str) -> f64 {
+    with_units(a, b, |a, b| {
+        let total = a.len() + b.len();
+        if total == 0 {
+            return 1.0;
+        }
+        // The Indel distance follows from the longest common subsequence.
+        let mut prev = vec![0; b.len() + 1];
+        let mut cur = vec![0; b.len() + 1];
+        for ca in a {
+            for (j, cb) in b.iter().enumerate() {
+                cur[j + 1] = if ca == cb {
+                    prev[j] + 1
+                } else {
+                    prev[j + 1].max(cur[j])
+                };
+            }
+            std::mem::swap(&mut prev, &mut cur);
+        }
+        2.0 * prev[b.len()] as f64 / total as f64
+    })
+}
+
+fn join_tokens<'a>(first: &'a str, rest: &[&'a str]) -> String {
+    let mut out = first.to_string();
+    for token in rest {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(token);
+    }
+    out
+}
+
+/// The token set ratio: the best [`indel_ratio`] between the sorted common whitespace
+/// separated tokens and the common tokens followed by the remaining tokens of either string.
+pub(crate) fn token_set_ratio_str(a: &str, b: &str) -> f64 {
+    let mut a_tokens = a.split_whitespace().collect::<Vec<_>>();
+    let mut b_tokens = b.split_whitespace().collect::<Vec<_>>();
+    if a_tokens.is_empty() || b_tokens.is_empty() {
+        return 0.0;
+    }
+    a_tokens.sort_unstable();
+    a_tokens.dedup();
+    b_tokens.sort_unstable();
+    b_tokens.dedup();
+
+    let common = a_tokens
+        .iter()
+        .filter(|t| b_tokens.binary_search(t).is_ok())
+        .copied()
+        .collect::<Vec<_>>();
+    let a_rest = a_tokens
+        .iter()
+        .filter(|t| common.binary_search(t).is_err())
+        .copied()
+        .collect::<Vec<_>>();
+    let b_rest = b_tokens
+        .iter()
+        .filter(|t| common.binary_search(t).is_err())
+        .copied()
+        .collect::<Vec<_>>();
+    // One token set contains the other.
+    if !common.is_empty() && (a_rest.is_empty() || b_rest.is_empty()) {
+        return 1.0;
+    }
+
+    let common = join_tokens("", &common);
+    let a_joined = join_tokens(&common, &a_rest);
+    let b_joined = join_tokens(&common, &b_rest);
+    let mut ratio = indel_ratio(&a_joined, &b_joined);
+    if !common.is_empty() {
+        ratio = ratio
+            .max(indel_ratio(&common, &a_joined))
+            .max(indel_ratio(&common, &b_joined));
+    }
+    ratio
+}
+
+/// The Levenshtein distance relative to the longest string, `1 - distance / max(len(a), len(b))`,
+/// or `None` as soon as it is known to be below `min_similarity`.
+pub(crate) fn levenshtein_similarity(a: &str, b: &str, min_similarity: f64) -> Option<f64> {
+    with_units(a, b, |a, b| {
+        let max_len = a.len().max(b.len());
+        if max_len == 0 {
+            return Some(1.0);
+        }
+        // The tolerance keeps a distance that exactly meets the similarity from being cut off by
+        // floating point rounding.
+        let max_distance = ((1.0 - min_similarity) * max_len as f64 + 1e-9).floor();
+        let distance = levenshtein_units(a, b, Some(max_distance.max(0.0) as u32))?;
+        Some(1.0 - distance as f64 / max_len as f64)
+    })
+}
+
+pub(crate) fn jaro_winkler_str(a: &str, b: &str) -> f64 {
+    with_units(a, b, jaro_winkler_units)
+}
+
+/// The Levenshtein distance between every pair of strings.
+///
+/// Pairs with a distance above `max_distance` are null. The computation of such a pair stops
+/// as soon as the distance is known to exceed it.
+pub fn levenshtein(
+    ca: &StringChunked,
+    other: &StringChunked,
+    max_distance: Option<u32>,
+) -> UInt32Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        with_units(a?, b?, |a, b| levenshtein_units(a, b, max_distance))
+    })
+}
+
+/// The Hamming distance between every pair of strings.
+///
+/// Pairs of different lengths and pairs with a distance above `max_distance` are null.
+pub fn hamming(
+    ca: &StringChunked,
+    other: &StringChunked,
+    max_distance: Option<u32>,
+) -> UInt32Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        with_units(a?, b?, |a, b| hamming_units(a, b, max_distance))
+    })
+}
+
+/// The Jaro-Winkler similarity between every pair of strings, between 0 and 1.
+pub fn jaro_winkler(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        Some(with_units(a?, b?, jaro_winkler_units))
+    })
+}
+
+/// The token set ratio between every pair of strings, between 0 and 1.
+///
+/// The order and repetition of whitespace separated tokens is ignored, and a string whose
+/// tokens are a subset of the tokens of the other string has a ratio of 1.
+pub fn token_set_ratio(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        Some(token_set_ratio_str(a?, b?))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_string_similarity() {
+        let lev = |a: &str, b: &str, max| with_units(a, b, |a, b| levenshtein_units(a, b, max));
+        assert_eq!(lev("kitten", "sitting", None), Some(3));
+        assert_eq!(lev("", "abc", None), Some(3));
+        assert_eq!(lev("naïve", "naive", None), Some(1));
+        assert_eq!(lev("kitten", "sitting", Some(3)), Some(3));
+        assert_eq!(lev("kitten", "sitting", Some(2)), None);
+        assert_eq!(lev("a", "abcd", Some(2)), None);
+
+        let ham = |a: &str, b: &str, max| with_units(a, b, |a, b| hamming_units(a, b, max));
+        assert_eq!(ham("karolin", "kathrin", None), Some(3));
+        assert_eq!(ham("karolin", "kathrin", Some(2)), None);
+        assert_eq!(ham("abc", "ab", None), None);
+
+        let jw = |a: &str, b: &str| with_units(a, b, jaro_winkler_units);
+        assert!((jw("martha", "marhta") - 0.961).abs() < 1e-3);
+        assert!((jw("dixon", "dicksonx") - 0.813).abs() < 1e-3);
+        assert_eq!(jw("", ""), 1.0);
+        assert_eq!(jw("abc", ""), 0.0);
+
+        assert_eq!(token_set_ratio_str("new york mets", "mets new york"), 1.0);
+        assert_eq!(token_set_ratio_str("york", "new york mets"), 1.0);
+        assert_eq!(token_set_ratio_str("abc", ""), 0.0);
+        assert!((token_set_ratio_str("ab", "ac") - 0.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,209 @@
+//! Splitting of URLs into their components, following the generic syntax of RFC 3986.
+//!
+//! The parser is lenient: it never fails, but a component that isn't present is null.
+use std::borrow::Cow;
+
+use polars_core::prelude::*;
+
+struct UrlParts<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+}
+
+fn is_scheme(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+fn split_url(s: &str) -> UrlParts<'_> {
+    let rest = s.split_once('#').map_or(s, |(rest, _)| rest);
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+
+    let mut scheme = None;
+    let mut rest = rest;
+    if let Some(end) = rest.find([':', '/']) {
+        if rest.as_bytes()[end] == b':' && is_scheme(&rest[..end]) {
+            scheme = Some(&rest[..end]);
+            rest = &rest[end + 1..];
+        }
+    }
+
+    let mut authority = None;
+    if let Some(after) = rest.strip_prefix("//") {
+        let end = after.find('/').unwrap_or(after.len());
+        authority = Some(&after[..end]);
+        rest = &after[end..];
+    }
+
+    UrlParts {
+        scheme,
+        authority,
+        path: rest,
+        query,
+    }
+}
+
+/// The host of an authority, without the user information and the port.
+fn authority_host(authority: &str) -> Option<&str> {
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, hp)| hp);
+    let host = if host_port.starts_with('[') {
+        // An IPv6 address keeps its brackets.
+        &host_port[..host_port.find(']')? + 1]
+    } else {
+        match host_port.rsplit_once(':') {
+            Some((host, port)) if port.bytes().all(|b| b.is_ascii_digit()) => host,
+            _ => host_port,
+        }
+    };
+    (!host.is_empty()).then_some(host)
+}
+
+fn ascii_lowercase(s: &str) -> Cow<'_, str> {
+    if s.bytes().any(|b| b.is_ascii_uppercase()) {
+        Cow::Owned(s.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decode the `%XX` escapes of `s`, or `None` if the decoded bytes aren't valid UTF-8.
+///
+/// Invalid escapes are kept as is. In a query, `+` is decoded as a space.
+fn percent_decode(s: &str, plus_as_space: bool) -> Option<Cow<'_, str>> {
+    if !s.contains('%') && !(plus_as_space && s.contains('+')) {
+        return Some(Cow::Borrowed(s));
+    }
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let escaped = bytes
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| Some((hex_value(hex[0])? << 4) | hex_value(hex[1])?));
+                match escaped {
+                    Some(b) => {
+                        out.push(b);
+                        i += 3;
+                        continue;
+                    },
+                    None => out.push(b'%'),
+                }
+            },
+            b'+' if plus_as_space => out.push(b' '),
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8(out).ok().map(Cow::Owned)
+}
+
+/// The value of the first parameter named `key` in a query string, decoded.
+fn query_value<'a>(query: &'a str, key: &str) -> Option<Cow<'a, str>> {
+    query.split('&').find_map(|pair| {
+        let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+        (percent_decode(k, true)? == key).then(|| percent_decode(v, true))?
+    })
+}
+
+fn map_url<'a, F>(ca: &'a StringChunked, mut f: F) -> StringChunked
+where
+    F: FnMut(&'a str) -> Option<Cow<'a, str>>,
+{
+    let mut out: StringChunked = ca.iter().map(|opt_s| opt_s.and_then(&mut f)).collect();
+    out.rename(ca.name().clone());
+    out
+}
+
+/// The lowercase scheme of every URL, such as "https".
+pub fn url_scheme(ca: &StringChunked) -> StringChunked {
+    map_url(ca, |s| split_url(s).scheme.map(ascii_lowercase))
+}
+
+/// The lowercase host of every URL, without the user information and the port.
+///
+/// URLs without an authority, which starts with "//", have a null host.
+pub fn url_host(ca: &StringChunked) -> StringChunked {
+    map_url(ca, |s| {
+        authority_host(split_url(s).authority?).map(ascii_lowercase)
+    })
+}
+
+/// The path of every URL, still percent-encoded. The path may be empty.
+pub fn url_path(ca: &StringChunked) -> StringChunked {
+    map_url(ca, |s| Some(Cow::Borrowed(split_url(s).path)))
+}
+
+/// The decoded value of the first query parameter named `key` of every URL.
+///
+/// URLs without that parameter have a null value, and a parameter without a value, such as
+/// "?flag", has an empty value.
+pub fn url_query_param(ca: &StringChunked, key: &str) -> StringChunked {
+    map_url(ca, |s| query_value(split_url(s).query?, key))
+}
+
+/// Decode the percent-encoded escapes of every string.
+///
+/// Strings that don't decode to valid UTF-8 become null.
+pub fn url_decode(ca: &StringChunked) -> StringChunked {
+    map_url(ca, |s| percent_decode(s, false))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_url() {
+        let parts = split_url("HTTPS://user:pw@Example.com:8080/a/b%20c?x=1&y=%C3%A9+e#frag");
+        assert_eq!(parts.scheme, Some("HTTPS"));
+        assert_eq!(parts.authority, Some("user:pw@Example.com:8080"));
+        assert_eq!(parts.path, "/a/b%20c");
+        assert_eq!(parts.query, Some("x=1&y=%C3%A9+e"));
+        assert_eq!(
+            authority_host(parts.authority.unwrap()),
+            Some("Example.com")
+        );
+        assert_eq!(
+            query_value(parts.query.unwrap(), "y").as_deref(),
+            Some("é e")
+        );
+        assert_eq!(query_value(parts.query.unwrap(), "z"), None);
+
+        assert_eq!(authority_host("[::1]:80"), Some("[::1]"));
+        assert_eq!(authority_host(""), None);
+
+        let parts = split_url("mailto:someone@example.com");
+        assert_eq!(parts.scheme, Some("mailto"));
+        assert_eq!(parts.authority, None);
+        assert_eq!(parts.path, "someone@example.com");
+
+        let parts = split_url("/relative/path?q");
+        assert_eq!(parts.scheme, None);
+        assert_eq!(parts.path, "/relative/path");
+        assert_eq!(query_value(parts.query.unwrap(), "q").as_deref(), Some(""));
+
+        assert_eq!(
+            percent_decode("a%2Fb%zz%", false).as_deref(),
+            Some("a/b%zz%")
+        );
+        assert_eq!(percent_decode("a+b", false).as_deref(), Some("a+b"));
+        assert_eq!(percent_decode("%FF", false), None);
+    }
+}
@@ -272,11 +272,13 @@ fn top_k_by_impl(
     }
 
     let multithreaded = k >= 10000 && POOL.current_num_threads() > 1;
+    // The selection is deterministic, so every column selected by the same `by` gets the same
+    // rows. Ties of several `by` columns are broken by row index to keep their order stable.
     let mut sort_options = SortMultipleOptions {
         descending: descending.into_iter().map(|x| !x).collect(),
         nulls_last: vec![true; by.len()],
         multithreaded,
-        maintain_order: false,
+        maintain_order: by.len() > 1,
         limit: None,
     };
 
@@ -0,0 +1,215 @@
+//! Comparison of two frames whose rows are identified by key columns, for example the
+//! outputs of two runs of a pipeline.
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+
+use super::IntoDf;
+use super::join::*;
+
+const LEFT_INDEX: &str = "__POLARS_COMPARE_LEFT";
+const RIGHT_INDEX: &str = "__POLARS_COMPARE_RIGHT";
+
+/// The differences between two frames, found by [`DataFrameCompare::compare`].
+#[derive(Clone, Debug)]
+pub struct FrameDiff {
+    /// The rows of the left frame whose keys aren't in the right frame.
+    pub left_only: DataFrame,
+    /// The rows of the right frame whose keys aren't in the left frame.
+    pub right_only: DataFrame,
+    /// The cells that differ between rows with the same keys, one row per cell: the keys,
+    /// "column", and the "left" and "right" values formatted as strings.
+    pub changed: DataFrame,
+    /// The columns whose data types differ, with "column" and the "left" and "right" data
+    /// types. A column that is missing from one frame has a null data type there.
+    pub dtype_mismatches: DataFrame,
+}
+
+impl FrameDiff {
+    /// Whether the frames are equal, up to the order of their rows and columns.
+    pub fn is_empty(&self) -> bool {
+        [
+            &self.left_only,
+            &self.right_only,
+            &self.changed,
+            &self.dtype_mismatches,
+        ]
+        .iter()
+        .all(|df| df.height() == 0)
+    }
+}
+
+/// Whether the values of every row differ.
+///
+/// With a `tolerance`, numeric values are equal if they differ by at most the tolerance.
+fn changed_mask(l: &Series, r: &Series, tolerance: Option<f64>) -> PolarsResult<BooleanChunked> {
+    match tolerance {
+        Some(tolerance) if l.dtype().is_primitive_numeric() => {
+            let l = l.cast(&DataType::Float64)?;
+            let r = r.cast(&DataType::Float64)?;
+            Ok(l.f64()?
+                .iter()
+                .zip(r.f64()?.iter())
+                .map(|(a, b)| match (a, b) {
+                    (Some(a), Some(b)) => {
+                        !(a == b || (a.is_nan() && b.is_nan()) || (a - b).abs() <= tolerance)
+                    },
+                    (a, b) => a.is_some() != b.is_some(),
+                })
+                .collect())
+        },
+        _ => l.not_equal_missing(r),
+    }
+}
+
+fn format_values(name: &str, s: &Series) -> PolarsResult<Column> {
+    let values = (0..s.len())
+        .map(|i| {
+            let av = s.get(i)?;
+            Ok((!av.is_null()).then(|| av.str_value().into_owned()))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(Column::new(name.into(), values))
+}
+
+/// The changed cells of one column, in the layout of [`FrameDiff::changed`].
+fn changed_cells(
+    keys: &DataFrame,
+    column: &str,
+    left: &Series,
+    right: &Series,
+) -> PolarsResult<DataFrame> {
+    let n = keys.height();
+    keys.hstack(&[
+        Column::new("column".into(), vec![column; n]),
+        format_values("left", left)?,
+        format_values("right", right)?,
+    ])
+}
+
+impl<T: IntoDf> DataFrameCompare for T {}
+
+pub trait DataFrameCompare: IntoDf {
+    /// Compare this frame with `other`, matching their rows by the `keys` columns.
+    ///
+    /// The keys must identify a row in both frames, and null keys match each other. The other
+    /// columns are compared if they are in both frames and their data types have a supertype.
+    /// With a `tolerance`, numeric values that differ by at most that much are equal; otherwise
+    /// values must be equal, where NaN equals NaN and null equals null.
+    ///
+    /// The rows of [`FrameDiff::left_only`] and [`FrameDiff::right_only`] are in the order of
+    /// their frames, the cells of [`FrameDiff::changed`] are ordered by column and then by row.
+    fn compare(
+        &self,
+        other: &DataFrame,
+        keys: &[PlSmallStr],
+        tolerance: Option<f64>,
+    ) -> PolarsResult<FrameDiff> {
+        polars_ensure!(
+            !keys.is_empty(),
+            InvalidOperation: "compare requires at least one key column"
+        );
+        if let Some(tolerance) = tolerance {
+            polars_ensure!(
+                tolerance >= 0.0,
+                InvalidOperation: "compare `tolerance` must be non-negative, got {}", tolerance
+            );
+        }
+        let df = self.to_df();
+        let left_keys = df.select(keys.iter().cloned())?;
+        let right_keys = other.select(keys.iter().cloned())?;
+
+        let mut args = JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns);
+        args.validation = JoinValidation::OneToOne;
+        args.nulls_equal = true;
+        args.maintain_order = MaintainOrderJoin::LeftRight;
+        let joined = left_keys.with_row_index(LEFT_INDEX.into(), None)?.join(
+            &right_keys.with_row_index(RIGHT_INDEX.into(), None)?,
+            keys.iter().cloned(),
+            keys.iter().cloned(),
+            args,
+            None,
+        )?;
+
+        let mut left_only = Vec::new();
+        let mut right_only = Vec::new();
+        let mut matched_left = Vec::new();
+        let mut matched_right = Vec::new();
+        let left_idx = joined.column(LEFT_INDEX)?.idx()?;
+        let right_idx = joined.column(RIGHT_INDEX)?.idx()?;
+        for (l, r) in left_idx.iter().zip(right_idx.iter()) {
+            match (l, r) {
+                (Some(l), Some(r)) => {
+                    matched_left.push(l);
+                    matched_right.push(r);
+                },
+                (Some(l), None) => left_only.push(l),
+                (None, Some(r)) => right_only.push(r),
+                (None, None) => unreachable!(),
+            }
+        }
+        left_only.sort_unstable();
+        right_only.sort_unstable();
+        let matched_left = IdxCa::from_vec(PlSmallStr::EMPTY, matched_left);
+        let matched_right = IdxCa::from_vec(PlSmallStr::EMPTY, matched_right);
+        let matched_keys = left_keys.take(&matched_left)?;
+
+        let empty = Series::new_empty(PlSmallStr::EMPTY, &DataType::String);
+        let mut changed = changed_cells(&matched_keys.clear(), "", &empty, &empty)?;
+        let mut mismatches: Vec<(&str, Option<String>, Option<String>)> = Vec::new();
+        for c in df.get_columns() {
+            let Some(r) = other.column(c.name()).ok() else {
+                mismatches.push((c.name().as_str(), Some(c.dtype().to_string()), None));
+                continue;
+            };
+            if c.dtype() != r.dtype() {
+                mismatches.push((
+                    c.name().as_str(),
+                    Some(c.dtype().to_string()),
+                    Some(r.dtype().to_string()),
+                ));
+            }
+            let Ok(supertype) = try_get_supertype(c.dtype(), r.dtype()) else {
+                continue;
+            };
+            if keys.contains(c.name()) {
+                continue;
+            }
+            let l = c.take(&matched_left)?.cast(&supertype)?;
+            let r = r.take(&matched_right)?.cast(&supertype)?;
+            let (l, r) = (l.as_materialized_series(), r.as_materialized_series());
+            let mask = changed_mask(l, r, tolerance)?;
+            if mask.any() {
+                let cells = changed_cells(
+                    &matched_keys.filter(&mask)?,
+                    c.name().as_str(),
+                    &l.filter(&mask)?,
+                    &r.filter(&mask)?,
+                )?;
+                changed.vstack_mut(&cells)?;
+            }
+        }
+        for r in other.get_columns() {
+            if df.get_column_index(r.name()).is_none() {
+                mismatches.push((r.name().as_str(), None, Some(r.dtype().to_string())));
+            }
+        }
+        changed.as_single_chunk_par();
+
+        let (names, (left_dtypes, right_dtypes)): (Vec<_>, (Vec<_>, Vec<_>)) = mismatches
+            .into_iter()
+            .map(|(name, l, r)| (name, (l, r)))
+            .unzip();
+        let dtype_mismatches = DataFrame::new(vec![
+            Column::new("column".into(), names),
+            Column::new("left".into(), left_dtypes),
+            Column::new("right".into(), right_dtypes),
+        ])?;
+
+        Ok(FrameDiff {
+            left_only: df.take(&IdxCa::from_vec(PlSmallStr::EMPTY, left_only))?,
+            right_only: other.take(&IdxCa::from_vec(PlSmallStr::EMPTY, right_only))?,
+            changed,
+            dtype_mismatches,
+        })
+    }
+}
@@ -29,6 +29,7 @@ pub struct JoinArgs {
     pub nulls_equal: bool,
     pub coalesce: JoinCoalesce,
     pub maintain_order: MaintainOrderJoin,
+    pub algorithm: JoinAlgorithm,
 }
 
 impl JoinArgs {
@@ -101,6 +102,21 @@ pub enum MaintainOrderJoin {
     RightLeft,
 }
 
+/// The algorithm used to find the matching rows of an equi join.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[strum(serialize_all = "snake_case")]
+pub enum JoinAlgorithm {
+    /// Use a sorted merge join if the keys are flagged as sorted, otherwise a hash join.
+    #[default]
+    Auto,
+    Hash,
+    /// Merge the keys, which must be sorted in ascending order and have no nulls.
+    ///
+    /// Only inner, left and right joins on a single key support a merge join.
+    Merge,
+}
+
 impl MaintainOrderJoin {
     pub(super) fn flip(&self) -> Self {
         match self {
@@ -123,6 +139,7 @@ impl JoinArgs {
             nulls_equal: false,
             coalesce: Default::default(),
             maintain_order: Default::default(),
+            algorithm: Default::default(),
         }
     }
 
@@ -136,6 +153,11 @@ impl JoinArgs {
         self
     }
 
+    pub fn with_algorithm(mut self, algorithm: JoinAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
     pub fn suffix(&self) -> &PlSmallStr {
         const DEFAULT: &PlSmallStr = &PlSmallStr::from_static("_right");
         self.suffix.as_ref().unwrap_or(DEFAULT)
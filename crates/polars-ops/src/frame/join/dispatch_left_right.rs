@@ -85,6 +85,7 @@ pub fn materialize_left_join_from_series(
         verbose,
         args.validation,
         args.nulls_equal,
+        args.algorithm,
     )?;
 
     let right = if let Some(drop_names) = drop_names {
@@ -0,0 +1,246 @@
+use polars_core::POOL;
+use polars_core::prelude::*;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{_finish_join, IntoDf};
+use crate::chunked_array::strings::similarity::{
+    jaro_winkler_str, levenshtein_similarity, token_set_ratio_str,
+};
+
+/// The similarity metric of a fuzzy join. All metrics score a pair between 0 and 1.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FuzzyMethod {
+    /// `1 - levenshtein(a, b) / max(len(a), len(b))`.
+    Levenshtein,
+    #[default]
+    JaroWinkler,
+    TokenSetRatio,
+}
+
+/// How the candidate pairs of a fuzzy join are found. Only candidate pairs are scored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FuzzyBlocking {
+    /// Sort the keys of both frames together and pair every left key with the right keys that
+    /// are at most `window` positions away. This works well for keys that only differ near the
+    /// end, but misses typos in the first characters.
+    SortedNeighborhood { window: usize },
+    /// Pair every left key with the right keys that share at least one character n-gram.
+    NGram { n: usize },
+}
+
+impl Default for FuzzyBlocking {
+    fn default() -> Self {
+        Self::NGram { n: 3 }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FuzzyJoinOptions {
+    pub method: FuzzyMethod,
+    /// The minimal score of a match, between 0 and 1.
+    pub threshold: f64,
+    pub blocking: FuzzyBlocking,
+    /// The name of the score column added to the output.
+    pub score_name: PlSmallStr,
+    pub suffix: Option<PlSmallStr>,
+}
+
+impl Default for FuzzyJoinOptions {
+    fn default() -> Self {
+        Self {
+            method: FuzzyMethod::default(),
+            threshold: 0.8,
+            blocking: FuzzyBlocking::default(),
+            score_name: PlSmallStr::from_static("score"),
+            suffix: None,
+        }
+    }
+}
+
+impl FuzzyJoinOptions {
+    fn validate(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            (0.0..=1.0).contains(&self.threshold),
+            InvalidOperation: "fuzzy join `threshold` must be between 0 and 1, got {}", self.threshold
+        );
+        match self.blocking {
+            FuzzyBlocking::SortedNeighborhood { window } => {
+                polars_ensure!(window > 0, InvalidOperation: "fuzzy join `window` must be positive")
+            },
+            FuzzyBlocking::NGram { n } => {
+                polars_ensure!(n > 0, InvalidOperation: "fuzzy join n-gram size must be positive")
+            },
+        }
+        Ok(())
+    }
+
+    /// The score of a pair, or `None` if it is below the threshold.
+    fn score(&self, a: &str, b: &str) -> Option<f64> {
+        let score = match self.method {
+            // Compares the distance itself, so the computation can stop early.
+            FuzzyMethod::Levenshtein => return levenshtein_similarity(a, b, self.threshold),
+            FuzzyMethod::JaroWinkler => jaro_winkler_str(a, b),
+            FuzzyMethod::TokenSetRatio => token_set_ratio_str(a, b),
+        };
+        (score >= self.threshold).then_some(score)
+    }
+}
+
+/// The `(left, right)` candidate pairs within `window` positions in the sorted keys.
+fn sorted_neighborhood_candidates(
+    left: &StringChunked,
+    right: &StringChunked,
+    window: usize,
+) -> Vec<(IdxSize, IdxSize)> {
+    let mut keys = left
+        .iter()
+        .enumerate()
+        .filter_map(|(i, k)| Some((k?, false, i as IdxSize)))
+        .chain(
+            right
+                .iter()
+                .enumerate()
+                .filter_map(|(i, k)| Some((k?, true, i as IdxSize))),
+        )
+        .collect::<Vec<_>>();
+    keys.sort_unstable();
+
+    let mut candidates = vec![];
+    for (pos, (_, is_right, left_idx)) in keys.iter().enumerate() {
+        if *is_right {
+            continue;
+        }
+        let end = (pos + window + 1).min(keys.len());
+        for (_, is_right, right_idx) in &keys[pos.saturating_sub(window)..end] {
+            if *is_right {
+                candidates.push((*left_idx, *right_idx));
+            }
+        }
+    }
+    candidates.sort_unstable();
+    candidates
+}
+
+/// The character n-grams of `s`, or `s` itself if it is shorter than `n`.
+fn ngrams(s: &str, n: usize) -> impl Iterator<Item = &str> {
+    let bounds = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect::<Vec<_>>();
+    let n = n.min(bounds.len().saturating_sub(1)).max(1);
+    let n_grams = bounds.len().saturating_sub(n);
+    (0..n_grams).map(move |i| &s[bounds[i]..bounds[i + n]])
+}
+
+/// The `(left, right)` candidate pairs that share at least one n-gram.
+fn ngram_candidates(
+    left: &StringChunked,
+    right: &StringChunked,
+    n: usize,
+) -> Vec<(IdxSize, IdxSize)> {
+    let mut index = PlHashMap::<&str, Vec<IdxSize>>::new();
+    for (right_idx, key) in right.iter().enumerate() {
+        let Some(key) = key else { continue };
+        for gram in ngrams(key, n) {
+            let rows = index.entry(gram).or_default();
+            // A key repeating an n-gram is only added once.
+            if rows.last() != Some(&(right_idx as IdxSize)) {
+                rows.push(right_idx as IdxSize);
+            }
+        }
+    }
+
+    let per_left = POOL.install(|| {
+        (0..left.len())
+            .into_par_iter()
+            .map(|left_idx| {
+                let Some(key) = left.get(left_idx) else {
+                    return vec![];
+                };
+                let mut rows = ngrams(key, n)
+                    .filter_map(|gram| index.get(gram))
+                    .flatten()
+                    .copied()
+                    .collect::<Vec<_>>();
+                rows.sort_unstable();
+                rows.dedup();
+                rows.into_iter()
+                    .map(|right_idx| (left_idx as IdxSize, right_idx))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    });
+    per_left.into_iter().flatten().collect()
+}
+
+impl<T: IntoDf> FuzzyJoin for T {}
+
+pub trait FuzzyJoin: IntoDf {
+    /// Join the rows whose string keys are similar, for example to link records with typos.
+    ///
+    /// Candidate pairs are found with the blocking strategy of `options` and every candidate
+    /// scoring at least the threshold is returned, together with a score column. A left row may
+    /// match several right rows. Null keys never match. The output is ordered by the left and
+    /// then the right row.
+    fn join_fuzzy(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        options: &FuzzyJoinOptions,
+    ) -> PolarsResult<DataFrame> {
+        options.validate()?;
+        let df = self.to_df();
+        let left_key = df.column(left_on)?.str()?.rechunk();
+        let right_key = other.column(right_on)?.str()?.rechunk();
+        let (left_key, right_key) = (left_key.as_ref(), right_key.as_ref());
+
+        let candidates = match options.blocking {
+            FuzzyBlocking::SortedNeighborhood { window } => {
+                sorted_neighborhood_candidates(left_key, right_key, window)
+            },
+            FuzzyBlocking::NGram { n } => ngram_candidates(left_key, right_key, n),
+        };
+        let matches = POOL.install(|| {
+            candidates
+                .into_par_iter()
+                .filter_map(|(left_idx, right_idx)| {
+                    // SAFETY: the candidates are rows of the keys.
+                    let (a, b) = unsafe {
+                        (
+                            left_key.get_unchecked(left_idx as usize)?,
+                            right_key.get_unchecked(right_idx as usize)?,
+                        )
+                    };
+                    Some((left_idx, right_idx, options.score(a, b)?))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let left_idx: IdxCa = matches.iter().map(|m| m.0).collect();
+        let right_idx: IdxCa = matches.iter().map(|m| m.1).collect();
+        let score = Float64Chunked::from_vec(
+            options.score_name.clone(),
+            matches.iter().map(|m| m.2).collect(),
+        );
+        let (df_left, df_right) = POOL.join(
+            // SAFETY: the indices are rows of the frames.
+            || unsafe { df.take_unchecked(&left_idx) },
+            || unsafe { other.take_unchecked(&right_idx) },
+        );
+        let mut out = _finish_join(df_left, df_right, options.suffix.clone())?;
+        polars_ensure!(
+            out.get_column_index(&options.score_name).is_none(),
+            Duplicate: "fuzzy join score column {:?} already exists, choose another name",
+            options.score_name
+        );
+        out.with_column(score)?;
+        Ok(out)
+    }
+}
@@ -6,6 +6,7 @@ use polars_core::utils::_split_offsets;
 use polars_core::utils::flatten::flatten_par;
 
 use super::*;
+use crate::series::SeriesMethods;
 
 #[cfg(feature = "performant")]
 fn par_sorted_merge_left_impl<T>(
@@ -175,6 +176,35 @@ pub(crate) fn to_left_join_ids(
     }
 }
 
+/// Check that the keys can be joined with [`JoinAlgorithm::Merge`].
+///
+/// The sortedness check is free if the keys are flagged as sorted and a single scan otherwise.
+fn check_merge_join_keys(
+    s_left: &Series,
+    s_right: &Series,
+    validate: JoinValidation,
+) -> PolarsResult<()> {
+    polars_ensure!(
+        !validate.needs_checks(),
+        InvalidOperation: "`validate` is not supported by the merge join algorithm"
+    );
+    polars_ensure!(
+        s_left.dtype().to_physical().is_primitive_numeric(),
+        InvalidOperation: "the merge join algorithm requires numeric keys, got {}", s_left.dtype()
+    );
+    for s in [s_left, s_right] {
+        polars_ensure!(
+            !s.has_nulls(),
+            InvalidOperation: "the merge join algorithm does not support null keys, key '{}' has nulls", s.name()
+        );
+        polars_ensure!(
+            s.is_sorted(SortOptions::default())?,
+            InvalidOperation: "the merge join algorithm requires keys sorted in ascending order, key '{}' is not sorted", s.name()
+        );
+    }
+    Ok(())
+}
+
 #[cfg(feature = "performant")]
 fn create_reverse_map_from_arg_sort(mut arg_sort: IdxCa) -> Vec<IdxSize> {
     let arr = unsafe { arg_sort.chunks_mut() }.pop().unwrap();
@@ -188,7 +218,12 @@ pub(crate) fn _sort_or_hash_inner(
     _verbose: bool,
     validate: JoinValidation,
     nulls_equal: bool,
+    algorithm: JoinAlgorithm,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
+    // Without the sorted merge kernels a merge join falls back to a hash join.
+    if algorithm == JoinAlgorithm::Merge {
+        check_merge_join_keys(s_left, s_right, validate)?;
+    }
     s_left.hash_join_inner(s_right, validate, nulls_equal)
 }
 
@@ -199,7 +234,20 @@ pub(crate) fn _sort_or_hash_inner(
     verbose: bool,
     validate: JoinValidation,
     nulls_equal: bool,
+    algorithm: JoinAlgorithm,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
+    match algorithm {
+        JoinAlgorithm::Auto => {},
+        JoinAlgorithm::Hash => return s_left.hash_join_inner(s_right, validate, nulls_equal),
+        JoinAlgorithm::Merge => {
+            check_merge_join_keys(s_left, s_right, validate)?;
+            if verbose {
+                eprintln!("inner join: use sorted merge join");
+            }
+            return Ok((par_sorted_merge_inner_no_nulls(s_left, s_right), true));
+        },
+    }
+
     // We check if keys are sorted.
     // - If they are we can do a sorted merge join
     // If one of the keys is not, it can still be faster to sort that key and use
@@ -236,6 +284,7 @@ pub(crate) fn _sort_or_hash_inner(
                 multithreaded: true,
                 maintain_order: false,
                 limit: None,
+                nan_position: NanPosition::Largest,
             });
             let s_right = unsafe { s_right.take_unchecked(&sort_idx) };
             let ids = par_sorted_merge_inner_no_nulls(s_left, &s_right);
@@ -264,6 +313,7 @@ pub(crate) fn _sort_or_hash_inner(
                 multithreaded: true,
                 maintain_order: false,
                 limit: None,
+                nan_position: NanPosition::Largest,
             });
             let s_left = unsafe { s_left.take_unchecked(&sort_idx) };
             let ids = par_sorted_merge_inner_no_nulls(&s_left, s_right);
@@ -291,7 +341,11 @@ pub(crate) fn sort_or_hash_left(
     _verbose: bool,
     validate: JoinValidation,
     nulls_equal: bool,
+    algorithm: JoinAlgorithm,
 ) -> PolarsResult<LeftJoinIds> {
+    if algorithm == JoinAlgorithm::Merge {
+        check_merge_join_keys(s_left, s_right, validate)?;
+    }
     s_left.hash_join_left(s_right, validate, nulls_equal)
 }
 
@@ -302,7 +356,21 @@ pub(crate) fn sort_or_hash_left(
     verbose: bool,
     validate: JoinValidation,
     nulls_equal: bool,
+    algorithm: JoinAlgorithm,
 ) -> PolarsResult<LeftJoinIds> {
+    match algorithm {
+        JoinAlgorithm::Auto => {},
+        JoinAlgorithm::Hash => return s_left.hash_join_left(s_right, validate, nulls_equal),
+        JoinAlgorithm::Merge => {
+            check_merge_join_keys(s_left, s_right, validate)?;
+            if verbose {
+                eprintln!("left join: use sorted merge join");
+            }
+            let (left_idx, right_idx) = par_sorted_merge_left(s_left, s_right);
+            return Ok(to_left_join_ids(left_idx, right_idx));
+        },
+    }
+
     if validate.needs_checks() {
         return s_left.hash_join_left(s_right, validate, nulls_equal);
     }
@@ -336,6 +404,7 @@ pub(crate) fn sort_or_hash_left(
                 multithreaded: true,
                 maintain_order: false,
                 limit: None,
+                nan_position: NanPosition::Largest,
             });
             let s_right = unsafe { s_right.take_unchecked(&sort_idx) };
 
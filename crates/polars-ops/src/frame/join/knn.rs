@@ -0,0 +1,212 @@
+use polars_core::POOL;
+use polars_core::prelude::*;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::IntoDf;
+use crate::chunked_array::array::linalg::{
+    dot_slice, l2_norm_slice, squared_l2_distance_slice, sub_arrays,
+};
+
+/// The distance between two vectors in a nearest-neighbor join. A smaller distance is closer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum KnnMetric {
+    /// The Euclidean distance.
+    #[default]
+    L2,
+    /// `1 - cosine_similarity(a, b)`. Zero vectors have no neighbors.
+    Cosine,
+    /// The negated dot product, for vectors that are already normalized.
+    InnerProduct,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KnnJoinOptions {
+    /// The number of neighbors of every left row.
+    pub k: usize,
+    pub metric: KnnMetric,
+    /// The name of the list column with the row indices of the neighbors in the right frame.
+    pub index_name: PlSmallStr,
+    /// The name of the list column with the distances to the neighbors.
+    pub distance_name: PlSmallStr,
+}
+
+impl Default for KnnJoinOptions {
+    fn default() -> Self {
+        Self {
+            k: 1,
+            metric: KnnMetric::default(),
+            index_name: PlSmallStr::from_static("knn_index"),
+            distance_name: PlSmallStr::from_static("knn_distance"),
+        }
+    }
+}
+
+/// The vectors of an embedding column, cast to `Float64`.
+fn vectors(df: &DataFrame, on: &str) -> PolarsResult<ArrayChunked> {
+    let c = df.column(on)?;
+    let DataType::Array(inner, width) = c.dtype() else {
+        polars_bail!(InvalidOperation: "knn join requires an Array column, got {} for {:?}", c.dtype(), on)
+    };
+    polars_ensure!(
+        inner.is_primitive_numeric(),
+        InvalidOperation: "knn join requires numeric vectors, got {} for {:?}", c.dtype(), on
+    );
+    let c = c.cast(&DataType::Array(Box::new(DataType::Float64), *width))?;
+    Ok(c.array()?.rechunk().into_owned())
+}
+
+/// Write the distances of `query` to all `candidates` to `out`, skipping undefined distances.
+fn distances(
+    query: &[f64],
+    candidates: &[(IdxSize, &[f64], f64)],
+    metric: KnnMetric,
+    out: &mut Vec<(f64, IdxSize)>,
+) {
+    out.clear();
+    match metric {
+        KnnMetric::L2 => out.extend(
+            candidates
+                .iter()
+                .map(|(idx, v, _)| (squared_l2_distance_slice(query, v), *idx)),
+        ),
+        KnnMetric::Cosine => {
+            let norm = l2_norm_slice(query);
+            out.extend(
+                candidates
+                    .iter()
+                    .map(|(idx, v, v_norm)| (1.0 - dot_slice(query, v) / (norm * v_norm), *idx)),
+            )
+        },
+        KnnMetric::InnerProduct => out.extend(
+            candidates
+                .iter()
+                .map(|(idx, v, _)| (-dot_slice(query, v), *idx)),
+        ),
+    }
+    // Zero vectors have an undefined cosine distance.
+    out.retain(|(d, _)| !d.is_nan());
+}
+
+/// Sort the `k` smallest distances to the front of `dist`, breaking ties by the row index.
+fn select_k(dist: &mut Vec<(f64, IdxSize)>, k: usize) {
+    let cmp = |a: &(f64, IdxSize), b: &(f64, IdxSize)| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1));
+    if dist.len() > k {
+        dist.select_nth_unstable_by(k, cmp);
+        dist.truncate(k);
+    }
+    dist.sort_unstable_by(cmp);
+}
+
+impl<T: IntoDf> KnnJoin for T {}
+
+pub trait KnnJoin: IntoDf {
+    /// Find the `k` nearest rows of `other` for every row of this frame, comparing the vectors
+    /// of two `Array` columns, for example to look up similar embeddings.
+    ///
+    /// The search is exact: every left vector is compared with every right vector. The output is
+    /// this frame with two list columns, holding the row indices of the neighbors in `other` and
+    /// their distances, ordered from the nearest neighbor. Vectors that are null or contain a
+    /// null have no neighbors, and a null left vector has null lists.
+    fn join_knn(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        options: &KnnJoinOptions,
+    ) -> PolarsResult<DataFrame> {
+        polars_ensure!(options.k > 0, InvalidOperation: "knn join `k` must be positive");
+        let df = self.to_df();
+        let left = vectors(df, left_on)?;
+        let right = vectors(other, right_on)?;
+        polars_ensure!(
+            left.width() == right.width(),
+            ShapeMismatch: "knn join requires vectors of the same width, got {} and {}",
+            left.width(), right.width()
+        );
+
+        let candidates = sub_arrays::<f64>(&right)
+            .enumerate()
+            .filter_map(|(idx, v)| {
+                let v = v?;
+                let norm = match options.metric {
+                    KnnMetric::Cosine => l2_norm_slice(v),
+                    _ => 0.0,
+                };
+                Some((idx as IdxSize, v, norm))
+            })
+            .collect::<Vec<_>>();
+        let queries = sub_arrays::<f64>(&left).collect::<Vec<_>>();
+
+        let neighbors = POOL.install(|| {
+            queries
+                .into_par_iter()
+                .map_init(Vec::new, |dist, query| {
+                    let query = query?;
+                    distances(query, &candidates, options.metric, dist);
+                    select_k(dist, options.k);
+                    if options.metric == KnnMetric::L2 {
+                        dist.iter_mut().for_each(|(d, _)| *d = d.sqrt());
+                    }
+                    Some(dist.clone())
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let n_values = neighbors.iter().flatten().map(Vec::len).sum();
+        let mut index = ListPrimitiveChunkedBuilder::<IdxType>::new(
+            options.index_name.clone(),
+            neighbors.len(),
+            n_values,
+            IDX_DTYPE,
+        );
+        let mut distance = ListPrimitiveChunkedBuilder::<Float64Type>::new(
+            options.distance_name.clone(),
+            neighbors.len(),
+            n_values,
+            DataType::Float64,
+        );
+        for row in &neighbors {
+            match row {
+                Some(row) => {
+                    index.append_values_iter(row.iter().map(|(_, idx)| *idx));
+                    distance.append_values_iter(row.iter().map(|(d, _)| *d));
+                },
+                None => {
+                    index.append_null();
+                    distance.append_null();
+                },
+            }
+        }
+
+        let mut out = df.clone();
+        for column in [index.finish(), distance.finish()] {
+            polars_ensure!(
+                out.get_column_index(column.name()).is_none(),
+                Duplicate: "knn join column {:?} already exists, choose another name",
+                column.name()
+            );
+            out.with_column(column)?;
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_select_k() {
+        let mut dist = vec![(3.0, 0), (1.0, 1), (2.0, 2), (1.0, 3)];
+        select_k(&mut dist, 3);
+        assert_eq!(dist, &[(1.0, 1), (1.0, 3), (2.0, 2)]);
+
+        let mut dist = vec![(2.0, 0)];
+        select_k(&mut dist, 3);
+        assert_eq!(dist, &[(2.0, 0)]);
+    }
+}
@@ -0,0 +1,152 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// What happens to a target row whose keys match a source row.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WhenMatched {
+    /// Replace the values of the target row with those of the source row.
+    #[default]
+    Update,
+    /// Remove the target row.
+    Delete,
+    /// Keep the target row as it is.
+    Ignore,
+}
+
+/// What happens to a source row whose keys match no target row.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WhenNotMatched {
+    /// Append the source row to the target.
+    #[default]
+    Insert,
+    /// Drop the source row.
+    Ignore,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MergeIntoOptions {
+    pub when_matched: WhenMatched,
+    pub when_not_matched: WhenNotMatched,
+}
+
+/// The suffix of the source columns in the joined frame.
+pub const MERGE_SOURCE_SUFFIX: &str = "__POLARS_MERGE_SOURCE";
+/// A column that is non-null for the rows of the joined frame that have a target row.
+pub const MERGE_IN_TARGET: &str = "__POLARS_MERGE_IN_TARGET";
+/// A column that is non-null for the rows of the joined frame that have a source row.
+pub const MERGE_IN_SOURCE: &str = "__POLARS_MERGE_IN_SOURCE";
+
+/// Check that the source can be merged into the target on `keys`: every source column is a
+/// target column, and the keys are columns of both.
+pub fn validate_merge_into(
+    target: &Schema,
+    source: &Schema,
+    keys: &[PlSmallStr],
+) -> PolarsResult<()> {
+    polars_ensure!(!keys.is_empty(), InvalidOperation: "merge_into needs at least one key");
+    for key in keys {
+        polars_ensure!(
+            source.contains(key),
+            ColumnNotFound: "merge key '{}' is not a column of the source", key
+        );
+    }
+    for name in source.iter_names() {
+        polars_ensure!(
+            target.contains(name),
+            ColumnNotFound: "source column '{}' is not a column of the target", name
+        );
+    }
+    Ok(())
+}
+
+/// The [`JoinArgs`] of the full join of the target with the source. The source keys must be
+/// unique, so that a target row matches at most one source row.
+pub fn merge_into_join_args() -> JoinArgs {
+    JoinArgs {
+        validation: JoinValidation::ManyToOne,
+        suffix: Some(PlSmallStr::from_static(MERGE_SOURCE_SUFFIX)),
+        coalesce: JoinCoalesce::CoalesceColumns,
+        maintain_order: MaintainOrderJoin::LeftRight,
+        ..JoinArgs::new(JoinType::Full)
+    }
+}
+
+pub trait MergeInto: IntoDf {
+    /// Merge the changes of `source` into this frame, on the `keys` columns (the `MERGE INTO`
+    /// of SQL, or an upsert).
+    ///
+    /// Target rows whose keys match a source row are updated, deleted or kept according to
+    /// [`MergeIntoOptions::when_matched`], and source rows that match no target row are
+    /// inserted or dropped according to [`MergeIntoOptions::when_not_matched`]. Target columns
+    /// that are missing from the source keep their values on update, and are null for
+    /// inserted rows. The result has the columns of the target, with the target rows in their
+    /// order followed by the inserted rows.
+    ///
+    /// The rows are merged with a single full join, so the keys of `source` must be unique;
+    /// the keys of the target need not be.
+    fn merge_into(
+        &self,
+        source: &DataFrame,
+        keys: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+        options: MergeIntoOptions,
+    ) -> PolarsResult<DataFrame> {
+        let target = self.to_df();
+        let keys = keys.into_iter().map(Into::into).collect::<Vec<_>>();
+        validate_merge_into(target.schema(), source.schema(), &keys)?;
+
+        let marker = |df: &DataFrame, name: &str| -> PolarsResult<DataFrame> {
+            let mut df = df.clone();
+            df.with_column(Column::new_scalar(
+                PlSmallStr::from_str(name),
+                Scalar::new(DataType::Boolean, AnyValue::Boolean(true)),
+                df.height(),
+            ))?;
+            Ok(df)
+        };
+        let joined = marker(target, MERGE_IN_TARGET)?.join(
+            &marker(source, MERGE_IN_SOURCE)?,
+            keys.iter().cloned(),
+            keys.iter().cloned(),
+            merge_into_join_args(),
+            None,
+        )?;
+        let in_target = joined.column(MERGE_IN_TARGET)?.is_not_null();
+        let in_source = joined.column(MERGE_IN_SOURCE)?.is_not_null();
+
+        let columns = target
+            .get_columns()
+            .iter()
+            .map(|c| {
+                let name = c.name();
+                let value = joined.column(name)?;
+                if keys.contains(name) || !source.schema().contains(name) {
+                    return Ok(value.clone());
+                }
+                let source_value = joined.column(&_join_suffix_name(name, MERGE_SOURCE_SUFFIX))?;
+                match options.when_matched {
+                    WhenMatched::Update => source_value.zip_with(&in_source, value),
+                    WhenMatched::Delete | WhenMatched::Ignore => {
+                        value.zip_with(&in_target, source_value)
+                    },
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut keep = in_target.clone();
+        if options.when_matched == WhenMatched::Delete {
+            keep = &keep & &!&in_source;
+        }
+        if options.when_not_matched == WhenNotMatched::Insert {
+            keep = &keep | &!&in_target;
+        }
+        DataFrame::new(columns)?.filter(&keep)
+    }
+}
+
+impl MergeInto for DataFrame {}
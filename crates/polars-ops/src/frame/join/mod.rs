@@ -5,10 +5,16 @@ mod asof;
 mod checks;
 mod cross_join;
 mod dispatch_left_right;
+#[cfg(feature = "fuzzy_join")]
+mod fuzzy;
 mod general;
 mod hash_join;
 #[cfg(feature = "iejoin")]
 mod iejoin;
+#[cfg(feature = "knn_join")]
+mod knn;
+#[cfg(feature = "merge_into")]
+mod merge_into;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
 
@@ -25,6 +31,8 @@ pub(crate) use checks::*;
 pub use cross_join::CrossJoin;
 #[cfg(feature = "chunked_ids")]
 use either::Either;
+#[cfg(feature = "fuzzy_join")]
+pub use fuzzy::{FuzzyBlocking, FuzzyJoin, FuzzyJoinOptions, FuzzyMethod};
 #[cfg(feature = "chunked_ids")]
 use general::create_chunked_index_mapping;
 pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
@@ -32,6 +40,10 @@ pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
 #[cfg(feature = "iejoin")]
 pub use iejoin::{IEJoinOptions, InequalityOperator};
+#[cfg(feature = "knn_join")]
+pub use knn::{KnnJoin, KnnJoinOptions, KnnMetric};
+#[cfg(feature = "merge_into")]
+pub use merge_into::*;
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::POOL;
@@ -258,6 +270,13 @@ pub trait DataFrameJoinOps: IntoDf {
             );
         }
 
+        polars_ensure!(
+            args.algorithm != JoinAlgorithm::Merge
+                || (selected_left.len() == 1
+                    && matches!(args.how, JoinType::Inner | JoinType::Left | JoinType::Right)),
+            InvalidOperation: "the merge join algorithm only supports inner, left and right joins on a single key"
+        );
+
         // Single keys.
         if selected_left.len() == 1 {
             let s_left = &selected_left[0];
@@ -561,8 +580,14 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         let left_df = self.to_df();
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
-        let ((join_tuples_left, join_tuples_right), sorted) =
-            _sort_or_hash_inner(s_left, s_right, verbose, args.validation, args.nulls_equal)?;
+        let ((join_tuples_left, join_tuples_right), sorted) = _sort_or_hash_inner(
+            s_left,
+            s_right,
+            verbose,
+            args.validation,
+            args.nulls_equal,
+            args.algorithm,
+        )?;
 
         let mut join_tuples_left = &*join_tuples_left;
         let mut join_tuples_right = &*join_tuples_right;
@@ -673,5 +698,12 @@ pub fn private_left_join_multiple_keys(
 
     let a = prepare_keys_multiple(&a_cols, nulls_equal)?.into_series();
     let b = prepare_keys_multiple(&b_cols, nulls_equal)?.into_series();
-    sort_or_hash_left(&a, &b, false, JoinValidation::ManyToMany, nulls_equal)
+    sort_or_hash_left(
+        &a,
+        &b,
+        false,
+        JoinValidation::ManyToMany,
+        nulls_equal,
+        JoinAlgorithm::Auto,
+    )
 }
@@ -1,7 +1,13 @@
+#[cfg(feature = "frame_compare")]
+mod compare;
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "split")]
+mod split;
 
+#[cfg(feature = "frame_compare")]
+pub use compare::*;
 pub use join::*;
 #[cfg(feature = "to_dummies")]
 use polars_core::POOL;
@@ -10,6 +16,8 @@ use polars_core::prelude::*;
 use polars_core::utils::accumulate_dataframes_horizontal;
 #[cfg(feature = "to_dummies")]
 use rayon::prelude::*;
+#[cfg(feature = "split")]
+pub use split::*;
 
 pub trait IntoDf {
     fn to_df(&self) -> &DataFrame;
@@ -9,7 +9,7 @@ use polars_core::utils::_split_offsets;
 use polars_core::{POOL, downcast_as_macro_arg_physical};
 use polars_utils::format_pl_smallstr;
 use rayon::prelude::*;
-pub use unpivot::UnpivotDF;
+pub use unpivot::{UnpivotDF, UnpivotGroups, UnpivotGroupsArgs};
 
 const HASHMAP_INIT_SIZE: usize = 512;
 
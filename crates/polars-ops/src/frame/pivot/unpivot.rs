@@ -3,13 +3,123 @@ use arrow::compute::concatenate::concatenate_unchecked;
 use polars_core::datatypes::{DataType, PlSmallStr};
 use polars_core::frame::DataFrame;
 use polars_core::frame::column::Column;
-use polars_core::prelude::{IntoVec, Series, UnpivotArgsIR};
+use polars_core::prelude::{AnyValue, IntoVec, Scalar, Schema, Series, UnpivotArgsIR};
 use polars_core::utils::merge_dtypes_many;
-use polars_error::{PolarsResult, polars_err};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_utils::aliases::PlHashSet;
 
 use crate::frame::IntoDf;
 
+/// Arguments of [`UnpivotDF::unpivot_groups`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnpivotGroupsArgs {
+    /// The stubs of the groups of columns; a column `{stub}{separator}{suffix}` holds the value
+    /// of `stub` for `suffix`. Every stub becomes a value column.
+    pub stubs: Vec<PlSmallStr>,
+    /// The columns to repeat for every suffix.
+    pub index: Vec<PlSmallStr>,
+    pub separator: PlSmallStr,
+    /// The name of the column of the suffixes, `"variable"` by default.
+    pub suffix_name: Option<PlSmallStr>,
+}
+
+impl Default for UnpivotGroupsArgs {
+    fn default() -> Self {
+        Self {
+            stubs: vec![],
+            index: vec![],
+            separator: PlSmallStr::from_static("_"),
+            suffix_name: None,
+        }
+    }
+}
+
+/// How the columns of a frame are unpivoted by [`UnpivotDF::unpivot_groups`].
+#[derive(Clone, Debug)]
+pub struct UnpivotGroups {
+    /// The suffixes, in the order in which they first appear in the columns.
+    pub suffixes: Vec<PlSmallStr>,
+    /// For every stub, the column of every suffix (if there is one) and the supertype of its
+    /// columns.
+    pub stubs: Vec<(Vec<Option<PlSmallStr>>, DataType)>,
+}
+
+impl UnpivotGroups {
+    /// Match the columns of `schema` to the stubs of `args`. A column matching several stubs is
+    /// taken by the longest.
+    pub fn try_new(schema: &Schema, args: &UnpivotGroupsArgs) -> PolarsResult<Self> {
+        polars_ensure!(
+            !args.stubs.is_empty(),
+            InvalidOperation: "'unpivot_groups' needs at least one stub"
+        );
+        let suffix_name = args
+            .suffix_name
+            .as_ref()
+            .map_or("variable", PlSmallStr::as_str);
+        for name in &args.index {
+            polars_ensure!(schema.contains(name), col_not_found = name);
+            polars_ensure!(
+                !args.stubs.contains(name) && name != suffix_name,
+                Duplicate: "index column '{}' is also an output column of 'unpivot_groups'", name
+            );
+        }
+        polars_ensure!(
+            !args.stubs.iter().any(|stub| stub == suffix_name),
+            Duplicate: "stub '{}' is also the name of the suffix column", suffix_name
+        );
+
+        let mut suffixes = Vec::<PlSmallStr>::new();
+        let mut matches = vec![vec![]; args.stubs.len()];
+        for (name, dtype) in schema.iter() {
+            if args.index.contains(name) {
+                continue;
+            }
+            let matched = args
+                .stubs
+                .iter()
+                .enumerate()
+                .filter_map(|(i, stub)| {
+                    let suffix = name
+                        .strip_prefix(stub.as_str())?
+                        .strip_prefix(args.separator.as_str())?;
+                    Some((i, stub.len(), suffix))
+                })
+                .max_by_key(|(_, len, _)| *len);
+            if let Some((i, _, suffix)) = matched {
+                let suffix = match suffixes.iter().position(|s| s == suffix) {
+                    Some(pos) => pos,
+                    None => {
+                        suffixes.push(suffix.into());
+                        suffixes.len() - 1
+                    },
+                };
+                matches[i].push((suffix, name.clone(), dtype.clone()));
+            }
+        }
+
+        let stubs = args
+            .stubs
+            .iter()
+            .zip(matches)
+            .map(|(stub, matches)| {
+                if matches.is_empty() {
+                    polars_bail!(
+                        ColumnNotFound:
+                        "no column matches the stub '{}{}' of 'unpivot_groups'", stub, args.separator
+                    );
+                }
+                let dtype = merge_dtypes_many(matches.iter().map(|(_, _, dtype)| dtype))?;
+                let mut columns = vec![None; suffixes.len()];
+                for (suffix, name, _) in matches {
+                    columns[suffix] = Some(name);
+                }
+                Ok((columns, dtype))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Self { suffixes, stubs })
+    }
+}
+
 pub trait UnpivotDF: IntoDf {
     /// Unpivot a `DataFrame` from wide to long format.
     ///
@@ -193,6 +303,68 @@ pub trait UnpivotDF: IntoDf {
 
         Ok(ids)
     }
+
+    /// Unpivot several groups of columns at once, like `wide_to_long` of pandas.
+    ///
+    /// The columns `{stub}{separator}{suffix}` of every stub are unpivoted into a value column
+    /// `stub`, next to a column of the suffixes. A suffix without a column for a stub gives
+    /// nulls for that stub; columns that neither match a stub nor are in `index` are dropped.
+    ///
+    /// The suffixes are strings; cast them if they are e.g. years.
+    ///
+    /// ```ignore
+    /// # use polars_core::prelude::*;
+    /// let df = df!("id" => [1, 2],
+    ///              "x_2020" => [1.0, 2.0],
+    ///              "x_2021" => [3.0, 4.0],
+    ///              "y_2020" => ["a", "b"],
+    ///              "y_2021" => ["c", "d"]
+    ///     )?;
+    /// let args = UnpivotGroupsArgs {
+    ///     stubs: vec!["x".into(), "y".into()],
+    ///     index: vec!["id".into()],
+    ///     suffix_name: Some("year".into()),
+    ///     ..Default::default()
+    /// };
+    /// // columns: id, year, x, y
+    /// let long = df.unpivot_groups(&args)?;
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    fn unpivot_groups(&self, args: &UnpivotGroupsArgs) -> PolarsResult<DataFrame> {
+        let self_ = self.to_df();
+        let schema = self_.schema();
+        let groups = UnpivotGroups::try_new(schema, args)?;
+        let suffix_name = args
+            .suffix_name
+            .clone()
+            .unwrap_or_else(|| PlSmallStr::from_static("variable"));
+        let height = self_.height();
+        let ids = self_.select_with_schema_unchecked(args.index.iter().cloned(), schema)?;
+
+        let mut out: Option<DataFrame> = None;
+        for (i, suffix) in groups.suffixes.iter().enumerate() {
+            let mut columns = ids.get_columns().to_vec();
+            let suffix = Scalar::new(DataType::String, AnyValue::StringOwned(suffix.clone()));
+            columns.push(Column::new_scalar(suffix_name.clone(), suffix, height));
+            for (stub, (stub_columns, dtype)) in args.stubs.iter().zip(&groups.stubs) {
+                let column = match &stub_columns[i] {
+                    Some(name) => self_.column(name)?.cast(dtype)?,
+                    None => Column::full_null(stub.clone(), height, dtype),
+                };
+                columns.push(column.with_name(stub.clone()));
+            }
+            let df = unsafe { DataFrame::new_no_checks(height, columns) };
+            match out.as_mut() {
+                None => out = Some(df),
+                Some(out) => {
+                    out.vstack_mut(&df)?;
+                },
+            }
+        }
+        let mut out = out.unwrap();
+        out.as_single_chunk_par();
+        Ok(out)
+    }
 }
 
 impl UnpivotDF for DataFrame {}
@@ -291,4 +463,35 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_unpivot_groups() -> PolarsResult<()> {
+        let df = df!("id" => [1, 2],
+         "x_2020" => [1, 2],
+         "y_2020" => ["a", "b"],
+         "x_2021" => [3, 4],
+         "z" => [0, 0]
+        )?;
+
+        let args = UnpivotGroupsArgs {
+            stubs: vec!["x".into(), "y".into()],
+            index: vec!["id".into()],
+            suffix_name: Some("year".into()),
+            ..Default::default()
+        };
+        let out = df.unpivot_groups(&args)?;
+        let expected = df!("id" => [1, 2, 1, 2],
+         "year" => ["2020", "2020", "2021", "2021"],
+         "x" => [1, 2, 3, 4],
+         "y" => [Some("a"), Some("b"), None, None]
+        )?;
+        assert!(out.equals_missing(&expected), "{out}");
+
+        let args = UnpivotGroupsArgs {
+            stubs: vec!["w".into()],
+            ..Default::default()
+        };
+        assert!(df.unpivot_groups(&args).is_err());
+        Ok(())
+    }
 }
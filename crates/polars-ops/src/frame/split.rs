@@ -0,0 +1,236 @@
+use polars_core::frame::group_by::GroupsIndicator;
+use polars_core::prelude::*;
+use rand::prelude::*;
+
+use super::IntoDf;
+
+fn get_random_seed() -> u64 {
+    let mut rng = SmallRng::from_entropy();
+
+    rng.next_u64()
+}
+
+fn validate_fractions(fractions: &[f64]) -> PolarsResult<()> {
+    polars_ensure!(!fractions.is_empty(), InvalidOperation: "`fractions` must not be empty");
+    polars_ensure!(
+        fractions.iter().all(|f| f.is_finite() && *f >= 0.0),
+        InvalidOperation: "`fractions` must be non-negative, got {:?}", fractions
+    );
+    let total: f64 = fractions.iter().sum();
+    polars_ensure!(
+        (total - 1.0).abs() < 1e-6,
+        InvalidOperation: "`fractions` must sum to 1, got {}", total
+    );
+    Ok(())
+}
+
+/// Distribute `rows` over `out` in contiguous parts sized by `fractions`.
+///
+/// The sizes are allocated with the largest remainder method. `carry` holds how many rows every
+/// part is short of its exact share of the rows that were assigned before, so the rows that do
+/// not divide evenly are spread over all parts instead of always going to the same one, even if
+/// every stratum has only a single row. Every row ends up in exactly one part.
+fn assign_parts(rows: &[IdxSize], fractions: &[f64], carry: &mut [f64], out: &mut [Vec<IdxSize>]) {
+    let n = rows.len();
+    let quotas = fractions.iter().map(|f| f * n as f64).collect::<Vec<_>>();
+    let mut sizes = quotas
+        .iter()
+        .map(|q| (q.floor() as usize).min(n))
+        .collect::<Vec<_>>();
+    let assigned = sizes.iter().sum::<usize>().min(n);
+
+    let mut by_remainder = (0..fractions.len()).collect::<Vec<_>>();
+    let remainder = |i: usize| quotas[i] - sizes[i] as f64 + carry[i];
+    by_remainder.sort_by(|&l, &r| remainder(r).total_cmp(&remainder(l)));
+    for &i in by_remainder.iter().cycle().take(n - assigned) {
+        sizes[i] += 1;
+    }
+
+    let mut start = 0;
+    for (i, size) in sizes.into_iter().enumerate() {
+        let end = (start + size).min(n);
+        carry[i] += quotas[i] - (end - start) as f64;
+        out[i].extend_from_slice(&rows[start..end]);
+        start = end;
+    }
+}
+
+/// Distribute every stratum over `out`, see [`assign_parts`].
+fn assign_strata(strata: Vec<Vec<IdxSize>>, fractions: &[f64]) -> Vec<Vec<IdxSize>> {
+    let mut parts = vec![vec![]; fractions.len()];
+    let mut carry = vec![0.0; fractions.len()];
+    for rows in strata {
+        assign_parts(&rows, fractions, &mut carry, &mut parts);
+    }
+    parts
+}
+
+/// The row indices of the frame, one vector per stratum, optionally shuffled.
+fn strata(
+    df: &DataFrame,
+    shuffle: bool,
+    stratify_by: Option<&str>,
+    seed: Option<u64>,
+) -> PolarsResult<Vec<Vec<IdxSize>>> {
+    let mut strata = match stratify_by {
+        None => vec![(0..df.height() as IdxSize).collect::<Vec<_>>()],
+        Some(by) => {
+            let groups = df.group_by_stable([by])?.take_groups();
+            groups
+                .iter()
+                .map(|g| match g {
+                    GroupsIndicator::Idx((_, idx)) => idx.to_vec(),
+                    GroupsIndicator::Slice([first, len]) => (first..first + len).collect(),
+                })
+                .collect()
+        },
+    };
+    if shuffle {
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
+        strata.iter_mut().for_each(|rows| rows.shuffle(&mut rng));
+    }
+    Ok(strata)
+}
+
+/// The row indices of the frame in the order of `by`, nulls last.
+fn ordered_rows(df: &DataFrame, by: &str) -> PolarsResult<Vec<IdxSize>> {
+    let idx = df
+        .column(by)?
+        .as_materialized_series()
+        .arg_sort(SortOptions {
+            nulls_last: true,
+            maintain_order: true,
+            ..Default::default()
+        });
+    Ok(idx.into_no_null_iter().collect())
+}
+
+fn into_idx_ca(mut rows: Vec<IdxSize>) -> IdxCa {
+    rows.sort_unstable();
+    let mut ca = IdxCa::from_vec(PlSmallStr::EMPTY, rows);
+    ca.set_sorted_flag(IsSorted::Ascending);
+    ca
+}
+
+/// Iterator over the `(train, test)` row indices of a k-fold split.
+///
+/// Created by [`DataFrameSplit::k_fold`] and [`DataFrameSplit::k_fold_ordered`]. The indices are
+/// sorted in ascending order and can be passed to [`DataFrame::take`].
+pub struct KFold {
+    folds: Vec<Vec<IdxSize>>,
+    // Only train on folds preceding the test fold.
+    expanding: bool,
+    idx: usize,
+}
+
+impl Iterator for KFold {
+    type Item = (IdxCa, IdxCa);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let test_fold = if self.expanding {
+            self.idx + 1
+        } else {
+            self.idx
+        };
+        let test = self.folds.get(test_fold)?.clone();
+        let train_folds = if self.expanding {
+            &self.folds[..test_fold]
+        } else {
+            &self.folds[..]
+        };
+        let train = train_folds
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != test_fold)
+            .flat_map(|(_, rows)| rows.iter().copied())
+            .collect();
+        self.idx += 1;
+        Some((into_idx_ca(train), into_idx_ca(test)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.folds.len() - self.expanding as usize - self.idx;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for KFold {}
+
+impl<T: IntoDf> DataFrameSplit for T {}
+
+pub trait DataFrameSplit: IntoDf {
+    /// Split the rows into parts sized by `fractions`, which must sum to 1.
+    ///
+    /// Returns the row indices of every part, sorted in ascending order, so no data is copied
+    /// until the indices are used with [`DataFrame::take`]. Every row is assigned to exactly one
+    /// part.
+    ///
+    /// If `shuffle` is set the rows are assigned randomly, otherwise in their current order.
+    /// With `stratify_by`, every group of that column is split with the same fractions, so all
+    /// parts keep the group proportions of the frame.
+    fn split(
+        &self,
+        fractions: &[f64],
+        shuffle: bool,
+        stratify_by: Option<&str>,
+        seed: Option<u64>,
+    ) -> PolarsResult<Vec<IdxCa>> {
+        validate_fractions(fractions)?;
+        let parts = assign_strata(strata(self.to_df(), shuffle, stratify_by, seed)?, fractions);
+        Ok(parts.into_iter().map(into_idx_ca).collect())
+    }
+
+    /// Split the rows into consecutive parts in the order of `by`, e.g. a timestamp.
+    ///
+    /// The first part holds the earliest rows, so later parts never contain rows that precede
+    /// the rows of an earlier part.
+    fn split_ordered(&self, fractions: &[f64], by: &str) -> PolarsResult<Vec<IdxCa>> {
+        validate_fractions(fractions)?;
+        let parts = assign_strata(vec![ordered_rows(self.to_df(), by)?], fractions);
+        Ok(parts.into_iter().map(into_idx_ca).collect())
+    }
+
+    /// Create `k` folds for cross-validation.
+    ///
+    /// Every fold is used once as the test set while the other `k - 1` folds form the training
+    /// set. `shuffle`, `stratify_by` and `seed` behave as in [`DataFrameSplit::split`].
+    fn k_fold(
+        &self,
+        k: usize,
+        shuffle: bool,
+        stratify_by: Option<&str>,
+        seed: Option<u64>,
+    ) -> PolarsResult<KFold> {
+        let df = self.to_df();
+        polars_ensure!(
+            k >= 2 && k <= df.height(),
+            InvalidOperation: "`k` must be between 2 and the number of rows ({}), got {}", df.height(), k
+        );
+        let fractions = vec![1.0 / k as f64; k];
+        let folds = assign_strata(strata(df, shuffle, stratify_by, seed)?, &fractions);
+        Ok(KFold {
+            folds,
+            expanding: false,
+            idx: 0,
+        })
+    }
+
+    /// Create `k` consecutive folds in the order of `by` for time-ordered cross-validation.
+    ///
+    /// This yields `k - 1` splits: the `i`-th split tests on fold `i + 1` and trains on all
+    /// folds before it, so the model never sees rows that come after the test rows.
+    fn k_fold_ordered(&self, k: usize, by: &str) -> PolarsResult<KFold> {
+        let df = self.to_df();
+        polars_ensure!(
+            k >= 2 && k <= df.height(),
+            InvalidOperation: "`k` must be between 2 and the number of rows ({}), got {}", df.height(), k
+        );
+        let fractions = vec![1.0 / k as f64; k];
+        let folds = assign_strata(vec![ordered_rows(df, by)?], &fractions);
+        Ok(KFold {
+            folds,
+            expanding: true,
+            idx: 0,
+        })
+    }
+}
@@ -6,6 +6,10 @@ pub use crate::chunked_array::*;
 pub use crate::frame::_merge_sorted_dfs;
 pub use crate::frame::join::*;
 #[cfg(feature = "pivot")]
-pub use crate::frame::pivot::UnpivotDF;
+pub use crate::frame::pivot::{UnpivotDF, UnpivotGroupsArgs};
+#[cfg(feature = "frame_compare")]
+pub use crate::frame::{DataFrameCompare, FrameDiff};
 pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
+#[cfg(feature = "split")]
+pub use crate::frame::{DataFrameSplit, KFold};
 pub use crate::series::*;
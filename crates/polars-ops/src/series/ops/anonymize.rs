@@ -0,0 +1,184 @@
+//! Privacy transforms: keyed tokenization, format-preserving hashing and the generalization of
+//! values for k-anonymity.
+//!
+//! The keyed transforms use BLAKE3 in keyed mode with an [`AnonymizeKey`] derived from a secret.
+//! Without the secret an output cannot be linked to its input, even if all possible inputs are
+//! known (e.g. all phone numbers); with it, the same input always gives the same output, so
+//! anonymized columns can still be joined and grouped on.
+use std::fmt::Write;
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The context string of the key derivation, which separates the keys of these transforms from
+/// other uses of the same secret.
+const KEY_CONTEXT: &str = "polars anonymize v1";
+
+/// The key of the keyed anonymization transforms.
+///
+/// Only the key derived from the secret is kept, so the secret cannot be recovered from a
+/// (serialized) query plan. The derived key does allow to compute the outputs of new inputs,
+/// so serialized plans are as sensitive as the secret itself.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnonymizeKey([u8; 32]);
+
+impl AnonymizeKey {
+    pub fn new(secret: &str) -> Self {
+        Self(blake3::derive_key(KEY_CONTEXT, secret.as_bytes()))
+    }
+
+    fn hasher(&self, value: &[u8]) -> blake3::OutputReader {
+        let mut hasher = blake3::Hasher::new_keyed(&self.0);
+        hasher.update(value);
+        hasher.finalize_xof()
+    }
+}
+
+impl std::fmt::Debug for AnonymizeKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AnonymizeKey(..)")
+    }
+}
+
+/// Replace every value by a token of `length` hexadecimal characters, computed with a keyed
+/// hash of the value. Values that are not strings or binary are tokenized as strings.
+pub fn tokenize(s: &Series, key: &AnonymizeKey, length: usize) -> PolarsResult<Series> {
+    let mut bytes = vec![0; length.div_ceil(2)];
+    let mut token = |value: &[u8], buf: &mut String| {
+        key.hasher(value).fill(&mut bytes);
+        for b in &bytes {
+            write!(buf, "{b:02x}").unwrap();
+        }
+        buf.truncate(length);
+    };
+    let out = match s.dtype() {
+        DataType::Binary => s
+            .binary()?
+            .apply_into_string_amortized(|value, buf| token(value, buf)),
+        DataType::String => s
+            .str()?
+            .apply_into_string_amortized(|value, buf| token(value.as_bytes(), buf)),
+        _ => s
+            .cast(&DataType::String)?
+            .str()?
+            .apply_into_string_amortized(|value, buf| token(value.as_bytes(), buf)),
+    };
+    Ok(out.into_series())
+}
+
+/// Replace every ASCII digit, lowercase and uppercase letter of the strings by one of the same
+/// class, chosen by a keyed hash of the whole string. Other characters are kept, so the outputs
+/// have the format of the inputs (e.g. of email addresses or phone numbers).
+///
+/// This is a hash and not an encryption: the outputs cannot be turned back into the inputs,
+/// and different inputs can give the same output.
+pub fn hash_preserving_format(s: &Series, key: &AnonymizeKey) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    let mut random = [0; 4];
+    let out = ca.apply_into_string_amortized(|value, buf| {
+        let mut reader = key.hasher(value.as_bytes());
+        let mut pick = |first: u8, n: u32| {
+            reader.fill(&mut random);
+            char::from(first + (u32::from_le_bytes(random) % n) as u8)
+        };
+        buf.extend(value.chars().map(|c| match c {
+            '0'..='9' => pick(b'0', 10),
+            'a'..='z' => pick(b'a', 26),
+            'A'..='Z' => pick(b'A', 26),
+            c => c,
+        }))
+    });
+    Ok(out.into_series())
+}
+
+/// Round the numbers down to a multiple of `width`, e.g. ages to the start of their decade.
+/// The output has the type of the input; buckets that do not fit in it are null.
+pub fn bucket(s: &Series, width: f64) -> PolarsResult<Series> {
+    polars_ensure!(
+        width > 0.0 && width.is_finite(),
+        InvalidOperation: "the bucket width must be positive, got {}", width
+    );
+    let dtype = s.dtype();
+    let out = if dtype.is_float() {
+        let ca = s.cast(&DataType::Float64)?;
+        ca.f64()?
+            .apply_values(|x| (x / width).floor() * width)
+            .into_series()
+    } else if dtype.is_integer() {
+        polars_ensure!(
+            width.fract() == 0.0,
+            InvalidOperation: "the bucket width of integers must be an integer, got {}", width
+        );
+        if dtype.is_unsigned_integer() {
+            let width = width as u64;
+            let ca = s.cast(&DataType::UInt64)?;
+            ca.u64()?.apply_values(|x| x - x % width).into_series()
+        } else {
+            let width = width as i64;
+            let ca = s.cast(&DataType::Int64)?;
+            ca.i64()?
+                .apply(|x| x.and_then(|x| x.div_euclid(width).checked_mul(width)))
+                .into_series()
+        }
+    } else {
+        polars_bail!(opq = bucket, dtype);
+    };
+    out.cast(dtype)
+}
+
+/// Keep the first `length` characters of the strings, e.g. the area of zip codes. If `fill` is
+/// given, the other characters are replaced by it instead of removed.
+pub fn truncate(s: &Series, length: usize, fill: Option<char>) -> PolarsResult<Series> {
+    let ca = s.str()?;
+    let out = ca.apply_into_string_amortized(|value, buf| {
+        let mut chars = value.chars();
+        buf.extend(chars.by_ref().take(length));
+        if let Some(fill) = fill {
+            buf.extend(chars.map(|_| fill));
+        }
+    });
+    Ok(out.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_anonymize() -> PolarsResult<()> {
+        let key = AnonymizeKey::new("secret");
+        let s = Series::new(
+            "a".into(),
+            [Some("Ann-42@x.org"), Some("Ann-42@x.org"), None],
+        );
+
+        let out = hash_preserving_format(&s, &key)?;
+        let out = out.str()?;
+        assert_eq!(out.get(0), out.get(1));
+        assert_eq!(out.get(2), None);
+        let masked = out.get(0).unwrap();
+        assert_ne!(masked, "Ann-42@x.org");
+        assert_eq!(masked.len(), 12);
+        assert_eq!(&masked[3..4], "-");
+        assert!(masked[4..6].bytes().all(|b| b.is_ascii_digit()));
+
+        let tokens = tokenize(&s, &key, 7)?;
+        let other = tokenize(&s, &AnonymizeKey::new("other"), 7)?;
+        assert_eq!(tokens.str()?.get(0).unwrap().len(), 7);
+        assert_ne!(tokens.str()?.get(0), other.str()?.get(0));
+
+        let ages = Series::new("age".into(), [0i32, 9, 10, 37, -1]);
+        let out = bucket(&ages, 10.0)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            [Some(0), Some(0), Some(10), Some(30), Some(-10)]
+        );
+
+        let zips = Series::new("zip".into(), ["94107", "1234"]);
+        let out = truncate(&zips, 3, Some('*'))?;
+        assert_eq!(Vec::from(out.str()?), [Some("941**"), Some("123*")]);
+        Ok(())
+    }
+}
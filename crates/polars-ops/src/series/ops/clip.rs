@@ -2,12 +2,26 @@ use polars_core::prelude::arity::{binary_elementwise, ternary_elementwise, unary
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
 
-/// Set values outside the given boundaries to the boundary value.
-pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
+fn validate_clip_dtype(dtype: &DataType) -> PolarsResult<()> {
     polars_ensure!(
-        s.dtype().to_physical().is_primitive_numeric(),
+        dtype.to_physical().is_primitive_numeric(),
         InvalidOperation: "`clip` only supports physical numeric types"
     );
+    // The physical values of an `Enum` follow the category order, those of a `Categorical`
+    // depend on the order the strings were seen in.
+    #[cfg(feature = "dtype-categorical")]
+    polars_ensure!(
+        !matches!(dtype, DataType::Categorical(_, _)),
+        InvalidOperation: "`clip` is not supported for Categorical, cast to an Enum first"
+    );
+    Ok(())
+}
+
+/// Set values outside the given boundaries to the boundary value.
+///
+/// An `Enum` is clipped by the order of its categories.
+pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
+    validate_clip_dtype(s.dtype())?;
     let n = [s.len(), min.len(), max.len()]
         .into_iter()
         .find(|l| *l != 1)
@@ -55,10 +69,7 @@ pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
 
 /// Set values above the given maximum to the maximum value.
 pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
-    polars_ensure!(
-        s.dtype().to_physical().is_primitive_numeric(),
-        InvalidOperation: "`clip` only supports physical numeric types"
-    );
+    validate_clip_dtype(s.dtype())?;
     polars_ensure!(
         s.len() == max.len() || s.len() == 1 || max.len() == 1,
         length_mismatch = "clip(max)",
@@ -89,10 +100,7 @@ pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
 
 /// Set values below the given minimum to the minimum value.
 pub fn clip_min(s: &Series, min: &Series) -> PolarsResult<Series> {
-    polars_ensure!(
-        s.dtype().to_physical().is_primitive_numeric(),
-        InvalidOperation: "`clip` only supports physical numeric types"
-    );
+    validate_clip_dtype(s.dtype())?;
     polars_ensure!(
         s.len() == min.len() || s.len() == 1 || min.len() == 1,
         length_mismatch = "clip(min)",
@@ -65,6 +65,35 @@ where
     }
 }
 
+/// The state of a cumulative product in the log domain.
+#[derive(Default)]
+struct LogProduct {
+    /// The compensated sum of the logarithms of the absolute values.
+    sum: f64,
+    compensation: f64,
+    negative: bool,
+}
+
+fn det_log_prod(state: &mut LogProduct, v: Option<f64>) -> Option<Option<f64>> {
+    match v {
+        Some(v) => {
+            let y = v.abs().ln() - state.compensation;
+            let t = state.sum + y;
+            // Once the sum is infinite or NaN it stays so, and the compensation would be NaN.
+            state.compensation = if t.is_finite() {
+                (t - state.sum) - y
+            } else {
+                0.0
+            };
+            state.sum = t;
+            state.negative ^= v.is_sign_negative();
+            let abs = state.sum.exp();
+            Some(Some(if state.negative { -abs } else { abs }))
+        },
+        None => Some(None),
+    }
+}
+
 fn cum_max_numeric<T>(ca: &ChunkedArray<T>, reverse: bool) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -196,6 +225,31 @@ pub fn cum_prod(s: &Series, reverse: bool) -> PolarsResult<Series> {
     Ok(out)
 }
 
+/// Get an array with the cumulative product computed at every element, in the log domain.
+///
+/// The product is the exponent of the cumulative sum of the logarithms of the absolute values,
+/// with the sign tracked separately. The sum is compensated, so unlike repeated multiplication
+/// its rounding error doesn't grow with the length of the `Series`, but integer products are
+/// no longer exact. The output is `Float32` for `Float32` input and `Float64` otherwise.
+pub fn cum_prod_log_domain(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    let dtype = s.dtype();
+    if !(dtype.is_primitive_numeric() || dtype.is_bool()) {
+        polars_bail!(opq = cum_prod, dtype);
+    }
+    let ca = s.cast(&DataType::Float64)?;
+    let ca = ca.f64()?;
+    let init = LogProduct::default();
+    let out: Float64Chunked = match reverse {
+        false => ca.iter().scan(init, det_log_prod).collect_trusted(),
+        true => ca.iter().rev().scan(init, det_log_prod).collect_reversed(),
+    };
+    let out = out.with_name(s.name().clone()).into_series();
+    match dtype {
+        DataType::Float32 => out.cast(&DataType::Float32),
+        _ => Ok(out),
+    }
+}
+
 /// Get an array with the cumulative sum computed at every element
 ///
 /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
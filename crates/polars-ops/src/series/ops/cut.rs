@@ -1,4 +1,6 @@
+use arrow::array::Utf8ViewArray;
 use polars_compute::rolling::QuantileMethod;
+use polars_core::datatypes::create_enum_dtype;
 use polars_core::prelude::*;
 use polars_utils::format_pl_smallstr;
 
@@ -8,8 +10,14 @@ fn map_cats(
     sorted_breaks: &[f64],
     left_closed: bool,
     include_breaks: bool,
+    as_enum: bool,
 ) -> PolarsResult<Series> {
     let out_name = PlSmallStr::from_static("category");
+    let enum_dtype = as_enum.then(|| cut_enum_dtype(labels)).transpose()?;
+    let finish = |cats: CategoricalChunked| match &enum_dtype {
+        Some(dtype) => cats.into_series().cast(dtype),
+        None => Ok(cats.into_series()),
+    };
 
     // Create new categorical and pre-register labels for consistent categorical indexes.
     let mut bld = CategoricalChunkedBuilder::new(out_name.clone(), s.len(), Default::default());
@@ -58,14 +66,16 @@ fn map_cats(
                 },
             });
 
-        let outvals = [brk_vals.finish().into_series(), unsafe {
-            bld.finish()
-                ._with_fast_unique(label_has_value.iter().all(bool::clone))
-                .into_series()
-        }];
+        let outvals = [
+            brk_vals.finish().into_series(),
+            finish(unsafe {
+                bld.finish()
+                    ._with_fast_unique(label_has_value.iter().all(bool::clone))
+            })?,
+        ];
         Ok(StructChunked::from_series(out_name, outvals[0].len(), outvals.iter())?.into_series())
     } else {
-        Ok(unsafe {
+        finish(unsafe {
             bld.drain_iter_and_finish(s_iter.map(|opt| {
                 opt.filter(|x| !x.is_nan()).map(|x| {
                     let pt = sorted_breaks.partition_point(|v| op(&x, v));
@@ -74,23 +84,76 @@ fn map_cats(
                 })
             }))
             ._with_fast_unique(label_has_value.iter().all(bool::clone))
-        }
-        .into_series())
+        })
     }
 }
 
-pub fn compute_labels(breaks: &[f64], left_closed: bool) -> PolarsResult<Vec<PlSmallStr>> {
+/// The `Enum` data type with the bin labels as categories, ordered from the lowest to the
+/// highest bin.
+pub fn cut_enum_dtype(labels: &[PlSmallStr]) -> PolarsResult<DataType> {
+    let mut seen = PlHashSet::with_capacity(labels.len());
+    polars_ensure!(
+        labels.iter().all(|l| seen.insert(l)),
+        Duplicate: "labels must be unique to create an Enum"
+    );
+    let categories = Utf8ViewArray::from_slice_values(labels);
+    Ok(create_enum_dtype(categories))
+}
+
+/// The labels of the bins created by [`cut`], sorting `breaks` first.
+pub fn cut_labels(
+    mut breaks: Vec<f64>,
+    labels: Option<Vec<PlSmallStr>>,
+    label_format: Option<&str>,
+    left_closed: bool,
+) -> PolarsResult<Vec<PlSmallStr>> {
+    match labels {
+        Some(labels) => {
+            ensure_single_labeling(label_format)?;
+            Ok(labels)
+        },
+        None => {
+            breaks.sort_unstable_by(|a, b| a.total_cmp(b));
+            compute_labels(&breaks, left_closed, label_format)
+        },
+    }
+}
+
+fn ensure_single_labeling(label_format: Option<&str>) -> PolarsResult<()> {
+    polars_ensure!(
+        label_format.is_none(),
+        InvalidOperation: "can only provide one of `labels` or `label_format`"
+    );
+    Ok(())
+}
+
+/// The labels of the bins between the sorted `breaks`.
+///
+/// A `label_format` replaces `{lower}` and `{upper}` by the bounds of each bin, otherwise the
+/// labels are intervals such as "(1, 2]".
+pub fn compute_labels(
+    breaks: &[f64],
+    left_closed: bool,
+    label_format: Option<&str>,
+) -> PolarsResult<Vec<PlSmallStr>> {
+    if let Some(fmt) = label_format {
+        polars_ensure!(
+            fmt.contains("{lower}") || fmt.contains("{upper}"),
+            InvalidOperation: "`label_format` must contain `{{lower}}` or `{{upper}}`, got {:?}", fmt
+        );
+    }
     let lo = std::iter::once(&f64::NEG_INFINITY).chain(breaks.iter());
     let hi = breaks.iter().chain(std::iter::once(&f64::INFINITY));
 
     let ret = lo
         .zip(hi)
-        .map(|(l, h)| {
-            if left_closed {
-                format_pl_smallstr!("[{}, {})", l, h)
-            } else {
-                format_pl_smallstr!("({}, {}]", l, h)
-            }
+        .map(|(l, h)| match label_format {
+            Some(fmt) => fmt
+                .replace("{lower}", &l.to_string())
+                .replace("{upper}", &h.to_string())
+                .into(),
+            None if left_closed => format_pl_smallstr!("[{}, {})", l, h),
+            None => format_pl_smallstr!("({}, {}]", l, h),
         })
         .collect();
     Ok(ret)
@@ -100,8 +163,10 @@ pub fn cut(
     s: &Series,
     mut breaks: Vec<f64>,
     labels: Option<Vec<PlSmallStr>>,
+    label_format: Option<&str>,
     left_closed: bool,
     include_breaks: bool,
+    as_enum: bool,
 ) -> PolarsResult<Series> {
     // Breaks must be sorted to cut inputs properly.
     polars_ensure!(!breaks.iter().any(|x| x.is_nan()), ComputeError: "breaks cannot be NaN");
@@ -114,31 +179,44 @@ pub fn cut(
     }
 
     let cut_labels = if let Some(l) = labels {
+        ensure_single_labeling(label_format)?;
         polars_ensure!(l.len() == breaks.len() + 1, ShapeMismatch: "provide len(quantiles) + 1 labels");
         l
     } else {
-        compute_labels(&breaks, left_closed)?
+        compute_labels(&breaks, left_closed, label_format)?
     };
-    map_cats(s, &cut_labels, &breaks, left_closed, include_breaks)
+    map_cats(
+        s,
+        &cut_labels,
+        &breaks,
+        left_closed,
+        include_breaks,
+        as_enum,
+    )
 }
 
 pub fn qcut(
     s: &Series,
     probs: Vec<f64>,
     labels: Option<Vec<PlSmallStr>>,
+    label_format: Option<&str>,
     left_closed: bool,
     allow_duplicates: bool,
     include_breaks: bool,
+    as_enum: bool,
 ) -> PolarsResult<Series> {
     polars_ensure!(!probs.iter().any(|x| x.is_nan()), ComputeError: "quantiles cannot be NaN");
+    if labels.is_some() {
+        ensure_single_labeling(label_format)?;
+    }
 
     if s.null_count() == s.len() {
         // If we only have nulls we don't have any breakpoints.
-        return Ok(Series::full_null(
-            s.name().clone(),
-            s.len(),
-            &DataType::Categorical(None, Default::default()),
-        ));
+        let dtype = match (&labels, as_enum) {
+            (Some(labels), true) => cut_enum_dtype(labels)?,
+            _ => DataType::Categorical(None, Default::default()),
+        };
+        return Ok(Series::full_null(s.name().clone(), s.len(), &dtype));
     }
 
     let s = s.cast(&DataType::Float64)?;
@@ -157,10 +235,17 @@ pub fn qcut(
         polars_ensure!(l.len() == qbreaks.len() + 1, ShapeMismatch: "provide len(quantiles) + 1 labels");
         l
     } else {
-        compute_labels(&qbreaks, left_closed)?
+        compute_labels(&qbreaks, left_closed, label_format)?
     };
 
-    map_cats(&s, &cut_labels, &qbreaks, left_closed, include_breaks)
+    map_cats(
+        &s,
+        &cut_labels,
+        &qbreaks,
+        left_closed,
+        include_breaks,
+        as_enum,
+    )
 }
 
 mod test {
@@ -181,12 +266,12 @@ mod test {
         let left_closed = false;
 
         let include_breaks = false;
-        let out = map_cats(&s, labels, breaks, left_closed, include_breaks).unwrap();
+        let out = map_cats(&s, labels, breaks, left_closed, include_breaks, false).unwrap();
         let out = out.categorical().unwrap();
         assert!(out._can_fast_unique());
 
         let include_breaks = true;
-        let out = map_cats(&s, labels, breaks, left_closed, include_breaks).unwrap();
+        let out = map_cats(&s, labels, breaks, left_closed, include_breaks, false).unwrap();
         let out = out.struct_().unwrap().fields_as_series()[1].clone();
         let out = out.categorical().unwrap();
         assert!(out._can_fast_unique());
@@ -218,3 +218,208 @@ where
         value
     }
 }
+
+/// Exponentially-weighted moving variance, where the decay between consecutive observations
+/// is determined by the gap in `times` rather than by row position.
+///
+/// Uses the same per-observation `alpha` as [`ewm_mean_by`] and returns the biased
+/// (population) estimate, so the first non-null observation has a variance of `0`.
+pub fn ewm_var_by(
+    s: &Series,
+    times: &Series,
+    half_life: i64,
+    times_is_sorted: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.len() == times.len(),
+        length_mismatch = "ewm_var_by",
+        s.len(),
+        times.len()
+    );
+    ewm_var_or_std_by(s, times, half_life, times_is_sorted, false)
+}
+
+/// Exponentially-weighted moving standard deviation by a time column.
+///
+/// This is the square root of [`ewm_var_by`].
+pub fn ewm_std_by(
+    s: &Series,
+    times: &Series,
+    half_life: i64,
+    times_is_sorted: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.len() == times.len(),
+        length_mismatch = "ewm_std_by",
+        s.len(),
+        times.len()
+    );
+    ewm_var_or_std_by(s, times, half_life, times_is_sorted, true)
+}
+
+fn ewm_var_or_std_by(
+    s: &Series,
+    times: &Series,
+    half_life: i64,
+    times_is_sorted: bool,
+    std: bool,
+) -> PolarsResult<Series> {
+    fn func<T>(
+        values: &ChunkedArray<T>,
+        times: &Int64Chunked,
+        half_life: i64,
+        times_is_sorted: bool,
+        std: bool,
+    ) -> PolarsResult<Series>
+    where
+        T: PolarsFloatType,
+        T::Native: Float + Zero + One,
+        ChunkedArray<T>: IntoSeries,
+    {
+        let out = if times_is_sorted {
+            ewm_var_by_impl(values, times, half_life, None, std)
+        } else {
+            let sorting_indices = times.arg_sort(Default::default());
+            let sorted_values = unsafe { values.take_unchecked(&sorting_indices) };
+            let sorted_times = unsafe { times.take_unchecked(&sorting_indices) };
+            let sorting_indices = sorting_indices
+                .cont_slice()
+                .expect("`arg_sort` should have returned a single chunk");
+            ewm_var_by_impl(
+                &sorted_values,
+                &sorted_times,
+                half_life,
+                Some(sorting_indices),
+                std,
+            )
+        };
+        let mut arr =
+            T::Array::from_zeroable_vec(out, values.dtype().to_arrow(CompatLevel::newest()));
+        if (times.null_count() > 0) || (values.null_count() > 0) {
+            let validity = binary_concatenate_validities(times, values);
+            arr = arr.with_validity_typed(validity);
+        }
+        Ok(ChunkedArray::with_chunk(values.name().clone(), arr).into_series())
+    }
+
+    match (s.dtype(), times.dtype()) {
+        (DataType::Float64, DataType::Int64) => func(
+            s.f64().unwrap(),
+            times.i64().unwrap(),
+            half_life,
+            times_is_sorted,
+            std,
+        ),
+        (DataType::Float32, DataType::Int64) => func(
+            s.f32().unwrap(),
+            times.i64().unwrap(),
+            half_life,
+            times_is_sorted,
+            std,
+        ),
+        #[cfg(feature = "dtype-datetime")]
+        (_, DataType::Datetime(time_unit, _)) => {
+            let half_life = adjust_half_life_to_time_unit(half_life, time_unit);
+            ewm_var_or_std_by(
+                s,
+                &times.cast(&DataType::Int64)?,
+                half_life,
+                times_is_sorted,
+                std,
+            )
+        },
+        #[cfg(feature = "dtype-date")]
+        (_, DataType::Date) => ewm_var_or_std_by(
+            s,
+            &times.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+            half_life,
+            times_is_sorted,
+            std,
+        ),
+        (_, DataType::UInt64 | DataType::UInt32 | DataType::Int32) => ewm_var_or_std_by(
+            s,
+            &times.cast(&DataType::Int64)?,
+            half_life,
+            times_is_sorted,
+            std,
+        ),
+        (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => {
+            ewm_var_or_std_by(
+                &s.cast(&DataType::Float64)?,
+                times,
+                half_life,
+                times_is_sorted,
+                std,
+            )
+        },
+        _ => {
+            polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
+                Int64, Int32, UInt64, UInt32, and `by` to be Date, Datetime, Int64, Int32, \
+                UInt64, or UInt32")
+        },
+    }
+}
+
+/// Computes the variance (or standard deviation) over `values` ordered by `times`.
+///
+/// If `sorting_indices` is given, `values` and `times` have been sorted on behalf of the user
+/// and the result for the `i`-th sorted row is written to `sorting_indices[i]`. Rows where
+/// either `values` or `times` is null are skipped and left zeroed; the caller masks them.
+fn ewm_var_by_impl<T>(
+    values: &ChunkedArray<T>,
+    times: &Int64Chunked,
+    half_life: i64,
+    sorting_indices: Option<&[IdxSize]>,
+    std: bool,
+) -> Vec<T::Native>
+where
+    T: PolarsFloatType,
+    T::Native: Float + Zero + One,
+{
+    let mut out: Vec<_> = zeroed_vec(times.len());
+
+    // (prev_time, mean, var) of the last non-null observation.
+    let mut state: Option<(i64, T::Native, T::Native)> = None;
+    values
+        .iter()
+        .zip(times.iter())
+        .enumerate()
+        .for_each(|(idx, (value, time))| {
+            let (Some(time), Some(value)) = (time, value) else {
+                return;
+            };
+            let (mean, var) = match state {
+                None => (value, T::Native::zero()),
+                Some((prev_time, prev_mean, prev_var)) => {
+                    let alpha: T::Native = alpha(time, prev_time, half_life);
+                    let diff = value - prev_mean;
+                    let incr = alpha * diff;
+                    (
+                        prev_mean + incr,
+                        (T::Native::one() - alpha) * (prev_var + diff * incr),
+                    )
+                },
+            };
+            state = Some((time, mean, var));
+            let out_idx = match sorting_indices {
+                Some(indices) => unsafe { *indices.get_unchecked(idx) as usize },
+                None => idx,
+            };
+            unsafe {
+                *out.get_unchecked_mut(out_idx) = if std { var.sqrt() } else { var };
+            }
+        });
+    out
+}
+
+fn alpha<T>(time: i64, prev_time: i64, half_life: i64) -> T
+where
+    T: Float + Zero + One + FromPrimitive,
+{
+    let delta_time = time - prev_time;
+    // alpha = 1 - exp(-delta_time*ln(2) / half_life)
+    T::one()
+        - T::from_f64(0.5)
+            .unwrap()
+            .powf(T::from_i64(delta_time).unwrap() / T::from_i64(half_life).unwrap())
+}
@@ -1,4 +1,8 @@
 use polars_compute::arithmetic::ArithmeticKernel;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::{
+    _get_decimal_scale_add_sub, _get_decimal_scale_floor_div,
+};
 use polars_core::chunked_array::ops::arity::apply_binary_kernel_broadcast;
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-struct")]
@@ -19,6 +23,19 @@ fn floor_div_ca<T: PolarsNumericType>(
     )
 }
 
+/// Floor divide two decimals after bringing them to a common scale.
+///
+/// The quotient is integral, so the output has a scale of 0.
+#[cfg(feature = "dtype-decimal")]
+fn floor_div_decimal(a: &DecimalChunked, b: &DecimalChunked) -> PolarsResult<Series> {
+    let scale = _get_decimal_scale_add_sub(a.scale(), b.scale());
+    let a = a.to_scale(scale)?;
+    let b = b.to_scale(scale)?;
+    Ok(floor_div_ca(&a.0, &b.0)
+        .into_decimal_unchecked(None, _get_decimal_scale_floor_div())
+        .into_series())
+}
+
 pub fn floor_div_series(a: &Series, b: &Series) -> PolarsResult<Series> {
     match (a.dtype(), b.dtype()) {
         #[cfg(feature = "dtype-struct")]
@@ -33,6 +50,10 @@ pub fn floor_div_series(a: &Series, b: &Series) -> PolarsResult<Series> {
             return polars_core::series::arithmetic::NumericFixedSizeListOp::floor_div()
                 .execute(a, b);
         },
+        #[cfg(feature = "dtype-decimal")]
+        (DataType::Decimal(_, _), DataType::Decimal(_, _)) => {
+            return floor_div_decimal(a.decimal()?, b.decimal()?);
+        },
         _ => {},
     }
 
@@ -1,10 +1,14 @@
 use std::borrow::Cow;
 
+use arrow::array::BooleanArray;
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_compute::sum::WrappingAdd;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::*;
 use polars_core::series::arithmetic::coerce_lhs_rhs;
 use polars_core::utils::dtypes_to_supertype;
 use polars_core::{POOL, with_match_physical_numeric_polars_type};
+use polars_utils::total_ord::TotalOrd;
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
 
 fn validate_column_lengths(cs: &[Column]) -> PolarsResult<()> {
@@ -61,6 +65,19 @@ impl SumMeanHorizontal for DataFrame {
     }
 }
 
+#[cfg(feature = "dtype-struct")]
+pub trait CumSumHorizontal {
+    /// Cumulatively sum the columns from left to right, see [`cum_sum_horizontal`].
+    fn cum_sum_horizontal(&self, null_strategy: NullStrategy) -> PolarsResult<Column>;
+}
+
+#[cfg(feature = "dtype-struct")]
+impl CumSumHorizontal for DataFrame {
+    fn cum_sum_horizontal(&self, null_strategy: NullStrategy) -> PolarsResult<Column> {
+        cum_sum_horizontal(self.get_columns(), null_strategy)
+    }
+}
+
 fn min_binary<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -83,6 +100,199 @@ where
     arity::binary_elementwise_values(left, right, op)
 }
 
+/// How [`reduce_wide`] handles nulls.
+#[derive(Copy, Clone, PartialEq)]
+enum WideNulls {
+    /// Skip nulls, a row is only null if all its values are.
+    Skip,
+    /// A row is null if any of its values is.
+    Propagate,
+    /// Nulls are zero, no row is null.
+    Zero,
+}
+
+/// Reduce columns of one primitive type into a single buffer, making one pass over every
+/// column instead of allocating an intermediate column for every pair. This is what makes the
+/// horizontal aggregations over many columns fast.
+fn reduce_wide<T, F>(columns: &[Series], nulls: WideNulls, op: F) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    let first: &ChunkedArray<T> = columns[0].as_ref().as_ref();
+    let first = first.rechunk();
+    let first = first.downcast_as_array();
+    let mut values = first.values().to_vec();
+    let mut validity = first.validity().cloned();
+    if nulls == WideNulls::Zero {
+        if let Some(validity) = validity.take() {
+            for (v, valid) in values.iter_mut().zip(validity.iter()) {
+                if !valid {
+                    *v = T::Native::default();
+                }
+            }
+        }
+    }
+
+    for s in &columns[1..] {
+        let ca: &ChunkedArray<T> = s.as_ref().as_ref();
+        let ca = ca.rechunk();
+        let arr = ca.downcast_as_array();
+        let rhs = arr.values().as_slice();
+        match (arr.validity().filter(|v| v.unset_bits() > 0), nulls) {
+            (None, WideNulls::Skip) => {
+                if let Some(acc_validity) = validity.take() {
+                    for ((a, v), valid) in values.iter_mut().zip(rhs).zip(acc_validity.iter()) {
+                        *a = if valid { op(*a, *v) } else { *v };
+                    }
+                } else {
+                    values
+                        .iter_mut()
+                        .zip(rhs)
+                        .for_each(|(a, v)| *a = op(*a, *v));
+                }
+            },
+            (None, _) => values
+                .iter_mut()
+                .zip(rhs)
+                .for_each(|(a, v)| *a = op(*a, *v)),
+            (Some(rhs_validity), WideNulls::Skip) => {
+                let mut out_validity = MutableBitmap::with_capacity(values.len());
+                for (i, (a, v)) in values.iter_mut().zip(rhs).enumerate() {
+                    let acc_valid = validity.as_ref().is_none_or(|b| b.get_bit(i));
+                    let rhs_valid = rhs_validity.get_bit(i);
+                    match (acc_valid, rhs_valid) {
+                        (true, true) => *a = op(*a, *v),
+                        (false, true) => *a = *v,
+                        _ => {},
+                    }
+                    out_validity.push(acc_valid || rhs_valid);
+                }
+                validity = Some(out_validity.freeze());
+            },
+            (Some(rhs_validity), WideNulls::Propagate) => {
+                values
+                    .iter_mut()
+                    .zip(rhs)
+                    .for_each(|(a, v)| *a = op(*a, *v));
+                validity = Some(match validity {
+                    None => rhs_validity.clone(),
+                    Some(acc_validity) => &acc_validity & rhs_validity,
+                });
+            },
+            (Some(rhs_validity), WideNulls::Zero) => {
+                for ((a, v), valid) in values.iter_mut().zip(rhs).zip(rhs_validity.iter()) {
+                    if valid {
+                        *a = op(*a, *v);
+                    }
+                }
+            },
+        }
+    }
+    ChunkedArray::from_vec_validity(columns[0].name().clone(), values, validity)
+}
+
+/// The physical columns to reduce with [`reduce_wide`], if there are more than two columns of
+/// the same primitive dtype and length.
+fn wide_columns(columns: &[&Series]) -> Option<Vec<Series>> {
+    let first = columns.first()?;
+    let dtype = first.dtype();
+    (columns.len() > 2
+        && dtype.to_physical().is_primitive_numeric()
+        && columns
+            .iter()
+            .all(|s| s.dtype() == dtype && s.len() == first.len()))
+    .then(|| {
+        columns
+            .iter()
+            .map(|s| s.to_physical_repr().into_owned())
+            .collect()
+    })
+}
+
+fn min_max_wide(columns: &[Column], min: bool) -> Option<PolarsResult<Column>> {
+    let series = columns
+        .iter()
+        .map(|c| c.as_materialized_series())
+        .collect::<Vec<_>>();
+    let physical = wide_columns(&series)?;
+    let dtype = series[0].dtype();
+    let out = with_match_physical_numeric_polars_type!(physical[0].dtype(), |$T| {
+        let out: ChunkedArray<$T> = if min {
+            reduce_wide(&physical, WideNulls::Skip, |a, b| if b.tot_lt(&a) { b } else { a })
+        } else {
+            reduce_wide(&physical, WideNulls::Skip, |a, b| if b.tot_gt(&a) { b } else { a })
+        };
+        out.into_series()
+    });
+    // SAFETY: the physical type of `dtype` is the type of `out`.
+    Some(unsafe { out.from_physical_unchecked(dtype) }.map(Column::from))
+}
+
+fn sum_wide(columns: &[&Series], ignore_nulls: bool) -> Option<Column> {
+    // Logical types such as dates can't be added.
+    if !columns.first()?.dtype().is_primitive_numeric() {
+        return None;
+    }
+    let physical = wide_columns(columns)?;
+    let nulls = if ignore_nulls {
+        WideNulls::Zero
+    } else {
+        WideNulls::Propagate
+    };
+    let out = with_match_physical_numeric_polars_type!(physical[0].dtype(), |$T| {
+        let out: ChunkedArray<$T> = reduce_wide(&physical, nulls, |a, b| WrappingAdd::wrapping_add(&a, &b));
+        out.into_series()
+    });
+    Some(out.into())
+}
+
+/// The Kleene `any` (or `all`) of the rows of boolean columns, accumulated into one pair of
+/// bitmaps: the rows that are decided by a `true` (`false` for `all`) and the rows with a null.
+fn any_all_horizontal(columns: &[Column], any: bool) -> PolarsResult<Column> {
+    validate_column_lengths(columns)?;
+    polars_ensure!(!columns.is_empty(), ComputeError: "cannot reduce zero columns horizontally");
+    let len = columns.iter().map(|c| c.len()).max().unwrap();
+
+    let mut decided = Bitmap::new_zeroed(len);
+    let mut has_null = Bitmap::new_zeroed(len);
+    for c in columns {
+        let c = if c.len() == len {
+            c.cast(&DataType::Boolean)?
+        } else {
+            c.cast(&DataType::Boolean)?.new_from_index(0, len)
+        };
+        let ca = c.bool()?.rechunk();
+        let arr = ca.downcast_as_array();
+        let values = if any {
+            arr.values().clone()
+        } else {
+            !arr.values()
+        };
+        match arr.validity().filter(|v| v.unset_bits() > 0) {
+            None => decided = &decided | &values,
+            Some(validity) => {
+                decided = &decided | &(&values & validity);
+                has_null = &has_null | &!validity;
+            },
+        }
+    }
+    let validity = &decided | &!&has_null;
+    let values = if any { decided } else { !&decided };
+    let arr = BooleanArray::new(ArrowDataType::Boolean, values, Some(validity));
+    Ok(BooleanChunked::with_chunk(columns[0].name().clone(), arr).into_column())
+}
+
+/// The Kleene `any` of the rows of boolean columns.
+pub fn any_horizontal_columns(columns: &[Column]) -> PolarsResult<Column> {
+    any_all_horizontal(columns, true)
+}
+
+/// The Kleene `all` of the rows of boolean columns.
+pub fn all_horizontal_columns(columns: &[Column]) -> PolarsResult<Column> {
+    any_all_horizontal(columns, false)
+}
+
 fn min_max_binary_columns(left: &Column, right: &Column, min: bool) -> PolarsResult<Column> {
     if left.dtype().to_physical().is_primitive_numeric()
         && left.null_count() == 0
@@ -134,6 +344,9 @@ pub fn max_horizontal(columns: &[Column]) -> PolarsResult<Option<Column>> {
     validate_column_lengths(columns)?;
 
     let max_fn = |acc: &Column, s: &Column| min_max_binary_columns(acc, s, false);
+    if let Some(out) = min_max_wide(columns, false) {
+        return out.map(Some);
+    }
 
     match columns.len() {
         0 => Ok(None),
@@ -160,6 +373,9 @@ pub fn min_horizontal(columns: &[Column]) -> PolarsResult<Option<Column>> {
     validate_column_lengths(columns)?;
 
     let min_fn = |acc: &Column, s: &Column| min_max_binary_columns(acc, s, true);
+    if let Some(out) = min_max_wide(columns, true) {
+        return out.map(Some);
+    }
 
     match columns.len() {
         0 => Ok(None),
@@ -246,6 +462,9 @@ pub fn sum_horizontal(
             .map(Column::from)
             .map(Some),
         _ => {
+            if let Some(out) = sum_wide(&non_null_cols, ignore_nulls) {
+                return Ok(Some(out));
+            }
             // the try_reduce_with is a bit slower in parallelism,
             // but I don't think it matters here as we parallelize over columns, not over elements
             let out = POOL.install(|| {
@@ -262,6 +481,105 @@ pub fn sum_horizontal(
     }
 }
 
+/// Broadcast the columns to a common length and cast them to `dtype`, returning their
+/// physical representation in a single chunk.
+#[cfg(feature = "dtype-struct")]
+pub(crate) fn horizontal_physical(
+    columns: &[Column],
+    dtype: &DataType,
+) -> PolarsResult<Vec<Series>> {
+    validate_column_lengths(columns)?;
+    let len = columns.iter().map(|c| c.len()).max().unwrap_or(0);
+    columns
+        .iter()
+        .map(|c| {
+            let c = c.cast(dtype)?;
+            let c = if c.len() == len {
+                c
+            } else {
+                c.new_from_index(0, len)
+            };
+            Ok(c.to_physical_repr().take_materialized_series().rechunk())
+        })
+        .collect()
+}
+
+#[cfg(feature = "dtype-struct")]
+fn cum_sum_fields<T>(columns: &[Series], null_strategy: NullStrategy) -> Vec<Series>
+where
+    T: PolarsNumericType,
+    T::Native: WrappingAdd,
+{
+    let mut acc = vec![T::Native::default(); columns[0].len()];
+    let mut acc_validity: Option<Bitmap> = None;
+    columns
+        .iter()
+        .map(|s| {
+            let ca: &ChunkedArray<T> = s.as_ref().as_ref();
+            let arr = ca.downcast_as_array();
+            let validity = arr.validity().filter(|v| v.unset_bits() > 0);
+            match validity {
+                None => acc
+                    .iter_mut()
+                    .zip(arr.values().iter())
+                    .for_each(|(a, v)| *a = WrappingAdd::wrapping_add(a, v)),
+                Some(validity) => {
+                    for ((a, v), valid) in
+                        acc.iter_mut().zip(arr.values().iter()).zip(validity.iter())
+                    {
+                        if valid {
+                            *a = WrappingAdd::wrapping_add(a, v);
+                        }
+                    }
+                },
+            }
+            let validity = match null_strategy {
+                NullStrategy::Ignore => validity.cloned(),
+                NullStrategy::Propagate => {
+                    acc_validity = match (acc_validity.take(), validity) {
+                        (Some(acc), Some(validity)) => Some(&acc & validity),
+                        (acc, validity) => acc.or_else(|| validity.cloned()),
+                    };
+                    acc_validity.clone()
+                },
+            };
+            ChunkedArray::<T>::from_vec_validity(s.name().clone(), acc.clone(), validity)
+                .into_series()
+        })
+        .collect()
+}
+
+/// Cumulatively sum the columns from left to right, returning a struct with the running sum up
+/// to every column as its fields.
+///
+/// The sums are accumulated in a single buffer that is copied into every field, without a list
+/// per row. With [`NullStrategy::Ignore`] a null is skipped: its field is null, but the sum
+/// continues in the next field (like `cum_sum`). With [`NullStrategy::Propagate`] every field
+/// from the first null of a row on is null.
+#[cfg(feature = "dtype-struct")]
+pub fn cum_sum_horizontal(columns: &[Column], null_strategy: NullStrategy) -> PolarsResult<Column> {
+    polars_ensure!(!columns.is_empty(), ComputeError: "cannot sum zero columns horizontally");
+    let dtype = match dtypes_to_supertype(columns.iter().map(|c| c.dtype()))? {
+        DataType::Boolean => IDX_DTYPE,
+        dt => dt,
+    };
+    polars_ensure!(
+        dtype.is_primitive_numeric() || dtype.is_decimal(),
+        InvalidOperation: "'cum_sum_horizontal' needs numeric columns, got {}", dtype
+    );
+    let physical = horizontal_physical(columns, &dtype)?;
+    let len = physical[0].len();
+    let fields = with_match_physical_numeric_polars_type!(physical[0].dtype(), |$T| {
+        cum_sum_fields::<$T>(&physical, null_strategy)
+    });
+    let fields = fields
+        .into_iter()
+        .map(|s| unsafe { s.from_physical_unchecked(&dtype) })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    StructChunked::from_series(columns[0].name().clone(), len, fields.iter())
+        .map(IntoColumn::into_column)
+}
+
 pub fn mean_horizontal(
     columns: &[Column],
     null_strategy: NullStrategy,
@@ -406,4 +724,67 @@ mod tests {
             &[Some(4), Some(2), Some(6)]
         );
     }
+
+    #[test]
+    fn test_horizontal_agg_wide() -> PolarsResult<()> {
+        let columns = [
+            Column::new("a".into(), [Some(1), None, Some(6), None]),
+            Column::new("b".into(), [Some(5), Some(2), None, None]),
+            Column::new("c".into(), [Some(4), None, Some(3), None]),
+        ];
+        let min = min_horizontal(&columns)?.unwrap();
+        assert_eq!(Vec::from(min.i32()?), &[Some(1), Some(2), Some(3), None]);
+        let max = max_horizontal(&columns)?.unwrap();
+        assert_eq!(Vec::from(max.i32()?), &[Some(5), Some(2), Some(6), None]);
+
+        let sum = sum_horizontal(&columns, NullStrategy::Ignore)?.unwrap();
+        assert_eq!(
+            Vec::from(sum.i32()?),
+            &[Some(10), Some(2), Some(9), Some(0)]
+        );
+        let sum = sum_horizontal(&columns, NullStrategy::Propagate)?.unwrap();
+        assert_eq!(Vec::from(sum.i32()?), &[Some(10), None, None, None]);
+
+        let columns = [
+            Column::new("a".into(), [Some(false), Some(false), None, Some(true)]),
+            Column::new("b".into(), [Some(false), None, None, Some(true)]),
+            Column::new(
+                "c".into(),
+                [Some(false), Some(true), Some(false), Some(true)],
+            ),
+        ];
+        let any = any_horizontal_columns(&columns)?;
+        assert_eq!(
+            Vec::from(any.bool()?),
+            &[Some(false), Some(true), None, Some(true)]
+        );
+        let all = all_horizontal_columns(&columns)?;
+        assert_eq!(
+            Vec::from(all.bool()?),
+            &[Some(false), Some(false), Some(false), Some(true)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_cum_sum_horizontal() -> PolarsResult<()> {
+        let columns = [
+            Column::new("a".into(), [Some(1i32), None, Some(3)]),
+            Column::new("b".into(), [Some(2i64), Some(5), None]),
+            Column::new("c".into(), [Some(4i32), Some(1), Some(1)]),
+        ];
+        let out = cum_sum_horizontal(&columns, NullStrategy::Ignore)?;
+        let fields = out.struct_()?.fields_as_series();
+        assert_eq!(fields[2].name(), "c");
+        assert_eq!(fields[2].dtype(), &DataType::Int64);
+        assert_eq!(Vec::from(fields[0].i64()?), &[Some(1), None, Some(3)]);
+        assert_eq!(Vec::from(fields[1].i64()?), &[Some(3), Some(5), None]);
+        assert_eq!(Vec::from(fields[2].i64()?), &[Some(7), Some(6), Some(4)]);
+
+        let out = cum_sum_horizontal(&columns, NullStrategy::Propagate)?;
+        let fields = out.struct_()?.fields_as_series();
+        assert_eq!(Vec::from(fields[2].i64()?), &[Some(7), None, None]);
+        Ok(())
+    }
 }
@@ -5,10 +5,8 @@ use arrow::bitmap::MutableBitmap;
 use num_traits::{NumCast, Zero};
 use polars_core::downcast_as_macro_arg_physical;
 use polars_core::prelude::*;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 
-use super::{linear_itp, nearest_itp};
+use super::{InterpolationMethod, linear_itp, nearest_itp};
 
 fn near_interp<T>(low: T, high: T, steps: IdxSize, steps_n: T, out: &mut Vec<T>)
 where
@@ -190,13 +188,6 @@ where
     interpolate_impl(ca, signed_interp::<T::Native>).into_series()
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum InterpolationMethod {
-    Linear,
-    Nearest,
-}
-
 pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
     match method {
         InterpolationMethod::Linear => interpolate_linear(s),
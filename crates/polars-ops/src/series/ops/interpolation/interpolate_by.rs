@@ -5,9 +5,10 @@ use arrow::bitmap::MutableBitmap;
 use bytemuck::allocation::zeroed_vec;
 use num_traits::{NumCast, Zero};
 use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
 use polars_utils::slice::SliceAble;
 
-use super::linear_itp;
+use super::{InterpolationMethod, linear_itp};
 
 /// # Safety
 /// - `x` must be non-empty.
@@ -263,7 +264,97 @@ where
     }
 }
 
+/// For every row, the index of the row it takes its value from when filling nulls with the
+/// value of the nearest non-null row in `by`. On ties the later row wins, matching
+/// [`InterpolationMethod::Nearest`] in `interpolate`.
+///
+/// Nulls before the first and after the last non-null value (in the order of `by`) are kept.
+fn nearest_idx_by<F>(is_valid: &BooleanChunked, by: &ChunkedArray<F>, is_sorted: bool) -> IdxCa
+where
+    F: PolarsNumericType,
+{
+    let by = by.rechunk();
+    let by_values = by.cont_slice().unwrap();
+    let is_valid = is_valid.rechunk();
+    let is_valid = is_valid.downcast_as_array().values();
+    let sorting_indices: Vec<IdxSize> = if is_sorted {
+        (0..by.len() as IdxSize).collect()
+    } else {
+        by.arg_sort(Default::default())
+            .into_no_null_iter()
+            .collect()
+    };
+
+    let mut out = vec![None; by.len()];
+    let mut low: Option<IdxSize> = None;
+    let mut pending = vec![];
+    for row in sorting_indices {
+        if !is_valid.get_bit(row as usize) {
+            pending.push(row);
+            continue;
+        }
+        out[row as usize] = Some(row);
+        if let Some(low) = low {
+            let x_low = by_values[low as usize];
+            let x_high = by_values[row as usize];
+            for i in pending.iter() {
+                let x = by_values[*i as usize];
+                let nearest = if x_high - x > x - x_low { low } else { row };
+                out[*i as usize] = Some(nearest);
+            }
+        }
+        pending.clear();
+        low = Some(row);
+    }
+    IdxCa::from_iter_options(PlSmallStr::EMPTY, out.into_iter())
+}
+
+fn interpolate_nearest_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResult<Column> {
+    if !s.has_nulls() || s.null_count() == s.len() {
+        return Ok(s.clone());
+    }
+    polars_ensure!(by.null_count() == 0, InvalidOperation: "null values in `by` column are not yet supported in 'interpolate_by' expression");
+    let by = by.to_physical_repr();
+    polars_ensure!(
+        by.dtype().is_primitive_numeric(),
+        InvalidOperation: "expected `by` to be Date, Datetime, Duration or numeric, got {}", by.dtype()
+    );
+    let is_valid = s.is_not_null();
+    let idx = with_match_physical_numeric_polars_type!(by.dtype(), |$T| {
+        let by: &ChunkedArray<$T> = by.as_materialized_series().as_ref().as_ref().as_ref();
+        nearest_idx_by(&is_valid, by, by_is_sorted)
+    });
+    s.take(&idx)
+}
+
+/// Fill null values by linearly interpolating against the values of `by`, which do not have to
+/// be evenly spaced.
+///
+/// See [`interpolate_by_with_method`].
 pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResult<Column> {
+    interpolate_by_with_method(s, by, by_is_sorted, InterpolationMethod::Linear)
+}
+
+/// Fill null values by interpolating against the values of `by`, which do not have to be
+/// evenly spaced.
+///
+/// Linear interpolation weighs the neighbouring non-null values by their distance in `by` and
+/// casts integers to `Float64`. Nearest interpolation takes the value of the non-null row that
+/// is closest in `by` and keeps the data type.
+pub fn interpolate_by_with_method(
+    s: &Column,
+    by: &Column,
+    by_is_sorted: bool,
+    method: InterpolationMethod,
+) -> PolarsResult<Column> {
+    polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
+    match method {
+        InterpolationMethod::Linear => interpolate_linear_by(s, by, by_is_sorted),
+        InterpolationMethod::Nearest => interpolate_nearest_by(s, by, by_is_sorted),
+    }
+}
+
+fn interpolate_linear_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResult<Column> {
     polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
 
     fn func<T, F>(
@@ -327,13 +418,15 @@ pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResu
             func(s.f32().unwrap(), by.u32().unwrap(), by_is_sorted)
         },
         #[cfg(feature = "dtype-date")]
-        (_, DataType::Date) => interpolate_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted),
+        (_, DataType::Date) => {
+            interpolate_linear_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted)
+        },
         #[cfg(feature = "dtype-datetime")]
         (_, DataType::Datetime(_, _)) => {
-            interpolate_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
+            interpolate_linear_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
         },
         (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => {
-            interpolate_by(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
+            interpolate_linear_by(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
         },
         _ => {
             polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
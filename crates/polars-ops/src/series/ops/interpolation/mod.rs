@@ -4,6 +4,16 @@ pub mod interpolate;
 #[cfg(feature = "interpolate_by")]
 pub mod interpolate_by;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InterpolationMethod {
+    Linear,
+    Nearest,
+}
+
 fn linear_itp<T>(low: T, step: T, slope: T) -> T
 where
     T: Sub<Output = T> + Mul<Output = T> + Add<Output = T> + Div<Output = T>,
@@ -1,5 +1,7 @@
 #[cfg(feature = "abs")]
 mod abs;
+#[cfg(feature = "anonymize")]
+pub mod anonymize;
 mod arg_min_max;
 mod bitwise;
 #[cfg(feature = "business")]
@@ -42,8 +44,12 @@ mod log;
 #[cfg(feature = "moment")]
 mod moment;
 mod negate;
+#[cfg(feature = "ols")]
+mod ols;
 #[cfg(feature = "pct_change")]
 mod pct_change;
+#[cfg(feature = "random")]
+mod random;
 #[cfg(feature = "rank")]
 mod rank;
 #[cfg(feature = "reinterpret")]
@@ -58,6 +64,8 @@ mod rolling;
 mod round;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+#[cfg(feature = "hash")]
+mod stable_hash;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
@@ -112,10 +120,14 @@ pub use log::*;
 #[cfg(feature = "moment")]
 pub use moment::*;
 pub use negate::*;
+#[cfg(feature = "ols")]
+pub use ols::*;
 #[cfg(feature = "pct_change")]
 pub use pct_change::*;
 pub use polars_core::chunked_array::ops::search_sorted::SearchSortedSide;
 use polars_core::prelude::*;
+#[cfg(feature = "random")]
+pub use random::*;
 #[cfg(feature = "rank")]
 pub use rank::*;
 #[cfg(feature = "reinterpret")]
@@ -130,6 +142,8 @@ pub use rolling::*;
 pub use round::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
+#[cfg(feature = "hash")]
+pub use stable_hash::*;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
@@ -0,0 +1,328 @@
+//! Ordinary least squares regression of a target column on one or more regressor columns.
+//!
+//! The coefficients solve the normal equations `X'X b = X'y` with a Cholesky decomposition.
+//! Rows with a null target or regressor are left out of a fit. A fit whose `X'X` is singular,
+//! for example because a regressor is constant or two regressors are collinear, has null
+//! coefficients.
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The name of the coefficient of the constant regressor.
+const INTERCEPT: &str = "intercept";
+
+/// A pivot of the Cholesky decomposition smaller than this fraction of its diagonal entry of
+/// `X'X` means that the regressor is a linear combination of the previous ones.
+const SINGULAR_TOLERANCE: f64 = 1e-10;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OlsMode {
+    /// A struct with a field per coefficient, named after the regressors.
+    #[default]
+    Coefficients,
+    /// The fitted values of every row.
+    Predictions,
+    /// The target minus the fitted values of every row.
+    Residuals,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OlsOptions {
+    /// Add a constant regressor, whose coefficient is the last field, "intercept".
+    pub fit_intercept: bool,
+    pub mode: OlsMode,
+}
+
+impl OlsOptions {
+    /// The names of the coefficients, which are the fields of the coefficient struct.
+    pub fn coefficient_names<'a>(
+        &self,
+        regressors: impl IntoIterator<Item = &'a PlSmallStr>,
+    ) -> Vec<PlSmallStr> {
+        let mut names: Vec<_> = regressors.into_iter().cloned().collect();
+        if self.fit_intercept {
+            names.push(PlSmallStr::from_static(INTERCEPT));
+        }
+        names
+    }
+}
+
+/// The target and regressors of a regression as `f64`.
+struct Regression {
+    /// The number of coefficients.
+    p: usize,
+    /// The regressors of every row, row-major, including the constant regressor.
+    x: Vec<f64>,
+    /// Whether the regressors of a row are all non-null.
+    x_valid: Vec<bool>,
+    y: Vec<Option<f64>>,
+}
+
+impl Regression {
+    fn new(y: &Series, x: &[Series], fit_intercept: bool) -> PolarsResult<Self> {
+        polars_ensure!(
+            !x.is_empty() || fit_intercept,
+            InvalidOperation: "least squares requires at least one regressor"
+        );
+        for s in std::iter::once(y).chain(x) {
+            polars_ensure!(
+                s.dtype().is_primitive_numeric() || s.dtype().is_bool(),
+                InvalidOperation: "least squares requires numeric columns, got {} for {:?}",
+                s.dtype(), s.name()
+            );
+            polars_ensure!(
+                s.len() == y.len(),
+                ShapeMismatch: "least squares requires columns of the same length, got {} and {}",
+                y.len(), s.len()
+            );
+        }
+
+        let p = x.len() + fit_intercept as usize;
+        let mut values = vec![1.0; y.len() * p];
+        let mut x_valid = vec![true; y.len()];
+        for (j, s) in x.iter().enumerate() {
+            let s = s.cast(&DataType::Float64)?;
+            for (i, v) in s.f64()?.iter().enumerate() {
+                match v {
+                    Some(v) => values[i * p + j] = v,
+                    None => x_valid[i] = false,
+                }
+            }
+        }
+        let y = y.cast(&DataType::Float64)?;
+        Ok(Self {
+            p,
+            x: values,
+            x_valid,
+            y: y.f64()?.iter().collect(),
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.y.len()
+    }
+
+    fn row(&self, i: usize) -> &[f64] {
+        &self.x[i * self.p..(i + 1) * self.p]
+    }
+
+    /// The target and regressors of a row, if the row takes part in a fit.
+    fn observation(&self, i: usize) -> Option<(f64, &[f64])> {
+        let y = self.y[i]?;
+        self.x_valid[i].then(|| (y, self.row(i)))
+    }
+
+    fn predict(&self, i: usize, coefficients: &[f64]) -> Option<f64> {
+        self.x_valid[i].then(|| {
+            self.row(i)
+                .iter()
+                .zip(coefficients)
+                .map(|(x, b)| x * b)
+                .sum()
+        })
+    }
+}
+
+/// The sums of the normal equations `X'X b = X'y` over a set of rows.
+struct NormalEquations {
+    p: usize,
+    /// The lower triangle of `X'X`, row-major.
+    xtx: Vec<f64>,
+    xty: Vec<f64>,
+}
+
+impl NormalEquations {
+    fn new(p: usize) -> Self {
+        Self {
+            p,
+            xtx: vec![0.0; p * p],
+            xty: vec![0.0; p],
+        }
+    }
+
+    fn clear(&mut self) {
+        self.xtx.fill(0.0);
+        self.xty.fill(0.0);
+    }
+
+    /// Add (`sign = 1.0`) or remove (`sign = -1.0`) a row.
+    fn update(&mut self, y: f64, x: &[f64], sign: f64) {
+        for (j, (xty, xj)) in self.xty.iter_mut().zip(x).enumerate() {
+            *xty += sign * xj * y;
+            let row = &mut self.xtx[j * self.p..=j * self.p + j];
+            for (xtx, xk) in row.iter_mut().zip(x) {
+                *xtx += sign * xj * xk;
+            }
+        }
+    }
+
+    /// The coefficients, or `None` if `X'X` is singular.
+    fn solve(&self) -> Option<Vec<f64>> {
+        let p = self.p;
+        // The Cholesky factor `L` with `L L' = X'X`.
+        let mut l = vec![0.0; p * p];
+        for j in 0..p {
+            let diag = self.xtx[j * p + j];
+            let pivot = diag - (0..j).map(|k| l[j * p + k] * l[j * p + k]).sum::<f64>();
+            if pivot.is_nan() || pivot <= SINGULAR_TOLERANCE * diag {
+                return None;
+            }
+            let pivot = pivot.sqrt();
+            l[j * p + j] = pivot;
+            for i in j + 1..p {
+                let dot = (0..j).map(|k| l[i * p + k] * l[j * p + k]).sum::<f64>();
+                l[i * p + j] = (self.xtx[i * p + j] - dot) / pivot;
+            }
+        }
+
+        // Solve `L z = X'y` and then `L' b = z`.
+        let mut b = self.xty.clone();
+        for i in 0..p {
+            let dot = (0..i).map(|k| l[i * p + k] * b[k]).sum::<f64>();
+            b[i] = (b[i] - dot) / l[i * p + i];
+        }
+        for i in (0..p).rev() {
+            let dot = (i + 1..p).map(|k| l[k * p + i] * b[k]).sum::<f64>();
+            b[i] = (b[i] - dot) / l[i * p + i];
+        }
+        Some(b)
+    }
+}
+
+/// The output of a regression given the coefficients of the fit of every row.
+fn finish(
+    reg: &Regression,
+    name: PlSmallStr,
+    names: Vec<PlSmallStr>,
+    coefficients: &[Option<Vec<f64>>],
+    mode: OlsMode,
+) -> PolarsResult<Series> {
+    let coefficients_of = |i: usize| {
+        // A single fit applies to all rows.
+        coefficients[if coefficients.len() == 1 { 0 } else { i }].as_deref()
+    };
+    let out: Float64Chunked = match mode {
+        OlsMode::Coefficients => {
+            let fields = names
+                .into_iter()
+                .enumerate()
+                .map(|(j, field)| {
+                    let ca: Float64Chunked = coefficients
+                        .iter()
+                        .map(|b| b.as_ref().map(|b| b[j]))
+                        .collect();
+                    ca.with_name(field).into_series()
+                })
+                .collect::<Vec<_>>();
+            let out = StructChunked::from_series(name, coefficients.len(), fields.iter())?;
+            return Ok(out.into_series());
+        },
+        OlsMode::Predictions => (0..reg.len())
+            .map(|i| reg.predict(i, coefficients_of(i)?))
+            .collect(),
+        OlsMode::Residuals => (0..reg.len())
+            .map(|i| Some(reg.y[i]? - reg.predict(i, coefficients_of(i)?)?))
+            .collect(),
+    };
+    Ok(out.with_name(name).into_series())
+}
+
+/// Regress `y` on the regressors `x` by ordinary least squares.
+///
+/// With [`OlsMode::Coefficients`] the output is a single struct, otherwise it has a value for
+/// every row. Predictions need the regressors of a row to be non-null, residuals also the target.
+pub fn least_squares(y: &Series, x: &[Series], options: OlsOptions) -> PolarsResult<Series> {
+    let reg = Regression::new(y, x, options.fit_intercept)?;
+    let mut eq = NormalEquations::new(reg.p);
+    for i in 0..reg.len() {
+        if let Some((y, x)) = reg.observation(i) {
+            eq.update(y, x, 1.0);
+        }
+    }
+    let names = options.coefficient_names(x.iter().map(|s| s.name()));
+    finish(&reg, y.name().clone(), names, &[eq.solve()], options.mode)
+}
+
+/// Regress `y` on the regressors `x` by ordinary least squares over a rolling window.
+///
+/// The window of a row consists of the row itself and the `window_size - 1` rows before it. A
+/// window with fewer than `min_periods` rows without nulls has null coefficients. Predictions
+/// and residuals are in-sample: those of a row use the fit of its own window.
+pub fn rolling_least_squares(
+    y: &Series,
+    x: &[Series],
+    options: OlsOptions,
+    window_size: usize,
+    min_periods: usize,
+) -> PolarsResult<Series> {
+    polars_ensure!(window_size > 0, InvalidOperation: "`window_size` must be positive");
+    let reg = Regression::new(y, x, options.fit_intercept)?;
+    let mut eq = NormalEquations::new(reg.p);
+    let mut count = 0;
+    let coefficients = (0..reg.len())
+        .map(|i| {
+            if i >= window_size && i % window_size == 0 {
+                // Rebuild the sums once per window, so that the rounding errors of adding and
+                // removing rows don't accumulate.
+                eq.clear();
+                count = 0;
+                for j in i + 1 - window_size..i {
+                    if let Some((y, x)) = reg.observation(j) {
+                        eq.update(y, x, 1.0);
+                        count += 1;
+                    }
+                }
+            } else if i >= window_size {
+                if let Some((y, x)) = reg.observation(i - window_size) {
+                    eq.update(y, x, -1.0);
+                    count -= 1;
+                }
+            }
+            if let Some((y, x)) = reg.observation(i) {
+                eq.update(y, x, 1.0);
+                count += 1;
+            }
+            if count >= min_periods.max(1) {
+                eq.solve()
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    let names = options.coefficient_names(x.iter().map(|s| s.name()));
+    finish(&reg, y.name().clone(), names, &coefficients, options.mode)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normal_equations() {
+        // y = 1 + 2 * x1 - x2
+        let rows = [[0.0, 1.0], [1.0, 0.0], [2.0, 3.0], [3.0, 1.0], [4.0, 5.0]];
+        let mut eq = NormalEquations::new(3);
+        for [x1, x2] in rows {
+            eq.update(1.0 + 2.0 * x1 - x2, &[x1, x2, 1.0], 1.0);
+        }
+        let b = eq.solve().unwrap();
+        for (b, expected) in b.iter().zip([2.0, -1.0, 1.0]) {
+            assert!((b - expected).abs() < 1e-9);
+        }
+
+        // Removing rows until fewer rows than coefficients remain makes the system singular.
+        for [x1, x2] in &rows[..3] {
+            eq.update(1.0 + 2.0 * x1 - x2, &[*x1, *x2, 1.0], -1.0);
+        }
+        assert!(eq.solve().is_none());
+
+        // A regressor equal to twice another one.
+        let mut eq = NormalEquations::new(2);
+        for x in [1.0, 2.0, 3.0] {
+            eq.update(x, &[x, 2.0 * x], 1.0);
+        }
+        assert!(eq.solve().is_none());
+    }
+}
@@ -0,0 +1,225 @@
+//! Samples of random distributions whose parameters can differ from row to row.
+use polars_core::prelude::*;
+use polars_core::random::get_global_random_u64;
+use rand::prelude::*;
+use rand_distr::{Poisson, StandardNormal};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[strum(serialize_all = "snake_case")]
+pub enum RandomDistribution {
+    /// Floats between `low` (inclusive) and `high` (exclusive).
+    Uniform,
+    /// Floats with a `mean` and a standard deviation `std`.
+    Normal,
+    /// Booleans that are true with probability `p`.
+    Bernoulli,
+    /// Integers with mean `lambda`.
+    Poisson,
+}
+
+impl RandomDistribution {
+    /// The names of the parameters, in the order in which they are passed.
+    pub fn parameter_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Uniform => &["low", "high"],
+            Self::Normal => &["mean", "std"],
+            Self::Bernoulli => &["p"],
+            Self::Poisson => &["lambda"],
+        }
+    }
+
+    pub fn dtype(&self) -> DataType {
+        match self {
+            Self::Uniform | Self::Normal => DataType::Float64,
+            Self::Bernoulli => DataType::Boolean,
+            Self::Poisson => DataType::Int64,
+        }
+    }
+}
+
+/// The parameters of the rows, which are the same for every row if the parameter is a scalar.
+struct Parameter {
+    name: &'static str,
+    ca: Float64Chunked,
+}
+
+impl Parameter {
+    fn new(name: &'static str, s: &Series, n: usize) -> PolarsResult<Self> {
+        polars_ensure!(
+            s.len() == 1 || s.len() == n,
+            ShapeMismatch: "random parameter `{}` must be a scalar or have length {}, got {}",
+            name, n, s.len()
+        );
+        let s = s.cast(&DataType::Float64)?;
+        Ok(Self {
+            name,
+            ca: s.f64()?.rechunk().into_owned(),
+        })
+    }
+
+    fn get(&self, i: usize) -> Option<f64> {
+        self.ca.get(if self.ca.len() == 1 { 0 } else { i })
+    }
+
+    fn invalid(&self, v: f64) -> PolarsError {
+        polars_err!(ComputeError: "invalid random parameter `{}`: {}", self.name, v)
+    }
+}
+
+fn sample_float<F>(n: usize, parameters: &[Parameter], mut f: F) -> PolarsResult<Series>
+where
+    F: FnMut(&[f64]) -> PolarsResult<f64>,
+{
+    let mut values = [0.0; 2];
+    let ca = (0..n)
+        .map(|i| {
+            for (v, p) in values.iter_mut().zip(parameters) {
+                match p.get(i) {
+                    Some(x) => *v = x,
+                    None => return Ok(None),
+                }
+            }
+            f(&values[..parameters.len()]).map(Some)
+        })
+        .collect::<PolarsResult<Float64Chunked>>()?;
+    Ok(ca.into_series())
+}
+
+/// Draw `n` samples of `distribution`, a row's sample using the parameters of that row.
+///
+/// Every parameter is a scalar or has length `n`. A row with a null parameter has a null
+/// sample. Without a `seed`, the global random generator seeds the samples.
+pub fn sample_distribution(
+    distribution: RandomDistribution,
+    n: usize,
+    parameters: &[Series],
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    let names = distribution.parameter_names();
+    polars_ensure!(
+        parameters.len() == names.len(),
+        InvalidOperation: "random distribution '{}' takes {} parameters, got {}",
+        <&str>::from(distribution), names.len(), parameters.len()
+    );
+    let parameters = names
+        .iter()
+        .zip(parameters)
+        .map(|(name, s)| Parameter::new(name, s, n))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+
+    let out = match distribution {
+        RandomDistribution::Uniform => sample_float(n, &parameters, |v| {
+            let (low, high) = (v[0], v[1]);
+            polars_ensure!(
+                low <= high && (high - low).is_finite(),
+                ComputeError: "invalid random parameters `low` and `high`: {} and {}", low, high
+            );
+            Ok(low + (high - low) * rng.r#gen::<f64>())
+        })?,
+        RandomDistribution::Normal => sample_float(n, &parameters, |v| {
+            let (mean, std) = (v[0], v[1]);
+            if std.is_nan() || std < 0.0 {
+                return Err(parameters[1].invalid(std));
+            }
+            Ok(mean + std * rng.sample::<f64, _>(StandardNormal))
+        })?,
+        RandomDistribution::Bernoulli => {
+            let p = &parameters[0];
+            (0..n)
+                .map(|i| {
+                    p.get(i)
+                        .map(|v| {
+                            if !(0.0..=1.0).contains(&v) {
+                                return Err(p.invalid(v));
+                            }
+                            Ok(rng.gen_bool(v))
+                        })
+                        .transpose()
+                })
+                .collect::<PolarsResult<BooleanChunked>>()?
+                .into_series()
+        },
+        RandomDistribution::Poisson => {
+            let lambda = &parameters[0];
+            (0..n)
+                .map(|i| {
+                    lambda
+                        .get(i)
+                        .map(|v| {
+                            if v == 0.0 {
+                                return Ok(0);
+                            }
+                            let poisson = Poisson::new(v)
+                                .ok()
+                                .filter(|_| v.is_finite())
+                                .ok_or_else(|| lambda.invalid(v))?;
+                            Ok(rng.sample::<f64, _>(poisson) as i64)
+                        })
+                        .transpose()
+                })
+                .collect::<PolarsResult<Int64Chunked>>()?
+                .into_series()
+        },
+    };
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sample_distribution() {
+        let sample = |distribution, parameters: &[Series]| {
+            sample_distribution(distribution, 1000, parameters, Some(0)).unwrap()
+        };
+        let scalar = |v: f64| Series::new(PlSmallStr::EMPTY, [v]);
+
+        let s = sample(RandomDistribution::Uniform, &[scalar(2.0), scalar(3.0)]);
+        assert_eq!(s.dtype(), &DataType::Float64);
+        let ca = s.f64().unwrap();
+        assert!(ca.into_no_null_iter().all(|v| (2.0..3.0).contains(&v)));
+
+        // The mean is within a few standard errors of the expected value.
+        let s = sample(RandomDistribution::Normal, &[scalar(10.0), scalar(1.0)]);
+        assert!((s.mean().unwrap() - 10.0).abs() < 0.2);
+        let s = sample(RandomDistribution::Poisson, &[scalar(4.0)]);
+        assert_eq!(s.dtype(), &DataType::Int64);
+        assert!((s.mean().unwrap() - 4.0).abs() < 0.4);
+
+        // Per-row parameters, where a null parameter gives a null sample.
+        let p = Series::new(
+            PlSmallStr::EMPTY,
+            (0..1000)
+                .map(|i| [Some(0.0), Some(1.0), None][i % 3])
+                .collect::<Vec<_>>(),
+        );
+        let s = sample(RandomDistribution::Bernoulli, &[p]);
+        let values = s.bool().unwrap().into_iter().collect::<Vec<_>>();
+        assert_eq!(&values[..3], &[Some(false), Some(true), None]);
+        assert_eq!(s.null_count(), 333);
+
+        assert!(
+            sample_distribution(RandomDistribution::Bernoulli, 1, &[scalar(1.5)], None).is_err()
+        );
+        assert!(sample_distribution(RandomDistribution::Normal, 3, &[scalar(0.0)], None).is_err());
+    }
+
+    #[test]
+    fn test_sample_distribution_seed() {
+        let parameters = [
+            Series::new(PlSmallStr::EMPTY, [0.0]),
+            Series::new(PlSmallStr::EMPTY, [1.0]),
+        ];
+        let a = sample_distribution(RandomDistribution::Uniform, 10, &parameters, Some(1)).unwrap();
+        let b = sample_distribution(RandomDistribution::Uniform, 10, &parameters, Some(1)).unwrap();
+        let c = sample_distribution(RandomDistribution::Uniform, 10, &parameters, Some(2)).unwrap();
+        assert!(a.equals(&b));
+        assert!(!a.equals(&c));
+    }
+}
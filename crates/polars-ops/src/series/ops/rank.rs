@@ -1,7 +1,15 @@
 #![allow(unsafe_op_in_unsafe_fn)]
+#[cfg(feature = "dtype-struct")]
+use arrow::array::Array;
 use arrow::array::BooleanArray;
 use arrow::compute::concatenate::concatenate_validities;
 use polars_core::prelude::*;
+#[cfg(feature = "dtype-struct")]
+use polars_core::with_match_physical_numeric_polars_type;
+#[cfg(feature = "dtype-struct")]
+use polars_utils::float::IsFloat;
+#[cfg(feature = "dtype-struct")]
+use polars_utils::total_ord::{TotalEq, TotalOrd};
 use rand::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -26,6 +34,8 @@ pub enum RankMethod {
 pub struct RankOptions {
     pub method: RankMethod,
     pub descending: bool,
+    /// Which ranks NaN values of a float column receive, see [`NanPosition`].
+    pub nan_position: NanPosition,
 }
 
 impl Default for RankOptions {
@@ -33,6 +43,7 @@ impl Default for RankOptions {
         Self {
             method: RankMethod::Dense,
             descending: false,
+            nan_position: NanPosition::Largest,
         }
     }
 }
@@ -63,7 +74,13 @@ unsafe fn rank_impl<F: FnMut(&mut [IdxSize])>(idxs: &IdxCa, neq: &BooleanArray,
     flush_ties(&mut ties_indices);
 }
 
-fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) -> Series {
+fn rank(
+    s: &Series,
+    method: RankMethod,
+    descending: bool,
+    nan_position: NanPosition,
+    seed: Option<u64>,
+) -> Series {
     let len = s.len();
     let null_count = s.null_count();
 
@@ -102,6 +119,7 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
         .arg_sort(SortOptions {
             descending,
             nulls_last: true,
+            nan_position,
             ..Default::default()
         })
         .slice(0, len - null_count);
@@ -193,12 +211,130 @@ fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) ->
 
 pub trait SeriesRank: SeriesSealed {
     fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
-        rank(self.as_series(), options.method, options.descending, seed)
+        rank(
+            self.as_series(),
+            options.method,
+            options.descending,
+            options.nan_position,
+            seed,
+        )
     }
 }
 
 impl SeriesRank for Series {}
 
+#[cfg(feature = "dtype-struct")]
+fn rank_fields<T: PolarsNumericType>(columns: &[Series], options: RankOptions) -> Vec<Series> {
+    let arrays = columns
+        .iter()
+        .map(|s| {
+            let ca: &ChunkedArray<T> = s.as_ref().as_ref();
+            ca.downcast_as_array()
+        })
+        .collect::<Vec<_>>();
+    let len = columns[0].len();
+    let average = options.method == RankMethod::Average;
+    let mut ranks = vec![vec![0 as IdxSize; if average { 0 } else { len }]; columns.len()];
+    let mut avg_ranks = vec![vec![0.0f64; if average { len } else { 0 }]; columns.len()];
+
+    let cmp = |a: &T::Native, b: &T::Native| {
+        let ord = if options.descending {
+            b.tot_cmp(a)
+        } else {
+            a.tot_cmp(b)
+        };
+        match options.nan_position {
+            NanPosition::Largest => ord,
+            NanPosition::First => b.is_nan().cmp(&a.is_nan()).then(ord),
+            NanPosition::Last => a.is_nan().cmp(&b.is_nan()).then(ord),
+        }
+    };
+
+    // The non-null values of a row with their column, reused for every row.
+    let mut row = Vec::with_capacity(columns.len());
+    for i in 0..len {
+        row.clear();
+        row.extend(
+            arrays
+                .iter()
+                .enumerate()
+                .filter(|(_, arr)| arr.is_valid(i))
+                .map(|(j, arr)| (arr.value(i), j)),
+        );
+        // A stable sort, so ordinal ranks of ties follow the column order.
+        row.sort_by(|a, b| cmp(&a.0, &b.0));
+
+        let mut start = 0;
+        let mut dense = 0;
+        while start < row.len() {
+            let end = start
+                + row[start..]
+                    .iter()
+                    .take_while(|(v, _)| v.tot_eq(&row[start].0))
+                    .count();
+            dense += 1;
+            for (pos, (_, j)) in row[start..end].iter().enumerate() {
+                let rank = match options.method {
+                    RankMethod::Average => {
+                        avg_ranks[*j][i] = 0.5 * ((start + 1) as f64 + end as f64);
+                        continue;
+                    },
+                    RankMethod::Min => start + 1,
+                    RankMethod::Max => end,
+                    RankMethod::Dense => dense,
+                    _ => start + pos + 1,
+                };
+                ranks[*j][i] = rank as IdxSize;
+            }
+            start = end;
+        }
+    }
+
+    columns
+        .iter()
+        .zip(arrays)
+        .zip(ranks.into_iter().zip(avg_ranks))
+        .map(|((s, arr), (ranks, avg_ranks))| {
+            let validity = arr.validity().cloned();
+            if average {
+                Float64Chunked::from_vec_validity(s.name().clone(), avg_ranks, validity)
+                    .into_series()
+            } else {
+                IdxCa::from_vec_validity(s.name().clone(), ranks, validity).into_series()
+            }
+        })
+        .collect()
+}
+
+/// Rank the values of every row across the columns, returning a struct with the rank of
+/// every column as its fields.
+///
+/// Every row is ranked in a buffer that is reused for the next row, without a list per row.
+/// Like [`SeriesRank::rank`], nulls are not ranked and the ranks are `Float64` for
+/// [`RankMethod::Average`] and `IdxSize` otherwise. Ties are broken by the column order for
+/// [`RankMethod::Ordinal`].
+#[cfg(feature = "dtype-struct")]
+pub fn rank_horizontal(columns: &[Column], options: RankOptions) -> PolarsResult<Column> {
+    polars_ensure!(!columns.is_empty(), ComputeError: "cannot rank zero columns horizontally");
+    #[cfg(feature = "random")]
+    polars_ensure!(
+        options.method != RankMethod::Random,
+        InvalidOperation: "'rank_horizontal' does not support the random method"
+    );
+    let dtype = polars_core::utils::dtypes_to_supertype(columns.iter().map(|c| c.dtype()))?;
+    polars_ensure!(
+        dtype.to_physical().is_primitive_numeric() && !dtype.is_categorical(),
+        InvalidOperation: "'rank_horizontal' needs numeric or temporal columns, got {}", dtype
+    );
+    let physical = crate::series::horizontal_physical(columns, &dtype)?;
+    let len = physical[0].len();
+    let fields = with_match_physical_numeric_polars_type!(physical[0].dtype(), |$T| {
+        rank_fields::<$T>(&physical, options)
+    });
+    StructChunked::from_series(columns[0].name().clone(), len, fields.iter())
+        .map(IntoColumn::into_column)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -207,7 +343,7 @@ mod test {
     fn test_rank() -> PolarsResult<()> {
         let s = Series::new("a".into(), &[1, 2, 3, 2, 2, 3, 0]);
 
-        let out = rank(&s, RankMethod::Ordinal, false, None)
+        let out = rank(&s, RankMethod::Ordinal, false, NanPosition::Largest, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
@@ -215,7 +351,7 @@ mod test {
 
         #[cfg(feature = "random")]
         {
-            let out = rank(&s, RankMethod::Random, false, None)
+            let out = rank(&s, RankMethod::Random, false, NanPosition::Largest, None)
                 .idx()?
                 .into_no_null_iter()
                 .collect::<Vec<_>>();
@@ -228,25 +364,25 @@ mod test {
             assert_ne!(out[3], out[4]);
         }
 
-        let out = rank(&s, RankMethod::Dense, false, None)
+        let out = rank(&s, RankMethod::Dense, false, NanPosition::Largest, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[2, 3, 4, 3, 3, 4, 1]);
 
-        let out = rank(&s, RankMethod::Max, false, None)
+        let out = rank(&s, RankMethod::Max, false, NanPosition::Largest, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[2, 5, 7, 5, 5, 7, 1]);
 
-        let out = rank(&s, RankMethod::Min, false, None)
+        let out = rank(&s, RankMethod::Min, false, NanPosition::Largest, None)
             .idx()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[2, 3, 6, 3, 3, 6, 1]);
 
-        let out = rank(&s, RankMethod::Average, false, None)
+        let out = rank(&s, RankMethod::Average, false, NanPosition::Largest, None)
             .f64()?
             .into_no_null_iter()
             .collect::<Vec<_>>();
@@ -257,7 +393,7 @@ mod test {
             &[Some(1), Some(2), Some(3), Some(2), None, None, Some(0)],
         );
 
-        let out = rank(&s, RankMethod::Average, false, None)
+        let out = rank(&s, RankMethod::Average, false, NanPosition::Largest, None)
             .f64()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -287,7 +423,7 @@ mod test {
                 Some(8),
             ],
         );
-        let out = rank(&s, RankMethod::Max, false, None)
+        let out = rank(&s, RankMethod::Max, false, NanPosition::Largest, None)
             .idx()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -311,12 +447,12 @@ mod test {
     #[test]
     fn test_rank_all_null() -> PolarsResult<()> {
         let s = UInt32Chunked::new("".into(), &[None, None, None]).into_series();
-        let out = rank(&s, RankMethod::Average, false, None)
+        let out = rank(&s, RankMethod::Average, false, NanPosition::Largest, None)
             .f64()?
             .into_iter()
             .collect::<Vec<_>>();
         assert_eq!(out, &[None, None, None]);
-        let out = rank(&s, RankMethod::Dense, false, None)
+        let out = rank(&s, RankMethod::Dense, false, NanPosition::Largest, None)
             .idx()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -327,16 +463,16 @@ mod test {
     #[test]
     fn test_rank_empty() {
         let s = UInt32Chunked::from_slice("".into(), &[]).into_series();
-        let out = rank(&s, RankMethod::Average, false, None);
+        let out = rank(&s, RankMethod::Average, false, NanPosition::Largest, None);
         assert_eq!(out.dtype(), &DataType::Float64);
-        let out = rank(&s, RankMethod::Max, false, None);
+        let out = rank(&s, RankMethod::Max, false, NanPosition::Largest, None);
         assert_eq!(out.dtype(), &IDX_DTYPE);
     }
 
     #[test]
     fn test_rank_reverse() -> PolarsResult<()> {
         let s = Series::new("".into(), &[None, Some(1), Some(1), Some(5), None]);
-        let out = rank(&s, RankMethod::Dense, true, None)
+        let out = rank(&s, RankMethod::Dense, true, NanPosition::Largest, None)
             .idx()?
             .into_iter()
             .collect::<Vec<_>>();
@@ -344,4 +480,45 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_rank_horizontal() -> PolarsResult<()> {
+        let columns = [
+            Column::new("a".into(), [Some(3.0f64), Some(1.0), None]),
+            Column::new("b".into(), [Some(1.0f64), Some(f64::NAN), Some(2.0)]),
+            Column::new("c".into(), [Some(3.0f64), Some(2.0), Some(1.0)]),
+        ];
+        let ranks = |method, descending| -> PolarsResult<Vec<Vec<Option<IdxSize>>>> {
+            let options = RankOptions {
+                method,
+                descending,
+                nan_position: NanPosition::Largest,
+            };
+            let out = rank_horizontal(&columns, options)?;
+            out.struct_()?
+                .fields_as_series()
+                .iter()
+                .map(|s| Ok(Vec::from(s.idx()?)))
+                .collect()
+        };
+
+        let out = ranks(RankMethod::Min, false)?;
+        assert_eq!(out[0], &[Some(2 as IdxSize), Some(1), None]);
+        assert_eq!(out[1], &[Some(1), Some(3), Some(2)]);
+        assert_eq!(out[2], &[Some(2), Some(2), Some(1)]);
+        let out = ranks(RankMethod::Ordinal, true)?;
+        assert_eq!(out[0], &[Some(1 as IdxSize), Some(3), None]);
+        assert_eq!(out[2], &[Some(2), Some(2), Some(2)]);
+
+        let options = RankOptions {
+            method: RankMethod::Average,
+            ..Default::default()
+        };
+        let out = rank_horizontal(&columns, options)?;
+        let fields = out.struct_()?.fields_as_series();
+        assert_eq!(fields[1].name(), "b");
+        assert_eq!(Vec::from(fields[0].f64()?), &[Some(2.5), Some(1.0), None]);
+        Ok(())
+    }
 }
@@ -1,62 +1,178 @@
+#[cfg(feature = "moment")]
+use arrow::array::ArrayRef;
+use arrow::array::PrimitiveArray;
+use polars_compute::rolling::{self, RollingRankMethod};
 use polars_core::prelude::*;
 #[cfg(feature = "moment")]
-use {
-    crate::series::ops::moment::MomentSeries,
-    num_traits::{Float, FromPrimitive, pow::Pow},
-    polars_core::utils::with_unstable_series,
-    std::ops::SubAssign,
-};
+use polars_core::with_match_physical_float_polars_type;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::total_ord::TotalOrd;
 
 use crate::series::ops::SeriesSealed;
 
 #[cfg(feature = "moment")]
-fn rolling_skew<T>(
+fn rolling_moment<T>(
     ca: &ChunkedArray<T>,
-    window_size: usize,
-    bias: bool,
-) -> PolarsResult<ChunkedArray<T>>
+    options: &RollingOptionsFixedWindow,
+    params: RollingFnParams,
+    is_skew: bool,
+) -> PolarsResult<ArrayRef>
 where
-    ChunkedArray<T>: IntoSeries,
     T: PolarsFloatType,
-    T::Native: Float + SubAssign + Pow<T::Native, Output = T::Native>,
 {
-    with_unstable_series(ca.dtype(), |us| {
-        ca.rolling_map_float(window_size, |arr| {
-            let arr = unsafe { arr.chunks_mut().get_mut(0).unwrap() };
-
-            // SAFETY: dtype is correct.
-            unsafe {
-                us.with_array(arr, |us| {
-                    us.as_ref()
-                        .skew(bias)
-                        .unwrap()
-                        .map(|flt| T::Native::from_f64(flt).unwrap())
-                })
-            }
-        })
+    let ca = ca.rechunk();
+    let arr = ca.downcast_as_array();
+    let (window_size, min_periods, center) =
+        (options.window_size, options.min_periods, options.center);
+    let weights = options.weights.as_deref();
+    Ok(match (arr.null_count(), is_skew) {
+        (0, true) => rolling::no_nulls::rolling_skew(
+            arr.values().as_slice(),
+            window_size,
+            min_periods,
+            center,
+            weights,
+            Some(params),
+        )?,
+        (0, false) => rolling::no_nulls::rolling_kurtosis(
+            arr.values().as_slice(),
+            window_size,
+            min_periods,
+            center,
+            weights,
+            Some(params),
+        )?,
+        (_, true) => {
+            polars_ensure!(weights.is_none(), InvalidOperation: "weights not yet supported on array with null values");
+            rolling::nulls::rolling_skew(arr, window_size, min_periods, center, None, Some(params))
+        },
+        (_, false) => {
+            polars_ensure!(weights.is_none(), InvalidOperation: "weights not yet supported on array with null values");
+            rolling::nulls::rolling_kurtosis(
+                arr,
+                window_size,
+                min_periods,
+                center,
+                None,
+                Some(params),
+            )
+        },
+    })
+}
+
+#[cfg(feature = "moment")]
+fn rolling_moment_series(
+    s: &Series,
+    options: RollingOptionsFixedWindow,
+    params: RollingFnParams,
+    is_skew: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
+    let s = match s.dtype() {
+        DataType::Float32 | DataType::Float64 => s.clone(),
+        dt if dt.is_primitive_numeric() => s.cast(&DataType::Float64)?,
+        dt => polars_bail!(opq = rolling_moment, dt),
+    };
+    if s.is_empty() {
+        return Ok(s);
+    }
+    with_match_physical_float_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        let arr = rolling_moment(ca, &options, params, is_skew)?;
+        Series::try_from((s.name().clone(), arr))
     })
 }
 
+fn rolling_rank_impl<T>(ca: &ChunkedArray<T>, options: &RollingOptionsFixedWindow) -> Float64Chunked
+where
+    T: PolarsNumericType,
+    T::Native: TotalOrd,
+{
+    let method = match options.fn_params {
+        Some(RollingFnParams::Rank { method }) => method,
+        _ => RollingRankMethod::default(),
+    };
+    let ca = ca.rechunk();
+    let arr = ca.downcast_as_array();
+    let out: PrimitiveArray<f64> = rolling::rolling_rank(
+        arr.values().as_slice(),
+        arr.validity(),
+        options.window_size,
+        options.min_periods,
+        options.center,
+        method,
+    );
+    Float64Chunked::with_chunk(ca.name().clone(), out)
+}
+
 pub trait RollingSeries: SeriesSealed {
+    /// Compute the rolling skew over windows of `window_size` values.
+    ///
+    /// The first `window_size - 1` values are null. Nulls inside a window are ignored.
     #[cfg(feature = "moment")]
     fn rolling_skew(&self, window_size: usize, bias: bool) -> PolarsResult<Series> {
         let s = self.as_series();
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods: 1,
+            ..Default::default()
+        };
+        let out = rolling_moment_series(s, options, RollingFnParams::Skew { bias }, true)?;
+        let n_head = std::cmp::min(window_size.saturating_sub(1), out.len());
+        let mut head = Series::full_null(out.name().clone(), n_head, out.dtype());
+        head.append(&out.slice(n_head as i64, out.len() - n_head))?;
+        Ok(head)
+    }
 
-        match s.dtype() {
-            DataType::Float64 => {
-                let ca = s.f64().unwrap();
-                rolling_skew(ca, window_size, bias).map(|ca| ca.into_series())
-            },
-            DataType::Float32 => {
-                let ca = s.f32().unwrap();
-                rolling_skew(ca, window_size, bias).map(|ca| ca.into_series())
-            },
-            dt if dt.is_primitive_numeric() => {
-                let s = s.cast(&DataType::Float64).unwrap();
-                s.rolling_skew(window_size, bias)
+    /// Compute the rolling skew, honoring the full set of fixed window options.
+    ///
+    /// The bias correction is taken from `RollingFnParams::Skew` and defaults to `bias=true`.
+    #[cfg(feature = "moment")]
+    fn rolling_skew_with_options(
+        &self,
+        options: RollingOptionsFixedWindow,
+    ) -> PolarsResult<Series> {
+        let params = match &options.fn_params {
+            Some(params @ RollingFnParams::Skew { .. }) => params.clone(),
+            _ => RollingFnParams::Skew { bias: true },
+        };
+        rolling_moment_series(self.as_series(), options, params, true)
+    }
+
+    /// Compute the rolling kurtosis.
+    ///
+    /// The definition is taken from `RollingFnParams::Kurtosis` and defaults to Fisher's
+    /// definition with `bias=true`.
+    #[cfg(feature = "moment")]
+    fn rolling_kurtosis(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let params = match &options.fn_params {
+            Some(params @ RollingFnParams::Kurtosis { .. }) => params.clone(),
+            _ => RollingFnParams::Kurtosis {
+                fisher: true,
+                bias: true,
             },
-            dt => polars_bail!(opq = rolling_skew, dt),
-        }
+        };
+        rolling_moment_series(self.as_series(), options, params, false)
+    }
+
+    /// Rank every value within its rolling window.
+    ///
+    /// The tie-breaking method is taken from `RollingFnParams::Rank` and defaults to
+    /// [`RollingRankMethod::Average`]. The output is always `Float64`.
+    fn rolling_rank(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let s = self.as_series();
+        polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
+        polars_ensure!(
+            s.dtype().is_primitive_numeric(),
+            opq = rolling_rank,
+            s.dtype()
+        );
+        let s = s.to_physical_repr();
+        let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_rank_impl(ca, &options)
+        });
+        Ok(out.into_series())
     }
 }
 
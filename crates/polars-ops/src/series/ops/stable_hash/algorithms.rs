@@ -0,0 +1,141 @@
+//! Hash algorithms with a specified output, so that hashes can be stored and compared with
+//! the ones of other implementations.
+use std::hash::Hasher;
+
+use sha2::{Digest, Sha256};
+use siphasher::sip::SipHasher13;
+
+const WYHASH_SECRET: [u64; 4] = [
+    0xa076_1d64_78bd_642f,
+    0xe703_7ed1_a0b4_28db,
+    0x8ebc_6af0_9c88_c6e3,
+    0x5899_65cc_7537_4cc3,
+];
+
+fn wymum(a: u64, b: u64) -> (u64, u64) {
+    let r = a as u128 * b as u128;
+    (r as u64, (r >> 64) as u64)
+}
+
+fn wymix(a: u64, b: u64) -> u64 {
+    let (a, b) = wymum(a, b);
+    a ^ b
+}
+
+fn read_u64(p: &[u8]) -> u64 {
+    u64::from_le_bytes(p[..8].try_into().unwrap())
+}
+
+fn read_u32(p: &[u8]) -> u64 {
+    u32::from_le_bytes(p[..4].try_into().unwrap()) as u64
+}
+
+/// wyhash (final version 4) with its default secret, as in the reference implementation.
+pub(super) fn wyhash(bytes: &[u8], seed: u64) -> u64 {
+    let s = &WYHASH_SECRET;
+    let len = bytes.len();
+    let mut seed = seed ^ wymix(seed ^ s[0], s[1]);
+    let (a, b);
+    if len <= 16 {
+        if len >= 4 {
+            let quarter = (len >> 3) << 2;
+            a = (read_u32(bytes) << 32) | read_u32(&bytes[quarter..]);
+            b = (read_u32(&bytes[len - 4..]) << 32) | read_u32(&bytes[len - 4 - quarter..]);
+        } else if len > 0 {
+            a = ((bytes[0] as u64) << 16) | ((bytes[len >> 1] as u64) << 8) | bytes[len - 1] as u64;
+            b = 0;
+        } else {
+            a = 0;
+            b = 0;
+        }
+    } else {
+        let mut p = bytes;
+        if p.len() > 48 {
+            let (mut see1, mut see2) = (seed, seed);
+            while p.len() > 48 {
+                seed = wymix(read_u64(p) ^ s[1], read_u64(&p[8..]) ^ seed);
+                see1 = wymix(read_u64(&p[16..]) ^ s[2], read_u64(&p[24..]) ^ see1);
+                see2 = wymix(read_u64(&p[32..]) ^ s[3], read_u64(&p[40..]) ^ see2);
+                p = &p[48..];
+            }
+            seed ^= see1 ^ see2;
+        }
+        while p.len() > 16 {
+            seed = wymix(read_u64(p) ^ s[1], read_u64(&p[8..]) ^ seed);
+            p = &p[16..];
+        }
+        // The last 16 bytes, which may overlap with bytes that were already mixed.
+        let tail = &bytes[len - 16..];
+        a = read_u64(tail);
+        b = read_u64(&tail[8..]);
+    }
+    let (a, b) = wymum(a ^ s[1], b ^ seed);
+    wymix(a ^ s[0] ^ len as u64, b ^ s[1])
+}
+
+/// SipHash-1-3 with the 128-bit key `(k0, k1)`.
+pub(super) fn siphash13(bytes: &[u8], k0: u64, k1: u64) -> u64 {
+    let mut hasher = SipHasher13::new_with_keys(k0, k1);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// The SHA-256 digest of `bytes`.
+pub(super) fn sha256(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn test_wyhash() {
+        // The test vectors of the reference implementation.
+        let vectors: [(u64, u64, &str); 5] = [
+            (0, 0x0409_638e_e2bd_e459, ""),
+            (1, 0xa841_2d09_1b5f_e0a9, "a"),
+            (2, 0x32dd_92e4_b291_5153, "abc"),
+            (3, 0x8619_1240_89a3_a16b, "message digest"),
+            (
+                6,
+                0xc39c_ab13_b115_aad3,
+                "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+            ),
+        ];
+        for (seed, expected, input) in vectors {
+            assert_eq!(wyhash(input.as_bytes(), seed), expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_siphash13() {
+        // As computed by the `DefaultHasher` of the standard library, which has a zero key.
+        assert_eq!(siphash13(b"", 0, 0), 0xd1fb_a762_150c_532c);
+        let bytes = (0..15).collect::<Vec<u8>>();
+        assert_eq!(siphash13(&bytes, 0, 0), 0xf30e_b725_bb91_c9ea);
+        assert_eq!(siphash13(b"abc", 1, 2), 0x27df_c47c_f121_981a);
+        assert_ne!(siphash13(b"abc", 1, 2), siphash13(b"abc", 2, 1));
+    }
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            hex(&sha256(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex(&sha256(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        // Messages that need a second padding block.
+        assert_eq!(
+            hex(&sha256(&[b'y'; 56])),
+            "4877e564e5e36e367c7c8d59670774becd3350610b6df4c399c9fa9b66da5813"
+        );
+    }
+}
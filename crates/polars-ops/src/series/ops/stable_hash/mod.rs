@@ -0,0 +1,108 @@
+mod algorithms;
+
+use polars_core::prelude::*;
+use polars_core::with_match_physical_integer_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh64::xxh64;
+
+use self::algorithms::{sha256, siphash13, wyhash};
+
+/// A hash algorithm whose output doesn't depend on the version of Polars or the platform.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HashAlgorithm {
+    /// 64-bit xxHash, seeded with `k0`.
+    XxHash64,
+    /// wyhash (final version 4), seeded with `k0`.
+    WyHash,
+    /// SipHash-1-3 with the 128-bit key `(k0, k1)`.
+    SipHash13,
+    /// SHA-256, which ignores the seeds.
+    Sha256,
+}
+
+fn canonical_f32(v: f32) -> f32 {
+    // A single NaN and zero, so that equal values have equal hashes.
+    if v.is_nan() { f32::NAN } else { v + 0.0 }
+}
+
+fn canonical_f64(v: f64) -> f64 {
+    if v.is_nan() { f64::NAN } else { v + 0.0 }
+}
+
+/// Apply `f` to the bytes of every value of `s`.
+///
+/// Strings, categoricals and binary values are hashed as their bytes, booleans as a single byte
+/// and numbers as the little-endian bytes of their physical type. Temporal and decimal values are
+/// hashed as their physical integers.
+fn hash_values<O>(s: &Series, mut f: impl FnMut(&[u8]) -> O) -> PolarsResult<Vec<Option<O>>> {
+    let s = match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => s.cast(&DataType::String)?,
+        _ => s.to_physical_repr().into_owned(),
+    };
+    Ok(match s.dtype() {
+        DataType::Null => (0..s.len()).map(|_| None).collect(),
+        DataType::Boolean => s.bool()?.iter().map(|v| v.map(|v| f(&[v as u8]))).collect(),
+        DataType::String => s
+            .str()?
+            .iter()
+            .map(|v| v.map(|v| f(v.as_bytes())))
+            .collect(),
+        DataType::Binary => s.binary()?.iter().map(|v| v.map(&mut f)).collect(),
+        DataType::Float32 => {
+            let ca = s.f32()?;
+            ca.iter()
+                .map(|v| v.map(|v| f(&canonical_f32(v).to_le_bytes())))
+                .collect()
+        },
+        DataType::Float64 => {
+            let ca = s.f64()?;
+            ca.iter()
+                .map(|v| v.map(|v| f(&canonical_f64(v).to_le_bytes())))
+                .collect()
+        },
+        dt if dt.is_integer() => with_match_physical_integer_polars_type!(dt, |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            ca.iter().map(|v| v.map(|v| f(&v.to_le_bytes()))).collect()
+        }),
+        dt => polars_bail!(
+            InvalidOperation: "a stable hash is not supported for data type {}; \
+            only numeric, boolean, string, binary, categorical, temporal and decimal \
+            data types are supported", dt
+        ),
+    })
+}
+
+/// Hash every value of `s` with `algorithm`. Null values have a null hash.
+///
+/// SHA-256 returns 32-byte binary digests and the other algorithms `UInt64` hashes. Unlike
+/// [`SeriesMethods::hash`](crate::series::SeriesMethods::hash), the hashes are stable across
+/// Polars versions and platforms.
+pub fn stable_hash(s: &Series, algorithm: HashAlgorithm, k0: u64, k1: u64) -> PolarsResult<Series> {
+    let name = s.name().clone();
+    let out = match algorithm {
+        HashAlgorithm::XxHash64 => hash_values(s, |b| xxh64(b, k0))?
+            .into_iter()
+            .collect::<UInt64Chunked>()
+            .into_series(),
+        HashAlgorithm::WyHash => hash_values(s, |b| wyhash(b, k0))?
+            .into_iter()
+            .collect::<UInt64Chunked>()
+            .into_series(),
+        HashAlgorithm::SipHash13 => hash_values(s, |b| siphash13(b, k0, k1))?
+            .into_iter()
+            .collect::<UInt64Chunked>()
+            .into_series(),
+        HashAlgorithm::Sha256 => {
+            let digests = hash_values(s, sha256)?;
+            digests
+                .iter()
+                .map(|d| d.as_ref().map(|d| d.as_slice()))
+                .collect::<BinaryChunked>()
+                .into_series()
+        },
+    };
+    Ok(out.with_name(name))
+}
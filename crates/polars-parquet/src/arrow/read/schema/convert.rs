@@ -1,5 +1,5 @@
 //! This module has entry points, [`parquet_to_arrow_schema`] and the more configurable [`parquet_to_arrow_schema_with_options`].
-use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, TimeUnit};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::arrow::read::schema::SchemaInferenceOptions;
@@ -170,6 +170,10 @@ fn from_fixed_len_byte_array(
     converted_type: Option<PrimitiveConvertedType>,
 ) -> ArrowDataType {
     match (logical_type, converted_type) {
+        (Some(PrimitiveLogicalType::Float16), _) if length == 2 => ArrowDataType::Float32,
+        (Some(PrimitiveLogicalType::Uuid), _) if length == 16 => {
+            ArrowDataType::Extension(Box::new(ExtensionType::uuid()))
+        },
         (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) => {
             ArrowDataType::Decimal(precision, scale)
         },
@@ -463,6 +467,7 @@ mod tests {
         message test_schema {
             REQUIRED BYTE_ARRAY binary;
             REQUIRED FIXED_LEN_BYTE_ARRAY (20) fixed_binary;
+            OPTIONAL FIXED_LEN_BYTE_ARRAY (16) uuid (UUID);
         }
         ";
         let expected = vec![
@@ -472,6 +477,11 @@ mod tests {
                 ArrowDataType::FixedSizeBinary(20),
                 false,
             ),
+            Field::new(
+                "uuid".into(),
+                ArrowDataType::Extension(Box::new(ExtensionType::uuid())),
+                true,
+            ),
         ];
 
         let parquet_schema = SchemaDescriptor::try_from_message(message)?;
@@ -88,6 +88,9 @@ pub struct WriteOptions {
     pub compression: CompressionOptions,
     /// The size to flush a page, defaults to 1024 * 1024 if None
     pub data_page_size: Option<usize>,
+    /// The maximum size of the zstd dictionary to train for every column chunk, if any. See
+    /// [`Compressor::with_zstd_dictionary_size`].
+    pub zstd_dictionary_size: Option<usize>,
 }
 
 use arrow::compute::aggregate::estimated_bytes_size;
@@ -46,6 +46,7 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
                         );
 
                         let compressed_pages = Compressor::new(pages, options.compression, vec![])
+                            .with_zstd_dictionary_size(options.zstd_dictionary_size)
                             .map_err(to_compute_err);
                         Ok(DynStreamingIterator::new(compressed_pages))
                     })
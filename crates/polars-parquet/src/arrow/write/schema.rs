@@ -87,6 +87,18 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
     } else {
         Repetition::Required
     };
+    if let ArrowDataType::Extension(ext) = field.dtype() {
+        if ext.is_uuid() {
+            return Ok(ParquetType::try_from_primitive(
+                name,
+                PhysicalType::FixedLenByteArray(16),
+                repetition,
+                None,
+                Some(PrimitiveLogicalType::Uuid),
+                None,
+            )?);
+        }
+    }
     // create type from field
     match field.dtype().to_logical_type() {
         ArrowDataType::Null => Ok(ParquetType::try_from_primitive(
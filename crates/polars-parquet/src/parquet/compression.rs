@@ -4,6 +4,13 @@ pub use super::parquet_bridge::{
 };
 use crate::parquet::error::{ParquetError, ParquetResult};
 
+/// The key of the column chunk metadata that holds the (base64 encoded) zstd dictionary its
+/// pages are compressed with.
+///
+/// Such dictionaries are not part of the parquet specification, so other readers cannot
+/// decompress these pages.
+pub const ZSTD_DICTIONARY_META_KEY: &str = "polars:zstd_dictionary";
+
 #[cfg(any(feature = "snappy", feature = "lz4"))]
 fn inner_compress<
     G: Fn(usize) -> ParquetResult<usize>,
@@ -230,6 +237,32 @@ pub fn decompress(
     }
 }
 
+/// Decompresses zstd data stored in slice `input_buf` that was compressed with `dictionary`, and
+/// writes the output to `output_buf`.
+#[allow(unused_variables)]
+pub fn decompress_zstd_with_dictionary(
+    dictionary: &[u8],
+    input_buf: &[u8],
+    output_buf: &mut [u8],
+) -> ParquetResult<()> {
+    #[cfg(feature = "zstd")]
+    {
+        let mut decompressor = zstd::bulk::Decompressor::with_dictionary(dictionary)?;
+        let written_size = decompressor.decompress_to_buffer(input_buf, output_buf)?;
+        if written_size != output_buf.len() {
+            return Err(ParquetError::oos(
+                "The page header reported the wrong uncompressed page size",
+            ));
+        }
+        Ok(())
+    }
+    #[cfg(not(feature = "zstd"))]
+    Err(ParquetError::FeatureNotActive(
+        crate::parquet::error::Feature::Zstd,
+        "decompress with zstd".to_string(),
+    ))
+}
+
 /// Try to decompress the buffer as if it was compressed with the Hadoop Lz4Codec.
 /// Translated from the apache arrow c++ function [TryDecompressHadoop](https://github.com/apache/arrow/blob/bf18e6e4b5bb6180706b1ba0d597a65a4ce5ca48/cpp/src/arrow/util/compression_lz4.cc#L474).
 /// Returns error if decompression failed.
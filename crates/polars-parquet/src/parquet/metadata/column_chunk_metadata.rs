@@ -1,7 +1,9 @@
+use base64::Engine as _;
+use base64::engine::general_purpose;
 use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
-use crate::parquet::compression::Compression;
+use crate::parquet::compression::{Compression, ZSTD_DICTIONARY_META_KEY};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -175,6 +177,12 @@ impl ColumnChunkMetadata {
         column_metadata_byte_range(self.metadata())
     }
 
+    /// Returns the zstd dictionary the pages of this column chunk are compressed with, if any.
+    pub fn zstd_dictionary(&self) -> Option<Vec<u8>> {
+        // this has been validated in [`try_from_thrift`]
+        column_metadata_zstd_dictionary(self.metadata()).map(|x| x.unwrap())
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
@@ -190,6 +198,10 @@ impl ColumnChunkMetadata {
             let _: u64 = meta.data_page_offset.try_into()?;
 
             let _: Compression = meta.codec.try_into()?;
+
+            if let Some(zstd_dictionary) = column_metadata_zstd_dictionary(meta) {
+                zstd_dictionary?;
+            }
         } else {
             return Err(ParquetError::oos("Column chunk requires metadata"));
         }
@@ -217,3 +229,19 @@ pub(super) fn column_metadata_byte_range(
     let len = column_metadata.total_compressed_size as u64;
     offset..offset.checked_add(len).unwrap()
 }
+
+fn column_metadata_zstd_dictionary(
+    column_metadata: &ColumnMetaData,
+) -> Option<ParquetResult<Vec<u8>>> {
+    let key_value = column_metadata
+        .key_value_metadata
+        .as_ref()?
+        .iter()
+        .find(|key_value| key_value.key == ZSTD_DICTIONARY_META_KEY)?;
+    let encoded = key_value.value.as_deref().unwrap_or_default();
+    Some(
+        general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|_| ParquetError::oos("The zstd dictionary of a column chunk is invalid")),
+    )
+}
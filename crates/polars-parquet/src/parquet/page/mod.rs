@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use super::CowBuffer;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::{Encoding, get_length};
@@ -22,6 +24,7 @@ pub struct CompressedDataPage {
     pub(crate) header: DataPageHeader,
     pub(crate) buffer: CowBuffer,
     pub(crate) compression: Compression,
+    pub(crate) zstd_dictionary: Option<Arc<[u8]>>,
     uncompressed_page_size: usize,
     pub(crate) descriptor: Descriptor,
     pub num_rows: Option<usize>,
@@ -41,6 +44,7 @@ impl CompressedDataPage {
             header,
             buffer,
             compression,
+            zstd_dictionary: None,
             uncompressed_page_size,
             descriptor,
             num_rows: Some(num_rows),
@@ -59,12 +63,19 @@ impl CompressedDataPage {
             header,
             buffer,
             compression,
+            zstd_dictionary: None,
             uncompressed_page_size,
             descriptor,
             num_rows: None,
         }
     }
 
+    /// Sets the zstd dictionary the data of this page is compressed with.
+    pub fn with_zstd_dictionary(mut self, zstd_dictionary: Option<Arc<[u8]>>) -> Self {
+        self.zstd_dictionary = zstd_dictionary;
+        self
+    }
+
     pub fn header(&self) -> &DataPageHeader {
         &self.header
     }
@@ -84,6 +95,11 @@ impl CompressedDataPage {
         self.compression
     }
 
+    /// The zstd dictionary the data of this page is compressed with, if any.
+    pub fn zstd_dictionary(&self) -> Option<&Arc<[u8]>> {
+        self.zstd_dictionary.as_ref()
+    }
+
     pub fn num_values(&self) -> usize {
         self.header.num_values()
     }
@@ -295,6 +311,13 @@ impl CompressedPage {
         }
     }
 
+    pub(crate) fn zstd_dictionary(&self) -> Option<&Arc<[u8]>> {
+        match self {
+            CompressedPage::Data(page) => page.zstd_dictionary(),
+            CompressedPage::Dict(page) => page.zstd_dictionary(),
+        }
+    }
+
     pub(crate) fn num_values(&self) -> usize {
         match self {
             CompressedPage::Data(page) => page.num_values(),
@@ -333,6 +356,7 @@ impl DictPage {
 pub struct CompressedDictPage {
     pub(crate) buffer: CowBuffer,
     compression: Compression,
+    zstd_dictionary: Option<Arc<[u8]>>,
     pub(crate) num_values: usize,
     pub(crate) uncompressed_page_size: usize,
     pub is_sorted: bool,
@@ -349,16 +373,28 @@ impl CompressedDictPage {
         Self {
             buffer,
             compression,
+            zstd_dictionary: None,
             uncompressed_page_size,
             num_values,
             is_sorted,
         }
     }
 
+    /// Sets the zstd dictionary the data of this page is compressed with.
+    pub fn with_zstd_dictionary(mut self, zstd_dictionary: Option<Arc<[u8]>>) -> Self {
+        self.zstd_dictionary = zstd_dictionary;
+        self
+    }
+
     /// The compression of the data in this page.
     pub fn compression(&self) -> Compression {
         self.compression
     }
+
+    /// The zstd dictionary the data of this page is compressed with, if any.
+    pub fn zstd_dictionary(&self) -> Option<&Arc<[u8]>> {
+        self.zstd_dictionary.as_ref()
+    }
 }
 
 pub struct EncodedSplitBuffer<'a> {
@@ -8,18 +8,37 @@ use crate::parquet::page::{
     CompressedDataPage, CompressedPage, DataPage, DataPageHeader, DictPage, Page,
 };
 
+fn decompress_with_dictionary(
+    compressed: &[u8],
+    compression: Compression,
+    zstd_dictionary: Option<&[u8]>,
+    buffer: &mut [u8],
+) -> ParquetResult<()> {
+    match (compression, zstd_dictionary) {
+        (_, None) => compression::decompress(compression, compressed, buffer),
+        (Compression::Zstd, Some(zstd_dictionary)) => {
+            compression::decompress_zstd_with_dictionary(zstd_dictionary, compressed, buffer)
+        },
+        (_, Some(_)) => Err(ParquetError::oos(
+            "A zstd dictionary is set on a page that is not compressed with zstd",
+        )),
+    }
+}
+
 fn decompress_v1(
     compressed: &[u8],
     compression: Compression,
+    zstd_dictionary: Option<&[u8]>,
     buffer: &mut [u8],
 ) -> ParquetResult<()> {
-    compression::decompress(compression, compressed, buffer)
+    decompress_with_dictionary(compressed, compression, zstd_dictionary, buffer)
 }
 
 fn decompress_v2(
     compressed: &[u8],
     page_header: &DataPageHeaderV2,
     compression: Compression,
+    zstd_dictionary: Option<&[u8]>,
     buffer: &mut [u8],
 ) -> ParquetResult<()> {
     // When processing data page v2, depending on enabled compression for the
@@ -44,7 +63,12 @@ fn decompress_v2(
 
         // https://github.com/pola-rs/polars/issues/22170
         if compressed.len() > offset {
-            compression::decompress(compression, &compressed[offset..], &mut buffer[offset..])?;
+            decompress_with_dictionary(
+                &compressed[offset..],
+                compression,
+                zstd_dictionary,
+                &mut buffer[offset..],
+            )?;
         }
     } else {
         if buffer.len() != compressed.len() {
@@ -83,11 +107,18 @@ pub fn decompress(compressed_page: CompressedPage, buffer: &mut Vec<u8>) -> Parq
                 buffer.truncate(read_size);
             }
 
+            let zstd_dictionary = page.zstd_dictionary.as_deref();
             match page.header() {
-                DataPageHeader::V1(_) => decompress_v1(&page.buffer, page.compression, buffer)?,
-                DataPageHeader::V2(header) => {
-                    decompress_v2(&page.buffer, header, page.compression, buffer)?
+                DataPageHeader::V1(_) => {
+                    decompress_v1(&page.buffer, page.compression, zstd_dictionary, buffer)?
                 },
+                DataPageHeader::V2(header) => decompress_v2(
+                    &page.buffer,
+                    header,
+                    page.compression,
+                    zstd_dictionary,
+                    buffer,
+                )?,
             }
             let buffer = CowBuffer::Owned(std::mem::take(buffer));
 
@@ -113,7 +144,12 @@ pub fn decompress(compressed_page: CompressedPage, buffer: &mut Vec<u8>) -> Parq
             } else {
                 buffer.truncate(read_size);
             }
-            decompress_v1(&page.buffer, page.compression(), buffer)?;
+            decompress_v1(
+                &page.buffer,
+                page.compression(),
+                page.zstd_dictionary().map(|x| &**x),
+                buffer,
+            )?;
             let buffer = CowBuffer::Owned(std::mem::take(buffer));
 
             Page::Dict(DictPage {
@@ -288,7 +324,7 @@ mod tests {
         buffer: &mut [u8],
         expected: &[u8],
     ) {
-        decompress_v2(compressed, page_header, compression, buffer).unwrap();
+        decompress_v2(compressed, page_header, compression, None, buffer).unwrap();
         assert_eq!(buffer, expected);
     }
 }
@@ -1,5 +1,5 @@
 use std::io::Seek;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 use polars_utils::mmap::{MemReader, MemSlice};
@@ -24,6 +24,8 @@ pub struct PageMetaData {
     pub num_values: i64,
     /// Compression type
     pub compression: Compression,
+    /// The zstd dictionary the pages are compressed with, if any
+    pub zstd_dictionary: Option<Arc<[u8]>>,
     /// The descriptor of this parquet column
     pub descriptor: Descriptor,
 }
@@ -40,6 +42,7 @@ impl PageMetaData {
             column_start,
             num_values,
             compression,
+            zstd_dictionary: None,
             descriptor,
         }
     }
@@ -51,6 +54,7 @@ impl From<&ColumnChunkMetadata> for PageMetaData {
             column_start: column.byte_range().start,
             num_values: column.num_values(),
             compression: column.compression(),
+            zstd_dictionary: column.zstd_dictionary().map(Arc::from),
             descriptor: column.descriptor().descriptor.clone(),
         }
     }
@@ -68,6 +72,8 @@ pub struct PageReader {
 
     compression: Compression,
 
+    zstd_dictionary: Option<Arc<[u8]>>,
+
     // The number of values we have seen so far.
     seen_num_values: i64,
 
@@ -110,6 +116,7 @@ impl PageReader {
             reader,
             total_num_values: reader_meta.num_values,
             compression: reader_meta.compression,
+            zstd_dictionary: reader_meta.zstd_dictionary,
             seen_num_values: 0,
             descriptor: reader_meta.descriptor,
             scratch,
@@ -160,7 +167,14 @@ impl PageReader {
             ));
         }
 
-        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
+        finish_page(
+            page_header,
+            buffer,
+            self.compression,
+            self.zstd_dictionary.as_ref(),
+            &self.descriptor,
+        )
+        .map(|p| {
             if let CompressedPage::Dict(d) = p {
                 Some(d)
             } else {
@@ -228,13 +242,21 @@ pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<Compre
         ));
     }
 
-    finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
+    finish_page(
+        page_header,
+        buffer,
+        reader.compression,
+        reader.zstd_dictionary.as_ref(),
+        &reader.descriptor,
+    )
+    .map(Some)
 }
 
 pub(super) fn finish_page(
     page_header: ParquetPageHeader,
     data: MemSlice,
     compression: Compression,
+    zstd_dictionary: Option<&Arc<[u8]>>,
     descriptor: &Descriptor,
 ) -> ParquetResult<CompressedPage> {
     let type_ = page_header.type_.try_into()?;
@@ -267,7 +289,8 @@ pub(super) fn finish_page(
                 uncompressed_page_size,
                 dict_header.num_values.try_into()?,
                 is_sorted,
-            );
+            )
+            .with_zstd_dictionary(zstd_dictionary.cloned());
 
             Ok(CompressedPage::Dict(page))
         },
@@ -287,13 +310,16 @@ pub(super) fn finish_page(
                 );
             }
 
-            Ok(CompressedPage::Data(CompressedDataPage::new_read(
-                DataPageHeader::V1(header),
-                CowBuffer::Borrowed(data),
-                compression,
-                uncompressed_page_size,
-                descriptor.clone(),
-            )))
+            Ok(CompressedPage::Data(
+                CompressedDataPage::new_read(
+                    DataPageHeader::V1(header),
+                    CowBuffer::Borrowed(data),
+                    compression,
+                    uncompressed_page_size,
+                    descriptor.clone(),
+                )
+                .with_zstd_dictionary(zstd_dictionary.cloned()),
+            ))
         },
         PageType::DataPageV2 => {
             let header = page_header.data_page_header_v2.ok_or_else(|| {
@@ -311,13 +337,16 @@ pub(super) fn finish_page(
                 );
             }
 
-            Ok(CompressedPage::Data(CompressedDataPage::new_read(
-                DataPageHeader::V2(header),
-                CowBuffer::Borrowed(data),
-                compression,
-                uncompressed_page_size,
-                descriptor.clone(),
-            )))
+            Ok(CompressedPage::Data(
+                CompressedDataPage::new_read(
+                    DataPageHeader::V2(header),
+                    CowBuffer::Borrowed(data),
+                    compression,
+                    uncompressed_page_size,
+                    descriptor.clone(),
+                )
+                .with_zstd_dictionary(zstd_dictionary.cloned()),
+            ))
         },
     }
 }
@@ -1,4 +1,5 @@
 use std::io::SeekFrom;
+use std::sync::Arc;
 
 use async_stream::try_stream;
 use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, Stream};
@@ -34,6 +35,7 @@ pub async fn get_page_stream_from_column_start<'a, R: AsyncRead + Unpin + Send>(
         reader,
         page_metadata.num_values,
         page_metadata.compression,
+        page_metadata.zstd_dictionary,
         page_metadata.descriptor,
         scratch,
         max_header_size,
@@ -53,6 +55,7 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
         reader,
         page_metadata.num_values,
         page_metadata.compression,
+        page_metadata.zstd_dictionary,
         page_metadata.descriptor,
         scratch,
         max_page_size,
@@ -63,6 +66,7 @@ fn _get_page_stream<R: AsyncRead + Unpin + Send>(
     reader: &mut R,
     total_num_values: i64,
     compression: Compression,
+    zstd_dictionary: Option<Arc<[u8]>>,
     descriptor: Descriptor,
     mut scratch: Vec<u8>,
     max_page_size: usize,
@@ -99,6 +103,7 @@ fn _get_page_stream<R: AsyncRead + Unpin + Send>(
                 page_header,
                 MemSlice::from_vec(std::mem::take(&mut scratch)),
                 compression,
+                zstd_dictionary.as_ref(),
                 &descriptor,
             )?;
         }
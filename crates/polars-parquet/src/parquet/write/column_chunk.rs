@@ -1,5 +1,8 @@
 use std::io::Write;
+use std::sync::Arc;
 
+use base64::Engine as _;
+use base64::engine::general_purpose;
 #[cfg(feature = "async")]
 use futures::AsyncWrite;
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
@@ -14,10 +17,10 @@ use super::page::write_page_async;
 use super::page::{PageWriteSpec, write_page};
 use super::statistics::reduce;
 use crate::parquet::FallibleStreamingIterator;
-use crate::parquet::compression::Compression;
+use crate::parquet::compression::{Compression, ZSTD_DICTIONARY_META_KEY};
 use crate::parquet::encoding::Encoding;
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::metadata::ColumnDescriptor;
+use crate::parquet::metadata::{ColumnDescriptor, KeyValue};
 use crate::parquet::page::{CompressedPage, PageType};
 
 pub fn write_column_chunk<W, E>(
@@ -113,6 +116,28 @@ fn build_column_chunk(
         .next()
         .unwrap_or(Compression::Uncompressed);
 
+    // The zstd dictionary is stored once in the metadata of the column chunk, so all its pages
+    // must be compressed with the same one.
+    let zstd_dictionary = specs.iter().find_map(|spec| spec.zstd_dictionary.as_ref());
+    if let Some(zstd_dictionary) = zstd_dictionary {
+        if specs.iter().any(|spec| {
+            !spec
+                .zstd_dictionary
+                .as_ref()
+                .is_some_and(|x| Arc::ptr_eq(x, zstd_dictionary))
+        }) {
+            return Err(crate::parquet::error::ParquetError::oos(
+                "All pages within a column chunk must be compressed with the same zstd dictionary",
+            ));
+        }
+    }
+    let key_value_metadata = zstd_dictionary.map(|zstd_dictionary| {
+        vec![KeyValue {
+            key: ZSTD_DICTIONARY_META_KEY.to_string(),
+            value: Some(general_purpose::STANDARD.encode(zstd_dictionary)),
+        }]
+    });
+
     // SPEC: the total compressed size is the total compressed size of each page + the header size
     let total_compressed_size = specs
         .iter()
@@ -188,7 +213,7 @@ fn build_column_chunk(
         num_values,
         total_uncompressed_size,
         total_compressed_size,
-        key_value_metadata: None,
+        key_value_metadata,
         data_page_offset,
         index_page_offset: None,
         dictionary_page_offset: None,
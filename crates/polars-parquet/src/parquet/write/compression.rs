@@ -1,3 +1,6 @@
+#[cfg(feature = "zstd")]
+use std::sync::Arc;
+
 use crate::parquet::compression::CompressionOptions;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{
@@ -6,11 +9,12 @@ use crate::parquet::page::{
 };
 use crate::parquet::{CowBuffer, FallibleStreamingIterator, compression};
 
-/// Compresses a [`DataPage`] into a [`CompressedDataPage`].
+/// Compresses a [`DataPage`] into a [`CompressedDataPage`] with `compress`.
 fn compress_data(
     page: DataPage,
     mut compressed_buffer: Vec<u8>,
     compression: CompressionOptions,
+    compress: impl FnOnce(&[u8], &mut Vec<u8>) -> ParquetResult<()>,
 ) -> ParquetResult<CompressedDataPage> {
     let DataPage {
         mut buffer,
@@ -23,18 +27,14 @@ fn compress_data(
     if compression != CompressionOptions::Uncompressed {
         match &header {
             DataPageHeader::V1(_) => {
-                compression::compress(compression, &buffer, &mut compressed_buffer)?;
+                compress(&buffer, &mut compressed_buffer)?;
             },
             DataPageHeader::V2(header) => {
                 let levels_byte_length = (header.repetition_levels_byte_length
                     + header.definition_levels_byte_length)
                     as usize;
                 compressed_buffer.extend_from_slice(&buffer[..levels_byte_length]);
-                compression::compress(
-                    compression,
-                    &buffer[levels_byte_length..],
-                    &mut compressed_buffer,
-                )?;
+                compress(&buffer[levels_byte_length..], &mut compressed_buffer)?;
             },
         };
     } else {
@@ -55,6 +55,7 @@ fn compress_dict(
     page: DictPage,
     mut compressed_buffer: Vec<u8>,
     compression: CompressionOptions,
+    compress: impl FnOnce(&[u8], &mut Vec<u8>) -> ParquetResult<()>,
 ) -> ParquetResult<CompressedDictPage> {
     let DictPage {
         buffer,
@@ -64,7 +65,7 @@ fn compress_dict(
 
     let uncompressed_page_size = buffer.len();
     let compressed_buffer = if compression != CompressionOptions::Uncompressed {
-        compression::compress(compression, &buffer, &mut compressed_buffer)?;
+        compress(&buffer, &mut compressed_buffer)?;
         CowBuffer::Owned(compressed_buffer)
     } else {
         buffer
@@ -91,13 +92,117 @@ pub fn compress(
     compressed_buffer: Vec<u8>,
     compression: CompressionOptions,
 ) -> ParquetResult<CompressedPage> {
+    let compress =
+        |input: &[u8], output: &mut Vec<u8>| compression::compress(compression, input, output);
     match page {
         Page::Data(page) => {
-            compress_data(page, compressed_buffer, compression).map(CompressedPage::Data)
+            compress_data(page, compressed_buffer, compression, compress).map(CompressedPage::Data)
         },
         Page::Dict(page) => {
-            compress_dict(page, compressed_buffer, compression).map(CompressedPage::Dict)
+            compress_dict(page, compressed_buffer, compression, compress).map(CompressedPage::Dict)
+        },
+    }
+}
+
+/// The minimum number of pages to train a zstd dictionary on. With fewer pages, the dictionary
+/// costs more space than it saves.
+#[cfg(feature = "zstd")]
+const MIN_ZSTD_DICTIONARY_SAMPLES: usize = 8;
+
+/// The maximum size of the pages a zstd dictionary is trained on, relative to the size of the
+/// dictionary. Beyond this size, training becomes slow while the dictionary hardly improves.
+#[cfg(feature = "zstd")]
+const ZSTD_DICTIONARY_SAMPLE_FACTOR: usize = 100;
+
+/// A zstd dictionary trained on the pages of a column chunk.
+#[cfg(feature = "zstd")]
+struct ZstdDictionary {
+    bytes: Arc<[u8]>,
+    /// The dictionary digested for the compression level.
+    prepared: zstd::dict::EncoderDictionary<'static>,
+}
+
+#[cfg(feature = "zstd")]
+impl ZstdDictionary {
+    /// Trains a dictionary of at most `max_size` bytes on an evenly spaced sample of `pages`.
+    ///
+    /// Returns `None` if the pages are too few or too small to train a dictionary on, in which
+    /// case they are compressed without one.
+    fn train(
+        pages: &[Page],
+        max_size: usize,
+        level: Option<compression::ZstdLevel>,
+    ) -> ParquetResult<Option<Self>> {
+        let total_size = pages
+            .iter()
+            .map(|page| compressed_part(page).len())
+            .sum::<usize>();
+        let max_sample_size = max_size
+            .saturating_mul(ZSTD_DICTIONARY_SAMPLE_FACTOR)
+            .max(1);
+        let samples = pages
+            .iter()
+            .step_by(total_size.div_ceil(max_sample_size).max(1))
+            .map(compressed_part)
+            .filter(|sample| !sample.is_empty())
+            .collect::<Vec<_>>();
+        if samples.len() < MIN_ZSTD_DICTIONARY_SAMPLES {
+            return Ok(None);
+        }
+
+        let Ok(bytes) = zstd::dict::from_samples(&samples, max_size) else {
+            return Ok(None);
+        };
+        let level = level.map(|v| v.compression_level()).unwrap_or_default();
+        let prepared = zstd::dict::EncoderDictionary::copy(&bytes, level);
+        Ok(Some(Self {
+            bytes: bytes.into(),
+            prepared,
+        }))
+    }
+
+    fn compress_page(
+        &self,
+        page: Page,
+        compressed_buffer: Vec<u8>,
+        compression: CompressionOptions,
+    ) -> ParquetResult<CompressedPage> {
+        let zstd_dictionary = Some(self.bytes.clone());
+        let compress = |input: &[u8], output: &mut Vec<u8>| -> ParquetResult<()> {
+            let old_len = output.len();
+            output.resize(old_len + zstd::zstd_safe::compress_bound(input.len()), 0);
+            let mut compressor = zstd::bulk::Compressor::with_prepared_dictionary(&self.prepared)?;
+            let written_size = compressor.compress_to_buffer(input, &mut output[old_len..])?;
+            output.truncate(old_len + written_size);
+            Ok(())
+        };
+        Ok(match page {
+            Page::Data(page) => CompressedPage::Data(
+                compress_data(page, compressed_buffer, compression, compress)?
+                    .with_zstd_dictionary(zstd_dictionary),
+            ),
+            Page::Dict(page) => CompressedPage::Dict(
+                compress_dict(page, compressed_buffer, compression, compress)?
+                    .with_zstd_dictionary(zstd_dictionary),
+            ),
+        })
+    }
+}
+
+/// The part of the buffer of `page` that [`compress`] compresses.
+#[cfg(feature = "zstd")]
+fn compressed_part(page: &Page) -> &[u8] {
+    match page {
+        Page::Data(page) => match page.header() {
+            DataPageHeader::V1(_) => page.buffer(),
+            DataPageHeader::V2(header) => {
+                let levels_byte_length = (header.repetition_levels_byte_length
+                    + header.definition_levels_byte_length)
+                    as usize;
+                &page.buffer()[levels_byte_length..]
+            },
         },
+        Page::Dict(page) => &page.buffer[..],
     }
 }
 
@@ -108,6 +213,12 @@ pub struct Compressor<I: Iterator<Item = ParquetResult<Page>>> {
     compression: CompressionOptions,
     buffer: Vec<u8>,
     current: Option<CompressedPage>,
+    /// The maximum size of the zstd dictionary to train before the first page is compressed.
+    zstd_dictionary_size: Option<usize>,
+    /// The pages that were taken from `iter` to train the zstd dictionary on.
+    buffered: Option<std::vec::IntoIter<Page>>,
+    #[cfg(feature = "zstd")]
+    zstd_dictionary: Option<ZstdDictionary>,
 }
 
 impl<I: Iterator<Item = ParquetResult<Page>>> Compressor<I> {
@@ -118,9 +229,24 @@ impl<I: Iterator<Item = ParquetResult<Page>>> Compressor<I> {
             compression,
             buffer,
             current: None,
+            zstd_dictionary_size: None,
+            buffered: None,
+            #[cfg(feature = "zstd")]
+            zstd_dictionary: None,
         }
     }
 
+    /// Train a zstd dictionary of at most `size` bytes on a sample of the pages, and compress all
+    /// pages with it. This only applies to zstd compression and requires all pages to be held in
+    /// memory.
+    ///
+    /// This substantially improves the compression of many small pages, but the pages can then
+    /// only be read by Polars.
+    pub fn with_zstd_dictionary_size(mut self, size: Option<usize>) -> Self {
+        self.zstd_dictionary_size = size;
+        self
+    }
+
     /// Creates a new [`Compressor`] (same as `new`)
     pub fn new_from_vec(iter: I, compression: CompressionOptions, buffer: Vec<u8>) -> Self {
         Self::new(iter, compression, buffer)
@@ -136,6 +262,52 @@ impl<I: Iterator<Item = ParquetResult<Page>>> Compressor<I> {
         buffer.clear();
         (self.iter, buffer)
     }
+
+    fn next_page(&mut self) -> Option<ParquetResult<Page>> {
+        if let Some(size) = self.zstd_dictionary_size.take() {
+            if let Err(err) = self.train_zstd_dictionary(size) {
+                return Some(Err(err));
+            }
+        }
+        match &mut self.buffered {
+            Some(pages) => pages.next().map(Ok),
+            None => self.iter.next(),
+        }
+    }
+
+    #[cfg(feature = "zstd")]
+    fn train_zstd_dictionary(&mut self, size: usize) -> ParquetResult<()> {
+        let CompressionOptions::Zstd(level) = self.compression else {
+            return Ok(());
+        };
+        let pages = self.iter.by_ref().collect::<ParquetResult<Vec<_>>>()?;
+        self.zstd_dictionary = ZstdDictionary::train(&pages, size, level)?;
+        self.buffered = Some(pages.into_iter());
+        Ok(())
+    }
+
+    #[cfg(not(feature = "zstd"))]
+    fn train_zstd_dictionary(&mut self, _size: usize) -> ParquetResult<()> {
+        match self.compression {
+            CompressionOptions::Zstd(_) => Err(ParquetError::FeatureNotActive(
+                crate::parquet::error::Feature::Zstd,
+                "train a zstd dictionary".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn compress_page(
+        &mut self,
+        page: Page,
+        compressed_buffer: Vec<u8>,
+    ) -> ParquetResult<CompressedPage> {
+        #[cfg(feature = "zstd")]
+        if let Some(zstd_dictionary) = &self.zstd_dictionary {
+            return zstd_dictionary.compress_page(page, compressed_buffer, self.compression);
+        }
+        compress(page, compressed_buffer, self.compression)
+    }
 }
 
 impl<I: Iterator<Item = ParquetResult<Page>>> FallibleStreamingIterator for Compressor<I> {
@@ -151,9 +323,8 @@ impl<I: Iterator<Item = ParquetResult<Page>>> FallibleStreamingIterator for Comp
         compressed_buffer.clear();
 
         let next = self
-            .iter
-            .next()
-            .map(|x| x.and_then(|page| compress(page, compressed_buffer, self.compression)))
+            .next_page()
+            .map(|x| x.and_then(|page| self.compress_page(page, compressed_buffer)))
             .transpose()?;
         self.current = next;
         Ok(())
@@ -175,12 +346,12 @@ impl<I: Iterator<Item = ParquetResult<Page>>> Iterator for Compressor<I> {
         };
         compressed_buffer.clear();
 
-        let page = self.iter.next()?;
+        let page = self.next_page()?;
         let page = match page {
             Ok(page) => page,
             Err(err) => return Some(Err(err)),
         };
 
-        Some(compress(page, compressed_buffer, self.compression))
+        Some(self.compress_page(page, compressed_buffer))
     }
 }
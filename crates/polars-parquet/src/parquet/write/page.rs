@@ -1,4 +1,5 @@
 use std::io::Write;
+use std::sync::Arc;
 
 #[cfg(feature = "async")]
 use futures::{AsyncWrite, AsyncWriteExt};
@@ -47,6 +48,8 @@ pub struct PageWriteSpec {
     pub offset: u64,
     pub bytes_written: u64,
     pub compression: Compression,
+    /// The zstd dictionary the page is compressed with, if any.
+    pub zstd_dictionary: Option<Arc<[u8]>>,
     pub statistics: Option<Statistics>,
 }
 
@@ -90,6 +93,7 @@ pub fn write_page<W: Write>(
         offset,
         bytes_written,
         compression: compressed_page.compression(),
+        zstd_dictionary: compressed_page.zstd_dictionary().cloned(),
         statistics,
         num_values,
         num_rows,
@@ -138,6 +142,7 @@ pub async fn write_page_async<W: AsyncWrite + Unpin + Send>(
         offset,
         bytes_written,
         compression: compressed_page.compression(),
+        zstd_dictionary: compressed_page.zstd_dictionary().cloned(),
         statistics,
         num_rows,
         num_values,
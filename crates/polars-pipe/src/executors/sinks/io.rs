@@ -71,6 +71,25 @@ fn clean_after_delay(time: Option<SystemTime>, secs: u64, path: &Path) {
     }
 }
 
+/// The number of bytes in the spill directories. These are shared by all processes that use the
+/// same temporary directory.
+pub fn spill_dir_size() -> u64 {
+    fn dir_size(dir: &Path) -> u64 {
+        let Ok(rd) = fs::read_dir(dir) else {
+            return 0;
+        };
+        // Files can be removed while we walk, so errors are skipped.
+        rd.filter_map(|entry| entry.ok())
+            .map(|entry| match entry.metadata() {
+                Ok(md) if md.is_dir() => dir_size(&entry.path()),
+                Ok(md) => md.len(),
+                Err(_) => 0,
+            })
+            .sum()
+    }
+    dir_size(&Path::new(get_base_temp_dir()).join("polars"))
+}
+
 /// Starts a new thread that will clean up operations of directories that don't
 /// have a lockfile (opened with 'w' permissions).
 fn gc_thread(operation_name: &'static str, rx: Receiver<PathBuf>) {
@@ -11,6 +11,7 @@ mod utils;
 
 use std::sync::OnceLock;
 
+pub use io::spill_dir_size;
 pub(crate) use joins::*;
 pub(crate) use ordered::*;
 #[cfg(any(
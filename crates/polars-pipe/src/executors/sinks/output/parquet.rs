@@ -74,6 +74,7 @@ impl ParquetSink {
         let writer = ParquetWriter::new(try_get_writeable(path.to_str().unwrap(), cloud_options)?)
             .with_compression(options.compression)
             .with_data_page_size(options.data_page_size)
+            .with_zstd_dictionary_size(options.zstd_dictionary_size)
             .with_statistics(options.statistics)
             .with_row_group_size(options.row_group_size)
             // This is important! Otherwise we will deadlock
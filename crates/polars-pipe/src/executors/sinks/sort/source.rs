@@ -102,6 +102,7 @@ impl SortSource {
                     multithreaded: true,
                     maintain_order: false,
                     limit: None,
+                    nan_position: NanPosition::Largest,
                 },
             ),
             Some((offset, len)) => {
@@ -121,6 +122,7 @@ impl SortSource {
                             multithreaded: true,
                             maintain_order: false,
                             limit: None,
+                            nan_position: NanPosition::Largest,
                         },
                     );
                     *len = len.saturating_sub(df_len);
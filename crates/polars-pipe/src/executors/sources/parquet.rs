@@ -150,6 +150,8 @@ impl ParquetSource {
                         .as_ref()
                         .map(|x| (x.clone(), Arc::from(path.to_str().unwrap()))),
                 );
+            // SAFETY: validation can only be disabled with the unsafe `Utf8Validation::trusted`.
+            reader = unsafe { reader.validate_utf8(options.validate_utf8.is_enabled()) };
 
             let n_rows_this_file = reader.num_rows().unwrap();
             let current_row_offset = self
@@ -219,6 +221,9 @@ impl ParquetSource {
                             .as_ref()
                             .map(|x| (x.clone(), Arc::from(path.to_str().unwrap()))),
                     );
+            // SAFETY: validation can only be disabled with the unsafe `Utf8Validation::trusted`.
+            async_reader =
+                unsafe { async_reader.validate_utf8(options.validate_utf8.is_enabled()) };
 
             let n_rows_this_file = async_reader.num_rows().await?;
             let current_row_offset = self
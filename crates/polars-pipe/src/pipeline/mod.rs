@@ -11,6 +11,7 @@ use polars_core::prelude::*;
 use polars_utils::cell::SyncUnsafeCell;
 
 pub use crate::executors::sinks::group_by::aggregates::can_convert_to_hash_agg;
+pub use crate::executors::sinks::spill_dir_size;
 use crate::operators::{Operator, Sink};
 
 pub(crate) fn morsels_per_sink() -> usize {
@@ -0,0 +1,42 @@
+pub use polars_ops::series::anonymize::AnonymizeKey;
+
+use super::*;
+
+/// Privacy transforms, see [`polars_ops::series::anonymize`].
+pub struct AnonymizeNameSpace(pub(crate) Expr);
+
+impl AnonymizeNameSpace {
+    /// Replace every value by a token of `length` hexadecimal characters, computed with a hash
+    /// of the value keyed by `key`.
+    pub fn tokenize(self, key: &AnonymizeKey, length: usize) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::Anonymize(AnonymizeFunction::Tokenize {
+                key: key.clone(),
+                length,
+            }))
+    }
+
+    /// Replace the digits and ASCII letters of the strings by ones of the same class, chosen by
+    /// a hash of the whole string keyed by `key`.
+    pub fn hash_preserving_format(self, key: &AnonymizeKey) -> Expr {
+        self.0.map_unary(FunctionExpr::Anonymize(
+            AnonymizeFunction::HashPreservingFormat { key: key.clone() },
+        ))
+    }
+
+    /// Round the numbers down to a multiple of `width`.
+    pub fn bucket(self, width: f64) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::Anonymize(AnonymizeFunction::Bucket { width }))
+    }
+
+    /// Keep the first `length` characters of the strings, replacing the others by `fill` if
+    /// it is given.
+    pub fn truncate(self, length: usize, fill: Option<char>) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::Anonymize(AnonymizeFunction::Truncate {
+                length,
+                fill,
+            }))
+    }
+}
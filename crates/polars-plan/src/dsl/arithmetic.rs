@@ -57,6 +57,21 @@ impl Expr {
         binary_expr(self, Operator::FloorDivide, rhs)
     }
 
+    /// Apply the arithmetic `op` to the decimals `self` and `rhs`, handling results that don't
+    /// fit in 38 digits according to `overflow`.
+    ///
+    /// `op` is one of [`Operator::Plus`], [`Operator::Minus`], [`Operator::Multiply`] and
+    /// [`Operator::TrueDivide`]. Integer operands are treated as decimals with a scale of 0.
+    #[cfg(feature = "dtype-decimal")]
+    pub fn decimal_arithmetic(
+        self,
+        op: Operator,
+        rhs: Self,
+        overflow: polars_core::chunked_array::arithmetic::DecimalOverflow,
+    ) -> Self {
+        self.map_binary(FunctionExpr::DecimalArithmetic { op, overflow }, rhs)
+    }
+
     /// Raise expression to the power `exponent`
     pub fn pow<E: Into<Expr>>(self, exponent: E) -> Self {
         self.map_binary(PowFunction::Generic, exponent.into())
@@ -176,6 +176,34 @@ impl ArrayNameSpace {
         self.0
             .map_binary(FunctionExpr::ArrayExpr(ArrayFunction::Shift), n)
     }
+
+    /// Compute the dot product of every sub-array with the matching sub-array of `other`.
+    pub fn dot(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ArrayExpr(ArrayFunction::Dot), other)
+    }
+
+    /// Compute the cosine similarity of every sub-array with the matching sub-array of `other`.
+    pub fn cosine_similarity(self, other: Expr) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::CosineSimilarity),
+            other,
+        )
+    }
+
+    /// Compute the Euclidean norm of every sub-array.
+    pub fn l2_norm(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::L2Norm))
+    }
+
+    /// Slice every sub-array. The result is an array with the width of the slice.
+    pub fn slice(self, offset: i64, length: Option<usize>) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::Slice(
+                offset, length,
+            )))
+    }
     /// Returns a column with a separate row for every array element.
     pub fn explode(self) -> Expr {
         self.0
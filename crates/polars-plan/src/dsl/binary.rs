@@ -53,6 +53,106 @@ impl BinaryNameSpace {
             .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
 
+    /// Format 16-byte values or a [`DataType::Uuid`] column as UUIDs in the lowercase hyphenated
+    /// form.
+    #[cfg(feature = "binary_encoding")]
+    pub fn uuid_to_string(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::UuidToString))
+    }
+
+    /// Format 4-byte and 16-byte values as IPv4 and IPv6 addresses respectively.
+    #[cfg(feature = "binary_encoding")]
+    pub fn ip_to_string(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::IpToString))
+    }
+
+    /// Check if IP addresses in their binary form are in the subnet given in CIDR notation, eg:
+    /// "10.0.0.0/8".
+    #[cfg(feature = "binary_encoding")]
+    pub fn ip_is_in_subnet(self, cidr: PlSmallStr) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::IpIsInSubnet(cidr)))
+    }
+
+    /// Slice the values to at most `length` bytes, starting at `offset`, which counts from the
+    /// end of the values if negative.
+    pub fn slice(self, offset: Expr, length: Expr) -> Expr {
+        self.0.map_ternary(
+            FunctionExpr::BinaryExpr(BinaryFunction::Slice),
+            offset,
+            length,
+        )
+    }
+
+    /// Append the values of `other` to the values.
+    pub fn concat(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::BinaryExpr(BinaryFunction::Concat), other)
+    }
+
+    /// The bitwise AND of values of the same size.
+    pub fn bitwise_and(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::BinaryExpr(BinaryFunction::BitwiseAnd), other)
+    }
+
+    /// The bitwise OR of values of the same size.
+    pub fn bitwise_or(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::BinaryExpr(BinaryFunction::BitwiseOr), other)
+    }
+
+    /// The bitwise XOR of values of the same size.
+    pub fn bitwise_xor(self, other: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::BinaryExpr(BinaryFunction::BitwiseXor), other)
+    }
+
+    /// Compute the CRC-32 checksum of the values.
+    pub fn crc32(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Crc32))
+    }
+
+    /// Compute the 64-bit XXH3 hash of the values, which is stable across Polars versions.
+    pub fn xxh3(self, seed: u64) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Xxh3(seed)))
+    }
+
+    /// Decode URL safe `base64`, with or without padding.
+    #[cfg(feature = "binary_encoding")]
+    pub fn base64url_decode(self, strict: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64UrlDecode(
+                strict,
+            )))
+    }
+
+    /// Encode as URL safe `base64`, without padding.
+    #[cfg(feature = "binary_encoding")]
+    pub fn base64url_encode(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Base64UrlEncode))
+    }
+
+    /// Convert IPv4 addresses in their binary form to integers.
+    #[cfg(feature = "binary_encoding")]
+    pub fn ipv4_to_integer(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Ipv4ToInteger))
+    }
+
+    /// Convert IP addresses in their binary form to 128-bit integers, stored as a struct of the
+    /// `high` and `low` 64 bits. IPv4 addresses are mapped to IPv6.
+    #[cfg(all(feature = "binary_encoding", feature = "dtype-struct"))]
+    pub fn ipv6_to_integer(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Ipv6ToInteger))
+    }
+
     #[cfg(feature = "binary_encoding")]
     pub fn from_buffer(self, to_type: DataType, is_little_endian: bool) -> Expr {
         self.0
@@ -4,6 +4,8 @@ use polars_core::prelude::*;
 use polars_io::HiveOptions;
 #[cfg(any(feature = "parquet", feature = "csv", feature = "ipc"))]
 use polars_io::RowIndex;
+#[cfg(feature = "parquet")]
+use polars_io::Utf8Validation;
 #[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
@@ -85,6 +87,7 @@ impl DslBuilder {
         low_memory: bool,
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
+        validate_utf8: Utf8Validation,
         schema: Option<SchemaRef>,
         hive_options: HiveOptions,
         glob: bool,
@@ -113,6 +116,7 @@ impl DslBuilder {
                     parallel,
                     low_memory,
                     use_statistics,
+                    validate_utf8,
                 },
                 cloud_options,
                 metadata: None,
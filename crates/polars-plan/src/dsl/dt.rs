@@ -23,6 +23,12 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Count the number of business days until `end`, excluding `end`.
+    #[cfg(all(feature = "business", feature = "dtype-date"))]
+    pub fn business_day_count(self, end: Expr, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+        super::functions::business_day_count(self.0, end, week_mask, holidays)
+    }
+
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(self, format: &str) -> Expr {
@@ -212,12 +218,43 @@ impl DateLikeNameSpace {
 
     /// Truncate the Datetime/Date range into buckets.
     pub fn truncate(self, every: Expr) -> Expr {
+        self.truncate_with_week_start(every, 0)
+    }
+
+    /// Truncate the Datetime/Date range into buckets, where weekly buckets start `week_start`
+    /// days after Monday, e.g. `6` for weeks starting on Sunday.
+    pub fn truncate_with_week_start(self, every: Expr, week_start: u8) -> Expr {
         self.0.map_binary(
-            FunctionExpr::TemporalExpr(TemporalFunction::Truncate),
+            FunctionExpr::TemporalExpr(TemporalFunction::Truncate {
+                week_start,
+                non_existent: None,
+            }),
             every,
         )
     }
 
+    /// Truncate a time zone aware Datetime in wall-clock time.
+    ///
+    /// Truncated datetimes which are ambiguous or non-existent in the time zone are resolved with
+    /// `ambiguous` and `non_existent` instead of being inferred from the original datetime.
+    /// `week_start` is the same as in [`DateLikeNameSpace::truncate_with_week_start`].
+    #[cfg(feature = "timezones")]
+    pub fn truncate_with_policy(
+        self,
+        every: Expr,
+        week_start: u8,
+        ambiguous: Expr,
+        non_existent: NonExistent,
+    ) -> Expr {
+        self.0.map_n_ary(
+            FunctionExpr::TemporalExpr(TemporalFunction::Truncate {
+                week_start,
+                non_existent: Some(non_existent),
+            }),
+            [every, ambiguous],
+        )
+    }
+
     /// Roll backward to the first day of the month.
     #[cfg(feature = "month_start")]
     pub fn month_start(self) -> Expr {
@@ -248,16 +285,55 @@ impl DateLikeNameSpace {
 
     /// Round the Datetime/Date range into buckets.
     pub fn round(self, every: Expr) -> Expr {
-        self.0
-            .map_binary(FunctionExpr::TemporalExpr(TemporalFunction::Round), every)
+        self.0.map_binary(
+            FunctionExpr::TemporalExpr(TemporalFunction::Round(None)),
+            every,
+        )
+    }
+
+    /// Round a time zone aware Datetime in wall-clock time.
+    ///
+    /// See [`DateLikeNameSpace::truncate_with_policy`] for how `ambiguous` and `non_existent` are
+    /// applied.
+    #[cfg(feature = "timezones")]
+    pub fn round_with_policy(
+        self,
+        every: Expr,
+        ambiguous: Expr,
+        non_existent: NonExistent,
+    ) -> Expr {
+        self.0.map_n_ary(
+            FunctionExpr::TemporalExpr(TemporalFunction::Round(Some(non_existent))),
+            [every, ambiguous],
+        )
     }
 
     /// Offset this `Date/Datetime` by a given offset [`Duration`].
     /// This will take leap years/ months into account.
     #[cfg(feature = "offset_by")]
     pub fn offset_by(self, by: Expr) -> Expr {
-        self.0
-            .map_binary(FunctionExpr::TemporalExpr(TemporalFunction::OffsetBy), by)
+        self.0.map_binary(
+            FunctionExpr::TemporalExpr(TemporalFunction::OffsetBy(None)),
+            by,
+        )
+    }
+
+    /// Offset a time zone aware Datetime by a given offset [`Duration`].
+    ///
+    /// The calendar part of the offset (months, weeks and days) is applied in wall-clock time and
+    /// resulting datetimes which are ambiguous or non-existent are resolved with `ambiguous` and
+    /// `non_existent`. The fixed part of the offset is added in absolute time.
+    #[cfg(all(feature = "offset_by", feature = "timezones"))]
+    pub fn offset_by_with_policy(
+        self,
+        by: Expr,
+        ambiguous: Expr,
+        non_existent: NonExistent,
+    ) -> Expr {
+        self.0.map_n_ary(
+            FunctionExpr::TemporalExpr(TemporalFunction::OffsetBy(Some(non_existent))),
+            [by, ambiguous],
+        )
     }
 
     #[cfg(feature = "timezones")]
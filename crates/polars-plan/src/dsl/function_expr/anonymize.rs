@@ -0,0 +1,98 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_ops::series::anonymize::{self, AnonymizeKey};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{ColumnsUdf, SpecialEq};
+use crate::dsl::{FieldsMapper, FunctionOptions};
+use crate::map;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug)]
+pub enum AnonymizeFunction {
+    Tokenize { key: AnonymizeKey, length: usize },
+    HashPreservingFormat { key: AnonymizeKey },
+    Bucket { width: f64 },
+    Truncate { length: usize, fill: Option<char> },
+}
+
+impl Hash for AnonymizeFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        use AnonymizeFunction as A;
+        match self {
+            A::Tokenize { key, length } => {
+                key.hash(state);
+                length.hash(state);
+            },
+            A::HashPreservingFormat { key } => key.hash(state),
+            A::Bucket { width } => width.to_bits().hash(state),
+            A::Truncate { length, fill } => {
+                length.hash(state);
+                fill.hash(state);
+            },
+        }
+    }
+}
+
+// The keys are not displayed, so that they do not end up in logs of query plans.
+impl fmt::Display for AnonymizeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use AnonymizeFunction as A;
+        let s = match self {
+            A::Tokenize { .. } => "tokenize",
+            A::HashPreservingFormat { .. } => "hash_preserving_format",
+            A::Bucket { .. } => "bucket",
+            A::Truncate { .. } => "truncate",
+        };
+        write!(f, "anonymize.{s}")
+    }
+}
+
+impl From<AnonymizeFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: AnonymizeFunction) -> Self {
+        use AnonymizeFunction as A;
+        match func {
+            A::Tokenize { key, length } => map!(tokenize, &key, length),
+            A::HashPreservingFormat { key } => map!(hash_preserving_format, &key),
+            A::Bucket { width } => map!(bucket, width),
+            A::Truncate { length, fill } => map!(truncate, length, fill),
+        }
+    }
+}
+
+impl AnonymizeFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use AnonymizeFunction as A;
+        match self {
+            A::Tokenize { .. } | A::HashPreservingFormat { .. } | A::Truncate { .. } => {
+                mapper.with_dtype(DataType::String)
+            },
+            A::Bucket { .. } => mapper.with_same_dtype(),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+fn tokenize(c: &Column, key: &AnonymizeKey, length: usize) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(|s| anonymize::tokenize(s, key, length))
+}
+
+fn hash_preserving_format(c: &Column, key: &AnonymizeKey) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(|s| anonymize::hash_preserving_format(s, key))
+}
+
+fn bucket(c: &Column, width: f64) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(|s| anonymize::bucket(s, width))
+}
+
+fn truncate(c: &Column, length: usize, fill: Option<char>) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(|s| anonymize::truncate(s, length, fill))
+}
@@ -1,3 +1,4 @@
+use polars_core::utils::slice_offsets;
 use polars_ops::chunked_array::array::*;
 
 use super::*;
@@ -31,8 +32,12 @@ pub enum ArrayFunction {
     #[cfg(feature = "array_count")]
     CountMatches,
     Shift,
+    Slice(i64, Option<usize>),
     Explode,
     Concat,
+    Dot,
+    CosineSimilarity,
+    L2Norm,
 }
 
 impl ArrayFunction {
@@ -69,7 +74,25 @@ impl ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
             Shift => mapper.with_same_dtype(),
+            Slice(offset, length) => mapper.try_map_dtype(|dt| {
+                let DataType::Array(inner, width) = dt else {
+                    polars_bail!(InvalidOperation: "expected Array type, got: {}", dt)
+                };
+                let (_, len) = slice_offsets(*offset, length.unwrap_or(usize::MAX), *width);
+                Ok(DataType::Array(inner.clone(), len))
+            }),
             Explode => mapper.try_map_to_array_inner_dtype(),
+            Dot | CosineSimilarity | L2Norm => {
+                let is_f32 = |f: &Field| match &f.dtype {
+                    DataType::Array(inner, _) => **inner == DataType::Float32,
+                    _ => false,
+                };
+                if mapper.args().iter().all(is_f32) {
+                    mapper.with_dtype(DataType::Float32)
+                } else {
+                    mapper.with_dtype(DataType::Float64)
+                }
+            },
         }
     }
 
@@ -99,7 +122,11 @@ impl ArrayFunction {
             | A::Concat
             | A::Get(_)
             | A::Join(_)
-            | A::Shift => FunctionOptions::elementwise(),
+            | A::Shift
+            | A::Slice(_, _)
+            | A::Dot
+            | A::CosineSimilarity
+            | A::L2Norm => FunctionOptions::elementwise(),
             A::Explode => FunctionOptions::row_separable(),
         }
     }
@@ -143,7 +170,11 @@ impl Display for ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
             Shift => "shift",
+            Slice(_, _) => "slice",
             Explode => "explode",
+            Dot => "dot",
+            CosineSimilarity => "cosine_similarity",
+            L2Norm => "l2_norm",
         };
         write!(f, "arr.{name}")
     }
@@ -179,7 +210,11 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "array_count")]
             CountMatches => map_as_slice!(count_matches),
             Shift => map_as_slice!(shift),
+            Slice(offset, length) => map!(slice, offset, length),
             Explode => map_as_slice!(explode),
+            Dot => map_as_slice!(dot),
+            CosineSimilarity => map_as_slice!(cosine_similarity),
+            L2Norm => map!(l2_norm),
         }
     }
 }
@@ -325,6 +360,26 @@ pub(super) fn shift(s: &[Column]) -> PolarsResult<Column> {
     ca.array_shift(n.as_materialized_series()).map(Column::from)
 }
 
+pub(super) fn slice(s: &Column, offset: i64, length: Option<usize>) -> PolarsResult<Column> {
+    s.array()?
+        .array_slice(offset, length)
+        .map(|ca| ca.into_column())
+}
+
+pub(super) fn dot(s: &[Column]) -> PolarsResult<Column> {
+    s[0].array()?.array_dot(s[1].array()?).map(Column::from)
+}
+
+pub(super) fn cosine_similarity(s: &[Column]) -> PolarsResult<Column> {
+    s[0].array()?
+        .array_cosine_similarity(s[1].array()?)
+        .map(Column::from)
+}
+
+pub(super) fn l2_norm(s: &Column) -> PolarsResult<Column> {
+    s.array()?.array_l2_norm().map(Column::from)
+}
+
 fn explode(c: &[Column]) -> PolarsResult<Column> {
     c[0].explode()
 }
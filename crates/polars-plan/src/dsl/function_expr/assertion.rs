@@ -0,0 +1,45 @@
+use super::*;
+
+/// The number of failing values shown in the error of an assertion.
+const SHOWN_FAILURES: usize = 5;
+
+pub(super) fn assert(s: &[Column], message: &str) -> PolarsResult<Column> {
+    let (values, predicate) = (&s[0], &s[1]);
+    let predicate = predicate.bool().map_err(|_| {
+        polars_err!(
+            InvalidOperation: "assertion predicate must be boolean, got {}", predicate.dtype()
+        )
+    })?;
+    let len = values.len();
+    polars_ensure!(
+        predicate.len() == len || predicate.len() == 1,
+        ShapeMismatch: "assertion predicate has length {}, expected {}", predicate.len(), len
+    );
+
+    // Nulls don't fail the assertion.
+    let failed = predicate.len() - predicate.sum().unwrap_or(0) as usize - predicate.null_count();
+    if failed == 0 {
+        return Ok(values.clone());
+    }
+    let (failed, shown) = if predicate.len() == 1 {
+        (len, values.head(Some(SHOWN_FAILURES)))
+    } else {
+        let mask = (!predicate).fill_null_with_values(false)?;
+        (failed, values.filter(&mask)?.head(Some(SHOWN_FAILURES)))
+    };
+    let shown = shown
+        .as_materialized_series()
+        .iter()
+        .map(|av| av.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let message = if message.is_empty() {
+        String::new()
+    } else {
+        format!("{message}: ")
+    };
+    polars_bail!(
+        AssertionError: "{}{} of {} values of {:?} failed, including [{}]",
+        message, failed, len, values.name(), shown
+    )
+}
@@ -18,9 +18,30 @@ pub enum BinaryFunction {
     Base64Decode(bool),
     #[cfg(feature = "binary_encoding")]
     Base64Encode,
+    #[cfg(feature = "binary_encoding")]
+    UuidToString,
+    #[cfg(feature = "binary_encoding")]
+    IpToString,
+    #[cfg(feature = "binary_encoding")]
+    IpIsInSubnet(PlSmallStr),
+    #[cfg(feature = "binary_encoding")]
+    Ipv4ToInteger,
+    #[cfg(all(feature = "binary_encoding", feature = "dtype-struct"))]
+    Ipv6ToInteger,
     Size,
     #[cfg(feature = "binary_encoding")]
     FromBuffer(DataType, bool),
+    Slice,
+    Concat,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    Crc32,
+    Xxh3(u64),
+    #[cfg(feature = "binary_encoding")]
+    Base64UrlDecode(bool),
+    #[cfg(feature = "binary_encoding")]
+    Base64UrlEncode,
 }
 
 impl BinaryFunction {
@@ -32,10 +53,28 @@ impl BinaryFunction {
             #[cfg(feature = "binary_encoding")]
             HexDecode(_) | Base64Decode(_) => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
-            HexEncode | Base64Encode => mapper.with_dtype(DataType::String),
+            HexEncode | Base64Encode | UuidToString | IpToString => {
+                mapper.with_dtype(DataType::String)
+            },
+            #[cfg(feature = "binary_encoding")]
+            IpIsInSubnet(_) => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "binary_encoding")]
+            Ipv4ToInteger => mapper.with_dtype(DataType::UInt32),
+            #[cfg(all(feature = "binary_encoding", feature = "dtype-struct"))]
+            Ipv6ToInteger => mapper.with_dtype(DataType::Struct(vec![
+                Field::new(PlSmallStr::from_static("high"), DataType::UInt64),
+                Field::new(PlSmallStr::from_static("low"), DataType::UInt64),
+            ])),
             Size => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "binary_encoding")]
             FromBuffer(dtype, _) => mapper.with_dtype(dtype.clone()),
+            Slice | Concat | BitwiseAnd | BitwiseOr | BitwiseXor => mapper.with_same_dtype(),
+            Crc32 => mapper.with_dtype(DataType::UInt32),
+            Xxh3(_) => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "binary_encoding")]
+            Base64UrlDecode(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "binary_encoding")]
+            Base64UrlEncode => mapper.with_dtype(DataType::String),
         }
     }
 
@@ -45,13 +84,28 @@ impl BinaryFunction {
             B::Contains | B::StartsWith | B::EndsWith => {
                 FunctionOptions::elementwise().with_supertyping(Default::default())
             },
-            B::Size => FunctionOptions::elementwise(),
+            B::Size
+            | B::Slice
+            | B::Concat
+            | B::BitwiseAnd
+            | B::BitwiseOr
+            | B::BitwiseXor
+            | B::Crc32
+            | B::Xxh3(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "binary_encoding")]
             B::HexDecode(_)
             | B::HexEncode
             | B::Base64Decode(_)
             | B::Base64Encode
-            | B::FromBuffer(_, _) => FunctionOptions::elementwise(),
+            | B::UuidToString
+            | B::IpToString
+            | B::IpIsInSubnet(_)
+            | B::Ipv4ToInteger
+            | B::FromBuffer(_, _)
+            | B::Base64UrlDecode(_)
+            | B::Base64UrlEncode => FunctionOptions::elementwise(),
+            #[cfg(all(feature = "binary_encoding", feature = "dtype-struct"))]
+            B::Ipv6ToInteger => FunctionOptions::elementwise(),
         }
     }
 }
@@ -71,9 +125,30 @@ impl Display for BinaryFunction {
             Base64Decode(_) => "base64_decode",
             #[cfg(feature = "binary_encoding")]
             Base64Encode => "base64_encode",
+            #[cfg(feature = "binary_encoding")]
+            UuidToString => "uuid_to_string",
+            #[cfg(feature = "binary_encoding")]
+            IpToString => "ip_to_string",
+            #[cfg(feature = "binary_encoding")]
+            IpIsInSubnet(_) => "ip_is_in_subnet",
+            #[cfg(feature = "binary_encoding")]
+            Ipv4ToInteger => "ipv4_to_integer",
+            #[cfg(all(feature = "binary_encoding", feature = "dtype-struct"))]
+            Ipv6ToInteger => "ipv6_to_integer",
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             FromBuffer(_, _) => "from_buffer",
+            Slice => "slice",
+            Concat => "concat",
+            BitwiseAnd => "bitwise_and",
+            BitwiseOr => "bitwise_or",
+            BitwiseXor => "bitwise_xor",
+            Crc32 => "crc32",
+            Xxh3(_) => "xxh3",
+            #[cfg(feature = "binary_encoding")]
+            Base64UrlDecode(_) => "base64url_decode",
+            #[cfg(feature = "binary_encoding")]
+            Base64UrlEncode => "base64url_encode",
         };
         write!(f, "bin.{s}")
     }
@@ -100,9 +175,30 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Base64Decode(strict) => map!(base64_decode, strict),
             #[cfg(feature = "binary_encoding")]
             Base64Encode => map!(base64_encode),
+            #[cfg(feature = "binary_encoding")]
+            UuidToString => map!(uuid_to_string),
+            #[cfg(feature = "binary_encoding")]
+            IpToString => map!(ip_to_string),
+            #[cfg(feature = "binary_encoding")]
+            IpIsInSubnet(cidr) => map!(ip_is_in_subnet, &cidr),
+            #[cfg(feature = "binary_encoding")]
+            Ipv4ToInteger => map!(ipv4_to_integer),
+            #[cfg(all(feature = "binary_encoding", feature = "dtype-struct"))]
+            Ipv6ToInteger => map!(ipv6_to_integer),
             Size => map!(size_bytes),
             #[cfg(feature = "binary_encoding")]
             FromBuffer(dtype, is_little_endian) => map!(from_buffer, &dtype, is_little_endian),
+            Slice => map_as_slice!(slice),
+            Concat => map_as_slice!(concat),
+            BitwiseAnd => map_as_slice!(bitwise_and),
+            BitwiseOr => map_as_slice!(bitwise_or),
+            BitwiseXor => map_as_slice!(bitwise_xor),
+            Crc32 => map!(crc32),
+            Xxh3(seed) => map!(xxh3, seed),
+            #[cfg(feature = "binary_encoding")]
+            Base64UrlDecode(strict) => map!(base64url_decode, strict),
+            #[cfg(feature = "binary_encoding")]
+            Base64UrlEncode => map!(base64url_encode),
         }
     }
 }
@@ -165,6 +261,40 @@ pub(super) fn base64_encode(s: &Column) -> PolarsResult<Column> {
     Ok(ca.base64_encode().into())
 }
 
+#[cfg(feature = "binary_encoding")]
+pub(super) fn uuid_to_string(s: &Column) -> PolarsResult<Column> {
+    let s = s.as_materialized_series();
+    let ca = match s.dtype() {
+        DataType::Uuid => s.extension()?.storage().binary()?,
+        _ => s.binary()?,
+    };
+    ca.uuid_encode().map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn ip_to_string(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.ip_encode().map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn ip_is_in_subnet(s: &Column, cidr: &str) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.ip_is_in_subnet(cidr).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn ipv4_to_integer(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.ipv4_to_integer().map(|ok| ok.into_column())
+}
+
+#[cfg(all(feature = "binary_encoding", feature = "dtype-struct"))]
+pub(super) fn ipv6_to_integer(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.ipv6_to_integer().map(|ok| ok.into_column())
+}
+
 #[cfg(feature = "binary_encoding")]
 pub(super) fn from_buffer(
     s: &Column,
@@ -176,6 +306,66 @@ pub(super) fn from_buffer(
         .map(|val| val.into())
 }
 
+pub(super) fn slice(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].binary()?;
+    Ok(ca.bin_slice(&s[1], &s[2])?.into_column())
+}
+
+pub(super) fn concat(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].binary()?;
+    let other = s[1].binary()?;
+    Ok(ca.concat(other)?.with_name(ca.name().clone()).into_column())
+}
+
+pub(super) fn bitwise_and(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].binary()?;
+    let other = s[1].binary()?;
+    Ok(ca
+        .bitwise_and(other)?
+        .with_name(ca.name().clone())
+        .into_column())
+}
+
+pub(super) fn bitwise_or(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].binary()?;
+    let other = s[1].binary()?;
+    Ok(ca
+        .bitwise_or(other)?
+        .with_name(ca.name().clone())
+        .into_column())
+}
+
+pub(super) fn bitwise_xor(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].binary()?;
+    let other = s[1].binary()?;
+    Ok(ca
+        .bitwise_xor(other)?
+        .with_name(ca.name().clone())
+        .into_column())
+}
+
+pub(super) fn crc32(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    Ok(ca.crc32().into_column())
+}
+
+pub(super) fn xxh3(s: &Column, seed: u64) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    Ok(ca.xxh3(seed).into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base64url_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.base64url_decode(strict).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base64url_encode(s: &Column) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    Ok(ca.base64url_encode().into())
+}
+
 impl From<BinaryFunction> for FunctionExpr {
     fn from(b: BinaryFunction) -> Self {
         FunctionExpr::BinaryExpr(b)
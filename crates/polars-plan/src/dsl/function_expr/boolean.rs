@@ -1,8 +1,3 @@
-use std::ops::{BitAnd, BitOr};
-
-use polars_core::POOL;
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-
 use super::*;
 #[cfg(feature = "is_in")]
 use crate::wrap;
@@ -254,44 +249,10 @@ fn not(s: &Column) -> PolarsResult<Column> {
 
 // We shouldn't hit these often only on very wide dataframes where we don't reduce to & expressions.
 fn any_horizontal(s: &[Column]) -> PolarsResult<Column> {
-    let out = POOL
-        .install(|| {
-            s.par_iter()
-                .try_fold(
-                    || BooleanChunked::new(PlSmallStr::EMPTY, &[false]),
-                    |acc, b| {
-                        let b = b.cast(&DataType::Boolean)?;
-                        let b = b.bool()?;
-                        PolarsResult::Ok((&acc).bitor(b))
-                    },
-                )
-                .try_reduce(
-                    || BooleanChunked::new(PlSmallStr::EMPTY, [false]),
-                    |a, b| Ok(a.bitor(b)),
-                )
-        })?
-        .with_name(s[0].name().clone());
-    Ok(out.into_column())
+    polars_ops::prelude::any_horizontal_columns(s)
 }
 
 // We shouldn't hit these often only on very wide dataframes where we don't reduce to & expressions.
 fn all_horizontal(s: &[Column]) -> PolarsResult<Column> {
-    let out = POOL
-        .install(|| {
-            s.par_iter()
-                .try_fold(
-                    || BooleanChunked::new(PlSmallStr::EMPTY, &[true]),
-                    |acc, b| {
-                        let b = b.cast(&DataType::Boolean)?;
-                        let b = b.bool()?;
-                        PolarsResult::Ok((&acc).bitand(b))
-                    },
-                )
-                .try_reduce(
-                    || BooleanChunked::new(PlSmallStr::EMPTY, [true]),
-                    |a, b| Ok(a.bitand(b)),
-                )
-        })?
-        .with_name(s[0].name().clone());
-    Ok(out.into_column())
+    polars_ops::prelude::all_horizontal_columns(s)
 }
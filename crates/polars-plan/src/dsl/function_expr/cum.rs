@@ -10,9 +10,14 @@ pub(super) fn cum_sum(s: &Column, reverse: bool) -> PolarsResult<Column> {
     polars_ops::prelude::cum_sum(s.as_materialized_series(), reverse).map(Column::from)
 }
 
-pub(super) fn cum_prod(s: &Column, reverse: bool) -> PolarsResult<Column> {
+pub(super) fn cum_prod(s: &Column, reverse: bool, log_domain: bool) -> PolarsResult<Column> {
     // @scalar-opt
-    polars_ops::prelude::cum_prod(s.as_materialized_series(), reverse).map(Column::from)
+    let s = s.as_materialized_series();
+    if log_domain {
+        polars_ops::prelude::cum_prod_log_domain(s, reverse).map(Column::from)
+    } else {
+        polars_ops::prelude::cum_prod(s, reverse).map(Column::from)
+    }
 }
 
 pub(super) fn cum_min(s: &Column, reverse: bool) -> PolarsResult<Column> {
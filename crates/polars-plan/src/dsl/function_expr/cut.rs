@@ -4,15 +4,19 @@ pub(crate) fn cut(
     s: &Column,
     breaks: Vec<f64>,
     labels: Option<Vec<PlSmallStr>>,
+    label_format: Option<&str>,
     left_closed: bool,
     include_breaks: bool,
+    as_enum: bool,
 ) -> PolarsResult<Column> {
     polars_ops::prelude::cut(
         s.as_materialized_series(),
         breaks,
         labels,
+        label_format,
         left_closed,
         include_breaks,
+        as_enum,
     )
     .map(Column::from)
 }
@@ -21,17 +25,21 @@ pub(crate) fn qcut(
     s: &Column,
     probs: Vec<f64>,
     labels: Option<Vec<PlSmallStr>>,
+    label_format: Option<&str>,
     left_closed: bool,
     allow_duplicates: bool,
     include_breaks: bool,
+    as_enum: bool,
 ) -> PolarsResult<Column> {
     polars_ops::prelude::qcut(
         s.as_materialized_series(),
         probs,
         labels,
+        label_format,
         left_closed,
         allow_duplicates,
         include_breaks,
+        as_enum,
     )
     .map(Column::from)
 }
@@ -8,6 +8,8 @@ use polars_time::base_utc_offset as base_utc_offset_fn;
 use polars_time::dst_offset as dst_offset_fn;
 #[cfg(feature = "offset_by")]
 use polars_time::impl_offset_by;
+#[cfg(all(feature = "offset_by", feature = "timezones"))]
+use polars_time::impl_offset_by_with_policy;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 use polars_time::replace::{replace_date, replace_datetime};
 #[cfg(feature = "serde")]
@@ -52,9 +54,15 @@ pub enum TemporalFunction {
     #[cfg(feature = "timezones")]
     ConvertTimeZone(TimeZone),
     TimeStamp(TimeUnit),
-    Truncate,
+    /// Truncate with weekly buckets starting `week_start` days after Monday, optionally in
+    /// wall-clock time with an explicit `non_existent` policy. The `ambiguous` policy is then
+    /// passed as an extra input.
+    Truncate {
+        week_start: u8,
+        non_existent: Option<NonExistent>,
+    },
     #[cfg(feature = "offset_by")]
-    OffsetBy,
+    OffsetBy(Option<NonExistent>),
     #[cfg(feature = "month_start")]
     MonthStart,
     #[cfg(feature = "month_end")]
@@ -63,7 +71,7 @@ pub enum TemporalFunction {
     BaseUtcOffset,
     #[cfg(feature = "timezones")]
     DSTOffset,
-    Round,
+    Round(Option<NonExistent>),
     Replace,
     #[cfg(feature = "timezones")]
     ReplaceTimeZone(Option<TimeZone>, NonExistent),
@@ -108,9 +116,9 @@ impl TemporalFunction {
                 DataType::Datetime(tu, _) => Ok(DataType::Datetime(*tu, None)),
                 dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
             }),
-            Truncate => mapper.with_same_dtype(),
+            Truncate { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "offset_by")]
-            OffsetBy => mapper.with_same_dtype(),
+            OffsetBy(_) => mapper.with_same_dtype(),
             #[cfg(feature = "month_start")]
             MonthStart => mapper.with_same_dtype(),
             #[cfg(feature = "month_end")]
@@ -119,7 +127,7 @@ impl TemporalFunction {
             BaseUtcOffset => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
             #[cfg(feature = "timezones")]
             DSTOffset => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
-            Round => mapper.with_same_dtype(),
+            Round(_) => mapper.with_same_dtype(),
             Replace => mapper.with_same_dtype(),
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(tz, _non_existent) => mapper.map_datetime_dtype_timezone(tz.as_ref()),
@@ -182,10 +190,10 @@ impl TemporalFunction {
             T::MonthEnd => FunctionOptions::elementwise(),
             #[cfg(feature = "timezones")]
             T::BaseUtcOffset | T::DSTOffset => FunctionOptions::elementwise(),
-            T::Truncate => FunctionOptions::elementwise(),
+            T::Truncate { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "offset_by")]
-            T::OffsetBy => FunctionOptions::elementwise(),
-            T::Round => FunctionOptions::elementwise(),
+            T::OffsetBy(_) => FunctionOptions::elementwise(),
+            T::Round(_) => FunctionOptions::elementwise(),
             T::Replace => FunctionOptions::elementwise(),
             T::Duration(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "timezones")]
@@ -234,9 +242,9 @@ impl Display for TemporalFunction {
             CastTimeUnit(_) => "cast_time_unit",
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
-            Truncate => "truncate",
+            Truncate { .. } => "truncate",
             #[cfg(feature = "offset_by")]
-            OffsetBy => "offset_by",
+            OffsetBy(_) => "offset_by",
             #[cfg(feature = "month_start")]
             MonthStart => "month_start",
             #[cfg(feature = "month_end")]
@@ -245,7 +253,7 @@ impl Display for TemporalFunction {
             BaseUtcOffset => "base_utc_offset",
             #[cfg(feature = "timezones")]
             DSTOffset => "dst_offset",
-            Round => "round",
+            Round(_) => "round",
             Replace => "replace",
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(_, _) => "replace_time_zone",
@@ -496,20 +504,63 @@ pub(super) fn cast_time_unit(s: &Column, tu: TimeUnit) -> PolarsResult<Column> {
     }
 }
 
-pub(super) fn truncate(s: &[Column]) -> PolarsResult<Column> {
+/// Apply `func` to the wall-clock time of `ca` and localize the result again, resolving ambiguous
+/// and non-existent datetimes with the given policies.
+#[cfg(feature = "timezones")]
+fn apply_in_local_time<F>(
+    ca: &DatetimeChunked,
+    ambiguous: &StringChunked,
+    non_existent: NonExistent,
+    func: F,
+) -> PolarsResult<DatetimeChunked>
+where
+    F: FnOnce(&DatetimeChunked) -> PolarsResult<DatetimeChunked>,
+{
+    let local = polars_ops::prelude::replace_time_zone(
+        ca,
+        None,
+        &StringChunked::from_iter(std::iter::once("raise")),
+        NonExistent::Raise,
+    )?;
+    let out = func(&local)?;
+    polars_ops::prelude::replace_time_zone(&out, ca.time_zone().as_deref(), ambiguous, non_existent)
+}
+
+pub(super) fn truncate(
+    s: &[Column],
+    week_start: u8,
+    non_existent: Option<NonExistent>,
+) -> PolarsResult<Column> {
     let time_series = &s[0];
     let every = s[1].str()?;
 
     let mut out = match time_series.dtype() {
-        DataType::Datetime(_, tz) => match tz {
+        DataType::Datetime(_, tz) => match (tz, non_existent) {
+            // The wall-clock buckets of the result may not be sorted in absolute time.
             #[cfg(feature = "timezones")]
-            Some(tz) => time_series
+            (Some(_), Some(non_existent)) => {
+                return Ok(apply_in_local_time(
+                    time_series.datetime()?,
+                    s[2].str()?,
+                    non_existent,
+                    |ca| ca.truncate_with_week_start(None, every, week_start),
+                )?
+                .into_column());
+            },
+            #[cfg(feature = "timezones")]
+            (Some(tz), None) => time_series
                 .datetime()?
-                .truncate(tz.parse::<Tz>().ok().as_ref(), every)?
+                .truncate_with_week_start(tz.parse::<Tz>().ok().as_ref(), every, week_start)?
+                .into_column(),
+            _ => time_series
+                .datetime()?
+                .truncate_with_week_start(None, every, week_start)?
                 .into_column(),
-            _ => time_series.datetime()?.truncate(None, every)?.into_column(),
         },
-        DataType::Date => time_series.date()?.truncate(None, every)?.into_column(),
+        DataType::Date => time_series
+            .date()?
+            .truncate_with_week_start(None, every, week_start)?
+            .into_column(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
     };
     out.set_sorted_flag(time_series.is_sorted_flag());
@@ -517,8 +568,18 @@ pub(super) fn truncate(s: &[Column]) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "offset_by")]
-pub(super) fn offset_by(s: &[Column]) -> PolarsResult<Column> {
-    impl_offset_by(s[0].as_materialized_series(), s[1].as_materialized_series()).map(Column::from)
+pub(super) fn offset_by(s: &[Column], non_existent: Option<NonExistent>) -> PolarsResult<Column> {
+    match non_existent {
+        #[cfg(feature = "timezones")]
+        Some(non_existent) => impl_offset_by_with_policy(
+            s[0].as_materialized_series(),
+            s[1].as_materialized_series(),
+            s[2].str()?,
+            non_existent,
+        ),
+        _ => impl_offset_by(s[0].as_materialized_series(), s[1].as_materialized_series()),
+    }
+    .map(Column::from)
 }
 
 #[cfg(feature = "month_start")]
@@ -588,14 +649,21 @@ pub(super) fn dst_offset(s: &Column) -> PolarsResult<Column> {
     }
 }
 
-pub(super) fn round(s: &[Column]) -> PolarsResult<Column> {
+pub(super) fn round(s: &[Column], non_existent: Option<NonExistent>) -> PolarsResult<Column> {
     let time_series = &s[0];
     let every = s[1].str()?;
 
     Ok(match time_series.dtype() {
-        DataType::Datetime(_, tz) => match tz {
+        DataType::Datetime(_, tz) => match (tz, non_existent) {
+            #[cfg(feature = "timezones")]
+            (Some(_), Some(non_existent)) => {
+                apply_in_local_time(time_series.datetime()?, s[2].str()?, non_existent, |ca| {
+                    ca.round(every, None)
+                })?
+                .into_column()
+            },
             #[cfg(feature = "timezones")]
-            Some(tz) => time_series
+            (Some(tz), None) => time_series
                 .datetime()
                 .unwrap()
                 .round(every, tz.parse::<Tz>().ok().as_ref())?
@@ -0,0 +1,62 @@
+use polars_core::chunked_array::arithmetic::{
+    _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul, DecimalOverflow,
+};
+
+use super::*;
+
+/// The decimal operand of a decimal arithmetic operation.
+///
+/// Integers are treated as decimals with a scale of 0.
+fn to_decimal(c: &Column, op: Operator) -> PolarsResult<DecimalChunked> {
+    let c = match c.dtype() {
+        DataType::Decimal(_, _) => c.clone(),
+        dt if dt.is_integer() => c.cast(&DataType::Decimal(None, Some(0)))?,
+        dt => polars_bail!(
+            InvalidOperation: "decimal overflow handling of `{}` requires decimal operands, got {}", op, dt
+        ),
+    };
+    Ok(c.decimal()?.clone())
+}
+
+pub(super) fn decimal_arithmetic(
+    s: &[Column],
+    op: Operator,
+    overflow: DecimalOverflow,
+) -> PolarsResult<Column> {
+    let lhs = to_decimal(&s[0], op)?;
+    let rhs = to_decimal(&s[1], op)?;
+    let out = match op {
+        Operator::Plus => lhs.add_with_overflow(&rhs, overflow)?,
+        Operator::Minus => lhs.sub_with_overflow(&rhs, overflow)?,
+        Operator::Multiply => lhs.mul_with_overflow(&rhs, overflow)?,
+        Operator::TrueDivide => lhs.div_with_overflow(&rhs, overflow)?,
+        _ => {
+            polars_bail!(InvalidOperation: "decimal overflow handling is not supported for `{}`", op)
+        },
+    };
+    Ok(out.into_series().with_name(s[0].name().clone()).into())
+}
+
+/// The scale of the output of a decimal arithmetic operation on `lhs` and `rhs`.
+pub(super) fn decimal_arithmetic_scale(
+    lhs: &DataType,
+    rhs: &DataType,
+    op: Operator,
+) -> PolarsResult<usize> {
+    let scale = |dt: &DataType| match dt {
+        DataType::Decimal(_, scale) => Ok(scale.unwrap_or(0)),
+        dt if dt.is_integer() || matches!(dt, DataType::Unknown(UnknownKind::Int(_))) => Ok(0),
+        dt => polars_bail!(
+            InvalidOperation: "decimal overflow handling of `{}` requires decimal operands, got {}", op, dt
+        ),
+    };
+    let (lhs, rhs) = (scale(lhs)?, scale(rhs)?);
+    Ok(match op {
+        Operator::Plus | Operator::Minus => _get_decimal_scale_add_sub(lhs, rhs),
+        Operator::Multiply => _get_decimal_scale_mul(lhs, rhs),
+        Operator::TrueDivide => _get_decimal_scale_div(lhs),
+        _ => {
+            polars_bail!(InvalidOperation: "decimal overflow handling is not supported for `{}`", op)
+        },
+    })
+}
@@ -40,10 +40,10 @@ pub(super) fn interpolate(s: &Column, method: InterpolationMethod) -> PolarsResu
 }
 
 #[cfg(feature = "interpolate_by")]
-pub(super) fn interpolate_by(s: &[Column]) -> PolarsResult<Column> {
+pub(super) fn interpolate_by(s: &[Column], method: InterpolationMethod) -> PolarsResult<Column> {
     let by = &s[1];
     let by_is_sorted = by.as_materialized_series().is_sorted(Default::default())?;
-    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted)
+    polars_ops::prelude::interpolate_by_with_method(&s[0], by, by_is_sorted, method)
 }
 
 pub(super) fn to_physical(s: &Column) -> PolarsResult<Column> {
@@ -129,6 +129,21 @@ pub(super) fn mean_horizontal(
     polars_ops::prelude::mean_horizontal(s, null_strategy)
 }
 
+#[cfg(feature = "dtype-struct")]
+pub(super) fn cum_sum_horizontal(s: &mut [Column], ignore_nulls: bool) -> PolarsResult<Column> {
+    let null_strategy = if ignore_nulls {
+        NullStrategy::Ignore
+    } else {
+        NullStrategy::Propagate
+    };
+    polars_ops::prelude::cum_sum_horizontal(s, null_strategy)
+}
+
+#[cfg(all(feature = "rank", feature = "dtype-struct"))]
+pub(super) fn rank_horizontal(s: &mut [Column], options: RankOptions) -> PolarsResult<Column> {
+    polars_ops::prelude::rank_horizontal(s, options)
+}
+
 pub(super) fn drop_nulls(s: &Column) -> PolarsResult<Column> {
     Ok(s.drop_nulls())
 }
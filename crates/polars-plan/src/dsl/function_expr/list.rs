@@ -47,6 +47,12 @@ pub enum ListFunction {
     Reverse,
     Unique(bool),
     NUnique,
+    #[cfg(feature = "dtype-struct")]
+    ValueCounts {
+        sort: bool,
+        normalize: bool,
+        name: PlSmallStr,
+    },
     #[cfg(feature = "list_sets")]
     SetOperation(SetOperation),
     #[cfg(feature = "list_any_all")]
@@ -108,6 +114,31 @@ impl ListFunction {
             Sort(_) => mapper.with_same_dtype(),
             Reverse => mapper.with_same_dtype(),
             Unique(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "dtype-struct")]
+            ValueCounts {
+                normalize, name, ..
+            } => mapper.try_map_field(|field| {
+                let DataType::List(inner) = field.dtype() else {
+                    polars_bail!(
+                        op = "list.value_counts",
+                        got = field.dtype(),
+                        expected = "List"
+                    )
+                };
+                let count_dtype = if *normalize {
+                    DataType::Float64
+                } else {
+                    IDX_DTYPE
+                };
+                let fields = vec![
+                    Field::new(field.name().clone(), inner.as_ref().clone()),
+                    Field::new(name.clone(), count_dtype),
+                ];
+                Ok(Field::new(
+                    field.name().clone(),
+                    DataType::List(Box::new(DataType::Struct(fields))),
+                ))
+            }),
             Length => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "list_sets")]
             SetOperation(_) => mapper.with_same_dtype(),
@@ -170,6 +201,8 @@ impl ListFunction {
             | L::Unique(_)
             | L::Join(_)
             | L::NUnique => FunctionOptions::elementwise(),
+            #[cfg(feature = "dtype-struct")]
+            L::ValueCounts { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "list_any_all")]
             L::Any | L::All => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-array")]
@@ -240,6 +273,8 @@ impl Display for ListFunction {
                 }
             },
             NUnique => "n_unique",
+            #[cfg(feature = "dtype-struct")]
+            ValueCounts { .. } => "value_counts",
             #[cfg(feature = "list_sets")]
             SetOperation(s) => return write!(f, "list.{s}"),
             #[cfg(feature = "list_any_all")]
@@ -312,6 +347,12 @@ impl From<ListFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "dtype-array")]
             ToArray(width) => map!(to_array, width),
             NUnique => map!(n_unique),
+            #[cfg(feature = "dtype-struct")]
+            ValueCounts {
+                sort,
+                normalize,
+                name,
+            } => map!(value_counts, sort, normalize, name.clone()),
             #[cfg(feature = "list_to_struct")]
             ToStruct(args) => map!(to_struct, &args),
         }
@@ -683,6 +724,39 @@ pub(super) fn unique(s: &Column, is_stable: bool) -> PolarsResult<Column> {
     }
 }
 
+/// Count the unique values of every list, without exploding the column first.
+#[cfg(feature = "dtype-struct")]
+pub(super) fn value_counts(
+    s: &Column,
+    sort: bool,
+    normalize: bool,
+    name: PlSmallStr,
+) -> PolarsResult<Column> {
+    let ca = s.list()?;
+    let values_name = ca.name().clone();
+    let out = ca.try_apply_amortized(|s| {
+        s.as_ref()
+            .clone()
+            .with_name(values_name.clone())
+            .value_counts(sort, false, name.clone(), normalize)
+            .map(|df| df.into_struct(PlSmallStr::EMPTY).into_series())
+    })?;
+    // Without any valid list the output keeps the inner type of the input.
+    if out.null_count() == out.len() {
+        let count_dtype = if normalize {
+            DataType::Float64
+        } else {
+            IDX_DTYPE
+        };
+        let inner = DataType::Struct(vec![
+            Field::new(values_name.clone(), ca.inner_dtype().clone()),
+            Field::new(name, count_dtype),
+        ]);
+        return Ok(ListChunked::full_null_with_dtype(values_name, out.len(), &inner).into_column());
+    }
+    Ok(out.into_column())
+}
+
 #[cfg(feature = "list_sets")]
 pub(super) fn set_operation(s: &[Column], set_type: SetOperation) -> PolarsResult<Column> {
     let s0 = &s[0];
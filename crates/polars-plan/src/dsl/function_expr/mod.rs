@@ -1,9 +1,12 @@
 #[cfg(feature = "abs")]
 mod abs;
+#[cfg(feature = "anonymize")]
+mod anonymize;
 #[cfg(feature = "arg_where")]
 mod arg_where;
 #[cfg(feature = "dtype-array")]
 mod array;
+mod assertion;
 mod binary;
 #[cfg(feature = "bitwise")]
 mod bitwise;
@@ -26,6 +29,8 @@ mod cum;
 mod cut;
 #[cfg(feature = "temporal")]
 mod datetime;
+#[cfg(feature = "dtype-decimal")]
+mod decimal;
 mod dispatch;
 #[cfg(feature = "ewma")]
 mod ewm;
@@ -40,6 +45,8 @@ mod list;
 #[cfg(feature = "log")]
 mod log;
 mod nan;
+#[cfg(feature = "ols")]
+mod ols;
 #[cfg(feature = "peaks")]
 mod peaks;
 #[cfg(feature = "ffi_plugin")]
@@ -65,6 +72,7 @@ mod shift_and_fill;
 mod shrink_type;
 #[cfg(feature = "sign")]
 mod sign;
+mod static_plugin;
 #[cfg(feature = "strings")]
 mod strings;
 #[cfg(feature = "dtype-struct")]
@@ -85,6 +93,8 @@ pub use correlation::CorrelationMethod;
 #[cfg(feature = "fused")]
 pub use fused::FusedOperator;
 pub use list::ListFunction;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::DecimalOverflow;
 pub use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 #[cfg(feature = "random")]
@@ -92,7 +102,12 @@ pub use random::RandomMethod;
 use schema::FieldsMapper;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+pub use static_plugin::{
+    StaticPlugin, register_static_plugin, static_plugin_function, unregister_static_plugin,
+};
 
+#[cfg(feature = "anonymize")]
+pub use self::anonymize::AnonymizeFunction;
 pub use self::binary::BinaryFunction;
 #[cfg(feature = "bitwise")]
 pub use self::bitwise::BitwiseFunction;
@@ -122,6 +137,8 @@ use super::*;
 #[derive(Clone, PartialEq, Debug)]
 pub enum FunctionExpr {
     // Namespaces
+    #[cfg(feature = "anonymize")]
+    Anonymize(AnonymizeFunction),
     #[cfg(feature = "dtype-array")]
     ArrayExpr(ArrayFunction),
     BinaryExpr(BinaryFunction),
@@ -154,6 +171,8 @@ pub enum FunctionExpr {
     Pow(PowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "row_hash")]
+    StableHash(HashAlgorithm, u64, u64),
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
@@ -221,6 +240,7 @@ pub enum FunctionExpr {
     #[cfg(feature = "cum_agg")]
     CumProd {
         reverse: bool,
+        log_domain: bool,
     },
     #[cfg(feature = "cum_agg")]
     CumMin {
@@ -251,7 +271,7 @@ pub enum FunctionExpr {
     #[cfg(feature = "interpolate")]
     Interpolate(InterpolationMethod),
     #[cfg(feature = "interpolate_by")]
-    InterpolateBy,
+    InterpolateBy(InterpolationMethod),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -282,11 +302,18 @@ pub enum FunctionExpr {
     LowerBound,
     #[cfg(feature = "fused")]
     Fused(fused::FusedOperator),
+    #[cfg(feature = "dtype-decimal")]
+    DecimalArithmetic {
+        op: Operator,
+        overflow: DecimalOverflow,
+    },
     ConcatExpr(bool),
     #[cfg(feature = "cov")]
     Correlation {
         method: correlation::CorrelationMethod,
     },
+    #[cfg(feature = "ols")]
+    Lstsq(OlsOptions),
     #[cfg(feature = "peaks")]
     PeakMin,
     #[cfg(feature = "peaks")]
@@ -295,16 +322,20 @@ pub enum FunctionExpr {
     Cut {
         breaks: Vec<f64>,
         labels: Option<Vec<PlSmallStr>>,
+        label_format: Option<PlSmallStr>,
         left_closed: bool,
         include_breaks: bool,
+        as_enum: bool,
     },
     #[cfg(feature = "cutqcut")]
     QCut {
         probs: Vec<f64>,
         labels: Option<Vec<PlSmallStr>>,
+        label_format: Option<PlSmallStr>,
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        as_enum: bool,
     },
     #[cfg(feature = "rle")]
     RLE,
@@ -317,6 +348,9 @@ pub enum FunctionExpr {
         seed: Option<u64>,
     },
     SetSortedFlag(IsSorted),
+    Assert {
+        message: PlSmallStr,
+    },
     #[cfg(feature = "ffi_plugin")]
     /// Creating this node is unsafe
     /// This will lead to calls over FFI.
@@ -329,6 +363,14 @@ pub enum FunctionExpr {
         /// Pickle serialized keyword arguments.
         kwargs: Arc<[u8]>,
     },
+    /// A function registered with [`register_static_plugin`].
+    StaticPlugin {
+        flags: FunctionOptions,
+        /// The name the function is registered under.
+        name: PlSmallStr,
+        /// Serialized keyword arguments.
+        kwargs: Arc<[u8]>,
+    },
     MaxHorizontal,
     MinHorizontal,
     SumHorizontal {
@@ -337,6 +379,14 @@ pub enum FunctionExpr {
     MeanHorizontal {
         ignore_nulls: bool,
     },
+    #[cfg(feature = "dtype-struct")]
+    CumSumHorizontal {
+        ignore_nulls: bool,
+    },
+    #[cfg(all(feature = "rank", feature = "dtype-struct"))]
+    RankHorizontal {
+        options: RankOptions,
+    },
     #[cfg(feature = "ewma")]
     EwmMean {
         options: EWMOptions,
@@ -345,6 +395,14 @@ pub enum FunctionExpr {
     EwmMeanBy {
         half_life: Duration,
     },
+    #[cfg(feature = "ewma_by")]
+    EwmStdBy {
+        half_life: Duration,
+    },
+    #[cfg(feature = "ewma_by")]
+    EwmVarBy {
+        half_life: Duration,
+    },
     #[cfg(feature = "ewma")]
     EwmStd {
         options: EWMOptions,
@@ -374,6 +432,8 @@ impl Hash for FunctionExpr {
         use FunctionExpr::*;
         match self {
             // Namespaces
+            #[cfg(feature = "anonymize")]
+            Anonymize(f) => f.hash(state),
             #[cfg(feature = "dtype-array")]
             ArrayExpr(f) => f.hash(state),
             BinaryExpr(f) => f.hash(state),
@@ -402,18 +462,25 @@ impl Hash for FunctionExpr {
             Random { method, .. } => method.hash(state),
             #[cfg(feature = "cov")]
             Correlation { method, .. } => method.hash(state),
+            #[cfg(feature = "ols")]
+            Lstsq(options) => options.hash(state),
             #[cfg(feature = "range")]
             Range(f) => f.hash(state),
             #[cfg(feature = "trigonometry")]
             Trigonometry(f) => f.hash(state),
             #[cfg(feature = "fused")]
             Fused(f) => f.hash(state),
+            #[cfg(feature = "dtype-decimal")]
+            DecimalArithmetic { op, overflow } => {
+                op.hash(state);
+                overflow.hash(state);
+            },
             #[cfg(feature = "diff")]
             Diff(null_behavior) => null_behavior.hash(state),
             #[cfg(feature = "interpolate")]
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {},
+            InterpolateBy(f) => f.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -425,6 +492,14 @@ impl Hash for FunctionExpr {
                 lib.hash(state);
                 symbol.hash(state);
             },
+            StaticPlugin {
+                flags: _,
+                name,
+                kwargs,
+            } => {
+                name.hash(state);
+                kwargs.hash(state);
+            },
             MaxHorizontal
             | MinHorizontal
             | SumHorizontal { .. }
@@ -435,6 +510,10 @@ impl Hash for FunctionExpr {
             | ArgUnique
             | Shift
             | ShiftAndFill => {},
+            #[cfg(feature = "dtype-struct")]
+            CumSumHorizontal { ignore_nulls } => ignore_nulls.hash(state),
+            #[cfg(all(feature = "rank", feature = "dtype-struct"))]
+            RankHorizontal { options } => options.hash(state),
             #[cfg(feature = "mode")]
             Mode => {},
             #[cfg(feature = "abs")]
@@ -451,6 +530,8 @@ impl Hash for FunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "row_hash")]
+            StableHash(algorithm, k0, k1) => (algorithm, k0, k1).hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr(f) => {
@@ -485,7 +566,10 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumSum { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
-            CumProd { reverse } => reverse.hash(state),
+            CumProd {
+                reverse,
+                log_domain,
+            } => (reverse, log_domain).hash(state),
             #[cfg(feature = "cum_agg")]
             CumMin { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
@@ -541,14 +625,18 @@ impl Hash for FunctionExpr {
             Cut {
                 breaks,
                 labels,
+                label_format,
                 left_closed,
                 include_breaks,
+                as_enum,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(breaks);
                 slice.hash(state);
                 labels.hash(state);
+                label_format.hash(state);
                 left_closed.hash(state);
                 include_breaks.hash(state);
+                as_enum.hash(state);
             },
             #[cfg(feature = "dtype-array")]
             Reshape(dims) => dims.hash(state),
@@ -558,16 +646,20 @@ impl Hash for FunctionExpr {
             QCut {
                 probs,
                 labels,
+                label_format,
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                as_enum,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(probs);
                 slice.hash(state);
                 labels.hash(state);
+                label_format.hash(state);
                 left_closed.hash(state);
                 allow_duplicates.hash(state);
                 include_breaks.hash(state);
+                as_enum.hash(state);
             },
             #[cfg(feature = "rle")]
             RLE => {},
@@ -575,10 +667,15 @@ impl Hash for FunctionExpr {
             RLEID => {},
             ToPhysical => {},
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
+            Assert { message } => message.hash(state),
             #[cfg(feature = "ewma")]
             EwmMean { options } => options.hash(state),
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { half_life } => (half_life).hash(state),
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { half_life } => (half_life).hash(state),
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { half_life } => (half_life).hash(state),
             #[cfg(feature = "ewma")]
             EwmStd { options } => options.hash(state),
             #[cfg(feature = "ewma")]
@@ -613,6 +710,8 @@ impl Display for FunctionExpr {
         use FunctionExpr::*;
         let s = match self {
             // Namespaces
+            #[cfg(feature = "anonymize")]
+            Anonymize(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-array")]
             ArrayExpr(func) => return write!(f, "{func}"),
             BinaryExpr(func) => return write!(f, "{func}"),
@@ -639,6 +738,8 @@ impl Display for FunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "row_hash")]
+            StableHash(_, _, _) => "stable_hash",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
@@ -717,7 +818,7 @@ impl Display for FunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => "interpolate_by",
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -745,9 +846,13 @@ impl Display for FunctionExpr {
             LowerBound => "lower_bound",
             #[cfg(feature = "fused")]
             Fused(fused) => return Display::fmt(fused, f),
+            #[cfg(feature = "dtype-decimal")]
+            DecimalArithmetic { .. } => "decimal_arithmetic",
             ConcatExpr(_) => "concat_expr",
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
+            #[cfg(feature = "ols")]
+            Lstsq(_) => "lstsq",
             #[cfg(feature = "peaks")]
             PeakMin => "peak_min",
             #[cfg(feature = "peaks")]
@@ -766,18 +871,33 @@ impl Display for FunctionExpr {
             RLEID => "rle_id",
             ToPhysical => "to_physical",
             #[cfg(feature = "random")]
+            Random {
+                method: RandomMethod::Distribution(distribution),
+                ..
+            } => distribution.into(),
+            #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
             SetSortedFlag(_) => "set_sorted",
+            Assert { .. } => "assert",
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin { lib, symbol, .. } => return write!(f, "{lib}:{symbol}"),
+            StaticPlugin { name, .. } => return write!(f, "{name}"),
             MaxHorizontal => "max_horizontal",
             MinHorizontal => "min_horizontal",
             SumHorizontal { .. } => "sum_horizontal",
             MeanHorizontal { .. } => "mean_horizontal",
+            #[cfg(feature = "dtype-struct")]
+            CumSumHorizontal { .. } => "cum_sum_horizontal",
+            #[cfg(all(feature = "rank", feature = "dtype-struct"))]
+            RankHorizontal { .. } => "rank_horizontal",
             #[cfg(feature = "ewma")]
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { .. } => "ewm_mean_by",
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { .. } => "ewm_std_by",
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { .. } => "ewm_var_by",
             #[cfg(feature = "ewma")]
             EwmStd { .. } => "ewm_std",
             #[cfg(feature = "ewma")]
@@ -885,6 +1005,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
         use FunctionExpr::*;
         match func {
             // Namespaces
+            #[cfg(feature = "anonymize")]
+            Anonymize(func) => func.into(),
             #[cfg(feature = "dtype-array")]
             ArrayExpr(func) => func.into(),
             BinaryExpr(func) => func.into(),
@@ -926,6 +1048,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Hash(k0, k1, k2, k3) => {
                 map!(row_hash::row_hash, k0, k1, k2, k3)
             },
+            #[cfg(feature = "row_hash")]
+            StableHash(algorithm, k0, k1) => {
+                map!(row_hash::stable_hash, algorithm, k0, k1)
+            },
             #[cfg(feature = "arg_where")]
             ArgWhere => {
                 wrap!(arg_where::arg_where)
@@ -970,6 +1096,9 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     Std(options) => map!(rolling::rolling_std, options.clone()),
                     #[cfg(feature = "moment")]
                     Skew(window_size, bias) => map!(rolling::rolling_skew, window_size, bias),
+                    #[cfg(feature = "moment")]
+                    Kurtosis(options) => map!(rolling::rolling_kurtosis, options.clone()),
+                    Rank(options) => map!(rolling::rolling_rank, options.clone()),
                     #[cfg(feature = "cov")]
                     CorrCov {
                         rolling_options,
@@ -983,6 +1112,11 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                             is_corr
                         )
                     },
+                    #[cfg(feature = "ols")]
+                    Ols {
+                        rolling_options,
+                        ols_options,
+                    } => map_as_slice!(ols::rolling_ols, &rolling_options, ols_options),
                 }
             },
             #[cfg(feature = "rolling_window_by")]
@@ -998,6 +1132,17 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     },
                     VarBy(options) => map_as_slice!(rolling_by::rolling_var_by, options.clone()),
                     StdBy(options) => map_as_slice!(rolling_by::rolling_std_by, options.clone()),
+                    #[cfg(feature = "cov")]
+                    CorrCovBy {
+                        options,
+                        ddof,
+                        is_corr,
+                    } => map_as_slice!(
+                        rolling_by::rolling_corr_cov_by,
+                        options.clone(),
+                        *ddof,
+                        *is_corr
+                    ),
                 }
             },
             #[cfg(feature = "hist")]
@@ -1048,7 +1193,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "cum_agg")]
             CumSum { reverse } => map!(cum::cum_sum, reverse),
             #[cfg(feature = "cum_agg")]
-            CumProd { reverse } => map!(cum::cum_prod, reverse),
+            CumProd {
+                reverse,
+                log_domain,
+            } => map!(cum::cum_prod, reverse, log_domain),
             #[cfg(feature = "cum_agg")]
             CumMin { reverse } => map!(cum::cum_min, reverse),
             #[cfg(feature = "cum_agg")]
@@ -1082,8 +1230,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 map!(dispatch::interpolate, method)
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {
-                map_as_slice!(dispatch::interpolate_by)
+            InterpolateBy(method) => {
+                map_as_slice!(dispatch::interpolate_by, method)
             },
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
@@ -1106,9 +1254,15 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             LowerBound => map!(bounds::lower_bound),
             #[cfg(feature = "fused")]
             Fused(op) => map_as_slice!(fused::fused, op),
+            #[cfg(feature = "dtype-decimal")]
+            DecimalArithmetic { op, overflow } => {
+                map_as_slice!(decimal::decimal_arithmetic, op, overflow)
+            },
             ConcatExpr(rechunk) => map_as_slice!(concat::concat_expr, rechunk),
             #[cfg(feature = "cov")]
             Correlation { method } => map_as_slice!(correlation::corr, method),
+            #[cfg(feature = "ols")]
+            Lstsq(options) => map_as_slice!(ols::lstsq, options),
             #[cfg(feature = "peaks")]
             PeakMin => map!(peaks::peak_min),
             #[cfg(feature = "peaks")]
@@ -1121,29 +1275,37 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Cut {
                 breaks,
                 labels,
+                label_format,
                 left_closed,
                 include_breaks,
+                as_enum,
             } => map!(
                 cut::cut,
                 breaks.clone(),
                 labels.clone(),
+                label_format.as_deref(),
                 left_closed,
-                include_breaks
+                include_breaks,
+                as_enum
             ),
             #[cfg(feature = "cutqcut")]
             QCut {
                 probs,
                 labels,
+                label_format,
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                as_enum,
             } => map!(
                 cut::qcut,
                 probs.clone(),
                 labels.clone(),
+                label_format.as_deref(),
                 left_closed,
                 allow_duplicates,
-                include_breaks
+                include_breaks,
+                as_enum
             ),
             #[cfg(feature = "rle")]
             RLE => map!(rle),
@@ -1166,9 +1328,13 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                             map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
                         }
                     },
+                    Distribution(distribution) => {
+                        map_as_slice!(random::distribution, distribution, seed)
+                    },
                 }
             },
             SetSortedFlag(sorted) => map!(dispatch::set_sorted_flag, sorted),
+            Assert { message } => map_as_slice!(assertion::assert, &message),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -1183,14 +1349,33 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     kwargs.as_ref()
                 )
             },
+            StaticPlugin {
+                flags: _,
+                name,
+                kwargs,
+            } => map_as_slice!(
+                static_plugin::call_static_plugin,
+                name.as_ref(),
+                kwargs.as_ref()
+            ),
             MaxHorizontal => wrap!(dispatch::max_horizontal),
             MinHorizontal => wrap!(dispatch::min_horizontal),
             SumHorizontal { ignore_nulls } => wrap!(dispatch::sum_horizontal, ignore_nulls),
             MeanHorizontal { ignore_nulls } => wrap!(dispatch::mean_horizontal, ignore_nulls),
+            #[cfg(feature = "dtype-struct")]
+            CumSumHorizontal { ignore_nulls } => {
+                map_as_slice!(dispatch::cum_sum_horizontal, ignore_nulls)
+            },
+            #[cfg(all(feature = "rank", feature = "dtype-struct"))]
+            RankHorizontal { options } => map_as_slice!(dispatch::rank_horizontal, options),
             #[cfg(feature = "ewma")]
             EwmMean { options } => map!(ewm::ewm_mean, options),
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { half_life } => map_as_slice!(ewm_by::ewm_mean_by, half_life),
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { half_life } => map_as_slice!(ewm_by::ewm_std_by, half_life),
+            #[cfg(feature = "ewma_by")]
+            EwmVarBy { half_life } => map_as_slice!(ewm_by::ewm_var_by, half_life),
             #[cfg(feature = "ewma")]
             EwmStd { options } => map!(ewm::ewm_std, options),
             #[cfg(feature = "ewma")]
@@ -1217,6 +1402,8 @@ impl FunctionExpr {
     pub fn function_options(&self) -> FunctionOptions {
         use FunctionExpr as F;
         match self {
+            #[cfg(feature = "anonymize")]
+            F::Anonymize(e) => e.function_options(),
             #[cfg(feature = "dtype-array")]
             F::ArrayExpr(e) => e.function_options(),
             F::BinaryExpr(e) => e.function_options(),
@@ -1244,7 +1431,7 @@ impl FunctionExpr {
             F::Hist { .. } => FunctionOptions::groupwise(),
             F::NullCount => FunctionOptions::aggregation(),
             #[cfg(feature = "row_hash")]
-            F::Hash(_, _, _, _) => FunctionOptions::elementwise(),
+            F::Hash(_, _, _, _) | F::StableHash(_, _, _) => FunctionOptions::elementwise(),
             #[cfg(feature = "arg_where")]
             F::ArgWhere => FunctionOptions::groupwise(),
             #[cfg(feature = "index_of")]
@@ -1266,6 +1453,10 @@ impl FunctionExpr {
                 FunctionOptions::elementwise()
             },
             F::FillNullWithStrategy(_) => FunctionOptions::groupwise(),
+            #[cfg(all(feature = "rolling_window", feature = "ols"))]
+            F::RollingExpr(RollingFunction::Ols { .. }) => {
+                FunctionOptions::length_preserving().with_input_wildcard_expansion(true)
+            },
             #[cfg(feature = "rolling_window")]
             F::RollingExpr(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "rolling_window_by")]
@@ -1326,7 +1517,7 @@ impl FunctionExpr {
             #[cfg(feature = "interpolate")]
             F::Interpolate(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "interpolate_by")]
-            F::InterpolateBy => FunctionOptions::length_preserving(),
+            F::InterpolateBy(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "log")]
             F::Log { .. } | F::Log1p | F::Exp => FunctionOptions::elementwise(),
             #[cfg(feature = "log")]
@@ -1339,6 +1530,8 @@ impl FunctionExpr {
             F::UpperBound | F::LowerBound => FunctionOptions::aggregation(),
             #[cfg(feature = "fused")]
             F::Fused(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "dtype-decimal")]
+            F::DecimalArithmetic { .. } => FunctionOptions::elementwise(),
             F::ConcatExpr(_) => FunctionOptions::groupwise()
                 .with_input_wildcard_expansion(true)
                 .with_supertyping(Default::default()),
@@ -1346,6 +1539,12 @@ impl FunctionExpr {
             F::Correlation { .. } => {
                 FunctionOptions::aggregation().with_supertyping(Default::default())
             },
+            #[cfg(feature = "ols")]
+            F::Lstsq(options) => match options.mode {
+                OlsMode::Coefficients => FunctionOptions::aggregation(),
+                OlsMode::Predictions | OlsMode::Residuals => FunctionOptions::length_preserving(),
+            }
+            .with_input_wildcard_expansion(true),
             #[cfg(feature = "peaks")]
             F::PeakMin | F::PeakMax => FunctionOptions::length_preserving(),
             #[cfg(feature = "cutqcut")]
@@ -1359,7 +1558,7 @@ impl FunctionExpr {
             F::ToPhysical => FunctionOptions::elementwise(),
             #[cfg(feature = "random")]
             F::Random {
-                method: RandomMethod::Sample { .. },
+                method: RandomMethod::Sample { .. } | RandomMethod::Distribution(_),
                 ..
             } => FunctionOptions::groupwise(),
             #[cfg(feature = "random")]
@@ -1368,20 +1567,32 @@ impl FunctionExpr {
                 ..
             } => FunctionOptions::length_preserving(),
             F::SetSortedFlag(_) => FunctionOptions::elementwise(),
+            F::Assert { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "ffi_plugin")]
             F::FfiPlugin { flags, .. } => *flags,
+            F::StaticPlugin { flags, .. } => *flags,
             F::MaxHorizontal | F::MinHorizontal => FunctionOptions::elementwise()
                 .with_input_wildcard_expansion(true)
                 .with_allow_rename(true),
             F::MeanHorizontal { .. } | F::SumHorizontal { .. } => {
                 FunctionOptions::elementwise().with_input_wildcard_expansion(true)
             },
+            #[cfg(feature = "dtype-struct")]
+            F::CumSumHorizontal { .. } => {
+                FunctionOptions::elementwise().with_input_wildcard_expansion(true)
+            },
+            #[cfg(all(feature = "rank", feature = "dtype-struct"))]
+            F::RankHorizontal { .. } => {
+                FunctionOptions::elementwise().with_input_wildcard_expansion(true)
+            },
             #[cfg(feature = "ewma")]
             F::EwmMean { .. } | F::EwmStd { .. } | F::EwmVar { .. } => {
                 FunctionOptions::length_preserving()
             },
             #[cfg(feature = "ewma_by")]
-            F::EwmMeanBy { .. } => FunctionOptions::length_preserving(),
+            F::EwmMeanBy { .. } | F::EwmStdBy { .. } | F::EwmVarBy { .. } => {
+                FunctionOptions::length_preserving()
+            },
             #[cfg(feature = "replace")]
             F::Replace => FunctionOptions::elementwise(),
             #[cfg(feature = "replace")]
@@ -0,0 +1,47 @@
+use super::*;
+
+fn target_and_regressors(s: &[Column]) -> (&Series, Vec<Series>) {
+    let x = s[1..]
+        .iter()
+        .map(|c| c.as_materialized_series().clone())
+        .collect();
+    (s[0].as_materialized_series(), x)
+}
+
+pub(super) fn lstsq(s: &[Column], options: OlsOptions) -> PolarsResult<Column> {
+    let (y, x) = target_and_regressors(s);
+    polars_ops::series::least_squares(y, &x, options).map(Column::from)
+}
+
+#[cfg(feature = "rolling_window")]
+pub(super) fn rolling_ols(
+    s: &[Column],
+    rolling_options: &RollingOptionsFixedWindow,
+    options: OlsOptions,
+) -> PolarsResult<Column> {
+    let (y, x) = target_and_regressors(s);
+    polars_ops::series::rolling_least_squares(
+        y,
+        &x,
+        options,
+        rolling_options.window_size,
+        rolling_options.min_periods,
+    )
+    .map(Column::from)
+}
+
+/// The output field of a regression: the coefficient struct, or the `Float64` fitted values or
+/// residuals.
+pub(super) fn ols_field(mapper: FieldsMapper, options: &OlsOptions) -> PolarsResult<Field> {
+    match options.mode {
+        OlsMode::Coefficients => {
+            let names = options.coefficient_names(mapper.args()[1..].iter().map(|f| f.name()));
+            let fields = names
+                .into_iter()
+                .map(|name| Field::new(name, DataType::Float64))
+                .collect();
+            mapper.with_dtype(DataType::Struct(fields))
+        },
+        OlsMode::Predictions | OlsMode::Residuals => mapper.with_dtype(DataType::Float64),
+    }
+}
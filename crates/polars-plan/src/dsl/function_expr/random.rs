@@ -15,11 +15,17 @@ pub enum RandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    /// Samples of a distribution, as many as the first input, which is the length of the frame
+    /// or group. The other inputs are the parameters of the distribution.
+    Distribution(RandomDistribution),
 }
 
 impl Hash for RandomMethod {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        std::mem::discriminant(self).hash(state)
+        std::mem::discriminant(self).hash(state);
+        if let Self::Distribution(distribution) = self {
+            distribution.hash(state)
+        }
     }
 }
 
@@ -72,3 +78,22 @@ pub(super) fn sample_n(
         None => Ok(Column::new_empty(src.name().clone(), src.dtype())),
     }
 }
+
+pub(super) fn distribution(
+    s: &[Column],
+    distribution: RandomDistribution,
+    seed: Option<u64>,
+) -> PolarsResult<Column> {
+    let len = &s[0];
+    polars_ensure!(
+        len.len() == 1,
+        ComputeError: "the number of random samples must be a single value"
+    );
+    let n = len.cast(&IDX_DTYPE)?.idx()?.get(0).unwrap_or(0) as usize;
+    let parameters = s[1..]
+        .iter()
+        .map(|c| c.as_materialized_series().clone())
+        .collect::<Vec<_>>();
+    let out = polars_ops::series::sample_distribution(distribution, n, &parameters, seed)?;
+    Ok(out.with_name(len.name().clone()).into())
+}
@@ -20,6 +20,9 @@ pub enum RollingFunction {
     Std(RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
     Skew(usize, bool),
+    #[cfg(feature = "moment")]
+    Kurtosis(RollingOptionsFixedWindow),
+    Rank(RollingOptionsFixedWindow),
     #[cfg(feature = "cov")]
     CorrCov {
         rolling_options: RollingOptionsFixedWindow,
@@ -27,6 +30,11 @@ pub enum RollingFunction {
         // Whether is Corr or Cov
         is_corr: bool,
     },
+    #[cfg(feature = "ols")]
+    Ols {
+        rolling_options: RollingOptionsFixedWindow,
+        ols_options: OlsOptions,
+    },
 }
 
 impl Display for RollingFunction {
@@ -43,6 +51,9 @@ impl Display for RollingFunction {
             Std(_) => "rolling_std",
             #[cfg(feature = "moment")]
             Skew(..) => "rolling_skew",
+            #[cfg(feature = "moment")]
+            Kurtosis(_) => "rolling_kurtosis",
+            Rank(_) => "rolling_rank",
             #[cfg(feature = "cov")]
             CorrCov { is_corr, .. } => {
                 if *is_corr {
@@ -51,6 +62,8 @@ impl Display for RollingFunction {
                     "rolling_cov"
                 }
             },
+            #[cfg(feature = "ols")]
+            Ols { .. } => "rolling_ols",
         };
 
         write!(f, "{name}")
@@ -72,6 +85,15 @@ impl Hash for RollingFunction {
             CorrCov { is_corr, .. } => {
                 is_corr.hash(state);
             },
+            #[cfg(feature = "ols")]
+            Ols {
+                rolling_options,
+                ols_options,
+            } => {
+                rolling_options.window_size.hash(state);
+                rolling_options.min_periods.hash(state);
+                ols_options.hash(state);
+            },
             _ => {},
         }
     }
@@ -137,6 +159,24 @@ pub(super) fn rolling_skew(s: &Column, window_size: usize, bias: bool) -> Polars
         .map(Column::from)
 }
 
+#[cfg(feature = "moment")]
+pub(super) fn rolling_kurtosis(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_kurtosis(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_rank(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_rank(options)
+        .map(Column::from)
+}
+
 #[cfg(feature = "cov")]
 fn det_count_x_y(window_size: usize, len: usize, dtype: &DataType) -> Series {
     match dtype {
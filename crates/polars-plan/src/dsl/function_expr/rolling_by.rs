@@ -12,6 +12,13 @@ pub enum RollingFunctionBy {
     QuantileBy(RollingOptionsDynamicWindow),
     VarBy(RollingOptionsDynamicWindow),
     StdBy(RollingOptionsDynamicWindow),
+    #[cfg(feature = "cov")]
+    CorrCovBy {
+        options: RollingOptionsDynamicWindow,
+        ddof: u8,
+        // Whether is Corr or Cov
+        is_corr: bool,
+    },
 }
 
 impl Display for RollingFunctionBy {
@@ -26,6 +33,14 @@ impl Display for RollingFunctionBy {
             QuantileBy(_) => "rolling_quantile_by",
             VarBy(_) => "rolling_var_by",
             StdBy(_) => "rolling_std_by",
+            #[cfg(feature = "cov")]
+            CorrCovBy { is_corr, .. } => {
+                if *is_corr {
+                    "rolling_corr_by"
+                } else {
+                    "rolling_cov_by"
+                }
+            },
         };
 
         write!(f, "{name}")
@@ -34,7 +49,17 @@ impl Display for RollingFunctionBy {
 
 impl Hash for RollingFunctionBy {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        use RollingFunctionBy::*;
+
         std::mem::discriminant(self).hash(state);
+        match self {
+            #[cfg(feature = "cov")]
+            CorrCovBy { ddof, is_corr, .. } => {
+                ddof.hash(state);
+                is_corr.hash(state);
+            },
+            _ => {},
+        }
     }
 }
 
@@ -107,3 +132,61 @@ pub(super) fn rolling_std_by(
         .rolling_std_by(s[1].as_materialized_series(), options)
         .map(Column::from)
 }
+
+#[cfg(feature = "cov")]
+pub(super) fn rolling_corr_cov_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+    ddof: u8,
+    is_corr: bool,
+) -> PolarsResult<Column> {
+    let mut x = s[0].as_materialized_series().to_float()?;
+    let mut y = s[1].as_materialized_series().to_float()?;
+    let by = s[2].as_materialized_series();
+    polars_ensure!(
+        x.len() == y.len(),
+        ShapeMismatch: "arguments for `rolling_corr_by`/`rolling_cov_by` have different lengths ({} != {})",
+        x.len(), y.len()
+    );
+    if x.dtype() != y.dtype() {
+        x = x.cast(&DataType::Float64)?;
+        y = y.cast(&DataType::Float64)?;
+    }
+    let dtype = x.dtype().clone();
+
+    let mean_options = RollingOptionsDynamicWindow {
+        fn_params: None,
+        ..options.clone()
+    };
+    let mean_x_y = (&x * &y)?.rolling_mean_by(by, mean_options.clone())?;
+    let mean_x = x.rolling_mean_by(by, mean_options.clone())?;
+    let mean_y = y.rolling_mean_by(by, mean_options.clone())?;
+
+    // The number of elements in every window, as wide as the (time-based) window itself.
+    let count_x_y = Series::new(PlSmallStr::EMPTY, &[1.0f64])
+        .cast(&dtype)?
+        .new_from_index(0, x.len())
+        .rolling_sum_by(by, mean_options)?;
+    let ddof_s = Series::new(PlSmallStr::EMPTY, &[AnyValue::from(ddof).cast(&dtype)]);
+
+    let numerator = ((mean_x_y - (mean_x * mean_y)?)? * (&count_x_y / &(&count_x_y - &ddof_s)?)?)?;
+
+    // Windows that don't have more elements than `ddof` have no defined covariance.
+    let has_dof = count_x_y.gt(ddof)?;
+    let numerator = numerator.zip_with(
+        &has_dof,
+        &Series::full_null(numerator.name().clone(), numerator.len(), numerator.dtype()),
+    )?;
+
+    if is_corr {
+        let std_options = RollingOptionsDynamicWindow {
+            fn_params: Some(RollingFnParams::Var(RollingVarParams { ddof })),
+            ..options
+        };
+        let std_x = x.rolling_std_by(by, std_options.clone())?;
+        let std_y = y.rolling_std_by(by, std_options)?;
+        Ok((numerator / (std_x * std_y)?)?.into_column())
+    } else {
+        Ok(numerator.into_column())
+    }
+}
@@ -14,6 +14,8 @@ impl FunctionExpr {
         let mapper = FieldsMapper { fields };
         match self {
             // Namespaces
+            #[cfg(feature = "anonymize")]
+            Anonymize(func) => func.get_field(mapper),
             #[cfg(feature = "dtype-array")]
             ArrayExpr(func) => func.get_field(mapper),
             BinaryExpr(s) => s.get_field(mapper),
@@ -44,6 +46,10 @@ impl FunctionExpr {
             Coalesce => mapper.map_to_supertype(),
             #[cfg(feature = "row_hash")]
             Hash(..) => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "row_hash")]
+            StableHash(HashAlgorithm::Sha256, _, _) => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "row_hash")]
+            StableHash(..) => mapper.with_dtype(DataType::UInt64),
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "index_of")]
@@ -68,8 +74,13 @@ impl FunctionExpr {
                     Sum(_) => mapper.sum_dtype(),
                     #[cfg(feature = "cov")]
                     CorrCov {..} => mapper.map_to_float_dtype(),
+                    #[cfg(feature = "ols")]
+                    Ols { ols_options, .. } => ols::ols_field(mapper, ols_options),
                     #[cfg(feature = "moment")]
                     Skew(..) => mapper.map_to_float_dtype(),
+                    #[cfg(feature = "moment")]
+                    Kurtosis(_) => mapper.map_to_float_dtype(),
+                    Rank(_) => mapper.with_dtype(DataType::Float64),
                 }
             },
             #[cfg(feature = "rolling_window_by")]
@@ -79,6 +90,8 @@ impl FunctionExpr {
                     MinBy(_) | MaxBy(_) => mapper.with_same_dtype(),
                     MeanBy(_) | QuantileBy(_) | VarBy(_) | StdBy(_) => mapper.map_to_float_dtype(),
                     SumBy(_) => mapper.sum_dtype(),
+                    #[cfg(feature = "cov")]
+                    CorrCovBy { .. } => mapper.map_to_float_dtype(),
                 }
             },
             ShiftAndFill => mapper.with_same_dtype(),
@@ -133,7 +146,15 @@ impl FunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumSum { .. } => mapper.map_dtype(cum::dtypes::cum_sum),
             #[cfg(feature = "cum_agg")]
-            CumProd { .. } => mapper.map_dtype(cum::dtypes::cum_prod),
+            CumProd {
+                log_domain: false,
+                ..
+            } => mapper.map_dtype(cum::dtypes::cum_prod),
+            #[cfg(feature = "cum_agg")]
+            CumProd {
+                log_domain: true,
+                ..
+            } => mapper.map_to_float_dtype(),
             #[cfg(feature = "cum_agg")]
             CumMin { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "cum_agg")]
@@ -190,7 +211,10 @@ impl FunctionExpr {
                 InterpolationMethod::Nearest => mapper.with_same_dtype(),
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => mapper.map_numeric_to_float_dtype(),
+            InterpolateBy(method) => match method {
+                InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(),
+                InterpolationMethod::Nearest => mapper.with_same_dtype(),
+            },
             ShrinkType => {
                 // we return the smallest type this can return
                 // this might not be correct once the actual data
@@ -222,31 +246,41 @@ impl FunctionExpr {
             UpperBound | LowerBound => mapper.with_same_dtype(),
             #[cfg(feature = "fused")]
             Fused(_) => mapper.map_to_supertype(),
+            #[cfg(feature = "dtype-decimal")]
+            DecimalArithmetic { op, .. } => mapper.try_map_dtypes(|dtypes| {
+                let scale = decimal::decimal_arithmetic_scale(dtypes[0], dtypes[1], *op)?;
+                Ok(DataType::Decimal(None, Some(scale)))
+            }),
             ConcatExpr(_) => mapper.map_to_supertype(),
             #[cfg(feature = "cov")]
             Correlation { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "ols")]
+            Lstsq(options) => ols::ols_field(mapper, options),
             #[cfg(feature = "peaks")]
             PeakMin => mapper.with_same_dtype(),
             #[cfg(feature = "peaks")]
             PeakMax => mapper.with_same_dtype(),
             #[cfg(feature = "cutqcut")]
             Cut {
-                include_breaks: false,
-                ..
-            } => mapper.with_dtype(DataType::Categorical(None, Default::default())),
-            #[cfg(feature = "cutqcut")]
-            Cut {
-                include_breaks: true,
-                ..
+                breaks,
+                labels,
+                label_format,
+                left_closed,
+                include_breaks,
+                as_enum,
             } => {
-                let struct_dt = DataType::Struct(vec![
-                    Field::new(PlSmallStr::from_static("breakpoint"), DataType::Float64),
-                    Field::new(
-                        PlSmallStr::from_static("category"),
-                        DataType::Categorical(None, Default::default()),
-                    ),
-                ]);
-                mapper.with_dtype(struct_dt)
+                let dtype = if *as_enum {
+                    let labels = polars_ops::prelude::cut_labels(
+                        breaks.clone(),
+                        labels.clone(),
+                        label_format.as_deref(),
+                        *left_closed,
+                    )?;
+                    polars_ops::prelude::cut_enum_dtype(&labels)?
+                } else {
+                    DataType::Categorical(None, Default::default())
+                };
+                mapper.with_dtype(cut_output_dtype(dtype, *include_breaks))
             },
             #[cfg(feature = "repeat_by")]
             RepeatBy => mapper.map_dtype(|dt| DataType::List(dt.clone().into())),
@@ -290,22 +324,19 @@ impl FunctionExpr {
             }),
             #[cfg(feature = "cutqcut")]
             QCut {
-                include_breaks: false,
-                ..
-            } => mapper.with_dtype(DataType::Categorical(None, Default::default())),
-            #[cfg(feature = "cutqcut")]
-            QCut {
-                include_breaks: true,
+                labels,
+                include_breaks,
+                as_enum,
                 ..
             } => {
-                let struct_dt = DataType::Struct(vec![
-                    Field::new(PlSmallStr::from_static("breakpoint"), DataType::Float64),
-                    Field::new(
-                        PlSmallStr::from_static("category"),
-                        DataType::Categorical(None, Default::default()),
+                let dtype = match (labels, as_enum) {
+                    (Some(labels), true) => polars_ops::prelude::cut_enum_dtype(labels)?,
+                    (None, true) => polars_bail!(
+                        InvalidOperation: "`qcut` with `as_enum=true` requires `labels`, as the bins are only known once the data is seen"
                     ),
-                ]);
-                mapper.with_dtype(struct_dt)
+                    _ => DataType::Categorical(None, Default::default()),
+                };
+                mapper.with_dtype(cut_output_dtype(dtype, *include_breaks))
             },
             #[cfg(feature = "rle")]
             RLE => mapper.map_dtype(|dt| {
@@ -318,8 +349,14 @@ impl FunctionExpr {
             RLEID => mapper.with_dtype(IDX_DTYPE),
             ToPhysical => mapper.to_physical_type(),
             #[cfg(feature = "random")]
+            Random {
+                method: RandomMethod::Distribution(distribution),
+                ..
+            } => mapper.with_dtype(distribution.dtype()),
+            #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
             SetSortedFlag(_) => mapper.with_same_dtype(),
+            Assert { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -327,6 +364,11 @@ impl FunctionExpr {
                 symbol,
                 kwargs,
             } => unsafe { plugin::plugin_field(fields, lib, symbol.as_ref(), kwargs) },
+            StaticPlugin {
+                flags: _,
+                name,
+                kwargs,
+            } => static_plugin::static_plugin_field(fields, name, kwargs),
             MaxHorizontal => mapper.map_to_supertype(),
             MinHorizontal => mapper.map_to_supertype(),
             SumHorizontal { .. } => {
@@ -346,10 +388,36 @@ impl FunctionExpr {
                     f
                 })
             }
+            #[cfg(feature = "dtype-struct")]
+            CumSumHorizontal { .. } => {
+                let mut dtype = mapper.map_to_supertype()?.dtype;
+                if dtype == DataType::Boolean {
+                    dtype = IDX_DTYPE;
+                }
+                let fields = fields
+                    .iter()
+                    .map(|f| Field::new(f.name().clone(), dtype.clone()))
+                    .collect();
+                Ok(Field::new(fields[0].name().clone(), DataType::Struct(fields)))
+            },
+            #[cfg(all(feature = "rank", feature = "dtype-struct"))]
+            RankHorizontal { options } => {
+                let dtype = match options.method {
+                    RankMethod::Average => DataType::Float64,
+                    _ => IDX_DTYPE,
+                };
+                let fields = fields
+                    .iter()
+                    .map(|f| Field::new(f.name().clone(), dtype.clone()))
+                    .collect();
+                Ok(Field::new(fields[0].name().clone(), DataType::Struct(fields)))
+            },
             #[cfg(feature = "ewma")]
             EwmMean { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma_by")]
             EwmMeanBy { .. } => mapper.map_to_float_dtype(),
+            #[cfg(feature = "ewma_by")]
+            EwmStdBy { .. } | EwmVarBy { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
             EwmStd { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "ewma")]
@@ -660,3 +728,16 @@ pub(crate) fn args_to_supertype<D: AsRef<DataType>>(dtypes: &[D]) -> PolarsResul
 
     Ok(st)
 }
+
+/// The output of `cut` and `qcut`, a struct with the breakpoint if `include_breaks` is set.
+#[cfg(feature = "cutqcut")]
+fn cut_output_dtype(category: DataType, include_breaks: bool) -> DataType {
+    if include_breaks {
+        DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("breakpoint"), DataType::Float64),
+            Field::new(PlSmallStr::from_static("category"), category),
+        ])
+    } else {
+        category
+    }
+}
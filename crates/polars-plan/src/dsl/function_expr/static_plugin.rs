@@ -0,0 +1,87 @@
+//! Expression functions implemented in Rust and registered under a name at runtime.
+//!
+//! Unlike FFI plugins these don't need a dynamic library: a downstream crate implements
+//! [`StaticPlugin`] and calls [`register_static_plugin`], after which
+//! [`static_plugin_function`] creates expressions that call the function. Plans serialize the
+//! name and the keyword arguments of the function, so they can be deserialized by any process
+//! that registered it.
+use std::sync::{LazyLock, RwLock};
+
+use super::*;
+
+static REGISTRY: LazyLock<RwLock<PlHashMap<PlSmallStr, Arc<dyn StaticPlugin>>>> =
+    LazyLock::new(Default::default);
+
+/// An expression function that can be registered with [`register_static_plugin`].
+///
+/// The `kwargs` are the keyword arguments given to [`static_plugin_function`], in an encoding
+/// of the plugin's choice.
+pub trait StaticPlugin: Send + Sync {
+    /// Compute the output of the function.
+    fn evaluate(&self, inputs: &mut [Column], kwargs: &[u8]) -> PolarsResult<Column>;
+
+    /// Resolve the output field of the function from the fields of its inputs.
+    fn output_field(&self, input_fields: &[Field], kwargs: &[u8]) -> PolarsResult<Field>;
+
+    /// The options that the optimizer and the engines use to decide how the function may be
+    /// executed. Elementwise functions (the default) are streamed in batches and allow
+    /// predicates to be pushed down past them; use [`FunctionOptions::groupwise`] if the
+    /// function needs to see all values at once.
+    fn options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+/// Register a [`StaticPlugin`] under `name`, replacing an earlier registration of that name.
+pub fn register_static_plugin(name: &str, plugin: impl StaticPlugin + 'static) {
+    REGISTRY
+        .write()
+        .unwrap()
+        .insert(PlSmallStr::from_str(name), Arc::new(plugin));
+}
+
+/// Remove the [`StaticPlugin`] registered under `name`, returning whether there was one.
+pub fn unregister_static_plugin(name: &str) -> bool {
+    REGISTRY.write().unwrap().remove(name).is_some()
+}
+
+fn get_plugin(name: &str) -> PolarsResult<Arc<dyn StaticPlugin>> {
+    match REGISTRY.read().unwrap().get(name) {
+        Some(plugin) => Ok(plugin.clone()),
+        None => polars_bail!(ComputeError: "no static plugin is registered as '{}'", name),
+    }
+}
+
+/// Call the [`StaticPlugin`] registered under `name` on the `args`.
+pub fn static_plugin_function<E: AsRef<[Expr]>>(
+    name: &str,
+    args: E,
+    kwargs: &[u8],
+) -> PolarsResult<Expr> {
+    let options = get_plugin(name)?.options();
+    Ok(Expr::Function {
+        input: args.as_ref().to_vec(),
+        function: FunctionExpr::StaticPlugin {
+            flags: options,
+            name: PlSmallStr::from_str(name),
+            kwargs: kwargs.into(),
+        },
+        options,
+    })
+}
+
+pub(super) fn call_static_plugin(
+    s: &mut [Column],
+    name: &str,
+    kwargs: &[u8],
+) -> PolarsResult<Column> {
+    get_plugin(name)?.evaluate(s, kwargs)
+}
+
+pub(super) fn static_plugin_field(
+    fields: &[Field],
+    name: &str,
+    kwargs: &[u8],
+) -> PolarsResult<Field> {
+    get_plugin(name)?.output_field(fields, kwargs)
+}
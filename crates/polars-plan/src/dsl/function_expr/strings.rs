@@ -3,10 +3,14 @@ use std::borrow::Cow;
 use arrow::legacy::utils::CustomIterTools;
 #[cfg(feature = "timezones")]
 use polars_core::chunked_array::temporal::validate_time_zone;
+#[cfg(feature = "binary_encoding")]
+use polars_core::extension::ExtensionChunked;
 use polars_core::utils::handle_casting_failures;
 #[cfg(feature = "dtype-struct")]
 use polars_utils::format_pl_smallstr;
 #[cfg(feature = "regex")]
+use polars_utils::regex_cache::with_regex_cache;
+#[cfg(feature = "regex")]
 use regex::{NoExpand, escape};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -46,6 +50,11 @@ pub enum StringFunction {
         dtype: DataType,
         pat: PlSmallStr,
     },
+    #[cfg(feature = "extract_groups")]
+    ExtractAllGroups {
+        dtype: DataType,
+        pat: PlSmallStr,
+    },
     #[cfg(feature = "regex")]
     Find {
         literal: bool,
@@ -56,6 +65,7 @@ pub enum StringFunction {
     LenBytes,
     LenChars,
     Lowercase,
+    Casefold,
     #[cfg(feature = "extract_jsonpath")]
     JsonDecode {
         dtype: Option<DataType>,
@@ -74,8 +84,32 @@ pub enum StringFunction {
     Normalize {
         form: UnicodeForm,
     },
+    #[cfg(feature = "string_normalize")]
+    StripDiacritics,
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    Hamming {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_similarity")]
+    TokenSetRatio,
+    #[cfg(feature = "string_url")]
+    UrlScheme,
+    #[cfg(feature = "string_url")]
+    UrlHost,
+    #[cfg(feature = "string_url")]
+    UrlPath,
+    #[cfg(feature = "string_url")]
+    UrlQueryParam(PlSmallStr),
+    #[cfg(feature = "string_url")]
+    UrlDecode,
     #[cfg(feature = "string_pad")]
     PadStart {
         length: usize,
@@ -97,6 +131,10 @@ pub enum StringFunction {
     Base64Encode,
     #[cfg(feature = "binary_encoding")]
     Base64Decode(bool),
+    #[cfg(feature = "binary_encoding")]
+    ToUuid(bool),
+    #[cfg(feature = "binary_encoding")]
+    ToIp(bool),
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -112,9 +150,24 @@ pub enum StringFunction {
     SplitN(usize),
     #[cfg(feature = "temporal")]
     Strptime(DataType, StrptimeOptions),
+    /// Parse datetimes with several `formats` tried in order, or with the format of every row
+    /// taken from the third input if `formats` is `None`.
+    #[cfg(feature = "dtype-datetime")]
+    StrptimeFormats {
+        time_unit: TimeUnit,
+        time_zone: Option<TimeZone>,
+        formats: Option<Vec<PlSmallStr>>,
+        strict: bool,
+        cache: bool,
+    },
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
+    #[cfg(feature = "dtype-duration")]
+    ToDuration {
+        time_unit: TimeUnit,
+        strict: bool,
+    },
     #[cfg(feature = "nightly")]
     Titlecase,
     Uppercase,
@@ -156,6 +209,8 @@ impl StringFunction {
             ExtractAll => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "extract_groups")]
             ExtractGroups { dtype, .. } => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "extract_groups")]
+            ExtractAllGroups { dtype, .. } => mapper.with_dtype(dtype.clone()),
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => mapper.with_dtype(DataType::Int64),
             #[cfg(feature = "regex")]
@@ -169,16 +224,36 @@ impl StringFunction {
             #[cfg(feature = "regex")]
             Replace { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_normalize")]
-            Normalize { .. } => mapper.with_same_dtype(),
+            Normalize { .. } | StripDiacritics => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { .. } | Hamming { .. } => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler | TokenSetRatio => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "string_url")]
+            UrlScheme | UrlHost | UrlPath | UrlQueryParam(_) | UrlDecode => {
+                mapper.with_same_dtype()
+            },
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "dtype-datetime")]
+            StrptimeFormats {
+                time_unit,
+                time_zone,
+                formats,
+                ..
+            } => mapper.with_dtype(DataType::Datetime(
+                *time_unit,
+                strings::formats_time_zone(formats.as_deref(), time_zone.as_ref()),
+            )),
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "nightly")]
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => mapper.with_dtype(DataType::Decimal(None, None)),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, .. } => mapper.with_dtype(DataType::Duration(*time_unit)),
             #[cfg(feature = "string_encoding")]
             HexEncode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
@@ -187,8 +262,12 @@ impl StringFunction {
             Base64Encode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => mapper.with_dtype(DataType::Binary),
-            Uppercase | Lowercase | StripChars | StripCharsStart | StripCharsEnd | StripPrefix
-            | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
+            #[cfg(feature = "binary_encoding")]
+            ToUuid(_) => mapper.with_dtype(DataType::Uuid),
+            #[cfg(feature = "binary_encoding")]
+            ToIp(_) => mapper.with_dtype(DataType::Binary),
+            Uppercase | Lowercase | Casefold | StripChars | StripCharsStart | StripCharsEnd
+            | StripPrefix | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
             #[cfg(feature = "string_pad")]
             PadStart { .. } | PadEnd { .. } | ZFill => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-struct")]
@@ -234,6 +313,8 @@ impl StringFunction {
             S::ExtractAll => FunctionOptions::elementwise(),
             #[cfg(feature = "extract_groups")]
             S::ExtractGroups { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "extract_groups")]
+            S::ExtractAllGroups { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_to_integer")]
             S::ToInteger { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "regex")]
@@ -251,22 +332,36 @@ impl StringFunction {
                 FunctionOptions::elementwise().with_supertyping(Default::default())
             },
             #[cfg(feature = "string_normalize")]
-            S::Normalize { .. } => FunctionOptions::elementwise(),
+            S::Normalize { .. } | S::StripDiacritics => FunctionOptions::elementwise(),
             #[cfg(feature = "string_reverse")]
             S::Reverse => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_similarity")]
+            S::Levenshtein { .. } | S::Hamming { .. } | S::JaroWinkler | S::TokenSetRatio => {
+                FunctionOptions::elementwise()
+            },
+            #[cfg(feature = "string_url")]
+            S::UrlScheme | S::UrlHost | S::UrlPath | S::UrlQueryParam(_) | S::UrlDecode => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "temporal")]
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             S::Strptime(_, _) => FunctionOptions::elementwise_with_infer(),
+            #[cfg(feature = "dtype-datetime")]
+            S::StrptimeFormats { .. } => FunctionOptions::elementwise(),
             S::Split(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "nightly")]
             S::Titlecase => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-decimal")]
             S::ToDecimal(_) => FunctionOptions::elementwise_with_infer(),
+            #[cfg(feature = "dtype-duration")]
+            S::ToDuration { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_encoding")]
             S::HexEncode | S::Base64Encode => FunctionOptions::elementwise(),
             #[cfg(feature = "binary_encoding")]
-            S::HexDecode(_) | S::Base64Decode(_) => FunctionOptions::elementwise(),
-            S::Uppercase | S::Lowercase => FunctionOptions::elementwise(),
+            S::HexDecode(_) | S::Base64Decode(_) | S::ToUuid(_) | S::ToIp(_) => {
+                FunctionOptions::elementwise()
+            },
+            S::Uppercase | S::Lowercase | S::Casefold => FunctionOptions::elementwise(),
             S::StripChars
             | S::StripCharsStart
             | S::StripCharsEnd
@@ -311,6 +406,8 @@ impl Display for StringFunction {
             ExtractAll => "extract_all",
             #[cfg(feature = "extract_groups")]
             ExtractGroups { .. } => "extract_groups",
+            #[cfg(feature = "extract_groups")]
+            ExtractAllGroups { .. } => "extract_all_groups",
             #[cfg(feature = "string_to_integer")]
             ToInteger { .. } => "to_integer",
             #[cfg(feature = "regex")]
@@ -323,6 +420,7 @@ impl Display for StringFunction {
             JsonPathMatch => "json_path_match",
             LenBytes => "len_bytes",
             Lowercase => "lowercase",
+            Casefold => "casefold",
             LenChars => "len_chars",
             #[cfg(feature = "string_pad")]
             PadEnd { .. } => "pad_end",
@@ -332,8 +430,28 @@ impl Display for StringFunction {
             Replace { .. } => "replace",
             #[cfg(feature = "string_normalize")]
             Normalize { .. } => "normalize",
+            #[cfg(feature = "string_normalize")]
+            StripDiacritics => "strip_diacritics",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { .. } => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            Hamming { .. } => "hamming",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_similarity")]
+            TokenSetRatio => "token_set_ratio",
+            #[cfg(feature = "string_url")]
+            UrlScheme => "url_scheme",
+            #[cfg(feature = "string_url")]
+            UrlHost => "url_host",
+            #[cfg(feature = "string_url")]
+            UrlPath => "url_path",
+            #[cfg(feature = "string_url")]
+            UrlQueryParam(_) => "url_query_param",
+            #[cfg(feature = "string_url")]
+            UrlDecode => "url_decode",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -342,6 +460,10 @@ impl Display for StringFunction {
             Base64Encode => "base64_encode",
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => "base64_decode",
+            #[cfg(feature = "binary_encoding")]
+            ToUuid(_) => "to_uuid",
+            #[cfg(feature = "binary_encoding")]
+            ToIp(_) => "to_ip",
             Slice => "slice",
             StartsWith => "starts_with",
             StripChars => "strip_chars",
@@ -361,6 +483,8 @@ impl Display for StringFunction {
             SplitN(_) => "splitn",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
+            #[cfg(feature = "dtype-datetime")]
+            StrptimeFormats { .. } => "strptime_formats",
             Split(inclusive) => {
                 if *inclusive {
                     "split_inclusive"
@@ -372,6 +496,8 @@ impl Display for StringFunction {
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(_) => "to_decimal",
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { .. } => "to_duration",
             Uppercase => "uppercase",
             #[cfg(feature = "string_pad")]
             ZFill => "zfill",
@@ -409,6 +535,10 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             ExtractGroups { pat, dtype } => {
                 map!(strings::extract_groups, &pat, &dtype)
             },
+            #[cfg(feature = "extract_groups")]
+            ExtractAllGroups { pat, dtype } => {
+                map!(strings::extract_all_groups, &pat, &dtype)
+            },
             #[cfg(feature = "regex")]
             Find { literal, strict } => map_as_slice!(strings::find, literal, strict),
             LenBytes => map!(strings::len_bytes),
@@ -429,6 +559,21 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Strptime(dtype, options) => {
                 map_as_slice!(strings::strptime, dtype.clone(), &options)
             },
+            #[cfg(feature = "dtype-datetime")]
+            StrptimeFormats {
+                time_unit,
+                time_zone,
+                formats,
+                strict,
+                cache,
+            } => map_as_slice!(
+                strings::strptime_formats,
+                time_unit,
+                time_zone.as_ref(),
+                formats.as_deref(),
+                strict,
+                cache
+            ),
             Split(inclusive) => {
                 map_as_slice!(strings::split, inclusive)
             },
@@ -450,10 +595,33 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
             #[cfg(feature = "string_normalize")]
             Normalize { form } => map!(strings::normalize, form.clone()),
+            #[cfg(feature = "string_normalize")]
+            StripDiacritics => map!(strings::strip_diacritics),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { max_distance } => {
+                map_as_slice!(strings::levenshtein, max_distance)
+            },
+            #[cfg(feature = "string_similarity")]
+            Hamming { max_distance } => map_as_slice!(strings::hamming, max_distance),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
+            #[cfg(feature = "string_similarity")]
+            TokenSetRatio => map_as_slice!(strings::token_set_ratio),
+            #[cfg(feature = "string_url")]
+            UrlScheme => map!(strings::url_scheme),
+            #[cfg(feature = "string_url")]
+            UrlHost => map!(strings::url_host),
+            #[cfg(feature = "string_url")]
+            UrlPath => map!(strings::url_path),
+            #[cfg(feature = "string_url")]
+            UrlQueryParam(key) => map!(strings::url_query_param, &key),
+            #[cfg(feature = "string_url")]
+            UrlDecode => map!(strings::url_decode),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
+            Casefold => map!(casefold),
             #[cfg(feature = "nightly")]
             Titlecase => map!(strings::titlecase),
             StripChars => map_as_slice!(strings::strip_chars),
@@ -474,8 +642,14 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Base64Encode => map!(strings::base64_encode),
             #[cfg(feature = "binary_encoding")]
             Base64Decode(strict) => map!(strings::base64_decode, strict),
+            #[cfg(feature = "binary_encoding")]
+            ToUuid(strict) => map!(strings::to_uuid, strict),
+            #[cfg(feature = "binary_encoding")]
+            ToIp(strict) => map!(strings::to_ip, strict),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(infer_len) => map!(strings::to_decimal, infer_len),
+            #[cfg(feature = "dtype-duration")]
+            ToDuration { time_unit, strict } => map!(strings::to_duration, time_unit, strict),
             #[cfg(feature = "extract_jsonpath")]
             JsonDecode {
                 dtype,
@@ -583,6 +757,11 @@ fn lowercase(s: &Column) -> PolarsResult<Column> {
     Ok(ca.to_lowercase().into_column())
 }
 
+fn casefold(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.to_casefold().into_column())
+}
+
 #[cfg(feature = "nightly")]
 pub(super) fn titlecase(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -646,6 +825,13 @@ pub(super) fn extract_groups(s: &Column, pat: &str, dtype: &DataType) -> PolarsR
     ca.extract_groups(pat, dtype).map(Column::from)
 }
 
+#[cfg(feature = "extract_groups")]
+/// Extract the capture groups of every match of a regex pattern as a list of structs
+pub(super) fn extract_all_groups(s: &Column, pat: &str, dtype: &DataType) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    ca.extract_all_groups(pat, dtype).map(Column::from)
+}
+
 #[cfg(feature = "string_pad")]
 pub(super) fn pad_start(s: &Column, length: usize, fill_char: char) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -872,6 +1058,161 @@ fn to_datetime(
     Ok(out.into_column())
 }
 
+#[cfg(feature = "dtype-datetime")]
+fn is_tz_aware(format: &str) -> bool {
+    #[cfg(all(feature = "regex", feature = "timezones"))]
+    {
+        TZ_AWARE_RE.is_match(format)
+    }
+    #[cfg(not(all(feature = "regex", feature = "timezones")))]
+    {
+        let _ = format;
+        false
+    }
+}
+
+/// The time zone of datetimes parsed with `formats`.
+///
+/// Offset-aware formats result in UTC if no `time_zone` is given. A per-row format column must
+/// be offset-naive.
+#[cfg(feature = "dtype-datetime")]
+pub(super) fn formats_time_zone(
+    formats: Option<&[PlSmallStr]>,
+    time_zone: Option<&TimeZone>,
+) -> Option<TimeZone> {
+    match formats {
+        Some([first, ..]) if time_zone.is_none() && is_tz_aware(first) => {
+            Some(PlSmallStr::from_static("UTC"))
+        },
+        _ => time_zone.cloned(),
+    }
+}
+
+/// Parse datetimes with the first format that succeeds, or with the format of every row.
+///
+/// Every format is applied to all rows that are not parsed yet, so the parsing itself stays
+/// vectorized (and cached) instead of switching formats row by row.
+#[cfg(feature = "dtype-datetime")]
+pub(super) fn strptime_formats(
+    s: &[Column],
+    time_unit: TimeUnit,
+    time_zone: Option<&TimeZone>,
+    formats: Option<&[PlSmallStr]>,
+    strict: bool,
+    cache: bool,
+) -> PolarsResult<Column> {
+    let mut datetime_strings = Cow::Borrowed(s[0].str()?);
+    let name = datetime_strings.name().clone();
+    let ambiguous = s[1].str()?;
+    #[cfg(feature = "timezones")]
+    if let Some(time_zone) = time_zone {
+        validate_time_zone(time_zone)?;
+    }
+
+    let per_row = formats.is_none();
+    // The formats, each with the rows it applies to (`None` meaning all rows).
+    let formats: Vec<(PlSmallStr, Option<BooleanChunked>)> = match formats {
+        Some(formats) => {
+            polars_ensure!(
+                !formats.is_empty(),
+                InvalidOperation: "at least one format must be given to `str.to_datetime`"
+            );
+            formats.iter().map(|fmt| (fmt.clone(), None)).collect()
+        },
+        None => {
+            let format_ca = s[2].str()?;
+            let len = datetime_strings.len();
+            polars_ensure!(
+                format_ca.len() == len || format_ca.len() == 1 || len == 1,
+                length_mismatch = "str.to_datetime",
+                len,
+                format_ca.len()
+            );
+            if len == 1 && format_ca.len() != 1 {
+                datetime_strings = Cow::Owned(datetime_strings.new_from_index(0, format_ca.len()));
+            }
+            // Rows without a format are null, rather than a parsing failure.
+            if format_ca.has_nulls() {
+                datetime_strings = Cow::Owned(if format_ca.len() == 1 {
+                    StringChunked::full_null(name.clone(), datetime_strings.len())
+                } else {
+                    datetime_strings.set(&format_ca.is_null(), None)?
+                });
+            }
+            if format_ca.len() == 1 {
+                format_ca
+                    .get(0)
+                    .map(|fmt| (fmt.into(), None))
+                    .into_iter()
+                    .collect()
+            } else {
+                format_ca
+                    .unique()?
+                    .iter()
+                    .flatten()
+                    .map(|fmt| (fmt.into(), Some(format_ca.equal_missing(fmt))))
+                    .collect()
+            }
+        },
+    };
+    let datetime_strings = datetime_strings.as_ref();
+    polars_ensure!(
+        datetime_strings.len() == ambiguous.len()
+            || datetime_strings.len() == 1
+            || ambiguous.len() == 1,
+        length_mismatch = "str.to_datetime",
+        datetime_strings.len(),
+        ambiguous.len()
+    );
+
+    let tz_aware = formats.first().is_some_and(|(fmt, _)| is_tz_aware(fmt));
+    polars_ensure!(
+        formats.iter().all(|(fmt, _)| is_tz_aware(fmt) == tz_aware),
+        ComputeError: "cannot mix offset-aware and offset-naive formats in `str.to_datetime`"
+    );
+    polars_ensure!(
+        !(tz_aware && per_row),
+        ComputeError: "offset-aware formats are not supported in a format column; \
+        parse the offset separately or pass the formats as a list"
+    );
+    let out_time_zone = if tz_aware && time_zone.is_none() {
+        Some(PlSmallStr::from_static("UTC"))
+    } else {
+        time_zone.cloned()
+    };
+
+    let mut out = Int64Chunked::full_null(name.clone(), datetime_strings.len());
+    for (fmt, rows) in formats {
+        // Skip the rows that are parsed already or that belong to another format.
+        let mut skip = out.is_not_null();
+        if let Some(rows) = rows {
+            skip = skip | !rows;
+        }
+        if skip.all() {
+            continue;
+        }
+        let remaining = datetime_strings.set(&skip, None)?;
+        let parsed = remaining.as_datetime(
+            Some(fmt.as_str()),
+            time_unit,
+            cache,
+            tz_aware,
+            time_zone,
+            ambiguous,
+        )?;
+        out = out.zip_with(&out.is_not_null(), &parsed)?;
+    }
+    let out = out.with_name(name).into_datetime(time_unit, out_time_zone);
+
+    if strict && datetime_strings.null_count() != out.null_count() {
+        handle_casting_failures(
+            &datetime_strings.clone().into_series(),
+            &out.clone().into_series(),
+        )?;
+    }
+    Ok(out.into_column())
+}
+
 #[cfg(feature = "dtype-time")]
 fn to_time(s: &Column, options: &StrptimeOptions) -> PolarsResult<Column> {
     polars_ensure!(
@@ -889,6 +1230,17 @@ fn to_time(s: &Column, options: &StrptimeOptions) -> PolarsResult<Column> {
     Ok(out.into_column())
 }
 
+#[cfg(feature = "dtype-duration")]
+pub(super) fn to_duration(s: &Column, time_unit: TimeUnit, strict: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    let out = ca.as_duration(time_unit, true).into_column();
+
+    if strict && ca.null_count() != out.null_count() {
+        handle_casting_failures(s.as_materialized_series(), out.as_materialized_series())?;
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "concat_str")]
 pub(super) fn join(s: &Column, delimiter: &str, ignore_nulls: bool) -> PolarsResult<Column> {
     let str_s = s.cast(&DataType::String)?;
@@ -947,6 +1299,54 @@ fn is_literal_pat(pat: &str) -> bool {
     pat.chars().all(|c| !c.is_ascii_punctuation())
 }
 
+#[cfg(feature = "regex")]
+/// Replace with a pattern per row, where `n` is `None` to replace all matches.
+///
+/// The replacement value is either a single value or one value per row.
+fn replace_dynamic(
+    ca: &StringChunked,
+    pat: &StringChunked,
+    val: &StringChunked,
+    literal: bool,
+    n: Option<usize>,
+) -> PolarsResult<StringChunked> {
+    polars_ensure!(
+        pat.len() == ca.len() && (val.len() == 1 || val.len() == ca.len()),
+        ComputeError:
+        "pattern length ({}) and replacement value length ({}) must be 1 or match string column length ({})",
+        pat.len(), val.len(), ca.len(),
+    );
+    if n.is_some_and(|n| n > 1) && !literal {
+        polars_bail!(ComputeError: "regex replacement with 'n > 1' not yet supported")
+    }
+
+    let vals: Box<dyn Iterator<Item = Option<&str>>> = if val.len() == 1 {
+        Box::new(std::iter::repeat(val.get(0)))
+    } else {
+        Box::new(val.iter())
+    };
+    let mut out: StringChunked = with_regex_cache(|reg_cache| {
+        ca.iter()
+            .zip(pat.iter())
+            .zip(vals)
+            .map(|((opt_src, opt_pat), opt_val)| {
+                let (Some(src), Some(pat), Some(val)) = (opt_src, opt_pat, opt_val) else {
+                    return Ok(None);
+                };
+                let out = match (literal, n) {
+                    (true, Some(n)) => src.replacen(pat, val, n),
+                    (true, None) => src.replace(pat, val),
+                    (false, Some(_)) => reg_cache.compile(pat)?.replace(src, val).into_owned(),
+                    (false, None) => reg_cache.compile(pat)?.replace_all(src, val).into_owned(),
+                };
+                Ok(Some(out))
+            })
+            .collect::<PolarsResult<_>>()
+    })?;
+    out.rename(ca.name().clone());
+    Ok(out)
+}
+
 #[cfg(feature = "regex")]
 fn replace_n<'a>(
     ca: &'a StringChunked,
@@ -1008,9 +1408,7 @@ fn replace_n<'a>(
             };
             Ok(iter_and_replace(ca, val, f))
         },
-        _ => polars_bail!(
-            ComputeError: "dynamic pattern length in 'str.replace' expressions is not supported yet"
-        ),
+        _ => replace_dynamic(ca, pat, val, literal, Some(n)),
     }
 }
 
@@ -1063,9 +1461,7 @@ fn replace_all<'a>(
 
             Ok(iter_and_replace(ca, val, f))
         },
-        _ => polars_bail!(
-            ComputeError: "dynamic pattern length in 'str.replace' expressions is not supported yet"
-        ),
+        _ => replace_dynamic(ca, pat, val, literal, None),
     }
 }
 
@@ -1094,12 +1490,80 @@ pub(super) fn normalize(s: &Column, form: UnicodeForm) -> PolarsResult<Column> {
     Ok(ca.str_normalize(form).into_column())
 }
 
+#[cfg(feature = "string_normalize")]
+pub(super) fn strip_diacritics(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_strip_diacritics().into_column())
+}
+
 #[cfg(feature = "string_reverse")]
 pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
     Ok(ca.str_reverse().into_column())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein(s: &[Column], max_distance: Option<u32>) -> PolarsResult<Column> {
+    _check_same_length(s, "levenshtein")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_levenshtein(other, max_distance).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming(s: &[Column], max_distance: Option<u32>) -> PolarsResult<Column> {
+    _check_same_length(s, "hamming")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_hamming(other, max_distance).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "jaro_winkler")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_jaro_winkler(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn token_set_ratio(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "token_set_ratio")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_token_set_ratio(other).into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_scheme(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_url_scheme().into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_host(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_url_host().into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_path(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_url_path().into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_query_param(s: &Column, key: &str) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_url_query_param(key).into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_decode(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_url_decode().into_column())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(s: &[Column], strict: bool) -> PolarsResult<Column> {
     let ca = s[0].str()?;
@@ -1171,6 +1635,19 @@ pub(super) fn base64_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
     s.str()?.base64_decode(strict).map(|ca| ca.into_column())
 }
 
+#[cfg(feature = "binary_encoding")]
+pub(super) fn to_uuid(s: &Column, strict: bool) -> PolarsResult<Column> {
+    let ca = s.str()?.to_uuid(strict)?;
+    Ok(ExtensionChunked::try_new_uuid(ca)?
+        .into_series()
+        .into_column())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn to_ip(s: &Column, strict: bool) -> PolarsResult<Column> {
+    s.str()?.to_ip(strict).map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "dtype-decimal")]
 pub(super) fn to_decimal(s: &Column, infer_len: usize) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -10,11 +10,18 @@ pub enum StructFunction {
     FieldByIndex(i64),
     FieldByName(PlSmallStr),
     RenameFields(Arc<[PlSmallStr]>),
+    /// Rename the fields at the given (dotted) paths, keeping all other fields.
+    RenameFieldsByPath(Arc<[(PlSmallStr, PlSmallStr)]>),
+    /// Drop the fields at the given (dotted) paths.
+    DropFields(Arc<[PlSmallStr]>),
     PrefixFields(PlSmallStr),
     SuffixFields(PlSmallStr),
     #[cfg(feature = "json")]
     JsonEncode,
     WithFields,
+    /// Like [`StructFunction::WithFields`], but a dotted name such as `"a.b"` adds or replaces
+    /// field `b` of the struct field `a`.
+    WithFieldsByPath,
     MultipleFields(Arc<[PlSmallStr]>),
 }
 
@@ -65,6 +72,20 @@ impl StructFunction {
                         .collect(),
                 ),
             }),
+            RenameFieldsByPath(renames) => mapper.try_map_field(|field| {
+                let mut out = field.clone();
+                for (path, new_name) in renames.iter() {
+                    out = rename_field_at_path(out, path, new_name)?;
+                }
+                Ok(out)
+            }),
+            DropFields(paths) => mapper.try_map_field(|field| {
+                let mut out = field.clone();
+                for path in paths.iter() {
+                    out = drop_field_at_path(out, path)?;
+                }
+                Ok(out)
+            }),
             PrefixFields(prefix) => mapper.try_map_dtype(|dt| match dt {
                 DataType::Struct(fields) => {
                     let fields = fields
@@ -120,6 +141,21 @@ impl StructFunction {
                     polars_bail!(op = "with_fields", got = dt, expected = "Struct")
                 }
             },
+            WithFieldsByPath => {
+                let args = mapper.args();
+                let struct_ = &args[0];
+
+                if let DataType::Struct(_) = struct_.dtype() {
+                    let mut out = struct_.clone();
+                    for arg in &args[1..] {
+                        out = insert_field_at_path(out, arg.name(), arg.clone())?;
+                    }
+                    Ok(out)
+                } else {
+                    let dt = struct_.dtype();
+                    polars_bail!(op = "with_fields_by_path", got = dt, expected = "Struct")
+                }
+            },
             MultipleFields(_) => panic!("should be expanded"),
         }
     }
@@ -130,12 +166,14 @@ impl StructFunction {
             S::FieldByIndex(_) | S::FieldByName(_) => {
                 FunctionOptions::elementwise().with_allow_rename(true)
             },
-            S::RenameFields(_) | S::PrefixFields(_) | S::SuffixFields(_) => {
-                FunctionOptions::elementwise()
-            },
+            S::RenameFields(_)
+            | S::RenameFieldsByPath(_)
+            | S::DropFields(_)
+            | S::PrefixFields(_)
+            | S::SuffixFields(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "json")]
             S::JsonEncode => FunctionOptions::elementwise(),
-            S::WithFields => FunctionOptions::elementwise()
+            S::WithFields | S::WithFieldsByPath => FunctionOptions::elementwise()
                 .with_pass_name_to_apply(true)
                 .with_input_wildcard_expansion(true),
             S::MultipleFields(_) => FunctionOptions::elementwise().with_allow_rename(true),
@@ -150,11 +188,14 @@ impl Display for StructFunction {
             FieldByIndex(index) => write!(f, "struct.field_by_index({index})"),
             FieldByName(name) => write!(f, "struct.field_by_name({name})"),
             RenameFields(names) => write!(f, "struct.rename_fields({:?})", names),
+            RenameFieldsByPath(renames) => write!(f, "struct.rename_fields({:?})", renames),
+            DropFields(paths) => write!(f, "struct.drop_fields({:?})", paths),
             PrefixFields(_) => write!(f, "name.prefix_fields"),
             SuffixFields(_) => write!(f, "name.suffixFields"),
             #[cfg(feature = "json")]
             JsonEncode => write!(f, "struct.to_json"),
             WithFields => write!(f, "with_fields"),
+            WithFieldsByPath => write!(f, "with_fields_by_path"),
             MultipleFields(_) => write!(f, "multiple_fields"),
         }
     }
@@ -167,11 +208,14 @@ impl From<StructFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             FieldByIndex(_) => panic!("should be replaced"),
             FieldByName(name) => map!(get_by_name, &name),
             RenameFields(names) => map!(rename_fields, names.clone()),
+            RenameFieldsByPath(renames) => map!(rename_fields_by_path, &renames),
+            DropFields(paths) => map!(drop_fields, &paths),
             PrefixFields(prefix) => map!(prefix_fields, prefix.as_str()),
             SuffixFields(suffix) => map!(suffix_fields, suffix.as_str()),
             #[cfg(feature = "json")]
             JsonEncode => map!(to_json),
             WithFields => map_as_slice!(with_fields),
+            WithFieldsByPath => map_as_slice!(with_fields_by_path),
             MultipleFields(_) => unimplemented!(),
         }
     }
@@ -199,6 +243,161 @@ pub(super) fn rename_fields(s: &Column, names: Arc<[PlSmallStr]>) -> PolarsResul
     Ok(out.into_column())
 }
 
+/// A struct column or its schema, of which the fields can be edited by (dotted) path.
+trait StructFields: Sized + Clone {
+    fn field_name(&self) -> &PlSmallStr;
+
+    fn with_field_name(self, name: PlSmallStr) -> Self;
+
+    fn struct_fields(&self) -> PolarsResult<Vec<Self>>;
+
+    fn with_struct_fields(&self, fields: Vec<Self>) -> PolarsResult<Self>;
+}
+
+impl StructFields for Field {
+    fn field_name(&self) -> &PlSmallStr {
+        self.name()
+    }
+
+    fn with_field_name(self, name: PlSmallStr) -> Self {
+        self.with_name(name)
+    }
+
+    fn struct_fields(&self) -> PolarsResult<Vec<Self>> {
+        match self.dtype() {
+            DataType::Struct(fields) => Ok(fields.clone()),
+            dt => polars_bail!(
+                InvalidOperation: "field {:?} is not a struct, got: `{}`", self.name(), dt
+            ),
+        }
+    }
+
+    fn with_struct_fields(&self, fields: Vec<Self>) -> PolarsResult<Self> {
+        Ok(Field::new(self.name().clone(), DataType::Struct(fields)))
+    }
+}
+
+impl StructFields for Column {
+    fn field_name(&self) -> &PlSmallStr {
+        self.name()
+    }
+
+    fn with_field_name(self, name: PlSmallStr) -> Self {
+        self.with_name(name)
+    }
+
+    fn struct_fields(&self) -> PolarsResult<Vec<Self>> {
+        match self.dtype() {
+            DataType::Struct(_) => Ok(self
+                .struct_()?
+                .fields_as_series()
+                .into_iter()
+                .map(Column::from)
+                .collect()),
+            dt => polars_bail!(
+                InvalidOperation: "field {:?} is not a struct, got: `{}`", self.name(), dt
+            ),
+        }
+    }
+
+    fn with_struct_fields(&self, fields: Vec<Self>) -> PolarsResult<Self> {
+        let ca = self.struct_()?;
+        let fields = fields
+            .iter()
+            .map(|c| c.as_materialized_series().clone())
+            .collect::<Vec<_>>();
+        let mut out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+        out.zip_outer_validity(ca);
+        Ok(out.into_column())
+    }
+}
+
+/// Apply `edit` to the fields of the struct that `path` points into, passing the name of the last
+/// path segment. A path is split at a `.` only if no field carries the full name, so fields with
+/// dots in their name can still be addressed.
+fn edit_at_path<S: StructFields>(
+    s: S,
+    path: &str,
+    edit: &mut dyn FnMut(&mut Vec<S>, &str) -> PolarsResult<()>,
+) -> PolarsResult<S> {
+    let mut fields = s.struct_fields()?;
+    if !fields.iter().any(|fld| fld.field_name().as_str() == path) {
+        for (i, _) in path.match_indices('.') {
+            if let Some(idx) = fields
+                .iter()
+                .position(|fld| fld.field_name().as_str() == &path[..i])
+            {
+                fields[idx] = edit_at_path(fields[idx].clone(), &path[i + 1..], edit)?;
+                return s.with_struct_fields(fields);
+            }
+        }
+    }
+    edit(&mut fields, path)?;
+    s.with_struct_fields(fields)
+}
+
+fn field_position<S: StructFields>(fields: &[S], name: &str) -> PolarsResult<usize> {
+    fields
+        .iter()
+        .position(|fld| fld.field_name().as_str() == name)
+        .ok_or_else(|| polars_err!(StructFieldNotFound: "{}", name))
+}
+
+fn rename_field_at_path<S: StructFields>(
+    s: S,
+    path: &str,
+    new_name: &PlSmallStr,
+) -> PolarsResult<S> {
+    edit_at_path(s, path, &mut |fields, name| {
+        let idx = field_position(fields, name)?;
+        fields[idx] = fields[idx].clone().with_field_name(new_name.clone());
+        Ok(())
+    })
+}
+
+fn drop_field_at_path<S: StructFields>(s: S, path: &str) -> PolarsResult<S> {
+    edit_at_path(s, path, &mut |fields, name| {
+        let idx = field_position(fields, name)?;
+        fields.remove(idx);
+        Ok(())
+    })
+}
+
+/// Replace the field at `path`, or append it to its parent struct if it does not exist.
+fn insert_field_at_path<S: StructFields>(s: S, path: &str, value: S) -> PolarsResult<S> {
+    let mut value = Some(value);
+    edit_at_path(s, path, &mut |fields, name| {
+        let value = value.take().unwrap().with_field_name(name.into());
+        match fields
+            .iter()
+            .position(|fld| fld.field_name().as_str() == name)
+        {
+            Some(idx) => fields[idx] = value,
+            None => fields.push(value),
+        }
+        Ok(())
+    })
+}
+
+pub(super) fn rename_fields_by_path(
+    s: &Column,
+    renames: &[(PlSmallStr, PlSmallStr)],
+) -> PolarsResult<Column> {
+    let mut out = s.clone();
+    for (path, new_name) in renames {
+        out = rename_field_at_path(out, path, new_name)?;
+    }
+    Ok(out)
+}
+
+pub(super) fn drop_fields(s: &Column, paths: &[PlSmallStr]) -> PolarsResult<Column> {
+    let mut out = s.clone();
+    for path in paths {
+        out = drop_field_at_path(out, path)?;
+    }
+    Ok(out)
+}
+
 pub(super) fn prefix_fields(s: &Column, prefix: &str) -> PolarsResult<Column> {
     let ca = s.struct_()?;
     let fields = ca
@@ -267,3 +466,11 @@ pub(super) fn with_fields(args: &[Column]) -> PolarsResult<Column> {
     out.zip_outer_validity(ca);
     Ok(out.into_column())
 }
+
+pub(super) fn with_fields_by_path(args: &[Column]) -> PolarsResult<Column> {
+    let mut out = args[0].clone();
+    for field in &args[1..] {
+        out = insert_field_at_path(out, field.name(), field.clone())?;
+    }
+    Ok(out)
+}
@@ -42,12 +42,15 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             ConvertTimeZone(tz) => map!(datetime::convert_time_zone, &tz),
             WithTimeUnit(tu) => map!(datetime::with_time_unit, tu),
             CastTimeUnit(tu) => map!(datetime::cast_time_unit, tu),
-            Truncate => {
-                map_as_slice!(datetime::truncate)
+            Truncate {
+                week_start,
+                non_existent,
+            } => {
+                map_as_slice!(datetime::truncate, week_start, non_existent)
             },
             #[cfg(feature = "offset_by")]
-            OffsetBy => {
-                map_as_slice!(datetime::offset_by)
+            OffsetBy(non_existent) => {
+                map_as_slice!(datetime::offset_by, non_existent)
             },
             #[cfg(feature = "month_start")]
             MonthStart => map!(datetime::month_start),
@@ -57,7 +60,7 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             BaseUtcOffset => map!(datetime::base_utc_offset),
             #[cfg(feature = "timezones")]
             DSTOffset => map!(datetime::dst_offset),
-            Round => map_as_slice!(datetime::round),
+            Round(non_existent) => map_as_slice!(datetime::round, non_existent),
             Replace => map_as_slice!(datetime::replace),
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(tz, non_existent) => {
@@ -1,3 +1,5 @@
+use polars_core::utils::try_get_supertype;
+
 use super::*;
 
 #[cfg(feature = "dtype-struct")]
@@ -101,6 +103,114 @@ where
     }
 }
 
+/// The supertype of the inputs of [`fold_horizontal`] and [`reduce_horizontal`].
+///
+/// Unlike [`try_get_supertype`], decimals keep the integer digits of every input: the supertype
+/// of `Decimal(10, 2)` and `Decimal(10, 8)` is `Decimal(16, 8)` rather than `Decimal(10, 8)`.
+fn horizontal_supertype<'a>(
+    dtypes: impl IntoIterator<Item = &'a DataType>,
+) -> PolarsResult<DataType> {
+    let mut dtypes = dtypes.into_iter();
+    let Some(first) = dtypes.next() else {
+        polars_bail!(ComputeError: "cannot determine the supertype of zero expressions");
+    };
+    dtypes.try_fold(first.clone(), |st, dt| {
+        Ok(match (st, dt) {
+            #[cfg(feature = "dtype-decimal")]
+            (DataType::Decimal(Some(p1), Some(s1)), DataType::Decimal(Some(p2), Some(s2))) => {
+                let scale = s1.max(*s2);
+                let integer_digits = (p1 - s1).max(p2 - s2);
+                DataType::Decimal(Some((integer_digits + scale).min(38)), Some(scale))
+            },
+            (st, dt) => try_get_supertype(&st, dt)?,
+        })
+    })
+}
+
+/// Cast the inputs to their supertype and fold them with `f`, keeping the accumulator in that
+/// supertype.
+fn fold_in_supertype<F>(f: &F, columns: &[Column]) -> PolarsResult<Column>
+where
+    F: Fn(Column, Column) -> PolarsResult<Option<Column>>,
+{
+    let st = horizontal_supertype(columns.iter().map(|c| c.dtype()))?;
+    let mut acc = columns[0].strict_cast(&st)?;
+    for c in &columns[1..] {
+        if let Some(a) = f(acc.clone(), c.strict_cast(&st)?)? {
+            acc = a.strict_cast(&st)?;
+        }
+    }
+    Ok(acc)
+}
+
+/// Like [`fold_exprs`], but the accumulator and the inputs are cast to their common supertype
+/// before calling `f`, and the result of every step is cast back to it. The output therefore
+/// has the supertype of all inputs (e.g. `Datetime` when folding a `Date` into a `Datetime`)
+/// instead of the type of the accumulator.
+///
+/// Selectors and wildcards in `exprs` are resolved against the schema; use
+/// [`sum_horizontal`], [`min_horizontal`], [`max_horizontal`], [`any_horizontal`] and
+/// [`all_horizontal`] where possible, as they have dedicated kernels.
+pub fn fold_horizontal<F, E>(acc: Expr, f: F, exprs: E) -> Expr
+where
+    F: 'static + Fn(Column, Column) -> PolarsResult<Option<Column>> + Send + Sync,
+    E: AsRef<[Expr]>,
+{
+    let mut exprs_v = Vec::with_capacity(exprs.as_ref().len() + 1);
+    exprs_v.push(acc);
+    exprs_v.extend(exprs.as_ref().iter().cloned());
+
+    let function =
+        new_column_udf(move |columns: &mut [Column]| fold_in_supertype(&f, columns).map(Some));
+
+    Expr::AnonymousFunction {
+        input: exprs_v,
+        function,
+        output_type: GetOutput::map_dtypes(|dtypes| horizontal_supertype(dtypes.iter().copied())),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            flags: FunctionFlags::default()
+                | FunctionFlags::INPUT_WILDCARD_EXPANSION
+                | FunctionFlags::RETURNS_SCALAR,
+            fmt_str: "fold_horizontal",
+            ..Default::default()
+        },
+    }
+}
+
+/// Like [`reduce_exprs`], but the inputs are cast to their common supertype before calling
+/// `f`, and the result of every step is cast back to it, so the output has the dtype promised
+/// by the schema for decimal and temporal inputs too.
+///
+/// Selectors and wildcards in `exprs` are resolved against the schema.
+pub fn reduce_horizontal<F, E>(f: F, exprs: E) -> Expr
+where
+    F: 'static + Fn(Column, Column) -> PolarsResult<Option<Column>> + Send + Sync,
+    E: AsRef<[Expr]>,
+{
+    let function = new_column_udf(move |columns: &mut [Column]| {
+        polars_ensure!(
+            !columns.is_empty(),
+            ComputeError: "`reduce_horizontal` did not have any expressions to fold"
+        );
+        fold_in_supertype(&f, columns).map(Some)
+    });
+
+    Expr::AnonymousFunction {
+        input: exprs.as_ref().to_vec(),
+        function,
+        output_type: GetOutput::map_dtypes(|dtypes| horizontal_supertype(dtypes.iter().copied())),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            flags: FunctionFlags::default()
+                | FunctionFlags::INPUT_WILDCARD_EXPANSION
+                | FunctionFlags::RETURNS_SCALAR,
+            fmt_str: "reduce_horizontal",
+            ..Default::default()
+        },
+    }
+}
+
 /// Accumulate over multiple columns horizontally / row wise.
 #[cfg(feature = "dtype-struct")]
 pub fn cum_reduce_exprs<F, E>(f: F, exprs: E) -> Expr
@@ -260,6 +370,31 @@ pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> Polars
     ))
 }
 
+/// Cumulatively sum the values horizontally across columns, from left to right.
+///
+/// The result is a struct with a field per input, holding the running sum up to and
+/// including that column.
+#[cfg(feature = "dtype-struct")]
+pub fn cum_sum_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
+    Ok(Expr::n_ary(
+        FunctionExpr::CumSumHorizontal { ignore_nulls },
+        exprs,
+    ))
+}
+
+/// Rank the values of every row across columns.
+///
+/// The result is a struct with a field per input, holding the rank of its value in the row.
+/// Null values are not ranked.
+#[cfg(all(feature = "rank", feature = "dtype-struct"))]
+pub fn rank_horizontal<E: AsRef<[Expr]>>(exprs: E, options: RankOptions) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "cannot rank zero columns horizontally");
+    Ok(Expr::n_ary(FunctionExpr::RankHorizontal { options }, exprs))
+}
+
 /// Folds the expressions from left to right keeping the first non-null values.
 ///
 /// It is an error to provide an empty `exprs`.
@@ -12,6 +12,10 @@ mod correlation;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
+#[cfg(feature = "ols")]
+mod ols;
+#[cfg(feature = "random")]
+mod random;
 #[cfg(feature = "range")]
 mod range;
 mod repeat;
@@ -31,8 +35,12 @@ pub use correlation::*;
 pub use horizontal::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
+#[cfg(feature = "ols")]
+pub use ols::*;
 #[cfg(feature = "dtype-struct")]
 use polars_core::utils::get_supertype;
+#[cfg(feature = "random")]
+pub use random::*;
 #[cfg(all(feature = "range", feature = "temporal"))]
 pub use range::date_range; // This shouldn't be necessary, but clippy complains about dead code
 #[cfg(all(feature = "range", feature = "dtype-time"))]
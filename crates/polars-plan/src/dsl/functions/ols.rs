@@ -0,0 +1,44 @@
+use super::*;
+
+fn target_and_regressors<E: AsRef<[Expr]>>(y: Expr, x: E) -> Vec<Expr> {
+    let mut input = vec![y];
+    input.extend_from_slice(x.as_ref());
+    input
+}
+
+/// Regress `y` on the regressors `x` by ordinary least squares.
+///
+/// With [`OlsMode::Coefficients`] this is an aggregation into a struct with a field per
+/// regressor, otherwise the output has the fitted value or residual of every row.
+pub fn lstsq<E: AsRef<[Expr]>>(y: Expr, x: E, options: OlsOptions) -> Expr {
+    Expr::Function {
+        input: target_and_regressors(y, x),
+        function: FunctionExpr::Lstsq(options),
+        options: Default::default(),
+    }
+}
+
+/// Regress `y` on the regressors `x` by ordinary least squares over a rolling window of the
+/// `window_size` last rows.
+#[cfg(feature = "rolling_window")]
+pub fn rolling_ols<E: AsRef<[Expr]>>(
+    y: Expr,
+    x: E,
+    window_size: usize,
+    min_periods: usize,
+    options: OlsOptions,
+) -> Expr {
+    let rolling_options = RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        ..Default::default()
+    };
+    Expr::Function {
+        input: target_and_regressors(y, x),
+        function: FunctionExpr::RollingExpr(RollingFunction::Ols {
+            rolling_options,
+            ols_options: options,
+        }),
+        options: Default::default(),
+    }
+}
@@ -0,0 +1,23 @@
+use super::*;
+
+/// Samples of `distribution`, one for every row of the frame, or of the group in an
+/// aggregation.
+///
+/// The `parameters` are those of [`RandomDistribution::parameter_names`], in that order, and
+/// can be scalars or differ per row. The output is named after the distribution.
+pub fn random_distribution<E: AsRef<[Expr]>>(
+    distribution: RandomDistribution,
+    parameters: E,
+    seed: Option<u64>,
+) -> Expr {
+    let mut input = vec![len().alias(<&'static str>::from(distribution))];
+    input.extend_from_slice(parameters.as_ref());
+    Expr::Function {
+        input,
+        function: FunctionExpr::Random {
+            method: RandomMethod::Distribution(distribution),
+            seed,
+        },
+        options: Default::default(),
+    }
+}
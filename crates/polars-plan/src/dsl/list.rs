@@ -135,6 +135,20 @@ impl ListNameSpace {
             .map_unary(FunctionExpr::ListExpr(ListFunction::NUnique))
     }
 
+    /// Count the occurrences of the unique values in every sublist.
+    ///
+    /// Every sublist becomes a list of structs mapping the values to their count, which is
+    /// stored in the field `name`.
+    #[cfg(feature = "dtype-struct")]
+    pub fn value_counts(self, sort: bool, normalize: bool, name: &str) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::ValueCounts {
+                sort,
+                normalize,
+                name: name.into(),
+            }))
+    }
+
     /// Get items in every sublist by index.
     pub fn get(self, index: Expr, null_on_oob: bool) -> Expr {
         self.0.map_binary(
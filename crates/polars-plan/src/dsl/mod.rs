@@ -8,6 +8,8 @@ pub use cat::*;
 #[cfg(feature = "rolling_window_by")]
 pub(crate) use polars_time::prelude::*;
 
+#[cfg(feature = "anonymize")]
+pub mod anonymize;
 mod arithmetic;
 mod arity;
 #[cfg(feature = "dtype-array")]
@@ -676,6 +678,39 @@ impl Expr {
         }
     }
 
+    /// Run a stateful function over the values in order, emitting a value for every row.
+    ///
+    /// This is a fold that keeps its intermediate results, for example for a custom moving
+    /// average or to debounce a signal. The values are cast to `I` and nulls are passed as
+    /// `None`. Every group starts from a clone of `init`, so the scan restarts per group in a
+    /// `group_by` or `over` context.
+    pub fn scan<I, O, S, F>(self, init: S, function: F) -> Self
+    where
+        I: PolarsNumericType,
+        O: PolarsNumericType,
+        S: Clone + Send + Sync + 'static,
+        F: Fn(&mut S, Option<I::Native>) -> Option<O::Native> + 'static + Send + Sync,
+    {
+        let f = move |c: &mut [Column]| {
+            let c = c[0].cast(&I::get_dtype())?;
+            let ca = c.as_materialized_series().unpack::<I>()?;
+            let mut state = init.clone();
+            let out: ChunkedArray<O> = ca.iter().map(|v| function(&mut state, v)).collect();
+            Ok(Some(out.with_name(c.name().clone()).into_column()))
+        };
+
+        Expr::AnonymousFunction {
+            input: vec![self],
+            function: new_column_udf(f),
+            output_type: GetOutput::from_type(O::get_dtype()),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::GroupWise,
+                fmt_str: "scan",
+                ..Default::default()
+            },
+        }
+    }
+
     /// Get mask of finite values if dtype is Float.
     #[allow(clippy::wrong_self_convention)]
     pub fn is_finite(self) -> Self {
@@ -723,7 +758,20 @@ impl Expr {
     /// Get an array with the cumulative product computed at every element.
     #[cfg(feature = "cum_agg")]
     pub fn cum_prod(self, reverse: bool) -> Self {
-        self.map_unary(FunctionExpr::CumProd { reverse })
+        self.map_unary(FunctionExpr::CumProd {
+            reverse,
+            log_domain: false,
+        })
+    }
+
+    /// Get an array with the cumulative product computed at every element, as the exponent of
+    /// the cumulative sum of the logarithms. This is more accurate for long float columns.
+    #[cfg(feature = "cum_agg")]
+    pub fn cum_prod_log_domain(self, reverse: bool) -> Self {
+        self.map_unary(FunctionExpr::CumProd {
+            reverse,
+            log_domain: true,
+        })
     }
 
     /// Get an array with the cumulative min computed at every element.
@@ -1184,9 +1232,17 @@ impl Expr {
     }
 
     #[cfg(feature = "interpolate_by")]
-    /// Fill null values using interpolation.
+    /// Fill null values using linear interpolation based on another column, which does not need
+    /// to be evenly spaced.
     pub fn interpolate_by(self, by: Expr) -> Expr {
-        self.map_binary(FunctionExpr::InterpolateBy, by)
+        self.interpolate_by_with_method(by, InterpolationMethod::Linear)
+    }
+
+    #[cfg(feature = "interpolate_by")]
+    /// Fill null values using interpolation based on another column, which does not need to be
+    /// evenly spaced.
+    pub fn interpolate_by_with_method(self, by: Expr, method: InterpolationMethod) -> Expr {
+        self.map_binary(FunctionExpr::InterpolateBy(method), by)
     }
 
     #[cfg(feature = "rolling_window")]
@@ -1341,9 +1397,36 @@ impl Expr {
         )))
     }
 
+    /// Apply a rolling kurtosis.
+    #[cfg(feature = "rolling_window")]
+    #[cfg(feature = "moment")]
+    pub fn rolling_kurtosis(
+        self,
+        fisher: bool,
+        bias: bool,
+        mut options: RollingOptionsFixedWindow,
+    ) -> Expr {
+        options.fn_params = Some(RollingFnParams::Kurtosis { fisher, bias });
+        self.finish_rolling(options, RollingFunction::Kurtosis)
+    }
+
+    /// Rank every value within its rolling window.
+    ///
+    /// Ties are resolved by `method`; the output is always `Float64`.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_rank(
+        self,
+        method: RollingRankMethod,
+        mut options: RollingOptionsFixedWindow,
+    ) -> Expr {
+        options.fn_params = Some(RollingFnParams::Rank { method });
+        self.finish_rolling(options, RollingFunction::Rank)
+    }
+
     #[cfg(feature = "rolling_window")]
     /// Apply a custom function over a rolling/ moving window of the array.
-    /// This has quite some dynamic dispatch, so prefer rolling_min, max, mean, sum over this.
+    /// This has quite some dynamic dispatch, so prefer rolling_min, max, mean, sum, skew,
+    /// kurtosis and rank over this.
     pub fn rolling_map(
         self,
         f: Arc<dyn Fn(&Series) -> Series + Send + Sync>,
@@ -1472,37 +1555,51 @@ impl Expr {
 
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories.
+    ///
+    /// If `as_enum` is set the categories are an `Enum` ordered from the lowest to the highest
+    /// bin.
     pub fn cut(
         self,
         breaks: Vec<f64>,
         labels: Option<impl IntoVec<PlSmallStr>>,
+        label_format: Option<PlSmallStr>,
         left_closed: bool,
         include_breaks: bool,
+        as_enum: bool,
     ) -> Expr {
         self.map_unary(FunctionExpr::Cut {
             breaks,
             labels: labels.map(|x| x.into_vec()),
+            label_format,
             left_closed,
             include_breaks,
+            as_enum,
         })
     }
 
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories based on their quantiles.
+    ///
+    /// `as_enum` requires `labels`, as the bins are only known once the data is seen. In a group
+    /// context, the quantiles are computed per group.
     pub fn qcut(
         self,
         probs: Vec<f64>,
         labels: Option<impl IntoVec<PlSmallStr>>,
+        label_format: Option<PlSmallStr>,
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        as_enum: bool,
     ) -> Expr {
         self.map_unary(FunctionExpr::QCut {
             probs,
             labels: labels.map(|x| x.into_vec()),
+            label_format,
             left_closed,
             allow_duplicates,
             include_breaks,
+            as_enum,
         })
     }
 
@@ -1512,17 +1609,21 @@ impl Expr {
         self,
         n_bins: usize,
         labels: Option<impl IntoVec<PlSmallStr>>,
+        label_format: Option<PlSmallStr>,
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        as_enum: bool,
     ) -> Expr {
         let probs = (1..n_bins).map(|b| b as f64 / n_bins as f64).collect();
         self.map_unary(FunctionExpr::QCut {
             probs,
             labels: labels.map(|x| x.into_vec()),
+            label_format,
             left_closed,
             allow_duplicates,
             include_breaks,
+            as_enum,
         })
     }
 
@@ -1607,6 +1708,18 @@ impl Expr {
         self.map_binary(FunctionExpr::EwmMeanBy { half_life }, times)
     }
 
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving standard deviation by a time column.
+    pub fn ewm_std_by(self, times: Expr, half_life: Duration) -> Self {
+        self.map_binary(FunctionExpr::EwmStdBy { half_life }, times)
+    }
+
+    #[cfg(feature = "ewma_by")]
+    /// Calculate the exponentially-weighted moving variance by a time column.
+    pub fn ewm_var_by(self, times: Expr, half_life: Duration) -> Self {
+        self.map_binary(FunctionExpr::EwmVarBy { half_life }, times)
+    }
+
     #[cfg(feature = "ewma")]
     /// Calculate the exponentially-weighted moving standard deviation.
     pub fn ewm_std(self, options: EWMOptions) -> Self {
@@ -1708,12 +1821,30 @@ impl Expr {
         self.map_unary(FunctionExpr::SetSortedFlag(sorted))
     }
 
+    /// Raise an error if `predicate` is `false` for any row, otherwise pass the values through.
+    ///
+    /// Rows for which `predicate` is null pass. The error names the `message`, the number of
+    /// failing rows and the first failing values.
+    pub fn assert<E: Into<Expr>>(self, predicate: E, message: impl Into<PlSmallStr>) -> Expr {
+        let message = message.into();
+        self.map_binary(FunctionExpr::Assert { message }, predicate.into())
+    }
+
     #[cfg(feature = "row_hash")]
     /// Compute the hash of every element.
     pub fn hash(self, k0: u64, k1: u64, k2: u64, k3: u64) -> Expr {
         self.map_unary(FunctionExpr::Hash(k0, k1, k2, k3))
     }
 
+    #[cfg(feature = "row_hash")]
+    /// Compute the hash of every element with `algorithm`, which is stable across Polars versions
+    /// and platforms.
+    ///
+    /// `k0` seeds the algorithm; SipHash-1-3 uses `(k0, k1)` as its key.
+    pub fn stable_hash(self, algorithm: HashAlgorithm, k0: u64, k1: u64) -> Expr {
+        self.map_unary(FunctionExpr::StableHash(algorithm, k0, k1))
+    }
+
     pub fn to_physical(self) -> Expr {
         self.map_unary(FunctionExpr::ToPhysical)
     }
@@ -1737,6 +1868,12 @@ impl Expr {
         string::StringNameSpace(self)
     }
 
+    #[cfg(feature = "anonymize")]
+    /// Get the [`anonymize::AnonymizeNameSpace`]
+    pub fn anonymize(self) -> anonymize::AnonymizeNameSpace {
+        anonymize::AnonymizeNameSpace(self)
+    }
+
     /// Get the [`binary::BinaryNameSpace`]
     pub fn binary(self) -> binary::BinaryNameSpace {
         binary::BinaryNameSpace(self)
@@ -4,7 +4,10 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use polars_core::error::{PolarsResult, to_compute_err};
-use polars_core::prelude::DataType;
+use polars_core::prelude::{
+    AnyValue, Column, DataFrame, DataType, IntoColumn, NewChunkedArray, Series, StringChunked,
+    UInt64Chunked,
+};
 use polars_core::scalar::Scalar;
 use polars_io::cloud::CloudOptions;
 use polars_io::utils::file::{DynWriteable, Writeable};
@@ -28,6 +31,10 @@ pub struct SinkOptions {
 
     /// Recursively create all the directories in the path.
     pub mkdir: bool,
+
+    /// Report the minimum and maximum of this column for every written file, see
+    /// [`SinkReport`].
+    pub report_key: Option<PlSmallStr>,
 }
 
 impl Default for SinkOptions {
@@ -36,6 +43,7 @@ impl Default for SinkOptions {
             sync_on_close: Default::default(),
             maintain_order: true,
             mkdir: false,
+            report_key: None,
         }
     }
 }
@@ -411,3 +419,161 @@ pub struct FileSinkOptions {
     pub path: Arc<PathBuf>,
     pub file_type: FileType,
 }
+
+/// What a sink wrote to a single file, see [`SinkReport`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SinkFileReport {
+    /// The path of the file, `None` if the target is not a path (e.g. an opened file).
+    pub path: Option<PathBuf>,
+    pub rows: u64,
+    /// The size of the file in bytes, `None` if it is not known (e.g. for cloud targets).
+    pub bytes: Option<u64>,
+    /// The minimum of the [`report_key`](SinkOptions::report_key) column in the file.
+    pub key_min: Option<Scalar>,
+    /// The maximum of the [`report_key`](SinkOptions::report_key) column in the file.
+    pub key_max: Option<Scalar>,
+}
+
+impl SinkFileReport {
+    pub fn new(target: &SinkTarget) -> Self {
+        let path = match target {
+            SinkTarget::Path(p) => Some(p.as_ref().clone()),
+            SinkTarget::Dyn(_) => None,
+        };
+        Self {
+            path,
+            rows: 0,
+            bytes: None,
+            key_min: None,
+            key_max: None,
+        }
+    }
+
+    /// Account for rows that are written to the file.
+    pub fn update(&mut self, df: &DataFrame, key: Option<&str>) -> PolarsResult<()> {
+        let mut chunk = Self {
+            rows: df.height() as u64,
+            ..Default::default()
+        };
+        if let Some(key) = key.filter(|_| df.height() > 0) {
+            let column = df.column(key)?;
+            chunk.key_min = Some(column.min_reduce()?);
+            chunk.key_max = Some(column.max_reduce()?);
+        }
+        self.merge(chunk)
+    }
+
+    /// Account for rows of the file that are recorded in `other`.
+    pub fn merge(&mut self, other: Self) -> PolarsResult<()> {
+        fn pair(a: Scalar, b: Scalar) -> PolarsResult<Series> {
+            let values = [a.value().clone(), b.value().clone()];
+            Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &values, a.dtype(), true)
+        }
+
+        self.rows += other.rows;
+        self.key_min = match (self.key_min.take(), other.key_min) {
+            (Some(a), Some(b)) => Some(pair(a, b)?.min_reduce()?),
+            (a, b) => a.or(b),
+        };
+        self.key_max = match (self.key_max.take(), other.key_max) {
+            (Some(a), Some(b)) => Some(pair(a, b)?.max_reduce()?),
+            (a, b) => a.or(b),
+        };
+        Ok(())
+    }
+
+    /// Record the size of the file, once it is closed.
+    pub fn finish(&mut self) {
+        if let Some(path) = self.path.as_ref().filter(|p| !polars_io::is_cloud_url(p)) {
+            self.bytes = std::fs::metadata(path).ok().map(|m| m.len());
+        }
+    }
+}
+
+/// What a sink wrote, to register its outputs without listing the destination.
+///
+/// Collecting a sink returns the report as a [`DataFrame`] with a row per file and the columns
+/// `path`, `rows` and `bytes`, followed by `key_min` and `key_max` if a
+/// [`report_key`](SinkOptions::report_key) is set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SinkReport {
+    pub files: Vec<SinkFileReport>,
+}
+
+impl SinkReport {
+    /// The total number of rows written.
+    pub fn rows(&self) -> u64 {
+        self.files.iter().map(|f| f.rows).sum()
+    }
+
+    /// The total size of the files, `None` if the size of any file is not known.
+    pub fn bytes(&self) -> Option<u64> {
+        self.files.iter().map(|f| f.bytes).sum()
+    }
+
+    /// Convert to the [`DataFrame`] returned by collecting a sink, where `key_dtype` is the
+    /// type of the [`report_key`](SinkOptions::report_key) column, if any.
+    pub fn into_frame(self, key_dtype: Option<&DataType>) -> PolarsResult<DataFrame> {
+        let files = self.files;
+        let path = StringChunked::from_iter_options(
+            "path".into(),
+            files
+                .iter()
+                .map(|f| f.path.as_ref().map(|p| p.display().to_string())),
+        );
+        let rows = UInt64Chunked::from_vec("rows".into(), files.iter().map(|f| f.rows).collect());
+        let bytes = UInt64Chunked::from_iter_options("bytes".into(), files.iter().map(|f| f.bytes));
+        let mut columns = vec![path.into_column(), rows.into_column(), bytes.into_column()];
+        if let Some(dtype) = key_dtype {
+            let mins = files
+                .iter()
+                .map(|f| {
+                    f.key_min
+                        .as_ref()
+                        .map_or(AnyValue::Null, |s| s.value().clone())
+                })
+                .collect::<Vec<_>>();
+            let maxs = files
+                .iter()
+                .map(|f| {
+                    f.key_max
+                        .as_ref()
+                        .map_or(AnyValue::Null, |s| s.value().clone())
+                })
+                .collect::<Vec<_>>();
+            for (name, values) in [("key_min", mins), ("key_max", maxs)] {
+                let s = Series::from_any_values_and_dtype(name.into(), &values, dtype, true)?;
+                columns.push(s.into_column());
+            }
+        }
+        DataFrame::new(columns)
+    }
+
+    /// Convert from the [`DataFrame`] returned by collecting a sink.
+    pub fn from_frame(df: &DataFrame) -> PolarsResult<Self> {
+        let path = df.column("path")?.str()?;
+        let rows = df.column("rows")?.u64()?;
+        let bytes = df.column("bytes")?.u64()?;
+        let key_min = df.column("key_min").ok();
+        let key_max = df.column("key_max").ok();
+        let scalar = |c: Option<&Column>, i: usize| -> PolarsResult<Option<Scalar>> {
+            let Some(c) = c else {
+                return Ok(None);
+            };
+            let value = c.get(i)?.into_static();
+            Ok((!value.is_null()).then(|| Scalar::new(c.dtype().clone(), value)))
+        };
+        let files = (0..df.height())
+            .map(|i| {
+                Ok(SinkFileReport {
+                    path: path.get(i).map(PathBuf::from),
+                    rows: rows.get(i).unwrap_or(0),
+                    bytes: bytes.get(i),
+                    key_min: scalar(key_min, i)?,
+                    key_max: scalar(key_max, i)?,
+                })
+            })
+            .collect::<PolarsResult<_>>()?;
+        Ok(Self { files })
+    }
+}
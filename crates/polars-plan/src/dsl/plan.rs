@@ -34,7 +34,8 @@ pub enum DslPlan {
     },
     Scan {
         sources: ScanSources,
-        /// Materialized at IR except for AnonymousScan.
+        /// Materialized at IR except for AnonymousScan. For other scans, a known row count in
+        /// here (e.g. from a catalog) replaces the row estimation from the files.
         file_info: Option<FileInfo>,
         file_options: Box<FileScanOptions>,
         scan_type: Box<FileScan>,
@@ -144,6 +144,18 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Base64Decode(strict))
     }
 
+    /// Parse UUIDs into a [`DataType::Uuid`] column.
+    #[cfg(feature = "binary_encoding")]
+    pub fn to_uuid(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::ToUuid(strict))
+    }
+
+    /// Parse IPv4 and IPv6 addresses into their 4 or 16 bytes.
+    #[cfg(feature = "binary_encoding")]
+    pub fn to_ip(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::ToIp(strict))
+    }
+
     /// Extract a regex pattern from the a string value. If `group_index` is out of bounds, null is returned.
     pub fn extract(self, pat: Expr, group_index: usize) -> Expr {
         self.0.map_binary(StringFunction::Extract(group_index), pat)
@@ -152,35 +164,27 @@ impl StringNameSpace {
     #[cfg(feature = "extract_groups")]
     // Extract all captures groups from a regex pattern as a struct
     pub fn extract_groups(self, pat: &str) -> PolarsResult<Expr> {
-        // regex will be compiled twice, because it doesn't support serde
-        // and we need to compile it here to determine the output datatype
-
-        use polars_utils::format_pl_smallstr;
-        let reg = polars_utils::regex_cache::compile_regex(pat)?;
-        let names = reg
-            .capture_names()
-            .enumerate()
-            .skip(1)
-            .map(|(idx, opt_name)| {
-                opt_name
-                    .map(PlSmallStr::from_str)
-                    .unwrap_or_else(|| format_pl_smallstr!("{idx}"))
-            })
-            .collect::<Vec<_>>();
-
-        let dtype = DataType::Struct(
-            names
-                .iter()
-                .map(|name| Field::new(name.clone(), DataType::String))
-                .collect(),
-        );
-
+        let dtype = capture_groups_dtype(pat)?;
         Ok(self.0.map_unary(StringFunction::ExtractGroups {
             dtype,
             pat: pat.into(),
         }))
     }
 
+    #[cfg(feature = "extract_groups")]
+    /// Extract the captures groups of every match of a regex pattern as a list of structs.
+    pub fn extract_all_groups(self, pat: &str) -> PolarsResult<Expr> {
+        let dtype = capture_groups_dtype(pat)?;
+        polars_ensure!(
+            !matches!(&dtype, DataType::Struct(fields) if fields.is_empty()),
+            InvalidOperation: "the pattern of `extract_all_groups` must have at least one capture group"
+        );
+        Ok(self.0.map_unary(StringFunction::ExtractAllGroups {
+            dtype: DataType::List(Box::new(dtype)),
+            pat: pat.into(),
+        }))
+    }
+
     /// Pad the start of the string until it reaches the given length.
     ///
     /// Padding is done using the specified `fill_char`.
@@ -284,21 +288,78 @@ impl StringNameSpace {
         // If time_unit is None, try to infer it from the format or set a default
         let time_unit = match (&options.format, time_unit) {
             (_, Some(time_unit)) => time_unit,
-            (Some(format), None) => {
-                if format.contains("%.9f") || format.contains("%9f") {
-                    TimeUnit::Nanoseconds
-                } else if format.contains("%.3f") || format.contains("%3f") {
-                    TimeUnit::Milliseconds
-                } else {
-                    TimeUnit::Microseconds
-                }
-            },
+            (Some(format), None) => infer_time_unit(format),
             (None, None) => TimeUnit::Microseconds,
         };
 
         self.strptime(DataType::Datetime(time_unit, time_zone), options, ambiguous)
     }
 
+    /// Convert a String column into a Datetime column, trying the `formats` in order.
+    ///
+    /// Every value is parsed with the first format that matches it. If `time_unit` is `None`,
+    /// the finest time unit inferred from any of the formats is used.
+    #[cfg(feature = "dtype-datetime")]
+    pub fn to_datetime_formats(
+        self,
+        formats: Vec<PlSmallStr>,
+        time_unit: Option<TimeUnit>,
+        time_zone: Option<TimeZone>,
+        strict: bool,
+        cache: bool,
+        ambiguous: Expr,
+    ) -> Expr {
+        let time_unit = time_unit.unwrap_or_else(|| {
+            formats
+                .iter()
+                .map(|format| infer_time_unit(format))
+                .min_by_key(|tu| match tu {
+                    TimeUnit::Nanoseconds => 0,
+                    TimeUnit::Microseconds => 1,
+                    TimeUnit::Milliseconds => 2,
+                })
+                .unwrap_or(TimeUnit::Microseconds)
+        });
+        self.0.map_binary(
+            StringFunction::StrptimeFormats {
+                time_unit,
+                time_zone,
+                formats: Some(formats),
+                strict,
+                cache,
+            },
+            ambiguous,
+        )
+    }
+
+    /// Convert a String column into a Datetime column, with the format of every row taken from
+    /// the `format` column.
+    ///
+    /// Rows with a null format result in null. If `time_unit` is `None`, it defaults to
+    /// microseconds.
+    #[cfg(feature = "dtype-datetime")]
+    pub fn to_datetime_by_format(
+        self,
+        format: Expr,
+        time_unit: Option<TimeUnit>,
+        time_zone: Option<TimeZone>,
+        strict: bool,
+        cache: bool,
+        ambiguous: Expr,
+    ) -> Expr {
+        self.0.map_ternary(
+            StringFunction::StrptimeFormats {
+                time_unit: time_unit.unwrap_or(TimeUnit::Microseconds),
+                time_zone,
+                formats: None,
+                strict,
+                cache,
+            },
+            ambiguous,
+            format,
+        )
+    }
+
     /// Convert a String column into a Time column.
     #[cfg(feature = "dtype-time")]
     pub fn to_time(self, options: StrptimeOptions) -> Expr {
@@ -311,6 +372,17 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::ToDecimal(infer_length))
     }
 
+    /// Convert a String column into a Duration column.
+    ///
+    /// Both the ISO 8601 form (eg: "P1DT2H") and the "polars" form (eg: "1d 2h") produced by
+    /// `dt.to_string` are accepted. Values that cannot be parsed become null, unless `strict` is
+    /// set, in which case an error is raised.
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_duration(self, time_unit: TimeUnit, strict: bool) -> Expr {
+        self.0
+            .map_unary(StringFunction::ToDuration { time_unit, strict })
+    }
+
     /// Concat the values into a string array.
     /// # Arguments
     ///
@@ -387,12 +459,75 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Normalize { form })
     }
 
+    #[cfg(feature = "string_normalize")]
+    /// Remove the diacritics, such as accents, of each string
+    pub fn strip_diacritics(self) -> Expr {
+        self.0.map_unary(StringFunction::StripDiacritics)
+    }
+
     #[cfg(feature = "string_reverse")]
     /// Reverse each string
     pub fn reverse(self) -> Expr {
         self.0.map_unary(StringFunction::Reverse)
     }
 
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Levenshtein distance to `other`, null if it exceeds `max_distance`.
+    pub fn levenshtein(self, other: Expr, max_distance: Option<u32>) -> Expr {
+        self.0
+            .map_binary(StringFunction::Levenshtein { max_distance }, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Hamming distance to `other`, null if the lengths differ or it exceeds
+    /// `max_distance`.
+    pub fn hamming(self, other: Expr, max_distance: Option<u32>) -> Expr {
+        self.0
+            .map_binary(StringFunction::Hamming { max_distance }, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the Jaro-Winkler similarity to `other`.
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::JaroWinkler, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Compute the token set ratio to `other`.
+    pub fn token_set_ratio(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::TokenSetRatio, other)
+    }
+
+    #[cfg(feature = "string_url")]
+    /// Get the lowercase scheme of each URL, such as "https".
+    pub fn url_scheme(self) -> Expr {
+        self.0.map_unary(StringFunction::UrlScheme)
+    }
+
+    #[cfg(feature = "string_url")]
+    /// Get the lowercase host of each URL, without the user information and the port.
+    pub fn url_host(self) -> Expr {
+        self.0.map_unary(StringFunction::UrlHost)
+    }
+
+    #[cfg(feature = "string_url")]
+    /// Get the percent-encoded path of each URL.
+    pub fn url_path(self) -> Expr {
+        self.0.map_unary(StringFunction::UrlPath)
+    }
+
+    #[cfg(feature = "string_url")]
+    /// Get the decoded value of the first query parameter named `key` of each URL.
+    pub fn url_query_param(self, key: &str) -> Expr {
+        self.0.map_unary(StringFunction::UrlQueryParam(key.into()))
+    }
+
+    #[cfg(feature = "string_url")]
+    /// Decode the percent-encoded escapes of each string.
+    pub fn url_decode(self) -> Expr {
+        self.0.map_unary(StringFunction::UrlDecode)
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_binary(StringFunction::StripChars, matches)
@@ -423,6 +558,11 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Lowercase)
     }
 
+    /// Fold the case of all characters, for caseless comparisons.
+    pub fn to_casefold(self) -> Expr {
+        self.0.map_unary(StringFunction::Casefold)
+    }
+
     /// Convert all characters to uppercase.
     pub fn to_uppercase(self) -> Expr {
         self.0.map_unary(StringFunction::Uppercase)
@@ -496,3 +636,36 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::EscapeRegex)
     }
 }
+
+/// Infer the time unit of datetimes parsed with `format` from its fractional seconds.
+#[cfg(feature = "dtype-datetime")]
+fn infer_time_unit(format: &str) -> TimeUnit {
+    if format.contains("%.9f") || format.contains("%9f") {
+        TimeUnit::Nanoseconds
+    } else if format.contains("%.3f") || format.contains("%3f") {
+        TimeUnit::Milliseconds
+    } else {
+        TimeUnit::Microseconds
+    }
+}
+
+#[cfg(feature = "extract_groups")]
+/// The struct of the capture groups of `pat`; unnamed groups are named by their index.
+fn capture_groups_dtype(pat: &str) -> PolarsResult<DataType> {
+    // regex will be compiled twice, because it doesn't support serde
+    // and we need to compile it here to determine the output datatype
+    use polars_utils::format_pl_smallstr;
+    let reg = polars_utils::regex_cache::compile_regex(pat)?;
+    let fields = reg
+        .capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(idx, opt_name)| {
+            let name = opt_name
+                .map(PlSmallStr::from_str)
+                .unwrap_or_else(|| format_pl_smallstr!("{idx}"));
+            Field::new(name, DataType::String)
+        })
+        .collect();
+    Ok(DataType::Struct(fields))
+}
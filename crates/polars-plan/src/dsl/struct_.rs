@@ -57,6 +57,38 @@ impl StructNameSpace {
             )))
     }
 
+    /// Rename the fields at the given paths, keeping all other fields.
+    ///
+    /// A path such as `"a.b"` refers to field `b` of the struct field `a`, unless a field is
+    /// named `"a.b"` itself.
+    pub fn rename_fields_by_path<I, S, T>(self, renames: I) -> Expr
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: Into<PlSmallStr>,
+        T: Into<PlSmallStr>,
+    {
+        self.0.map_unary(FunctionExpr::StructExpr(
+            StructFunction::RenameFieldsByPath(
+                renames
+                    .into_iter()
+                    .map(|(path, name)| (path.into(), name.into()))
+                    .collect(),
+            ),
+        ))
+    }
+
+    /// Drop the fields at the given (dotted) paths of the [`StructChunked`].
+    pub fn drop_fields<I, S>(self, paths: I) -> Expr
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        self.0
+            .map_unary(FunctionExpr::StructExpr(StructFunction::DropFields(
+                paths.into_iter().map(|x| x.into()).collect(),
+            )))
+    }
+
     #[cfg(feature = "json")]
     pub fn json_encode(self) -> Expr {
         self.0
@@ -64,6 +96,17 @@ impl StructNameSpace {
     }
 
     pub fn with_fields(self, fields: Vec<Expr>) -> PolarsResult<Expr> {
+        self.with_fields_impl(fields, StructFunction::WithFields)
+    }
+
+    /// Add or replace fields like [`with_fields`](Self::with_fields), but the name of an
+    /// expression such as `"a.b"` refers to field `b` of the struct field `a`, unless a field is
+    /// named `"a.b"` itself.
+    pub fn with_fields_by_path(self, fields: Vec<Expr>) -> PolarsResult<Expr> {
+        self.with_fields_impl(fields, StructFunction::WithFieldsByPath)
+    }
+
+    fn with_fields_impl(self, fields: Vec<Expr>, function: StructFunction) -> PolarsResult<Expr> {
         fn materialize_field(this: &Expr, field: Expr) -> PolarsResult<Expr> {
             field.try_map_expr(|e| match e {
                 Expr::Field(names) => {
@@ -83,7 +126,7 @@ impl StructNameSpace {
 
         let s = self.0.clone();
         self.0.try_map_n_ary(
-            FunctionExpr::StructExpr(StructFunction::WithFields),
+            FunctionExpr::StructExpr(function),
             fields.into_iter().map(|e| materialize_field(&s, e)),
         )
     }
@@ -1,6 +1,7 @@
 #[cfg(feature = "dtype-decimal")]
 use polars_core::chunked_array::arithmetic::{
-    _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul,
+    _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_floor_div,
+    _get_decimal_scale_mul,
 };
 use recursive::recursive;
 
@@ -611,6 +612,8 @@ fn get_arithmetic_field(
                         Operator::Divide | Operator::TrueDivide => {
                             _get_decimal_scale_div(*scale_left)
                         },
+                        Operator::Modulus => _get_decimal_scale_add_sub(*scale_left, *scale_right),
+                        Operator::FloorDivide => _get_decimal_scale_floor_div(),
                         _ => {
                             debug_assert!(false);
                             *scale_left
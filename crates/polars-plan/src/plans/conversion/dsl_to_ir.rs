@@ -3,6 +3,8 @@ use either::Either;
 use expr_expansion::{is_regex_projection, rewrite_projections};
 use hive::hive_partitions_from_paths;
 
+use super::random_seed::seed_random_expressions;
+use super::scan_policy::scan_policy;
 use super::stack_opt::ConversionOptimizer;
 use super::*;
 use crate::plans::conversion::expr_expansion::expand_selectors;
@@ -145,6 +147,12 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             if cached_ir.is_none() {
                 let mut file_options = file_options.clone();
                 let mut scan_type = scan_type.clone();
+                // A known row count that comes with the scan, e.g. from a catalog, is used
+                // instead of the estimation from the files.
+                let provided_row_estimation = file_info
+                    .as_ref()
+                    .map(|file_info| file_info.row_estimation)
+                    .filter(|(known_size, _)| known_size.is_some());
 
                 if let Some(hive_schema) = file_options.hive_options.schema.as_deref() {
                     match file_options.hive_options.enabled {
@@ -247,6 +255,10 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     },
                 };
 
+                if let Some(row_estimation) = provided_row_estimation {
+                    file_info.row_estimation = row_estimation;
+                }
+
                 if file_options.hive_options.enabled.is_none() {
                     // We expect this to be `Some(_)` after this point. If it hasn't been auto-enabled
                     // we explicitly set it to disabled.
@@ -360,7 +372,9 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 cached_ir.replace(ir);
             }
 
-            cached_ir.clone().unwrap()
+            let ir = cached_ir.clone().unwrap();
+            drop(cached_ir);
+            return apply_scan_policy(ir, ctxt).map_err(|e| e.context(failed_here!(scan policy)));
         },
         #[cfg(feature = "python")]
         DslPlan::PythonScan { mut options } => {
@@ -777,6 +791,10 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     let ir = IR::MapFunction { input, function };
                     return Ok(ctxt.lp_arena.add(ir));
                 },
+                DslFunction::RandomSeed(seed) => {
+                    seed_random_expressions(input, seed, ctxt.lp_arena, ctxt.expr_arena);
+                    return Ok(input);
+                },
                 DslFunction::FillNan(fill_value) => {
                     let exprs = input_schema
                         .iter()
@@ -1039,6 +1057,48 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
     Ok(ctxt.lp_arena.add(v))
 }
 
+/// Add the `scan` to the plan, followed by the filters and masks of the scan policy, if any.
+fn apply_scan_policy(scan: IR, ctxt: &mut DslConversionContext) -> PolarsResult<Node> {
+    let Some(policy) = scan_policy() else {
+        return Ok(ctxt.lp_arena.add(scan));
+    };
+    let sources = match &scan {
+        IR::Scan { sources, .. } => sources.clone(),
+        _ => ScanSources::default(),
+    };
+    let mut input = ctxt.lp_arena.add(scan);
+    let schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena).into_owned();
+    let ScanPolicy { filters, masks } = policy(&sources, &schema)?;
+
+    for predicate in filters {
+        let predicate = expand_filter(predicate, input, ctxt.lp_arena, ctxt.opt_flags)?;
+        let predicate = to_expr_ir(predicate, ctxt.expr_arena)?;
+        ctxt.conversion_optimizer
+            .push_scratch(predicate.node(), ctxt.expr_arena);
+        let lp = IR::Filter { input, predicate };
+        input = run_conversion(lp, ctxt, "scan policy filter")?;
+    }
+
+    if !masks.is_empty() {
+        let (exprs, masked_schema) =
+            resolve_with_columns(masks, input, ctxt.lp_arena, ctxt.expr_arena, ctxt.opt_flags)?;
+        polars_ensure!(
+            masked_schema.len() == schema.len(),
+            InvalidOperation: "the masks of a scan policy can only replace existing columns"
+        );
+        ctxt.conversion_optimizer
+            .fill_scratch(&exprs, ctxt.expr_arena);
+        let lp = IR::HStack {
+            input,
+            exprs,
+            schema: masked_schema,
+            options: ProjectionOptions::default(),
+        };
+        input = run_conversion(lp, ctxt, "scan policy mask")?;
+    }
+    Ok(input)
+}
+
 fn expand_filter(
     predicate: Expr,
     input: Node,
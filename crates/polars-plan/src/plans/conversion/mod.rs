@@ -3,6 +3,8 @@ mod dsl_to_ir;
 mod expr_expansion;
 mod expr_to_ir;
 mod ir_to_dsl;
+mod random_seed;
+mod scan_policy;
 #[cfg(any(
     feature = "ipc",
     feature = "parquet",
@@ -23,6 +25,7 @@ use polars_utils::idx_vec::UnitVec;
 use polars_utils::unitvec;
 use polars_utils::vec::ConvertVec;
 use recursive::recursive;
+pub use scan_policy::{ScanPolicy, register_scan_policy, unregister_scan_policy};
 #[cfg(any(
     feature = "ipc",
     feature = "parquet",
@@ -0,0 +1,80 @@
+//! Seeding of the random expressions of a query, so that it gives the same result on every run.
+use super::*;
+
+/// The `index`-th output of a SplitMix64 generator seeded with `seed`.
+///
+/// Consecutive outputs are well mixed, so they seed independent random streams even if `seed`
+/// is small.
+pub(super) fn derive_seed(seed: u64, index: u64) -> u64 {
+    let mut z = seed.wrapping_add(index.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// The seed of a random function, if it has one.
+fn seed_of(function: &mut FunctionExpr) -> Option<&mut Option<u64>> {
+    match function {
+        #[cfg(feature = "random")]
+        FunctionExpr::Random { seed, .. } => Some(seed),
+        #[cfg(feature = "rank")]
+        FunctionExpr::Rank { options, seed } if options.method == RankMethod::Random => Some(seed),
+        #[cfg(feature = "list_sample")]
+        FunctionExpr::ListExpr(ListFunction::Sample { seed, .. }) => Some(seed),
+        _ => None,
+    }
+}
+
+/// Give every random expression of the plan rooted at `root` that has no seed of its own a
+/// seed derived from `seed`.
+///
+/// The expressions are numbered in the order of a depth-first traversal of the plan, which
+/// doesn't depend on how the query is executed, and the expression with number `i` gets the
+/// `i`-th derived seed.
+pub(super) fn seed_random_expressions(
+    root: Node,
+    seed: u64,
+    lp_arena: &Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) {
+    let mut index = 0;
+    let mut visited = PlHashSet::new();
+    let mut stack = unitvec![root];
+    let mut exprs = vec![];
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+        let ir = lp_arena.get(node);
+        exprs.clear();
+        ir.copy_exprs(&mut exprs);
+        for e in &exprs {
+            let nodes = (&*expr_arena)
+                .iter(e.node())
+                .map(|(node, _)| node)
+                .collect::<Vec<_>>();
+            for node in nodes {
+                if let AExpr::Function { function, .. } = expr_arena.get_mut(node) {
+                    if let Some(expr_seed @ None) = seed_of(function) {
+                        *expr_seed = Some(derive_seed(seed, index));
+                        index += 1;
+                    }
+                }
+            }
+        }
+        ir.copy_inputs(&mut stack);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_derive_seed() {
+        // The first outputs of SplitMix64 seeded with 0.
+        assert_eq!(derive_seed(0, 0), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(derive_seed(0, 1), 0x6E78_9E6A_A1B9_65F4);
+        assert_ne!(derive_seed(1, 0), derive_seed(0, 0));
+    }
+}
@@ -0,0 +1,44 @@
+//! Mandatory filters and column masks on every scan, for services that run the queries of
+//! several tenants.
+//!
+//! The installed scan policy is called with the sources and schema of each scan while the
+//! query is converted, before the optimizer runs. Its filters and masks are placed directly
+//! above the scan, so every part of the query only sees the rows and values the policy allows.
+//! The optimizer still pushes the filters into the scan, but it never moves a filter of the
+//! query below a mask of a column it refers to.
+use std::sync::{Arc, RwLock};
+
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+/// What a scan policy applies to a scan.
+#[derive(Clone, Debug, Default)]
+pub struct ScanPolicy {
+    /// Filters that every row of the scan has to pass.
+    pub filters: Vec<Expr>,
+    /// Expressions that replace the column of their output name, e.g.
+    /// `col("email").hash(0).alias("email")`. They are evaluated after the filters.
+    pub masks: Vec<Expr>,
+}
+
+type ScanPolicyFn = dyn Fn(&ScanSources, &Schema) -> PolarsResult<ScanPolicy> + Send + Sync;
+
+static SCAN_POLICY: RwLock<Option<Arc<ScanPolicyFn>>> = RwLock::new(None);
+
+/// Install `policy`, replacing the one installed before. It is called with the sources and the
+/// schema of every scan of files or in-memory buffers, and of anonymous scans.
+pub fn register_scan_policy(
+    policy: impl Fn(&ScanSources, &Schema) -> PolarsResult<ScanPolicy> + Send + Sync + 'static,
+) {
+    *SCAN_POLICY.write().unwrap() = Some(Arc::new(policy));
+}
+
+/// Remove the installed scan policy, returning whether there was one.
+pub fn unregister_scan_policy() -> bool {
+    SCAN_POLICY.write().unwrap().take().is_some()
+}
+
+pub(super) fn scan_policy() -> Option<Arc<ScanPolicyFn>> {
+    SCAN_POLICY.read().unwrap().clone()
+}
@@ -0,0 +1,203 @@
+//! Fingerprints of optimized plans, to recognize queries that give the same result.
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+
+use polars_io::cloud::CloudOptions;
+use xxhash_rust::xxh3::Xxh3;
+
+use super::*;
+use crate::plans::visitor::IRNode;
+
+/// The fingerprint of an optimized plan, see [`plan_fingerprint`].
+pub struct PlanFingerprint {
+    /// A 128-bit digest, so that different plans practically never have the same fingerprint.
+    pub hash: u128,
+    /// In-memory data is identified by its address, so it is kept alive for as long as the
+    /// fingerprint is, to make sure the address is not reused by other data.
+    _referenced: Vec<Box<dyn Any + Send + Sync>>,
+}
+
+/// Fingerprint the plan at `root`, such that plans with the same fingerprint give the same
+/// result.
+///
+/// Scanned files are identified by their paths, sizes and versions: the modification time of
+/// local files and the ETag (or modification time) of cloud objects. Returns `None` for plans
+/// that may give a different result when run again, or whose result is not only the data:
+/// plans with Python or Rust UDFs, plugins, random expressions, anonymous scans, scans of open
+/// files and sinks to files.
+pub fn plan_fingerprint(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<Option<PlanFingerprint>> {
+    let mut hasher = Xxh3::new();
+    let mut referenced: Vec<Box<dyn Any + Send + Sync>> = vec![];
+    let mut stack = vec![root];
+    let mut inputs = vec![];
+    let mut exprs = vec![];
+
+    while let Some(node) = stack.pop() {
+        let ir = lp_arena.get(node);
+        let sources = match ir {
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } => return Ok(None),
+            IR::Scan {
+                sources, scan_type, ..
+            }
+            | IR::MapFunction {
+                function:
+                    FunctionIR::FastCount {
+                        sources, scan_type, ..
+                    },
+                ..
+            } => Some((sources, scan_type)),
+            IR::MapFunction {
+                function: FunctionIR::Opaque { .. } | FunctionIR::Pipeline { .. },
+                ..
+            } => return Ok(None),
+            #[cfg(feature = "python")]
+            IR::MapFunction {
+                function: FunctionIR::OpaquePython(_),
+                ..
+            } => return Ok(None),
+            IR::Sink { payload, .. } if !matches!(payload, SinkTypeIR::Memory) => {
+                return Ok(None);
+            },
+            IR::GroupBy { apply: Some(_), .. } => return Ok(None),
+            IR::DataFrameScan { df, .. } => {
+                referenced.push(Box::new(df.clone()));
+                None
+            },
+            _ => None,
+        };
+        if let Some((sources, scan_type)) = sources {
+            match sources {
+                ScanSources::Paths(paths) => {
+                    let Some(cloud_options) = scan_cloud_options(scan_type) else {
+                        return Ok(None);
+                    };
+                    hash_file_versions(paths, cloud_options, &mut hasher)?;
+                },
+                // Files can be written to while they are open.
+                ScanSources::Files(_) => return Ok(None),
+                ScanSources::Buffers(_) => referenced.push(Box::new(sources.clone())),
+            }
+        }
+
+        // Caches are identified by ids that differ between otherwise identical queries.
+        if matches!(ir, IR::Cache { .. }) {
+            std::mem::discriminant(ir).hash(&mut hasher);
+        } else {
+            IRNode::new(node)
+                .hashable_and_cmp(lp_arena, expr_arena)
+                .hash(&mut hasher);
+        }
+
+        exprs.clear();
+        ir.copy_exprs(&mut exprs);
+        for e in &exprs {
+            if !hash_expr_literals(e.node(), expr_arena, &mut hasher) {
+                return Ok(None);
+            }
+        }
+
+        inputs.clear();
+        ir.copy_inputs(&mut inputs);
+        inputs.len().hash(&mut hasher);
+        stack.extend(inputs.iter().rev());
+    }
+
+    Ok(Some(PlanFingerprint {
+        hash: hasher.digest128(),
+        _referenced: referenced,
+    }))
+}
+
+/// The cloud options of a file scan, or `None` for anonymous scans.
+fn scan_cloud_options(scan_type: &FileScan) -> Option<Option<&CloudOptions>> {
+    match scan_type {
+        #[cfg(feature = "csv")]
+        FileScan::Csv { cloud_options, .. } => Some(cloud_options.as_ref()),
+        #[cfg(feature = "json")]
+        FileScan::NDJson { cloud_options, .. } => Some(cloud_options.as_ref()),
+        #[cfg(feature = "parquet")]
+        FileScan::Parquet { cloud_options, .. } => Some(cloud_options.as_ref()),
+        #[cfg(feature = "ipc")]
+        FileScan::Ipc { cloud_options, .. } => Some(cloud_options.as_ref()),
+        FileScan::Anonymous { .. } => None,
+    }
+}
+
+/// The hashes of the expressions only sample the values of literal series, so hash them
+/// completely. Returns false if the expression is not deterministic.
+fn hash_expr_literals<H: Hasher>(node: Node, expr_arena: &Arena<AExpr>, state: &mut H) -> bool {
+    for (_, ae) in expr_arena.iter(node) {
+        match ae {
+            AExpr::AnonymousFunction { .. } => return false,
+            // Plugins may have state or side effects that the plan does not show.
+            #[cfg(feature = "ffi_plugin")]
+            AExpr::Function {
+                function: FunctionExpr::FfiPlugin { .. },
+                ..
+            } => return false,
+            AExpr::Function {
+                function: FunctionExpr::StaticPlugin { .. },
+                ..
+            } => return false,
+            #[cfg(feature = "random")]
+            AExpr::Function {
+                function: FunctionExpr::Random { .. },
+                ..
+            } => return false,
+            AExpr::Literal(LiteralValue::Series(s)) => {
+                for av in s.iter() {
+                    av.hash(state);
+                }
+            },
+            _ => {},
+        }
+    }
+    true
+}
+
+fn hash_file_versions<H: Hasher>(
+    paths: &[PathBuf],
+    cloud_options: Option<&CloudOptions>,
+    state: &mut H,
+) -> PolarsResult<()> {
+    let (cloud_paths, local_paths): (Vec<_>, Vec<_>) =
+        paths.iter().partition(|p| polars_io::is_cloud_url(p));
+
+    for path in local_paths {
+        let metadata = std::fs::metadata(path)?;
+        metadata.len().hash(state);
+        metadata.modified().ok().hash(state);
+    }
+
+    if !cloud_paths.is_empty() {
+        #[cfg(feature = "cloud")]
+        {
+            use polars_io::cloud::{build_object_store, object_path_from_str};
+
+            let metas = polars_io::pl_async::get_runtime().block_in_place_on(
+                futures::future::try_join_all(cloud_paths.iter().map(|path| async move {
+                    let uri = path.to_str().unwrap();
+                    let (location, store) = build_object_store(uri, cloud_options, false).await?;
+                    store.head(&object_path_from_str(&location.prefix)?).await
+                })),
+            )?;
+            for meta in metas {
+                meta.size.hash(state);
+                meta.e_tag.hash(state);
+                meta.last_modified.timestamp_millis().hash(state);
+            }
+        }
+        #[cfg(not(feature = "cloud"))]
+        {
+            let _ = cloud_options;
+            polars_bail!(ComputeError: "fingerprinting cloud scans requires the 'cloud' feature");
+        }
+    }
+    Ok(())
+}
@@ -47,6 +47,14 @@ pub enum DslFunction {
     /// FillValue
     FillNan(Expr),
     Drop(DropFunction),
+    /// Seed the random expressions of the input that have no seed of their own.
+    RandomSeed(u64),
+    #[cfg(feature = "random")]
+    ReservoirSample {
+        n: usize,
+        by: Vec<Selector>,
+        seed: Option<u64>,
+    },
     // Function that is already converted to IR.
     #[cfg_attr(feature = "serde", serde(skip))]
     FunctionIR(FunctionIR),
@@ -145,9 +153,16 @@ impl DslFunction {
             },
             #[cfg(feature = "python")]
             DslFunction::OpaquePython(inner) => FunctionIR::OpaquePython(inner),
+            #[cfg(feature = "random")]
+            DslFunction::ReservoirSample { n, by, seed } => {
+                let by = expand_selectors(by, input_schema, &[])?;
+                validate_columns_in_input(by.as_ref(), input_schema, "sample")?;
+                FunctionIR::ReservoirSample { n, by, seed }
+            },
             DslFunction::Stats(_)
             | DslFunction::FillNan(_)
             | DslFunction::Drop(_)
+            | DslFunction::RandomSeed(_)
             | DslFunction::Explode { .. } => {
                 // We should not reach this.
                 panic!("impl error")
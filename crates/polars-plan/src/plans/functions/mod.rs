@@ -78,6 +78,13 @@ pub enum FunctionIR {
         // used for formatting
         fmt_str: PlSmallStr,
     },
+    /// A uniform sample of at most `n` rows of every group of `by`, drawn in a single pass.
+    #[cfg(feature = "random")]
+    ReservoirSample {
+        n: usize,
+        by: Arc<[PlSmallStr]>,
+        seed: Option<u64>,
+    },
     /// Streaming engine pipeline
     #[cfg_attr(feature = "ir_serde", serde(skip))]
     Pipeline {
@@ -162,6 +169,12 @@ impl Hash for FunctionIR {
                 name.hash(state);
                 offset.hash(state);
             },
+            #[cfg(feature = "random")]
+            FunctionIR::ReservoirSample { n, by, seed } => {
+                n.hash(state);
+                by.hash(state);
+                seed.hash(state);
+            },
         }
     }
 }
@@ -179,6 +192,8 @@ impl FunctionIR {
             #[cfg(feature = "python")]
             OpaquePython(OpaquePythonUdf { streamable, .. }) => *streamable,
             RowIndex { .. } => false,
+            #[cfg(feature = "random")]
+            ReservoirSample { .. } => false,
         }
     }
 
@@ -203,6 +218,8 @@ impl FunctionIR {
             Unpivot { .. } => true,
             Rechunk | Unnest { .. } | Rename { .. } | Explode { .. } => true,
             RowIndex { .. } | FastCount { .. } => false,
+            #[cfg(feature = "random")]
+            ReservoirSample { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -217,6 +234,8 @@ impl FunctionIR {
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             RowIndex { .. } => true,
+            #[cfg(feature = "random")]
+            ReservoirSample { .. } => true,
             Pipeline { .. } => unimplemented!(),
         }
     }
@@ -226,6 +245,8 @@ impl FunctionIR {
         match self {
             Unnest { columns } => Cow::Borrowed(columns.as_ref()),
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
+            #[cfg(feature = "random")]
+            ReservoirSample { by, .. } => Cow::Borrowed(by.as_ref()),
             _ => Cow::Borrowed(&[]),
         }
     }
@@ -275,6 +296,12 @@ impl FunctionIR {
                 df.unpivot2(args)
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.clone(), *offset),
+            #[cfg(feature = "random")]
+            ReservoirSample { n, by, seed } => {
+                let mut sampler = ReservoirSampler::new(*n, by.to_vec(), *seed);
+                sampler.push(&df)?;
+                Ok(sampler.finish())
+            },
         }
     }
 
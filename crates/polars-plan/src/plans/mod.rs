@@ -14,6 +14,7 @@ pub(crate) mod conversion;
 #[cfg(feature = "debugging")]
 pub(crate) mod debug;
 pub mod expr_ir;
+mod fingerprint;
 mod functions;
 pub mod hive;
 pub(crate) mod iterator;
@@ -32,6 +33,7 @@ pub use anonymous_scan::*;
 pub use apply::*;
 pub use builder_ir::*;
 pub use conversion::*;
+pub use fingerprint::*;
 pub(crate) use expr_ir::*;
 pub use functions::*;
 pub use ir::*;
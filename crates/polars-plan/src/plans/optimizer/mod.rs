@@ -58,10 +58,19 @@ pub trait Optimize {
 // arbitrary constant to reduce reallocation.
 const HASHMAP_SIZE: usize = 16;
 
+/// Enter a `tracing` span for an optimization pass, which is closed at the end of the scope.
+macro_rules! pass_span {
+    ($pass:literal) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("optimization_pass", pass = $pass).entered();
+    };
+}
+
 pub(crate) fn init_hashmap<K, V>(max_len: Option<usize>) -> PlHashMap<K, V> {
     PlHashMap::with_capacity(std::cmp::min(max_len.unwrap_or(HASHMAP_SIZE), HASHMAP_SIZE))
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
 pub fn optimize(
     logical_plan: DslPlan,
     mut opt_flags: OptFlags,
@@ -98,7 +107,10 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     if opt_flags.contains(OptFlags::EAGER) {
         opt_flags &= !(OptFlags::COMM_SUBEXPR_ELIM | OptFlags::COMM_SUBEXPR_ELIM);
     }
-    let mut lp_top = to_alp(logical_plan, expr_arena, lp_arena, &mut opt_flags)?;
+    let mut lp_top = {
+        pass_span!("conversion");
+        to_alp(logical_plan, expr_arena, lp_arena, &mut opt_flags)?
+    };
 
     // Don't run optimizations that don't make sense on a single node.
     // This keeps eager execution more snappy.
@@ -132,6 +144,7 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     if opt_flags.contains(OptFlags::CHECK_ORDER_OBSERVE) {
         let members = get_or_init_members!();
         if members.has_group_by | members.has_sort | members.has_distinct {
+            pass_span!("set_order_flags");
             set_order_flags(lp_top, lp_arena, expr_arena, scratch);
         }
     }
@@ -151,6 +164,7 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
             if verbose {
                 eprintln!("found multiple sources; run comm_subplan_elim")
             }
+            pass_span!("comm_subplan_elim");
 
             let (lp, changed, cid2c) = cse::elim_cmn_subplans(lp_top, lp_arena, expr_arena);
 
@@ -170,6 +184,7 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
 
     // Should be run before predicate pushdown.
     if opt_flags.projection_pushdown() {
+        pass_span!("projection_pushdown");
         let mut projection_pushdown_opt = ProjectionPushDown::new(opt_flags.new_streaming());
         let alp = lp_arena.take(lp_top);
         let alp = projection_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
@@ -182,6 +197,7 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     }
 
     if opt_flags.predicate_pushdown() {
+        pass_span!("predicate_pushdown");
         let mut predicate_pushdown_opt =
             PredicatePushDown::new(expr_eval, opt_flags.new_streaming());
         let alp = lp_arena.take(lp_top);
@@ -190,11 +206,13 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     }
 
     if opt_flags.cluster_with_columns() {
+        pass_span!("cluster_with_columns");
         cluster_with_columns::optimize(lp_top, lp_arena, expr_arena)
     }
 
     // Make sure it is after predicate pushdown
     if opt_flags.collapse_joins() && get_or_init_members!().has_filter_with_join_input {
+        pass_span!("collapse_joins");
         collapse_joins::optimize(lp_top, lp_arena, expr_arena);
     }
 
@@ -210,6 +228,7 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     }
 
     if opt_flags.slice_pushdown() {
+        pass_span!("slice_pushdown");
         let mut slice_pushdown_opt =
             SlicePushDown::new(opt_flags.streaming(), opt_flags.new_streaming());
         let alp = lp_arena.take(lp_top);
@@ -230,13 +249,17 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         rules.push(Box::new(FlattenUnionRule {}));
     }
 
-    lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
+    lp_top = {
+        pass_span!("optimize_loop");
+        opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?
+    };
 
     if _cse_plan_changed
         && get_members_opt!()
             .is_some_and(|members| members.has_joins_or_unions && members.has_cache)
     {
         // We only want to run this on cse inserted caches
+        pass_span!("set_cache_states");
         cache_states::set_cache_states(
             lp_top,
             lp_arena,
@@ -251,6 +274,7 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
     // This one should run (nearly) last as this modifies the projections
     #[cfg(feature = "cse")]
     if comm_subexpr_elim && !get_or_init_members!().has_ext_context {
+        pass_span!("comm_subexpr_elim");
         let mut optimizer = CommonSubExprOptimizer::new();
         let alp_node = IRNode::new_mutate(lp_top);
 
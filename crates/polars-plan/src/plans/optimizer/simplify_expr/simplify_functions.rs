@@ -101,11 +101,12 @@ pub(super) fn optimize_functions(
                         options,
                     })
                 },
+                // Reversing a limited sort would keep different rows.
                 AExpr::SortBy {
                     expr,
                     by,
                     sort_options,
-                } => {
+                } if sort_options.limit.is_none() => {
                     let mut sort_options = sort_options.clone();
                     let reversed_descending = sort_options.descending.iter().map(|x| !*x).collect();
                     sort_options.descending = reversed_descending;
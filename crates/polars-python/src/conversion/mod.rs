@@ -16,6 +16,7 @@ use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
 use polars::series::ops::NullBehavior;
+use polars_core::chunked_array::arithmetic::DecimalOverflow;
 use polars_core::utils::arrow::array::Array;
 use polars_core::utils::arrow::types::NativeType;
 use polars_core::utils::materialize_dyn_int;
@@ -31,7 +32,7 @@ use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
-use pyo3::types::{PyDict, PyList, PySequence, PyString};
+use pyo3::types::{PyBytes, PyDict, PyList, PySequence, PyString};
 
 use crate::error::PyPolarsErr;
 use crate::file::{PythonScanSourceInput, get_python_scan_source_input};
@@ -114,6 +115,12 @@ pub(crate) fn to_series(py: Python, s: PySeries) -> PyResult<Bound<PyAny>> {
     constructor.call1((s,))
 }
 
+/// Convert the 16 bytes of a [`DataType::Uuid`] value to a Python `uuid.UUID`.
+pub(crate) fn to_py_uuid<'py>(py: Python<'py>, bytes: &[u8]) -> PyResult<Bound<'py, PyAny>> {
+    let uuid = PyModule::import(py, intern!(py, "uuid"))?.getattr(intern!(py, "UUID"))?;
+    uuid.call1((py.None(), PyBytes::new(py, bytes)))
+}
+
 impl<'a> FromPyObject<'a> for Wrap<PlSmallStr> {
     fn extract_bound(ob: &Bound<'a, PyAny>) -> PyResult<Self> {
         Ok(Wrap((&*ob.extract::<PyBackedStr>()?).into()))
@@ -314,6 +321,12 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Unknown"))?;
                 class.call0()
             },
+            DataType::Uuid => {
+                let class = pl.getattr(intern!(py, "Uuid"))?;
+                class.call0()
+            },
+            // Python has no extension types, so they are shown as their storage.
+            DataType::Extension(_, _, storage) => Wrap(storage.as_ref().clone()).into_pyobject(py),
             DataType::BinaryOffset => {
                 unimplemented!()
             },
@@ -362,6 +375,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "Boolean" => DataType::Boolean,
                     "String" => DataType::String,
                     "Binary" => DataType::Binary,
+                    "Uuid" => DataType::Uuid,
                     "Categorical" => DataType::Categorical(None, Default::default()),
                     "Enum" => DataType::Enum(None, Default::default()),
                     "Date" => DataType::Date,
@@ -397,6 +411,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "Boolean" => DataType::Boolean,
             "String" => DataType::String,
             "Binary" => DataType::Binary,
+            "Uuid" => DataType::Uuid,
             "Categorical" => {
                 let ordering = ob.getattr(intern!(py, "ordering")).unwrap();
                 let ordering = ordering.extract::<Wrap<CategoricalOrdering>>()?.0;
@@ -818,6 +833,54 @@ impl<'py> FromPyObject<'py> for Wrap<StartBy> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<OlsMode> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "coefficients" => OlsMode::Coefficients,
+            "predictions" => OlsMode::Predictions,
+            "residuals" => OlsMode::Residuals,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`mode` must be one of {{'coefficients', 'predictions', 'residuals'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<RandomDistribution> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "uniform" => RandomDistribution::Uniform,
+            "normal" => RandomDistribution::Normal,
+            "bernoulli" => RandomDistribution::Bernoulli,
+            "poisson" => RandomDistribution::Poisson,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`distribution` must be one of {{'uniform', 'normal', 'bernoulli', 'poisson'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<ContractPolicy> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "strict" => ContractPolicy::Strict,
+            "coerce" => ContractPolicy::Coerce,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`policy` must be one of {{'strict', 'coerce'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<ClosedWindow> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -905,6 +968,47 @@ impl<'py> FromPyObject<'py> for Wrap<Label> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<UpsampleFillStrategy> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let Ok(strategy) = ob.extract::<PyBackedStr>() else {
+            let value = ob.extract::<crate::PyExpr>()?;
+            return Ok(Wrap(UpsampleFillStrategy::Value(value.inner)));
+        };
+        let parsed = match &*strategy {
+            "forward" => UpsampleFillStrategy::Forward,
+            "backward" => UpsampleFillStrategy::Backward,
+            "interpolate" => UpsampleFillStrategy::Interpolate,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`fill` strategy must be one of {{'forward', 'backward', 'interpolate'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<UpsampleFill> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if ob.is_none() {
+            return Ok(Wrap(UpsampleFill::Null));
+        }
+        let Ok(dict) = ob.downcast::<PyDict>() else {
+            let strategy = ob.extract::<Wrap<UpsampleFillStrategy>>()?;
+            return Ok(Wrap(UpsampleFill::All(strategy.0)));
+        };
+        let fills = dict
+            .iter()
+            .map(|(name, strategy)| {
+                let name = name.extract::<PyBackedStr>()?;
+                let strategy = strategy.extract::<Wrap<UpsampleFillStrategy>>()?;
+                Ok((PlSmallStr::from_str(&name), strategy.0))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Wrap(UpsampleFill::Columns(fills)))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<ListToStructWidthStrategy> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1018,6 +1122,38 @@ impl<'py> FromPyObject<'py> for Wrap<QuantileMethod> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<DecimalOverflow> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "wrap" => DecimalOverflow::Wrap,
+            "error" => DecimalOverflow::Error,
+            "saturate" => DecimalOverflow::Saturate,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`decimal_overflow` must be one of {{'wrap', 'error', 'saturate'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<RollingRankMethod> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "average" => RollingRankMethod::Average,
+            "min" => RollingRankMethod::Min,
+            "max" => RollingRankMethod::Max,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "rolling rank `method` must be one of {{'average', 'min', 'max'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<RankMethod> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1037,6 +1173,22 @@ impl<'py> FromPyObject<'py> for Wrap<RankMethod> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<NanPosition> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "largest" => NanPosition::Largest,
+            "first" => NanPosition::First,
+            "last" => NanPosition::Last,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`nan_position` must be one of {{'largest', 'first', 'last'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<Roll> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1103,6 +1255,23 @@ impl<'py> FromPyObject<'py> for Wrap<SearchSortedSide> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<HashAlgorithm> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "xxhash64" => HashAlgorithm::XxHash64,
+            "wyhash" => HashAlgorithm::WyHash,
+            "siphash13" => HashAlgorithm::SipHash13,
+            "sha256" => HashAlgorithm::Sha256,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "hash `algorithm` must be one of {{'default', 'xxhash64', 'wyhash', 'siphash13', 'sha256'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<ClosedInterval> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1171,6 +1340,22 @@ impl<'py> FromPyObject<'py> for Wrap<MaintainOrderJoin> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<JoinAlgorithm> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "auto" => JoinAlgorithm::Auto,
+            "hash" => JoinAlgorithm::Hash,
+            "merge" => JoinAlgorithm::Merge,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`algorithm` must be one of {{'auto', 'hash', 'merge'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "csv")]
 impl<'py> FromPyObject<'py> for Wrap<QuoteStyle> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -41,6 +41,11 @@ impl PyDataFrame {
         self.df.estimated_size()
     }
 
+    pub fn memory_profile(&self) -> PyResult<Self> {
+        let df = self.df.memory_profile().map_err(PyPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn dtype_strings(&self) -> Vec<String> {
         self.df
             .get_columns()
@@ -116,6 +121,38 @@ impl PyDataFrame {
         })
     }
 
+    #[pyo3(signature = (by, n, with_replacement, shuffle, seed=None))]
+    pub fn sample_n_stratified(
+        &self,
+        py: Python,
+        by: Vec<String>,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        py.enter_polars_df(|| {
+            self.df
+                .sample_n_stratified(by, n, with_replacement, shuffle, seed)
+        })
+    }
+
+    #[pyo3(signature = (by, frac, with_replacement, shuffle, seed=None))]
+    pub fn sample_frac_stratified(
+        &self,
+        py: Python,
+        by: Vec<String>,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        py.enter_polars_df(|| {
+            self.df
+                .sample_frac_stratified(by, frac, with_replacement, shuffle, seed)
+        })
+    }
+
     pub fn rechunk(&self, py: Python) -> PyResult<Self> {
         py.enter_polars_df(|| {
             let mut df = self.df.clone();
@@ -124,6 +161,14 @@ impl PyDataFrame {
         })
     }
 
+    pub fn rechunk_to(&self, py: Python, chunk_size: usize) -> PyResult<Self> {
+        py.enter_polars_df(|| {
+            let mut df = self.df.clone();
+            df.rechunk_to(chunk_size)?;
+            Ok(df)
+        })
+    }
+
     /// Format `DataFrame` as String
     pub fn as_str(&self) -> String {
         format!("{:?}", self.df)
@@ -521,12 +566,14 @@ impl PyDataFrame {
         py.enter_polars_series(|| self.df.hash_rows(Some(hb)))
     }
 
-    #[pyo3(signature = (keep_names_as, column_names))]
+    #[pyo3(signature = (keep_names_as, column_names, dtype, as_struct))]
     pub fn transpose(
         &mut self,
         py: Python,
         keep_names_as: Option<&str>,
         column_names: &Bound<PyAny>,
+        dtype: Option<Wrap<DataType>>,
+        as_struct: bool,
     ) -> PyResult<Self> {
         let new_col_names = if let Ok(name) = column_names.extract::<Vec<String>>() {
             Some(Either::Right(name))
@@ -535,7 +582,15 @@ impl PyDataFrame {
         } else {
             None
         };
-        py.enter_polars_df(|| self.df.transpose(keep_names_as, new_col_names))
+        let dtype = match dtype {
+            _ if as_struct => TransposeDtype::Struct,
+            Some(dtype) => TransposeDtype::Cast(dtype.0),
+            None => TransposeDtype::Supertype,
+        };
+        py.enter_polars_df(|| {
+            self.df
+                .transpose_with_dtype(keep_names_as, new_col_names, dtype)
+        })
     }
 
     pub fn upsample(
@@ -398,7 +398,8 @@ impl PyDataFrame {
     #[cfg(feature = "parquet")]
     #[pyo3(signature = (
         py_f, compression, compression_level, statistics, row_group_size, data_page_size,
-        partition_by, partition_chunk_size_bytes, cloud_options, credential_provider, retries
+        zstd_dictionary_size, partition_by, partition_chunk_size_bytes, cloud_options,
+        credential_provider, retries
     ))]
     pub fn write_parquet(
         &mut self,
@@ -409,6 +410,7 @@ impl PyDataFrame {
         statistics: Wrap<StatisticsOptions>,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        zstd_dictionary_size: Option<usize>,
         partition_by: Option<Vec<String>>,
         partition_chunk_size_bytes: usize,
         cloud_options: Option<Vec<(String, String)>>,
@@ -445,6 +447,7 @@ impl PyDataFrame {
                     statistics: statistics.0,
                     row_group_size,
                     data_page_size,
+                    zstd_dictionary_size,
                 };
                 write_partitioned_dataset(
                     &mut self.df,
@@ -465,6 +468,7 @@ impl PyDataFrame {
                 .with_statistics(statistics.0)
                 .with_row_group_size(row_group_size)
                 .with_data_page_size(data_page_size)
+                .with_zstd_dictionary_size(zstd_dictionary_size)
                 .finish(&mut self.df)?;
 
             crate::file::close_file(f)
@@ -70,6 +70,8 @@ impl From<&DataType> for PyDataType {
             DataType::Categorical(_, _) => Categorical,
             DataType::Enum(rev_map, _) => Enum(rev_map.as_ref().unwrap().get_categories().clone()),
             DataType::Struct(_) => Struct,
+            DataType::Extension(_, _, storage) => storage.as_ref().into(),
+            DataType::Uuid => Binary,
             DataType::Null | DataType::Unknown(_) | DataType::BinaryOffset => {
                 panic!("null or unknown not expected here")
             },
@@ -10,6 +10,7 @@ use pyo3::exceptions::{
     PyRuntimeError, PyUserWarning,
 };
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 use crate::Wrap;
 use crate::exceptions::{
@@ -109,6 +110,21 @@ impl From<PyPolarsErr> for PyErr {
                     let tmp = PyPolarsErr::Polars(err.context_trace());
                     PyErr::from(tmp)
                 },
+                PolarsError::PlanContext { error, context } => {
+                    let error = error
+                        .context_trace()
+                        .wrap_msg(|msg| format!("{msg}\n\n{context}"));
+                    let err = PyErr::from(PyPolarsErr::Polars(error));
+                    // The error is raised even if its context cannot be attached.
+                    let _ = Python::with_gil(|py| {
+                        let dict = PyDict::new(py);
+                        dict.set_item("operator", &context.operator)?;
+                        dict.set_item("expressions", context.expressions.as_slice())?;
+                        dict.set_item("columns", context.columns.as_slice())?;
+                        err.value(py).setattr("error_context", dict)
+                    });
+                    err
+                },
             },
             Python(err) => err,
             err => PyRuntimeError::new_err(format!("{:?}", &err)),
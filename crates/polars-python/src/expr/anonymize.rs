@@ -0,0 +1,30 @@
+use polars::lazy::dsl::anonymize::AnonymizeKey;
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn anonymize_tokenize(&self, key: &str, length: usize) -> Self {
+        let key = AnonymizeKey::new(key);
+        self.inner.clone().anonymize().tokenize(&key, length).into()
+    }
+
+    fn anonymize_hash_preserving_format(&self, key: &str) -> Self {
+        let key = AnonymizeKey::new(key);
+        self.inner
+            .clone()
+            .anonymize()
+            .hash_preserving_format(&key)
+            .into()
+    }
+
+    fn anonymize_bucket(&self, width: f64) -> Self {
+        self.inner.clone().anonymize().bucket(width).into()
+    }
+
+    #[pyo3(signature = (length, fill))]
+    fn anonymize_truncate(&self, length: usize, fill: Option<char>) -> Self {
+        self.inner.clone().anonymize().truncate(length, fill).into()
+    }
+}
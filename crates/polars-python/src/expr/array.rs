@@ -137,6 +137,27 @@ impl PyExpr {
         self.inner.clone().arr().shift(n.inner).into()
     }
 
+    fn arr_dot(&self, other: PyExpr) -> Self {
+        self.inner.clone().arr().dot(other.inner).into()
+    }
+
+    fn arr_cosine_similarity(&self, other: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .cosine_similarity(other.inner)
+            .into()
+    }
+
+    fn arr_l2_norm(&self) -> Self {
+        self.inner.clone().arr().l2_norm().into()
+    }
+
+    #[pyo3(signature = (offset, length=None))]
+    fn arr_slice(&self, offset: i64, length: Option<usize>) -> Self {
+        self.inner.clone().arr().slice(offset, length).into()
+    }
+
     fn arr_explode(&self) -> Self {
         self.inner.clone().arr().explode().into()
     }
@@ -42,6 +42,35 @@ impl PyExpr {
         self.inner.clone().binary().base64_encode().into()
     }
 
+    #[cfg(feature = "binary_encoding")]
+    fn bin_uuid_to_string(&self) -> Self {
+        self.inner.clone().binary().uuid_to_string().into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn bin_ip_to_string(&self) -> Self {
+        self.inner.clone().binary().ip_to_string().into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn bin_ip_is_in_subnet(&self, cidr: &str) -> Self {
+        self.inner
+            .clone()
+            .binary()
+            .ip_is_in_subnet(cidr.into())
+            .into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn bin_ipv4_to_integer(&self) -> Self {
+        self.inner.clone().binary().ipv4_to_integer().into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn bin_ipv6_to_integer(&self) -> Self {
+        self.inner.clone().binary().ipv6_to_integer().into()
+    }
+
     #[cfg(feature = "binary_encoding")]
     #[allow(clippy::wrong_self_convention)]
     fn from_buffer(&self, dtype: Wrap<DataType>, kind: &str) -> PyResult<Self> {
@@ -67,4 +96,46 @@ impl PyExpr {
     fn bin_size_bytes(&self) -> Self {
         self.inner.clone().binary().size_bytes().into()
     }
+
+    fn bin_slice(&self, offset: PyExpr, length: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .binary()
+            .slice(offset.inner, length.inner)
+            .into()
+    }
+
+    fn bin_concat(&self, other: PyExpr) -> Self {
+        self.inner.clone().binary().concat(other.inner).into()
+    }
+
+    fn bin_bitwise_and(&self, other: PyExpr) -> Self {
+        self.inner.clone().binary().bitwise_and(other.inner).into()
+    }
+
+    fn bin_bitwise_or(&self, other: PyExpr) -> Self {
+        self.inner.clone().binary().bitwise_or(other.inner).into()
+    }
+
+    fn bin_bitwise_xor(&self, other: PyExpr) -> Self {
+        self.inner.clone().binary().bitwise_xor(other.inner).into()
+    }
+
+    fn bin_crc32(&self) -> Self {
+        self.inner.clone().binary().crc32().into()
+    }
+
+    fn bin_xxh3(&self, seed: u64) -> Self {
+        self.inner.clone().binary().xxh3(seed).into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn bin_base64url_decode(&self, strict: bool) -> Self {
+        self.inner.clone().binary().base64url_decode(strict).into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn bin_base64url_encode(&self) -> Self {
+        self.inner.clone().binary().base64url_encode().into()
+    }
 }
@@ -28,6 +28,20 @@ impl PyExpr {
         self.inner.clone().dt().offset_by(by.inner).into()
     }
 
+    #[cfg(feature = "timezones")]
+    fn dt_offset_by_with_policy(
+        &self,
+        by: PyExpr,
+        ambiguous: Self,
+        non_existent: Wrap<NonExistent>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .offset_by_with_policy(by.inner, ambiguous.inner, non_existent.0)
+            .into()
+    }
+
     fn dt_epoch_seconds(&self) -> Self {
         self.inner
             .clone()
@@ -74,8 +88,27 @@ impl PyExpr {
             .into()
     }
 
-    fn dt_truncate(&self, every: Self) -> Self {
-        self.inner.clone().dt().truncate(every.inner).into()
+    fn dt_truncate(&self, every: Self, week_start: u8) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .truncate_with_week_start(every.inner, week_start)
+            .into()
+    }
+
+    #[cfg(feature = "timezones")]
+    fn dt_truncate_with_policy(
+        &self,
+        every: Self,
+        week_start: u8,
+        ambiguous: Self,
+        non_existent: Wrap<NonExistent>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .truncate_with_policy(every.inner, week_start, ambiguous.inner, non_existent.0)
+            .into()
     }
 
     fn dt_month_start(&self) -> Self {
@@ -99,6 +132,20 @@ impl PyExpr {
         self.inner.clone().dt().round(every.inner).into()
     }
 
+    #[cfg(feature = "timezones")]
+    fn dt_round_with_policy(
+        &self,
+        every: Self,
+        ambiguous: Self,
+        non_existent: Wrap<NonExistent>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .round_with_policy(every.inner, ambiguous.inner, non_existent.0)
+            .into()
+    }
+
     fn dt_replace(
         &self,
         year: Self,
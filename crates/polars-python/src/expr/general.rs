@@ -3,12 +3,14 @@ use std::ops::Neg;
 use polars::lazy::dsl;
 use polars::prelude::*;
 use polars::series::ops::NullBehavior;
+use polars_core::chunked_array::arithmetic::DecimalOverflow;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::series::IsSorted;
 use polars_plan::plans::predicates::aexpr_to_skip_batch_predicate;
 use polars_plan::plans::{node_to_expr, to_aexpr};
 use polars_utils::arena::Arena;
 use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
@@ -161,53 +163,76 @@ impl PyExpr {
             .into()
     }
 
-    #[pyo3(signature = (breaks, labels, left_closed, include_breaks))]
+    #[pyo3(signature = (breaks, labels, label_format, left_closed, include_breaks, as_enum))]
     #[cfg(feature = "cutqcut")]
     fn cut(
         &self,
         breaks: Vec<f64>,
         labels: Option<Vec<String>>,
+        label_format: Option<String>,
         left_closed: bool,
         include_breaks: bool,
+        as_enum: bool,
     ) -> Self {
         self.inner
             .clone()
-            .cut(breaks, labels, left_closed, include_breaks)
+            .cut(
+                breaks,
+                labels,
+                label_format.map(PlSmallStr::from),
+                left_closed,
+                include_breaks,
+                as_enum,
+            )
             .into()
     }
-    #[pyo3(signature = (probs, labels, left_closed, allow_duplicates, include_breaks))]
+    #[pyo3(signature = (probs, labels, label_format, left_closed, allow_duplicates, include_breaks, as_enum))]
     #[cfg(feature = "cutqcut")]
     fn qcut(
         &self,
         probs: Vec<f64>,
         labels: Option<Vec<String>>,
+        label_format: Option<String>,
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        as_enum: bool,
     ) -> Self {
         self.inner
             .clone()
-            .qcut(probs, labels, left_closed, allow_duplicates, include_breaks)
+            .qcut(
+                probs,
+                labels,
+                label_format.map(PlSmallStr::from),
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                as_enum,
+            )
             .into()
     }
-    #[pyo3(signature = (n_bins, labels, left_closed, allow_duplicates, include_breaks))]
+    #[pyo3(signature = (n_bins, labels, label_format, left_closed, allow_duplicates, include_breaks, as_enum))]
     #[cfg(feature = "cutqcut")]
     fn qcut_uniform(
         &self,
         n_bins: usize,
         labels: Option<Vec<String>>,
+        label_format: Option<String>,
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        as_enum: bool,
     ) -> Self {
         self.inner
             .clone()
             .qcut_uniform(
                 n_bins,
                 labels,
+                label_format.map(PlSmallStr::from),
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                as_enum,
             )
             .into()
     }
@@ -256,7 +281,12 @@ impl PyExpr {
         let expr = self.inner.clone().cast_with_options(dt, options);
         expr.into()
     }
-    fn sort_with(&self, descending: bool, nulls_last: bool) -> Self {
+    fn sort_with(
+        &self,
+        descending: bool,
+        nulls_last: bool,
+        nan_position: Wrap<NanPosition>,
+    ) -> Self {
         self.inner
             .clone()
             .sort(SortOptions {
@@ -265,11 +295,17 @@ impl PyExpr {
                 multithreaded: true,
                 maintain_order: false,
                 limit: None,
+                nan_position: nan_position.0,
             })
             .into()
     }
 
-    fn arg_sort(&self, descending: bool, nulls_last: bool) -> Self {
+    fn arg_sort(
+        &self,
+        descending: bool,
+        nulls_last: bool,
+        nan_position: Wrap<NanPosition>,
+    ) -> Self {
         self.inner
             .clone()
             .arg_sort(SortOptions {
@@ -278,6 +314,7 @@ impl PyExpr {
                 multithreaded: true,
                 maintain_order: false,
                 limit: None,
+                nan_position: nan_position.0,
             })
             .into()
     }
@@ -653,6 +690,30 @@ impl PyExpr {
         self.inner.clone().repeat_by(by.inner).into()
     }
 
+    fn decimal_arithmetic(
+        &self,
+        op: &str,
+        other: Self,
+        overflow: Wrap<DecimalOverflow>,
+    ) -> PyResult<Self> {
+        let op = match op {
+            "add" => Operator::Plus,
+            "sub" => Operator::Minus,
+            "mul" => Operator::Multiply,
+            "truediv" => Operator::TrueDivide,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "decimal overflow handling is not supported for `{v}`"
+                )));
+            },
+        };
+        Ok(self
+            .inner
+            .clone()
+            .decimal_arithmetic(op, other.inner, overflow.0)
+            .into())
+    }
+
     fn pow(&self, exponent: Self) -> Self {
         self.inner.clone().pow(exponent.inner).into()
     }
@@ -674,8 +735,12 @@ impl PyExpr {
     fn cum_min(&self, reverse: bool) -> Self {
         self.inner.clone().cum_min(reverse).into()
     }
-    fn cum_prod(&self, reverse: bool) -> Self {
-        self.inner.clone().cum_prod(reverse).into()
+    fn cum_prod(&self, reverse: bool, log_domain: bool) -> Self {
+        if log_domain {
+            self.inner.clone().cum_prod_log_domain(reverse).into()
+        } else {
+            self.inner.clone().cum_prod(reverse).into()
+        }
     }
     fn cum_count(&self, reverse: bool) -> Self {
         self.inner.clone().cum_count(reverse).into()
@@ -735,8 +800,11 @@ impl PyExpr {
     fn interpolate(&self, method: Wrap<InterpolationMethod>) -> Self {
         self.inner.clone().interpolate(method.0).into()
     }
-    fn interpolate_by(&self, by: PyExpr) -> Self {
-        self.inner.clone().interpolate_by(by.inner).into()
+    fn interpolate_by(&self, by: PyExpr, method: Wrap<InterpolationMethod>) -> Self {
+        self.inner
+            .clone()
+            .interpolate_by_with_method(by.inner, method.0)
+            .into()
     }
 
     fn lower_bound(&self) -> Self {
@@ -747,11 +815,18 @@ impl PyExpr {
         self.inner.clone().upper_bound().into()
     }
 
-    #[pyo3(signature = (method, descending, seed=None))]
-    fn rank(&self, method: Wrap<RankMethod>, descending: bool, seed: Option<u64>) -> Self {
+    #[pyo3(signature = (method, descending, nan_position, seed=None))]
+    fn rank(
+        &self,
+        method: Wrap<RankMethod>,
+        descending: bool,
+        nan_position: Wrap<NanPosition>,
+        seed: Option<u64>,
+    ) -> Self {
         let options = RankOptions {
             method: method.0,
             descending,
+            nan_position: nan_position.0,
         };
         self.inner.clone().rank(options, seed).into()
     }
@@ -827,6 +902,16 @@ impl PyExpr {
             .into())
     }
 
+    fn ewm_std_by(&self, times: PyExpr, half_life: &str) -> PyResult<Self> {
+        let half_life = Duration::try_parse(half_life).map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().ewm_std_by(times.inner, half_life).into())
+    }
+
+    fn ewm_var_by(&self, times: PyExpr, half_life: &str) -> PyResult<Self> {
+        let half_life = Duration::try_parse(half_life).map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().ewm_var_by(times.inner, half_life).into())
+    }
+
     fn ewm_std(
         &self,
         alpha: f64,
@@ -893,6 +978,12 @@ impl PyExpr {
     fn hash(&self, seed: u64, seed_1: u64, seed_2: u64, seed_3: u64) -> Self {
         self.inner.clone().hash(seed, seed_1, seed_2, seed_3).into()
     }
+    fn stable_hash(&self, algorithm: Wrap<HashAlgorithm>, seed: u64, seed_1: u64) -> Self {
+        self.inner
+            .clone()
+            .stable_hash(algorithm.0, seed, seed_1)
+            .into()
+    }
     fn set_sorted_flag(&self, descending: bool) -> Self {
         let is_sorted = if descending {
             IsSorted::Descending
@@ -902,6 +993,10 @@ impl PyExpr {
         self.inner.clone().set_sorted_flag(is_sorted).into()
     }
 
+    fn assert_(&self, predicate: Self, message: &str) -> Self {
+        self.inner.clone().assert(predicate.inner, message).into()
+    }
+
     fn replace(&self, old: PyExpr, new: PyExpr) -> Self {
         self.inner.clone().replace(old.inner, new.inner).into()
     }
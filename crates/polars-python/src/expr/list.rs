@@ -255,6 +255,14 @@ impl PyExpr {
         self.inner.clone().list().n_unique().into()
     }
 
+    fn list_value_counts(&self, sort: bool, normalize: bool, name: &str) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .value_counts(sort, normalize, name)
+            .into()
+    }
+
     fn list_unique(&self, maintain_order: bool) -> Self {
         let e = self.inner.clone();
 
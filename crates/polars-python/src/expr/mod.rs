@@ -1,4 +1,6 @@
 #[cfg(feature = "pymethods")]
+mod anonymize;
+#[cfg(feature = "pymethods")]
 mod array;
 #[cfg(feature = "pymethods")]
 mod binary;
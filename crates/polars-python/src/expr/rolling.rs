@@ -327,6 +327,46 @@ impl PyExpr {
         self.inner.clone().rolling_skew(window_size, bias).into()
     }
 
+    #[pyo3(signature = (window_size, fisher, bias, min_periods, center))]
+    fn rolling_kurtosis(
+        &self,
+        window_size: usize,
+        fisher: bool,
+        bias: bool,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner
+            .clone()
+            .rolling_kurtosis(fisher, bias, options)
+            .into()
+    }
+
+    #[pyo3(signature = (window_size, method, min_periods, center))]
+    fn rolling_rank(
+        &self,
+        window_size: usize,
+        method: Wrap<RollingRankMethod>,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_rank(method.0, options).into()
+    }
+
     #[pyo3(signature = (lambda, window_size, weights, min_periods, center))]
     fn rolling_map(
         &self,
@@ -60,6 +60,54 @@ impl PyExpr {
             .into()
     }
 
+    #[pyo3(signature = (formats, time_unit, time_zone, strict, cache, ambiguous))]
+    fn str_to_datetime_formats(
+        &self,
+        formats: Vec<String>,
+        time_unit: Option<Wrap<TimeUnit>>,
+        time_zone: Option<Wrap<TimeZone>>,
+        strict: bool,
+        cache: bool,
+        ambiguous: Self,
+    ) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .to_datetime_formats(
+                formats.into_iter().map(|x| x.into()).collect(),
+                time_unit.map(|tu| tu.0),
+                time_zone.map(|x| x.0),
+                strict,
+                cache,
+                ambiguous.inner,
+            )
+            .into()
+    }
+
+    #[pyo3(signature = (format, time_unit, time_zone, strict, cache, ambiguous))]
+    fn str_to_datetime_by_format(
+        &self,
+        format: Self,
+        time_unit: Option<Wrap<TimeUnit>>,
+        time_zone: Option<Wrap<TimeZone>>,
+        strict: bool,
+        cache: bool,
+        ambiguous: Self,
+    ) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .to_datetime_by_format(
+                format.inner,
+                time_unit.map(|tu| tu.0),
+                time_zone.map(|x| x.0),
+                strict,
+                cache,
+                ambiguous.inner,
+            )
+            .into()
+    }
+
     #[pyo3(signature = (format, strict, cache))]
     fn str_to_time(&self, format: Option<String>, strict: bool, cache: bool) -> Self {
         let format = format.map(|x| x.into());
@@ -125,6 +173,10 @@ impl PyExpr {
         self.inner.clone().str().to_lowercase().into()
     }
 
+    fn str_to_casefold(&self) -> Self {
+        self.inner.clone().str().to_casefold().into()
+    }
+
     #[cfg(feature = "nightly")]
     fn str_to_titlecase(&self) -> Self {
         self.inner.clone().str().to_titlecase().into()
@@ -160,10 +212,60 @@ impl PyExpr {
         self.inner.clone().str().normalize(form.0).into()
     }
 
+    fn str_strip_diacritics(&self) -> Self {
+        self.inner.clone().str().strip_diacritics().into()
+    }
+
     fn str_reverse(&self) -> Self {
         self.inner.clone().str().reverse().into()
     }
 
+    #[pyo3(signature = (other, max_distance))]
+    fn str_levenshtein(&self, other: Self, max_distance: Option<u32>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .levenshtein(other.inner, max_distance)
+            .into()
+    }
+
+    #[pyo3(signature = (other, max_distance))]
+    fn str_hamming(&self, other: Self, max_distance: Option<u32>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .hamming(other.inner, max_distance)
+            .into()
+    }
+
+    fn str_jaro_winkler(&self, other: Self) -> Self {
+        self.inner.clone().str().jaro_winkler(other.inner).into()
+    }
+
+    fn str_token_set_ratio(&self, other: Self) -> Self {
+        self.inner.clone().str().token_set_ratio(other.inner).into()
+    }
+
+    fn str_url_scheme(&self) -> Self {
+        self.inner.clone().str().url_scheme().into()
+    }
+
+    fn str_url_host(&self) -> Self {
+        self.inner.clone().str().url_host().into()
+    }
+
+    fn str_url_path(&self) -> Self {
+        self.inner.clone().str().url_path().into()
+    }
+
+    fn str_url_query_param(&self, key: &str) -> Self {
+        self.inner.clone().str().url_query_param(key).into()
+    }
+
+    fn str_url_decode(&self) -> Self {
+        self.inner.clone().str().url_decode().into()
+    }
+
     fn str_pad_start(&self, length: usize, fill_char: char) -> Self {
         self.inner.clone().str().pad_start(length, fill_char).into()
     }
@@ -211,6 +313,16 @@ impl PyExpr {
         self.inner.clone().str().hex_decode(strict).into()
     }
 
+    #[cfg(feature = "binary_encoding")]
+    fn str_to_uuid(&self, strict: bool) -> Self {
+        self.inner.clone().str().to_uuid(strict).into()
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn str_to_ip(&self, strict: bool) -> Self {
+        self.inner.clone().str().to_ip(strict).into()
+    }
+
     fn str_base64_encode(&self) -> Self {
         self.inner.clone().str().base64_encode().into()
     }
@@ -272,6 +384,17 @@ impl PyExpr {
             .into())
     }
 
+    #[cfg(feature = "extract_groups")]
+    fn str_extract_all_groups(&self, pat: &str) -> PyResult<Self> {
+        Ok(self
+            .inner
+            .clone()
+            .str()
+            .extract_all_groups(pat)
+            .map_err(PyPolarsErr::from)?
+            .into())
+    }
+
     fn str_count_matches(&self, pat: Self, literal: bool) -> Self {
         self.inner
             .clone()
@@ -308,6 +431,14 @@ impl PyExpr {
         self.inner.clone().str().to_decimal(infer_len).into()
     }
 
+    fn str_to_duration(&self, time_unit: Wrap<TimeUnit>, strict: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .to_duration(time_unit.0, strict)
+            .into()
+    }
+
     #[cfg(feature = "find_many")]
     fn str_contains_any(&self, patterns: PyExpr, ascii_case_insensitive: bool) -> Self {
         self.inner
@@ -22,6 +22,18 @@ impl PyExpr {
         self.inner.clone().struct_().rename_fields(names).into()
     }
 
+    fn struct_rename_fields_by_path(&self, renames: Vec<(String, String)>) -> Self {
+        self.inner
+            .clone()
+            .struct_()
+            .rename_fields_by_path(renames)
+            .into()
+    }
+
+    fn struct_drop_fields(&self, paths: Vec<String>) -> Self {
+        self.inner.clone().struct_().drop_fields(paths).into()
+    }
+
     #[cfg(feature = "json")]
     fn struct_json_encode(&self) -> Self {
         self.inner.clone().struct_().json_encode().into()
@@ -37,4 +49,15 @@ impl PyExpr {
             .map_err(PyPolarsErr::from)?;
         Ok(e.into())
     }
+
+    fn struct_with_fields_by_path(&self, fields: Vec<PyExpr>) -> PyResult<Self> {
+        let fields = fields.to_exprs();
+        let e = self
+            .inner
+            .clone()
+            .struct_()
+            .with_fields_by_path(fields)
+            .map_err(PyPolarsErr::from)?;
+        Ok(e.into())
+    }
 }
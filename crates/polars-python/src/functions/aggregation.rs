@@ -1,7 +1,9 @@
 use polars::lazy::dsl;
+use polars::prelude::{NanPosition, RankMethod, RankOptions};
 use pyo3::prelude::*;
 
 use crate::PyExpr;
+use crate::conversion::Wrap;
 use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 
@@ -46,3 +48,27 @@ pub fn mean_horizontal(exprs: Vec<PyExpr>, ignore_nulls: bool) -> PyResult<PyExp
     let e = dsl::mean_horizontal(exprs, ignore_nulls).map_err(PyPolarsErr::from)?;
     Ok(e.into())
 }
+
+#[pyfunction]
+pub fn cum_sum_horizontal(exprs: Vec<PyExpr>, ignore_nulls: bool) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::cum_sum_horizontal(exprs, ignore_nulls).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
+#[pyfunction]
+pub fn rank_horizontal(
+    exprs: Vec<PyExpr>,
+    method: Wrap<RankMethod>,
+    descending: bool,
+    nan_position: Wrap<NanPosition>,
+) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let options = RankOptions {
+        method: method.0,
+        descending,
+        nan_position: nan_position.0,
+    };
+    let e = dsl::rank_horizontal(exprs, options).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
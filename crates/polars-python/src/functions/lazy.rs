@@ -63,12 +63,87 @@ pub fn rolling_cov(
 }
 
 #[pyfunction]
+pub fn lstsq(y: PyExpr, x: Vec<PyExpr>, fit_intercept: bool, mode: Wrap<OlsMode>) -> PyExpr {
+    let options = OlsOptions {
+        fit_intercept,
+        mode: mode.0,
+    };
+    dsl::lstsq(y.inner, x.to_exprs(), options).into()
+}
+
+#[pyfunction]
+pub fn rolling_ols(
+    y: PyExpr,
+    x: Vec<PyExpr>,
+    window_size: usize,
+    min_periods: usize,
+    fit_intercept: bool,
+    mode: Wrap<OlsMode>,
+) -> PyExpr {
+    let options = OlsOptions {
+        fit_intercept,
+        mode: mode.0,
+    };
+    dsl::rolling_ols(y.inner, x.to_exprs(), window_size, min_periods, options).into()
+}
+
+#[pyfunction]
+#[pyo3(signature = (distribution, parameters, seed=None))]
+pub fn random_distribution(
+    distribution: Wrap<RandomDistribution>,
+    parameters: Vec<PyExpr>,
+    seed: Option<u64>,
+) -> PyExpr {
+    dsl::random_distribution(distribution.0, parameters.to_exprs(), seed).into()
+}
+
+#[pyfunction]
+pub fn rolling_corr_by(
+    x: PyExpr,
+    y: PyExpr,
+    by: PyExpr,
+    window_size: &str,
+    min_periods: usize,
+    closed: Wrap<ClosedWindow>,
+    ddof: u8,
+) -> PyResult<PyExpr> {
+    let options = RollingOptionsDynamicWindow {
+        window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+        min_periods,
+        closed_window: closed.0,
+        fn_params: None,
+    };
+    Ok(dsl::rolling_corr_by(x.inner, y.inner, by.inner, options, ddof).into())
+}
+
+#[pyfunction]
+pub fn rolling_cov_by(
+    x: PyExpr,
+    y: PyExpr,
+    by: PyExpr,
+    window_size: &str,
+    min_periods: usize,
+    closed: Wrap<ClosedWindow>,
+    ddof: u8,
+) -> PyResult<PyExpr> {
+    let options = RollingOptionsDynamicWindow {
+        window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+        min_periods,
+        closed_window: closed.0,
+        fn_params: None,
+    };
+    Ok(dsl::rolling_cov_by(x.inner, y.inner, by.inner, options, ddof).into())
+}
+
+#[pyfunction]
+#[pyo3(signature = (by, descending, nulls_last, multithreaded, maintain_order, limit))]
 pub fn arg_sort_by(
     by: Vec<PyExpr>,
     descending: Vec<bool>,
     nulls_last: Vec<bool>,
     multithreaded: bool,
     maintain_order: bool,
+    limit: Option<IdxSize>,
 ) -> PyExpr {
     let by = by.into_iter().map(|e| e.inner).collect::<Vec<Expr>>();
     dsl::arg_sort_by(
@@ -78,7 +153,7 @@ pub fn arg_sort_by(
             nulls_last,
             multithreaded,
             maintain_order,
-            limit: None,
+            limit,
         },
     )
     .into()
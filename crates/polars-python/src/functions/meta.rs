@@ -3,6 +3,7 @@ use polars_core::fmt::FloatFmt;
 use polars_core::prelude::IDX_DTYPE;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 use crate::conversion::Wrap;
 
@@ -16,6 +17,23 @@ pub fn thread_pool_size() -> usize {
     POOL.current_num_threads()
 }
 
+#[pyfunction]
+pub fn engine_stats(py: Python) -> PyResult<Bound<PyDict>> {
+    let stats = polars::lazy::engine_stats::engine_stats();
+    let dict = PyDict::new(py);
+    dict.set_item("thread_pool_size", stats.thread_pool_size)?;
+    // In seconds, the Python side turns it into a `timedelta`.
+    dict.set_item(
+        "thread_pool_cpu_time",
+        stats.thread_pool_cpu_time.map(|t| t.as_secs_f64()),
+    )?;
+    dict.set_item("string_cache_enabled", stats.string_cache_enabled)?;
+    dict.set_item("string_cache_len", stats.string_cache_len)?;
+    dict.set_item("string_cache_bytes", stats.string_cache_bytes)?;
+    dict.set_item("spill_bytes", stats.spill_bytes)?;
+    Ok(dict)
+}
+
 #[pyfunction]
 pub fn set_float_fmt(fmt: &str) -> PyResult<()> {
     let fmt = match fmt {
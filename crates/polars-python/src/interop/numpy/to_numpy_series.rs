@@ -277,6 +277,10 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
             let values = std::iter::repeat_n(f32::NAN, n);
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
+        // Python has no extension types, so they are converted as their storage.
+        Extension(_, _, _) | Uuid => {
+            series_to_numpy_with_copy(py, s.extension().unwrap().storage(), writable)
+        },
         Unknown(_) | BinaryOffset => unreachable!(),
     }
 }
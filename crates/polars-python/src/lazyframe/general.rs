@@ -350,6 +350,7 @@ impl PyLazyFrame {
             low_memory,
             cloud_options: None,
             use_statistics,
+            validate_utf8: Default::default(),
             schema: schema.map(|x| Arc::new(x.0)),
             hive_options,
             glob,
@@ -427,6 +428,7 @@ impl PyLazyFrame {
             cloud_options: None,
             hive_options,
             include_file_paths: include_file_paths.map(|x| x.into()),
+            validate_utf8: Default::default(),
         };
 
         let sources = sources.0;
@@ -724,7 +726,7 @@ impl PyLazyFrame {
     #[cfg(all(feature = "streaming", feature = "parquet"))]
     #[pyo3(signature = (
         target, compression, compression_level, statistics, row_group_size, data_page_size,
-        cloud_options, credential_provider, retries, sink_options
+        zstd_dictionary_size, cloud_options, credential_provider, retries, sink_options
     ))]
     fn sink_parquet(
         &self,
@@ -735,6 +737,7 @@ impl PyLazyFrame {
         statistics: Wrap<StatisticsOptions>,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        zstd_dictionary_size: Option<usize>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
@@ -747,6 +750,7 @@ impl PyLazyFrame {
             statistics: statistics.0,
             row_group_size,
             data_page_size,
+            zstd_dictionary_size,
         };
 
         let cloud_options = match target.base_path() {
@@ -1096,6 +1100,26 @@ impl PyLazyFrame {
         Ok(PyLazyGroupBy { lgb: Some(lazy_gb) })
     }
 
+    fn upsample(
+        &self,
+        time_column: &str,
+        every: &str,
+        group_by: Vec<PyBackedStr>,
+        maintain_order: bool,
+        fill: Wrap<UpsampleFill>,
+    ) -> PyResult<Self> {
+        let every = Duration::try_parse(every).map_err(PyPolarsErr::from)?;
+        let group_by = group_by
+            .iter()
+            .map(|name| PlSmallStr::from_str(name))
+            .collect::<Vec<_>>();
+        let ldf = self.ldf.clone();
+        let out = ldf
+            .upsample(time_column, every, &group_by, maintain_order, fill.0)
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
     fn with_context(&self, contexts: Vec<Self>) -> Self {
         let contexts = contexts.into_iter().map(|ldf| ldf.ldf).collect::<Vec<_>>();
         self.ldf.clone().with_context(contexts).into()
@@ -1151,7 +1175,7 @@ impl PyLazyFrame {
             .into())
     }
 
-    #[pyo3(signature = (other, left_on, right_on, allow_parallel, force_parallel, nulls_equal, how, suffix, validate, maintain_order, coalesce=None))]
+    #[pyo3(signature = (other, left_on, right_on, allow_parallel, force_parallel, nulls_equal, how, suffix, validate, maintain_order, coalesce=None, algorithm=Wrap(JoinAlgorithm::Auto)))]
     fn join(
         &self,
         other: Self,
@@ -1165,6 +1189,7 @@ impl PyLazyFrame {
         validate: Wrap<JoinValidation>,
         maintain_order: Wrap<MaintainOrderJoin>,
         coalesce: Option<bool>,
+        algorithm: Wrap<JoinAlgorithm>,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
             None => JoinCoalesce::JoinSpecific,
@@ -1195,6 +1220,7 @@ impl PyLazyFrame {
             .validate(validate.0)
             .coalesce(coalesce)
             .maintain_order(maintain_order.0)
+            .algorithm(algorithm.0)
             .finish()
             .into())
     }
@@ -1243,6 +1269,26 @@ impl PyLazyFrame {
         out.into()
     }
 
+    #[pyo3(signature = (by, n, seed=None))]
+    fn sample_n_stratified(&self, by: Vec<PyExpr>, n: usize, seed: Option<u64>) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.sample_n_stratified(by.to_exprs(), n, seed).into()
+    }
+
+    #[pyo3(signature = (by, frac, seed=None))]
+    fn sample_frac_stratified(
+        &self,
+        by: Vec<PyExpr>,
+        frac: PyExpr,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let out = ldf
+            .sample_frac_stratified(by.to_exprs(), frac.inner, seed)
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
     fn fill_nan(&self, fill_value: PyExpr) -> Self {
         let ldf = self.ldf.clone();
         ldf.fill_nan(fill_value.inner).into()
@@ -1374,6 +1420,11 @@ impl PyLazyFrame {
         ldf.with_row_index(name, offset).into()
     }
 
+    fn with_random_seed(&self, seed: u64) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_random_seed(seed).into()
+    }
+
     #[pyo3(signature = (lambda, predicate_pushdown, projection_pushdown, slice_pushdown, streamable, schema, validate_output))]
     fn map_batches(
         &self,
@@ -1423,6 +1474,18 @@ impl PyLazyFrame {
         self.ldf.clone().cast_all(dtype.0, strict).into()
     }
 
+    fn with_schema_contract(
+        &self,
+        py: Python,
+        schema: Wrap<Schema>,
+        policy: Wrap<ContractPolicy>,
+    ) -> PyResult<Self> {
+        let contract = SchemaContract::new(schema.0, policy.0);
+        let ldf = self.ldf.clone();
+        let ldf = py.enter_polars(|| ldf.with_schema_contract(&contract))?;
+        Ok(ldf.into())
+    }
+
     fn clone(&self) -> Self {
         self.ldf.clone().into()
     }
@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 use polars::prelude::sync_on_close::SyncOnCloseType;
-use polars::prelude::{PartitionVariant, SinkOptions, SpecialEq};
+use polars::prelude::{PartitionVariant, PlSmallStr, SinkOptions, SpecialEq};
 use polars_utils::IdxSize;
 use polars_utils::python_function::{PythonFunction, PythonObject};
 use pyo3::exceptions::PyValueError;
@@ -150,9 +150,9 @@ impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
 
-        if parsed.len() != 3 {
+        if parsed.len() != 4 {
             return Err(PyValueError::new_err(
-                "`sink_options` must be a dictionary with the exactly 3 field.",
+                "`sink_options` must be a dictionary with the exactly 4 field.",
             ));
         }
 
@@ -172,10 +172,18 @@ impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
             .ok_or_else(|| PyValueError::new_err("`sink_options` must contain `mkdir` field"))?;
         let mkdir = mkdir.extract::<bool>()?;
 
+        let report_key = PyDictMethods::get_item(&parsed, "report_key")?.ok_or_else(|| {
+            PyValueError::new_err("`sink_options` must contain `report_key` field")
+        })?;
+        let report_key = report_key
+            .extract::<Option<String>>()?
+            .map(PlSmallStr::from);
+
         Ok(Wrap(SinkOptions {
             sync_on_close,
             maintain_order,
             mkdir,
+            report_key,
         }))
     }
 }
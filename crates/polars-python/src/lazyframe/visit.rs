@@ -58,7 +58,13 @@ impl NodeTraverser {
     // Increment major on breaking changes to the IR (e.g. renaming
     // fields, reordering tuples), minor on backwards compatible
     // changes (e.g. exposing a new expression node).
-    const VERSION: Version = (6, 0);
+    //
+    // 6.1: add the "reservoir_sample" map function.
+    // 7.0: the "truncate", "offset_by" and "round" temporal functions gain a `non_existent`
+    //      element.
+    // 8.0: the "truncate" temporal function gains a `week_start` element before
+    //      `non_existent`.
+    const VERSION: Version = (8, 0);
 
     pub fn new(root: Node, lp_arena: Arena<IR>, expr_arena: Arena<AExpr>) -> Self {
         Self {
@@ -4,9 +4,9 @@ use polars::series::ops::NullBehavior;
 use polars_core::series::IsSorted;
 #[cfg(feature = "string_normalize")]
 use polars_ops::chunked_array::UnicodeForm;
-use polars_ops::series::InterpolationMethod;
 #[cfg(feature = "search_sorted")]
 use polars_ops::series::SearchSortedSide;
+use polars_ops::series::{HashAlgorithm, InterpolationMethod};
 use polars_plan::dsl::function_expr::rolling::RollingFunction;
 use polars_plan::dsl::function_expr::rolling_by::RollingFunctionBy;
 use polars_plan::dsl::{BooleanFunction, StringFunction, TemporalFunction};
@@ -157,6 +157,8 @@ pub enum PyStringFunction {
     HexDecode,
     Base64Encode,
     Base64Decode,
+    ToUuid,
+    ToIp,
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -166,8 +168,10 @@ pub enum PyStringFunction {
     SplitExact,
     SplitN,
     Strptime,
+    StrptimeFormats,
     Split,
     ToDecimal,
+    ToDuration,
     Titlecase,
     Uppercase,
     ZFill,
@@ -175,6 +179,18 @@ pub enum PyStringFunction {
     ReplaceMany,
     EscapeRegex,
     Normalize,
+    Levenshtein,
+    Hamming,
+    JaroWinkler,
+    TokenSetRatio,
+    ExtractAllGroups,
+    Casefold,
+    StripDiacritics,
+    UrlScheme,
+    UrlHost,
+    UrlPath,
+    UrlQueryParam,
+    UrlDecode,
 }
 
 #[pymethods]
@@ -746,6 +762,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
+                FunctionExpr::Anonymize(_) => {
+                    return Err(PyNotImplementedError::new_err("anonymize expr"));
+                },
                 FunctionExpr::StringExpr(strfun) => match strfun {
                     StringFunction::ConcatHorizontal {
                         delimiter,
@@ -784,6 +803,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         pat.as_str(),
                     )
                         .into_py_any(py),
+                    #[cfg(feature = "extract_groups")]
+                    StringFunction::ExtractAllGroups { dtype, pat } => (
+                        PyStringFunction::ExtractAllGroups,
+                        &Wrap(dtype.clone()),
+                        pat.as_str(),
+                    )
+                        .into_py_any(py),
                     #[cfg(feature = "regex")]
                     StringFunction::Find { literal, strict } => {
                         (PyStringFunction::Find, literal, strict).into_py_any(py)
@@ -794,6 +820,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::LenBytes => (PyStringFunction::LenBytes,).into_py_any(py),
                     StringFunction::LenChars => (PyStringFunction::LenChars,).into_py_any(py),
                     StringFunction::Lowercase => (PyStringFunction::Lowercase,).into_py_any(py),
+                    StringFunction::Casefold => (PyStringFunction::Casefold,).into_py_any(py),
                     #[cfg(feature = "extract_jsonpath")]
                     StringFunction::JsonDecode {
                         dtype: _,
@@ -817,7 +844,27 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         },
                     )
                         .into_py_any(py),
+                    StringFunction::StripDiacritics => {
+                        (PyStringFunction::StripDiacritics,).into_py_any(py)
+                    },
                     StringFunction::Reverse => (PyStringFunction::Reverse,).into_py_any(py),
+                    StringFunction::Levenshtein { max_distance } => {
+                        (PyStringFunction::Levenshtein, max_distance).into_py_any(py)
+                    },
+                    StringFunction::Hamming { max_distance } => {
+                        (PyStringFunction::Hamming, max_distance).into_py_any(py)
+                    },
+                    StringFunction::JaroWinkler => (PyStringFunction::JaroWinkler,).into_py_any(py),
+                    StringFunction::TokenSetRatio => {
+                        (PyStringFunction::TokenSetRatio,).into_py_any(py)
+                    },
+                    StringFunction::UrlScheme => (PyStringFunction::UrlScheme,).into_py_any(py),
+                    StringFunction::UrlHost => (PyStringFunction::UrlHost,).into_py_any(py),
+                    StringFunction::UrlPath => (PyStringFunction::UrlPath,).into_py_any(py),
+                    StringFunction::UrlQueryParam(key) => {
+                        (PyStringFunction::UrlQueryParam, key.as_str()).into_py_any(py)
+                    },
+                    StringFunction::UrlDecode => (PyStringFunction::UrlDecode,).into_py_any(py),
                     StringFunction::PadStart { length, fill_char } => {
                         (PyStringFunction::PadStart, length, fill_char).into_py_any(py)
                     },
@@ -839,6 +886,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::Base64Decode(strict) => {
                         (PyStringFunction::Base64Decode, strict).into_py_any(py)
                     },
+                    #[cfg(feature = "binary_encoding")]
+                    StringFunction::ToUuid(strict) => {
+                        (PyStringFunction::ToUuid, strict).into_py_any(py)
+                    },
+                    #[cfg(feature = "binary_encoding")]
+                    StringFunction::ToIp(strict) => {
+                        (PyStringFunction::ToIp, strict).into_py_any(py)
+                    },
                     StringFunction::StartsWith => (PyStringFunction::StartsWith,).into_py_any(py),
                     StringFunction::StripChars => (PyStringFunction::StripChars,).into_py_any(py),
                     StringFunction::StripCharsStart => {
@@ -861,12 +916,32 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         options.cache,
                     )
                         .into_py_any(py),
+                    StringFunction::StrptimeFormats {
+                        time_unit,
+                        time_zone,
+                        formats,
+                        strict,
+                        cache,
+                    } => (
+                        PyStringFunction::StrptimeFormats,
+                        Wrap(*time_unit),
+                        time_zone.as_ref().map(|s| s.as_str()),
+                        formats
+                            .as_ref()
+                            .map(|f| f.iter().map(|s| s.as_str()).collect::<Vec<_>>()),
+                        strict,
+                        cache,
+                    )
+                        .into_py_any(py),
                     StringFunction::Split(inclusive) => {
                         (PyStringFunction::Split, inclusive).into_py_any(py)
                     },
                     StringFunction::ToDecimal(inference_length) => {
                         (PyStringFunction::ToDecimal, inference_length).into_py_any(py)
                     },
+                    StringFunction::ToDuration { time_unit, strict } => {
+                        (PyStringFunction::ToDuration, Wrap(*time_unit), strict).into_py_any(py)
+                    },
                     #[cfg(feature = "nightly")]
                     StringFunction::Titlecase => (PyStringFunction::Titlecase,).into_py_any(py),
                     StringFunction::Uppercase => (PyStringFunction::Uppercase,).into_py_any(py),
@@ -964,8 +1039,20 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     TemporalFunction::TimeStamp(time_unit) => {
                         (PyTemporalFunction::TimeStamp, Wrap(*time_unit)).into_py_any(py)
                     },
-                    TemporalFunction::Truncate => (PyTemporalFunction::Truncate,).into_py_any(py),
-                    TemporalFunction::OffsetBy => (PyTemporalFunction::OffsetBy,).into_py_any(py),
+                    TemporalFunction::Truncate {
+                        week_start,
+                        non_existent,
+                    } => (
+                        PyTemporalFunction::Truncate,
+                        week_start,
+                        non_existent.map(Into::<&str>::into),
+                    )
+                        .into_py_any(py),
+                    TemporalFunction::OffsetBy(non_existent) => (
+                        PyTemporalFunction::OffsetBy,
+                        non_existent.map(Into::<&str>::into),
+                    )
+                        .into_py_any(py),
                     TemporalFunction::MonthStart => {
                         (PyTemporalFunction::MonthStart,).into_py_any(py)
                     },
@@ -976,7 +1063,11 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     },
                     #[cfg(feature = "timezones")]
                     TemporalFunction::DSTOffset => (PyTemporalFunction::DSTOffset,).into_py_any(py),
-                    TemporalFunction::Round => (PyTemporalFunction::Round,).into_py_any(py),
+                    TemporalFunction::Round(non_existent) => (
+                        PyTemporalFunction::Round,
+                        non_existent.map(Into::<&str>::into),
+                    )
+                        .into_py_any(py),
                     TemporalFunction::Replace => (PyTemporalFunction::Replace).into_py_any(py),
                     #[cfg(feature = "timezones")]
                     TemporalFunction::ReplaceTimeZone(time_zone, non_existent) => (
@@ -1052,6 +1143,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Hash(seed, seed_1, seed_2, seed_3) => {
                     ("hash", seed, seed_1, seed_2, seed_3).into_py_any(py)
                 },
+                FunctionExpr::StableHash(algorithm, seed, seed_1) => (
+                    "stable_hash",
+                    match algorithm {
+                        HashAlgorithm::XxHash64 => "xxhash64",
+                        HashAlgorithm::WyHash => "wyhash",
+                        HashAlgorithm::SipHash13 => "siphash13",
+                        HashAlgorithm::Sha256 => "sha256",
+                    },
+                    seed,
+                    seed_1,
+                )
+                    .into_py_any(py),
                 FunctionExpr::ArgWhere => ("argwhere",).into_py_any(py),
                 #[cfg(feature = "index_of")]
                 FunctionExpr::IndexOf => ("index_of",).into_py_any(py),
@@ -1119,9 +1222,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     RollingFunction::Skew(_, _) => {
                         return Err(PyNotImplementedError::new_err("rolling skew"));
                     },
+                    RollingFunction::Kurtosis(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling kurtosis"));
+                    },
+                    RollingFunction::Rank(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling rank"));
+                    },
                     RollingFunction::CorrCov { .. } => {
                         return Err(PyNotImplementedError::new_err("rolling cor_cov"));
                     },
+                    RollingFunction::Ols { .. } => {
+                        return Err(PyNotImplementedError::new_err("rolling ols"));
+                    },
                 },
                 FunctionExpr::RollingExprBy(rolling) => match rolling {
                     RollingFunctionBy::MinBy(_) => {
@@ -1145,6 +1257,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     RollingFunctionBy::StdBy(_) => {
                         return Err(PyNotImplementedError::new_err("rolling std by"));
                     },
+                    RollingFunctionBy::CorrCovBy { .. } => {
+                        return Err(PyNotImplementedError::new_err("rolling cor_cov by"));
+                    },
                 },
                 FunctionExpr::ShiftAndFill => ("shift_and_fill",).into_py_any(py),
                 FunctionExpr::Shift => ("shift",).into_py_any(py),
@@ -1170,7 +1285,13 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::TopK { descending } => ("top_k", descending).into_py_any(py),
                 FunctionExpr::CumCount { reverse } => ("cum_count", reverse).into_py_any(py),
                 FunctionExpr::CumSum { reverse } => ("cum_sum", reverse).into_py_any(py),
-                FunctionExpr::CumProd { reverse } => ("cum_prod", reverse).into_py_any(py),
+                FunctionExpr::CumProd {
+                    reverse,
+                    log_domain: false,
+                } => ("cum_prod", reverse).into_py_any(py),
+                FunctionExpr::CumProd {
+                    log_domain: true, ..
+                } => return Err(PyNotImplementedError::new_err("cum_prod in the log domain")),
                 FunctionExpr::CumMin { reverse } => ("cum_min", reverse).into_py_any(py),
                 FunctionExpr::CumMax { reverse } => ("cum_max", reverse).into_py_any(py),
                 FunctionExpr::Reverse => ("reverse",).into_py_any(py),
@@ -1202,7 +1323,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     },
                 )
                     .into_py_any(py),
-                FunctionExpr::InterpolateBy => ("interpolate_by",).into_py_any(py),
+                FunctionExpr::InterpolateBy(method) => (
+                    "interpolate_by",
+                    match method {
+                        InterpolationMethod::Linear => "linear",
+                        InterpolationMethod::Nearest => "nearest",
+                    },
+                )
+                    .into_py_any(py),
                 FunctionExpr::Entropy { base, normalize } => {
                     ("entropy", base, normalize).into_py_any(py)
                 },
@@ -1217,12 +1345,16 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::UpperBound => ("upper_bound",).into_py_any(py),
                 FunctionExpr::LowerBound => ("lower_bound",).into_py_any(py),
                 FunctionExpr::Fused(_) => return Err(PyNotImplementedError::new_err("fused")),
+                FunctionExpr::DecimalArithmetic { .. } => {
+                    return Err(PyNotImplementedError::new_err("decimal arithmetic"));
+                },
                 FunctionExpr::ConcatExpr(_) => {
                     return Err(PyNotImplementedError::new_err("concat expr"));
                 },
                 FunctionExpr::Correlation { .. } => {
                     return Err(PyNotImplementedError::new_err("corr"));
                 },
+                FunctionExpr::Lstsq(_) => return Err(PyNotImplementedError::new_err("lstsq")),
                 #[cfg(feature = "peaks")]
                 FunctionExpr::PeakMin => ("peak_max",).into_py_any(py),
                 #[cfg(feature = "peaks")]
@@ -1239,6 +1371,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Random { .. } => {
                     return Err(PyNotImplementedError::new_err("random"));
                 },
+                FunctionExpr::Assert { message } => ("assert", message.as_str()).into_py_any(py),
                 FunctionExpr::SetSortedFlag(sorted) => (
                     "set_sorted",
                     match sorted {
@@ -1252,6 +1385,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::FfiPlugin { .. } => {
                     return Err(PyNotImplementedError::new_err("ffi plugin"));
                 },
+                FunctionExpr::StaticPlugin { .. } => {
+                    return Err(PyNotImplementedError::new_err("static plugin"));
+                },
                 FunctionExpr::SumHorizontal { ignore_nulls } => {
                     ("sum_horizontal", ignore_nulls).into_py_any(py)
                 },
@@ -1260,6 +1396,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     ("mean_horizontal", ignore_nulls).into_py_any(py)
                 },
                 FunctionExpr::MinHorizontal => ("min_horizontal",).into_py_any(py),
+                FunctionExpr::CumSumHorizontal { ignore_nulls } => {
+                    ("cum_sum_horizontal", ignore_nulls).into_py_any(py)
+                },
+                FunctionExpr::RankHorizontal { options: _ } => {
+                    return Err(PyNotImplementedError::new_err("rank horizontal"));
+                },
                 FunctionExpr::EwmMean { options: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm mean"));
                 },
@@ -1291,6 +1433,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::EwmMeanBy { half_life: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm_mean_by"));
                 },
+                FunctionExpr::EwmStdBy { half_life: _ } => {
+                    return Err(PyNotImplementedError::new_err("ewm_std_by"));
+                },
+                FunctionExpr::EwmVarBy { half_life: _ } => {
+                    return Err(PyNotImplementedError::new_err("ewm_var_by"));
+                },
             }?,
             options: py.None(),
         }
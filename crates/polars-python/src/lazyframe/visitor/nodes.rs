@@ -573,6 +573,13 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                 )
                     .into_py_any(py)?,
                 FunctionIR::Rechunk => ("rechunk",).into_py_any(py)?,
+                FunctionIR::ReservoirSample { n, by, seed } => (
+                    "reservoir_sample",
+                    *n,
+                    by.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+                    *seed,
+                )
+                    .into_py_any(py)?,
                 FunctionIR::Rename {
                     existing,
                     new,
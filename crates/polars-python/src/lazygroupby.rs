@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use polars::lazy::frame::{LazyFrame, LazyGroupBy};
-use polars::prelude::{DataFrame, PolarsError, Schema};
+use polars::prelude::{DataFrame, IdxSize, PolarsError, Schema, UpsampleFill};
 use pyo3::prelude::*;
 
 use crate::conversion::Wrap;
@@ -25,6 +25,17 @@ impl PyLazyGroupBy {
         lgb.agg(aggs).into()
     }
 
+    fn agg_fill_gaps(
+        &mut self,
+        aggs: Vec<PyExpr>,
+        fill: Wrap<UpsampleFill>,
+    ) -> PyResult<PyLazyFrame> {
+        let lgb = self.lgb.clone().unwrap();
+        let aggs = aggs.to_exprs();
+        let out = lgb.agg_fill_gaps(aggs, fill.0).map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
     fn head(&mut self, n: usize) -> PyLazyFrame {
         let lgb = self.lgb.clone().unwrap();
         lgb.head(Some(n)).into()
@@ -35,6 +46,18 @@ impl PyLazyGroupBy {
         lgb.tail(Some(n)).into()
     }
 
+    #[cfg(feature = "top_k")]
+    fn top_k_by(&mut self, by: Vec<PyExpr>, k: IdxSize, reverse: Vec<bool>) -> PyLazyFrame {
+        let lgb = self.lgb.clone().unwrap();
+        lgb.top_k_by(k, by.to_exprs(), reverse).into()
+    }
+
+    #[cfg(feature = "top_k")]
+    fn bottom_k_by(&mut self, by: Vec<PyExpr>, k: IdxSize, reverse: Vec<bool>) -> PyLazyFrame {
+        let lgb = self.lgb.clone().unwrap();
+        lgb.bottom_k_by(k, by.to_exprs(), reverse).into()
+    }
+
     #[pyo3(signature = (lambda, schema=None))]
     fn map_groups(
         &mut self,
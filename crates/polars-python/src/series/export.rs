@@ -4,6 +4,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyCapsule, PyList};
 
 use super::PySeries;
+use crate::conversion::to_py_uuid;
 use crate::error::PyPolarsErr;
 use crate::interop;
 use crate::interop::arrow::to_py::series_to_stream;
@@ -130,6 +131,22 @@ impl PySeries {
 
                     PyList::new(py, NullIter { iter, n })?
                 },
+                DataType::Extension(_, _, _) => {
+                    let storage = series.extension().map_err(PyPolarsErr::from)?.storage();
+                    return to_list_recursive(py, storage);
+                },
+                DataType::Uuid => {
+                    let storage = series.extension().map_err(PyPolarsErr::from)?.storage();
+                    let ca = storage.binary().map_err(PyPolarsErr::from)?;
+                    let values = ca
+                        .iter()
+                        .map(|opt_b| match opt_b {
+                            Some(b) => to_py_uuid(py, b),
+                            None => Ok(py.None().into_bound(py)),
+                        })
+                        .collect::<PyResult<Vec<_>>>()?;
+                    PyList::new(py, values)?
+                },
                 DataType::Unknown(_) => {
                     panic!("to_list not implemented for unknown")
                 },
@@ -9,6 +9,7 @@ use pyo3::{IntoPyObjectExt, Python};
 
 use self::row_encode::get_row_encoding_context;
 use super::PySeries;
+use crate::conversion::to_py_uuid;
 use crate::dataframe::PyDataFrame;
 use crate::error::PyPolarsErr;
 use crate::prelude::*;
@@ -131,6 +132,9 @@ impl PySeries {
                 let pyseries = PySeries::new(s);
                 polars(py).getattr(py, "wrap_s")?.call1(py, (pyseries,))
             },
+            AnyValue::Binary(b) if self.series.dtype() == &DataType::Uuid => {
+                to_py_uuid(py, b)?.into_py_any(py)
+            },
             _ => Wrap(av).into_py_any(py),
         }
     }
@@ -231,13 +235,15 @@ impl PySeries {
         descending: bool,
         nulls_last: bool,
         multithreaded: bool,
+        nan_position: Wrap<NanPosition>,
     ) -> PyResult<Self> {
         py.enter_polars_series(|| {
             self.series.sort(
                 SortOptions::default()
                     .with_order_descending(descending)
                     .with_nulls_last(nulls_last)
-                    .with_multithreaded(multithreaded),
+                    .with_multithreaded(multithreaded)
+                    .with_nan_position(nan_position.0),
             )
         })
     }
@@ -412,6 +418,7 @@ impl PySeries {
             multithreaded: true,
             maintain_order: false,
             limit: None,
+            nan_position: NanPosition::Largest,
         };
         py.enter_polars(|| self.series.is_sorted(options))
     }
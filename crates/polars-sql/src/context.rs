@@ -1,28 +1,40 @@
 use std::cell::RefCell;
 use std::ops::Deref;
+use std::path::PathBuf;
 
 use polars_core::frame::row::Row;
 use polars_core::prelude::*;
+use polars_lazy::catalog::{Catalog, CatalogTable};
 use polars_lazy::prelude::*;
-use polars_ops::frame::JoinCoalesce;
+use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
-    OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    BinaryOperator, CopyOption, CopySource, CopyTarget, CreateTable, Cte, Delete, Distinct,
+    ExcludeSelectItem, Expr as SQLExpr, FromTable, FunctionArg, GroupByExpr, Ident, Insert, Join,
+    JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset, OrderBy, Query, RenameSelectItem,
+    Select, SelectItem, SetExpr, SetOperator, SetQuantifier, Statement, TableAlias, TableFactor,
+    TableWithJoins, UnaryOperator, Value as SQLValue, Values, WildcardAdditionalOptions,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
+use crate::dialect::Dialect;
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::prepared::{SQLParams, SQLPreparedStatement};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
 use crate::table_functions::PolarsTableFunctions;
+use crate::types::map_polars_dtype_to_sql;
+use crate::user_functions::{SQLAggregateFunction, SQLScalarFunction, UserFunction};
+
+const QUALIFY_NAME: &str = "__POLARS_QUALIFY";
+/// The prefix of the name of the row index of the outer frame of a correlated subquery.
+const OUTER_INDEX: &str = "__POLARS_OUTER_IDX";
+/// The name the outer frame of a correlated subquery is registered under.
+pub(crate) const OUTER_FRAME: &str = "__POLARS_OUTER";
 
 #[derive(Clone)]
 pub struct TableInfo {
@@ -57,8 +69,13 @@ impl SelectModifiers {
 pub struct SQLContext {
     pub(crate) table_map: PlHashMap<String, LazyFrame>,
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
+    pub(crate) user_functions: PlHashMap<String, UserFunction>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
+    pub(crate) max_recursive_iterations: usize,
+    pub(crate) params: SQLParams,
+    pub(crate) dialect: Dialect,
+    pub(crate) catalog: Option<Arc<dyn Catalog>>,
 
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
@@ -69,12 +86,17 @@ impl Default for SQLContext {
     fn default() -> Self {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
+            user_functions: Default::default(),
             table_map: Default::default(),
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
+            max_recursive_iterations: 1000,
+            params: Default::default(),
+            dialect: Default::default(),
+            catalog: None,
         }
     }
 }
@@ -141,20 +163,55 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
-        parser = parser.with_options(ParserOptions {
-            trailing_commas: true,
-            ..Default::default()
-        });
+        let statement = parse_statement(query, self.dialect)?;
+        self.execute_parsed(&statement)
+    }
 
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
-            .parse_statements()
-            .map_err(to_sql_interface_err)?;
+    /// Parse a SQL statement with placeholders, to execute it with
+    /// [`execute_prepared`](Self::execute_prepared).
+    /// ```rust
+    /// # use polars_sql::{SQLContext, SQLParams};
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! {
+    ///    "a" =>  [1, 2, 3],
+    /// }
+    /// .unwrap();
+    ///
+    /// ctx.register("df", df.lazy());
+    /// let stmt = ctx.prepare("SELECT * FROM df WHERE a > $1").unwrap();
+    /// let sql_df = ctx
+    ///     .execute_prepared(&stmt, &SQLParams::new().with_value(1))
+    ///     .unwrap()
+    ///     .collect()
+    ///     .unwrap();
+    /// assert_eq!(sql_df.height(), 2);
+    /// # }
+    ///```
+    pub fn prepare(&self, query: &str) -> PolarsResult<SQLPreparedStatement> {
+        Ok(SQLPreparedStatement {
+            statement: parse_statement(query, self.dialect)?,
+        })
+    }
 
-        polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
+    /// Execute a prepared statement, returning a [`LazyFrame`]. Its placeholders are bound to
+    /// the literal values of `params`.
+    pub fn execute_prepared(
+        &mut self,
+        statement: &SQLPreparedStatement,
+        params: &SQLParams,
+    ) -> PolarsResult<LazyFrame> {
+        self.params = params.clone();
+        let res = self.execute_parsed(&statement.statement);
+        self.params = Default::default();
+        res
+    }
+
+    fn execute_parsed(&mut self, statement: &Statement) -> PolarsResult<LazyFrame> {
+        let res = self.execute_statement(statement)?;
 
         // Ensure the result uses the proper arenas.
         // This will instantiate new arenas with a new version.
@@ -177,6 +234,66 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of times the recursive part of a `WITH RECURSIVE` CTE is
+    /// evaluated; a query that needs more iterations fails. The default is 1000.
+    pub fn with_max_recursive_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_recursive_iterations = max_iterations;
+        self
+    }
+
+    /// Set the SQL dialect that queries are parsed and translated with.
+    /// ```rust
+    /// # use polars_sql::{Dialect, SQLContext};
+    /// # fn main() {
+    /// let ctx = SQLContext::new().with_dialect(Dialect::Postgres);
+    /// # }
+    /// ```
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Resolve the tables that are not registered with the context, e.g. `sales.orders`, from
+    /// `catalog`.
+    pub fn with_catalog(mut self, catalog: Arc<dyn Catalog>) -> Self {
+        self.catalog = Some(catalog);
+        self
+    }
+
+    /// Register a scalar function, which can then be called from SQL by `name` (which is
+    /// case-insensitive). The built-in functions take precedence over a function of the same
+    /// name.
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # use polars_sql::{SQLContext, SQLScalarFunction};
+    /// # fn main() -> PolarsResult<()> {
+    /// let mut ctx = SQLContext::new();
+    /// ctx.register("df", df! { "a" => [1, 2, 3] }?.lazy());
+    /// ctx.register_function("double", SQLScalarFunction::new(|args: &[Series]| Ok(&args[0] * 2)));
+    /// let df = ctx.execute("SELECT double(a) AS a2 FROM df")?.collect()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_function(&mut self, name: &str, function: SQLScalarFunction) {
+        self.user_functions
+            .insert(name.to_lowercase(), UserFunction::Scalar(function));
+    }
+
+    /// Register an aggregate function, which can then be called from SQL by `name` (which is
+    /// case-insensitive), on the groups of a `GROUP BY` or on all rows of a query without one.
+    /// The built-in functions take precedence over a function of the same name.
+    pub fn register_aggregate_function(&mut self, name: &str, function: SQLAggregateFunction) {
+        self.user_functions
+            .insert(name.to_lowercase(), UserFunction::Aggregate(function));
+    }
+
+    /// Remove a function registered with [`register_function`](Self::register_function) or
+    /// [`register_aggregate_function`](Self::register_aggregate_function).
+    pub fn unregister_function(&mut self, name: &str) {
+        self.user_functions.remove(&name.to_lowercase());
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -194,12 +311,15 @@ impl SQLContext {
         Ok(match ast {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
+            stmt @ Statement::Copy { .. } => self.execute_copy(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
             stmt @ Statement::Drop {
                 object_type: ObjectType::Table,
                 ..
             } => self.execute_drop_table(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
+            stmt @ Statement::ExplainTable { .. } => self.execute_describe_table(stmt)?,
+            stmt @ Statement::Insert { .. } => self.execute_insert_into(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
             _ => polars_bail!(
@@ -234,6 +354,24 @@ impl SQLContext {
             })
     }
 
+    /// Resolve the table `name` from the catalog, unless it is a table of the context.
+    fn get_catalog_table(&self, name: &[Ident]) -> PolarsResult<Option<LazyFrame>> {
+        let Some(catalog) = &self.catalog else {
+            return Ok(None);
+        };
+        if let [name] = name {
+            if self.get_table_from_current_scope(&name.value).is_some() {
+                return Ok(None);
+            }
+        }
+        let name = name
+            .iter()
+            .map(|ident| ident.value.as_str())
+            .collect::<Vec<_>>()
+            .join(".");
+        Ok(catalog.resolve(&name)?.map(CatalogTable::into_lazy))
+    }
+
     fn expr_or_ordinal(
         &mut self,
         e: &SQLExpr,
@@ -483,6 +621,73 @@ impl SQLContext {
         Ok(df.lazy())
     }
 
+    // DESCRIBE <tbl>
+    fn execute_describe_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        match stmt {
+            Statement::ExplainTable { table_name, .. } => {
+                let mut lf = match table_name.0.as_slice() {
+                    [schema, tbl_name]
+                        if schema.value.eq_ignore_ascii_case("information_schema") =>
+                    {
+                        self.get_information_schema_table(&tbl_name.value)?
+                    },
+                    _ => {
+                        let tbl_name = table_name.0.first().unwrap().value.as_str();
+                        self.get_table_from_current_scope(tbl_name).ok_or_else(
+                            || polars_err!(SQLInterface: "relation '{}' was not found", tbl_name),
+                        )?
+                    },
+                };
+                let schema = self.get_frame_schema(&mut lf)?;
+                let columns: Vec<_> = schema.iter_names().map(|name| name.as_str()).collect();
+                let dtypes: Vec<_> = schema.iter_values().map(map_polars_dtype_to_sql).collect();
+                let df = df! {
+                    "column_name" => columns,
+                    "data_type" => dtypes,
+                }?;
+                Ok(df.lazy())
+            },
+            _ => polars_bail!(SQLInterface: "unexpected statement type; expected DESCRIBE"),
+        }
+    }
+
+    /// The virtual tables `information_schema.tables` and `information_schema.columns`, which
+    /// describe the registered tables.
+    fn get_information_schema_table(&mut self, name: &str) -> PolarsResult<LazyFrame> {
+        let table_names = self.get_tables();
+        let df = match name.to_lowercase().as_str() {
+            "tables" => df! {
+                "table_name" => table_names.clone(),
+                "table_type" => vec!["BASE TABLE"; table_names.len()],
+            }?,
+            "columns" => {
+                let mut tables = vec![];
+                let mut columns = vec![];
+                let mut positions = vec![];
+                let mut dtypes = vec![];
+                for table_name in &table_names {
+                    let mut lf = self.table_map.get(table_name).unwrap().clone();
+                    let schema = self.get_frame_schema(&mut lf)?;
+                    for (i, (name, dtype)) in schema.iter().enumerate() {
+                        tables.push(table_name.as_str());
+                        columns.push(name.to_string());
+                        positions.push(i as i64 + 1);
+                        dtypes.push(map_polars_dtype_to_sql(dtype));
+                    }
+                }
+                df! {
+                    "table_name" => tables,
+                    "column_name" => columns,
+                    "ordinal_position" => positions,
+                    "data_type" => dtypes,
+                    "is_nullable" => vec!["YES"; positions.len()],
+                }?
+            },
+            _ => polars_bail!(SQLInterface: "relation 'information_schema.{}' was not found", name),
+        };
+        Ok(df.lazy())
+    }
+
     // DROP TABLE <tbl>
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
@@ -594,12 +799,19 @@ impl SQLContext {
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
+                let mut lf = match &*cte.query.body {
+                    SetExpr::SetOperation {
+                        op: SetOperator::Union,
+                        set_quantifier,
+                        left,
+                        right,
+                    } if with.recursive && references_table(right, &cte_name) => {
+                        self.execute_recursive_cte(cte, left, right, set_quantifier)?
+                    },
+                    _ => self.execute_query(&cte.query)?,
+                };
                 lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
                 self.register_cte(&cte_name, lf);
             }
@@ -607,11 +819,93 @@ impl SQLContext {
         Ok(())
     }
 
+    /// Evaluate a recursive CTE, `<anchor> UNION [ALL] <recursive part>`.
+    ///
+    /// The recursive part is evaluated with the CTE bound to the rows of the previous
+    /// evaluation (starting with the anchor), until it gives no new rows. Every evaluation is
+    /// collected, so the CTE is materialised when the query is translated.
+    fn execute_recursive_cte(
+        &mut self,
+        cte: &Cte,
+        anchor: &SetExpr,
+        recursive: &SetExpr,
+        quantifier: &SetQuantifier,
+    ) -> PolarsResult<LazyFrame> {
+        let cte_name = cte.alias.name.value.as_str();
+        let distinct = match quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => polars_bail!(
+                SQLInterface: "'UNION {}' is not supported in recursive CTEs", quantifier
+            ),
+        };
+        let lf = self.process_query(anchor, &cte.query)?;
+        let mut working = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+        if distinct {
+            working = working.unique_stable(None, UniqueKeepStrategy::First);
+        }
+        let mut working = working.collect()?;
+        let schema = working.schema().clone();
+
+        let mut results = vec![working.clone()];
+        let mut iterations = 0;
+        while working.height() > 0 {
+            polars_ensure!(
+                iterations < self.max_recursive_iterations,
+                SQLInterface: "recursive CTE '{}' did not finish within {} iterations",
+                cte_name, self.max_recursive_iterations
+            );
+            iterations += 1;
+
+            self.register_cte(cte_name, working.lazy());
+            let mut lf = self.process_query(recursive, &cte.query)?;
+            let lf_schema = self.get_frame_schema(&mut lf)?;
+            polars_ensure!(
+                lf_schema.len() == schema.len(),
+                SQLInterface: "the recursive part of CTE '{}' must have the same number of columns as its anchor",
+                cte_name
+            );
+            // The columns of the recursive part take the names and types of the anchor.
+            let exprs = lf_schema
+                .iter_names()
+                .zip(schema.iter())
+                .map(|(name, (anchor_name, dtype))| {
+                    col(name.clone())
+                        .cast(dtype.clone())
+                        .alias(anchor_name.clone())
+                })
+                .collect::<Vec<_>>();
+            let lf = lf.select(exprs);
+
+            working = if distinct {
+                // Only keep the rows that were not found before.
+                let all = results.pop().unwrap();
+                let n_rows = all.height();
+                let all = polars_lazy::dsl::concat(vec![all.lazy(), lf], UnionArgs::default())?
+                    .unique_stable(None, UniqueKeepStrategy::First)
+                    .collect()?;
+                let new_rows = all.slice(n_rows as i64, all.height() - n_rows);
+                results.push(all);
+                new_rows
+            } else {
+                let new_rows = lf.collect()?;
+                results.push(new_rows.clone());
+                new_rows
+            };
+        }
+        let frames = results.into_iter().map(|df| df.lazy()).collect::<Vec<_>>();
+        polars_lazy::dsl::concat(frames, UnionArgs::default())
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
         if !tbl_expr.joins.is_empty() {
             for join in &tbl_expr.joins {
+                if let Some(joined) = self.process_lateral_join(&l_name, lf.clone(), join)? {
+                    lf = joined;
+                    continue;
+                }
                 let (r_name, mut rf) = self.get_table(&join.relation)?;
                 if r_name.is_empty() {
                     // Require non-empty to avoid duplicate column errors from nested self-joins.
@@ -704,6 +998,282 @@ impl SQLContext {
         Ok(lf)
     }
 
+    /// Join a relation that refers to the columns of the relations before it: an `UNNEST` of
+    /// list or struct columns, or a `LATERAL` subquery. Returns `None` for other relations.
+    fn process_lateral_join(
+        &mut self,
+        l_name: &str,
+        lf: LazyFrame,
+        join: &Join,
+    ) -> PolarsResult<Option<LazyFrame>> {
+        let is_lateral = match &join.relation {
+            TableFactor::UNNEST { array_exprs, .. } => {
+                !array_exprs.iter().all(|e| matches!(e, SQLExpr::Array(_)))
+            },
+            TableFactor::Derived { lateral, .. } => *lateral,
+            _ => false,
+        };
+        if !is_lateral {
+            return Ok(None);
+        }
+        let is_unconstrained = |constraint: &JoinConstraint| {
+            matches!(
+                constraint,
+                JoinConstraint::None | JoinConstraint::On(SQLExpr::Value(SQLValue::Boolean(true)))
+            )
+        };
+        let join_type = match &join.join_operator {
+            JoinOperator::CrossJoin => JoinType::Inner,
+            JoinOperator::Inner(c) if is_unconstrained(c) => JoinType::Inner,
+            JoinOperator::LeftOuter(c) if is_unconstrained(c) => JoinType::Left,
+            _ => polars_bail!(
+                SQLInterface:
+                "lateral joins must be CROSS JOIN, or INNER/LEFT JOIN ... ON TRUE"
+            ),
+        };
+        match &join.relation {
+            TableFactor::UNNEST {
+                alias,
+                array_exprs,
+                with_offset,
+                with_ordinality,
+                ..
+            } => {
+                polars_ensure!(
+                    !(*with_offset || *with_ordinality),
+                    SQLInterface: "UNNEST of columns does not (yet) support WITH ORDINALITY|OFFSET"
+                );
+                self.process_unnest_columns(lf, alias.as_ref(), array_exprs, join_type)
+                    .map(Some)
+            },
+            TableFactor::Derived {
+                subquery, alias, ..
+            } => {
+                let Some(alias) = alias else {
+                    polars_bail!(SQLSyntax: "LATERAL subqueries must have aliases");
+                };
+                self.process_lateral_subquery(l_name, lf, subquery, alias, join_type)
+                    .map(Some)
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Flatten the list columns given to `UNNEST` into one row per element, and the struct
+    /// columns into one column per field.
+    fn process_unnest_columns(
+        &mut self,
+        mut lf: LazyFrame,
+        alias: Option<&TableAlias>,
+        array_exprs: &[SQLExpr],
+        join_type: JoinType,
+    ) -> PolarsResult<LazyFrame> {
+        let column_names: Vec<PlSmallStr> = match alias {
+            Some(alias) if !alias.columns.is_empty() => {
+                polars_ensure!(
+                    alias.columns.len() == array_exprs.len(),
+                    SQLSyntax: "UNNEST table alias requires {} column names, found {}",
+                    array_exprs.len(), alias.columns.len()
+                );
+                alias
+                    .columns
+                    .iter()
+                    .map(|c| c.name.value.as_str().into())
+                    .collect()
+            },
+            Some(alias) if array_exprs.len() == 1 => vec![alias.name.value.as_str().into()],
+            None if array_exprs.len() == 1 => vec![PlSmallStr::from_static("unnest")],
+            _ => polars_bail!(
+                SQLSyntax:
+                "UNNEST of multiple columns requires a table alias with column names, eg: u (a,b)"
+            ),
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        let exprs = array_exprs
+            .iter()
+            .zip(&column_names)
+            .map(|(e, name)| Ok(parse_sql_expr(e, self, Some(&schema))?.alias(name.clone())))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        lf = lf.with_columns(exprs);
+
+        let schema = self.get_frame_schema(&mut lf)?;
+        let (mut lists, mut structs) = (vec![], vec![]);
+        for name in &column_names {
+            match schema.get(name) {
+                Some(DataType::List(_)) => lists.push(name.clone()),
+                Some(DataType::Struct(_)) => structs.push(name.clone()),
+                dtype => polars_bail!(
+                    SQLInterface:
+                    "UNNEST expects list or struct columns; found {:?} for '{}'",
+                    dtype, name
+                ),
+            }
+        }
+        if !lists.is_empty() {
+            if join_type == JoinType::Inner {
+                // rows without any elements have no match in an inner join
+                let has_elements = lists
+                    .iter()
+                    .map(|name| col(name.clone()).list().len().gt(lit(0)))
+                    .reduce(|acc, e| acc.or(e))
+                    .unwrap();
+                lf = lf.filter(has_elements);
+            }
+            lf = lf.explode(lists);
+        }
+        if !structs.is_empty() {
+            lf = lf.unnest(structs);
+        }
+        if let Some(alias) = alias {
+            self.register_cte(&alias.name.value, lf.clone());
+        }
+        Ok(lf)
+    }
+
+    /// Evaluate a `LATERAL` subquery once for all rows of the relations before it, and join
+    /// the result back to the outer frame on the outer row index.
+    fn process_lateral_subquery(
+        &mut self,
+        l_name: &str,
+        mut lf: LazyFrame,
+        subquery: &Query,
+        alias: &TableAlias,
+        join_type: JoinType,
+    ) -> PolarsResult<LazyFrame> {
+        let outer_name = l_name.split_whitespace().next().unwrap_or_default();
+        let index = outer_index_name(&self.get_frame_schema(&mut lf)?);
+        let mut lf = lf.with_row_index(index.clone(), None);
+        let rf = self.execute_correlated_subquery(outer_name, lf.clone(), subquery, &index, false);
+        let mut rf = self.rename_columns_from_table_alias(rf?, alias)?;
+
+        let r_name = alias.name.value.clone();
+        self.table_map
+            .insert(r_name.clone(), rf.clone().drop([index.clone()]));
+        let left_schema = self.get_frame_schema(&mut lf)?;
+        let right_schema = self.get_frame_schema(&mut rf)?;
+        let mut lf = lf
+            .join_builder()
+            .with(rf)
+            .left_on([col(index.clone())])
+            .right_on([col(index.clone())])
+            .how(join_type)
+            .suffix(format!(":{}", r_name))
+            .maintain_order(MaintainOrderJoin::Left)
+            .finish()
+            .drop([index]);
+
+        // track join-aliased columns so we can resolve them later
+        let joined_schema = self.get_frame_schema(&mut lf)?;
+        self.joined_aliases.borrow_mut().insert(
+            r_name.clone(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    let aliased_name = format!("{}:{}", name, r_name);
+                    (left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()))
+                        .then(|| (name.to_string(), aliased_name))
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+        Ok(lf)
+    }
+
+    /// Execute `subquery`, which refers to the columns of the `outer` frame by the name
+    /// `outer_name`, for all rows of `outer` at once.
+    ///
+    /// The subquery is decorrelated: `outer` (which must have a row index column named `index`,
+    /// see [`outer_index_name`]) is cross joined into its `FROM` clause, and the index is added
+    /// to its projection (and, if `group_by_index` or the subquery has a `GROUP BY`, to its
+    /// grouping keys), so that every result row is tagged with the index of the outer row that
+    /// it belongs to.
+    pub(crate) fn execute_correlated_subquery(
+        &mut self,
+        outer_name: &str,
+        outer: LazyFrame,
+        subquery: &Query,
+        index: &str,
+        group_by_index: bool,
+    ) -> PolarsResult<LazyFrame> {
+        let mut subquery = subquery.clone();
+        polars_ensure!(
+            subquery.limit.is_none() && subquery.offset.is_none() && subquery.fetch.is_none(),
+            SQLInterface: "correlated subqueries do not (yet) support LIMIT, OFFSET or FETCH"
+        );
+        let SetExpr::Select(select) = subquery.body.as_mut() else {
+            polars_bail!(SQLInterface: "correlated subqueries must be a SELECT");
+        };
+        if select
+            .projection
+            .iter()
+            .any(|item| matches!(item, SelectItem::Wildcard(_)))
+        {
+            polars_bail!(
+                SQLInterface:
+                "correlated subqueries do not support unqualified wildcards; use 'tbl.*' instead"
+            )
+        }
+
+        // join the outer frame into the subquery, and keep the outer row index
+        let mut relation = Parser::new(&GenericDialect)
+            .try_with_sql(OUTER_FRAME)
+            .and_then(|mut p| p.parse_table_factor())
+            .map_err(to_sql_interface_err)?;
+        if let TableFactor::Table { alias, .. } = &mut relation {
+            alias.replace(TableAlias {
+                name: Ident::new(outer_name),
+                columns: vec![],
+            });
+        }
+        match select.from.first_mut() {
+            Some(tbl) => tbl.joins.push(Join {
+                relation,
+                global: false,
+                join_operator: JoinOperator::CrossJoin,
+            }),
+            None => select.from.push(TableWithJoins {
+                relation,
+                joins: vec![],
+            }),
+        }
+        // qualified, so that it cannot be confused with a column of the subquery
+        let index_name = Ident::new(index);
+        let index = SQLExpr::CompoundIdentifier(vec![Ident::new(outer_name), index_name.clone()]);
+        select.projection.push(SelectItem::ExprWithAlias {
+            expr: index.clone(),
+            alias: index_name,
+        });
+        if let GroupByExpr::Expressions(exprs, _) = &mut select.group_by {
+            if group_by_index || !exprs.is_empty() {
+                exprs.push(index);
+            }
+        }
+
+        // the outer name refers to the outer frame inside the subquery only
+        let saved_frame = self.table_map.insert(OUTER_FRAME.to_string(), outer);
+        let saved_alias = self.table_aliases.borrow().get(outer_name).cloned();
+        let saved_joined = self.joined_aliases.borrow().get(outer_name).cloned();
+        let res = self.execute_query_no_ctes(&subquery);
+        match saved_frame {
+            Some(lf) => self.table_map.insert(OUTER_FRAME.to_string(), lf),
+            None => self.table_map.remove(OUTER_FRAME),
+        };
+        match saved_alias {
+            Some(name) => self
+                .table_aliases
+                .borrow_mut()
+                .insert(outer_name.to_string(), name),
+            None => self.table_aliases.borrow_mut().remove(outer_name),
+        };
+        match saved_joined {
+            Some(aliases) => self
+                .joined_aliases
+                .borrow_mut()
+                .insert(outer_name.to_string(), aliases),
+            None => self.joined_aliases.borrow_mut().remove(outer_name),
+        };
+        res
+    }
+
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
         let mut lf = if select_stmt.from.is_empty() {
@@ -718,14 +1288,40 @@ impl SQLContext {
             self.execute_from_statement(from.first().unwrap())?
         };
 
-        // Filter expression (WHERE clause)
+        // Filter expression (WHERE clause), with its subqueries decorrelated into joins
+        let mut selection = select_stmt.selection.clone();
+        let mut added = vec![];
+        if let Some(expr) = &mut selection {
+            self.decorrelate_subqueries(&mut lf, &select_stmt.from, expr, &mut added, true)?;
+        }
+        lf = self.process_where(lf, &selection, false)?;
+        if !added.is_empty() {
+            lf = lf.drop(std::mem::take(&mut added));
+        }
+
+        // Subqueries of the projections are decorrelated into columns that are not selected
+        let mut projection = select_stmt.projection.clone();
+        for item in &mut projection {
+            if let SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } = item {
+                self.decorrelate_subqueries(&mut lf, &select_stmt.from, expr, &mut added, false)?;
+            }
+        }
+        let decorrelated_stmt;
+        let select_stmt = if added.is_empty() {
+            select_stmt
+        } else {
+            decorrelated_stmt = Select {
+                projection,
+                ..select_stmt.clone()
+            };
+            &decorrelated_stmt
+        };
         let schema = self.get_frame_schema(&mut lf)?;
-        lf = self.process_where(lf, &select_stmt.selection, false)?;
 
         // 'SELECT *' modifiers
         let mut select_modifiers = SelectModifiers {
             ilike: None,
-            exclude: PlHashSet::new(),
+            exclude: added.iter().map(|name| name.to_string()).collect(),
             rename: PlHashMap::new(),
             replace: vec![],
         };
@@ -777,6 +1373,8 @@ impl SQLContext {
                             matches!(e, Expr::Agg(_))
                                 || matches!(e, Expr::Len)
                                 || matches!(e, Expr::Window { .. })
+                                || matches!(e, Expr::AnonymousFunction { options, .. }
+                                    if options.returns_scalar())
                         }) {
                             group_by_keys.push(expr.clone())
                         }
@@ -791,6 +1389,14 @@ impl SQLContext {
                 polars_bail!(SQLSyntax: "HAVING clause not valid outside of GROUP BY; found:\n{:?}", select_stmt.having);
             };
 
+            // The 'qualify' clause is evaluated as a hidden projection, so that its window
+            // functions see the same rows as those of the other projections.
+            let mut projections = projections;
+            if let Some(expr) = &select_stmt.qualify {
+                let qualify = self.process_qualify(expr, &projections, &schema)?;
+                projections.push(qualify.alias(QUALIFY_NAME));
+            }
+
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
             let mut retained_names = Vec::with_capacity(projections.len());
@@ -807,8 +1413,9 @@ impl SQLContext {
                     .to_field(schema.deref(), Context::Default)?
                     .name
                     .to_string();
-                if select_modifiers.matches_ilike(&name)
-                    && !select_modifiers.exclude.contains(&name)
+                if name == QUALIFY_NAME
+                    || (select_modifiers.matches_ilike(&name)
+                        && !select_modifiers.exclude.contains(&name))
                 {
                     projection_heights |= ExprSqlProjectionHeightBehavior::identify_from_expr(p);
 
@@ -858,6 +1465,7 @@ impl SQLContext {
                                 nulls_equal: false,
                                 coalesce: Default::default(),
                                 maintain_order: polars_ops::frame::MaintainOrderJoin::Left,
+                                algorithm: Default::default(),
                             },
                         );
                 }
@@ -881,6 +1489,9 @@ impl SQLContext {
             } else {
                 lf = lf.select(retained_cols);
             }
+            if select_stmt.qualify.is_some() {
+                lf = lf.filter(col(QUALIFY_NAME)).drop([QUALIFY_NAME]);
+            }
 
             if !select_modifiers.rename.is_empty() {
                 lf = lf.rename(
@@ -896,7 +1507,13 @@ impl SQLContext {
 
             // Apply optional 'having' clause, post-aggregation.
             let schema = Some(self.get_frame_schema(&mut lf)?);
-            match select_stmt.having.as_ref() {
+            lf = match select_stmt.having.as_ref() {
+                Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
+                None => lf,
+            };
+
+            // Apply optional 'qualify' clause, to the aggregated rows.
+            match select_stmt.qualify.as_ref() {
                 Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
                 None => lf,
             }
@@ -929,6 +1546,30 @@ impl SQLContext {
         Ok(lf)
     }
 
+    /// Translate the expression of a 'qualify' clause, which can refer to the aliases of the
+    /// projections (e.g. `SELECT ROW_NUMBER() OVER (...) AS rn ... QUALIFY rn = 1`).
+    fn process_qualify(
+        &mut self,
+        expr: &SQLExpr,
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<Expr> {
+        let aliased: PlHashMap<&PlSmallStr, &Expr> = projections
+            .iter()
+            .filter_map(|p| match p {
+                Expr::Alias(e, name) => Some((name, e.as_ref())),
+                _ => None,
+            })
+            .collect();
+        let expr = parse_sql_expr(expr, self, Some(schema))?;
+        Ok(expr.map_expr(|e| match e {
+            Expr::Column(name) if !schema.contains(&name) => aliased
+                .get(&name)
+                .map_or(Expr::Column(name), |aliased_expr| (*aliased_expr).clone()),
+            e => e,
+        }))
+    }
+
     fn column_projections(
         &mut self,
         select_stmt: &Select,
@@ -1098,6 +1739,182 @@ impl SQLContext {
         }
     }
 
+    fn execute_insert_into(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Insert(Insert {
+            table_name,
+            columns,
+            overwrite,
+            source,
+            ..
+        }) = stmt
+        {
+            let tbl_name = table_name.0.first().unwrap().value.as_str();
+            let Some(mut lf) = self.table_map.get(tbl_name).cloned() else {
+                polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
+            };
+            let Some(source) = source else {
+                polars_bail!(SQLInterface: "only `INSERT INTO ... SELECT/VALUES` is currently supported");
+            };
+            let mut rows = self.execute_query(source)?;
+            let schema = self.get_frame_schema(&mut lf)?;
+            let rows_schema = self.get_frame_schema(&mut rows)?;
+
+            // The rows are inserted into the given columns (by default all columns, in order),
+            // and the other columns are null.
+            let target_columns: Vec<PlSmallStr> = if columns.is_empty() {
+                schema.iter_names().cloned().collect()
+            } else {
+                columns
+                    .iter()
+                    .map(|c| {
+                        polars_ensure!(
+                            schema.contains(c.value.as_str()),
+                            SQLInterface: "column '{}' was not found in table '{}'", c.value, tbl_name
+                        );
+                        Ok(PlSmallStr::from_str(c.value.as_str()))
+                    })
+                    .collect::<PolarsResult<_>>()?
+            };
+            polars_ensure!(
+                target_columns.len() == rows_schema.len(),
+                SQLInterface: "INSERT INTO '{}' expects {} columns; found {}",
+                tbl_name, target_columns.len(), rows_schema.len()
+            );
+            let exprs = schema
+                .iter()
+                .map(|(name, dtype)| {
+                    let value = match target_columns.iter().position(|c| c == name) {
+                        Some(idx) => col(rows_schema.get_at_index(idx).unwrap().0.clone()),
+                        None => lit(NULL),
+                    };
+                    value.cast(dtype.clone()).alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+            let rows = rows.select(exprs);
+
+            // INSERT OVERWRITE replaces the rows of the table
+            let lf = if *overwrite {
+                rows
+            } else {
+                polars_lazy::dsl::concat(vec![lf, rows], UnionArgs::default())?
+            };
+            self.register(tbl_name, lf);
+            let out = df! {
+                "Response" => ["INSERT INTO"]
+            }
+            .unwrap()
+            .lazy();
+            Ok(out)
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn execute_copy(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Copy {
+            source,
+            to,
+            target,
+            options,
+            legacy_options,
+            ..
+        } = stmt
+        {
+            if !*to {
+                polars_bail!(SQLInterface: "only `COPY ... TO` is currently supported")
+            }
+            let lf = match source {
+                CopySource::Query(query) => self.execute_query(query)?,
+                CopySource::Table {
+                    table_name,
+                    columns,
+                } => {
+                    let tbl_name = table_name.0.first().unwrap().value.as_str();
+                    let lf = self.get_table_from_current_scope(tbl_name).ok_or_else(
+                        || polars_err!(SQLInterface: "relation '{}' was not found", tbl_name),
+                    )?;
+                    if columns.is_empty() {
+                        lf
+                    } else {
+                        lf.select(
+                            columns
+                                .iter()
+                                .map(|c| col(c.value.as_str()))
+                                .collect::<Vec<_>>(),
+                        )
+                    }
+                },
+            };
+            let CopyTarget::File { filename } = target else {
+                polars_bail!(SQLInterface: "COPY can only write to a file; found {}", target)
+            };
+            if !legacy_options.is_empty() {
+                polars_bail!(SQLInterface: "legacy COPY options are not supported")
+            }
+
+            let mut format = None;
+            let mut include_header = true;
+            let mut separator = b',';
+            for option in options {
+                match option {
+                    CopyOption::Format(ident) => format = Some(ident.value.to_lowercase()),
+                    CopyOption::Header(header) => include_header = *header,
+                    CopyOption::Delimiter(c) if c.is_ascii() => separator = *c as u8,
+                    _ => polars_bail!(SQLInterface: "COPY option is not supported: {}", option),
+                }
+            }
+            // Without a FORMAT option, the format is inferred from the file extension
+            let path = PathBuf::from(filename);
+            let format = format
+                .or_else(|| {
+                    path.extension()
+                        .and_then(|ext| ext.to_str())
+                        .map(|ext| ext.to_lowercase())
+                })
+                .ok_or_else(|| {
+                    polars_err!(SQLInterface: "cannot infer the format of '{}'; use the FORMAT option", filename)
+                })?;
+            let target = SinkTarget::Path(Arc::new(path));
+            let sink_options = SinkOptions::default();
+
+            let sink = match format.as_str() {
+                #[cfg(feature = "csv")]
+                "csv" => {
+                    let mut csv_options = CsvWriterOptions {
+                        include_header,
+                        ..Default::default()
+                    };
+                    csv_options.serialize_options.separator = separator;
+                    lf.sink_csv(target, csv_options, None, sink_options)?
+                },
+                #[cfg(feature = "ipc")]
+                "arrow" | "feather" | "ipc" => {
+                    lf.sink_ipc(target, Default::default(), None, sink_options)?
+                },
+                #[cfg(feature = "json")]
+                "json" | "jsonl" | "ndjson" => {
+                    lf.sink_json(target, Default::default(), None, sink_options)?
+                },
+                #[cfg(feature = "parquet")]
+                "parquet" => lf.sink_parquet(target, Default::default(), None, sink_options)?,
+                _ => {
+                    // (the sink arguments are only used by the formats of enabled features)
+                    let _ = (lf, target, sink_options, include_header, separator);
+                    polars_bail!(SQLInterface: "COPY does not support the '{}' format", format)
+                },
+            };
+            sink.collect_with_engine(Engine::Auto)?;
+            let out = df! {
+                "Response" => ["COPY"]
+            }
+            .unwrap()
+            .lazy();
+            Ok(out)
+        } else {
+            unreachable!()
+        }
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -1106,6 +1923,23 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, &args.args);
                 }
+                if let [schema, tbl_name] = name.0.as_slice() {
+                    if schema.value.eq_ignore_ascii_case("information_schema") {
+                        // register the virtual table for the statement, so that its columns
+                        // can be qualified by its name or alias
+                        let lf = self.get_information_schema_table(&tbl_name.value)?;
+                        let tbl_name = alias.as_ref().map_or(&tbl_name.value, |a| &a.name.value);
+                        self.register_cte(tbl_name, lf.clone());
+                        return Ok((tbl_name.clone(), lf));
+                    }
+                }
+                if let Some(lf) = self.get_catalog_table(&name.0)? {
+                    let tbl_name = alias
+                        .as_ref()
+                        .map_or(&name.0.last().unwrap().value, |a| &a.name.value);
+                    self.register_cte(tbl_name, lf.clone());
+                    return Ok((tbl_name.clone(), lf));
+                }
                 let tbl_name = name.0.first().unwrap().value.as_str();
                 if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
                     match alias {
@@ -1307,8 +2141,10 @@ impl SQLContext {
 
         for mut e in projections {
             // `Len` represents COUNT(*) so we treat as an aggregation here.
-            let is_agg_or_window = has_expr(e, |e| {
-                matches!(e, Expr::Agg(_) | Expr::Len | Expr::Window { .. })
+            let is_agg_or_window = has_expr(e, |e| match e {
+                // aggregate user-defined functions
+                Expr::AnonymousFunction { options, .. } => options.returns_scalar(),
+                _ => matches!(e, Expr::Agg(_) | Expr::Len | Expr::Window { .. }),
             });
 
             // Note: if simple aliased expression we defer aliasing until after the group_by.
@@ -1547,6 +2383,64 @@ impl SQLContext {
     }
 }
 
+fn parse_statement(query: &str, dialect: Dialect) -> PolarsResult<Statement> {
+    let mut parser = Parser::new(dialect.parser_dialect());
+    parser = parser.with_options(ParserOptions {
+        trailing_commas: true,
+        ..Default::default()
+    });
+
+    let mut ast = parser
+        .try_with_sql(query)
+        .map_err(to_sql_interface_err)?
+        .parse_statements()
+        .map_err(to_sql_interface_err)?;
+
+    polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
+    Ok(ast.pop().unwrap())
+}
+
+/// A name for the row index of an outer frame of a correlated subquery that is not a column
+/// of `schema`.
+pub(crate) fn outer_index_name(schema: &Schema) -> PlSmallStr {
+    let mut name = PlSmallStr::from_static(OUTER_INDEX);
+    let mut n = 0;
+    while schema.contains(&name) {
+        n += 1;
+        name = format_pl_smallstr!("{}_{}", OUTER_INDEX, n);
+    }
+    name
+}
+
+/// Whether the `FROM` clauses of a set expression (or of its subqueries) refer to the table
+/// `name`.
+fn references_table(expr: &SetExpr, name: &str) -> bool {
+    fn in_table_factor(factor: &TableFactor, name: &str) -> bool {
+        match factor {
+            TableFactor::Table { name: tbl_name, .. } => {
+                tbl_name.0.first().is_some_and(|ident| ident.value == name)
+            },
+            TableFactor::Derived { subquery, .. } => references_table(&subquery.body, name),
+            TableFactor::NestedJoin {
+                table_with_joins, ..
+            } => in_table_with_joins(table_with_joins, name),
+            _ => false,
+        }
+    }
+    fn in_table_with_joins(tbl: &TableWithJoins, name: &str) -> bool {
+        in_table_factor(&tbl.relation, name)
+            || tbl.joins.iter().any(|j| in_table_factor(&j.relation, name))
+    }
+    match expr {
+        SetExpr::Select(select) => select.from.iter().any(|tbl| in_table_with_joins(tbl, name)),
+        SetExpr::Query(query) => references_table(&query.body, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            references_table(left, name) || references_table(right, name)
+        },
+        _ => false,
+    }
+}
+
 fn collect_compound_identifiers(
     left: &[Ident],
     right: &[Ident],
@@ -0,0 +1,129 @@
+//! The SQL dialects that a [`SQLContext`](crate::SQLContext) can parse and translate.
+use polars_core::prelude::{PolarsResult, polars_bail};
+use sqlparser::dialect::{Dialect as ParserDialect, GenericDialect, PostgreSqlDialect};
+
+/// The SQL dialect of the queries executed by a [`SQLContext`](crate::SQLContext), set with
+/// [`SQLContext::with_dialect`](crate::SQLContext::with_dialect).
+///
+/// A dialect other than the default determines how queries are parsed, and makes the functions
+/// of that dialect available in addition to the Polars SQL functions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Dialect {
+    /// The Polars SQL dialect.
+    #[default]
+    Generic,
+    /// The PostgreSQL dialect, which adds the functions `DATE_TRUNC`, `REGEXP_REPLACE`,
+    /// `TO_CHAR`, `TO_DATE` and `TO_TIMESTAMP`.
+    Postgres,
+}
+
+impl Dialect {
+    /// The dialect that statements are parsed with.
+    pub(crate) fn parser_dialect(&self) -> &'static dyn ParserDialect {
+        match self {
+            Self::Generic => &GenericDialect,
+            Self::Postgres => &PostgreSqlDialect {},
+        }
+    }
+}
+
+/// The interval (as used by `dt.truncate`) of a PostgreSQL `date_trunc` unit.
+pub(crate) fn date_trunc_interval(unit: &str) -> PolarsResult<&'static str> {
+    let unit = unit.to_lowercase();
+    Ok(match unit.strip_suffix('s').unwrap_or(unit.as_str()) {
+        "microsecond" => "1us",
+        "millisecond" => "1ms",
+        "second" => "1s",
+        "minute" => "1m",
+        "hour" => "1h",
+        "day" => "1d",
+        "week" => "1w",
+        "month" => "1mo",
+        "quarter" => "1q",
+        "year" => "1y",
+        _ => polars_bail!(SQLInterface: "DATE_TRUNC does not support the unit '{}'", unit),
+    })
+}
+
+/// Template patterns of PostgreSQL formatting functions, and their chrono equivalents. Longer
+/// patterns come first, so that they take precedence over their prefixes.
+const FORMAT_PATTERNS: &[(&str, &str)] = &[
+    ("HH24", "%H"),
+    ("HH12", "%I"),
+    ("HH", "%I"),
+    ("MI", "%M"),
+    ("SS", "%S"),
+    ("MS", "%3f"),
+    ("US", "%6f"),
+    ("AM", "%p"),
+    ("PM", "%p"),
+    ("am", "%P"),
+    ("pm", "%P"),
+    ("YYYY", "%Y"),
+    ("YY", "%y"),
+    ("MONTH", "%B"),
+    ("Month", "%B"),
+    ("MON", "%b"),
+    ("Mon", "%b"),
+    ("MM", "%m"),
+    ("DAY", "%A"),
+    ("Day", "%A"),
+    ("DY", "%a"),
+    ("Dy", "%a"),
+    ("DDD", "%j"),
+    ("DD", "%d"),
+    ("TZ", "%Z"),
+    ("OF", "%:z"),
+];
+
+/// Convert the format of a PostgreSQL formatting function (such as `to_char`) to a chrono
+/// format string. Text in double quotes is copied verbatim.
+pub(crate) fn postgres_to_chrono_format(format: &str) -> String {
+    let mut out = String::with_capacity(format.len() * 2);
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let (text, remaining) = quoted.split_once('"').unwrap_or((quoted, ""));
+            out.push_str(&text.replace('%', "%%"));
+            rest = remaining;
+        } else if let Some((pattern, chrono)) =
+            FORMAT_PATTERNS.iter().find(|(p, _)| rest.starts_with(p))
+        {
+            out.push_str(chrono);
+            rest = &rest[pattern.len()..];
+        } else {
+            if c == '%' {
+                out.push('%');
+            }
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    out
+}
+
+/// Convert the back-references of a PostgreSQL regex replacement (`\1`, `\&`) to the syntax
+/// of the regex crate (`${1}`, `${0}`).
+pub(crate) fn postgres_to_regex_replacement(replacement: &str) -> String {
+    let mut out = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('\\', Some(&d)) if d.is_ascii_digit() => {
+                out.push_str(&format!("${{{}}}", d));
+                chars.next();
+            },
+            ('\\', Some('&')) => {
+                out.push_str("${0}");
+                chars.next();
+            },
+            ('\\', Some('\\')) => {
+                out.push('\\');
+                chars.next();
+            },
+            ('$', _) => out.push_str("$$"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
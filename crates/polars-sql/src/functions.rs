@@ -1,8 +1,9 @@
 use std::ops::Sub;
 
-use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
+use polars_core::chunked_array::ops::SortMultipleOptions;
 use polars_core::prelude::{
-    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_err,
+    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_ensure,
+    polars_err,
 };
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
@@ -16,12 +17,16 @@ use sqlparser::ast::helpers::attached_token::AttachedToken;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowType,
 };
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
+use crate::dialect::{
+    Dialect, date_trunc_interval, postgres_to_chrono_format, postgres_to_regex_replacement,
+};
 use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::window::{WindowAggregate, WindowFunction};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -291,12 +296,36 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT DATE_PART('year', column_1) FROM df;
     /// SELECT DATE_PART('day', column_1) FROM df;
     DatePart,
+    /// SQL 'date_trunc' function (PostgreSQL dialect).
+    /// Truncates a date (or datetime) to the given precision, such as 'hour' or 'month'.
+    /// ```sql
+    /// SELECT DATE_TRUNC('month', column_1) FROM df;
+    /// ```
+    DateTrunc,
     /// SQL 'strftime' function.
     /// Converts a datetime to a string using a format string.
     /// ```sql
     /// SELECT STRFTIME(column_1, '%d-%m-%Y %H:%M') FROM df;
     /// ```
     Strftime,
+    /// SQL 'to_char' function (PostgreSQL dialect).
+    /// Converts a datetime to a string using a PostgreSQL format string.
+    /// ```sql
+    /// SELECT TO_CHAR(column_1, 'YYYY-MM-DD HH24:MI:SS') FROM df;
+    /// ```
+    ToChar,
+    /// SQL 'to_date' function (PostgreSQL dialect).
+    /// Parses a string to a date using a PostgreSQL format string.
+    /// ```sql
+    /// SELECT TO_DATE(column_1, 'DD/MM/YYYY') FROM df;
+    /// ```
+    ToDate,
+    /// SQL 'to_timestamp' function (PostgreSQL dialect).
+    /// Parses a string to a datetime using a PostgreSQL format string.
+    /// ```sql
+    /// SELECT TO_TIMESTAMP(column_1, 'YYYY-MM-DD HH24:MI') FROM df;
+    /// ```
+    ToTimestamp,
 
     // ----
     // String functions
@@ -385,6 +414,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT REGEXP_LIKE(column_1, 'xyz', 'i') FROM df;
     /// ```
     RegexpLike,
+    /// SQL 'regexp_replace' function (PostgreSQL dialect).
+    /// Replaces the first match of `pattern` (or all matches, with the 'g' flag) with
+    /// `replacement`, which can refer to capture groups as `\1`.
+    /// ```sql
+    /// SELECT REGEXP_REPLACE(column_1, '(\w+) (\w+)', '\2 \1', 'g') FROM df;
+    /// ```
+    RegexpReplace,
     /// SQL 'replace' function.
     /// Replace a given substring with another string.
     /// ```sql
@@ -595,6 +631,69 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     Variance,
 
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'cume_dist' function.
+    /// Returns the fraction of the rows of the partition that precede the current row or are
+    /// its peers in the window order.
+    /// ```sql
+    /// SELECT CUME_DIST() OVER (ORDER BY column_1) FROM df;
+    /// ```
+    CumeDist,
+    /// SQL 'dense_rank' function.
+    /// Returns the rank of the current row in the window order, without gaps.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'first_value' function.
+    /// Returns the value of the first row of the window frame.
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    FirstValue,
+    /// SQL 'lag' function.
+    /// Returns the value of the row the given number of rows (default 1) before the current row
+    /// in the window order, or a default value (default NULL).
+    /// ```sql
+    /// SELECT LAG(column_1, 1, 0) OVER (ORDER BY column_2) FROM df;
+    /// ```
+    Lag,
+    /// SQL 'last_value' function.
+    /// Returns the value of the last row of the window frame.
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1) OVER (
+    ///   ORDER BY column_2 ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+    /// ) FROM df;
+    /// ```
+    LastValue,
+    /// SQL 'lead' function.
+    /// Returns the value of the row the given number of rows (default 1) after the current row
+    /// in the window order, or a default value (default NULL).
+    /// ```sql
+    /// SELECT LEAD(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    Lead,
+    /// SQL 'percent_rank' function.
+    /// Returns the relative rank of the current row, (rank - 1) / (partition rows - 1).
+    /// ```sql
+    /// SELECT PERCENT_RANK() OVER (ORDER BY column_1) FROM df;
+    /// ```
+    PercentRank,
+    /// SQL 'rank' function.
+    /// Returns the rank of the current row in the window order, with gaps.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2 DESC) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'row_number' function.
+    /// Returns the number of the current row in the window order, starting at 1.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+
     // ----
     // Array functions
     // ----
@@ -725,18 +824,25 @@ impl PolarsSQLFunctions {
             "cot",
             "cotd",
             "count",
+            "cume_dist",
             "date",
             "date_part",
+            "date_trunc",
             "degrees",
+            "dense_rank",
             "ends_with",
             "exp",
             "first",
+            "first_value",
             "floor",
             "greatest",
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "last_value",
+            "lead",
             "least",
             "left",
             "length",
@@ -754,17 +860,21 @@ impl PolarsSQLFunctions {
             "mod",
             "nullif",
             "octet_length",
+            "percent_rank",
             "pi",
             "pow",
             "power",
             "quantile_cont",
             "quantile_disc",
             "radians",
+            "rank",
             "regexp_like",
+            "regexp_replace",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -782,6 +892,9 @@ impl PolarsSQLFunctions {
             "sum",
             "tan",
             "tand",
+            "to_char",
+            "to_date",
+            "to_timestamp",
             "unnest",
             "upper",
             "var",
@@ -862,6 +975,10 @@ impl PolarsSQLFunctions {
             // ----
             "date_part" => Self::DatePart,
             "strftime" => Self::Strftime,
+            "date_trunc" if ctx.dialect == Dialect::Postgres => Self::DateTrunc,
+            "to_char" if ctx.dialect == Dialect::Postgres => Self::ToChar,
+            "to_date" if ctx.dialect == Dialect::Postgres => Self::ToDate,
+            "to_timestamp" if ctx.dialect == Dialect::Postgres => Self::ToTimestamp,
 
             // ----
             // String functions
@@ -882,6 +999,7 @@ impl PolarsSQLFunctions {
             "octet_length" => Self::OctetLength,
             "strpos" => Self::StrPos,
             "regexp_like" => Self::RegexpLike,
+            "regexp_replace" if ctx.dialect == Dialect::Postgres => Self::RegexpReplace,
             "replace" => Self::Replace,
             "reverse" => Self::Reverse,
             "right" => Self::Right,
@@ -910,6 +1028,19 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "cume_dist" => Self::CumeDist,
+            "dense_rank" => Self::DenseRank,
+            "first_value" => Self::FirstValue,
+            "lag" => Self::Lag,
+            "last_value" => Self::LastValue,
+            "lead" => Self::Lead,
+            "percent_rank" => Self::PercentRank,
+            "rank" => Self::Rank,
+            "row_number" => Self::RowNumber,
+
             // ----
             // Array functions
             // ----
@@ -932,7 +1063,7 @@ impl PolarsSQLFunctions {
             "columns" => Self::Columns,
 
             other => {
-                if ctx.function_registry.contains(other) {
+                if ctx.user_functions.contains_key(other) || ctx.function_registry.contains(other) {
                     Self::Udf(other.to_string())
                 } else {
                     polars_bail!(SQLInterface: "unsupported function '{}'", other);
@@ -1101,6 +1232,33 @@ impl SQLFunctionVisitor<'_> {
                     },
                 }
             },
+            DateTrunc => self.try_visit_binary(|part, e| match part {
+                Expr::Literal(p) if p.extract_str().is_some() => {
+                    let every = date_trunc_interval(p.extract_str().unwrap())?;
+                    Ok(e.dt().truncate(lit(every)))
+                },
+                _ => {
+                    polars_bail!(SQLSyntax: "DATE_TRUNC expects a literal unit (found {:?})", part)
+                },
+            }),
+            ToChar => self
+                .visit_binary(|e, fmt: String| e.dt().to_string(&postgres_to_chrono_format(&fmt))),
+            ToDate => self.visit_binary(|e, fmt: String| {
+                e.str().to_date(StrptimeOptions {
+                    format: Some(postgres_to_chrono_format(&fmt).into()),
+                    ..Default::default()
+                })
+            }),
+            ToTimestamp => self.visit_binary(|e, fmt: String| {
+                e.str().strptime(
+                    DataType::Datetime(TimeUnit::Microseconds, None),
+                    StrptimeOptions {
+                        format: Some(postgres_to_chrono_format(&fmt).into()),
+                        ..Default::default()
+                    },
+                    lit("latest"),
+                )
+            }),
 
             // ----
             // String functions
@@ -1238,6 +1396,48 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "REGEXP_LIKE expects 2-3 arguments (found {})",args.len()),
                 }
             },
+            RegexpReplace => {
+                let args = extract_args(function)?;
+                polars_ensure!(
+                    args.len() == 3 || args.len() == 4,
+                    SQLSyntax: "REGEXP_REPLACE expects 3-4 arguments (found {})", args.len()
+                );
+                self.try_visit_variadic(|exprs: &[Expr]| {
+                    let literal_str = |e: &Expr| match e {
+                        Expr::Literal(lv) => lv.extract_str().map(str::to_string),
+                        _ => None,
+                    };
+                    let (Some(pattern), Some(flags)) = (
+                        literal_str(&exprs[1]),
+                        exprs.get(3).map_or(Some(String::new()), literal_str),
+                    ) else {
+                        polars_bail!(SQLSyntax: "REGEXP_REPLACE expects a literal pattern and flags");
+                    };
+                    let mut inline_flags = String::new();
+                    for flag in flags.chars() {
+                        match flag {
+                            'c' | 'g' => {},
+                            'i' | 'x' => inline_flags.push(flag),
+                            _ => polars_bail!(SQLSyntax: "invalid REGEXP_REPLACE flag '{}'", flag),
+                        }
+                    }
+                    let pattern = if inline_flags.is_empty() {
+                        lit(pattern)
+                    } else {
+                        lit(format!("(?{}){}", inline_flags, pattern))
+                    };
+                    let replacement = match literal_str(&exprs[2]) {
+                        Some(r) => lit(postgres_to_regex_replacement(&r)),
+                        None => exprs[2].clone(),
+                    };
+                    let e = exprs[0].clone().str();
+                    Ok(if flags.contains('g') {
+                        e.replace_all(pattern, replacement, false)
+                    } else {
+                        e.replace(pattern, replacement, false)
+                    })
+                })
+            },
             Replace => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1405,11 +1605,11 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
-            Avg => self.visit_unary(Expr::mean),
+            Avg => self.visit_unary_with_opt_window(Expr::mean, WindowAggregate::Avg),
             Count => self.visit_count(),
             First => self.visit_unary(Expr::first),
             Last => self.visit_unary(Expr::last),
-            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
+            Max => self.visit_unary_with_opt_window(Expr::max, WindowAggregate::Max),
             Median => self.visit_unary(Expr::median),
             QuantileCont => {
                 let args = extract_args(function)?;
@@ -1463,11 +1663,24 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "QUANTILE_DISC expects 2 arguments (found {})", args.len()),
                 }
             },
-            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
+            Min => self.visit_unary_with_opt_window(Expr::min, WindowAggregate::Min),
             StdDev => self.visit_unary(|e| e.std(1)),
-            Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
+            Sum => self.visit_unary_with_opt_window(Expr::sum, WindowAggregate::Sum),
             Variance => self.visit_unary(|e| e.var(1)),
 
+            // ----
+            // Window functions
+            // ----
+            CumeDist => self.visit_window_function(WindowFunction::CumeDist),
+            DenseRank => self.visit_window_function(WindowFunction::DenseRank),
+            FirstValue => self.visit_window_function(WindowFunction::FirstValue),
+            Lag => self.visit_window_function(WindowFunction::Lag),
+            LastValue => self.visit_window_function(WindowFunction::LastValue),
+            Lead => self.visit_window_function(WindowFunction::Lead),
+            PercentRank => self.visit_window_function(WindowFunction::PercentRank),
+            Rank => self.visit_window_function(WindowFunction::Rank),
+            RowNumber => self.visit_window_function(WindowFunction::RowNumber),
+
             // ----
             // Array functions
             // ----
@@ -1551,6 +1764,9 @@ impl SQLFunctionVisitor<'_> {
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
+        if let Some(function) = self.ctx.user_functions.get(func_name) {
+            return function.call(func_name, args);
+        }
         self.ctx
             .function_registry
             .get_udf(func_name)?
@@ -1558,45 +1774,6 @@ impl SQLFunctionVisitor<'_> {
             .call(args)
     }
 
-    /// Window specs without partition bys are essentially cumulative functions
-    /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
-    fn apply_cumulative_window(
-        &mut self,
-        f: impl Fn(Expr) -> Expr,
-        cumulative_f: impl Fn(Expr, bool) -> Expr,
-        WindowSpec {
-            partition_by,
-            order_by,
-            ..
-        }: &WindowSpec,
-    ) -> PolarsResult<Expr> {
-        if !order_by.is_empty() && partition_by.is_empty() {
-            let (order_by, desc): (Vec<Expr>, Vec<bool>) = order_by
-                .iter()
-                .map(|o| {
-                    let expr = parse_sql_expr(&o.expr, self.ctx, self.active_schema)?;
-                    Ok(match o.asc {
-                        Some(b) => (expr, !b),
-                        None => (expr, false),
-                    })
-                })
-                .collect::<PolarsResult<Vec<_>>>()?
-                .into_iter()
-                .unzip();
-            self.visit_unary_no_window(|e| {
-                cumulative_f(
-                    e.sort_by(
-                        &order_by,
-                        SortMultipleOptions::default().with_order_descending_multi(desc.clone()),
-                    ),
-                    false,
-                )
-            })
-        } else {
-            self.visit_unary(f)
-        }
-    }
-
     fn visit_unary(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
         self.try_visit_unary(|e| Ok(f(e)))
     }
@@ -1617,28 +1794,6 @@ impl SQLFunctionVisitor<'_> {
         .and_then(|e| self.apply_window_spec(e, &self.func.over))
     }
 
-    /// Some functions have cumulative equivalents that can be applied to window specs
-    /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
-    /// visit_unary_with_cumulative_window will take in a function & a cumulative function
-    /// if there is a cumulative window spec, it will apply the cumulative function,
-    /// otherwise it will apply the function
-    fn visit_unary_with_opt_cumulative(
-        &mut self,
-        f: impl Fn(Expr) -> Expr,
-        cumulative_f: impl Fn(Expr, bool) -> Expr,
-    ) -> PolarsResult<Expr> {
-        match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
-                self.apply_cumulative_window(f, cumulative_f, spec)
-            },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
-            _ => self.visit_unary(f),
-        }
-    }
-
     fn visit_unary_no_window(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
         let args = extract_args(self.func)?;
         match args.as_slice() {
@@ -1795,6 +1950,9 @@ impl SQLFunctionVisitor<'_> {
     }
 
     fn visit_count(&mut self) -> PolarsResult<Expr> {
+        if let Some(expr) = self.visit_count_with_opt_window()? {
+            return Ok(expr);
+        }
         let (args, is_distinct) = extract_args_distinct(self.func)?;
         match (is_distinct, args.as_slice()) {
             // count(*), count()
@@ -1844,25 +2002,22 @@ impl SQLFunctionVisitor<'_> {
     ) -> PolarsResult<Expr> {
         Ok(match &window_type {
             Some(WindowType::WindowSpec(window_spec)) => {
-                if window_spec.partition_by.is_empty() {
-                    let exprs = window_spec
-                        .order_by
-                        .iter()
-                        .map(|o| {
-                            let e = parse_sql_expr(&o.expr, self.ctx, self.active_schema)?;
-                            Ok(o.asc.map_or(e.clone(), |b| {
-                                e.sort(SortOptions::default().with_order_descending(!b))
-                            }))
-                        })
-                        .collect::<PolarsResult<Vec<_>>>()?;
-                    expr.over(exprs)
+                // Windows with an ORDER BY or a frame clause are only supported for the functions
+                // of the `window` module.
+                if !window_spec.order_by.is_empty() || window_spec.window_frame.is_some() {
+                    polars_bail!(
+                        SQLInterface: "ORDER BY and frame clauses are not supported in the window of '{}'",
+                        self.func.name
+                    )
+                }
+                let partition_by = window_spec
+                    .partition_by
+                    .iter()
+                    .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                if partition_by.is_empty() {
+                    expr
                 } else {
-                    // Process for simple window specification, partition by first
-                    let partition_by = window_spec
-                        .partition_by
-                        .iter()
-                        .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
-                        .collect::<PolarsResult<Vec<_>>>()?;
                     expr.over(partition_by)
                 }
             },
@@ -1874,7 +2029,7 @@ impl SQLFunctionVisitor<'_> {
         })
     }
 
-    fn not_supported_error(&self) -> PolarsResult<Expr> {
+    pub(crate) fn not_supported_error(&self) -> PolarsResult<Expr> {
         polars_bail!(
             SQLInterface:
             "no function matches the given name and arguments: `{}`",
@@ -1883,12 +2038,14 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
-fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
+pub(crate) fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
 }
 
-fn extract_args_distinct(func: &SQLFunction) -> PolarsResult<(Vec<&FunctionArgExpr>, bool)> {
+pub(crate) fn extract_args_distinct(
+    func: &SQLFunction,
+) -> PolarsResult<(Vec<&FunctionArgExpr>, bool)> {
     let (args, is_distinct, _) = _extract_func_args(func, true, false)?;
     Ok((args, is_distinct))
 }
@@ -2,12 +2,20 @@
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
 mod context;
+mod dialect;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
+mod prepared;
 mod sql_expr;
+mod subquery;
 mod table_functions;
 mod types;
+mod user_functions;
+mod window;
 
 pub use context::SQLContext;
+pub use dialect::Dialect;
+pub use prepared::{SQLParams, SQLPreparedStatement};
 pub use sql_expr::sql_expr;
+pub use user_functions::{SQLAggregateFunction, SQLScalarFunction};
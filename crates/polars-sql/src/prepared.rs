@@ -0,0 +1,63 @@
+//! Prepared statements, whose placeholders are bound to values when they are executed.
+use polars_core::prelude::{PlHashMap, PlSmallStr, PolarsResult, Scalar, polars_bail, polars_err};
+use sqlparser::ast::Statement;
+
+/// A parsed SQL statement, created with [`SQLContext::prepare`](crate::SQLContext::prepare).
+///
+/// The placeholders of the statement, positional (`$1`, `$2`, ...) or named (`:name`, `$name`
+/// or `@name`), are bound to the literal values of a [`SQLParams`] every time the statement is
+/// executed with [`SQLContext::execute_prepared`](crate::SQLContext::execute_prepared). Values
+/// are never interpolated into the SQL text.
+#[derive(Clone, Debug)]
+pub struct SQLPreparedStatement {
+    pub(crate) statement: Statement,
+}
+
+/// The values bound to the placeholders of a [`SQLPreparedStatement`].
+///
+/// Every value is a typed [`Scalar`] and becomes a literal of that type.
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_sql::SQLParams;
+/// let params = SQLParams::new()
+///     .with_value(10i64)
+///     .with_named("name", PlSmallStr::from("polars"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SQLParams {
+    positional: Vec<Scalar>,
+    named: PlHashMap<PlSmallStr, Scalar>,
+}
+
+impl SQLParams {
+    /// Create an empty set of values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind the next positional placeholder; the first call binds `$1`, the second `$2`, etc.
+    pub fn with_value(mut self, value: impl Into<Scalar>) -> Self {
+        self.positional.push(value.into());
+        self
+    }
+
+    /// Bind the named placeholder `:name` (or `$name`, `@name`).
+    pub fn with_named(mut self, name: &str, value: impl Into<Scalar>) -> Self {
+        self.named.insert(PlSmallStr::from_str(name), value.into());
+        self
+    }
+
+    /// The value bound to a placeholder, as written in the statement (e.g. `$1` or `:name`).
+    pub(crate) fn get(&self, placeholder: &str) -> PolarsResult<Scalar> {
+        let value = match placeholder.strip_prefix('$').map(str::parse::<usize>) {
+            Some(Ok(n)) => n.checked_sub(1).and_then(|idx| self.positional.get(idx)),
+            _ if placeholder == "?" => polars_bail!(
+                SQLInterface: "anonymous '?' placeholders are not supported; use '$1' or ':name'"
+            ),
+            _ => placeholder.get(1..).and_then(|name| self.named.get(name)),
+        };
+        value.cloned().ok_or_else(
+            || polars_err!(SQLInterface: "no value is bound to the placeholder '{}'", placeholder),
+        )
+    }
+}
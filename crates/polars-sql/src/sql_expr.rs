@@ -798,6 +798,7 @@ impl SQLExprVisitor<'_> {
                 lit(hex::decode(x.clone()).unwrap())
             },
             SQLValue::Null => Expr::Literal(LiteralValue::untyped_null()),
+            SQLValue::Placeholder(placeholder) => lit(self.ctx.params.get(placeholder)?),
             SQLValue::Number(s, _) => {
                 // Check for existence of decimal separator dot
                 if s.contains('.') {
@@ -837,6 +838,7 @@ impl SQLExprVisitor<'_> {
                 AnyValue::BinaryOwned(hex::decode(x.clone()).unwrap())
             },
             SQLValue::Null => AnyValue::Null,
+            SQLValue::Placeholder(placeholder) => self.ctx.params.get(placeholder)?.into_value(),
             SQLValue::Number(s, _) => {
                 let negate = match op {
                     Some(UnaryOperator::Minus) => true,
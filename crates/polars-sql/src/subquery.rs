@@ -0,0 +1,799 @@
+//! Decorrelation of the subqueries of an expression (`EXISTS`, `IN` and scalar subqueries)
+//! into joins.
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_ops::frame::{JoinValidation, MaintainOrderJoin};
+use polars_plan::prelude::*;
+use polars_utils::format_pl_smallstr;
+#[cfg(feature = "semi_anti_join")]
+use sqlparser::ast::Value as SQLValue;
+use sqlparser::ast::{
+    BinaryOperator, Expr as SQLExpr, FunctionArg, FunctionArgExpr, FunctionArguments, GroupByExpr,
+    Ident, Query, Select, SelectItem, SetExpr, TableFactor, TableWithJoins, UnaryOperator,
+};
+
+use crate::SQLContext;
+use crate::context::{OUTER_FRAME, outer_index_name};
+use crate::sql_expr::parse_sql_expr;
+
+/// The aggregate functions, which make a correlated scalar subquery without `GROUP BY`
+/// aggregate per outer row.
+const AGGREGATE_FUNCTIONS: &[&str] = &[
+    "array_agg",
+    "avg",
+    "count",
+    "first",
+    "last",
+    "max",
+    "median",
+    "min",
+    "quantile_cont",
+    "quantile_disc",
+    "stddev",
+    "stddev_samp",
+    "stdev",
+    "stdev_samp",
+    "string_agg",
+    "sum",
+    "var",
+    "var_samp",
+    "variance",
+];
+
+impl SQLContext {
+    /// Replace the subqueries of `expr` with columns of `lf` that hold their results.
+    ///
+    /// `EXISTS` and scalar subqueries, and `IN` subqueries that refer to the columns of `lf`
+    /// (uncorrelated `IN` subqueries are evaluated as sub-plans instead), are evaluated for all
+    /// rows of `lf` at once and joined to it. If `expr` is a `filter`, the `EXISTS` and `IN`
+    /// subqueries that it requires to hold filter `lf` by semi and anti joins instead. The names
+    /// of the added columns are appended to `added`; the caller is expected to remove them once
+    /// they are no longer needed.
+    pub(crate) fn decorrelate_subqueries(
+        &mut self,
+        lf: &mut LazyFrame,
+        from: &[TableWithJoins],
+        expr: &mut SQLExpr,
+        added: &mut Vec<PlSmallStr>,
+        filter: bool,
+    ) -> PolarsResult<()> {
+        if filter {
+            #[cfg(feature = "semi_anti_join")]
+            for conjunct in conjuncts_mut(expr) {
+                self.semi_join_subquery(lf, from, conjunct, added)?;
+            }
+        }
+
+        let mut result = Ok(());
+        walk_sql_expr_mut(
+            expr,
+            &mut |e| match self.decorrelate_subquery(lf, from, e, added) {
+                Ok(replaced) => !replaced,
+                Err(err) => {
+                    result = Err(err);
+                    false
+                },
+            },
+        );
+        result
+    }
+
+    /// Filter `lf` by a semi (or anti) join if `conjunct` is a correlated `EXISTS` (or
+    /// `NOT EXISTS`) or `IN` subquery, and replace it with `TRUE`.
+    #[cfg(feature = "semi_anti_join")]
+    fn semi_join_subquery(
+        &mut self,
+        lf: &mut LazyFrame,
+        from: &[TableWithJoins],
+        conjunct: &mut SQLExpr,
+        added: &mut Vec<PlSmallStr>,
+    ) -> PolarsResult<()> {
+        let (subquery, how) = match conjunct {
+            SQLExpr::Exists { subquery, negated } => (
+                subquery,
+                if *negated {
+                    JoinType::Anti
+                } else {
+                    JoinType::Semi
+                },
+            ),
+            SQLExpr::InSubquery {
+                subquery,
+                negated: false,
+                ..
+            } => (subquery, JoinType::Semi),
+            _ => return Ok(()),
+        };
+        let scope = self.outer_scope(lf, from, subquery)?;
+        if !scope.is_correlated(subquery) {
+            return Ok(());
+        }
+        polars_ensure!(
+            subquery.with.is_none(),
+            SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause"
+        );
+        let name = format_pl_smallstr!("__POLARS_SUBQUERY_{}", added.len());
+        let (res, keys) = self.correlated_matches(lf, &scope, conjunct, &name, added)?;
+        let keys = keys.into_iter().map(col).collect::<Vec<_>>();
+        *lf = lf
+            .clone()
+            .join_builder()
+            .with(res)
+            .left_on(&keys)
+            .right_on(&keys)
+            .how(how)
+            .maintain_order(MaintainOrderJoin::Left)
+            .finish();
+        *conjunct = SQLExpr::Value(SQLValue::Boolean(true));
+        Ok(())
+    }
+
+    /// Replace `expr` with a column of `lf` if it is a subquery; returns whether it was.
+    fn decorrelate_subquery(
+        &mut self,
+        lf: &mut LazyFrame,
+        from: &[TableWithJoins],
+        expr: &mut SQLExpr,
+        added: &mut Vec<PlSmallStr>,
+    ) -> PolarsResult<bool> {
+        let subquery = match expr {
+            SQLExpr::Exists { subquery, .. }
+            | SQLExpr::Subquery(subquery)
+            | SQLExpr::InSubquery { subquery, .. } => subquery.as_ref().clone(),
+            _ => return Ok(false),
+        };
+        let scope = self.outer_scope(lf, from, &subquery)?;
+        let correlated = scope.is_correlated(&subquery);
+        if !correlated && matches!(expr, SQLExpr::InSubquery { .. }) {
+            return Ok(false);
+        }
+        polars_ensure!(
+            subquery.with.is_none(),
+            SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause"
+        );
+        let name = format_pl_smallstr!("__POLARS_SUBQUERY_{}", added.len());
+        let column = SQLExpr::Identifier(Ident::new(name.as_str()));
+
+        if !correlated {
+            // uncorrelated subqueries give a single value, shared by all rows
+            let mut res = self.execute_query_no_ctes(&subquery)?;
+            let value = match expr {
+                SQLExpr::Exists { .. } => len().gt(lit(0)),
+                _ => {
+                    let schema = self.get_frame_schema(&mut res)?;
+                    polars_ensure!(
+                        schema.len() == 1,
+                        SQLSyntax: "SQL subquery returns more than one column"
+                    );
+                    let value = schema.get_at_index(0).unwrap().0.clone();
+                    col(value).apply(scalar_subquery_value, GetOutput::same_type())
+                },
+            };
+            *lf = lf
+                .clone()
+                .cross_join(res.select([value.alias(name.clone())]), None);
+            added.push(name);
+            *expr = negate_if_not_exists(expr, column);
+            return Ok(true);
+        }
+
+        // The expression that computes the result column from the joined one.
+        let (res, keys, result) = match expr {
+            SQLExpr::Exists { .. } => {
+                let (res, keys) = self.correlated_matches(lf, &scope, expr, &name, added)?;
+                let res = res
+                    .unique(None, UniqueKeepStrategy::Any)
+                    .with_column(lit(true).alias(name.clone()));
+                (res, keys, Some(col(name.clone()).fill_null(lit(false))))
+            },
+            SQLExpr::InSubquery { .. } => {
+                let (res, keys) = self.correlated_matches(lf, &scope, expr, &name, added)?;
+                let (key, correlation_keys) = keys.split_last().unwrap();
+                let correlation_keys = correlation_keys
+                    .iter()
+                    .cloned()
+                    .map(col)
+                    .collect::<Vec<_>>();
+
+                // Without a match, `IN` is null (unknown) if the key or a value of the subquery
+                // is null, and false otherwise, so that `NOT IN` keeps the row only if it is false.
+                let has_null = format_pl_smallstr!("{}_has_null", name);
+                let nulls = res
+                    .clone()
+                    .group_by(&correlation_keys)
+                    .agg([col(key.clone()).is_null().any(true).alias(has_null.clone())]);
+                *lf = lf
+                    .clone()
+                    .join_builder()
+                    .with(nulls)
+                    .left_on(&correlation_keys)
+                    .right_on(&correlation_keys)
+                    .how(JoinType::Left)
+                    .validate(JoinValidation::ManyToOne)
+                    .maintain_order(MaintainOrderJoin::Left)
+                    .finish();
+                added.push(has_null.clone());
+
+                let res = res
+                    .unique(None, UniqueKeepStrategy::Any)
+                    .with_column(lit(true).alias(name.clone()));
+                let result = when(col(name.clone()).is_not_null())
+                    .then(lit(true))
+                    .when(col(has_null.clone()).is_null())
+                    .then(lit(false))
+                    .when(col(has_null).or(col(key.clone()).is_null()))
+                    .then(lit(NULL).cast(DataType::Boolean))
+                    .otherwise(lit(false));
+                (res, keys, Some(result))
+            },
+            _ => {
+                let item = single_projection(&subquery)?;
+                // a count over no rows is zero, not null
+                let is_count = matches!(
+                    item,
+                    SQLExpr::Function(f) if f.name.to_string().eq_ignore_ascii_case("count")
+                );
+                let group_by_keys = has_aggregate(item);
+                let (mut res, keys) =
+                    self.execute_correlated(lf, &scope, subquery, group_by_keys, &name, added)?;
+                let value = self
+                    .get_frame_schema(&mut res)?
+                    .get_at_index(0)
+                    .unwrap()
+                    .0
+                    .clone();
+                let key_cols = keys.iter().cloned().map(col).collect::<Vec<_>>();
+                let mut res = res.select(
+                    key_cols
+                        .iter()
+                        .cloned()
+                        .chain([col(value).alias(name.clone())])
+                        .collect::<Vec<_>>(),
+                );
+                if !group_by_keys {
+                    // only the rows that belong to an outer row have to be unique
+                    res = lf
+                        .clone()
+                        .select(&key_cols)
+                        .unique(None, UniqueKeepStrategy::Any)
+                        .join(res, &key_cols, &key_cols, JoinArgs::new(JoinType::Inner));
+                }
+                let result = is_count.then(|| col(name.clone()).fill_null(lit(0)));
+                (res, keys, result)
+            },
+        };
+        let validation = match expr {
+            SQLExpr::Subquery(_) => JoinValidation::ManyToOne,
+            _ => JoinValidation::ManyToMany,
+        };
+        let keys = keys.into_iter().map(col).collect::<Vec<_>>();
+        let mut joined = lf
+            .clone()
+            .join_builder()
+            .with(res)
+            .left_on(&keys)
+            .right_on(&keys)
+            .how(JoinType::Left)
+            .validate(validation)
+            .maintain_order(MaintainOrderJoin::Left)
+            .finish();
+        if let Some(result) = result {
+            joined = joined.with_column(result.alias(name.clone()));
+        }
+        *lf = joined;
+        added.push(name);
+        *expr = negate_if_not_exists(expr, column);
+        Ok(true)
+    }
+
+    /// The rows of the correlated `EXISTS` or `IN` subquery `expr` that match a row of `lf`,
+    /// as the columns of join keys that `lf` has columns of the same name for (see
+    /// [`SQLContext::execute_correlated`]).
+    ///
+    /// For an `IN` subquery, the last key is its value, and the column of `lf` the expression
+    /// that is looked up in it.
+    fn correlated_matches(
+        &mut self,
+        lf: &mut LazyFrame,
+        scope: &OuterScope,
+        expr: &SQLExpr,
+        name: &str,
+        added: &mut Vec<PlSmallStr>,
+    ) -> PolarsResult<(LazyFrame, Vec<PlSmallStr>)> {
+        match expr {
+            SQLExpr::Exists { subquery, .. } => {
+                let mut subquery = subquery.as_ref().clone();
+                if let SetExpr::Select(select) = subquery.body.as_mut() {
+                    select.projection.clear();
+                }
+                let (res, keys) =
+                    self.execute_correlated(lf, scope, subquery, false, name, added)?;
+                let res = res.select(keys.iter().cloned().map(col).collect::<Vec<_>>());
+                Ok((res, keys))
+            },
+            SQLExpr::InSubquery {
+                expr: in_expr,
+                subquery,
+                ..
+            } => {
+                single_projection(subquery)?;
+                let key = format_pl_smallstr!("{}_key", name);
+                let schema = self.get_frame_schema(lf)?;
+                let key_expr = parse_sql_expr(in_expr, self, Some(&schema))?;
+                *lf = lf.clone().with_column(key_expr.alias(key.clone()));
+                added.push(key.clone());
+                let key_dtype = self.get_frame_schema(lf)?.get(&key).unwrap().clone();
+
+                let subquery = subquery.as_ref().clone();
+                let (mut res, mut keys) =
+                    self.execute_correlated(lf, scope, subquery, false, name, added)?;
+                let value = self
+                    .get_frame_schema(&mut res)?
+                    .get_at_index(0)
+                    .unwrap()
+                    .0
+                    .clone();
+                let res = res.select(
+                    keys.iter()
+                        .cloned()
+                        .map(col)
+                        .chain([col(value).cast(key_dtype).alias(key.clone())])
+                        .collect::<Vec<_>>(),
+                );
+                keys.push(key);
+                Ok((res, keys))
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Execute the correlated `subquery` for all rows of `lf` at once.
+    ///
+    /// The equality predicates of its `WHERE` clause between an expression of the subquery and
+    /// one of the outer query become join keys: the subquery projects its side of them (and,
+    /// if `group_by_keys` or it has a `GROUP BY`, groups by them), and `lf` gets columns of the
+    /// same names for the other side, whose names are returned. If the subquery refers to the
+    /// outer query in any other way, the key is the row index of `lf` instead, see
+    /// [`SQLContext::execute_correlated_subquery`].
+    fn execute_correlated(
+        &mut self,
+        lf: &mut LazyFrame,
+        scope: &OuterScope,
+        mut subquery: Query,
+        group_by_keys: bool,
+        name: &str,
+        added: &mut Vec<PlSmallStr>,
+    ) -> PolarsResult<(LazyFrame, Vec<PlSmallStr>)> {
+        polars_ensure!(
+            subquery.limit.is_none() && subquery.offset.is_none() && subquery.fetch.is_none(),
+            SQLInterface: "correlated subqueries do not (yet) support LIMIT, OFFSET or FETCH"
+        );
+        let SetExpr::Select(select) = subquery.body.as_mut() else {
+            polars_bail!(SQLInterface: "correlated subqueries must be a SELECT");
+        };
+        let mut predicates = vec![];
+        let mut equalities = vec![];
+        for predicate in select.selection.clone().map(conjuncts).unwrap_or_default() {
+            match scope.equality(&predicate) {
+                Some(equality) => equalities.push(equality),
+                None => predicates.push(predicate),
+            }
+        }
+        let selection = predicates
+            .into_iter()
+            .reduce(|left, right| SQLExpr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            });
+        let decorrelated = Select {
+            selection,
+            ..select.as_ref().clone()
+        };
+        if equalities.is_empty() || scope.is_correlated_select(&decorrelated) {
+            let index = outer_index_name(&self.get_frame_schema(lf)?);
+            *lf = lf.clone().with_row_index(index.clone(), None);
+            added.push(index.clone());
+            let outer_name = scope.referenced_relation(select).unwrap_or(OUTER_FRAME);
+            let res = self.execute_correlated_subquery(
+                outer_name,
+                lf.clone(),
+                &subquery,
+                &index,
+                group_by_keys,
+            )?;
+            return Ok((res, vec![index]));
+        }
+
+        **select = decorrelated;
+        let schema = self.get_frame_schema(lf)?;
+        let group_by = group_by_keys
+            || matches!(&select.group_by, GroupByExpr::Expressions(exprs, _) if !exprs.is_empty());
+        let mut keys = Vec::with_capacity(equalities.len());
+        let mut outer_keys = Vec::with_capacity(equalities.len());
+        for (i, (inner, outer)) in equalities.into_iter().enumerate() {
+            let key = format_pl_smallstr!("{}_key_{}", name, i);
+            outer_keys.push(parse_sql_expr(&outer, self, Some(&schema))?.alias(key.clone()));
+            select.projection.push(SelectItem::ExprWithAlias {
+                expr: inner.clone(),
+                alias: Ident::new(key.as_str()),
+            });
+            if let GroupByExpr::Expressions(exprs, _) = &mut select.group_by {
+                if group_by {
+                    exprs.push(inner);
+                }
+            }
+            keys.push(key);
+        }
+        *lf = lf.clone().with_columns(outer_keys);
+        added.extend(keys.iter().cloned());
+
+        // the keys of both sides must have the same type to be joined
+        let schema = self.get_frame_schema(lf)?;
+        let res = self.execute_query_no_ctes(&subquery)?.with_columns(
+            keys.iter()
+                .map(|key| col(key.clone()).cast(schema.get(key).unwrap().clone()))
+                .collect::<Vec<_>>(),
+        );
+        Ok((res, keys))
+    }
+
+    /// The scope of the outer query, of `lf` and the relations `from`, within `subquery`.
+    fn outer_scope(
+        &mut self,
+        lf: &mut LazyFrame,
+        from: &[TableWithJoins],
+        subquery: &Query,
+    ) -> PolarsResult<OuterScope> {
+        let (inner_from, aliases) = match subquery.body.as_ref() {
+            SetExpr::Select(select) => (
+                select.from.as_slice(),
+                select
+                    .projection
+                    .iter()
+                    .filter_map(|item| match item {
+                        SelectItem::ExprWithAlias { alias, .. } => Some(alias.value.clone()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            _ => (&[][..], vec![]),
+        };
+        let inner_names = inner_from
+            .iter()
+            .flat_map(relation_names)
+            .collect::<Vec<_>>();
+        let relations = from
+            .iter()
+            .flat_map(relation_names)
+            .filter(|name| !inner_names.contains(name))
+            .collect();
+
+        // unqualified names refer to the outer query only if they are no columns of the
+        // subquery, which is only known if its relations are tables
+        let mut inner_schemas = vec![];
+        for relation in inner_from.iter().flat_map(table_factors) {
+            let tbl = match relation {
+                TableFactor::Table {
+                    name, args: None, ..
+                } => match name.0.as_slice() {
+                    [tbl_name] => self.get_table_from_current_scope(&tbl_name.value),
+                    _ => None,
+                },
+                _ => None,
+            };
+            let Some(mut tbl) = tbl else {
+                return Ok(OuterScope {
+                    relations,
+                    columns: PlHashSet::new(),
+                });
+            };
+            inner_schemas.push(self.get_frame_schema(&mut tbl)?);
+        }
+        let columns = self
+            .get_frame_schema(lf)?
+            .iter_names()
+            .filter(|name| {
+                !aliases.iter().any(|alias| alias == name.as_str())
+                    && !inner_schemas.iter().any(|schema| schema.contains(name))
+            })
+            .map(|name| name.to_string())
+            .collect();
+        Ok(OuterScope { relations, columns })
+    }
+}
+
+/// The columns of an outer query that can be referred to within a subquery.
+struct OuterScope {
+    /// The names of the relations of the outer query that the subquery does not shadow.
+    relations: Vec<String>,
+    /// The columns of the outer query that can be referred to without qualification.
+    columns: PlHashSet<String>,
+}
+
+impl OuterScope {
+    /// Whether `expr` is a column of the outer query.
+    fn is_outer_column(&self, expr: &SQLExpr) -> bool {
+        match expr {
+            SQLExpr::CompoundIdentifier(idents) => match idents.as_slice() {
+                [relation, _] => self.relations.contains(&relation.value),
+                _ => false,
+            },
+            SQLExpr::Identifier(ident) => self.columns.contains(&ident.value),
+            _ => false,
+        }
+    }
+
+    /// Whether `expr` refers to columns of the outer query, and whether it refers to other
+    /// columns (or has subqueries).
+    fn references(&self, expr: &SQLExpr) -> (bool, bool) {
+        let (mut outer, mut inner) = (false, false);
+        walk_sql_expr_mut(&mut expr.clone(), &mut |e| {
+            match e {
+                SQLExpr::Identifier(_) | SQLExpr::CompoundIdentifier(_) => {
+                    if self.is_outer_column(e) {
+                        outer = true;
+                    } else {
+                        inner = true;
+                    }
+                },
+                // the subqueries of the subquery can only qualify the columns of the outer query
+                SQLExpr::Exists { subquery, .. }
+                | SQLExpr::Subquery(subquery)
+                | SQLExpr::InSubquery { subquery, .. } => {
+                    let scope = OuterScope {
+                        relations: self.relations.clone(),
+                        columns: PlHashSet::new(),
+                    };
+                    outer |= scope.is_correlated(subquery);
+                    inner = true;
+                },
+                _ => {},
+            }
+            true
+        });
+        (outer, inner)
+    }
+
+    /// The inner and the outer side of `predicate` if it is an equality between an expression
+    /// of the subquery and one of the outer query.
+    fn equality(&self, predicate: &SQLExpr) -> Option<(SQLExpr, SQLExpr)> {
+        let SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } = predicate
+        else {
+            return None;
+        };
+        match (self.references(left), self.references(right)) {
+            ((true, false), (false, true)) => Some((right.as_ref().clone(), left.as_ref().clone())),
+            ((false, true), (true, false)) => Some((left.as_ref().clone(), right.as_ref().clone())),
+            _ => None,
+        }
+    }
+
+    /// Whether `subquery` refers to columns of the outer query.
+    fn is_correlated(&self, subquery: &Query) -> bool {
+        match subquery.body.as_ref() {
+            SetExpr::Select(select) => self.is_correlated_select(select),
+            _ => false,
+        }
+    }
+
+    /// Whether `select` refers to columns of the outer query.
+    fn is_correlated_select(&self, select: &Select) -> bool {
+        select_exprs(select).any(|e| self.references(e).0)
+    }
+
+    /// The first relation of the outer query that `select` qualifies a column with.
+    fn referenced_relation<'a>(&'a self, select: &Select) -> Option<&'a str> {
+        let mut found = None;
+        for expr in select_exprs(select) {
+            walk_sql_expr_mut(&mut expr.clone(), &mut |e| {
+                if let SQLExpr::CompoundIdentifier(idents) = e {
+                    if let [relation, _] = idents.as_slice() {
+                        found = found.or_else(|| {
+                            self.relations
+                                .iter()
+                                .find(|name| **name == relation.value)
+                                .map(String::as_str)
+                        });
+                    }
+                }
+                found.is_none()
+            });
+        }
+        found.or_else(|| self.relations.first().map(String::as_str))
+    }
+}
+
+/// The value of an uncorrelated scalar subquery: its only row, or null if it has no rows.
+fn scalar_subquery_value(c: Column) -> PolarsResult<Option<Column>> {
+    polars_ensure!(
+        c.len() <= 1,
+        SQLInterface: "SQL scalar subquery returned more than one row"
+    );
+    Ok(Some(if c.is_empty() {
+        Column::full_null(c.name().clone(), 1, c.dtype())
+    } else {
+        c
+    }))
+}
+
+/// The `column` that replaces a subquery expression, negated for `NOT EXISTS` and `NOT IN`.
+fn negate_if_not_exists(expr: &SQLExpr, column: SQLExpr) -> SQLExpr {
+    match expr {
+        SQLExpr::Exists { negated: true, .. } | SQLExpr::InSubquery { negated: true, .. } => {
+            SQLExpr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr: Box::new(column),
+            }
+        },
+        _ => column,
+    }
+}
+
+/// The expression of the only projection of a subquery.
+fn single_projection(subquery: &Query) -> PolarsResult<&SQLExpr> {
+    match subquery.body.as_ref() {
+        SetExpr::Select(select) => match select.projection.as_slice() {
+            [SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. }] => Ok(expr),
+            _ => polars_bail!(SQLSyntax: "SQL subquery must return exactly one column"),
+        },
+        _ => polars_bail!(SQLInterface: "correlated subqueries must be a SELECT"),
+    }
+}
+
+/// Whether `expr` calls an aggregate function (outside of a window).
+fn has_aggregate(expr: &SQLExpr) -> bool {
+    let mut found = false;
+    walk_sql_expr_mut(&mut expr.clone(), &mut |e| {
+        if let SQLExpr::Function(f) = e {
+            let name = f.name.to_string().to_lowercase();
+            found |= f.over.is_none() && AGGREGATE_FUNCTIONS.contains(&name.as_str());
+        }
+        !found
+    });
+    found
+}
+
+/// The relations of `tbl`.
+fn table_factors(tbl: &TableWithJoins) -> impl Iterator<Item = &TableFactor> {
+    std::iter::once(&tbl.relation).chain(tbl.joins.iter().map(|join| &join.relation))
+}
+
+/// The names by which the columns of the relations of `tbl` can be qualified.
+fn relation_names(tbl: &TableWithJoins) -> Vec<String> {
+    table_factors(tbl)
+        .filter_map(|relation| match relation {
+            TableFactor::Table { name, alias, .. } => Some(match alias {
+                Some(alias) => alias.name.value.clone(),
+                None => name.0.last()?.value.clone(),
+            }),
+            TableFactor::Derived { alias, .. }
+            | TableFactor::UNNEST { alias, .. }
+            | TableFactor::NestedJoin { alias, .. } => Some(alias.as_ref()?.name.value.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The expressions of `select` that can refer to the columns of an outer query.
+fn select_exprs(select: &Select) -> impl Iterator<Item = &SQLExpr> {
+    let projection = select.projection.iter().filter_map(|item| match item {
+        SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => Some(expr),
+        _ => None,
+    });
+    let group_by = match &select.group_by {
+        GroupByExpr::Expressions(exprs, _) => exprs.as_slice(),
+        _ => &[],
+    };
+    projection
+        .chain(select.selection.iter())
+        .chain(group_by)
+        .chain(select.having.iter())
+        .chain(select.qualify.iter())
+}
+
+/// The conjuncts of `expr`, the operands of its top-level `AND`s.
+fn conjuncts(expr: SQLExpr) -> Vec<SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut out = conjuncts(*left);
+            out.extend(conjuncts(*right));
+            out
+        },
+        expr => vec![expr],
+    }
+}
+
+/// The conjuncts of `expr`, see [`conjuncts`].
+#[cfg(feature = "semi_anti_join")]
+fn conjuncts_mut(expr: &mut SQLExpr) -> Vec<&mut SQLExpr> {
+    if !matches!(
+        expr,
+        SQLExpr::BinaryOp {
+            op: BinaryOperator::And,
+            ..
+        }
+    ) {
+        return vec![expr];
+    }
+    let SQLExpr::BinaryOp { left, right, .. } = expr else {
+        unreachable!()
+    };
+    let mut out = conjuncts_mut(left);
+    out.extend(conjuncts_mut(right));
+    out
+}
+
+/// Call `f` on `expr` and the expressions nested in it, outermost first; the expressions
+/// nested in an expression for which `f` returns `false` are skipped. Subqueries are not
+/// entered.
+fn walk_sql_expr_mut(expr: &mut SQLExpr, f: &mut impl FnMut(&mut SQLExpr) -> bool) {
+    if !f(expr) {
+        return;
+    }
+    match expr {
+        SQLExpr::BinaryOp { left, right, .. } => {
+            walk_sql_expr_mut(left, f);
+            walk_sql_expr_mut(right, f);
+        },
+        SQLExpr::UnaryOp { expr, .. }
+        | SQLExpr::Nested(expr)
+        | SQLExpr::Cast { expr, .. }
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsNotNull(expr)
+        | SQLExpr::IsTrue(expr)
+        | SQLExpr::IsNotTrue(expr)
+        | SQLExpr::IsFalse(expr)
+        | SQLExpr::IsNotFalse(expr)
+        | SQLExpr::InSubquery { expr, .. } => walk_sql_expr_mut(expr, f),
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => {
+            walk_sql_expr_mut(expr, f);
+            walk_sql_expr_mut(low, f);
+            walk_sql_expr_mut(high, f);
+        },
+        SQLExpr::InList { expr, list, .. } => {
+            walk_sql_expr_mut(expr, f);
+            list.iter_mut().for_each(|e| walk_sql_expr_mut(e, f));
+        },
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => {
+            operand
+                .iter_mut()
+                .chain(else_result.iter_mut())
+                .for_each(|e| walk_sql_expr_mut(e, f));
+            conditions
+                .iter_mut()
+                .chain(results.iter_mut())
+                .for_each(|e| walk_sql_expr_mut(e, f));
+        },
+        SQLExpr::Function(function) => {
+            if let FunctionArguments::List(list) = &mut function.args {
+                for arg in &mut list.args {
+                    if let FunctionArg::Unnamed(FunctionArgExpr::Expr(e))
+                    | FunctionArg::Named {
+                        arg: FunctionArgExpr::Expr(e),
+                        ..
+                    } = arg
+                    {
+                        walk_sql_expr_mut(e, f);
+                    }
+                }
+            }
+        },
+        _ => {},
+    }
+}
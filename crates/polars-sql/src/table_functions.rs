@@ -1,17 +1,14 @@
 use std::str::FromStr;
 
-#[cfg(any(
-    feature = "csv",
-    feature = "parquet",
-    feature = "ipc",
-    feature = "json"
-))]
-use polars_core::prelude::polars_ensure;
-use polars_core::prelude::{PolarsError, PolarsResult, polars_bail};
+use polars_core::prelude::{PlSmallStr, PolarsError, PolarsResult, polars_bail, polars_ensure};
 #[cfg(feature = "csv")]
 use polars_lazy::prelude::LazyCsvReader;
 use polars_lazy::prelude::LazyFrame;
-use sqlparser::ast::{FunctionArg, FunctionArgExpr};
+#[cfg(feature = "ipc")]
+use polars_lazy::prelude::ScanArgsIpc;
+#[cfg(feature = "parquet")]
+use polars_lazy::prelude::ScanArgsParquet;
+use sqlparser::ast::{Expr as SQLExpr, FunctionArg, FunctionArgExpr, Value as SQLValue};
 
 /// Table functions that are supported by Polars
 #[allow(clippy::enum_variant_names)]
@@ -19,12 +16,14 @@ pub(crate) enum PolarsTableFunctions {
     /// SQL 'read_csv' function.
     /// ```sql
     /// SELECT * FROM read_csv('path/to/file.csv')
+    /// SELECT * FROM read_csv('path/to/*.csv', delim => ';', header => false)
     /// ```
     #[cfg(feature = "csv")]
     ReadCsv,
     /// SQL 'read_parquet' function.
     /// ```sql
     /// SELECT * FROM read_parquet('path/to/file.parquet')
+    /// SELECT * FROM read_parquet('s3://bucket/*.parquet', hive_partitioning => true)
     /// ```
     #[cfg(feature = "parquet")]
     ReadParquet,
@@ -79,49 +78,156 @@ impl PolarsTableFunctions {
 
     #[cfg(feature = "csv")]
     fn read_csv(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_csv` expects a single file path; found {:?} arguments", args.len());
-
         use polars_lazy::frame::LazyFileListReader;
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(&path)
-            .with_try_parse_dates(true)
-            .with_missing_is_null(true)
-            .finish()?;
-        Ok((path, lf))
+
+        let (path, mut opts) = self.get_path_and_options(args, "read_csv")?;
+        let mut reader = LazyCsvReader::new(&path)
+            .with_try_parse_dates(opts.take_bool("try_parse_dates")?.unwrap_or(true))
+            .with_missing_is_null(opts.take_bool("missing_is_null")?.unwrap_or(true));
+        if let Some(separator) = opts.take_byte(&["delim", "sep", "separator"])? {
+            reader = reader.with_separator(separator);
+        }
+        if let Some(has_header) = opts.take_bool("header")? {
+            reader = reader.with_has_header(has_header);
+        }
+        if let Some(quote_char) = opts.take_byte(&["quote", "quote_char"])? {
+            reader = reader.with_quote_char(Some(quote_char));
+        }
+        if let Some(prefix) = opts.take_string("comment_prefix")? {
+            reader = reader.with_comment_prefix(Some(prefix.into()));
+        }
+        if let Some(skip_rows) = opts.take_usize("skip_rows")? {
+            reader = reader.with_skip_rows(skip_rows);
+        }
+        if let Some(n_rows) = opts.take_usize("n_rows")? {
+            reader = reader.with_n_rows(Some(n_rows));
+        }
+        if let Some(length) = opts.take_usize("infer_schema_length")? {
+            reader = reader.with_infer_schema_length(Some(length));
+        }
+        if let Some(ignore_errors) = opts.take_bool("ignore_errors")? {
+            reader = reader.with_ignore_errors(ignore_errors);
+        }
+        if let Some(decimal_comma) = opts.take_bool("decimal_comma")? {
+            reader = reader.with_decimal_comma(decimal_comma);
+        }
+        if let Some(truncate) = opts.take_bool("truncate_ragged_lines")? {
+            reader = reader.with_truncate_ragged_lines(truncate);
+        }
+        if let Some(glob) = opts.take_bool("glob")? {
+            reader = reader.with_glob(glob);
+        }
+        reader = reader.with_include_file_paths(opts.take_name("include_file_paths")?);
+        opts.finish()?;
+        Ok((path, reader.finish()?))
     }
 
     #[cfg(feature = "parquet")]
     fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_parquet` expects a single file path; found {:?} arguments", args.len());
+        let (path, mut opts) = self.get_path_and_options(args, "read_parquet")?;
+        let defaults = ScanArgsParquet::default();
+        let mut scan_args = ScanArgsParquet {
+            n_rows: opts.take_usize("n_rows")?,
+            glob: opts.take_bool("glob")?.unwrap_or(defaults.glob),
+            use_statistics: opts
+                .take_bool("use_statistics")?
+                .unwrap_or(defaults.use_statistics),
+            allow_missing_columns: opts
+                .take_bool("allow_missing_columns")?
+                .unwrap_or(defaults.allow_missing_columns),
+            include_file_paths: opts.take_name("include_file_paths")?,
+            ..defaults
+        };
+        scan_args.hive_options.enabled = opts.take_bool("hive_partitioning")?;
+        if let Some(try_parse_dates) = opts.take_bool("try_parse_hive_dates")? {
+            scan_args.hive_options.try_parse_dates = try_parse_dates;
+        }
+        opts.finish()?;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+        let lf = LazyFrame::scan_parquet(&path, scan_args)?;
         Ok((path, lf))
     }
 
     #[cfg(feature = "ipc")]
     fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ipc` expects a single file path; found {:?} arguments", args.len());
+        let (path, mut opts) = self.get_path_and_options(args, "read_ipc")?;
+        let mut scan_args = ScanArgsIpc {
+            n_rows: opts.take_usize("n_rows")?,
+            include_file_paths: opts.take_name("include_file_paths")?,
+            ..Default::default()
+        };
+        scan_args.hive_options.enabled = opts.take_bool("hive_partitioning")?;
+        opts.finish()?;
 
-        let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(&path, Default::default())?;
+        let lf = LazyFrame::scan_ipc(&path, scan_args)?;
         Ok((path, lf))
     }
+
     #[cfg(feature = "json")]
     fn read_ndjson(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
-        polars_ensure!(args.len() == 1, SQLSyntax: "`read_ndjson` expects a single file path; found {:?} arguments", args.len());
+        use std::num::NonZeroUsize;
 
         use polars_lazy::frame::LazyFileListReader;
         use polars_lazy::prelude::LazyJsonLineReader;
 
+        let (path, mut opts) = self.get_path_and_options(args, "read_json")?;
+        let mut reader = LazyJsonLineReader::new(path.clone())
+            .with_n_rows(opts.take_usize("n_rows")?)
+            .with_include_file_paths(opts.take_name("include_file_paths")?);
+        if let Some(ignore_errors) = opts.take_bool("ignore_errors")? {
+            reader = reader.with_ignore_errors(ignore_errors);
+        }
+        if let Some(length) = opts.take_usize("infer_schema_length")? {
+            reader = reader.with_infer_schema_length(NonZeroUsize::new(length));
+        }
+        opts.finish()?;
+        Ok((path, reader.finish()?))
+    }
+
+    /// Split the arguments of a table function into the file path (its only positional
+    /// argument) and the named options that follow it (as in `name => value`).
+    #[allow(dead_code)]
+    fn get_path_and_options<'a>(
+        &self,
+        args: &'a [FunctionArg],
+        function: &'static str,
+    ) -> PolarsResult<(String, TableFunctionOptions<'a>)> {
+        let n_positional = args
+            .iter()
+            .filter(|arg| matches!(arg, FunctionArg::Unnamed(_)))
+            .count();
+        polars_ensure!(
+            n_positional == 1 && matches!(args.first(), Some(FunctionArg::Unnamed(_))),
+            SQLSyntax: "`{}` expects a single file path; found {} arguments",
+            function, n_positional
+        );
         let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyJsonLineReader::new(path.clone()).finish()?;
-        Ok((path, lf))
+        let mut options: Vec<(String, &SQLExpr)> = vec![];
+        for arg in &args[1..] {
+            let (name, value) = match arg {
+                FunctionArg::Named {
+                    name,
+                    arg: FunctionArgExpr::Expr(value),
+                    ..
+                } => (name.value.to_lowercase(), value),
+                FunctionArg::ExprNamed {
+                    name: SQLExpr::Identifier(name),
+                    arg: FunctionArgExpr::Expr(value),
+                    ..
+                } => (name.value.to_lowercase(), value),
+                _ => polars_bail!(SQLSyntax: "invalid `{}` option: {}", function, arg),
+            };
+            polars_ensure!(
+                options.iter().all(|(n, _)| *n != name),
+                SQLSyntax: "`{}` option '{}' is given more than once", function, name
+            );
+            options.push((name, value));
+        }
+        Ok((path, TableFunctionOptions { function, options }))
     }
 
     #[allow(dead_code)]
     fn get_file_path_from_arg(&self, arg: &FunctionArg) -> PolarsResult<String> {
-        use sqlparser::ast::{Expr as SQLExpr, Value as SQLValue};
         match arg {
             FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::Value(
                 SQLValue::SingleQuotedString(s),
@@ -134,6 +240,84 @@ impl PolarsTableFunctions {
     }
 }
 
+/// The named options of a table function, which are taken (and validated) one by one.
+#[allow(dead_code)]
+struct TableFunctionOptions<'a> {
+    function: &'static str,
+    options: Vec<(String, &'a SQLExpr)>,
+}
+
+#[allow(dead_code)]
+impl TableFunctionOptions<'_> {
+    /// Take the value of the first of the given option names that is present.
+    fn take(&mut self, names: &[&str]) -> Option<(String, &SQLExpr)> {
+        let idx = self
+            .options
+            .iter()
+            .position(|(name, _)| names.contains(&name.as_str()))?;
+        Some(self.options.remove(idx))
+    }
+
+    fn take_bool(&mut self, name: &str) -> PolarsResult<Option<bool>> {
+        match self.take(&[name]) {
+            None => Ok(None),
+            Some((_, SQLExpr::Value(SQLValue::Boolean(b)))) => Ok(Some(*b)),
+            Some((name, v)) => self.invalid(&name, v, "a boolean"),
+        }
+    }
+
+    fn take_usize(&mut self, name: &str) -> PolarsResult<Option<usize>> {
+        match self.take(&[name]) {
+            None => Ok(None),
+            Some((name, v)) => match v {
+                SQLExpr::Value(SQLValue::Number(n, _)) if n.parse::<usize>().is_ok() => {
+                    Ok(n.parse().ok())
+                },
+                _ => self.invalid(&name, v, "a non-negative integer"),
+            },
+        }
+    }
+
+    fn take_string(&mut self, name: &str) -> PolarsResult<Option<String>> {
+        match self.take(&[name]) {
+            None => Ok(None),
+            Some((_, SQLExpr::Value(SQLValue::SingleQuotedString(s)))) => Ok(Some(s.clone())),
+            Some((name, v)) => self.invalid(&name, v, "a single-quoted string"),
+        }
+    }
+
+    fn take_name(&mut self, name: &str) -> PolarsResult<Option<PlSmallStr>> {
+        Ok(self.take_string(name)?.map(PlSmallStr::from))
+    }
+
+    /// Take a single-character option, given under any of the `names`.
+    fn take_byte(&mut self, names: &[&str]) -> PolarsResult<Option<u8>> {
+        match self.take(names) {
+            None => Ok(None),
+            Some((_, SQLExpr::Value(SQLValue::SingleQuotedString(s)))) if s.len() == 1 => {
+                Ok(Some(s.as_bytes()[0]))
+            },
+            Some((name, v)) => self.invalid(&name, v, "a single (ascii) character"),
+        }
+    }
+
+    fn invalid<T>(&self, name: &str, value: &SQLExpr, expected: &str) -> PolarsResult<T> {
+        polars_bail!(
+            SQLSyntax: "`{}` option '{}' expects {}; found {}", self.function, name, expected, value
+        )
+    }
+
+    /// Raise an error for the options that were not taken.
+    fn finish(self) -> PolarsResult<()> {
+        match self.options.first() {
+            None => Ok(()),
+            Some((name, _)) => {
+                polars_bail!(SQLSyntax: "`{}` has no option '{}'", self.function, name)
+            },
+        }
+    }
+}
+
 impl PolarsTableFunctions {
     // list sql names of all table functions
     pub(crate) fn keywords() -> &'static [&'static str] {
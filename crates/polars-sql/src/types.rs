@@ -197,3 +197,41 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
         },
     })
 }
+
+/// The SQL name of a Polars datatype, as shown by `DESCRIBE` and `information_schema.columns`.
+///
+/// Datatypes without a SQL equivalent are shown with their Polars name.
+pub(crate) fn map_polars_dtype_to_sql(dtype: &DataType) -> String {
+    match dtype {
+        DataType::Boolean => "BOOLEAN".to_string(),
+        DataType::Int8 => "TINYINT".to_string(),
+        DataType::Int16 => "SMALLINT".to_string(),
+        DataType::Int32 => "INTEGER".to_string(),
+        DataType::Int64 => "BIGINT".to_string(),
+        DataType::Int128 => "HUGEINT".to_string(),
+        DataType::UInt8 => "UTINYINT".to_string(),
+        DataType::UInt16 => "USMALLINT".to_string(),
+        DataType::UInt32 => "UINTEGER".to_string(),
+        DataType::UInt64 => "UBIGINT".to_string(),
+        DataType::Float32 => "REAL".to_string(),
+        DataType::Float64 => "DOUBLE".to_string(),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(Some(p), Some(s)) => format!("DECIMAL({p},{s})"),
+        DataType::String => "VARCHAR".to_string(),
+        DataType::Binary => "BLOB".to_string(),
+        DataType::Date => "DATE".to_string(),
+        DataType::Time => "TIME".to_string(),
+        DataType::Datetime(tu, tz) => {
+            let precision = match tu {
+                TimeUnit::Milliseconds => "(3)",
+                TimeUnit::Microseconds => "",
+                TimeUnit::Nanoseconds => "(9)",
+            };
+            let tz = if tz.is_some() { " WITH TIME ZONE" } else { "" };
+            format!("TIMESTAMP{precision}{tz}")
+        },
+        DataType::Duration(_) => "INTERVAL".to_string(),
+        DataType::List(inner) => format!("{}[]", map_polars_dtype_to_sql(inner)),
+        _ => dtype.to_string(),
+    }
+}
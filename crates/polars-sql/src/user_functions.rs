@@ -0,0 +1,201 @@
+//! Scalar and aggregate functions defined in Rust, which can be called from SQL once they are
+//! registered with [`SQLContext::register_function`](crate::SQLContext::register_function) or
+//! [`SQLContext::register_aggregate_function`](crate::SQLContext::register_aggregate_function).
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_plan::prelude::*;
+
+type ScalarFn = dyn Fn(&[Series]) -> PolarsResult<Series> + Send + Sync;
+
+/// A scalar function, called with the values of its arguments (one [`Series`] per argument)
+/// and returning a [`Series`] of the same length.
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_sql::SQLScalarFunction;
+/// let add = SQLScalarFunction::new(|args: &[Series]| &args[0] + &args[1]);
+/// let ratio = SQLScalarFunction::new(|args: &[Series]| {
+///     &args[0].cast(&DataType::Float64)? / &args[1]
+/// })
+/// .with_return_type(DataType::Float64);
+/// ```
+#[derive(Clone)]
+pub struct SQLScalarFunction {
+    function: Arc<ScalarFn>,
+    return_type: Option<DataType>,
+}
+
+impl SQLScalarFunction {
+    /// Create a scalar function from a closure over the argument values.
+    pub fn new(
+        function: impl Fn(&[Series]) -> PolarsResult<Series> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            function: Arc::new(function),
+            return_type: None,
+        }
+    }
+
+    /// Set the type of the result; by default it is the type of the first argument.
+    pub fn with_return_type(mut self, dtype: DataType) -> Self {
+        self.return_type = Some(dtype);
+        self
+    }
+}
+
+/// The state-handling closures of an aggregate function, with the type of its state erased.
+trait Aggregator: Send + Sync {
+    fn aggregate(&self, args: &[Series]) -> PolarsResult<Scalar>;
+}
+
+struct AggregatorFns<S, I, U, M, F> {
+    init: I,
+    update: U,
+    merge: M,
+    finalize: F,
+    _state: std::marker::PhantomData<fn() -> S>,
+}
+
+impl<S, I, U, M, F> Aggregator for AggregatorFns<S, I, U, M, F>
+where
+    I: Fn() -> S + Send + Sync,
+    U: Fn(&mut S, &[Series]) -> PolarsResult<()> + Send + Sync,
+    M: Fn(&mut S, S) -> PolarsResult<()> + Send + Sync,
+    F: Fn(S) -> PolarsResult<Scalar> + Send + Sync,
+{
+    fn aggregate(&self, args: &[Series]) -> PolarsResult<Scalar> {
+        // every chunk of the group is aggregated into a state of its own, and these
+        // states are merged into the state of the group
+        let mut state = (self.init)();
+        let mut offset = 0;
+        for len in args[0].chunk_lengths().collect::<Vec<_>>() {
+            let batch = args
+                .iter()
+                .map(|s| s.slice(offset as i64, len))
+                .collect::<Vec<_>>();
+            let mut batch_state = (self.init)();
+            (self.update)(&mut batch_state, &batch)?;
+            (self.merge)(&mut state, batch_state)?;
+            offset += len;
+        }
+        (self.finalize)(state)
+    }
+}
+
+/// An aggregate function, which reduces the values of its arguments in a group (or in the
+/// whole frame, without `GROUP BY`) to a single value.
+///
+/// The aggregation starts from a state created by `init`. Batches of the values of the
+/// arguments are added to a state with `update`, states of different batches of the same
+/// group are combined with `merge`, and `finalize` turns the state into the result.
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_sql::SQLAggregateFunction;
+/// // the sum of the squares of the (non-null) values
+/// let sum_squares = SQLAggregateFunction::new(
+///     || 0.0,
+///     |acc: &mut f64, args: &[Series]| {
+///         let values = args[0].cast(&DataType::Float64)?;
+///         *acc += values.f64()?.into_no_null_iter().map(|v| v * v).sum::<f64>();
+///         Ok(())
+///     },
+///     |acc: &mut f64, other: f64| {
+///         *acc += other;
+///         Ok(())
+///     },
+///     |acc: f64| Ok(acc.into()),
+/// )
+/// .with_return_type(DataType::Float64);
+/// ```
+#[derive(Clone)]
+pub struct SQLAggregateFunction {
+    aggregator: Arc<dyn Aggregator>,
+    return_type: Option<DataType>,
+}
+
+impl SQLAggregateFunction {
+    /// Create an aggregate function from its `init`, `update`, `merge` and `finalize` steps.
+    pub fn new<S, I, U, M, F>(init: I, update: U, merge: M, finalize: F) -> Self
+    where
+        S: 'static,
+        I: Fn() -> S + Send + Sync + 'static,
+        U: Fn(&mut S, &[Series]) -> PolarsResult<()> + Send + Sync + 'static,
+        M: Fn(&mut S, S) -> PolarsResult<()> + Send + Sync + 'static,
+        F: Fn(S) -> PolarsResult<Scalar> + Send + Sync + 'static,
+    {
+        Self {
+            aggregator: Arc::new(AggregatorFns {
+                init,
+                update,
+                merge,
+                finalize,
+                _state: std::marker::PhantomData,
+            }),
+            return_type: None,
+        }
+    }
+
+    /// Set the type of the result; by default it is the type of the first argument.
+    pub fn with_return_type(mut self, dtype: DataType) -> Self {
+        self.return_type = Some(dtype);
+        self
+    }
+}
+
+/// A function registered on a [`SQLContext`](crate::SQLContext).
+#[derive(Clone)]
+pub(crate) enum UserFunction {
+    Scalar(SQLScalarFunction),
+    Aggregate(SQLAggregateFunction),
+}
+
+impl UserFunction {
+    /// The expression that calls the function with the given arguments.
+    pub(crate) fn call(&self, name: &str, args: Vec<Expr>) -> PolarsResult<Expr> {
+        polars_ensure!(
+            !args.is_empty(),
+            SQLSyntax: "user-defined function '{}' expects at least one argument", name
+        );
+        let output_type = |dtype: &Option<DataType>| match dtype {
+            Some(dtype) => GetOutput::from_type(dtype.clone()),
+            None => GetOutput::same_type(),
+        };
+        Ok(match self {
+            Self::Scalar(f) => {
+                let function = f.function.clone();
+                map_multiple(
+                    move |c: &mut [Column]| {
+                        let args = materialized_series(c);
+                        let out = function(&args)?;
+                        Ok(Some(out.with_name(args[0].name().clone()).into_column()))
+                    },
+                    args,
+                    output_type(&f.return_type),
+                )
+            },
+            Self::Aggregate(f) => {
+                let aggregator = f.aggregator.clone();
+                let return_type = f.return_type.clone();
+                apply_multiple(
+                    move |c: &mut [Column]| {
+                        let args = materialized_series(c);
+                        let value = aggregator.aggregate(&args)?;
+                        let dtype = return_type.as_ref().unwrap_or(args[0].dtype());
+                        let out = value.into_series(args[0].name().clone()).cast(dtype)?;
+                        Ok(Some(out.into_column()))
+                    },
+                    args,
+                    output_type(&f.return_type),
+                    true,
+                )
+            },
+        })
+    }
+}
+
+fn materialized_series(columns: &[Column]) -> Vec<Series> {
+    columns
+        .iter()
+        .map(|c| c.as_materialized_series().clone())
+        .collect()
+}
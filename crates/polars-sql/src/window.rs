@@ -0,0 +1,463 @@
+//! Window functions, `f(...) OVER (PARTITION BY ... ORDER BY ... <frame>)`.
+//!
+//! A window function is evaluated on the rows of every partition sorted by the `ORDER BY` of the
+//! window, after which the results are put back in the order of the rows. The partitions are
+//! evaluated with `over`, the sorting with `sort_by`, and putting the results back with a `gather`
+//! by the inverse of the sorting permutation.
+use polars_core::chunked_array::ops::{FillNullStrategy, SortMultipleOptions, SortOptions};
+use polars_core::prelude::{
+    DataType, IDX_DTYPE, PolarsResult, RollingOptionsFixedWindow, Schema, polars_bail,
+};
+use polars_lazy::dsl::Expr;
+use polars_plan::dsl::{arg_sort_by, int_range, len, when};
+use polars_plan::plans::{DynLiteralValue, LiteralValue};
+use polars_plan::prelude::lit;
+use sqlparser::ast::{
+    Expr as SQLExpr, FunctionArgExpr, WindowFrameBound, WindowFrameUnits, WindowSpec, WindowType,
+};
+
+use crate::SQLContext;
+use crate::functions::{SQLFunctionVisitor, extract_args, extract_args_distinct};
+use crate::sql_expr::parse_sql_expr;
+
+/// An aggregate function that can be evaluated over a window frame.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum WindowAggregate {
+    Avg,
+    Count,
+    Max,
+    Min,
+    Sum,
+}
+
+/// A function that can only be evaluated over a window.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum WindowFunction {
+    CumeDist,
+    DenseRank,
+    FirstValue,
+    Lag,
+    LastValue,
+    Lead,
+    PercentRank,
+    Rank,
+    RowNumber,
+}
+
+/// The rows of a window frame, relative to the current row.
+#[derive(Clone, Copy, PartialEq)]
+enum Frame {
+    /// All rows of the partition.
+    Partition,
+    /// The rows up to the current row.
+    Running,
+    /// The rows from the current row on.
+    ReverseRunning,
+    /// The current row and the given number of rows before it.
+    Preceding(usize),
+}
+
+struct Window {
+    partition_by: Vec<Expr>,
+    order_by: Vec<Expr>,
+    sort_options: SortMultipleOptions,
+    frame: Frame,
+    /// Whether a frame that ends (or starts) at the current row extends to the last (or first)
+    /// row with the same `ORDER BY` values, as in a `RANGE` frame.
+    peers: bool,
+}
+
+fn null() -> Expr {
+    Expr::Literal(LiteralValue::untyped_null())
+}
+
+fn parse_offset(
+    expr: &SQLExpr,
+    ctx: &mut SQLContext,
+    active_schema: Option<&Schema>,
+) -> PolarsResult<usize> {
+    match parse_sql_expr(expr, ctx, active_schema)? {
+        Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n >= 0 => Ok(n as usize),
+        _ => {
+            polars_bail!(SQLSyntax: "window offset must be a non-negative integer; found {}", expr)
+        },
+    }
+}
+
+impl Window {
+    fn try_new(
+        spec: &WindowSpec,
+        ctx: &mut SQLContext,
+        active_schema: Option<&Schema>,
+    ) -> PolarsResult<Self> {
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, ctx, active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut order_by = Vec::with_capacity(spec.order_by.len());
+        let mut descending = Vec::with_capacity(spec.order_by.len());
+        let mut nulls_last = Vec::with_capacity(spec.order_by.len());
+        for ob in &spec.order_by {
+            // 'NULLS FIRST' is the default for DESC, 'NULLS LAST' otherwise
+            let desc_order = !ob.asc.unwrap_or(true);
+            order_by.push(parse_sql_expr(&ob.expr, ctx, active_schema)?);
+            nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
+            descending.push(desc_order);
+        }
+        let sort_options = SortMultipleOptions::default()
+            .with_order_descending_multi(descending)
+            .with_nulls_last_multi(nulls_last)
+            .with_maintain_order(true);
+
+        // Without a frame clause the frame is 'RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT
+        // ROW' if there is an ORDER BY, and the whole partition otherwise.
+        let (mut frame, peers) = match &spec.window_frame {
+            None => (Frame::Running, true),
+            Some(window_frame) => {
+                let peers = match window_frame.units {
+                    WindowFrameUnits::Rows => false,
+                    WindowFrameUnits::Range => true,
+                    WindowFrameUnits::Groups => {
+                        polars_bail!(SQLInterface: "GROUPS window frames are not currently supported")
+                    },
+                };
+                let end_bound = window_frame
+                    .end_bound
+                    .as_ref()
+                    .unwrap_or(&WindowFrameBound::CurrentRow);
+                let frame = match (&window_frame.start_bound, end_bound) {
+                    (WindowFrameBound::Preceding(None), WindowFrameBound::CurrentRow) => {
+                        Frame::Running
+                    },
+                    (WindowFrameBound::Preceding(None), WindowFrameBound::Following(None)) => {
+                        Frame::Partition
+                    },
+                    (WindowFrameBound::CurrentRow, WindowFrameBound::Following(None)) => {
+                        Frame::ReverseRunning
+                    },
+                    (WindowFrameBound::Preceding(Some(n)), WindowFrameBound::CurrentRow)
+                        if !peers =>
+                    {
+                        Frame::Preceding(parse_offset(n, ctx, active_schema)?)
+                    },
+                    (WindowFrameBound::CurrentRow, WindowFrameBound::CurrentRow) if !peers => {
+                        Frame::Preceding(0)
+                    },
+                    _ => polars_bail!(
+                        SQLInterface: "window frame is not currently supported: {}", spec
+                    ),
+                };
+                (frame, peers)
+            },
+        };
+        // Without an ORDER BY, all rows of a partition are peers.
+        if order_by.is_empty() && peers {
+            frame = Frame::Partition;
+        }
+        Ok(Self {
+            partition_by,
+            order_by,
+            sort_options,
+            frame,
+            peers,
+        })
+    }
+
+    /// Sort the rows of a partition by the `ORDER BY` of the window.
+    fn sorted(&self, e: Expr) -> Expr {
+        if self.order_by.is_empty() {
+            e
+        } else {
+            e.sort_by(&self.order_by, self.sort_options.clone())
+        }
+    }
+
+    fn over(&self, e: Expr) -> Expr {
+        if self.partition_by.is_empty() {
+            e
+        } else {
+            e.over(&self.partition_by)
+        }
+    }
+
+    /// Put the results of the sorted rows of every partition back in the order of the rows.
+    fn finish(&self, e: Expr) -> Expr {
+        let e = if self.order_by.is_empty() {
+            e
+        } else {
+            let order = arg_sort_by(&self.order_by, self.sort_options.clone());
+            e.gather(order.arg_sort(SortOptions::default()))
+        };
+        self.over(e)
+    }
+
+    /// The number of every sorted row, starting at 1.
+    fn row_number(&self) -> Expr {
+        int_range(lit(1), len() + lit(1), 1, IDX_DTYPE)
+    }
+
+    /// Whether a sorted row is the first (`offset` 1) or the last (`offset` -1) of its peers.
+    fn is_peer_bound(&self, offset: i64) -> Expr {
+        let bound = if offset > 0 { lit(1) } else { len() };
+        self.order_by
+            .iter()
+            .fold(self.row_number().eq(bound), |acc, e| {
+                let e = self.sorted(e.clone());
+                acc.or(e.clone().neq_missing(e.shift(lit(offset))))
+            })
+    }
+
+    /// The rank of every sorted row: the number of the first of its peers.
+    fn rank(&self) -> Expr {
+        when(self.is_peer_bound(1))
+            .then(self.row_number())
+            .otherwise(null())
+            .fill_null_with_strategy(FillNullStrategy::Forward(None))
+    }
+
+    /// Extend the values of a frame that ends (or starts) at the current sorted row to its last
+    /// (or first) peer.
+    fn extend_to_peers(&self, e: Expr) -> Expr {
+        let (offset, strategy) = match self.frame {
+            Frame::Running if self.peers => (-1, FillNullStrategy::Backward(None)),
+            Frame::ReverseRunning if self.peers => (1, FillNullStrategy::Forward(None)),
+            _ => return e,
+        };
+        let idx = when(self.is_peer_bound(offset))
+            .then(self.row_number() - lit(1))
+            .otherwise(null())
+            .fill_null_with_strategy(strategy);
+        e.gather(idx)
+    }
+
+    fn rolling_options(n: usize) -> RollingOptionsFixedWindow {
+        RollingOptionsFixedWindow {
+            window_size: n + 1,
+            min_periods: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Aggregate the rows of the frame of every row; `e` is `None` for `COUNT(*)`.
+    fn aggregate(&self, agg: WindowAggregate, e: Option<Expr>) -> Expr {
+        use WindowAggregate::*;
+
+        if self.frame == Frame::Partition {
+            let out = match (agg, e) {
+                (Count, None) => len(),
+                (Count, Some(e)) => e.count(),
+                (_, None) => unreachable!(),
+                (Avg, Some(e)) => e.mean(),
+                (Max, Some(e)) => e.max(),
+                (Min, Some(e)) => e.min(),
+                (Sum, Some(e)) => e.sum(),
+            };
+            return self.over(out);
+        }
+
+        let Some(e) = e else {
+            let rn = self.row_number();
+            let out = match self.frame {
+                Frame::Running => rn,
+                Frame::ReverseRunning => len() - rn + lit(1),
+                Frame::Preceding(n) => {
+                    let n = lit(n as u64 + 1).cast(IDX_DTYPE);
+                    when(rn.clone().gt(n.clone())).then(n).otherwise(rn)
+                },
+                Frame::Partition => unreachable!(),
+            };
+            return self.finish(self.extend_to_peers(out));
+        };
+
+        let s = self.sorted(e);
+        let out = match self.frame {
+            Frame::Running | Frame::ReverseRunning => {
+                let reverse = self.frame == Frame::ReverseRunning;
+                // The cumulative functions are null where the value is null, whereas the
+                // aggregate of a frame only ignores null values.
+                let fill = |e: Expr| {
+                    e.fill_null_with_strategy(if reverse {
+                        FillNullStrategy::Backward(None)
+                    } else {
+                        FillNullStrategy::Forward(None)
+                    })
+                };
+                match agg {
+                    Avg => {
+                        fill(s.clone().cast(DataType::Float64).cum_sum(reverse))
+                            / s.cum_count(reverse)
+                    },
+                    Count => s.cum_count(reverse),
+                    Max => fill(s.cum_max(reverse)),
+                    Min => fill(s.cum_min(reverse)),
+                    Sum => fill(s.cum_sum(reverse)),
+                }
+            },
+            Frame::Preceding(n) => {
+                let options = Self::rolling_options(n);
+                match agg {
+                    Avg => s.cast(DataType::Float64).rolling_mean(options),
+                    Count => s.is_not_null().cast(IDX_DTYPE).rolling_sum(options),
+                    Max => s.rolling_max(options),
+                    Min => s.rolling_min(options),
+                    Sum => s.rolling_sum(options),
+                }
+            },
+            Frame::Partition => unreachable!(),
+        };
+        self.finish(self.extend_to_peers(out))
+    }
+}
+
+impl SQLFunctionVisitor<'_> {
+    /// The window of the function, if it has an `ORDER BY` or a frame clause. A window with only
+    /// a `PARTITION BY` is an aggregation broadcast with `over`.
+    fn ordered_window(&mut self) -> PolarsResult<Option<Window>> {
+        let func = self.func;
+        match &func.over {
+            Some(WindowType::WindowSpec(spec))
+                if !spec.order_by.is_empty() || spec.window_frame.is_some() =>
+            {
+                Ok(Some(Window::try_new(spec, self.ctx, self.active_schema)?))
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Visit an aggregate function with a single argument, which is evaluated over the frame of
+    /// every row if the function has an ordered window.
+    pub(crate) fn visit_unary_with_opt_window(
+        &mut self,
+        f: impl Fn(Expr) -> Expr,
+        agg: WindowAggregate,
+    ) -> PolarsResult<Expr> {
+        match self.ordered_window()? {
+            Some(window) => self.visit_window_aggregate(window, agg),
+            None => self.visit_unary(f),
+        }
+    }
+
+    /// Visit `COUNT` with an ordered window, if it has one.
+    pub(crate) fn visit_count_with_opt_window(&mut self) -> PolarsResult<Option<Expr>> {
+        match self.ordered_window()? {
+            Some(window) => Ok(Some(
+                self.visit_window_aggregate(window, WindowAggregate::Count)?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn visit_window_aggregate(
+        &mut self,
+        window: Window,
+        agg: WindowAggregate,
+    ) -> PolarsResult<Expr> {
+        let (args, is_distinct) = extract_args_distinct(self.func)?;
+        if is_distinct {
+            polars_bail!(SQLInterface: "DISTINCT is not supported in a window with ORDER BY or a frame clause")
+        }
+        let e = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => {
+                Some(parse_sql_expr(sql_expr, self.ctx, self.active_schema)?)
+            },
+            [FunctionArgExpr::Wildcard] | [] if agg == WindowAggregate::Count => None,
+            _ => return self.not_supported_error(),
+        };
+        Ok(window.aggregate(agg, e))
+    }
+
+    /// Visit a ranking function, `LAG`, `LEAD`, `FIRST_VALUE` or `LAST_VALUE`.
+    pub(crate) fn visit_window_function(&mut self, function: WindowFunction) -> PolarsResult<Expr> {
+        use WindowFunction::*;
+
+        let func = self.func;
+        let window = match &func.over {
+            Some(WindowType::WindowSpec(spec)) => {
+                Window::try_new(spec, self.ctx, self.active_schema)?
+            },
+            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
+                SQLInterface: "Named windows are not currently supported; found {:?}",
+                named_window
+            ),
+            None => polars_bail!(SQLSyntax: "{} requires an OVER clause", func.name),
+        };
+        let args = extract_args(func)?;
+        let mut exprs = Vec::with_capacity(args.len());
+        for arg in &args {
+            match arg {
+                FunctionArgExpr::Expr(sql_expr) => exprs.push(sql_expr),
+                _ => return self.not_supported_error(),
+            }
+        }
+
+        let rn = window.row_number();
+        Ok(match (function, exprs.as_slice()) {
+            (RowNumber, []) => window.finish(rn),
+            (Rank, []) => window.finish(window.rank()),
+            (DenseRank, []) => {
+                window.finish(window.is_peer_bound(1).cast(IDX_DTYPE).cum_sum(false))
+            },
+            (PercentRank, []) => {
+                let rank = (window.rank() - lit(1)).cast(DataType::Float64);
+                let n = (len() - lit(1)).cast(DataType::Float64);
+                window.finish(when(len().gt(lit(1))).then(rank / n).otherwise(lit(0.0)))
+            },
+            (CumeDist, []) => {
+                let last_peer = when(window.is_peer_bound(-1))
+                    .then(rn)
+                    .otherwise(null())
+                    .fill_null_with_strategy(FillNullStrategy::Backward(None));
+                window.finish(last_peer.cast(DataType::Float64) / len().cast(DataType::Float64))
+            },
+            (Lag | Lead, [e, rest @ ..]) if rest.len() <= 2 => {
+                let e = parse_sql_expr(e, self.ctx, self.active_schema)?;
+                let offset = match rest.first() {
+                    Some(offset) => parse_offset(offset, self.ctx, self.active_schema)?,
+                    None => 1,
+                } as i64;
+                let (shift, outside) = if function == Lag {
+                    (offset, rn.lt_eq(lit(offset)))
+                } else {
+                    (-offset, (rn + lit(offset)).gt(len()))
+                };
+                let shifted = window.sorted(e).shift(lit(shift));
+                let out = match rest.get(1) {
+                    Some(default) => {
+                        let default = parse_sql_expr(default, self.ctx, self.active_schema)?;
+                        let default = if matches!(default, Expr::Literal(_)) {
+                            default
+                        } else {
+                            window.sorted(default)
+                        };
+                        when(outside).then(default).otherwise(shifted)
+                    },
+                    None => shifted,
+                };
+                window.finish(out)
+            },
+            (FirstValue, [e]) => {
+                let s = window.sorted(parse_sql_expr(e, self.ctx, self.active_schema)?);
+                match window.frame {
+                    Frame::Partition | Frame::Running => window.over(s.first()),
+                    Frame::ReverseRunning => window.finish(window.extend_to_peers(s)),
+                    Frame::Preceding(n) => window.finish(
+                        when(rn.gt(lit(n as u64)))
+                            .then(s.clone().shift(lit(n as i64)))
+                            .otherwise(s.first()),
+                    ),
+                }
+            },
+            (LastValue, [e]) => {
+                let s = window.sorted(parse_sql_expr(e, self.ctx, self.active_schema)?);
+                match window.frame {
+                    Frame::Partition | Frame::ReverseRunning => window.over(s.last()),
+                    Frame::Running => window.finish(window.extend_to_peers(s)),
+                    Frame::Preceding(_) => window.finish(s),
+                }
+            },
+            _ => return self.not_supported_error(),
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+        "ts" => ["2024-05-17 13:45:10", "2023-11-02 08:05:59"],
+        "name" => ["John Smith", "Jane Doe"],
+        "path" => ["a/b/c", "d/e/f"],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new().with_dialect(Dialect::Postgres);
+    ctx.register("df", df);
+    ctx
+}
+
+#[test]
+fn test_postgres_temporal_functions() {
+    let mut ctx = create_ctx();
+    let actual = ctx
+        .execute(
+            "SELECT
+              TO_CHAR(DATE_TRUNC('hour', ts::timestamp), 'YYYY-MM-DD HH24:MI:SS') AS trunc_hour,
+              TO_CHAR(DATE_TRUNC('month', ts::timestamp), 'DD Mon YYYY') AS trunc_month,
+              TO_CHAR(ts::timestamp, 'HH12:MI AM \"on\" Dy') AS formatted,
+              TO_CHAR(TO_DATE(SPLIT_PART(ts, ' ', 1), 'YYYY-MM-DD'), 'DD/MM/YYYY') AS parsed_date,
+              TO_TIMESTAMP(ts, 'YYYY-MM-DD HH24:MI:SS') = ts::timestamp AS parsed
+            FROM df",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "trunc_hour" => ["2024-05-17 13:00:00", "2023-11-02 08:00:00"],
+        "trunc_month" => ["01 May 2024", "01 Nov 2023"],
+        "formatted" => ["01:45 PM on Fri", "08:05 AM on Thu"],
+        "parsed_date" => ["17/05/2024", "02/11/2023"],
+        "parsed" => [true, true],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_postgres_regexp_replace() {
+    let mut ctx = create_ctx();
+    let actual = ctx
+        .execute(
+            r"SELECT
+              REGEXP_REPLACE(name, '(\w+) (\w+)', '\2, \1') AS swapped,
+              REGEXP_REPLACE(path, '/', '.') AS first_slash,
+              REGEXP_REPLACE(path, '[A-Z]', '_', 'gi') AS all_letters
+            FROM df",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "swapped" => ["Smith, John", "Doe, Jane"],
+        "first_slash" => ["a.b/c", "d.e/f"],
+        "all_letters" => ["_/_/_", "_/_/_"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_postgres_functions_need_dialect() {
+    let mut ctx = create_ctx().with_dialect(Dialect::Generic);
+    for sql in [
+        "SELECT DATE_TRUNC('day', ts::timestamp) FROM df",
+        "SELECT REGEXP_REPLACE(name, 'J', 'j') FROM df",
+        "SELECT TO_CHAR(ts::timestamp, 'YYYY') FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+    let mut ctx = create_ctx();
+    assert!(
+        ctx.execute("SELECT DATE_TRUNC('century', ts::timestamp) FROM df")
+            .is_err()
+    );
+    assert!(
+        ctx.execute("SELECT REGEXP_REPLACE(name, 'J', 'j', 'q') FROM df")
+            .is_err()
+    );
+}
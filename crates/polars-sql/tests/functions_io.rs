@@ -1,8 +1,8 @@
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_core::prelude::*;
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_lazy::prelude::*;
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_sql::*;
 
 #[test]
@@ -64,6 +64,57 @@ fn read_csv_tbl_func_inline_2() {
     assert!(df_sql.equals(&expected));
 }
 
+#[test]
+#[cfg(feature = "csv")]
+fn read_csv_tbl_func_options() {
+    let mut context = SQLContext::new();
+    let sql = r#"
+            SELECT column_1 AS category
+            FROM read_csv(
+              '../../examples/datasets/foods1.csv',
+              header => false, skip_rows => 1, n_rows => 3
+            )"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "category" => ["vegetables", "seafood", "meat"] }.unwrap();
+    assert!(df_sql.equals(&expected));
+
+    let df_sql = context
+        .execute("SELECT * FROM read_csv('../../examples/datasets/foods1.csv', delim => '|')")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(df_sql.width(), 1);
+
+    for options in [
+        "unknown => 1",
+        "header => 'no'",
+        "delim => ';;'",
+        "n_rows => 1, n_rows => 2",
+        "'../../examples/datasets/foods2.csv'",
+    ] {
+        let sql =
+            format!("SELECT * FROM read_csv('../../examples/datasets/foods1.csv', {options})");
+        assert!(context.execute(&sql).is_err(), "{options}");
+    }
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn read_parquet_tbl_options() {
+    let mut context = SQLContext::new();
+    let sql = r#"
+            SELECT * FROM read_parquet(
+              '../../examples/datasets/foods1.parquet',
+              n_rows => 5, include_file_paths => 'path'
+            )"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(df_sql.height(), 5);
+    assert_eq!(
+        df_sql.get_column_names(),
+        ["category", "calories", "fats_g", "sugars_g", "path"]
+    );
+}
+
 #[test]
 #[cfg(feature = "parquet")]
 fn read_parquet_tbl() {
@@ -109,3 +160,61 @@ fn read_ipc_tbl() {
     assert_eq!(df_2.height(), 27);
     assert_eq!(df_2.width(), 4);
 }
+
+#[test]
+#[cfg(feature = "csv")]
+fn copy_to_csv() {
+    let mut context = SQLContext::new();
+    let path = std::env::temp_dir().join("polars_sql_copy_to.csv");
+    let sql = format!(
+        r#"
+            COPY (
+              SELECT category, calories
+              FROM read_csv('../../examples/datasets/foods1.csv')
+              WHERE calories > 100
+            ) TO '{}' (FORMAT CSV)"#,
+        path.display()
+    );
+    let df_sql = context.execute(&sql).unwrap().collect().unwrap();
+    assert!(df_sql.equals(&df! { "Response" => ["COPY"] }.unwrap()));
+
+    let expected = LazyCsvReader::new("../../examples/datasets/foods1.csv")
+        .finish()
+        .unwrap()
+        .select(&[col("category"), col("calories")])
+        .filter(col("calories").gt(lit(100)))
+        .collect()
+        .unwrap();
+    let df_copy = LazyCsvReader::new(&path)
+        .finish()
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(df_copy.equals(&expected));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn select_from_catalog() {
+    use polars_lazy::catalog::FileCatalog;
+
+    let catalog = Arc::new(FileCatalog::new("../../examples"));
+    let mut context = SQLContext::new().with_catalog(catalog);
+    let sql = r#"
+        SELECT f.category, COUNT(*) AS n
+        FROM datasets.foods1 AS f
+        GROUP BY f.category
+        ORDER BY f.category"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+
+    let expected = LazyCsvReader::new("../../examples/datasets/foods1.csv")
+        .finish()
+        .unwrap()
+        .group_by([col("category")])
+        .agg([len().alias("n")])
+        .sort(["category"], Default::default())
+        .collect()
+        .unwrap();
+    assert!(df_sql.equals(&expected));
+}
@@ -0,0 +1,175 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "id" => [1, 2, 3, 4, 5, 6, 7],
+      "category" => ["a", "a", "a", "b", "b", "b", "b"],
+      "value" => [10, 20, 20, 5, 15, 15, 30]
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+fn execute(sql: &str) -> DataFrame {
+    let query = format!("SELECT id, {sql} AS result FROM df ORDER BY id");
+    create_ctx()
+        .execute(&query)
+        .unwrap()
+        .collect()
+        .unwrap()
+        .drop("id")
+        .unwrap()
+}
+
+#[test]
+fn test_row_number() {
+    let actual = execute("ROW_NUMBER() OVER (PARTITION BY category ORDER BY value DESC, id)");
+    let expected = df! { "result" => [3 as IdxSize, 1, 2, 4, 2, 3, 1] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_rank() {
+    let actual = execute("RANK() OVER (PARTITION BY category ORDER BY value)");
+    let expected = df! { "result" => [1 as IdxSize, 2, 2, 1, 2, 2, 4] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+
+    let actual = execute("DENSE_RANK() OVER (PARTITION BY category ORDER BY value)");
+    let expected = df! { "result" => [1 as IdxSize, 2, 2, 1, 2, 2, 3] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_percent_rank_cume_dist() {
+    let actual = execute("PERCENT_RANK() OVER (PARTITION BY category ORDER BY value)");
+    let expected = df! { "result" => [0.0, 0.5, 0.5, 0.0, 1.0 / 3.0, 1.0 / 3.0, 1.0] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+
+    let actual = execute("CUME_DIST() OVER (PARTITION BY category ORDER BY value)");
+    let expected = df! { "result" => [1.0 / 3.0, 1.0, 1.0, 0.25, 0.75, 0.75, 1.0] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_running_sum() {
+    // Without a frame clause, rows with the same ORDER BY values are in each other's frame.
+    let actual = execute("SUM(value) OVER (PARTITION BY category ORDER BY value)");
+    let expected = df! { "result" => [10, 50, 50, 5, 35, 35, 65] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+
+    let actual = execute(
+        "SUM(value) OVER (
+          PARTITION BY category ORDER BY value, id
+          ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+        )",
+    );
+    let expected = df! { "result" => [10, 30, 50, 5, 20, 35, 65] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+
+    let actual = execute("COUNT(*) OVER (ORDER BY id DESC)");
+    let expected = df! { "result" => [7 as IdxSize, 6, 5, 4, 3, 2, 1] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_rows_preceding() {
+    let actual = execute("SUM(value) OVER (ORDER BY id ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)");
+    let expected = df! { "result" => [10, 30, 40, 25, 20, 30, 45] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+
+    let actual =
+        execute("MIN(value) OVER (PARTITION BY category ORDER BY id DESC ROWS 2 PRECEDING)");
+    let expected = df! { "result" => [10, 20, 20, 5, 15, 15, 30] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_lag_lead() {
+    let actual = execute("LAG(value) OVER (PARTITION BY category ORDER BY id)");
+    let expected = df! {
+        "result" => [None, Some(10), Some(20), None, Some(5), Some(15), Some(15)]
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual}");
+
+    let actual = execute("LEAD(value, 2, 0) OVER (PARTITION BY category ORDER BY id)");
+    let expected = df! { "result" => [20, 0, 0, 15, 30, 0, 0] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_first_last_value() {
+    let actual = execute("FIRST_VALUE(id) OVER (PARTITION BY category ORDER BY value DESC)");
+    let expected = df! { "result" => [2, 2, 2, 7, 7, 7, 7] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+
+    let actual = execute(
+        "LAST_VALUE(id) OVER (
+          PARTITION BY category ORDER BY value
+          ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+        )",
+    );
+    let expected = df! { "result" => [3, 3, 3, 7, 7, 7, 7] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_qualify() {
+    let mut ctx = create_ctx();
+    let query = |qualify: &str| {
+        format!(
+            "SELECT id, ROW_NUMBER() OVER (PARTITION BY category ORDER BY value DESC, id) AS rn
+            FROM df {qualify} ORDER BY id"
+        )
+    };
+    for qualify in [
+        "QUALIFY rn = 1",
+        "QUALIFY ROW_NUMBER() OVER (PARTITION BY category ORDER BY value DESC, id) < 2",
+    ] {
+        let actual = ctx.execute(&query(qualify)).unwrap().collect().unwrap();
+        let expected = df! { "id" => [2, 7], "rn" => [1 as IdxSize, 1] }.unwrap();
+        assert!(actual.equals(&expected), "{actual}");
+    }
+
+    // window functions in the filter see all rows, not only the qualifying ones
+    let actual = ctx
+        .execute(
+            "SELECT id, COUNT(*) OVER (PARTITION BY category) AS n
+            FROM df QUALIFY n > 3 AND value > 10",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "id" => [5, 6, 7], "n" => [4 as IdxSize, 4, 4] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+
+    // after aggregation, the filter applies to the groups
+    let actual = ctx
+        .execute(
+            "SELECT category, SUM(value) AS total FROM df GROUP BY category
+            QUALIFY RANK() OVER (ORDER BY total DESC) = 1",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "category" => ["b"], "total" => [65] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_window_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT ROW_NUMBER() FROM df",
+        "SELECT SUM(value) OVER (ORDER BY id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) FROM df",
+        "SELECT SUM(value) OVER (ORDER BY id GROUPS UNBOUNDED PRECEDING) FROM df",
+        "SELECT FIRST(value) OVER (ORDER BY id) FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
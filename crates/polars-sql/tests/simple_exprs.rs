@@ -463,6 +463,44 @@ fn test_create_table() {
     assert!(df_2.equals(&expected));
 }
 
+#[test]
+fn test_insert_into() {
+    let df = df! {
+        "a" => [1, 2],
+        "b" => ["x", "y"],
+    }
+    .unwrap();
+    let mut context = SQLContext::new();
+    context.register("df", df.lazy());
+
+    let df_sql = context
+        .execute("INSERT INTO df VALUES (3, 'z')")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(df_sql.equals(&df! { "Response" => ["INSERT INTO"] }.unwrap()));
+
+    // columns that are not listed are null
+    context
+        .execute("INSERT INTO df (a) SELECT a * 10 FROM df WHERE a > 2")
+        .unwrap();
+    let df_2 = context
+        .execute("SELECT * FROM df")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "a" => [1, 2, 3, 30],
+        "b" => [Some("x"), Some("y"), Some("z"), None],
+    }
+    .unwrap();
+    assert!(df_2.equals_missing(&expected));
+
+    assert!(context.execute("INSERT INTO df VALUES (4)").is_err());
+    assert!(context.execute("INSERT INTO df (c) VALUES (4)").is_err());
+    assert!(context.execute("INSERT INTO missing VALUES (4)").is_err());
+}
+
 #[test]
 fn test_unary_minus_0() {
     let df = df! {
@@ -581,6 +619,52 @@ fn test_cte_values() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_recursive_cte() -> PolarsResult<()> {
+    let mut context = SQLContext::new();
+    let sql = r#"
+        WITH RECURSIVE t(n) AS (
+          SELECT 1
+          UNION ALL
+          SELECT n + 1 FROM t WHERE n < 5
+        )
+        SELECT n FROM t
+    "#;
+    let df_sql = context.execute(sql)?.collect()?;
+    assert!(df_sql.equals(&df! { "n" => [1, 2, 3, 4, 5] }?));
+
+    // A graph with a cycle: 'UNION' only continues from the rows that were not found before.
+    let edges = df! {
+        "src" => [1, 2, 3, 3, 5],
+        "dst" => [2, 3, 1, 4, 6],
+    }?;
+    context.register("edges", edges.lazy());
+    let sql = r#"
+        WITH RECURSIVE reachable AS (
+          SELECT 1 AS node
+          UNION
+          SELECT e.dst FROM reachable r JOIN edges e ON r.node = e.src
+        )
+        SELECT node FROM reachable ORDER BY node
+    "#;
+    let df_sql = context.execute(sql)?.collect()?;
+    assert!(df_sql.equals(&df! { "node" => [1, 2, 3, 4] }?));
+
+    // With 'UNION ALL' the cycle never ends.
+    let sql = r#"
+        WITH RECURSIVE reachable AS (
+          SELECT 1 AS node
+          UNION ALL
+          SELECT e.dst FROM reachable r JOIN edges e ON r.node = e.src
+        )
+        SELECT node FROM reachable
+    "#;
+    let mut context = context.with_max_recursive_iterations(10);
+    assert!(context.execute(sql).is_err());
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "ipc")]
 fn test_group_by_2() -> PolarsResult<()> {
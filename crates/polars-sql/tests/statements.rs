@@ -626,3 +626,291 @@ fn test_compound_invalid_3() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     let _ = ctx.execute(sql).unwrap();
 }
+
+#[test]
+fn test_prepared_statement() {
+    let mut ctx = create_ctx();
+    let stmt = ctx
+        .prepare("SELECT b FROM df WHERE b > $1 AND b <= :upper ORDER BY b")
+        .unwrap();
+
+    for (lower, upper, expected) in [(2i64, 4i64, vec![3i64, 4]), (7, 100, vec![8, 9])] {
+        let params = SQLParams::new()
+            .with_value(lower)
+            .with_named("upper", upper);
+        let actual = ctx
+            .execute_prepared(&stmt, &params)
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert!(actual.equals(&df! { "b" => expected }.unwrap()));
+    }
+
+    // values are bound as literals, never parsed as SQL
+    let stmt = ctx
+        .prepare("SELECT $1 AS s, b FROM df WHERE b IN ($2, $3)")
+        .unwrap();
+    let params = SQLParams::new()
+        .with_value(PlSmallStr::from("x' OR '1'='1"))
+        .with_value(1i64)
+        .with_value(2i64);
+    let actual = ctx
+        .execute_prepared(&stmt, &params)
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "s" => ["x' OR '1'='1", "x' OR '1'='1"],
+        "b" => [1i64, 2],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected));
+
+    // unbound placeholders are an error
+    let stmt = ctx.prepare("SELECT * FROM df WHERE b > $1").unwrap();
+    assert!(ctx.execute_prepared(&stmt, &SQLParams::new()).is_err());
+    assert!(ctx.execute("SELECT * FROM df WHERE b > $1").is_err());
+    assert!(ctx.execute("SELECT * FROM df WHERE b > ?").is_err());
+}
+
+#[test]
+fn test_describe_and_information_schema() {
+    let mut ctx = create_ctx();
+    let other = df! { "x" => ["a"], "y" => [1.5] }.unwrap();
+    ctx.register("other", other.lazy());
+
+    let actual = ctx.execute("DESCRIBE other").unwrap().collect().unwrap();
+    let expected = df! {
+        "column_name" => ["x", "y"],
+        "data_type" => ["VARCHAR", "DOUBLE"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected));
+
+    let actual = ctx
+        .execute("SELECT * FROM information_schema.tables")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "table_name" => ["df", "other"],
+        "table_type" => ["BASE TABLE", "BASE TABLE"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected));
+
+    let actual = ctx
+        .execute(
+            "SELECT c.table_name, c.column_name, c.data_type
+            FROM information_schema.columns c
+            WHERE c.ordinal_position = 1",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "table_name" => ["df", "other"],
+        "column_name" => ["a", "x"],
+        "data_type" => ["BIGINT", "VARCHAR"],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected));
+
+    assert!(ctx.execute("DESCRIBE missing").is_err());
+    assert!(
+        ctx.execute("SELECT * FROM information_schema.views")
+            .is_err()
+    );
+}
+
+#[test]
+fn test_lateral_joins() {
+    let mut ctx = SQLContext::new();
+    let customers = DataFrame::new(vec![
+        Column::new("id".into(), [1i64, 2, 3]),
+        Column::new(
+            "tags".into(),
+            [
+                Series::new("".into(), ["x", "y"]),
+                Series::new_empty("".into(), &DataType::String),
+                Series::new("".into(), ["z"]),
+            ],
+        ),
+    ])
+    .unwrap();
+    let orders = df! { "cid" => [1i64, 1, 3], "amount" => [10i64, 20, 5] }.unwrap();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+
+    // rows with empty lists are only kept by a LEFT JOIN
+    let actual = ctx
+        .execute("SELECT c.id, u.tag FROM customers c CROSS JOIN UNNEST(c.tags) AS u(tag)")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "id" => [1i64, 1, 3], "tag" => ["x", "y", "z"] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+
+    let actual = ctx
+        .execute("SELECT c.id, tag FROM customers c LEFT JOIN UNNEST(c.tags) AS u(tag) ON TRUE")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+        "id" => [1i64, 1, 2, 3],
+        "tag" => [Some("x"), Some("y"), None, Some("z")],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual}");
+
+    // lateral subqueries see the columns of the relations before them
+    for (join, on, ids, totals) in [
+        ("CROSS JOIN", "", vec![1i64, 3], vec![Some(30i64), Some(5)]),
+        (
+            "LEFT JOIN",
+            "ON TRUE",
+            vec![1, 2, 3],
+            vec![Some(30), None, Some(5)],
+        ),
+    ] {
+        let actual = ctx
+            .execute(&format!(
+                "SELECT c.id, o.total FROM customers c
+                {join} LATERAL (
+                  SELECT o.cid, SUM(o.amount) AS total
+                  FROM orders o WHERE o.cid = c.id GROUP BY o.cid
+                ) AS o {on}"
+            ))
+            .unwrap()
+            .collect()
+            .unwrap();
+        let expected = df! { "id" => ids, "total" => totals }.unwrap();
+        assert!(actual.equals_missing(&expected), "{actual}");
+    }
+
+    assert!(
+        ctx.execute("SELECT * FROM customers c JOIN UNNEST(c.tags) AS u(tag) ON c.id = 1")
+            .is_err()
+    );
+    assert!(
+        ctx.execute("SELECT * FROM customers c CROSS JOIN LATERAL (SELECT * FROM orders) AS o")
+            .is_err()
+    );
+}
+
+#[test]
+fn test_correlated_subqueries() {
+    let mut ctx = SQLContext::new();
+    let customers = df! { "id" => [1i64, 2, 3], "name" => ["a", "b", "c"] }.unwrap();
+    let orders = df! { "cid" => [1i64, 1, 3], "amount" => [10i64, 20, 5] }.unwrap();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+    let mut execute = |sql: &str| ctx.execute(sql).unwrap().collect().unwrap();
+
+    for (cond, ids) in [
+        (
+            "EXISTS (SELECT 1 FROM orders o WHERE o.cid = c.id)",
+            vec![1i64, 3],
+        ),
+        (
+            "NOT EXISTS (SELECT 1 FROM orders o WHERE o.cid = c.id)",
+            vec![2],
+        ),
+        (
+            "c.id IN (SELECT o.cid FROM orders o WHERE o.amount > c.id * 5)",
+            vec![1],
+        ),
+        (
+            "c.id * 10 < (SELECT SUM(o.amount) FROM orders o WHERE o.cid = c.id)",
+            vec![1],
+        ),
+        (
+            "EXISTS (SELECT 1 FROM orders WHERE amount > 15)",
+            vec![1, 2, 3],
+        ),
+        ("c.id < (SELECT MAX(cid) FROM orders)", vec![1, 2]),
+        // unqualified columns that only the outer query has refer to it
+        ("EXISTS (SELECT 1 FROM orders WHERE cid = id)", vec![1, 3]),
+        // not a conjunct of the filter, so not a semi join
+        (
+            "EXISTS (SELECT 1 FROM orders o WHERE o.cid = c.id) OR c.id = 2",
+            vec![1, 2, 3],
+        ),
+        (
+            "c.id IN (SELECT o.cid FROM orders o WHERE o.cid = c.id AND o.amount > 15)",
+            vec![1],
+        ),
+    ] {
+        let actual = execute(&format!("SELECT c.id FROM customers c WHERE {cond}"));
+        let expected = df! { "id" => ids }.unwrap();
+        assert!(actual.equals(&expected), "{cond}: {actual}");
+    }
+
+    // scalar subqueries in the projection, with a count of zero for customers without orders
+    let actual = execute(
+        "SELECT c.*,
+          (SELECT COUNT(*) FROM orders o WHERE o.cid = c.id) AS n,
+          (SELECT MAX(o.amount) FROM orders o WHERE o.cid = c.id) AS largest
+        FROM customers c ORDER BY c.id DESC",
+    );
+    let expected = df! {
+        "id" => [3i64, 2, 1],
+        "name" => ["c", "b", "a"],
+        "n" => [1 as IdxSize, 0, 2],
+        "largest" => [Some(5i64), None, Some(20)],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual}");
+
+    // the row index of the outer frame does not collide with its columns
+    let customers = df! { "id" => [1i64, 2, 3], "__POLARS_OUTER_IDX" => [7i64, 8, 9] }.unwrap();
+    ctx.register("customers", customers.lazy());
+    let actual = ctx
+        .execute(
+            "SELECT c.* FROM customers c
+            WHERE c.id IN (SELECT o.cid FROM orders o WHERE o.amount > c.id * 5)",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! { "id" => [1i64], "__POLARS_OUTER_IDX" => [7i64] }.unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_subquery_null_semantics() {
+    let mut ctx = SQLContext::new();
+    let customers = df! { "id" => [1i64, 2, 3] }.unwrap();
+    let orders = df! {
+        "cid" => [Some(1i64), None, Some(3)],
+        "amount" => [10i64, 20, 5],
+    }
+    .unwrap();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+
+    // a null in the subquery makes `IN` unknown for the rows without a match
+    for (cond, ids) in [
+        (
+            "c.id IN (SELECT o.cid FROM orders o WHERE o.amount > c.id * 7)",
+            vec![1i64],
+        ),
+        (
+            "c.id NOT IN (SELECT o.cid FROM orders o WHERE o.amount > c.id * 7)",
+            vec![3],
+        ),
+    ] {
+        let sql = format!("SELECT c.id FROM customers c WHERE {cond}");
+        let actual = ctx.execute(&sql).unwrap().collect().unwrap();
+        let expected = df! { "id" => ids }.unwrap();
+        assert!(actual.equals(&expected), "{cond}: {actual}");
+    }
+
+    // an uncorrelated scalar subquery must not return several rows
+    let err = ctx
+        .execute("SELECT c.id FROM customers c WHERE c.id < (SELECT cid FROM orders)")
+        .unwrap()
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("more than one row"), "{err}");
+}
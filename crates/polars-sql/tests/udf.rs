@@ -1,8 +1,8 @@
 use polars_core::prelude::*;
 use polars_lazy::prelude::IntoLazy;
 use polars_plan::prelude::{GetOutput, UserDefinedFunction};
-use polars_sql::SQLContext;
 use polars_sql::function_registry::FunctionRegistry;
+use polars_sql::{SQLAggregateFunction, SQLContext, SQLScalarFunction};
 
 struct MyFunctionRegistry {
     functions: PlHashMap<String, UserDefinedFunction>,
@@ -98,3 +98,90 @@ fn test_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_registered_functions() -> PolarsResult<()> {
+    let mut ctx = SQLContext::new();
+    let df = df! {
+        "grp" => ["x", "y", "x", "y", "x"],
+        "a" => [1, 2, 3, 4, 5],
+        "b" => [10, 20, 30, 40, 50],
+    }?;
+    ctx.register("df", df.lazy());
+
+    ctx.register_function(
+        "weighted",
+        SQLScalarFunction::new(|args: &[Series]| &args[0] * &(&args[1] / 10))
+            .with_return_type(DataType::Int32),
+    );
+    // the sum of squares, and the difference between the largest and the smallest value
+    ctx.register_aggregate_function(
+        "sum_sq",
+        SQLAggregateFunction::new(
+            || 0i64,
+            |acc: &mut i64, args: &[Series]| {
+                let values = args[0].cast(&DataType::Int64)?;
+                *acc += values
+                    .i64()?
+                    .into_no_null_iter()
+                    .map(|v| v * v)
+                    .sum::<i64>();
+                Ok(())
+            },
+            |acc: &mut i64, other: i64| {
+                *acc += other;
+                Ok(())
+            },
+            |acc: i64| Ok(acc.into()),
+        )
+        .with_return_type(DataType::Int64),
+    );
+    ctx.register_aggregate_function(
+        "spread",
+        SQLAggregateFunction::new(
+            || None::<(i32, i32)>,
+            |acc: &mut Option<(i32, i32)>, args: &[Series]| {
+                for v in args[0].i32()?.into_iter().flatten() {
+                    *acc = Some(acc.map_or((v, v), |(lo, hi)| (lo.min(v), hi.max(v))));
+                }
+                Ok(())
+            },
+            |acc: &mut Option<(i32, i32)>, other: Option<(i32, i32)>| {
+                if let Some((lo, hi)) = other {
+                    *acc = Some(acc.map_or((lo, hi), |(l, h)| (l.min(lo), h.max(hi))));
+                }
+                Ok(())
+            },
+            |acc: Option<(i32, i32)>| Ok(acc.map_or(0, |(lo, hi)| hi - lo).into()),
+        ),
+    );
+
+    let res = ctx
+        .execute("SELECT a, WEIGHTED(a, b) AS w FROM df WHERE weighted(a, b) > 4")?
+        .collect()?;
+    let expected = df! { "a" => [3, 4, 5], "w" => [9, 16, 25] }?;
+    assert!(res.equals(&expected), "{res}");
+
+    let res = ctx
+        .execute(
+            "SELECT grp, sum_sq(a) AS sq, spread(b) AS spread
+            FROM df GROUP BY grp ORDER BY grp",
+        )?
+        .collect()?;
+    let expected = df! {
+        "grp" => ["x", "y"],
+        "sq" => [35i64, 20],
+        "spread" => [40, 20],
+    }?;
+    assert!(res.equals(&expected), "{res}");
+
+    let res = ctx.execute("SELECT sum_sq(a) AS sq FROM df")?.collect()?;
+    assert!(res.equals(&df! { "sq" => [55i64] }?), "{res}");
+
+    // built-in functions take precedence, and unregistered functions are unknown
+    ctx.register_function("abs", SQLScalarFunction::new(|_: &[Series]| unreachable!()));
+    assert!(ctx.execute("SELECT abs(a) FROM df")?.collect().is_ok());
+    ctx.unregister_function("spread");
+    assert!(ctx.execute("SELECT spread(a) FROM df").is_err());
+    Ok(())
+}
@@ -270,11 +270,21 @@ impl Executor {
                     .unwrap_or(4);
             }
 
+            let affinity = polars_core::config::thread_affinity();
+            // The executor runs at the same time as the rayon pool, so its threads are placed
+            // after those of the pool.
+            let affinity_offset = match affinity {
+                polars_core::config::ThreadAffinity::None => 0,
+                _ => polars_core::POOL.current_num_threads(),
+            };
             let thread_task_lists = (0..n_threads)
                 .map(|t| {
                     std::thread::Builder::new()
                         .name(format!("async-executor-{t}"))
-                        .spawn(move || Self::global().runner(t))
+                        .spawn(move || {
+                            affinity.place_current_thread(affinity_offset + t);
+                            Self::global().runner(t)
+                        })
                         .unwrap();
 
                     let high_prio_tasks = WorkQueue::new_lifo();
@@ -16,6 +16,7 @@ use crate::async_primitives::linearizer::{Inserter, Linearizer};
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::execute::StreamingExecutionState;
 use crate::nodes::TaskPriority;
+use crate::nodes::io_sinks::report::SinkReporter;
 
 mod phase;
 use phase::PhaseOutcome;
@@ -29,6 +30,7 @@ pub mod json;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 pub mod partition;
+pub mod report;
 
 // This needs to be low to increase the backpressure.
 static DEFAULT_SINK_LINEARIZER_BUFFER_SIZE: LazyLock<usize> = LazyLock::new(|| {
@@ -185,6 +187,7 @@ struct StartedSinkComputeNode {
 pub struct SinkComputeNode {
     sink: Box<dyn SinkNode + Send + Sync>,
     started: Option<StartedSinkComputeNode>,
+    reporter: Option<SinkReporter>,
 }
 
 impl SinkComputeNode {
@@ -192,8 +195,15 @@ impl SinkComputeNode {
         Self {
             sink,
             started: None,
+            reporter: None,
         }
     }
+
+    /// Output the report of the files written by the sink that `reporter` wraps.
+    pub fn with_reporter(mut self, reporter: SinkReporter) -> Self {
+        self.reporter = Some(reporter);
+        self
+    }
 }
 
 impl<T: SinkNode + Send + Sync + 'static> From<T> for SinkComputeNode {
@@ -233,6 +243,10 @@ impl ComputeNode for SinkComputeNode {
         Ok(())
     }
 
+    fn get_output(&mut self) -> PolarsResult<Option<DataFrame>> {
+        self.reporter.take().map(|r| r.finish()).transpose()
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -107,6 +107,7 @@ impl SinkNode for ParquetSinkNode {
             compression: write_options.compression.into(),
             version: Version::V1,
             data_page_size: write_options.data_page_size,
+            zstd_dictionary_size: write_options.zstd_dictionary_size,
         };
 
         // Buffer task.
@@ -163,6 +164,7 @@ impl SinkNode for ParquetSinkNode {
                                         options.compression,
                                         vec![],
                                     )
+                                    .with_zstd_dictionary_size(options.zstd_dictionary_size)
                                     .collect::<ParquetResult<Vec<_>>>()
                                 })
                                 .collect::<ParquetResult<Vec<_>>>()?;
@@ -251,6 +253,7 @@ impl SinkNode for ParquetSinkNode {
                 compression: write_options.compression.into(),
                 version: Version::V1,
                 data_page_size: write_options.data_page_size,
+                zstd_dictionary_size: write_options.zstd_dictionary_size,
             };
             let file_writer = Mutex::new(FileWriter::new_with_parquet_schema(
                 writer,
@@ -12,6 +12,7 @@ use polars_plan::dsl::{
     FileType, PartitionTargetCallback, PartitionTargetContext, SinkOptions, SinkTarget,
 };
 
+use super::report::SinkReporter;
 use super::{DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE, SinkInputPort, SinkNode};
 use crate::async_executor::{AbortOnDropHandle, spawn};
 use crate::async_primitives::wait_group::WaitGroup;
@@ -28,12 +29,14 @@ pub type CreateNewSinkFn = Arc<
     dyn Send + Sync + Fn(SchemaRef, SinkTarget) -> PolarsResult<Box<dyn SinkNode + Send + Sync>>,
 >;
 
+/// Create the sinks of the files, which report what they write to `reporter`.
 pub fn get_create_new_fn(
     file_type: FileType,
     sink_options: SinkOptions,
     cloud_options: Option<CloudOptions>,
+    reporter: SinkReporter,
 ) -> CreateNewSinkFn {
-    match file_type {
+    let create_new: CreateNewSinkFn = match file_type {
         #[cfg(feature = "ipc")]
         FileType::Ipc(ipc_writer_options) => Arc::new(move |input_schema, target| {
             let sink = Box::new(super::ipc::IpcSinkNode::new(
@@ -87,7 +90,11 @@ pub fn get_create_new_fn(
         _ => {
             panic!("activate source feature")
         },
-    }
+    };
+    Arc::new(move |input_schema, target| {
+        let sink = create_new(input_schema, target.clone())?;
+        Ok(reporter.wrap(sink, &target))
+    })
 }
 
 enum SinkSender {
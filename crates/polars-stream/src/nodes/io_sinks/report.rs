@@ -0,0 +1,137 @@
+use std::sync::{Arc, Mutex};
+
+use polars_core::frame::DataFrame;
+use polars_core::prelude::DataType;
+use polars_core::schema::Schema;
+use polars_error::PolarsResult;
+use polars_plan::dsl::{SinkFileReport, SinkOptions, SinkReport, SinkTarget};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::{SinkInputPort, SinkNode};
+use crate::async_executor::spawn;
+use crate::async_primitives::connector::{Receiver, connector};
+use crate::execute::StreamingExecutionState;
+use crate::nodes::io_sinks::phase::PhaseOutcome;
+use crate::nodes::{JoinHandle, Morsel, TaskPriority};
+
+/// Records a [`SinkFileReport`] for every file a sink writes.
+#[derive(Clone)]
+pub struct SinkReporter {
+    key: Option<(PlSmallStr, DataType)>,
+    files: Arc<Mutex<Vec<SinkFileReport>>>,
+}
+
+impl SinkReporter {
+    pub fn new(sink_options: &SinkOptions, input_schema: &Schema) -> PolarsResult<Self> {
+        let key = sink_options
+            .report_key
+            .as_ref()
+            .map(|key| PolarsResult::Ok((key.clone(), input_schema.try_get(key)?.clone())))
+            .transpose()?;
+        Ok(Self {
+            key,
+            files: Default::default(),
+        })
+    }
+
+    /// Wrap the sink writing to `target`, to report what it writes.
+    pub fn wrap(
+        &self,
+        sink: Box<dyn SinkNode + Send + Sync>,
+        target: &SinkTarget,
+    ) -> Box<dyn SinkNode + Send + Sync> {
+        let mut files = self.files.lock().unwrap();
+        files.push(SinkFileReport::new(target));
+        Box::new(ReportingSinkNode {
+            sink,
+            reporter: self.clone(),
+            file_idx: files.len() - 1,
+        })
+    }
+
+    /// The report of all written files, as returned by collecting the sink.
+    pub fn finish(&self) -> PolarsResult<DataFrame> {
+        let mut files = std::mem::take(&mut *self.files.lock().unwrap());
+        files.iter_mut().for_each(SinkFileReport::finish);
+        SinkReport { files }.into_frame(self.key.as_ref().map(|(_, dtype)| dtype))
+    }
+}
+
+/// Passes the morsels on to another sink, recording them in a [`SinkReporter`].
+struct ReportingSinkNode {
+    sink: Box<dyn SinkNode + Send + Sync>,
+    reporter: SinkReporter,
+    file_idx: usize,
+}
+
+impl SinkNode for ReportingSinkNode {
+    fn name(&self) -> &str {
+        self.sink.name()
+    }
+
+    fn is_sink_input_parallel(&self) -> bool {
+        self.sink.is_sink_input_parallel()
+    }
+
+    fn do_maintain_order(&self) -> bool {
+        self.sink.do_maintain_order()
+    }
+
+    fn spawn_sink(
+        &mut self,
+        mut recv_ports_recv: Receiver<(PhaseOutcome, SinkInputPort)>,
+        state: &StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let (mut sink_tx, sink_rx) = connector();
+        self.sink.spawn_sink(sink_rx, state, join_handles);
+
+        let reporter = self.reporter.clone();
+        let file_idx = self.file_idx;
+        join_handles.push(spawn(TaskPriority::High, async move {
+            while let Ok((outcome, port)) = recv_ports_recv.recv().await {
+                let (port, passes) = match port {
+                    SinkInputPort::Serial(rx) => {
+                        let (tx, pass_rx) = connector::<Morsel>();
+                        (SinkInputPort::Serial(pass_rx), vec![(rx, tx)])
+                    },
+                    SinkInputPort::Parallel(rxs) => {
+                        let (txs, pass_rxs) = (0..rxs.len())
+                            .map(|_| connector::<Morsel>())
+                            .collect::<(Vec<_>, Vec<_>)>();
+                        let passes = rxs.into_iter().zip(txs).collect();
+                        (SinkInputPort::Parallel(pass_rxs), passes)
+                    },
+                };
+
+                // Every pass records its morsels locally, and merges them into the report of
+                // the file once the phase is done.
+                let passes = passes
+                    .into_iter()
+                    .map(|(mut rx, mut tx)| {
+                        let reporter = reporter.clone();
+                        spawn(TaskPriority::High, async move {
+                            let key = reporter.key.as_ref().map(|(key, _)| key.as_str());
+                            let mut report = SinkFileReport::default();
+                            while let Ok(morsel) = rx.recv().await {
+                                report.update(morsel.df(), key)?;
+                                if tx.send(morsel).await.is_err() {
+                                    break;
+                                }
+                            }
+                            reporter.files.lock().unwrap()[file_idx].merge(report)
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                if sink_tx.send((outcome, port)).await.is_err() {
+                    return Ok(());
+                }
+                for pass in passes {
+                    pass.await?;
+                }
+            }
+            Ok(())
+        }));
+    }
+}
@@ -38,6 +38,7 @@ pub mod builder {
     use arrow::io::ipc::read::FileMetadata;
     use polars_core::config;
     use polars_io::cloud::CloudOptions;
+    use polars_io::ipc::IpcScanOptions;
     use polars_plan::dsl::ScanSource;
 
     use super::IpcFileReader;
@@ -47,6 +48,7 @@ pub mod builder {
 
     #[derive(Debug)]
     pub struct IpcReaderBuilder {
+        pub options: IpcScanOptions,
         pub first_metadata: Option<Arc<FileMetadata>>,
     }
 
@@ -81,6 +83,7 @@ pub mod builder {
                 scan_source,
                 cloud_options,
                 metadata,
+                validate_utf8: self.options.validate_utf8.is_enabled(),
                 verbose,
                 init_data: None,
             };
@@ -100,6 +103,7 @@ struct IpcFileReader {
     scan_source: ScanSource,
     cloud_options: Option<Arc<CloudOptions>>,
     metadata: Option<Arc<FileMetadata>>,
+    validate_utf8: bool,
     verbose: bool,
 
     init_data: Option<InitializedState>,
@@ -183,6 +187,7 @@ impl FileReader for IpcFileReader {
         args: BeginReadArgs,
     ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
         let verbose = self.verbose;
+        let validate_utf8 = self.validate_utf8;
 
         let InitializedState {
             memslice,
@@ -403,6 +408,9 @@ impl FileReader for IpcFileReader {
                                 None,
                             );
 
+                            // SAFETY: the validation is only disabled for files from trusted
+                            // sources.
+                            unsafe { reader.set_validate_utf8(validate_utf8) };
                             reader.set_current_block(block_range.start);
                             reader.set_scratches((
                                 std::mem::take(&mut data_scratch),
@@ -408,6 +408,7 @@ impl ParquetReadImpl {
             predicate_arrow_field_indices,
             non_predicate_arrow_field_indices,
             min_values_per_thread,
+            validate_utf8: self.options.validate_utf8.is_enabled(),
         }
     }
 }
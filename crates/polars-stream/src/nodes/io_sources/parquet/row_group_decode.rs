@@ -11,7 +11,7 @@ use polars_io::hive;
 use polars_io::predicates::{ColumnPredicateExpr, ScanIOPredicate, SpecializedColumnPredicateExpr};
 pub use polars_io::prelude::_internal::PrefilterMaskSetting;
 use polars_io::prelude::_internal::calc_prefilter_cost;
-use polars_io::prelude::try_set_sorted_flag;
+use polars_io::prelude::{column_chunk_into_column, try_set_sorted_flag};
 use polars_parquet::read::{Filter, PredicateFilter};
 use polars_utils::IdxSize;
 use polars_utils::index::AtomicIdxSize;
@@ -32,6 +32,7 @@ pub(super) struct RowGroupDecoder {
     /// Indices into `projected_arrow_schema. This must be sorted.
     pub(super) non_predicate_arrow_field_indices: Vec<usize>,
     pub(super) min_values_per_thread: usize,
+    pub(super) validate_utf8: bool,
 }
 
 impl RowGroupDecoder {
@@ -152,6 +153,7 @@ impl RowGroupDecoder {
         filter: Option<polars_parquet::read::Filter>,
     ) -> PolarsResult<()> {
         let projected_arrow_schema = &self.projected_arrow_schema;
+        let validate_utf8 = self.validate_utf8;
         let expected_num_rows = filter
             .as_ref()
             .map_or(row_group_data.row_group_metadata.num_rows(), |x| {
@@ -170,6 +172,7 @@ impl RowGroupDecoder {
                     row_group_data,
                     filter.clone(),
                     expected_num_rows,
+                    validate_utf8,
                 )
             }) {
                 out_vec.push(s?.0)
@@ -204,6 +207,7 @@ impl RowGroupDecoder {
                                     &row_group_data,
                                     filter.clone(),
                                     expected_num_rows,
+                                    validate_utf8,
                                 )
                             })
                             .collect::<PolarsResult<Vec<_>>>()
@@ -227,6 +231,7 @@ impl RowGroupDecoder {
                     row_group_data,
                     filter.clone(),
                     expected_num_rows,
+                    validate_utf8,
                 )
             })
         {
@@ -246,6 +251,7 @@ fn decode_column(
     row_group_data: &RowGroupData,
     filter: Option<polars_parquet::read::Filter>,
     expected_num_rows: usize,
+    validate_utf8: bool,
 ) -> PolarsResult<(Column, Bitmap)> {
     let Some(iter) = row_group_data
         .row_group_metadata
@@ -280,6 +286,7 @@ fn decode_column(
         columns_to_deserialize,
         arrow_field.clone(),
         filter,
+        validate_utf8,
     )?;
 
     if !skip_num_rows_check {
@@ -288,18 +295,19 @@ fn decode_column(
 
     let mut series = Series::try_from((arrow_field, array))?;
 
-    if let Some(col_idxs) = row_group_data
+    let col_idx = row_group_data
         .row_group_metadata
         .columns_idxs_under_root_iter(&arrow_field.name)
-    {
-        if col_idxs.len() == 1 {
-            try_set_sorted_flag(&mut series, col_idxs[0], &row_group_data.sorting_map);
-        }
+        .filter(|col_idxs| col_idxs.len() == 1)
+        .map(|col_idxs| col_idxs[0]);
+    if let Some(col_idx) = col_idx {
+        try_set_sorted_flag(&mut series, col_idx, &row_group_data.sorting_map);
     }
 
     // TODO: Also load in the metadata.
 
-    Ok((series.into_column(), pred_true_mask))
+    let column = column_chunk_into_column(series, col_idx, &row_group_data.sorting_map)?;
+    Ok((column, pred_true_mask))
 }
 
 /// # Safety
@@ -463,7 +471,13 @@ impl RowGroupDecoder {
                 } else {
                     (None, None)
                 };
-                let res = decode_column(arrow_field, &row_group_data, filter, projection_height);
+                let res = decode_column(
+                    arrow_field,
+                    &row_group_data,
+                    filter,
+                    projection_height,
+                    self.validate_utf8,
+                );
 
                 match (res, constant) {
                     (Ok((c, m)), None) => (c, m),
@@ -583,6 +597,7 @@ impl RowGroupDecoder {
                     &mask,
                     &mask_bitmap,
                     expected_num_rows,
+                    self.validate_utf8,
                 ) {
                     Ok(v) => v,
                     e @ Err(_) => {
@@ -631,6 +646,7 @@ fn decode_column_prefiltered(
     mask: &BooleanChunked,
     mask_bitmap: &Bitmap,
     expected_num_rows: usize,
+    validate_utf8: bool,
 ) -> PolarsResult<Column> {
     let Some(iter) = row_group_data
         .row_group_metadata
@@ -665,17 +681,18 @@ fn decode_column_prefiltered(
         columns_to_deserialize,
         arrow_field.clone(),
         deserialize_filter,
+        validate_utf8,
     )?;
 
     let mut series = Series::try_from((arrow_field, array))?;
 
-    if let Some(col_idxs) = row_group_data
+    let col_idx = row_group_data
         .row_group_metadata
         .columns_idxs_under_root_iter(&arrow_field.name)
-    {
-        if col_idxs.len() == 1 {
-            try_set_sorted_flag(&mut series, col_idxs[0], &row_group_data.sorting_map);
-        }
+        .filter(|col_idxs| col_idxs.len() == 1)
+        .map(|col_idxs| col_idxs[0]);
+    if let Some(col_idx) = col_idx {
+        try_set_sorted_flag(&mut series, col_idx, &row_group_data.sorting_map);
     }
 
     let series = if !prefilter {
@@ -686,7 +703,7 @@ fn decode_column_prefiltered(
 
     assert_eq!(series.len(), expected_num_rows);
 
-    Ok(series.into_column())
+    column_chunk_into_column(series, col_idx, &row_group_data.sorting_map)
 }
 
 mod tests {
@@ -14,6 +14,8 @@ pub mod multiplexer;
 pub mod negative_slice;
 pub mod ordered_union;
 pub mod reduce;
+#[cfg(feature = "random")]
+pub mod reservoir_sample;
 pub mod select;
 pub mod simple_projection;
 pub mod streaming_slice;
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use polars_core::prelude::ReservoirSampler;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+
+/// A node that draws a uniform sample of at most `n` rows of every group in a single pass.
+/// Only the sample is kept in memory, it is sent once the input is done.
+enum ReservoirSampleState {
+    Sampling(ReservoirSampler),
+    Source(InMemorySourceNode),
+    Done,
+}
+
+pub struct ReservoirSampleNode {
+    state: ReservoirSampleState,
+}
+
+impl ReservoirSampleNode {
+    pub fn new(n: usize, by: Vec<PlSmallStr>, seed: Option<u64>) -> Self {
+        Self {
+            state: ReservoirSampleState::Sampling(ReservoirSampler::new(n, by, seed)),
+        }
+    }
+}
+
+impl ComputeNode for ReservoirSampleNode {
+    fn name(&self) -> &str {
+        "reservoir_sample"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        use ReservoirSampleState::*;
+
+        if send[0] == PortState::Done {
+            self.state = Done;
+        }
+
+        if recv[0] == PortState::Done && matches!(self.state, Sampling(_)) {
+            let Sampling(sampler) = std::mem::replace(&mut self.state, Done) else {
+                unreachable!()
+            };
+            let df = sampler.finish();
+            if df.height() > 0 {
+                self.state = Source(InMemorySourceNode::new(Arc::new(df), MorselSeq::default()));
+            }
+        }
+
+        match &mut self.state {
+            Sampling(_) => {
+                recv[0] = PortState::Ready;
+                send[0] = PortState::Blocked;
+            },
+            Source(node) => {
+                recv[0] = PortState::Done;
+                node.update_state(&mut [], send, state)?;
+            },
+            Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        match &mut self.state {
+            ReservoirSampleState::Sampling(sampler) => {
+                let mut recv = recv_ports[0].take().unwrap().serial();
+                assert!(send_ports[0].is_none());
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    while let Ok(morsel) = recv.recv().await {
+                        sampler.push(morsel.df())?;
+                    }
+
+                    Ok(())
+                }));
+            },
+            ReservoirSampleState::Source(in_memory_source_node) => {
+                assert!(recv_ports[0].is_none());
+                in_memory_source_node.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            ReservoirSampleState::Done => unreachable!(),
+        }
+    }
+}
@@ -105,6 +105,11 @@ fn visualize_plan_rec(
             format!("slice\\noffset: {offset}, length: {length}"),
             from_ref(input),
         ),
+        #[cfg(feature = "random")]
+        PhysNodeKind::ReservoirSample { input, n, by, .. } => (
+            format!("reservoir-sample\\nn: {n}, by: {}", by.join(", ")),
+            from_ref(input),
+        ),
         PhysNodeKind::Filter { input, predicate } => (
             format!("filter\\n{}", fmt_exprs(from_ref(predicate), expr_arena)),
             from_ref(input),
@@ -620,6 +620,7 @@ fn lower_exprs_with_ctx(
                         nulls_equal,
                         coalesce: Default::default(),
                         maintain_order: Default::default(),
+                        algorithm: Default::default(),
                     },
                     output_bool: true,
                 };
@@ -753,7 +754,7 @@ fn lower_exprs_with_ctx(
                             )
                         })
                         .collect(),
-                    slice: None,
+                    slice: sort_options.limit.map(|limit| (0, limit as usize)),
                     sort_options,
                 };
                 let output_schema = ctx.phys_sm[select_stream.node].output_schema.clone();
@@ -7,6 +7,7 @@ use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_bail};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
+use polars_ops::frame::JoinAlgorithm;
 use polars_plan::dsl::{
     FileScan, FileSinkType, PartitionSinkTypeIR, PartitionVariantIR, SinkTypeIR,
 };
@@ -362,6 +363,14 @@ pub fn lower_ir(
                     offset,
                 },
 
+                #[cfg(feature = "random")]
+                FunctionIR::ReservoirSample { n, by, seed } => PhysNodeKind::ReservoirSample {
+                    input: phys_input,
+                    n,
+                    by,
+                    seed,
+                },
+
                 function if function.is_streamable() => {
                     let map = Arc::new(move |df| function.evaluate(df));
                     PhysNodeKind::Map {
@@ -465,11 +474,12 @@ pub fn lower_ir(
                     ),
                     #[cfg(feature = "ipc")]
                     FileScan::Ipc {
-                        options: polars_io::ipc::IpcScanOptions {},
+                        options,
                         cloud_options,
                         metadata: first_metadata,
                     } => (
                         Arc::new(crate::nodes::io_sources::ipc::builder::IpcReaderBuilder {
+                            options: options.clone(),
                             first_metadata: first_metadata.clone(),
                         }) as Arc<dyn FileReaderBuilder>,
                         cloud_options,
@@ -761,7 +771,11 @@ pub fn lower_ir(
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
             let supported_join_type = args.how.is_equi() || args.how.is_semi_anti();
-            if supported_join_type && !args.validation.needs_checks() {
+            // A merge join is done by the in-memory engine.
+            if supported_join_type
+                && !args.validation.needs_checks()
+                && args.algorithm != JoinAlgorithm::Merge
+            {
                 // When lowering the expressions for the keys we need to ensure we keep around the
                 // payload columns, otherwise the input nodes can get replaced by input-independent
                 // nodes since the lowering code does not see we access any non-literal expressions.
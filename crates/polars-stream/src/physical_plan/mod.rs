@@ -121,6 +121,14 @@ pub enum PhysNodeKind {
         length: usize,
     },
 
+    #[cfg(feature = "random")]
+    ReservoirSample {
+        input: PhysStream,
+        n: usize,
+        by: Arc<[PlSmallStr]>,
+        seed: Option<u64>,
+    },
+
     Filter {
         input: PhysStream,
         predicate: ExprIR,
@@ -328,6 +336,12 @@ fn visit_node_inputs_mut(
                 visit(input);
             },
 
+            #[cfg(feature = "random")]
+            PhysNodeKind::ReservoirSample { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
             PhysNodeKind::InMemoryJoin {
                 input_left,
                 input_right,
@@ -28,7 +28,8 @@ use crate::expression::StreamExpr;
 use crate::graph::{Graph, GraphNodeKey};
 use crate::morsel::{MorselSeq, get_ideal_morsel_size};
 use crate::nodes;
-use crate::nodes::io_sinks::SinkComputeNode;
+use crate::nodes::io_sinks::report::SinkReporter;
+use crate::nodes::io_sinks::{SinkComputeNode, SinkNode};
 use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
 use crate::physical_plan::lower_expr::compute_output_schema;
@@ -140,6 +141,15 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "random")]
+        ReservoirSample { input, n, by, seed } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::reservoir_sample::ReservoirSampleNode::new(*n, by.to_vec(), *seed),
+                [(input_key, input.port)],
+            )
+        },
+
         Filter { predicate, input } => {
             let input_schema = &ctx.phys_sm[input.node].output_schema;
             let phys_predicate_expr = create_stream_expr(predicate, ctx, input_schema)?;
@@ -248,50 +258,45 @@ fn to_graph_rec<'a>(
             let sink_options = sink_options.clone();
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
             let input_key = to_graph_rec(input.node, ctx)?;
+            let reporter = SinkReporter::new(&sink_options, &input_schema)?;
 
-            match file_type {
+            let sink: Box<dyn SinkNode + Send + Sync> = match file_type {
                 #[cfg(feature = "ipc")]
-                FileType::Ipc(ipc_writer_options) => ctx.graph.add_node(
-                    SinkComputeNode::from(nodes::io_sinks::ipc::IpcSinkNode::new(
+                FileType::Ipc(ipc_writer_options) => {
+                    Box::new(nodes::io_sinks::ipc::IpcSinkNode::new(
                         input_schema,
                         target.clone(),
                         sink_options,
                         *ipc_writer_options,
                         cloud_options.clone(),
-                    )),
-                    [(input_key, input.port)],
-                ),
+                    ))
+                },
                 #[cfg(feature = "json")]
-                FileType::Json(_) => ctx.graph.add_node(
-                    SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
-                        target.clone(),
-                        sink_options,
-                        cloud_options.clone(),
-                    )),
-                    [(input_key, input.port)],
-                ),
+                FileType::Json(_) => Box::new(nodes::io_sinks::json::NDJsonSinkNode::new(
+                    target.clone(),
+                    sink_options,
+                    cloud_options.clone(),
+                )),
                 #[cfg(feature = "parquet")]
-                FileType::Parquet(parquet_writer_options) => ctx.graph.add_node(
-                    SinkComputeNode::from(nodes::io_sinks::parquet::ParquetSinkNode::new(
+                FileType::Parquet(parquet_writer_options) => {
+                    Box::new(nodes::io_sinks::parquet::ParquetSinkNode::new(
                         input_schema,
                         target.clone(),
                         sink_options,
                         parquet_writer_options,
                         cloud_options.clone(),
-                    )?),
-                    [(input_key, input.port)],
-                ),
+                    )?)
+                },
                 #[cfg(feature = "csv")]
-                FileType::Csv(csv_writer_options) => ctx.graph.add_node(
-                    SinkComputeNode::from(nodes::io_sinks::csv::CsvSinkNode::new(
+                FileType::Csv(csv_writer_options) => {
+                    Box::new(nodes::io_sinks::csv::CsvSinkNode::new(
                         target.clone(),
                         input_schema,
                         sink_options,
                         csv_writer_options.clone(),
                         cloud_options.clone(),
-                    )),
-                    [(input_key, input.port)],
-                ),
+                    ))
+                },
                 #[cfg(not(any(
                     feature = "csv",
                     feature = "parquet",
@@ -301,7 +306,11 @@ fn to_graph_rec<'a>(
                 _ => {
                     panic!("activate source feature")
                 },
-            }
+            };
+            ctx.graph.add_node(
+                SinkComputeNode::new(reporter.wrap(sink, target)).with_reporter(reporter),
+                [(input_key, input.port)],
+            )
         },
 
         PartitionSink {
@@ -319,10 +328,12 @@ fn to_graph_rec<'a>(
             let base_path = base_path.clone();
             let file_path_cb = file_path_cb.clone();
             let ext = PlSmallStr::from_static(file_type.extension());
+            let reporter = SinkReporter::new(sink_options, &input_schema)?;
             let create_new = nodes::io_sinks::partition::get_create_new_fn(
                 file_type.clone(),
                 sink_options.clone(),
                 cloud_options.clone(),
+                reporter.clone(),
             );
 
             match variant {
@@ -337,7 +348,8 @@ fn to_graph_rec<'a>(
                             ext,
                             sink_options.clone(),
                         ),
-                    ),
+                    )
+                    .with_reporter(reporter),
                     [(input_key, input.port)],
                 ),
                 PartitionVariantIR::Parted {
@@ -355,7 +367,8 @@ fn to_graph_rec<'a>(
                             sink_options.clone(),
                             *include_key,
                         ),
-                    ),
+                    )
+                    .with_reporter(reporter),
                     [(input_key, input.port)],
                 ),
                 PartitionVariantIR::ByKey {
@@ -373,7 +386,8 @@ fn to_graph_rec<'a>(
                             sink_options.clone(),
                             *include_key,
                         ),
-                    ),
+                    )
+                    .with_reporter(reporter),
                     [(input_key, input.port)],
                 ),
             }
@@ -0,0 +1,375 @@
+//! Proptest strategies that generate arbitrary, but valid, DataFrames.
+//!
+//! The strategies are meant for fuzzing: kernels that must accept any input, and round trips
+//! through file formats that must give back the frame they were given.
+//!
+//! # Example
+//!
+//! ```
+//! use polars_testing::arbitrary::{DataFrameStrategyOptions, dataframe_strategy};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn test_clone_equals(df in dataframe_strategy(DataFrameStrategyOptions::default())) {
+//!         prop_assert!(df.equals_missing(&df.clone()));
+//!     }
+//! }
+//! ```
+use std::ops::Range;
+
+use polars_core::prelude::*;
+use proptest::prelude::*;
+
+/// Configuration options for generating DataFrames.
+#[derive(Clone, Debug)]
+pub struct DataFrameStrategyOptions {
+    /// The range of the number of columns.
+    pub n_columns: Range<usize>,
+    /// The range of the number of rows.
+    pub n_rows: Range<usize>,
+    /// The maximum depth of the nested (List, Array and Struct) data types; 0 generates flat
+    /// schemas only.
+    pub max_nesting_depth: u32,
+    /// Whether columns may contain nulls. Every column gets its own pattern: no nulls, some,
+    /// mostly nulls or only nulls.
+    pub allow_nulls: bool,
+    /// Whether flat columns may be sorted, which sets their sortedness flag.
+    pub allow_sorted: bool,
+}
+
+impl Default for DataFrameStrategyOptions {
+    /// Creates a new `DataFrameStrategyOptions` with default settings.
+    ///
+    /// Default configuration:
+    /// - 1 to 7 columns and 0 to 99 rows
+    /// - Nested data types up to a depth of 2
+    /// - Nulls and sorted columns are allowed
+    fn default() -> Self {
+        Self {
+            n_columns: 1..8,
+            n_rows: 0..100,
+            max_nesting_depth: 2,
+            allow_nulls: true,
+            allow_sorted: true,
+        }
+    }
+}
+
+impl DataFrameStrategyOptions {
+    /// Creates a new `DataFrameStrategyOptions` with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the range of the number of columns.
+    pub fn with_n_columns(mut self, value: Range<usize>) -> Self {
+        self.n_columns = value;
+        self
+    }
+
+    /// Sets the range of the number of rows.
+    pub fn with_n_rows(mut self, value: Range<usize>) -> Self {
+        self.n_rows = value;
+        self
+    }
+
+    /// Sets the maximum depth of the nested data types.
+    pub fn with_max_nesting_depth(mut self, value: u32) -> Self {
+        self.max_nesting_depth = value;
+        self
+    }
+
+    /// Sets whether columns may contain nulls.
+    pub fn with_allow_nulls(mut self, value: bool) -> Self {
+        self.allow_nulls = value;
+        self
+    }
+
+    /// Sets whether flat columns may be sorted.
+    pub fn with_allow_sorted(mut self, value: bool) -> Self {
+        self.allow_sorted = value;
+        self
+    }
+}
+
+/// The flat data types that are generated.
+fn leaf_dtypes() -> Vec<DataType> {
+    use DataType::*;
+    vec![
+        Boolean,
+        Int8,
+        Int16,
+        Int32,
+        Int64,
+        UInt8,
+        UInt16,
+        UInt32,
+        UInt64,
+        Float32,
+        Float64,
+        String,
+        Binary,
+        Date,
+        Datetime(TimeUnit::Milliseconds, None),
+        Datetime(TimeUnit::Microseconds, None),
+        Datetime(TimeUnit::Nanoseconds, None),
+        Duration(TimeUnit::Microseconds),
+    ]
+}
+
+/// Returns a strategy of an arbitrary data type, nested up to `max_nesting_depth` levels.
+pub fn dtype_strategy(max_nesting_depth: u32) -> BoxedStrategy<DataType> {
+    prop::sample::select(leaf_dtypes())
+        .prop_recursive(max_nesting_depth, 16, 3, |inner| {
+            prop_oneof![
+                inner
+                    .clone()
+                    .prop_map(|dtype| DataType::List(Box::new(dtype))),
+                (inner.clone(), 1..4usize)
+                    .prop_map(|(dtype, width)| DataType::Array(Box::new(dtype), width)),
+                prop::collection::vec(inner, 1..4).prop_map(|dtypes| {
+                    let fields = dtypes
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, dtype)| Field::new(format!("f{i}").into(), dtype))
+                        .collect();
+                    DataType::Struct(fields)
+                }),
+            ]
+        })
+        .boxed()
+}
+
+/// `len` values, of which a fraction of about `1 - some_probability` is null.
+fn values<S: Strategy>(
+    strategy: S,
+    len: usize,
+    some_probability: f64,
+) -> impl Strategy<Value = Vec<Option<S::Value>>> {
+    prop::collection::vec(prop::option::weighted(some_probability, strategy), len)
+}
+
+fn leaf_strategy(dtype: &DataType, len: usize, some_probability: f64) -> BoxedStrategy<Series> {
+    macro_rules! leaf {
+        ($strategy:expr) => {
+            values($strategy, len, some_probability)
+                .prop_map(|v| Series::new(PlSmallStr::EMPTY, v))
+                .boxed()
+        };
+    }
+
+    let cast_to = |s: BoxedStrategy<Series>, dtype: &DataType| {
+        let dtype = dtype.clone();
+        s.prop_map(move |s| s.cast(&dtype).unwrap()).boxed()
+    };
+    match dtype {
+        DataType::Boolean => leaf!(any::<bool>()),
+        DataType::Int8 => leaf!(any::<i8>()),
+        DataType::Int16 => leaf!(any::<i16>()),
+        DataType::Int32 => leaf!(any::<i32>()),
+        DataType::Int64 => leaf!(any::<i64>()),
+        DataType::UInt8 => leaf!(any::<u8>()),
+        DataType::UInt16 => leaf!(any::<u16>()),
+        DataType::UInt32 => leaf!(any::<u32>()),
+        DataType::UInt64 => leaf!(any::<u64>()),
+        DataType::Float32 => leaf!(any::<f32>()),
+        DataType::Float64 => leaf!(any::<f64>()),
+        DataType::String => leaf!("\\PC{0,8}"),
+        DataType::Binary => values(
+            prop::collection::vec(any::<u8>(), 0..8),
+            len,
+            some_probability,
+        )
+        .prop_map(|v| v.into_iter().collect::<BinaryChunked>().into_series())
+        .boxed(),
+        // Stay within a few centuries around the epoch, which all time units can represent.
+        DataType::Date => cast_to(leaf!(-100_000i32..100_000), dtype),
+        DataType::Datetime(unit, _) | DataType::Duration(unit) => {
+            let max: i64 = match unit {
+                TimeUnit::Nanoseconds => 1 << 62,
+                TimeUnit::Microseconds => 1 << 52,
+                TimeUnit::Milliseconds => 1 << 42,
+            };
+            cast_to(leaf!(-max..max), dtype)
+        },
+        dtype => unreachable!("{dtype} is not a generated flat data type"),
+    }
+}
+
+/// `len` values of `dtype`, with a fraction of about `1 - some_probability` nulls.
+fn values_strategy(
+    dtype: &DataType,
+    len: usize,
+    some_probability: f64,
+    options: &DataFrameStrategyOptions,
+) -> BoxedStrategy<Series> {
+    match dtype {
+        DataType::List(inner) | DataType::Array(inner, _) => {
+            let width = match dtype {
+                DataType::Array(_, width) => *width..*width + 1,
+                _ => 0..4,
+            };
+            let row = {
+                let (inner, options) = ((**inner).clone(), options.clone());
+                width.prop_flat_map(move |n| series_strategy(inner.clone(), n, &options))
+            };
+            let (inner, dtype) = ((**inner).clone(), dtype.clone());
+            values(row, len, some_probability)
+                .prop_map(move |rows| {
+                    let mut builder = get_list_builder(&inner, 0, rows.len(), PlSmallStr::EMPTY);
+                    for row in &rows {
+                        builder.append_opt_series(row.as_ref()).unwrap();
+                    }
+                    builder.finish().into_series().cast(&dtype).unwrap()
+                })
+                .boxed()
+        },
+        DataType::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    let name = field.name().clone();
+                    series_strategy(field.dtype().clone(), len, options)
+                        .prop_map(move |s| s.with_name(name.clone()))
+                })
+                .collect::<Vec<_>>();
+            let dtype = dtype.clone();
+            (fields, values(Just(()), len, some_probability))
+                .prop_map(move |(fields, validity)| {
+                    let s = StructChunked::from_series(PlSmallStr::EMPTY, len, fields.iter())
+                        .unwrap()
+                        .into_series();
+                    let mask: BooleanChunked = validity.iter().map(Option::is_some).collect();
+                    let nulls = Series::full_null(PlSmallStr::EMPTY, len, &dtype);
+                    s.zip_with(&mask, &nulls).unwrap()
+                })
+                .boxed()
+        },
+        dtype => {
+            let values = leaf_strategy(dtype, len, some_probability);
+            if !options.allow_sorted {
+                return values;
+            }
+            let sort = prop::option::weighted(0.25, (any::<bool>(), any::<bool>()));
+            (values, sort)
+                .prop_map(|(s, sort)| match sort {
+                    Some((descending, nulls_last)) => s
+                        .sort(
+                            SortOptions::default()
+                                .with_order_descending(descending)
+                                .with_nulls_last(nulls_last),
+                        )
+                        .unwrap(),
+                    None => s,
+                })
+                .boxed()
+        },
+    }
+}
+
+/// Returns a strategy of an unnamed Series of `len` values of `dtype`.
+///
+/// With nulls allowed, the Series has no nulls, some, mostly nulls or only nulls, and nested
+/// values have their own null patterns.
+pub fn series_strategy(
+    dtype: DataType,
+    len: usize,
+    options: &DataFrameStrategyOptions,
+) -> BoxedStrategy<Series> {
+    let some_probability = if options.allow_nulls {
+        prop::sample::select(vec![1.0, 0.9, 0.5, 0.0]).boxed()
+    } else {
+        Just(1.0).boxed()
+    };
+    let options = options.clone();
+    some_probability
+        .prop_flat_map(move |p| values_strategy(&dtype, len, p, &options))
+        .boxed()
+}
+
+/// Returns a strategy of an arbitrary DataFrame, with columns named "c0", "c1", and so on.
+pub fn dataframe_strategy(options: DataFrameStrategyOptions) -> BoxedStrategy<DataFrame> {
+    let dtypes = prop::collection::vec(
+        dtype_strategy(options.max_nesting_depth),
+        options.n_columns.clone(),
+    );
+    (dtypes, options.n_rows.clone())
+        .prop_flat_map(move |(dtypes, height)| {
+            let columns = dtypes
+                .into_iter()
+                .enumerate()
+                .map(|(i, dtype)| {
+                    series_strategy(dtype, height, &options)
+                        .prop_map(move |s| s.with_name(format!("c{i}").into()).into_column())
+                })
+                .collect::<Vec<_>>();
+            columns.prop_map(move |columns| DataFrame::new_with_height(height, columns).unwrap())
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn nesting_depth(dtype: &DataType) -> u32 {
+        match dtype {
+            DataType::List(inner) | DataType::Array(inner, _) => 1 + nesting_depth(inner),
+            DataType::Struct(fields) => {
+                1 + fields
+                    .iter()
+                    .map(|f| nesting_depth(f.dtype()))
+                    .max()
+                    .unwrap_or(0)
+            },
+            _ => 0,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_dataframe_strategy(df in dataframe_strategy(DataFrameStrategyOptions::default())) {
+            prop_assert!((1..8).contains(&df.width()));
+            prop_assert!(df.height() < 100);
+            for c in df.get_columns() {
+                prop_assert!(nesting_depth(c.dtype()) <= 2);
+                let s = c.as_materialized_series();
+                let sorted = match s.is_sorted_flag() {
+                    IsSorted::Ascending => Some(false),
+                    IsSorted::Descending => Some(true),
+                    IsSorted::Not => None,
+                };
+                if let Some(descending) = sorted {
+                    let nulls_last = s.null_count() > 0 && !s.get(0).unwrap().is_null();
+                    let options = SortOptions::default()
+                        .with_order_descending(descending)
+                        .with_nulls_last(nulls_last);
+                    let mut unflagged = s.clone();
+                    unflagged.set_sorted_flag(IsSorted::Not);
+                    prop_assert!(s.equals_missing(&unflagged.sort(options).unwrap()));
+                }
+            }
+        }
+
+        #[test]
+        fn test_dataframe_strategy_options(
+            df in dataframe_strategy(
+                DataFrameStrategyOptions::default()
+                    .with_n_columns(3..4)
+                    .with_n_rows(5..6)
+                    .with_max_nesting_depth(0)
+                    .with_allow_nulls(false)
+                    .with_allow_sorted(false)
+            )
+        ) {
+            prop_assert_eq!(df.shape(), (5, 3));
+            for c in df.get_columns() {
+                prop_assert!(!c.dtype().is_nested());
+                prop_assert_eq!(c.null_count(), 0);
+            }
+        }
+    }
+}
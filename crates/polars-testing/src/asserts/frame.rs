@@ -0,0 +1,243 @@
+/// Asserts that two DataFrames are equal according to the specified options.
+///
+/// This macro compares two Polars DataFrame objects and panics with a detailed error message if
+/// they are not equal. A value mismatch shows a table of the rows that differ. It provides two
+/// forms:
+/// - With custom comparison options
+/// - With default comparison options
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_testing::assert_dataframe_equal;
+/// use polars_testing::asserts::DataFrameEqualOptions;
+///
+/// // Create two DataFrames to compare
+/// let df1 = df!("id" => [1, 2], "price" => [1.0, 2.0]).unwrap();
+/// let df2 = df!("id" => [2, 1], "price" => [2.0, 1.0 + 1e-9]).unwrap();
+///
+/// // Assert with default options
+/// assert_dataframe_equal!(&df1, &df1);
+///
+/// // Assert with custom options
+/// let options = DataFrameEqualOptions::default()
+///     .with_check_row_order(false)
+///     .with_keys(["id"])
+///     .with_column_tolerance("price", 0.0, 1e-6);
+/// assert_dataframe_equal!(&df1, &df2, options);
+/// ```
+///
+/// # Panics
+///
+/// Panics when the DataFrames are not equal according to the specified comparison criteria.
+///
+#[macro_export]
+macro_rules! assert_dataframe_equal {
+    ($left:expr, $right:expr $(, $options:expr)?) => {
+        {
+            #[allow(unused_assignments)]
+            #[allow(unused_mut)]
+            let mut options = $crate::asserts::DataFrameEqualOptions::default();
+            $(options = $options;)?
+
+            match $crate::asserts::assert_dataframe_equal($left, $right, options) {
+                Ok(_) => {},
+                Err(e) => panic!("{}", e),
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::prelude::*;
+
+    use crate::asserts::DataFrameEqualOptions;
+
+    // Testing default struct implementation
+    #[test]
+    fn test_dataframe_equal_options() {
+        let options = DataFrameEqualOptions::default();
+
+        assert!(options.check_row_order);
+        assert!(options.check_column_order);
+        assert!(options.check_dtypes);
+        assert!(options.check_exact);
+        assert_eq!(options.rtol, 1e-5);
+        assert_eq!(options.atol, 1e-8);
+        assert!(!options.categorical_as_str);
+        assert!(options.column_tolerances.is_empty());
+        assert!(options.keys.is_none());
+    }
+
+    // Testing columns and data types
+    #[test]
+    #[should_panic(expected = r#"columns ["b"] in left DataFrame, but not in right"#)]
+    fn test_dataframe_missing_column() {
+        let df1 = df!("a" => [1, 2], "b" => [3, 4]).unwrap();
+        let df2 = df!("a" => [1, 2]).unwrap();
+
+        assert_dataframe_equal!(&df1, &df2);
+    }
+
+    #[test]
+    #[should_panic(expected = "columns are not in the same order")]
+    fn test_dataframe_column_order_mismatch() {
+        let df1 = df!("a" => [1, 2], "b" => [3, 4]).unwrap();
+        let df2 = df!("b" => [3, 4], "a" => [1, 2]).unwrap();
+
+        assert_dataframe_equal!(&df1, &df2);
+    }
+
+    #[test]
+    fn test_dataframe_check_column_order_false() {
+        let df1 = df!("a" => [1, 2], "b" => [3, 4]).unwrap();
+        let df2 = df!("b" => [3, 4], "a" => [1, 2]).unwrap();
+
+        let options = DataFrameEqualOptions::default().with_check_column_order(false);
+
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+
+    #[test]
+    #[should_panic(expected = "dtypes do not match")]
+    fn test_dataframe_dtype_mismatch() {
+        let df1 = df!("a" => [1, 2]).unwrap();
+        let df2 = df!("a" => [1.0, 2.0]).unwrap();
+
+        assert_dataframe_equal!(&df1, &df2);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of rows does not match")]
+    fn test_dataframe_height_mismatch() {
+        let df1 = df!("a" => [1, 2]).unwrap();
+        let df2 = df!("a" => [1, 2, 3]).unwrap();
+
+        assert_dataframe_equal!(&df1, &df2);
+    }
+
+    // Testing values
+    #[test]
+    #[should_panic(expected = r#"value mismatch for column "b""#)]
+    fn test_dataframe_value_mismatch() {
+        let df1 = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"]).unwrap();
+        let df2 = df!("a" => [1, 2, 3], "b" => ["x", "q", "z"]).unwrap();
+
+        assert_dataframe_equal!(&df1, &df2);
+    }
+
+    #[test]
+    fn test_dataframe_value_mismatch_diff() {
+        let df1 = df!("id" => [1, 2, 3], "price" => [1.0, 2.0, 3.0]).unwrap();
+        let df2 = df!("id" => [3, 2, 1], "price" => [3.5, 2.0, 1.0]).unwrap();
+
+        let options = DataFrameEqualOptions::default()
+            .with_check_row_order(false)
+            .with_keys(["id"]);
+        let err = crate::asserts::assert_dataframe_equal(&df1, &df2, options)
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("1 of 3 rows differ"));
+        assert!(err.contains("[left]"));
+        assert!(err.contains("3.5"));
+    }
+
+    // Testing row order
+    #[test]
+    #[should_panic(expected = r#"value mismatch for column "a""#)]
+    fn test_dataframe_row_order_mismatch() {
+        let df1 = df!("a" => [1, 2], "b" => [3, 4]).unwrap();
+        let df2 = df!("a" => [2, 1], "b" => [4, 3]).unwrap();
+
+        assert_dataframe_equal!(&df1, &df2);
+    }
+
+    #[test]
+    fn test_dataframe_check_row_order_false() {
+        let df1 = df!("a" => [1, 2], "b" => [3, 4]).unwrap();
+        let df2 = df!("a" => [2, 1], "b" => [4, 3]).unwrap();
+
+        let options = DataFrameEqualOptions::default().with_check_row_order(false);
+
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+
+    #[test]
+    fn test_dataframe_row_order_by_keys() {
+        // Sorting by all columns would misalign the rows, as the floats aren't equal.
+        let df1 = df!("value" => [1.0, 1.1], "id" => [1, 2]).unwrap();
+        let df2 = df!("value" => [1.05, 1.2], "id" => [2, 1]).unwrap();
+
+        let options = DataFrameEqualOptions::default()
+            .with_check_row_order(false)
+            .with_keys(["id"])
+            .with_column_tolerance("value", 0.0, 0.3);
+
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+
+    // Testing float tolerances
+    #[test]
+    #[should_panic(expected = "values not within tolerance")]
+    fn test_dataframe_float_exceeded_tol() {
+        let df1 = df!("a" => [1.0, 2.0], "b" => [1.0, 2.0]).unwrap();
+        let df2 = df!("a" => [1.0, 2.0], "b" => [1.1, 2.0]).unwrap();
+
+        let options = DataFrameEqualOptions::default().with_check_exact(false);
+
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+
+    #[test]
+    fn test_dataframe_float_within_tol() {
+        let df1 = df!("a" => [1.0, 2.0], "b" => [1.0, 2.0]).unwrap();
+        let df2 = df!("a" => [1.000005, 2.000015], "b" => [1.0, 2.0]).unwrap();
+
+        let options = DataFrameEqualOptions::default().with_check_exact(false);
+
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+
+    #[test]
+    fn test_dataframe_column_tolerance() {
+        let df1 = df!("a" => [1.0, 2.0], "b" => [1.0, 2.0]).unwrap();
+        let df2 = df!("a" => [1.0, 2.0], "b" => [1.05, 2.1]).unwrap();
+
+        let options = DataFrameEqualOptions::default().with_column_tolerance("b", 0.1, 0.0);
+
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+
+    #[test]
+    #[should_panic(expected = r#"value mismatch for column "a""#)]
+    fn test_dataframe_column_tolerance_other_column_exact() {
+        let df1 = df!("a" => [1.0, 2.0], "b" => [1.0, 2.0]).unwrap();
+        let df2 = df!("a" => [1.0, 2.0 + 1e-9], "b" => [1.05, 2.1]).unwrap();
+
+        let options = DataFrameEqualOptions::default().with_column_tolerance("b", 0.1, 0.0);
+
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+
+    // Testing categorical values
+    #[test]
+    fn test_dataframe_categorical_as_str_unordered() {
+        let cat = |values: &[&str]| {
+            Series::new("c".into(), values)
+                .cast(&DataType::Categorical(None, Default::default()))
+                .unwrap()
+                .into_column()
+        };
+        let df1 = DataFrame::new(vec![cat(&["b", "a", "c"])]).unwrap();
+        let df2 = DataFrame::new(vec![cat(&["a", "c", "b"])]).unwrap();
+
+        let options = DataFrameEqualOptions::default()
+            .with_check_row_order(false)
+            .with_categorical_as_str(true);
+
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+}
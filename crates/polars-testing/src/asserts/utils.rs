@@ -554,3 +554,324 @@ pub fn assert_series_equal(
         options.categorical_as_str,
     )
 }
+
+/// Configuration options for comparing DataFrame equality.
+///
+/// Controls the behavior of DataFrame equality comparisons by specifying which aspects to
+/// check, the tolerances for floating point comparisons and how rows are aligned.
+pub struct DataFrameEqualOptions {
+    /// Whether to check that rows appear in the same order.
+    pub check_row_order: bool,
+    /// Whether to check that columns appear in the same order.
+    pub check_column_order: bool,
+    /// Whether to check that the data types of the columns match.
+    pub check_dtypes: bool,
+    /// Whether to check for exact equality (true) or approximate equality (false) for floating point values.
+    pub check_exact: bool,
+    /// Relative tolerance for approximate equality of floating point values.
+    pub rtol: f64,
+    /// Absolute tolerance for approximate equality of floating point values.
+    pub atol: f64,
+    /// Whether to compare categorical values as strings.
+    pub categorical_as_str: bool,
+    /// Relative and absolute tolerances of specific columns, which are then compared approximately.
+    pub column_tolerances: PlHashMap<PlSmallStr, (f64, f64)>,
+    /// Columns that identify a row. If rows may be in a different order, they are aligned by
+    /// these columns instead of by sorting on all columns, and the keys of mismatching rows are
+    /// shown in the error.
+    pub keys: Option<Vec<PlSmallStr>>,
+}
+
+impl Default for DataFrameEqualOptions {
+    /// Creates a new `DataFrameEqualOptions` with default settings.
+    ///
+    /// Default configuration:
+    /// - Checks row order, column order and data types
+    /// - Uses exact equality comparisons
+    /// - Sets relative tolerance to 1e-5 and absolute tolerance to 1e-8 for floating point comparisons
+    /// - Does not convert categorical values to strings for comparison
+    /// - Has no per-column tolerances and no key columns
+    fn default() -> Self {
+        Self {
+            check_row_order: true,
+            check_column_order: true,
+            check_dtypes: true,
+            check_exact: true,
+            rtol: 1e-5,
+            atol: 1e-8,
+            categorical_as_str: false,
+            column_tolerances: PlHashMap::new(),
+            keys: None,
+        }
+    }
+}
+
+impl DataFrameEqualOptions {
+    /// Creates a new `DataFrameEqualOptions` with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to check that rows appear in the same order.
+    pub fn with_check_row_order(mut self, value: bool) -> Self {
+        self.check_row_order = value;
+        self
+    }
+
+    /// Sets whether to check that columns appear in the same order.
+    pub fn with_check_column_order(mut self, value: bool) -> Self {
+        self.check_column_order = value;
+        self
+    }
+
+    /// Sets whether to check that the data types of the columns match.
+    pub fn with_check_dtypes(mut self, value: bool) -> Self {
+        self.check_dtypes = value;
+        self
+    }
+
+    /// Sets whether to check for exact equality (true) or approximate equality (false) for floating point values.
+    pub fn with_check_exact(mut self, value: bool) -> Self {
+        self.check_exact = value;
+        self
+    }
+
+    /// Sets the relative tolerance for approximate equality of floating point values.
+    pub fn with_rtol(mut self, value: f64) -> Self {
+        self.rtol = value;
+        self
+    }
+
+    /// Sets the absolute tolerance for approximate equality of floating point values.
+    pub fn with_atol(mut self, value: f64) -> Self {
+        self.atol = value;
+        self
+    }
+
+    /// Sets whether to compare categorical values as strings.
+    pub fn with_categorical_as_str(mut self, value: bool) -> Self {
+        self.categorical_as_str = value;
+        self
+    }
+
+    /// Compares the floating point values of a column approximately, with its own tolerances.
+    pub fn with_column_tolerance(mut self, column: &str, rtol: f64, atol: f64) -> Self {
+        self.column_tolerances.insert(column.into(), (rtol, atol));
+        self
+    }
+
+    /// Sets the columns that identify a row.
+    pub fn with_keys(mut self, keys: impl IntoIterator<Item = impl Into<PlSmallStr>>) -> Self {
+        self.keys = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// The number of mismatching rows shown in the error of `assert_dataframe_equal`.
+const SHOWN_MISMATCHES: usize = 10;
+
+/// Returns which rows of two Series differ, taking the float tolerance into account.
+fn mismatching_rows(
+    left: &Series,
+    right: &Series,
+    check_exact: bool,
+    rtol: f64,
+    atol: f64,
+) -> PolarsResult<BooleanChunked> {
+    let unequal = left.not_equal_missing(right)?;
+    if check_exact || !comparing_floats(left.dtype(), right.dtype()) {
+        return Ok(unequal);
+    }
+    let difference = abs(&(left - right)?)?;
+    let tolerance = &(&abs(right)? * rtol) + atol;
+    let within_tolerance = difference.lt_eq(&tolerance)?;
+    Ok(unequal
+        .into_iter()
+        .zip(within_tolerance.into_iter())
+        .map(|(unequal, within)| unequal.unwrap_or(false) && !within.unwrap_or(false))
+        .collect())
+}
+
+/// Renders the rows of a column that differ between two DataFrames as a table.
+///
+/// The rows are identified by their key columns if there are any, and otherwise by their
+/// position after the rows have been aligned.
+fn render_mismatches(
+    left: &DataFrame,
+    l: &Series,
+    r: &Series,
+    keys: Option<&[PlSmallStr]>,
+    check_exact: bool,
+    rtol: f64,
+    atol: f64,
+) -> PolarsResult<String> {
+    let mismatching = mismatching_rows(l, r, check_exact, rtol, atol)?;
+    let n_mismatching = mismatching.sum().unwrap_or(0) as usize;
+    let ids = match keys {
+        Some(keys) => left.select(keys.iter().cloned())?,
+        None => DataFrame::empty_with_height(left.height()).with_row_index("row".into(), None)?,
+    };
+    let diff = ids
+        .hstack(&[
+            l.clone().with_name("[left]".into()).into_column(),
+            r.clone().with_name("[right]".into()).into_column(),
+        ])?
+        .filter(&mismatching)?
+        .head(Some(SHOWN_MISMATCHES));
+    Ok(format!(
+        "{n_mismatching} of {} rows differ\n{diff}",
+        left.height()
+    ))
+}
+
+/// Verifies that two DataFrames are equal according to a set of configurable criteria.
+///
+/// This function checks the columns of both DataFrames before comparing their values column
+/// by column with `assert_series_values_equal`.
+///
+/// # Arguments
+///
+/// * `left` - The first DataFrame to compare
+/// * `right` - The second DataFrame to compare
+/// * `options` - A `DataFrameEqualOptions` struct containing configuration parameters
+///
+/// # Returns
+///
+/// * `Ok(())` if the DataFrames match according to all specified criteria
+/// * `Err` with details about the first mismatch encountered:
+///   * Columns that are in only one of the DataFrames
+///   * Column order mismatch (if checking column order)
+///   * Data type mismatch (if checking dtypes)
+///   * Height mismatch
+///   * Value mismatches, with a table of the rows that differ
+///
+/// # Row alignment
+///
+/// If `check_row_order` is false, both DataFrames are sorted before their values are compared:
+/// by the `keys` columns if they are set, keeping the order of rows with equal keys, and
+/// otherwise by all columns.
+///
+pub fn assert_dataframe_equal(
+    left: &DataFrame,
+    right: &DataFrame,
+    options: DataFrameEqualOptions,
+) -> PolarsResult<()> {
+    let left_not_right = left
+        .get_column_names()
+        .into_iter()
+        .filter(|name| right.get_column_index(name).is_none())
+        .collect::<Vec<_>>();
+    if !left_not_right.is_empty() {
+        polars_bail!(
+            AssertionError: "columns {:?} in left DataFrame, but not in right", left_not_right
+        );
+    }
+    let right_not_left = right
+        .get_column_names()
+        .into_iter()
+        .filter(|name| left.get_column_index(name).is_none())
+        .collect::<Vec<_>>();
+    if !right_not_left.is_empty() {
+        polars_bail!(
+            AssertionError: "columns {:?} in right DataFrame, but not in left", right_not_left
+        );
+    }
+
+    if options.check_column_order && left.get_column_names() != right.get_column_names() {
+        return Err(polars_err!(
+            assertion_error = "DataFrames",
+            "columns are not in the same order",
+            format!("{:?}", left.get_column_names()),
+            format!("{:?}", right.get_column_names())
+        ));
+    }
+
+    if options.check_dtypes {
+        let dtype_mismatch = left
+            .get_columns()
+            .iter()
+            .any(|c| right.column(c.name()).unwrap().dtype() != c.dtype());
+        if dtype_mismatch {
+            return Err(polars_err!(
+                assertion_error = "DataFrames",
+                "dtypes do not match",
+                format!("{:?}", left.schema()),
+                format!("{:?}", right.schema())
+            ));
+        }
+    }
+
+    if left.height() != right.height() {
+        return Err(polars_err!(
+            assertion_error = "DataFrames",
+            "number of rows does not match",
+            left.height(),
+            right.height()
+        ));
+    }
+
+    let to_string = |df: &DataFrame| -> DataFrame {
+        df.get_columns()
+            .iter()
+            .map(|c| categorical_series_to_string(c.as_materialized_series()).into_column())
+            .collect()
+    };
+    let (left, right) = if options.categorical_as_str {
+        (to_string(left), to_string(right))
+    } else {
+        (left.clone(), right.clone())
+    };
+
+    let (left, right) = if options.check_row_order {
+        (left, right)
+    } else {
+        let (by, sort_options) = match &options.keys {
+            Some(keys) => (
+                keys.clone(),
+                SortMultipleOptions::default().with_maintain_order(true),
+            ),
+            None => (
+                left.get_column_names_owned(),
+                SortMultipleOptions::default(),
+            ),
+        };
+        (
+            left.sort(by.clone(), sort_options.clone())?,
+            right.sort(by, sort_options)?,
+        )
+    };
+
+    for c in left.get_columns() {
+        let l = c.as_materialized_series();
+        let r = right.column(c.name())?.as_materialized_series();
+        let (check_exact, rtol, atol) = match options.column_tolerances.get(c.name()) {
+            Some((rtol, atol)) => (false, *rtol, *atol),
+            None => (options.check_exact, options.rtol, options.atol),
+        };
+        if let Err(e) = assert_series_values_equal(
+            l,
+            r,
+            true,
+            check_exact,
+            rtol,
+            atol,
+            options.categorical_as_str,
+        ) {
+            let rendered = render_mismatches(
+                &left,
+                l,
+                r,
+                options.keys.as_deref(),
+                check_exact,
+                rtol,
+                atol,
+            )?;
+            polars_bail!(
+                AssertionError: "DataFrames are different (value mismatch for column {:?})\n{}\n\n{}",
+                c.name(), rendered, e
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -1 +1,3 @@
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
 pub mod asserts;
@@ -0,0 +1,175 @@
+//! Parsing of the two textual forms produced by `dt.to_string` for durations:
+//! the ISO 8601 form (eg: "-P1DT2H3M4.5S") and the "polars" form seen in the
+//! frame repr (eg: "1d 2h 3m 4s 500ms").
+use polars_core::prelude::TimeUnit;
+
+const NS_SECOND: i128 = 1_000_000_000;
+const NS_MINUTE: i128 = 60 * NS_SECOND;
+const NS_HOUR: i128 = 60 * NS_MINUTE;
+const NS_DAY: i128 = 24 * NS_HOUR;
+const NS_WEEK: i128 = 7 * NS_DAY;
+
+/// Parse a duration string in either the ISO 8601 or the "polars" form into a
+/// value of the given [`TimeUnit`]; values finer than the unit are truncated.
+///
+/// Returns `None` if the string is in neither form, uses calendar units (years
+/// or months), or does not fit in an `i64`.
+pub(super) fn parse_duration(s: &str, tu: TimeUnit) -> Option<i64> {
+    let s = s.trim();
+    let unsigned = s.strip_prefix(['-', '+']).unwrap_or(s);
+    let ns = if unsigned.starts_with('P') {
+        let ns = parse_iso(&unsigned[1..])?;
+        if s.starts_with('-') { -ns } else { ns }
+    } else {
+        parse_polars(s)?
+    };
+    let factor = match tu {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    };
+    i64::try_from(ns / factor).ok()
+}
+
+/// Split off the leading ascii digits of `s`.
+fn split_digits(s: &str) -> (&str, &str) {
+    let end = s
+        .bytes()
+        .position(|b| !b.is_ascii_digit())
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+/// Parse `digits` as a (possibly huge) integer number of `size` nanoseconds.
+fn scaled(digits: &str, size: i128) -> Option<i128> {
+    digits.parse::<i128>().ok()?.checked_mul(size)
+}
+
+/// Parse the part of an ISO 8601 duration that follows the "P" designator.
+fn parse_iso(s: &str) -> Option<i128> {
+    let (date_part, time_part) = match s.split_once('T') {
+        Some((date_part, time_part)) => (date_part, Some(time_part)),
+        None => (s, None),
+    };
+    let mut ns = 0;
+    let mut n_parts = 0;
+
+    let mut rest = date_part;
+    let mut designators = ['W', 'D'].as_slice();
+    while !rest.is_empty() {
+        let (digits, tail) = split_digits(rest);
+        let designator = tail.chars().next()?;
+        let pos = designators.iter().position(|d| *d == designator)?;
+        designators = &designators[pos + 1..];
+        let size = if designator == 'W' { NS_WEEK } else { NS_DAY };
+        ns = scaled(digits, size)?.checked_add(ns)?;
+        n_parts += 1;
+        rest = &tail[1..];
+    }
+
+    if let Some(time_part) = time_part {
+        if time_part.is_empty() {
+            return None;
+        }
+        let mut rest = time_part;
+        let mut designators = ['H', 'M', 'S'].as_slice();
+        while !rest.is_empty() {
+            let (digits, mut tail) = split_digits(rest);
+            let mut fraction = 0;
+            if let Some(frac) = tail.strip_prefix(['.', ',']) {
+                let (frac_digits, frac_tail) = split_digits(frac);
+                if frac_digits.is_empty() || !frac_tail.starts_with('S') {
+                    return None;
+                }
+                // Digits beyond nanosecond precision are truncated.
+                let frac_digits = &frac_digits[..frac_digits.len().min(9)];
+                fraction =
+                    frac_digits.parse::<i128>().ok()? * 10_i128.pow(9 - frac_digits.len() as u32);
+                tail = frac_tail;
+            }
+            let designator = tail.chars().next()?;
+            let pos = designators.iter().position(|d| *d == designator)?;
+            designators = &designators[pos + 1..];
+            let size = match designator {
+                'H' => NS_HOUR,
+                'M' => NS_MINUTE,
+                _ => NS_SECOND,
+            };
+            ns = scaled(digits, size)?.checked_add(ns + fraction)?;
+            n_parts += 1;
+            rest = &tail[1..];
+        }
+    }
+    (n_parts > 0).then_some(ns)
+}
+
+/// Parse the "polars" form: whitespace-separated, individually signed parts
+/// such as "-1d -42s" or "3d 22m 55s 1ms".
+fn parse_polars(s: &str) -> Option<i128> {
+    let mut ns = 0;
+    let mut n_parts = 0;
+    for part in s.split_whitespace() {
+        let (negative, unsigned) = match part.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, part.strip_prefix('+').unwrap_or(part)),
+        };
+        let (digits, unit) = split_digits(unsigned);
+        let size = match unit {
+            "ns" => 1,
+            "µs" | "us" => 1_000,
+            "ms" => 1_000_000,
+            "s" => NS_SECOND,
+            "m" => NS_MINUTE,
+            "h" => NS_HOUR,
+            "d" => NS_DAY,
+            "w" => NS_WEEK,
+            _ => return None,
+        };
+        let v = scaled(digits, size)?;
+        ns = if negative {
+            ns.checked_sub(v)?
+        } else {
+            ns.checked_add(v)?
+        };
+        n_parts += 1;
+    }
+    (n_parts > 0).then_some(ns)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        let us = TimeUnit::Microseconds;
+        let day_us = 86_400_000_000;
+        for (s, expected) in [
+            ("PT0S", Some(0)),
+            ("P14DT1H", Some(14 * day_us + 3_600_000_000)),
+            ("-P1DT42S", Some(-day_us - 42_000_000)),
+            ("PT1.5S", Some(1_500_000)),
+            ("P1W", Some(7 * day_us)),
+            ("0µs", Some(0)),
+            ("-1d -42s", Some(-day_us - 42_000_000)),
+            (
+                "13d 14h 100µs",
+                Some(13 * day_us + 14 * 3_600_000_000 + 100),
+            ),
+            ("1y", None),
+            ("P1Y", None),
+            ("PT", None),
+            ("P1H", None),
+            ("PT1S1M", None),
+            ("", None),
+            ("1d2", None),
+        ] {
+            assert_eq!(parse_duration(s, us), expected, "{s}");
+        }
+        assert_eq!(parse_duration("1ms 1ns", TimeUnit::Milliseconds), Some(1));
+        assert_eq!(
+            parse_duration("1ms 1ns", TimeUnit::Nanoseconds),
+            Some(1_000_001)
+        );
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-duration")]
+mod duration;
 pub mod infer;
 use chrono::DateTime;
 mod patterns;
@@ -90,6 +92,22 @@ fn sniff_fmt_time(ca_string: &StringChunked) -> PolarsResult<&'static str> {
 }
 
 pub trait StringMethods: AsString {
+    #[cfg(feature = "dtype-duration")]
+    /// Parsing string values in the ISO 8601 (eg: "P1DT2H") or the "polars" (eg: "1d 2h")
+    /// duration form and return a [`DurationChunked`]
+    fn as_duration(&self, tu: TimeUnit, use_cache: bool) -> DurationChunked {
+        let string_ca = self.as_string();
+        let use_cache = use_cache && string_ca.len() > 50;
+
+        let mut convert = LruCachedFunc::new(
+            |s| duration::parse_duration(s, tu),
+            (string_ca.len() as f64).sqrt() as usize,
+        );
+        let ca: Int64Chunked =
+            unary_elementwise(string_ca, |opt_s| convert.eval(opt_s?, use_cache));
+        ca.with_name(string_ca.name().clone()).into_duration(tu)
+    }
+
     #[cfg(feature = "dtype-time")]
     /// Parsing string values and return a [`TimeChunked`]
     fn as_time(&self, fmt: Option<&str>, use_cache: bool) -> PolarsResult<TimeChunked> {
@@ -1,3 +1,5 @@
+#[cfg(feature = "timezones")]
+use arrow::legacy::kernels::NonExistent;
 use arrow::legacy::time_zone::Tz;
 use polars_core::prelude::arity::broadcast_try_binary_elementwise;
 use polars_core::prelude::*;
@@ -127,3 +129,71 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
         })
     }
 }
+
+/// Offset a time zone aware `Datetime` by `offsets`, resolving ambiguous and non-existent results
+/// with the given policies.
+///
+/// The months, weeks and days of every offset are applied to the wall-clock time and the result
+/// is localized with `ambiguous` and `non_existent`. The remaining fixed part of the offset is
+/// added afterwards in absolute time. Inputs without a time zone are offset with
+/// [`impl_offset_by`].
+#[cfg(feature = "timezones")]
+pub fn impl_offset_by_with_policy(
+    ts: &Series,
+    offsets: &Series,
+    ambiguous: &StringChunked,
+    non_existent: NonExistent,
+) -> PolarsResult<Series> {
+    let DataType::Datetime(tu, Some(tz)) = ts.dtype() else {
+        return impl_offset_by(ts, offsets);
+    };
+    let offsets = offsets.str()?;
+    polars_ensure!(
+        ts.len() == offsets.len() || offsets.len() == 1 || ts.len() == 1,
+        length_mismatch = "dt.offset_by",
+        ts.len(),
+        offsets.len()
+    );
+
+    let (offset_fn, nsecs_to_unit): (fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>, i64) =
+        match tu {
+            TimeUnit::Milliseconds => (Duration::add_ms, 1_000_000),
+            TimeUnit::Microseconds => (Duration::add_us, 1_000),
+            TimeUnit::Nanoseconds => (Duration::add_ns, 1),
+        };
+
+    let local = polars_ops::prelude::replace_time_zone(
+        ts.datetime()?,
+        None,
+        &StringChunked::from_iter(std::iter::once("raise")),
+        NonExistent::Raise,
+    )?;
+    let shifted: Int64Chunked =
+        broadcast_try_binary_elementwise(&local.0, offsets, |timestamp_opt, offset_opt| {
+            match (timestamp_opt, offset_opt) {
+                (Some(timestamp), Some(offset)) => {
+                    let (calendar, _) = Duration::try_parse(offset)?.split_calendar();
+                    offset_fn(&calendar, timestamp, None).map(Some)
+                },
+                _ => Ok(None),
+            }
+        })?;
+    let shifted = polars_ops::prelude::replace_time_zone(
+        &shifted.into_datetime(*tu, None),
+        Some(tz.as_str()),
+        ambiguous,
+        non_existent,
+    )?;
+    let out: Int64Chunked = broadcast_try_binary_elementwise(
+        &shifted.0,
+        offsets,
+        |timestamp_opt, offset_opt| match (timestamp_opt, offset_opt) {
+            (Some(timestamp), Some(offset)) => {
+                let (_, nsecs) = Duration::try_parse(offset)?.split_calendar();
+                Ok(Some(timestamp + nsecs / nsecs_to_unit))
+            },
+            _ => Ok(None),
+        },
+    )?;
+    Ok(out.into_datetime(*tu, Some(tz.clone())).into_series())
+}
@@ -8,10 +8,32 @@ use crate::prelude::*;
 
 pub trait PolarsTruncate {
     fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self>
+    where
+        Self: Sized,
+    {
+        self.truncate_with_week_start(tz, every, 0)
+    }
+
+    /// Truncate like [`PolarsTruncate::truncate`], but let weekly buckets start `week_start`
+    /// days after Monday, e.g. `6` for weeks starting on Sunday.
+    fn truncate_with_week_start(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        week_start: u8,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
+fn validate_week_start(week_start: u8) -> PolarsResult<()> {
+    polars_ensure!(
+        week_start < 7,
+        InvalidOperation: "`week_start` must be between 0 (Monday) and 6 (Sunday), got {}", week_start
+    );
+    Ok(())
+}
+
 #[inline(always)]
 pub(crate) fn fast_truncate(t: i64, every: i64) -> i64 {
     let remainder = t % every;
@@ -19,7 +41,13 @@ pub(crate) fn fast_truncate(t: i64, every: i64) -> i64 {
 }
 
 impl PolarsTruncate for DatetimeChunked {
-    fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
+    fn truncate_with_week_start(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        week_start: u8,
+    ) -> PolarsResult<Self> {
+        validate_week_start(week_start)?;
         polars_ensure!(
             self.len() == every.len() || self.len() == 1 || every.len() == 1,
             length_mismatch = "dt.truncate",
@@ -28,7 +56,7 @@ impl PolarsTruncate for DatetimeChunked {
         );
 
         let time_zone = self.time_zone();
-        let offset = Duration::new(0);
+        let tu = self.time_unit();
 
         // Let's check if we can use a fastpath...
         if every.len() == 1 {
@@ -51,18 +79,9 @@ impl PolarsTruncate for DatetimeChunked {
                         .apply_values(|t| fast_truncate(t, every))
                         .into_datetime(self.time_unit(), time_zone.clone()));
                 } else {
-                    let w = Window::new(every_parsed, every_parsed, offset);
-                    let out = match self.time_unit() {
-                        TimeUnit::Milliseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_ms(t, tz))
-                        },
-                        TimeUnit::Microseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_us(t, tz))
-                        },
-                        TimeUnit::Nanoseconds => {
-                            self.try_apply_nonnull_values_generic(|t| w.truncate_ns(t, tz))
-                        },
-                    };
+                    let out = self.try_apply_nonnull_values_generic(|t| {
+                        every_parsed.truncate_with_week_start(t, tz, tu, week_start)
+                    });
                     return Ok(out?.into_datetime(self.time_unit(), self.time_zone().clone()));
                 }
             } else {
@@ -74,12 +93,6 @@ impl PolarsTruncate for DatetimeChunked {
         // A sqrt(n) cache is not too small, not too large.
         let mut duration_cache = LruCache::with_capacity((every.len() as f64).sqrt() as usize);
 
-        let func = match self.time_unit() {
-            TimeUnit::Nanoseconds => Window::truncate_ns,
-            TimeUnit::Microseconds => Window::truncate_us,
-            TimeUnit::Milliseconds => Window::truncate_ms,
-        };
-
         let out = broadcast_try_binary_elementwise(self, every, |opt_timestamp, opt_every| match (
             opt_timestamp,
             opt_every,
@@ -91,8 +104,9 @@ impl PolarsTruncate for DatetimeChunked {
                     polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
                 }
 
-                let w = Window::new(every, every, offset);
-                func(&w, timestamp, tz).map(Some)
+                every
+                    .truncate_with_week_start(timestamp, tz, tu, week_start)
+                    .map(Some)
             },
             _ => Ok(None),
         });
@@ -101,7 +115,13 @@ impl PolarsTruncate for DatetimeChunked {
 }
 
 impl PolarsTruncate for DateChunked {
-    fn truncate(&self, _tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self> {
+    fn truncate_with_week_start(
+        &self,
+        _tz: Option<&Tz>,
+        every: &StringChunked,
+        week_start: u8,
+    ) -> PolarsResult<Self> {
+        validate_week_start(week_start)?;
         polars_ensure!(
             self.len() == every.len() || self.len() == 1 || every.len() == 1,
             length_mismatch = "dt.truncate",
@@ -109,7 +129,14 @@ impl PolarsTruncate for DateChunked {
             every.len()
         );
 
-        let offset = Duration::new(0);
+        let truncate_days = |every: &Duration, t: i32| -> PolarsResult<i32> {
+            Ok((every.truncate_with_week_start(
+                MILLISECONDS_IN_DAY * t as i64,
+                None,
+                TimeUnit::Milliseconds,
+                week_start,
+            )? / MILLISECONDS_IN_DAY) as i32)
+        };
         let out = match every.len() {
             1 => {
                 if let Some(every) = every.get(0) {
@@ -117,11 +144,7 @@ impl PolarsTruncate for DateChunked {
                     if every.negative {
                         polars_bail!(ComputeError: "cannot truncate a Date to a negative duration")
                     }
-                    let w = Window::new(every, every, offset);
-                    self.try_apply_nonnull_values_generic(|t| {
-                        Ok((w.truncate_ms(MILLISECONDS_IN_DAY * t as i64, None)?
-                            / MILLISECONDS_IN_DAY) as i32)
-                    })
+                    self.try_apply_nonnull_values_generic(|t| truncate_days(&every, t))
                 } else {
                     Ok(Int32Chunked::full_null(self.name().clone(), self.len()))
                 }
@@ -138,11 +161,7 @@ impl PolarsTruncate for DateChunked {
                             polars_bail!(ComputeError: "cannot truncate a Date to a negative duration")
                         }
 
-                        let w = Window::new(every, every, offset);
-                        Ok(Some(
-                            (w.truncate_ms(MILLISECONDS_IN_DAY * t as i64, None)?
-                                / MILLISECONDS_IN_DAY) as i32,
-                        ))
+                        truncate_days(&every, t).map(Some)
                     },
                     _ => Ok(None),
                 }
@@ -12,8 +12,8 @@ use arrow::temporal_conversions::{
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use polars_core::datatypes::DataType;
 use polars_core::prelude::{
-    PolarsResult, datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us,
-    polars_bail,
+    PolarsResult, TimeUnit, datetime_to_timestamp_ms, datetime_to_timestamp_ns,
+    datetime_to_timestamp_us, polars_bail,
 };
 use polars_error::polars_ensure;
 #[cfg(feature = "serde")]
@@ -466,6 +466,18 @@ impl Duration {
         self.negative
     }
 
+    /// Splits the duration into its calendar part (months, weeks and days), whose length depends
+    /// on the time zone, and its fixed part in signed nanoseconds.
+    pub fn split_calendar(&self) -> (Self, i64) {
+        let calendar = Self { nsecs: 0, ..*self };
+        let nsecs = if self.negative {
+            -self.nsecs
+        } else {
+            self.nsecs
+        };
+        (calendar, nsecs)
+    }
+
     /// Estimated duration of the window duration. Not a very good one if not a constant duration.
     #[doc(hidden)]
     pub const fn duration_ns(&self) -> i64 {
@@ -641,6 +653,7 @@ impl Duration {
         _timestamp_to_datetime: G,
         _datetime_to_timestamp: J,
         daily_duration: i64,
+        week_start: u8,
     ) -> PolarsResult<i64>
     where
         G: Fn(i64) -> NaiveDateTime,
@@ -666,8 +679,10 @@ impl Duration {
         //   t - (t % (7 * self.weeks * daily_duration))
         // then the timestamp would get truncated to the previous Thursday,
         // because 1970-01-01 (timestamp 0) is a Thursday.
-        // So, we adjust by 4 days to get to Monday.
-        let mut remainder = (t - 4 * daily_duration) % (7 * self.weeks * daily_duration);
+        // So, we adjust by 4 days to get to Monday, and by `week_start` more days to get to
+        // the first day of the week.
+        let mut remainder =
+            (t - (4 + week_start as i64) * daily_duration) % (7 * self.weeks * daily_duration);
         if remainder < 0 {
             remainder += 7 * self.weeks * daily_duration
         }
@@ -780,6 +795,7 @@ impl Duration {
         nsecs_to_unit: F,
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
+        week_start: u8,
     ) -> PolarsResult<i64>
     where
         F: Fn(i64) -> i64,
@@ -819,6 +835,7 @@ impl Duration {
                     timestamp_to_datetime,
                     datetime_to_timestamp,
                     duration,
+                    week_start,
                 )
             },
             // truncate by months
@@ -847,6 +864,7 @@ impl Duration {
             |nsecs| nsecs,
             timestamp_ns_to_datetime,
             datetime_to_timestamp_ns,
+            0,
         )
     }
 
@@ -859,6 +877,7 @@ impl Duration {
             |nsecs| nsecs / 1000,
             timestamp_us_to_datetime,
             datetime_to_timestamp_us,
+            0,
         )
     }
 
@@ -871,9 +890,47 @@ impl Duration {
             |nsecs| nsecs / 1_000_000,
             timestamp_ms_to_datetime,
             datetime_to_timestamp_ms,
+            0,
         )
     }
 
+    /// Truncate the given timestamp by the window boundary, where weekly windows start
+    /// `week_start` days after Monday instead of on Monday.
+    pub fn truncate_with_week_start(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        tu: TimeUnit,
+        week_start: u8,
+    ) -> PolarsResult<i64> {
+        match tu {
+            TimeUnit::Nanoseconds => self.truncate_impl(
+                t,
+                tz,
+                |nsecs| nsecs,
+                timestamp_ns_to_datetime,
+                datetime_to_timestamp_ns,
+                week_start,
+            ),
+            TimeUnit::Microseconds => self.truncate_impl(
+                t,
+                tz,
+                |nsecs| nsecs / 1000,
+                timestamp_us_to_datetime,
+                datetime_to_timestamp_us,
+                week_start,
+            ),
+            TimeUnit::Milliseconds => self.truncate_impl(
+                t,
+                tz,
+                |nsecs| nsecs / 1_000_000,
+                timestamp_ms_to_datetime,
+                datetime_to_timestamp_ms,
+                week_start,
+            ),
+        }
+    }
+
     fn add_impl_month_week_or_day<F, G, J>(
         &self,
         mut t: i64,
@@ -1108,6 +1165,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_truncate_week_start() {
+        let day = NS_DAY / 1_000_000;
+        // 2024-01-06, a Saturday.
+        let t = 19728 * day;
+        let one_week = Duration::parse("1w");
+        let truncate = |week_start| {
+            one_week
+                .truncate_with_week_start(t, None, TimeUnit::Milliseconds, week_start)
+                .unwrap()
+                / day
+        };
+        // Monday 2024-01-01.
+        assert_eq!(truncate(0), 19723);
+        // Saturday 2024-01-06 itself.
+        assert_eq!(truncate(5), 19728);
+        // Sunday 2023-12-31.
+        assert_eq!(truncate(6), 19722);
+        assert_eq!(truncate(0) * day, one_week.truncate_ms(t, None).unwrap());
+    }
+
     #[test]
     fn test_display() {
         let duration = Duration::parse("1h");
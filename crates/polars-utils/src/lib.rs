@@ -26,6 +26,7 @@ pub mod idx_mapper;
 pub mod idx_vec;
 pub mod mem;
 pub mod min_max;
+pub mod numa;
 pub mod pl_str;
 pub mod priority;
 pub mod regex_cache;
@@ -113,11 +113,26 @@ mod private {
         }
 
         #[inline]
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
         pub fn from_file(file: &File) -> PolarsResult<Self> {
             let mmap = MMapSemaphore::new_from_file(file)?;
             Ok(Self::from_mmap(Arc::new(mmap)))
         }
 
+        /// There is no memory mapping on wasm32-unknown-unknown, so the file is read into memory
+        /// instead.
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        pub fn from_file(file: &File) -> PolarsResult<Self> {
+            use std::io::{Read, Seek, SeekFrom};
+
+            // like a memory map, this covers the whole file regardless of its position
+            let mut file = file;
+            file.seek(SeekFrom::Start(0))?;
+            let mut buf = vec![];
+            file.read_to_end(&mut buf)?;
+            Ok(Self::from_vec(buf))
+        }
+
         /// Construct a `MemSlice` that simply wraps around a `&[u8]`.
         #[inline]
         pub const fn from_static(slice: &'static [u8]) -> Self {
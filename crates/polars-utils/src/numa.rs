@@ -0,0 +1,172 @@
+//! Placement of the threads of the thread pools on the NUMA nodes of the machine.
+use std::sync::LazyLock;
+
+use crate::config::verbose;
+
+/// A NUMA node and the CPUs that belong to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumaNode {
+    pub id: usize,
+    pub cpus: Vec<usize>,
+}
+
+/// The NUMA nodes of the machine, ordered by id.
+///
+/// On platforms where the topology is unknown this is a single node with all CPUs.
+pub static NUMA_NODES: LazyLock<Vec<NumaNode>> = LazyLock::new(|| {
+    #[cfg(target_os = "linux")]
+    if let Some(nodes) = linux_nodes().filter(|nodes| !nodes.is_empty()) {
+        return nodes;
+    }
+    let n_cpus = std::thread::available_parallelism().map_or(1, |n| n.get());
+    vec![NumaNode {
+        id: 0,
+        cpus: (0..n_cpus).collect(),
+    }]
+});
+
+#[cfg(target_os = "linux")]
+fn linux_nodes() -> Option<Vec<NumaNode>> {
+    let mut nodes = vec![];
+    for entry in std::fs::read_dir("/sys/devices/system/node").ok()? {
+        let entry = entry.ok()?;
+        let Some(id) = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.strip_prefix("node"))
+            .and_then(|id| id.parse().ok())
+        else {
+            continue;
+        };
+        let cpus = std::fs::read_to_string(entry.path().join("cpulist")).ok()?;
+        let cpus = parse_cpu_list(cpus.trim())?;
+        // Nodes without CPUs only hold memory.
+        if !cpus.is_empty() {
+            nodes.push(NumaNode { id, cpus });
+        }
+    }
+    nodes.sort_by_key(|node| node.id);
+    Some(nodes)
+}
+
+/// Parse a list of CPUs in the format of Linux, e.g. `0-3,8,10-11`.
+pub fn parse_cpu_list(list: &str) -> Option<Vec<usize>> {
+    let mut cpus = vec![];
+    for range in list.split(',').filter(|range| !range.is_empty()) {
+        match range.split_once('-') {
+            Some((start, end)) => cpus.extend(start.parse().ok()?..=end.parse().ok()?),
+            None => cpus.push(range.parse().ok()?),
+        }
+    }
+    Some(cpus)
+}
+
+/// Where the threads of the thread pools run, set with `POLARS_THREAD_AFFINITY`.
+///
+/// The threads of a pool are spread over the NUMA nodes round-robin, and on machines with
+/// more than one node every placed thread prefers to allocate its memory on its own node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThreadAffinity {
+    /// Leave the placement of the threads to the operating system.
+    #[default]
+    None,
+    /// Bind every thread to the CPUs of a NUMA node.
+    Numa,
+    /// Pin every thread to a single CPU.
+    Cores,
+}
+
+impl ThreadAffinity {
+    /// Place the `idx`-th thread of a pool, to be called from that thread when it starts.
+    ///
+    /// Pools whose threads run at the same time should use disjoint ranges of `idx`, so that
+    /// with [`ThreadAffinity::Cores`] their threads are not pinned to the same CPUs.
+    ///
+    /// Placing is best-effort: on platforms that do not support it the thread is left as is.
+    pub fn place_current_thread(self, idx: usize) {
+        let nodes = &*NUMA_NODES;
+        let node = &nodes[idx % nodes.len()];
+        let cpus = match self {
+            ThreadAffinity::None => return,
+            ThreadAffinity::Numa => node.cpus.as_slice(),
+            ThreadAffinity::Cores => {
+                std::slice::from_ref(&node.cpus[(idx / nodes.len()) % node.cpus.len()])
+            },
+        };
+        let placed = pin_current_thread(cpus) && (nodes.len() == 1 || prefer_node_memory(node.id));
+        if !placed && verbose() {
+            eprintln!("could not place thread {idx} on NUMA node {}", node.id);
+        }
+    }
+}
+
+/// Restrict the current thread to run on `cpus`, returns whether it succeeded.
+///
+/// CPUs that do not fit in a `cpu_set_t` (`CPU_SETSIZE`, usually 1024) are skipped.
+pub fn pin_current_thread(cpus: &[usize]) -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        // SAFETY: a zeroed cpu_set_t is an empty set.
+        let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+        let mut any = false;
+        for &cpu in cpus.iter().filter(|&&cpu| cpu < libc::CPU_SETSIZE as usize) {
+            unsafe { libc::CPU_SET(cpu, &mut set) };
+            any = true;
+        }
+        any && unsafe {
+            libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) == 0
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = cpus;
+        false
+    }
+}
+
+/// Prefer allocating the memory of the current thread on `node`, falling back to the other
+/// nodes when it is full. Returns whether it succeeded.
+pub fn prefer_node_memory(node: usize) -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        const MPOL_PREFERRED: libc::c_int = 1;
+        const BITS: usize = libc::c_ulong::BITS as usize;
+        let mut mask: Vec<libc::c_ulong> = vec![0; node / BITS + 1];
+        mask[node / BITS] |= 1 << (node % BITS);
+        let max_node = mask.len() * BITS + 1;
+        unsafe {
+            libc::syscall(
+                libc::SYS_set_mempolicy,
+                MPOL_PREFERRED,
+                mask.as_ptr(),
+                max_node as libc::c_ulong,
+            ) == 0
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = node;
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(
+            parse_cpu_list("0-3,8,10-11"),
+            Some(vec![0, 1, 2, 3, 8, 10, 11])
+        );
+        assert_eq!(parse_cpu_list(""), Some(vec![]));
+        assert_eq!(parse_cpu_list("1-x"), None);
+    }
+
+    #[test]
+    fn test_numa_nodes() {
+        assert!(!NUMA_NODES.is_empty());
+        assert!(NUMA_NODES.iter().all(|node| !node.cpus.is_empty()));
+    }
+}
@@ -1,12 +1,31 @@
+/// Whether this target has no threads at all, in which case all work runs on the calling
+/// thread. Other wasm targets forward to rayon.
+const NO_THREADS: bool = cfg!(all(target_arch = "wasm32", target_os = "unknown"));
+
+/// A stand-in for the rayon thread pool on wasm targets other than emscripten.
+///
+/// On `wasm32-unknown-unknown` (e.g. in the browser) there are no threads, so all work runs
+/// on the calling thread: `join` runs its closures one after the other and `spawn` runs the
+/// task before returning. Parallel iterators run sequentially on rayon's fallback pool of the
+/// current thread.
 pub struct Pool;
 
 impl Pool {
     pub fn current_num_threads(&self) -> usize {
-        rayon::current_num_threads()
+        if NO_THREADS {
+            1
+        } else {
+            rayon::current_num_threads()
+        }
     }
 
+    /// Without threads the caller is never one of the workers.
     pub fn current_thread_index(&self) -> Option<usize> {
-        rayon::current_thread_index()
+        if NO_THREADS {
+            None
+        } else {
+            rayon::current_thread_index()
+        }
     }
 
     pub fn current_thread_has_pending_tasks(&self) -> Option<bool> {
@@ -28,14 +47,22 @@ impl Pool {
         RA: Send,
         RB: Send,
     {
-        rayon::join(oper_a, oper_b)
+        if NO_THREADS {
+            (oper_a(), oper_b())
+        } else {
+            rayon::join(oper_a, oper_b)
+        }
     }
 
     pub fn spawn<F>(&self, func: F)
     where
         F: 'static + FnOnce() + Send,
     {
-        rayon::spawn(func);
+        if NO_THREADS {
+            func()
+        } else {
+            rayon::spawn(func)
+        }
     }
 
     pub fn scope<'scope, OP, R>(&self, op: OP) -> R
@@ -46,3 +73,10 @@ impl Pool {
         rayon::scope(op)
     }
 }
+
+/// A random identifier of the process, as the system time is not available on
+/// `wasm32-unknown-unknown`.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+pub fn process_id() -> u128 {
+    rand::random()
+}
@@ -0,0 +1,106 @@
+/*
+ * The C API of Polars (see `crates/polars/src/capi.rs`), enabled by the `capi` feature.
+ *
+ * Data is exchanged through the Arrow C Data Interface and C Stream Interface:
+ * https://arrow.apache.org/docs/format/CDataInterface.html
+ * https://arrow.apache.org/docs/format/CStreamInterface.html
+ */
+#ifndef POLARS_H
+#define POLARS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define POLARS_CAPI_VERSION 1
+
+#ifndef ARROW_C_DATA_INTERFACE
+#define ARROW_C_DATA_INTERFACE
+
+#define ARROW_FLAG_DICTIONARY_ORDERED 1
+#define ARROW_FLAG_NULLABLE 2
+#define ARROW_FLAG_MAP_KEYS_SORTED 4
+
+struct ArrowSchema {
+  const char* format;
+  const char* name;
+  const char* metadata;
+  int64_t flags;
+  int64_t n_children;
+  struct ArrowSchema** children;
+  struct ArrowSchema* dictionary;
+  void (*release)(struct ArrowSchema*);
+  void* private_data;
+};
+
+struct ArrowArray {
+  int64_t length;
+  int64_t null_count;
+  int64_t offset;
+  int64_t n_buffers;
+  int64_t n_children;
+  const void** buffers;
+  struct ArrowArray** children;
+  struct ArrowArray* dictionary;
+  void (*release)(struct ArrowArray*);
+  void* private_data;
+};
+
+#endif  /* ARROW_C_DATA_INTERFACE */
+
+#ifndef ARROW_C_STREAM_INTERFACE
+#define ARROW_C_STREAM_INTERFACE
+
+struct ArrowArrayStream {
+  int (*get_schema)(struct ArrowArrayStream*, struct ArrowSchema* out);
+  int (*get_next)(struct ArrowArrayStream*, struct ArrowArray* out);
+  const char* (*get_last_error)(struct ArrowArrayStream*);
+  void (*release)(struct ArrowArrayStream*);
+  void* private_data;
+};
+
+#endif  /* ARROW_C_STREAM_INTERFACE */
+
+/* An owned DataFrame, released with `polars_dataframe_free`. */
+typedef struct PolarsDataFrame polars_dataframe_t;
+
+/* The version of the C ABI of the library, compare with `POLARS_CAPI_VERSION`. */
+uint32_t polars_capi_version(void);
+
+/* The message of the last error on the calling thread; valid until the next call. */
+const char* polars_last_error_message(void);
+
+/* Import a struct array whose fields are the columns; the array is moved, the schema
+ * borrowed. Returns NULL on error. */
+polars_dataframe_t* polars_dataframe_from_arrow(const struct ArrowSchema* schema,
+                                                struct ArrowArray* array);
+
+/* Import (and consume) a stream of struct arrays. Returns NULL on error. */
+polars_dataframe_t* polars_dataframe_from_arrow_stream(struct ArrowArrayStream* stream);
+
+/* Release a DataFrame; NULL is a no-op. */
+void polars_dataframe_free(polars_dataframe_t* df);
+
+/* The number of rows; 0 on error. */
+size_t polars_dataframe_height(const polars_dataframe_t* df);
+
+/* The number of columns; 0 on error. */
+size_t polars_dataframe_width(const polars_dataframe_t* df);
+
+/* Export a DataFrame as a stream of struct arrays. A DataFrame without columns is one
+ * batch of a struct array without fields, of its height. Returns 0 on success. */
+int32_t polars_dataframe_to_arrow_stream(const polars_dataframe_t* df,
+                                         struct ArrowArrayStream* out);
+
+/* Execute a plan serialized with `LazyFrame.serialize` (binary format). Returns NULL on
+ * error. */
+polars_dataframe_t* polars_plan_execute(const uint8_t* plan, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  /* POLARS_H */
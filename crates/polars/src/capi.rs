@@ -0,0 +1,249 @@
+//! A small, stable C ABI to embed Polars in non-Rust hosts (e.g. C++, or Java via JNI).
+//!
+//! Data crosses the boundary through the Arrow
+//! [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html) and
+//! [C Stream Interface](https://arrow.apache.org/docs/format/CStreamInterface.html), so any
+//! Arrow implementation can produce the inputs and consume the results. Queries are plans
+//! serialized with `LazyFrame.serialize` (the binary format), e.g. by the Python API.
+//!
+//! The functions are declared in `include/polars.h`. To call them, link a `cdylib` or
+//! `staticlib` crate that depends on `polars` with the `capi` feature.
+//!
+//! # Conventions
+//! * A `polars_dataframe_t` is owned by the caller and released with
+//!   [`polars_dataframe_free`].
+//! * Functions that can fail return a null pointer or a non-zero status. The message of the
+//!   last error on the calling thread is available from [`polars_last_error_message`].
+//! * Panics are caught and reported as errors; they never unwind into the host.
+#![allow(unsafe_op_in_unsafe_fn)]
+use std::cell::RefCell;
+use std::ffi::{CString, c_char};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use arrow::array::{Array, ArrayRef, StructArray};
+use arrow::datatypes::{ArrowDataType, Field as ArrowField};
+use arrow::ffi::{self, ArrowArray, ArrowArrayStream, ArrowArrayStreamReader, ArrowSchema};
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+/// The version of the C ABI, incremented on every change that is not backwards compatible.
+pub const POLARS_CAPI_VERSION: u32 = 1;
+
+/// An opaque, owned [`DataFrame`].
+pub struct PolarsDataFrame(DataFrame);
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+fn set_last_error(msg: &str) {
+    let msg = CString::new(msg.replace('\0', "")).unwrap();
+    LAST_ERROR.with(|last| *last.borrow_mut() = msg);
+}
+
+/// Run `f`, recording its error (or panic) as the last error of this thread.
+fn guard<T>(f: impl FnOnce() -> PolarsResult<T>) -> Option<T> {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => Some(value),
+        Ok(Err(err)) => {
+            set_last_error(&err.to_string());
+            None
+        },
+        Err(panic) => {
+            let msg = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            set_last_error(&format!("polars panicked: {msg}"));
+            None
+        },
+    }
+}
+
+fn into_raw(df: DataFrame) -> *mut PolarsDataFrame {
+    Box::into_raw(Box::new(PolarsDataFrame(df)))
+}
+
+unsafe fn frame_ref<'a>(df: *const PolarsDataFrame) -> PolarsResult<&'a DataFrame> {
+    match df.as_ref() {
+        Some(df) => Ok(&df.0),
+        None => polars_bail!(InvalidOperation: "received a null polars_dataframe_t"),
+    }
+}
+
+/// Convert a struct array (a record batch) to a [`DataFrame`]. A struct array without fields
+/// gives a DataFrame without columns of the same height.
+fn struct_to_frame(array: ArrayRef) -> PolarsResult<DataFrame> {
+    match array.as_any().downcast_ref::<StructArray>() {
+        Some(array) if array.fields().is_empty() => Ok(DataFrame::empty_with_height(array.len())),
+        Some(array) => DataFrame::try_from(array.clone()),
+        None => polars_bail!(
+            ComputeError: "expected a struct array of the columns, got {:?}", array.dtype()
+        ),
+    }
+}
+
+/// The version of the C ABI of this library ([`POLARS_CAPI_VERSION`]).
+#[unsafe(no_mangle)]
+pub extern "C" fn polars_capi_version() -> u32 {
+    POLARS_CAPI_VERSION
+}
+
+/// The message of the last error on the calling thread, valid until the next call into the
+/// library on this thread; an empty string if there was none.
+#[unsafe(no_mangle)]
+pub extern "C" fn polars_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ptr())
+}
+
+/// Import a DataFrame from a struct array (whose fields are the columns) and its schema.
+///
+/// # Safety
+/// `schema` and `array` must be valid pointers to C Data Interface structs. The array is
+/// moved into the library (and released by it); the schema is only borrowed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn polars_dataframe_from_arrow(
+    schema: *const ArrowSchema,
+    array: *mut ArrowArray,
+) -> *mut PolarsDataFrame {
+    guard(|| {
+        polars_ensure!(
+            !schema.is_null() && !array.is_null(),
+            InvalidOperation: "received a null ArrowSchema or ArrowArray"
+        );
+        let field = ffi::import_field_from_c(&*schema)?;
+        let array = std::ptr::replace(array, ArrowArray::empty());
+        let array = ffi::import_array_from_c(array, field.dtype().clone())?;
+        struct_to_frame(array)
+    })
+    .map_or(std::ptr::null_mut(), into_raw)
+}
+
+/// Import a DataFrame from a stream of struct arrays (record batches), consuming the stream.
+///
+/// # Safety
+/// `stream` must be a valid pointer to a C Stream Interface struct. The stream is moved into
+/// the library (and released by it).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn polars_dataframe_from_arrow_stream(
+    stream: *mut ArrowArrayStream,
+) -> *mut PolarsDataFrame {
+    guard(|| {
+        polars_ensure!(
+            !stream.is_null(),
+            InvalidOperation: "received a null ArrowArrayStream"
+        );
+        let stream = Box::new(std::ptr::replace(stream, ArrowArrayStream::empty()));
+        let mut reader = ArrowArrayStreamReader::try_new(stream)?;
+        let ArrowDataType::Struct(fields) = reader.field().dtype() else {
+            polars_bail!(ComputeError: "expected a stream of struct arrays");
+        };
+        let schema = fields.iter().map(|f| (f.name.clone(), f.clone())).collect();
+        let mut df = DataFrame::empty_with_arrow_schema(&schema);
+        while let Some(array) = reader.next() {
+            df.vstack_mut(&struct_to_frame(array?)?)?;
+        }
+        Ok(df)
+    })
+    .map_or(std::ptr::null_mut(), into_raw)
+}
+
+/// Release a DataFrame; passing null is a no-op.
+///
+/// # Safety
+/// `df` must be null or a pointer returned by this library that was not released before.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn polars_dataframe_free(df: *mut PolarsDataFrame) {
+    guard(|| {
+        if !df.is_null() {
+            drop(Box::from_raw(df));
+        }
+        Ok(())
+    });
+}
+
+/// The number of rows of a DataFrame; 0 on error (e.g. a null pointer).
+///
+/// # Safety
+/// `df` must be null or a valid pointer returned by this library.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn polars_dataframe_height(df: *const PolarsDataFrame) -> usize {
+    guard(|| frame_ref(df).map(|df| df.height())).unwrap_or(0)
+}
+
+/// The number of columns of a DataFrame; 0 on error (e.g. a null pointer).
+///
+/// # Safety
+/// `df` must be null or a valid pointer returned by this library.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn polars_dataframe_width(df: *const PolarsDataFrame) -> usize {
+    guard(|| frame_ref(df).map(|df| df.width())).unwrap_or(0)
+}
+
+/// Export a DataFrame as a stream of struct arrays (record batches) into `out`; returns 0 on
+/// success. The DataFrame can be released while the stream is still in use.
+///
+/// A DataFrame without columns is exported as a single batch of a struct array without
+/// fields whose length is the height, so the number of rows is kept.
+///
+/// # Safety
+/// `df` must be a valid pointer returned by this library, and `out` must point to writable
+/// memory for an `ArrowArrayStream` (which is overwritten without being released).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn polars_dataframe_to_arrow_stream(
+    df: *const PolarsDataFrame,
+    out: *mut ArrowArrayStream,
+) -> i32 {
+    let stream = guard(|| {
+        polars_ensure!(!out.is_null(), InvalidOperation: "received a null ArrowArrayStream");
+        let mut df = frame_ref(df)?.clone();
+        df.align_chunks_par();
+
+        let schema = df.schema().to_arrow(CompatLevel::newest());
+        let dtype = ArrowDataType::Struct(schema.into_iter_values().collect());
+        let columns = df
+            .take_columns()
+            .into_iter()
+            .map(|c| c.take_materialized_series())
+            .collect::<Vec<_>>();
+        let height = df.height();
+        let n_chunks = columns
+            .first()
+            .map_or((height > 0) as usize, |s| s.n_chunks());
+        let batch_dtype = dtype.clone();
+        let batches = (0..n_chunks).map(move |i| {
+            let arrays = columns
+                .iter()
+                .map(|s| s.to_arrow(i, CompatLevel::newest()))
+                .collect::<Vec<_>>();
+            let len = arrays.first().map_or(height, |arr| arr.len());
+            Ok(StructArray::new(batch_dtype.clone(), len, arrays, None).boxed())
+        });
+        let field = ArrowField::new(PlSmallStr::EMPTY, dtype, false);
+        Ok(ffi::export_iterator(Box::new(batches), field))
+    });
+    match stream {
+        Some(stream) => {
+            std::ptr::write(out, stream);
+            0
+        },
+        None => 1,
+    }
+}
+
+/// Execute a plan serialized with `LazyFrame.serialize` (in the binary format), and return
+/// the resulting DataFrame.
+///
+/// # Safety
+/// `plan` must point to `len` readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn polars_plan_execute(plan: *const u8, len: usize) -> *mut PolarsDataFrame {
+    guard(|| {
+        polars_ensure!(!plan.is_null(), InvalidOperation: "received a null plan");
+        let bytes = std::slice::from_raw_parts(plan, len);
+        let plan = DslPlan::deserialize_versioned(bytes)?;
+        LazyFrame::from(plan).collect()
+    })
+    .map_or(std::ptr::null_mut(), into_raw)
+}
@@ -179,8 +179,17 @@
 //!     - `dot_diagram` - Create dot diagrams from lazy logical plans.
 //! * `sql` - Pass SQL queries to Polars.
 //! * `streaming` - Process datasets larger than RAM.
+//! * `unbounded` - Experimental: run a query over every window of a source that never ends
+//!   (see `polars_lazy::unbounded`).
+//! * `materialized_view` - Experimental: keep a query result over append-only files up to date
+//!   by only processing the new rows (see `polars_lazy::materialized_view`).
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
+//! * `arrow_rs` - Convert between [`DataFrame`] and the `RecordBatch` of [arrow-rs](https://docs.rs/arrow/)
+//!   with `polars_arrow::interchange::convert`
+//! * `capi` - A stable C ABI to embed Polars in other languages (see `polars::capi`)
+//! * `tracing` - Emit [tracing](https://docs.rs/tracing/) spans for the optimization passes, the
+//!   executed plan nodes and the cloud requests, e.g. to export them with OpenTelemetry.
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for [`StringChunked`]
@@ -204,6 +213,8 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `kafka` - Scan micro-batches of Kafka topics with `LazyFrame::scan_kafka`
+//!     - `flight` - Stream query results to an Arrow Flight server with `LazyFrame::sink_flight`
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!       Supported compressions:
 //!          - gzip
@@ -224,6 +235,9 @@
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
+//!     - `fuzzy_join` - Join on similar instead of equal string keys.
+//!     - `knn_join` - Join on the nearest neighbors of the vectors in `Array` columns.
+//!     - `frame_compare` - Report the differences between two [`DataFrame`]s with the same keys.
 //!     - `row_hash` - Utility to hash [`DataFrame`] rows to [`UInt64Chunked`]
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
 //!     - `dataframe_arithmetic` - Arithmetic on ([`Dataframe`] and [`DataFrame`]s) and ([`DataFrame`] on [`Series`])
@@ -271,7 +285,11 @@
 //!     - `propagate_nans` - NaN propagating min/max aggregations.
 //!     - `extract_groups` - Extract multiple regex groups from strings.
 //!     - `cov` - Covariance and correlation functions.
+//!     - `ols` - Least-squares and rolling least-squares regressions.
+//!     - `anonymize` - Keyed tokenization, format-preserving hashing and generalization of values.
 //!     - `find_many` - Find/replace multiple string patterns at once.
+//!     - `string_similarity` - Edit distances and similarity scores between strings.
+//!     - `string_url` - Extract the parts of URLs and decode percent-encoded strings.
 //! * [`DataFrame`] pretty printing
 //!     - `fmt` - Activate [`DataFrame`] formatting
 //!
@@ -395,6 +413,11 @@
 //! * `POLARS_FMT_STR_LEN` -> maximum number of characters printed per string value.
 //! * `POLARS_TABLE_WIDTH` -> width of the tables used during DataFrame formatting.
 //! * `POLARS_MAX_THREADS` -> maximum number of threads used to initialize thread pool (on startup).
+//! * `POLARS_THREAD_AFFINITY` -> place the threads of the thread pools on large multi-socket machines
+//!   (on startup), spreading them over the NUMA nodes with their memory allocated on their own node:
+//!   * `numa` -> bind every thread to the CPUs of a node.
+//!   * `cores` -> pin every thread to a single CPU, the threads of the streaming engine's executor
+//!     to other CPUs than those of the thread pool as long as there are enough.
 //! * `POLARS_VERBOSE` -> print logging info to stderr.
 //! * `POLARS_NO_PARTITION` -> polars may choose to partition the group_by operation, based on data
 //!   cardinality. Setting this env var will turn partitioned group_by's off.
@@ -413,14 +436,16 @@
 //! If you want to read more, check the [user guide](https://docs.pola.rs/).
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![allow(ambiguous_glob_reexports)]
+#[cfg(feature = "capi")]
+pub mod capi;
 pub mod docs;
 pub mod prelude;
 #[cfg(feature = "sql")]
 pub mod sql;
 
 pub use polars_core::{
-    apply_method_all_arrow_series, chunked_array, datatypes, df, error, frame, functions, series,
-    testing,
+    apply_method_all_arrow_series, chunked_array, datatypes, df, error, extension, frame,
+    functions, series, testing,
 };
 #[cfg(feature = "dtype-categorical")]
 pub use polars_core::{enable_string_cache, using_string_cache};
@@ -1,5 +1,5 @@
 use arrow::array::*;
-use arrow::compute::aggregate::estimated_bytes_size;
+use arrow::compute::aggregate::{MemoryProfile, MemoryProfiler, estimated_bytes_size};
 use arrow::datatypes::{ArrowDataType, Field};
 
 #[test]
@@ -30,3 +30,40 @@ fn fixed_size_list() {
     let a = FixedSizeListArray::new(dtype, 2, values, None);
     assert_eq!(6 * size_of::<f32>(), estimated_bytes_size(&a));
 }
+
+#[test]
+fn memory_profile() {
+    let a = Utf8Array::<i32>::from([Some("aaa"), None]);
+    let sliced = a.clone().sliced(1, 1);
+
+    let mut profiler = MemoryProfiler::new();
+    let mut profile = MemoryProfile::default();
+    profiler.add_array(&a, &mut profile);
+    assert_eq!(
+        profile,
+        MemoryProfile {
+            values: 3,
+            validity: 1,
+            offsets: 3 * size_of::<i32>(),
+            dictionary: 0,
+            shared: 0,
+        }
+    );
+
+    let mut profile = MemoryProfile::default();
+    profiler.add_array(&sliced, &mut profile);
+    assert_eq!(profile.total(), 0);
+    assert_eq!(profile.shared, 3 + 1 + 3 * size_of::<i32>());
+}
+
+#[test]
+fn memory_profile_dictionary() {
+    let values = Utf8Array::<i32>::from_slice(["a", "bb"]);
+    let keys = UInt32Array::from_slice([0, 1, 1]);
+    let a = DictionaryArray::try_from_keys(keys, values.boxed()).unwrap();
+
+    let mut profile = MemoryProfile::default();
+    MemoryProfiler::new().add_array(&a, &mut profile);
+    assert_eq!(profile.values, 3 * size_of::<u32>());
+    assert_eq!(profile.dictionary, 3 + 3 * size_of::<i32>());
+}
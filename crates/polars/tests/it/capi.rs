@@ -0,0 +1,92 @@
+use std::ffi::CStr;
+
+use arrow::array::StructArray;
+use arrow::datatypes::{ArrowDataType, Field as ArrowField};
+use arrow::ffi::{self, ArrowArrayStream, ArrowArrayStreamReader};
+use polars::capi::*;
+use polars::prelude::*;
+
+unsafe fn to_frame(df: *mut PolarsDataFrame) -> DataFrame {
+    let mut stream = ArrowArrayStream::empty();
+    assert_eq!(
+        unsafe { polars_dataframe_to_arrow_stream(df, &mut stream) },
+        0
+    );
+    unsafe { polars_dataframe_free(df) };
+
+    let df = unsafe { polars_dataframe_from_arrow_stream(&mut stream) };
+    assert!(!df.is_null());
+    let mut out = ArrowArrayStream::empty();
+    assert_eq!(unsafe { polars_dataframe_to_arrow_stream(df, &mut out) }, 0);
+    unsafe { polars_dataframe_free(df) };
+
+    let mut reader = ArrowArrayStreamReader::try_new(Box::new(out)).unwrap();
+    let mut frames = vec![];
+    while let Some(array) = unsafe { reader.next() } {
+        let array = array.unwrap();
+        let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        frames.push(DataFrame::try_from(array.clone()).unwrap());
+    }
+    let mut df = DataFrame::empty();
+    for other in frames {
+        df.vstack_mut(&other).unwrap();
+    }
+    df
+}
+
+#[test]
+fn test_capi_plan_roundtrip() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+    let lf = df.clone().lazy().filter(col("a").gt(lit(1)));
+    let mut plan = vec![];
+    lf.logical_plan.serialize_versioned(&mut plan)?;
+
+    let out = unsafe { polars_plan_execute(plan.as_ptr(), plan.len()) };
+    assert!(!out.is_null());
+    assert_eq!(unsafe { polars_dataframe_height(out) }, 2);
+    assert_eq!(unsafe { polars_dataframe_width(out) }, 2);
+
+    let out = unsafe { to_frame(out) };
+    let expected = df.slice(1, 2);
+    assert!(out.equals(&expected), "{out}");
+    Ok(())
+}
+
+#[test]
+fn test_capi_zero_width() {
+    let array = StructArray::new(ArrowDataType::Struct(vec![]), 3, vec![], None).boxed();
+    let field = ArrowField::new(PlSmallStr::EMPTY, array.dtype().clone(), false);
+    let schema = ffi::export_field_to_c(&field);
+    let mut array = ffi::export_array_to_c(array);
+
+    let df = unsafe { polars_dataframe_from_arrow(&schema, &mut array) };
+    assert!(!df.is_null());
+    assert_eq!(unsafe { polars_dataframe_height(df) }, 3);
+    assert_eq!(unsafe { polars_dataframe_width(df) }, 0);
+    let out = unsafe { to_frame(df) };
+    assert_eq!(out.shape(), (3, 0));
+}
+
+#[test]
+fn test_capi_errors() {
+    assert_eq!(polars_capi_version(), POLARS_CAPI_VERSION);
+
+    let garbage = [1u8, 2, 3];
+    let out = unsafe { polars_plan_execute(garbage.as_ptr(), garbage.len()) };
+    assert!(out.is_null());
+    let msg = unsafe { CStr::from_ptr(polars_last_error_message()) };
+    assert!(!msg.to_bytes().is_empty());
+
+    let out = unsafe { polars_dataframe_from_arrow_stream(std::ptr::null_mut()) };
+    assert!(out.is_null());
+    let msg = unsafe { CStr::from_ptr(polars_last_error_message()) };
+    assert!(msg.to_str().unwrap().contains("null ArrowArrayStream"));
+
+    assert_eq!(unsafe { polars_dataframe_height(std::ptr::null()) }, 0);
+    let msg = unsafe { CStr::from_ptr(polars_last_error_message()) };
+    assert!(msg.to_str().unwrap().contains("null polars_dataframe_t"));
+    unsafe { polars_dataframe_free(std::ptr::null_mut()) };
+}
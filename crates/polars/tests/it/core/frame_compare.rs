@@ -0,0 +1,81 @@
+use super::*;
+
+fn strings(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<String>>> {
+    Ok(df
+        .column(name)?
+        .str()?
+        .into_iter()
+        .map(|s| s.map(str::to_string))
+        .collect())
+}
+
+fn some(values: &[&str]) -> Vec<Option<String>> {
+    values.iter().map(|s| Some(s.to_string())).collect()
+}
+
+fn frames() -> PolarsResult<(DataFrame, DataFrame)> {
+    let left = df![
+        "id" => [1, 2, 3, 4],
+        "price" => [1.0, 2.0, 3.0, f64::NAN],
+        "name" => [Some("a"), Some("b"), None, Some("d")],
+        "flag" => [true, true, false, false],
+    ]?;
+    let right = df![
+        "id" => [5, 4, 2, 1],
+        "price" => [5.0, f64::NAN, 2.001, 1.0],
+        "name" => [Some("e"), Some("d"), Some("b"), None],
+        "flag" => ["false", "false", "true", "true"],
+        "extra" => [0i32, 0, 0, 0],
+    ]?;
+    Ok((left, right))
+}
+
+#[test]
+fn test_compare() -> PolarsResult<()> {
+    let (left, right) = frames()?;
+    let diff = left.compare(&right, &["id".into()], None)?;
+    assert!(!diff.is_empty());
+    assert!(diff.left_only.equals_missing(&left.slice(2, 1)));
+    assert!(diff.right_only.equals_missing(&right.slice(0, 1)));
+
+    let changed = &diff.changed;
+    assert_eq!(
+        changed.get_column_names_str(),
+        &["id", "column", "left", "right"]
+    );
+    let ids: Vec<_> = changed.column("id")?.i32()?.into_no_null_iter().collect();
+    assert_eq!(ids, &[2, 1]);
+    assert_eq!(strings(changed, "column")?, some(&["price", "name"]));
+    assert_eq!(strings(changed, "left")?, some(&["2.0", "a"]));
+    assert_eq!(
+        strings(changed, "right")?,
+        [Some("2.001".to_string()), None]
+    );
+
+    let mismatches = &diff.dtype_mismatches;
+    assert_eq!(strings(mismatches, "column")?, some(&["flag", "extra"]));
+    assert_eq!(
+        strings(mismatches, "left")?,
+        [Some("bool".to_string()), None]
+    );
+    assert_eq!(strings(mismatches, "right")?, some(&["str", "i32"]));
+    Ok(())
+}
+
+#[test]
+fn test_compare_tolerance() -> PolarsResult<()> {
+    let (left, right) = frames()?;
+    let left = left.select(["id", "price"])?;
+    let right = right.select(["id", "price"])?;
+    let diff = left.compare(&right, &["id".into()], Some(0.01))?;
+    assert_eq!(diff.changed.height(), 0);
+    assert_eq!(diff.dtype_mismatches.height(), 0);
+
+    let diff = left.compare(&left, &["id".into()], None)?;
+    assert!(diff.is_empty());
+
+    let duplicated = df!["id" => [1, 1], "price" => [1.0, 2.0]]?;
+    assert!(left.compare(&duplicated, &["id".into()], None).is_err());
+    assert!(left.compare(&right, &["id".into()], Some(-1.0)).is_err());
+    Ok(())
+}
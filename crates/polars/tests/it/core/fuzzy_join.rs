@@ -0,0 +1,73 @@
+use super::*;
+
+fn frames() -> PolarsResult<(DataFrame, DataFrame)> {
+    let left = df![
+        "name" => [Some("apple inc"), Some("microsoft corp"), Some("alphabet"), None],
+        "id" => [1, 2, 3, 4],
+    ]?;
+    let right = df![
+        "company" => ["apple inc.", "microsoft corporation", "amazon", "aple inc"],
+        "id" => [10, 20, 30, 40],
+    ]?;
+    Ok((left, right))
+}
+
+#[test]
+fn test_fuzzy_join_ngram() -> PolarsResult<()> {
+    let (left, right) = frames()?;
+    let options = FuzzyJoinOptions {
+        threshold: 0.9,
+        ..Default::default()
+    };
+    let out = left.join_fuzzy(&right, "name", "company", &options)?;
+    assert_eq!(
+        out.get_column_names(),
+        &["name", "id", "company", "id_right", "score"]
+    );
+    let ids = out
+        .column("id")?
+        .i32()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    let right_ids = out.column("id_right")?.i32()?;
+    assert_eq!(ids, &[1, 1, 2]);
+    assert_eq!(Vec::from(right_ids), &[Some(10), Some(40), Some(20)]);
+    let score = out.column("score")?.f64()?;
+    assert!(score.into_no_null_iter().all(|s| s >= 0.9));
+    Ok(())
+}
+
+#[test]
+fn test_fuzzy_join_sorted_neighborhood() -> PolarsResult<()> {
+    let (left, right) = frames()?;
+    let options = FuzzyJoinOptions {
+        method: FuzzyMethod::Levenshtein,
+        threshold: 0.85,
+        blocking: FuzzyBlocking::SortedNeighborhood { window: 1 },
+        ..Default::default()
+    };
+    let out = left.join_fuzzy(&right, "name", "company", &options)?;
+    let right_ids = out.column("id_right")?.i32()?;
+    assert_eq!(Vec::from(right_ids), &[Some(10), Some(40)]);
+    let score = out.column("score")?.f64()?;
+    assert!((score.get(0).unwrap() - 0.9).abs() < 1e-9);
+    assert!((score.get(1).unwrap() - 8.0 / 9.0).abs() < 1e-9);
+
+    let options = FuzzyJoinOptions {
+        threshold: 1.5,
+        ..Default::default()
+    };
+    assert!(
+        left.join_fuzzy(&right, "name", "company", &options)
+            .is_err()
+    );
+    let options = FuzzyJoinOptions {
+        score_name: "id".into(),
+        ..Default::default()
+    };
+    assert!(
+        left.join_fuzzy(&right, "name", "company", &options)
+            .is_err()
+    );
+    Ok(())
+}
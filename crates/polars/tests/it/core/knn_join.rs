@@ -0,0 +1,99 @@
+use super::*;
+
+fn vectors(name: &str, rows: &[Option<[f64; 2]>]) -> PolarsResult<Series> {
+    let list: ListChunked = rows
+        .iter()
+        .map(|v| v.map(|v| Series::new(PlSmallStr::EMPTY, v)))
+        .collect();
+    list.into_series()
+        .with_name(name.into())
+        .cast(&DataType::Array(Box::new(DataType::Float64), 2))
+}
+
+fn frames() -> PolarsResult<(DataFrame, DataFrame)> {
+    let left = DataFrame::new(vec![
+        vectors("query", &[Some([0.9, 0.1]), None, Some([0.0, 0.0])])?.into(),
+    ])?;
+    let right = DataFrame::new(vec![
+        vectors(
+            "embedding",
+            &[
+                Some([0.0, 0.0]),
+                Some([1.0, 0.0]),
+                Some([0.0, 2.0]),
+                Some([5.0, 5.0]),
+            ],
+        )?
+        .into(),
+    ])?;
+    Ok((left, right))
+}
+
+fn lists<T: PolarsNumericType>(
+    out: &DataFrame,
+    name: &str,
+) -> PolarsResult<Vec<Option<Vec<T::Native>>>> {
+    Ok(out
+        .column(name)?
+        .list()?
+        .into_iter()
+        .map(|s| s.map(|s| s.unpack::<T>().unwrap().into_no_null_iter().collect()))
+        .collect())
+}
+
+#[test]
+fn test_knn_join_l2() -> PolarsResult<()> {
+    let (left, right) = frames()?;
+    let options = KnnJoinOptions {
+        k: 2,
+        ..Default::default()
+    };
+    let out = left.join_knn(&right, "query", "embedding", &options)?;
+    assert_eq!(
+        out.get_column_names(),
+        &["query", "knn_index", "knn_distance"]
+    );
+    assert_eq!(
+        lists::<IdxType>(&out, "knn_index")?,
+        &[Some(vec![1, 0]), None, Some(vec![0, 1])]
+    );
+    let distances = lists::<Float64Type>(&out, "knn_distance")?;
+    let first = distances[0].as_ref().unwrap();
+    assert!((first[0] - 0.02f64.sqrt()).abs() < 1e-12);
+    assert!((first[1] - 0.82f64.sqrt()).abs() < 1e-12);
+    assert_eq!(distances[2], Some(vec![0.0, 1.0]));
+    Ok(())
+}
+
+#[test]
+fn test_knn_join_cosine() -> PolarsResult<()> {
+    let (left, right) = frames()?;
+    let options = KnnJoinOptions {
+        metric: KnnMetric::Cosine,
+        ..Default::default()
+    };
+    let out = left.join_knn(&right, "query", "embedding", &options)?;
+    // Zero vectors have no neighbors.
+    assert_eq!(
+        lists::<IdxType>(&out, "knn_index")?,
+        &[Some(vec![1]), None, Some(vec![])]
+    );
+
+    let options = KnnJoinOptions {
+        k: 0,
+        ..Default::default()
+    };
+    assert!(
+        left.join_knn(&right, "query", "embedding", &options)
+            .is_err()
+    );
+    let options = KnnJoinOptions {
+        index_name: "query".into(),
+        ..Default::default()
+    };
+    assert!(
+        left.join_knn(&right, "query", "embedding", &options)
+            .is_err()
+    );
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+fn frames() -> PolarsResult<(DataFrame, DataFrame)> {
+    let target = df![
+        "id" => [1, 2, 3],
+        "name" => ["a", "b", "c"],
+        "score" => [10, 20, 30],
+    ]?;
+    let source = df![
+        "id" => [2, 4],
+        "score" => [21, 40],
+    ]?;
+    Ok((target, source))
+}
+
+#[test]
+fn test_merge_into_upsert() -> PolarsResult<()> {
+    let (target, source) = frames()?;
+    let out = target.merge_into(&source, ["id"], MergeIntoOptions::default())?;
+    let expected = df![
+        "id" => [1, 2, 3, 4],
+        "name" => [Some("a"), Some("b"), Some("c"), None],
+        "score" => [10, 21, 30, 40],
+    ]?;
+    assert!(out.equals_missing(&expected), "{out}");
+
+    #[cfg(feature = "lazy")]
+    {
+        let out = target
+            .lazy()
+            .merge_into(source.lazy(), ["id"], MergeIntoOptions::default())?
+            .collect()?;
+        assert!(out.equals_missing(&expected), "{out}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merge_into_delete() -> PolarsResult<()> {
+    let (target, source) = frames()?;
+    let options = MergeIntoOptions {
+        when_matched: WhenMatched::Delete,
+        when_not_matched: WhenNotMatched::Ignore,
+    };
+    let out = target.merge_into(&source, ["id"], options)?;
+    let expected = df![
+        "id" => [1, 3],
+        "name" => ["a", "c"],
+        "score" => [10, 30],
+    ]?;
+    assert!(out.equals(&expected), "{out}");
+
+    let duplicated = df!["id" => [2, 2], "score" => [0, 1]]?;
+    assert!(target.merge_into(&duplicated, ["id"], options).is_err());
+    Ok(())
+}
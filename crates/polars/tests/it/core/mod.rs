@@ -1,13 +1,23 @@
 mod date_like;
+#[cfg(feature = "frame_compare")]
+mod frame_compare;
+#[cfg(feature = "fuzzy_join")]
+mod fuzzy_join;
 mod group_by;
 mod joins;
+#[cfg(feature = "knn_join")]
+mod knn_join;
 mod list;
+#[cfg(feature = "merge_into")]
+mod merge_into;
 mod ops;
 #[cfg(feature = "pivot")]
 mod pivot;
 #[cfg(feature = "rolling_window")]
 mod rolling_window;
 mod series;
+#[cfg(feature = "split")]
+mod split;
 mod utils;
 
 use polars::prelude::*;
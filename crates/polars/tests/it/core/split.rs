@@ -0,0 +1,115 @@
+use super::*;
+
+fn to_vec(idx: &IdxCa) -> Vec<IdxSize> {
+    idx.into_no_null_iter().collect()
+}
+
+#[test]
+fn test_split_fractions() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..10).collect::<Vec<i32>>(),
+    ]?;
+
+    let parts = df.split(&[0.6, 0.2, 0.2], false, None, None)?;
+    assert_eq!(to_vec(&parts[0]), &[0, 1, 2, 3, 4, 5]);
+    assert_eq!(to_vec(&parts[1]), &[6, 7]);
+    assert_eq!(to_vec(&parts[2]), &[8, 9]);
+
+    let parts = df.split(&[0.7, 0.3], true, None, Some(0))?;
+    let mut all = to_vec(&parts[0]);
+    all.extend(to_vec(&parts[1]));
+    all.sort();
+    assert_eq!(parts[0].len(), 7);
+    assert_eq!(all, (0..10).collect::<Vec<IdxSize>>());
+
+    // Seeded splits are reproducible.
+    let again = df.split(&[0.7, 0.3], true, None, Some(0))?;
+    assert_eq!(to_vec(&parts[1]), to_vec(&again[1]));
+
+    assert!(df.split(&[0.7, 0.2], false, None, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_split_stratified() -> PolarsResult<()> {
+    let df = df![
+        "label" => ["a", "a", "a", "a", "b", "b", "b", "b", "b", "b", "b", "b"],
+    ]?;
+
+    let parts = df.split(&[0.5, 0.5], true, Some("label"), Some(1))?;
+    for part in &parts {
+        let labels = df.take(part)?;
+        let n_a = labels.column("label")?.str()?.equal("a").sum().unwrap();
+        assert_eq!(n_a, 2);
+        assert_eq!(part.len(), 6);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_split_small_strata() -> PolarsResult<()> {
+    let df = df![
+        "label" => ["a", "b", "c", "c", "d", "e", "e", "f"],
+    ]?;
+
+    // The singleton strata alternate between the parts instead of all going to the first one.
+    let parts = df.split(&[0.5, 0.5], false, Some("label"), None)?;
+    assert_eq!(to_vec(&parts[0]), &[0, 2, 4, 5]);
+    assert_eq!(to_vec(&parts[1]), &[1, 3, 6, 7]);
+
+    let mut sizes = df
+        .k_fold(3, false, Some("label"), None)?
+        .map(|(_, test)| test.len())
+        .collect::<Vec<_>>();
+    sizes.sort();
+    assert_eq!(sizes, &[2, 3, 3]);
+    Ok(())
+}
+
+#[test]
+fn test_split_ordered() -> PolarsResult<()> {
+    let df = df![
+        "t" => [5, 1, 4, 2, 3],
+    ]?;
+
+    let parts = df.split_ordered(&[0.6, 0.4], "t")?;
+    assert_eq!(to_vec(&parts[0]), &[1, 3, 4]);
+    assert_eq!(to_vec(&parts[1]), &[0, 2]);
+    Ok(())
+}
+
+#[test]
+fn test_k_fold() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..6).collect::<Vec<i32>>(),
+    ]?;
+
+    let folds = df.k_fold(3, false, None, None)?;
+    assert_eq!(folds.len(), 3);
+    let folds = folds
+        .map(|(train, test)| (to_vec(&train), to_vec(&test)))
+        .collect::<Vec<_>>();
+    assert_eq!(folds[0], (vec![2, 3, 4, 5], vec![0, 1]));
+    assert_eq!(folds[1], (vec![0, 1, 4, 5], vec![2, 3]));
+    assert_eq!(folds[2], (vec![0, 1, 2, 3], vec![4, 5]));
+
+    assert!(df.k_fold(1, false, None, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_k_fold_ordered() -> PolarsResult<()> {
+    let df = df![
+        "t" => [3, 0, 5, 1, 4, 2],
+    ]?;
+
+    let folds = df
+        .k_fold_ordered(3, "t")?
+        .map(|(train, test)| (to_vec(&train), to_vec(&test)))
+        .collect::<Vec<_>>();
+    assert_eq!(folds.len(), 2);
+    // Rows ordered by `t`: [1, 3, 5, 0, 4, 2].
+    assert_eq!(folds[0], (vec![1, 3], vec![0, 5]));
+    assert_eq!(folds[1], (vec![0, 1, 3, 5], vec![2, 4]));
+    Ok(())
+}
@@ -151,3 +151,109 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+fn test_read_ipc_without_utf8_validation() -> PolarsResult<()> {
+    let list = [
+        Series::new("".into(), ["x", "a string longer than twelve bytes"]),
+        Series::new("".into(), ["y"]),
+    ];
+    let mut df = df![
+        "a" => ["x", "z"],
+        "b" => list,
+    ]?;
+
+    let mut buf = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+
+    // SAFETY: the strings were written by us, so they are valid UTF-8.
+    let out = unsafe { IpcReader::new(buf).validate_utf8(false) }.finish()?;
+    assert!(out.equals(&df));
+    Ok(())
+}
+
+#[test]
+#[cfg(unix)]
+fn test_ipc_shared_memory_roundtrip() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(1i64), None, Some(3)],
+        "b" => ["x", "y", "a string longer than twelve bytes"],
+    ]?;
+
+    let name = format!("/polars-test-{}", std::process::id());
+    let mut shm = SharedMemory::create(&name)?;
+    shm.write(&mut df)?;
+    // SAFETY: no other process has access to the object.
+    let out = unsafe { SharedMemory::open(&name)?.read()? };
+    SharedMemory::unlink(&name)?;
+    assert!(out.equals_missing(&df));
+
+    // The frame maps the memory, so it cannot be overwritten while the frame is alive.
+    assert!(shm.write(&mut df).is_err());
+    drop(out);
+    shm.write(&mut df.head(Some(1)))?;
+    // SAFETY: the object is only written through `shm`.
+    assert!(unsafe { shm.read()? }.equals_missing(&df.head(Some(1))));
+    Ok(())
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_ipc_shared_memory_memfd() -> PolarsResult<()> {
+    use std::os::fd::{AsFd, OwnedFd};
+
+    let mut df = df!["a" => [1u32, 2, 3]]?;
+    let mut shm = SharedMemory::memfd("polars-test")?;
+    shm.write(&mut df)?;
+
+    let fd: OwnedFd = shm.as_fd().try_clone_to_owned()?;
+    // SAFETY: the object is not written to while `out` is alive.
+    let out = unsafe { SharedMemory::from(fd).read()? };
+    assert!(out.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_ipc_extension_type() -> PolarsResult<()> {
+    use polars::extension::{ExtensionChunked, ExtensionTypeImpl, register_extension_type};
+
+    struct Celsius;
+
+    impl ExtensionTypeImpl for Celsius {
+        fn name(&self) -> &str {
+            "test.ipc.celsius"
+        }
+
+        fn validate(&self, storage: &ArrowDataType, metadata: Option<&str>) -> PolarsResult<()> {
+            polars_ensure!(
+                storage == &ArrowDataType::Float64 && metadata == Some("scale=1"),
+                ComputeError: "invalid celsius storage: {:?}", storage
+            );
+            Ok(())
+        }
+    }
+
+    register_extension_type(Arc::new(Celsius));
+    let temp = Series::new("temp".into(), [22.1, 19.9, 7.]);
+    let temp = ExtensionChunked::try_new("test.ipc.celsius".into(), Some("scale=1".into()), temp)?;
+    let mut df = DataFrame::new(vec![
+        temp.into_series().into(),
+        Column::new("days".into(), [0, 1, 2]),
+    ])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+    buf.seek(SeekFrom::Start(0))?;
+    let out = IpcReader::new(buf).finish()?;
+    assert_eq!(out.schema(), df.schema());
+
+    let expected = df.column("temp")?.as_materialized_series().extension()?;
+    let temp = out.column("temp")?.as_materialized_series().extension()?;
+    assert_eq!(
+        temp.extension_metadata().map(|md| md.as_str()),
+        Some("scale=1")
+    );
+    assert!(temp.storage().equals(expected.storage()));
+    Ok(())
+}
@@ -672,6 +672,7 @@ fn integration_write(
         compression: CompressionOptions::Uncompressed,
         version: Version::V1,
         data_page_size: None,
+        zstd_dictionary_size: None,
     };
 
     let encodings = schema
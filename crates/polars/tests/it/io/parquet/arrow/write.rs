@@ -36,6 +36,7 @@ fn round_trip_opt_stats(
         compression,
         version,
         data_page_size: None,
+        zstd_dictionary_size: None,
     };
 
     let iter = vec![RecordBatchT::try_new(
@@ -206,3 +206,65 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_parquet_extension_type() -> PolarsResult<()> {
+    use polars::extension::{ExtensionChunked, ExtensionTypeImpl, register_extension_type};
+
+    struct Label;
+
+    impl ExtensionTypeImpl for Label {
+        fn name(&self) -> &str {
+            "test.parquet.label"
+        }
+
+        fn validate(&self, storage: &ArrowDataType, _metadata: Option<&str>) -> PolarsResult<()> {
+            polars_ensure!(
+                matches!(storage, ArrowDataType::Utf8View | ArrowDataType::LargeUtf8),
+                ComputeError: "invalid label storage: {:?}", storage
+            );
+            Ok(())
+        }
+    }
+
+    register_extension_type(Arc::new(Label));
+    let label = Series::new("label".into(), ["a", "b", "c"]);
+    let label = ExtensionChunked::try_new("test.parquet.label".into(), None, label)?;
+    let df = DataFrame::new(vec![label.into_series().into()])?;
+    let mut stacked = df.clone();
+    stacked.vstack_mut(&df)?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut stacked)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.schema(), stacked.schema());
+
+    let expected = stacked
+        .column("label")?
+        .as_materialized_series()
+        .extension()?;
+    let label = read_df
+        .column("label")?
+        .as_materialized_series()
+        .extension()?;
+    assert!(label.storage().equals(expected.storage()));
+    Ok(())
+}
+
+#[test]
+fn test_parquet_uuid() -> PolarsResult<()> {
+    let (a, b) = ([1u8; 16], [2u8; 16]);
+    let id = Series::new("id".into(), [Some(a.as_slice()), None, Some(b.as_slice())])
+        .cast(&DataType::Uuid)?;
+    let mut df = DataFrame::new(vec![id.into()])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.schema(), df.schema());
+
+    let expected = df.column("id")?.cast(&DataType::Binary)?;
+    let id = read_df.column("id")?.cast(&DataType::Binary)?;
+    assert!(id.equals_missing(&expected));
+    Ok(())
+}
@@ -27,6 +27,7 @@ fn round_trip(
         compression,
         version,
         data_page_size: None,
+        zstd_dictionary_size: None,
     };
 
     let iter = vec![RecordBatchT::try_new(
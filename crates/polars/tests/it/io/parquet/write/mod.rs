@@ -297,3 +297,34 @@ fn test_read_parquet_with_columns() {
     assert_eq!(df_read.shape(), (3, 2));
     df_read.equals(&expected);
 }
+
+#[test]
+fn test_parquet_zstd_dictionary_round_trip() -> PolarsResult<()> {
+    let emails = (0..20_000)
+        .map(|i| format!("user-{i}@example.org"))
+        .collect::<Vec<_>>();
+    let mut df = df!("email" => emails)?;
+
+    let mut write = |zstd_dictionary_size| -> PolarsResult<Vec<u8>> {
+        let mut buf = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf)
+            .with_data_page_size(Some(1024))
+            .with_zstd_dictionary_size(zstd_dictionary_size)
+            .finish(&mut df)?;
+        Ok(buf.into_inner())
+    };
+    let plain = write(None)?;
+    let trained = write(Some(4 * 1024))?;
+    assert!(trained.len() < plain.len());
+
+    let metadata = read_metadata(&mut Cursor::new(&trained))?;
+    assert!(
+        metadata.row_groups[0].parquet_columns()[0]
+            .zstd_dictionary()
+            .is_some()
+    );
+
+    let read = ParquetReader::new(Cursor::new(trained)).finish()?;
+    assert!(read.equals(&df));
+    Ok(())
+}
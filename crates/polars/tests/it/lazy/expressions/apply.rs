@@ -115,3 +115,70 @@ fn test_apply_groups_empty() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_scan() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 1, 2, 2],
+        "x" => [Some(1.0), Some(3.0), Some(10.0), None, Some(30.0), Some(50.0)],
+    ]?;
+    // An exponential moving average with alpha 0.5 that skips nulls.
+    let ema = |state: &mut Option<f64>, v: Option<f64>| {
+        let v = v?;
+        let ema = state.map_or(v, |s| 0.5 * s + 0.5 * v);
+        *state = Some(ema);
+        Some(ema)
+    };
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("x").scan::<Float64Type, Float64Type, _, _>(None, ema),
+            col("x")
+                .scan::<Float64Type, Float64Type, _, _>(None, ema)
+                .over([col("g")])
+                .alias("per_group"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.f64()?),
+        &[
+            Some(1.0),
+            Some(2.0),
+            Some(6.0),
+            None,
+            Some(18.0),
+            Some(34.0)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("per_group")?.f64()?),
+        &[
+            Some(1.0),
+            Some(2.0),
+            Some(10.0),
+            None,
+            Some(20.0),
+            Some(35.0)
+        ]
+    );
+
+    // The length of the current run of increasing values.
+    let out = df
+        .lazy()
+        .select([col("g").scan::<Int32Type, UInt32Type, _, _>(
+            (None, 0),
+            |(prev, run): &mut (Option<i32>, u32), v| {
+                *run = if v > *prev { *run + 1 } else { 0 };
+                *prev = v;
+                Some(*run)
+            },
+        )])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("g")?.u32()?),
+        &[Some(1), Some(0), Some(1), Some(0), Some(1), Some(0)]
+    );
+    Ok(())
+}
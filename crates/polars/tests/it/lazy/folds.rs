@@ -25,3 +25,29 @@ fn test_fold_wildcard() -> PolarsResult<()> {
         .collect()?;
     Ok(())
 }
+
+#[test]
+fn test_fold_horizontal_supertype() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [0.5, 1.5, 2.5],
+    ]?;
+    let add = |a: Column, b: Column| (&a + &b).map(Some);
+
+    let mut lf = df.lazy().select([
+        fold_horizontal(lit(0), add, [col("*")]).alias("fold"),
+        reduce_horizontal(add, [col("*")]).alias("reduce"),
+    ]);
+    let schema = lf.collect_schema()?;
+    assert_eq!(schema.get("fold"), Some(&DataType::Float64));
+    assert_eq!(schema.get("reduce"), Some(&DataType::Float64));
+
+    let out = lf.collect()?;
+    for name in ["fold", "reduce"] {
+        assert_eq!(
+            Vec::from(out.column(name)?.f64()?),
+            &[Some(1.5), Some(3.5), Some(5.5)]
+        );
+    }
+    Ok(())
+}
@@ -183,3 +183,34 @@ fn test_filter_aggregated_expression() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_unique_with_survivorship() -> PolarsResult<()> {
+    let df = df![
+        "id" => [2, 1, 2, 1, 2],
+        "score" => [Some(3), None, Some(5), Some(1), Some(4)],
+        "email" => [None, Some("a@x"), Some("b@x"), None, Some("c@x")],
+        "name" => ["bo", "al", "bob", "alice", "b"],
+        "updated" => [10, 20, 30, 40, 50],
+    ]?;
+
+    let survivorship = Survivorship::new(SurvivorshipRule::Last)
+        .with_rule("score", SurvivorshipRule::Max)
+        .with_rule("email", SurvivorshipRule::NonNull)
+        .with_rule("name", SurvivorshipRule::Longest);
+    let out = df
+        .lazy()
+        .unique_with_survivorship(vec!["id".into()], &survivorship)?
+        .collect()?;
+
+    let expected = df![
+        "id" => [2, 1],
+        "score" => [Some(5), Some(1)],
+        "email" => [Some("b@x"), Some("a@x")],
+        "name" => ["bob", "alice"],
+        "updated" => [50, 40],
+    ]?;
+    assert!(out.equals_missing(&expected), "{out}");
+    Ok(())
+}
@@ -1,6 +1,8 @@
 #![cfg_attr(feature = "nightly", allow(clippy::result_large_err))] // remove once stable
 #![cfg_attr(feature = "nightly", allow(clippy::manual_repeat_n))] // remove once stable
 #![cfg_attr(feature = "nightly", allow(clippy::len_zero))] // remove once stable
+#[cfg(feature = "capi")]
+mod capi;
 mod core;
 mod io;
 mod joins;
@@ -27,11 +27,12 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 
 from typing import Any
 
-from polars import api, exceptions, plugins, selectors
+from polars import api, exceptions, plugins, random, selectors
 from polars._utils.polars_version import get_polars_version as _get_polars_version
 
 # TODO: remove need for importing wrap utils at top level
 from polars._utils.wrap import wrap_df, wrap_s  # noqa: F401
+from polars.business import BusinessCalendar
 from polars.catalog.unity import Catalog
 from polars.config import Config
 from polars.convert import (
@@ -77,6 +78,7 @@ from polars.datatypes import (
     UInt64,
     Unknown,
     Utf8,
+    Uuid,
 )
 from polars.expr import Expr
 from polars.functions import (
@@ -134,6 +136,7 @@ from polars.functions import (
     linear_space,
     linear_spaces,
     lit,
+    lstsq,
     map_batches,
     map_groups,
     max,
@@ -147,10 +150,14 @@ from polars.functions import (
     nth,
     ones,
     quantile,
+    rank_horizontal,
     reduce,
     repeat,
     rolling_corr,
+    rolling_corr_by,
     rolling_cov,
+    rolling_cov_by,
+    rolling_ols,
     select,
     set_random_seed,
     sql_expr,
@@ -174,6 +181,7 @@ from polars.io import (
     PartitionByKey,
     PartitionMaxSize,
     PartitionParted,
+    SinkReport,
     defer,
     read_avro,
     read_clipboard,
@@ -210,6 +218,7 @@ from polars.io.cloud import (
 from polars.lazyframe import GPUEngine, LazyFrame
 from polars.meta import (
     build_info,
+    engine_stats,
     get_index_type,
     show_versions,
     thread_pool_size,
@@ -233,6 +242,7 @@ __all__ = [
     "api",
     "exceptions",
     "plugins",
+    "random",
     "selectors",
     # core classes
     "DataFrame",
@@ -274,6 +284,7 @@ __all__ = [
     "UInt64",
     "Unknown",
     "Utf8",
+    "Uuid",
     # polars.io
     "defer",
     "KeyedPartition",
@@ -282,6 +293,7 @@ __all__ = [
     "PartitionByKey",
     "PartitionMaxSize",
     "PartitionParted",
+    "SinkReport",
     "read_avro",
     "read_clipboard",
     "read_csv",
@@ -320,6 +332,8 @@ __all__ = [
     "using_string_cache",
     # polars.config
     "Config",
+    # polars.business
+    "BusinessCalendar",
     # polars.functions.whenthen
     "when",
     # polars.functions
@@ -350,6 +364,7 @@ __all__ = [
     "mean_horizontal",
     "min",
     "min_horizontal",
+    "rank_horizontal",
     "sum",
     "sum_horizontal",
     # polars.functions.lazy
@@ -390,6 +405,7 @@ __all__ = [
     "linear_space",
     "linear_spaces",
     "lit",
+    "lstsq",
     "map_batches",
     "map_groups",
     "mean",
@@ -399,7 +415,10 @@ __all__ = [
     "quantile",
     "reduce",
     "rolling_corr",
+    "rolling_corr_by",
     "rolling_cov",
+    "rolling_cov_by",
+    "rolling_ols",
     "select",
     "std",
     "struct",
@@ -422,6 +441,7 @@ __all__ = [
     "json_normalize",
     # polars.meta
     "build_info",
+    "engine_stats",
     "get_index_type",
     "show_versions",
     "thread_pool_size",
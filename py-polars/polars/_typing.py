@@ -105,17 +105,21 @@ AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+DecimalOverflow: TypeAlias = Literal["wrap", "error", "saturate"]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
 ]
+UpsampleFillStrategy: TypeAlias = Literal["forward", "backward", "interpolate"]
 FloatFmt: TypeAlias = Literal["full", "mixed"]
 IndexOrder: TypeAlias = Literal["c", "fortran"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
+JoinAlgorithm: TypeAlias = Literal["auto", "hash", "merge"]
 JoinValidation: TypeAlias = Literal["m:m", "m:1", "1:m", "1:1"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
 MaintainOrderJoin: TypeAlias = Literal[
     "none", "left", "right", "left_right", "right_left"
 ]
+NanPosition: TypeAlias = Literal["largest", "first", "last"]
 NonExistent: TypeAlias = Literal["raise", "null"]
 NullBehavior: TypeAlias = Literal["ignore", "drop"]
 ParallelStrategy: TypeAlias = Literal[
@@ -128,7 +132,9 @@ PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
+RechunkPolicy: TypeAlias = Union[Literal["auto", "never", "always_before_join"], int]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
+RollingRankMethod: TypeAlias = Literal["average", "min", "max"]
 SerializationFormat: TypeAlias = Literal["binary", "json"]
 Endianness: TypeAlias = Literal["little", "big"]
 SizeUnit: TypeAlias = Literal[
@@ -155,6 +161,9 @@ StartBy: TypeAlias = Literal[
     "sunday",
 ]
 SyncOnCloseMethod: TypeAlias = Literal["data", "all"]
+Weekday: TypeAlias = Literal[
+    "monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"
+]
 TimeUnit: TypeAlias = Literal["ns", "us", "ms"]
 UnicodeForm: TypeAlias = Literal["NFC", "NFKC", "NFD", "NFKD"]
 UniqueKeepStrategy: TypeAlias = Literal["first", "last", "any", "none"]
@@ -164,6 +173,9 @@ MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
 # The following have a Rust enum equivalent with a different name
 AsofJoinStrategy: TypeAlias = Literal["backward", "forward", "nearest"]  # AsofStrategy
 ClosedInterval: TypeAlias = Literal["left", "right", "both", "none"]  # ClosedWindow
+HashAlgorithm: TypeAlias = Literal[
+    "default", "xxhash64", "wyhash", "siphash13", "sha256"
+]
 InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
 JoinStrategy: TypeAlias = Literal[
     "inner", "left", "right", "full", "semi", "anti", "cross", "outer"
@@ -186,16 +198,20 @@ ConcatMethod = Literal[
     "align_left",
     "align_right",
 ]
+ContractPolicy: TypeAlias = Literal["strict", "coerce"]
 CorrelationMethod: TypeAlias = Literal["pearson", "spearman"]
 DbReadEngine: TypeAlias = Literal["adbc", "connectorx"]
 DbWriteEngine: TypeAlias = Literal["sqlalchemy", "adbc"]
 DbWriteMode: TypeAlias = Literal["replace", "append", "fail"]
 EpochTimeUnit = Literal["ns", "us", "ms", "s", "d"]
 JaxExportType: TypeAlias = Literal["array", "dict"]
+OlsMode: TypeAlias = Literal["coefficients", "predictions", "residuals"]
 Orientation: TypeAlias = Literal["col", "row"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
 TorchExportType: TypeAlias = Literal["tensor", "dataset", "dict"]
 TransferEncoding: TypeAlias = Literal["hex", "base64"]
+ViolationStrategy: TypeAlias = Literal["raise", "return"]
+BinaryTransferEncoding: TypeAlias = Literal["hex", "base64", "base64url"]
 WindowMappingStrategy: TypeAlias = Literal["group_to_rows", "join", "explode"]
 ExplainFormat: TypeAlias = Literal["plain", "tree"]
 
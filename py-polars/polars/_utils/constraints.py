@@ -0,0 +1,87 @@
+"""Checks of the constraints of `DataFrame.validate` and `LazyFrame.validate`."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+from polars import functions as F
+from polars.exceptions import ComputeError
+
+if TYPE_CHECKING:
+    from collections.abc import Mapping, Sequence
+
+    from polars import DataFrame, LazyFrame
+
+# The number of violating rows shown per constraint in the error of a validation.
+SHOWN_VIOLATIONS = 5
+
+_INDEX = "__POLARS_VALIDATE_INDEX"
+
+
+def constraint_violations(
+    lf: LazyFrame,
+    *,
+    unique: str | Sequence[str] | None,
+    not_null: str | Sequence[str] | None,
+    ranges: Mapping[str, tuple[Any, Any]] | None,
+    references: Mapping[str, tuple[DataFrame | LazyFrame, str]] | None,
+) -> LazyFrame:
+    """
+    The rows of `lf` that violate a constraint, as a frame of row indices.
+
+    The violations are ordered by constraint, in the order of the arguments, and then
+    by row.
+    """
+    base = lf.with_row_index(_INDEX)
+
+    def violations(rows: LazyFrame, constraint: str) -> LazyFrame:
+        return rows.select(
+            F.col(_INDEX).alias("index"), F.lit(constraint).alias("constraint")
+        )
+
+    pieces = []
+    if unique is not None:
+        keys = [unique] if isinstance(unique, str) else list(unique)
+        rows = base.filter(F.struct(keys).is_duplicated())
+        pieces.append(violations(rows, f"unique({', '.join(keys)})"))
+    if not_null is not None:
+        for name in [not_null] if isinstance(not_null, str) else not_null:
+            rows = base.filter(F.col(name).is_null())
+            pieces.append(violations(rows, f"not_null({name})"))
+    for name, (lower, upper) in (ranges or {}).items():
+        outside = F.lit(False)
+        if lower is not None:
+            outside |= F.col(name) < lower
+        if upper is not None:
+            outside |= F.col(name) > upper
+        pieces.append(violations(base.filter(outside), f"range({name})"))
+    for name, (other, other_name) in (references or {}).items():
+        keys = other.lazy().select(F.col(other_name).alias(name)).unique()
+        rows = (
+            base.filter(F.col(name).is_not_null())
+            .join(keys, on=name, how="anti")
+            .sort(_INDEX)
+        )
+        pieces.append(violations(rows, f"reference({name})"))
+
+    if not pieces:
+        return violations(base, "").clear()
+    return F.concat(pieces)
+
+
+def raise_on_violations(violations: DataFrame) -> None:
+    """Raise an error that summarizes the violations, if there are any."""
+    if violations.is_empty():
+        return
+    summary = violations.group_by("constraint", maintain_order=True).agg(
+        F.len(), F.col("index").head(SHOWN_VIOLATIONS)
+    )
+    lines = [
+        f"\n  {constraint}: {n} {'row' if n == 1 else 'rows'}, including rows {rows}"
+        for constraint, n, rows in summary.iter_rows()
+    ]
+    msg = "validation failed:" + "".join(lines)
+    raise ComputeError(msg)
+
+
+__all__ = ["constraint_violations", "raise_on_violations"]
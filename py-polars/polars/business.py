@@ -0,0 +1,146 @@
+from __future__ import annotations
+
+from datetime import date
+from typing import TYPE_CHECKING
+
+from polars.datatypes import Date, Datetime
+
+if TYPE_CHECKING:
+    from collections.abc import Iterable
+
+    from polars import Series
+
+__all__ = ["BusinessCalendar"]
+
+_DEFAULT_WEEK_MASK = (True, True, True, True, True, False, False)
+_UNIX_EPOCH = date(1970, 1, 1)
+
+
+class BusinessCalendar:
+    """
+    A calendar of business days: the business days of the week and a list of holidays.
+
+    A calendar can be passed to :func:`business_day_count` and to the business day
+    methods of the `dt` namespace instead of passing `week_mask` and `holidays` to
+    every call.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    week_mask
+        Which days of the week are business days. The default is Monday to Friday.
+        If your business days are Monday to Thursday, you would pass
+        `(True, True, True, True, False, False, False)`.
+    holidays
+        Days that are not business days, even if they fall on a business day of
+        `week_mask`.
+
+    See Also
+    --------
+    BusinessCalendar.from_series
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> calendar = pl.BusinessCalendar(
+    ...     week_mask=(True, True, True, True, False, False, False),
+    ...     holidays=[date(2020, 1, 1)],
+    ... )
+    >>> df = pl.DataFrame({"start": [date(2020, 1, 1), date(2020, 1, 6)]})
+    >>> df.with_columns(
+    ...     is_business_day=pl.col("start").dt.is_business_day(calendar=calendar),
+    ...     plus_2=pl.col("start").dt.add_business_days(
+    ...         2, calendar=calendar, roll="forward"
+    ...     ),
+    ... )
+    shape: (2, 3)
+    ┌────────────┬─────────────────┬────────────┐
+    │ start      ┆ is_business_day ┆ plus_2     │
+    │ ---        ┆ ---             ┆ ---        │
+    │ date       ┆ bool            ┆ date       │
+    ╞════════════╪═════════════════╪════════════╡
+    │ 2020-01-01 ┆ false           ┆ 2020-01-07 │
+    │ 2020-01-06 ┆ true            ┆ 2020-01-08 │
+    └────────────┴─────────────────┴────────────┘
+    """
+
+    def __init__(
+        self,
+        week_mask: Iterable[bool] = _DEFAULT_WEEK_MASK,
+        holidays: Iterable[date] = (),
+    ) -> None:
+        week_mask = tuple(week_mask)
+        if len(week_mask) != 7:
+            msg = f"`week_mask` must have 7 elements, got {len(week_mask)}"
+            raise ValueError(msg)
+        if not any(week_mask):
+            msg = "`week_mask` must have at least one business day"
+            raise ValueError(msg)
+        self.week_mask: tuple[bool, ...] = week_mask
+        self.holidays: tuple[date, ...] = tuple(sorted(set(holidays)))
+
+    @classmethod
+    def from_series(
+        cls,
+        holidays: Series,
+        *,
+        week_mask: Iterable[bool] = _DEFAULT_WEEK_MASK,
+    ) -> BusinessCalendar:
+        """
+        Create a calendar from a Series of holidays.
+
+        Parameters
+        ----------
+        holidays
+            Series of data type :class:`Date` or :class:`Datetime` holding the
+            holidays. Datetimes are converted to their date and null values are
+            ignored.
+        week_mask
+            Which days of the week are business days. The default is Monday to Friday.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> holidays = pl.Series([date(2024, 12, 25), date(2024, 12, 26), None])
+        >>> calendar = pl.BusinessCalendar.from_series(holidays)
+        >>> calendar.holidays
+        (datetime.date(2024, 12, 25), datetime.date(2024, 12, 26))
+        """
+        if isinstance(holidays.dtype, Datetime):
+            holidays = holidays.dt.date()
+        elif holidays.dtype != Date:
+            msg = f"expected a Series of Date or Datetime, got {holidays.dtype}"
+            raise TypeError(msg)
+        return cls(week_mask, holidays.drop_nulls().to_list())
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, BusinessCalendar):
+            return NotImplemented
+        return self.week_mask == other.week_mask and self.holidays == other.holidays
+
+    def __hash__(self) -> int:
+        return hash((self.week_mask, self.holidays))
+
+    def __repr__(self) -> str:
+        return (
+            f"BusinessCalendar(week_mask={self.week_mask!r}, "
+            f"holidays=<{len(self.holidays)} dates>)"
+        )
+
+
+def _business_day_args(
+    week_mask: Iterable[bool],
+    holidays: Iterable[date],
+    calendar: BusinessCalendar | None,
+) -> tuple[tuple[bool, ...], list[int]]:
+    """Resolve the `week_mask` and the holidays in days since the UNIX epoch."""
+    if calendar is not None:
+        holidays = tuple(holidays)
+        if tuple(week_mask) != _DEFAULT_WEEK_MASK or holidays:
+            msg = "cannot pass `week_mask` or `holidays` together with `calendar`"
+            raise ValueError(msg)
+        week_mask, holidays = calendar.week_mask, calendar.holidays
+    return tuple(week_mask), [(holiday - _UNIX_EPOCH).days for holiday in holidays]
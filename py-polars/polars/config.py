@@ -14,7 +14,7 @@ if TYPE_CHECKING:
     import sys
     from types import TracebackType
 
-    from polars._typing import FloatFmt
+    from polars._typing import FloatFmt, RechunkPolicy
 
     if sys.version_info >= (3, 10):
         from typing import TypeAlias
@@ -69,6 +69,7 @@ _POLARS_CFG_ENV_VARS = {
     "POLARS_FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION",
     "POLARS_FMT_TABLE_INLINE_COLUMN_DATA_TYPE",
     "POLARS_FMT_TABLE_ROUNDED_CORNERS",
+    "POLARS_RECHUNK_POLICY",
     "POLARS_STREAMING_CHUNK_SIZE",
     "POLARS_TABLE_WIDTH",
     "POLARS_VERBOSE",
@@ -102,6 +103,7 @@ class ConfigParameters(TypedDict, total=False):
     fmt_float: FloatFmt | None
     fmt_str_lengths: int | None
     fmt_table_cell_list_len: int | None
+    rechunk_policy: RechunkPolicy | None
     streaming_chunk_size: int | None
     tbl_cell_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     tbl_cell_numeric_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -127,6 +129,7 @@ class ConfigParameters(TypedDict, total=False):
     set_fmt_float: FloatFmt | None
     set_fmt_str_lengths: int | None
     set_fmt_table_cell_list_len: int | None
+    set_rechunk_policy: RechunkPolicy | None
     set_streaming_chunk_size: int | None
     set_tbl_cell_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     set_tbl_cell_numeric_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -864,6 +867,54 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_FMT_TABLE_CELL_LIST_LEN"] = str(n)
         return cls
 
+    @classmethod
+    def set_rechunk_policy(cls, policy: RechunkPolicy | None = "auto") -> type[Config]:
+        """
+        Set when the in-memory engine rechunks its inputs into a single chunk.
+
+        Contiguous data speeds up operations like sorts, group-bys and joins, but
+        rechunking copies the data. Frames whose columns have misaligned chunks are
+        always rechunked where an operation needs them aligned.
+
+        Parameters
+        ----------
+        policy : {'auto', 'never', 'always_before_join'}, int, or None
+            * 'auto': rechunk before sorts and group-bys (the default).
+            * 'never': never rechunk frames whose chunks are aligned.
+            * int: like 'auto', but only rechunk frames with more chunks than this.
+            * 'always_before_join': like 'auto', and also rechunk both inputs of a
+              join.
+
+            Set to ``None`` to revert to the default.
+
+        See Also
+        --------
+        DataFrame.rechunk_to : Rechunk into evenly sized chunks.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3]})
+        >>> with pl.Config(rechunk_policy="always_before_join"):
+        ...     lf.join(lf, on="a").collect().height
+        3
+        """
+        if policy is None:
+            os.environ.pop("POLARS_RECHUNK_POLICY", None)
+        elif isinstance(policy, int):
+            if policy < 0:
+                msg = f"the chunk threshold must be >= 0, got {policy}"
+                raise ValueError(msg)
+            os.environ["POLARS_RECHUNK_POLICY"] = str(policy)
+        elif policy in {"auto", "never", "always_before_join"}:
+            os.environ["POLARS_RECHUNK_POLICY"] = policy
+        else:
+            msg = (
+                "`policy` must be one of {'auto', 'never', 'always_before_join'} "
+                f"or an integer, got {policy!r}"
+            )
+            raise ValueError(msg)
+        return cls
+
     @classmethod
     def set_streaming_chunk_size(cls, size: int | None) -> type[Config]:
         """
@@ -34,6 +34,7 @@ from polars._utils.construction import (
     sequence_to_pydf,
     series_to_pydf,
 )
+from polars._utils.constraints import constraint_violations, raise_on_violations
 from polars._utils.convert import parse_as_duration_string
 from polars._utils.deprecation import (
     deprecate_function,
@@ -141,6 +142,7 @@ if TYPE_CHECKING:
         IntoExpr,
         IntoExprColumn,
         IpcCompression,
+        JoinAlgorithm,
         JoinStrategy,
         JoinValidation,
         Label,
@@ -165,6 +167,8 @@ if TYPE_CHECKING:
         StartBy,
         UniqueKeepStrategy,
         UnstackDirection,
+        UpsampleFillStrategy,
+        ViolationStrategy,
     )
     from polars._utils.various import NoDefault
     from polars.interchange.dataframe import PolarsDataFrame
@@ -3836,6 +3840,7 @@ class DataFrame:
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        zstd_dictionary_size: int | None = None,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
         partition_by: str | Sequence[str] | None = None,
@@ -3888,6 +3893,15 @@ class DataFrame:
             Size of the row groups in number of rows. Defaults to 512^2 rows.
         data_page_size
             Size of the data page in bytes. Defaults to 1024^2 bytes.
+        zstd_dictionary_size
+            Train a zstd dictionary of at most this many bytes on a sample of the pages
+            of every column chunk, and compress all its pages with it. This
+            substantially improves the compression of many small pages, e.g. of string
+            columns with a small `data_page_size`. Only applies to "zstd" compression.
+
+            .. warning::
+                The dictionary is not part of the Parquet specification, so the file
+                can then only be read by Polars. Not supported with `use_pyarrow`.
         use_pyarrow
             Use C++ parquet implementation vs Rust parquet implementation.
             At the moment C++ supports more features.
@@ -3969,6 +3983,9 @@ class DataFrame:
                 file = normalize_filepath(file)
 
         if use_pyarrow:
+            if zstd_dictionary_size is not None:
+                msg = "`zstd_dictionary_size` is not supported with `use_pyarrow=True`"
+                raise ValueError(msg)
             if statistics == "full" or isinstance(statistics, dict):
                 msg = "write_parquet with `use_pyarrow=True` allows only boolean values for `statistics`"
                 raise ValueError(msg)
@@ -4059,6 +4076,7 @@ class DataFrame:
             statistics,
             row_group_size,
             data_page_size,
+            zstd_dictionary_size,
             partition_by=partition_by,
             partition_chunk_size_bytes=partition_chunk_size_bytes,
             cloud_options=storage_options,
@@ -4669,12 +4687,54 @@ class DataFrame:
         sz = self._df.estimated_size()
         return scale_bytes(sz, unit)
 
+    @unstable()
+    def memory_profile(self) -> DataFrame:
+        """
+        Return the heap memory held by every column of the `DataFrame` in bytes.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns a row per column with the bytes held by its `values`, `validity`
+        bitmaps, `offsets`, and `dictionary` (categorical mappings and the values of
+        dictionary arrays), which sum up to its `total`.
+
+        Unlike :meth:`estimated_size`, the whole allocation underlying a sliced
+        buffer is counted, and buffers shared between columns are only attributed to
+        the first column holding them. The bytes of a column that were already
+        attributed are reported as `shared`, so the `total` of all columns sums up to
+        the memory held by the `DataFrame`.
+
+        See Also
+        --------
+        estimated_size
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        >>> df.with_columns(c=pl.col("a")).memory_profile()
+        shape: (3, 8)
+        ┌────────┬───────┬────────┬──────────┬─────────┬────────────┬────────┬───────┐
+        │ column ┆ dtype ┆ values ┆ validity ┆ offsets ┆ dictionary ┆ shared ┆ total │
+        │ ---    ┆ ---   ┆ ---    ┆ ---      ┆ ---     ┆ ---        ┆ ---    ┆ ---   │
+        │ str    ┆ str   ┆ u64    ┆ u64      ┆ u64     ┆ u64        ┆ u64    ┆ u64   │
+        ╞════════╪═══════╪════════╪══════════╪═════════╪════════════╪════════╪═══════╡
+        │ a      ┆ i64   ┆ 24     ┆ 0        ┆ 0       ┆ 0          ┆ 0      ┆ 24    │
+        │ b      ┆ str   ┆ 48     ┆ 0        ┆ 0       ┆ 0          ┆ 0      ┆ 48    │
+        │ c      ┆ i64   ┆ 0      ┆ 0        ┆ 0       ┆ 0          ┆ 24     ┆ 0     │
+        └────────┴───────┴────────┴──────────┴─────────┴────────────┴────────┴───────┘
+        """
+        return wrap_df(self._df.memory_profile())
+
     def transpose(
         self,
         *,
         include_header: bool = False,
         header_name: str = "column",
         column_names: str | Iterable[str] | None = None,
+        dtype: PolarsDataType | None = None,
+        as_struct: bool = False,
     ) -> DataFrame:
         """
         Transpose a DataFrame over the diagonal.
@@ -4689,6 +4749,14 @@ class DataFrame:
         column_names
             Optional iterable yielding strings or a string naming an existing column.
             These will name the value (non-header) columns in the transposed data.
+        dtype
+            The dtype of the value columns; every column is strictly cast to it. By
+            default this is the supertype of all columns, which is `String` as soon as
+            a column is a string.
+        as_struct
+            Keep the dtype of every column by returning struct value columns with one
+            field per distinct dtype, named after the dtype. In the row of a column,
+            only the field of its dtype is set. Cannot be combined with `dtype`.
 
         Notes
         -----
@@ -4782,11 +4850,30 @@ class DataFrame:
         │ a      ┆ 1   ┆ 2   ┆ 3   │
         │ b      ┆ 4   ┆ 5   ┆ 6   │
         └────────┴─────┴─────┴─────┘
+
+        Keep the dtypes of mixed columns instead of converting them to strings
+
+        >>> df = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+        >>> df.transpose(as_struct=True)
+        shape: (2, 2)
+        ┌────────────┬────────────┐
+        │ column_0   ┆ column_1   │
+        │ ---        ┆ ---        │
+        │ struct[2]  ┆ struct[2]  │
+        ╞════════════╪════════════╡
+        │ {1,null}   ┆ {2,null}   │
+        │ {null,"x"} ┆ {null,"y"} │
+        └────────────┴────────────┘
         """
+        if dtype is not None and as_struct:
+            msg = "cannot set both `dtype` and `as_struct` in `transpose`"
+            raise ValueError(msg)
         keep_names_as = header_name if include_header else None
         if isinstance(column_names, Generator):
             column_names = [next(column_names) for _ in range(self.height)]
-        return self._from_pydf(self._df.transpose(keep_names_as, column_names))
+        return self._from_pydf(
+            self._df.transpose(keep_names_as, column_names, dtype, as_struct)
+        )
 
     def reverse(self) -> DataFrame:
         """
@@ -6781,6 +6868,10 @@ class DataFrame:
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        fill_gaps: UpsampleFillStrategy
+        | IntoExpr
+        | Mapping[str, UpsampleFillStrategy | IntoExpr]
+        | None = None,
     ) -> DynamicGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -6851,6 +6942,12 @@ class DataFrame:
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        fill_gaps
+            Insert a row for every window without data and fill its aggregated
+            columns, see :meth:`LazyFrame.upsample` for the accepted strategies. Pass a
+            mapping of column names to strategies to fill every column differently;
+            columns that are not in the mapping are left null. Only supported with
+            `label='left'` and column names as `index_column` and `group_by`.
 
         Returns
         -------
@@ -6880,8 +6977,9 @@ class DataFrame:
                df.set_index("ts").resample("D")["value"].sum().reset_index()
 
            though note that, unlike pandas, polars doesn't add extra rows for empty
-           windows. If you need `index_column` to be evenly spaced, then please combine
-           with :func:`DataFrame.upsample`.
+           windows unless `fill_gaps` is set. If you need `index_column` to be evenly
+           spaced, then please pass `fill_gaps` or combine with
+           :func:`DataFrame.upsample`.
 
         2) The `every`, `period` and `offset` arguments are created with
            the following string language:
@@ -7083,6 +7181,7 @@ class DataFrame:
             closed=closed,
             group_by=group_by,
             start_by=start_by,
+            fill_gaps=fill_gaps,
         )
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -7550,6 +7649,7 @@ class DataFrame:
         nulls_equal: bool = False,
         coalesce: bool | None = None,
         maintain_order: MaintainOrderJoin | None = None,
+        algorithm: JoinAlgorithm = "auto",
     ) -> DataFrame:
         """
         Join in SQL-like fashion.
@@ -7632,6 +7732,22 @@ class DataFrame:
                 First preserves the order of the left DataFrame, then the right.
             * *right_left*
                 First preserves the order of the right DataFrame, then the left.
+        algorithm : {'auto', 'hash', 'merge'}
+            The algorithm used to match the join keys.
+
+            * *auto*
+                Use a sorted merge join if both keys are flagged as sorted,
+                otherwise a hash join.
+            * *hash*
+                Always use a hash join.
+            * *merge*
+                Merge the keys, which must be sorted in ascending order and may not
+                contain nulls. Only inner, left and right joins on a single numeric
+                key are supported.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         See Also
         --------
@@ -7761,6 +7877,7 @@ class DataFrame:
                 nulls_equal=nulls_equal,
                 coalesce=coalesce,
                 maintain_order=maintain_order,
+                algorithm=algorithm,
             )
             .collect(_eager=True)
         )
@@ -10453,6 +10570,103 @@ class DataFrame:
             .collect(_eager=True)
         )
 
+    @unstable()
+    def validate(
+        self,
+        *,
+        unique: str | Sequence[str] | None = None,
+        not_null: str | Sequence[str] | None = None,
+        ranges: Mapping[str, tuple[Any, Any]] | None = None,
+        references: Mapping[str, tuple[DataFrame | LazyFrame, str]] | None = None,
+        on_violation: ViolationStrategy = "raise",
+    ) -> DataFrame:
+        """
+        Check constraints on the rows of this DataFrame.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        unique
+            Columns that together must be unique, like a primary key.
+        not_null
+            Columns that must not contain nulls.
+        ranges
+            A mapping of columns to their inclusive `(lower, upper)` bounds, where
+            `None` is unbounded. Nulls pass.
+        references
+            A mapping of columns to an `(other, column)` pair, meaning that every
+            non-null value must occur in that column of the other frame, like a foreign
+            key.
+        on_violation : {'raise', 'return'}
+            What to do with the rows that violate a constraint.
+
+            - 'raise': Raise a `ComputeError` that lists every violated constraint
+              with its number of violating rows.
+            - 'return': Return the violations, as a frame with the row index of every
+              violating row and the violated constraint. A row breaking several
+              constraints appears once for each.
+
+        Returns
+        -------
+        DataFrame
+            With `on_violation='raise'`, this DataFrame. Otherwise the violations.
+
+        See Also
+        --------
+        LazyFrame.validate
+        Expr.assert_
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"id": [1, 2, 2, 4], "price": [9.5, None, 12.0, -1.0]})
+        >>> df.validate(
+        ...     unique="id",
+        ...     not_null="price",
+        ...     ranges={"price": (0, None)},
+        ...     on_violation="return",
+        ... )
+        shape: (4, 2)
+        ┌───────┬─────────────────┐
+        │ index ┆ constraint      │
+        │ ---   ┆ ---             │
+        │ u32   ┆ str             │
+        ╞═══════╪═════════════════╡
+        │ 1     ┆ unique(id)      │
+        │ 2     ┆ unique(id)      │
+        │ 1     ┆ not_null(price) │
+        │ 3     ┆ range(price)    │
+        └───────┴─────────────────┘
+
+        By default, violations raise an error.
+
+        >>> df.validate(unique="id", ranges={"price": (0, None)})  # doctest: +SKIP
+        Traceback (most recent call last):
+        ...
+        polars.exceptions.ComputeError: validation failed:
+          unique(id): 2 rows, including rows [1, 2]
+          range(price): 1 row, including rows [3]
+        """
+        if on_violation not in ("raise", "return"):
+            msg = (
+                "`on_violation` must be one of {'raise', 'return'},"
+                f" got {on_violation!r}"
+            )
+            raise ValueError(msg)
+        violations = constraint_violations(
+            self.lazy(),
+            unique=unique,
+            not_null=not_null,
+            ranges=ranges,
+            references=references,
+        ).collect(_eager=True)
+        if on_violation == "return":
+            return violations
+        raise_on_violations(violations)
+        return self
+
     def n_unique(self, subset: str | Expr | Sequence[str | Expr] | None = None) -> int:
         """
         Return the number of unique rows, or the number of unique row-subsets.
@@ -10564,6 +10778,30 @@ class DataFrame:
         """
         return self._from_pydf(self._df.rechunk())
 
+    def rechunk_to(self, chunk_size: int) -> DataFrame:
+        """
+        Rechunk the data in this DataFrame into evenly sized chunks.
+
+        The chunks hold at most `chunk_size` rows. Use this to balance the work of
+        parallel operations after a filter left chunks of skewed sizes.
+
+        Parameters
+        ----------
+        chunk_size
+            The maximum number of rows per chunk.
+
+        See Also
+        --------
+        rechunk
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": range(10)})
+        >>> df.rechunk_to(4).n_chunks("all")
+        [3]
+        """
+        return self._from_pydf(self._df.rechunk_to(chunk_size))
+
     def null_count(self) -> DataFrame:
         """
         Create a new DataFrame that shows the null counts per column.
@@ -10661,6 +10899,91 @@ class DataFrame:
 
         return self._from_pydf(self._df.sample_n(n._s, with_replacement, shuffle, seed))
 
+    def sample_stratified(
+        self,
+        by: str | Sequence[str],
+        n: int | None = None,
+        *,
+        fraction: float | None = None,
+        with_replacement: bool = False,
+        shuffle: bool = False,
+        seed: int | None = None,
+    ) -> DataFrame:
+        """
+        Sample from every group of this DataFrame.
+
+        The groups are determined by the `by` columns and appear in the output in the
+        order of their first occurrence.
+
+        Parameters
+        ----------
+        by
+            Column name(s) that determine the groups (strata) to sample from.
+        n
+            Number of items to return per group. Cannot be used with `fraction`.
+            Defaults to 1 if `fraction` is None.
+        fraction
+            Fraction of items to return per group. Cannot be used with `n`.
+        with_replacement
+            Allow values to be sampled more than once.
+        shuffle
+            If set to True, the order of the sampled rows within a group will be
+            shuffled. If set to False (default), the order of the returned rows will
+            be neither stable nor fully random.
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated for each sample operation.
+
+        See Also
+        --------
+        sample
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "label": ["a", "a", "a", "b", "b", "c"],
+        ...         "value": [1, 2, 3, 4, 5, 6],
+        ...     }
+        ... )
+        >>> df.sample_stratified("label", n=1, seed=0)  # doctest: +IGNORE_RESULT
+        shape: (3, 2)
+        ┌───────┬───────┐
+        │ label ┆ value │
+        │ ---   ┆ ---   │
+        │ str   ┆ i64   │
+        ╞═══════╪═══════╡
+        │ a     ┆ 2     │
+        │ b     ┆ 4     │
+        │ c     ┆ 6     │
+        └───────┴───────┘
+        """
+        if n is not None and fraction is not None:
+            msg = "cannot specify both `n` and `fraction`"
+            raise ValueError(msg)
+
+        if isinstance(by, str):
+            by = [by]
+        else:
+            by = list(by)
+
+        if seed is None:
+            seed = random.randint(0, 10000)
+
+        if n is None and fraction is not None:
+            return self._from_pydf(
+                self._df.sample_frac_stratified(
+                    by, fraction, with_replacement, shuffle, seed
+                )
+            )
+
+        if n is None:
+            n = 1
+
+        return self._from_pydf(
+            self._df.sample_n_stratified(by, n, with_replacement, shuffle, seed)
+        )
+
     def fold(self, operation: Callable[[Series, Series], Series]) -> Series:
         """
         Apply a horizontal reduction on a DataFrame.
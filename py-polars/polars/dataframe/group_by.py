@@ -8,7 +8,7 @@ from polars._utils.deprecation import deprecate_renamed_function
 
 if TYPE_CHECKING:
     import sys
-    from collections.abc import Iterable
+    from collections.abc import Iterable, Mapping, Sequence
     from datetime import timedelta
 
     from polars import DataFrame
@@ -19,6 +19,7 @@ if TYPE_CHECKING:
         RollingInterpolationMethod,
         SchemaDict,
         StartBy,
+        UpsampleFillStrategy,
     )
 
     if sys.version_info >= (3, 11):
@@ -410,6 +411,124 @@ class GroupBy:
             .collect(no_optimization=True)
         )
 
+    def top_k_by(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        k: int = 5,
+        *,
+        reverse: bool | Sequence[bool] = False,
+    ) -> DataFrame:
+        """
+        Get the `k` rows of each group with the largest values of the `by` column(s).
+
+        Rows are selected without sorting each group, and non-null values are always
+        preferred over nulls. With several `by` columns, ties are broken by the order
+        of the rows; with a single one, which of the tied rows is selected is not
+        specified.
+
+        Parameters
+        ----------
+        by
+            Column(s) used to determine the largest rows.
+            Accepts expression input. Strings are parsed as column names.
+        k
+            Number of rows to return per group.
+        reverse
+            Consider the `k` smallest rows of the `by` column(s) instead.
+            This can be specified per column by passing a sequence of booleans.
+
+        See Also
+        --------
+        bottom_k_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "letters": ["c", "c", "a", "c", "a", "b"],
+        ...         "nrs": [1, 2, 3, 4, 5, 6],
+        ...     }
+        ... )
+        >>> df.group_by("letters").top_k_by("nrs", 2).sort("letters", "nrs")
+        shape: (5, 2)
+        ┌─────────┬─────┐
+        │ letters ┆ nrs │
+        │ ---     ┆ --- │
+        │ str     ┆ i64 │
+        ╞═════════╪═════╡
+        │ a       ┆ 3   │
+        │ a       ┆ 5   │
+        │ b       ┆ 6   │
+        │ c       ┆ 2   │
+        │ c       ┆ 4   │
+        └─────────┴─────┘
+        """
+        return (
+            self.df.lazy()
+            .group_by(*self.by, **self.named_by, maintain_order=self.maintain_order)
+            .top_k_by(by, k, reverse=reverse)
+            .collect(no_optimization=True)
+        )
+
+    def bottom_k_by(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        k: int = 5,
+        *,
+        reverse: bool | Sequence[bool] = False,
+    ) -> DataFrame:
+        """
+        Get the `k` rows of each group with the smallest values of the `by` column(s).
+
+        Rows are selected without sorting each group, and non-null values are always
+        preferred over nulls. With several `by` columns, ties are broken by the order
+        of the rows; with a single one, which of the tied rows is selected is not
+        specified.
+
+        Parameters
+        ----------
+        by
+            Column(s) used to determine the smallest rows.
+            Accepts expression input. Strings are parsed as column names.
+        k
+            Number of rows to return per group.
+        reverse
+            Consider the `k` largest rows of the `by` column(s) instead.
+            This can be specified per column by passing a sequence of booleans.
+
+        See Also
+        --------
+        top_k_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "letters": ["c", "c", "a", "c", "a", "b"],
+        ...         "nrs": [1, 2, 3, 4, 5, 6],
+        ...     }
+        ... )
+        >>> df.group_by("letters").bottom_k_by("nrs", 2).sort("letters", "nrs")
+        shape: (5, 2)
+        ┌─────────┬─────┐
+        │ letters ┆ nrs │
+        │ ---     ┆ --- │
+        │ str     ┆ i64 │
+        ╞═════════╪═════╡
+        │ a       ┆ 3   │
+        │ a       ┆ 5   │
+        │ b       ┆ 6   │
+        │ c       ┆ 1   │
+        │ c       ┆ 2   │
+        └─────────┴─────┘
+        """
+        return (
+            self.df.lazy()
+            .group_by(*self.by, **self.named_by, maintain_order=self.maintain_order)
+            .bottom_k_by(by, k, reverse=reverse)
+            .collect(no_optimization=True)
+        )
+
     def all(self) -> DataFrame:
         """
         Aggregate the groups into Series.
@@ -910,6 +1029,10 @@ class DynamicGroupBy:
         label: Label,
         group_by: IntoExpr | Iterable[IntoExpr] | None,
         start_by: StartBy,
+        fill_gaps: UpsampleFillStrategy
+        | IntoExpr
+        | Mapping[str, UpsampleFillStrategy | IntoExpr]
+        | None = None,
     ) -> None:
         every = parse_as_duration_string(every)
         period = parse_as_duration_string(period)
@@ -925,6 +1048,7 @@ class DynamicGroupBy:
         self.closed = closed
         self.group_by = group_by
         self.start_by = start_by
+        self.fill_gaps = fill_gaps
 
     def __iter__(self) -> Self:
         temp_col = "__POLARS_GB_GROUP_INDICES"
@@ -991,6 +1115,7 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                fill_gaps=self.fill_gaps,
             )
             .agg(*aggs, **named_aggs)
             .collect(no_optimization=True)
@@ -33,6 +33,7 @@ from polars.datatypes.classes import (
     UInt64,
     Unknown,
     Utf8,
+    Uuid,
 )
 from polars.datatypes.constants import (
     DTYPE_TEMPORAL_UNITS,
@@ -90,6 +91,7 @@ __all__ = [
     "UInt8",
     "Unknown",
     "Utf8",
+    "Uuid",
     # constants
     "N_INFER_DEFAULT",
     "DTYPE_TEMPORAL_UNITS",
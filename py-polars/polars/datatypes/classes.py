@@ -439,6 +439,17 @@ class Binary(DataType):
     """Binary type."""
 
 
+class Uuid(DataType):
+    """
+    Data type representing a UUID.
+
+    Notes
+    -----
+    Each value is stored as its 16 bytes. Parquet and Arrow files store this type as
+    the Parquet UUID logical type and Arrow's canonical `arrow.uuid` extension type.
+    """
+
+
 class Date(TemporalType):
     """
     Data type representing a calendar date.
@@ -8,6 +8,7 @@ from collections.abc import Collection
 from datetime import date, datetime, time, timedelta
 from decimal import Decimal as PyDecimal
 from typing import TYPE_CHECKING, Any, Optional, Union
+from uuid import UUID
 
 from polars.datatypes.classes import (
     Array,
@@ -40,6 +41,7 @@ from polars.datatypes.classes import (
     UInt32,
     UInt64,
     Unknown,
+    Uuid,
 )
 from polars.dependencies import numpy as np
 from polars.dependencies import pyarrow as pa
@@ -194,6 +196,7 @@ class _DataTypeMappings:
             UInt32: int,
             UInt64: int,
             UInt8: int,
+            Uuid: UUID,
             # the below mappings are appropriate as we restrict cat/enum to strings
             Enum: str,
             Categorical: str,
@@ -0,0 +1,171 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+class ExprAnonymizeNameSpace:
+    """
+    Namespace for privacy transforms.
+
+    The keyed transforms hash with BLAKE3 in keyed mode, with a key derived from a
+    secret. Without the secret, an output cannot be linked to its input, even if all
+    possible inputs are known; with it, the same input always gives the same output, so
+    anonymized columns can still be joined and grouped on.
+
+    The derived key is part of the query plan, so a serialized plan is as sensitive as
+    the secret itself. It is not shown when the plan is printed.
+    """
+
+    _accessor = "anonymize"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def tokenize(self, key: str, *, length: int = 32) -> Expr:
+        """
+        Replace every value by a token computed with a keyed hash of the value.
+
+        Values that are not strings or binary are tokenized as strings.
+
+        Parameters
+        ----------
+        key
+            The secret the hash is keyed with.
+        length
+            The number of hexadecimal characters of a token.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"email": ["ann@x.org", "bob@y.org", "ann@x.org"]})
+        >>> token = pl.col("email").anonymize.tokenize("secret", length=8)
+        >>> df.select(
+        ...     n_tokens=token.n_unique(),
+        ...     length=token.str.len_chars().max(),
+        ... )
+        shape: (1, 2)
+        ┌──────────┬────────┐
+        │ n_tokens ┆ length │
+        │ ---      ┆ ---    │
+        │ u32      ┆ u32    │
+        ╞══════════╪════════╡
+        │ 2        ┆ 8      │
+        └──────────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.anonymize_tokenize(key, length))
+
+    def hash_preserving_format(self, key: str) -> Expr:
+        """
+        Replace the digits and ASCII letters by ones chosen by a keyed hash.
+
+        Digits are replaced by digits, and lowercase and uppercase letters by letters
+        of the same case, so the output has the format of the input (e.g. of an email
+        address or a phone number). Other characters are kept.
+
+        This is a hash and not an encryption: the outputs cannot be turned back into
+        the inputs, and different inputs can give the same output.
+
+        Parameters
+        ----------
+        key
+            The secret the hash is keyed with.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"phone": ["+1 415-555-0100", "+1 212-555-0199"]})
+        >>> df.select(
+        ...     pl.col("phone")
+        ...     .anonymize.hash_preserving_format("secret")
+        ...     .str.contains(r"^\\+\\d \\d{3}-\\d{3}-\\d{4}$")
+        ... )
+        shape: (2, 1)
+        ┌───────┐
+        │ phone │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ true  │
+        └───────┘
+        """
+        return wrap_expr(self._pyexpr.anonymize_hash_preserving_format(key))
+
+    def bucket(self, width: float) -> Expr:
+        """
+        Round the numbers down to a multiple of `width`.
+
+        This generalizes values into ranges, e.g. ages into decades. The output has the
+        data type of the input; buckets that do not fit in it are null.
+
+        Parameters
+        ----------
+        width
+            The width of the buckets; an integer for integer columns.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"age": [7, 23, 38, 40]})
+        >>> df.with_columns(decade=pl.col("age").anonymize.bucket(10))
+        shape: (4, 2)
+        ┌─────┬────────┐
+        │ age ┆ decade │
+        │ --- ┆ ---    │
+        │ i64 ┆ i64    │
+        ╞═════╪════════╡
+        │ 7   ┆ 0      │
+        │ 23  ┆ 20     │
+        │ 38  ┆ 30     │
+        │ 40  ┆ 40     │
+        └─────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.anonymize_bucket(width))
+
+    def truncate(self, length: int, *, fill: str | None = None) -> Expr:
+        """
+        Keep the first `length` characters of the strings, e.g. the area of zip codes.
+
+        Parameters
+        ----------
+        length
+            The number of characters to keep.
+        fill
+            A single character that replaces the other characters, so the strings keep
+            their length. If `None`, the other characters are removed.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"zip": ["94107", "10001"]})
+        >>> df.with_columns(
+        ...     area=pl.col("zip").anonymize.truncate(3),
+        ...     masked=pl.col("zip").anonymize.truncate(3, fill="*"),
+        ... )
+        shape: (2, 3)
+        ┌───────┬──────┬────────┐
+        │ zip   ┆ area ┆ masked │
+        │ ---   ┆ ---  ┆ ---    │
+        │ str   ┆ str  ┆ str    │
+        ╞═══════╪══════╪════════╡
+        │ 94107 ┆ 941  ┆ 941**  │
+        │ 10001 ┆ 100  ┆ 100**  │
+        └───────┴──────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.anonymize_truncate(length, fill))
@@ -3,14 +3,17 @@ from __future__ import annotations
 from collections.abc import Sequence
 from typing import TYPE_CHECKING, Callable
 
+import polars._reexport as pl
 from polars._utils.parse import parse_into_expression
 from polars._utils.wrap import wrap_expr
+from polars.datatypes import Array, Float64
 
 if TYPE_CHECKING:
     from datetime import date, datetime, time
 
-    from polars import Expr
+    from polars import Expr, Series
     from polars._typing import IntoExpr, IntoExprColumn
+    from polars.polars import PyExpr
 
 
 class ExprArrayNameSpace:
@@ -794,3 +797,314 @@ class ExprArrayNameSpace:
         """
         n = parse_into_expression(n)
         return wrap_expr(self._pyexpr.arr_shift(n))
+
+    def slice(self, offset: int, length: int | None = None) -> Expr:
+        """
+        Slice every sub-array.
+
+        As all sub-arrays have the same width, the result is an array with the width
+        of the slice.
+
+        Parameters
+        ----------
+        offset
+            Start index. Negative indexing is supported.
+        length
+            Length of the slice. If set to `None` (default), the slice is taken to the
+            end of the sub-array.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Array`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, 5, 6]]}, schema={"a": pl.Array(pl.Int64, 3)}
+        ... )
+        >>> df.with_columns(slice=pl.col("a").arr.slice(1))
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ slice         │
+        │ ---           ┆ ---           │
+        │ array[i64, 3] ┆ array[i64, 2] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 2, 3]     ┆ [2, 3]        │
+        │ [4, 5, 6]     ┆ [5, 6]        │
+        └───────────────┴───────────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_slice(offset, length))
+
+    def head(self, n: int = 5) -> Expr:
+        """
+        Slice the first `n` values of every sub-array.
+
+        Parameters
+        ----------
+        n
+            Number of values to return for each sub-array.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Array`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, 5, 6]]}, schema={"a": pl.Array(pl.Int64, 3)}
+        ... )
+        >>> df.with_columns(head=pl.col("a").arr.head(2))
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ head          │
+        │ ---           ┆ ---           │
+        │ array[i64, 3] ┆ array[i64, 2] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 2, 3]     ┆ [1, 2]        │
+        │ [4, 5, 6]     ┆ [4, 5]        │
+        └───────────────┴───────────────┘
+        """
+        return self.slice(0, n)
+
+    def tail(self, n: int = 5) -> Expr:
+        """
+        Slice the last `n` values of every sub-array.
+
+        Parameters
+        ----------
+        n
+            Number of values to return for each sub-array.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Array`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, 5, 6]]}, schema={"a": pl.Array(pl.Int64, 3)}
+        ... )
+        >>> df.with_columns(tail=pl.col("a").arr.tail(2))
+        shape: (2, 2)
+        ┌───────────────┬───────────────┐
+        │ a             ┆ tail          │
+        │ ---           ┆ ---           │
+        │ array[i64, 3] ┆ array[i64, 2] │
+        ╞═══════════════╪═══════════════╡
+        │ [1, 2, 3]     ┆ [2, 3]        │
+        │ [4, 5, 6]     ┆ [5, 6]        │
+        └───────────────┴───────────────┘
+        """
+        return self.slice(-n, n)
+
+    def gather(
+        self,
+        indices: Expr | Series | list[int] | list[list[int]],
+        *,
+        null_on_oob: bool = False,
+    ) -> Expr:
+        """
+        Take values from every sub-array by multiple indices.
+
+        The indices may be defined in a single column, or by sublists in another
+        column of dtype `List`.
+
+        Parameters
+        ----------
+        indices
+            Indices to return per sub-array.
+        null_on_oob
+            Behavior if an index is out of bounds:
+            True -> set as null
+            False -> raise an error
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List`, as the number of indices may differ
+            per row. Use :meth:`Expr.list.to_array` to convert it back to an array.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, 5, 6]]}, schema={"a": pl.Array(pl.Int64, 3)}
+        ... )
+        >>> df.with_columns(gather=pl.col("a").arr.gather([0, 2]))
+        shape: (2, 2)
+        ┌───────────────┬───────────┐
+        │ a             ┆ gather    │
+        │ ---           ┆ ---       │
+        │ array[i64, 3] ┆ list[i64] │
+        ╞═══════════════╪═══════════╡
+        │ [1, 2, 3]     ┆ [1, 3]    │
+        │ [4, 5, 6]     ┆ [4, 6]    │
+        └───────────────┴───────────┘
+        """
+        lists = wrap_expr(self._pyexpr.arr_to_list())
+        return lists.list.gather(indices, null_on_oob=null_on_oob)
+
+    def eval(self, expr: Expr, *, parallel: bool = False) -> Expr:
+        """
+        Run any polars expression against the sub-arrays' elements.
+
+        Parameters
+        ----------
+        expr
+            Expression to run. Note that you can select an element with `pl.first()`, or
+            `pl.col()`
+        parallel
+            Run all expression parallel. Don't activate this blindly.
+            Parallelism is worth it if there is enough work to do per thread.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List`, as `expr` may change the number of
+            elements. Use :meth:`Expr.list.to_array` to convert it back to an array.
+
+        See Also
+        --------
+        polars.Expr.list.eval : Run any polars expression against the lists' elements.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1, 2, 3], [4, 5, 6]]}, schema={"a": pl.Array(pl.Int64, 3)}
+        ... )
+        >>> df.with_columns(cum_sum=pl.col("a").arr.eval(pl.element().cum_sum()))
+        shape: (2, 2)
+        ┌───────────────┬────────────┐
+        │ a             ┆ cum_sum    │
+        │ ---           ┆ ---        │
+        │ array[i64, 3] ┆ list[i64]  │
+        ╞═══════════════╪════════════╡
+        │ [1, 2, 3]     ┆ [1, 3, 6]  │
+        │ [4, 5, 6]     ┆ [4, 9, 15] │
+        └───────────────┴────────────┘
+        """
+        lists = wrap_expr(self._pyexpr.arr_to_list())
+        return lists.list.eval(expr, parallel=parallel)
+
+    def dot(self, other: IntoExpr | Sequence[float]) -> Expr:
+        """
+        Compute the dot product of every sub-array with the sub-array of `other`.
+
+        Parameters
+        ----------
+        other
+            Array expression of the same width, or a single vector given as a
+            sequence of numbers, which is compared with every row.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float32` if both arrays hold
+            :class:`Float32` values, :class:`Float64` otherwise. Sub-arrays that are
+            null or contain a null give a null.
+
+        See Also
+        --------
+        cosine_similarity
+        l2_norm
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1.0, 2.0], [3.0, 4.0]], "b": [[1.0, 0.0], [0.0, 1.0]]},
+        ...     schema={"a": pl.Array(pl.Float64, 2), "b": pl.Array(pl.Float64, 2)},
+        ... )
+        >>> df.with_columns(dot=pl.col("a").arr.dot(pl.col("b")))
+        shape: (2, 3)
+        ┌───────────────┬───────────────┬─────┐
+        │ a             ┆ b             ┆ dot │
+        │ ---           ┆ ---           ┆ --- │
+        │ array[f64, 2] ┆ array[f64, 2] ┆ f64 │
+        ╞═══════════════╪═══════════════╪═════╡
+        │ [1.0, 2.0]    ┆ [1.0, 0.0]    ┆ 1.0 │
+        │ [3.0, 4.0]    ┆ [0.0, 1.0]    ┆ 4.0 │
+        └───────────────┴───────────────┴─────┘
+        """
+        other = _parse_vector(other)
+        return wrap_expr(self._pyexpr.arr_dot(other))
+
+    def cosine_similarity(self, other: IntoExpr | Sequence[float]) -> Expr:
+        """
+        Compute the cosine similarity of every sub-array with the sub-array of `other`.
+
+        The similarity of a zero vector is NaN.
+
+        Parameters
+        ----------
+        other
+            Array expression of the same width, or a single vector given as a
+            sequence of numbers, which is compared with every row.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float32` if both arrays hold
+            :class:`Float32` values, :class:`Float64` otherwise. Sub-arrays that are
+            null or contain a null give a null.
+
+        See Also
+        --------
+        dot
+        l2_norm
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1.0, 2.0], [3.0, 4.0]]}, schema={"a": pl.Array(pl.Float64, 2)}
+        ... )
+        >>> df.with_columns(cos=pl.col("a").arr.cosine_similarity([1.0, 1.0]))
+        shape: (2, 2)
+        ┌───────────────┬──────────┐
+        │ a             ┆ cos      │
+        │ ---           ┆ ---      │
+        │ array[f64, 2] ┆ f64      │
+        ╞═══════════════╪══════════╡
+        │ [1.0, 2.0]    ┆ 0.948683 │
+        │ [3.0, 4.0]    ┆ 0.989949 │
+        └───────────────┴──────────┘
+        """
+        other = _parse_vector(other)
+        return wrap_expr(self._pyexpr.arr_cosine_similarity(other))
+
+    def l2_norm(self) -> Expr:
+        """
+        Compute the Euclidean norm of every sub-array.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float32` if the array holds
+            :class:`Float32` values, :class:`Float64` otherwise.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [[1.0, 2.0], [3.0, 4.0]]}, schema={"a": pl.Array(pl.Float64, 2)}
+        ... )
+        >>> df.with_columns(norm=pl.col("a").arr.l2_norm())
+        shape: (2, 2)
+        ┌───────────────┬──────────┐
+        │ a             ┆ norm     │
+        │ ---           ┆ ---      │
+        │ array[f64, 2] ┆ f64      │
+        ╞═══════════════╪══════════╡
+        │ [1.0, 2.0]    ┆ 2.236068 │
+        │ [3.0, 4.0]    ┆ 5.0      │
+        └───────────────┴──────────┘
+        """
+        return wrap_expr(self._pyexpr.arr_l2_norm())
+
+
+def _parse_vector(other: IntoExpr | Sequence[float]) -> PyExpr:
+    """Parse a sequence of numbers into a single array, or else into an expression."""
+    if isinstance(other, Sequence) and not isinstance(other, str):
+        vector = list(other)
+        other = pl.Series([vector], dtype=Array(Float64, len(vector)))
+    return parse_into_expression(other)
@@ -10,11 +10,12 @@ from polars.datatypes import parse_into_dtype
 if TYPE_CHECKING:
     from polars import Expr
     from polars._typing import (
+        BinaryTransferEncoding,
         Endianness,
         IntoExpr,
+        IntoExprColumn,
         PolarsDataType,
         SizeUnit,
-        TransferEncoding,
     )
 
 
@@ -169,14 +170,17 @@ class ExprBinaryNameSpace:
         prefix = parse_into_expression(prefix, str_as_lit=True)
         return wrap_expr(self._pyexpr.bin_starts_with(prefix))
 
-    def decode(self, encoding: TransferEncoding, *, strict: bool = True) -> Expr:
+    def decode(
+        self, encoding: BinaryTransferEncoding, *, strict: bool = True
+    ) -> Expr:
         r"""
         Decode values using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            `'base64'`, which is decoded with or without padding.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -212,18 +216,24 @@ class ExprBinaryNameSpace:
             return wrap_expr(self._pyexpr.bin_hex_decode(strict))
         elif encoding == "base64":
             return wrap_expr(self._pyexpr.bin_base64_decode(strict))
+        elif encoding == "base64url":
+            return wrap_expr(self._pyexpr.bin_base64url_decode(strict))
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'base64url'},"
+                f" got {encoding!r}"
+            )
             raise ValueError(msg)
 
-    def encode(self, encoding: TransferEncoding) -> Expr:
+    def encode(self, encoding: BinaryTransferEncoding) -> Expr:
         r"""
         Encode a value using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            `'base64'`, which is encoded without padding.
 
         Returns
         -------
@@ -256,8 +266,13 @@ class ExprBinaryNameSpace:
             return wrap_expr(self._pyexpr.bin_hex_encode())
         elif encoding == "base64":
             return wrap_expr(self._pyexpr.bin_base64_encode())
+        elif encoding == "base64url":
+            return wrap_expr(self._pyexpr.bin_base64url_encode())
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'base64url'},"
+                f" got {encoding!r}"
+            )
             raise ValueError(msg)
 
     def size(self, unit: SizeUnit = "b") -> Expr:
@@ -297,6 +312,149 @@ class ExprBinaryNameSpace:
         sz = scale_bytes(sz, unit)
         return sz
 
+    def ip_to_string(self) -> Expr:
+        """
+        Format IP addresses in their binary form as text.
+
+        Values of 4 bytes are formatted as IPv4 and values of 16 bytes as IPv6
+        addresses; an error is raised for values of any other length.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        See Also
+        --------
+        polars.Expr.str.to_ip : Parse IP addresses into their bytes.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": [b"\x7f\x00\x00\x01", bytes(15) + b"\x01", None]})
+        >>> df.select(pl.col("ip").bin.ip_to_string())
+        shape: (3, 1)
+        ┌───────────┐
+        │ ip        │
+        │ ---       │
+        │ str       │
+        ╞═══════════╡
+        │ 127.0.0.1 │
+        │ ::1       │
+        │ null      │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_ip_to_string())
+
+    def ip_is_in_subnet(self, cidr: str) -> Expr:
+        """
+        Check if IP addresses in their binary form are in a subnet.
+
+        Parameters
+        ----------
+        cidr
+            The subnet in CIDR notation, eg: `"10.0.0.0/8"` or `"2001:db8::/32"`.
+            Host bits of the address are ignored and an address without a prefix
+            length selects that single address. IPv4 addresses are never in an IPv6
+            subnet and vice versa.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        See Also
+        --------
+        polars.Expr.str.to_ip : Parse IP addresses into their bytes.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["192.168.1.20", "192.168.2.20", "::1"]})
+        >>> df.with_columns(
+        ...     lan=pl.col("ip").str.to_ip().bin.ip_is_in_subnet("192.168.1.0/24")
+        ... )
+        shape: (3, 2)
+        ┌──────────────┬───────┐
+        │ ip           ┆ lan   │
+        │ ---          ┆ ---   │
+        │ str          ┆ bool  │
+        ╞══════════════╪═══════╡
+        │ 192.168.1.20 ┆ true  │
+        │ 192.168.2.20 ┆ false │
+        │ ::1          ┆ false │
+        └──────────────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.bin_ip_is_in_subnet(cidr))
+
+    def ipv4_to_integer(self) -> Expr:
+        """
+        Convert IPv4 addresses in their binary form to integers.
+
+        An error is raised for values that are not 4 bytes long.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        polars.Expr.str.to_ip : Parse IP addresses into their bytes.
+        polars.Expr.bin.ipv6_to_integer : Convert IP addresses to 128-bit integers.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["192.168.0.1", "10.0.0.1", None]})
+        >>> df.with_columns(int=pl.col("ip").str.to_ip().bin.ipv4_to_integer())
+        shape: (3, 2)
+        ┌─────────────┬────────────┐
+        │ ip          ┆ int        │
+        │ ---         ┆ ---        │
+        │ str         ┆ u32        │
+        ╞═════════════╪════════════╡
+        │ 192.168.0.1 ┆ 3232235521 │
+        │ 10.0.0.1    ┆ 167772161  │
+        │ null        ┆ null       │
+        └─────────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_ipv4_to_integer())
+
+    def ipv6_to_integer(self) -> Expr:
+        """
+        Convert IP addresses in their binary form to 128-bit integers.
+
+        Polars has no 128-bit unsigned integer data type, so the integer is stored
+        as a struct of its `high` and `low` 64 bits. IPv4 addresses are mapped to
+        IPv6 (eg: `"10.0.0.1"` to `"::ffff:10.0.0.1"`) and an error is raised for
+        values that are neither 4 nor 16 bytes long.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with fields `high` and `low` of
+            data type :class:`UInt64`.
+
+        See Also
+        --------
+        polars.Expr.str.to_ip : Parse IP addresses into their bytes.
+        polars.Expr.bin.ipv4_to_integer : Convert IPv4 addresses to integers.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["2001:db8::1", "10.0.0.1", None]})
+        >>> df.with_columns(int=pl.col("ip").str.to_ip().bin.ipv6_to_integer())
+        shape: (3, 2)
+        ┌─────────────┬─────────────────────────┐
+        │ ip          ┆ int                     │
+        │ ---         ┆ ---                     │
+        │ str         ┆ struct[2]               │
+        ╞═════════════╪═════════════════════════╡
+        │ 2001:db8::1 ┆ {2306139568115548160,1} │
+        │ 10.0.0.1    ┆ {0,281470849515521}     │
+        │ null        ┆ null                    │
+        └─────────────┴─────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_ipv6_to_integer())
+
     def reinterpret(
         self, *, dtype: PolarsDataType, endianness: Endianness = "little"
     ) -> Expr:
@@ -338,3 +496,273 @@ class ExprBinaryNameSpace:
         dtype = parse_into_dtype(dtype)
 
         return wrap_expr(self._pyexpr.from_buffer(dtype, endianness))
+
+    def uuid_to_string(self) -> Expr:
+        """
+        Format 16-byte values as UUIDs in the lowercase hyphenated form.
+
+        Raises an error if any value is not exactly 16 bytes long. Use
+        :meth:`uuid.to_string` for columns of data type :class:`Uuid`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        See Also
+        --------
+        polars.Expr.str.to_uuid : Parse UUIDs from their textual form.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"id": [bytes(range(16)), None]})
+        >>> df.select(pl.col("id").bin.uuid_to_string())
+        shape: (2, 1)
+        ┌──────────────────────────────────────┐
+        │ id                                   │
+        │ ---                                  │
+        │ str                                  │
+        ╞══════════════════════════════════════╡
+        │ 00010203-0405-0607-0809-0a0b0c0d0e0f │
+        │ null                                 │
+        └──────────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_uuid_to_string())
+
+    def slice(
+        self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
+    ) -> Expr:
+        r"""
+        Extract a slice of bytes from each binary value.
+
+        Parameters
+        ----------
+        offset
+            Start index in bytes. Negative indexing is supported.
+        length
+            Length of the slice in bytes. If set to `None` (default), the slice is
+            taken to the end of the value.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        Notes
+        -----
+        Slicing past the end of a value yields an empty value, like slicing Python
+        `bytes`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"data": [b"\x01\x02\x03\x04", b"\x05", None]})
+        >>> df.with_columns(
+        ...     slice=pl.col("data").bin.slice(1, 2),
+        ...     last_2=pl.col("data").bin.slice(-2),
+        ... )
+        shape: (3, 3)
+        ┌─────────────────────┬─────────────┬─────────────┐
+        │ data                ┆ slice       ┆ last_2      │
+        │ ---                 ┆ ---         ┆ ---         │
+        │ binary              ┆ binary      ┆ binary      │
+        ╞═════════════════════╪═════════════╪═════════════╡
+        │ b"\x01\x02\x03\x04" ┆ b"\x02\x03" ┆ b"\x03\x04" │
+        │ b"\x05"             ┆ b""         ┆ b"\x05"     │
+        │ null                ┆ null        ┆ null        │
+        └─────────────────────┴─────────────┴─────────────┘
+        """
+        offset = parse_into_expression(offset)
+        length = parse_into_expression(length)
+        return wrap_expr(self._pyexpr.bin_slice(offset, length))
+
+    def concat(self, other: IntoExpr) -> Expr:
+        r"""
+        Append the binary values of `other` to the binary values.
+
+        The result is null if either value is null.
+
+        Parameters
+        ----------
+        other
+            The binary values to append; bytes are parsed as a literal.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": [b"\x00", b"\x01\x02", None], "b": [b"\xff", b"", b"\x03"]}
+        ... )
+        >>> df.with_columns(ab=pl.col("a").bin.concat(pl.col("b")))
+        shape: (3, 3)
+        ┌─────────────┬─────────┬─────────────┐
+        │ a           ┆ b       ┆ ab          │
+        │ ---         ┆ ---     ┆ ---         │
+        │ binary      ┆ binary  ┆ binary      │
+        ╞═════════════╪═════════╪═════════════╡
+        │ b"\x00"     ┆ b"\xff" ┆ b"\x00\xff" │
+        │ b"\x01\x02" ┆ b""     ┆ b"\x01\x02" │
+        │ null        ┆ b"\x03" ┆ null        │
+        └─────────────┴─────────┴─────────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.bin_concat(other))
+
+    def bitwise_and(self, other: IntoExpr) -> Expr:
+        r"""
+        Take the bitwise AND of the bytes of the binary values and `other`.
+
+        Both values must have the same size; a mismatch raises an error. The result
+        is null if either value is null.
+
+        Parameters
+        ----------
+        other
+            The binary values to combine with; bytes are parsed as a literal.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        See Also
+        --------
+        bitwise_or
+        bitwise_xor
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"data": [b"\x0f\xf0", b"\xf0\x0f"]})
+        >>> mask = b"\xf0\xf0"
+        >>> df.select(
+        ...     pl.col("data").bin.bitwise_and(mask).alias("and"),
+        ...     pl.col("data").bin.bitwise_or(mask).alias("or"),
+        ...     pl.col("data").bin.bitwise_xor(mask).alias("xor"),
+        ... )
+        shape: (2, 3)
+        ┌─────────────┬─────────────┬─────────────┐
+        │ and         ┆ or          ┆ xor         │
+        │ ---         ┆ ---         ┆ ---         │
+        │ binary      ┆ binary      ┆ binary      │
+        ╞═════════════╪═════════════╪═════════════╡
+        │ b"\x00\xf0" ┆ b"\xff\xf0" ┆ b"\xff\x00" │
+        │ b"\xf0\x00" ┆ b"\xf0\xff" ┆ b"\x00\xff" │
+        └─────────────┴─────────────┴─────────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.bin_bitwise_and(other))
+
+    def bitwise_or(self, other: IntoExpr) -> Expr:
+        r"""
+        Take the bitwise OR of the bytes of the binary values and `other`.
+
+        Both values must have the same size; a mismatch raises an error. The result
+        is null if either value is null.
+
+        Parameters
+        ----------
+        other
+            The binary values to combine with; bytes are parsed as a literal.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        See Also
+        --------
+        bitwise_and : Includes an example of all bitwise operations.
+        bitwise_xor
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.bin_bitwise_or(other))
+
+    def bitwise_xor(self, other: IntoExpr) -> Expr:
+        r"""
+        Take the bitwise XOR of the bytes of the binary values and `other`.
+
+        Both values must have the same size; a mismatch raises an error. The result
+        is null if either value is null.
+
+        Parameters
+        ----------
+        other
+            The binary values to combine with; bytes are parsed as a literal.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        See Also
+        --------
+        bitwise_and : Includes an example of all bitwise operations.
+        bitwise_or
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.bin_bitwise_xor(other))
+
+    def crc32(self) -> Expr:
+        r"""
+        Compute the CRC-32 checksum of the binary values.
+
+        The checksum is the same as the one of :func:`zlib.crc32`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"data": [b"hello", b"polars", b"", None]})
+        >>> df.with_columns(crc32=pl.col("data").bin.crc32())
+        shape: (4, 2)
+        ┌───────────┬────────────┐
+        │ data      ┆ crc32      │
+        │ ---       ┆ ---        │
+        │ binary    ┆ u32        │
+        ╞═══════════╪════════════╡
+        │ b"hello"  ┆ 907060870  │
+        │ b"polars" ┆ 1298308768 │
+        │ b""       ┆ 0          │
+        │ null      ┆ null       │
+        └───────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_crc32())
+
+    def xxh3(self, seed: int = 0) -> Expr:
+        r"""
+        Compute the 64-bit XXH3 hash of the binary values.
+
+        Unlike :meth:`Expr.hash`, the hash doesn't depend on the Polars version, so it
+        can be stored and compared with hashes computed by other XXH3 implementations.
+
+        Parameters
+        ----------
+        seed
+            The seed of the hash.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt64`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"data": [b"", None]})
+        >>> df.select(pl.col("data").bin.xxh3())
+        shape: (2, 1)
+        ┌─────────────────────┐
+        │ data                │
+        │ ---                 │
+        │ u64                 │
+        ╞═════════════════════╡
+        │ 3244421341483603138 │
+        │ null                │
+        └─────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_xxh3(seed))
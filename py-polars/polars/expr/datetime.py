@@ -10,12 +10,13 @@ from polars._utils.deprecation import deprecate_function, deprecate_nonkeyword_a
 from polars._utils.parse import parse_into_expression, parse_into_list_of_expressions
 from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
+from polars.business import _business_day_args
 from polars.datatypes import DTYPE_TEMPORAL_UNITS, Date, Int32
 
 if TYPE_CHECKING:
     from collections.abc import Iterable
 
-    from polars import Expr
+    from polars import BusinessCalendar, Expr
     from polars._typing import (
         Ambiguous,
         EpochTimeUnit,
@@ -24,8 +25,19 @@ if TYPE_CHECKING:
         NonExistent,
         Roll,
         TimeUnit,
+        Weekday,
     )
 
+_WEEKDAYS = (
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+)
+
 
 class ExprDateTimeNameSpace:
     """Namespace for datetime related expressions."""
@@ -43,6 +55,7 @@ class ExprDateTimeNameSpace:
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        calendar: BusinessCalendar | None = None,
     ) -> Expr:
         """
         Offset by `n` business days.
@@ -79,6 +92,9 @@ class ExprDateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            A :class:`BusinessCalendar` to take the business days from, instead of
+            passing `week_mask` and `holidays`.
 
         Returns
         -------
@@ -150,22 +166,93 @@ class ExprDateTimeNameSpace:
         └────────────┴─────────────────┘
         """
         n_pyexpr = parse_into_expression(n)
-        unix_epoch = dt.date(1970, 1, 1)
+        week_mask, holidays_int = _business_day_args(week_mask, holidays, calendar)
         return wrap_expr(
-            self._pyexpr.dt_add_business_days(
-                n_pyexpr,
-                week_mask,
-                [(holiday - unix_epoch).days for holiday in holidays],
-                roll,
-            )
+            self._pyexpr.dt_add_business_days(n_pyexpr, week_mask, holidays_int, roll)
         )
 
-    def truncate(self, every: str | dt.timedelta | Expr) -> Expr:
+    @unstable()
+    def business_day_count(
+        self,
+        end: dt.date | IntoExprColumn,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+        calendar: BusinessCalendar | None = None,
+    ) -> Expr:
+        """
+        Count the number of business days until `end` (not including `end`).
+
+        This is the same as :func:`polars.business_day_count` with the underlying
+        dates as `start`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        end
+            End dates.
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude from the count.
+        calendar
+            A :class:`BusinessCalendar` to take the business days from, instead of
+            passing `week_mask` and `holidays`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "start": [date(2020, 1, 1), date(2020, 1, 2)],
+        ...         "end": [date(2020, 1, 2), date(2020, 1, 10)],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     business_day_count=pl.col("start").dt.business_day_count("end"),
+        ... )
+        shape: (2, 3)
+        ┌────────────┬────────────┬────────────────────┐
+        │ start      ┆ end        ┆ business_day_count │
+        │ ---        ┆ ---        ┆ ---                │
+        │ date       ┆ date       ┆ i32                │
+        ╞════════════╪════════════╪════════════════════╡
+        │ 2020-01-01 ┆ 2020-01-02 ┆ 1                  │
+        │ 2020-01-02 ┆ 2020-01-10 ┆ 6                  │
+        └────────────┴────────────┴────────────────────┘
+        """
+        return F.business_day_count(
+            wrap_expr(self._pyexpr),
+            end,
+            week_mask=week_mask,
+            holidays=holidays,
+            calendar=calendar,
+        )
+
+    def truncate(
+        self,
+        every: str | dt.timedelta | Expr,
+        *,
+        week_start: Weekday = "monday",
+        ambiguous: Ambiguous | Expr | None = None,
+        non_existent: NonExistent | None = None,
+    ) -> Expr:
         """
         Divide the date/datetime range into buckets.
 
         Each date/datetime is mapped to the start of its bucket using the corresponding
-        local datetime. Note that weekly buckets start on Monday.
+        local datetime. Weekly buckets start on `week_start`, which is Monday by
+        default, so `"1w"` truncates to the start of the ISO week.
         Ambiguous results are localised using the DST offset of the original timestamp -
         for example, truncating `'2022-11-06 01:30:00 CST'` by `'1h'` results in
         `'2022-11-06 01:00:00 CST'`, whereas truncating `'2022-11-06 01:30:00 CDT'` by
@@ -175,6 +262,27 @@ class ExprDateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        week_start
+            The day of the week on which weekly buckets start. Only applies when
+            `every` is a number of weeks. Buckets spanning several weeks are aligned
+            to the first `week_start` day on or after Monday 1970-01-05.
+        ambiguous
+            Determine how to deal with ambiguous results in time zones with daylight
+            saving time. If neither `ambiguous` nor `non_existent` is set, ambiguous
+            results are localised using the DST offset of the original timestamp.
+            Otherwise, the datetime is truncated in wall-clock time and the result is
+            localised with:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: set to null
+        non_existent
+            Determine how to deal with non-existent results, for example a bucket which
+            starts in the gap of a DST transition:
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Notes
         -----
@@ -279,13 +387,69 @@ class ExprDateTimeNameSpace:
         │ 2001-01-01 00:50:00 ┆ 2001-01-01 00:30:00 │
         │ 2001-01-01 01:00:00 ┆ 2001-01-01 01:00:00 │
         └─────────────────────┴─────────────────────┘
+
+        Use `week_start` for weeks which do not start on Monday:
+
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 1, 6), date(2024, 1, 7), date(2024, 1, 8)]}
+        ... )
+        >>> df.with_columns(
+        ...     iso_week=pl.col("date").dt.truncate("1w"),
+        ...     sunday_week=pl.col("date").dt.truncate("1w", week_start="sunday"),
+        ... )
+        shape: (3, 3)
+        ┌────────────┬────────────┬─────────────┐
+        │ date       ┆ iso_week   ┆ sunday_week │
+        │ ---        ┆ ---        ┆ ---         │
+        │ date       ┆ date       ┆ date        │
+        ╞════════════╪════════════╪═════════════╡
+        │ 2024-01-06 ┆ 2024-01-01 ┆ 2023-12-31  │
+        │ 2024-01-07 ┆ 2024-01-01 ┆ 2024-01-07  │
+        │ 2024-01-08 ┆ 2024-01-08 ┆ 2024-01-07  │
+        └────────────┴────────────┴─────────────┘
+
+        Use `ambiguous` and `non_existent` to control how buckets which fall on a DST
+        transition are localised. Here, the start of the day does not exist in
+        `'America/Sao_Paulo'`, because clocks jumped from midnight to 1am:
+
+        >>> df = pl.DataFrame(
+        ...     {"datetime": [datetime(2018, 11, 4, 12), datetime(2018, 11, 5, 12)]}
+        ... ).with_columns(pl.col("datetime").dt.replace_time_zone("America/Sao_Paulo"))
+        >>> df.select(
+        ...     pl.col("datetime").dt.truncate("1d", non_existent="null")
+        ... ).to_series()
+        shape: (2,)
+        Series: 'datetime' [datetime[μs, America/Sao_Paulo]]
+        [
+                null
+                2018-11-05 00:00:00 -02
+        ]
         """
         if isinstance(every, dt.timedelta):
             every = parse_as_duration_string(every)
         every = parse_into_expression(every, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_truncate(every))
+        if week_start not in _WEEKDAYS:
+            msg = f"`week_start` must be one of {_WEEKDAYS}, got {week_start!r}"
+            raise ValueError(msg)
+        week_start_idx = _WEEKDAYS.index(week_start)
+        if ambiguous is None and non_existent is None:
+            return wrap_expr(self._pyexpr.dt_truncate(every, week_start_idx))
+        if not isinstance(ambiguous, pl.Expr):
+            ambiguous = F.lit(ambiguous or "raise")
+        return wrap_expr(
+            self._pyexpr.dt_truncate_with_policy(
+                every, week_start_idx, ambiguous._pyexpr, non_existent or "raise"
+            )
+        )
 
-    def round(self, every: str | dt.timedelta | IntoExprColumn) -> Expr:
+    def round(
+        self,
+        every: str | dt.timedelta | IntoExprColumn,
+        *,
+        ambiguous: Ambiguous | Expr | None = None,
+        non_existent: NonExistent | None = None,
+    ) -> Expr:
         """
         Divide the date/datetime range into buckets.
 
@@ -304,6 +468,23 @@ class ExprDateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        ambiguous
+            Determine how to deal with ambiguous results in time zones with daylight
+            saving time. If neither `ambiguous` nor `non_existent` is set, ambiguous
+            results are localised using the DST offset of the original timestamp.
+            Otherwise, the datetime is rounded in wall-clock time and the result is
+            localised with:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: set to null
+        non_existent
+            Determine how to deal with non-existent results, for example a bucket which
+            starts in the gap of a DST transition:
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Returns
         -------
@@ -388,7 +569,15 @@ class ExprDateTimeNameSpace:
         if isinstance(every, dt.timedelta):
             every = parse_as_duration_string(every)
         every = parse_into_expression(every, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_round(every))
+        if ambiguous is None and non_existent is None:
+            return wrap_expr(self._pyexpr.dt_round(every))
+        if not isinstance(ambiguous, pl.Expr):
+            ambiguous = F.lit(ambiguous or "raise")
+        return wrap_expr(
+            self._pyexpr.dt_round_with_policy(
+                every, ambiguous._pyexpr, non_existent or "raise"
+            )
+        )
 
     def replace(
         self,
@@ -580,7 +769,8 @@ class ExprDateTimeNameSpace:
         * Duration dtype expressions cannot be formatted with `strftime`. Instead,
           only "iso" and "polars" are supported as format strings. The "iso" format
           string results in ISO8601 duration string output, and "polars" results
-          in the same form seen in the frame `repr`.
+          in the same form seen in the frame `repr`. Both forms can be parsed
+          back into a Duration with :meth:`Expr.str.to_duration`.
 
         Examples
         --------
@@ -897,6 +1087,7 @@ class ExprDateTimeNameSpace:
         *,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
+        calendar: BusinessCalendar | None = None,
     ) -> Expr:
         """
         Determine whether each day lands on a business day.
@@ -924,6 +1115,9 @@ class ExprDateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `is_business_day`.
+        calendar
+            A :class:`BusinessCalendar` to take the business days from, instead of
+            passing `week_mask` and `holidays`.
 
         Returns
         -------
@@ -978,13 +1172,8 @@ class ExprDateTimeNameSpace:
         │ 2020-01-05 ┆ false           │
         └────────────┴─────────────────┘
         """
-        unix_epoch = dt.date(1970, 1, 1)
-        return wrap_expr(
-            self._pyexpr.dt_is_business_day(
-                week_mask,
-                [(holiday - unix_epoch).days for holiday in holidays],
-            )
-        )
+        week_mask, holidays_int = _business_day_args(week_mask, holidays, calendar)
+        return wrap_expr(self._pyexpr.dt_is_business_day(week_mask, holidays_int))
 
     def is_leap_year(self) -> Expr:
         """
@@ -1057,6 +1246,87 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_iso_year())
 
+    def iso_week(self) -> Expr:
+        """
+        Extract the ISO week number from the underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the week number from 1 to 53 in the ISO week date system, in which
+        weeks start on Monday and the first week of the year is the week containing
+        its first Thursday. Together with :meth:`iso_year` and :meth:`iso_weekday`
+        this gives the ISO week date. Same as :meth:`week`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        See Also
+        --------
+        iso_year
+        iso_weekday
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(1977, 1, 1), date(1978, 1, 1), date(1979, 1, 1)]}
+        ... )
+        >>> df.with_columns(
+        ...     pl.col("date").dt.iso_year().alias("iso_year"),
+        ...     pl.col("date").dt.iso_week().alias("iso_week"),
+        ...     pl.col("date").dt.iso_weekday().alias("iso_weekday"),
+        ... )
+        shape: (3, 4)
+        ┌────────────┬──────────┬──────────┬─────────────┐
+        │ date       ┆ iso_year ┆ iso_week ┆ iso_weekday │
+        │ ---        ┆ ---      ┆ ---      ┆ ---         │
+        │ date       ┆ i32      ┆ i8       ┆ i8          │
+        ╞════════════╪══════════╪══════════╪═════════════╡
+        │ 1977-01-01 ┆ 1976     ┆ 53       ┆ 6           │
+        │ 1978-01-01 ┆ 1977     ┆ 52       ┆ 7           │
+        │ 1979-01-01 ┆ 1979     ┆ 1        ┆ 1           │
+        └────────────┴──────────┴──────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_week())
+
+    def iso_weekday(self) -> Expr:
+        """
+        Extract the ISO weekday number from the underlying Date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the day of the week in the ISO week date system, where Monday is 1
+        and Sunday is 7. Same as :meth:`weekday`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        See Also
+        --------
+        iso_year
+        iso_week
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"date": [date(2024, 1, 6), date(2024, 1, 8)]})
+        >>> df.with_columns(pl.col("date").dt.iso_weekday().alias("iso_weekday"))
+        shape: (2, 2)
+        ┌────────────┬─────────────┐
+        │ date       ┆ iso_weekday │
+        │ ---        ┆ ---         │
+        │ date       ┆ i8          │
+        ╞════════════╪═════════════╡
+        │ 2024-01-06 ┆ 6           │
+        │ 2024-01-08 ┆ 1           │
+        └────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_weekday())
+
     def quarter(self) -> Expr:
         """
         Extract quarter from underlying Date representation.
@@ -2304,7 +2574,13 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_total_nanoseconds())
 
-    def offset_by(self, by: str | Expr) -> Expr:
+    def offset_by(
+        self,
+        by: str | Expr,
+        *,
+        ambiguous: Ambiguous | Expr | None = None,
+        non_existent: NonExistent | None = None,
+    ) -> Expr:
         """
         Offset this date by a relative time offset.
 
@@ -2332,6 +2608,24 @@ class ExprDateTimeNameSpace:
             By "calendar day", we mean the corresponding time on the next day (which may
             not be 24 hours, due to daylight savings). Similarly for "calendar week",
             "calendar month", "calendar quarter", and "calendar year".
+        ambiguous
+            Determine how to deal with results which are ambiguous in time zones with
+            daylight saving time, after applying the calendar part (months, weeks and
+            days) of the offset:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: set to null
+
+            The fixed part of the offset (hours and smaller units) is added afterwards
+            in absolute time.
+        non_existent
+            Determine how to deal with results which are non-existent after applying
+            the calendar part of the offset:
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Returns
         -------
@@ -2385,9 +2679,36 @@ class ExprDateTimeNameSpace:
         │ 2004-01-01 00:00:00 ┆ null   ┆ null                │
         │ 2005-01-01 00:00:00 ┆ 1y     ┆ 2006-01-01 00:00:00 │
         └─────────────────────┴────────┴─────────────────────┘
+
+        Offsetting by calendar days can land on a time which is repeated when clocks
+        are turned back. Use `ambiguous` to pick one:
+
+        >>> df = pl.DataFrame({"ts": [datetime(2022, 11, 5, 1, 30)]}).with_columns(
+        ...     pl.col("ts").dt.replace_time_zone("America/Chicago")
+        ... )
+        >>> df.select(
+        ...     earliest=pl.col("ts").dt.offset_by("1d", ambiguous="earliest"),
+        ...     latest=pl.col("ts").dt.offset_by("1d", ambiguous="latest"),
+        ... )
+        shape: (1, 2)
+        ┌───────────────────────────────┬───────────────────────────────┐
+        │ earliest                      ┆ latest                        │
+        │ ---                           ┆ ---                           │
+        │ datetime[μs, America/Chicago] ┆ datetime[μs, America/Chicago] │
+        ╞═══════════════════════════════╪═══════════════════════════════╡
+        │ 2022-11-06 01:30:00 CDT       ┆ 2022-11-06 01:30:00 CST       │
+        └───────────────────────────────┴───────────────────────────────┘
         """
         by = parse_into_expression(by, str_as_lit=True)
-        return wrap_expr(self._pyexpr.dt_offset_by(by))
+        if ambiguous is None and non_existent is None:
+            return wrap_expr(self._pyexpr.dt_offset_by(by))
+        if not isinstance(ambiguous, pl.Expr):
+            ambiguous = F.lit(ambiguous or "raise")
+        return wrap_expr(
+            self._pyexpr.dt_offset_by_with_policy(
+                by, ambiguous._pyexpr, non_existent or "raise"
+            )
+        )
 
     def month_start(self) -> Expr:
         """
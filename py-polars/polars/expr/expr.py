@@ -46,6 +46,7 @@ from polars.datatypes import Int64, is_polars_dtype, parse_into_dtype
 from polars.dependencies import _check_for_numpy
 from polars.dependencies import numpy as np
 from polars.exceptions import CustomUFuncWarning, PolarsInefficientMapWarning
+from polars.expr.anonymize import ExprAnonymizeNameSpace
 from polars.expr.array import ExprArrayNameSpace
 from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
@@ -55,6 +56,7 @@ from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
 from polars.expr.string import ExprStringNameSpace
 from polars.expr.struct import ExprStructNameSpace
+from polars.expr.uuid import ExprUuidNameSpace
 from polars.meta import thread_pool_size
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -70,16 +72,20 @@ if TYPE_CHECKING:
     from polars import DataFrame, LazyFrame, Series
     from polars._typing import (
         ClosedInterval,
+        DecimalOverflow,
         FillNullStrategy,
+        HashAlgorithm,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
         MapElementsStrategy,
+        NanPosition,
         NullBehavior,
         NumericLiteral,
         PolarsDataType,
         RankMethod,
         RollingInterpolationMethod,
+        RollingRankMethod,
         SchemaDict,
         SearchSortedSide,
         SerializationFormat,
@@ -110,6 +116,7 @@ class Expr:
 
     _pyexpr: PyExpr = None
     _accessors: ClassVar[set[str]] = {
+        "anonymize",
         "arr",
         "cat",
         "dt",
@@ -119,6 +126,7 @@ class Expr:
         "str",
         "bin",
         "struct",
+        "uuid",
     }
 
     @classmethod
@@ -1438,7 +1446,7 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.cum_sum(reverse))
 
-    def cum_prod(self, *, reverse: bool = False) -> Expr:
+    def cum_prod(self, *, reverse: bool = False, log_domain: bool = False) -> Expr:
         """
         Get an array with the cumulative product computed at every element.
 
@@ -1446,6 +1454,13 @@ class Expr:
         ----------
         reverse
             Reverse the operation.
+        log_domain
+            Compute the product as the exponent of the cumulative sum of the
+            logarithms of the absolute values, tracking the sign separately. The
+            rounding error of the sum doesn't grow with the length of the column as it
+            does for repeated multiplication, and an intermediate product that
+            underflows doesn't make all further products zero. The output is a float
+            column, so products of integers are no longer exact.
 
         Notes
         -----
@@ -1470,8 +1485,24 @@ class Expr:
         │ 3   ┆ 6        ┆ 12               │
         │ 4   ┆ 24       ┆ 4                │
         └─────┴──────────┴──────────────────┘
-        """
-        return self._from_pyexpr(self._pyexpr.cum_prod(reverse))
+
+        Compute the product in the log domain:
+
+        >>> df = pl.DataFrame({"a": [-1.5, 2.0, 4.0, -0.5]})
+        >>> df.with_columns(pl.col("a").cum_prod(log_domain=True).alias("cum_prod"))
+        shape: (4, 2)
+        ┌──────┬──────────┐
+        │ a    ┆ cum_prod │
+        │ ---  ┆ ---      │
+        │ f64  ┆ f64      │
+        ╞══════╪══════════╡
+        │ -1.5 ┆ -1.5     │
+        │ 2.0  ┆ -3.0     │
+        │ 4.0  ┆ -12.0    │
+        │ -0.5 ┆ 6.0      │
+        └──────┴──────────┘
+        """
+        return self._from_pyexpr(self._pyexpr.cum_prod(reverse, log_domain))
 
     def cum_min(self, *, reverse: bool = False) -> Expr:
         """
@@ -1793,7 +1824,13 @@ class Expr:
         dtype = parse_into_dtype(dtype)
         return self._from_pyexpr(self._pyexpr.cast(dtype, strict, wrap_numerical))
 
-    def sort(self, *, descending: bool = False, nulls_last: bool = False) -> Expr:
+    def sort(
+        self,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        nan_position: NanPosition = "largest",
+    ) -> Expr:
         """
         Sort this column.
 
@@ -1806,6 +1843,10 @@ class Expr:
             Sort in descending order.
         nulls_last
             Place null values last.
+        nan_position : {'largest', 'first', 'last'}
+            Where to place NaN values of a float column. By default NaN is treated
+            as larger than every number. Use `'first'` or `'last'` to place NaN values
+            before or after all other non-null values, regardless of `descending`.
 
         Examples
         --------
@@ -1870,7 +1911,9 @@ class Expr:
         │ one   ┆ [1, 2, 98] │
         └───────┴────────────┘
         """
-        return self._from_pyexpr(self._pyexpr.sort_with(descending, nulls_last))
+        return self._from_pyexpr(
+            self._pyexpr.sort_with(descending, nulls_last, nan_position)
+        )
 
     def top_k(self, k: int | IntoExprColumn = 5) -> Expr:
         r"""
@@ -2216,7 +2259,13 @@ class Expr:
         reverse = extend_bool(reverse, len(by), "reverse", "by")
         return self._from_pyexpr(self._pyexpr.bottom_k_by(by, k=k, reverse=reverse))
 
-    def arg_sort(self, *, descending: bool = False, nulls_last: bool = False) -> Expr:
+    def arg_sort(
+        self,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        nan_position: NanPosition = "largest",
+    ) -> Expr:
         """
         Get the index values that would sort this column.
 
@@ -2226,6 +2275,10 @@ class Expr:
             Sort in descending (descending) order.
         nulls_last
             Place null values last instead of first.
+        nan_position : {'largest', 'first', 'last'}
+            Where to place NaN values of a float column. By default NaN is treated
+            as larger than every number. Use `'first'` or `'last'` to place NaN values
+            before or after all other non-null values, regardless of `descending`.
 
         Returns
         -------
@@ -2271,7 +2324,9 @@ class Expr:
         │ 3   │
         └─────┘
         """
-        return self._from_pyexpr(self._pyexpr.arg_sort(descending, nulls_last))
+        return self._from_pyexpr(
+            self._pyexpr.arg_sort(descending, nulls_last, nan_position)
+        )
 
     def arg_max(self) -> Expr:
         """
@@ -3873,8 +3928,10 @@ class Expr:
         breaks: Sequence[float],
         *,
         labels: Sequence[str] | None = None,
+        label_format: str | None = None,
         left_closed: bool = False,
         include_breaks: bool = False,
+        as_enum: bool = False,
     ) -> Expr:
         """
         Bin continuous values into discrete categories.
@@ -3890,18 +3947,27 @@ class Expr:
         labels
             Names of the categories. The number of labels must be equal to the number
             of cut points plus one.
+        label_format
+            Format of the category names, in which `{lower}` and `{upper}` are
+            replaced by the bounds of each bin, such as `"{lower} - {upper}"`. By
+            default, the categories are intervals such as `"(1, 2]"`. Cannot be
+            combined with `labels`.
         left_closed
             Set the intervals to be left-closed instead of right-closed.
         include_breaks
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        as_enum
+            Return the categories as an :class:`Enum` ordered from the lowest to the
+            highest bin, so they can be compared and sorted in bin order.
 
         Returns
         -------
         Expr
-            Expression of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise an expression of data type :class:`Struct`.
+            Expression of data type :class:`Categorical` (or :class:`Enum` if
+            `as_enum` is set) if `include_breaks` is set to `False` (default),
+            otherwise an expression of data type :class:`Struct`.
 
         See Also
         --------
@@ -3945,9 +4011,31 @@ class Expr:
         │ 1   ┆ 1.0        ┆ (-1, 1]    │
         │ 2   ┆ inf        ┆ (1, inf]   │
         └─────┴────────────┴────────────┘
+
+        Name the categories with a format.
+
+        >>> df.with_columns(
+        ...     pl.col("foo")
+        ...     .cut([-1, 1], label_format="{lower} to {upper}")
+        ...     .alias("cut")
+        ... )
+        shape: (5, 2)
+        ┌─────┬────────────┐
+        │ foo ┆ cut        │
+        │ --- ┆ ---        │
+        │ i64 ┆ cat        │
+        ╞═════╪════════════╡
+        │ -2  ┆ -inf to -1 │
+        │ -1  ┆ -inf to -1 │
+        │ 0   ┆ -1 to 1    │
+        │ 1   ┆ -1 to 1    │
+        │ 2   ┆ 1 to inf   │
+        └─────┴────────────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.cut(breaks, labels, left_closed, include_breaks)
+            self._pyexpr.cut(
+                breaks, labels, label_format, left_closed, include_breaks, as_enum
+            )
         )
 
     @unstable()
@@ -3956,9 +4044,11 @@ class Expr:
         quantiles: Sequence[float] | int,
         *,
         labels: Sequence[str] | None = None,
+        label_format: str | None = None,
         left_closed: bool = False,
         allow_duplicates: bool = False,
         include_breaks: bool = False,
+        as_enum: bool = False,
     ) -> Expr:
         """
         Bin continuous values into discrete categories based on their quantiles.
@@ -3975,6 +4065,11 @@ class Expr:
         labels
             Names of the categories. The number of labels must be equal to the number
             of categories.
+        label_format
+            Format of the category names, in which `{lower}` and `{upper}` are
+            replaced by the bounds of each bin, such as `"{lower} - {upper}"`. By
+            default, the categories are intervals such as `"(1, 2]"`. Cannot be
+            combined with `labels`.
         left_closed
             Set the intervals to be left-closed instead of right-closed.
         allow_duplicates
@@ -3985,12 +4080,16 @@ class Expr:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        as_enum
+            Return the categories as an :class:`Enum` ordered from the lowest to the
+            highest bin. This requires `labels`, as the bins depend on the data.
 
         Returns
         -------
         Expr
-            Expression of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise an expression of data type :class:`Struct`.
+            Expression of data type :class:`Categorical` (or :class:`Enum` if
+            `as_enum` is set) if `include_breaks` is set to `False` (default),
+            otherwise an expression of data type :class:`Struct`.
 
         See Also
         --------
@@ -4055,14 +4154,46 @@ class Expr:
         │ 1   ┆ 1.0        ┆ (-1, 1]    │
         │ 2   ┆ inf        ┆ (1, inf]   │
         └─────┴────────────┴────────────┘
+
+        Compute the quantiles per group.
+
+        >>> df = pl.DataFrame({"g": ["a"] * 3 + ["b"] * 3, "x": [1, 2, 3, 10, 20, 30]})
+        >>> df.with_columns(
+        ...     qcut=pl.col("x").qcut([0.5], label_format="up to {upper}").over("g")
+        ... )
+        shape: (6, 3)
+        ┌─────┬─────┬───────────┐
+        │ g   ┆ x   ┆ qcut      │
+        │ --- ┆ --- ┆ ---       │
+        │ str ┆ i64 ┆ cat       │
+        ╞═════╪═════╪═══════════╡
+        │ a   ┆ 1   ┆ up to 2   │
+        │ a   ┆ 2   ┆ up to 2   │
+        │ a   ┆ 3   ┆ up to inf │
+        │ b   ┆ 10  ┆ up to 20  │
+        │ b   ┆ 20  ┆ up to 20  │
+        │ b   ┆ 30  ┆ up to inf │
+        └─────┴─────┴───────────┘
         """
         if isinstance(quantiles, int):
             pyexpr = self._pyexpr.qcut_uniform(
-                quantiles, labels, left_closed, allow_duplicates, include_breaks
+                quantiles,
+                labels,
+                label_format,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                as_enum,
             )
         else:
             pyexpr = self._pyexpr.qcut(
-                quantiles, labels, left_closed, allow_duplicates, include_breaks
+                quantiles,
+                labels,
+                label_format,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                as_enum,
             )
 
         return self._from_pyexpr(pyexpr)
@@ -5338,7 +5469,15 @@ class Expr:
         other = parse_into_expression(other, str_as_lit=True)
         return self._from_pyexpr(self._pyexpr.neq_missing(other))
 
-    def add(self, other: Any) -> Expr:
+    def _decimal_arithmetic(
+        self, op: str, other: Any, decimal_overflow: DecimalOverflow
+    ) -> Expr:
+        other = parse_into_expression(other)
+        return self._from_pyexpr(
+            self._pyexpr.decimal_arithmetic(op, other, decimal_overflow)
+        )
+
+    def add(self, other: Any, *, decimal_overflow: DecimalOverflow = "wrap") -> Expr:
         """
         Method equivalent of addition operator `expr + other`.
 
@@ -5346,6 +5485,16 @@ class Expr:
         ----------
         other
             numeric or string value; accepts expression input.
+        decimal_overflow : {'wrap', 'error', 'saturate'}
+            How to handle decimal results that do not fit in 38 digits. Integer
+            operands are treated as decimals with a scale of 0; other operands are
+            only supported with the default.
+
+            * 'wrap': wrap around the underlying 128-bit integer, like
+              `expr + other`.
+            * 'error': raise an error.
+            * 'saturate': clamp the result to the largest (or smallest) decimal with
+              38 digits.
 
         Examples
         --------
@@ -5382,6 +5531,8 @@ class Expr:
         │ g   ┆ h   ┆ i   ┆ ghi │
         └─────┴─────┴─────┴─────┘
         """
+        if decimal_overflow != "wrap":
+            return self._decimal_arithmetic("add", other, decimal_overflow)
         return self.__add__(other)
 
     def floordiv(self, other: Any) -> Expr:
@@ -5499,7 +5650,7 @@ class Expr:
         """
         return self.__mod__(other)
 
-    def mul(self, other: Any) -> Expr:
+    def mul(self, other: Any, *, decimal_overflow: DecimalOverflow = "wrap") -> Expr:
         """
         Method equivalent of multiplication operator `expr * other`.
 
@@ -5507,6 +5658,16 @@ class Expr:
         ----------
         other
             Numeric literal or expression value.
+        decimal_overflow : {'wrap', 'error', 'saturate'}
+            How to handle decimal results that do not fit in 38 digits. Integer
+            operands are treated as decimals with a scale of 0; other operands are
+            only supported with the default.
+
+            * 'wrap': wrap around the underlying 128-bit integer, like
+              `expr * other`.
+            * 'error': raise an error.
+            * 'saturate': clamp the result to the largest (or smallest) decimal with
+              38 digits.
 
         Examples
         --------
@@ -5528,9 +5689,11 @@ class Expr:
         │ 16  ┆ 32  ┆ 64.0      │
         └─────┴─────┴───────────┘
         """
+        if decimal_overflow != "wrap":
+            return self._decimal_arithmetic("mul", other, decimal_overflow)
         return self.__mul__(other)
 
-    def sub(self, other: Any) -> Expr:
+    def sub(self, other: Any, *, decimal_overflow: DecimalOverflow = "wrap") -> Expr:
         """
         Method equivalent of subtraction operator `expr - other`.
 
@@ -5538,6 +5701,16 @@ class Expr:
         ----------
         other
             Numeric literal or expression value.
+        decimal_overflow : {'wrap', 'error', 'saturate'}
+            How to handle decimal results that do not fit in 38 digits. Integer
+            operands are treated as decimals with a scale of 0; other operands are
+            only supported with the default.
+
+            * 'wrap': wrap around the underlying 128-bit integer, like
+              `expr - other`.
+            * 'error': raise an error.
+            * 'saturate': clamp the result to the largest (or smallest) decimal with
+              38 digits.
 
         Examples
         --------
@@ -5559,6 +5732,8 @@ class Expr:
         │ 4   ┆ 2   ┆ -6     │
         └─────┴─────┴────────┘
         """
+        if decimal_overflow != "wrap":
+            return self._decimal_arithmetic("sub", other, decimal_overflow)
         return self.__sub__(other)
 
     def neg(self) -> Expr:
@@ -5583,7 +5758,9 @@ class Expr:
         """
         return self.__neg__()
 
-    def truediv(self, other: Any) -> Expr:
+    def truediv(
+        self, other: Any, *, decimal_overflow: DecimalOverflow = "wrap"
+    ) -> Expr:
         """
         Method equivalent of float division operator `expr / other`.
 
@@ -5591,6 +5768,16 @@ class Expr:
         ----------
         other
             Numeric literal or expression value.
+        decimal_overflow : {'wrap', 'error', 'saturate'}
+            How to handle decimal results that do not fit in 38 digits. Integer
+            operands are treated as decimals with a scale of 0; other operands are
+            only supported with the default.
+
+            * 'wrap': wrap around the underlying 128-bit integer, like
+              `expr / other`.
+            * 'error': raise an error.
+            * 'saturate': clamp the result to the largest (or smallest) decimal with
+              38 digits.
 
         Notes
         -----
@@ -5625,6 +5812,8 @@ class Expr:
         │ 2   ┆ -0.5 ┆ 1.0  ┆ -4.0  │
         └─────┴──────┴──────┴───────┘
         """
+        if decimal_overflow != "wrap":
+            return self._decimal_arithmetic("truediv", other, decimal_overflow)
         return self.__truediv__(other)
 
     def pow(self, exponent: IntoExprColumn | int | float) -> Expr:
@@ -5949,11 +6138,13 @@ class Expr:
         seed_1: int | None = None,
         seed_2: int | None = None,
         seed_3: int | None = None,
+        *,
+        algorithm: HashAlgorithm = "default",
     ) -> Expr:
         """
         Hash the elements in the selection.
 
-        The hash value is of type `UInt64`.
+        The hash value is of type `UInt64`, or `Binary` for the `'sha256'` algorithm.
 
         Parameters
         ----------
@@ -5962,15 +6153,39 @@ class Expr:
         seed_1
             Random seed parameter. Defaults to `seed` if not set.
         seed_2
-            Random seed parameter. Defaults to `seed` if not set.
+            Random seed parameter. Defaults to `seed` if not set. Only used by the
+            default algorithm.
         seed_3
-            Random seed parameter. Defaults to `seed` if not set.
+            Random seed parameter. Defaults to `seed` if not set. Only used by the
+            default algorithm.
+        algorithm : {'default', 'xxhash64', 'wyhash', 'siphash13', 'sha256'}
+            The hash algorithm.
+
+            - `'default'`: the fast algorithm Polars uses internally, which is not
+              stable across Polars versions.
+            - `'xxhash64'`: 64-bit xxHash, seeded with `seed`.
+            - `'wyhash'`: wyhash (final version 4), seeded with `seed`.
+            - `'siphash13'`: SipHash-1-3, keyed with `seed` and `seed_1`.
+            - `'sha256'`: 32-byte SHA-256 digests. The seeds are ignored.
 
         Notes
         -----
-        This implementation of `hash` does not guarantee stable results
-        across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        The default algorithm does not guarantee stable results across different
+        Polars versions. Its stability is only guaranteed within a single version.
+
+        The other algorithms are stable across Polars versions and platforms, so
+        their hashes can be stored, for example as external IDs, and compared with
+        hashes computed by other implementations of the same algorithm. For those,
+        every value is hashed as its bytes:
+
+        - Strings and categoricals as UTF-8, and binary values as they are.
+        - Booleans as a single byte.
+        - Numbers as the little-endian bytes of their data type, so the same value
+          hashes differently as `Int32` and `Int64`. The zeros and the NaNs of floats
+          are normalized first.
+        - Temporal and decimal values as their underlying integers.
+
+        Null values have a null hash, and nested data types are not supported.
 
         Examples
         --------
@@ -5991,9 +6206,35 @@ class Expr:
         │ 1101441246220388612  ┆ 11638928888656214026 │
         │ 11638928888656214026 ┆ 13382926553367784577 │
         └──────────────────────┴──────────────────────┘
+
+        Use a stable algorithm for hashes that can be stored.
+
+        >>> df = pl.DataFrame({"a": ["polars", "rust", None]})
+        >>> df.with_columns(
+        ...     xxhash64=pl.col("a").hash(algorithm="xxhash64"),
+        ...     sha256=pl.col("a")
+        ...     .hash(algorithm="sha256")
+        ...     .bin.encode("hex")
+        ...     .str.slice(0, 16),
+        ... )
+        shape: (3, 3)
+        ┌────────┬─────────────────────┬──────────────────┐
+        │ a      ┆ xxhash64            ┆ sha256           │
+        │ ---    ┆ ---                 ┆ ---              │
+        │ str    ┆ u64                 ┆ str              │
+        ╞════════╪═════════════════════╪══════════════════╡
+        │ polars ┆ 6375477685702046748 ┆ e37e7e864c58e1e9 │
+        │ rust   ┆ 6868822986778387468 ┆ 521fe5c9ece1aa1f │
+        │ null   ┆ null                ┆ null             │
+        └────────┴─────────────────────┴──────────────────┘
         """
         k0 = seed
         k1 = seed_1 if seed_1 is not None else seed
+        if algorithm != "default":
+            if seed_2 is not None or seed_3 is not None:
+                msg = "`seed_2` and `seed_3` are only used by the default algorithm"
+                raise ValueError(msg)
+            return self._from_pyexpr(self._pyexpr.stable_hash(algorithm, k0, k1))
         k2 = seed_2 if seed_2 is not None else seed
         k3 = seed_3 if seed_3 is not None else seed
         return self._from_pyexpr(self._pyexpr.hash(k0, k1, k2, k3))
@@ -6143,14 +6384,28 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.interpolate(method))
 
-    def interpolate_by(self, by: IntoExpr) -> Expr:
+    def interpolate_by(
+        self, by: IntoExpr, *, method: InterpolationMethod = "linear"
+    ) -> Expr:
         """
         Fill null values using interpolation based on another column.
 
+        Unlike :meth:`interpolate`, the values of `by` do not have to be evenly spaced,
+        so this can be used with timestamps that contain gaps or with arbitrary
+        floating point positions.
+
         Parameters
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest'}
+            Interpolation method.
+
+            - 'linear': weigh the neighbouring non-null values by their distance in
+              `by`. Integer columns are cast to Float64.
+            - 'nearest': take the value of the neighbouring non-null row that is
+              closest in `by`, preferring the later row on ties. The data type is
+              preserved.
 
         Examples
         --------
@@ -6174,9 +6429,26 @@ class Expr:
         │ null ┆ 7   ┆ 2.714286       │
         │ 3    ┆ 8   ┆ 3.0            │
         └──────┴─────┴────────────────┘
+
+        Fill null values with the value that is nearest in `b`.
+
+        >>> df.with_columns(
+        ...     a_nearest=pl.col("a").interpolate_by("b", method="nearest")
+        ... )
+        shape: (4, 3)
+        ┌──────┬─────┬───────────┐
+        │ a    ┆ b   ┆ a_nearest │
+        │ ---  ┆ --- ┆ ---       │
+        │ i64  ┆ i64 ┆ i64       │
+        ╞══════╪═════╪═══════════╡
+        │ 1    ┆ 1   ┆ 1         │
+        │ null ┆ 2   ┆ 1         │
+        │ null ┆ 7   ┆ 3         │
+        │ 3    ┆ 8   ┆ 3         │
+        └──────┴─────┴───────────┘
         """
         by = parse_into_expression(by)
-        return self._from_pyexpr(self._pyexpr.interpolate_by(by))
+        return self._from_pyexpr(self._pyexpr.interpolate_by(by, method))
 
     @unstable()
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
@@ -8292,6 +8564,132 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.rolling_skew(window_size, bias))
 
+    @unstable()
+    def rolling_kurtosis(
+        self,
+        window_size: int,
+        *,
+        fisher: bool = True,
+        bias: bool = True,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Compute a rolling kurtosis.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9, 3, 7]})
+        >>> df.select(pl.col("a").rolling_kurtosis(4))
+        shape: (6, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ f64       │
+        ╞═══════════╡
+        │ null      │
+        │ null      │
+        │ null      │
+        │ -1.0      │
+        │ -0.839477 │
+        │ -1.715867 │
+        └───────────┘
+
+        Note how the values match the following:
+
+        >>> pl.Series([1, 4, 2, 9]).kurtosis()
+        -1.0
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_kurtosis(
+                window_size, fisher, bias, min_samples, center
+            )
+        )
+
+    @unstable()
+    def rolling_rank(
+        self,
+        window_size: int,
+        method: RollingRankMethod = "average",
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Compute the rank of every value within its rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it. The value at the given row is ranked against the other
+        non-null values in its window, starting at 1.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        method : {'average', 'min', 'max'}
+            The method used to assign ranks to tied elements.
+            The following methods are available (default is 'average'):
+
+            - 'average' : The average of the ranks that would have been assigned to
+              all the tied values is assigned to each value.
+            - 'min' : The minimum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'max' : The maximum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9, 3, 7]})
+        >>> df.select(pl.col("a").rolling_rank(3))
+        shape: (6, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ null │
+        │ null │
+        │ 2.0  │
+        │ 3.0  │
+        │ 2.0  │
+        │ 2.0  │
+        └──────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_rank(window_size, method, min_samples, center)
+        )
+
     @unstable()
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def rolling_map(
@@ -8389,6 +8787,7 @@ class Expr:
         method: RankMethod = "average",
         *,
         descending: bool = False,
+        nan_position: NanPosition = "largest",
         seed: int | None = None,
     ) -> Expr:
         """
@@ -8416,6 +8815,10 @@ class Expr:
               on the order that the values occur in the Series.
         descending
             Rank in descending order.
+        nan_position : {'largest', 'first', 'last'}
+            Which ranks NaN values of a float column receive. By default NaN is
+            treated as larger than every number. Use `'first'` or `'last'` to give NaN
+            values the lowest or highest ranks, regardless of `descending`.
         seed
             If `method="random"`, use this as seed.
 
@@ -8494,7 +8897,9 @@ class Expr:
         └──────┴──────┴───────────┘
 
         """
-        return self._from_pyexpr(self._pyexpr.rank(method, descending, seed))
+        return self._from_pyexpr(
+            self._pyexpr.rank(method, descending, nan_position, seed)
+        )
 
     def diff(
         self, n: int | IntoExpr = 1, null_behavior: NullBehavior = "ignore"
@@ -9543,6 +9948,164 @@ class Expr:
         half_life = parse_as_duration_string(half_life)
         return self._from_pyexpr(self._pyexpr.ewm_mean_by(by, half_life))
 
+    def ewm_std_by(
+        self,
+        by: str | IntoExpr,
+        *,
+        half_life: str | timedelta,
+    ) -> Expr:
+        r"""
+        Compute time-based exponentially weighted moving standard deviation.
+
+        Uses the same time-based weights as :meth:`ewm_mean_by`. Given observations
+        :math:`x_0, x_1, \ldots, x_{n-1}` at times :math:`t_0, t_1, \ldots, t_{n-1}`,
+        the (biased) variance is calculated as
+
+            .. math::
+
+                \mu_0 &= x_0, \quad \sigma^2_0 = 0
+
+                \alpha_i &= 1 - \exp \left\{ \frac{ -\ln(2)(t_i-t_{i-1}) }
+                    { \tau } \right\}
+
+                \mu_i &= \alpha_i x_i + (1 - \alpha_i) \mu_{i-1}
+
+                \sigma^2_i &= (1 - \alpha_i) \left( \sigma^2_{i-1}
+                    + \alpha_i (x_i - \mu_{i-1})^2 \right); \quad i > 0
+
+        where :math:`\tau` is the `half_life`, and the standard deviation is
+        :math:`\sigma_i`. Null values are skipped and do not reset the state.
+
+        Parameters
+        ----------
+        by
+            Times to calculate standard deviation by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value.
+
+            Uses the same string language as :meth:`ewm_mean_by`, and is treated as a
+            constant duration.
+
+        Returns
+        -------
+        Expr
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df.with_columns(
+        ...     result=pl.col("values").ewm_std_by("times", half_life="4d"),
+        ... )
+        shape: (5, 3)
+        ┌────────┬────────────┬──────────┐
+        │ values ┆ times      ┆ result   │
+        │ ---    ┆ ---        ┆ ---      │
+        │ i64    ┆ date       ┆ f64      │
+        ╞════════╪════════════╪══════════╡
+        │ 0      ┆ 2020-01-01 ┆ 0.0      │
+        │ 1      ┆ 2020-01-03 ┆ 0.45509  │
+        │ 2      ┆ 2020-01-10 ┆ 0.818774 │
+        │ null   ┆ 2020-01-15 ┆ null     │
+        │ 4      ┆ 2020-01-17 ┆ 1.229996 │
+        └────────┴────────────┴──────────┘
+        """
+        by = parse_into_expression(by)
+        half_life = parse_as_duration_string(half_life)
+        return self._from_pyexpr(self._pyexpr.ewm_std_by(by, half_life))
+
+    def ewm_var_by(
+        self,
+        by: str | IntoExpr,
+        *,
+        half_life: str | timedelta,
+    ) -> Expr:
+        r"""
+        Compute time-based exponentially weighted moving variance.
+
+        Uses the same time-based weights as :meth:`ewm_mean_by`. Given observations
+        :math:`x_0, x_1, \ldots, x_{n-1}` at times :math:`t_0, t_1, \ldots, t_{n-1}`,
+        the (biased) variance is calculated as
+
+            .. math::
+
+                \mu_0 &= x_0, \quad \sigma^2_0 = 0
+
+                \alpha_i &= 1 - \exp \left\{ \frac{ -\ln(2)(t_i-t_{i-1}) }
+                    { \tau } \right\}
+
+                \mu_i &= \alpha_i x_i + (1 - \alpha_i) \mu_{i-1}
+
+                \sigma^2_i &= (1 - \alpha_i) \left( \sigma^2_{i-1}
+                    + \alpha_i (x_i - \mu_{i-1})^2 \right); \quad i > 0
+
+        where :math:`\tau` is the `half_life`. Null values are skipped and do not
+        reset the state.
+
+        Parameters
+        ----------
+        by
+            Times to calculate variance by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value.
+
+            Uses the same string language as :meth:`ewm_mean_by`, and is treated as a
+            constant duration.
+
+        Returns
+        -------
+        Expr
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df.with_columns(
+        ...     result=pl.col("values").ewm_var_by("times", half_life="4d"),
+        ... )
+        shape: (5, 3)
+        ┌────────┬────────────┬──────────┐
+        │ values ┆ times      ┆ result   │
+        │ ---    ┆ ---        ┆ ---      │
+        │ i64    ┆ date       ┆ f64      │
+        ╞════════╪════════════╪══════════╡
+        │ 0      ┆ 2020-01-01 ┆ 0.0      │
+        │ 1      ┆ 2020-01-03 ┆ 0.207107 │
+        │ 2      ┆ 2020-01-10 ┆ 0.670392 │
+        │ null   ┆ 2020-01-15 ┆ null     │
+        │ 4      ┆ 2020-01-17 ┆ 1.512891 │
+        └────────┴────────────┴──────────┘
+        """
+        by = parse_into_expression(by)
+        half_life = parse_as_duration_string(half_life)
+        return self._from_pyexpr(self._pyexpr.ewm_var_by(by, half_life))
+
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def ewm_std(
         self,
@@ -10052,6 +10615,59 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.set_sorted_flag(descending))
 
+    @unstable()
+    def assert_(self, predicate: IntoExpr, message: str = "") -> Expr:
+        """
+        Raise an error if a predicate doesn't hold, otherwise pass the values through.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        predicate
+            A boolean expression that must be true for every row. Rows for which it
+            is null pass.
+        message
+            A description of the assertion, shown in the error.
+
+        Raises
+        ------
+        AssertionError
+            If the predicate is false for any row. The error shows the number of
+            failing rows and the first failing values.
+
+        Notes
+        -----
+        The assertion is only checked for the rows that the query computes this
+        expression for, so a filter that is applied later may be pushed down
+        before it.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"price": [1.5, 3.0, 2.0]})
+        >>> df.select(pl.col("price").assert_(pl.col("price") > 0, "positive price"))
+        shape: (3, 1)
+        ┌───────┐
+        │ price │
+        │ ---   │
+        │ f64   │
+        ╞═══════╡
+        │ 1.5   │
+        │ 3.0   │
+        │ 2.0   │
+        └───────┘
+        >>> df.select(
+        ...     pl.col("price").assert_(pl.col("price") < 2.5, "cheap")
+        ... )  # doctest: +SKIP
+        Traceback (most recent call last):
+        ...
+        AssertionError: cheap: 1 of 3 values of "price" failed, including [3.0]
+        """
+        predicate = parse_into_expression(predicate)
+        return self._from_pyexpr(self._pyexpr.assert_(predicate, message))
+
     def shrink_dtype(self) -> Expr:
         """
         Shrink numeric columns to the minimal required datatype.
@@ -10116,7 +10732,15 @@ class Expr:
 
         Returns
         -------
-        DataFrame
+        Expr
+            Expression with the count of every bin, or a :class:`Struct` with a
+            `count` field if `include_breakpoint` or `include_category` is set.
+
+        Notes
+        -----
+        In a `group_by` or `over` context, a histogram is computed for every group.
+        If `bin_count` is given, the bin edges are determined from the data of each
+        group, so pass explicit `bins` to compare the histograms of groups.
 
         Examples
         --------
@@ -10145,9 +10769,25 @@ class Expr:
         │ {2.0,"[1.0, 2.0]",3} │
         │ {3.0,"(2.0, 3.0]",2} │
         └──────────────────────┘
+
+        Compute a histogram per group.
+
+        >>> df = pl.DataFrame({"g": ["a", "a", "b", "b", "b"], "x": [1, 2, 2, 3, 3]})
+        >>> df.group_by("g", maintain_order=True).agg(
+        ...     pl.col("x").hist(bins=[0, 1, 2, 3], include_breakpoint=True)
+        ... )
+        shape: (2, 2)
+        ┌─────┬─────────────────────────────┐
+        │ g   ┆ x                           │
+        │ --- ┆ ---                         │
+        │ str ┆ list[struct[2]]             │
+        ╞═════╪═════════════════════════════╡
+        │ a   ┆ [{1.0,1}, {2.0,1}, {3.0,0}] │
+        │ b   ┆ [{1.0,0}, {2.0,1}, {3.0,2}] │
+        └─────┴─────────────────────────────┘
         """
         if bins is not None:
-            if isinstance(bins, list):
+            if isinstance(bins, (list, tuple)):
                 bins = pl.Series(bins)
             bins = parse_into_expression(bins)
         return self._from_pyexpr(
@@ -10156,7 +10796,7 @@ class Expr:
 
     def replace(
         self,
-        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any] | DataFrame,
         new: IntoExpr | Sequence[Any] | NoDefault = no_default,
         *,
         default: IntoExpr | NoDefault = no_default,
@@ -10172,7 +10812,8 @@ class Expr:
             Accepts expression input. Sequences are parsed as Series,
             other non-expression inputs are parsed as literals.
             Also accepts a mapping of values to their replacement as syntactic sugar for
-            `replace(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+            `replace(old=Series(mapping.keys()), new=Series(mapping.values()))`,
+            or a DataFrame with two columns: the values and their replacement.
         new
             Value or sequence of values to replace by.
             Accepts expression input. Sequences are parsed as Series,
@@ -10311,13 +10952,17 @@ class Expr:
             )
 
         if new is no_default:
-            if not isinstance(old, Mapping):
+            if isinstance(old, pl.DataFrame):
+                old, new = _split_mapping_frame(old)
+            elif not isinstance(old, Mapping):
                 msg = (
                     "`new` argument is required if `old` argument is not a Mapping type"
+                    " or a DataFrame"
                 )
                 raise TypeError(msg)
-            new = pl.Series(old.values())
-            old = pl.Series(old.keys())
+            else:
+                new = pl.Series(old.values())
+                old = pl.Series(old.keys())
         else:
             if isinstance(old, Sequence) and not isinstance(old, (str, pl.Series)):
                 old = pl.Series(old)
@@ -10336,7 +10981,7 @@ class Expr:
 
     def replace_strict(
         self,
-        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any] | DataFrame,
         new: IntoExpr | Sequence[Any] | NoDefault = no_default,
         *,
         default: IntoExpr | NoDefault = no_default,
@@ -10352,7 +10997,8 @@ class Expr:
             Accepts expression input. Sequences are parsed as Series,
             other non-expression inputs are parsed as literals.
             Also accepts a mapping of values to their replacement as syntactic sugar for
-            `replace_all(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+            `replace_all(old=Series(mapping.keys()), new=Series(mapping.values()))`,
+            or a DataFrame with two columns: the values and their replacement.
         new
             Value or sequence of values to replace by.
             Accepts expression input. Sequences are parsed as Series,
@@ -10417,6 +11063,23 @@ class Expr:
         │ 3   ┆ 300      │
         └─────┴──────────┘
 
+        A DataFrame with two columns, such as a lookup table read from a file, can be
+        used as the mapping as well.
+
+        >>> lookup = pl.DataFrame({"key": [1, 2, 3], "value": [100, 200, 300]})
+        >>> df.with_columns(replaced=pl.col("a").replace_strict(lookup))
+        shape: (4, 2)
+        ┌─────┬──────────┐
+        │ a   ┆ replaced │
+        │ --- ┆ ---      │
+        │ i64 ┆ i64      │
+        ╞═════╪══════════╡
+        │ 1   ┆ 100      │
+        │ 2   ┆ 200      │
+        │ 2   ┆ 200      │
+        │ 3   ┆ 300      │
+        └─────┴──────────┘
+
         By default, an error is raised if any non-null values were not replaced.
         Specify a default to set all values that were not matched.
 
@@ -10507,13 +11170,17 @@ class Expr:
         └─────┴─────┴──────────┘
         """  # noqa: W505
         if new is no_default:
-            if not isinstance(old, Mapping):
+            if isinstance(old, pl.DataFrame):
+                old, new = _split_mapping_frame(old)
+            elif not isinstance(old, Mapping):
                 msg = (
                     "`new` argument is required if `old` argument is not a Mapping type"
+                    " or a DataFrame"
                 )
                 raise TypeError(msg)
-            new = pl.Series(old.values())
-            old = pl.Series(old.keys())
+            else:
+                new = pl.Series(old.values())
+                old = pl.Series(old.keys())
 
         old = parse_into_expression(old, str_as_lit=True, list_as_series=True)  # type: ignore[arg-type]
         new = parse_into_expression(new, str_as_lit=True, list_as_series=True)  # type: ignore[arg-type]
@@ -10528,6 +11195,58 @@ class Expr:
             self._pyexpr.replace_strict(old, new, default, return_dtype)
         )
 
+    @unstable()
+    def match_map(
+        self,
+        mapping: Mapping[Any, Any],
+        *,
+        default: IntoExpr = None,
+        return_dtype: PolarsDataType | None = None,
+    ) -> Expr:
+        """
+        Look up every value in a literal table, like a `match` statement.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This is the same as a `when/then` chain comparing the expression with every
+        key, but uses a hash table instead of a comparison per key.
+
+        Parameters
+        ----------
+        mapping
+            A mapping of values to their output.
+        default
+            The output of values that aren't in `mapping`, null by default.
+            Accepts expression input. Non-expression inputs are parsed as literals.
+        return_dtype
+            The data type of the resulting expression. If set to `None` (default),
+            the data type is determined automatically based on the other inputs.
+
+        See Also
+        --------
+        replace_strict
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"code": [200, 404, 500, 301]})
+        >>> statuses = {200: "ok", 404: "missing", 500: "error"}
+        >>> df.with_columns(status=pl.col("code").match_map(statuses))
+        shape: (4, 2)
+        ┌──────┬─────────┐
+        │ code ┆ status  │
+        │ ---  ┆ ---     │
+        │ i64  ┆ str     │
+        ╞══════╪═════════╡
+        │ 200  ┆ ok      │
+        │ 404  ┆ missing │
+        │ 500  ┆ error   │
+        │ 301  ┆ null    │
+        └──────┴─────────┘
+        """
+        return self.replace_strict(mapping, default=default, return_dtype=return_dtype)
+
     def bitwise_count_ones(self) -> Expr:
         """Evaluate the number of set bits."""
         return self._from_pyexpr(self._pyexpr.bitwise_count_ones())
@@ -10752,6 +11471,15 @@ class Expr:
         )
         return cls.deserialize(StringIO(value), format="json")
 
+    @property
+    def anonymize(self) -> ExprAnonymizeNameSpace:
+        """
+        Create an object namespace of all privacy related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprAnonymizeNameSpace(self)
+
     @property
     def bin(self) -> ExprBinaryNameSpace:
         """
@@ -10887,6 +11615,15 @@ class Expr:
         """
         return ExprStructNameSpace(self)
 
+    @property
+    def uuid(self) -> ExprUuidNameSpace:
+        """
+        Create an object namespace of all uuid related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprUuidNameSpace(self)
+
     def _skip_batch_predicate(self, schema: SchemaDict) -> Expr | None:
         result = self._pyexpr.skip_batch_predicate(schema)
         if result is None:
@@ -10894,6 +11631,17 @@ class Expr:
         return self._from_pyexpr(result)
 
 
+def _split_mapping_frame(mapping: DataFrame) -> tuple[Series, Series]:
+    """Split a DataFrame mapping of `replace` into the old and new values."""
+    if mapping.width != 2:
+        msg = (
+            "a DataFrame mapping must have two columns, the values and their"
+            f" replacement, got {mapping.width} columns"
+        )
+        raise ValueError(msg)
+    return mapping.to_series(0), mapping.to_series(1)
+
+
 def _prepare_alpha(
     com: float | int | None = None,
     span: float | int | None = None,
@@ -465,6 +465,47 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_n_unique())
 
+    def value_counts(
+        self,
+        *,
+        sort: bool = False,
+        name: str | None = None,
+        normalize: bool = False,
+    ) -> Expr:
+        """
+        Count the occurrences of the unique values in every sub-list.
+
+        This is equivalent to exploding the column and calling `value_counts` per
+        row, without the round trip.
+
+        Parameters
+        ----------
+        sort
+            Sort the values of every sub-list by count in descending order.
+            If set to `False` (default), the order is random.
+        name
+            Give the resulting count field a specific name;
+            if `normalize` is True defaults to "proportion",
+            otherwise defaults to "count".
+        normalize
+            If true gives relative frequencies of the unique values
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List` of :class:`Struct` with a mapping
+            of the unique values to their count.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 1, 2], [3]]})
+        >>> df.select(pl.col("a").list.value_counts(sort=True)).to_series().to_list()
+        [[{'a': 1, 'count': 2}, {'a': 2, 'count': 1}], [{'a': 3, 'count': 1}]]
+        """
+        if name is None:
+            name = "proportion" if normalize else "count"
+        return wrap_expr(self._pyexpr.list_value_counts(sort, normalize, name))
+
     def concat(self, other: list[Expr | str] | Expr | str | Series | list[Any]) -> Expr:
         """
         Concat the arrays in a Series dtype List in linear time.
@@ -1208,7 +1249,8 @@ class ExprListNameSpace:
         ----------
         expr
             Expression to run. Note that you can select an element with `pl.first()`, or
-            `pl.col()`
+            `pl.col()`. Other columns can be referenced by name; their value in the
+            row of a list is broadcast to all elements of that list.
         parallel
             Run all expression parallel. Don't activate this blindly.
             Parallelism is worth it if there is enough work to do per thread.
@@ -1232,6 +1274,20 @@ class ExprListNameSpace:
         │ 8   ┆ 5   ┆ [2.0, 1.0] │
         │ 3   ┆ 2   ┆ [2.0, 1.0] │
         └─────┴─────┴────────────┘
+
+        Refer to a column of the same row:
+
+        >>> df = pl.DataFrame({"items": [[1.0, 2.0], [3.0]], "fx_rate": [0.5, 2.0]})
+        >>> df.select(pl.col("items").list.eval(pl.element() * pl.col("fx_rate")))
+        shape: (2, 1)
+        ┌────────────┐
+        │ items      │
+        │ ---        │
+        │ list[f64]  │
+        ╞════════════╡
+        │ [0.5, 1.0] │
+        │ [6.0]      │
+        └────────────┘
         """
         return wrap_expr(self._pyexpr.list_eval(expr._pyexpr, parallel))
 
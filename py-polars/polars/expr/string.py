@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import sys
 import warnings
 from collections.abc import Mapping
 from typing import TYPE_CHECKING
@@ -12,13 +13,20 @@ from polars._utils.deprecation import (
 )
 from polars._utils.parse import parse_into_expression
 from polars._utils.unstable import unstable
-from polars._utils.various import find_stacklevel, no_default
+from polars._utils.various import (
+    BUILDING_SPHINX_DOCS,
+    find_stacklevel,
+    no_default,
+    sphinx_accessor,
+)
 from polars._utils.wrap import wrap_expr
 from polars.datatypes import Date, Datetime, Time, parse_into_dtype
 from polars.datatypes.constants import N_INFER_DEFAULT
 from polars.exceptions import ChronoFormatWarning
 
 if TYPE_CHECKING:
+    from collections.abc import Sequence
+
     from polars import Expr
     from polars._typing import (
         Ambiguous,
@@ -31,6 +39,11 @@ if TYPE_CHECKING:
         UnicodeForm,
     )
     from polars._utils.various import NoDefault
+elif BUILDING_SPHINX_DOCS:
+    # note: we assign this way to work around an autocomplete issue in ipython/jedi
+    # (ref: https://github.com/davidhalter/jedi/issues/2057)
+    current_module = sys.modules[__name__]
+    current_module.property = sphinx_accessor
 
 
 class ExprStringNameSpace:
@@ -41,6 +54,15 @@ class ExprStringNameSpace:
     def __init__(self, expr: Expr) -> None:
         self._pyexpr = expr._pyexpr
 
+    @property
+    def url(self) -> ExprStringUrlNameSpace:
+        """
+        Create an object namespace of all URL related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprStringUrlNameSpace(self)
+
     def to_date(
         self,
         format: str | None = None,
@@ -88,7 +110,7 @@ class ExprStringNameSpace:
 
     def to_datetime(
         self,
-        format: str | None = None,
+        format: str | Sequence[str] | Expr | None = None,
         *,
         time_unit: TimeUnit | None = None,
         time_zone: str | None = None,
@@ -107,11 +129,19 @@ class ExprStringNameSpace:
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for the full specification. Example: `"%Y-%m-%d %H:%M:%S"`.
             If set to None (default), the format is inferred from the data.
+
+            * A list of formats is tried in order: every value is parsed with the
+              first format that matches it, which helps with feeds that mix date
+              conventions.
+            * An expression provides the format of every row; rows with a null
+              format result in null. These formats cannot contain a UTC offset.
         time_unit : {None, 'us', 'ns', 'ms'}
             Unit of time for the resulting Datetime column. If set to None (default),
             the time unit is inferred from the format string if given, eg:
             `"%F %T%.3f"` => `Datetime("ms")`. If no fractional second component is
-            found, the default is `"us"`.
+            found, the default is `"us"`. For a list of formats the finest time unit
+            inferred from any of them is used, for a format expression the default
+            is `"us"`.
         time_zone
             Time zone for the resulting Datetime column. Rules are:
 
@@ -128,7 +158,7 @@ class ExprStringNameSpace:
             Raise an error if any conversion fails.
         exact
             Require an exact format match. If False, allow the format to match anywhere
-            in the target string.
+            in the target string. Only supported for a single format.
 
             .. note::
                 Using `exact=False` introduces a performance penalty - cleaning your
@@ -153,10 +183,65 @@ class ExprStringNameSpace:
                 2020-01-01 01:00:00 UTC
                 2020-01-01 02:00:00 UTC
         ]
+
+        Try several formats in order:
+
+        >>> s = pl.Series(["2024-03-01 10:00", "01/03/2024 11:30", "2024-03-02"])
+        >>> s.str.to_datetime(["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M", "%Y-%m-%d"])
+        shape: (3,)
+        Series: '' [datetime[μs]]
+        [
+                2024-03-01 10:00:00
+                2024-03-01 11:30:00
+                2024-03-02 00:00:00
+        ]
+
+        Take the format of every row from another column:
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "value": ["2024-03-01", "03/01/2024", "01.03.2024"],
+        ...         "format": ["%Y-%m-%d", "%m/%d/%Y", "%d.%m.%Y"],
+        ...     }
+        ... )
+        >>> df.with_columns(parsed=pl.col("value").str.to_datetime(pl.col("format")))
+        shape: (3, 3)
+        ┌────────────┬──────────┬─────────────────────┐
+        │ value      ┆ format   ┆ parsed              │
+        │ ---        ┆ ---      ┆ ---                 │
+        │ str        ┆ str      ┆ datetime[μs]        │
+        ╞════════════╪══════════╪═════════════════════╡
+        │ 2024-03-01 ┆ %Y-%m-%d ┆ 2024-03-01 00:00:00 │
+        │ 03/01/2024 ┆ %m/%d/%Y ┆ 2024-03-01 00:00:00 │
+        │ 01.03.2024 ┆ %d.%m.%Y ┆ 2024-03-01 00:00:00 │
+        └────────────┴──────────┴─────────────────────┘
         """
-        _validate_format_argument(format)
         if not isinstance(ambiguous, pl.Expr):
             ambiguous = F.lit(ambiguous)
+        if format is not None and not isinstance(format, str):
+            if not exact:
+                msg = "`exact=False` is only supported for a single format"
+                raise ValueError(msg)
+            if isinstance(format, (pl.Expr, pl.Series)):
+                return wrap_expr(
+                    self._pyexpr.str_to_datetime_by_format(
+                        parse_into_expression(format),
+                        time_unit,
+                        time_zone,
+                        strict,
+                        cache,
+                        ambiguous._pyexpr,
+                    )
+                )
+            formats = list(format)
+            for fmt in formats:
+                _validate_format_argument(fmt)
+            return wrap_expr(
+                self._pyexpr.str_to_datetime_formats(
+                    formats, time_unit, time_zone, strict, cache, ambiguous._pyexpr
+                )
+            )
+        _validate_format_argument(format)
         return wrap_expr(
             self._pyexpr.str_to_datetime(
                 format,
@@ -362,6 +447,167 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_to_decimal(inference_length))
 
+    def to_duration(self, time_unit: TimeUnit = "us", *, strict: bool = True) -> Expr:
+        """
+        Convert a String column into a Duration column.
+
+        Both textual forms produced by :meth:`Expr.dt.to_string` for durations are
+        accepted, so duration columns can be round-tripped through text:
+
+        * The ISO 8601 form, eg: `"P1DT2H3M4.5S"` or `"-PT42S"`. Weeks are accepted,
+          but years and months are not, as they have no fixed length.
+        * The "polars" form seen in the frame repr, eg: `"1d 2h 3m 4s 500ms"`. Every
+          part can carry its own sign, eg: `"-1d -42s"`.
+
+        Parameters
+        ----------
+        time_unit : {'us', 'ns', 'ms'}
+            Time unit of the resulting Duration; parts finer than it are truncated.
+        strict
+            Raise an error if any conversion fails. If set to `False`, values that
+            cannot be parsed are set to null.
+
+        See Also
+        --------
+        polars.Expr.dt.to_string : Format a Duration column as text.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"s": ["P1DT2H", "1d 2h", "-PT1.5S", "3m 20s 100ms", None]},
+        ... )
+        >>> df.with_columns(td=pl.col("s").str.to_duration())
+        shape: (5, 2)
+        ┌──────────────┬──────────────┐
+        │ s            ┆ td           │
+        │ ---          ┆ ---          │
+        │ str          ┆ duration[μs] │
+        ╞══════════════╪══════════════╡
+        │ P1DT2H       ┆ 1d 2h        │
+        │ 1d 2h        ┆ 1d 2h        │
+        │ -PT1.5S      ┆ -1s -500ms   │
+        │ 3m 20s 100ms ┆ 3m 20s 100ms │
+        │ null         ┆ null         │
+        └──────────────┴──────────────┘
+
+        Round-trip a Duration column through its ISO 8601 representation:
+
+        >>> from datetime import timedelta
+        >>> df = pl.DataFrame({"td": [timedelta(days=-1, seconds=-42)]})
+        >>> iso = pl.col("td").dt.to_string("iso")
+        >>> df.select(iso=iso, equal=iso.str.to_duration() == pl.col("td"))
+        shape: (1, 2)
+        ┌──────────┬───────┐
+        │ iso      ┆ equal │
+        │ ---      ┆ ---   │
+        │ str      ┆ bool  │
+        ╞══════════╪═══════╡
+        │ -P1DT42S ┆ true  │
+        └──────────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.str_to_duration(time_unit, strict))
+
+    def to_uuid(self, *, strict: bool = True) -> Expr:
+        """
+        Parse UUIDs from their textual form.
+
+        The hyphenated form (eg: `"67e55044-10b1-426f-9247-bb680e5fe0c8"`) and the
+        simple form without hyphens are accepted, optionally wrapped in braces or
+        prefixed with `"urn:uuid:"`. Hexadecimal digits are case-insensitive.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if any value is not a valid UUID. If set to `False`,
+            invalid values are set to null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Uuid`.
+
+        See Also
+        --------
+        polars.Expr.uuid.to_string : Format UUIDs in their textual form.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "id": [
+        ...             "67E55044-10B1-426F-9247-BB680E5FE0C8",
+        ...             "urn:uuid:1a2b3c4d5e6f708192a3b4c5d6e7f809",
+        ...             None,
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(pl.col("id").str.to_uuid().uuid.to_string())
+        shape: (3, 1)
+        ┌──────────────────────────────────────┐
+        │ id                                   │
+        │ ---                                  │
+        │ str                                  │
+        ╞══════════════════════════════════════╡
+        │ 67e55044-10b1-426f-9247-bb680e5fe0c8 │
+        │ 1a2b3c4d-5e6f-7081-92a3-b4c5d6e7f809 │
+        │ null                                 │
+        └──────────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_to_uuid(strict))
+
+    def to_ip(self, *, strict: bool = True) -> Expr:
+        """
+        Parse IPv4 and IPv6 addresses into their bytes.
+
+        An IPv4 address (eg: `"192.168.0.1"`) becomes 4 bytes and an IPv6 address
+        (eg: `"2001:db8::1"`) becomes 16 bytes, both in network (big-endian) order.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if any value is not a valid IP address. If set to `False`,
+            invalid values are set to null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`.
+
+        See Also
+        --------
+        polars.Expr.bin.ip_to_string : Format IP addresses in their binary form.
+        polars.Expr.bin.ip_is_in_subnet : Check if IP addresses are in a subnet.
+        polars.Expr.bin.ipv4_to_integer : Convert IPv4 addresses to integers.
+        polars.Expr.bin.ipv6_to_integer : Convert IP addresses to 128-bit integers.
+
+        Notes
+        -----
+        Polars has no IP address data type: addresses are stored as plain
+        :class:`Binary` values, so IPv4 and IPv6 addresses can be mixed in one
+        column and are told apart by their length only.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["10.1.2.3", "192.168.0.1", "2001:db8::1", None]})
+        >>> ip = pl.col("ip").str.to_ip()
+        >>> df.with_columns(
+        ...     private=ip.bin.ip_is_in_subnet("10.0.0.0/8"),
+        ...     size=ip.bin.size(),
+        ... )
+        shape: (4, 3)
+        ┌─────────────┬─────────┬──────┐
+        │ ip          ┆ private ┆ size │
+        │ ---         ┆ ---     ┆ ---  │
+        │ str         ┆ bool    ┆ u32  │
+        ╞═════════════╪═════════╪══════╡
+        │ 10.1.2.3    ┆ true    ┆ 4    │
+        │ 192.168.0.1 ┆ false   ┆ 4    │
+        │ 2001:db8::1 ┆ false   ┆ 16   │
+        │ null        ┆ null    ┆ null │
+        └─────────────┴─────────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.str_to_ip(strict))
+
     def len_bytes(self) -> Expr:
         """
         Return the length of each string as the number of bytes.
@@ -489,6 +735,44 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_to_lowercase())
 
+    def to_casefold(self) -> Expr:
+        """
+        Fold the case of strings, for caseless comparisons.
+
+        Case folding is like converting to lowercase, but also folds characters that
+        have no single lowercase equivalent, for example "ß" to "ss". Use it to compare,
+        join or deduplicate text regardless of case; it is not meant for display.
+
+        Notes
+        -----
+        Every character is folded to the lowercase of its full uppercase mapping. This
+        is not locale-aware: like the default Unicode case folding, the dotted and
+        dotless "i" of Turkish are kept apart.
+
+        See Also
+        --------
+        to_lowercase
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"city": ["Straße", "STRASSE", "strasse"]})
+        >>> df.with_columns(
+        ...     lower=pl.col("city").str.to_lowercase(),
+        ...     folded=pl.col("city").str.to_casefold(),
+        ... )
+        shape: (3, 3)
+        ┌─────────┬─────────┬─────────┐
+        │ city    ┆ lower   ┆ folded  │
+        │ ---     ┆ ---     ┆ ---     │
+        │ str     ┆ str     ┆ str     │
+        ╞═════════╪═════════╪═════════╡
+        │ Straße  ┆ straße  ┆ strasse │
+        │ STRASSE ┆ strasse ┆ strasse │
+        │ strasse ┆ strasse ┆ strasse │
+        └─────────┴─────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_to_casefold())
+
     def to_titlecase(self) -> Expr:
         """
         Modify strings to their titlecase equivalent.
@@ -930,7 +1214,14 @@ class ExprStringNameSpace:
         return wrap_expr(self._pyexpr.str_zfill(length))
 
     def contains(
-        self, pattern: str | Expr, *, literal: bool = False, strict: bool = True
+        self,
+        pattern: str | Expr,
+        *,
+        literal: bool = False,
+        strict: bool = True,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
     ) -> Expr:
         """
         Check if the string contains a substring that matches a pattern.
@@ -945,6 +1236,13 @@ class ExprStringNameSpace:
         strict
             Raise an error if the underlying pattern is not a valid regex,
             otherwise mask out with a null value.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
 
         Notes
         -----
@@ -996,6 +1294,13 @@ class ExprStringNameSpace:
         │ null        ┆ null  ┆ null    │
         └─────────────┴───────┴─────────┘
         """
+        pattern = _with_regex_flags(
+            pattern,
+            literal=literal,
+            ignore_case=ignore_case,
+            multiline=multiline,
+            dot_all=dot_all,
+        )
         pattern = parse_into_expression(pattern, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_contains(pattern, literal, strict))
 
@@ -1385,7 +1690,15 @@ class ExprStringNameSpace:
             msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
             raise ValueError(msg)
 
-    def extract(self, pattern: IntoExprColumn, group_index: int = 1) -> Expr:
+    def extract(
+        self,
+        pattern: IntoExprColumn,
+        group_index: int = 1,
+        *,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
+    ) -> Expr:
         r"""
         Extract the target capture group from provided patterns.
 
@@ -1398,6 +1711,13 @@ class ExprStringNameSpace:
             Index of the targeted capture group.
             Group 0 means the whole pattern, the first group begins at index 1.
             Defaults to the first capture group.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
 
         Notes
         -----
@@ -1465,10 +1785,20 @@ class ExprStringNameSpace:
         │ ronaldo   ┆ polars  ┆ null  │
         └───────────┴─────────┴───────┘
         """
+        pattern = _with_regex_flags(
+            pattern, ignore_case=ignore_case, multiline=multiline, dot_all=dot_all
+        )
         pattern = parse_into_expression(pattern, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_extract(pattern, group_index))
 
-    def extract_all(self, pattern: str | Expr) -> Expr:
+    def extract_all(
+        self,
+        pattern: str | Expr,
+        *,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
+    ) -> Expr:
         r'''
         Extract all matches for the given regex pattern.
 
@@ -1480,6 +1810,13 @@ class ExprStringNameSpace:
         pattern
             A valid regular expression pattern, compatible with the `regex crate
             <https://docs.rs/regex/latest/regex/>`_.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
 
         Notes
         -----
@@ -1551,10 +1888,20 @@ class ExprStringNameSpace:
         └────────────────┘
 
         '''
+        pattern = _with_regex_flags(
+            pattern, ignore_case=ignore_case, multiline=multiline, dot_all=dot_all
+        )
         pattern = parse_into_expression(pattern, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_extract_all(pattern))
 
-    def extract_groups(self, pattern: str) -> Expr:
+    def extract_groups(
+        self,
+        pattern: str,
+        *,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
+    ) -> Expr:
         r"""
         Extract all capture groups for the given regex pattern.
 
@@ -1563,6 +1910,13 @@ class ExprStringNameSpace:
         pattern
             A valid regular expression pattern containing at least one capture group,
             compatible with the `regex crate <https://docs.rs/regex/latest/regex/>`_.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
 
         Notes
         -----
@@ -1642,9 +1996,87 @@ class ExprStringNameSpace:
         if not isinstance(pattern, str):
             msg = f"extract_groups expects a `str`, given a `{type(pattern)}`"
             raise TypeError(msg)
+        pattern = _with_regex_flags(
+            pattern, ignore_case=ignore_case, multiline=multiline, dot_all=dot_all
+        )
         return wrap_expr(self._pyexpr.str_extract_groups(pattern))
 
-    def count_matches(self, pattern: str | Expr, *, literal: bool = False) -> Expr:
+    def extract_all_groups(
+        self,
+        pattern: str,
+        *,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
+    ) -> Expr:
+        r"""
+        Extract the capture groups of every match of the given regex pattern.
+
+        Each successive non-overlapping match becomes a struct of its capture groups,
+        named as in :meth:`extract_groups`.
+
+        Parameters
+        ----------
+        pattern
+            A valid regular expression pattern containing at least one capture group,
+            compatible with the `regex crate <https://docs.rs/regex/latest/regex/>`_.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `List(Struct)` with fields of data type
+            :class:`String`. Returns null if the original value is null and an
+            empty list if there is no match.
+
+        See Also
+        --------
+        extract_groups : Extract the capture groups of the first match.
+        extract_all : Extract all matches of a pattern.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"s": ["a=1, B=2", "c=3", "none", None]})
+        >>> df.select(
+        ...     pl.col("s").str.extract_all_groups(
+        ...         r"(?<key>[a-z])=(?<value>\d)", ignore_case=True
+        ...     )
+        ... )
+        shape: (4, 1)
+        ┌────────────────────────┐
+        │ s                      │
+        │ ---                    │
+        │ list[struct[2]]        │
+        ╞════════════════════════╡
+        │ [{"a","1"}, {"B","2"}] │
+        │ [{"c","3"}]            │
+        │ []                     │
+        │ null                   │
+        └────────────────────────┘
+        """
+        if not isinstance(pattern, str):
+            msg = f"extract_all_groups expects a `str`, given a `{type(pattern)}`"
+            raise TypeError(msg)
+        pattern = _with_regex_flags(
+            pattern, ignore_case=ignore_case, multiline=multiline, dot_all=dot_all
+        )
+        return wrap_expr(self._pyexpr.str_extract_all_groups(pattern))
+
+    def count_matches(
+        self,
+        pattern: str | Expr,
+        *,
+        literal: bool = False,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
+    ) -> Expr:
         r"""
         Count all successive non-overlapping regex matches.
 
@@ -1655,6 +2087,13 @@ class ExprStringNameSpace:
             <https://docs.rs/regex/latest/regex/>`_.
         literal
             Treat `pattern` as a literal string, not as a regular expression.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
 
         Returns
         -------
@@ -1698,6 +2137,13 @@ class ExprStringNameSpace:
         │ null       ┆ null         │
         └────────────┴──────────────┘
         """
+        pattern = _with_regex_flags(
+            pattern,
+            literal=literal,
+            ignore_case=ignore_case,
+            multiline=multiline,
+            dot_all=dot_all,
+        )
         pattern = parse_into_expression(pattern, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_count_matches(pattern, literal))
 
@@ -1906,7 +2352,9 @@ class ExprStringNameSpace:
             A valid regular expression pattern, compatible with the `regex crate
             <https://docs.rs/regex/latest/regex/>`_.
         value
-            String that will replace the matched substring.
+            String that will replace the matched substring. Accepts expression input,
+            so every row can have its own replacement, for example from another
+            column.
         literal
             Treat `pattern` as a literal string.
         n
@@ -2022,7 +2470,9 @@ class ExprStringNameSpace:
             A valid regular expression pattern, compatible with the `regex crate
             <https://docs.rs/regex/latest/regex/>`_.
         value
-            String that will replace the matched substring.
+            String that will replace the matched substring. Accepts expression input,
+            so every row can have its own replacement, for example from another
+            column; capture groups are expanded in the value of every row.
         literal
             Treat `pattern` as a literal string.
 
@@ -2099,6 +2549,28 @@ class ExprStringNameSpace:
         │ hut  ┆ bud        ┆ bud   │
         └──────┴────────────┴───────┘
 
+        The pattern and the replacement value can be expressions, which are evaluated
+        per row. Here, numbers are redacted with the token of another column, keeping
+        their last digit through a capture group:
+
+        >>> df = pl.DataFrame(
+        ...     {"text": ["call 555-1234", "id 42"], "token": ["<phone>", "<id>"]}
+        ... )
+        >>> df.with_columns(
+        ...     pl.col("text").str.replace_all(
+        ...         r"\d*(\d)", pl.concat_str(pl.col("token"), pl.lit("$1"))
+        ...     )
+        ... )
+        shape: (2, 2)
+        ┌────────────────────────┬─────────┐
+        │ text                   ┆ token   │
+        │ ---                    ┆ ---     │
+        │ str                    ┆ str     │
+        ╞════════════════════════╪═════════╡
+        │ call <phone>5-<phone>4 ┆ <phone> │
+        │ id <id>2               ┆ <id>    │
+        └────────────────────────┴─────────┘
+
         Apply case-insensitive string replacement using the `(?i)` flag.
 
         >>> df = pl.DataFrame(
@@ -2151,6 +2623,183 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_reverse())
 
+    def levenshtein(
+        self, other: str | Expr, *, max_distance: int | None = None
+    ) -> Expr:
+        """
+        Compute the Levenshtein distance to another string.
+
+        The distance is the minimal number of inserted, deleted or substituted
+        characters needed to turn one string into the other.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input. Strings are parsed
+            as literals.
+        max_distance
+            Return null for distances above this value. The computation of such
+            a pair stops as soon as the distance is known to exceed it, which makes
+            filtering for close matches much cheaper.
+
+        See Also
+        --------
+        hamming : Count the characters that differ between equally long strings.
+        jaro_winkler : Compute the Jaro-Winkler similarity to another string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "polars", None],
+        ...         "b": ["sitting", "lawn", "polars", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     dist=pl.col("a").str.levenshtein(pl.col("b")),
+        ...     close=pl.col("a").str.levenshtein(pl.col("b"), max_distance=2),
+        ... )
+        shape: (4, 4)
+        ┌────────┬─────────┬──────┬───────┐
+        │ a      ┆ b       ┆ dist ┆ close │
+        │ ---    ┆ ---     ┆ ---  ┆ ---   │
+        │ str    ┆ str     ┆ u32  ┆ u32   │
+        ╞════════╪═════════╪══════╪═══════╡
+        │ kitten ┆ sitting ┆ 3    ┆ null  │
+        │ flaw   ┆ lawn    ┆ 2    ┆ 2     │
+        │ polars ┆ polars  ┆ 0    ┆ 0     │
+        │ null   ┆ x       ┆ null ┆ null  │
+        └────────┴─────────┴──────┴───────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein(other, max_distance))
+
+    def hamming(self, other: str | Expr, *, max_distance: int | None = None) -> Expr:
+        """
+        Count the characters that differ from another string of the same length.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input. Strings are parsed
+            as literals.
+        max_distance
+            Return null for distances above this value.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`. The result is null if the
+            strings have a different number of characters.
+
+        See Also
+        --------
+        levenshtein : Compute the Levenshtein distance to another string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["karolin", "1011101", "abc"], "b": ["kathrin", "1001001", "ab"]}
+        ... )
+        >>> df.with_columns(dist=pl.col("a").str.hamming(pl.col("b")))
+        shape: (3, 3)
+        ┌─────────┬─────────┬──────┐
+        │ a       ┆ b       ┆ dist │
+        │ ---     ┆ ---     ┆ ---  │
+        │ str     ┆ str     ┆ u32  │
+        ╞═════════╪═════════╪══════╡
+        │ karolin ┆ kathrin ┆ 3    │
+        │ 1011101 ┆ 1001001 ┆ 2    │
+        │ abc     ┆ ab      ┆ null │
+        └─────────┴─────────┴──────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming(other, max_distance))
+
+    def jaro_winkler(self, other: str | Expr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity is between 0 and 1, where 1 means the strings are equal.
+        Strings that share a prefix of up to four characters score higher.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input. Strings are parsed
+            as literals.
+
+        See Also
+        --------
+        levenshtein : Compute the Levenshtein distance to another string.
+        token_set_ratio : Compare the words of two strings, ignoring their order.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["martha", "dixon", "polars"],
+        ...         "b": ["marhta", "dicksonx", "bears"],
+        ...     }
+        ... )
+        >>> df.with_columns(sim=pl.col("a").str.jaro_winkler(pl.col("b")).round(3))
+        shape: (3, 3)
+        ┌────────┬──────────┬───────┐
+        │ a      ┆ b        ┆ sim   │
+        │ ---    ┆ ---      ┆ ---   │
+        │ str    ┆ str      ┆ f64   │
+        ╞════════╪══════════╪═══════╡
+        │ martha ┆ marhta   ┆ 0.961 │
+        │ dixon  ┆ dicksonx ┆ 0.813 │
+        │ polars ┆ bears    ┆ 0.7   │
+        └────────┴──────────┴───────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaro_winkler(other))
+
+    def token_set_ratio(self, other: str | Expr) -> Expr:
+        """
+        Compare the whitespace separated words of two strings, ignoring their order.
+
+        The words common to both strings are compared with the common words followed
+        by the remaining words of either string, and the best
+        `1 - (insertions + deletions) / (len(a) + len(b))` similarity is returned.
+        The ratio is between 0 and 1, and is 1 if the words of one string are a subset
+        of the words of the other.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input. Strings are parsed
+            as literals.
+
+        See Also
+        --------
+        jaro_winkler : Compute the Jaro-Winkler similarity to another string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["new york mets", "york", "great apple"],
+        ...         "b": ["mets new york", "new york mets", "great banana"],
+        ...     }
+        ... )
+        >>> df.with_columns(ratio=pl.col("a").str.token_set_ratio(pl.col("b")))
+        shape: (3, 3)
+        ┌───────────────┬───────────────┬───────┐
+        │ a             ┆ b             ┆ ratio │
+        │ ---           ┆ ---           ┆ ---   │
+        │ str           ┆ str           ┆ f64   │
+        ╞═══════════════╪═══════════════╪═══════╡
+        │ new york mets ┆ mets new york ┆ 1.0   │
+        │ york          ┆ new york mets ┆ 1.0   │
+        │ great apple   ┆ great banana  ┆ 0.625 │
+        └───────────────┴───────────────┴───────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_token_set_ratio(other))
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Expr:
@@ -2980,6 +3629,196 @@ class ExprStringNameSpace:
         """  # noqa: RUF002
         return wrap_expr(self._pyexpr.str_normalize(form))
 
+    def strip_diacritics(self) -> Expr:
+        """
+        Remove the diacritics, such as accents, from the string values.
+
+        Characters are decomposed into a base character and combining marks (NFD),
+        the marks are removed and the result is composed again (NFC). Characters that
+        have no decomposition, such as "ø" or "ł", are kept as is.
+
+        See Also
+        --------
+        normalize
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["Crème Brûlée", "naïve", "Ångström", "Øresund"]})
+        >>> df.with_columns(stripped=pl.col("text").str.strip_diacritics())
+        shape: (4, 2)
+        ┌──────────────┬──────────────┐
+        │ text         ┆ stripped     │
+        │ ---          ┆ ---          │
+        │ str          ┆ str          │
+        ╞══════════════╪══════════════╡
+        │ Crème Brûlée ┆ Creme Brulee │
+        │ naïve        ┆ naive        │
+        │ Ångström     ┆ Angstrom     │
+        │ Øresund      ┆ Øresund      │
+        └──────────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_strip_diacritics())
+
+
+class ExprStringUrlNameSpace:
+    """Namespace for URL related expressions, under `expr.str.url`."""
+
+    _accessor = "str.url"
+
+    def __init__(self, str_namespace: ExprStringNameSpace) -> None:
+        # note: the namespace class itself is passed when building the docs
+        self._pyexpr = getattr(str_namespace, "_pyexpr", None)
+
+    def scheme(self) -> Expr:
+        """
+        Get the scheme of every URL, such as `"https"`, in lowercase.
+
+        URLs are split following the generic syntax of RFC 3986. The scheme is null
+        for relative URLs.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://Pola.rs:443/docs?q=a%20b",
+        ...             "mailto:info@pola.rs",
+        ...             "/search?q=polars+url",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(
+        ...     scheme=pl.col("url").str.url.scheme(),
+        ...     host=pl.col("url").str.url.host(),
+        ...     path=pl.col("url").str.url.path(),
+        ...     q=pl.col("url").str.url.query_param("q"),
+        ... )
+        shape: (3, 4)
+        ┌────────┬─────────┬──────────────┬────────────┐
+        │ scheme ┆ host    ┆ path         ┆ q          │
+        │ ---    ┆ ---     ┆ ---          ┆ ---        │
+        │ str    ┆ str     ┆ str          ┆ str        │
+        ╞════════╪═════════╪══════════════╪════════════╡
+        │ https  ┆ pola.rs ┆ /docs        ┆ a b        │
+        │ mailto ┆ null    ┆ info@pola.rs ┆ null       │
+        │ null   ┆ null    ┆ /search      ┆ polars url │
+        └────────┴─────────┴──────────────┴────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_url_scheme())
+
+    def host(self) -> Expr:
+        """
+        Get the host of every URL in lowercase, without the user info and the port.
+
+        The host is null for URLs without an authority, which starts with `"//"`, so
+        a URL such as `"pola.rs/docs"` has no host. See :meth:`scheme` for an example.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"url": ["https://user@Pola.rs:443/", "http://[::1]:80"]})
+        >>> df.select(pl.col("url").str.url.host())
+        shape: (2, 1)
+        ┌─────────┐
+        │ url     │
+        │ ---     │
+        │ str     │
+        ╞═════════╡
+        │ pola.rs │
+        │ [::1]   │
+        └─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_url_host())
+
+    def path(self) -> Expr:
+        """
+        Get the path of every URL, which is still percent-encoded.
+
+        The path can be empty, for example for `"https://pola.rs"`. Use :meth:`decode`
+        to decode it. See :meth:`scheme` for an example.
+        """
+        return wrap_expr(self._pyexpr.str_url_path())
+
+    def query_param(self, key: str) -> Expr:
+        """
+        Get the value of the first query parameter named `key` of every URL.
+
+        Both the names and the values are decoded, with `"+"` decoded as a space.
+        The value is null if the parameter is missing, and an empty string for a
+        parameter without value, such as `"flag"` in `"?flag&q=1"`.
+
+        Parameters
+        ----------
+        key
+            The (decoded) name of the query parameter.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"url": ["/a?id=1&tag=x%2By", "/b?tag", "/c"]})
+        >>> df.select(
+        ...     id=pl.col("url").str.url.query_param("id"),
+        ...     tag=pl.col("url").str.url.query_param("tag"),
+        ... )
+        shape: (3, 2)
+        ┌──────┬──────┐
+        │ id   ┆ tag  │
+        │ ---  ┆ ---  │
+        │ str  ┆ str  │
+        ╞══════╪══════╡
+        │ 1    ┆ x+y  │
+        │ null ┆      │
+        │ null ┆ null │
+        └──────┴──────┘
+        """
+        if not isinstance(key, str):
+            msg = f"`key` must be a string, got {type(key).__name__!r}"
+            raise TypeError(msg)
+        return wrap_expr(self._pyexpr.str_url_query_param(key))
+
+    def decode(self) -> Expr:
+        """
+        Decode the percent-encoded escapes, such as `"%20"`, of every string.
+
+        Invalid escapes are kept as they are, and strings that don't decode to valid
+        UTF-8 become null. Unlike :meth:`query_param`, `"+"` is not decoded.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"path": ["/caf%C3%A9/a%20b", "/100%", "/%FF"]})
+        >>> df.select(pl.col("path").str.url.decode())
+        shape: (3, 1)
+        ┌───────────┐
+        │ path      │
+        │ ---       │
+        │ str       │
+        ╞═══════════╡
+        │ /café/a b │
+        │ /100%     │
+        │ null      │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.str_url_decode())
+
+
+def _with_regex_flags(
+    pattern: IntoExprColumn,
+    *,
+    literal: bool = False,
+    ignore_case: bool,
+    multiline: bool,
+    dot_all: bool,
+) -> IntoExprColumn:
+    """Prefix a regex pattern with the inline syntax of the given flags."""
+    flags = "i" * ignore_case + "m" * multiline + "s" * dot_all
+    if not flags:
+        return pattern
+    if literal:
+        msg = "regex flags cannot be combined with `literal=True`"
+        raise ValueError(msg)
+    prefix = f"(?{flags})"
+    if isinstance(pattern, str):
+        return prefix + pattern
+    return F.concat_str([F.lit(prefix), pattern])
+
 
 def _validate_format_argument(format: str | None) -> None:
     if format is not None and ".%f" in format:
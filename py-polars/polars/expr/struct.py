@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import os
+from collections.abc import Mapping
 from typing import TYPE_CHECKING
 
 from polars._utils.parse import parse_into_list_of_expressions
@@ -188,14 +189,17 @@ class ExprStructNameSpace:
         """
         return self.field("*")
 
-    def rename_fields(self, names: Sequence[str]) -> Expr:
+    def rename_fields(self, names: Sequence[str] | Mapping[str, str]) -> Expr:
         """
         Rename the fields of the struct.
 
         Parameters
         ----------
         names
-            New names, given in the same order as the struct's fields.
+            New names, given in the same order as the struct's fields, or a mapping
+            of field names to new names. Fields missing from the mapping keep their
+            name, and a dotted key such as `"a.b"` renames field `b` of the nested
+            struct field `a`.
 
         Examples
         --------
@@ -249,9 +253,53 @@ class ExprStructNameSpace:
 
         >>> df.select(pl.col("struct_col").struct.field("aaa"))  # doctest: +SKIP
         StructFieldNotFoundError: aaa
+
+        Rename a single (nested) field with a mapping:
+
+        >>> df = pl.DataFrame({"s": [{"a": 1, "b": {"c": 2}}]})
+        >>> df.select(
+        ...     pl.col("s").struct.rename_fields({"a": "x", "b.c": "y"})
+        ... ).schema
+        Schema({'s': Struct({'x': Int64, 'b': Struct({'y': Int64})})})
         """
+        if isinstance(names, Mapping):
+            return wrap_expr(
+                self._pyexpr.struct_rename_fields_by_path(list(names.items()))
+            )
         return wrap_expr(self._pyexpr.struct_rename_fields(names))
 
+    def drop_fields(self, name: str | list[str], *more_names: str) -> Expr:
+        """
+        Drop fields of the struct.
+
+        Parameters
+        ----------
+        name
+            Name of the field to drop, or a list of names.
+        *more_names
+            Additional names of fields to drop, specified as positional arguments.
+
+        Notes
+        -----
+        A dotted name such as `"a.b"` drops field `b` of the nested struct field
+        `a`, unless the struct has a field named `"a.b"`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"s": [{"a": 1, "b": {"c": 2, "d": 3}, "e": 4}]})
+        >>> df.select(pl.col("s").struct.drop_fields("a", "b.c"))
+        shape: (1, 1)
+        ┌───────────┐
+        │ s         │
+        │ ---       │
+        │ struct[2] │
+        ╞═══════════╡
+        │ {{3},4}   │
+        └───────────┘
+        """
+        names = [*([name] if isinstance(name, str) else name), *more_names]
+        return wrap_expr(self._pyexpr.struct_drop_fields(names))
+
     def json_encode(self) -> Expr:
         """
         Convert this struct to a string column with json values.
@@ -346,6 +394,7 @@ class ExprStructNameSpace:
         See Also
         --------
         field
+        with_fields_by_path
         """
         structify = bool(int(os.environ.get("POLARS_AUTO_STRUCTIFY", 0)))
 
@@ -354,3 +403,48 @@ class ExprStructNameSpace:
         )
 
         return wrap_expr(self._pyexpr.struct_with_fields(pyexprs))
+
+    def with_fields_by_path(
+        self,
+        *exprs: IntoExpr | Iterable[IntoExpr],
+        **named_exprs: IntoExpr,
+    ) -> Expr:
+        """
+        Add or overwrite fields of this struct, addressing nested fields by path.
+
+        This is like :meth:`with_fields`, but a dotted name such as `"a.b"` adds or
+        overwrites field `b` of the nested struct field `a`, unless the struct has a
+        field named `"a.b"`.
+
+        Parameters
+        ----------
+        *exprs
+            Field(s) to add, specified as positional arguments.
+            Accepts expression input. Strings are parsed as column names, other
+            non-expression inputs are parsed as literals.
+        **named_exprs
+            Additional fields to add, specified as keyword arguments.
+            The columns will be renamed to the keyword used.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"s": [{"a": {"b": 1}}]})
+        >>> df.select(
+        ...     pl.col("s").struct.with_fields_by_path(
+        ...         (pl.field("a").struct.field("b") * 10).alias("a.b"),
+        ...         **{"a.c": pl.lit("x")},
+        ...     )
+        ... ).item()
+        {'a': {'b': 10, 'c': 'x'}}
+
+        See Also
+        --------
+        with_fields
+        """
+        structify = bool(int(os.environ.get("POLARS_AUTO_STRUCTIFY", 0)))
+
+        pyexprs = parse_into_list_of_expressions(
+            *exprs, **named_exprs, __structify=structify
+        )
+
+        return wrap_expr(self._pyexpr.struct_with_fields_by_path(pyexprs))
@@ -0,0 +1,46 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+class ExprUuidNameSpace:
+    """Namespace for uuid related expressions."""
+
+    _accessor = "uuid"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def to_string(self) -> Expr:
+        """
+        Format UUIDs in the lowercase hyphenated form.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        See Also
+        --------
+        polars.Expr.str.to_uuid : Parse UUIDs from their textual form.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"id": ["67E55044-10B1-426F-9247-BB680E5FE0C8", None]})
+        >>> df.select(pl.col("id").str.to_uuid().uuid.to_string())
+        shape: (2, 1)
+        ┌──────────────────────────────────────┐
+        │ id                                   │
+        │ ---                                  │
+        │ str                                  │
+        ╞══════════════════════════════════════╡
+        │ 67e55044-10b1-426f-9247-bb680e5fe0c8 │
+        │ null                                 │
+        └──────────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_uuid_to_string())
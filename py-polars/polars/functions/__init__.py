@@ -10,6 +10,7 @@ from polars.functions.aggregation import (
     mean_horizontal,
     min,
     min_horizontal,
+    rank_horizontal,
     sum,
     sum_horizontal,
 )
@@ -54,6 +55,7 @@ from polars.functions.lazy import (
     head,
     implode,
     last,
+    lstsq,
     map_batches,
     map_groups,
     mean,
@@ -63,7 +65,10 @@ from polars.functions.lazy import (
     quantile,
     reduce,
     rolling_corr,
+    rolling_corr_by,
     rolling_cov,
+    rolling_cov_by,
+    rolling_ols,
     select,
     sql_expr,
     std,
@@ -102,6 +107,7 @@ __all__ = [
     "cum_sum_horizontal",
     "max_horizontal",
     "min_horizontal",
+    "rank_horizontal",
     "sum_horizontal",
     # polars.functions.eager
     "align_frames",
@@ -156,6 +162,7 @@ __all__ = [
     "linear_space",
     "linear_spaces",
     "lit",
+    "lstsq",
     "map_batches",
     "map_groups",
     "mean",
@@ -166,7 +173,10 @@ __all__ = [
     "quantile",
     "reduce",
     "rolling_corr",
+    "rolling_corr_by",
     "rolling_cov",
+    "rolling_cov_by",
+    "rolling_ols",
     "select",
     "set_random_seed",
     "std",
@@ -5,6 +5,7 @@ from polars.functions.aggregation.horizontal import (
     max_horizontal,
     mean_horizontal,
     min_horizontal,
+    rank_horizontal,
     sum_horizontal,
 )
 from polars.functions.aggregation.vertical import (
@@ -28,6 +29,7 @@ __all__ = [
     "mean_horizontal",
     "min",
     "min_horizontal",
+    "rank_horizontal",
     "sum",
     "sum_horizontal",
 ]
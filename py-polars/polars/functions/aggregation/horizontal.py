@@ -3,10 +3,8 @@ from __future__ import annotations
 import contextlib
 from typing import TYPE_CHECKING
 
-import polars.functions as F
 from polars._utils.parse import parse_into_list_of_expressions
 from polars._utils.wrap import wrap_expr
-from polars.datatypes import UInt32
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     import polars.polars as plr
@@ -15,7 +13,7 @@ if TYPE_CHECKING:
     from collections.abc import Iterable
 
     from polars import Expr
-    from polars._typing import IntoExpr
+    from polars._typing import IntoExpr, NanPosition, RankMethod
 
 
 def all_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
@@ -290,9 +288,62 @@ def cum_sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     └─────┴──────┴─────┴───────────┘
     """
     pyexprs = parse_into_list_of_expressions(*exprs)
-    exprs_wrapped = [wrap_expr(e) for e in pyexprs]
+    return wrap_expr(plr.cum_sum_horizontal(pyexprs, False)).alias("cum_sum")
 
-    # (Expr): use u32 as that will not cast to float as eagerly
-    return F.cum_fold(F.lit(0).cast(UInt32), lambda a, b: a + b, exprs_wrapped).alias(
-        "cum_sum"
-    )
+
+def rank_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr],
+    method: RankMethod = "dense",
+    descending: bool = False,
+    nan_position: NanPosition = "largest",
+) -> Expr:
+    """
+    Rank the values of every row across columns.
+
+    Each row is ranked on its own, without building a list per row. Null values are
+    not ranked.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to rank. Accepts expression input. Strings are parsed as column
+        names, other non-expression inputs are parsed as literals.
+    method : {'average', 'min', 'max', 'dense', 'ordinal'}
+        The method used to assign ranks to tied values, see :meth:`Expr.rank`.
+        With `'ordinal'`, ties are ranked in the order of the columns.
+    descending
+        Rank in descending order.
+    nan_position : {'largest', 'first', 'last'}
+        Which ranks NaN values receive, see :meth:`Expr.rank`.
+
+    Returns
+    -------
+    Expr
+        A struct named `rank` with a field per input column. The ranks are of type
+        :class:`Float64` for `method='average'`, and :class:`UInt32` otherwise.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [1, 8, 3],
+    ...         "b": [4, 5, None],
+    ...         "c": [2, 8, 1],
+    ...     }
+    ... )
+    >>> df.with_columns(pl.rank_horizontal("a", "b", "c", method="min"))
+    shape: (3, 4)
+    ┌─────┬──────┬─────┬────────────┐
+    │ a   ┆ b    ┆ c   ┆ rank       │
+    │ --- ┆ ---  ┆ --- ┆ ---        │
+    │ i64 ┆ i64  ┆ i64 ┆ struct[3]  │
+    ╞═════╪══════╪═════╪════════════╡
+    │ 1   ┆ 4    ┆ 2   ┆ {1,3,2}    │
+    │ 8   ┆ 5    ┆ 8   ┆ {2,1,2}    │
+    │ 3   ┆ null ┆ 1   ┆ {2,null,1} │
+    └─────┴──────┴─────┴────────────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    return wrap_expr(
+        plr.rank_horizontal(pyexprs, method, descending, nan_position)
+    ).alias("rank")
@@ -1,21 +1,22 @@
 from __future__ import annotations
 
 import contextlib
-from datetime import date
 from typing import TYPE_CHECKING
 
 from polars._utils.deprecation import deprecate_nonkeyword_arguments
 from polars._utils.parse import parse_into_expression
 from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
+from polars.business import _business_day_args
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     import polars.polars as plr
 
 if TYPE_CHECKING:
     from collections.abc import Iterable
+    from datetime import date
 
-    from polars import Expr
+    from polars import BusinessCalendar, Expr
     from polars._typing import IntoExprColumn
 
 
@@ -26,6 +27,7 @@ def business_day_count(
     end: date | IntoExprColumn,
     week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
     holidays: Iterable[date] = (),
+    calendar: BusinessCalendar | None = None,
 ) -> Expr:
     """
     Count the number of business days between `start` and `end` (not including `end`).
@@ -57,6 +59,9 @@ def business_day_count(
             my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
         and pass `holidays=my_holidays` when you call `business_day_count`.
+    calendar
+        A :class:`BusinessCalendar` to take the business days from, instead of
+        passing `week_mask` and `holidays`.
 
     Returns
     -------
@@ -124,12 +129,7 @@ def business_day_count(
     """
     start_pyexpr = parse_into_expression(start)
     end_pyexpr = parse_into_expression(end)
-    unix_epoch = date(1970, 1, 1)
+    week_mask, holidays_int = _business_day_args(week_mask, holidays, calendar)
     return wrap_expr(
-        plr.business_day_count(
-            start_pyexpr,
-            end_pyexpr,
-            week_mask,
-            [(holiday - unix_epoch).days for holiday in holidays],
-        )
+        plr.business_day_count(start_pyexpr, end_pyexpr, week_mask, holidays_int)
     )
@@ -2,11 +2,13 @@ from __future__ import annotations
 
 import contextlib
 from collections.abc import Sequence
+from datetime import timedelta
 from typing import TYPE_CHECKING, Any, Callable, overload
 
 import polars._reexport as pl
 import polars.functions as F
 from polars._utils.async_ import _AioDataFrameResult, _GeventDataFrameResult
+from polars._utils.convert import parse_as_duration_string
 from polars._utils.deprecation import (
     deprecate_function,
     deprecate_renamed_parameter,
@@ -32,10 +34,12 @@ if TYPE_CHECKING:
 
     from polars import DataFrame, Expr, LazyFrame, Series
     from polars._typing import (
+        ClosedInterval,
         CorrelationMethod,
         EngineType,
         EpochTimeUnit,
         IntoExpr,
+        OlsMode,
         PolarsDataType,
         RollingInterpolationMethod,
     )
@@ -1643,6 +1647,7 @@ def arg_sort_by(
     nulls_last: bool | Sequence[bool] = False,
     multithreaded: bool = True,
     maintain_order: bool = False,
+    limit: int | None = None,
 ) -> Expr:
     """
     Return the row indices that would sort the column(s).
@@ -1663,6 +1668,9 @@ def arg_sort_by(
         Sort using multiple threads.
     maintain_order
         Whether the order should be maintained if elements are equal.
+    limit
+        Only return the indices of the first `limit` rows of the sort order. This
+        does a partial sort, which is cheaper than sorting all rows.
 
     See Also
     --------
@@ -1723,12 +1731,30 @@ def arg_sort_by(
     │ 2   │
     │ 3   │
     └─────┘
+
+    Get the indices of the two smallest rows by `a`, and by `b` descending on ties.
+
+    >>> df.select(pl.arg_sort_by("a", "b", descending=[False, True], limit=2))
+    shape: (2, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 0   │
+    │ 3   │
+    └─────┘
     """
     exprs = parse_into_list_of_expressions(exprs, *more_exprs)
     descending = extend_bool(descending, len(exprs), "descending", "exprs")
     nulls_last = extend_bool(nulls_last, len(exprs), "nulls_last", "exprs")
+    if limit is not None and limit < 0:
+        msg = f"`limit` must be non-negative, got {limit}"
+        raise ValueError(msg)
     return wrap_expr(
-        plr.arg_sort_by(exprs, descending, nulls_last, multithreaded, maintain_order)
+        plr.arg_sort_by(
+            exprs, descending, nulls_last, multithreaded, maintain_order, limit
+        )
     )
 
 
@@ -2449,6 +2475,357 @@ def rolling_corr(
     )
 
 
+@unstable()
+def rolling_cov_by(
+    a: str | Expr,
+    b: str | Expr,
+    by: IntoExpr,
+    window_size: timedelta | str,
+    *,
+    min_samples: int = 1,
+    closed: ClosedInterval = "right",
+    ddof: int = 1,
+) -> Expr:
+    """
+    Compute the rolling covariance between two columns/ expressions based on `by`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+    (the default) means the windows will be:
+
+        - (t_0 - window_size, t_0]
+        - (t_1 - window_size, t_1]
+        - ...
+        - (t_n - window_size, t_n]
+
+    Parameters
+    ----------
+    a
+        Column name or Expression.
+    b
+        Column name or Expression.
+    by
+        Should be ``DateTime``, ``Date``, ``UInt64``, ``UInt32``, ``Int64``,
+        or ``Int32`` data type (note that the integral ones require using `'i'`
+        in `window size`). It must be sorted in ascending order.
+    window_size
+        The length of the window. Can be a dynamic temporal
+        size indicated by a timedelta or the same string language as
+        :meth:`Expr.rolling_mean_by`.
+    min_samples
+        The number of values in the window that should be non-null before computing
+        a result.
+    closed : {'left', 'right', 'both', 'none'}
+        Define which sides of the temporal interval are closed (inclusive),
+        defaults to `'right'`.
+    ddof
+        Delta degrees of freedom. The divisor used in calculations
+        is `N - ddof`, where `N` represents the number of elements.
+
+    Examples
+    --------
+    >>> from datetime import datetime
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "date": pl.datetime_range(
+    ...             datetime(2001, 1, 1), datetime(2001, 1, 1, 4), "1h", eager=True
+    ...         ),
+    ...         "a": [1, 4, 2, 9, 3],
+    ...         "b": [2, 3, 7, 8, 4],
+    ...     }
+    ... )
+    >>> df.select(pl.rolling_cov_by("a", "b", "date", window_size="3h"))
+    shape: (5, 1)
+    ┌──────────┐
+    │ a        │
+    │ ---      │
+    │ f64      │
+    ╞══════════╡
+    │ null     │
+    │ 1.5      │
+    │ 0.0      │
+    │ 4.0      │
+    │ 4.666667 │
+    └──────────┘
+    """
+    if isinstance(window_size, timedelta):
+        window_size = parse_as_duration_string(window_size)
+    if isinstance(a, str):
+        a = F.col(a)
+    if isinstance(b, str):
+        b = F.col(b)
+    by = parse_into_expression(by)
+    return wrap_expr(
+        plr.rolling_cov_by(
+            a._pyexpr, b._pyexpr, by, window_size, min_samples, closed, ddof
+        )
+    )
+
+
+@unstable()
+def rolling_corr_by(
+    a: str | Expr,
+    b: str | Expr,
+    by: IntoExpr,
+    window_size: timedelta | str,
+    *,
+    min_samples: int = 1,
+    closed: ClosedInterval = "right",
+    ddof: int = 1,
+) -> Expr:
+    """
+    Compute the rolling correlation between two columns/ expressions based on `by`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+    (the default) means the windows will be:
+
+        - (t_0 - window_size, t_0]
+        - (t_1 - window_size, t_1]
+        - ...
+        - (t_n - window_size, t_n]
+
+    Parameters
+    ----------
+    a
+        Column name or Expression.
+    b
+        Column name or Expression.
+    by
+        Should be ``DateTime``, ``Date``, ``UInt64``, ``UInt32``, ``Int64``,
+        or ``Int32`` data type (note that the integral ones require using `'i'`
+        in `window size`). It must be sorted in ascending order.
+    window_size
+        The length of the window. Can be a dynamic temporal
+        size indicated by a timedelta or the same string language as
+        :meth:`Expr.rolling_mean_by`.
+    min_samples
+        The number of values in the window that should be non-null before computing
+        a result.
+    closed : {'left', 'right', 'both', 'none'}
+        Define which sides of the temporal interval are closed (inclusive),
+        defaults to `'right'`.
+    ddof
+        Delta degrees of freedom. The divisor used in calculations
+        is `N - ddof`, where `N` represents the number of elements.
+
+    Examples
+    --------
+    >>> from datetime import datetime, timedelta
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "date": pl.datetime_range(
+    ...             datetime(2001, 1, 1), datetime(2001, 1, 1, 4), "1h", eager=True
+    ...         ),
+    ...         "a": [1, 4, 2, 9, 3],
+    ...         "b": [2, 3, 7, 8, 4],
+    ...     }
+    ... )
+    >>> df.select(
+    ...     pl.rolling_corr_by("a", "b", "date", window_size=timedelta(hours=3))
+    ... )
+    shape: (5, 1)
+    ┌──────────┐
+    │ a        │
+    │ ---      │
+    │ f64      │
+    ╞══════════╡
+    │ null     │
+    │ 1.0      │
+    │ 0.0      │
+    │ 0.419314 │
+    │ 0.592137 │
+    └──────────┘
+    """
+    if isinstance(window_size, timedelta):
+        window_size = parse_as_duration_string(window_size)
+    if isinstance(a, str):
+        a = F.col(a)
+    if isinstance(b, str):
+        b = F.col(b)
+    by = parse_into_expression(by)
+    return wrap_expr(
+        plr.rolling_corr_by(
+            a._pyexpr, b._pyexpr, by, window_size, min_samples, closed, ddof
+        )
+    )
+
+
+@unstable()
+def lstsq(
+    y: IntoExpr,
+    x: IntoExpr | Iterable[IntoExpr],
+    *,
+    fit_intercept: bool = False,
+    mode: OlsMode = "coefficients",
+) -> Expr:
+    """
+    Regress a column on one or more columns by ordinary least squares.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Rows with a null target or regressor are left out of the fit. If the
+    regressors are collinear, for example because one of them is constant while
+    `fit_intercept` is set, the coefficients are null.
+
+    Parameters
+    ----------
+    y
+        The target. Accepts expression input. Strings are parsed as column names.
+    x
+        The regressors. Accepts expression input. Strings are parsed as column names.
+    fit_intercept
+        Add a constant regressor, whose coefficient is the `"intercept"` field.
+    mode : {'coefficients', 'predictions', 'residuals'}
+        What to return.
+
+        - 'coefficients': a struct with a `Float64` field per regressor, named
+          after it. This is an aggregation.
+        - 'predictions': the fitted value of every row, null where a regressor is
+          null.
+        - 'residuals': the target minus the fitted value of every row, null where
+          the target or a regressor is null.
+
+    See Also
+    --------
+    rolling_ols
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "group": ["a", "a", "a", "b", "b", "b"],
+    ...         "y": [2.0, 4.1, 5.9, 1.0, 0.5, 0.1],
+    ...         "x": [1, 2, 3, 1, 2, 3],
+    ...     }
+    ... )
+    >>> df.group_by("group", maintain_order=True).agg(
+    ...     pl.lstsq("y", "x", fit_intercept=True).alias("coefficients")
+    ... ).unnest("coefficients")
+    shape: (2, 3)
+    ┌───────┬───────┬───────────┐
+    │ group ┆ x     ┆ intercept │
+    │ ---   ┆ ---   ┆ ---       │
+    │ str   ┆ f64   ┆ f64       │
+    ╞═══════╪═══════╪═══════════╡
+    │ a     ┆ 1.95  ┆ 0.1       │
+    │ b     ┆ -0.45 ┆ 1.433333  │
+    └───────┴───────┴───────────┘
+
+    The residuals of a regression within every group:
+
+    >>> df.with_columns(
+    ...     pl.lstsq("y", "x", fit_intercept=True, mode="residuals")
+    ...     .over("group")
+    ...     .alias("residual")
+    ... )
+    shape: (6, 4)
+    ┌───────┬─────┬─────┬───────────┐
+    │ group ┆ y   ┆ x   ┆ residual  │
+    │ ---   ┆ --- ┆ --- ┆ ---       │
+    │ str   ┆ f64 ┆ i64 ┆ f64       │
+    ╞═══════╪═════╪═════╪═══════════╡
+    │ a     ┆ 2.0 ┆ 1   ┆ -0.05     │
+    │ a     ┆ 4.1 ┆ 2   ┆ 0.1       │
+    │ a     ┆ 5.9 ┆ 3   ┆ -0.05     │
+    │ b     ┆ 1.0 ┆ 1   ┆ 0.016667  │
+    │ b     ┆ 0.5 ┆ 2   ┆ -0.033333 │
+    │ b     ┆ 0.1 ┆ 3   ┆ 0.016667  │
+    └───────┴─────┴─────┴───────────┘
+    """
+    y = parse_into_expression(y)
+    x = parse_into_list_of_expressions(x)
+    return wrap_expr(plr.lstsq(y, x, fit_intercept, mode))
+
+
+@unstable()
+def rolling_ols(
+    y: IntoExpr,
+    x: IntoExpr | Iterable[IntoExpr],
+    *,
+    window_size: int,
+    min_samples: int | None = None,
+    fit_intercept: bool = False,
+    mode: OlsMode = "coefficients",
+) -> Expr:
+    """
+    Regress a column on one or more columns over a rolling window.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    The window at a given row includes the row itself and the
+    `window_size - 1` elements before it. Rows with a null target or regressor
+    are left out of the fit of a window.
+
+    Parameters
+    ----------
+    y
+        The target. Accepts expression input. Strings are parsed as column names.
+    x
+        The regressors. Accepts expression input. Strings are parsed as column names.
+    window_size
+        The length of the window.
+    min_samples
+        The number of rows in the window without nulls that are required for a fit.
+        If None, it will be set equal to window size.
+    fit_intercept
+        Add a constant regressor, whose coefficient is the `"intercept"` field.
+    mode : {'coefficients', 'predictions', 'residuals'}
+        What to return for every row.
+
+        - 'coefficients': a struct with a `Float64` field per regressor, named
+          after it, holding the coefficients of the fit of the window.
+        - 'predictions': the fitted value of the row, using the fit of its window.
+        - 'residuals': the target minus the fitted value of the row.
+
+    See Also
+    --------
+    lstsq
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "y": [1.0, 3.0, 2.0, 4.5, 5.0, 7.5],
+    ...         "x": [1, 2, 3, 4, 5, 6],
+    ...     }
+    ... )
+    >>> df.select(
+    ...     pl.rolling_ols("y", "x", window_size=3, fit_intercept=True)
+    ... ).unnest("y")
+    shape: (6, 2)
+    ┌──────┬───────────┐
+    │ x    ┆ intercept │
+    │ ---  ┆ ---       │
+    │ f64  ┆ f64       │
+    ╞══════╪═══════════╡
+    │ null ┆ null      │
+    │ null ┆ null      │
+    │ 0.5  ┆ 1.0       │
+    │ 0.75 ┆ 0.916667  │
+    │ 1.5  ┆ -2.166667 │
+    │ 1.5  ┆ -1.833333 │
+    └──────┴───────────┘
+    """
+    if min_samples is None:
+        min_samples = window_size
+    y = parse_into_expression(y)
+    x = parse_into_list_of_expressions(x)
+    return wrap_expr(
+        plr.rolling_ols(y, x, window_size, min_samples, fit_intercept, mode)
+    )
+
+
 @overload
 def sql_expr(sql: str) -> Expr:  # type: ignore[overload-overlap]
     ...
@@ -20,6 +20,7 @@ from polars.io.partition import (
 )
 from polars.io.plugins import _defer as defer
 from polars.io.pyarrow_dataset import scan_pyarrow_dataset
+from polars.io.sink_report import SinkReport
 from polars.io.spreadsheet import read_excel, read_ods
 
 __all__ = [
@@ -30,6 +31,7 @@ __all__ = [
     "KeyedPartition",
     "BasePartitionContext",
     "KeyedPartitionContext",
+    "SinkReport",
     "read_avro",
     "read_clipboard",
     "read_csv",
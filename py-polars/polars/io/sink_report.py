@@ -0,0 +1,59 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from polars import DataFrame
+
+
+class SinkReport:
+    """
+    What a sink wrote, as returned by the `LazyFrame.sink_*` methods.
+
+    Use it to register the written files (e.g. in a catalog) without listing the
+    destination.
+
+    .. warning::
+        This functionality is currently considered **unstable**. It may be
+        changed at any point without it being considered a breaking change.
+
+    Examples
+    --------
+    >>> lf = pl.LazyFrame({"a": [3, 1, 2]})
+    >>> report = lf.sink_parquet("out.parquet", report_key="a")  # doctest: +SKIP
+    >>> report.rows  # doctest: +SKIP
+    3
+    >>> report.files.select("path", "rows", "key_min", "key_max")  # doctest: +SKIP
+    shape: (1, 4)
+    ┌─────────────┬──────┬─────────┬─────────┐
+    │ path        ┆ rows ┆ key_min ┆ key_max │
+    │ ---         ┆ ---  ┆ ---     ┆ ---     │
+    │ str         ┆ u64  ┆ i64     ┆ i64     │
+    ╞═════════════╪══════╪═════════╪═════════╡
+    │ out.parquet ┆ 3    ┆ 1       ┆ 3       │
+    └─────────────┴──────┴─────────┴─────────┘
+    """
+
+    def __init__(self, files: DataFrame) -> None:
+        self.files = files
+
+    #: A row per written file, with its `path` (null if the target is not a path),
+    #: the number of `rows` and the size in `bytes` (null if it is not known, e.g. for
+    #: cloud storage). With a `report_key`, the minimum and maximum of that column in
+    #: the file are in `key_min` and `key_max`.
+    files: DataFrame
+
+    @property
+    def rows(self) -> int:
+        """The total number of written rows."""
+        return self.files["rows"].sum()
+
+    @property
+    def bytes(self) -> int | None:
+        """The total size of the written files, `None` if it is not known."""
+        if self.files["bytes"].null_count() > 0:
+            return None
+        return self.files["bytes"].sum()
+
+    def __repr__(self) -> str:
+        return f"SinkReport(files={self.files.height}, rows={self.rows})"
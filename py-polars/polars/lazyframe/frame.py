@@ -26,6 +26,7 @@ from polars._typing import (
     PartitioningScheme,
 )
 from polars._utils.async_ import _AioDataFrameResult, _GeventDataFrameResult
+from polars._utils.constraints import constraint_violations, raise_on_violations
 from polars._utils.convert import negate_duration_string, parse_as_duration_string
 from polars._utils.deprecation import (
     deprecate_function,
@@ -114,6 +115,7 @@ if TYPE_CHECKING:
         AsofJoinStrategy,
         ClosedInterval,
         ColumnNameOrSelector,
+        ContractPolicy,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
@@ -122,6 +124,7 @@ if TYPE_CHECKING:
         IntoExpr,
         IntoExprColumn,
         IpcCompression,
+        JoinAlgorithm,
         JoinStrategy,
         JoinValidation,
         Label,
@@ -136,9 +139,12 @@ if TYPE_CHECKING:
         StartBy,
         SyncOnCloseMethod,
         UniqueKeepStrategy,
+        UpsampleFillStrategy,
+        ViolationStrategy,
     )
     from polars.dependencies import numpy as np
     from polars.io.cloud import CredentialProviderFunction
+    from polars.io.sink_report import SinkReport
 
     if sys.version_info >= (3, 10):
         from typing import Concatenate, ParamSpec
@@ -215,6 +221,26 @@ def _gpu_engine_callback(
     return partial(cudf_polars.execute_with_cudf, config=engine)
 
 
+def _parse_upsample_fill(
+    fill: UpsampleFillStrategy
+    | IntoExpr
+    | Mapping[str, UpsampleFillStrategy | IntoExpr]
+    | None,
+) -> Any:
+    """Convert the `fill` values that are not a strategy to expressions."""
+
+    def parse(value: UpsampleFillStrategy | IntoExpr) -> Any:
+        if isinstance(value, str) and value in ("forward", "backward", "interpolate"):
+            return value
+        return parse_into_expression(value, str_as_lit=True)
+
+    if fill is None:
+        return None
+    if isinstance(fill, Mapping):
+        return {name: parse(value) for name, value in fill.items()}
+    return parse(fill)
+
+
 class LazyFrame:
     """
     Representation of a Lazy computation graph/query against a DataFrame.
@@ -2437,6 +2463,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        zstd_dictionary_size: int | None = None,
         maintain_order: bool = True,
         type_coercion: bool = True,
         _type_check: bool = True,
@@ -2453,9 +2480,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
-    ) -> None: ...
+    ) -> SinkReport: ...
     @overload
     def sink_parquet(
         self,
@@ -2466,6 +2494,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        zstd_dictionary_size: int | None = None,
         maintain_order: bool = True,
         type_coercion: bool = True,
         _type_check: bool = True,
@@ -2482,6 +2511,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: Literal[True],
         engine: EngineType = "auto",
     ) -> LazyFrame: ...
@@ -2495,6 +2525,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        zstd_dictionary_size: int | None = None,
         maintain_order: bool = True,
         type_coercion: bool = True,
         _type_check: bool = True,
@@ -2511,9 +2542,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: bool = False,
         engine: EngineType = "auto",
-    ) -> LazyFrame | None:
+    ) -> LazyFrame | SinkReport:
         """
         Evaluate the query in streaming mode and write to a Parquet file.
 
@@ -2564,6 +2596,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         data_page_size
             Size limit of individual data pages.
             If not set defaults to 1024 * 1024 bytes
+        zstd_dictionary_size
+            Train a zstd dictionary of at most this many bytes on a sample of the pages
+            of every column chunk, and compress all its pages with it. This
+            substantially improves the compression of many small pages, e.g. of string
+            columns with a small `data_page_size`. Only applies to "zstd" compression.
+
+            .. warning::
+                The dictionary is not part of the Parquet specification, so the file
+                can then only be read by Polars.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -2613,6 +2654,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * `all` syncs the file contents and metadata.
         mkdir: bool
             Recursively create all the directories in the path.
+        report_key
+            Report the minimum and maximum of this column for every written file in
+            the returned :class:`SinkReport`.
         lazy: bool
             Wait to start execution until `collect` is called.
         engine
@@ -2629,7 +2673,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         Returns
         -------
-        DataFrame
+        SinkReport
+            What was written, or a LazyFrame that writes when it is collected if
+            `lazy=True`.
 
         Examples
         --------
@@ -2686,6 +2732,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "report_key": report_key,
         }
 
         lf = lf.sink_parquet(
@@ -2695,6 +2742,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             statistics=statistics,
             row_group_size=row_group_size,
             data_page_size=data_page_size,
+            zstd_dictionary_size=zstd_dictionary_size,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -2703,8 +2751,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         lf = LazyFrame._from_pyldf(lf)
 
         if not lazy:
-            lf.collect(engine=engine)
-            return None
+            from polars.io.sink_report import SinkReport
+
+            return SinkReport(lf.collect(engine=engine))
         return lf
 
     @overload
@@ -2730,9 +2779,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
-    ) -> None: ...
+    ) -> SinkReport: ...
     @overload
     def sink_ipc(
         self,
@@ -2756,6 +2806,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: Literal[True],
         engine: EngineType = "auto",
     ) -> LazyFrame: ...
@@ -2782,9 +2833,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: bool = False,
         engine: EngineType = "auto",
-    ) -> LazyFrame | None:
+    ) -> LazyFrame | SinkReport:
         """
         Evaluate the query in streaming mode and write to an IPC file.
 
@@ -2853,6 +2905,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * `all` syncs the file contents and metadata.
         mkdir: bool
             Recursively create all the directories in the path.
+        report_key
+            Report the minimum and maximum of this column for every written file in
+            the returned :class:`SinkReport`.
         lazy: bool
             Wait to start execution until `collect` is called.
         engine
@@ -2869,7 +2924,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         Returns
         -------
-        DataFrame
+        SinkReport
+            What was written, or a LazyFrame that writes when it is collected if
+            `lazy=True`.
 
         Examples
         --------
@@ -2909,6 +2966,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "report_key": report_key,
         }
 
         if compat_level is None:
@@ -2931,8 +2989,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         lf = LazyFrame._from_pyldf(lf)
 
         if not lazy:
-            lf.collect(engine=engine)
-            return None
+            from polars.io.sink_report import SinkReport
+
+            return SinkReport(lf.collect(engine=engine))
         return lf
 
     @overload
@@ -2969,9 +3028,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
-    ) -> None: ...
+    ) -> SinkReport: ...
     @overload
     def sink_csv(
         self,
@@ -3006,6 +3066,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: Literal[True],
         engine: EngineType = "auto",
     ) -> LazyFrame: ...
@@ -3043,9 +3104,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: bool = False,
         engine: EngineType = "auto",
-    ) -> LazyFrame | None:
+    ) -> LazyFrame | SinkReport:
         """
         Evaluate the query in streaming mode and write to a CSV file.
 
@@ -3159,6 +3221,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * `all` syncs the file contents and metadata.
         mkdir: bool
             Recursively create all the directories in the path.
+        report_key
+            Report the minimum and maximum of this column for every written file in
+            the returned :class:`SinkReport`.
         lazy: bool
             Wait to start execution until `collect` is called.
         engine
@@ -3175,7 +3240,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         Returns
         -------
-        DataFrame
+        SinkReport
+            What was written, or a LazyFrame that writes when it is collected if
+            `lazy=True`.
 
         Examples
         --------
@@ -3221,6 +3288,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "report_key": report_key,
         }
 
         lf = lf.sink_csv(
@@ -3246,8 +3314,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         lf = LazyFrame._from_pyldf(lf)
 
         if not lazy:
-            lf.collect(engine=engine)
-            return None
+            from polars.io.sink_report import SinkReport
+
+            return SinkReport(lf.collect(engine=engine))
         return lf
 
     @overload
@@ -3271,9 +3340,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
-    ) -> None: ...
+    ) -> SinkReport: ...
     @overload
     def sink_ndjson(
         self,
@@ -3295,6 +3365,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: Literal[True],
         engine: EngineType = "auto",
     ) -> LazyFrame: ...
@@ -3319,9 +3390,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        report_key: str | None = None,
         lazy: bool = False,
         engine: EngineType = "auto",
-    ) -> LazyFrame | None:
+    ) -> LazyFrame | SinkReport:
         """
         Evaluate the query in streaming mode and write to an NDJSON file.
 
@@ -3384,6 +3456,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             * `all` syncs the file contents and metadata.
         mkdir: bool
             Recursively create all the directories in the path.
+        report_key
+            Report the minimum and maximum of this column for every written file in
+            the returned :class:`SinkReport`.
         lazy: bool
             Wait to start execution until `collect` is called.
         engine
@@ -3400,7 +3475,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         Returns
         -------
-        DataFrame
+        SinkReport
+            What was written, or a LazyFrame that writes when it is collected if
+            `lazy=True`.
 
         Examples
         --------
@@ -3440,6 +3517,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "report_key": report_key,
         }
 
         lf = lf.sink_json(
@@ -3452,8 +3530,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         lf = LazyFrame._from_pyldf(lf)
 
         if not lazy:
-            lf.collect(engine=engine)
-            return None
+            from polars.io.sink_report import SinkReport
+
+            return SinkReport(lf.collect(engine=engine))
         return lf
 
     def _set_sink_optimizations(
@@ -3803,6 +3882,65 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return self._from_pyldf(self._ldf.cast(cast_map, strict))
 
+    @unstable()
+    def with_schema_contract(
+        self,
+        schema: SchemaDict | Sequence[tuple[str, PolarsDataType]],
+        *,
+        policy: ContractPolicy = "strict",
+    ) -> LazyFrame:
+        """
+        Enforce a declared schema, for example right after a scan or before a sink.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The schema of the LazyFrame is checked when this method is called, so a
+        pipeline stage fails early when its input or output doesn't match the
+        contract. The result has the columns of the contract, in its order.
+
+        Parameters
+        ----------
+        schema
+            The declared schema.
+        policy : {'strict', 'coerce'}
+            How to treat a LazyFrame that doesn't match the contract. Missing columns
+            always raise.
+
+            - 'strict': Raise on columns that aren't in the contract and on differing
+              data types.
+            - 'coerce': Drop columns that aren't in the contract and cast to the
+              declared data types. Values that can't be cast raise when the query is
+              collected.
+
+        Raises
+        ------
+        SchemaError
+            If the LazyFrame violates the contract. The error lists all violations.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"id": [1, 2], "name": ["a", "b"], "extra": [True, False]})
+        >>> contract = {"id": pl.Int32, "name": pl.String}
+        >>> lf.with_schema_contract(contract, policy="coerce").collect()
+        shape: (2, 2)
+        ┌─────┬──────┐
+        │ id  ┆ name │
+        │ --- ┆ ---  │
+        │ i32 ┆ str  │
+        ╞═════╪══════╡
+        │ 1   ┆ a    │
+        │ 2   ┆ b    │
+        └─────┴──────┘
+        >>> lf.with_schema_contract(contract)  # doctest: +SKIP
+        Traceback (most recent call last):
+        ...
+        polars.exceptions.SchemaError: schema contract violated: column "id" has data type i64, expected i32; unexpected column "extra"
+        """  # noqa: W505
+        ldf = self._ldf.with_schema_contract(Schema(schema), policy)
+        return self._from_pyldf(ldf)
+
     def clear(self, n: int = 0) -> LazyFrame:
         """
         Create an empty copy of the current LazyFrame, with zero to 'n' rows.
@@ -4687,6 +4825,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        fill_gaps: UpsampleFillStrategy
+        | IntoExpr
+        | Mapping[str, UpsampleFillStrategy | IntoExpr]
+        | None = None,
     ) -> LazyGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -4757,6 +4899,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        fill_gaps
+            Insert a row for every window without data and fill its aggregated
+            columns, see :meth:`LazyFrame.upsample` for the accepted strategies. Pass a
+            mapping of column names to strategies to fill every column differently;
+            columns that are not in the mapping are left null. Only supported with
+            `label='left'` and column names as `index_column` and `group_by`.
 
         Returns
         -------
@@ -4786,8 +4934,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                df.set_index("ts").resample("D")["value"].sum().reset_index()
 
            though note that, unlike pandas, polars doesn't add extra rows for empty
-           windows. If you need `index_column` to be evenly spaced, then please combine
-           with :func:`DataFrame.upsample`.
+           windows unless `fill_gaps` is set. If you need `index_column` to be evenly
+           spaced, then please pass `fill_gaps` or combine with
+           :func:`DataFrame.upsample`.
 
         2) The `every`, `period` and `offset` arguments are created with
            the following string language:
@@ -5007,7 +5156,133 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             pyexprs_by,
             start_by,
         )
-        return LazyGroupBy(lgb)
+        if fill_gaps is None:
+            return LazyGroupBy(lgb)
+        return LazyGroupBy(lgb, fill_gaps=_parse_upsample_fill(fill_gaps))
+
+    def upsample(
+        self,
+        time_column: str,
+        *,
+        every: str | timedelta,
+        group_by: str | Sequence[str] | None = None,
+        maintain_order: bool = False,
+        fill: UpsampleFillStrategy
+        | IntoExpr
+        | Mapping[str, UpsampleFillStrategy | IntoExpr]
+        | None = None,
+    ) -> LazyFrame:
+        """
+        Upsample a LazyFrame at a regular frequency.
+
+        This inserts a row for every point of a regular grid between the first and the
+        last value of `time_column` that does not occur in the data. The `every`
+        argument uses the string language of :meth:`DataFrame.upsample`; integer
+        time columns take an index count such as `"2i"`.
+
+        Parameters
+        ----------
+        time_column
+            Time column will be used to determine a date_range.
+        every
+            Interval will start 'every' duration.
+        group_by
+            First group by these columns and then upsample for every group.
+        maintain_order
+            Keep the ordering predictable. This is slower.
+        fill
+            How to fill the other columns of the inserted rows. Rows that are in the
+            data are never changed, including their null values.
+
+            - None: leave the inserted values null.
+            - 'forward': take the previous value.
+            - 'backward': take the next value.
+            - 'interpolate': interpolate linearly against `time_column`.
+            - any other value or expression: fill with this value.
+
+            The group is taken into account if `group_by` is set. Pass a mapping of
+            column names to strategies to fill every column differently; columns that
+            are not in the mapping are left null.
+
+        Returns
+        -------
+        LazyFrame
+            Result will be sorted by `time_column` (but note that if `group_by` columns
+            are passed, it will only be sorted within each group). The columns of the
+            inserted rows that are in `group_by` hold the group key.
+
+        See Also
+        --------
+        DataFrame.upsample
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "time": [date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 5)],
+        ...         "values": [1.0, 2.0, 5.0],
+        ...     }
+        ... )
+        >>> lf.upsample("time", every="1d", fill="interpolate").collect()
+        shape: (5, 2)
+        ┌────────────┬────────┐
+        │ time       ┆ values │
+        │ ---        ┆ ---    │
+        │ date       ┆ f64    │
+        ╞════════════╪════════╡
+        │ 2024-01-01 ┆ 1.0    │
+        │ 2024-01-02 ┆ 2.0    │
+        │ 2024-01-03 ┆ 3.0    │
+        │ 2024-01-04 ┆ 4.0    │
+        │ 2024-01-05 ┆ 5.0    │
+        └────────────┴────────┘
+
+        Upsample every group separately and fill with a default value.
+
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "time": [
+        ...             date(2024, 1, 1),
+        ...             date(2024, 1, 3),
+        ...             date(2024, 1, 1),
+        ...             date(2024, 1, 2),
+        ...         ],
+        ...         "group": ["a", "a", "b", "b"],
+        ...         "values": [1, 3, 10, 20],
+        ...     }
+        ... )
+        >>> lf.upsample(
+        ...     "time", every="1d", group_by="group", maintain_order=True, fill=0
+        ... ).collect()
+        shape: (5, 3)
+        ┌────────────┬───────┬────────┐
+        │ time       ┆ group ┆ values │
+        │ ---        ┆ ---   ┆ ---    │
+        │ date       ┆ str   ┆ i64    │
+        ╞════════════╪═══════╪════════╡
+        │ 2024-01-01 ┆ a     ┆ 1      │
+        │ 2024-01-02 ┆ a     ┆ 0      │
+        │ 2024-01-03 ┆ a     ┆ 3      │
+        │ 2024-01-01 ┆ b     ┆ 10     │
+        │ 2024-01-02 ┆ b     ┆ 20     │
+        └────────────┴───────┴────────┘
+        """
+        if group_by is None:
+            group_by = []
+        if isinstance(group_by, str):
+            group_by = [group_by]
+
+        every = parse_as_duration_string(every)
+        return self._from_pyldf(
+            self._ldf.upsample(
+                time_column,
+                every,
+                list(group_by),
+                maintain_order,
+                _parse_upsample_fill(fill),
+            )
+        )
 
     def join_asof(
         self,
@@ -5396,6 +5671,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         nulls_equal: bool = False,
         coalesce: bool | None = None,
         maintain_order: MaintainOrderJoin | None = None,
+        algorithm: JoinAlgorithm = "auto",
         allow_parallel: bool = True,
         force_parallel: bool = False,
     ) -> LazyFrame:
@@ -5479,6 +5755,22 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 First preserves the order of the left DataFrame, then the right.
             * *right_left*
                 First preserves the order of the right DataFrame, then the left.
+        algorithm : {'auto', 'hash', 'merge'}
+            The algorithm used to match the join keys.
+
+            * *auto*
+                Use a sorted merge join if both keys are flagged as sorted,
+                otherwise a hash join.
+            * *hash*
+                Always use a hash join.
+            * *merge*
+                Merge the keys, which must be sorted in ascending order and may not
+                contain nulls. Only inner, left and right joins on a single numeric
+                key are supported.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
         allow_parallel
             Allow the physical plan to optionally evaluate the computation of both
             DataFrames up to the join in parallel.
@@ -5623,6 +5915,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                     suffix,
                     validate,
                     maintain_order,
+                    algorithm=algorithm,
                 )
             )
 
@@ -5650,6 +5943,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 validate,
                 maintain_order,
                 coalesce,
+                algorithm,
             )
         )
 
@@ -6553,6 +6847,36 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             msg = f"`offset` input for `with_row_index` cannot be {issue}, got {offset}"
             raise ValueError(msg) from None
 
+    @unstable()
+    def with_random_seed(self, seed: int) -> LazyFrame:
+        """
+        Seed the random expressions of the query, so it gives the same result every run.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Every random expression of the LazyFrame that has no seed of its own, such as
+        :meth:`Expr.sample`, :meth:`Expr.shuffle`, :meth:`Expr.rank` with
+        `method="random"` and :meth:`Expr.list.sample`, gets a different seed derived
+        from `seed`. The derived seeds don't depend on the number of threads, so the
+        result is the same for any parallelism. Random expressions that are added
+        after this call are not seeded.
+
+        Parameters
+        ----------
+        seed
+            The seed from which the seeds of the random expressions are derived.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": range(10)})
+        >>> sampled = lf.select(pl.col("a").shuffle()).with_random_seed(42)
+        >>> sampled.collect().equals(sampled.collect())
+        True
+        """
+        return self._from_pyldf(self._ldf.with_random_seed(seed))
+
     @deprecate_function(
         "Use `with_row_index` instead."
         " Note that the default column name has changed from 'row_nr' to 'index'.",
@@ -6777,6 +7101,78 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return self.select(F.all().fill_null(value, strategy, limit))
 
+    def sample_stratified(
+        self,
+        by: str | Sequence[str],
+        n: int | None = None,
+        *,
+        fraction: float | Expr | None = None,
+        seed: int | None = None,
+    ) -> LazyFrame:
+        """
+        Sample from every group of this LazyFrame, without replacement.
+
+        Groups with no more rows than `n` are kept whole.
+
+        With `n`, the sample is drawn in a single pass with a reservoir per group, so
+        only the sampled rows are kept in memory, also on the streaming engine. The
+        groups are in the order in which they first occur, the order of the rows
+        within a group is unspecified. With `fraction`, the size of every group is
+        needed and the whole input is materialized; the sampled rows keep their order.
+
+        Parameters
+        ----------
+        by
+            Column(s) that determine the groups (strata) to sample from.
+        n
+            Number of rows to return per group. Cannot be used with `fraction`.
+            Defaults to 1 if `fraction` is None.
+        fraction
+            Fraction of rows to return per group. Cannot be used with `n`.
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated for each sample operation.
+
+        See Also
+        --------
+        DataFrame.sample_stratified
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "label": ["a", "a", "a", "b", "b", "c"],
+        ...         "value": [1, 2, 3, 4, 5, 6],
+        ...     }
+        ... )
+        >>> sampled = lf.sample_stratified("label", n=1, seed=0)
+        >>> sampled.collect()  # doctest: +IGNORE_RESULT
+        shape: (3, 2)
+        ┌───────┬───────┐
+        │ label ┆ value │
+        │ ---   ┆ ---   │
+        │ str   ┆ i64   │
+        ╞═══════╪═══════╡
+        │ a     ┆ 2     │
+        │ b     ┆ 4     │
+        │ c     ┆ 6     │
+        └───────┴───────┘
+        """
+        if n is not None and fraction is not None:
+            msg = "cannot specify both `n` and `fraction`"
+            raise ValueError(msg)
+
+        by_pyexprs = parse_into_list_of_expressions(by)
+        if n is None and fraction is not None:
+            fraction_pyexpr = parse_into_expression(fraction)
+            return self._from_pyldf(
+                self._ldf.sample_frac_stratified(by_pyexprs, fraction_pyexpr, seed)
+            )
+
+        if n is None:
+            n = 1
+        return self._from_pyldf(self._ldf.sample_n_stratified(by_pyexprs, n, seed))
+
     def fill_nan(self, value: int | float | Expr | None) -> LazyFrame:
         """
         Fill floating point NaN values.
@@ -7214,6 +7610,106 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             subset = parse_into_list_of_expressions(subset)
         return self._from_pyldf(self._ldf.unique(maintain_order, subset, keep))
 
+    @unstable()
+    def validate(
+        self,
+        *,
+        unique: str | Sequence[str] | None = None,
+        not_null: str | Sequence[str] | None = None,
+        ranges: Mapping[str, tuple[Any, Any]] | None = None,
+        references: Mapping[str, tuple[DataFrame | LazyFrame, str]] | None = None,
+        on_violation: ViolationStrategy = "raise",
+    ) -> LazyFrame:
+        """
+        Check constraints on the rows of this LazyFrame.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        unique
+            Columns that together must be unique, like a primary key.
+        not_null
+            Columns that must not contain nulls.
+        ranges
+            A mapping of columns to their inclusive `(lower, upper)` bounds, where
+            `None` is unbounded. Nulls pass.
+        references
+            A mapping of columns to an `(other, column)` pair, meaning that every
+            non-null value must occur in that column of the other frame, like a foreign
+            key.
+        on_violation : {'raise', 'return'}
+            What to do with the rows that violate a constraint.
+
+            - 'raise': Raise a `ComputeError` that lists every violated constraint
+              with its number of violating rows.
+            - 'return': Return the violations, as a frame with the row index of every
+              violating row and the violated constraint. A row breaking several
+              constraints appears once for each.
+
+        Returns
+        -------
+        LazyFrame
+            With `on_violation='raise'`, this LazyFrame, which raises when it is
+            collected and a constraint is violated. Otherwise the violations.
+
+        Notes
+        -----
+        The check of `on_violation='raise'` runs on the whole frame, so the
+        optimizer doesn't push projections, filters or slices past it.
+
+        See Also
+        --------
+        DataFrame.validate
+        Expr.assert_
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"id": [1, 2, 3], "customer": ["a", "b", "x"]})
+        >>> customers = pl.LazyFrame({"name": ["a", "b", "c"]})
+        >>> lf.validate(
+        ...     unique="id",
+        ...     references={"customer": (customers, "name")},
+        ...     on_violation="return",
+        ... ).collect()
+        shape: (1, 2)
+        ┌───────┬─────────────────────┐
+        │ index ┆ constraint          │
+        │ ---   ┆ ---                 │
+        │ u32   ┆ str                 │
+        ╞═══════╪═════════════════════╡
+        │ 2     ┆ reference(customer) │
+        └───────┴─────────────────────┘
+        """
+        constraints = {
+            "unique": unique,
+            "not_null": not_null,
+            "ranges": ranges,
+            "references": references,
+        }
+        if on_violation == "return":
+            return constraint_violations(self, **constraints)
+        elif on_violation != "raise":
+            msg = (
+                "`on_violation` must be one of {'raise', 'return'},"
+                f" got {on_violation!r}"
+            )
+            raise ValueError(msg)
+
+        def check(df: DataFrame) -> DataFrame:
+            violations = constraint_violations(df.lazy(), **constraints)
+            raise_on_violations(violations.collect())
+            return df
+
+        return self.map_batches(
+            check,
+            predicate_pushdown=False,
+            projection_pushdown=False,
+            slice_pushdown=False,
+        )
+
     def drop_nans(
         self,
         subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
@@ -1,14 +1,15 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Callable
+from typing import TYPE_CHECKING, Any, Callable
 
 from polars import functions as F
 from polars._utils.deprecation import deprecate_renamed_function
 from polars._utils.parse import parse_into_list_of_expressions
+from polars._utils.various import extend_bool
 from polars._utils.wrap import wrap_ldf
 
 if TYPE_CHECKING:
-    from collections.abc import Iterable
+    from collections.abc import Iterable, Sequence
 
     from polars import DataFrame, LazyFrame
     from polars._typing import IntoExpr, RollingInterpolationMethod, SchemaDict
@@ -22,8 +23,10 @@ class LazyGroupBy:
     Generated by calling `df.lazy().group_by(...)`.
     """
 
-    def __init__(self, lgb: PyLazyGroupBy) -> None:
+    def __init__(self, lgb: PyLazyGroupBy, fill_gaps: Any = None) -> None:
         self.lgb = lgb
+        # The parsed `fill_gaps` of a `group_by_dynamic`, see `LazyFrame.upsample`.
+        self._fill_gaps = fill_gaps
 
     def agg(
         self,
@@ -145,6 +148,8 @@ class LazyGroupBy:
             raise TypeError(msg)
 
         pyexprs = parse_into_list_of_expressions(*aggs, **named_aggs)
+        if self._fill_gaps is not None:
+            return wrap_ldf(self.lgb.agg_fill_gaps(pyexprs, self._fill_gaps))
         return wrap_ldf(self.lgb.agg(pyexprs))
 
     def map_groups(
@@ -310,6 +315,122 @@ class LazyGroupBy:
         """
         return wrap_ldf(self.lgb.tail(n))
 
+    def top_k_by(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        k: int = 5,
+        *,
+        reverse: bool | Sequence[bool] = False,
+    ) -> LazyFrame:
+        """
+        Get the `k` rows of each group with the largest values of the `by` column(s).
+
+        Rows are selected without sorting each group, and non-null values are always
+        preferred over nulls. With several `by` columns, ties are broken by the order
+        of the rows; with a single one, which of the tied rows is selected is not
+        specified.
+
+        Parameters
+        ----------
+        by
+            Column(s) used to determine the largest rows.
+            Accepts expression input. Strings are parsed as column names.
+        k
+            Number of rows to return per group.
+        reverse
+            Consider the `k` smallest rows of the `by` column(s) instead.
+            This can be specified per column by passing a sequence of booleans.
+
+        See Also
+        --------
+        bottom_k_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "letters": ["c", "c", "a", "c", "a", "b"],
+        ...         "nrs": [1, 2, 3, 4, 5, 6],
+        ...     }
+        ... )
+        >>> df.lazy().group_by("letters").top_k_by("nrs", 2).sort(
+        ...     "letters", "nrs"
+        ... ).collect()
+        shape: (5, 2)
+        ┌─────────┬─────┐
+        │ letters ┆ nrs │
+        │ ---     ┆ --- │
+        │ str     ┆ i64 │
+        ╞═════════╪═════╡
+        │ a       ┆ 3   │
+        │ a       ┆ 5   │
+        │ b       ┆ 6   │
+        │ c       ┆ 2   │
+        │ c       ┆ 4   │
+        └─────────┴─────┘
+        """
+        by = parse_into_list_of_expressions(by)
+        reverse = extend_bool(reverse, len(by), "reverse", "by")
+        return wrap_ldf(self.lgb.top_k_by(by, k, reverse))
+
+    def bottom_k_by(
+        self,
+        by: IntoExpr | Iterable[IntoExpr],
+        k: int = 5,
+        *,
+        reverse: bool | Sequence[bool] = False,
+    ) -> LazyFrame:
+        """
+        Get the `k` rows of each group with the smallest values of the `by` column(s).
+
+        Rows are selected without sorting each group, and non-null values are always
+        preferred over nulls. With several `by` columns, ties are broken by the order
+        of the rows; with a single one, which of the tied rows is selected is not
+        specified.
+
+        Parameters
+        ----------
+        by
+            Column(s) used to determine the smallest rows.
+            Accepts expression input. Strings are parsed as column names.
+        k
+            Number of rows to return per group.
+        reverse
+            Consider the `k` largest rows of the `by` column(s) instead.
+            This can be specified per column by passing a sequence of booleans.
+
+        See Also
+        --------
+        top_k_by
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "letters": ["c", "c", "a", "c", "a", "b"],
+        ...         "nrs": [1, 2, 3, 4, 5, 6],
+        ...     }
+        ... )
+        >>> df.lazy().group_by("letters").bottom_k_by("nrs", 2).sort(
+        ...     "letters", "nrs"
+        ... ).collect()
+        shape: (5, 2)
+        ┌─────────┬─────┐
+        │ letters ┆ nrs │
+        │ ---     ┆ --- │
+        │ str     ┆ i64 │
+        ╞═════════╪═════╡
+        │ a       ┆ 3   │
+        │ a       ┆ 5   │
+        │ b       ┆ 6   │
+        │ c       ┆ 1   │
+        │ c       ┆ 2   │
+        └─────────┴─────┘
+        """
+        by = parse_into_list_of_expressions(by)
+        reverse = extend_bool(reverse, len(by), "reverse", "by")
+        return wrap_ldf(self.lgb.bottom_k_by(by, k, reverse))
+
     def all(self) -> LazyFrame:
         """
         Aggregate the groups into Series.
@@ -0,0 +1,54 @@
+from __future__ import annotations
+
+import contextlib
+from datetime import timedelta
+from typing import Any
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
+
+
+def engine_stats() -> dict[str, Any]:
+    """
+    Return a snapshot of the global state of the Polars engine.
+
+    This is meant for the health dashboards of applications that embed Polars; the
+    statistics are cheap enough to be polled periodically.
+
+    Returns
+    -------
+    dict
+        A dictionary with the following keys:
+
+        - `thread_pool_size`: the number of threads in the thread pool.
+        - `thread_pool_cpu_time`: the CPU time the threads of the thread pool have
+          used since they were started, as a `timedelta`, or `None` if the platform
+          does not report it (it is only known on Linux). The utilization of the
+          thread pool over an interval is the difference between two snapshots,
+          divided by the length of the interval times `thread_pool_size`.
+        - `string_cache_enabled`: whether the global string cache is enabled.
+        - `string_cache_len`: the number of strings in the global string cache.
+        - `string_cache_bytes`: the number of bytes of those strings.
+        - `spill_bytes`: the number of bytes the streaming engine spilled to the
+          temporary directory, including those of other processes that use the same
+          directory.
+
+    Notes
+    -----
+    Polars has no pool of buffers to report on: buffers are allocated from the global
+    allocator.
+
+    Examples
+    --------
+    >>> pl.engine_stats()  # doctest: +SKIP
+    {'thread_pool_size': 16,
+     'thread_pool_cpu_time': datetime.timedelta(seconds=2, microseconds=491803),
+     'string_cache_enabled': False,
+     'string_cache_len': 0,
+     'string_cache_bytes': 0,
+     'spill_bytes': 0}
+    """
+    stats = plr.engine_stats()
+    if (cpu_seconds := stats["thread_pool_cpu_time"]) is not None:
+        stats["thread_pool_cpu_time"] = timedelta(seconds=cpu_seconds)
+    return stats
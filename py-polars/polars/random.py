@@ -0,0 +1,177 @@
+"""
+Expressions that sample random distributions.
+
+The expressions produce a sample for every row of the frame, or of the group in an
+aggregation. The parameters of a distribution accept expression input, so they can be
+scalars or differ from row to row. A row with a null parameter has a null sample.
+"""
+
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+__all__ = ["bernoulli", "normal", "poisson", "uniform"]
+
+
+def _sample(distribution: str, *parameters: IntoExpr, seed: int | None) -> Expr:
+    pyexprs = [parse_into_expression(p) for p in parameters]
+    return wrap_expr(plr.random_distribution(distribution, pyexprs, seed))
+
+
+@unstable()
+def uniform(
+    low: IntoExpr = 0.0, high: IntoExpr = 1.0, *, seed: int | None = None
+) -> Expr:
+    """
+    Sample a uniform distribution between `low` (inclusive) and `high` (exclusive).
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    low
+        The lower bound. Accepts expression input. Strings are parsed as column names.
+    high
+        The upper bound, which must not be smaller than `low`. Accepts expression
+        input. Strings are parsed as column names.
+    seed
+        The seed for the random generator. If set to None (default), a random seed
+        is generated every time the query runs.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Float64`, named "uniform".
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"id": [1, 2, 3]})
+    >>> df.with_columns(pl.random.uniform(10, 20, seed=0)).schema
+    Schema({'id': Int64, 'uniform': Float64})
+    """
+    return _sample("uniform", low, high, seed=seed)
+
+
+@unstable()
+def normal(
+    mean: IntoExpr = 0.0, std: IntoExpr = 1.0, *, seed: int | None = None
+) -> Expr:
+    """
+    Sample a normal distribution.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    mean
+        The mean. Accepts expression input. Strings are parsed as column names.
+    std
+        The standard deviation, which must not be negative. Accepts expression
+        input. Strings are parsed as column names.
+    seed
+        The seed for the random generator. If set to None (default), a random seed
+        is generated every time the query runs.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Float64`, named "normal".
+
+    Examples
+    --------
+    Add noise whose standard deviation differs per row:
+
+    >>> df = pl.DataFrame({"x": [1.0, 2.0, 3.0], "noise": [0.1, 0.5, 1.0]})
+    >>> df.select(pl.col("x") + pl.random.normal(std="noise", seed=0)).schema
+    Schema({'x': Float64})
+    """
+    return _sample("normal", mean, std, seed=seed)
+
+
+@unstable()
+def bernoulli(p: IntoExpr = 0.5, *, seed: int | None = None) -> Expr:
+    """
+    Sample a Bernoulli distribution, which is true with probability `p`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    p
+        The probability of true, between 0 and 1. Accepts expression input. Strings
+        are parsed as column names.
+    seed
+        The seed for the random generator. If set to None (default), a random seed
+        is generated every time the query runs.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Boolean`, named "bernoulli".
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"p": [0.0, 1.0, None]})
+    >>> df.with_columns(pl.random.bernoulli("p"))
+    shape: (3, 2)
+    ┌──────┬───────────┐
+    │ p    ┆ bernoulli │
+    │ ---  ┆ ---       │
+    │ f64  ┆ bool      │
+    ╞══════╪═══════════╡
+    │ 0.0  ┆ false     │
+    │ 1.0  ┆ true      │
+    │ null ┆ null      │
+    └──────┴───────────┘
+    """
+    return _sample("bernoulli", p, seed=seed)
+
+
+@unstable()
+def poisson(lam: IntoExpr = 1.0, *, seed: int | None = None) -> Expr:
+    """
+    Sample a Poisson distribution.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    lam
+        The mean, which must not be negative. Accepts expression input. Strings are
+        parsed as column names.
+    seed
+        The seed for the random generator. If set to None (default), a random seed
+        is generated every time the query runs.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Int64`, named "poisson".
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"rate": [0.0, 2.5, 10.0]})
+    >>> df.with_columns(pl.random.poisson("rate", seed=0)).schema
+    Schema({'rate': Float64, 'poisson': Int64})
+    """
+    return _sample("poisson", lam, seed=seed)
@@ -0,0 +1,130 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class AnonymizeNameSpace:
+    """Series.anonymize namespace."""
+
+    _accessor = "anonymize"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def tokenize(self, key: str, *, length: int = 32) -> Series:
+        """
+        Replace every value by a token computed with a keyed hash of the value.
+
+        Values that are not strings or binary are tokenized as strings.
+
+        Parameters
+        ----------
+        key
+            The secret the hash is keyed with.
+        length
+            The number of hexadecimal characters of a token.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series("email", ["ann@x.org", "bob@y.org", "ann@x.org"])
+        >>> tokens = s.anonymize.tokenize("secret", length=8)
+        >>> tokens.n_unique(), tokens.str.len_chars().max()
+        (2, 8)
+        """
+
+    def hash_preserving_format(self, key: str) -> Series:
+        r"""
+        Replace the digits and ASCII letters by ones chosen by a keyed hash.
+
+        Digits are replaced by digits, and lowercase and uppercase letters by letters
+        of the same case, so the output has the format of the input (e.g. of an email
+        address or a phone number). Other characters are kept.
+
+        This is a hash and not an encryption: the outputs cannot be turned back into
+        the inputs, and different inputs can give the same output.
+
+        Parameters
+        ----------
+        key
+            The secret the hash is keyed with.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series("phone", ["+1 415-555-0100", "+1 212-555-0199"])
+        >>> s.anonymize.hash_preserving_format("secret").str.contains(
+        ...     r"^\+\d \d{3}-\d{3}-\d{4}$"
+        ... ).all()
+        True
+        """
+
+    def bucket(self, width: float) -> Series:
+        """
+        Round the numbers down to a multiple of `width`.
+
+        This generalizes values into ranges, e.g. ages into decades. The output has the
+        data type of the input; buckets that do not fit in it are null.
+
+        Parameters
+        ----------
+        width
+            The width of the buckets; an integer for integer columns.
+
+        Examples
+        --------
+        >>> s = pl.Series("age", [7, 23, 38, 40])
+        >>> s.anonymize.bucket(10)
+        shape: (4,)
+        Series: 'age' [i64]
+        [
+            0
+            20
+            30
+            40
+        ]
+        """
+
+    def truncate(self, length: int, *, fill: str | None = None) -> Series:
+        """
+        Keep the first `length` characters of the strings, e.g. the area of zip codes.
+
+        Parameters
+        ----------
+        length
+            The number of characters to keep.
+        fill
+            A single character that replaces the other characters, so the strings keep
+            their length. If `None`, the other characters are removed.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series("zip", ["94107", "10001"])
+        >>> s.anonymize.truncate(3, fill="*")
+        shape: (2,)
+        Series: 'zip' [str]
+        [
+            "941**"
+            "100**"
+        ]
+        """
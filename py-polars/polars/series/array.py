@@ -10,7 +10,7 @@ if TYPE_CHECKING:
     from collections.abc import Sequence
     from datetime import date, datetime, time
 
-    from polars import Series
+    from polars import Expr, Series
     from polars._typing import IntoExpr, IntoExprColumn
     from polars.polars import PySeries
 
@@ -643,3 +643,236 @@ class ArrayNameSpace:
             [6, null, null]
         ]
         """
+
+    def slice(self, offset: int, length: int | None = None) -> Series:
+        """
+        Slice every sub-array.
+
+        As all sub-arrays have the same width, the result is an array with the width
+        of the slice.
+
+        Parameters
+        ----------
+        offset
+            Start index. Negative indexing is supported.
+        length
+            Length of the slice. If set to `None` (default), the slice is taken to the
+            end of the sub-array.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Array`.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2, 3], [4, 5, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.slice(1)
+        shape: (2,)
+        Series: '' [array[i64, 2]]
+        [
+            [2, 3]
+            [5, 6]
+        ]
+        """
+
+    def head(self, n: int = 5) -> Series:
+        """
+        Slice the first `n` values of every sub-array.
+
+        Parameters
+        ----------
+        n
+            Number of values to return for each sub-array.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Array`.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2, 3], [4, 5, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.head(2)
+        shape: (2,)
+        Series: '' [array[i64, 2]]
+        [
+            [1, 2]
+            [4, 5]
+        ]
+        """
+
+    def tail(self, n: int = 5) -> Series:
+        """
+        Slice the last `n` values of every sub-array.
+
+        Parameters
+        ----------
+        n
+            Number of values to return for each sub-array.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Array`.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2, 3], [4, 5, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.tail(2)
+        shape: (2,)
+        Series: '' [array[i64, 2]]
+        [
+            [2, 3]
+            [5, 6]
+        ]
+        """
+
+    def gather(
+        self,
+        indices: Series | list[int] | list[list[int]],
+        *,
+        null_on_oob: bool = False,
+    ) -> Series:
+        """
+        Take values from every sub-array by multiple indices.
+
+        Parameters
+        ----------
+        indices
+            Indices to return per sub-array.
+        null_on_oob
+            Behavior if an index is out of bounds:
+            True -> set as null
+            False -> raise an error
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List`, as the number of indices may differ
+            per row. Use :meth:`Series.list.to_array` to convert it back to an array.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2, 3], [4, 5, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.gather([0, 2])
+        shape: (2,)
+        Series: '' [list[i64]]
+        [
+            [1, 3]
+            [4, 6]
+        ]
+        """
+
+    def eval(self, expr: Expr, *, parallel: bool = False) -> Series:
+        """
+        Run any polars expression against the sub-arrays' elements.
+
+        Parameters
+        ----------
+        expr
+            Expression to run. Note that you can select an element with `pl.first()`, or
+            `pl.col()`
+        parallel
+            Run all expression parallel. Don't activate this blindly.
+            Parallelism is worth it if there is enough work to do per thread.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List`, as `expr` may change the number of
+            elements. Use :meth:`Series.list.to_array` to convert it back to an array.
+
+        Examples
+        --------
+        >>> s = pl.Series([[1, 2, 3], [4, 5, 6]], dtype=pl.Array(pl.Int64, 3))
+        >>> s.arr.eval(pl.element().cum_sum())
+        shape: (2,)
+        Series: '' [list[i64]]
+        [
+            [1, 3, 6]
+            [4, 9, 15]
+        ]
+        """
+
+    def dot(self, other: IntoExpr | Sequence[float]) -> Series:
+        """
+        Compute the dot product of every sub-array with the sub-array of `other`.
+
+        Parameters
+        ----------
+        other
+            Array Series of the same width, or a single vector given as a sequence of
+            numbers, which is compared with every element.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float32` if both arrays hold :class:`Float32`
+            values, :class:`Float64` otherwise. Sub-arrays that are null or contain a
+            null give a null.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1.0, 2.0], [3.0, 4.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.dot([1.0, 1.0])
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+                3.0
+                7.0
+        ]
+        """
+
+    def cosine_similarity(self, other: IntoExpr | Sequence[float]) -> Series:
+        """
+        Compute the cosine similarity of every sub-array with the sub-array of `other`.
+
+        The similarity of a zero vector is NaN.
+
+        Parameters
+        ----------
+        other
+            Array Series of the same width, or a single vector given as a sequence of
+            numbers, which is compared with every element.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float32` if both arrays hold :class:`Float32`
+            values, :class:`Float64` otherwise. Sub-arrays that are null or contain a
+            null give a null.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1.0, 2.0], [3.0, 4.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.cosine_similarity([1.0, 0.0])
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+                0.447214
+                0.6
+        ]
+        """
+
+    def l2_norm(self) -> Series:
+        """
+        Compute the Euclidean norm of every sub-array.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float32` if the array holds :class:`Float32`
+            values, :class:`Float64` otherwise.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1.0, 2.0], [3.0, 4.0]], dtype=pl.Array(pl.Float64, 2))
+        >>> s.arr.l2_norm()
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+                2.236068
+                5.0
+        ]
+        """
@@ -7,11 +7,12 @@ from polars.series.utils import expr_dispatch
 if TYPE_CHECKING:
     from polars import Series
     from polars._typing import (
+        BinaryTransferEncoding,
         Endianness,
         IntoExpr,
+        IntoExprColumn,
         PolarsDataType,
         SizeUnit,
-        TransferEncoding,
     )
     from polars.polars import PySeries
 
@@ -96,14 +97,17 @@ class BinaryNameSpace:
         ]
         """
 
-    def decode(self, encoding: TransferEncoding, *, strict: bool = True) -> Series:
+    def decode(
+        self, encoding: BinaryTransferEncoding, *, strict: bool = True
+    ) -> Series:
         r"""
         Decode values using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            `'base64'`, which is decoded with or without padding.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -152,14 +156,15 @@ class BinaryNameSpace:
         ]
         """
 
-    def encode(self, encoding: TransferEncoding) -> Series:
+    def encode(self, encoding: BinaryTransferEncoding) -> Series:
         r"""
         Encode values using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'base64url'}
+            The encoding to use. `'base64url'` is the URL and filename safe variant of
+            `'base64'`, which is encoded without padding.
 
         Returns
         -------
@@ -190,6 +195,17 @@ class BinaryNameSpace:
             "//8A"
             "AAD/"
         ]
+
+        Encode values using the URL safe Base64 encoding.
+
+        >>> s.bin.encode("base64url")
+        shape: (3,)
+        Series: 'colors' [str]
+        [
+            "AAAA"
+            "__8A"
+            "AAD_"
+        ]
         """
 
     def size(self, unit: SizeUnit = "b") -> Series:
@@ -216,6 +232,133 @@ class BinaryNameSpace:
         ]
         """
 
+    def ip_to_string(self) -> Series:
+        """
+        Format IP addresses in their binary form as text.
+
+        Values of 4 bytes are formatted as IPv4 and values of 16 bytes as IPv6
+        addresses; an error is raised for values of any other length.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        See Also
+        --------
+        polars.Series.str.to_ip : Parse IP addresses into their bytes.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", [b"\x7f\x00\x00\x01", bytes(15) + b"\x01", None])
+        >>> s.bin.ip_to_string()
+        shape: (3,)
+        Series: 'ip' [str]
+        [
+            "127.0.0.1"
+            "::1"
+            null
+        ]
+        """
+
+    def ip_is_in_subnet(self, cidr: str) -> Series:
+        """
+        Check if IP addresses in their binary form are in a subnet.
+
+        Parameters
+        ----------
+        cidr
+            The subnet in CIDR notation, eg: `"10.0.0.0/8"` or `"2001:db8::/32"`.
+            Host bits of the address are ignored and an address without a prefix
+            length selects that single address. IPv4 addresses are never in an IPv6
+            subnet and vice versa.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        See Also
+        --------
+        polars.Series.str.to_ip : Parse IP addresses into their bytes.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", ["192.168.1.20", "192.168.2.20", "::1"])
+        >>> s.str.to_ip().bin.ip_is_in_subnet("192.168.1.0/24")
+        shape: (3,)
+        Series: 'ip' [bool]
+        [
+            true
+            false
+            false
+        ]
+        """
+
+    def ipv4_to_integer(self) -> Series:
+        """
+        Convert IPv4 addresses in their binary form to integers.
+
+        An error is raised for values that are not 4 bytes long.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        See Also
+        --------
+        polars.Series.str.to_ip : Parse IP addresses into their bytes.
+        polars.Series.bin.ipv6_to_integer : Convert IP addresses to 128-bit integers.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", ["192.168.0.1", "10.0.0.1", None])
+        >>> s.str.to_ip().bin.ipv4_to_integer()
+        shape: (3,)
+        Series: 'ip' [u32]
+        [
+            3232235521
+            167772161
+            null
+        ]
+        """
+
+    def ipv6_to_integer(self) -> Series:
+        """
+        Convert IP addresses in their binary form to 128-bit integers.
+
+        Polars has no 128-bit unsigned integer data type, so the integer is stored
+        as a struct of its `high` and `low` 64 bits. IPv4 addresses are mapped to
+        IPv6 (eg: `"10.0.0.1"` to `"::ffff:10.0.0.1"`) and an error is raised for
+        values that are neither 4 nor 16 bytes long.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Struct` with fields `high` and `low` of data
+            type :class:`UInt64`.
+
+        See Also
+        --------
+        polars.Series.str.to_ip : Parse IP addresses into their bytes.
+        polars.Series.bin.ipv4_to_integer : Convert IPv4 addresses to integers.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", ["2001:db8::1", "10.0.0.1"])
+        >>> s.str.to_ip().bin.ipv6_to_integer().struct.unnest()
+        shape: (2, 2)
+        ┌─────────────────────┬─────────────────┐
+        │ high                ┆ low             │
+        │ ---                 ┆ ---             │
+        │ u64                 ┆ u64             │
+        ╞═════════════════════╪═════════════════╡
+        │ 2306139568115548160 ┆ 1               │
+        │ 0                   ┆ 281470849515521 │
+        └─────────────────────┴─────────────────┘
+        """
+
     def reinterpret(
         self, *, dtype: PolarsDataType, endianness: Endianness = "little"
     ) -> Series:
@@ -248,3 +391,231 @@ class BinaryNameSpace:
         ]
 
         """
+
+    def uuid_to_string(self) -> Series:
+        """
+        Format 16-byte values as UUIDs in the lowercase hyphenated form.
+
+        Raises an error if any value is not exactly 16 bytes long. Use
+        :meth:`uuid.to_string` for columns of data type :class:`Uuid`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        See Also
+        --------
+        polars.Series.str.to_uuid : Parse UUIDs from their textual form.
+
+        Examples
+        --------
+        >>> s = pl.Series("id", [bytes(range(16)), None])
+        >>> s.bin.uuid_to_string()
+        shape: (2,)
+        Series: 'id' [str]
+        [
+            "00010203-0405-0607-0809-0a0b0c0d0e0f"
+            null
+        ]
+        """
+
+    def slice(
+        self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
+    ) -> Series:
+        r"""
+        Extract a slice of bytes from each binary value.
+
+        Parameters
+        ----------
+        offset
+            Start index in bytes. Negative indexing is supported.
+        length
+            Length of the slice in bytes. If set to `None` (default), the slice is
+            taken to the end of the value.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series([b"\x01\x02\x03\x04", b"\x05", None])
+        >>> s.bin.slice(1, 2)
+        shape: (3,)
+        Series: '' [binary]
+        [
+            b"\x02\x03"
+            b""
+            null
+        ]
+        """
+
+    def concat(self, other: IntoExpr) -> Series:
+        r"""
+        Append the binary values of `other` to the binary values.
+
+        The result is null if either value is null.
+
+        Parameters
+        ----------
+        other
+            The binary values to append; bytes are parsed as a literal.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series([b"\x00", None])
+        >>> s.bin.concat(b"\xff")
+        shape: (2,)
+        Series: '' [binary]
+        [
+            b"\x00\xff"
+            null
+        ]
+        """
+
+    def bitwise_and(self, other: IntoExpr) -> Series:
+        r"""
+        Take the bitwise AND of the bytes of the binary values and `other`.
+
+        Both values must have the same size; a mismatch raises an error. The result
+        is null if either value is null.
+
+        Parameters
+        ----------
+        other
+            The binary values to combine with; bytes are parsed as a literal.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series([b"\x0f\xf0", b"\xf0\x0f"])
+        >>> s.bin.bitwise_and(b"\xf0\xf0")
+        shape: (2,)
+        Series: '' [binary]
+        [
+            b"\x00\xf0"
+            b"\xf0\x00"
+        ]
+        """
+
+    def bitwise_or(self, other: IntoExpr) -> Series:
+        r"""
+        Take the bitwise OR of the bytes of the binary values and `other`.
+
+        Both values must have the same size; a mismatch raises an error. The result
+        is null if either value is null.
+
+        Parameters
+        ----------
+        other
+            The binary values to combine with; bytes are parsed as a literal.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series([b"\x0f\xf0", b"\xf0\x0f"])
+        >>> s.bin.bitwise_or(b"\xf0\xf0")
+        shape: (2,)
+        Series: '' [binary]
+        [
+            b"\xff\xf0"
+            b"\xf0\xff"
+        ]
+        """
+
+    def bitwise_xor(self, other: IntoExpr) -> Series:
+        r"""
+        Take the bitwise XOR of the bytes of the binary values and `other`.
+
+        Both values must have the same size; a mismatch raises an error. The result
+        is null if either value is null.
+
+        Parameters
+        ----------
+        other
+            The binary values to combine with; bytes are parsed as a literal.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        Examples
+        --------
+        >>> s = pl.Series([b"\x0f\xf0", b"\xf0\x0f"])
+        >>> s.bin.bitwise_xor(b"\xf0\xf0")
+        shape: (2,)
+        Series: '' [binary]
+        [
+            b"\xff\x00"
+            b"\x00\xff"
+        ]
+        """
+
+    def crc32(self) -> Series:
+        r"""
+        Compute the CRC-32 checksum of the binary values.
+
+        The checksum is the same as the one of :func:`zlib.crc32`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series([b"hello", b"", None])
+        >>> s.bin.crc32()
+        shape: (3,)
+        Series: '' [u32]
+        [
+            907060870
+            0
+            null
+        ]
+        """
+
+    def xxh3(self, seed: int = 0) -> Series:
+        r"""
+        Compute the 64-bit XXH3 hash of the binary values.
+
+        Unlike :meth:`Series.hash`, the hash doesn't depend on the Polars version, so it
+        can be stored and compared with hashes computed by other XXH3 implementations.
+
+        Parameters
+        ----------
+        seed
+            The seed of the hash.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt64`.
+
+        Examples
+        --------
+        >>> s = pl.Series([b"", None])
+        >>> s.bin.xxh3()
+        shape: (2,)
+        Series: '' [u64]
+        [
+            3244421341483603138
+            null
+        ]
+        """
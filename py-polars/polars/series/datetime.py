@@ -11,7 +11,7 @@ if TYPE_CHECKING:
     import datetime as dt
     from collections.abc import Iterable
 
-    from polars import Series
+    from polars import BusinessCalendar, Expr, Series
     from polars._typing import (
         Ambiguous,
         EpochTimeUnit,
@@ -21,6 +21,7 @@ if TYPE_CHECKING:
         Roll,
         TemporalLiteral,
         TimeUnit,
+        Weekday,
     )
     from polars.polars import PySeries
 
@@ -46,6 +47,7 @@ class DateTimeNameSpace:
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        calendar: BusinessCalendar | None = None,
     ) -> Series:
         """
         Offset by `n` business days.
@@ -82,6 +84,9 @@ class DateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            A :class:`BusinessCalendar` to take the business days from, instead of
+            passing `week_mask` and `holidays`.
 
         Returns
         -------
@@ -135,6 +140,54 @@ class DateTimeNameSpace:
         ]
         """
 
+    @unstable()
+    def business_day_count(
+        self,
+        end: dt.date | IntoExprColumn,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[dt.date] = (),
+        calendar: BusinessCalendar | None = None,
+    ) -> Series:
+        """
+        Count the number of business days until `end` (not including `end`).
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        end
+            End dates.
+        week_mask
+            Which days of the week to count. The default is Monday to Friday.
+            If you wanted to count only Monday to Thursday, you would pass
+            `(True, True, True, True, False, False, False)`.
+        holidays
+            Holidays to exclude from the count.
+        calendar
+            A :class:`BusinessCalendar` to take the business days from, instead of
+            passing `week_mask` and `holidays`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("start", [date(2020, 1, 1), date(2020, 1, 2)])
+        >>> s.dt.business_day_count(date(2020, 1, 10))
+        shape: (2,)
+        Series: 'start' [i32]
+        [
+                7
+                6
+        ]
+        """
+
     def min(self) -> dt.date | dt.datetime | dt.timedelta | None:
         """
         Return minimum as Python datetime.
@@ -249,7 +302,8 @@ class DateTimeNameSpace:
         * Duration dtype expressions cannot be formatted with `strftime`. Instead,
           only "iso" and "polars" are supported as format strings. The "iso" format
           string results in ISO8601 duration string output, and "polars" results
-          in the same form seen in the frame `repr`.
+          in the same form seen in the frame `repr`. Both forms can be parsed
+          back into a Duration with :meth:`Series.str.to_duration`.
 
         Examples
         --------
@@ -487,6 +541,7 @@ class DateTimeNameSpace:
         *,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
         holidays: Iterable[dt.date] = (),
+        calendar: BusinessCalendar | None = None,
     ) -> Series:
         """
         Determine whether each day lands on a business day.
@@ -514,6 +569,9 @@ class DateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `is_business_day`.
+        calendar
+            A :class:`BusinessCalendar` to take the business days from, instead of
+            passing `week_mask` and `holidays`.
 
         Returns
         -------
@@ -609,6 +667,64 @@ class DateTimeNameSpace:
         ]
         """
 
+    def iso_week(self) -> Series:
+        """
+        Extract the ISO week number from the underlying date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the week number from 1 to 53 in the ISO week date system, in which
+        weeks start on Monday and the first week of the year is the week containing
+        its first Thursday. Same as :meth:`week`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(1977, 1, 1), date(1978, 1, 1), date(1979, 1, 1)]
+        ... )
+        >>> s.dt.iso_week()
+        shape: (3,)
+        Series: 'date' [i8]
+        [
+                53
+                52
+                1
+        ]
+        """
+
+    def iso_weekday(self) -> Series:
+        """
+        Extract the ISO weekday number from the underlying date representation.
+
+        Applies to Date and Datetime columns.
+
+        Returns the day of the week in the ISO week date system, where Monday is 1
+        and Sunday is 7. Same as :meth:`weekday`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("date", [date(2024, 1, 6), date(2024, 1, 8)])
+        >>> s.dt.iso_weekday()
+        shape: (2,)
+        Series: 'date' [i8]
+        [
+                6
+                1
+        ]
+        """
+
     def quarter(self) -> Series:
         """
         Extract quarter from underlying Date representation.
@@ -1703,7 +1819,13 @@ class DateTimeNameSpace:
         ]
         """
 
-    def offset_by(self, by: str | IntoExprColumn) -> Series:
+    def offset_by(
+        self,
+        by: str | IntoExprColumn,
+        *,
+        ambiguous: Ambiguous | Expr | None = None,
+        non_existent: NonExistent | None = None,
+    ) -> Series:
         """
         Offset this date by a relative time offset.
 
@@ -1732,6 +1854,24 @@ class DateTimeNameSpace:
             (which may not be 24 hours, due to daylight savings). Similarly for
             "calendar week", "calendar month", "calendar quarter", and
             "calendar year".
+        ambiguous
+            Determine how to deal with results which are ambiguous in time zones with
+            daylight saving time, after applying the calendar part (months, weeks and
+            days) of the offset:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: set to null
+
+            The fixed part of the offset (hours and smaller units) is added afterwards
+            in absolute time.
+        non_existent
+            Determine how to deal with results which are non-existent after applying
+            the calendar part of the offset:
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Returns
         -------
@@ -1779,12 +1919,20 @@ class DateTimeNameSpace:
         ]
         """
 
-    def truncate(self, every: str | dt.timedelta | IntoExprColumn) -> Series:
+    def truncate(
+        self,
+        every: str | dt.timedelta | IntoExprColumn,
+        *,
+        week_start: Weekday = "monday",
+        ambiguous: Ambiguous | Expr | None = None,
+        non_existent: NonExistent | None = None,
+    ) -> Series:
         """
         Divide the date/ datetime range into buckets.
 
         Each date/datetime is mapped to the start of its bucket using the corresponding
-        local datetime. Note that weekly buckets start on Monday.
+        local datetime. Weekly buckets start on `week_start`, which is Monday by
+        default, so `"1w"` truncates to the start of the ISO week.
         Ambiguous results are localised using the DST offset of the original timestamp -
         for example, truncating `'2022-11-06 01:30:00 CST'` by `'1h'` results in
         `'2022-11-06 01:00:00 CST'`, whereas truncating `'2022-11-06 01:30:00 CDT'` by
@@ -1794,6 +1942,27 @@ class DateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        week_start
+            The day of the week on which weekly buckets start. Only applies when
+            `every` is a number of weeks. Buckets spanning several weeks are aligned
+            to the first `week_start` day on or after Monday 1970-01-05.
+        ambiguous
+            Determine how to deal with ambiguous results in time zones with daylight
+            saving time. If neither `ambiguous` nor `non_existent` is set, ambiguous
+            results are localised using the DST offset of the original timestamp.
+            Otherwise, the datetime is truncated in wall-clock time and the result is
+            localised with:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: set to null
+        non_existent
+            Determine how to deal with non-existent results, for example a bucket which
+            starts in the gap of a DST transition:
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Notes
         -----
@@ -1892,7 +2061,13 @@ class DateTimeNameSpace:
         ]
         """
 
-    def round(self, every: str | dt.timedelta | IntoExprColumn) -> Series:
+    def round(
+        self,
+        every: str | dt.timedelta | IntoExprColumn,
+        *,
+        ambiguous: Ambiguous | Expr | None = None,
+        non_existent: NonExistent | None = None,
+    ) -> Series:
         """
         Divide the date/ datetime range into buckets.
 
@@ -1911,6 +2086,23 @@ class DateTimeNameSpace:
         ----------
         every
             Every interval start and period length
+        ambiguous
+            Determine how to deal with ambiguous results in time zones with daylight
+            saving time. If neither `ambiguous` nor `non_existent` is set, ambiguous
+            results are localised using the DST offset of the original timestamp.
+            Otherwise, the datetime is rounded in wall-clock time and the result is
+            localised with:
+
+            - `'raise'` (default): raise
+            - `'earliest'`: use the earliest datetime
+            - `'latest'`: use the latest datetime
+            - `'null'`: set to null
+        non_existent
+            Determine how to deal with non-existent results, for example a bucket which
+            starts in the gap of a DST transition:
+
+            - `'raise'` (default): raise
+            - `'null'`: set to null
 
         Returns
         -------
@@ -369,6 +369,35 @@ class ListNameSpace:
         ]
         """
 
+    def value_counts(
+        self,
+        *,
+        sort: bool = False,
+        name: str | None = None,
+        normalize: bool = False,
+    ) -> Series:
+        """
+        Count the occurrences of the unique values in every sub-list.
+
+        Parameters
+        ----------
+        sort
+            Sort the values of every sub-list by count in descending order.
+            If set to `False` (default), the order is random.
+        name
+            Give the resulting count field a specific name;
+            if `normalize` is True defaults to "proportion",
+            otherwise defaults to "count".
+        normalize
+            If true gives relative frequencies of the unique values
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 1, 2], [3]])
+        >>> s.list.value_counts(sort=True, name="n").to_list()
+        [[{'a': 1, 'n': 2}, {'a': 2, 'n': 1}], [{'a': 3, 'n': 1}]]
+        """
+
     def concat(self, other: list[Series] | Series | list[Any]) -> Series:
         """
         Concat the arrays in a Series dtype List in linear time.
@@ -96,6 +96,7 @@ from polars.dependencies import pandas as pd
 from polars.dependencies import pyarrow as pa
 from polars.exceptions import ComputeError, ModuleUpgradeRequiredError, ShapeError
 from polars.interchange.protocol import CompatLevel
+from polars.series.anonymize import AnonymizeNameSpace
 from polars.series.array import ArrayNameSpace
 from polars.series.binary import BinaryNameSpace
 from polars.series.categorical import CatNameSpace
@@ -104,6 +105,7 @@ from polars.series.list import ListNameSpace
 from polars.series.plotting import SeriesPlot
 from polars.series.string import StringNameSpace
 from polars.series.struct import StructNameSpace
+from polars.series.uuid import UuidNameSpace
 from polars.series.utils import expr_dispatch, get_ffi_func
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -124,10 +126,12 @@ if TYPE_CHECKING:
         ClosedInterval,
         ComparisonOperator,
         FillNullStrategy,
+        HashAlgorithm,
         InterpolationMethod,
         IntoExpr,
         IntoExprColumn,
         MultiIndexSelector,
+        NanPosition,
         NonNestedLiteral,
         NullBehavior,
         NumericLiteral,
@@ -135,6 +139,7 @@ if TYPE_CHECKING:
         PythonLiteral,
         RankMethod,
         RollingInterpolationMethod,
+        RollingRankMethod,
         SearchSortedSide,
         SeriesBuffers,
         SingleIndexSelector,
@@ -249,6 +254,7 @@ class Series:
 
     _s: PySeries = None
     _accessors: ClassVar[set[str]] = {
+        "anonymize",
         "arr",
         "cat",
         "dt",
@@ -256,6 +262,7 @@ class Series:
         "str",
         "bin",
         "struct",
+        "uuid",
         "plot",
     }
 
@@ -2235,8 +2242,10 @@ class Series:
         breaks: Sequence[float],
         *,
         labels: Sequence[str] | None = None,
+        label_format: str | None = None,
         left_closed: bool = False,
         include_breaks: bool = False,
+        as_enum: bool = False,
     ) -> Series:
         """
         Bin continuous values into discrete categories.
@@ -2252,18 +2261,27 @@ class Series:
         labels
             Names of the categories. The number of labels must be equal to the number
             of cut points plus one.
+        label_format
+            Format of the category names, in which `{lower}` and `{upper}` are
+            replaced by the bounds of each bin, such as `"{lower} - {upper}"`. By
+            default, the categories are intervals such as `"(1, 2]"`. Cannot be
+            combined with `labels`.
         left_closed
             Set the intervals to be left-closed instead of right-closed.
         include_breaks
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        as_enum
+            Return the categories as an :class:`Enum` ordered from the lowest to the
+            highest bin, so they can be compared and sorted in bin order.
 
         Returns
         -------
         Series
-            Series of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise a Series of data type :class:`Struct`.
+            Series of data type :class:`Categorical` (or :class:`Enum` if `as_enum`
+            is set) if `include_breaks` is set to `False` (default), otherwise a
+            Series of data type :class:`Struct`.
 
         See Also
         --------
@@ -2309,9 +2327,11 @@ class Series:
         quantiles: Sequence[float] | int,
         *,
         labels: Sequence[str] | None = None,
+        label_format: str | None = None,
         left_closed: bool = False,
         allow_duplicates: bool = False,
         include_breaks: bool = False,
+        as_enum: bool = False,
     ) -> Series:
         """
         Bin continuous values into discrete categories based on their quantiles.
@@ -2328,6 +2348,11 @@ class Series:
         labels
             Names of the categories. The number of labels must be equal to the number
             of cut points plus one.
+        label_format
+            Format of the category names, in which `{lower}` and `{upper}` are
+            replaced by the bounds of each bin, such as `"{lower} - {upper}"`. By
+            default, the categories are intervals such as `"(1, 2]"`. Cannot be
+            combined with `labels`.
         left_closed
             Set the intervals to be left-closed instead of right-closed.
         allow_duplicates
@@ -2338,12 +2363,16 @@ class Series:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        as_enum
+            Return the categories as an :class:`Enum` ordered from the lowest to the
+            highest bin. This requires `labels`, as the bins depend on the data.
 
         Returns
         -------
         Series
-            Series of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise a Series of data type :class:`Struct`.
+            Series of data type :class:`Categorical` (or :class:`Enum` if `as_enum`
+            is set) if `include_breaks` is set to `False` (default), otherwise a
+            Series of data type :class:`Struct`.
 
         See Also
         --------
@@ -2834,7 +2863,7 @@ class Series:
         ]
         """
 
-    def cum_prod(self, *, reverse: bool = False) -> Series:
+    def cum_prod(self, *, reverse: bool = False, log_domain: bool = False) -> Series:
         """
         Get an array with the cumulative product computed at every element.
 
@@ -2842,6 +2871,10 @@ class Series:
         ----------
         reverse
             reverse the operation.
+        log_domain
+            Compute the product as the exponent of the cumulative sum of the
+            logarithms of the absolute values, tracking the sign separately. This is
+            more accurate for long float columns. The output is a float column.
 
         Notes
         -----
@@ -3235,6 +3268,7 @@ class Series:
         nulls_last: bool = False,
         multithreaded: bool = True,
         in_place: bool = False,
+        nan_position: NanPosition = "largest",
     ) -> Self:
         """
         Sort this Series.
@@ -3249,6 +3283,10 @@ class Series:
             Sort using multiple threads.
         in_place
             Sort in-place.
+        nan_position : {'largest', 'first', 'last'}
+            Where to place NaN values of a float column. By default NaN is treated
+            as larger than every number. Use `'first'` or `'last'` to place NaN values
+            before or after all other non-null values, regardless of `descending`.
 
         Examples
         --------
@@ -3272,13 +3310,12 @@ class Series:
                 1
         ]
         """
+        pyseries = self._s.sort(descending, nulls_last, multithreaded, nan_position)
         if in_place:
-            self._s = self._s.sort(descending, nulls_last, multithreaded)
+            self._s = pyseries
             return self
         else:
-            return self._from_pyseries(
-                self._s.sort(descending, nulls_last, multithreaded)
-            )
+            return self._from_pyseries(pyseries)
 
     def top_k(self, k: int = 5) -> Series:
         r"""
@@ -3348,7 +3385,13 @@ class Series:
         ]
         """
 
-    def arg_sort(self, *, descending: bool = False, nulls_last: bool = False) -> Series:
+    def arg_sort(
+        self,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        nan_position: NanPosition = "largest",
+    ) -> Series:
         """
         Get the index values that would sort this Series.
 
@@ -3358,6 +3401,10 @@ class Series:
             Sort in descending order.
         nulls_last
             Place null values last instead of first.
+        nan_position : {'largest', 'first', 'last'}
+            Where to place NaN values of a float column. By default NaN is treated
+            as larger than every number. Use `'first'` or `'last'` to place NaN values
+            before or after all other non-null values, regardless of `descending`.
 
         See Also
         --------
@@ -6054,6 +6101,111 @@ class Series:
         (0.38180177416060584, 0.47033046033698594)
         """
 
+    @unstable()
+    def rolling_kurtosis(
+        self,
+        window_size: int,
+        *,
+        fisher: bool = True,
+        bias: bool = True,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute a rolling kurtosis.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row includes the row itself and the
+        `window_size - 1` elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> pl.Series([1, 4, 2, 9, 3, 7]).rolling_kurtosis(4)
+        shape: (6,)
+        Series: '' [f64]
+        [
+            null
+            null
+            null
+            -1.0
+            -0.839477
+            -1.715867
+        ]
+        """
+
+    @unstable()
+    def rolling_rank(
+        self,
+        window_size: int,
+        method: RollingRankMethod = "average",
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute the rank of every value within its rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row includes the row itself and the
+        `window_size - 1` elements before it. The value at the given row is ranked
+        against the other non-null values in its window, starting at 1.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        method : {'average', 'min', 'max'}
+            The method used to assign ranks to tied elements.
+            The following methods are available (default is 'average'):
+
+            - 'average' : The average of the ranks that would have been assigned to
+              all the tied values is assigned to each value.
+            - 'min' : The minimum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'max' : The maximum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> pl.Series([1, 4, 2, 9, 3, 7]).rolling_rank(3)
+        shape: (6,)
+        Series: '' [f64]
+        [
+            null
+            null
+            2.0
+            3.0
+            2.0
+            2.0
+        ]
+        """
+
     def sample(
         self,
         n: int | None = None,
@@ -6164,11 +6316,13 @@ class Series:
         seed_1: int | None = None,
         seed_2: int | None = None,
         seed_3: int | None = None,
+        *,
+        algorithm: HashAlgorithm = "default",
     ) -> Series:
         """
         Hash the Series.
 
-        The hash value is of type `UInt64`.
+        The hash value is of type `UInt64`, or `Binary` for the `'sha256'` algorithm.
 
         Parameters
         ----------
@@ -6177,15 +6331,39 @@ class Series:
         seed_1
             Random seed parameter. Defaults to `seed` if not set.
         seed_2
-            Random seed parameter. Defaults to `seed` if not set.
+            Random seed parameter. Defaults to `seed` if not set. Only used by the
+            default algorithm.
         seed_3
-            Random seed parameter. Defaults to `seed` if not set.
+            Random seed parameter. Defaults to `seed` if not set. Only used by the
+            default algorithm.
+        algorithm : {'default', 'xxhash64', 'wyhash', 'siphash13', 'sha256'}
+            The hash algorithm.
+
+            - `'default'`: the fast algorithm Polars uses internally, which is not
+              stable across Polars versions.
+            - `'xxhash64'`: 64-bit xxHash, seeded with `seed`.
+            - `'wyhash'`: wyhash (final version 4), seeded with `seed`.
+            - `'siphash13'`: SipHash-1-3, keyed with `seed` and `seed_1`.
+            - `'sha256'`: 32-byte SHA-256 digests. The seeds are ignored.
 
         Notes
         -----
-        This implementation of `hash` does not guarantee stable results
-        across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        The default algorithm does not guarantee stable results across different
+        Polars versions. Its stability is only guaranteed within a single version.
+
+        The other algorithms are stable across Polars versions and platforms, so
+        their hashes can be stored, for example as external IDs, and compared with
+        hashes computed by other implementations of the same algorithm. For those,
+        every value is hashed as its bytes:
+
+        - Strings and categoricals as UTF-8, and binary values as they are.
+        - Booleans as a single byte.
+        - Numbers as the little-endian bytes of their data type, so the same value
+          hashes differently as `Int32` and `Int64`. The zeros and the NaNs of floats
+          are normalized first.
+        - Temporal and decimal values as their underlying integers.
+
+        Null values have a null hash, and nested data types are not supported.
 
         Examples
         --------
@@ -6198,6 +6376,17 @@ class Series:
             3022416320763508302
             13756996518000038261
         ]
+
+        Use a stable algorithm for hashes that can be stored.
+
+        >>> s.hash(seed=42, algorithm="wyhash")
+        shape: (3,)
+        Series: 'a' [u64]
+        [
+            14184485330598920855
+            3742453734528888582
+            12411246033169709547
+        ]
         """
 
     def reinterpret(self, *, signed: bool = True) -> Series:
@@ -6257,14 +6446,20 @@ class Series:
         ]
         """
 
-    def interpolate_by(self, by: IntoExpr) -> Series:
+    def interpolate_by(
+        self, by: IntoExpr, *, method: InterpolationMethod = "linear"
+    ) -> Series:
         """
         Fill null values using interpolation based on another column.
 
+        Unlike :meth:`interpolate`, the values of `by` do not have to be evenly spaced.
+
         Parameters
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest'}
+            Interpolation method.
 
         Examples
         --------
@@ -6281,6 +6476,18 @@ class Series:
             2.714286
             3.0
         ]
+
+        Fill null values with the value that is nearest in `by`.
+
+        >>> s.interpolate_by(by, method="nearest")
+        shape: (4,)
+        Series: '' [i64]
+        [
+            1
+            1
+            3
+            3
+        ]
         """
 
     def abs(self) -> Series:
@@ -6307,6 +6514,7 @@ class Series:
         method: RankMethod = "average",
         *,
         descending: bool = False,
+        nan_position: NanPosition = "largest",
         seed: int | None = None,
     ) -> Series:
         """
@@ -6334,6 +6542,10 @@ class Series:
               on the order that the values occur in the Series.
         descending
             Rank in descending order.
+        nan_position : {'largest', 'first', 'last'}
+            Which ranks NaN values of a float column receive. By default NaN is
+            treated as larger than every number. Use `'first'` or `'last'` to give NaN
+            values the lowest or highest ranks, regardless of `descending`.
         seed
             If `method="random"`, use this as seed.
 
@@ -6653,7 +6865,7 @@ class Series:
 
     def replace(
         self,
-        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any] | DataFrame,
         new: IntoExpr | Sequence[Any] | NoDefault = no_default,
         *,
         default: IntoExpr | NoDefault = no_default,
@@ -6667,7 +6879,8 @@ class Series:
         old
             Value or sequence of values to replace.
             Also accepts a mapping of values to their replacement as syntactic sugar for
-            `replace(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+            `replace(old=Series(mapping.keys()), new=Series(mapping.values()))`,
+            or a DataFrame with two columns: the values and their replacement.
         new
             Value or sequence of values to replace by.
             Length must match the length of `old` or have length 1.
@@ -6757,7 +6970,7 @@ class Series:
 
     def replace_strict(
         self,
-        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any] | DataFrame,
         new: IntoExpr | Sequence[Any] | NoDefault = no_default,
         *,
         default: IntoExpr | NoDefault = no_default,
@@ -6771,7 +6984,8 @@ class Series:
         old
             Value or sequence of values to replace.
             Also accepts a mapping of values to their replacement as syntactic sugar for
-            `replace_all(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+            `replace_all(old=Series(mapping.keys()), new=Series(mapping.values()))`,
+            or a DataFrame with two columns: the values and their replacement.
         new
             Value or sequence of values to replace by.
             Length must match the length of `old` or have length 1.
@@ -6891,6 +7105,50 @@ class Series:
         ]
         """  # noqa: W505
 
+    @unstable()
+    def match_map(
+        self,
+        mapping: Mapping[Any, Any],
+        *,
+        default: IntoExpr = None,
+        return_dtype: PolarsDataType | None = None,
+    ) -> Self:
+        """
+        Look up every value in a literal table, like a `match` statement.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        mapping
+            A mapping of values to their output.
+        default
+            The output of values that aren't in `mapping`, null by default.
+            Accepts expression input. Non-expression inputs are parsed as literals.
+        return_dtype
+            The data type of the resulting Series. If set to `None` (default),
+            the data type is determined automatically based on the other inputs.
+
+        See Also
+        --------
+        replace_strict
+
+        Examples
+        --------
+        >>> s = pl.Series("code", [200, 404, 500, 301])
+        >>> s.match_map({200: "ok", 404: "missing", 500: "error"}, default="other")
+        shape: (4,)
+        Series: 'code' [str]
+        [
+                "ok"
+                "missing"
+                "error"
+                "other"
+        ]
+        """
+
     def reshape(self, dimensions: tuple[int, ...]) -> Series:
         """
         Reshape this Series to a flat Series or an Array Series.
@@ -7130,6 +7388,114 @@ class Series:
         ]
         """
 
+    def ewm_std_by(
+        self,
+        by: IntoExpr,
+        *,
+        half_life: str | timedelta,
+    ) -> Series:
+        """
+        Compute time-based exponentially weighted moving standard deviation.
+
+        See :meth:`Expr.ewm_std_by` for the exact definition.
+
+        Parameters
+        ----------
+        by
+            Times to calculate standard deviation by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value.
+
+            Uses the same string language as :meth:`ewm_mean_by`, and is treated as a
+            constant duration.
+
+        Returns
+        -------
+        Series
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df["values"].ewm_std_by(df["times"], half_life="4d")
+        shape: (5,)
+        Series: 'values' [f64]
+        [
+                0.0
+                0.45509
+                0.818774
+                null
+                1.229996
+        ]
+        """
+
+    def ewm_var_by(
+        self,
+        by: IntoExpr,
+        *,
+        half_life: str | timedelta,
+    ) -> Series:
+        """
+        Compute time-based exponentially weighted moving variance.
+
+        See :meth:`Expr.ewm_var_by` for the exact definition.
+
+        Parameters
+        ----------
+        by
+            Times to calculate variance by. Should be ``DateTime``, ``Date``,
+            ``UInt64``, ``UInt32``, ``Int64``, or ``Int32`` data type.
+        half_life
+            Unit over which observation decays to half its value.
+
+            Uses the same string language as :meth:`ewm_mean_by`, and is treated as a
+            constant duration.
+
+        Returns
+        -------
+        Series
+            Float32 if input is Float32, otherwise Float64.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [0, 1, 2, None, 4],
+        ...         "times": [
+        ...             date(2020, 1, 1),
+        ...             date(2020, 1, 3),
+        ...             date(2020, 1, 10),
+        ...             date(2020, 1, 15),
+        ...             date(2020, 1, 17),
+        ...         ],
+        ...     }
+        ... ).sort("times")
+        >>> df["values"].ewm_var_by(df["times"], half_life="4d")
+        shape: (5,)
+        Series: 'values' [f64]
+        [
+                0.0
+                0.207107
+                0.670392
+                null
+                1.512891
+        ]
+        """
+
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def ewm_std(
         self,
@@ -7501,6 +7867,11 @@ class Series:
     # Keep the `list` and `str` properties below at the end of the definition of Series,
     # as to not confuse mypy with the type annotation `str` and `list`
 
+    @property
+    def anonymize(self) -> AnonymizeNameSpace:
+        """Create an object namespace of all privacy related methods."""
+        return AnonymizeNameSpace(self)
+
     @property
     def bin(self) -> BinaryNameSpace:
         """Create an object namespace of all binary related methods."""
@@ -7536,6 +7907,11 @@ class Series:
         """Create an object namespace of all struct related methods."""
         return StructNameSpace(self)
 
+    @property
+    def uuid(self) -> UuidNameSpace:
+        """Create an object namespace of all uuid related methods."""
+        return UuidNameSpace(self)
+
     @property
     @unstable()
     def plot(self) -> SeriesPlot:
@@ -1,15 +1,16 @@
 from __future__ import annotations
 
+import sys
 from typing import TYPE_CHECKING
 
 from polars._utils.deprecation import deprecate_function, deprecate_nonkeyword_arguments
 from polars._utils.unstable import unstable
-from polars._utils.various import no_default
+from polars._utils.various import BUILDING_SPHINX_DOCS, no_default, sphinx_accessor
 from polars.datatypes.constants import N_INFER_DEFAULT
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
-    from collections.abc import Mapping
+    from collections.abc import Mapping, Sequence
 
     from polars import Expr, Series
     from polars._typing import (
@@ -24,6 +25,11 @@ if TYPE_CHECKING:
     )
     from polars._utils.various import NoDefault
     from polars.polars import PySeries
+elif BUILDING_SPHINX_DOCS:
+    # note: we assign this way to work around an autocomplete issue in ipython/jedi
+    # (ref: https://github.com/davidhalter/jedi/issues/2057)
+    current_module = sys.modules[__name__]
+    current_module.property = sphinx_accessor
 
 
 @expr_dispatch
@@ -35,6 +41,15 @@ class StringNameSpace:
     def __init__(self, series: Series) -> None:
         self._s: PySeries = series._s
 
+    @property
+    def url(self) -> StringUrlNameSpace:
+        """
+        Create an object namespace of all URL related methods.
+
+        See the individual method pages for full details.
+        """
+        return StringUrlNameSpace(self)
+
     def to_date(
         self,
         format: str | None = None,
@@ -80,7 +95,7 @@ class StringNameSpace:
 
     def to_datetime(
         self,
-        format: str | None = None,
+        format: str | Sequence[str] | Series | None = None,
         *,
         time_unit: TimeUnit | None = None,
         time_zone: str | None = None,
@@ -99,11 +114,19 @@ class StringNameSpace:
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for the full specification. Example: `"%Y-%m-%d %H:%M:%S"`.
             If set to None (default), the format is inferred from the data.
+
+            * A list of formats is tried in order: every value is parsed with the
+              first format that matches it, which helps with feeds that mix date
+              conventions.
+            * A Series provides the format of every row; rows with a null format
+              result in null. These formats cannot contain a UTC offset.
         time_unit : {None, 'us', 'ns', 'ms'}
             Unit of time for the resulting Datetime column. If set to None (default),
             the time unit is inferred from the format string if given, eg:
             `"%F %T%.3f"` => `Datetime("ms")`. If no fractional second component is
-            found, the default is `"us"`.
+            found, the default is `"us"`. For a list of formats the finest time unit
+            inferred from any of them is used, for a format Series the default is
+            `"us"`.
         time_zone
             Time zone for the resulting Datetime column. Rules are:
 
@@ -120,7 +143,7 @@ class StringNameSpace:
             Raise an error if any conversion fails.
         exact
             Require an exact format match. If False, allow the format to match anywhere
-            in the target string.
+            in the target string. Only supported for a single format.
 
             .. note::
                 Using `exact=False` introduces a performance penalty - cleaning your
@@ -145,6 +168,18 @@ class StringNameSpace:
                 2020-01-01 01:00:00 UTC
                 2020-01-01 02:00:00 UTC
         ]
+
+        Try several formats in order:
+
+        >>> s = pl.Series(["2024-03-01 10:00", "01/03/2024 11:30", "2024-03-02"])
+        >>> s.str.to_datetime(["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M", "%Y-%m-%d"])
+        shape: (3,)
+        Series: '' [datetime[μs]]
+        [
+                2024-03-01 10:00:00
+                2024-03-01 11:30:00
+                2024-03-02 00:00:00
+        ]
         """
 
     def to_time(
@@ -305,6 +340,124 @@ class StringNameSpace:
         ]
         """
 
+    def to_duration(self, time_unit: TimeUnit = "us", *, strict: bool = True) -> Series:
+        """
+        Convert a String column into a Duration column.
+
+        Both textual forms produced by :meth:`Series.dt.to_string` for durations are
+        accepted, so duration columns can be round-tripped through text:
+
+        * The ISO 8601 form, eg: `"P1DT2H3M4.5S"` or `"-PT42S"`. Weeks are accepted,
+          but years and months are not, as they have no fixed length.
+        * The "polars" form seen in the frame repr, eg: `"1d 2h 3m 4s 500ms"`. Every
+          part can carry its own sign, eg: `"-1d -42s"`.
+
+        Parameters
+        ----------
+        time_unit : {'us', 'ns', 'ms'}
+            Time unit of the resulting Duration; parts finer than it are truncated.
+        strict
+            Raise an error if any conversion fails. If set to `False`, values that
+            cannot be parsed are set to null.
+
+        See Also
+        --------
+        polars.Series.dt.to_string : Format a Duration column as text.
+
+        Examples
+        --------
+        >>> s = pl.Series(["P1DT2H", "1d 2h", "-PT1.5S", "3m 20s 100ms", None])
+        >>> s.str.to_duration()
+        shape: (5,)
+        Series: '' [duration[μs]]
+        [
+            1d 2h
+            1d 2h
+            -1s -500ms
+            3m 20s 100ms
+            null
+        ]
+        """
+
+    def to_uuid(self, *, strict: bool = True) -> Series:
+        """
+        Parse UUIDs from their textual form.
+
+        The hyphenated form (eg: `"67e55044-10b1-426f-9247-bb680e5fe0c8"`) and the
+        simple form without hyphens are accepted, optionally wrapped in braces or
+        prefixed with `"urn:uuid:"`. Hexadecimal digits are case-insensitive.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if any value is not a valid UUID. If set to `False`,
+            invalid values are set to null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Uuid`.
+
+        See Also
+        --------
+        polars.Series.uuid.to_string : Format UUIDs in their textual form.
+
+        Examples
+        --------
+        >>> s = pl.Series(["{67E55044-10B1-426F-9247-BB680E5FE0C8}", "not-a-uuid"])
+        >>> s.str.to_uuid(strict=False).uuid.to_string()
+        shape: (2,)
+        Series: '' [str]
+        [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8"
+            null
+        ]
+        """
+
+    def to_ip(self, *, strict: bool = True) -> Series:
+        """
+        Parse IPv4 and IPv6 addresses into their bytes.
+
+        An IPv4 address (eg: `"192.168.0.1"`) becomes 4 bytes and an IPv6 address
+        (eg: `"2001:db8::1"`) becomes 16 bytes, both in network (big-endian) order.
+
+        Parameters
+        ----------
+        strict
+            Raise an error if any value is not a valid IP address. If set to `False`,
+            invalid values are set to null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`.
+
+        See Also
+        --------
+        polars.Series.bin.ip_to_string : Format IP addresses in their binary form.
+        polars.Series.bin.ip_is_in_subnet : Check if IP addresses are in a subnet.
+        polars.Series.bin.ipv4_to_integer : Convert IPv4 addresses to integers.
+        polars.Series.bin.ipv6_to_integer : Convert IP addresses to 128-bit integers.
+
+        Notes
+        -----
+        Polars has no IP address data type: addresses are stored as plain
+        :class:`Binary` values, so IPv4 and IPv6 addresses can be mixed in one
+        column and are told apart by their length only.
+
+        Examples
+        --------
+        >>> s = pl.Series(["10.0.0.1", "not-an-ip", "2001:DB8:0:0::1"])
+        >>> s.str.to_ip(strict=False).bin.ip_to_string()
+        shape: (3,)
+        Series: '' [str]
+        [
+            "10.0.0.1"
+            null
+            "2001:db8::1"
+        ]
+        """
+
     def len_bytes(self) -> Series:
         """
         Return the length of each string as the number of bytes.
@@ -379,7 +532,14 @@ class StringNameSpace:
         """
 
     def contains(
-        self, pattern: str | Expr, *, literal: bool = False, strict: bool = True
+        self,
+        pattern: str | Expr,
+        *,
+        literal: bool = False,
+        strict: bool = True,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
     ) -> Series:
         """
         Check if the string contains a substring that matches a pattern.
@@ -394,6 +554,13 @@ class StringNameSpace:
         strict
             Raise an error if the underlying pattern is not a valid regex,
             otherwise mask out with a null value.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
 
         Notes
         -----
@@ -717,7 +884,15 @@ class StringNameSpace:
         ]
         """
 
-    def extract(self, pattern: IntoExprColumn, group_index: int = 1) -> Series:
+    def extract(
+        self,
+        pattern: IntoExprColumn,
+        group_index: int = 1,
+        *,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
+    ) -> Series:
         r"""
         Extract the target capture group from provided patterns.
 
@@ -730,6 +905,13 @@ class StringNameSpace:
             Index of the targeted capture group.
             Group 0 means the whole pattern, the first group begins at index 1.
             Defaults to the first capture group.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
 
         Returns
         -------
@@ -781,7 +963,14 @@ class StringNameSpace:
         ]
         """
 
-    def extract_all(self, pattern: str | Series) -> Series:
+    def extract_all(
+        self,
+        pattern: str | Series,
+        *,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
+    ) -> Series:
         r'''
         Extract all matches for the given regex pattern.
 
@@ -793,6 +982,13 @@ class StringNameSpace:
         pattern
             A valid regular expression pattern, compatible with the `regex crate
             <https://docs.rs/regex/latest/regex/>`_.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
 
         Notes
         -----
@@ -851,7 +1047,14 @@ class StringNameSpace:
 
         '''
 
-    def extract_groups(self, pattern: str) -> Series:
+    def extract_groups(
+        self,
+        pattern: str,
+        *,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
+    ) -> Series:
         r"""
         Extract all capture groups for the given regex pattern.
 
@@ -860,6 +1063,13 @@ class StringNameSpace:
         pattern
             A valid regular expression pattern containing at least one capture group,
             compatible with the `regex crate <https://docs.rs/regex/latest/regex/>`_.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
 
         Notes
         -----
@@ -907,7 +1117,62 @@ class StringNameSpace:
         ]
         """
 
-    def count_matches(self, pattern: str | Series, *, literal: bool = False) -> Series:
+    def extract_all_groups(
+        self,
+        pattern: str,
+        *,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
+    ) -> Series:
+        r"""
+        Extract the capture groups of every match of the given regex pattern.
+
+        Each successive non-overlapping match becomes a struct of its capture groups,
+        named as in :meth:`extract_groups`.
+
+        Parameters
+        ----------
+        pattern
+            A valid regular expression pattern containing at least one capture group,
+            compatible with the `regex crate <https://docs.rs/regex/latest/regex/>`_.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
+
+        Returns
+        -------
+        Series
+            Series of data type `List(Struct)` with fields of data type
+            :class:`String`. Returns null if the original value is null and an
+            empty list if there is no match.
+
+        Examples
+        --------
+        >>> s = pl.Series("s", ["a=1, b=2", "none", None])
+        >>> s.str.extract_all_groups(r"(?<key>\w)=(?<value>\d)")
+        shape: (3,)
+        Series: 's' [list[struct[2]]]
+        [
+            [{"a","1"}, {"b","2"}]
+            []
+            null
+        ]
+        """
+
+    def count_matches(
+        self,
+        pattern: str | Series,
+        *,
+        literal: bool = False,
+        ignore_case: bool = False,
+        multiline: bool = False,
+        dot_all: bool = False,
+    ) -> Series:
         r"""
         Count all successive non-overlapping regex matches.
 
@@ -919,6 +1184,13 @@ class StringNameSpace:
             regular expressions.
         literal
             Treat `pattern` as a literal string, not as a regular expression.
+        ignore_case
+            Match letters case-insensitively, like the inline `(?i)` flag.
+        multiline
+            Let `^` and `$` match at the start and end of every line, like the
+            inline `(?m)` flag.
+        dot_all
+            Let `.` match a newline too, like the inline `(?s)` flag.
 
         Returns
         -------
@@ -1552,6 +1824,32 @@ class StringNameSpace:
         ]
         """
 
+    def to_casefold(self) -> Series:
+        """
+        Fold the case of strings, for caseless comparisons.
+
+        Case folding is like converting to lowercase, but also folds characters that
+        have no single lowercase equivalent, for example "ß" to "ss". Use it to compare,
+        join or deduplicate text regardless of case; it is not meant for display.
+
+        Notes
+        -----
+        Every character is folded to the lowercase of its full uppercase mapping. This
+        is not locale-aware: like the default Unicode case folding, the dotted and
+        dotless "i" of Turkish are kept apart.
+
+        Examples
+        --------
+        >>> s = pl.Series("city", ["Straße", "STRASSE"])
+        >>> s.str.to_casefold()
+        shape: (2,)
+        Series: 'city' [str]
+        [
+            "strasse"
+            "strasse"
+        ]
+        """
+
     def to_uppercase(self) -> Series:
         """
         Modify strings to their uppercase equivalent.
@@ -1615,6 +1913,121 @@ class StringNameSpace:
         ]
         """
 
+    def levenshtein(
+        self, other: str | Expr, *, max_distance: int | None = None
+    ) -> Series:
+        """
+        Compute the Levenshtein distance to another string.
+
+        The distance is the minimal number of inserted, deleted or substituted
+        characters needed to turn one string into the other.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input. Strings are parsed
+            as literals.
+        max_distance
+            Return null for distances above this value. The computation of such
+            a pair stops as soon as the distance is known to exceed it.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "flaw", "polars", None])
+        >>> s.str.levenshtein("sitting")
+        shape: (4,)
+        Series: '' [u32]
+        [
+            3
+            7
+            7
+            null
+        ]
+        """
+
+    def hamming(self, other: str | Expr, *, max_distance: int | None = None) -> Series:
+        """
+        Count the characters that differ from another string of the same length.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input. Strings are parsed
+            as literals.
+        max_distance
+            Return null for distances above this value.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`. The result is null if the strings
+            have a different number of characters.
+
+        Examples
+        --------
+        >>> s = pl.Series(["karolin", "kathrin", "abc"])
+        >>> s.str.hamming("kerstin")
+        shape: (3,)
+        Series: '' [u32]
+        [
+            3
+            4
+            null
+        ]
+        """
+
+    def jaro_winkler(self, other: str | Expr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity is between 0 and 1, where 1 means the strings are equal.
+        Strings that share a prefix of up to four characters score higher.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input. Strings are parsed
+            as literals.
+
+        Examples
+        --------
+        >>> s = pl.Series(["martha", "marhta", "polars"])
+        >>> s.str.jaro_winkler("martha").round(3)
+        shape: (3,)
+        Series: '' [f64]
+        [
+            1.0
+            0.961
+            0.556
+        ]
+        """
+
+    def token_set_ratio(self, other: str | Expr) -> Series:
+        """
+        Compare the whitespace separated words of two strings, ignoring their order.
+
+        The ratio is between 0 and 1, and is 1 if the words of one string are a subset
+        of the words of the other.
+
+        Parameters
+        ----------
+        other
+            The string to compare with. Accepts expression input. Strings are parsed
+            as literals.
+
+        Examples
+        --------
+        >>> s = pl.Series(["new york mets", "york", "great apple"])
+        >>> s.str.token_set_ratio("mets new york")
+        shape: (3,)
+        Series: '' [f64]
+        [
+            1.0
+            1.0
+            0.25
+        ]
+        """
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Series:
@@ -2256,3 +2669,139 @@ class StringNameSpace:
                 "KADOKAWA"
         ]
         """  # noqa: RUF002
+
+    def strip_diacritics(self) -> Series:
+        """
+        Remove the diacritics, such as accents, from the string values.
+
+        Characters are decomposed into a base character and combining marks (NFD),
+        the marks are removed and the result is composed again (NFC). Characters that
+        have no decomposition, such as "ø" or "ł", are kept as is.
+
+        Examples
+        --------
+        >>> s = pl.Series(["Crème Brûlée", "naïve", "Øresund"])
+        >>> s.str.strip_diacritics()
+        shape: (3,)
+        Series: '' [str]
+        [
+            "Creme Brulee"
+            "naive"
+            "Øresund"
+        ]
+        """
+
+
+@expr_dispatch
+class StringUrlNameSpace:
+    """Series.str.url namespace."""
+
+    _accessor = "str.url"
+
+    def __init__(self, str_namespace: StringNameSpace) -> None:
+        # note: the namespace class itself is passed when building the docs
+        self._s: PySeries | None = getattr(str_namespace, "_s", None)
+
+    def scheme(self) -> Series:
+        """
+        Get the scheme of every URL, such as `"https"`, in lowercase.
+
+        URLs are split following the generic syntax of RFC 3986. The scheme is null
+        for relative URLs.
+
+        Examples
+        --------
+        >>> s = pl.Series(["HTTPS://pola.rs", "mailto:info@pola.rs", "/docs"])
+        >>> s.str.url.scheme()
+        shape: (3,)
+        Series: '' [str]
+        [
+            "https"
+            "mailto"
+            null
+        ]
+        """
+
+    def host(self) -> Series:
+        """
+        Get the host of every URL in lowercase, without the user info and the port.
+
+        The host is null for URLs without an authority, which starts with `"//"`, so
+        a URL such as `"pola.rs/docs"` has no host.
+
+        Examples
+        --------
+        >>> s = pl.Series(["https://user@Pola.rs:443/", "http://[::1]:80", "pola.rs"])
+        >>> s.str.url.host()
+        shape: (3,)
+        Series: '' [str]
+        [
+            "pola.rs"
+            "[::1]"
+            null
+        ]
+        """
+
+    def path(self) -> Series:
+        """
+        Get the path of every URL, which is still percent-encoded.
+
+        The path can be empty, for example for `"https://pola.rs"`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["https://pola.rs/a%20b?q=1", "https://pola.rs"])
+        >>> s.str.url.path()
+        shape: (2,)
+        Series: '' [str]
+        [
+            "/a%20b"
+            ""
+        ]
+        """
+
+    def query_param(self, key: str) -> Series:
+        """
+        Get the value of the first query parameter named `key` of every URL.
+
+        Both the names and the values are decoded, with `"+"` decoded as a space.
+        The value is null if the parameter is missing, and an empty string for a
+        parameter without value, such as `"flag"` in `"?flag&q=1"`.
+
+        Parameters
+        ----------
+        key
+            The (decoded) name of the query parameter.
+
+        Examples
+        --------
+        >>> s = pl.Series(["/a?id=1&tag=x%2By", "/b?tag", "/c"])
+        >>> s.str.url.query_param("tag")
+        shape: (3,)
+        Series: '' [str]
+        [
+            "x+y"
+            ""
+            null
+        ]
+        """
+
+    def decode(self) -> Series:
+        """
+        Decode the percent-encoded escapes, such as `"%20"`, of every string.
+
+        Invalid escapes are kept as they are, and strings that don't decode to valid
+        UTF-8 become null. Unlike :meth:`query_param`, `"+"` is not decoded.
+
+        Examples
+        --------
+        >>> s = pl.Series(["/caf%C3%A9/a%20b", "/100%", "/%FF"])
+        >>> s.str.url.decode()
+        shape: (3,)
+        Series: '' [str]
+        [
+            "/café/a b"
+            "/100%"
+            null
+        ]
+        """
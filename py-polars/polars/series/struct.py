@@ -9,7 +9,7 @@ from polars.schema import Schema
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
-    from collections.abc import Sequence
+    from collections.abc import Mapping, Sequence
 
     from polars import DataFrame, Series
     from polars.polars import PySeries
@@ -77,14 +77,15 @@ class StructNameSpace:
         ]
         """
 
-    def rename_fields(self, names: Sequence[str]) -> Series:
+    def rename_fields(self, names: Sequence[str] | Mapping[str, str]) -> Series:
         """
         Rename the fields of the struct.
 
         Parameters
         ----------
         names
-            New names in the order of the struct's fields.
+            New names in the order of the struct's fields, or a mapping of (dotted)
+            field names to new names.
 
         Examples
         --------
@@ -94,6 +95,31 @@ class StructNameSpace:
         >>> s = s.struct.rename_fields(["c", "d"])
         >>> s.struct.fields
         ['c', 'd']
+        >>> s.struct.rename_fields({"d": "e"}).struct.fields
+        ['c', 'e']
+        """
+
+    def drop_fields(self, name: str | list[str], *more_names: str) -> Series:
+        """
+        Drop fields of the struct.
+
+        Parameters
+        ----------
+        name
+            Name of the field to drop, or a list of names.
+        *more_names
+            Additional names of fields to drop, specified as positional arguments.
+
+        Notes
+        -----
+        A dotted name such as `"a.b"` drops field `b` of the nested struct field
+        `a`.
+
+        Examples
+        --------
+        >>> s = pl.Series([{"a": 1, "b": {"c": 2, "d": 3}}])
+        >>> s.struct.drop_fields("b.c").struct.schema
+        Schema({'a': Int64, 'b': Struct({'d': Int64})})
         """
 
     @property
@@ -66,9 +66,10 @@ def _expr_lookup(namespace: str | None) -> set[tuple[str | None, str, tuple[str,
     expr = pl.Expr()
     expr._pyexpr = None
 
-    # optional indirection to "expr.str", "expr.dt", etc
+    # optional indirection to "expr.str", "expr.dt", etc (or nested, as "expr.str.url")
     if namespace is not None:
-        expr = getattr(expr, namespace)
+        for accessor in namespace.split("."):
+            expr = getattr(expr, accessor)
 
     lookup = set()
     for name in dir(expr):
@@ -101,7 +102,8 @@ def call_expr(func: SeriesMethod) -> SeriesMethod:
         s = wrap_s(self._s)
         expr = F.col(s.name)
         if (namespace := getattr(self, "_accessor", None)) is not None:
-            expr = getattr(expr, namespace)
+            for accessor in namespace.split("."):
+                expr = getattr(expr, accessor)
         f = getattr(expr, func.__name__)
         return s.to_frame().select_seq(f(*args, **kwargs)).to_series()
 
@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class UuidNameSpace:
+    """Series.uuid namespace."""
+
+    _accessor = "uuid"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def to_string(self) -> Series:
+        """
+        Format UUIDs in the lowercase hyphenated form.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        See Also
+        --------
+        polars.Series.str.to_uuid : Parse UUIDs from their textual form.
+
+        Examples
+        --------
+        >>> s = pl.Series("id", ["67E55044-10B1-426F-9247-BB680E5FE0C8", None])
+        >>> s.str.to_uuid().uuid.to_string()
+        shape: (2,)
+        Series: 'id' [str]
+        [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8"
+            null
+        ]
+        """
@@ -159,6 +159,10 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::mean_horizontal))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::cum_sum_horizontal))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rank_horizontal))
+        .unwrap();
 
     // Functions - lazy
     m.add_wrapped(wrap_pyfunction!(functions::arg_sort_by))
@@ -211,6 +215,7 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::first)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::fold)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::last)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::lstsq)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::lit)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::map_mul)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::nth)).unwrap();
@@ -220,6 +225,14 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::rolling_cov))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rolling_corr_by))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rolling_cov_by))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::rolling_ols))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::random_distribution))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::reduce)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::repeat)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::spearman_rank_corr))
@@ -257,6 +270,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::thread_pool_size))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::engine_stats))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::enable_string_cache))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::disable_string_cache))
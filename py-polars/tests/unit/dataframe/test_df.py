@@ -3057,3 +3057,41 @@ def test_nan_to_null() -> None:
     )
 
     assert_frame_equal(df1, df2)
+
+
+def test_memory_profile() -> None:
+    df = pl.DataFrame({"a": [1, None, 3], "b": ["x", "y", "z"]})
+    df = df.with_columns(c=pl.col("a"))
+
+    profile = df.memory_profile()
+    assert profile["column"].to_list() == ["a", "b", "c"]
+    assert profile["dtype"].to_list() == ["i64", "str", "i64"]
+    assert profile.row(0, named=True) == {
+        "column": "a",
+        "dtype": "i64",
+        "values": 24,
+        "validity": 1,
+        "offsets": 0,
+        "dictionary": 0,
+        "shared": 0,
+        "total": 25,
+    }
+    assert profile.row(2, named=True)["shared"] == 25
+    assert profile.row(2, named=True)["total"] == 0
+
+    # A slice still holds the whole buffers.
+    assert df.slice(1).memory_profile().row(0, named=True)["total"] == 25
+
+
+def test_rechunk_to() -> None:
+    df = pl.DataFrame({"a": range(10), "b": [str(i) for i in range(10)]})
+    df = pl.concat([df.head(1), df.tail(9)], rechunk=False)
+
+    out = df.rechunk_to(4)
+    assert out.n_chunks("all") == [3, 3]
+    assert [len(s) for s in out.get_column("a").get_chunks()] == [4, 3, 3]
+    assert_frame_equal(out, df)
+
+    assert df.rechunk_to(100).n_chunks("all") == [1, 1]
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.rechunk_to(0)
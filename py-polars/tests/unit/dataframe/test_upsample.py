@@ -1,7 +1,7 @@
 from __future__ import annotations
 
-from datetime import datetime
-from typing import TYPE_CHECKING
+from datetime import date, datetime
+from typing import TYPE_CHECKING, Any
 from zoneinfo import ZoneInfo
 
 import pytest
@@ -281,3 +281,94 @@ def test_upsample_sorted_only_within_group_but_no_group_by_provided() -> None:
         match=r"argument in operation 'upsample' is not sorted, please sort the 'expr/series/column' first",
     ):
         df.upsample(time_column="time", every="1mo")
+
+
+def test_upsample_lazy_matches_eager() -> None:
+    df = pl.DataFrame(
+        {
+            "time": [
+                datetime(2021, 2, 1),
+                datetime(2021, 4, 1),
+                datetime(2021, 5, 1),
+                datetime(2021, 6, 1),
+            ],
+            "groups": ["A", "B", "A", "B"],
+            "values": [0, 1, 2, 3],
+        }
+    ).set_sorted("time")
+
+    result = df.lazy().upsample(
+        "time", every="1mo", group_by="groups", maintain_order=True
+    )
+    expected = df.upsample(
+        "time", every="1mo", group_by="groups", maintain_order=True
+    ).with_columns(pl.col("groups").fill_null(strategy="forward"))
+    assert_frame_equal(result.collect(), expected)
+
+
+@pytest.mark.parametrize(
+    ("fill", "expected_values"),
+    [
+        (None, [1.0, None, None, 4.0, None, 6.0]),
+        ("forward", [1.0, 1.0, 1.0, 4.0, None, 6.0]),
+        ("backward", [1.0, 4.0, 4.0, 4.0, None, 6.0]),
+        ("interpolate", [1.0, 2.0, 3.0, 4.0, None, 6.0]),
+        (0.0, [1.0, 0.0, 0.0, 4.0, None, 6.0]),
+        (pl.lit(-1.0), [1.0, -1.0, -1.0, 4.0, None, 6.0]),
+    ],
+)
+def test_upsample_lazy_fill(fill: Any, expected_values: list[float | None]) -> None:
+    lf = pl.LazyFrame(
+        {
+            "time": [
+                date(2024, 1, 1),
+                date(2024, 1, 4),
+                date(2024, 1, 5),
+                date(2024, 1, 6),
+            ],
+            "values": [1.0, 4.0, None, 6.0],
+        }
+    )
+    result = lf.upsample("time", every="1d", fill=fill).collect()
+    expected = pl.DataFrame(
+        {
+            "time": pl.date_range(date(2024, 1, 1), date(2024, 1, 6), eager=True),
+            "values": expected_values,
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_upsample_lazy_fill_per_column_in_groups() -> None:
+    lf = pl.LazyFrame(
+        {
+            "idx": [0, 4, 0, 2],
+            "group": ["a", "a", None, None],
+            "x": [0, 4, 10, 30],
+            "y": ["p", "q", "r", "s"],
+        }
+    )
+    result = lf.upsample(
+        "idx",
+        every="2i",
+        group_by="group",
+        maintain_order=True,
+        fill={"x": "interpolate", "y": "forward"},
+    ).collect()
+    expected = pl.DataFrame(
+        {
+            "idx": [0, 2, 4, 0, 2],
+            "group": ["a", "a", "a", None, None],
+            "x": [0.0, 2.0, 4.0, 10.0, 30.0],
+            "y": ["p", "p", "q", "r", "s"],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_upsample_lazy_invalid() -> None:
+    lf = pl.LazyFrame({"idx": [0, 2], "x": [1, 2]})
+    with pytest.raises(InvalidOperationError, match="must be a parsed integer"):
+        lf.upsample("idx", every="1d")
+    with pytest.raises(InvalidOperationError, match="unsupported data type"):
+        pl.LazyFrame({"s": ["a", "b"]}).upsample("s", every="1d")
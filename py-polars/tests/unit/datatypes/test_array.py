@@ -402,3 +402,24 @@ def test_sort() -> None:
     tc([[2], [1]], [[1], [2]], 1)
     tc([[2, 1]], [[2, 1]], 2)
     tc([[2, 1], [1, 2]], [[1, 2], [2, 1]], 2)
+
+
+def test_array_as_group_by_and_join_key() -> None:
+    df = pl.DataFrame(
+        {"emb": [[1, 2], [3, 4], [1, 2], None], "x": [1, 2, 3, 4]},
+        schema={"emb": pl.Array(pl.Int64, 2), "x": pl.Int64},
+    )
+
+    out = df.group_by("emb", maintain_order=True).agg(pl.col("x").sum())
+    assert out.to_dict(as_series=False) == {
+        "emb": [[1, 2], [3, 4], None],
+        "x": [4, 2, 4],
+    }
+    assert df["emb"].n_unique() == 3
+
+    other = pl.DataFrame(
+        {"emb": [[3, 4], [1, 2]], "y": ["a", "b"]},
+        schema={"emb": pl.Array(pl.Int64, 2), "y": pl.String},
+    )
+    joined = df.join(other, on="emb", how="left", maintain_order="left")
+    assert joined["y"].to_list() == ["b", "a", "b", None]
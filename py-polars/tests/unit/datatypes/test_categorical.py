@@ -405,7 +405,49 @@ def test_categorical_error_on_local_cmp() -> None:
         StringCacheMismatchError,
         match="cannot compare categoricals coming from different sources",
     ):
-        df_cat.filter(pl.col("a_cat") == pl.col("b_cat"))
+        _ = df_cat["a_cat"] == df_cat["b_cat"]
+
+
+def test_categorical_local_cmp_in_query() -> None:
+    df_cat = pl.DataFrame(
+        [
+            pl.Series("a_cat", ["c", "a", "b", "c", "b"], dtype=pl.Categorical),
+            pl.Series("b_cat", ["F", "a", "E", "c", "G"], dtype=pl.Categorical),
+        ]
+    )
+    # Queries intern the categories of both columns, so they can be compared.
+    out = df_cat.filter(pl.col("a_cat") == pl.col("b_cat"))
+    assert out["a_cat"].to_list() == ["a", "c"]
+    assert out["b_cat"].to_list() == ["a", "c"]
+
+    out = df_cat.group_by(pl.col("a_cat").cast(pl.String).alias("g")).agg(
+        (pl.col("a_cat") != pl.col("b_cat")).sum()
+    )
+    assert out.sort("g").to_dict(as_series=False) == {
+        "g": ["a", "b", "c"],
+        "a_cat": [0, 2, 1],
+    }
+    assert not pl.using_string_cache()
+
+
+def test_categorical_query_scoped_string_cache() -> None:
+    # Joins re-encode categorical keys from different sources.
+    left = pl.LazyFrame({"key": ["c", "a", "b"], "x": [1, 2, 3]})
+    right = pl.LazyFrame({"key": ["b", "c", "d"], "y": [4, 5, 6]})
+    cat = pl.col("key").cast(pl.Categorical)
+
+    out = (
+        left.with_columns(cat)
+        .join(right.with_columns(cat), on="key")
+        .sort("x")
+        .collect()
+    )
+    expected = pl.DataFrame(
+        {"key": ["c", "b"], "x": [1, 3], "y": [5, 4]},
+        schema_overrides={"key": pl.Categorical},
+    )
+    assert_frame_equal(out, expected)
+    assert not pl.using_string_cache()
 
 
 @pytest.mark.usefixtures("test_global_and_local")
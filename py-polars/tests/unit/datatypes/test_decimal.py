@@ -7,13 +7,16 @@ from dataclasses import dataclass
 from decimal import Decimal as D
 from math import ceil, floor
 from random import choice, randrange, seed
-from typing import Any, Callable, NamedTuple
+from typing import TYPE_CHECKING, Any, Callable, NamedTuple
 
 import pytest
 
 import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import DecimalOverflow
+
 
 @pytest.fixture(scope="module")
 def permutations_int_dec_none() -> list[tuple[D | int | None, ...]]:
@@ -718,3 +721,147 @@ def test_decimal_min_over_21096() -> None:
     df = pl.Series("x", [1, 2], pl.Decimal(scale=2)).to_frame()
     result = df.select(pl.col("x").min().over("x"))
     assert result["x"].to_list() == [D("1.00"), D("2.00")]
+
+
+def test_decimal_group_by_float_aggregations() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2],
+            "a": [D("1.5"), D("2.5"), D("5.0"), D("10.25"), D("20.75")],
+        },
+        schema={"g": pl.Int64, "a": pl.Decimal(10, 2)},
+    )
+    result = (
+        df.lazy()
+        .group_by("g", maintain_order=True)
+        .agg(
+            mean=pl.col("a").mean(),
+            median=pl.col("a").median(),
+            std=pl.col("a").std(),
+            var=pl.col("a").var(),
+            quantile=pl.col("a").quantile(1.0),
+        )
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2],
+            "mean": [3.0, 15.5],
+            "median": [2.5, 15.5],
+            "std": [1.8027756377319946, 7.424621202458749],
+            "var": [3.25, 55.125],
+            "quantile": [5.0, 20.75],
+        }
+    )
+    assert result.collect_schema() == expected.schema
+    assert_frame_equal(result.collect(), expected)
+
+
+def test_decimal_var() -> None:
+    s = pl.Series("a", [D("1.5"), D("2.5"), D("5.0"), None], dtype=pl.Decimal(10, 2))
+    assert s.var() == pytest.approx(3.25)
+    assert s.std() == pytest.approx(1.8027756377319946)
+    assert s.to_frame().select(pl.col("a").var()).item() == pytest.approx(3.25)
+
+
+def test_decimal_mod_floordiv() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [D("7.50"), D("10.00"), D("0.25"), None],
+            "b": [D("2.0"), D("3.3"), D("0.1"), D("1.0")],
+        },
+        schema={"a": pl.Decimal(10, 2), "b": pl.Decimal(10, 1)},
+    )
+    q = df.lazy().select(
+        mod=pl.col("a") % pl.col("b"),
+        floordiv=pl.col("a") // pl.col("b"),
+    )
+    result = q.collect()
+    assert q.collect_schema() == result.schema
+    assert result.schema == {
+        "mod": pl.Decimal(None, 2),
+        "floordiv": pl.Decimal(None, 0),
+    }
+    assert result.to_dict(as_series=False) == {
+        "mod": [D("1.50"), D("0.10"), D("0.05"), None],
+        "floordiv": [D("3"), D("3"), D("2"), None],
+    }
+
+
+def test_decimal_overflow() -> None:
+    max_digits = "9" * 38
+    df = pl.DataFrame(
+        {
+            "a": [D(max_digits), D(f"-{max_digits}"), None, D(1)],
+            "b": [D(1), D(1), D(1), D(1)],
+        }
+    )
+    a, b = pl.col("a"), pl.col("b")
+
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal addition overflowed"):
+        df.select(a.add(b, decimal_overflow="error"))
+    with pytest.raises(
+        pl.exceptions.ComputeError, match="decimal multiplication overflowed"
+    ):
+        df.select(a.mul(a, decimal_overflow="error"))
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal rescaling overflowed"):
+        df.select(a.truediv(b, decimal_overflow="error"))
+
+    result = df.select(
+        add=a.add(b, decimal_overflow="saturate"),
+        sub=a.sub(-a, decimal_overflow="saturate"),
+        mul=a.mul(a, decimal_overflow="saturate"),
+        # the rescaling to the common scale saturates as well
+        add_scaled=a.add(D("0.5"), decimal_overflow="saturate"),
+        # integers are treated as decimals with a scale of 0
+        mul_int=a.mul(10, decimal_overflow="saturate"),
+    )
+    assert result.to_dict(as_series=False) == {
+        "add": [D(max_digits), D(f"-{max_digits}") + 1, None, D(2)],
+        "sub": [D(max_digits), D(f"-{max_digits}"), None, D(2)],
+        "mul": [D(max_digits), D(max_digits), None, D(1)],
+        "add_scaled": [
+            D("9" * 37 + ".9"),
+            D("-" + "9" * 37 + ".4"),
+            None,
+            D("1.5"),
+        ],
+        "mul_int": [D(max_digits), D(f"-{max_digits}"), None, D(10)],
+    }
+
+    # the operators keep wrapping, and values that fit are unaffected by the policy
+    small = df.tail(1)
+    assert small.select(a * 3 + b).item() == D(4)
+    policies: list[DecimalOverflow] = ["wrap", "error", "saturate"]
+    for policy in policies:
+        expr = a.mul(3, decimal_overflow=policy).add(b, decimal_overflow=policy)
+        assert small.select(expr).item() == D(4)
+
+
+def test_decimal_overflow_schema() -> None:
+    lf = pl.LazyFrame(
+        {"a": [D("1.5")], "b": [D("0.25")]},
+        schema={"a": pl.Decimal(10, 1), "b": pl.Decimal(10, 2)},
+    )
+    a, b = pl.col("a"), pl.col("b")
+    q = lf.select(
+        add=a.add(b, decimal_overflow="error"),
+        mul=a.mul(b, decimal_overflow="error"),
+        div=a.truediv(b, decimal_overflow="error"),
+    )
+    assert q.collect_schema() == q.collect().schema
+    assert q.collect_schema() == {
+        "add": pl.Decimal(None, 2),
+        "mul": pl.Decimal(None, 3),
+        "div": pl.Decimal(None, 5),
+    }
+    assert q.collect().row(0) == (D("1.75"), D("0.375"), D("6.00000"))
+
+
+def test_decimal_overflow_invalid() -> None:
+    df = pl.DataFrame({"a": [1.5]})
+    with pytest.raises(ValueError, match="`decimal_overflow` must be one of"):
+        df.select(pl.col("a").add(1, decimal_overflow="promote"))  # type: ignore[arg-type]
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="requires decimal operands"
+    ):
+        df.select(pl.col("a").add(1, decimal_overflow="error"))
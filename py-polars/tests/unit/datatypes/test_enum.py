@@ -723,3 +723,39 @@ def test_read_enum_from_csv() -> None:
     read = pl.read_csv(f, schema=schema)
     assert read.schema == schema
     assert_frame_equal(df.cast(schema), read)  # type: ignore[arg-type]
+
+
+def test_enum_ordered_operations() -> None:
+    dtype = pl.Enum(["LOW", "MEDIUM", "HIGH"])
+    df = pl.DataFrame(
+        {
+            "group": [1, 1, 2, 2, 2],
+            "level": pl.Series(["HIGH", "LOW", "MEDIUM", None, "LOW"], dtype=dtype),
+        }
+    )
+
+    out = df.select(pl.col("level") < "HIGH").to_series()
+    assert out.to_list() == [False, True, True, None, True]
+
+    out = df.select(pl.col("level").clip(pl.lit("MEDIUM"))).to_series()
+    assert_series_equal(
+        out, pl.Series("level", ["HIGH", "MEDIUM", "MEDIUM", None, "MEDIUM"], dtype)
+    )
+    out = df.select(pl.col("level").clip(upper_bound=pl.lit("MEDIUM"))).to_series()
+    assert out.to_list() == ["MEDIUM", "LOW", "MEDIUM", None, "LOW"]
+
+    out = df.group_by("group", maintain_order=True).agg(
+        min=pl.col("level").min(), max=pl.col("level").max()
+    )
+    expected = pl.DataFrame(
+        {
+            "group": [1, 2],
+            "min": pl.Series(["LOW", "LOW"], dtype=dtype),
+            "max": pl.Series(["HIGH", "MEDIUM"], dtype=dtype),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    s = pl.Series(["b", "a"], dtype=pl.Categorical)
+    with pytest.raises(InvalidOperationError, match="cast to an Enum first"):
+        s.clip(pl.lit("a"))
@@ -1,8 +1,17 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import numpy as np
 import pyarrow as pa
+import pyarrow.parquet as pq
 import pytest
 
 import polars as pl
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
 
 
 def test_nan_in_group_by_agg() -> None:
@@ -306,3 +315,52 @@ def test_arrow_float16_read_empty_20946() -> None:
     df = pl.from_arrow(table)
     assert df.shape == (0, 1)
     assert df.schema == pl.Schema([("float_column", pl.Float32)])  # type: ignore[union-attr]
+
+
+def test_arrow_float16_read_nested() -> None:
+    values = pa.array(
+        np.array([0.5, -1.0, 2.25, 0.0], dtype=np.float16),
+        mask=np.array([False, False, False, True]),
+    )
+    table = pa.table(
+        {
+            "list": pa.ListArray.from_arrays([0, 2, 4], values),
+            "array": pa.FixedSizeListArray.from_arrays(values, 2),
+            "struct": pa.StructArray.from_arrays([values[:2]], ["x"]),
+        }
+    )
+
+    df = pl.from_arrow(table)
+    assert df.schema == pl.Schema(  # type: ignore[union-attr]
+        {
+            "list": pl.List(pl.Float32),
+            "array": pl.Array(pl.Float32, 2),
+            "struct": pl.Struct({"x": pl.Float32}),
+        }
+    )
+    assert df.to_dict(as_series=False) == {  # type: ignore[union-attr]
+        "list": [[0.5, -1.0], [2.25, None]],
+        "array": [[0.5, -1.0], [2.25, None]],
+        "struct": [{"x": 0.5}, {"x": -1.0}],
+    }
+
+
+@pytest.mark.write_disk
+def test_float16_read_ipc_parquet(tmp_path: Path) -> None:
+    values = pa.array(np.array([0.5, -1.0, 2.25], dtype=np.float16))
+    table = pa.table(
+        {"x": values, "list": pa.ListArray.from_arrays([0, 1, 3], values)}
+    )
+    with pa.ipc.new_file(str(tmp_path / "f16.arrow"), table.schema) as writer:
+        writer.write_table(table)
+    pq.write_table(table, tmp_path / "f16.parquet")
+    pq.write_table(table, tmp_path / "f16_no_schema.parquet", store_schema=False)
+
+    expected = pl.DataFrame(
+        {"x": [0.5, -1.0, 2.25], "list": [[0.5], [-1.0, 2.25]]},
+        schema={"x": pl.Float32, "list": pl.List(pl.Float32)},
+    )
+    assert_frame_equal(pl.read_ipc(tmp_path / "f16.arrow"), expected)
+    for name in ["f16.parquet", "f16_no_schema.parquet"]:
+        assert_frame_equal(pl.read_parquet(tmp_path / name), expected)
+        assert_frame_equal(pl.scan_parquet(tmp_path / name).collect(), expected)
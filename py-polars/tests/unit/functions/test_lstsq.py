@@ -0,0 +1,116 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    # y = 1 + 2 * x1 - x2, and the row with a null regressor is left out of the fit.
+    return pl.DataFrame(
+        {
+            "y": [0.0, 3.0, 2.0, 6.0, 4.0, 100.0, 9.0],
+            "x1": [0, 1, 2, 3, 4, None, 5],
+            "x2": [1.0, 0.0, 3.0, 1.0, 5.0, 2.0, 2.0],
+        }
+    )
+
+
+def test_lstsq_coefficients(df: pl.DataFrame) -> None:
+    out = df.select(pl.lstsq("y", ["x1", "x2"], fit_intercept=True))
+    assert out.schema == pl.Schema(
+        {"y": pl.Struct({"x1": pl.Float64, "x2": pl.Float64, "intercept": pl.Float64})}
+    )
+    assert_frame_equal(
+        out.unnest("y"), pl.DataFrame({"x1": [2.0], "x2": [-1.0], "intercept": [1.0]})
+    )
+
+    # Without an intercept the fit goes through the origin.
+    out = df.select(pl.lstsq("y", pl.col("x1", "x2"))).unnest("y")
+    assert out.columns == ["x1", "x2"]
+
+    # A constant regressor and the intercept are collinear.
+    one = (pl.col("x2") * 0 + 1).alias("one")
+    out = df.select(pl.lstsq("y", one, fit_intercept=True))
+    assert out.item() == {"one": None, "intercept": None}
+
+
+def test_lstsq_predictions_residuals(df: pl.DataFrame) -> None:
+    out = df.select(
+        pl.lstsq("y", ["x1", "x2"], fit_intercept=True, mode="predictions").alias(
+            "prediction"
+        ),
+        pl.lstsq("y", ["x1", "x2"], fit_intercept=True, mode="residuals").alias(
+            "residual"
+        ),
+    )
+    expected = df["y"].to_list()
+    expected[5] = None
+    assert_series_equal(
+        out["prediction"], pl.Series("prediction", expected, dtype=pl.Float64)
+    )
+    assert_series_equal(
+        out["residual"],
+        pl.Series("residual", [0.0, 0.0, 0.0, 0.0, 0.0, None, 0.0]),
+        abs_tol=1e-9,
+    )
+
+
+def test_lstsq_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 2],
+            "y": [1.0, 3.0, 5.0, 3.0, 2.0, 1.0],
+            "x": [0, 1, 2, 0, 1, 2],
+        }
+    )
+    out = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.lstsq("y", "x", fit_intercept=True))
+        .unnest("y")
+    )
+    expected = pl.DataFrame({"g": [1, 2], "x": [2.0, -1.0], "intercept": [1.0, 3.0]})
+    assert_frame_equal(out, expected)
+
+    out = df.select(
+        pl.lstsq("y", "x", fit_intercept=True, mode="predictions").over("g")
+    )
+    assert_series_equal(out["y"], df["y"])
+
+
+def test_rolling_ols(df: pl.DataFrame) -> None:
+    out = df.select(
+        pl.rolling_ols("y", ["x1", "x2"], window_size=4, fit_intercept=True)
+    ).unnest("y")
+    for i in range(len(df)):
+        window = df.slice(max(i - 3, 0), min(i + 1, 4))
+        # Every window without a null must have all 4 rows.
+        if window.height < 4 or window["x1"].null_count() > 0:
+            assert out.row(i) == (None, None, None)
+            continue
+        fit = window.select(pl.lstsq("y", ["x1", "x2"], fit_intercept=True))
+        assert out.row(i) == pytest.approx(tuple(fit.item().values()))
+
+    out = df.select(
+        pl.rolling_ols(
+            "y",
+            ["x1", "x2"],
+            window_size=4,
+            min_samples=3,
+            fit_intercept=True,
+            mode="residuals",
+        )
+    )
+    is_null = [True, True, False, False, False, True, False]
+    assert out["y"].is_null().to_list() == is_null
+    assert out["y"].drop_nulls().abs().max() < 1e-9  # type: ignore[operator]
+
+
+def test_lstsq_invalid(df: pl.DataFrame) -> None:
+    with pytest.raises(ValueError, match="`mode` must be one of"):
+        pl.lstsq("y", "x1", mode="weights")  # type: ignore[arg-type]
+    with pytest.raises(InvalidOperationError, match="numeric columns"):
+        df.select(pl.lstsq("y", pl.lit("a")))
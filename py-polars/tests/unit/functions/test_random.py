@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_random_distributions() -> None:
+    df = pl.DataFrame({"x": range(10_000)})
+    out = df.select(
+        pl.random.uniform(2, 3, seed=0),
+        pl.random.normal(10, 2, seed=1),
+        pl.random.bernoulli(0.25, seed=2),
+        pl.random.poisson(4, seed=3),
+    )
+
+    assert out.schema == pl.Schema(
+        {
+            "uniform": pl.Float64,
+            "normal": pl.Float64,
+            "bernoulli": pl.Boolean,
+            "poisson": pl.Int64,
+        }
+    )
+    assert out.height == 10_000
+    assert out["uniform"].min() >= 2  # type: ignore[operator]
+    assert out["uniform"].max() < 3  # type: ignore[operator]
+    assert abs(out["normal"].mean() - 10) < 0.1  # type: ignore[operator]
+    assert abs(out["normal"].std() - 2) < 0.1  # type: ignore[operator]
+    assert abs(out["bernoulli"].mean() - 0.25) < 0.02  # type: ignore[operator]
+    assert abs(out["poisson"].mean() - 4) < 0.1  # type: ignore[operator]
+
+
+def test_random_seed() -> None:
+    df = pl.DataFrame({"x": range(100)})
+    q = df.lazy().select(pl.random.normal(seed=7))
+
+    assert_frame_equal(q.collect(), q.collect())
+    assert not q.collect().equals(df.select(pl.random.normal(seed=8)))
+
+
+def test_random_per_row_parameters() -> None:
+    df = pl.DataFrame(
+        {"low": [0.0, 10.0, None], "high": [1.0, 10.0, 5.0], "p": [0.0, 1.0, 1.0]}
+    )
+    out = df.select(
+        pl.random.uniform("low", "high"),
+        pl.random.bernoulli("p"),
+    )
+
+    assert out["uniform"][0] < 1  # type: ignore[operator]
+    assert out["uniform"][1:].to_list() == [10.0, None]
+    assert_series_equal(out["bernoulli"], pl.Series("bernoulli", [False, True, True]))
+
+
+def test_random_group_by() -> None:
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2]})
+    out = df.group_by("g", maintain_order=True).agg(pl.random.uniform().alias("u"))
+
+    assert out["u"].list.len().to_list() == [3, 2]
+
+
+def test_random_invalid_parameters() -> None:
+    df = pl.DataFrame({"x": [1, 2]})
+    with pytest.raises(ComputeError, match="invalid random parameter `p`"):
+        df.select(pl.random.bernoulli(1.5))
+    with pytest.raises(ComputeError, match="invalid random parameter `std`"):
+        df.select(pl.random.normal(0, -1))
+    with pytest.raises(ComputeError, match="invalid random parameters `low`"):
+        df.select(pl.random.uniform(1, 0))
@@ -9,7 +9,7 @@ import pytest
 
 import polars as pl
 from polars.exceptions import InvalidOperationError, ShapeError
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_when_then() -> None:
@@ -755,3 +755,47 @@ def test_when_then_to_decimal_18375() -> None:
         schema={"a": pl.String, "b": pl.Decimal, "c": pl.Decimal},
     )
     assert_frame_equal(result, expected)
+
+
+def test_when_then_long_numeric_chain() -> None:
+    # Long enough for the chain to be evaluated with a single select kernel, and for
+    # several blocks of 64 rows.
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 2, 2, 2, 1] * 30,
+            "x": [None, 1, 2, 3, 4, 5] * 30,
+            "y": [10.0, None, 30.0, 40.0, 50.0, 60.0] * 30,
+        }
+    )
+    chain = (
+        pl.when(pl.col("x") == 1)
+        .then(pl.col("y"))
+        .when(pl.col("x") == 2)
+        .then(-1.0)
+        .when(pl.col("x") > 3)
+        .then(pl.col("y") * 2)
+        .when(pl.col("x").is_null())
+        .then(None)
+        .otherwise(pl.col("x"))
+    )
+    expected = pl.Series("y", [None, None, -1.0, 3.0, 100.0, 120.0] * 30)
+
+    assert_series_equal(df.select(chain).to_series(), expected)
+    assert_series_equal(df.select(chain.over("g")).to_series(), expected)
+
+    result = df.group_by("g", maintain_order=True).agg(chain).explode("y")
+    expected_g = df.with_columns(expected).sort("g", maintain_order=True)
+    assert_frame_equal(result, expected_g.select("g", "y"))
+
+    # A predicate that is a single value is broadcast.
+    chain = (
+        pl.when(pl.lit(False))
+        .then(0)
+        .when(pl.col("x") < 3)
+        .then(pl.col("x"))
+        .when(pl.lit(None, dtype=pl.Boolean))
+        .then(1)
+        .otherwise(pl.lit(7))
+    )
+    expected = pl.Series("literal", [7, 1, 2, 7, 7, 7] * 30)
+    assert_series_equal(df.select(chain).to_series(), expected)
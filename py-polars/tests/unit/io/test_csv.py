@@ -2385,6 +2385,15 @@ def test_csv_quoted_newlines_skip_rows_19535() -> None:
     )
 
 
+def test_csv_count_rows_quoted_newlines_parallel() -> None:
+    # Enough rows to count in parallel chunks, whose boundaries must not be placed
+    # at the newlines within the quoted fields.
+    n_rows = 10_000
+    csv = b"a,b\n" + b'1,"x\ny\n""z"""\n' * n_rows
+    assert pl.scan_csv(csv).select(pl.len()).item() == n_rows
+    assert pl.read_csv(csv).height == n_rows
+
+
 @pytest.mark.write_disk
 def test_csv_read_time_dtype(tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
@@ -7,6 +7,7 @@ from datetime import date, datetime, time, timezone
 from decimal import Decimal
 from itertools import chain
 from typing import TYPE_CHECKING, Any, Callable, Literal, cast
+from uuid import uuid4
 
 import fsspec
 import numpy as np
@@ -2154,6 +2155,37 @@ def test_conserve_sortedness(
     )
 
 
+@pytest.mark.parametrize("parallel", ["prefiltered", "columns", "row_groups", "auto"])
+def test_sorted_long_runs(parallel: pl.ParallelStrategy) -> None:
+    # Sorted columns with long runs are run-end encoded when they are read.
+    df = pl.DataFrame(
+        {
+            "status": [None] * 10 + [1] * 100 + [2] * 50 + [3] * 40,
+            "f": range(200),
+        }
+    )
+    f = io.BytesIO()
+    pq.write_table(
+        df.to_arrow(),
+        f,
+        sorting_columns=[pq.SortingColumn(0, False, True)],
+    )
+
+    f.seek(0)
+    out = pl.read_parquet(f, parallel=parallel)
+    assert_frame_equal(out, df)
+    assert out["status"].flags["SORTED_ASC"]
+
+    f.seek(0)
+    q = pl.scan_parquet(f, parallel=parallel).filter(pl.col.f % 3 != 0)
+    expected = df.filter(pl.col.f % 3 != 0)
+    assert_frame_equal(q.collect(), expected)
+    assert_frame_equal(
+        q.filter(pl.col.status == 2).collect(),
+        expected.filter(pl.col.status == 2),
+    )
+
+
 @pytest.mark.parametrize("use_dictionary", [True, False])
 @pytest.mark.parametrize(
     "values",
@@ -3131,3 +3163,47 @@ def test_unspecialized_decoding_prefiltering() -> None:
         .collect(engine="streaming")
     )
     assert_frame_equal(result, df.filter(expr))
+
+
+def test_write_parquet_zstd_dictionary(tmp_path: Path) -> None:
+    df = pl.DataFrame({"email": [f"user-{i}@example.org" for i in range(20_000)]})
+
+    plain = io.BytesIO()
+    df.write_parquet(plain, data_page_size=1024)
+    trained = io.BytesIO()
+    df.write_parquet(trained, data_page_size=1024, zstd_dictionary_size=4096)
+    assert len(trained.getvalue()) < len(plain.getvalue())
+
+    trained.seek(0)
+    assert_frame_equal(pl.read_parquet(trained), df)
+
+    path = tmp_path / "trained.parquet"
+    df.lazy().sink_parquet(path, data_page_size=1024, zstd_dictionary_size=4096)
+    assert_frame_equal(pl.read_parquet(path), df)
+
+    with pytest.raises(ValueError, match="zstd_dictionary_size"):
+        df.write_parquet(io.BytesIO(), zstd_dictionary_size=4096, use_pyarrow=True)
+
+
+def test_parquet_uuid_logical_type_roundtrip() -> None:
+    if not hasattr(pa, "uuid"):
+        pytest.skip("requires the pyarrow UUID extension type")
+    values = [uuid4() for _ in range(3)]
+    storage = pa.array([v.bytes for v in values] + [None], type=pa.binary(16))
+    table = pa.table({"id": pa.ExtensionArray.from_storage(pa.uuid(), storage)})
+    f = io.BytesIO()
+    pq.write_table(table, f, store_schema=False)
+
+    # Columns with the UUID logical type are read as `pl.Uuid`.
+    f.seek(0)
+    df = pl.read_parquet(f)
+    assert df.schema == pl.Schema({"id": pl.Uuid})
+    assert df["id"].to_list() == [*values, None]
+
+    # Writing keeps the UUID logical type, also for other readers.
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    assert str(pq.read_metadata(f).schema.column(0).logical_type) == "UUID"
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
@@ -189,6 +189,31 @@ def test_partition_by_key(
     )
 
 
+@pytest.mark.parametrize("io_type", io_types)
+@pytest.mark.write_disk
+def test_partition_report(tmp_path: Path, io_type: IOType) -> None:
+    lf = pl.LazyFrame({"a": [i % 3 for i in range(7)], "b": list(range(7))})
+
+    report = (io_type["sink"])(
+        lf,
+        PartitionByKey(
+            tmp_path, file_path=lambda ctx: f"{ctx.file_idx}.{io_type['ext']}", by="a"
+        ),
+        report_key="b",
+        engine="streaming",
+        sync_on_close="data",
+    )
+
+    assert report.rows == 7
+    files = report.files.sort("path")
+    paths = [tmp_path / f"{i}.{io_type['ext']}" for i in range(3)]
+    assert files["path"].to_list() == [str(p) for p in paths]
+    assert files["rows"].to_list() == [3, 2, 2]
+    assert files["bytes"].to_list() == [p.stat().st_size for p in paths]
+    assert files["key_min"].to_list() == [0, 1, 2]
+    assert files["key_max"].to_list() == [6, 4, 5]
+
+
 @pytest.mark.parametrize("io_type", io_types)
 @pytest.mark.write_disk
 def test_partition_parted(
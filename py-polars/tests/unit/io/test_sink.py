@@ -106,3 +106,31 @@ def test_sink_to_file(tmp_path: Path, sink: Any, scan: Any) -> None:
             scan(f).collect(),
             df,
         )
+
+
+@pytest.mark.parametrize(("scan", "sink"), SINKS)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.write_disk
+def test_sink_report(tmp_path: Path, scan: Any, sink: Any, engine: EngineType) -> None:
+    df = pl.DataFrame({"a": [5, 10, 1996], "b": ["x", "y", "z"]})
+    path = tmp_path / "f"
+
+    report = sink(df.lazy(), path, report_key="a", engine=engine)
+    assert isinstance(report, pl.SinkReport)
+    assert report.rows == 3
+    assert report.bytes == path.stat().st_size
+    assert report.files.to_dicts() == [
+        {
+            "path": str(path),
+            "rows": 3,
+            "bytes": path.stat().st_size,
+            "key_min": 5,
+            "key_max": 1996,
+        }
+    ]
+
+    report = sink(df.lazy(), path, engine=engine)
+    assert report.files.columns == ["path", "rows", "bytes"]
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        sink(df.lazy(), path, report_key="c", engine=engine)
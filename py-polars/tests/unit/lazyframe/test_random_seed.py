@@ -0,0 +1,39 @@
+from __future__ import annotations
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_random_seed_reproducible() -> None:
+    lf = pl.LazyFrame({"a": range(100), "g": [i % 3 for i in range(100)]})
+    q = lf.select(
+        pl.col("a").shuffle(),
+        b=pl.col("a").shuffle(),
+        c=pl.col("a").rank("random"),
+    )
+
+    a = q.with_random_seed(0).collect()
+    assert_frame_equal(a, q.with_random_seed(0).collect())
+    assert not a.equals(q.with_random_seed(1).collect())
+
+    # The expressions get different seeds.
+    assert not a["a"].equals(a["b"])
+
+
+def test_random_seed_keeps_expression_seed() -> None:
+    lf = pl.LazyFrame({"a": range(100)})
+    q = lf.select(pl.col("a").shuffle(seed=7))
+
+    assert_frame_equal(
+        q.with_random_seed(0).collect(),
+        lf.select(pl.col("a").shuffle(seed=7)).collect(),
+    )
+
+
+def test_random_seed_group_by() -> None:
+    lf = pl.LazyFrame({"a": range(1000), "g": [i % 10 for i in range(1000)]})
+    q = lf.group_by("g").agg(pl.col("a").shuffle()).sort("g").with_random_seed(3)
+
+    expected = q.collect()
+    for _ in range(5):
+        assert_frame_equal(q.collect(), expected)
@@ -0,0 +1,65 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError, SchemaError
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def test_schema_contract_strict() -> None:
+    lf = pl.LazyFrame({"b": ["x", "y"], "a": [1, 2]})
+    contract = {"a": pl.Int64, "b": pl.String}
+
+    result = lf.with_schema_contract(contract).collect()
+    assert_frame_equal(result, pl.DataFrame({"a": [1, 2], "b": ["x", "y"]}))
+
+    msg = (
+        r'schema contract violated: column "a" has data type i64, expected i32; '
+        r'missing column "c"; unexpected column "b"'
+    )
+    with pytest.raises(SchemaError, match=msg):
+        lf.with_schema_contract({"a": pl.Int32, "c": pl.Boolean})
+
+
+def test_schema_contract_coerce() -> None:
+    lf = pl.LazyFrame({"a": [1, 300], "b": ["x", "y"], "c": [True, False]})
+
+    result = lf.with_schema_contract([("a", pl.Int16)], policy="coerce").collect()
+    assert_frame_equal(result, pl.DataFrame({"a": [1, 300]}, schema={"a": pl.Int16}))
+
+    # Values that don't fit raise when collected.
+    lf = lf.with_schema_contract({"a": pl.Int8, "b": pl.String}, policy="coerce")
+    with pytest.raises(InvalidOperationError):
+        lf.collect()
+
+    with pytest.raises(SchemaError, match='missing column "d"'):
+        lf.with_schema_contract({"d": pl.Int8}, policy="coerce")
+
+    with pytest.raises(ValueError, match="`policy` must be one of"):
+        lf.with_schema_contract(
+            {"a": pl.Int8},
+            policy="lenient",  # type: ignore[arg-type]
+        )
+
+
+def test_schema_contract_scan_and_sink(tmp_path: Path) -> None:
+    path = tmp_path / "stage.parquet"
+    pl.DataFrame({"id": [1, 2], "score": [0.5, 1.5]}).write_parquet(path)
+    contract = {"id": pl.Int64, "score": pl.Float32}
+
+    result = (
+        pl.scan_parquet(path).with_schema_contract(contract, policy="coerce").collect()
+    )
+    assert result.schema == pl.Schema(contract)
+
+    out = tmp_path / "out.parquet"
+    lf = pl.scan_parquet(path).with_columns(debug=pl.lit(True))
+    with pytest.raises(SchemaError, match='unexpected column "debug"'):
+        lf.with_schema_contract({"id": pl.Int64, "score": pl.Float64}).sink_parquet(out)
+    assert not out.exists()
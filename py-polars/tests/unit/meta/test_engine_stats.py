@@ -0,0 +1,27 @@
+from __future__ import annotations
+
+from datetime import timedelta
+
+import polars as pl
+
+
+def test_engine_stats() -> None:
+    with pl.StringCache():
+        pl.Series(["engine-stats"], dtype=pl.Categorical)
+        stats = pl.engine_stats()
+
+    assert list(stats) == [
+        "thread_pool_size",
+        "thread_pool_cpu_time",
+        "string_cache_enabled",
+        "string_cache_len",
+        "string_cache_bytes",
+        "spill_bytes",
+    ]
+    assert stats["thread_pool_size"] == pl.thread_pool_size()
+    cpu_time = stats["thread_pool_cpu_time"]
+    assert cpu_time is None or isinstance(cpu_time, timedelta)
+    assert stats["string_cache_enabled"]
+    assert stats["string_cache_len"] >= 1
+    assert stats["string_cache_bytes"] >= len("engine-stats")
+    assert stats["spill_bytes"] >= 0
@@ -365,6 +365,50 @@ def test_cum_sum_horizontal() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_cum_sum_horizontal_nulls() -> None:
+    df = pl.DataFrame({"a": [1, None], "b": [True, True], "c": [2.5, 1.0]})
+    result = df.select(pl.cum_sum_horizontal(pl.all()))
+    expected = pl.DataFrame(
+        {
+            "cum_sum": [
+                {"a": 1.0, "b": 2.0, "c": 4.5},
+                {"a": None, "b": None, "c": None},
+            ]
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_rank_horizontal() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [3.0, 1.0, None],
+            "b": [1.0, float("nan"), 2.0],
+            "c": [3.0, 2.0, 1.0],
+        }
+    )
+    result = df.select(pl.rank_horizontal(pl.all(), method="min"))
+    expected = pl.DataFrame(
+        {
+            "rank": [
+                {"a": 2, "b": 1, "c": 2},
+                {"a": 1, "b": 3, "c": 2},
+                {"a": None, "b": 2, "c": 1},
+            ]
+        },
+        schema={"rank": pl.Struct({"a": pl.UInt32, "b": pl.UInt32, "c": pl.UInt32})},
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.select(
+        pl.rank_horizontal("a", "b", "c", method="ordinal", nan_position="first")
+    )
+    assert result["rank"].struct.field("b").to_list() == [1, 1, 2]
+
+    result = df.select(pl.rank_horizontal(pl.all(), method="average", descending=True))
+    assert result["rank"].struct.field("a").to_list() == [1.5, 3.0, None]
+
+
 def test_sum_dtype_12028() -> None:
     result = pl.select(
         pl.sum_horizontal([pl.duration(seconds=10)]).alias("sum_duration")
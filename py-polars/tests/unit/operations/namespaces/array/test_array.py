@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import datetime
+import math
 from typing import Any
 
 import pytest
@@ -549,3 +550,82 @@ def test_array_shift_self_broadcast_22124() -> None:
             pl.Array(pl.String, 3),
         ),
     )
+
+
+def test_array_slice() -> None:
+    s = pl.Series("a", [[1, 2, 3], None, [4, 5, 6]], pl.Array(pl.Int64, 3))
+
+    assert_series_equal(
+        s.arr.slice(1),
+        pl.Series("a", [[2, 3], None, [5, 6]], pl.Array(pl.Int64, 2)),
+    )
+    assert_series_equal(
+        s.arr.slice(-2, 1),
+        pl.Series("a", [[2], None, [5]], pl.Array(pl.Int64, 1)),
+    )
+    assert_series_equal(s.arr.head(5), s)
+    assert_series_equal(
+        s.arr.tail(1),
+        pl.Series("a", [[3], None, [6]], pl.Array(pl.Int64, 1)),
+    )
+    assert s.arr.slice(5).dtype == pl.Array(pl.Int64, 0)
+
+    lf = s.to_frame().lazy().select(pl.col("a").arr.head(2))
+    assert lf.collect_schema() == {"a": pl.Array(pl.Int64, 2)}
+
+
+def test_array_gather_eval() -> None:
+    s = pl.Series("a", [[1, 2, 3], [4, 5, 6]], pl.Array(pl.Int64, 3))
+
+    assert_series_equal(
+        s.arr.gather([2, 0]),
+        pl.Series("a", [[3, 1], [6, 4]], pl.List(pl.Int64)),
+    )
+    assert_series_equal(
+        s.arr.gather([3], null_on_oob=True),
+        pl.Series("a", [[None], [None]], pl.List(pl.Int64)),
+    )
+    assert_series_equal(
+        s.arr.eval(pl.element() * 2),
+        pl.Series("a", [[2, 4, 6], [8, 10, 12]], pl.List(pl.Int64)),
+    )
+
+
+def test_arr_dot_cosine_similarity_l2_norm() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 3], [0, 0, 0], None, [1, None, 1]],
+            "b": [[4.0, 5.0, 6.0], [1.0, 1.0, 1.0], [1.0, 1.0, 1.0], [1.0, 1.0, 1.0]],
+        },
+        schema={"a": pl.Array(pl.Int64, 3), "b": pl.Array(pl.Float64, 3)},
+    )
+    out = df.select(
+        dot=pl.col("a").arr.dot(pl.col("b")),
+        cos=pl.col("a").arr.cosine_similarity("b"),
+        norm=pl.col("a").arr.l2_norm(),
+        query=pl.col("b").arr.dot([1, 0, -1]),
+    )
+    assert out.schema == pl.Schema(
+        {"dot": pl.Float64, "cos": pl.Float64, "norm": pl.Float64, "query": pl.Float64}
+    )
+    assert out["dot"].to_list() == [32.0, 0.0, None, None]
+    assert out["cos"][0] == pytest.approx(32 / (14**0.5 * 77**0.5))
+    assert math.isnan(out["cos"][1])
+    assert out["cos"][2:].to_list() == [None, None]
+    assert out["norm"].to_list() == [pytest.approx(14**0.5), 0.0, None, None]
+    assert out["query"].to_list() == [-2.0, 0.0, 0.0, 0.0]
+
+    # long vectors use the vectorized accumulation
+    s = pl.Series([list(range(37))], dtype=pl.Array(pl.Float32, 37))
+    norm = s.arr.l2_norm()
+    assert norm.dtype == pl.Float32
+    assert norm[0] == pytest.approx(sum(x * x for x in range(37)) ** 0.5)
+    assert s.arr.dot(s).dtype == pl.Float32
+
+
+def test_arr_dot_invalid() -> None:
+    s = pl.Series([[1.0, 2.0]], dtype=pl.Array(pl.Float64, 2))
+    with pytest.raises(pl.exceptions.ShapeMismatchError, match="same width"):
+        s.arr.dot([1.0, 2.0, 3.0])
+    with pytest.raises(InvalidOperationError, match="requires numeric arrays"):
+        pl.Series([["a", "b"]], dtype=pl.Array(pl.String, 2)).arr.l2_norm()
@@ -130,3 +130,34 @@ def test_list_eval_list_output_18510(data: dict[str, Any], expr: pl.Expr) -> Non
     df = pl.DataFrame(data)
     result = df.select(pl.col("a").list.eval(pl.lit("")))
     assert result.to_series().dtype == pl.List(pl.String)
+
+
+@pytest.mark.parametrize("parallel", [False, True])
+def test_list_eval_parent_columns(parallel: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "items": [[1.0, 2.0], [3.0], None, []],
+            "fx_rate": [0.5, 2.0, 3.0, 4.0],
+            "offset": [1, 2, 3, 4],
+        }
+    )
+    out = df.select(
+        pl.col("items").list.eval(
+            pl.element() * pl.col("fx_rate") + pl.col("offset"), parallel=parallel
+        )
+    )
+    expected = pl.DataFrame({"items": [[1.5, 2.0], [8.0], None, []]})
+    assert_frame_equal(out, expected)
+
+    # without nulls the group-by engine is used
+    out = df.drop_nulls().select(
+        pl.col("items").list.eval(
+            pl.element().filter(pl.element() > pl.col("fx_rate")), parallel=parallel
+        )
+    )
+    expected = pl.DataFrame({"items": [[1.0, 2.0], [3.0], []]})
+    assert_frame_equal(out, expected)
+
+    out = df.lazy().select(pl.col("items").list.eval(pl.col("offset").cast(pl.String)))
+    assert out.collect_schema() == {"items": pl.List(pl.String)}
+    assert out.collect()["items"].to_list() == [["1", "1"], ["2"], None, []]
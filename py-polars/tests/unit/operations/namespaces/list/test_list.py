@@ -932,6 +932,40 @@ def test_list_n_unique() -> None:
     assert_frame_equal(out, expected)
 
 
+
+def test_list_value_counts() -> None:
+    df = pl.DataFrame({"a": [[1, 1, 2], [3, 3], [None], None, []]})
+
+    out = df.select(pl.col("a").list.value_counts(sort=True))
+    counts = pl.Struct({"a": pl.Int64, "count": pl.UInt32})
+    expected = pl.DataFrame(
+        {
+            "a": [
+                [{"a": 1, "count": 2}, {"a": 2, "count": 1}],
+                [{"a": 3, "count": 2}],
+                [{"a": None, "count": 1}],
+                None,
+                [],
+            ]
+        },
+        schema={"a": pl.List(counts)},
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("a").list.value_counts(normalize=True, sort=True))
+    assert out.schema == {
+        "a": pl.List(pl.Struct({"a": pl.Int64, "proportion": pl.Float64}))
+    }
+    assert out["a"][0].to_list() == [
+        {"a": 1, "proportion": 2 / 3},
+        {"a": 2, "proportion": 1 / 3},
+    ]
+
+    # without any valid list the output still has the struct type
+    out = pl.Series("a", [None], dtype=pl.List(pl.String)).list.value_counts()
+    assert out.dtype == pl.List(pl.Struct({"a": pl.String, "count": pl.UInt32}))
+    assert out.to_list() == [None]
+
 def test_list_get_with_null() -> None:
     df = pl.DataFrame({"a": [None, [1, 2]], "b": [False, True]})
 
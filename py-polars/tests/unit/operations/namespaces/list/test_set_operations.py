@@ -231,3 +231,33 @@ def test_broadcast_sliced() -> None:
     expected = pl.DataFrame({"a": [[2], [3, 4]]})
 
     assert_frame_equal(out, expected)
+
+
+def test_list_set_operations_sorted() -> None:
+    # sorted lists without nulls take the merge-based path
+    df = pl.DataFrame(
+        {
+            "a": [[1.0, 2, 2, 5], [1.5, 3], []],
+            "b": [[2.0, 3, 5, 5], [1.0], [1]],
+        }
+    )
+    a, b = pl.col("a"), pl.col("b")
+    out = df.select(
+        union=a.list.set_union(b),
+        intersection=a.list.set_intersection(b),
+        difference=a.list.set_difference(b),
+        symmetric_difference=a.list.set_symmetric_difference(b),
+    )
+    assert out.to_dict(as_series=False) == {
+        "union": [[1.0, 2.0, 5.0, 3.0], [1.5, 3.0, 1.0], [1.0]],
+        "intersection": [[2.0, 5.0], [], []],
+        "difference": [[1.0], [1.5, 3.0], []],
+        "symmetric_difference": [[1.0, 3.0], [1.5, 3.0, 1.0], [1.0]],
+    }
+
+    # the merge-based path gives the same values as the hash-based one
+    shuffled = df.select(pl.all().list.reverse())
+    for op in ["set_union", "set_intersection", "set_symmetric_difference"]:
+        expected = df.select(getattr(a.list, op)(b).list.sort())
+        result = shuffled.select(getattr(a.list, op)(b).list.sort())
+        assert_frame_equal(result, expected)
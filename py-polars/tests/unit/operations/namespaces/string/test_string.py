@@ -1,6 +1,7 @@
 from __future__ import annotations
 
-from typing import Any
+from datetime import timedelta
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
@@ -15,6 +16,9 @@ from polars.exceptions import (
 )
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import TimeUnit
+
 
 def test_str_slice() -> None:
     df = pl.DataFrame({"a": ["foobar", "barfoo"]})
@@ -437,6 +441,14 @@ def test_str_case_cyrillic() -> None:
     assert s.str.to_uppercase().to_list() == [a.upper() for a in vals]
 
 
+def test_str_to_casefold() -> None:
+    vals = ["Straße", "STRASSE", "ΣΊΣΥΦΟΣ", "ﬁle", "İstanbul", "ıi", "µ", "Ǆ", None]
+    s = pl.Series(vals)
+    assert s.str.to_casefold().to_list() == [
+        None if a is None else a.casefold() for a in vals
+    ]
+
+
 def test_str_to_integer() -> None:
     bin = pl.Series(["110", "101", "010"])
     assert_series_equal(bin.str.to_integer(base=2), pl.Series([6, 5, 2]).cast(pl.Int64))
@@ -1163,6 +1175,40 @@ def test_replace_expressions() -> None:
     }
 
 
+def test_replace_expression_patterns_and_values() -> None:
+    df = pl.DataFrame(
+        {
+            "text": ["call 555-1234", "id 42", None],
+            "token": ["[PHONE]", "[ID]", "[X]"],
+            "pat": [r"\d{3}-\d{4}", r"\d+", r"\d"],
+        }
+    )
+    out = df.select(
+        column=pl.col("text").str.replace_all(r"\d+", pl.col("token")),
+        groups=pl.col("text").str.replace_all(
+            r"(\d+)", pl.concat_str(pl.col("token"), pl.lit("$1"))
+        ),
+        patterns=pl.col("text").str.replace_all(pl.col("pat"), pl.col("token")),
+        first=pl.col("text").str.replace(pl.col("pat"), "#"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "column": ["call [PHONE]-[PHONE]", "id [ID]", None],
+        "groups": ["call [PHONE]555-[PHONE]1234", "id [ID]42", None],
+        "patterns": ["call [PHONE]", "id [ID]", None],
+        "first": ["call #", "id #", None],
+    }
+
+    df = pl.DataFrame({"s": ["a.b.c", "x+y+z"], "p": [".", "+"]})
+    out = df.select(
+        all=pl.col("s").str.replace_all(pl.col("p"), "_", literal=True),
+        n=pl.col("s").str.replace(pl.col("p"), "_", literal=True, n=1),
+    )
+    assert out.to_dict(as_series=False) == {
+        "all": ["a_b_c", "x_y_z"],
+        "n": ["a_b.c", "x_y+z"],
+    }
+
+
 @pytest.mark.parametrize(
     ("pattern", "replacement", "case_insensitive", "expected"),
     [
@@ -1430,6 +1476,58 @@ def test_extract_groups() -> None:
     ).to_dict(as_series=False) == {"literal": [{"foo": "foo", "bar": None}]}
 
 
+def test_extract_all_groups() -> None:
+    s = pl.Series("s", ["a=1, b=2, 3", "c=", "", None])
+    out = s.str.extract_all_groups(r"(?<key>[a-z])?=?(\d)")
+    assert out.dtype == pl.List(pl.Struct({"key": pl.String, "2": pl.String}))
+    assert out.to_list() == [
+        [{"key": "a", "2": "1"}, {"key": "b", "2": "2"}, {"key": None, "2": "3"}],
+        [],
+        [],
+        None,
+    ]
+
+    # Empty matches are found once per position.
+    assert pl.Series(["ab"]).str.extract_all_groups(r"(x*)").to_list() == [
+        [{"1": ""}, {"1": ""}, {"1": ""}]
+    ]
+
+    with pytest.raises(InvalidOperationError, match="at least one capture group"):
+        s.str.extract_all_groups(r"\d")
+
+
+def test_regex_flags() -> None:
+    s = pl.Series(["Foo\nbar", "FOO", None])
+    assert s.str.contains("foo", ignore_case=True).to_list() == [True, True, None]
+    assert s.str.contains("^bar", multiline=True).to_list() == [True, False, None]
+    assert s.str.contains("o.b", dot_all=True).to_list() == [True, False, None]
+    assert s.str.count_matches("o", ignore_case=True).to_list() == [2, 2, None]
+    assert s.str.extract(r"(o+)", ignore_case=True).to_list() == ["oo", "OO", None]
+    assert s.str.extract_all(r"^\w", multiline=True).to_list() == [
+        ["F", "b"],
+        ["F"],
+        None,
+    ]
+    assert s.str.extract_groups(r"(?<x>F.+)", dot_all=True).to_list() == [
+        {"x": "Foo\nbar"},
+        {"x": "FOO"},
+        None,
+    ]
+    assert s.str.extract_all_groups(r"(o)", ignore_case=True).to_list() == [
+        [{"1": "o"}, {"1": "o"}],
+        [{"1": "O"}, {"1": "O"}],
+        None,
+    ]
+
+    df = pl.DataFrame({"s": ["Foo", "bar"], "pat": ["foo", "BAR"]})
+    assert df.select(
+        pl.col("s").str.contains(pl.col("pat"), ignore_case=True)
+    ).to_series().to_list() == [True, True]
+
+    with pytest.raises(ValueError, match="literal"):
+        s.str.contains("foo", literal=True, ignore_case=True)
+
+
 def test_starts_ends_with() -> None:
     df = pl.DataFrame(
         {
@@ -1834,6 +1932,44 @@ def test_string_reverse() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_string_similarity() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["kitten", "naïve", "karolin", "new york mets", None, ""],
+            "b": ["sitting", "naive", "kathrin", "mets new york", "x", ""],
+        }
+    )
+    a, b = pl.col("a").str, pl.col("b")
+    result = df.select(
+        lev=a.levenshtein(b),
+        lev_max=a.levenshtein(b, max_distance=2),
+        ham=a.hamming(b),
+        ham_max=a.hamming(b, max_distance=2),
+        jw=a.jaro_winkler(b).round(3),
+        tsr=a.token_set_ratio(b).round(3),
+    )
+    expected = pl.DataFrame(
+        {
+            "lev": [3, 1, 3, 10, None, 0],
+            "lev_max": [None, 1, None, None, None, 0],
+            "ham": [None, 1, 3, 11, None, 0],
+            "ham_max": [None, 1, None, None, None, 0],
+            "jw": [0.746, 0.893, 0.848, 0.679, None, 1.0],
+            "tsr": [0.615, 0.8, 0.714, 1.0, None, 0.0],
+        },
+        schema_overrides={
+            "lev": pl.UInt32,
+            "lev_max": pl.UInt32,
+            "ham": pl.UInt32,
+            "ham_max": pl.UInt32,
+        },
+    )
+    assert_frame_equal(result, expected)
+
+    # A literal is broadcast to every row.
+    assert df.select(a.levenshtein("kitten")).to_series().to_list()[0] == 0
+
+
 @pytest.mark.parametrize(
     ("data", "expected_data"),
     [
@@ -1964,6 +2100,56 @@ def test_string_normalize_wrong_input() -> None:
         pl.Series(["01²"], dtype=pl.String).str.normalize("foobar")  # type: ignore[arg-type]
 
 
+def test_string_strip_diacritics() -> None:
+    s = pl.Series(["Crème Brûlée", "naïve", "Ångström", "Øresund", "ǅ", "", None])
+    assert s.str.strip_diacritics().to_list() == [
+        "Creme Brulee",
+        "naive",
+        "Angstrom",
+        "Øresund",
+        "ǅ",
+        "",
+        None,
+    ]
+
+
+def test_str_url() -> None:
+    urls = [
+        "HTTPS://user@Pola.rs:443/docs%20v1?q=a%20b&flag#top",
+        "mailto:info@pola.rs",
+        "/search?q=polars+url&q=other",
+        "pola.rs/docs",
+        None,
+    ]
+    df = pl.DataFrame({"url": urls})
+    out = df.select(
+        scheme=pl.col("url").str.url.scheme(),
+        host=pl.col("url").str.url.host(),
+        path=pl.col("url").str.url.path(),
+        q=pl.col("url").str.url.query_param("q"),
+        flag=pl.col("url").str.url.query_param("flag"),
+    )
+    expected = pl.DataFrame(
+        {
+            "scheme": ["https", "mailto", None, None, None],
+            "host": ["pola.rs", None, None, None, None],
+            "path": ["/docs%20v1", "info@pola.rs", "/search", "pola.rs/docs", None],
+            "q": ["a b", None, "polars url", None, None],
+            "flag": ["", None, None, None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    s = pl.Series(["/caf%C3%A9/a+b", "100%", "%zz", "%FF", None])
+    assert s.str.url.decode().to_list() == ["/café/a+b", "100%", "%zz", None, None]
+    assert pl.Series(urls).str.url.host().to_list() == expected["host"].to_list()
+
+
+def test_str_url_query_param_key_type() -> None:
+    with pytest.raises(TypeError, match="`key` must be a string"):
+        pl.col("url").str.url.query_param(pl.lit("q"))  # type: ignore[arg-type]
+
+
 def test_to_integer_unequal_lengths_22034() -> None:
     s = pl.Series("a", ["1", "2", "3"], pl.String)
     with pytest.raises(pl.exceptions.ShapeError):
@@ -1999,3 +2185,51 @@ def test_str_split_self_broadcast() -> None:
         pl.Series(["a-/c"]).str.split(pl.Series(["-", "/", "+"])),
         pl.Series([["a", "/c"], ["a-", "c"], ["a-/c"]]),
     )
+
+
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+@pytest.mark.parametrize("format", ["iso", "polars"])
+def test_str_to_duration_roundtrip(time_unit: TimeUnit, format: str) -> None:
+    s = pl.Series(
+        "td",
+        [
+            timedelta(days=-1, seconds=-42),
+            timedelta(days=14, hours=-10, milliseconds=100),
+            timedelta(0),
+            timedelta(weeks=3, minutes=1, seconds=1),
+            None,
+        ],
+        dtype=pl.Duration(time_unit),
+    )
+    assert_series_equal(s.dt.to_string(format).str.to_duration(time_unit), s)
+
+
+def test_str_to_duration() -> None:
+    s = pl.Series(["P1W", "PT1.123456789S", "+P1D", " 1d  2h ", "90s", None])
+    assert s.str.to_duration().to_list() == [
+        timedelta(weeks=1),
+        timedelta(seconds=1, microseconds=123456),
+        timedelta(days=1),
+        timedelta(days=1, hours=2),
+        timedelta(seconds=90),
+        None,
+    ]
+    assert s.str.to_duration("ms").dtype == pl.Duration("ms")
+
+
+def test_str_to_duration_invalid() -> None:
+    s = pl.Series(["P1Y", "P1M", "PT", "1mo", "1d 2", "P1H", "1d", "x"])
+    assert s.str.to_duration(strict=False).to_list() == [
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        timedelta(days=1),
+        None,
+    ]
+    with pytest.raises(
+        InvalidOperationError, match=r"conversion from `str` to `duration\[μs\]` failed"
+    ):
+        s.str.to_duration()
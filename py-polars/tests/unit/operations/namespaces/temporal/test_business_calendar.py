@@ -0,0 +1,84 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_business_calendar() -> None:
+    calendar = pl.BusinessCalendar(
+        week_mask=(True, True, True, True, False, False, False),
+        holidays=[date(2024, 1, 3), date(2024, 1, 1), date(2024, 1, 3)],
+    )
+    assert calendar.week_mask == (True, True, True, True, False, False, False)
+    assert calendar.holidays == (date(2024, 1, 1), date(2024, 1, 3))
+    assert calendar == pl.BusinessCalendar(
+        (True, True, True, True, False, False, False),
+        (date(2024, 1, 1), date(2024, 1, 3)),
+    )
+    assert calendar != pl.BusinessCalendar()
+
+    with pytest.raises(ValueError, match="must have 7 elements"):
+        pl.BusinessCalendar(week_mask=(True, True))
+    with pytest.raises(ValueError, match="at least one business day"):
+        pl.BusinessCalendar(week_mask=(False,) * 7)
+
+
+def test_business_calendar_from_series() -> None:
+    holidays = pl.Series(
+        [datetime(2024, 12, 26, 12), None, datetime(2024, 12, 25), None],
+    )
+    calendar = pl.BusinessCalendar.from_series(holidays)
+    assert calendar.holidays == (date(2024, 12, 25), date(2024, 12, 26))
+    assert calendar.week_mask == (True, True, True, True, True, False, False)
+
+    with pytest.raises(TypeError, match="expected a Series of Date or Datetime"):
+        pl.BusinessCalendar.from_series(pl.Series([1, 2]))
+
+
+def test_business_calendar_in_dt_namespace() -> None:
+    calendar = pl.BusinessCalendar.from_series(
+        pl.Series([date(2024, 12, 25), date(2024, 12, 26)]),
+    )
+    df = pl.DataFrame(
+        {
+            "start": [date(2024, 12, 23), date(2024, 12, 25)],
+            "end": [date(2024, 12, 30), date(2024, 12, 31)],
+        }
+    )
+    result = df.select(
+        pl.col("start").dt.is_business_day(calendar=calendar).alias("is_business_day"),
+        pl.col("start")
+        .dt.add_business_days(2, calendar=calendar, roll="forward")
+        .alias("plus_2"),
+        pl.col("start").dt.business_day_count("end", calendar=calendar).alias("count"),
+        pl.business_day_count("start", "end", calendar=calendar).alias("count_fn"),
+    )
+    expected = pl.DataFrame(
+        {
+            "is_business_day": [True, False],
+            "plus_2": [date(2024, 12, 27), date(2024, 12, 31)],
+            "count": pl.Series([3, 2], dtype=pl.Int32),
+            "count_fn": pl.Series([3, 2], dtype=pl.Int32),
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    # Passing the calendar is the same as passing its week mask and holidays.
+    assert_series_equal(
+        df["start"].dt.business_day_count(df["end"], calendar=calendar),
+        df["start"].dt.business_day_count(
+            df["end"], holidays=[date(2024, 12, 25), date(2024, 12, 26)]
+        ),
+    )
+
+
+def test_business_calendar_with_week_mask_raises() -> None:
+    calendar = pl.BusinessCalendar()
+    with pytest.raises(ValueError, match="together with `calendar`"):
+        pl.col("a").dt.is_business_day(calendar=calendar, holidays=[date(2024, 1, 1)])
+    with pytest.raises(ValueError, match="together with `calendar`"):
+        pl.col("a").dt.add_business_days(1, calendar=calendar, week_mask=(True,) * 7)
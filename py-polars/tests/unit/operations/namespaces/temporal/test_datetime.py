@@ -1044,6 +1044,29 @@ def test_weekday(time_unit: TimeUnit) -> None:
     assert friday.cast(pl.Date).dt.weekday()[0] == 5
 
 
+def test_iso_week_date() -> None:
+    # ISO years start on the Monday of the week containing January 4th.
+    s = pl.Series(
+        [date(2020, 12, 31), date(2021, 1, 3), date(2021, 1, 4), date(2024, 12, 30)]
+    )
+    df = pl.DataFrame({"d": s}).select(
+        pl.col("d").dt.iso_year().alias("year"),
+        pl.col("d").dt.iso_week().alias("week"),
+        pl.col("d").dt.iso_weekday().alias("weekday"),
+    )
+    expected = pl.DataFrame(
+        {
+            "year": [2020, 2020, 2021, 2025],
+            "week": [53, 53, 1, 1],
+            "weekday": [4, 7, 1, 1],
+        },
+        schema={"year": pl.Int32, "week": pl.Int8, "weekday": pl.Int8},
+    )
+    assert_frame_equal(df, expected)
+    assert_series_equal(s.dt.iso_week(), s.dt.week())
+    assert_series_equal(s.dt.iso_weekday(), s.dt.weekday())
+
+
 @pytest.mark.parametrize(
     ("values", "expected_median"),
     [
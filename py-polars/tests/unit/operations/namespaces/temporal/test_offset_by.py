@@ -171,3 +171,65 @@ def test_offset_by_unequal_length_22018() -> None:
         pl.Series([datetime(2088, 8, 8, 8, 8, 8, 8)] * 2).dt.offset_by(
             pl.Series([f"{h}y" for h in range(3)])
         )
+
+
+def test_offset_by_ambiguous_policy() -> None:
+    s = pl.Series([datetime(2022, 11, 5, 1, 30)]).dt.replace_time_zone(
+        "America/Chicago"
+    )
+    with pytest.raises(pl.exceptions.ComputeError, match="ambiguous"):
+        s.dt.offset_by("1d")
+
+    result = s.dt.offset_by("1d", ambiguous="earliest")
+    expected = pl.Series([datetime(2022, 11, 6, 1, 30)]).dt.replace_time_zone(
+        "America/Chicago", ambiguous="earliest"
+    )
+    assert_series_equal(result, expected)
+
+    result = s.dt.offset_by("1d", ambiguous="latest")
+    expected = pl.Series([datetime(2022, 11, 6, 1, 30)]).dt.replace_time_zone(
+        "America/Chicago", ambiguous="latest"
+    )
+    assert_series_equal(result, expected)
+
+    # The fixed part of the offset is added in absolute time.
+    result = s.dt.offset_by("1d1h", ambiguous="earliest")
+    assert_series_equal(result, expected)
+
+    assert s.dt.offset_by("1d", ambiguous="null").to_list() == [None]
+
+
+def test_offset_by_non_existent_policy() -> None:
+    s = pl.Series([datetime(2022, 3, 12, 2, 30), datetime(2022, 3, 12, 4)])
+    s = s.dt.replace_time_zone("America/Chicago")
+    with pytest.raises(pl.exceptions.ComputeError, match="non-existent"):
+        s.dt.offset_by("1d")
+
+    result = s.dt.offset_by("1d", non_existent="null")
+    expected = pl.Series([None, datetime(2022, 3, 13, 4)]).dt.replace_time_zone(
+        "America/Chicago"
+    )
+    assert_series_equal(result, expected)
+
+
+def test_offset_by_policy_expr() -> None:
+    df = pl.DataFrame(
+        {
+            "ts": [datetime(2022, 11, 5, 1, 30)] * 2,
+            "offset": ["1d", "1mo"],
+            "ambiguous": ["latest", "raise"],
+        }
+    ).with_columns(pl.col("ts").dt.replace_time_zone("America/Chicago"))
+    result = df.select(
+        pl.col("ts").dt.offset_by(pl.col("offset"), ambiguous=pl.col("ambiguous"))
+    ).to_series()
+    expected = pl.Series(
+        "ts", [datetime(2022, 11, 6, 1, 30), datetime(2022, 12, 5, 1, 30)]
+    ).dt.replace_time_zone("America/Chicago", ambiguous="latest")
+    assert_series_equal(result, expected)
+
+
+def test_offset_by_policy_without_time_zone() -> None:
+    s = pl.Series([date(2022, 11, 5), date(2022, 3, 12)])
+    result = s.dt.offset_by("1d", ambiguous="null", non_existent="null")
+    assert_series_equal(result, s.dt.offset_by("1d"))
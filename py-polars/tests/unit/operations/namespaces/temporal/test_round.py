@@ -279,3 +279,33 @@ def test_round_big() -> None:
     assert abs(big_s.round_sig_figs(4).item() - 1.234e308) <= max_err
     assert abs(big_s.round_sig_figs(4).item() - big) <= max_err
     assert big_s.round_sig_figs(100).item() == big
+
+
+def test_round_ambiguous_policy() -> None:
+    s = pl.Series([datetime(2022, 11, 6, 1, 20)]).dt.replace_time_zone(
+        "America/Chicago", ambiguous="latest"
+    )
+    result = s.dt.round("1h")
+    expected = pl.Series([datetime(2022, 11, 6, 1)]).dt.replace_time_zone(
+        "America/Chicago", ambiguous="latest"
+    )
+    assert_series_equal(result, expected)
+
+    result = s.dt.round("1h", ambiguous="earliest")
+    expected = pl.Series([datetime(2022, 11, 6, 1)]).dt.replace_time_zone(
+        "America/Chicago", ambiguous="earliest"
+    )
+    assert_series_equal(result, expected)
+
+    result = s.dt.round("1h", ambiguous="null")
+    assert result.to_list() == [None]
+
+
+def test_round_non_existent_policy() -> None:
+    # Clocks in Sao Paulo jumped from midnight to 1am on 2018-11-04.
+    s = pl.Series([datetime(2018, 11, 3, 20)]).dt.replace_time_zone(
+        "America/Sao_Paulo"
+    )
+    result = s.dt.round("1d", non_existent="null")
+    assert result.dtype == pl.Datetime("us", "America/Sao_Paulo")
+    assert result.to_list() == [None]
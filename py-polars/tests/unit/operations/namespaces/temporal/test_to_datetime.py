@@ -195,3 +195,98 @@ def test_to_datetime_two_digit_year_17213(
 ) -> None:
     result = pl.Series([inputs]).str.to_date(format=format).item()
     assert result == expected
+
+
+def test_to_datetime_formats() -> None:
+    s = pl.Series("ts", ["2024-03-01 10:00", "01/03/2024 11:30", "2024-03-02", None])
+    result = s.str.to_datetime(["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M", "%Y-%m-%d"])
+    expected = pl.Series(
+        "ts",
+        [
+            datetime(2024, 3, 1, 10),
+            datetime(2024, 3, 1, 11, 30),
+            datetime(2024, 3, 2),
+            None,
+        ],
+    )
+    assert_series_equal(result, expected)
+
+    # The first matching format wins.
+    result = pl.Series(["01/02/2024"]).str.to_datetime(["%d/%m/%Y", "%m/%d/%Y"])
+    assert result.item() == datetime(2024, 2, 1)
+    result = pl.Series(["01/02/2024"]).str.to_datetime(["%m/%d/%Y", "%d/%m/%Y"])
+    assert result.item() == datetime(2024, 1, 2)
+
+
+def test_to_datetime_formats_time_unit_and_zone() -> None:
+    s = pl.Series(["2024-03-01 10:00:00.123456789", "2024-03-01 10:00:00.123"])
+    result = s.str.to_datetime(["%Y-%m-%d %H:%M:%S%.9f", "%Y-%m-%d %H:%M:%S%.3f"])
+    assert result.dtype == pl.Datetime("ns")
+
+    s = pl.Series(["2024-03-01 10:00+01:00", "2024-03-01T10:00+0200"])
+    result = s.str.to_datetime(["%Y-%m-%d %H:%M%#z", "%Y-%m-%dT%H:%M%z"])
+    assert result.dtype == pl.Datetime("us", "UTC")
+    assert result.to_list() == [
+        datetime(2024, 3, 1, 9, tzinfo=ZoneInfo("UTC")),
+        datetime(2024, 3, 1, 8, tzinfo=ZoneInfo("UTC")),
+    ]
+
+    result = pl.Series(["2024-03-01 10:00", "01/03/2024"]).str.to_datetime(
+        ["%Y-%m-%d %H:%M", "%d/%m/%Y"], time_zone="Europe/Amsterdam"
+    )
+    assert result.dtype == pl.Datetime("us", "Europe/Amsterdam")
+    assert result.dt.replace_time_zone(None).to_list() == [
+        datetime(2024, 3, 1, 10),
+        datetime(2024, 3, 1),
+    ]
+
+
+def test_to_datetime_formats_invalid() -> None:
+    s = pl.Series(["2024-03-01", "not a date"])
+    with pytest.raises(InvalidOperationError, match="conversion from `str`"):
+        s.str.to_datetime(["%Y-%m-%d", "%d/%m/%Y"])
+    assert s.str.to_datetime(["%Y-%m-%d", "%d/%m/%Y"], strict=False).to_list() == [
+        datetime(2024, 3, 1),
+        None,
+    ]
+    with pytest.raises(ComputeError, match="cannot mix offset-aware"):
+        s.str.to_datetime(["%Y-%m-%d", "%Y-%m-%d%z"])
+    with pytest.raises(InvalidOperationError, match="at least one format"):
+        s.str.to_datetime([])
+    with pytest.raises(ValueError, match="only supported for a single format"):
+        s.str.to_datetime(["%Y-%m-%d"], exact=False)
+
+
+def test_to_datetime_format_column() -> None:
+    df = pl.DataFrame(
+        {
+            "value": ["2024-03-01", "03/01/2024", "01.03.2024", "2024-03-01", "x"],
+            "format": ["%Y-%m-%d", "%m/%d/%Y", "%d.%m.%Y", None, None],
+        }
+    )
+    result = df.select(pl.col("value").str.to_datetime(pl.col("format")))
+    expected = pl.Series("value", [datetime(2024, 3, 1)] * 3 + [None, None])
+    assert_series_equal(result.to_series(), expected)
+
+    result = df.select(
+        pl.lit("2024-03-01").str.to_datetime(pl.col("format"), strict=False)
+    )
+    expected = pl.Series("literal", [datetime(2024, 3, 1)] + [None] * 4)
+    assert_series_equal(result.to_series(), expected)
+
+    # The Series namespace takes a Series of formats.
+    result_s = df["value"].head(3).str.to_datetime(df["format"].head(3))
+    assert result_s.to_list() == [datetime(2024, 3, 1)] * 3
+
+
+def test_to_datetime_format_column_invalid() -> None:
+    df = pl.DataFrame({"value": ["2024-03-01"] * 2, "format": ["%d/%m/%Y"] * 2})
+    with pytest.raises(InvalidOperationError, match="conversion from `str`"):
+        df.select(pl.col("value").str.to_datetime(pl.col("format")))
+    assert df.select(
+        pl.col("value").str.to_datetime(pl.col("format"), strict=False)
+    ).to_series().to_list() == [None, None]
+
+    df = pl.DataFrame({"value": ["2024-03-01+01:00"], "format": ["%Y-%m-%d%#z"]})
+    with pytest.raises(ComputeError, match="offset-aware formats are not supported"):
+        df.select(pl.col("value").str.to_datetime(pl.col("format")))
@@ -12,7 +12,7 @@ from polars._utils.convert import parse_as_duration_string
 from polars.testing import assert_series_equal
 
 if TYPE_CHECKING:
-    from polars._typing import TimeUnit
+    from polars._typing import TimeUnit, Weekday
 
 
 @given(
@@ -128,3 +128,112 @@ def test_truncate_unequal_length_22018(as_date: bool) -> None:
         s = s.dt.date()
     with pytest.raises(pl.exceptions.ShapeError):
         s.dt.truncate(pl.Series(["1y"] * 3))
+
+
+def test_truncate_ambiguous_policy() -> None:
+    s = pl.Series([datetime(2022, 11, 6, 1, 30)]).dt.replace_time_zone(
+        "America/Chicago", ambiguous="latest"
+    )
+    # By default, the DST offset of the original datetime is kept.
+    result = s.dt.truncate("1h")
+    expected = pl.Series([datetime(2022, 11, 6, 1)]).dt.replace_time_zone(
+        "America/Chicago", ambiguous="latest"
+    )
+    assert_series_equal(result, expected)
+
+    result = s.dt.truncate("1h", ambiguous="earliest")
+    expected = pl.Series([datetime(2022, 11, 6, 1)]).dt.replace_time_zone(
+        "America/Chicago", ambiguous="earliest"
+    )
+    assert_series_equal(result, expected)
+
+    result = s.dt.truncate("1h", ambiguous="null")
+    assert result.dtype == pl.Datetime("us", "America/Chicago")
+    assert result.to_list() == [None]
+
+    with pytest.raises(pl.exceptions.ComputeError, match="ambiguous"):
+        s.dt.truncate("1h", non_existent="null")
+
+
+def test_truncate_non_existent_policy() -> None:
+    # Clocks in Sao Paulo jumped from midnight to 1am on 2018-11-04.
+    s = pl.Series(
+        [datetime(2018, 11, 4, 12), datetime(2018, 11, 5, 12)]
+    ).dt.replace_time_zone("America/Sao_Paulo")
+    with pytest.raises(pl.exceptions.ComputeError, match="non-existent"):
+        s.dt.truncate("1d")
+
+    result = s.dt.truncate("1d", non_existent="null")
+    expected = pl.Series([None, datetime(2018, 11, 5)]).dt.replace_time_zone(
+        "America/Sao_Paulo"
+    )
+    assert_series_equal(result, expected)
+
+
+def test_truncate_policy_ambiguous_expr() -> None:
+    df = pl.DataFrame(
+        {
+            "ts": [datetime(2022, 11, 6, 1, 30)] * 2,
+            "ambiguous": ["earliest", "latest"],
+        }
+    ).with_columns(
+        pl.col("ts").dt.replace_time_zone("America/Chicago", ambiguous="earliest")
+    )
+    result = df.select(
+        pl.col("ts").dt.truncate("1h", ambiguous=pl.col("ambiguous"))
+    ).to_series()
+    expected = pl.Series("ts", [datetime(2022, 11, 6, 1)] * 2).dt.replace_time_zone(
+        "America/Chicago", ambiguous=pl.lit(pl.Series(["earliest", "latest"]))
+    )
+    assert_series_equal(result, expected)
+
+
+@pytest.mark.parametrize("as_date", [False, True])
+def test_truncate_policy_without_time_zone(as_date: bool) -> None:
+    s = pl.Series([datetime(2022, 11, 6, 1, 30)])
+    if as_date:
+        s = s.dt.date()
+    result = s.dt.truncate("1h", ambiguous="null", non_existent="null")
+    assert_series_equal(result, s.dt.truncate("1h"))
+
+
+@pytest.mark.parametrize(
+    ("week_start", "expected"),
+    [
+        ("monday", [date(2024, 1, 1), date(2024, 1, 1), date(2024, 1, 8)]),
+        ("saturday", [date(2024, 1, 6), date(2024, 1, 6), date(2024, 1, 6)]),
+        ("sunday", [date(2023, 12, 31), date(2024, 1, 7), date(2024, 1, 7)]),
+    ],
+)
+@pytest.mark.parametrize("time_zone", [None, "Europe/London"])
+def test_truncate_week_start(
+    week_start: Weekday, expected: list[date], time_zone: str | None
+) -> None:
+    dates = pl.Series([date(2024, 1, 6), date(2024, 1, 7), date(2024, 1, 8)])
+    result = dates.dt.truncate("1w", week_start=week_start)
+    assert_series_equal(result, pl.Series(expected))
+
+    datetimes = (dates.cast(pl.Datetime) + timedelta(hours=13)).dt.replace_time_zone(
+        time_zone
+    )
+    result = datetimes.dt.truncate("1w", week_start=week_start)
+    expected_s = pl.Series(expected).cast(pl.Datetime).dt.replace_time_zone(time_zone)
+    assert_series_equal(result, expected_s)
+
+
+def test_truncate_week_start_multiple_weeks() -> None:
+    s = pl.Series([date(2024, 1, 6), date(2024, 1, 13), date(2024, 1, 14)])
+    result = s.dt.truncate("2w", week_start="sunday")
+    expected = pl.Series([date(2023, 12, 31), date(2023, 12, 31), date(2024, 1, 14)])
+    assert_series_equal(result, expected)
+
+
+def test_truncate_week_start_does_not_affect_days() -> None:
+    s = pl.Series([datetime(2024, 1, 6, 13)])
+    assert_series_equal(s.dt.truncate("1d", week_start="sunday"), s.dt.truncate("1d"))
+
+
+def test_truncate_invalid_week_start() -> None:
+    s = pl.Series([date(2024, 1, 6)])
+    with pytest.raises(ValueError, match="`week_start` must be one of"):
+        s.dt.truncate("1w", week_start="sun")  # type: ignore[arg-type]
@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_tokenize() -> None:
+    df = pl.DataFrame({"a": ["x", "y", "x", None], "b": [1, 2, 1, None]})
+    out = df.select(
+        pl.all().anonymize.tokenize("secret", length=12),
+        other=pl.col("a").anonymize.tokenize("other", length=12),
+    )
+    assert out.schema == {"a": pl.String, "b": pl.String, "other": pl.String}
+    assert out["a"].str.len_chars().to_list() == [12, 12, 12, None]
+    assert out["a"][0] == out["a"][2]
+    assert out["a"][0] != out["a"][1]
+    assert out["a"][0] != out["other"][0]
+    assert out["b"][0] == out["b"][2]
+
+    # Numbers are tokenized as their string representation.
+    assert out["b"][0] == pl.Series(["1"]).anonymize.tokenize("secret", length=12)[0]
+
+
+def test_hash_preserving_format() -> None:
+    s = pl.Series(["Ann-42@x.org", "Bob-07@y.org"])
+    out = s.anonymize.hash_preserving_format("secret")
+    assert out.str.contains(r"^[A-Z][a-z]{2}-\d{2}@[a-z]\.[a-z]{3}$").all()
+    assert (out != s).all()
+    assert_series_equal(out, s.anonymize.hash_preserving_format("secret"))
+
+
+def test_bucket() -> None:
+    df = pl.DataFrame(
+        {
+            "i": pl.Series([-1, 9, 10, 37], dtype=pl.Int16),
+            "u": pl.Series([1, 9, 10, 37], dtype=pl.UInt8),
+            "f": [-0.5, 0.25, 1.0, 1.75],
+        }
+    )
+    out = df.select(
+        pl.col("i", "u").anonymize.bucket(10),
+        pl.col("f").anonymize.bucket(0.5),
+    )
+    expected = pl.DataFrame(
+        {
+            "i": pl.Series([-10, 0, 10, 30], dtype=pl.Int16),
+            "u": pl.Series([0, 0, 10, 30], dtype=pl.UInt8),
+            "f": [-0.5, 0.0, 1.0, 1.5],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(InvalidOperationError, match="must be an integer"):
+        df.select(pl.col("i").anonymize.bucket(2.5))
+    with pytest.raises(InvalidOperationError, match="must be positive"):
+        df.select(pl.col("f").anonymize.bucket(0))
+
+
+def test_truncate() -> None:
+    s = pl.Series(["94107", "12", None])
+    assert s.anonymize.truncate(3).to_list() == ["941", "12", None]
+    assert s.anonymize.truncate(3, fill="*").to_list() == ["941**", "12", None]
+
+
+def test_anonymize_key_not_displayed() -> None:
+    lf = pl.LazyFrame({"a": ["x"]}).select(pl.col("a").anonymize.tokenize("my-secret"))
+    assert "my-secret" not in lf.explain()
+    assert "anonymize.tokenize" in lf.explain()
@@ -1,13 +1,15 @@
 from __future__ import annotations
 
+import ipaddress
 import random
 import struct
 from typing import TYPE_CHECKING
+from uuid import uuid4
 
 import pytest
 
 import polars as pl
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
     from polars._typing import SizeUnit, TransferEncoding
@@ -113,6 +115,25 @@ def test_base64_decode() -> None:
     assert df["data"].bin.decode("base64").to_list() == [b"asd", b"qwe"]
 
 
+def test_base64url_roundtrip() -> None:
+    data = [b"\xfb\xff", b"hello?", b"", None]
+    s = pl.Series(data)
+    encoded = s.bin.encode("base64url")
+    assert encoded.to_list() == ["-_8", "aGVsbG8_", "", None]
+    assert encoded.cast(pl.Binary).bin.decode("base64url").to_list() == data
+
+    # padded input is accepted too, but not the standard alphabet
+    padded = pl.Series([b"-_8=", b"+/8="])
+    assert padded.bin.decode("base64url", strict=False).to_list() == [
+        b"\xfb\xff",
+        None,
+    ]
+    with pytest.raises(pl.exceptions.ComputeError, match="invalid `base64url`"):
+        padded.bin.decode("base64url")
+    with pytest.raises(ValueError, match="must be one of"):
+        s.bin.encode("base32")  # type: ignore[arg-type]
+
+
 def test_hex_encode() -> None:
     df = pl.DataFrame({"data": [b"asd", b"qwe"]})
 
@@ -280,3 +301,230 @@ def test_bin_contains_unequal_lengths_22018(func: str) -> None:
     f = getattr(s, func)
     with pytest.raises(pl.exceptions.ShapeError):
         f(pl.Series([b"x", b"y", b"z"]))
+
+
+def test_uuid_roundtrip() -> None:
+    values = [uuid4() for _ in range(5)]
+    s = pl.Series("id", [str(v) for v in values] + [None])
+
+    parsed = s.str.to_uuid()
+    assert parsed.dtype == pl.Uuid
+    assert parsed.to_list() == [*values, None]
+    assert parsed[0] == values[0]
+    assert parsed.uuid.to_string().to_list() == s.to_list()
+
+    # uuids can be cast from and to their 16 bytes
+    as_bytes = parsed.cast(pl.Binary)
+    assert as_bytes.to_list() == [v.bytes for v in values] + [None]
+    assert as_bytes.bin.uuid_to_string().to_list() == s.to_list()
+    assert_series_equal(as_bytes.cast(pl.Uuid), parsed)
+
+    # uuids can be sorted and used as join and group-by keys
+    assert parsed.sort().to_list() == [None, *sorted(values, key=lambda v: v.bytes)]
+    df = pl.DataFrame({"id": s, "x": range(6)}).with_columns(pl.col("id").str.to_uuid())
+    joined = df.join(df, on="id").sort("x")
+    assert joined.schema["id"] == pl.Uuid
+    assert joined["x"].to_list() == joined["x_right"].to_list() == [0, 1, 2, 3, 4]
+    grouped = pl.concat([df, df]).group_by("id").agg(pl.len()).drop_nulls()
+    assert grouped.schema["id"] == pl.Uuid
+    assert grouped["len"].to_list() == [2] * 5
+
+
+def test_uuid_forms() -> None:
+    expected = "67e55044-10b1-426f-9247-bb680e5fe0c8"
+    s = pl.Series(
+        [
+            expected.upper(),
+            expected.replace("-", ""),
+            f"{{{expected}}}",
+            f"urn:uuid:{expected}",
+        ]
+    )
+    result = s.to_frame().select(pl.first().str.to_uuid().uuid.to_string())
+    assert result.to_series().to_list() == [expected] * 4
+
+
+def test_uuid_invalid() -> None:
+    s = pl.Series(
+        ["67e55044-10b1-426f-9247-bb680e5fe0c", "67e5504410b1426f9247bb680e5fe0c8"]
+    )
+    with pytest.raises(pl.exceptions.ComputeError, match="invalid UUID found"):
+        s.str.to_uuid()
+    assert s.str.to_uuid(strict=False).uuid.to_string().to_list() == [
+        None,
+        "67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ]
+
+    with pytest.raises(pl.exceptions.ComputeError, match="must be 16 bytes long"):
+        pl.Series([b"\x00" * 15]).bin.uuid_to_string()
+    with pytest.raises(pl.exceptions.ComputeError, match="must be 16 bytes long"):
+        pl.Series([b"\x00" * 15]).cast(pl.Uuid)
+    assert pl.Series([b"\x00" * 15]).cast(pl.Uuid, strict=False).to_list() == [None]
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="str.to_uuid"):
+        s.cast(pl.Uuid)
+
+
+def test_ip_roundtrip() -> None:
+    s = pl.Series("ip", ["192.168.0.1", "::1", "2001:DB8:0:0::1", None])
+    encoded = s.str.to_ip()
+    assert encoded.bin.size().to_list() == [4, 16, 16, None]
+    assert encoded.bin.ip_to_string().to_list() == [
+        "192.168.0.1",
+        "::1",
+        "2001:db8::1",
+        None,
+    ]
+    assert encoded.head(1).bin.reinterpret(
+        dtype=pl.UInt32, endianness="big"
+    ).item() == (192 << 24 | 168 << 16 | 1)
+
+
+def test_ip_to_integer() -> None:
+    s = pl.Series("ip", ["192.168.0.1", "2001:db8::1", None]).str.to_ip()
+
+    v4 = s.gather([0, 2]).bin.ipv4_to_integer()
+    assert v4.dtype == pl.UInt32
+    assert v4.to_list() == [192 << 24 | 168 << 16 | 1, None]
+    with pytest.raises(pl.exceptions.ComputeError, match="must be 4 bytes"):
+        s.bin.ipv4_to_integer()
+
+    v6 = s.bin.ipv6_to_integer()
+    assert v6.dtype == pl.Struct({"high": pl.UInt64, "low": pl.UInt64})
+    ints = [None if v is None else v["high"] << 64 | v["low"] for v in v6]
+    assert ints == [
+        int(ipaddress.IPv6Address("::ffff:192.168.0.1")),
+        int(ipaddress.IPv6Address("2001:db8::1")),
+        None,
+    ]
+    with pytest.raises(pl.exceptions.ComputeError, match="must be 4"):
+        pl.Series([b"\x00" * 5]).bin.ipv6_to_integer()
+
+
+def test_ip_is_in_subnet() -> None:
+    s = pl.Series(["10.1.2.3", "11.0.0.1", "2001:db8::ff", "::1", None]).str.to_ip()
+    assert s.bin.ip_is_in_subnet("10.0.0.0/8").to_list() == [
+        True,
+        False,
+        False,
+        False,
+        None,
+    ]
+    assert s.bin.ip_is_in_subnet("2001:db8::1/32").to_list() == [
+        False,
+        False,
+        True,
+        False,
+        None,
+    ]
+    assert s.bin.ip_is_in_subnet("11.0.0.1").to_list() == [
+        False,
+        True,
+        False,
+        False,
+        None,
+    ]
+    assert s.bin.ip_is_in_subnet("0.0.0.0/0").to_list() == [
+        True,
+        True,
+        False,
+        False,
+        None,
+    ]
+
+
+def test_ip_invalid() -> None:
+    s = pl.Series(["10.0.0.256", "10.0.0.1"])
+    with pytest.raises(pl.exceptions.ComputeError, match="invalid IP address found"):
+        s.str.to_ip()
+    assert s.str.to_ip(strict=False).bin.ip_to_string().to_list() == [
+        None,
+        "10.0.0.1",
+    ]
+
+    with pytest.raises(pl.exceptions.ComputeError, match="must be 4"):
+        pl.Series([b"\x00" * 5]).bin.ip_to_string()
+    for cidr in ["10.0.0.0/33", "10.0.0.0/", "not-a-subnet"]:
+        with pytest.raises(pl.exceptions.InvalidOperationError, match="invalid subnet"):
+            s.str.to_ip(strict=False).bin.ip_is_in_subnet(cidr)
+
+
+def test_bin_slice() -> None:
+    df = pl.DataFrame(
+        {
+            "data": [b"\x01\x02\x03\x04", b"\x05", b"", None],
+            "offset": [1, -3, 0, 0],
+            "length": [2, 2, None, 1],
+        }
+    )
+    out = df.select(
+        lit=pl.col("data").bin.slice(1, 2),
+        tail=pl.col("data").bin.slice(-2),
+        expr=pl.col("data").bin.slice("offset", "length"),
+    )
+    assert out.to_dict(as_series=False) == {
+        "lit": [b"\x02\x03", b"", b"", None],
+        "tail": [b"\x03\x04", b"\x05", b"", None],
+        "expr": [b"\x02\x03", b"", b"", None],
+    }
+    # on ascii, slicing bytes matches slicing strings
+    for offset in range(-6, 6):
+        for length in [None, 0, 1, 3, 10]:
+            expected = pl.Series(["abcd"]).str.slice(offset, length).item()
+            out = pl.Series([b"abcd"]).bin.slice(offset, length).item()
+            assert out == expected.encode()
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        pl.Series([b"\x01"]).bin.slice(0, -1)
+
+
+def test_bin_concat_and_bitwise() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [b"\x0f\xf0", b"\xff", None],
+            "b": [b"\xf0\xf0", b"\x01", b"\x00"],
+        }
+    )
+    out = df.select(
+        concat=pl.col("a").bin.concat(pl.col("b")),
+        concat_lit=pl.col("a").bin.concat(b"!"),
+        bit_and=pl.col("a").bin.bitwise_and(pl.col("b").bin.slice(0, 2)),
+    )
+    assert out.to_dict(as_series=False) == {
+        "concat": [b"\x0f\xf0\xf0\xf0", b"\xff\x01", None],
+        "concat_lit": [b"\x0f\xf0!", b"\xff!", None],
+        "bit_and": [b"\x00\xf0", b"\x01", None],
+    }
+    s = pl.Series([b"\x0f\xf0"])
+    assert s.bin.bitwise_or(b"\xf0\xf0").item() == b"\xff\xf0"
+    assert s.bin.bitwise_xor(b"\xf0\xf0").item() == b"\xff\x00"
+    assert s.bin.bitwise_xor(s).item() == b"\x00\x00"
+
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="requires values of the same size"
+    ):
+        s.bin.bitwise_and(b"\x00")
+    with pytest.raises(pl.exceptions.ShapeError):
+        df.select(pl.col("a").bin.concat(pl.Series([b"\x00", b"\x01"])))
+
+
+def test_bin_digests() -> None:
+    import zlib
+
+    data = [b"hello", b"polars", b"", bytes(range(256)) * 5, None]
+    s = pl.Series(data)
+    assert s.bin.crc32().to_list() == [
+        None if v is None else zlib.crc32(v) for v in data
+    ]
+    assert s.bin.crc32().dtype == pl.UInt32
+
+    xxh3 = s.bin.xxh3()
+    assert xxh3.dtype == pl.UInt64
+    # the reference hash of the empty input
+    assert xxh3[2] == 0x2D06800538D394C2
+    assert xxh3[4] is None
+    assert xxh3.n_unique() == 5
+    assert s.bin.xxh3(seed=1)[0] != xxh3[0]
+    assert_frame_equal(
+        pl.DataFrame({"x": data}).lazy().select(pl.col("x").bin.xxh3()).collect(),
+        xxh3.to_frame("x"),
+    )
@@ -8,6 +8,7 @@ import pytest
 import polars as pl
 from polars.exceptions import (
     OutOfBoundsError,
+    StructFieldNotFoundError,
 )
 from polars.testing import assert_frame_equal
 
@@ -117,3 +118,60 @@ def test_field_by_index_18732() -> None:
     expected_df = pl.DataFrame({"foo": [{"a": 2, "b": 1}]})
     result_df = df.filter(pl.col.foo.struct[-1] == 1)
     assert_frame_equal(expected_df, result_df)
+
+
+def test_struct_nested_field_paths() -> None:
+    df = pl.DataFrame(
+        {"s": [{"a": 1, "b": {"c": 2, "d": 3}}, None, {"a": 4, "b": None}]}
+    )
+
+    result = df.select(pl.col("s").struct.drop_fields("b.c"))
+    expected = pl.DataFrame({"s": [{"a": 1, "b": {"d": 3}}, None, {"a": 4, "b": None}]})
+    assert_frame_equal(result, expected)
+    assert df.select(pl.col("s").struct.drop_fields(["a", "b"])).schema == {
+        "s": pl.Struct({})
+    }
+
+    result = df.select(pl.col("s").struct.rename_fields({"b.d": "e", "a": "x"}))
+    assert result.schema == {
+        "s": pl.Struct({"x": pl.Int64, "b": pl.Struct({"c": pl.Int64, "e": pl.Int64})})
+    }
+    assert result["s"].struct.field("b").struct.field("e").to_list() == [3, None, None]
+
+    result = df.select(
+        pl.col("s").struct.with_fields_by_path(
+            (pl.field("a") * 10).alias("b.c"), **{"b.f": pl.lit(True)}
+        )
+    )
+    assert result.schema == {
+        "s": pl.Struct(
+            {
+                "a": pl.Int64,
+                "b": pl.Struct({"c": pl.Int64, "d": pl.Int64, "f": pl.Boolean}),
+            }
+        )
+    }
+    assert result["s"].to_list() == [
+        {"a": 1, "b": {"c": 10, "d": 3, "f": True}},
+        None,
+        {"a": 4, "b": None},
+    ]
+
+    # a field with a dot in its name takes precedence over the nested path
+    df = pl.DataFrame({"s": [{"b.c": 1, "b": {"c": 2}}]})
+    result = df.select(pl.col("s").struct.drop_fields("b.c"))
+    assert result.schema == {"s": pl.Struct({"b": pl.Struct({"c": pl.Int64})})}
+
+    with pytest.raises(StructFieldNotFoundError):
+        df.select(pl.col("s").struct.drop_fields("b.x"))
+    with pytest.raises(StructFieldNotFoundError):
+        df.select(pl.col("s").struct.rename_fields({"x": "y"}))
+
+
+def test_struct_with_fields_dotted_name_is_flat() -> None:
+    df = pl.DataFrame({"s": [{"a": {"b": 1}}]})
+    result = df.select(pl.col("s").struct.with_fields(pl.lit(2).alias("a.b")))
+    assert result.schema == {
+        "s": pl.Struct({"a": pl.Struct({"b": pl.Int64}), "a.b": pl.Int32})
+    }
+    assert result.item() == {"a": {"b": 1}, "a.b": 2}
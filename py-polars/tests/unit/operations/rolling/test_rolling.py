@@ -20,7 +20,12 @@ from tests.unit.conftest import INTEGER_DTYPES
 if TYPE_CHECKING:
     from hypothesis.strategies import SearchStrategy
 
-    from polars._typing import ClosedInterval, PolarsDataType, TimeUnit
+    from polars._typing import (
+        ClosedInterval,
+        PolarsDataType,
+        RollingRankMethod,
+        TimeUnit,
+    )
 
 
 @pytest.fixture
@@ -196,6 +201,83 @@ def test_rolling_skew() -> None:
     )
 
 
+def test_rolling_kurtosis() -> None:
+    s = pl.Series([1, 2, 3, 3, 2, 10, 8])
+    assert s.rolling_kurtosis(4).to_list() == pytest.approx(
+        [
+            None,
+            None,
+            None,
+            -1.371900826446281,
+            -2.0,
+            -0.7055324211778702,
+            -1.7878967572797353,
+        ]
+    )
+    assert s.rolling_kurtosis(4, fisher=False, bias=False).to_list() == pytest.approx(
+        [
+            None,
+            None,
+            None,
+            1.7107438016528924,
+            -3.0,
+            6.708506841165974,
+            -1.4092256795980145,
+        ]
+    )
+    assert s.rolling_kurtosis(4, min_samples=3).to_list() == pytest.approx(
+        [
+            None,
+            None,
+            -1.5,
+            -1.371900826446281,
+            -2.0,
+            -0.7055324211778702,
+            -1.7878967572797353,
+        ]
+    )
+
+
+def test_rolling_kurtosis_matches_kurtosis() -> None:
+    s = pl.Series([1.0, None, 4.0, 2.0, 9.0, 3.0, None, 7.0, 5.0])
+    out = s.rolling_kurtosis(4, min_samples=2, bias=False).to_list()
+    for i, value in enumerate(out):
+        window = s[max(0, i - 3) : i + 1].drop_nulls()
+        if len(window) < 2:
+            assert value is None
+        else:
+            assert value == pytest.approx(window.kurtosis(bias=False), nan_ok=True)
+
+
+@pytest.mark.parametrize(
+    ("method", "expected"),
+    [
+        ("average", [1.0, 2.0, 2.5, 1.0, None, 2.0]),
+        ("min", [1.0, 2.0, 2.0, 1.0, None, 2.0]),
+        ("max", [1.0, 2.0, 3.0, 2.0, None, 2.0]),
+    ],
+)
+def test_rolling_rank(method: RollingRankMethod, expected: list[float | None]) -> None:
+    s = pl.Series("a", [1, 3, 3, 2, None, 5])
+    out = s.rolling_rank(3, method, min_samples=1)
+    assert_series_equal(out, pl.Series("a", expected, dtype=pl.Float64))
+
+    out = s.to_frame().select(pl.col("a").rolling_rank(3, method, min_samples=1))
+    assert_series_equal(out.to_series(), pl.Series("a", expected, dtype=pl.Float64))
+
+
+def test_rolling_rank_center_and_min_samples() -> None:
+    s = pl.Series([4, 1, 3, 2, 5])
+    assert s.rolling_rank(3).to_list() == [None, None, 2.0, 2.0, 3.0]
+    assert s.rolling_rank(3, center=True, min_samples=2).to_list() == [
+        2.0,
+        1.0,
+        3.0,
+        1.0,
+        2.0,
+    ]
+
+
 @pytest.mark.parametrize("time_zone", [None, "US/Central"])
 @pytest.mark.parametrize(
     ("rolling_fn", "expected_values", "expected_dtype"),
@@ -474,24 +556,26 @@ def test_overlapping_groups_4628() -> None:
 
 def test_rolling_skew_lagging_null_5179() -> None:
     s = pl.Series([None, 3, 4, 1, None, None, None, None, 3, None, 5, 4, 7, 2, 1, None])
-    assert s.rolling_skew(3).fill_nan(-1.0).to_list() == [
-        None,
-        None,
-        0.0,
-        -0.3818017741606059,
-        0.0,
-        -1.0,
-        None,
-        None,
-        -1.0,
-        -1.0,
-        0.0,
-        0.0,
-        0.38180177416060695,
-        0.23906314692954517,
-        0.6309038567106234,
-        0.0,
-    ]
+    assert s.rolling_skew(3).fill_nan(-1.0).to_list() == pytest.approx(
+        [
+            None,
+            None,
+            0.0,
+            -0.3818017741606059,
+            0.0,
+            -1.0,
+            None,
+            None,
+            -1.0,
+            -1.0,
+            0.0,
+            0.0,
+            0.38180177416060695,
+            0.23906314692954517,
+            0.6309038567106234,
+            0.0,
+        ]
+    )
 
 
 def test_rolling_var_numerical_stability_5197() -> None:
@@ -630,6 +714,65 @@ def test_rolling_cov_corr_nulls() -> None:
     assert_frame_equal(val_2, df2_expected, atol=0.0000001)
 
 
+def test_rolling_cov_corr_by() -> None:
+    df = pl.DataFrame(
+        {
+            "date": [date(2020, 1, d) for d in [1, 2, 4, 5, 9]],
+            "x": [1, 4, 2, 9, 3],
+            "y": [2, 3, 7, 8, 4],
+        }
+    )
+    res = df.select(
+        pl.rolling_cov_by("x", "y", "date", window_size="4d").alias("cov"),
+        pl.rolling_cov_by("x", "y", "date", window_size="4d", ddof=0).alias("cov0"),
+        pl.rolling_corr_by("x", "y", "date", window_size="4d").alias("corr"),
+    )
+    assert res["cov"].to_list() == pytest.approx([None, 1.5, 0.0, 4.0, None])
+    assert res["cov0"].to_list() == pytest.approx(
+        [0.0, 0.75, 0.0, 2.6666666666666665, 0.0]
+    )
+    assert res["corr"].to_list() == pytest.approx(
+        [None, 1.0, 0.0, 0.4193139346887673, None]
+    )
+
+
+def test_rolling_cov_corr_by_matches_fixed_window() -> None:
+    df = pl.DataFrame({"x": [3, 1, 3, 5, 8, 1], "y": [3, 4, 6, 4, 8, 2]})
+    df = df.with_row_index()
+    res = df.select(
+        pl.rolling_cov_by("x", "y", "index", window_size="3i", min_samples=3),
+        pl.rolling_cov("x", "y", window_size=3).alias("expected_cov"),
+        pl.rolling_corr_by("x", "y", "index", window_size="3i", min_samples=3).alias(
+            "corr"
+        ),
+        pl.rolling_corr("x", "y", window_size=3).alias("expected_corr"),
+    )
+    assert res["x"].to_list() == pytest.approx(res["expected_cov"].to_list())
+    assert res["corr"].to_list() == pytest.approx(res["expected_corr"].to_list())
+
+
+def test_rolling_cov_by_over() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b", "b"],
+            "t": [1, 2, 3, 1, 2, 3],
+            "x": [1.0, 2.0, 4.0, 1.0, 3.0, 2.0],
+            "y": [1.0, 3.0, 2.0, 2.0, 2.0, 5.0],
+        }
+    )
+    res = df.select(
+        pl.rolling_cov_by("x", "y", "t", window_size="2i").over("g").alias("cov")
+    )
+    expected = pl.Series("cov", [None, 1.0, -1.0, None, 0.0, -1.5])
+    assert_series_equal(res["cov"], expected)
+
+
+def test_rolling_cov_by_nulls_raise() -> None:
+    df = pl.DataFrame({"t": [1, 2, 3], "x": [1.0, None, 2.0], "y": [1.0, 2.0, 3.0]})
+    with pytest.raises(InvalidOperationError, match="null values"):
+        df.select(pl.rolling_cov_by("x", "y", "t", window_size="2i"))
+
+
 @pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
 def test_rolling_empty_window_9406(time_unit: TimeUnit) -> None:
     datecol = pl.Series(
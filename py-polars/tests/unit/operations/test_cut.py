@@ -137,3 +137,45 @@ def test_cut_fast_unique_15981(
     assert_series_equal(s_cut.to_physical(), expected_physical)
     assert s_cut.n_unique() == s_cut.to_physical().n_unique() == expected_unique
     s_cut.to_frame().group_by(s.name).len()
+
+
+def test_cut_as_enum() -> None:
+    s = pl.Series("a", [2, -2, 0])
+
+    result = s.cut([-1, 1], as_enum=True)
+    dtype = pl.Enum(["(-inf, -1]", "(-1, 1]", "(1, inf]"])
+    expected = pl.Series("a", ["(1, inf]", "(-inf, -1]", "(-1, 1]"], dtype)
+    assert_series_equal(result, expected)
+    assert (result > "(-inf, -1]").to_list() == [True, False, True]
+    assert result.max() == "(1, inf]"
+
+    result = s.cut([1, -1], labels=["low", "mid", "high"], as_enum=True)
+    assert result.dtype == pl.Enum(["low", "mid", "high"])
+    assert result.sort().to_list() == ["low", "mid", "high"]
+
+    result = pl.select(
+        pl.lit(s).cut([0], labels=["low", "high"], include_breaks=True, as_enum=True)
+    ).to_series()
+    assert result.dtype == pl.Struct(
+        {"breakpoint": pl.Float64, "category": pl.Enum(["low", "high"])}
+    )
+    assert result.struct.field("category").to_list() == ["high", "low", "low"]
+
+    with pytest.raises(pl.exceptions.DuplicateError, match="labels must be unique"):
+        s.cut([0], labels=["a", "a"], as_enum=True)
+
+
+def test_cut_label_format() -> None:
+    s = pl.Series("a", [-2, 0, 2, None])
+
+    result = s.cut([-1, 1.5], label_format="{lower}..{upper}")
+    expected = pl.Series("a", ["-inf..-1", "-1..1.5", "1.5..inf", None])
+    assert_series_equal(result, expected, categorical_as_str=True)
+
+    result = s.cut([-1, 1], label_format="<= {upper}", as_enum=True)
+    assert result.dtype == pl.Enum(["<= -1", "<= 1", "<= inf"])
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="only provide one"):
+        s.cut([0], labels=["a", "b"], label_format="{lower}")
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="must contain"):
+        s.cut([0], label_format="bin")
@@ -239,3 +239,46 @@ def test_ewma_by_multiple_chunks() -> None:
     values = pl.Series([1, 2]).append(pl.Series([None], dtype=pl.Int64))
     result = values.ewm_mean_by(times, half_life="2i")
     assert_series_equal(result, expected)
+
+
+@pytest.mark.parametrize("sort", [True, False])
+def test_ewm_var_std_by_irregular(sort: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "values": [1.0, 2.0, None, 4.0],
+            "times": [1, 2, 3, 5],
+        }
+    )
+    if not sort:
+        df = df.reverse()
+    result = df.with_columns(
+        var=pl.col("values").ewm_var_by("times", half_life="2i"),
+        std=pl.col("values").ewm_std_by("times", half_life="2i"),
+    ).sort("times")
+    expected_var = pl.Series(
+        "var", [0.0, 0.20710678118654752, None, 1.7481601717798214]
+    )
+    assert_series_equal(result["var"], expected_var)
+    assert_series_equal(result["std"], expected_var.sqrt().alias("std"))
+
+
+def test_ewm_var_by_nulls_in_times() -> None:
+    df = pl.DataFrame(
+        {
+            "values": [1.0, 5.0, 2.0],
+            "times": [1, None, 2],
+        }
+    )
+    result = df.select(pl.col("values").ewm_var_by("times", half_life="2i"))
+    expected = pl.DataFrame({"values": [0.0, None, 0.20710678118654752]})
+    assert_frame_equal(result, expected)
+
+
+def test_ewm_std_by_f32() -> None:
+    df = pl.DataFrame(
+        {"values": [1.0, 2.0], "times": [1, 2]},
+        schema={"values": pl.Float32, "times": pl.Int64},
+    )
+    result = df.lazy().select(pl.col("values").ewm_std_by("times", half_life="2i"))
+    assert result.collect_schema()["values"] == pl.Float32
+    assert result.collect().schema["values"] == pl.Float32
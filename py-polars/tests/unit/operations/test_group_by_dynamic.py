@@ -1073,3 +1073,73 @@ def test_group_by_dynamic_overlapping_19704() -> None:
         }
     )
     assert_frame_equal(result, expected)
+
+
+def test_group_by_dynamic_fill_gaps() -> None:
+    df = pl.DataFrame(
+        {
+            "time": [
+                datetime(2024, 1, 1, 0),
+                datetime(2024, 1, 1, 1),
+                datetime(2024, 1, 1, 4),
+            ],
+            "n": [1, 2, 5],
+        }
+    )
+    result = df.group_by_dynamic(
+        "time",
+        every="1h",
+        include_boundaries=True,
+        fill_gaps={"n_sum": 0, "n_mean": "interpolate"},
+    ).agg(
+        n_sum=pl.col("n").sum(),
+        n_mean=pl.col("n").mean(),
+        n_max=pl.col("n").max(),
+    )
+    expected = pl.DataFrame(
+        {
+            "_lower_boundary": pl.datetime_range(
+                datetime(2024, 1, 1, 0), datetime(2024, 1, 1, 4), "1h", eager=True
+            ),
+            "_upper_boundary": pl.datetime_range(
+                datetime(2024, 1, 1, 1), datetime(2024, 1, 1, 5), "1h", eager=True
+            ),
+            "time": pl.datetime_range(
+                datetime(2024, 1, 1, 0), datetime(2024, 1, 1, 4), "1h", eager=True
+            ),
+            "n_sum": [1, 2, 0, 0, 5],
+            "n_mean": [1.0, 2.0, 3.0, 4.0, 5.0],
+            "n_max": [1, 2, None, None, 5],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_group_by_dynamic_fill_gaps_in_groups() -> None:
+    lf = pl.LazyFrame(
+        {
+            "idx": [0, 1, 6, 0, 3],
+            "g": ["a", "a", "a", "b", "b"],
+            "n": [1, 2, 3, 4, 5],
+        }
+    )
+    result = (
+        lf.group_by_dynamic("idx", every="2i", group_by="g", fill_gaps="forward")
+        .agg(pl.col("n").last())
+        .collect()
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "a", "b", "b"],
+            "idx": [0, 2, 4, 6, 0, 2],
+            "n": [2, 2, 2, 3, 4, 5],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_group_by_dynamic_fill_gaps_invalid() -> None:
+    lf = pl.LazyFrame({"idx": [0, 4], "n": [1, 2]})
+    gb = lf.group_by_dynamic("idx", every="2i", label="right", fill_gaps=0)
+    with pytest.raises(InvalidOperationError, match="requires `label='left'`"):
+        gb.agg(pl.col("n").sum()).collect()
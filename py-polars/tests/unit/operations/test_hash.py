@@ -1,4 +1,10 @@
+import hashlib
+import struct
+
+import pytest
+
 import polars as pl
+from polars.testing import assert_series_equal
 
 
 def test_hash_struct() -> None:
@@ -9,3 +15,67 @@ def test_hash_struct() -> None:
         6116564025432436932,
         49592145888590321,
     ]
+
+
+def test_hash_stable_algorithms() -> None:
+    s = pl.Series("a", ["polars", "rust", None])
+    assert s.hash(algorithm="xxhash64").to_list() == [
+        6375477685702046748,
+        6868822986778387468,
+        None,
+    ]
+    assert s.hash(algorithm="sha256").to_list() == [
+        None if v is None else hashlib.sha256(v.encode()).digest() for v in s
+    ]
+    # categoricals hash as their strings
+    assert_series_equal(
+        s.cast(pl.Categorical).hash(algorithm="siphash13"),
+        s.hash(algorithm="siphash13"),
+    )
+
+    # integers hash as their little-endian bytes
+    ints = pl.Series("a", [1, 2, 3])
+    assert ints.hash(seed=42, algorithm="wyhash").to_list() == [
+        14184485330598920855,
+        3742453734528888582,
+        12411246033169709547,
+    ]
+    assert ints.hash(seed=42, algorithm="siphash13").to_list() == [
+        13744926046855620988,
+        4444684834691176226,
+        8595243996716556356,
+    ]
+    assert ints.hash(algorithm="sha256").to_list() == [
+        hashlib.sha256(struct.pack("<q", v)).digest() for v in ints
+    ]
+    assert (
+        ints.hash(algorithm="siphash13", seed=42).to_list()
+        != ints.hash(algorithm="siphash13", seed=42, seed_1=0).to_list()
+    )
+
+
+def test_hash_stable_float_normalization() -> None:
+    s = pl.Series([0.0, -0.0, float("nan"), -float("nan")])
+    for algorithm in ["xxhash64", "wyhash", "siphash13", "sha256"]:
+        out = s.hash(algorithm=algorithm)  # type: ignore[arg-type]
+        assert out[0] == out[1]
+        assert out[2] == out[3]
+
+
+def test_hash_stable_lazy_schema() -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": [True, None]})
+    q = lf.select(
+        pl.col("a").hash(algorithm="sha256"),
+        pl.col("b").hash(algorithm="xxhash64"),
+    )
+    assert q.collect_schema() == pl.Schema({"a": pl.Binary, "b": pl.UInt64})
+    assert q.collect()["b"].null_count() == 1
+
+
+def test_hash_stable_invalid() -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="stable hash"):
+        pl.Series([[1]]).hash(algorithm="xxhash64")
+    with pytest.raises(ValueError, match="only used by the default algorithm"):
+        pl.col("a").hash(0, 1, 2, algorithm="wyhash")
+    with pytest.raises(ValueError, match="`algorithm` must be one of"):
+        pl.col("a").hash(algorithm="md5")  # type: ignore[arg-type]
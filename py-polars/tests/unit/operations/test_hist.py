@@ -536,3 +536,54 @@ def test_hist_include_lower_22056() -> None:
             }
         )
     assert_frame_equal(result, expected)
+
+
+def test_hist_group_by() -> None:
+    df = pl.DataFrame({"g": ["a", "a", "b", "b", "b"], "x": [1, 2, 2, 3, 3]})
+    idx = pl.get_index_type()
+
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("x").hist(bins=[0, 1, 2, 3], include_breakpoint=True)
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b"],
+            "x": [
+                [
+                    {"breakpoint": 1.0, "count": 1},
+                    {"breakpoint": 2.0, "count": 1},
+                    {"breakpoint": 3.0, "count": 0},
+                ],
+                [
+                    {"breakpoint": 1.0, "count": 0},
+                    {"breakpoint": 2.0, "count": 1},
+                    {"breakpoint": 3.0, "count": 2},
+                ],
+            ],
+        },
+        schema={
+            "g": pl.String,
+            "x": pl.List(pl.Struct({"breakpoint": pl.Float64, "count": idx})),
+        },
+    )
+    assert_frame_equal(out, expected)
+
+    # the edges of a bin count are determined per group
+    out = df.group_by("g", maintain_order=True).agg(pl.col("x").hist(bin_count=1))
+    expected = pl.DataFrame(
+        {"g": ["a", "b"], "x": [[2], [3]]},
+        schema={"g": pl.String, "x": pl.List(idx)},
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_hist_over() -> None:
+    df = pl.DataFrame({"g": ["a", "a", "b", "b", "b"], "x": [1, 2, 2, 3, 3]})
+    out = df.select(
+        pl.col("x").hist(bins=(0, 1, 2, 3)).over("g", mapping_strategy="join")
+    )
+    expected = pl.DataFrame(
+        {"x": [[1, 1, 0]] * 2 + [[0, 1, 2]] * 3},
+        schema={"x": pl.List(pl.get_index_type())},
+    )
+    assert_frame_equal(out, expected)
@@ -248,3 +248,67 @@ def test_interpolate_by_invalid() -> None:
         match="null values in `by` column are not yet supported in 'interpolate_by'",
     ):
         s.interpolate_by(by)
+
+
+def test_interpolate_by_nearest() -> None:
+    df = pl.DataFrame(
+        {
+            "times": [1, 3, 10, 11, 12, 16, 21, 30],
+            "values": [1, None, None, 5, None, None, None, 6],
+        }
+    )
+    result = df.select(pl.col("values").interpolate_by("times", method="nearest"))
+    expected = pl.DataFrame({"values": [1, 1, 5, 5, 5, 5, 6, 6]})
+    assert_frame_equal(result, expected)
+
+    # The values don't have to be sorted by `by`.
+    result = (
+        df.reverse()
+        .with_columns(pl.col("values").interpolate_by("times", method="nearest"))
+        .reverse()
+    )
+    assert_frame_equal(result, df.with_columns(expected))
+
+
+def test_interpolate_by_nearest_keeps_dtype_and_boundary_nulls() -> None:
+    s = pl.Series(["a", None, None, "b", None])
+    by = pl.Series([0.0, 0.4, 0.6, 1.0, 2.0])
+    result = s.interpolate_by(by, method="nearest")
+    assert_series_equal(result, pl.Series(["a", "a", "b", "b", None]))
+
+    # Ties take the later value.
+    s = pl.Series([None, 10, None, 20], dtype=pl.UInt8)
+    by = pl.Series([0, 1, 2, 3])
+    result = s.interpolate_by(by, method="nearest")
+    assert_series_equal(result, pl.Series([None, 10, 20, 20], dtype=pl.UInt8))
+
+
+def test_interpolate_by_gaps_in_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b", "b", "b"],
+            "ts": [
+                date(2020, 1, 1),
+                date(2020, 1, 2),
+                date(2020, 1, 5),
+                date(2020, 1, 1),
+                date(2020, 1, 9),
+                date(2020, 1, 10),
+                date(2020, 1, 11),
+            ],
+            "value": [0.0, None, 4.0, 10.0, None, None, 0.0],
+        }
+    )
+    result = df.with_columns(
+        linear=pl.col("value").interpolate_by("ts").over("g"),
+        nearest=pl.col("value").interpolate_by("ts", method="nearest").over("g"),
+    )
+    assert result["linear"].to_list() == [0.0, 1.0, 4.0, 10.0, 2.0, 1.0, 0.0]
+    assert result["nearest"].to_list() == [0.0, 0.0, 4.0, 10.0, 0.0, 0.0, 0.0]
+
+    result = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.col("value").interpolate_by("ts", method="nearest"))
+        .explode("value")
+    )
+    assert result["value"].to_list() == [0.0, 0.0, 4.0, 10.0, 0.0, 0.0, 0.0]
@@ -331,6 +331,20 @@ def test_sorted_flag() -> None:
     pl.Series([{"a": 1}], dtype=pl.Object).set_sorted(descending=True)
 
 
+def test_sorted_flag_propagation() -> None:
+    s = pl.Series("a", [None, 1, 1, 2, 3, 3]).set_sorted()
+    assert s.filter(s != 2).flags["SORTED_ASC"]
+    assert s.head(3).flags["SORTED_ASC"]
+    assert s.tail(3).flags["SORTED_ASC"]
+    assert s.unique().flags["SORTED_ASC"]
+    assert s.drop_nulls().flags["SORTED_ASC"]
+
+    left = pl.DataFrame({"a": [1, 2, 2, 3, 5]}).sort("a")
+    right = pl.DataFrame({"a": [2, 3, 4, 5], "b": [1, 2, 3, 4]}).sort("a")
+    assert left.join(right, on="a", how="inner")["a"].flags["SORTED_ASC"]
+    assert left.join(right, on="a", how="left")["a"].flags["SORTED_ASC"]
+
+
 @pytest.mark.may_fail_auto_streaming
 def test_sorted_flag_after_joins() -> None:
     np.random.seed(1)
@@ -2132,3 +2132,34 @@ def test_empty_outer_join_22206() -> None:
         df,
         check_row_order=False,
     )
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right"])
+def test_join_algorithm_merge(how: JoinStrategy) -> None:
+    left = pl.DataFrame({"a": [1, 2, 2, 4, 7], "x": range(5)})
+    right = pl.DataFrame({"a": [2, 3, 4, 4, 8], "y": range(5)})
+
+    expected = left.join(right, on="a", how=how, algorithm="hash")
+    # The keys are sorted, but not flagged as sorted.
+    out = left.join(right, on="a", how=how, algorithm="merge")
+    assert_frame_equal(out, expected, check_row_order=False)
+
+    out = left.lazy().join(right.lazy(), on="a", how=how, algorithm="merge")
+    assert_frame_equal(out.collect(), expected, check_row_order=False)
+
+
+def test_join_algorithm_merge_invalid() -> None:
+    left = pl.DataFrame({"a": [1, 3, 2], "b": [1, 2, 3]})
+    right = pl.DataFrame({"a": [1, 2, 3], "b": [1, 2, 3]})
+
+    with pytest.raises(InvalidOperationError, match="key 'a' is not sorted"):
+        left.join(right, on="a", algorithm="merge")
+    with pytest.raises(InvalidOperationError, match="null keys"):
+        right.join(pl.DataFrame({"a": [None, 1]}), on="a", algorithm="merge")
+    with pytest.raises(InvalidOperationError, match="on a single key"):
+        right.join(right, on=["a", "b"], algorithm="merge")
+    with pytest.raises(InvalidOperationError, match="on a single key"):
+        right.join(right, on="a", how="full", algorithm="merge")
+    with pytest.raises(ValueError, match="`algorithm` must be one of"):
+        right.join(right, on="a", algorithm="sort")  # type: ignore[arg-type]
+
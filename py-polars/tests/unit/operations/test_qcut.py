@@ -141,3 +141,44 @@ def test_qcut_over() -> None:
         dtype=pl.Categorical,
     )
     assert_series_equal(out, expected, categorical_as_str=True)
+
+
+def test_qcut_as_enum() -> None:
+    s = pl.Series("a", [3, 0, 1, 2])
+
+    result = s.qcut(2, labels=["low", "high"], as_enum=True)
+    dtype = pl.Enum(["low", "high"])
+    assert_series_equal(result, pl.Series("a", ["high", "low", "low", "high"], dtype))
+    assert (result < "high").to_list() == [False, True, True, False]
+
+    result = pl.Series("a", [None, None], dtype=pl.Int64).qcut(
+        [0.5], labels=["low", "high"], as_enum=True
+    )
+    assert result.dtype == dtype
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="requires `labels`"):
+        s.qcut(2, as_enum=True)
+
+
+def test_qcut_label_format_over() -> None:
+    df = pl.DataFrame({"g": ["a"] * 3 + ["b"] * 3, "x": [1, 2, 3, 10, 20, 30]})
+
+    out = df.select(
+        pl.col("x").qcut([0.5], label_format="({lower}, {upper}]").over("g")
+    ).to_series()
+    expected = pl.Series(
+        "x",
+        ["(-inf, 2]", "(-inf, 2]", "(2, inf]", "(-inf, 20]", "(-inf, 20]", "(20, inf]"],
+        dtype=pl.Categorical,
+    )
+    assert_series_equal(out, expected, categorical_as_str=True)
+
+    out = df.select(
+        pl.col("x").qcut(2, label_format="{upper}", include_breaks=True).over("g")
+    ).unnest("x")
+    assert out["breakpoint"].to_list() == [2.0, 2.0, inf, 20.0, 20.0, inf]
+    categories = ["2", "2", "inf", "20", "20", "inf"]
+    assert out["category"].cast(pl.String).to_list() == categories
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="only provide one"):
+        df["x"].qcut(2, labels=["a", "b"], label_format="{lower}")
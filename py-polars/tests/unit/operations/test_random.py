@@ -65,6 +65,80 @@ def test_sample_df() -> None:
         df.sample(n=2, fraction=0.4)
 
 
+def test_sample_stratified() -> None:
+    df = pl.DataFrame(
+        {
+            "label": ["a", "a", "a", "a", "b", "b", "c"],
+            "x": [1, 1, 2, 2, 1, 1, 1],
+            "value": range(7),
+        }
+    )
+
+    out = df.sample_stratified("label", seed=0)
+    assert out["label"].to_list() == ["a", "b", "c"]
+
+    out = df.sample_stratified("label", fraction=0.5, seed=0)
+    assert out["label"].to_list() == ["a", "a", "b"]
+    assert set(out["value"]).issubset(set(df["value"]))
+
+    out = df.sample_stratified(["label", "x"], n=1, seed=0)
+    assert out.select("label", "x").rows() == [("a", 1), ("a", 2), ("b", 1), ("c", 1)]
+
+    # Seeded samples are reproducible.
+    assert_frame_equal(
+        df.sample_stratified("label", n=2, with_replacement=True, seed=1),
+        df.sample_stratified("label", n=2, with_replacement=True, seed=1),
+    )
+
+    with pytest.raises(ShapeError):
+        df.sample_stratified("label", n=2)
+    with pytest.raises(ValueError, match="cannot specify both `n` and `fraction`"):
+        df.sample_stratified("label", n=2, fraction=0.4)
+
+
+def test_sample_stratified_lazy() -> None:
+    df = pl.DataFrame(
+        {
+            "label": ["a", "a", "a", "a", "b", "b", "c"],
+            "value": range(7),
+        }
+    )
+    lf = df.lazy()
+
+    out = lf.sample_stratified("label", seed=0).collect()
+    assert out["label"].to_list() == ["a", "b", "c"]
+
+    out = lf.sample_stratified("label", fraction=0.5, seed=0).collect()
+    assert out["label"].to_list() == ["a", "a", "b"]
+    # The sampled rows keep their order.
+    assert out["value"].is_sorted()
+
+    # Groups with fewer rows than `n` are kept whole.
+    out = lf.sample_stratified("label", n=3, seed=0).collect()
+    assert out["label"].value_counts(sort=True, name="n").rows() == [
+        ("a", 3),
+        ("b", 2),
+        ("c", 1),
+    ]
+
+    assert_frame_equal(
+        lf.sample_stratified("label", n=2, seed=1).collect(),
+        lf.sample_stratified("label", n=2, seed=1).collect(),
+    )
+    with pytest.raises(ValueError, match="cannot specify both `n` and `fraction`"):
+        lf.sample_stratified("label", n=2, fraction=0.4)
+
+    # The reservoir sample runs in a single pass on the streaming engine.
+    assert_frame_equal(
+        lf.sample_stratified("label", n=2, seed=1).collect(engine="streaming"),
+        lf.sample_stratified("label", n=2, seed=1).collect(),
+    )
+
+    taken = lf.with_columns(__POLARS_STRATIFIED_KEY=pl.lit(1))
+    with pytest.raises(pl.exceptions.DuplicateError, match="reserved"):
+        taken.sample_stratified("label", fraction=0.5)
+
+
 def test_sample_n_expr() -> None:
     df = pl.DataFrame(
         {
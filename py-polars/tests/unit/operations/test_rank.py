@@ -95,3 +95,15 @@ def test_rank_series() -> None:
 
     assert s.rank(method="average").dtype == pl.Float64
     assert s.rank(method="max").dtype == pl.get_index_type()
+
+
+def test_rank_nan_position() -> None:
+    s = pl.Series("a", [float("nan"), 1.0, 3.0, None])
+
+    assert s.rank("ordinal").to_list() == [3, 1, 2, None]
+    assert s.rank("ordinal", nan_position="first").to_list() == [1, 2, 3, None]
+    assert s.rank("ordinal", descending=True).to_list() == [1, 3, 2, None]
+    assert s.rank(
+        "ordinal", descending=True, nan_position="last"
+    ).to_list() == [3, 2, 1, None]
+    assert s.rank("dense", nan_position="first").to_list() == [1, 2, 3, None]
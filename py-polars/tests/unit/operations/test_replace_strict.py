@@ -59,6 +59,35 @@ def test_replace_strict_mapping_null_with_default() -> None:
     assert_series_equal(result, expected)
 
 
+def test_replace_strict_dataframe_mapping() -> None:
+    s = pl.Series("a", [1, 2, 3, None])
+    lookup = pl.DataFrame({"key": [1, 2, None], "value": ["x", "y", "z"]})
+
+    result = s.replace_strict(lookup, default=pl.lit("other"))
+    assert_series_equal(result, pl.Series("a", ["x", "y", "other", "z"]))
+
+    lookup = pl.DataFrame({"key": [1, 2], "value": [10, 20]})
+    result = s.to_frame().select(pl.col("a").replace(lookup))
+    assert_series_equal(result.to_series(), pl.Series("a", [10, 20, 3, None]))
+
+    with pytest.raises(ValueError, match="must have two columns"):
+        s.replace_strict(lookup.select("key"))
+
+
+def test_match_map() -> None:
+    s = pl.Series("a", [1, 2, 3, None])
+
+    result = s.match_map({1: "x", 2: "y"})
+    assert_series_equal(result, pl.Series("a", ["x", "y", None, None]))
+
+    result = s.match_map({1: 10, 2: 20}, default=0, return_dtype=pl.Int32)
+    expected = pl.Series("a", [10, 20, 0, 0], dtype=pl.Int32)
+    assert_series_equal(result, expected)
+
+    result = s.to_frame().select(pl.col("a").match_map({3: 1.5}, default=pl.col("a")))
+    assert_series_equal(result.to_series(), pl.Series("a", [1.0, 2.0, 1.5, None]))
+
+
 def test_replace_strict_empty() -> None:
     lf = pl.LazyFrame({"a": [None, None]})
     result = lf.select(pl.col("a").replace_strict({}))
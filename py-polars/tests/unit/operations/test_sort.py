@@ -833,6 +833,37 @@ def test_arg_sort_by_descending() -> None:
         df.select(pl.arg_sort_by(["a", "b"], descending=[True]))
 
 
+def test_arg_sort_by_limit() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [3, 1, 2, 1, None, 2, 1],
+            "b": [0, 5, 1, 7, 2, 1, 5],
+            "g": [1, 1, 1, 2, 2, 2, 2],
+        }
+    )
+    opts: dict[str, Any] = {
+        "descending": [False, True],
+        "nulls_last": True,
+        "maintain_order": True,
+    }
+    full = df.select(pl.arg_sort_by("a", "b", **opts)).to_series()
+    assert full.to_list() == [3, 1, 6, 2, 5, 0, 4]
+    for limit in [0, 3, 7, 10]:
+        result = df.select(pl.arg_sort_by("a", "b", **opts, limit=limit))
+        assert_series_equal(result.to_series(), full.head(limit))
+
+    result = df.select(pl.arg_sort_by("b", maintain_order=True, limit=2))
+    assert result.to_series().to_list() == [0, 2]
+
+    result = df.group_by("g", maintain_order=True).agg(
+        pl.arg_sort_by("a", "b", **opts, limit=2)
+    )
+    assert result["a"].to_list() == [[1, 2], [0, 3]]
+
+    with pytest.raises(ValueError, match="`limit` must be non-negative"):
+        pl.arg_sort_by("a", limit=-1)
+
+
 def test_arg_sort_struct() -> None:
     df = pl.DataFrame(
         {
@@ -1137,3 +1168,56 @@ def test_sort_nested_multi_column() -> None:
     assert pl.DataFrame({"a": [0, 0], "b": [[2], [1]]}).sort(["a", "b"]).to_dict(
         as_series=False
     ) == {"a": [0, 0], "b": [[1], [2]]}
+
+
+def test_sort_nan_position() -> None:
+    nan = float("nan")
+    s = pl.Series("a", [nan, 1.0, None, 3.0, nan, 2.0])
+
+    def sorted_as(values: list[float | None], **kwargs: Any) -> None:
+        assert_series_equal(s.sort(**kwargs), pl.Series("a", values))
+
+    sorted_as([None, 1.0, 2.0, 3.0, nan, nan])
+    sorted_as([None, nan, nan, 1.0, 2.0, 3.0], nan_position="first")
+    sorted_as([None, 1.0, 2.0, 3.0, nan, nan], nan_position="last")
+    sorted_as([None, nan, nan, 3.0, 2.0, 1.0], descending=True)
+    sorted_as([None, 3.0, 2.0, 1.0, nan, nan], descending=True, nan_position="last")
+    sorted_as([nan, nan, 1.0, 2.0, 3.0, None], nulls_last=True, nan_position="first")
+
+    options: list[dict[str, Any]] = [
+        {"nan_position": "first"},
+        {"descending": True, "nan_position": "last"},
+    ]
+    for kwargs in options:
+        assert_series_equal(s.gather(s.arg_sort(**kwargs)), s.sort(**kwargs))
+
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "a": [nan, 1.0, 2.0, nan]})
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").sort(nan_position="first")
+    )
+    assert out["a"].list.first().is_nan().all()
+    assert out["a"].list.last().to_list() == [1.0, 2.0]
+
+    # Non-float columns are unaffected.
+    assert pl.Series([2, 1]).sort(nan_position="first").to_list() == [1, 2]
+
+    with pytest.raises(ValueError, match="`nan_position` must be one of"):
+        s.sort(nan_position="middle")  # type: ignore[arg-type]
+
+
+def test_sort_multiple_integer_and_enum_keys() -> None:
+    enum = pl.Enum(["low", "mid", "high"])
+    df = pl.DataFrame(
+        {
+            "a": pl.Series(["mid", "low", None, "high", "low", "mid"], dtype=enum),
+            "b": [3, None, 1, -2, 7, 3],
+            "c": pl.Series([1, 2, 3, 4, 5, 0], dtype=pl.UInt8),
+        }
+    )
+    out = df.sort(["a", "b", "c"], descending=[False, True, False], nulls_last=True)
+    expected = df[[4, 1, 5, 0, 3, 2]]
+    assert_frame_equal(out, expected)
+
+    out = df.sort(["b", "a"], descending=True, nulls_last=[False, True])
+    assert out["b"].to_list() == [None, 7, 3, 3, 1, -2]
+    assert out["a"].to_list() == ["low", "low", "mid", "mid", None, "high"]
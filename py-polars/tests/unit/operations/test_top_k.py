@@ -544,3 +544,35 @@ def test_top_k_sorted_21260() -> None:
     assert s.bottom_k(3).sort().to_list() == [1, 2, 3]
     assert s.sort(descending=False).bottom_k(3).sort().to_list() == [1, 2, 3]
     assert s.sort(descending=True).bottom_k(3).sort().to_list() == [1, 2, 3]
+
+
+def test_group_by_top_k_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 1, 2],
+            "a": [3, 5, 5, None, 1, 5, 2],
+            "b": ["x", "y", "z", "u", "v", "w", "t"],
+        }
+    )
+    out = df.group_by("g").top_k_by(["a", "b"], 2).sort("g", maintain_order=True)
+    expected = pl.DataFrame(
+        {"g": [1, 1, 2, 2], "a": [5, 5, 2, 1], "b": ["z", "y", "t", "v"]}
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.lazy().group_by("g").bottom_k_by("a", 1).sort("g").collect()
+    expected = pl.DataFrame({"g": [1, 2], "a": [3, 1], "b": ["x", "v"]})
+    assert_frame_equal(out, expected)
+
+    # Ties keep whole rows together, also for k larger than the group.
+    out = df.group_by("g", maintain_order=True).top_k_by(["a", "b"], 5, reverse=True)
+    assert out.rows() == [
+        (1, 3, "x"),
+        (1, 5, "w"),
+        (1, 5, "y"),
+        (1, 5, "z"),
+        (2, 1, "v"),
+        (2, 2, "t"),
+        (2, None, "u"),
+    ]
+
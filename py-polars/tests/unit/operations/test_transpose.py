@@ -224,3 +224,34 @@ def test_transpose_multiple_chunks() -> None:
 def test_nested_struct_transpose_21923() -> None:
     df = pl.DataFrame({"x": [{"a": {"b": 1, "c": 2}}]})
     assert df.transpose().item() == df.item()
+
+
+@pytest.mark.may_fail_auto_streaming
+def test_transpose_dtype() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": [1.0, 2.0]})
+    result = df.transpose(dtype=pl.Int16)
+    expected = pl.DataFrame(
+        {"column_0": [1, 1], "column_1": [2, 2]},
+        schema={"column_0": pl.Int16, "column_1": pl.Int16},
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(InvalidOperationError):
+        pl.DataFrame({"a": [1], "b": ["x"]}).transpose(dtype=pl.Int64)
+
+
+@pytest.mark.may_fail_auto_streaming
+def test_transpose_as_struct() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    result = df.transpose(include_header=True, as_struct=True)
+    expected = pl.DataFrame(
+        {
+            "column": ["a", "b"],
+            "column_0": [{"i64": 1, "str": None}, {"i64": None, "str": "x"}],
+            "column_1": [{"i64": 2, "str": None}, {"i64": None, "str": "y"}],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(ValueError, match="cannot set both"):
+        df.transpose(dtype=pl.String, as_struct=True)
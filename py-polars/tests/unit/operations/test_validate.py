@@ -0,0 +1,104 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_frame_equal
+
+
+def test_assert() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2], "x": [1, None, 3]})
+
+    result = df.select(pl.col("x").assert_(pl.col("x") > 0, "positive") * 2)
+    assert_frame_equal(result, pl.DataFrame({"x": [2, None, 6]}))
+
+    result = df.group_by("g", maintain_order=True).agg(
+        pl.col("x").assert_(pl.col("x") < 4).sum()
+    )
+    assert_frame_equal(result, pl.DataFrame({"g": [1, 2], "x": [1, 3]}))
+
+    with pytest.raises(
+        AssertionError, match=r'^below 3: 1 of 3 values of "x" failed, including \[3\]'
+    ):
+        df.select(pl.col("x").assert_(pl.col("x") < 3, "below 3"))
+
+    with pytest.raises(AssertionError, match=r"^3 of 3 values"):
+        df.lazy().select(pl.col("x").assert_(pl.lit(False))).collect()
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="must be boolean"):
+        df.select(pl.col("x").assert_(pl.col("x")))
+
+
+@pytest.fixture
+def orders() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "id": [1, 2, 2, 4, 5],
+            "customer": ["a", "b", None, "z", "a"],
+            "amount": [10.0, None, -5.0, 20.0, 300.0],
+        }
+    )
+
+
+def test_validate_return(orders: pl.DataFrame) -> None:
+    customers = pl.DataFrame({"name": ["a", "b"]})
+    result = orders.validate(
+        unique="id",
+        not_null=["customer", "amount"],
+        ranges={"amount": (0, 100)},
+        references={"customer": (customers.lazy(), "name")},
+        on_violation="return",
+    )
+    expected = pl.DataFrame(
+        {
+            "index": [1, 2, 2, 1, 2, 4, 3],
+            "constraint": [
+                "unique(id)",
+                "unique(id)",
+                "not_null(customer)",
+                "not_null(amount)",
+                "range(amount)",
+                "range(amount)",
+                "reference(customer)",
+            ],
+        },
+        schema_overrides={"index": pl.get_index_type()},
+    )
+    assert_frame_equal(result, expected)
+
+    lf = orders.lazy().validate(ranges={"id": (None, 4)}, on_violation="return")
+    assert_frame_equal(
+        lf.collect(),
+        pl.DataFrame(
+            {"index": [4], "constraint": ["range(id)"]},
+            schema_overrides={"index": pl.get_index_type()},
+        ),
+    )
+
+    result = orders.validate(on_violation="return")
+    assert result.is_empty()
+    assert result.schema == expected.schema
+
+
+def test_validate_raise(orders: pl.DataFrame) -> None:
+    assert_frame_equal(orders.validate(unique=["id", "customer"]), orders)
+
+    msg = (
+        r"validation failed:\n"
+        r"  unique\(id\): 2 rows, including rows \[1, 2\]\n"
+        r"  range\(amount\): 1 row, including rows \[2\]"
+    )
+    with pytest.raises(ComputeError, match=msg):
+        orders.validate(unique="id", ranges={"amount": (0, None)})
+
+    lf = orders.lazy().validate(not_null="customer")
+    with pytest.raises(ComputeError, match=r"not_null\(customer\): 1 row"):
+        lf.select("id").collect()
+
+    # The check runs before any later filter.
+    with pytest.raises(ComputeError, match=r"not_null\(customer\)"):
+        lf.filter(pl.col("customer").is_not_null()).collect()
+
+    with pytest.raises(ValueError, match="`on_violation` must be one of"):
+        orders.validate(on_violation="ignore")  # type: ignore[arg-type]
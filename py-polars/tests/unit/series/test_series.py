@@ -57,6 +57,29 @@ def test_cum_agg_with_nulls() -> None:
     assert_series_equal(s.cum_prod(), pl.Series("a", [None, 2, None, 14, 112, None]))
 
 
+def test_cum_prod_log_domain() -> None:
+    s = pl.Series("a", [1.5, -2.0, None, 4.0, 0.0, 3.0])
+    for reverse in [False, True]:
+        assert_series_equal(
+            s.cum_prod(reverse=reverse, log_domain=True), s.cum_prod(reverse=reverse)
+        )
+
+    s = pl.Series("a", [1, 2, 3, 4], dtype=pl.Int32)
+    assert_series_equal(
+        s.cum_prod(log_domain=True), pl.Series("a", [1.0, 2.0, 6.0, 24.0])
+    )
+    assert s.cast(pl.Float32).cum_prod(log_domain=True).dtype == pl.Float32
+
+    # The intermediate product underflows, but the next one doesn't.
+    s = pl.Series([1e-200, 1e-200, 1e300])
+    assert s.cum_prod()[2] == 0.0
+    assert s.cum_prod(log_domain=True)[2] == pytest.approx(1e-100)
+
+    df = pl.DataFrame({"g": [1, 2, 1, 2], "a": [2.0, 3.0, -4.0, 5.0]})
+    out = df.select(pl.col("a").cum_prod(log_domain=True).over("g"))
+    assert_series_equal(out["a"], pl.Series("a", [2.0, 3.0, -8.0, 15.0]))
+
+
 def test_cum_min_max_bool() -> None:
     s = pl.Series("a", [None, True, True, None, False, None, True, False, False, None])
     assert_series_equal(s.cum_min().cast(pl.Int32), s.cast(pl.Int32).cum_min())
@@ -110,6 +110,39 @@ def test_join_cross_11927() -> None:
     assert res.collect().is_empty()
 
 
+def test_join_lateral() -> None:
+    df = pl.DataFrame(  # noqa: F841
+        {
+            "id": [1, 2, 3],
+            "tags": [["x", "y"], [], ["z"]],
+            "info": [{"n": 1, "s": "a"}, {"n": 2, "s": "b"}, {"n": 3, "s": "c"}],
+        }
+    )
+    res = pl.sql(
+        "SELECT df.id, u.tag FROM df CROSS JOIN UNNEST(df.tags) AS u(tag)"
+    ).collect()
+    assert res.rows() == [(1, "x"), (1, "y"), (3, "z")]
+
+    res = pl.sql(
+        "SELECT id, tag FROM df LEFT JOIN UNNEST(df.tags) AS u(tag) ON TRUE"
+    ).collect()
+    assert res.rows() == [(1, "x"), (1, "y"), (2, None), (3, "z")]
+
+    # struct fields become columns
+    res = pl.sql("SELECT id, n, s FROM df CROSS JOIN UNNEST(df.info) AS u").collect()
+    assert res.rows() == [(1, 1, "a"), (2, 2, "b"), (3, 3, "c")]
+
+    res = pl.sql(
+        """
+        SELECT df.id, t.v
+        FROM df CROSS JOIN LATERAL (
+          SELECT u.v FROM UNNEST([1, 2, 3]) AS u(v) WHERE u.v < df.id
+        ) AS t
+        """
+    ).collect()
+    assert res.rows() == [(2, 1), (3, 1), (3, 2)]
+
+
 @pytest.mark.parametrize(
     "join_clause",
     [
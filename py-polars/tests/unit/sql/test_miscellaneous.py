@@ -344,6 +344,23 @@ def test_read_csv(tmp_path: Path) -> None:
     res = pl.sql(f"SELECT * FROM read_csv('{csv_target}')").collect()
     assert_frame_equal(df, res)
 
+    # named options are mapped to the scan options
+    df.write_csv(csv_target, separator=";", include_header=False)
+    res = pl.sql(
+        f"""
+        SELECT * FROM read_csv(
+          '{csv_target}', delim => ';', header => false, n_rows => 2
+        )
+        """
+    ).collect()
+    assert res.rows() == [
+        ("lorem", -1, date(1969, 7, 5)),
+        (None, None, date(1999, 12, 31)),
+    ]
+
+    with pytest.raises(SQLSyntaxError, match="`read_csv` has no option 'delimiter'"):
+        pl.sql(f"SELECT * FROM read_csv('{csv_target}', delimiter => ';')")
+
     with pytest.raises(
         SQLSyntaxError,
         match="`read_csv` expects a single file path; found 3 arguments",
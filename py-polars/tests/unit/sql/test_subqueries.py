@@ -146,3 +146,29 @@ def test_in_subquery() -> None:
             """,
             eager=True,
         )
+
+
+def test_correlated_subquery() -> None:
+    customers = pl.DataFrame({"id": [1, 2, 3], "name": ["a", "b", "c"]})
+    orders = pl.DataFrame({"cid": [1, 1, 3], "amount": [10, 20, 5]})
+
+    with pl.SQLContext(customers=customers, orders=orders) as ctx:
+        res = ctx.execute(
+            """
+            SELECT
+              c.name,
+              (SELECT COUNT(*) FROM orders o WHERE o.cid = c.id) AS n_orders
+            FROM customers c
+            WHERE NOT EXISTS (
+              SELECT 1 FROM orders o WHERE o.cid = c.id AND o.amount < 10
+            )
+            """,
+            eager=True,
+        )
+    assert_frame_equal(
+        res,
+        pl.DataFrame(
+            {"name": ["a", "b"], "n_orders": [2, 0]},
+            schema_overrides={"n_orders": pl.UInt32},
+        ),
+    )
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import re
 from datetime import date
+from typing import TYPE_CHECKING
 
 import pytest
 
@@ -9,6 +10,9 @@ import polars as pl
 from polars.exceptions import SQLInterfaceError
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 
 @pytest.fixture
 def test_frame() -> pl.LazyFrame:
@@ -22,6 +26,36 @@ def test_frame() -> pl.LazyFrame:
     )
 
 
+@pytest.mark.parametrize("extension", ["csv", "ipc", "ndjson", "parquet"])
+def test_copy_to(extension: str, test_frame: pl.LazyFrame, tmp_path: Path) -> None:
+    path = tmp_path / f"out.{extension}"
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        res = ctx.execute(f"COPY (SELECT x, y FROM frame WHERE x > 1) TO '{path}'")
+        assert_frame_equal(res, pl.DataFrame({"Response": ["COPY"]}))
+
+    read = {
+        "csv": pl.read_csv,
+        "ipc": pl.read_ipc,
+        "ndjson": pl.read_ndjson,
+        "parquet": pl.read_parquet,
+    }[extension]
+    assert_frame_equal(
+        read(path),
+        pl.DataFrame({"x": [2, 3], "y": ["bbb", "ccc"]}),
+        check_dtypes=False,
+    )
+
+
+def test_copy_to_options(test_frame: pl.LazyFrame, tmp_path: Path) -> None:
+    path = tmp_path / "out.txt"
+    with pl.SQLContext(frame=test_frame) as ctx:
+        ctx.execute(f"COPY frame (x, y) TO '{path}' (FORMAT CSV, DELIMITER ';')")
+        assert path.read_text().splitlines() == ["x;y", "1;aaa", "2;bbb", "3;ccc"]
+
+        with pytest.raises(SQLInterfaceError, match="cannot infer the format"):
+            ctx.execute(f"COPY frame TO '{tmp_path / 'out'}'")
+
+
 @pytest.mark.parametrize(
     ("delete_constraint", "expected_ids"),
     [
@@ -58,6 +92,18 @@ def test_delete_clause(delete_constraint: str, expected_ids: set[int]) -> None:
     assert set(res["id"]) == expected_ids
 
 
+def test_describe_table(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        res = ctx.execute("DESCRIBE frame")
+        expected = pl.DataFrame(
+            {
+                "column_name": ["x", "y", "z"],
+                "data_type": ["UTINYINT", "VARCHAR", "DATE"],
+            }
+        )
+        assert_frame_equal(res, expected)
+
+
 def test_drop_table(test_frame: pl.LazyFrame) -> None:
     # 'drop' completely removes the table from sql context
     expected = pl.DataFrame()
@@ -89,6 +135,52 @@ def test_explain_query(test_frame: pl.LazyFrame) -> None:
         )
 
 
+def test_information_schema(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(tbl2=test_frame, tbl1=test_frame, eager=True) as ctx:
+        res = ctx.execute("SELECT * FROM information_schema.tables")
+        expected = pl.DataFrame(
+            {"table_name": ["tbl1", "tbl2"], "table_type": ["BASE TABLE"] * 2}
+        )
+        assert_frame_equal(res, expected)
+
+        res = ctx.execute(
+            """
+            SELECT column_name, ordinal_position, data_type
+            FROM information_schema.columns
+            WHERE table_name = 'tbl1'
+            """
+        )
+        expected = pl.DataFrame(
+            {
+                "column_name": ["x", "y", "z"],
+                "ordinal_position": [1, 2, 3],
+                "data_type": ["UTINYINT", "VARCHAR", "DATE"],
+            }
+        )
+        assert_frame_equal(res, expected)
+
+
+def test_insert_into(test_frame: pl.LazyFrame) -> None:
+    with pl.SQLContext(frame=test_frame, eager=True) as ctx:
+        res = ctx.execute("INSERT INTO frame VALUES (4, 'ddd', '2024-01-01')")
+        assert_frame_equal(res, pl.DataFrame({"Response": ["INSERT INTO"]}))
+
+        ctx.execute("INSERT INTO frame (y, x) SELECT y || '!', x * 2 FROM frame")
+        res = ctx.execute("SELECT x, y FROM frame")
+        expected = pl.DataFrame(
+            {
+                "x": [1, 2, 3, 4, 2, 4, 6, 8],
+                "y": ["aaa", "bbb", "ccc", "ddd", "aaa!", "bbb!", "ccc!", "ddd!"],
+            },
+            schema_overrides={"x": pl.UInt8},
+        )
+        assert_frame_equal(res, expected)
+        assert ctx.execute("SELECT z FROM frame")["z"].null_count() == 4
+
+        with pytest.raises(SQLInterfaceError, match="expects 3 columns; found 1"):
+            ctx.execute("INSERT INTO frame VALUES (5)")
+
+
 def test_show_tables(test_frame: pl.LazyFrame) -> None:
     # 'show tables' lists all tables registered with the sql context in sorted order
     with pl.SQLContext(
@@ -165,6 +165,18 @@ def test_select_replace(
         assert res.columns == expected_columns
 
 
+def test_select_exclude_replace_qualify(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT * EXCLUDE (Address, City) REPLACE (ID * 2 AS ID)
+        FROM self
+        QUALIFY ROW_NUMBER() OVER (ORDER BY ID DESC) <= 2
+        ORDER BY ID
+        """
+    )
+    assert res.rows() == [(1332, "Diana", "Prince"), (1998, "Clark", "Kent")]
+
+
 def test_select_wildcard_errors(df: pl.DataFrame) -> None:
     # EXCLUDE and ILIKE are not allowed together
     with pytest.raises(SQLInterfaceError, match="ILIKE"):
@@ -939,6 +939,7 @@ def test_warn_unstable(recwarn: pytest.WarningsRecorder) -> None:
     [
         ("POLARS_ENGINE_AFFINITY", "set_engine_affinity", "gpu", "gpu"),
         ("POLARS_AUTO_STRUCTIFY", "set_auto_structify", True, "1"),
+        ("POLARS_RECHUNK_POLICY", "set_rechunk_policy", 8, "8"),
         ("POLARS_FMT_MAX_COLS", "set_tbl_cols", 12, "12"),
         ("POLARS_FMT_MAX_ROWS", "set_tbl_rows", 3, "3"),
         ("POLARS_FMT_STR_LEN", "set_fmt_str_lengths", 42, "42"),
@@ -1008,3 +1009,21 @@ def test_unset_config_env_vars(
 
     with pl.Config(**{config_setting: None}):  # type: ignore[arg-type]
         assert environment_variable not in os.environ
+
+
+@pytest.mark.parametrize("policy", ["never", "always_before_join", 1, 10])
+def test_rechunk_policy(policy: Any) -> None:
+    lf = pl.concat(
+        [pl.LazyFrame({"a": [3, 1]}), pl.LazyFrame({"a": [2]})], rechunk=False
+    )
+    with pl.Config(rechunk_policy=policy):
+        assert lf.sort("a").collect()["a"].to_list() == [1, 2, 3]
+        assert lf.join(lf, on="a").collect().height == 3
+        assert lf.group_by("a").len().collect().height == 3
+
+
+def test_rechunk_policy_invalid() -> None:
+    with pytest.raises(ValueError, match="must be one of"):
+        pl.Config.set_rechunk_policy("sometimes")  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match=">= 0"):
+        pl.Config.set_rechunk_policy(-1)
@@ -735,3 +735,15 @@ def test_raise_on_different_results_20104() -> None:
         df.rolling("x", period="3i").agg(
             result=pl.col("x").gather_every(2, offset=1).map_batches(pl.Series.min)
         )
+
+
+def test_error_context() -> None:
+    lf = pl.LazyFrame({"a": ["1", "x"], "b": [1, 2]})
+    with pytest.raises(InvalidOperationError, match="plan node 'with_columns'") as exc:
+        lf.with_columns(c=pl.col("a").cast(pl.Int64) + pl.col("b")).collect()
+
+    context = exc.value.error_context  # type: ignore[attr-defined]
+    assert context["operator"] == "with_columns"
+    assert context["columns"] == ["a", "b"]
+    assert len(context["expressions"]) == 1
+    assert 'col("a")' in context["expressions"][0]